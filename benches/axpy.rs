@@ -0,0 +1,214 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Compares the level-1 BLAS kernels in `qd::slice` against the obvious loops written with
+// the arithmetic operators, both for speed and for accuracy. The accuracy comparison uses
+// a cancellation-heavy axpy, where each element of y is the negative of the rounded
+// product α*x, so that the exact result is just the rounding error of that product. The
+// errors are printed before the timings, as the worst relative error in units of
+// `EPSILON`.
+//
+// Run with `cargo bench --bench axpy`.
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use qd::{slice, Double, Quad};
+use std::hint::black_box;
+
+mod common;
+
+const COUNT: usize = 1 << 14;
+
+fn accuracy(r: &[f64]) {
+    let alpha = Double::E;
+
+    // With an x that only has one component, the exact products and sums fit in a `Quad`
+    let promote = |d: Double| Quad::from((d[0], d[1], 0.0, 0.0));
+    let x: Vec<Double> = r.iter().map(|&u| Double::from(u)).collect();
+    let start: Vec<Double> = x.iter().map(|&u| -(alpha * u)).collect();
+    let mut kernel = start.clone();
+    slice::axpy(alpha, &x, &mut kernel);
+    let naive: Vec<Double> = x.iter().zip(&start).map(|(&u, &v)| alpha * u + v).collect();
+    let error = |y: &[Double]| {
+        let mut worst = 0.0f64;
+        for i in 0..COUNT {
+            let exact = promote(alpha) * promote(x[i]) + promote(start[i]);
+            let err = ((promote(y[i]) - exact) / exact).abs();
+            worst = worst.max(err[0] / Double::EPSILON[0]);
+        }
+        worst
+    };
+    println!(
+        "cancelling axpy Double: kernel {:.3e} eps, naive loop {:.3e} eps",
+        error(&kernel),
+        error(&naive)
+    );
+}
+
+fn axpy(c: &mut Criterion) {
+    let r = common::randoms(COUNT);
+    accuracy(&r);
+
+    let dx: Vec<Double> = r.iter().map(|&a| Double::PI * Double::from(a)).collect();
+    let qx: Vec<Quad> = r.iter().map(|&a| Quad::PI * Quad::from(a)).collect();
+    let dalpha = Double::E;
+    let dbeta = Double::LN_2;
+    let qalpha = Quad::E;
+    let alpha = std::f64::consts::E;
+    let rev: Vec<f64> = r.iter().rev().map(|&u| u - 1.0).collect();
+
+    let mut y = vec![Double::ONE; COUNT];
+    let mut group = c.benchmark_group("axpy Double");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    group.bench_function("kernel", |b| {
+        b.iter(|| slice::axpy(dalpha, black_box(&dx), black_box(&mut y)))
+    });
+    group.bench_function("naive loop", |b| {
+        b.iter(|| {
+            for (v, &u) in black_box(&mut y).iter_mut().zip(black_box(&dx)) {
+                *v = dalpha * u + *v;
+            }
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("scal Double");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    group.bench_function("kernel", |b| {
+        b.iter(|| slice::scal(dalpha, black_box(&mut y)))
+    });
+    group.bench_function("naive loop", |b| {
+        b.iter(|| {
+            for v in black_box(&mut y).iter_mut() {
+                *v = dalpha * *v;
+            }
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("axpby Double");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    group.bench_function("kernel", |b| {
+        b.iter(|| slice::axpby(dalpha, black_box(&dx), dbeta, black_box(&mut y)))
+    });
+    group.bench_function("naive loop", |b| {
+        b.iter(|| {
+            for (v, &u) in black_box(&mut y).iter_mut().zip(black_box(&dx)) {
+                *v = dalpha * u + dbeta * *v;
+            }
+        })
+    });
+    group.finish();
+
+    let mut qy = vec![Quad::ONE; COUNT];
+    let mut group = c.benchmark_group("axpy Quad");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    group.bench_function("kernel", |b| {
+        b.iter(|| slice::axpy(qalpha, black_box(&qx), black_box(&mut qy)))
+    });
+    group.bench_function("naive loop", |b| {
+        b.iter(|| {
+            for (v, &u) in black_box(&mut qy).iter_mut().zip(black_box(&qx)) {
+                *v = qalpha * u + *v;
+            }
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("scal Quad");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    group.bench_function("kernel", |b| {
+        b.iter(|| slice::scal(qalpha, black_box(&mut qy)))
+    });
+    group.bench_function("naive loop", |b| {
+        b.iter(|| {
+            for v in black_box(&mut qy).iter_mut() {
+                *v = qalpha * *v;
+            }
+        })
+    });
+    group.finish();
+
+    let mut y = vec![Double::ONE; COUNT];
+    let mut group = c.benchmark_group("axpy f64 into Double");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    group.bench_function("kernel", |b| {
+        b.iter(|| slice::axpy_f64_into_double(alpha, black_box(&r), black_box(&mut y)))
+    });
+    group.bench_function("naive loop", |b| {
+        b.iter(|| {
+            let a = Double::from(alpha);
+            for (v, &u) in black_box(&mut y).iter_mut().zip(black_box(&r)) {
+                *v = a * Double::from(u) + *v;
+            }
+        })
+    });
+    group.finish();
+
+    let mut ry = r.clone();
+    let mut group = c.benchmark_group("axpy Quad into f64");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    group.bench_function("kernel", |b| {
+        b.iter(|| slice::axpy_quad_into_f64(qalpha, black_box(&r), black_box(&mut ry)))
+    });
+    group.bench_function("naive loop", |b| {
+        b.iter(|| {
+            for (v, &u) in black_box(&mut ry).iter_mut().zip(black_box(&r)) {
+                *v = (qalpha * Quad::from(u) + Quad::from(*v))[0];
+            }
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("dot f64 into Double");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    group.bench_function("kernel", |b| {
+        b.iter(|| slice::dot_f64_into_double(black_box(&r), black_box(&rev)))
+    });
+    group.bench_function("naive loop", |b| {
+        b.iter(|| {
+            let mut s = Double::ZERO;
+            for (&u, &v) in black_box(&r).iter().zip(black_box(&rev)) {
+                s += Double::from(u) * Double::from(v);
+            }
+            s
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("dot f64 into Quad");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    group.bench_function("kernel", |b| {
+        b.iter(|| slice::dot_f64_into_quad(black_box(&r), black_box(&rev)))
+    });
+    group.bench_function("naive loop", |b| {
+        b.iter(|| {
+            let mut s = Quad::ZERO;
+            for (&u, &v) in black_box(&r).iter().zip(black_box(&rev)) {
+                s += Quad::from(u) * Quad::from(v);
+            }
+            s
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("dot Quad");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    group.bench_function("kernel", |b| {
+        b.iter(|| slice::dot(black_box(&qx), black_box(&qx)))
+    });
+    group.bench_function("naive loop", |b| {
+        b.iter(|| {
+            let mut s = Quad::ZERO;
+            for &u in black_box(&qx).iter() {
+                s += u * u;
+            }
+            s
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, axpy);
+criterion_main!(benches);
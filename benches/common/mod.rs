@@ -0,0 +1,41 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// What the benchmarks share, which is where their operands come from. Each benchmark is its
+// own crate and includes this with `mod common`, and not all of them use all of it.
+#![allow(dead_code)]
+
+/// The seed that every benchmark starts from, so that each one times the same operands
+/// from one run to the next.
+pub const SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+/// A xorshift64 random number generator, which is plenty random for picking operands.
+pub struct Rng(u64);
+
+impl Rng {
+    /// Creates a generator from a seed, which must not be zero.
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    /// Returns the next random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a random `f64` in [0, 1), made from the top 53 bits of the next `u64`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Returns `count` random `f64`s in [0.5, 1.5).
+pub fn randoms(count: usize) -> Vec<f64> {
+    let mut rng = Rng::new(SEED);
+    (0..count).map(|_| rng.next_f64() + 0.5).collect()
+}
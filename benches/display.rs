@@ -0,0 +1,104 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Measures formatting `Double`s and `Quad`s with a few representative format specifiers,
+// including full precision and width/fill. Each format writes into the same `String`, so
+// that the time is spent on formatting rather than on allocating the output. Values near
+// the ends of the exponent range are timed both formatting and parsing, since those are
+// the ones that need the largest powers of ten.
+//
+// Run with `cargo bench --bench display`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use qd::{dd, qd, Double, Quad};
+use std::fmt::Write;
+use std::hint::black_box;
+
+// Times formatting `$value` with each of the specifiers
+macro_rules! formats {
+    ($group:ident, $value:expr, $buf:ident, $($spec:literal),* $(,)?) => {
+        $(
+            $group.bench_function($spec, |b| {
+                b.iter(|| {
+                    $buf.clear();
+                    write!($buf, $spec, black_box($value)).unwrap();
+                    black_box(&$buf);
+                })
+            });
+        )*
+    };
+}
+
+// Times parsing each of the strings in `$s`
+macro_rules! parse {
+    ($group:ident, $ty:ty, $($s:literal),* $(,)?) => {
+        $(
+            $group.bench_function(concat!("parse ", $s), |b| {
+                b.iter(|| black_box(black_box($s).parse::<$ty>().unwrap()))
+            });
+        )*
+    };
+}
+
+fn double(c: &mut Criterion) {
+    let mut buf = String::with_capacity(256);
+
+    let mut group = c.benchmark_group("Double");
+    formats!(
+        group,
+        Double::PI,
+        buf,
+        "{}",
+        "{:.30}",
+        "{:>50.30}",
+        "{:*^50.30}",
+        "{:+050.30}",
+        "{:e}",
+        "{:#}",
+    );
+    group.finish();
+
+    let mut group = c.benchmark_group("Double 1e300");
+    formats!(group, dd!("3.14159265358979e300"), buf, "{:.20e}");
+    parse!(group, Double, "3.14159265358979e300");
+    group.finish();
+
+    let mut group = c.benchmark_group("Double 1e-300");
+    formats!(group, dd!("3.14159265358979e-300"), buf, "{:.20e}");
+    parse!(group, Double, "3.14159265358979e-300");
+    group.finish();
+}
+
+fn quad(c: &mut Criterion) {
+    let mut buf = String::with_capacity(256);
+
+    let mut group = c.benchmark_group("Quad");
+    formats!(
+        group,
+        Quad::PI,
+        buf,
+        "{}",
+        "{:.60}",
+        "{:>80.60}",
+        "{:*^80.60}",
+        "{:+080.60}",
+        "{:e}",
+        "{:#}",
+    );
+    group.finish();
+
+    let mut group = c.benchmark_group("Quad 1e300");
+    formats!(group, qd!("3.14159265358979e300"), buf, "{:.20e}");
+    parse!(group, Quad, "3.14159265358979e300");
+    group.finish();
+
+    let mut group = c.benchmark_group("Quad 1e-300");
+    formats!(group, qd!("3.14159265358979e-300"), buf, "{:.20e}");
+    parse!(group, Quad, "3.14159265358979e-300");
+    group.finish();
+}
+
+criterion_group!(benches, double, quad);
+criterion_main!(benches);
@@ -0,0 +1,92 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Compares parsing fixed-point fields with `from_ascii_fixed` against the obvious
+// alternative of converting the bytes to a string, inserting the decimal point, and
+// parsing that with `FromStr`.
+//
+// Run with `cargo bench --bench fixed`.
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use qd::{Double, Quad};
+use std::hint::black_box;
+use std::str::FromStr;
+
+mod common;
+
+const DECIMALS: usize = 4;
+
+// Fixed-width, zero-padded price fields like those in a market data feed
+fn fields() -> Vec<[u8; 12]> {
+    let mut rng = common::Rng::new(common::SEED);
+    (0..10_000)
+        .map(|_| {
+            let mut field = [b'0'; 12];
+            let mut n = rng.next_u64() % 100_000_000_000;
+            for b in field.iter_mut().rev() {
+                *b = b'0' + (n % 10) as u8;
+                n /= 10;
+            }
+            field
+        })
+        .collect()
+}
+
+// The string path: validate as UTF-8, trim, insert the decimal point, and parse
+fn via_str<T: FromStr>(field: &[u8]) -> T
+where
+    T::Err: std::fmt::Debug,
+{
+    let s = std::str::from_utf8(field).unwrap().trim();
+    let (int, frac) = s.split_at(s.len() - DECIMALS);
+    let mut owned = String::with_capacity(s.len() + 1);
+    owned.push_str(int);
+    owned.push('.');
+    owned.push_str(frac);
+    owned.parse().unwrap()
+}
+
+fn fixed(c: &mut Criterion) {
+    let fields = fields();
+
+    let mut group = c.benchmark_group("Double");
+    group.throughput(Throughput::Elements(fields.len() as u64));
+    group.bench_function("from_ascii_fixed", |b| {
+        b.iter(|| {
+            for field in &fields {
+                black_box(Double::from_ascii_fixed(black_box(field), DECIMALS as u32).unwrap());
+            }
+        })
+    });
+    group.bench_function("string path", |b| {
+        b.iter(|| {
+            for field in &fields {
+                black_box(via_str::<Double>(black_box(field)));
+            }
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("Quad");
+    group.throughput(Throughput::Elements(fields.len() as u64));
+    group.bench_function("from_ascii_fixed", |b| {
+        b.iter(|| {
+            for field in &fields {
+                black_box(Quad::from_ascii_fixed(black_box(field), DECIMALS as u32).unwrap());
+            }
+        })
+    });
+    group.bench_function("string path", |b| {
+        b.iter(|| {
+            for field in &fields {
+                black_box(via_str::<Quad>(black_box(field)));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, fixed);
+criterion_main!(benches);
@@ -0,0 +1,151 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Compares accumulating through `qd::mixed::AccumView` against the two obvious
+// alternatives: keeping the state in `Double`s and adding to them directly, which is
+// inaccurate, and keeping it in `Quad`s, which takes twice the memory. Each timestep adds
+// a batch of small contributions of alternating sign to every element of the state, the
+// way a simulation adds up forces. The errors are printed before the timings, as the worst
+// relative error of the final state against the `Quad` state, in units of
+// `Double::EPSILON`. The view's
+// state is rounded to `Double` once per timestep rather than once per addition, so its
+// error grows with the number of timesteps, but far more slowly. The memory is what each
+// approach keeps between timesteps plus the scratch it needs during one; the view works
+// on a block of the state at a time, so its scratch only needs to be as long as a block.
+//
+// Run with `cargo bench --bench mixed`.
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use qd::mixed::AccumView;
+use qd::{Double, Quad};
+use std::mem::size_of;
+
+mod common;
+
+const COUNT: usize = 1 << 12;
+const STEPS: usize = 20;
+const CONTRIBUTIONS: usize = 16;
+const BLOCK: usize = 256;
+
+fn contributions() -> Vec<f64> {
+    let mut rng = common::Rng::new(common::SEED);
+    (0..CONTRIBUTIONS)
+        .map(|k| {
+            let x = (rng.next_f64() + 0.5) * 1e-20;
+            if k % 2 == 0 {
+                x
+            } else {
+                -x
+            }
+        })
+        .collect()
+}
+
+fn start() -> Vec<Double> {
+    (0..COUNT)
+        .map(|i| Double::PI * Double::from(i as f64 + 1.0))
+        .collect()
+}
+
+fn promote(d: Double) -> Quad {
+    Quad::new(d[0], d[1], 0.0, 0.0)
+}
+
+fn run_quad(quads: &mut [Quad], cs: &[f64]) {
+    for _ in 0..STEPS {
+        for q in quads.iter_mut() {
+            for &c in cs {
+                *q += Quad::new(c, 0.0, 0.0, 0.0);
+            }
+        }
+    }
+}
+
+fn run_double(doubles: &mut [Double], cs: &[f64]) {
+    for _ in 0..STEPS {
+        for d in doubles.iter_mut() {
+            for &c in cs {
+                *d += Double::new(c, 0.0);
+            }
+        }
+    }
+}
+
+// One scratch slice, only as long as a block, serves every block of the state
+fn run_view(state: &mut [Double], scratch: &mut [Double], cs: &[f64]) {
+    for _ in 0..STEPS {
+        for block in state.chunks_mut(BLOCK) {
+            let n = block.len();
+            let mut view = AccumView::with_scratch(block, &mut scratch[..n]);
+            for i in 0..n {
+                for &c in cs {
+                    view.add_f64(i, c);
+                }
+            }
+            view.flush();
+        }
+    }
+}
+
+fn report(name: &str, error: f64, bytes: usize) {
+    println!(
+        "{:<12} error {:>10.3e} eps   memory {:>7} KiB",
+        name,
+        error,
+        bytes / 1024
+    );
+}
+
+fn mixed(c: &mut Criterion) {
+    let cs = contributions();
+    let mut scratch = vec![Double::ZERO; BLOCK];
+
+    let mut quads: Vec<Quad> = start().into_iter().map(promote).collect();
+    run_quad(&mut quads, &cs);
+    let error = |state: &[Double]| {
+        let mut worst = 0.0f64;
+        for (&d, &q) in state.iter().zip(&quads) {
+            let err = ((promote(d) - q) / q).abs();
+            worst = worst.max(err[0] / Double::EPSILON[0]);
+        }
+        worst
+    };
+
+    let mut doubles = start();
+    run_double(&mut doubles, &cs);
+    report("Double", error(&doubles), COUNT * size_of::<Double>());
+    let mut state = start();
+    run_view(&mut state, &mut scratch, &cs);
+    report(
+        "AccumView",
+        error(&state),
+        (COUNT + BLOCK) * size_of::<Double>(),
+    );
+    report("Quad", 0.0, COUNT * size_of::<Quad>());
+
+    let mut group = c.benchmark_group("mixed");
+    group.throughput(Throughput::Elements((COUNT * STEPS * CONTRIBUTIONS) as u64));
+    group.bench_function("Double", |b| {
+        b.iter_batched_ref(start, |s| run_double(s, &cs), BatchSize::LargeInput)
+    });
+    group.bench_function("AccumView", |b| {
+        b.iter_batched_ref(
+            start,
+            |s| run_view(s, &mut scratch, &cs),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("Quad", |b| {
+        b.iter_batched_ref(
+            || start().into_iter().map(promote).collect::<Vec<_>>(),
+            |s| run_quad(s, &cs),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, mixed);
+criterion_main!(benches);
@@ -0,0 +1,148 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Measures the basic operations (add, mul, div, sqr, cube, sqrt, recip_sqrt) and two of
+// the transcendental functions (exp, sin) for both types. Each operation is applied to
+// every element of a vector of full-precision operands, so the times are throughput: the
+// operations on different elements don't depend on each other and can overlap, just as
+// they do in a loop over an array. `mul` is also timed as a dependent chain, where each
+// product is the next multiplicand, which shows latency instead. Squaring is timed both as
+// `sqr` and as a number multiplied by itself, and the reciprocal square root both as
+// `recip_sqrt` and as 1 divided by `sqrt`, to show what the dedicated methods save.
+//
+// Run with `cargo bench --bench ops`, and with `cargo bench --bench ops --features sloppy`
+// to compare the faster multiplication that the `sloppy` feature provides.
+
+use criterion::measurement::WallTime;
+use criterion::{criterion_group, criterion_main, BenchmarkGroup, Criterion, Throughput};
+use qd::{Double, Quad};
+use std::hint::black_box;
+
+mod common;
+
+const COUNT: usize = 1 << 12;
+
+// Times a unary operation over every element of `x`
+fn unary<T: Copy, F: Fn(T) -> T>(group: &mut BenchmarkGroup<WallTime>, op: &str, x: &[T], f: F) {
+    let mut y = x.to_vec();
+    group.bench_function(op, |b| {
+        b.iter(|| {
+            for (v, &a) in black_box(&mut y).iter_mut().zip(black_box(x)) {
+                *v = f(a);
+            }
+        })
+    });
+}
+
+// Times a binary operation over every pair of elements of `x` and `y`
+fn binary<T: Copy, F: Fn(T, T) -> T>(
+    group: &mut BenchmarkGroup<WallTime>,
+    op: &str,
+    x: &[T],
+    y: &[T],
+    f: F,
+) {
+    let mut z = x.to_vec();
+    group.bench_function(op, |b| {
+        b.iter(|| {
+            for ((v, &a), &b) in black_box(&mut z)
+                .iter_mut()
+                .zip(black_box(x))
+                .zip(black_box(y))
+            {
+                *v = f(a, b);
+            }
+        })
+    });
+}
+
+fn double(c: &mut Criterion) {
+    let r = common::randoms(4 * COUNT);
+
+    // Full-precision operands between 1/3 and 1, with every component in use
+    let x: Vec<Double> = r[..COUNT]
+        .iter()
+        .map(|&a| Double::ONE / Double::from(a * 2.0 + 0.5))
+        .collect();
+    let y: Vec<Double> = r[COUNT..2 * COUNT]
+        .iter()
+        .map(|&a| Double::ONE / Double::from(a * 2.0 + 0.5))
+        .collect();
+    // Multipliers close to 1, so that the chain neither overflows nor underflows
+    let near: Vec<Double> = x
+        .iter()
+        .map(|&a| Double::ONE + (a - Double::from(0.5)) * Double::from(0.001))
+        .collect();
+
+    let mut group = c.benchmark_group("Double");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    binary(&mut group, "add", &x, &y, |a, b| a + b);
+    binary(&mut group, "mul", &x, &y, |a, b| a * b);
+    binary(&mut group, "div", &x, &y, |a, b| a / b);
+    unary(&mut group, "mul (self)", &x, |a| a * a);
+    unary(&mut group, "sqr", &x, |a| a.sqr());
+    unary(&mut group, "cube", &x, |a| a.cube());
+    unary(&mut group, "sqrt", &x, |a| a.sqrt());
+    unary(&mut group, "recip_sqrt", &x, |a| a.recip_sqrt());
+    unary(&mut group, "1 / sqrt", &x, |a| Double::ONE / a.sqrt());
+    unary(&mut group, "exp", &x, |a| a.exp());
+    unary(&mut group, "sin", &x, |a| a.sin());
+    group.bench_function("mul (chain)", |b| {
+        b.iter(|| {
+            let mut p = Double::ONE;
+            for &a in black_box(&near) {
+                p *= a;
+            }
+            p
+        })
+    });
+    group.finish();
+}
+
+fn quad(c: &mut Criterion) {
+    let r = common::randoms(4 * COUNT);
+
+    // Full-precision operands between 1/3 and 1, with every component in use
+    let x: Vec<Quad> = r[2 * COUNT..3 * COUNT]
+        .iter()
+        .map(|&a| Quad::ONE / Quad::from(a * 2.0 + 0.5))
+        .collect();
+    let y: Vec<Quad> = r[3 * COUNT..]
+        .iter()
+        .map(|&a| Quad::ONE / Quad::from(a * 2.0 + 0.5))
+        .collect();
+    // Multipliers close to 1, so that the chain neither overflows nor underflows
+    let near: Vec<Quad> = x
+        .iter()
+        .map(|&a| Quad::ONE + (a - Quad::from(0.5)) * Quad::from(0.001))
+        .collect();
+
+    let mut group = c.benchmark_group("Quad");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    binary(&mut group, "add", &x, &y, |a, b| a + b);
+    binary(&mut group, "mul", &x, &y, |a, b| a * b);
+    binary(&mut group, "div", &x, &y, |a, b| a / b);
+    unary(&mut group, "mul (self)", &x, |a| a * a);
+    unary(&mut group, "sqr", &x, |a| a.sqr());
+    unary(&mut group, "cube", &x, |a| a.cube());
+    unary(&mut group, "sqrt", &x, |a| a.sqrt());
+    unary(&mut group, "recip_sqrt", &x, |a| a.recip_sqrt());
+    unary(&mut group, "1 / sqrt", &x, |a| Quad::ONE / a.sqrt());
+    unary(&mut group, "exp", &x, |a| a.exp());
+    unary(&mut group, "sin", &x, |a| a.sin());
+    group.bench_function("mul (chain)", |b| {
+        b.iter(|| {
+            let mut p = Quad::ONE;
+            for &a in black_box(&near) {
+                p *= a;
+            }
+            p
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, double, quad);
+criterion_main!(benches);
@@ -0,0 +1,65 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Measures parsing decimal strings of different lengths into `Double`s and `Quad`s, the way
+// that reading a data file of them does. Each length gets its own set of pseudo-random
+// strings, with exponents spread over most of the range, and the throughput is reported
+// in strings per second. Long strings are where the cost of turning digits into a number
+// shows; 60 digits is about what it takes to write out a `Quad`.
+//
+// Run with `cargo bench --bench parse`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use qd::{Double, Quad};
+use std::hint::black_box;
+
+mod common;
+
+const COUNT: usize = 4_096;
+const LENGTHS: [usize; 5] = [8, 17, 34, 60, 72];
+
+fn strings(len: usize) -> Vec<String> {
+    let mut rng = common::Rng::new(common::SEED ^ len as u64);
+    (0..COUNT)
+        .map(|_| {
+            let digits: String = (0..len)
+                .map(|_| (b'0' + (rng.next_u64() % 10) as u8) as char)
+                .collect();
+            let exp = (rng.next_u64() % 500) as i32 - 250;
+            format!("{}.{}e{}", &digits[..1], &digits[1..], exp)
+        })
+        .collect()
+}
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Double");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    for &len in &LENGTHS {
+        group.bench_with_input(BenchmarkId::new("digits", len), &strings(len), |b, s| {
+            b.iter(|| {
+                for x in s {
+                    black_box(black_box(x.as_str()).parse::<Double>().unwrap());
+                }
+            })
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("Quad");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    for &len in &LENGTHS {
+        group.bench_with_input(BenchmarkId::new("digits", len), &strings(len), |b, s| {
+            b.iter(|| {
+                for x in s {
+                    black_box(black_box(x.as_str()).parse::<Quad>().unwrap());
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
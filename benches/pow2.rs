@@ -0,0 +1,94 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Compares `ldexp` against multiplying by the same power of 2 as a `Double` or `Quad`, in
+// a kernel that scales each element of a vector by a power of 2 that depends on its index
+// (as FFT twiddle factors and binary splitting do). Both give exactly the same results;
+// `ldexp` just doesn't do any of the work that a general multiplication does to find the
+// rounding error of each product, since there isn't any.
+//
+// Run with `cargo bench --bench pow2`.
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use qd::{Double, Quad};
+use std::hint::black_box;
+
+mod common;
+
+const COUNT: usize = 1 << 14;
+
+// The power of 2 for each index, which covers both directions
+fn exponent(i: usize) -> i32 {
+    (i % 64) as i32 - 32
+}
+
+fn double(c: &mut Criterion) {
+    let x: Vec<Double> = common::randoms(COUNT)
+        .iter()
+        .map(|&a| Double::PI * Double::from(a))
+        .collect();
+    let powers: Vec<Double> = (0..COUNT)
+        .map(|i| Double::from(2f64.powi(exponent(i))))
+        .collect();
+    let mut y = vec![Double::ZERO; COUNT];
+
+    let mut group = c.benchmark_group("Double");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    group.bench_function("ldexp", |b| {
+        b.iter(|| {
+            for (i, (v, &u)) in black_box(&mut y).iter_mut().zip(black_box(&x)).enumerate() {
+                *v = u.ldexp(exponent(i));
+            }
+        })
+    });
+    group.bench_function("operator", |b| {
+        b.iter(|| {
+            for ((v, &u), &p) in black_box(&mut y)
+                .iter_mut()
+                .zip(black_box(&x))
+                .zip(black_box(&powers))
+            {
+                *v = u * p;
+            }
+        })
+    });
+    group.finish();
+}
+
+fn quad(c: &mut Criterion) {
+    let x: Vec<Quad> = common::randoms(COUNT)
+        .iter()
+        .map(|&a| Quad::PI * Quad::from(a))
+        .collect();
+    let powers: Vec<Quad> = (0..COUNT)
+        .map(|i| Quad::from(2f64.powi(exponent(i))))
+        .collect();
+    let mut y = vec![Quad::ZERO; COUNT];
+
+    let mut group = c.benchmark_group("Quad");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    group.bench_function("ldexp", |b| {
+        b.iter(|| {
+            for (i, (v, &u)) in black_box(&mut y).iter_mut().zip(black_box(&x)).enumerate() {
+                *v = u.ldexp(exponent(i));
+            }
+        })
+    });
+    group.bench_function("operator", |b| {
+        b.iter(|| {
+            for ((v, &u), &p) in black_box(&mut y)
+                .iter_mut()
+                .zip(black_box(&x))
+                .zip(black_box(&powers))
+            {
+                *v = u * p;
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, double, quad);
+criterion_main!(benches);
@@ -0,0 +1,80 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Compares `mul_i32` against multiplying by an integer converted to a `Double` or `Quad`,
+// in a kernel that scales each element of a vector by its index. The integer-valued
+// vectors always take the exact path; the others never do, which shows the cost of
+// checking for exactness before falling back to full multiplication.
+//
+// Run with `cargo bench --bench scale`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use qd::{Double, Quad};
+use std::hint::black_box;
+
+mod common;
+
+const COUNT: usize = 1 << 14;
+
+fn double(c: &mut Criterion) {
+    let r = common::randoms(COUNT);
+
+    // Integers of up to 32 bits, which an index of up to 14 bits can't push past 53
+    let ints: Vec<Double> = r.iter().map(|&a| Double::from((a * 4e9).floor())).collect();
+    let full: Vec<Double> = r.iter().map(|&a| Double::PI * Double::from(a)).collect();
+    let mut y = vec![Double::ZERO; COUNT];
+
+    let mut group = c.benchmark_group("Double");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    for (kind, x) in &[("integers", &ints), ("full", &full)] {
+        group.bench_with_input(BenchmarkId::new("mul_i32", kind), *x, |b, x| {
+            b.iter(|| {
+                for (i, (v, &u)) in black_box(&mut y).iter_mut().zip(black_box(x)).enumerate() {
+                    *v = u.mul_i32(i as i32);
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("operator", kind), *x, |b, x| {
+            b.iter(|| {
+                for (i, (v, &u)) in black_box(&mut y).iter_mut().zip(black_box(x)).enumerate() {
+                    *v = u * Double::from(i as f64);
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+fn quad(c: &mut Criterion) {
+    let r = common::randoms(COUNT);
+
+    // Integers of up to 32 bits, which an index of up to 14 bits can't push past 53
+    let ints: Vec<Quad> = r.iter().map(|&a| Quad::from((a * 4e9).floor())).collect();
+    let full: Vec<Quad> = r.iter().map(|&a| Quad::PI * Quad::from(a)).collect();
+    let mut y = vec![Quad::ZERO; COUNT];
+
+    let mut group = c.benchmark_group("Quad");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    for (kind, x) in &[("integers", &ints), ("full", &full)] {
+        group.bench_with_input(BenchmarkId::new("mul_i32", kind), *x, |b, x| {
+            b.iter(|| {
+                for (i, (v, &u)) in black_box(&mut y).iter_mut().zip(black_box(x)).enumerate() {
+                    *v = u.mul_i32(i as i32);
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("operator", kind), *x, |b, x| {
+            b.iter(|| {
+                for (i, (v, &u)) in black_box(&mut y).iter_mut().zip(black_box(x)).enumerate() {
+                    *v = u * Quad::from(i as f64);
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, double, quad);
+criterion_main!(benches);
@@ -0,0 +1,123 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Compares the bulk layout conversions in `qd::slice` against the obvious per-element
+// loops, and against a plain `copy_from_slice` of the same number of bytes, which is as
+// fast as moving memory gets.
+//
+// Run with `cargo bench --bench soa`.
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use qd::{slice, Quad};
+use std::hint::black_box;
+
+mod common;
+
+const COUNT: usize = 1 << 16;
+
+fn soa(c: &mut Criterion) {
+    let values: Vec<Quad> = common::randoms(COUNT)
+        .iter()
+        .map(|&r| Quad::PI * Quad::from(r))
+        .collect();
+    let (mut a, mut b, mut c0, mut d) = (
+        vec![0.0; COUNT],
+        vec![0.0; COUNT],
+        vec![0.0; COUNT],
+        vec![0.0; COUNT],
+    );
+    slice::to_soa(&values, [&mut a, &mut b, &mut c0, &mut d]);
+    let flat: Vec<f64> = values
+        .iter()
+        .flat_map(|q| (0..4).map(move |i| q[i]))
+        .collect();
+    let mut flat_out = vec![0.0; 4 * COUNT];
+    let mut out = vec![Quad::ZERO; COUNT];
+    let mut heads = vec![0.0; COUNT];
+
+    let mut group = c.benchmark_group("to_soa");
+    group.throughput(Throughput::Bytes((4 * 8 * COUNT) as u64));
+    group.bench_function("slice", |bn| {
+        bn.iter(|| {
+            slice::to_soa(black_box(&values), [&mut a, &mut b, &mut c0, &mut d]);
+            black_box((&a, &b, &c0, &d));
+        })
+    });
+    group.bench_function("naive loop", |bn| {
+        bn.iter(|| {
+            for (i, q) in black_box(&values).iter().enumerate() {
+                a[i] = q[0];
+                b[i] = q[1];
+                c0[i] = q[2];
+                d[i] = q[3];
+            }
+            black_box((&a, &b, &c0, &d));
+        })
+    });
+    group.bench_function("memcpy", |bn| {
+        bn.iter(|| {
+            flat_out.copy_from_slice(black_box(&flat));
+            black_box(&flat_out);
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("from_soa");
+    group.throughput(Throughput::Bytes((4 * 8 * COUNT) as u64));
+    group.bench_function("slice", |bn| {
+        bn.iter(|| {
+            slice::from_soa([&a, &b, &c0, &d], black_box(&mut out));
+            black_box(&out);
+        })
+    });
+    group.bench_function("slice (raw)", |bn| {
+        bn.iter(|| {
+            slice::from_soa_raw([&a, &b, &c0, &d], black_box(&mut out));
+            black_box(&out);
+        })
+    });
+    group.bench_function("naive loop", |bn| {
+        bn.iter(|| {
+            for (i, q) in black_box(&mut out).iter_mut().enumerate() {
+                *q = Quad::from((a[i], b[i], c0[i], d[i]));
+            }
+            black_box(&out);
+        })
+    });
+    group.bench_function("memcpy", |bn| {
+        bn.iter(|| {
+            flat_out.copy_from_slice(black_box(&flat));
+            black_box(&flat_out);
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("heads");
+    group.throughput(Throughput::Bytes((8 * COUNT) as u64));
+    group.bench_function("slice", |bn| {
+        bn.iter(|| {
+            slice::heads(black_box(&values), &mut heads);
+            black_box(&heads);
+        })
+    });
+    group.bench_function("naive loop", |bn| {
+        bn.iter(|| {
+            for (h, q) in heads.iter_mut().zip(black_box(&values)) {
+                *h = f64::from(*q);
+            }
+            black_box(&heads);
+        })
+    });
+    group.bench_function("memcpy", |bn| {
+        bn.iter(|| {
+            flat_out[..COUNT].copy_from_slice(black_box(&flat[..COUNT]));
+            black_box(&flat_out);
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, soa);
+criterion_main!(benches);
@@ -3,6 +3,27 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+pub mod accumulator;
+pub mod bigint;
+// The parsers' tests use this to measure their errors exactly, serde or not
+#[cfg(any(feature = "serde", test))]
+#[cfg_attr(not(feature = "serde"), allow(dead_code))]
+pub mod decimal;
 pub mod display;
+pub mod fixed;
+pub mod hex;
+pub mod input;
+pub mod literal;
 pub mod primitive;
+pub mod radix;
+// Random arguments for the tests that check many of them at once
+#[cfg(test)]
+pub mod random;
+pub mod reduction;
+// Checked against by the tests of the hard-coded constants and tables
+#[cfg(test)]
+pub mod reference;
+pub mod repr;
+pub mod rounding;
+pub mod strict;
 pub mod utils;
@@ -0,0 +1,161 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::primitive as p;
+
+/// The exact sum of any number of `f64`s, kept as an expansion that grows one number at a
+/// time.
+///
+/// The terms are the sum of everything added so far, divided by `scale`. They don't overlap
+/// and they're kept smallest first, which is the order that lets each new number be added
+/// with a single pass of error-free sums (Shewchuk's "Adaptive Precision Floating-Point
+/// Arithmetic and Fast Robust Geometric Predicates", the same algorithm as Python's
+/// `math.fsum`). Every error term that isn't zero is kept, so nothing is ever rounded away.
+/// Since the terms don't overlap, there can't be more of them than fit in the exponent
+/// range of an `f64`, which is a few dozen.
+///
+/// A partial sum can overflow even when the whole sum won't. When one does, everything is
+/// halved and `scale` is doubled, as with [`distill_sum`]. Halving can drop the lowest bit
+/// of a subnormal term, which is far below the precision of any sum large enough to need
+/// it.
+///
+/// Infinities and NaNs are summed separately into `special`, which is 0 until one of them
+/// is added and then is the result no matter what else is.
+///
+/// [`distill_sum`]: ../utils/fn.distill_sum.html
+#[derive(Clone, Debug)]
+pub struct Partials {
+    terms: Vec<f64>,
+    scale: f64,
+    special: f64,
+}
+
+impl Partials {
+    pub fn new() -> Partials {
+        Partials {
+            terms: vec![],
+            scale: 1.0,
+            special: 0.0,
+        }
+    }
+
+    pub fn add(&mut self, x: f64) {
+        if x.is_finite() {
+            self.add_scaled(x / self.scale);
+        } else {
+            self.special += x;
+        }
+    }
+
+    pub fn merge(&mut self, other: &Partials) {
+        if other.scale > self.scale {
+            self.rescale(other.scale);
+        }
+        let factor = other.scale / self.scale;
+        for &t in &other.terms {
+            self.add_scaled(t * factor);
+        }
+        self.special += other.special;
+    }
+
+    /// Returns the first `n` components of the sum: the `f64` nearest the sum, then the
+    /// `f64` nearest what's left after subtracting that, and so on. The components depend
+    /// only on the value of the sum and not on the terms that happen to represent it, so
+    /// every way of adding up the same numbers gives the same components. If any
+    /// infinities or NaNs were added, or if the sum is too large to represent, the first
+    /// component is infinite or NaN and the rest are 0.
+    pub fn components(&self, n: usize) -> Vec<f64> {
+        if self.special != 0.0 {
+            let mut result = vec![0.0; n];
+            result[0] = self.special;
+            return result;
+        }
+        let mut result = vec![0.0; n];
+        let mut rest = self.clone();
+        for r in result.iter_mut() {
+            let c = rest.nearest();
+            *r = c * rest.scale;
+            if c == 0.0 || !r.is_finite() {
+                // Either nothing is left, or the sum is too large for an `f64` and there's
+                // nothing left to say
+                break;
+            }
+            rest.add_scaled(-c);
+        }
+        result
+    }
+
+    // Rounds the terms to the nearest `f64` (still divided by the scale). This is the last
+    // step of `math.fsum`: the terms are added largest first until one is lost in rounding,
+    // and the rounding is then corrected if it looked like a tie but the terms below made it
+    // not one.
+    fn nearest(&self) -> f64 {
+        let mut k = self.terms.len();
+        if k == 0 {
+            return 0.0;
+        }
+        k -= 1;
+        let mut hi = self.terms[k];
+        let mut lo = 0.0;
+        while k > 0 {
+            let x = hi;
+            let y = self.terms[k - 1];
+            k -= 1;
+            hi = x + y;
+            lo = y - (hi - x);
+            if lo != 0.0 {
+                break;
+            }
+        }
+        if k > 0 && ((lo < 0.0 && self.terms[k - 1] < 0.0) || (lo > 0.0 && self.terms[k - 1] > 0.0))
+        {
+            let y = lo * 2.0;
+            let x = hi + y;
+            if y == x - hi {
+                hi = x;
+            }
+        }
+        hi
+    }
+
+    // Adds a number that's already been divided by the scale.
+    fn add_scaled(&mut self, x: f64) {
+        let mut x = x;
+        let mut i = 0;
+        for j in 0..self.terms.len() {
+            let (s, e) = p::two_sum(x, self.terms[j]);
+            if !s.is_finite() || !e.is_finite() {
+                // Nothing has been lost yet: the first `i` terms, `x`, and the terms from
+                // `j` on still add up to the sum, so they're added again at a larger scale
+                let mut rest = self.terms.split_off(j);
+                self.terms.truncate(i);
+                rest.push(x);
+                let done = std::mem::take(&mut self.terms);
+                self.rescale_terms(self.scale * 2.0, done.into_iter().chain(rest));
+                return;
+            }
+            if e != 0.0 {
+                self.terms[i] = e;
+                i += 1;
+            }
+            x = s;
+        }
+        self.terms.truncate(i);
+        self.terms.push(x);
+    }
+
+    fn rescale(&mut self, scale: f64) {
+        let terms = std::mem::take(&mut self.terms);
+        self.rescale_terms(scale, terms.into_iter());
+    }
+
+    fn rescale_terms(&mut self, scale: f64, terms: impl Iterator<Item = f64>) {
+        let factor = self.scale / scale;
+        self.scale = scale;
+        for t in terms {
+            self.add_scaled(t * factor);
+        }
+    }
+}
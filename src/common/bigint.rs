@@ -0,0 +1,226 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::cmp::Ordering;
+
+// A minimal unsigned arbitrary-precision integer, with just enough operations to convert
+// the exact value of a `Double` or `Quad` to and from digits in an arbitrary radix. The
+// limbs are little-endian and there are never any zero limbs at the top, so zero has no
+// limbs at all.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Big {
+    limbs: Vec<u32>,
+}
+
+impl Big {
+    pub fn from_u64(n: u64) -> Big {
+        let mut b = Big {
+            limbs: vec![n as u32, (n >> 32) as u32],
+        };
+        b.trim();
+        b
+    }
+
+    fn trim(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    // The low 64 bits of the number.
+    pub fn low_u64(&self) -> u64 {
+        let limb = |i| self.limbs.get(i).map_or(0, |&x| x as u64);
+        limb(1) << 32 | limb(0)
+    }
+
+    // The number of bits needed to write the number, which is zero for zero.
+    pub fn bit_len(&self) -> u64 {
+        match self.limbs.last() {
+            None => 0,
+            Some(&top) => 32 * self.limbs.len() as u64 - top.leading_zeros() as u64,
+        }
+    }
+
+    pub fn bit(&self, n: u64) -> bool {
+        match self.limbs.get((n / 32) as usize) {
+            None => false,
+            Some(&limb) => limb >> (n % 32) & 1 == 1,
+        }
+    }
+
+    // Returns true if any of the bits below bit `n` are set.
+    pub fn any_below(&self, n: u64) -> bool {
+        let whole = ((n / 32) as usize).min(self.limbs.len());
+        if self.limbs[..whole].iter().any(|&limb| limb != 0) {
+            return true;
+        }
+        let part = n % 32;
+        part > 0 && whole < self.limbs.len() && self.limbs[whole] & ((1 << part) - 1) != 0
+    }
+
+    // The number shifted left by `n` bits.
+    pub fn shl(&self, n: u64) -> Big {
+        if self.is_zero() {
+            return Big::default();
+        }
+        let (whole, part) = ((n / 32) as usize, n % 32);
+        let mut limbs = vec![0; whole];
+        let mut carry = 0;
+        for &limb in &self.limbs {
+            limbs.push(((limb as u64) << part) as u32 | carry);
+            carry = if part == 0 { 0 } else { limb >> (32 - part) };
+        }
+        limbs.push(carry);
+        let mut b = Big { limbs };
+        b.trim();
+        b
+    }
+
+    // The number shifted right by `n` bits, with the bits shifted out discarded.
+    pub fn shr(&self, n: u64) -> Big {
+        let (whole, part) = ((n / 32) as usize, n % 32);
+        if whole >= self.limbs.len() {
+            return Big::default();
+        }
+        let src = &self.limbs[whole..];
+        let mut limbs = Vec::with_capacity(src.len());
+        for i in 0..src.len() {
+            let hi = src.get(i + 1).map_or(0, |&limb| (limb as u64) << 32);
+            limbs.push(((hi | src[i] as u64) >> part) as u32);
+        }
+        let mut b = Big { limbs };
+        b.trim();
+        b
+    }
+
+    // The bits of the number below bit `n`.
+    pub fn low_bits(&self, n: u64) -> Big {
+        let (whole, part) = ((n / 32) as usize, n % 32);
+        if whole >= self.limbs.len() {
+            return self.clone();
+        }
+        let mut limbs = self.limbs[..whole].to_vec();
+        if part > 0 {
+            limbs.push(self.limbs[whole] & ((1 << part) - 1));
+        }
+        let mut b = Big { limbs };
+        b.trim();
+        b
+    }
+
+    // Removes the bits at bit `n` and above, returning them. They have to fit into a
+    // `u64`.
+    pub fn take_above(&mut self, n: u64) -> u64 {
+        let (whole, part) = ((n / 32) as usize, n % 32);
+        if whole >= self.limbs.len() {
+            return 0;
+        }
+        let high = self.limbs[whole..]
+            .iter()
+            .rev()
+            .fold(0u128, |acc, &limb| acc << 32 | limb as u128);
+        self.limbs.truncate(whole + 1);
+        self.limbs[whole] &= ((1u64 << part) - 1) as u32;
+        self.trim();
+        (high >> part) as u64
+    }
+
+    pub fn mul_small(&mut self, m: u32) {
+        let mut carry = 0;
+        for limb in self.limbs.iter_mut() {
+            let p = *limb as u64 * m as u64 + carry;
+            *limb = p as u32;
+            carry = p >> 32;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+        self.trim();
+    }
+
+    pub fn add_small(&mut self, a: u32) {
+        let mut carry = a as u64;
+        for limb in self.limbs.iter_mut() {
+            if carry == 0 {
+                return;
+            }
+            let s = *limb as u64 + carry;
+            *limb = s as u32;
+            carry = s >> 32;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+
+    // Divides in place, returning the remainder.
+    pub fn div_small(&mut self, d: u32) -> u32 {
+        let mut rem = 0u64;
+        for limb in self.limbs.iter_mut().rev() {
+            let n = rem << 32 | *limb as u64;
+            *limb = (n / d as u64) as u32;
+            rem = n % d as u64;
+        }
+        self.trim();
+        rem as u32
+    }
+
+    pub fn add(&self, other: &Big) -> Big {
+        let (long, short) = if self.limbs.len() >= other.limbs.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut limbs = Vec::with_capacity(long.limbs.len() + 1);
+        let mut carry = 0u64;
+        for (i, &limb) in long.limbs.iter().enumerate() {
+            let s = limb as u64 + short.limbs.get(i).map_or(0, |&x| x as u64) + carry;
+            limbs.push(s as u32);
+            carry = s >> 32;
+        }
+        limbs.push(carry as u32);
+        let mut b = Big { limbs };
+        b.trim();
+        b
+    }
+
+    // Subtracts a number that's no larger than this one.
+    pub fn sub(&self, other: &Big) -> Big {
+        debug_assert!(*self >= *other);
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow = 0i64;
+        for (i, &limb) in self.limbs.iter().enumerate() {
+            let mut d = limb as i64 - other.limbs.get(i).map_or(0, |&x| x as i64) - borrow;
+            borrow = 0;
+            if d < 0 {
+                d += 1 << 32;
+                borrow = 1;
+            }
+            limbs.push(d as u32);
+        }
+        let mut b = Big { limbs };
+        b.trim();
+        b
+    }
+}
+
+impl PartialOrd for Big {
+    fn partial_cmp(&self, other: &Big) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Big {
+    fn cmp(&self, other: &Big) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
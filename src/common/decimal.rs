@@ -0,0 +1,432 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Exact decimal arithmetic, for the few places where a `Double` or a `Quad` has to be turned
+// into a decimal string and back again without losing a single bit. The regular parser and
+// formatter work in the precision of the type itself, which is plenty for reading and
+// writing numbers but can't promise that every bit of every component survives the trip.
+//
+// Every finite `f64` has an exact, finite decimal expansion, so the sum of the components
+// of a `Double` or `Quad` does too. That sum is what gets written. Reading a number back
+// splits it greedily: the first component is the `f64` nearest to the whole number (std's
+// parser rounds correctly no matter how many digits it's given), the second is the `f64`
+// nearest to what's left after subtracting the first exactly, and so on.
+
+// An exact decimal number: `digits` (least significant first) times 10^`exp`, negated if
+// `negative` is set. `digits` never has zeros at either end, and zero has no digits at all.
+#[derive(Clone, Debug)]
+pub struct Decimal {
+    negative: bool,
+    digits: Vec<u8>,
+    exp: i64,
+}
+
+impl Decimal {
+    // Returns the exact decimal value of a finite `f64`.
+    pub fn from_f64(x: f64) -> Decimal {
+        let bits = x.to_bits();
+        let negative = bits >> 63 != 0;
+        let biased = ((bits >> 52) & 0x7ff) as i64;
+        let frac = bits & ((1 << 52) - 1);
+        let (mut m, e) = if biased == 0 {
+            (frac, -1074)
+        } else {
+            (frac | (1 << 52), biased - 1075)
+        };
+
+        let mut digits = Vec::with_capacity(20);
+        while m > 0 {
+            digits.push((m % 10) as u8);
+            m /= 10;
+        }
+        let mut d = Decimal {
+            negative,
+            digits,
+            exp: 0,
+        };
+        if e >= 0 {
+            // m × 2^e, multiplied in chunks small enough that nothing overflows a u64
+            let mut left = e;
+            while left > 0 {
+                let k = left.min(30);
+                d.mul_small(1 << k);
+                left -= k;
+            }
+        } else {
+            // m × 2^e = m × 5^-e × 10^e
+            let mut left = -e;
+            while left > 0 {
+                let k = left.min(13);
+                d.mul_small(5u64.pow(k as u32));
+                left -= k;
+            }
+            d.exp = e;
+        }
+        d.trim();
+        d
+    }
+
+    // Parses a string that has already been accepted by `from_str`, so it is known to be
+    // an optional sign, digits with at most one decimal point among them, underscores,
+    // and an optional exponent. It must not be one of the non-finite words.
+    pub fn parse(s: &str) -> Decimal {
+        let s = s.trim().to_ascii_lowercase();
+        let mut negative = false;
+        let mut msd_first = Vec::with_capacity(s.len());
+        let mut frac_digits: i64 = 0;
+        let mut in_fraction = false;
+        let mut exp: i64 = 0;
+
+        for (index, ch) in s.char_indices() {
+            match ch {
+                '0'..='9' => {
+                    msd_first.push(ch as u8 - b'0');
+                    if in_fraction {
+                        frac_digits += 1;
+                    }
+                }
+                '.' => in_fraction = true,
+                '-' => negative = true,
+                'e' => {
+                    exp = s[(index + 1)..]
+                        .replace('_', "")
+                        .parse::<i64>()
+                        .unwrap_or(0);
+                    break;
+                }
+                _ => {}
+            }
+        }
+        msd_first.reverse();
+        let mut d = Decimal {
+            negative,
+            digits: msd_first,
+            exp: exp - frac_digits,
+        };
+        d.trim();
+        d
+    }
+
+    // Returns the `f64` nearest to this number.
+    pub fn to_f64(&self) -> f64 {
+        if self.digits.is_empty() {
+            return if self.negative { -0.0 } else { 0.0 };
+        }
+        let mut s = String::with_capacity(self.digits.len() + 24);
+        if self.negative {
+            s.push('-');
+        }
+        s.extend(self.digits.iter().rev().map(|&d| (b'0' + d) as char));
+        s.push('e');
+        s.push_str(&self.exp.to_string());
+        // Exponents too large or too small for an f64 parse as infinity or zero, so the
+        // only way this can fail is for the exponent to be too large for the parser to
+        // read at all
+        s.parse().unwrap_or(if self.exp > 0 {
+            if self.negative {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            }
+        } else if self.negative {
+            -0.0
+        } else {
+            0.0
+        })
+    }
+
+    // Returns the exact sum of this number and another.
+    pub fn add(&self, other: &Decimal) -> Decimal {
+        if other.digits.is_empty() {
+            return self.clone();
+        }
+        if self.digits.is_empty() {
+            return other.clone();
+        }
+        let exp = self.exp.min(other.exp);
+        let a = self.aligned(exp);
+        let b = other.aligned(exp);
+
+        let mut result = if self.negative == other.negative {
+            Decimal {
+                negative: self.negative,
+                digits: add_digits(&a, &b),
+                exp,
+            }
+        } else if cmp_digits(&a, &b) == std::cmp::Ordering::Less {
+            Decimal {
+                negative: other.negative,
+                digits: sub_digits(&b, &a),
+                exp,
+            }
+        } else {
+            Decimal {
+                negative: self.negative,
+                digits: sub_digits(&a, &b),
+                exp,
+            }
+        };
+        result.trim();
+        result
+    }
+
+    // Returns this number with its sign flipped.
+    pub fn neg(&self) -> Decimal {
+        Decimal {
+            negative: !self.negative,
+            digits: self.digits.clone(),
+            exp: self.exp,
+        }
+    }
+
+    // Returns this number divided by four, which is always exact in decimal.
+    pub fn quarter(&self) -> Decimal {
+        let mut d = self.clone();
+        d.mul_small(25);
+        d.exp -= 2;
+        d.trim();
+        d
+    }
+
+    // Returns the shortest decimal number strictly between `lo` and `hi`, which must both
+    // be positive with `lo < hi`.
+    pub fn shortest_between(lo: &Decimal, hi: &Decimal) -> Decimal {
+        let exp = lo.exp.min(hi.exp);
+        let mut a = lo.aligned(exp);
+        let mut b = hi.aligned(exp);
+        let len = a.len().max(b.len());
+        a.resize(len, 0);
+        b.resize(len, 0);
+        a.reverse();
+        b.reverse();
+
+        // Try keeping more and more of the leading digits. The smallest number with that
+        // many digits that is greater than `lo` is `lo` truncated and bumped up by one in
+        // its last kept digit, and if that's still less than `hi`, it's the answer.
+        for keep in 1..=len {
+            let mut candidate = a[..keep].to_vec();
+            let mut i = keep;
+            loop {
+                if i == 0 {
+                    candidate.insert(0, 1);
+                    break;
+                }
+                i -= 1;
+                if candidate[i] == 9 {
+                    candidate[i] = 0;
+                } else {
+                    candidate[i] += 1;
+                    break;
+                }
+            }
+            // Compare against `hi` truncated to the same scale; a carry out of the top
+            // makes the candidate longer than `hi` and therefore too big
+            let extra = candidate.len() - keep;
+            let bigger = extra > 0 || {
+                let head = &b[..keep];
+                match candidate.as_slice().cmp(head) {
+                    std::cmp::Ordering::Less => false,
+                    std::cmp::Ordering::Greater => true,
+                    // Equal to the truncation of `hi` is fine only if `hi` has more
+                    // non-zero digits after that
+                    std::cmp::Ordering::Equal => b[keep..].iter().all(|&d| d == 0),
+                }
+            };
+            if !bigger {
+                candidate.reverse();
+                let mut d = Decimal {
+                    negative: false,
+                    digits: candidate,
+                    exp: exp + (len - keep) as i64,
+                };
+                d.trim();
+                return d;
+            }
+        }
+        lo.clone()
+    }
+
+    // Writes the number in scientific notation, e.g. "-3.14159e0". Zero is written as "0"
+    // or "-0".
+    pub fn to_scientific(&self) -> String {
+        let mut s = String::with_capacity(self.digits.len() + 24);
+        if self.negative {
+            s.push('-');
+        }
+        if self.digits.is_empty() {
+            s.push('0');
+            return s;
+        }
+        let mut iter = self.digits.iter().rev();
+        s.push((b'0' + iter.next().unwrap()) as char);
+        if self.digits.len() > 1 {
+            s.push('.');
+            s.extend(iter.map(|&d| (b'0' + d) as char));
+        }
+        s.push('e');
+        s.push_str(&(self.exp + self.digits.len() as i64 - 1).to_string());
+        s
+    }
+
+    fn mul_small(&mut self, k: u64) {
+        let mut carry = 0u64;
+        for d in self.digits.iter_mut() {
+            let t = *d as u64 * k + carry;
+            *d = (t % 10) as u8;
+            carry = t / 10;
+        }
+        while carry > 0 {
+            self.digits.push((carry % 10) as u8);
+            carry /= 10;
+        }
+    }
+
+    // Removes zeros from both ends of the digits, adjusting the exponent for the ones
+    // removed from the bottom.
+    fn trim(&mut self) {
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+        let zeros = self.digits.iter().take_while(|&&d| d == 0).count();
+        if zeros > 0 {
+            self.digits.drain(..zeros);
+            self.exp += zeros as i64;
+        }
+        if self.digits.is_empty() {
+            self.exp = 0;
+        }
+    }
+
+    // Returns the digits rescaled to the given (smaller or equal) exponent.
+    fn aligned(&self, exp: i64) -> Vec<u8> {
+        let shift = (self.exp - exp) as usize;
+        let mut v = vec![0; shift];
+        v.extend_from_slice(&self.digits);
+        v
+    }
+}
+
+fn cmp_digits(a: &[u8], b: &[u8]) -> std::cmp::Ordering {
+    let len = |v: &[u8]| v.len() - v.iter().rev().take_while(|&&d| d == 0).count();
+    let (la, lb) = (len(a), len(b));
+    if la != lb {
+        return la.cmp(&lb);
+    }
+    a[..la].iter().rev().cmp(b[..lb].iter().rev())
+}
+
+fn add_digits(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let n = a.len().max(b.len());
+    let mut result = Vec::with_capacity(n + 1);
+    let mut carry = 0;
+    for i in 0..n {
+        let t = a.get(i).unwrap_or(&0) + b.get(i).unwrap_or(&0) + carry;
+        result.push(t % 10);
+        carry = t / 10;
+    }
+    if carry > 0 {
+        result.push(carry);
+    }
+    result
+}
+
+// Subtracts `b` from `a`, which must be at least as large.
+fn sub_digits(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, &d) in a.iter().enumerate() {
+        let sub = b.get(i).unwrap_or(&0) + borrow;
+        if d >= sub {
+            result.push(d - sub);
+            borrow = 0;
+        } else {
+            result.push(d + 10 - sub);
+            borrow = 1;
+        }
+    }
+    result
+}
+
+// Returns the distance from a finite `f64` to the next one away from zero. Zero and
+// subnormals all have the same ULP, the smallest subnormal.
+fn ulp(x: f64) -> f64 {
+    let biased = ((x.to_bits() >> 52) & 0x7ff) as i64;
+    if biased <= 53 {
+        f64::from_bits(1 << (biased.max(1) - 1))
+    } else {
+        f64::from_bits(((biased - 52) as u64) << 52)
+    }
+}
+
+// Returns the exact sum of a set of finite components. If the sum is zero, it has the sign
+// of the first component.
+fn exact_sum(components: &[f64]) -> Decimal {
+    components[1..]
+        .iter()
+        .fold(Decimal::from_f64(components[0]), |acc, &c| {
+            acc.add(&Decimal::from_f64(c))
+        })
+}
+
+// Splits a decimal number greedily into components, as described at the top of this file.
+// Once a component comes out as zero or infinite, the rest are set to zero.
+pub fn split(d: &Decimal, components: &mut [f64]) {
+    let mut rest = d.clone();
+    let mut done = false;
+    for (i, c) in components.iter_mut().enumerate() {
+        if done {
+            *c = 0.0;
+            continue;
+        }
+        // A zero after the first component is always a positive zero, matching the way
+        // that the rest of the library fills in components that aren't needed
+        *c = if i > 0 && rest.digits.is_empty() {
+            0.0
+        } else {
+            rest.to_f64()
+        };
+        if *c == 0.0 || c.is_infinite() {
+            done = true;
+        } else {
+            rest = rest.add(&Decimal::from_f64(*c).neg());
+        }
+    }
+}
+
+// Writes the sum of a set of finite, normalized components as a decimal string that
+// `split` turns back into exactly the same components.
+//
+// The string is the shortest one that lies well within the range of numbers that split
+// into those components: the exact sum plus or minus a quarter of the ULP of the last
+// component. If that somehow doesn't come back exactly (which could only happen if a
+// component sits exactly halfway between two of the previous component's neighbors), the
+// exact sum, which is longer, is written instead.
+pub fn to_exact_string(components: &[f64]) -> String {
+    let sum = exact_sum(components);
+    if sum.digits.is_empty() {
+        return sum.to_scientific();
+    }
+    let margin = Decimal::from_f64(ulp(*components.last().unwrap())).quarter();
+    let magnitude = Decimal {
+        negative: false,
+        ..sum.clone()
+    };
+    let lo = magnitude.add(&margin.neg());
+    let hi = magnitude.add(&margin);
+    let mut short = Decimal::shortest_between(&lo, &hi);
+    short.negative = sum.negative;
+
+    let mut check = vec![0.0; components.len()];
+    split(&short, &mut check);
+    if check
+        .iter()
+        .zip(components)
+        .all(|(a, b)| a.to_bits() == b.to_bits() || (*a == 0.0 && *b == 0.0))
+    {
+        short.to_scientific()
+    } else {
+        sum.to_scientific()
+    }
+}
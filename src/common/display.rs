@@ -3,203 +3,577 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-use std::fmt::Formatter;
-use std::{char, fmt::Alignment};
+use crate::common::radix;
+use std::fmt::{Alignment, Formatter, Result, Write};
 
-// Add a "not-a-number" representation to the input vector.
-pub fn push_nan(chars: &mut Vec<char>) {
-    chars.append(&mut "NaN".chars().collect());
+// The most digits that either type extracts. That's one more than a `Quad` can represent,
+// since the extra digit is used for rounding.
+const MAX_DIGITS: usize = 63;
+
+// How far off the last extracted digit can be, in units of that digit. The digits come from
+// a chain of multiplications in the type's own precision, so the last one or two aren't
+// always the digits of the exact value. When the digits past a rounding point are closer
+// to a tie than this, they can't be trusted to say which way to round.
+const SLOP: u64 = 40;
+
+// A run of zeros that's long enough that most numbers only need one slice of it.
+const ZEROS: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+// The decimal digits of a positive number, as ASCII, along with the decimal exponent of the
+// first of them. The digits live on the stack, since there are never more than
+// `MAX_DIGITS` of them. Any zeros that are needed to reach the decimal point or to fill out
+// a precision are never stored; they're written as they're needed instead.
+//
+// The components of the number are kept as well, so that a rounding that the digits can't
+// decide can be done exactly instead.
+pub struct Digits {
+    buf: [u8; MAX_DIGITS],
+    len: usize,
+    pub exp: i32,
+    limit: usize,
+    parts: [f64; 4],
 }
 
-// Add an "infinity" representation to the input vector.
-pub fn push_inf(chars: &mut Vec<char>) {
-    chars.append(&mut "inf".chars().collect());
+impl Digits {
+    // Creates an empty set of digits for the number made of `parts`, the first of which
+    // will have the exponent `exp`. `accuracy` is the most significant digits that the
+    // type can represent.
+    //
+    // No more than that many digits are ever kept, and for most numbers that's exactly how
+    // many mean something. Very small numbers have fewer. No component of either type can
+    // hold a bit below the smallest subnormal `f64`, 2^-1074 (about 4.9 x 10^-324), so the
+    // last decimal place that a number can say anything about is 10^-323, whatever its
+    // type and however many components it has. Counting from the first digit, at place
+    // `exp`, that's `exp + 324` digits, and the ones past it are garbage.
+    //
+    // Large numbers aren't limited by this at all. Their last components are far above the
+    // subnormal range, so they keep the full `accuracy`.
+    pub fn new(exp: i32, parts: &[f64], accuracy: usize) -> Digits {
+        let mut all = [0.0; 4];
+        all[..parts.len()].copy_from_slice(parts);
+        Digits {
+            buf: [b'0'; MAX_DIGITS],
+            len: 0,
+            exp,
+            limit: (324 + exp).clamp(1, accuracy as i32) as usize,
+            parts: all,
+        }
+    }
+
+    // Adds the last `count` decimal digits of `n` to the end, including leading zeros.
+    pub fn push(&mut self, mut n: u64, count: usize) {
+        for i in (self.len..self.len + count).rev() {
+            self.buf[i] = b'0' + (n % 10) as u8;
+            n /= 10;
+        }
+        self.len += count;
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    // Rounds the digits to `len` of them (or to as many as can be kept, if that's fewer),
+    // to nearest with ties going to an even last digit. If rounding carries all the way
+    // out of the first digit, the digits become a 1 and the exponent goes up by one.
+    //
+    // A `len` of zero rounds to the digit *before* the first one, which leaves either no
+    // digits or a 1 that belongs in that position.
+    //
+    // This must be called before the digits are written, even if nothing needs to be cut
+    // off, since the last extracted digits are never good enough to write as they are.
+    pub fn round(&mut self, len: usize) {
+        let len = len.min(self.limit);
+        if len > self.len {
+            return;
+        }
+        match self.direction(len) {
+            Some(up) => {
+                self.len = len;
+                if up {
+                    self.carry();
+                }
+            }
+            None => self.round_exact(len),
+        }
+    }
+
+    // Decides from the digits after the first `len` whether rounding to `len` digits goes
+    // up, or returns `None` if they're too close to half of a unit in the last place to
+    // tell (or if there aren't any).
+    //
+    // Only the first 18 digits after the rounding point are looked at, which is plenty
+    // when there are more of them than that. Then even the slop is too small to see, but
+    // a tail that starts with 4999... or 5000... is still left to the exact rounding.
+    fn direction(&self, len: usize) -> Option<bool> {
+        let end = self.len.min(self.limit + 1);
+        let count = (end - len).min(18);
+        if count == 0 {
+            return None;
+        }
+        let tail = self.buf[len..len + count]
+            .iter()
+            .fold(0, |n, &b| n * 10 + (b - b'0') as u64);
+        let half = 5 * 10u64.pow(count as u32 - 1);
+        let slop = if end - len > 18 { 1 } else { SLOP };
+        if tail + slop < half {
+            Some(false)
+        } else if tail > half + slop {
+            Some(true)
+        } else {
+            None
+        }
+    }
+
+    // Adds one to the last digit, carrying as far as it needs to go.
+    fn carry(&mut self) {
+        let mut i = self.len;
+        while i > 0 && self.buf[i - 1] == b'9' {
+            self.buf[i - 1] = b'0';
+            i -= 1;
+        }
+        if i > 0 {
+            self.buf[i - 1] += 1;
+        } else {
+            self.buf[0] = b'1';
+            self.len = 1;
+            self.exp += 1;
+        }
+    }
+
+    // Rounds to `len` digits from the exact value of the number. This replaces all of the
+    // digits, since the ones that were extracted may be off by one in the last place kept
+    // (they may read 2999... when the number is really 3000..., for instance).
+    fn round_exact(&mut self, len: usize) {
+        // The decimal place being rounded to, and the one that holds the first nonzero
+        // digit. That place is one lower than `exp` if the exponent estimate was too high.
+        let place = self.exp - len as i32 + 1;
+        let lead = if self.buf[0] == b'0' {
+            self.exp - 1
+        } else {
+            self.exp
+        };
+
+        if lead >= place {
+            let exact = radix::to_radix(&self.parts, 10, (lead - place + 1) as usize);
+            let bytes = exact.digits().as_bytes();
+            self.buf[..bytes.len()].copy_from_slice(bytes);
+            self.len = bytes.len();
+            self.exp = exact.exponent();
+        } else {
+            // None of the digits are kept, so the number rounds either to zero or to a 1 in
+            // the place being rounded to. It only rounds up if it's more than half of that
+            // place; a tie goes to zero, which is even.
+            let exact = radix::to_radix(&self.parts, 10, usize::MAX);
+            let half = place - 1;
+            self.len = 0;
+            if exact.exponent() > half || (exact.exponent() == half && exact.digits() > "5") {
+                self.buf[0] = b'1';
+                self.len = 1;
+                self.exp = place;
+            }
+        }
+    }
+
+    // Removes trailing zeros, though never the first digit.
+    fn trim(&mut self) {
+        while self.len > 1 && self.buf[self.len - 1] == b'0' {
+            self.len -= 1;
+        }
+    }
+
+    // Replaces a leading 0 that comes from an estimated exponent being one too high.
+    fn drop_leading_zero(&mut self) {
+        if self.buf[0] == b'0' && self.len > 1 {
+            self.buf.copy_within(1..self.len, 0);
+            self.len -= 1;
+            self.exp -= 1;
+        }
+    }
+
+    // Returns the first `count` significant digits, as numbers from 0 to 9 rather than as
+    // ASCII, along with the exponent of the first of them. Digits past the ones that can be
+    // kept come from the exact value of the number rather than being filled with zeros.
+    pub fn into_parts(mut self, count: usize) -> (Vec<u8>, i32) {
+        self.drop_leading_zero();
+        if count > self.limit {
+            let exact = radix::to_radix(&self.parts, 10, count);
+            let mut digits: Vec<u8> = exact.digits().bytes().map(|b| b - b'0').collect();
+            digits.resize(count, 0);
+            return (digits, exact.exponent());
+        }
+        self.round(count);
+        let mut digits: Vec<u8> = self.as_bytes().iter().map(|b| b - b'0').collect();
+        digits.resize(count, 0);
+        (digits, self.exp)
+    }
+}
+
+// A piece of formatted output. Numbers are written as a handful of these so that long runs
+// of zeros (and the exponent) don't have to be put into a buffer first.
+#[derive(Clone, Copy)]
+enum Part<'a> {
+    Copy(&'a [u8]),
+    Zeros(usize),
+    Exp(char, i32),
 }
 
-// Add zero to the input vector. The number of characters in the zero is determined by the
-// supplied formatter.
-pub fn push_zero(chars: &mut Vec<char>, f: &Formatter) {
-    chars.push('0');
-    if let Some(p) = f.precision() {
-        if p > 0 {
-            chars.push('.');
-            for _ in 0..p {
-                chars.push('0');
+impl Part<'_> {
+    fn len(&self) -> usize {
+        match *self {
+            Part::Copy(bytes) => bytes.len(),
+            Part::Zeros(n) => n,
+            Part::Exp(_, exp) => {
+                let mut n = if exp < 0 { 3 } else { 2 };
+                let mut e = exp.unsigned_abs();
+                while e >= 10 {
+                    e /= 10;
+                    n += 1;
+                }
+                n
             }
         }
     }
+
+    fn write(&self, f: &mut Formatter) -> Result {
+        match *self {
+            // The bytes are always ASCII digits and decimal points
+            Part::Copy(bytes) => f.write_str(std::str::from_utf8(bytes).unwrap()),
+            Part::Zeros(mut n) => {
+                while n > ZEROS.len() {
+                    f.write_str(ZEROS)?;
+                    n -= ZEROS.len();
+                }
+                f.write_str(&ZEROS[..n])
+            }
+            Part::Exp(marker, exp) => {
+                f.write_char(marker)?;
+                write!(f, "{}", exp)
+            }
+        }
+    }
+}
+
+// Returns the sign to write for the number, if any.
+pub fn sign(negative: bool, f: &Formatter) -> &'static str {
+    if negative {
+        "-"
+    } else if f.sign_plus() {
+        "+"
+    } else {
+        ""
+    }
+}
+
+// Writes a "not-a-number" representation. This is never zero-filled, and as with `f64`, it
+// never has a sign, even if the sign bit is set or a `+` is asked for.
+pub fn write_nan(f: &mut Formatter) -> Result {
+    pad(f, "", &[Part::Copy(b"NaN")], false)
+}
+
+// Writes an "infinity" representation. This is never zero-filled.
+pub fn write_inf(f: &mut Formatter, sign: &str) -> Result {
+    pad(f, sign, &[Part::Copy(b"inf")], false)
+}
+
+// Writes zero, with as many zeros after the decimal point as the formatter's precision
+// calls for. If there's an exponent marker, an exponent of 0 follows, and the zero isn't
+// zero-filled, since zeros in front of it would just look like more of it.
+pub fn write_zero(f: &mut Formatter, sign: &str, marker: Option<char>) -> Result {
+    let prec = f.precision().unwrap_or(0);
+    let exp = match marker {
+        Some(m) => Part::Exp(m, 0),
+        None => Part::Zeros(0),
+    };
+    let zero_fill = marker.is_none();
+    if prec > 0 {
+        pad(
+            f,
+            sign,
+            &[Part::Copy(b"0."), Part::Zeros(prec), exp],
+            zero_fill,
+        )
+    } else {
+        pad(f, sign, &[Part::Copy(b"0"), exp], zero_fill)
+    }
 }
 
-// Add an exponent to the input vector.
-pub fn push_exp(chars: &mut Vec<char>, marker: char, exp: i32) {
-    chars.push(marker);
-    chars.append(&mut exp.to_string().chars().collect());
+// Writes zero with the supplied number of significant digits, all of which are zeros. This
+// is the zero that goes with `write_sig`.
+pub fn write_zero_sig(f: &mut Formatter, sign: &str, prec: usize) -> Result {
+    if prec > 1 {
+        pad(f, sign, &[Part::Copy(b"0."), Part::Zeros(prec - 1)], true)
+    } else {
+        pad(f, sign, &[Part::Copy(b"0")], true)
+    }
 }
 
-// Rounds the digits in a vector to a certain index and then truncates the vector at that
-// index.
-pub fn round_and_trunc(digits: &mut Vec<u8>, len: usize) {
-    if digits[len] >= 5 {
-        // Round up if the digit after the last desired digit is 5 or higher
-        let mut i: usize = 1;
-        digits[len - 1] += 1;
-        // Round up until there are no more 9's, if it's 9's all the way, leave
-        // the first element as a 10, which we'll handle later
-        while digits[len - i] == 10 && len != i {
-            digits[len - i] = 0;
-            i += 1;
-            digits[len - i] += 1;
+// Writes the digits in fixed form, with a decimal point (if needed) rather than an
+// exponent.
+//
+// If there's a precision, the number is rounded to that many places after the decimal
+// point, and zeros are added to fill out that many places if necessary. Otherwise, every
+// digit is written except for trailing zeros after the decimal point.
+pub fn write_fixed(f: &mut Formatter, sign: &str, mut digits: Digits) -> Result {
+    let prec = match f.precision() {
+        Some(p) => {
+            // The number of digits that come before the place being rounded to. This can
+            // be zero or less when all of the digits are past that place.
+            let keep = digits.exp as isize + 1 + p as isize;
+            if keep < 0 {
+                digits.len = 0;
+            } else {
+                digits.round(keep as usize);
+            }
+            p
         }
+        None => {
+            digits.round(digits.limit);
+            digits.trim();
+            0.max(digits.len as i32 - digits.exp - 1) as usize
+        }
+    };
+    if digits.len == 0 {
+        // Every digit rounded away
+        digits.exp = 0;
     }
-    digits.truncate(len);
+    layout_fixed(f, sign, &digits, prec, None)
 }
 
-// Adds or removes zeros to the vector depending on the exponent. If the exponent is
-// positive, this will only add zeros to the end if zeros need to be added in order for the
-// number to reach the decimal point position. If the exponent is negative, zeros will be
-// added to the beginning of the vector.
+// Writes the digits in exponential form, with one digit before the decimal point.
 //
-// The exponent parameter is used *only* for determining the number of zeros that need to be
-// added or removed. If this is being used for a number that is to be in exponential form,
-// use 0 for the exponent.
-pub fn adjust_zeros(digits: &mut Vec<u8>, exp: i32) {
-    let absexp = exp.abs() as usize;
-    let accuracy = digits.len() as isize;
-    // First we want to drop trailing zeros that make the width of the vector higher than
-    // the exponent + 1 (i.e., trailing zeros that would be to the right of the decimal
-    // point, after we put in a decimal point). These will be re-added later if the
-    // specified precision requires it.
-    if digits.len() as i32 > exp + 1 {
-        let mut new_len = digits.len();
-        while digits[new_len - 1] == 0 && new_len > 1 && new_len as i32 > exp + 1 {
-            new_len -= 1;
-        }
-        digits.truncate(new_len);
-    }
-
-    // Add zeros to the left (if exp is negative) or the right (if non-negative) to make
-    // the whole number reach the decimal point if it doesn't already.
-    if exp < 0 {
-        digits.splice(..0, vec![0; absexp]);
+// If there's a precision, the number is rounded to that many places after the decimal
+// point, and zeros are added to fill out that many places if necessary. Otherwise, every
+// digit is written except for trailing zeros.
+pub fn write_exp(f: &mut Formatter, sign: &str, mut digits: Digits, marker: char) -> Result {
+    let prec = match f.precision() {
+        Some(p) => {
+            digits.round(p + 1);
+            p
+        }
+        None => {
+            digits.round(digits.limit);
+            digits.trim();
+            digits.len - 1
+        }
+    };
+    let exp = digits.exp;
+    digits.exp = 0;
+    layout_fixed(f, sign, &digits, prec, Some(Part::Exp(marker, exp)))
+}
+
+// Writes the digits with exactly `prec` significant digits, keeping any trailing zeros
+// unless `trim` is set. This is the equivalent of C's `%#g` (or `%g` with `trim`): the
+// number is written in fixed form unless its exponent is less than -4 or at least `prec`,
+// in which case it's written in exponential form.
+//
+// The digits are expected to be the output of a type's `extract_digits`. The exponent
+// that was used to extract them is an estimate based on the first component, so it's
+// corrected here if the first digit turns out to be a 0. Since the exponent can also
+// change when rounding cascades all the way to the first digit, the choice between fixed
+// and exponential form isn't made until after rounding.
+pub fn write_sig(
+    f: &mut Formatter,
+    sign: &str,
+    mut digits: Digits,
+    prec: usize,
+    trim: bool,
+) -> Result {
+    digits.drop_leading_zero();
+    digits.round(prec);
+    // Trimmed digits keep only as many places as it takes to hold what's left
+    let sig = if trim {
+        digits.trim();
+        digits.len
+    } else {
+        prec
+    };
+
+    let exp = digits.exp;
+    if exp < -4 || exp >= prec as i32 {
+        digits.exp = 0;
+        layout_fixed(f, sign, &digits, sig - 1, Some(Part::Exp('e', exp)))
+    } else {
+        let places = (sig as i32 - exp - 1).max(0) as usize;
+        layout_fixed(f, sign, &digits, places, None)
+    }
+}
+
+// Writes digits with `prec` places after the decimal point, followed by an exponent if
+// there is one. The digits must already be rounded so that none of them go past those
+// places. Empty digits are written as zero.
+fn layout_fixed(
+    f: &mut Formatter,
+    sign: &str,
+    digits: &Digits,
+    prec: usize,
+    exp: Option<Part>,
+) -> Result {
+    let bytes = digits.as_bytes();
+    let exp = exp.unwrap_or(Part::Zeros(0));
+    let point: &[u8] = if prec > 0 { b"." } else { b"" };
+
+    if digits.exp < 0 {
+        // All of the digits are after the decimal point, with zeros in front of them
+        let lead = (-digits.exp - 1) as usize;
+        let trail = prec - lead - bytes.len();
+        pad(
+            f,
+            sign,
+            &[
+                Part::Copy(b"0"),
+                Part::Copy(point),
+                Part::Zeros(lead),
+                Part::Copy(bytes),
+                Part::Zeros(trail),
+                exp,
+            ],
+            true,
+        )
     } else {
-        let zero_length = 0.max(absexp as isize - accuracy + 1) as usize;
-        if zero_length > 0 {
-            digits.append(&mut vec![0; zero_length]);
+        let int = digits.exp as usize + 1;
+        if bytes.len() <= int {
+            let zeros = if bytes.is_empty() {
+                1
+            } else {
+                int - bytes.len()
+            };
+            pad(
+                f,
+                sign,
+                &[
+                    Part::Copy(bytes),
+                    Part::Zeros(zeros),
+                    Part::Copy(point),
+                    Part::Zeros(prec),
+                    exp,
+                ],
+                true,
+            )
+        } else {
+            let frac = bytes.len() - int;
+            pad(
+                f,
+                sign,
+                &[
+                    Part::Copy(&bytes[..int]),
+                    Part::Copy(point),
+                    Part::Copy(&bytes[int..]),
+                    Part::Zeros(prec - frac),
+                    exp,
+                ],
+                true,
+            )
         }
     }
 }
 
-// Adds zeros or removes digits from the vector to correspond to the supplied precision. If
-// the vector is not long enough to reach that precision, zeros will be added to the end; if
-// it's too long, the vector will be truncated. In this case, the last removed digit will be
-// used to round the rest of the digits.
-//
-// In the case of a number consisting of all 9's that must be truncated, the first element
-// will become a 10 while the rest will be 0's. This will happen only with numbers with
-// positive exponents, as the ones with negative exponents already had the pre-decimal-point
-// zero added which will halt rounding cascades on those numbers. This makes it easy to tell
-// later when an exponent needs to be adjusted to place the decimal point correctly. This is
-// also the only time when any vector element will be something other than a single-digit
-// integer.
-pub fn adjust_prec(digits: &mut Vec<u8>, exp: i32, prec: Option<usize>) {
-    if let Some(p) = prec {
-        // If exp < 0, we add 1 for the zero before the decimal point
-        let desired = if exp < 0 { p + 1 } else { exp as usize + p + 1 };
-
-        if desired > digits.len() {
-            digits.append(&mut vec![0; desired - digits.len()]);
-        } else if desired < digits.len() {
-            // The only other option is desired == digits.len(), as zeros would have
-            // already been appended if desired > digits.len()
-            round_and_trunc(digits, desired);
-        }
-    }
-}
-
-// Positions a decimal point at the correct location dependiong on the exponent. Since the
-// decimal point is not a `u8` like the digits are, this function returns a character vector
-// rather than manipulating the input vector in place.
+// Writes the sign and the parts, adjusting the width based on alignment, width, and fill
+// settings. This function also handles the sign-aware zero fill, which puts zeros between
+// the sign and the first digit, when `zero_fill` is set. When it isn't, because there's no
+// digit for the zeros to go in front of (infinity and NaN) or because they'd look like part
+// of the number (zero in exponential form), the parts are right-aligned with spaces
+// instead, as C's `printf` does for infinity and NaN.
 //
-// The function also handles the `10` "digit" that can be in the first position for a
-// positive-exponent number that cascade rounded all the way to the first digit.
+// A width setting can increase the number of characters written, but it cannot decrease
+// it. As a consequence, align and fill are ignored if there isn't a width specified that is
+// higher than the length of the number.
 //
-// A decimal point will *not* be placed if the length of the vector indicates that the last
-// digit is right at the decimal point location. This means we'll end up with "10" rather
-// than "10.", for instance.
-pub fn place_decimal(digits: Vec<u8>, exp: i32) -> Vec<char> {
-    let offset = if digits[0] == 10 { 1 } else { 0 };
-    let mut result = Vec::with_capacity(digits.len() + offset + 1);
-
-    for d in digits {
-        if d == 10 {
-            // The only "digit" that might be 10 is the first one, if cascading rounding
-            // reached all the way to the beginning of the number. When this happens we have
-            // to push both characters.
-            result.push('1');
-            result.push('0');
-        } else {
-            result.push(char::from_digit(d as u32, 10).unwrap());
+// The length is taken from the parts themselves, so any rounding has to be finished before
+// this is called. That includes a carry that adds a digit, as when 999.9995 becomes 1000.000.
+fn pad(f: &mut Formatter, sign: &str, parts: &[Part], zero_fill: bool) -> Result {
+    let len = sign.len() + parts.iter().map(Part::len).sum::<usize>();
+    let delta = f.width().map_or(0, |w| w.saturating_sub(len));
+    let fill = f.fill();
+
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, delta),
+        Some(Alignment::Right) => (delta, 0),
+        Some(Alignment::Center) => (delta / 2, delta - delta / 2),
+        None => {
+            if f.sign_aware_zero_pad() && zero_fill {
+                f.write_str(sign)?;
+                Part::Zeros(delta).write(f)?;
+                return write_parts(f, parts);
+            }
+            (delta, 0)
         }
+    };
+
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(sign)?;
+    write_parts(f, parts)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+fn write_parts(f: &mut Formatter, parts: &[Part]) -> Result {
+    for part in parts {
+        part.write(f)?;
     }
+    Ok(())
+}
 
-    let position = 0.max(exp) as usize + offset + 1;
-    // Don't add a decimal point if the number doesn't have any digits after the decimal
-    // point
-    if position < result.len() {
-        result.insert(position, '.');
+// Puts `sep` between each group of three digits in the integer part of `s`, which is a
+// number written in fixed form by `Display`, and replaces its decimal point with
+// `decimal_sep`. Integer parts of fewer than four digits get no separator at all. Infinity
+// and NaN, having no digits, come through unchanged.
+pub fn group(s: &str, sep: char, decimal_sep: char) -> String {
+    let (sign, rest) = match s.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", s),
+    };
+    let (int, frac) = match rest.split_once('.') {
+        Some((int, frac)) => (int, Some(frac)),
+        None => (rest, None),
+    };
+    if !int.bytes().all(|b| b.is_ascii_digit()) {
+        return s.to_string();
     }
 
-    result
+    let mut out = String::with_capacity(s.len() + int.len() / 3 * sep.len_utf8());
+    out.push_str(sign);
+    for (i, c) in int.chars().enumerate() {
+        if i > 0 && (int.len() - i) % 3 == 0 {
+            out.push(sep);
+        }
+        out.push(c);
+    }
+    if let Some(frac) = frac {
+        out.push(decimal_sep);
+        out.push_str(frac);
+    }
+    out
 }
 
-// Adjust the width of the number based on alignment, width, and fill settings. This
-// function also handles the sign-aware zero fill.
-//
-// This function does nothing if either no width is specified or if the specified width is
-// not greater than the current vector length. A width setting can increase the number of
-// characters in the vector, but it cannot decrease it. As a consequence, align and fill are
-// ignored if there isn't a width specified that is higher than the vector length.
-pub fn align_and_fill(chars: &mut Vec<char>, signed: bool, f: &mut Formatter) {
-    if let Some(width) = f.width() {
-        let len = chars.len();
-        if len < width {
-            let delta = width - len;
-            let fill = f.fill();
-
-            match f.align() {
-                Some(Alignment::Left) => {
-                    for _ in 0..delta {
-                        chars.push(fill);
-                    }
-                }
-                Some(Alignment::Right) => {
-                    for _ in 0..delta {
-                        chars.insert(0, fill);
-                    }
-                }
-                Some(Alignment::Center) => {
-                    let left = delta / 2;
-                    let right = delta - left;
-                    for _ in 0..left {
-                        chars.insert(0, fill);
-                    }
-                    for _ in 0..right {
-                        chars.push(fill);
-                    }
-                }
-                None => {
-                    if f.sign_aware_zero_pad() {
-                        let index = if signed { 1 } else { 0 };
-                        for _ in 0..delta {
-                            chars.insert(index, '0');
-                        }
-                    } else {
-                        for _ in 0..delta {
-                            chars.insert(0, fill);
-                        }
-                    }
-                }
-            }
+// Writes significant digits (numbers from 0 to 9, as returned by `Digits::into_parts`), the
+// first of which is at the decimal place `exp`, in engineering notation. The exponent is
+// the multiple of 3 that leaves one to three digits before the decimal point, and zeros
+// are added to fill out those digits if there are too few significant ones. Every
+// significant digit is written, including trailing zeros.
+pub fn eng(negative: bool, digits: &[u8], exp: i32) -> String {
+    let eng = exp.div_euclid(3) * 3;
+    let int = (exp - eng + 1) as usize;
+
+    let mut out = String::with_capacity(digits.len() + 8);
+    if negative {
+        out.push('-');
+    }
+    for i in 0..int.max(digits.len()) {
+        if i == int {
+            out.push('.');
         }
+        out.push((b'0' + digits.get(i).copied().unwrap_or(0)) as char);
     }
+    // Writing to a `String` can't fail
+    let _ = write!(out, "e{}", eng);
+    out
 }
@@ -0,0 +1,164 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::error::{ErrorKind, FormatErrorKind};
+
+// The largest number of implied decimals whose power of ten fits into a `u128`, and
+// therefore the largest that can be applied with a single exact scaling.
+pub const MAX_EXACT_DECIMALS: u32 = 38;
+
+// Powers of ten that fit into a `u128`, indexed by exponent.
+const POWERS_OF_TEN: [u128; 39] = {
+    let mut table = [1u128; 39];
+    let mut i = 1;
+    while i < 39 {
+        table[i] = table[i - 1] * 10;
+        i += 1;
+    }
+    table
+};
+
+// Returns the power of ten with the given exponent as a `u128`. The exponent must be no
+// larger than `MAX_EXACT_DECIMALS`.
+#[inline]
+pub fn pow10(exp: u32) -> u128 {
+    debug_assert!(
+        exp <= MAX_EXACT_DECIMALS,
+        "10^{} does not fit into a u128",
+        exp
+    );
+    POWERS_OF_TEN[exp as usize]
+}
+
+// Splits a `u128` into three `f64`s that add up to exactly that `u128`. Each part holds no
+// more than 43 bits, so none of them are rounded. They don't overlap, but they aren't
+// normalized either; that's up to the caller.
+#[inline]
+pub fn split_u128(n: u128) -> (f64, f64, f64) {
+    const MASK: u128 = (1 << 43) - 1;
+    let a = (n >> 86) as f64 * 2f64.powi(86);
+    let b = ((n >> 43) & MASK) as f64 * 2f64.powi(43);
+    let c = (n & MASK) as f64;
+    (a, b, c)
+}
+
+// Parses a fixed-width field of ASCII bytes into a sign (true if negative) and an unsigned
+// integer.
+//
+// The field may have any number of spaces on either end. Within the spaces there must be
+// an optional sign followed by one or more digits; leading zeros are simply accumulated
+// like any other digit. The integer is accumulated in a `u128`, and if it grows too large
+// for that an `Overflow` error results. Nothing here allocates or validates UTF-8.
+pub fn parse_fixed(bytes: &[u8]) -> Result<(bool, u128), ErrorKind> {
+    let start = match bytes.iter().position(|&b| b != b' ') {
+        Some(i) => i,
+        None => return Err(ErrorKind::Empty),
+    };
+    // There's at least one non-space byte, so this will always find something
+    let end = bytes.iter().rposition(|&b| b != b' ').unwrap() + 1;
+    let field = &bytes[start..end];
+
+    let (negative, digits) = match field[0] {
+        b'-' => (true, &field[1..]),
+        b'+' => (false, &field[1..]),
+        _ => (false, field),
+    };
+    if digits.is_empty() {
+        return Err(ErrorKind::Invalid);
+    }
+
+    let mut n: u128 = 0;
+    for &b in digits {
+        if !b.is_ascii_digit() {
+            return Err(ErrorKind::Invalid);
+        }
+        n = n
+            .checked_mul(10)
+            .and_then(|n| n.checked_add((b - b'0') as u128))
+            .ok_or(ErrorKind::Overflow)?;
+    }
+    Ok((negative, n))
+}
+
+// Writes a sign and an unsigned integer into a fixed-width field of ASCII bytes. The
+// integer is right-aligned and the field is padded with zeros. If the integer is negative,
+// the sign goes into the first byte of the field. The number of bytes written (which is
+// always the entire field) is returned.
+pub fn write_fixed(negative: bool, n: u128, buf: &mut [u8]) -> Result<usize, FormatErrorKind> {
+    let negative = negative && n != 0;
+    let mut digits = 1;
+    let mut m = n / 10;
+    while m > 0 {
+        digits += 1;
+        m /= 10;
+    }
+    if digits + negative as usize > buf.len() {
+        return Err(FormatErrorKind::BufferTooSmall);
+    }
+
+    let mut m = n;
+    for b in buf.iter_mut().rev() {
+        *b = b'0' + (m % 10) as u8;
+        m /= 10;
+    }
+    if negative {
+        buf[0] = b'-';
+    }
+    Ok(buf.len())
+}
+
+// Rounds an exact sum of `f64` products to the nearest integer, with ties going to the
+// even integer. The products are given as pairs of factors, which are turned into exact
+// terms with `two_prod`. `guess` must already be close to the answer (within the range of
+// an `i128`, and close enough that the difference fits into an `f64`); the residual
+// between the exact sum and the guess is then computed exactly and used to correct it.
+//
+// `terms` is scratch space. It must have room for two terms per product plus four more.
+pub fn round_half_even(products: &[(f64, f64)], guess: i128, terms: &mut [f64]) -> i128 {
+    let np = 2 * products.len();
+    let n = np + 4;
+    let terms = &mut terms[..n];
+
+    // Fills in the terms with the products minus the guess (plus an offset), distills
+    // them, and returns the sign of their exact sum.
+    let sign = |terms: &mut [f64], guess: i128, offset: f64| {
+        for (i, &(a, b)) in products.iter().enumerate() {
+            let (h, l) = crate::common::primitive::two_prod(a, b);
+            terms[2 * i] = h;
+            terms[2 * i + 1] = l;
+        }
+        let (a, b, c) = split_u128(guess.unsigned_abs());
+        let s = if guess < 0 { 1.0 } else { -1.0 };
+        terms[np] = s * a;
+        terms[np + 1] = s * b;
+        terms[np + 2] = s * c;
+        terms[np + 3] = offset;
+        crate::common::utils::distill(terms);
+        terms[n - 1]
+    };
+
+    let mut k = guess;
+    // In practice, the guess is right or off by one, so this is only a few iterations. The
+    // limit is there to make certain that it ends even if it somehow isn't.
+    for _ in 0..8 {
+        let r = sign(terms, k, 0.0);
+        if r.abs() > 1.0 {
+            k += r.round() as i128;
+            continue;
+        }
+        let above = sign(terms, k, -0.5);
+        if above > 0.0 || (above == 0.0 && k % 2 != 0) {
+            k += 1;
+            continue;
+        }
+        let below = sign(terms, k, 0.5);
+        if below < 0.0 || (below == 0.0 && k % 2 != 0) {
+            k -= 1;
+            continue;
+        }
+        break;
+    }
+    k
+}
@@ -0,0 +1,274 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::error::ErrorKind;
+use std::fmt::Write;
+
+// Returns 2^exp as an `f64`. The exponent has to be in the normal range, -1022 to 1023.
+#[inline]
+fn pow2(exp: i64) -> f64 {
+    f64::from_bits(((exp + 1023) as u64) << 52)
+}
+
+// Writes the components of a number as hexadecimal floats, in the form of C's `%a`, with
+// the sign of each component after the first written as a separating ` + ` or ` - `.
+//
+// A number that has a non-finite component is written as one word instead: `inf` or `-inf`
+// if its first component is infinite and the rest are finite, and `NaN` otherwise.
+pub fn to_hex_string(components: &[f64]) -> String {
+    let first = components[0];
+    if components.iter().any(|x| !x.is_finite()) {
+        let rest_finite = components[1..].iter().all(|x| x.is_finite());
+        return String::from(match first {
+            f64::INFINITY if rest_finite => "inf",
+            f64::NEG_INFINITY if rest_finite => "-inf",
+            _ => "NaN",
+        });
+    }
+
+    let mut s = String::with_capacity(24 * components.len());
+    if first.is_sign_negative() {
+        s.push('-');
+    }
+    write_f64(&mut s, first);
+    for &x in &components[1..] {
+        s.push_str(if x.is_sign_negative() { " - " } else { " + " });
+        write_f64(&mut s, x);
+    }
+    s
+}
+
+// Writes the absolute value of a finite `f64` as a hexadecimal float. Normal numbers have a
+// leading 1 and subnormals have a leading 0 with the smallest normal exponent, and trailing
+// zeros in the fraction are dropped, along with the point if that leaves nothing after it.
+fn write_f64(s: &mut String, x: f64) {
+    let bits = x.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    let mut frac = bits & ((1 << 52) - 1);
+    let (lead, exp) = match biased {
+        0 if frac == 0 => ('0', 0),
+        0 => ('0', -1022),
+        _ => ('1', biased - 1023),
+    };
+
+    s.push_str("0x");
+    s.push(lead);
+    if frac != 0 {
+        let mut width = 13;
+        while frac & 0xf == 0 {
+            frac >>= 4;
+            width -= 1;
+        }
+        // Writing to a `String` can't fail
+        write!(s, ".{:0width$x}", frac, width = width).unwrap();
+    }
+    write!(s, "p{:+}", exp).unwrap();
+}
+
+// Parses a string of hexadecimal floats, as written by `to_hex_string`, into `out`. Any
+// components that the string doesn't have are set to zero. The components are not
+// normalized; that's up to the caller.
+//
+// Each component must be exactly representable as an `f64`, since the point of this format
+// is that nothing gets rounded. A component with too many significant bits, or one that is
+// too large or too small for an `f64`, is `Invalid`.
+pub fn parse(s: &str, out: &mut [f64]) -> Result<(), ErrorKind> {
+    // Error positions are offsets into the string as it was passed in, so they have to
+    // count any whitespace that gets trimmed off of the front
+    let offset = s.len() - s.trim_start().len();
+    let s = s.trim().to_ascii_lowercase();
+    let bytes = s.as_bytes();
+    if bytes.is_empty() {
+        return Err(ErrorKind::Empty);
+    }
+    for x in out.iter_mut() {
+        *x = 0.0;
+    }
+
+    let (mut negative, mut i) = match bytes[0] {
+        b'-' => (true, 1),
+        b'+' => (false, 1),
+        _ => (false, 0),
+    };
+    match &s[i..] {
+        "nan" => {
+            out[0] = if negative { -f64::NAN } else { f64::NAN };
+            return Ok(());
+        }
+        "inf" | "infinity" => {
+            out[0] = if negative {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            };
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    let mut count = 0;
+    loop {
+        let (x, end) = parse_f64(bytes, i, offset)?;
+        out[count] = if negative { -x } else { x };
+        count += 1;
+
+        i = skip_spaces(bytes, end);
+        if i == bytes.len() {
+            return Ok(());
+        }
+        negative = match bytes[i] {
+            b'-' if count < out.len() => true,
+            b'+' if count < out.len() => false,
+            _ => {
+                return Err(ErrorKind::InvalidDigit {
+                    position: i + offset,
+                })
+            }
+        };
+        i = skip_spaces(bytes, i + 1);
+    }
+}
+
+fn skip_spaces(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+        i += 1;
+    }
+    i
+}
+
+// Parses one unsigned hexadecimal float starting at byte `start`, returning its value and
+// the index of the byte after it. The float is a `0x`, hexadecimal digits with at most one
+// point among them, and a `p` followed by an optionally signed decimal exponent.
+fn parse_f64(bytes: &[u8], start: usize, offset: usize) -> Result<(f64, usize), ErrorKind> {
+    let invalid_digit = |i: usize| {
+        if i < bytes.len() {
+            ErrorKind::InvalidDigit {
+                position: i + offset,
+            }
+        } else {
+            ErrorKind::Invalid
+        }
+    };
+
+    let mut i = start;
+    for &expected in b"0x" {
+        if bytes.get(i) != Some(&expected) {
+            return Err(invalid_digit(i));
+        }
+        i += 1;
+    }
+
+    // The significant digits are accumulated into `mant` while there's room for them.
+    // Digits past that point can only be zeros if the component is to be exact, and they
+    // scale the mantissa up if they come before the point.
+    let mut mant: u64 = 0;
+    let mut exp: i64 = 0;
+    let mut digits = 0;
+    let mut point = false;
+    let mut exact = true;
+    while i < bytes.len() {
+        match (bytes[i] as char).to_digit(16) {
+            Some(d) => {
+                digits += 1;
+                if mant >> 60 == 0 {
+                    mant = mant << 4 | d as u64;
+                    if point {
+                        exp -= 4;
+                    }
+                } else {
+                    exact &= d == 0;
+                    if !point {
+                        exp += 4;
+                    }
+                }
+            }
+            None if bytes[i] == b'.' && !point => point = true,
+            None => break,
+        }
+        i += 1;
+    }
+    if digits == 0 {
+        return Err(invalid_digit(i));
+    }
+    if bytes.get(i) != Some(&b'p') {
+        return Err(invalid_digit(i));
+    }
+    let (pexp, end) = parse_exp(bytes, i + 1, offset)?;
+
+    if !exact {
+        return Err(ErrorKind::Invalid);
+    }
+    make_f64(mant, exp + pexp as i64)
+        .map(|x| (x, end))
+        .ok_or(ErrorKind::Invalid)
+}
+
+// Parses a decimal exponent starting at byte `start`, which may have a sign. Like the
+// exponent of a decimal string, it's read in full before an overflow is reported.
+fn parse_exp(bytes: &[u8], start: usize, offset: usize) -> Result<(i32, usize), ErrorKind> {
+    let mut i = start;
+    let negative = match bytes.get(i) {
+        Some(b'-') => {
+            i += 1;
+            true
+        }
+        Some(b'+') => {
+            i += 1;
+            false
+        }
+        _ => false,
+    };
+
+    let mut exp: i32 = 0;
+    let mut digits = 0;
+    let mut overflow = false;
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        digits += 1;
+        // Accumulating negative numbers lets i32::MIN through
+        let d = (bytes[i] - b'0') as i32;
+        let d = if negative { -d } else { d };
+        match exp.checked_mul(10).and_then(|e| e.checked_add(d)) {
+            Some(e) => exp = e,
+            None => overflow = true,
+        }
+        i += 1;
+    }
+
+    if digits == 0 {
+        Err(if i < bytes.len() {
+            ErrorKind::InvalidDigit {
+                position: i + offset,
+            }
+        } else {
+            ErrorKind::Invalid
+        })
+    } else if overflow {
+        Err(ErrorKind::ExponentOverflow)
+    } else {
+        Ok((exp, i))
+    }
+}
+
+// Returns mant * 2^exp as an `f64`, or `None` if that can't be represented exactly.
+pub fn make_f64(mut mant: u64, mut exp: i64) -> Option<f64> {
+    if mant == 0 {
+        return Some(0.0);
+    }
+    let zeros = mant.trailing_zeros();
+    mant >>= zeros;
+    exp += zeros as i64;
+
+    // Every bit has to be a bit of an `f64`: no more than 53 of them, the lowest no lower
+    // than the lowest bit of the smallest subnormal, and the highest no higher than the
+    // highest bit of `f64::MAX`
+    let bits = 64 - mant.leading_zeros() as i64;
+    if bits > 53 || exp < -1074 || exp + bits - 1 > 1023 {
+        return None;
+    }
+    // Two steps, since 2^exp on its own might not be a normal number. Neither step
+    // rounds, because the mantissa has no more than 53 bits and the result is exact.
+    let half = exp / 2;
+    Some(mant as f64 * pow2(half) * pow2(exp - half))
+}
@@ -0,0 +1,65 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! The conversion behind `try_dd!` and `try_qd!` when they're given something other than a
+//! literal. Strings are parsed, and their errors are passed along. Anything else that the
+//! type has a `From` implementation for can't fail, so it's converted and wrapped in `Ok`.
+
+use crate::double::Double;
+use crate::quad::Quad;
+use std::str::FromStr;
+
+/// Converts a string or a number into a `Double` or a `Quad`, returning the error from
+/// parsing a string instead of turning it into NaN. This is only public so that `try_dd!`
+/// and `try_qd!` can name it.
+#[doc(hidden)]
+pub trait TryInput<T: FromStr> {
+    fn try_input(self) -> Result<T, T::Err>;
+}
+
+impl<T: FromStr> TryInput<T> for &str {
+    #[inline]
+    fn try_input(self) -> Result<T, T::Err> {
+        self.parse()
+    }
+}
+
+impl<T: FromStr> TryInput<T> for String {
+    #[inline]
+    fn try_input(self) -> Result<T, T::Err> {
+        self.parse()
+    }
+}
+
+impl<T: FromStr> TryInput<T> for &String {
+    #[inline]
+    fn try_input(self) -> Result<T, T::Err> {
+        self.parse()
+    }
+}
+
+impl<T: FromStr + From<N>, N: Number> TryInput<T> for N {
+    #[inline]
+    fn try_input(self) -> Result<T, T::Err> {
+        Ok(T::from(self))
+    }
+}
+
+// The types that aren't strings. `&str` is kept out of this so that the implementation
+// above doesn't overlap the one for strings, even though `Double` and `Quad` have `From`
+// implementations for it.
+pub trait Number {}
+
+macro_rules! number_impl {
+    ($($t:ty),*) => ($(
+        impl Number for $t {}
+    )*);
+}
+
+number_impl! {
+    i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, f32, f64,
+    (f64, f64), (f64, f64, f64), (f64, f64, f64, f64),
+    Double, Quad
+}
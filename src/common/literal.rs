@@ -0,0 +1,888 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Support for the `dd!` and `qd!` macros, which turn literals into numbers at compile time.
+// The macros `stringify!` their literal and hand the text to a `const fn` on `Double` or
+// `Quad`, which uses what's in here to pick the literal apart and, for float literals, to
+// decide whether its digits have to be parsed or whether it can be converted at all.
+//
+// A float literal stands for the number its digits spell out, so that's what the macros
+// make of it: `0.1` is converted just as the string "0.1" would be, and `1e23` is exactly
+// 10^23, even though the `f64`s nearest to them are neither. Where the `f64` is exactly
+// equal to the literal, it's used as it is, which saves parsing anything.
+//
+// Everywhere else in Rust, though, a float literal is rounded to an `f64`, and the digits
+// past the 15 or so that one can hold make no difference to it. A literal with more digits
+// than that is written as though they mattered, and rather than quietly make the macros the
+// one place where they do, they turn it into a compile error unless the `f64` is exactly
+// equal to the literal anyway. Digits like that belong in a string literal.
+
+/// Why `dd!` or `qd!` couldn't convert a literal. The macros turn this into a compile
+/// error, so it's only public because they need to name it.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LiteralError {
+    /// A float literal has more significant digits than an `f64` (or `f32`) can hold, and
+    /// isn't exactly equal to one either.
+    Lossy,
+    /// The literal can't be converted at all, for the reason given.
+    Invalid(&'static str),
+}
+
+// A literal, as text from `stringify!`, picked apart.
+pub enum Literal<'a> {
+    // The contents of a string literal (or raw string literal)
+    Str(&'a str),
+    // An integer literal, with its magnitude and its type suffix
+    Int {
+        negative: bool,
+        value: u128,
+        suffix: IntSuffix,
+    },
+    // A float literal, or an integer literal with a float suffix
+    Float(Decimal),
+}
+
+// The suffix of an integer literal, with the width of the type in bits. The sizes of
+// `isize` and `usize` depend on the target, and since neither type has a `From`
+// implementation, they aren't accepted anyway.
+#[derive(Clone, Copy)]
+pub enum IntSuffix {
+    None,
+    Signed(u32),
+    Unsigned(u32),
+    Size,
+}
+
+// The value of a float literal as `digits * 10^exp`. `digits` has neither leading nor
+// trailing zeros (unless it's zero itself), and `count` is the number of digits in it,
+// which is the number of significant digits in the literal.
+#[derive(Clone, Copy)]
+pub struct Decimal {
+    pub negative: bool,
+    pub digits: u128,
+    pub count: i32,
+    pub exp: i32,
+    pub single: bool,
+}
+
+// The most significant digits that fit into `Decimal::digits`. A literal with more is
+// always rejected as lossy; rounding it to an `f64` that's exactly equal to it is
+// possible, but it's not something anybody writes.
+const MAX_DIGITS: i32 = 38;
+
+/// Picks a literal apart, given its text as produced by `stringify!`.
+pub const fn lex(text: &str) -> Result<Literal<'_>, LiteralError> {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    let negative = !bytes.is_empty() && bytes[0] == b'-';
+    if negative {
+        i += 1;
+        // `stringify!` keeps any whitespace that was between the sign and the number
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+    }
+    if i == bytes.len() {
+        return Err(LiteralError::Invalid("expected a literal"));
+    }
+    match bytes[i] {
+        b'"' | b'r' => lex_str(text, i),
+        b'0'..=b'9' => {
+            if i + 1 < bytes.len() && bytes[i] == b'0' {
+                let radix = match bytes[i + 1] {
+                    b'x' => 16,
+                    b'o' => 8,
+                    b'b' => 2,
+                    _ => 10,
+                };
+                if radix != 10 {
+                    return lex_int(bytes, i + 2, radix, negative);
+                }
+            }
+            lex_decimal(bytes, i, negative)
+        }
+        _ => Err(LiteralError::Invalid(
+            "only number and string literals can be converted to numbers",
+        )),
+    }
+}
+
+// Lexes a string literal or a raw string literal, starting at byte `i`.
+const fn lex_str(text: &str, i: usize) -> Result<Literal<'_>, LiteralError> {
+    let bytes = text.as_bytes();
+    let mut start = i;
+    let mut hashes = 0;
+    let raw = bytes[i] == b'r';
+    if raw {
+        start += 1;
+        while bytes[start] == b'#' {
+            start += 1;
+            hashes += 1;
+        }
+    }
+    if i > 0 {
+        return Err(LiteralError::Invalid("a string literal can't be negated"));
+    }
+    // Skip the opening quote, and leave off the closing one and any hashes after it
+    let start = start + 1;
+    let end = bytes.len() - 1 - hashes;
+    if !raw {
+        let mut j = start;
+        while j < end {
+            if bytes[j] == b'\\' {
+                return Err(LiteralError::Invalid(
+                    "escape sequences in string literals aren't supported here",
+                ));
+            }
+            j += 1;
+        }
+    }
+    let (_, s) = text.split_at(start);
+    let (s, _) = s.split_at(end - start);
+    Ok(Literal::Str(s))
+}
+
+// Lexes the digits of a binary, octal, or hexadecimal integer literal, starting at byte
+// `i` (just past the prefix), along with its suffix.
+const fn lex_int(
+    bytes: &[u8],
+    i: usize,
+    radix: u32,
+    negative: bool,
+) -> Result<Literal<'_>, LiteralError> {
+    let mut i = i;
+    let mut value: u128 = 0;
+    while i < bytes.len() {
+        let d = match bytes[i] {
+            b'_' => {
+                i += 1;
+                continue;
+            }
+            b @ b'0'..=b'9' => (b - b'0') as u32,
+            b @ b'a'..=b'f' if radix == 16 => (b - b'a') as u32 + 10,
+            b @ b'A'..=b'F' if radix == 16 => (b - b'A') as u32 + 10,
+            _ => break,
+        };
+        if d >= radix {
+            return Err(LiteralError::Invalid("invalid digit in an integer literal"));
+        }
+        value = match value.checked_mul(radix as u128) {
+            Some(v) => match v.checked_add(d as u128) {
+                Some(v) => v,
+                None => return Err(LiteralError::Invalid("integer literal is too large")),
+            },
+            None => return Err(LiteralError::Invalid("integer literal is too large")),
+        };
+        i += 1;
+    }
+    match int_suffix(bytes, i) {
+        Some(suffix) => Ok(Literal::Int {
+            negative,
+            value,
+            suffix,
+        }),
+        None => Err(LiteralError::Invalid(
+            "unrecognized suffix on an integer literal",
+        )),
+    }
+}
+
+// Lexes a decimal literal, which may be either an integer or a float, starting at byte `i`.
+const fn lex_decimal(bytes: &[u8], i: usize, negative: bool) -> Result<Literal<'_>, LiteralError> {
+    let mut i = i;
+    let mut digits: u128 = 0;
+    let mut count = 0;
+    // Zeros that have been seen but not yet added to `digits`, since they might turn out to
+    // be trailing zeros
+    let mut zeros = 0;
+    let mut fraction = 0;
+    let mut in_fraction = false;
+    let mut is_float = false;
+    let mut too_long = false;
+    // The integer value, for when this turns out to be an integer literal
+    let mut int_value: u128 = 0;
+    let mut int_overflow = false;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'_' => {}
+            // A point is part of the literal only if it isn't followed by something that
+            // would make it a method call or a range
+            b'.' if !in_fraction && (i + 1 == bytes.len() || bytes[i + 1].is_ascii_digit()) => {
+                in_fraction = true;
+                is_float = true;
+            }
+            b @ b'0'..=b'9' => {
+                let d = (b - b'0') as u128;
+                if in_fraction {
+                    fraction += 1;
+                } else {
+                    int_value = match int_value.checked_mul(10) {
+                        Some(v) => match v.checked_add(d) {
+                            Some(v) => v,
+                            None => {
+                                int_overflow = true;
+                                0
+                            }
+                        },
+                        None => {
+                            int_overflow = true;
+                            0
+                        }
+                    };
+                }
+                if d == 0 {
+                    if count > 0 {
+                        zeros += 1;
+                    }
+                } else if count + zeros + 1 > MAX_DIGITS {
+                    too_long = true;
+                } else {
+                    let mut k = 0;
+                    while k <= zeros {
+                        digits *= 10;
+                        k += 1;
+                    }
+                    digits += d;
+                    count += zeros + 1;
+                    zeros = 0;
+                }
+            }
+            _ => break,
+        }
+        i += 1;
+    }
+
+    let mut exp: i64 = 0;
+    if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+        is_float = true;
+        i += 1;
+        let mut exp_negative = false;
+        if i < bytes.len() && (bytes[i] == b'+' || bytes[i] == b'-') {
+            exp_negative = bytes[i] == b'-';
+            i += 1;
+        }
+        let mut exp_digits = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'_' => {}
+                b @ b'0'..=b'9' => {
+                    // Past this, the number is out of range (or zero) no matter what
+                    if exp < 1_000_000_000 {
+                        exp = exp * 10 + (b - b'0') as i64;
+                    }
+                    exp_digits += 1;
+                }
+                _ => break,
+            }
+            i += 1;
+        }
+        if exp_digits == 0 {
+            return Err(LiteralError::Invalid(
+                "expected at least one digit in exponent",
+            ));
+        }
+        if exp_negative {
+            exp = -exp;
+        }
+    }
+
+    // An integer with a float suffix, like `1f32`, is a float
+    let single = match float_suffix(bytes, i) {
+        Some(single) if is_float || i < bytes.len() => single,
+        _ if is_float => {
+            return Err(LiteralError::Invalid(
+                "unrecognized suffix on a float literal",
+            ));
+        }
+        _ => {
+            return match int_suffix(bytes, i) {
+                Some(_) if int_overflow => {
+                    Err(LiteralError::Invalid("integer literal is too large"))
+                }
+                Some(suffix) => Ok(Literal::Int {
+                    negative,
+                    value: int_value,
+                    suffix,
+                }),
+                None => Err(LiteralError::Invalid(
+                    "unrecognized suffix on an integer literal",
+                )),
+            };
+        }
+    };
+
+    if too_long {
+        return Err(LiteralError::Lossy);
+    }
+    let exp = exp - fraction as i64 + zeros as i64;
+    let exp = if exp > i32::MAX as i64 {
+        i32::MAX
+    } else if exp < i32::MIN as i64 {
+        i32::MIN
+    } else {
+        exp as i32
+    };
+    Ok(Literal::Float(Decimal {
+        negative,
+        digits,
+        count,
+        exp,
+        single,
+    }))
+}
+
+// Reads the suffix of an integer literal, which starts at byte `i` and runs to the end.
+const fn int_suffix(bytes: &[u8], i: usize) -> Option<IntSuffix> {
+    let (_, suffix) = bytes.split_at(i);
+    let (signed, width) = match suffix {
+        [] => return Some(IntSuffix::None),
+        [b'i', width @ ..] => (true, width),
+        [b'u', width @ ..] => (false, width),
+        _ => return None,
+    };
+    let bits = match width {
+        b"8" => 8,
+        b"16" => 16,
+        b"32" => 32,
+        b"64" => 64,
+        b"128" => 128,
+        b"size" => return Some(IntSuffix::Size),
+        _ => return None,
+    };
+    Some(if signed {
+        IntSuffix::Signed(bits)
+    } else {
+        IntSuffix::Unsigned(bits)
+    })
+}
+
+// Reads the suffix of a float literal, which starts at byte `i` and runs to the end.
+// Returns whether it's `f32`, or `None` if it isn't a float suffix at all. No suffix is the
+// same as `f64`.
+const fn float_suffix(bytes: &[u8], i: usize) -> Option<bool> {
+    let (_, suffix) = bytes.split_at(i);
+    match suffix {
+        [] | b"f64" => Some(false),
+        b"f32" => Some(true),
+        _ => None,
+    }
+}
+
+/// Checks that an integer literal fits into the type named by its suffix.
+pub const fn check_int(negative: bool, value: u128, suffix: IntSuffix) -> Result<(), LiteralError> {
+    let max = match suffix {
+        IntSuffix::Signed(bits) => (1u128 << (bits - 1)) - 1 + negative as u128,
+        IntSuffix::Unsigned(_) if negative => {
+            return Err(LiteralError::Invalid(
+                "an unsigned integer can't be negative",
+            ));
+        }
+        IntSuffix::Unsigned(128) => u128::MAX,
+        IntSuffix::Unsigned(bits) => (1u128 << bits) - 1,
+        IntSuffix::None | IntSuffix::Size => u128::MAX,
+    };
+    if value > max {
+        Err(LiteralError::Invalid(
+            "integer literal is out of range for its type",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// How a float literal should be converted.
+pub enum Conversion {
+    /// Into the given `f64` with nothing else added to it, because the literal is exactly
+    /// equal to it.
+    Exact(f64),
+    /// By parsing the digits of the literal.
+    Digits,
+}
+
+/// Decides how a float literal has to be converted to give the number that its digits spell
+/// out, or whether it has too many digits to be converted at all. `approx` must be within a
+/// few ULPs of the magnitude of the literal; the parsed literal is good enough.
+pub const fn convert(dec: &Decimal, approx: f64) -> Result<Conversion, LiteralError> {
+    let format = if dec.single { F32 } else { F64 };
+    if dec.digits == 0 {
+        return Ok(Conversion::Exact(if dec.negative { -0.0 } else { 0.0 }));
+    }
+
+    // The decimal exponent of the leading digit. Past these bounds the literal is out of
+    // range for either type or so small that it rounds to zero.
+    let lead = dec.exp as i64 + dec.count as i64 - 1;
+    if lead > format.max_exp10 {
+        return Err(LiteralError::Invalid(if dec.single {
+            "literal out of range for `f32`"
+        } else {
+            "literal out of range for `f64`"
+        }));
+    }
+    if lead < format.min_exp10 {
+        return Err(LiteralError::Lossy);
+    }
+
+    let approx = if dec.single {
+        (approx as f32).to_bits() as u64
+    } else {
+        approx.to_bits()
+    };
+    let bits = nearest(dec, approx, format);
+    if bits > format.max_bits() {
+        return Err(LiteralError::Invalid(if dec.single {
+            "literal out of range for `f32`"
+        } else {
+            "literal out of range for `f64`"
+        }));
+    }
+    let (m, q) = format.parts(bits);
+    let x = if dec.single {
+        f32::from_bits(bits as u32) as f64
+    } else {
+        f64::from_bits(bits)
+    };
+    let x = if dec.negative { -x } else { x };
+
+    if compare(dec, m, q) == 0 {
+        Ok(Conversion::Exact(x))
+    } else if recoverable(dec, bits, format) {
+        Ok(Conversion::Digits)
+    } else {
+        Err(LiteralError::Lossy)
+    }
+}
+
+// Whether the digits of a literal can be read back out of the float `bits` that it rounds
+// to, which is the case when they're the shortest digits that round to that float (and,
+// of those, the closest to it), as printing the float would give them.
+//
+// For a normal number that's so of every literal with no more digits than `digits_held`.
+// A subnormal has fewer bits, so that rule would turn down even `5e-324`, and it's checked
+// directly instead: no literal with one digit fewer can round to the same float, and the
+// literal has to be within half a unit in its last digit of that float.
+const fn recoverable(dec: &Decimal, bits: u64, format: Format) -> bool {
+    let (m, q) = format.parts(bits);
+    if m >> format.mantissa_bits != 0 {
+        return dec.count <= digits_held(m);
+    }
+    if m == 0 {
+        return false;
+    }
+
+    // The literals with one digit fewer that are closest to this one, on either side of it.
+    // Any shorter literal that rounds to the float would make one of these round to it too.
+    // Each is checked against the bounds of the floats that round to this one, which are
+    // half a unit in its last place on either side of it, with ties going to even.
+    let below = dec.digits / 10;
+    let shorter = [below, below + 1];
+    let mut i = 0;
+    while i < shorter.len() {
+        let d = Decimal {
+            digits: shorter[i],
+            exp: dec.exp + 1,
+            ..*dec
+        };
+        let lower = compare(&d, 2 * m - 1, q - 1);
+        let upper = compare(&d, 2 * m + 1, q - 1);
+        let tie = m % 2 == 0;
+        if shorter[i] != 0 && (lower > 0 || lower == 0 && tie) && (upper < 0 || upper == 0 && tie) {
+            return false;
+        }
+        i += 1;
+    }
+
+    // The literal, less and plus half a unit in its last digit, on either side of the float
+    let low = Decimal {
+        digits: 2 * dec.digits - 1,
+        ..*dec
+    };
+    let high = Decimal {
+        digits: 2 * dec.digits + 1,
+        ..*dec
+    };
+    compare(&low, 2 * m, q) <= 0 && compare(&high, 2 * m, q) >= 0
+}
+
+// The number of decimal digits that survive a round trip through a normal float with the
+// significand `m`. Every number with that many significant digits rounds to a different
+// float, so the shortest digits that round to a float are the digits it was rounded from.
+// This is 15 for an `f64` and 6 for an `f32`.
+const fn digits_held(m: u64) -> i32 {
+    let bits = 64 - m.leading_zeros() as i64;
+    // log10(2) is 0.30103 to more places than this needs
+    ((bits - 1) * 30103 / 100_000) as i32
+}
+
+// The layout of a binary floating-point type.
+#[derive(Clone, Copy)]
+struct Format {
+    mantissa_bits: u32,
+    exponent_bits: u32,
+    // Bounds on the decimal exponent of the leading digit of a finite, nonzero number
+    max_exp10: i64,
+    min_exp10: i64,
+}
+
+const F64: Format = Format {
+    mantissa_bits: 52,
+    exponent_bits: 11,
+    max_exp10: 308,
+    min_exp10: -324,
+};
+
+const F32: Format = Format {
+    mantissa_bits: 23,
+    exponent_bits: 8,
+    max_exp10: 38,
+    min_exp10: -46,
+};
+
+impl Format {
+    // The bits of the largest finite number.
+    const fn max_bits(self) -> u64 {
+        let max_exp = (1u64 << self.exponent_bits) - 2;
+        (max_exp << self.mantissa_bits) | ((1u64 << self.mantissa_bits) - 1)
+    }
+
+    // Splits the bits of a finite, non-negative number into an integer significand `m` and
+    // an exponent `q` with the number equal to m * 2^q.
+    const fn parts(self, bits: u64) -> (u64, i32) {
+        let biased = (bits >> self.mantissa_bits) as i32;
+        let fraction = bits & ((1u64 << self.mantissa_bits) - 1);
+        let bias = (1 << (self.exponent_bits - 1)) - 1;
+        let min_q = 1 - bias - self.mantissa_bits as i32;
+        if biased == 0 {
+            (fraction, min_q)
+        } else {
+            (fraction | (1u64 << self.mantissa_bits), biased - 1 + min_q)
+        }
+    }
+}
+
+// Finds the bits of the float nearest to a literal, starting from `approx`, the bits of a
+// float within a few ULPs of it. The result is one past the largest finite number if the
+// literal rounds to infinity.
+//
+// Each step compares the literal with the point halfway between a float and the one after
+// it, exactly, so this is correctly rounded (with ties going to an even significand) no
+// matter how close the literal is to one of those halfway points.
+const fn nearest(dec: &Decimal, approx: u64, format: Format) -> u64 {
+    let max = format.max_bits();
+    let mut x = if approx > max { max } else { approx };
+    while x > 0 {
+        let (m, q) = format.parts(x - 1);
+        let c = compare(dec, 2 * m + 1, q - 1);
+        if c < 0 || (c == 0 && m % 2 == 0) {
+            x -= 1;
+        } else {
+            break;
+        }
+    }
+    loop {
+        let (m, q) = format.parts(x);
+        let c = compare(dec, 2 * m + 1, q - 1);
+        if c > 0 || (c == 0 && m % 2 == 1) {
+            x += 1;
+            if x > max {
+                return x;
+            }
+        } else {
+            return x;
+        }
+    }
+}
+
+// Compares the magnitude of a literal with m * 2^q, returning -1, 0, or 1 as the literal is
+// less than, equal to, or greater than it. Both sides are scaled by whatever powers of 2
+// and 10 it takes to make them integers.
+const fn compare(dec: &Decimal, m: u64, q: i32) -> i32 {
+    let mut lhs = Big::from_u128(dec.digits);
+    let mut rhs = Big::from_u128(m as u128);
+    if dec.exp > 0 {
+        lhs.mul_pow10(dec.exp as u32);
+    } else {
+        rhs.mul_pow10(dec.exp.unsigned_abs());
+    }
+    if q > 0 {
+        rhs.shl(q as u32);
+    } else {
+        lhs.shl(q.unsigned_abs());
+    }
+    lhs.cmp(&rhs)
+}
+
+// A fixed-size unsigned integer, little-endian, big enough for both sides of `compare`: at
+// most 38 digits times 10^308 and a little over 2^1075, or 55 bits times 10^361 and 2^1023.
+const LIMBS: usize = 40;
+
+struct Big {
+    limbs: [u64; LIMBS],
+    len: usize,
+}
+
+impl Big {
+    const fn from_u128(n: u128) -> Big {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = n as u64;
+        limbs[1] = (n >> 64) as u64;
+        Big { limbs, len: 2 }
+    }
+
+    const fn mul_small(&mut self, k: u64) {
+        let mut carry = 0u128;
+        let mut i = 0;
+        while i < self.len {
+            let p = self.limbs[i] as u128 * k as u128 + carry;
+            self.limbs[i] = p as u64;
+            carry = p >> 64;
+            i += 1;
+        }
+        if carry > 0 {
+            self.limbs[self.len] = carry as u64;
+            self.len += 1;
+        }
+    }
+
+    const fn mul_pow10(&mut self, n: u32) {
+        let mut n = n;
+        while n >= 19 {
+            self.mul_small(10_000_000_000_000_000_000);
+            n -= 19;
+        }
+        self.mul_small(10u64.pow(n));
+    }
+
+    const fn shl(&mut self, n: u32) {
+        let words = (n / 64) as usize;
+        let bits = n % 64;
+        let mut i = self.len + words + 1;
+        while i > 0 {
+            i -= 1;
+            let hi = if i >= words && i - words < self.len {
+                self.limbs[i - words]
+            } else {
+                0
+            };
+            let lo = if bits > 0 && i > words && i - words - 1 < self.len {
+                self.limbs[i - words - 1] >> (64 - bits)
+            } else {
+                0
+            };
+            self.limbs[i] = (hi << bits) | lo;
+        }
+        self.len += words + 1;
+    }
+
+    const fn cmp(&self, other: &Big) -> i32 {
+        let mut i = if self.len > other.len {
+            self.len
+        } else {
+            other.len
+        };
+        while i > 0 {
+            i -= 1;
+            let a = if i < self.len { self.limbs[i] } else { 0 };
+            let b = if i < other.len { other.limbs[i] } else { 0 };
+            if a != b {
+                return if a < b { -1 } else { 1 };
+            }
+        }
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::random::Rng;
+
+    fn dec(s: &str) -> Decimal {
+        match lex(s) {
+            Ok(Literal::Float(d)) => d,
+            _ => panic!("not a float literal: {}", s),
+        }
+    }
+
+    fn to_f64(s: &str) -> Result<f64, LiteralError> {
+        let d = dec(s);
+        let approx: f64 = s.trim_end_matches("f64").replace('_', "").parse().unwrap();
+        match convert(&d, approx.abs())? {
+            Conversion::Exact(x) => Ok(x),
+            Conversion::Digits => Ok(f64::NAN),
+        }
+    }
+
+    #[test]
+    fn lex_decimal() {
+        let d = dec("-0012.3400e-2_f64");
+        assert!(d.negative && d.digits == 1234 && d.count == 4 && d.exp == -4 && !d.single);
+        let d = dec("1_000.");
+        assert!(d.digits == 1 && d.count == 1 && d.exp == 3);
+        let d = dec("- 2.5e-3_f32");
+        assert!(d.negative && d.digits == 25 && d.exp == -4 && d.single);
+        let d = dec("0.0");
+        assert!(d.digits == 0 && d.count == 0);
+        assert!(matches!(
+            lex("1.000000000000000000000000000000000000001"),
+            Err(LiteralError::Lossy)
+        ));
+    }
+
+    #[test]
+    fn lex_int() {
+        assert!(matches!(
+            lex("0x_ff_u8"),
+            Ok(Literal::Int {
+                negative: false,
+                value: 255,
+                suffix: IntSuffix::Unsigned(8)
+            })
+        ));
+        assert!(matches!(
+            lex("-0b1010"),
+            Ok(Literal::Int {
+                negative: true,
+                value: 10,
+                suffix: IntSuffix::None
+            })
+        ));
+        assert!(matches!(
+            lex("0o17usize"),
+            Ok(Literal::Int {
+                value: 15,
+                suffix: IntSuffix::Size,
+                ..
+            })
+        ));
+        assert!(matches!(
+            lex("12i128"),
+            Ok(Literal::Int {
+                value: 12,
+                suffix: IntSuffix::Signed(128),
+                ..
+            })
+        ));
+        assert!(matches!(
+            lex("0x1f32"),
+            Ok(Literal::Int {
+                value: 0x1f32,
+                suffix: IntSuffix::None,
+                ..
+            })
+        ));
+        assert!(matches!(
+            lex("1f32"),
+            Ok(Literal::Float(Decimal { single: true, .. }))
+        ));
+        assert!(matches!(lex("0o18"), Err(LiteralError::Invalid(_))));
+        assert!(matches!(lex("1u7"), Err(LiteralError::Invalid(_))));
+        assert!(matches!(
+            lex("340282366920938463463374607431768211456"),
+            Err(LiteralError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn lex_str() {
+        assert!(matches!(lex("\"1.5\""), Ok(Literal::Str("1.5"))));
+        assert!(matches!(lex("r#\"1.5\"#"), Ok(Literal::Str("1.5"))));
+        assert!(matches!(lex("r\"1\\5\""), Ok(Literal::Str("1\\5"))));
+        assert!(matches!(lex("\"\\u{31}\""), Err(LiteralError::Invalid(_))));
+        assert!(matches!(lex("b\"1\""), Err(LiteralError::Invalid(_))));
+        assert!(matches!(lex("'1'"), Err(LiteralError::Invalid(_))));
+        assert!(matches!(lex("true"), Err(LiteralError::Invalid(_))));
+    }
+
+    #[test]
+    fn convert_exact() {
+        assert!(to_f64("0.5") == Ok(0.5));
+        assert!(to_f64("-1.25e2") == Ok(-125.0));
+        assert!(to_f64("1e22") == Ok(1e22));
+        // Whole numbers, but not the ones that their f64s are
+        assert!(to_f64("1e23").unwrap().is_nan());
+        assert!(to_f64("3e200").unwrap().is_nan());
+        assert!(to_f64("1e300").unwrap().is_nan());
+        assert!(to_f64("1_234_567_890_123_456.0") == Ok(1_234_567_890_123_456.0));
+        assert!(to_f64("1.0005645751953125") == Ok(1.0005645751953125));
+        assert!(to_f64("9007199254740993.0") == Err(LiteralError::Lossy));
+        assert!(to_f64("0.1").unwrap().is_nan());
+        assert!(to_f64("0.100000000000000005551115123125783").is_err());
+        assert!(to_f64("1e-400") == Err(LiteralError::Lossy));
+        assert!(matches!(to_f64("2e308"), Err(LiteralError::Invalid(_))));
+        assert!(to_f64("-0.0").unwrap().is_sign_negative());
+    }
+
+    #[test]
+    fn convert_subnormal() {
+        // The shortest digits of subnormals, which are what printing them gives
+        assert!(to_f64("5e-324").unwrap().is_nan());
+        assert!(to_f64("-1e-323").unwrap().is_nan());
+        assert!(to_f64("2.2250738585072e-308").unwrap().is_nan());
+        assert!(matches!(
+            convert(&dec("1e-45f32"), 1e-45),
+            Ok(Conversion::Digits)
+        ));
+        // Digits that round to the same subnormal as shorter or closer ones do
+        assert!(to_f64("3e-324") == Err(LiteralError::Lossy));
+        assert!(to_f64("4.9e-324") == Err(LiteralError::Lossy));
+        assert!(to_f64("1.4e-323") == Err(LiteralError::Lossy));
+        // Rounds to zero
+        assert!(to_f64("2e-324") == Err(LiteralError::Lossy));
+
+        let mut rng = Rng::new(0x0123_4567_89ab_cdef);
+        for _ in 0..2_000 {
+            let bits = rng.next_u64() >> (12 + rng.next_u64() % 52);
+            let x = f64::from_bits(bits.max(1));
+            let s = format!("{:e}", x);
+            assert!(to_f64(&s).is_ok(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn nearest_ties() {
+        // 2^53 + 1 is halfway between two f64s, and goes to the even one
+        let d = dec("9007199254740993.0");
+        assert!(nearest(&d, 0x4340_0000_0000_0000, F64) == 9007199254740992f64.to_bits());
+        let d = dec("9007199254740995.0");
+        assert!(nearest(&d, 0x4340_0000_0000_0000, F64) == 9007199254740996f64.to_bits());
+        // Either side of halfway between the largest finite f64 and the next power of 2
+        let d = dec("1.7976931348623158e308");
+        assert!(nearest(&d, 0x7fef_ffff_ffff_ffff, F64) == f64::MAX.to_bits());
+        let d = dec("1.7976931348623159e308");
+        assert!(nearest(&d, 0x7fef_ffff_ffff_ffff, F64) == f64::INFINITY.to_bits());
+    }
+
+    #[test]
+    fn nearest_random() {
+        let mut rng = Rng::new(0x0123_4567_89ab_cdef);
+        for _ in 0..20_000 {
+            let n = rng.next_u64();
+            let digits = 1 + (n % 25) as usize;
+            let mut s: String = (0..digits)
+                .map(|_| (b'0' + (rng.next_u64() % 10) as u8) as char)
+                .collect();
+            s.insert(0, '1');
+            let exp = rng.range(-340, 320);
+            let s = format!("{}e{}", s, exp);
+            let expected: f64 = s.parse().unwrap();
+            let d = dec(&s);
+            // Start a few ULPs away to exercise both directions
+            let start = expected.to_bits().saturating_sub(3) + rng.next_u64() % 7;
+            assert!(nearest(&d, start, F64) == expected.to_bits(), "{}", s);
+            let expected32: f32 = s.parse().unwrap();
+            if expected32.is_finite() {
+                let d = dec(&format!("{}f32", s));
+                let start = (expected32.to_bits() as u64).saturating_sub(2) + rng.next_u64() % 5;
+                assert!(
+                    nearest(&d, start, F32) == expected32.to_bits() as u64,
+                    "{}f32",
+                    s
+                );
+            }
+        }
+    }
+}
@@ -54,7 +54,7 @@ const SPLIT_SHIFT_UP: f64 = 268435456.0; // = 2^28
 /// [`two_sum`](#fn.two_sum) but carries the restriction that |a| >= |b|, which
 /// [`two_sum`](#fn.two_sum) does not.
 #[inline]
-pub fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+pub const fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
     let s = a + b;
     let e = b - (s - a);
     (s, e)
@@ -72,7 +72,7 @@ pub fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
 /// This calculation performs 6 floating-point operations. It is less efficient than
 /// [`quick_two_sum`](#fn.quick_two_sum) but it carries no restrictions on its input values.
 #[inline]
-pub fn two_sum(a: f64, b: f64) -> (f64, f64) {
+pub const fn two_sum(a: f64, b: f64) -> (f64, f64) {
     let s = a + b;
     let v = s - a;
     let e = (a - (s - v)) + (b - v);
@@ -82,7 +82,7 @@ pub fn two_sum(a: f64, b: f64) -> (f64, f64) {
 /// Calculates fl(a - b) and err(a - b).
 ///
 /// This calculation performs 6 floating-point operations.
-pub fn two_diff(a: f64, b: f64) -> (f64, f64) {
+pub const fn two_diff(a: f64, b: f64) -> (f64, f64) {
     let s = a - b;
     let v = s - a;
     let e = (a - (s - v)) - (b + v);
@@ -98,7 +98,7 @@ pub fn two_diff(a: f64, b: f64) -> (f64, f64) {
 /// floating-point operations to increase precision in the large number.
 #[cfg(no_fma)]
 #[inline]
-fn split(a: f64) -> (f64, f64) {
+const fn split(a: f64) -> (f64, f64) {
     if a > SPLIT_THRESHOLD || a < -SPLIT_THRESHOLD {
         let s = a * SPLIT_SHIFT_DOWN;
         let t = SPLIT_FACTOR * s;
@@ -120,7 +120,7 @@ fn split(a: f64) -> (f64, f64) {
 /// more operations.
 #[cfg(not(no_fma))]
 #[inline]
-pub fn two_prod(a: f64, b: f64) -> (f64, f64) {
+pub const fn two_prod(a: f64, b: f64) -> (f64, f64) {
     let p = a * b;
     let e = a.mul_add(b, -p);
     (p, e)
@@ -133,7 +133,7 @@ pub fn two_prod(a: f64, b: f64) -> (f64, f64) {
 /// many as 23 floating-point operations.
 #[cfg(no_fma)]
 #[inline]
-pub fn two_prod(a: f64, b: f64) -> (f64, f64) {
+pub const fn two_prod(a: f64, b: f64) -> (f64, f64) {
     let p = a * b;
     let (ahi, alo) = split(a);
     let (bhi, blo) = split(b);
@@ -148,7 +148,7 @@ pub fn two_prod(a: f64, b: f64) -> (f64, f64) {
 /// enabled, it will use considerably more operations.
 #[cfg(not(no_fma))]
 #[inline]
-pub fn two_sqr(a: f64) -> (f64, f64) {
+pub const fn two_sqr(a: f64) -> (f64, f64) {
     let p = a * a;
     let e = a.mul_add(a, -p);
     (p, e)
@@ -162,7 +162,7 @@ pub fn two_sqr(a: f64) -> (f64, f64) {
 /// be 15.
 #[cfg(no_fma)]
 #[inline]
-pub fn two_sqr(a: f64) -> (f64, f64) {
+pub const fn two_sqr(a: f64) -> (f64, f64) {
     let p = a * a;
     let (hi, lo) = split(a);
     let e = hi * hi - p + 2.0 * hi * lo + lo * lo;
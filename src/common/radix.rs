@@ -0,0 +1,462 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::bigint::Big;
+use crate::common::hex::make_f64;
+use crate::error::ErrorKind;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter, Result};
+
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// The digits of a [`Double`] or a [`Quad`] in an arbitrary radix, as produced by their
+/// `to_string_radix_any` methods.
+///
+/// The number is held as a string of significant digits along with the power of the radix
+/// of the first of them, so that the digits `"1201"` with an exponent of -2 are the number
+/// 0.01201 in that radix. There are no leading zeros and no trailing zeros, except that zero
+/// itself is the single digit `"0"` with an exponent of 0.
+///
+/// Displaying a `RadixString` writes the number positionally, with a decimal point (well, a
+/// radix point) and as many zeros as it takes to get the digits to the right place. If the
+/// digits aren't the complete expansion of the number, `...` is added to the end.
+///
+/// # Examples
+/// ```
+/// # use qd::{qd, Quad};
+/// let r = qd!(0.375).to_string_radix_any(2, 100);
+/// assert!(r.digits() == "11");
+/// assert!(r.exponent() == -2);
+/// assert!(r.is_exact());
+/// assert!(r.to_string() == "0.011");
+/// ```
+///
+/// [`Double`]: struct.Double.html
+/// [`Quad`]: struct.Quad.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RadixString {
+    negative: bool,
+    finite: bool,
+    digits: String,
+    exponent: i32,
+    exact: bool,
+}
+
+impl RadixString {
+    /// Returns the significant digits, using the letters `a` through `z` for digits past 9
+    /// (or `A` through `Z` after [`to_uppercase`]). The digits of an infinity are `inf` and
+    /// those of a NaN are `NaN`.
+    ///
+    /// [`to_uppercase`]: #method.to_uppercase
+    pub fn digits(&self) -> &str {
+        &self.digits
+    }
+
+    /// Returns the power of the radix that the first digit is multiplied by. This is 0 for
+    /// zero, infinity, and NaN.
+    pub fn exponent(&self) -> i32 {
+        self.exponent
+    }
+
+    /// Returns `true` if the digits are the complete expansion of the number, and `false`
+    /// if the expansion was cut off and rounded to fit into the maximum number of digits.
+    ///
+    /// The value of every finite `Double` and `Quad` is a fraction with a power of two in
+    /// its denominator, so its expansion always terminates in an even radix, given enough
+    /// digits. In an odd radix, it only terminates if the number is an integer. Infinity
+    /// and NaN have no expansion at all, so this is `false` for them.
+    pub fn is_exact(&self) -> bool {
+        self.exact
+    }
+
+    /// Returns `true` if the number is negative, including negative zero.
+    pub fn is_sign_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns `true` if the number is neither infinite nor NaN.
+    pub fn is_finite(&self) -> bool {
+        self.finite
+    }
+
+    /// Returns the same number with its digits past 9 written as uppercase letters.
+    pub fn to_uppercase(&self) -> RadixString {
+        let mut r = self.clone();
+        if r.finite {
+            r.digits.make_ascii_uppercase();
+        }
+        r
+    }
+}
+
+impl Display for RadixString {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut s = String::with_capacity(self.digits.len() + 8);
+        if self.negative && (self.finite || self.digits != "NaN") {
+            s.push('-');
+        }
+        if !self.finite {
+            s.push_str(&self.digits);
+            return f.pad(&s);
+        }
+
+        let len = self.digits.len() as i64;
+        let exp = self.exponent as i64;
+        if exp < 0 {
+            s.push_str("0.");
+            for _ in 0..-exp - 1 {
+                s.push('0');
+            }
+            s.push_str(&self.digits);
+        } else if len <= exp + 1 {
+            s.push_str(&self.digits);
+            for _ in 0..exp + 1 - len {
+                s.push('0');
+            }
+        } else {
+            let (int, frac) = self.digits.split_at(exp as usize + 1);
+            s.push_str(int);
+            s.push('.');
+            s.push_str(frac);
+        }
+        if !self.exact {
+            s.push_str("...");
+        }
+        f.pad(&s)
+    }
+}
+
+fn check_radix(radix: u32) {
+    assert!(
+        (2..=36).contains(&radix),
+        "radix must be between 2 and 36, not {}",
+        radix
+    );
+}
+
+// Returns the exact value of the components as a sign and an integer m and shift s such
+// that the magnitude is m / 2^s. The components must all be finite.
+fn exact_value(components: &[f64]) -> (bool, Big, u64) {
+    // Each component is an integer mantissa times a power of two, and the smallest of
+    // those powers is where the integer has to end
+    let parts: Vec<(bool, u64, i64)> = components
+        .iter()
+        .filter(|&&x| x != 0.0)
+        .map(|&x| {
+            let bits = x.to_bits();
+            let biased = ((bits >> 52) & 0x7ff) as i64;
+            let frac = bits & ((1 << 52) - 1);
+            if biased == 0 {
+                (x < 0.0, frac, -1074)
+            } else {
+                (x < 0.0, frac | 1 << 52, biased - 1075)
+            }
+        })
+        .collect();
+    let low = parts.iter().map(|p| p.2).min().unwrap_or(0);
+
+    let mut pos = Big::default();
+    let mut neg = Big::default();
+    for &(negative, mant, exp) in &parts {
+        let term = Big::from_u64(mant).shl((exp - low) as u64);
+        if negative {
+            neg = neg.add(&term);
+        } else {
+            pos = pos.add(&term);
+        }
+    }
+    let (negative, m) = match pos.cmp(&neg) {
+        Ordering::Less => (true, neg.sub(&pos)),
+        Ordering::Greater => (false, pos.sub(&neg)),
+        Ordering::Equal => (components[0].is_sign_negative(), Big::default()),
+    };
+    if low >= 0 {
+        (negative, m.shl(low as u64), 0)
+    } else {
+        (negative, m, -low as u64)
+    }
+}
+
+// Compares the fraction f / 2^s, which must be less than 1, to 1/2.
+fn cmp_half(f: &Big, s: u64) -> Ordering {
+    if s == 0 || !f.bit(s - 1) {
+        Ordering::Less
+    } else if f.any_below(s - 1) {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+// Writes the exact value of the components in the given radix, rounded to no more than
+// `max_digits` significant digits.
+//
+// The integer part is converted by repeated division by the radix, and the fraction by
+// repeated multiplication, both with exact integers. If the digits run out before the
+// number does, what's left is compared exactly against half of a unit in the last place
+// kept, and the digits are rounded to nearest, with ties going to an even last digit.
+pub fn to_radix(components: &[f64], radix: u32, max_digits: usize) -> RadixString {
+    check_radix(radix);
+    assert!(max_digits > 0, "max_digits must be at least 1");
+
+    let first = components[0];
+    if components.iter().any(|x| !x.is_finite()) {
+        let nan = first.is_nan() || components[1..].iter().any(|x| !x.is_finite());
+        return RadixString {
+            negative: first.is_sign_negative(),
+            finite: false,
+            digits: String::from(if nan { "NaN" } else { "inf" }),
+            exponent: 0,
+            exact: false,
+        };
+    }
+
+    let (negative, m, s) = exact_value(components);
+    if m.is_zero() {
+        return RadixString {
+            negative,
+            finite: true,
+            digits: String::from("0"),
+            exponent: 0,
+            exact: true,
+        };
+    }
+
+    // The digits come out as many at a time as fit into a `u32`, rather than one at a
+    // time, which saves most of the work on the big integers.
+    let (power, size) = chunk(radix);
+    let mut int = m.shr(s);
+    let mut frac = m.low_bits(s);
+    let mut digits = Vec::new();
+    while !int.is_zero() {
+        // Every chunk but the one on top has all of its digits, including leading zeros
+        let mut r = int.div_small(power);
+        let count = if int.is_zero() { 0 } else { size };
+        let mut n = 0;
+        while r > 0 || n < count {
+            digits.push((r % radix) as u8);
+            r /= radix;
+            n += 1;
+        }
+    }
+    digits.reverse();
+
+    let mut exponent = digits.len() as i64 - 1;
+    let (order, exact) = if digits.len() > max_digits {
+        // The digits ran out in the integer part. The rest of it, r, is compared to half of
+        // the place value of the last digit kept, u, along with the fraction: the question
+        // is how 2r + 2f/2^s compares to u, where 2f/2^s is less than 2.
+        let mut rest = Big::default();
+        let mut unit = Big::from_u64(1);
+        for &d in &digits[max_digits..] {
+            rest.mul_small(radix);
+            rest.add_small(d as u32);
+            unit.mul_small(radix);
+        }
+        digits.truncate(max_digits);
+        let exact = rest.is_zero() && frac.is_zero();
+        let twice = rest.add(&rest);
+        let order = match twice.cmp(&unit) {
+            Ordering::Greater => Ordering::Greater,
+            Ordering::Equal if frac.is_zero() => Ordering::Equal,
+            Ordering::Equal => Ordering::Greater,
+            Ordering::Less => {
+                let mut next = twice;
+                next.add_small(1);
+                if next < unit {
+                    Ordering::Less
+                } else {
+                    cmp_half(&frac, s)
+                }
+            }
+        };
+        (order, exact)
+    } else {
+        while digits.len() < max_digits && !frac.is_zero() {
+            // Never more digits at once than are still wanted, so that the rounding below
+            // looks at exactly what's left after the last digit kept
+            let count = (max_digits - digits.len()).min(size);
+            let mut unit = radix.pow(count as u32 - 1);
+            frac.mul_small(unit * radix);
+            let n = frac.take_above(s) as u32;
+            while unit > 0 {
+                let d = (n / unit % radix) as u8;
+                unit /= radix;
+                if digits.is_empty() && d == 0 {
+                    // A leading zero of a number less than one
+                    exponent -= 1;
+                } else {
+                    digits.push(d);
+                }
+            }
+        }
+        (cmp_half(&frac, s), frac.is_zero())
+    };
+
+    let up = match order {
+        Ordering::Greater => true,
+        Ordering::Equal => digits[digits.len() - 1] % 2 == 1,
+        Ordering::Less => false,
+    };
+    if up {
+        let mut i = digits.len();
+        while i > 0 && digits[i - 1] as u32 == radix - 1 {
+            digits[i - 1] = 0;
+            i -= 1;
+        }
+        if i > 0 {
+            digits[i - 1] += 1;
+        } else {
+            digits[0] = 1;
+            exponent += 1;
+        }
+    }
+    while digits.len() > 1 && digits[digits.len() - 1] == 0 {
+        digits.pop();
+    }
+
+    RadixString {
+        negative,
+        finite: true,
+        digits: digits.iter().map(|&d| DIGITS[d as usize] as char).collect(),
+        exponent: exponent as i32,
+        exact,
+    }
+}
+
+// The largest power of the radix that fits into a `u32`, along with its exponent.
+fn chunk(radix: u32) -> (u32, usize) {
+    let mut power = radix;
+    let mut count = 1;
+    while let Some(p) = power.checked_mul(radix) {
+        power = p;
+        count += 1;
+    }
+    (power, count)
+}
+
+// Multiplies by radix^count.
+fn mul_pow(n: &mut Big, radix: u32, count: usize) {
+    let (power, size) = chunk(radix);
+    for _ in 0..count / size {
+        n.mul_small(power);
+    }
+    for _ in 0..count % size {
+        n.mul_small(radix);
+    }
+}
+
+// Divides by radix^count, returning `true` if there was any remainder.
+fn div_pow(n: &mut Big, radix: u32, count: usize) -> bool {
+    let (power, size) = chunk(radix);
+    let mut sticky = false;
+    for _ in 0..count / size {
+        sticky |= n.div_small(power) != 0;
+    }
+    for _ in 0..count % size {
+        sticky |= n.div_small(radix) != 0;
+    }
+    sticky
+}
+
+// Parses digits in the given radix, with an optional sign and an optional radix point,
+// into `out`. The exact value of the digits is split into components one at a time, each
+// the closest `f64` to what the components before it left, so a string that represents a
+// number exactly (like the ones written for exact `RadixString`s) produces exactly the
+// components of that number. Components past the point where nothing is left are zero.
+//
+// Numbers too large for an `f64` produce an infinite first component.
+pub fn parse(s: &str, radix: u32, out: &mut [f64]) -> std::result::Result<(), ErrorKind> {
+    check_radix(radix);
+
+    // Error positions are offsets into the string as it was passed in, so they have to
+    // count any whitespace that gets trimmed off of the front
+    let offset = s.len() - s.trim_start().len();
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ErrorKind::Empty);
+    }
+    for x in out.iter_mut() {
+        *x = 0.0;
+    }
+
+    let mut n = Big::default();
+    let mut negative = false;
+    let mut digits = 0;
+    let mut point = None;
+    for (index, ch) in s.char_indices() {
+        match ch.to_digit(radix) {
+            Some(d) => {
+                n.mul_small(radix);
+                n.add_small(d);
+                digits += 1;
+            }
+            None => match ch {
+                '.' if point.is_none() => point = Some(digits),
+                '-' if index == 0 => negative = true,
+                '+' if index == 0 => {}
+                _ => {
+                    return Err(ErrorKind::InvalidDigit {
+                        position: index + offset,
+                    })
+                }
+            },
+        }
+    }
+    if digits == 0 {
+        return Err(ErrorKind::Invalid);
+    }
+    if n.is_zero() {
+        out[0] = if negative { -0.0 } else { 0.0 };
+        return Ok(());
+    }
+    let places = digits - point.unwrap_or(digits);
+
+    // The number is a / (radix^places * 2^1075), where the 2^1075 puts the lowest bit that
+    // matters to an `f64` (half of the smallest subnormal) at the ones place
+    let mut a = n.shl(1075);
+    for x in out.iter_mut() {
+        let mut q = a.clone();
+        let sticky = div_pow(&mut q, radix, places);
+        let len = q.bit_len();
+        if len == 0 {
+            // Nothing left that's as large as half of the smallest subnormal
+            break;
+        }
+
+        // Round to 53 bits, or to the smallest subnormal if that's coarser
+        let shift = len.saturating_sub(54) + 1;
+        let mut mant = q.shr(shift).low_u64();
+        let half = q.bit(shift - 1);
+        if half && (sticky || q.any_below(shift - 1) || mant & 1 == 1) {
+            mant += 1;
+        }
+        let value = match make_f64(mant, shift as i64 - 1075) {
+            Some(v) => v,
+            None => {
+                *x = if negative {
+                    f64::NEG_INFINITY
+                } else {
+                    f64::INFINITY
+                };
+                return Ok(());
+            }
+        };
+        *x = if negative { -value } else { value };
+
+        let mut used = Big::from_u64(mant).shl(shift);
+        mul_pow(&mut used, radix, places);
+        if used <= a {
+            a = a.sub(&used);
+        } else {
+            a = used.sub(&a);
+            negative = !negative;
+        }
+        if a.is_zero() {
+            break;
+        }
+    }
+    Ok(())
+}
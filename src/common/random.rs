@@ -0,0 +1,47 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// The random numbers that the tests of `Double` and `Quad` use to check their functions
+// against many arguments at once.
+//
+// The generator is Marsaglia's xorshift64, which is plenty random for picking test
+// arguments and, more to the point, is completely determined by its seed. Each test seeds
+// its own generator, so a failure always happens with the same arguments and can be
+// reproduced by running the test again.
+
+/// A xorshift64 random number generator.
+pub struct Rng(u64);
+
+impl Rng {
+    /// Creates a generator from a seed, which must not be zero.
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    /// Returns the next random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a random `f64` in [0, 1), made from the top 53 bits of the next `u64`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * 2f64.powi(-53)
+    }
+
+    /// Returns a random integer in [`lo`, `hi`).
+    pub fn range(&mut self, lo: i32, hi: i32) -> i32 {
+        lo + (self.next_u64() % (hi - lo) as u64) as i32
+    }
+
+    /// Returns a random `f64` in [1, 2) with a random sign, scaled by 2^`exp`.
+    pub fn float(&mut self, exp: i32) -> f64 {
+        let m = f64::from_bits(0x3ff0_0000_0000_0000 | (self.next_u64() >> 12));
+        let s = if self.next_u64() & 1 == 0 { 1.0 } else { -1.0 };
+        s * m * 2f64.powi(exp)
+    }
+}
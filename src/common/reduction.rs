@@ -0,0 +1,306 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::utils as u;
+
+// Argument reduction for huge arguments, by the method of Payne and Hanek ("Radian
+// Reduction for Trigonometric Functions", 1983).
+//
+// Subtracting a multiple of π/2 that's only as precise as a `Double` or a `Quad` leaves an
+// error of about the argument times that precision, which is all of the reduced argument
+// once the argument passes 10^31 or 10^63. Instead, each component of the argument is
+// multiplied by enough of the bits of 2/π to find the product's fractional part exactly,
+// and the integer parts, which are whole quarter turns, are thrown away (all but the last
+// two bits, which say which quadrant the argument falls in). The bits of 2/π that are
+// needed start just above the binary point of the product, so while the table below has
+// to cover the whole exponent range of an `f64`, each component only takes `LIMBS` words
+// of it.
+//
+// The products are summed in fixed point, in `LIMBS` 64-bit words. The top word is the
+// integer part and the rest are 576 bits of fraction, so the reduced argument is good to
+// well over 400 bits past the quarter turn, even when the argument is close enough to a
+// multiple of π/2 that most of those bits cancel.
+
+// The number of 64-bit words in a fixed-point number, and the number of bits after its
+// binary point.
+const LIMBS: usize = 10;
+const FRAC_BITS: u32 = 64 * (LIMBS as u32 - 1);
+
+// The bits of 2/π after the binary point, 64 at a time. That's enough for every bit that
+// can land within `FRAC_BITS` bits of the binary point when it's multiplied by an `f64`.
+#[rustfmt::skip]
+const TWO_OVER_PI: [u64; 26] = [
+    0xa2f9_836e_4e44_1529, 0xfc27_57d1_f534_ddc0, 0xdb62_9599_3c43_9041,
+    0xfe51_63ab_debb_c561, 0xb724_6e3a_424d_d2e0, 0x0649_2eea_09d1_921c,
+    0xfe1d_eb1c_b129_a73e, 0xe882_35f5_2ebb_4484, 0xe99c_7026_b45f_7e41,
+    0x3991_d639_8353_39f4, 0x9c84_5f8b_bdf9_283b, 0x1ff8_97ff_de05_980f,
+    0xef2f_118b_5a0a_6d1f, 0x6d36_7ecf_27cb_09b7, 0x4f46_3f66_9e5f_ea2d,
+    0x7527_bac7_ebe5_f17b, 0x3d07_39f7_8a52_92ea, 0x6bfb_5fb1_1f8d_5d08,
+    0x5603_3046_fc7b_6bab, 0xf0cf_bc20_9af4_361d, 0xa9e3_9161_5ee6_1b08,
+    0x6599_855f_14a0_6840, 0x8dff_d880_4d73_2731, 0x0606_1556_ca73_a8c9,
+    0x60e2_7bc0_8c6b_47c4, 0x19c3_67cd_dce8_092a,
+];
+
+// A fixed-point number, two's complement, with the least significant word first.
+type Fixed = [u64; LIMBS];
+
+/// Finds the sum of `parts` divided by π/2, modulo 4. The result is split into the integer
+/// nearest to that, `n`, and what's left, `f`, which is written into `out` as components
+/// (from the largest) whose sum is `f`. The sum of `parts` is then (4*m* + `n` + `f`)π/2
+/// for some integer *m*, with `n` in [0, 3] and |`f`| no more than 1/2.
+///
+/// If `steps` isn't 1, `f` is split further into *k*/`steps` + `f` for the integer *k*
+/// nearest to `f` times `steps`, which is returned as the second result. `steps` has to be
+/// a power of 2.
+pub fn quarter_turns(parts: &[f64], steps: u32, out: &mut [f64]) -> (i32, i32) {
+    let mut acc = product(parts);
+    let n = round_off(&mut acc, FRAC_BITS) as i32 & 3;
+    let k = if steps > 1 {
+        round_off(&mut acc, FRAC_BITS - steps.trailing_zeros()) as i32
+    } else {
+        0
+    };
+    components(acc, out);
+    (n, k)
+}
+
+/// Finds the sum of `parts` divided by π/2, modulo 4, as a number in [-2, 2]. Its
+/// components are written into `out`, from the largest. Multiplied by π/2, this is the
+/// remainder of the sum divided by 2π.
+pub fn half_turns(parts: &[f64], out: &mut [f64]) {
+    let mut acc = product(parts);
+    round_off(&mut acc, FRAC_BITS + 2);
+    components(acc, out);
+}
+
+// The sum of `parts` times 2/π, modulo 2^64, in fixed point.
+fn product(parts: &[f64]) -> Fixed {
+    let mut acc = [0; LIMBS];
+    for &x in parts {
+        if x == 0.0 {
+            continue;
+        }
+        // x is ±m·2^e for an integer m of no more than 53 bits
+        let bits = x.to_bits();
+        let biased = (bits >> 52 & 0x7ff) as i32;
+        let (m, e) = if biased == 0 {
+            (bits & 0xf_ffff_ffff_ffff, -1074)
+        } else {
+            (bits & 0xf_ffff_ffff_ffff | 1 << 52, biased - 1075)
+        };
+
+        // 2/π times 2^(e + FRAC_BITS), modulo 2^(64 * LIMBS). Word j holds the bits of 2/π
+        // that land 64j to 64j + 63 bits above the least significant bit of the sum. The
+        // bits that are cut off at the bottom are worth less than m units of that bit
+        // together.
+        let mut g = [0; LIMBS];
+        for (j, w) in g.iter_mut().enumerate() {
+            *w = window(e + FRAC_BITS as i32 - 64 * j as i32 - 64);
+        }
+
+        let mut carry = 0;
+        for w in g.iter_mut() {
+            let p = *w as u128 * m as u128 + carry;
+            *w = p as u64;
+            carry = p >> 64;
+        }
+        if x < 0.0 {
+            negate(&mut g);
+        }
+        add(&mut acc, &g);
+    }
+    acc
+}
+
+// The 64 bits of 2/π that start `s` bits after the binary point. Bits before the binary
+// point are all 0.
+fn window(s: i32) -> u64 {
+    if s <= -64 {
+        0
+    } else if s < 0 {
+        TWO_OVER_PI[0] >> -s
+    } else {
+        let (i, b) = ((s / 64) as usize, s % 64);
+        let word = |i: usize| TWO_OVER_PI.get(i).copied().unwrap_or(0);
+        if b == 0 {
+            word(i)
+        } else {
+            word(i) << b | word(i + 1) >> (64 - b)
+        }
+    }
+}
+
+fn add(a: &mut Fixed, b: &Fixed) {
+    let mut carry = false;
+    for (x, &y) in a.iter_mut().zip(b.iter()) {
+        let (s, c1) = x.overflowing_add(y);
+        let (s, c2) = s.overflowing_add(carry as u64);
+        *x = s;
+        carry = c1 || c2;
+    }
+}
+
+fn negate(a: &mut Fixed) {
+    let mut carry = true;
+    for x in a.iter_mut() {
+        let (s, c) = (!*x).overflowing_add(carry as u64);
+        *x = s;
+        carry = c;
+    }
+}
+
+// Adds (a power of 2) 2^`bit` to `a`.
+fn add_bit(a: &mut Fixed, bit: u32) {
+    let mut b = [0; LIMBS];
+    b[(bit / 64) as usize] = 1 << (bit % 64);
+    add(a, &b);
+}
+
+// Rounds `a` to the nearest multiple of 2^`bits`, with ties going up, and leaves the
+// difference in `a`. Returns the low 64 bits of the multiple, as a signed number.
+fn round_off(a: &mut Fixed, bits: u32) -> i64 {
+    add_bit(a, bits - 1);
+    let (i, b) = ((bits / 64) as usize, bits % 64);
+    let low = a[i];
+    let high = a.get(i + 1).copied().unwrap_or(0);
+    let multiple = if b == 0 {
+        low
+    } else {
+        low >> b | high << (64 - b)
+    };
+
+    for x in a[i + 1..].iter_mut() {
+        *x = 0;
+    }
+    a[i] &= (1 << b) - 1;
+    // Taking the half back off brings what's left into [-1/2, 1/2) of a multiple
+    let mut half = [0; LIMBS];
+    add_bit(&mut half, bits - 1);
+    negate(&mut half);
+    add(a, &half);
+    multiple as i64
+}
+
+// Rounds `a`, which is no more than 2^(64 * LIMBS - 2) in magnitude, to the `f64`s in `out`
+// one at a time, subtracting each one before finding the next. Each component is the
+// nearest `f64` to what's left, scaled down by 2^FRAC_BITS.
+fn components(mut a: Fixed, out: &mut [f64]) {
+    for c in out.iter_mut() {
+        let negative = a[LIMBS - 1] >> 63 == 1;
+        let mut mag = a;
+        if negative {
+            negate(&mut mag);
+        }
+        let top = match mag.iter().rposition(|&w| w != 0) {
+            None => {
+                *c = 0.0;
+                continue;
+            }
+            Some(top) => top,
+        };
+        // The position of the leading bit, counting from the least significant bit of `a`
+        let lead = 64 * top as i32 + 63 - mag[top].leading_zeros() as i32;
+
+        // The 53 bits starting at the leading bit, rounded to nearest
+        let shift = (lead - 52).max(0);
+        let m = if shift == 0 {
+            mag[0]
+        } else {
+            let bits = |at: i32| {
+                let (i, b) = ((at / 64) as usize, at % 64);
+                let high = mag.get(i + 1).copied().unwrap_or(0);
+                if b == 0 {
+                    mag[i]
+                } else {
+                    mag[i] >> b | high << (64 - b)
+                }
+            };
+            let m = bits(shift) & ((1 << 53) - 1);
+            m + (bits(shift - 1) & 1)
+        };
+
+        let mut sub = [0; LIMBS];
+        let (i, b) = ((shift / 64) as usize, shift % 64);
+        sub[i] = m << b;
+        if b > 0 && i + 1 < LIMBS {
+            sub[i + 1] = m >> (64 - b);
+        }
+        let value = m as f64 * u::pow2(shift - FRAC_BITS as i32);
+        if negative {
+            add(&mut a, &sub);
+            *c = -value;
+        } else {
+            negate(&mut sub);
+            add(&mut a, &sub);
+            *c = value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table() {
+        // The first 53 bits, rounded, are the f64 nearest 2/π
+        let top = (TWO_OVER_PI[0] >> 11) + (TWO_OVER_PI[0] >> 10 & 1);
+        assert!(top as f64 * 2f64.powi(-53) == std::f64::consts::FRAC_2_PI);
+        assert!(window(-1) == TWO_OVER_PI[0] >> 1);
+        assert!(window(64) == TWO_OVER_PI[1]);
+        assert!(window(4) == TWO_OVER_PI[0] << 4 | TWO_OVER_PI[1] >> 60);
+    }
+
+    #[test]
+    fn small() {
+        let mut out = [0.0; 2];
+        // 3 = (2 - 0.0901406828972559...)·π/2
+        let (n, k) = quarter_turns(&[3.0], 1, &mut out);
+        assert!(n == 2 && k == 0);
+        assert!((out[0] + 0.090_140_682_897_256).abs() < 1e-15);
+        // -3 = (-2 + 0.0901406828972559...)·π/2, and -2 is 2 modulo 4
+        let (n, _) = quarter_turns(&[-3.0], 1, &mut out);
+        assert!(n == 2);
+        assert!((out[0] - 0.090_140_682_897_256).abs() < 1e-15);
+
+        half_turns(&[-5.0], &mut out);
+        // -5 + 2π = 1.2831853071795864...
+        assert!((out[0] * std::f64::consts::FRAC_PI_2 - 1.283_185_307_179_586_5).abs() < 1e-15);
+    }
+
+    #[test]
+    fn steps() {
+        let mut out = [0.0; 2];
+        // 0.3·π/2 is 0.25·π/2 + 0.05·π/2, or 2 steps of π/16 and 0.05 of a quarter turn
+        let x = 0.3 * std::f64::consts::FRAC_PI_2;
+        let (n, k) = quarter_turns(&[x], 8, &mut out);
+        assert!(n == 0 && k == 2);
+        assert!((out[0] - 0.05).abs() < 1e-15);
+        let (n, k) = quarter_turns(&[-x], 8, &mut out);
+        assert!(n == 0 && k == -2);
+        assert!((out[0] + 0.05).abs() < 1e-15);
+    }
+
+    #[test]
+    fn components_third() {
+        // 1/3 in fixed point, and the f64s that each come nearest to what's left of it
+        let mut a = [0x5555_5555_5555_5555; LIMBS];
+        a[LIMBS - 1] = 0;
+        let mut out = [0.0; 4];
+        components(a, &mut out);
+        assert!(
+            out == [
+                0.333_333_333_333_333_3,
+                1.850_371_707_708_594e-17,
+                1.027_162_637_006_525_7e-33,
+                5.701_898_048_196_684e-50,
+            ]
+        );
+
+        negate(&mut a);
+        components(a, &mut out);
+        assert!(out[0] == -0.333_333_333_333_333_3 && out[3] == -5.701_898_048_196_684e-50);
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Reference values for the hard-coded constants and tables of `Double` and `Quad`.
+//
+// Every constant that's written out component by component (the associated constants like
+// `PI`, and the internal tables of reciprocal factorials, sines and cosines, and Stirling
+// coefficients) is supposed to be the true value rounded one component at a time: each
+// component is the `f64` nearest to what the components before it leave over. That's
+// exactly what `from_string_radix_any` produces from a decimal string, because it builds
+// the number exactly before splitting it. So each constant is checked by parsing an
+// 80-digit decimal value from `tests/constants.txt`, which is far more than either type
+// can hold, and comparing the result component for component.
+//
+// The file has one value per line, a name followed by the decimal digits. Tables use the
+// name of the `Quad` table with an index into it, and `Double`'s shorter tables look up
+// the entries with the same values.
+
+use std::collections::HashMap;
+use std::fs;
+
+const REFERENCES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/constants.txt");
+
+/// Reads the reference values, keyed by name. Blank lines and lines starting with `#`
+/// are skipped.
+pub fn references() -> HashMap<String, String> {
+    let text = fs::read_to_string(REFERENCES).expect("the reference values should be readable");
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (name, value) = line
+                .split_once(' ')
+                .unwrap_or_else(|| panic!("malformed reference line: {}", line));
+            (name.to_string(), value.trim().to_string())
+        })
+        .collect()
+}
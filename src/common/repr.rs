@@ -0,0 +1,88 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Parsing of what the `Debug` implementations write, so that a number copied out of debug
+// output can be turned back into the number that was printed.
+//
+// `Debug` writes the name of the type and then its components in parentheses, each in the
+// `{:e}` form of an `f64`. That's the shortest string that parses back to the same `f64`,
+// and `f64`'s parser reads `inf`, `-inf`, `NaN`, and `-0e0` as well, so every component
+// comes back bit for bit (apart from the sign of a NaN, which `Debug` doesn't write).
+
+use crate::error::ErrorKind;
+
+// Parses `s` as `name` followed by `N` comma-separated `f64`s in parentheses. Whitespace,
+// including line breaks, is allowed around each piece, so this reads both the one-line
+// form of `{:?}` and the multi-line form of `{:#?}`. A comma after the last component is
+// allowed too.
+//
+// Errors are those of `from_str`, with positions that are byte offsets into `s`. A
+// component that isn't an `f64` is reported at its first character.
+pub fn parse<const N: usize>(s: &str, name: &str) -> Result<[f64; N], ErrorKind> {
+    let bytes = s.as_bytes();
+    let mut i = skip_space(bytes, 0);
+    if i == bytes.len() {
+        return Err(ErrorKind::Empty);
+    }
+    for &b in name.as_bytes() {
+        expect(bytes, i, b)?;
+        i += 1;
+    }
+    i = skip_space(bytes, i);
+    expect(bytes, i, b'(')?;
+    i += 1;
+
+    let mut parts = [0.0; N];
+    for (k, part) in parts.iter_mut().enumerate() {
+        let start = skip_space(bytes, i);
+        let end = bytes[start..]
+            .iter()
+            .position(|&b| b == b',' || b == b')' || b.is_ascii_whitespace())
+            .map_or(bytes.len(), |n| start + n);
+        if start == end {
+            return Err(if end == bytes.len() {
+                ErrorKind::Invalid
+            } else {
+                ErrorKind::InvalidDigit { position: end }
+            });
+        }
+        *part = s[start..end]
+            .parse()
+            .map_err(|_| ErrorKind::InvalidDigit { position: start })?;
+        i = skip_space(bytes, end);
+        if k + 1 < N {
+            expect(bytes, i, b',')?;
+            i += 1;
+        }
+    }
+
+    if bytes.get(i) == Some(&b',') {
+        i = skip_space(bytes, i + 1);
+    }
+    expect(bytes, i, b')')?;
+    i = skip_space(bytes, i + 1);
+    if i < bytes.len() {
+        return Err(ErrorKind::InvalidDigit { position: i });
+    }
+    Ok(parts)
+}
+
+// Returns the index of the first byte at or after `i` that isn't whitespace.
+fn skip_space(bytes: &[u8], i: usize) -> usize {
+    bytes[i..]
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .map_or(bytes.len(), |n| i + n)
+}
+
+// Checks that the byte at `i` is `b`. The string ending early makes it invalid, and any
+// other byte is an invalid character at that position.
+fn expect(bytes: &[u8], i: usize, b: u8) -> Result<(), ErrorKind> {
+    match bytes.get(i) {
+        Some(&c) if c == b => Ok(()),
+        Some(_) => Err(ErrorKind::InvalidDigit { position: i }),
+        None => Err(ErrorKind::Invalid),
+    }
+}
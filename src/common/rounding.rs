@@ -0,0 +1,217 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+/// The direction in which a [`Double`] or a [`Quad`] is rounded when it's converted into a
+/// type with less precision, as by their `to_f64_round` and `to_f32_round` methods.
+///
+/// These are the rounding directions of IEEE 754. A result too large for the type it's
+/// rounded into is infinite when rounding to nearest, and when rounding in the direction of
+/// that infinity; otherwise it's the largest finite number of that type.
+///
+/// # Examples
+/// ```
+/// # use qd::{Double, RoundingMode};
+/// let x = Double::new(1.0, 1e-20);
+/// assert!(x.to_f64_round(RoundingMode::Nearest) == 1.0);
+/// assert!(x.to_f64_round(RoundingMode::TowardZero) == 1.0);
+/// assert!(x.to_f64_round(RoundingMode::TowardPosInf) == 1.0 + f64::EPSILON);
+/// assert!(x.to_f64_round(RoundingMode::TowardNegInf) == 1.0);
+/// ```
+///
+/// [`Double`]: struct.Double.html
+/// [`Quad`]: struct.Quad.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds to the nearest number, with ties going to the one with an even last bit.
+    Nearest,
+    /// Rounds to the nearest number that's no larger in magnitude.
+    TowardZero,
+    /// Rounds to the nearest number that's no smaller (toward positive infinity).
+    TowardPosInf,
+    /// Rounds to the nearest number that's no larger (toward negative infinity).
+    TowardNegInf,
+}
+
+// Rounds the number whose normalized components are `parts` to an `f64`.
+//
+// The first component is the number rounded to nearest, and the components don't overlap,
+// so the sign of the first nonzero one after it is the sign of everything after it. That's
+// enough to know which way each of the directed modes goes. Rounding to nearest only needs
+// more when the second component is exactly half of the way to the first one's neighbor;
+// then the first component is the even one of the two, which is right for an exact tie
+// but not if the components after the second carry the number past halfway.
+pub fn to_f64(parts: &[f64], mode: RoundingMode) -> f64 {
+    let a = parts[0];
+    let rest = first_nonzero(&parts[1..]);
+    if !a.is_finite() || rest == 0.0 {
+        return a;
+    }
+    match mode {
+        RoundingMode::Nearest => {
+            let b = parts[1];
+            let neighbor = if b > 0.0 { next_up(a) } else { next_down(a) };
+            let beyond = first_nonzero(&parts[2..]);
+            if 2.0 * b == neighbor - a && beyond != 0.0 && (beyond > 0.0) == (b > 0.0) {
+                neighbor
+            } else {
+                a
+            }
+        }
+        RoundingMode::TowardZero => {
+            if a > 0.0 && rest < 0.0 {
+                next_down(a)
+            } else if a < 0.0 && rest > 0.0 {
+                next_up(a)
+            } else {
+                a
+            }
+        }
+        RoundingMode::TowardPosInf => {
+            if rest > 0.0 {
+                next_up(a)
+            } else {
+                a
+            }
+        }
+        RoundingMode::TowardNegInf => {
+            if rest < 0.0 {
+                next_down(a)
+            } else {
+                a
+            }
+        }
+    }
+}
+
+// Rounds the number whose normalized components are `parts` to the nearest integer, in
+// place, with ties going away from zero or, if `even` is set, to the even integer.
+//
+// The components before the first one that isn't an integer are already part of the
+// integer. The ones after it are too small to carry that one across a halfway point, since
+// it's at least its own ULP away from any halfway point that it isn't exactly on, and they
+// add up to no more than half of that. So only that component is rounded, and the ones
+// after it only matter when it's exactly halfway, where their sign says which way the
+// whole number is from the tie. The components that come out are integers, but they may
+// need to be normalized again. Numbers that aren't finite are left alone.
+pub fn round_to_integer(parts: &mut [f64], even: bool) {
+    if !parts[0].is_finite() {
+        return;
+    }
+    let k = match parts.iter().position(|x| x.fract() != 0.0) {
+        Some(k) => k,
+        None => return,
+    };
+    let x = parts[k];
+    let floor = x.floor();
+    let frac = x - floor;
+    let rest = first_nonzero(&parts[k + 1..]);
+
+    let up = if frac != 0.5 {
+        frac > 0.5
+    } else if rest != 0.0 {
+        rest > 0.0
+    } else if even {
+        // The integer below the tie is odd if an odd number of its parts are
+        let odd = parts[..k]
+            .iter()
+            .chain(std::iter::once(&floor))
+            .filter(|&&p| p % 2.0 != 0.0)
+            .count();
+        odd % 2 == 1
+    } else {
+        parts[0] > 0.0
+    };
+
+    let r = if up { floor + 1.0 } else { floor };
+    // A number that rounds to zero keeps its sign, as it does with `f64`
+    parts[k] = if r == 0.0 { 0f64.copysign(x) } else { r };
+    for p in parts[k + 1..].iter_mut() {
+        *p = 0.0;
+    }
+}
+
+// Rounds the number whose normalized components are `parts` to an `f32`.
+//
+// Rounding in one direction twice is the same as rounding in that direction once, so the
+// directed modes round to an `f64` first and then on to an `f32` in the same direction.
+// Rounding to nearest twice isn't the same, since the first rounding can land exactly on a
+// halfway point between two `f32`s that the number itself isn't on. So the first rounding
+// is to odd instead, which never lands on a halfway point unless the number is on it too.
+pub fn to_f32(parts: &[f64], mode: RoundingMode) -> f32 {
+    if mode == RoundingMode::Nearest {
+        return to_f64_odd(parts) as f32;
+    }
+    let x = to_f64(parts, mode);
+    // `as` rounds to nearest, which leaves the result no more than one f32 away from the
+    // one that's wanted
+    let y = x as f32;
+    let back = y as f64;
+    match mode {
+        RoundingMode::TowardZero if back.abs() > x.abs() => {
+            if y > 0.0 {
+                next_down_f32(y)
+            } else {
+                next_up_f32(y)
+            }
+        }
+        RoundingMode::TowardPosInf if back < x => next_up_f32(y),
+        RoundingMode::TowardNegInf if back > x => next_down_f32(y),
+        _ => y,
+    }
+}
+
+// Rounds to the `f64` with an odd last bit on whichever side of the number that one is,
+// unless the number is exactly an `f64` already.
+fn to_f64_odd(parts: &[f64]) -> f64 {
+    let a = parts[0];
+    if !a.is_finite() || first_nonzero(&parts[1..]) == 0.0 {
+        return a;
+    }
+    let t = to_f64(parts, RoundingMode::TowardZero);
+    if t.to_bits() & 1 == 1 {
+        t
+    } else if a > 0.0 {
+        next_up(t)
+    } else {
+        next_down(t)
+    }
+}
+
+fn first_nonzero(parts: &[f64]) -> f64 {
+    parts.iter().copied().find(|&x| x != 0.0).unwrap_or(0.0)
+}
+
+// The next `f64` toward positive infinity. This is only called with finite numbers, and
+// the next one after the largest finite number is infinity.
+fn next_up(x: f64) -> f64 {
+    if x == 0.0 {
+        f64::from_bits(1)
+    } else if x > 0.0 {
+        f64::from_bits(x.to_bits() + 1)
+    } else {
+        f64::from_bits(x.to_bits() - 1)
+    }
+}
+
+fn next_down(x: f64) -> f64 {
+    -next_up(-x)
+}
+
+// The next `f32` toward positive infinity. Unlike `next_up`, this can be called with an
+// infinity (when an `f64` overflows on its way to an `f32`), but only with the one on the
+// other side.
+fn next_up_f32(x: f32) -> f32 {
+    if x == 0.0 {
+        f32::from_bits(1)
+    } else if x > 0.0 {
+        f32::from_bits(x.to_bits() + 1)
+    } else {
+        f32::from_bits(x.to_bits() - 1)
+    }
+}
+
+fn next_down_f32(x: f32) -> f32 {
+    -next_up_f32(-x)
+}
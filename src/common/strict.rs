@@ -0,0 +1,241 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Support for the `strict` and `strict-panic` features, which make operations loud when
+// they produce NaN or an infinity from operands that were all finite. This is essentially
+// floating-point exceptions done in software, for those who would rather crash than
+// propagate a NaN through a long calculation.
+//
+// The policy is the same everywhere: the arithmetic operators and the functions with
+// shortcut results (the `pre_*` functions) pass their operands and their result through
+// `guard`. That includes results that are returned on purpose, like the infinity from
+// `exp` of a large number or from `ln(0)`, and it includes operations done inside of other
+// functions (including parsing and formatting). If a NaN or infinity is already among the
+// operands, nothing is reported; the problem was reported (or let through on purpose) when
+// that operand was created.
+//
+// With `strict`, the check is a debug assertion, so it only happens in debug builds. With
+// `strict-panic`, it happens in release builds as well. Without either feature, `guard`
+// does nothing but return its result and compiles down to nothing at all.
+//
+// The one exception is code run by `quietly`, which is how the `checked_*` and
+// `overflowing_*` methods ask for results that they're going to check themselves.
+
+/// Returns `result` after checking that it isn't NaN or infinite unless one of the
+/// `operands` (the first components of the actual operands) already was. If the check
+/// fails, this panics with a message naming the operation and its operands.
+#[cfg(feature = "strict")]
+#[inline]
+pub fn guard<T: std::ops::Index<usize, Output = f64>>(op: &str, operands: &[f64], result: T) -> T {
+    if (cfg!(feature = "strict-panic") || cfg!(debug_assertions))
+        && !result[0].is_finite()
+        && operands.iter().all(|x| x.is_finite())
+        && !QUIET.with(|q| q.get())
+    {
+        report(op, operands, result[0]);
+    }
+    result
+}
+
+/// Returns `result`. The check is only done when the `strict` feature is enabled.
+#[cfg(not(feature = "strict"))]
+#[inline(always)]
+pub fn guard<T>(_op: &str, _operands: &[f64], result: T) -> T {
+    result
+}
+
+#[cfg(feature = "strict")]
+thread_local! {
+    static QUIET: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Runs `f` without reporting anything that the operations inside of it produce. This is
+/// for callers that check the result themselves and would rather return it than panic.
+#[cfg(feature = "strict")]
+pub fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    let was = QUIET.with(|q| q.replace(true));
+    let result = f();
+    QUIET.with(|q| q.set(was));
+    result
+}
+
+/// Runs `f`. Nothing is reported anyway unless the `strict` feature is enabled.
+#[cfg(not(feature = "strict"))]
+#[inline(always)]
+pub fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    f()
+}
+
+#[cfg(feature = "strict")]
+#[cold]
+#[inline(never)]
+fn report(op: &str, operands: &[f64], result: f64) -> ! {
+    let operands = operands
+        .iter()
+        .map(|x| format!("{:e}", x))
+        .collect::<Vec<_>>()
+        .join(", ");
+    panic!(
+        "{} produced {} from finite operands ({})",
+        op, result, operands
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    // The checks only happen in debug builds unless `strict-panic` is on
+    #[cfg(all(feature = "strict", any(debug_assertions, feature = "strict-panic")))]
+    mod enabled {
+        use crate::{Double, Quad};
+
+        #[test]
+        #[should_panic(expected = "Double + Double produced")]
+        fn double_add() {
+            let _ = Double::MAX + Double::MAX;
+        }
+
+        #[test]
+        #[should_panic(expected = "Double - Double produced")]
+        fn double_sub() {
+            let _ = -Double::MAX - Double::MAX;
+        }
+
+        #[test]
+        #[should_panic(expected = "Double * Double produced")]
+        fn double_mul() {
+            let _ = Double::MAX * Double::from(2);
+        }
+
+        #[test]
+        #[should_panic(expected = "Double / Double produced inf from finite operands (1e0, 0e0)")]
+        fn double_div_by_zero() {
+            let _ = Double::ONE / Double::ZERO;
+        }
+
+        #[test]
+        #[should_panic(expected = "Double / Double produced NaN from finite operands (0e0, 0e0)")]
+        fn double_zero_div_zero() {
+            let _ = Double::ZERO / Double::ZERO;
+        }
+
+        #[test]
+        #[should_panic(expected = "Double % Double produced NaN from finite operands (3e0, 0e0)")]
+        fn double_rem() {
+            let _ = Double::from(3) % Double::ZERO;
+        }
+
+        #[test]
+        #[should_panic(expected = "Quad + Quad produced")]
+        fn quad_add() {
+            let _ = Quad::MAX + Quad::MAX;
+        }
+
+        #[test]
+        #[should_panic(expected = "Quad - Quad produced")]
+        fn quad_sub() {
+            let _ = -Quad::MAX - Quad::MAX;
+        }
+
+        #[test]
+        #[should_panic(expected = "Quad * Quad produced")]
+        fn quad_mul() {
+            let _ = Quad::MAX * Quad::from(2);
+        }
+
+        #[test]
+        #[should_panic(expected = "Quad / Quad produced -inf from finite operands (-1e0, 0e0)")]
+        fn quad_div_by_zero() {
+            let _ = -Quad::ONE / Quad::ZERO;
+        }
+
+        #[test]
+        #[should_panic(expected = "Quad % Quad produced NaN from finite operands (3e0, 0e0)")]
+        fn quad_rem() {
+            let _ = Quad::from(3) % Quad::ZERO;
+        }
+
+        // Infinities and NaNs that functions return on purpose are reported too
+        #[test]
+        #[should_panic(expected = "Double::exp produced inf from finite operands (8e2)")]
+        fn double_exp_overflow() {
+            let _ = Double::from(800).exp();
+        }
+
+        #[test]
+        #[should_panic(expected = "Double::ln produced -inf from finite operands (0e0)")]
+        fn double_ln_zero() {
+            let _ = Double::ZERO.ln();
+        }
+
+        #[test]
+        #[should_panic(expected = "Quad::sqrt produced NaN from finite operands (-2e0)")]
+        fn quad_sqrt_negative() {
+            let _ = Quad::from(-2).sqrt();
+        }
+
+        #[test]
+        #[should_panic(expected = "Quad::powi produced inf from finite operands (1e1, 4e2)")]
+        fn quad_powi_overflow() {
+            let _ = Quad::from(10).powi(400);
+        }
+
+        // Nothing is reported when the operands weren't finite to begin with
+        #[test]
+        fn nonfinite_operands() {
+            assert!((Double::INFINITY + Double::ONE).is_infinite());
+            assert!((Double::NAN * Double::from(2)).is_nan());
+            assert!((Quad::INFINITY - Quad::INFINITY).is_nan());
+            assert!(Quad::NEG_INFINITY.exp() == Quad::ZERO);
+            assert!(Quad::NAN.ln().is_nan());
+        }
+
+        // Nor by the checked methods, which return None instead
+        #[test]
+        fn checked() {
+            assert!(Double::MAX.checked_add(Double::MAX).is_none());
+            assert!(Double::ZERO.checked_ln().is_none());
+            assert!(Quad::ONE.checked_div(Quad::ZERO).is_none());
+            assert!(Quad::from(-2).overflowing_mul(Quad::MAX).1);
+        }
+
+        // The methods don't leave reports turned off
+        #[test]
+        #[should_panic(expected = "Double + Double produced")]
+        fn after_checked() {
+            let _ = Double::MAX.checked_add(Double::MAX);
+            let _ = Double::MAX + Double::MAX;
+        }
+
+        // Underflow isn't reported, and neither are the huge intermediate values that
+        // calculating tiny numbers could otherwise produce
+        #[test]
+        fn underflow() {
+            assert!(Double::from(-800).exp() == Double::ZERO);
+            assert!(Quad::from(10).powi(-400) == Quad::ZERO);
+            assert!("1e-999".parse::<Double>().unwrap() == Double::ZERO);
+            assert!("1e-320".parse::<Quad>().unwrap() > Quad::ZERO);
+            assert!(format!("{:e}", Double::from((5e-324, 0.0))).ends_with("e-324"));
+        }
+    }
+
+    // Without the feature, nothing is checked and nothing panics
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn disabled() {
+        use super::guard;
+        use crate::{Double, Quad};
+
+        assert!(guard("test", &[1.0], Double::NAN).is_nan());
+        assert!(!(Double::MAX + Double::MAX).is_finite());
+        assert!(Double::ONE / Double::ZERO == Double::INFINITY);
+        assert!((Double::ZERO / Double::ZERO).is_nan());
+        assert!((Double::from(3) % Double::ZERO).is_nan());
+        assert!(Double::ZERO.ln() == Double::NEG_INFINITY);
+        assert!(!(Quad::MAX * Quad::from(2)).is_finite());
+        assert!(-Quad::ONE / Quad::ZERO == Quad::NEG_INFINITY);
+        assert!(Quad::from(-2).sqrt().is_nan());
+        assert!(Quad::from(800).exp() == Quad::INFINITY);
+    }
+}
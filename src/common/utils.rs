@@ -3,11 +3,12 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::accumulator::Partials;
 use crate::common::primitive as p;
 
 /// Calculates the sum of three `f64`s in double-double precision.
 #[inline]
-pub fn three_two_sum(a: f64, b: f64, c: f64) -> (f64, f64) {
+pub const fn three_two_sum(a: f64, b: f64, c: f64) -> (f64, f64) {
     let (u, v) = p::two_sum(a, b);
     let (s, w) = p::two_sum(c, u);
     (s, v + w)
@@ -15,7 +16,7 @@ pub fn three_two_sum(a: f64, b: f64, c: f64) -> (f64, f64) {
 
 /// Calculates the sum of three `f64`s in triple-double precision.
 #[inline]
-pub fn three_three_sum(a: f64, b: f64, c: f64) -> (f64, f64, f64) {
+pub const fn three_three_sum(a: f64, b: f64, c: f64) -> (f64, f64, f64) {
     let (u, v) = p::two_sum(a, b);
     let (s, w) = p::two_sum(c, u);
     let (e1, e2) = p::two_sum(v, w);
@@ -24,14 +25,14 @@ pub fn three_three_sum(a: f64, b: f64, c: f64) -> (f64, f64, f64) {
 
 /// Calculates the sum of four `f64`s in double-double precision.
 #[inline]
-pub fn four_two_sum(a: f64, b: f64, c: f64, d: f64) -> (f64, f64) {
+pub const fn four_two_sum(a: f64, b: f64, c: f64, d: f64) -> (f64, f64) {
     let (s0, s1) = p::two_sum(a, c);
     (s0, s1 + b + d)
 }
 
 /// Calculates the sum of six `f64`s in triple-double precision.
 #[inline]
-pub fn six_three_sum(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> (f64, f64, f64) {
+pub const fn six_three_sum(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> (f64, f64, f64) {
     let (p0, p1, p2) = three_three_sum(a, b, c);
     let (q0, q1, q2) = three_three_sum(d, e, f);
     let (r0, r1) = p::two_sum(p0, q0);
@@ -44,7 +45,7 @@ pub fn six_three_sum(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> (f64, f6
 /// Calculates the sum of nine `f64`s in double-double precision.
 #[allow(clippy::too_many_arguments)]
 #[inline]
-pub fn nine_two_sum(
+pub const fn nine_two_sum(
     a: f64,
     b: f64,
     c: f64,
@@ -72,7 +73,7 @@ pub fn nine_two_sum(
 /// tuple component and the second and third contain the remainder. Otherwise, the first
 /// tuple component is `0.0` and the sum is in the other two components.
 #[inline]
-pub fn accumulate(a: f64, b: f64, c: f64) -> (f64, f64, f64) {
+pub const fn accumulate(a: f64, b: f64, c: f64) -> (f64, f64, f64) {
     let (s, b) = p::two_sum(b, c);
     let (s, a) = p::two_sum(a, s);
 
@@ -92,7 +93,7 @@ pub fn accumulate(a: f64, b: f64, c: f64) -> (f64, f64, f64) {
 /// way that the absolute value of the last component is no more than half the ULP of the
 /// first.
 #[inline]
-pub fn renorm2(a: f64, b: f64) -> (f64, f64) {
+pub const fn renorm2(a: f64, b: f64) -> (f64, f64) {
     p::quick_two_sum(a, b)
 }
 
@@ -102,7 +103,7 @@ pub fn renorm2(a: f64, b: f64) -> (f64, f64) {
 /// way that the absolute value of the last component is no more than half the ULP of the
 /// first.
 #[inline]
-pub fn renorm3(a: f64, b: f64, c: f64) -> (f64, f64) {
+pub const fn renorm3(a: f64, b: f64, c: f64) -> (f64, f64) {
     let (u, v) = p::quick_two_sum(a, b);
     let (s, w) = p::quick_two_sum(c, u);
     p::quick_two_sum(s, v + w)
@@ -114,7 +115,7 @@ pub fn renorm3(a: f64, b: f64, c: f64) -> (f64, f64) {
 /// way that the absolute value of each component is no more than half of the ULP of the
 /// prior component.
 #[inline]
-pub fn renorm4(a: f64, b: f64, c: f64, d: f64) -> (f64, f64, f64, f64) {
+pub const fn renorm4(a: f64, b: f64, c: f64, d: f64) -> (f64, f64, f64, f64) {
     let (x, s3) = p::quick_two_sum(c, d);
     let (x, s2) = p::quick_two_sum(b, x);
     let (s0, s1) = p::quick_two_sum(a, x);
@@ -146,7 +147,7 @@ pub fn renorm4(a: f64, b: f64, c: f64, d: f64) -> (f64, f64, f64, f64) {
 /// way that the absolute value of each component is no more than half of the ULP of the
 /// prior component.
 #[inline]
-pub fn renorm5(a: f64, b: f64, c: f64, d: f64, e: f64) -> (f64, f64, f64, f64) {
+pub const fn renorm5(a: f64, b: f64, c: f64, d: f64, e: f64) -> (f64, f64, f64, f64) {
     let (x, s4) = p::quick_two_sum(d, e);
     let (x, s3) = p::quick_two_sum(c, x);
     let (x, s2) = p::quick_two_sum(b, x);
@@ -196,18 +197,421 @@ pub fn renorm5(a: f64, b: f64, c: f64, d: f64, e: f64) -> (f64, f64, f64, f64) {
     }
 }
 
-/// Determines whether a number is exact (true) or has floating-point error (false).
+/// Sums integer components as a `u128`, wrapping modulo 2<sup>128</sup>.
 ///
-/// A number is exactly representable in binary if it can be rendered as a fraction with a
-/// power of two as an exponent. If so, then floating-point error doesn't exist and the
-/// number can be turned into a quad- or double-double much more efficiently.
-pub fn is_dyadic(n: f64) -> bool {
-    let f = n.fract();
-    if f == 0.0 {
-        true
+/// Each component has to be an integer no larger than 2<sup>128</sup> in magnitude. When
+/// the exact sum fits into a 128-bit integer type, the result is that sum, reinterpreted as
+/// an `i128` if it's negative. The components are added without ever being rounded, so
+/// every bit of each of them counts, and a first component of 2<sup>128</sup> (which a
+/// `u128` can't hold, but which a smaller number can have when its next component is
+/// negative) comes out right too.
+pub fn wrapping_sum(parts: &[f64]) -> u128 {
+    parts.iter().fold(0u128, |sum, &x| {
+        let n = if x.abs() >= 2f64.powi(128) {
+            0
+        } else {
+            x.abs() as u128
+        };
+        if x < 0.0 {
+            sum.wrapping_sub(n)
+        } else {
+            sum.wrapping_add(n)
+        }
+    })
+}
+
+/// Calculates the product of two `f64`s as a rounded product and its error, without
+/// letting either overflow or underflow partway through.
+///
+/// [`two_prod`] alone can go wrong at the ends of the exponent range. Without FMA, the
+/// halves that it splits its arguments into can multiply to more than `f64::MAX` even when
+/// the product is finite, so the larger argument is first scaled down by 2<sup>-64</sup>.
+/// And a product under 2<sup>-969</sup> can have an error too small for even a subnormal
+/// number, so there the error is found at a scale 2<sup>106</sup> times larger and only
+/// rounded when it's scaled back. The error is exact whenever an `f64` can hold it. A
+/// product that isn't finite comes back with an error of 0.
+///
+/// [`two_prod`]: ../primitive/fn.two_prod.html
+pub fn exact_prod(a: f64, b: f64) -> (f64, f64) {
+    const BIG: f64 = 6.696928794914171e299; // 2^996
+    const SMALL: f64 = 2.004168360008973e-292; // 2^-969
+    const DOWN: f64 = 5.421010862427522e-20; // 2^-64
+    const UP: f64 = 8.112963841460668e31; // 2^106
+
+    let prod = a * b;
+    if !prod.is_finite() || prod == 0.0 {
+        (prod, 0.0)
+    } else if prod.abs() >= BIG {
+        let (h, l) = if a.abs() >= b.abs() {
+            p::two_prod(a * DOWN, b)
+        } else {
+            p::two_prod(a, b * DOWN)
+        };
+        (h / DOWN, l / DOWN)
+    } else if prod.abs() < SMALL {
+        let (h, l) = if a.abs() <= b.abs() {
+            p::two_prod(a * UP, b)
+        } else {
+            p::two_prod(a, b * UP)
+        };
+        // `h` and the scaled `prod` are within a factor of 2 of each other, so subtracting
+        // them is exact
+        (prod, ((h - prod * UP) + l) / UP)
+    } else {
+        p::two_prod(a, b)
+    }
+}
+
+/// Rounds four normalized components to the nearest two-component value.
+///
+/// The second component is usually already the nearest `f64` to everything after the
+/// first, but the last two components can tip it over to the next `f64` when it was
+/// rounded from a halfway point. Everything below the second component is first summed
+/// exactly and then rounded to odd, which keeps a nonzero remainder from looking like an
+/// exact tie when the sum is rounded again to the nearest `f64`.
+pub fn round2(a: f64, b: f64, c: f64, d: f64) -> (f64, f64) {
+    if !a.is_finite() || a == 0.0 {
+        return (a, b);
+    }
+    let (s, e) = p::two_sum(b, c);
+    let (t, f) = p::two_sum(e, d);
+    let t = if f != 0.0 && t.to_bits() & 1 == 0 {
+        let bits = t.to_bits();
+        f64::from_bits(if (f > 0.0) == (t > 0.0) {
+            bits + 1
+        } else {
+            bits - 1
+        })
+    } else {
+        t
+    };
+    p::quick_two_sum(a, s + t)
+}
+
+/// Returns the binary exponent of a finite, nonzero `f64`, the *k* for which
+/// 2<sup>*k*</sup> &le; |*n*| < 2<sup>*k*+1</sup>.
+///
+/// This is read directly out of the bits of the number, so unlike `n.log2().floor()` it is
+/// exact for every such number, including subnormals.
+pub const fn exponent(n: f64) -> i32 {
+    let bits = n.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    if biased == 0 {
+        let mantissa = bits & 0x000f_ffff_ffff_ffff;
+        -1011 - mantissa.leading_zeros() as i32
+    } else {
+        biased - 1023
+    }
+}
+
+/// Multiplies an `f64` by 2<sup>*n*</sup> for any *n*, rounding no more than once.
+///
+/// A product too large to represent is infinite, and one too small is zero, in either case
+/// with the sign of `x`. This is C's `scalbn`: 2<sup>*n*</sup> may not be an `f64` itself,
+/// so the factor is applied in up to three steps. Every step but the last is exact, and
+/// when the product is subnormal, the step before the last leaves so little of the factor
+/// that an earlier step rounding into the subnormal range would mean the product rounds to
+/// zero anyway.
+pub fn ldexp(x: f64, n: i32) -> f64 {
+    // 2^1023 and 2^-969. The second is 2^-1022 (the smallest normal power of 2) times 2^53,
+    // which leaves every remaining factor less than 2^-53.
+    const UP: f64 = 8.98846567431158e307;
+    const DOWN: f64 = 2.004168360008973e-292;
+
+    let mut x = x;
+    let mut n = n;
+    if n > 1023 {
+        x *= UP;
+        n -= 1023;
+        if n > 1023 {
+            x *= UP;
+            n = (n - 1023).min(1023);
+        }
+    } else if n < -1022 {
+        x *= DOWN;
+        n += 969;
+        if n < -1022 {
+            x *= DOWN;
+            n = (n + 969).max(-1022);
+        }
+    }
+    x * pow2(n)
+}
+
+/// Returns 2<sup>*n*</sup> for an *n* from -1022 to 1023, the powers of 2 that are normal
+/// `f64`s. Multiplying by one of these is exact unless the product is subnormal or
+/// overflows.
+#[inline]
+pub const fn pow2(n: i32) -> f64 {
+    f64::from_bits(((0x3ff + n) as u64) << 52)
+}
+
+/// Finds where a string starts and ends once leading and trailing whitespace are trimmed
+/// off, as byte offsets into the string.
+///
+/// This is what `str::trim` does, and whitespace means the same thing (Unicode's
+/// `White_Space` property), but it's a `const fn` so that strings can be parsed at compile
+/// time.
+pub const fn trim(s: &str) -> (usize, usize) {
+    let bytes = s.as_bytes();
+    let mut start = 0;
+    while start < bytes.len() {
+        let (ch, len) = decode(bytes, start);
+        if !ch.is_whitespace() {
+            break;
+        }
+        start += len;
+    }
+    let mut end = bytes.len();
+    while end > start {
+        // Back up over continuation bytes to the start of the last character
+        let mut i = end - 1;
+        while bytes[i] & 0xc0 == 0x80 {
+            i -= 1;
+        }
+        if !decode(bytes, i).0.is_whitespace() {
+            break;
+        }
+        end = i;
+    }
+    (start, end)
+}
+
+// Decodes the character whose first byte is at `i`, returning it and its length in bytes.
+// The bytes come from a `str`, so they're valid UTF-8.
+const fn decode(bytes: &[u8], i: usize) -> (char, usize) {
+    let b = bytes[i] as u32;
+    let (mut code, len) = if b < 0x80 {
+        (b, 1)
+    } else if b < 0xe0 {
+        (b & 0x1f, 2)
+    } else if b < 0xf0 {
+        (b & 0x0f, 3)
+    } else {
+        (b & 0x07, 4)
+    };
+    let mut j = 1;
+    while j < len {
+        code = (code << 6) | (bytes[i + j] as u32 & 0x3f);
+        j += 1;
+    }
+    match char::from_u32(code) {
+        Some(ch) => (ch, len),
+        None => (char::REPLACEMENT_CHARACTER, len),
+    }
+}
+
+/// Finds the smallest number with as many normalized components as `parts` that's larger
+/// than the finite number whose normalized components are `parts`. Returns its components
+/// along with the gap between the two numbers.
+///
+/// Every component is a multiple of 2<sup>-1074</sup>, the smallest subnormal `f64`, so the
+/// gap is too, and it's always a power of 2. If the last component is zero, the gap is
+/// that smallest subnormal, since it can take the place of the first zero component.
+/// Otherwise the last nonzero component has to change, and moving it up to the next `f64`
+/// (its ULP) usually works, even though it can carry into the components before it. Half
+/// of that can also work. That happens when the last component is a negative power of 2 or
+/// when a carry leaves the last component in a lower binade than it was in. So each of
+/// those gaps is tried, smallest first, and the first one that gives a sum whose nearest
+/// components are no more than `parts` has and are normalized is the one.
+///
+/// With more than two components, normalization breaks ties between components, and a
+/// carry can land on one that breaks the wrong way, so that there's no way to represent
+/// the sum. Then the gap is doubled until there is, or until the sum overflows. For the
+/// same reason, a number can have more than one set of normalized components (one might
+/// end in a half-ULP tie where another ends in zeros). Its nearest components are the set
+/// with the most room at the end, so the gaps are found from those when they're
+/// normalized.
+pub fn next_up(parts: &[f64]) -> (Vec<f64>, f64) {
+    let n = parts.len();
+    let base = match exact_components(parts, 0.0) {
+        Some(c) if is_normalized(&c) => c,
+        _ => parts.to_vec(),
+    };
+
+    if base[n - 1] == 0.0 {
+        if let Some(c) = exact_components(&base, f64::from_bits(1)) {
+            if is_normalized(&c) {
+                return (c, f64::from_bits(1));
+            }
+        }
+    }
+    let last = base
+        .iter()
+        .rev()
+        .find(|&&x| x != 0.0)
+        .map_or(0.0, |x| x.abs());
+    let mut gap = f64::from_bits(last.to_bits() + 1) - last;
+    if gap / 2.0 != 0.0 {
+        gap /= 2.0;
+    }
+    loop {
+        match exact_components(&base, gap) {
+            Some(c) if c[0].is_infinite() || is_normalized(&c) => return (c, gap),
+            _ => gap *= 2.0,
+        }
+    }
+}
+
+// The nearest components of the exact sum of `parts` and `x`, as many as `parts` has, or
+// `None` if those aren't enough to represent the sum exactly.
+fn exact_components(parts: &[f64], x: f64) -> Option<Vec<f64>> {
+    let mut sum = Partials::new();
+    parts.iter().for_each(|&p| sum.add(p));
+    sum.add(x);
+    let mut c = sum.components(parts.len() + 1);
+    if c.pop() == Some(0.0) {
+        Some(c)
     } else {
-        let len = f.to_string().len() - 2; // ignore the leading "0."
-        let base = 2f64.powi(-(len as i32));
-        f % base == 0.0
+        None
+    }
+}
+
+// Whether components are finite and normalized: each one added to the one before it leaves
+// that one unchanged, which also means that any zero components are last.
+fn is_normalized(parts: &[f64]) -> bool {
+    parts.iter().all(|x| x.is_finite()) && parts.windows(2).all(|w| w[0] + w[1] == w[0])
+}
+
+/// Distills a slice of `f64`s in place so that no two of them overlap.
+///
+/// This runs a cascade of `two_sum`s over the terms until a cascade no longer changes
+/// anything. Each cascade leaves the exact sum of the terms unchanged (it's made entirely
+/// of error-free transformations), but it concentrates that sum into the last term and
+/// shrinks the others. Once a cascade has no effect, each term is no more than half an ULP
+/// of the next and any zeros are at the front, so the last term is the sum rounded to an
+/// `f64` and has the same sign as the exact sum.
+///
+/// Every cascade but the last shrinks the off terms by about 50 bits, so with terms that
+/// come from double-double or quad-double values, only a handful are needed. The number is
+/// capped anyway, and this returns `false` if the cap was reached before the terms stopped
+/// changing.
+///
+/// It's written with `while` loops so that it can be a `const fn`, which lets numbers be
+/// made from their components at compile time.
+pub const fn distill(terms: &mut [f64]) -> bool {
+    let n = terms.len();
+    let mut pass = 0;
+    while pass < 4 * n {
+        let mut changed = false;
+        let mut i = 1;
+        while i < n {
+            let (s, e) = p::two_sum(terms[i], terms[i - 1]);
+            changed |= s != terms[i] || e != terms[i - 1];
+            terms[i] = s;
+            terms[i - 1] = e;
+            i += 1;
+        }
+        if !changed {
+            return true;
+        }
+        pass += 1;
+    }
+    false
+}
+
+/// Distills the exact sum of a slice of finite `f64`s into terms that don't overlap.
+///
+/// The terms come back largest last, as from [`distill`], so the last one is the sum
+/// rounded to an `f64` and the ones before it hold what's left over. Terms of widely
+/// different magnitudes can take many cascades to settle, but distillation always settles
+/// eventually, so unlike [`distill`] this doesn't give up.
+///
+/// Partial sums can overflow even when the whole sum doesn't. When they do, the terms are
+/// halved and distilled again, as many times as it takes, and the second value returned is
+/// the power of 2 that the distilled terms have to be multiplied by to get the sum back.
+/// Halving can drop the lowest bit of a subnormal term, which is far below the precision of
+/// any sum large enough to need it.
+pub fn distill_sum(parts: &[f64]) -> (Vec<f64>, f64) {
+    if parts.is_empty() {
+        return (vec![], 1.0);
+    }
+    let mut scale = 1.0;
+    loop {
+        let mut terms: Vec<f64> = parts.iter().map(|x| x / scale).collect();
+        loop {
+            let done = distill(&mut terms);
+            if !terms.iter().all(|x| x.is_finite()) {
+                // An overflow leaves NaNs behind, and those never stop changing
+                break;
+            }
+            if done {
+                return (terms, scale);
+            }
+        }
+        scale *= 2.0;
+    }
+}
+
+/// Calculates a rigorous upper bound on the absolute value of the exact sum of a slice of
+/// `f64`s.
+///
+/// The terms are first distilled in place with [`distill`], after which the sum of their
+/// absolute values is the absolute value of their sum to within a couple of ULPs, no matter
+/// how much cancellation there was between the original terms. That sum of absolute values
+/// is then inflated to cover the rounding error in its own calculation.
+///
+/// The bound is only as good as the terms. If they come from error-free products that
+/// could have underflowed, the caller has to account for that separately. If distillation
+/// doesn't finish, the result is still a valid bound, just a looser one.
+pub fn sum_bound(terms: &mut [f64]) -> f64 {
+    let n = terms.len();
+    distill(terms);
+    let sum = terms.iter().fold(0.0, |acc, t| acc + t.abs());
+    sum * (1.0 + (n as f64 + 2.0) * f64::EPSILON)
+}
+
+/// Calculates the remainder of the division of one positive number by another, with the
+/// quotient truncated toward zero, as an exact sum of `f64`s.
+///
+/// Both numbers are given as their components, largest first, and both have to be finite
+/// and positive. This works like long division. Each partial quotient is an integer of no
+/// more than about 41 bits times a power of two, so its product with each component of `b`
+/// is exactly the pair of `f64`s from an error-free product. Those pairs are subtracted by
+/// appending them to the terms of the remainder, which are distilled after every step.
+/// That keeps the remainder exact from start to finish, no matter how large the quotient
+/// is, unless some of the products underflow.
+///
+/// Each partial quotient is shaded down enough that it can't be larger than the exact one,
+/// so the remainder never goes negative. When the partial quotient comes out less than 1,
+/// the remainder is between 0 and a hair more than `b`, and one last exact comparison
+/// decides whether to subtract `b` once more.
+///
+/// The returned terms are distilled and in increasing order of magnitude, with zeros
+/// removed and then added back at the front until there are at least as many terms as `a`
+/// has components. The last term is the remainder rounded to an `f64`.
+pub fn rem_terms(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let mut terms: Vec<f64> = a.iter().rev().copied().collect();
+    loop {
+        distill(&mut terms);
+        terms.retain(|&t| t != 0.0);
+        let r = terms.last().copied().unwrap_or(0.0);
+
+        // Partial quotients are measured in units of 2^s times `b`, which keeps them small
+        // enough for their products to be exact. Scaling by 2^s is done in two halves so
+        // that the factors themselves never overflow.
+        let s = (exponent(r) - exponent(b[0]) - 40).max(0);
+        let (f, g) = (2f64.powi(s / 2), 2f64.powi(s - s / 2));
+        let q = (r / (b[0] * f * g) * (1.0 - 4.0 * f64::EPSILON)).floor();
+
+        if q < 1.0 {
+            let mut trial = terms.clone();
+            trial.extend(b.iter().rev().map(|&x| -x));
+            distill(&mut trial);
+            if trial.last().copied().unwrap_or(0.0) >= 0.0 {
+                terms = trial;
+                terms.retain(|&t| t != 0.0);
+            }
+            break;
+        }
+        for &x in b {
+            let (h, l) = p::two_prod(q, x * f * g);
+            terms.push(-h);
+            terms.push(-l);
+        }
+    }
+    while terms.len() < a.len() {
+        terms.insert(0, 0.0);
     }
+    terms
 }
@@ -15,6 +15,27 @@ mod macros {
     /// number that is not a `u128` or `i128`, and 2-tuples of any of those primitive number
     /// types.
     ///
+    /// The number can also be given as separate integer and fractional parts, with an
+    /// optional power-of-ten exponent, as `dd!(integer: 3, fraction: "14159")` or
+    /// `dd!(integer: 3, fraction: "14159", exponent: -2)`. These call [`from_parts`] and
+    /// produce exactly the same number as parsing `"3.14159"` or `"3.14159e-2"` would. Just
+    /// as with strings, [`NAN`] is produced if the fraction isn't made up of digits.
+    /// [`try_dd!`] returns the error instead, for strings and for parts alike.
+    ///
+    /// A literal is converted at compile time, so `dd!` can be used to define constants and
+    /// statics. That includes a string literal, which is parsed just as [`from_str`] would
+    /// parse it.
+    ///
+    /// A float literal is converted from its digits, just as the same digits in a string
+    /// would be, so `dd!(0.1)` is the `Double` nearest to 0.1 and `dd!(1e23)` is exactly
+    /// 10<sup>23</sup>, even though the `f64`s nearest to them are neither. Anywhere else,
+    /// though, the compiler rounds a float literal to an `f64`, which keeps no more than 15
+    /// significant digits (6 for an `f32`; a number too small to be normal keeps fewer, but
+    /// always the ones that printing it gives, like the single digit of `5e-324`). A
+    /// float literal with more digits than that is a compile error unless it's exactly equal
+    /// to its `f64`, rather than `dd!` being the one place where the rest of them count.
+    /// Those digits can be given in a string instead.
+    ///
     /// # Examples
     /// ```
     /// # use qd::{dd, Double};
@@ -24,38 +45,188 @@ mod macros {
     /// let expected = dd!("0.70710678118654752440084436210485");
     /// let diff = (x - expected).abs();
     /// assert!(diff < dd!(1e-30));
+    ///
+    /// assert!(dd!(integer: -2, fraction: "5", exponent: -3) == dd!("-2.5e-3"));
+    ///
+    /// const TAU_OVER_7: Double = dd!("0.8975979010256552108570956133638");
+    /// static TENTH: Double = dd!(0.1);
+    /// assert!(TENTH == dd!("0.1"));
+    /// assert!(dd!(1e23) == dd!("1e23"));
+    /// // dd!(3.14159265358979323846) doesn't compile
     /// ```
+    ///
+    /// [`from_parts`]: struct.Double.html#method.from_parts
+    /// [`from_str`]: struct.Double.html#method.from_str
+    /// [`NAN`]: struct.Double.html#associatedconstant.NAN
+    /// [`try_dd!`]: macro.try_dd.html
     #[macro_export]
     macro_rules! dd {
+        (integer: $i:expr, fraction: $f:expr) => {
+            $crate::Double::from_parts($i, $f, 0).unwrap_or($crate::Double::NAN)
+        };
+        (integer: $i:expr, fraction: $f:expr, exponent: $e:expr) => {
+            $crate::Double::from_parts($i, $f, $e).unwrap_or($crate::Double::NAN)
+        };
+        (@literal $($x:tt)+) => {{
+            const VALUE: $crate::Double = match $crate::Double::__from_literal(stringify!($($x)+)) {
+                ::core::result::Result::Ok(x) => x,
+                ::core::result::Result::Err($crate::LiteralError::Lossy) => panic!(
+                    "{}",
+                    concat!(
+                        "`dd!(",
+                        stringify!($($x)+),
+                        ")` has more significant digits than the float it's rounded to can ",
+                        "hold, and would lose them anywhere else; to keep them all, pass ",
+                        "them in a string literal instead"
+                    )
+                ),
+                ::core::result::Result::Err($crate::LiteralError::Invalid(reason)) => {
+                    panic!("{}", reason)
+                }
+            };
+            VALUE
+        }};
+        // A `-` followed by something other than a literal has to be caught here; the
+        // `literal` fragment would otherwise take the `-` and then fail to parse the rest
+        (- $x:literal) => {
+            $crate::dd!(@literal - $x)
+        };
+        (- $($x:tt)+) => {
+            $crate::Double::from(- $($x)+)
+        };
+        ($x:literal) => {
+            $crate::dd!(@literal $x)
+        };
         ($x:expr) => {
             $crate::Double::from($x)
         };
     }
+
+    /// Creates a double-double like [`dd!`] does, but returns a `Result` instead of turning a string
+    /// that can't be parsed into [`NAN`].
+    ///
+    /// The arguments are the same as for [`dd!`]. A string (whether a `&str`, a `String`, or
+    /// a `&String`) is parsed as [`from_str`] would parse it, and the result is
+    /// `Err` with the [`ParseDoubleError`] that [`from_str`] gives if it can't be. The same goes for
+    /// the integer and fractional parts given as `dd!(integer: ..., fraction: ...)`. Any
+    /// other number always converts, so it comes back in `Ok`. This makes `try_dd!` the one
+    /// to use on input that might not be a number, where NaN would be hard to tell from the
+    /// string `"nan"`.
+    ///
+    /// Literals are converted at compile time, just as they are by [`dd!`], and literals
+    /// that [`dd!`] won't accept don't compile here either.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, try_dd, Double};
+    /// # use qd::error::{ErrorKind, ParseDoubleError};
+    /// assert!(try_dd!("2.5") == Ok(dd!(2.5)));
+    /// assert!(try_dd!(3u64) == Ok(dd!(3)));
+    ///
+    /// let input = String::from("1.2x3");
+    /// let err = try_dd!(input).unwrap_err();
+    /// assert!(err.kind == ErrorKind::InvalidDigit { position: 3 });
+    ///
+    /// assert!(try_dd!(integer: 3, fraction: "14159") == Ok(dd!("3.14159")));
+    /// assert!(try_dd!(integer: 3, fraction: "1.4").is_err());
+    ///
+    /// const HALF: Result<Double, ParseDoubleError> = try_dd!("0.5");
+    /// assert!(HALF == Ok(dd!(0.5)));
+    /// ```
+    ///
+    /// [`dd!`]: macro.dd.html
+    /// [`from_str`]: struct.Double.html#method.from_str
+    /// [`ParseDoubleError`]: error/struct.ParseDoubleError.html
+    /// [`NAN`]: struct.Double.html#associatedconstant.NAN
+    #[macro_export]
+    macro_rules! try_dd {
+        (integer: $i:expr, fraction: $f:expr) => {
+            $crate::Double::from_parts($i, $f, 0)
+        };
+        (integer: $i:expr, fraction: $f:expr, exponent: $e:expr) => {
+            $crate::Double::from_parts($i, $f, $e)
+        };
+        (@literal $($x:tt)+) => {{
+            const VALUE: ::core::result::Result<$crate::Double, $crate::error::ParseDoubleError> =
+                match $crate::Double::__try_from_literal(stringify!($($x)+)) {
+                    ::core::result::Result::Ok(x) => x,
+                    ::core::result::Result::Err($crate::LiteralError::Lossy) => panic!(
+                        "{}",
+                        concat!(
+                            "`try_dd!(",
+                            stringify!($($x)+),
+                            ")` has more significant digits than the float it's rounded to ",
+                            "can hold, and would lose them anywhere else; to keep them all, ",
+                            "pass them in a string literal instead"
+                        )
+                    ),
+                    ::core::result::Result::Err($crate::LiteralError::Invalid(reason)) => {
+                        panic!("{}", reason)
+                    }
+                };
+            VALUE
+        }};
+        (- $x:literal) => {
+            $crate::try_dd!(@literal - $x)
+        };
+        (- $($x:tt)+) => {
+            $crate::TryInput::<$crate::Double>::try_input(- $($x)+)
+        };
+        ($x:literal) => {
+            $crate::try_dd!(@literal $x)
+        };
+        ($x:expr) => {
+            $crate::TryInput::<$crate::Double>::try_input($x)
+        };
+    }
 }
 
 #[cfg(test)]
 #[macro_use]
 mod test_macros;
 
+mod accumulator;
 mod aconsts; // "a" for "associated", or maybe just to make it appear first in docs
 mod add;
 mod alg;
+mod bessel;
+mod bounded;
+mod checked;
 mod common;
 mod comp;
+mod components;
 mod display;
 mod div;
+mod erf;
+mod fixed;
 mod from;
 mod from_str;
+mod gamma;
+mod hex;
 mod hyper;
 mod iter;
 mod misc;
 mod mul;
 mod neg;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+#[cfg(feature = "plotters")]
+mod plotters;
+mod poly;
+mod radix;
 mod rem;
+#[cfg(feature = "serde")]
+mod serde;
+mod slice;
+mod smooth;
 mod sub;
 mod trans;
 mod trig;
 
+pub use self::accumulator::DoubleAccumulator;
+#[cfg(feature = "plotters")]
+pub use self::plotters::DoubleRange;
+
 /// A 128-bit floating-point number implemented as the unevaluated sum of two 64-bit
 /// floating-point numbers. Discarding the bits used for exponents, this makes for about
 /// 106 bits of mantissa accuracy, or around 31 decimal digits.
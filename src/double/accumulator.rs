@@ -0,0 +1,301 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::accumulator::Partials;
+use crate::double::common as c;
+use crate::double::Double;
+use std::iter::FromIterator;
+
+/// An accumulator that sums a stream of `f64`s or `Double`s exactly, producing a `Double`.
+///
+/// This does what [`Double::from_sum`] does without needing the numbers in a slice. Every
+/// number pushed is added with a cascade of error-free sums, and every nonzero error term is
+/// kept, so the running sum is always exact. Only [`finish`] rounds, to the `Double` nearest
+/// the exact sum. The result therefore doesn't depend on the order of the numbers, on how
+/// much they cancel, or on how they were split up between accumulators that were later
+/// [`merge`]d, which makes accumulators suitable for parallel reductions.
+///
+/// The running sum takes no more memory than a few dozen `f64`s, however many numbers are
+/// pushed, and each push costs a pass over that sum. When the numbers are of similar
+/// magnitudes, that's only a few terms.
+///
+/// As with `f64` addition, the result is infinite if infinities of only one sign were
+/// pushed and NaN if a NaN or infinities of both signs were. A sum too large to represent
+/// is infinite even if partial sums were too, and a sum that comes back into range after
+/// its partial sums overflowed is still accurate.
+///
+/// # Examples
+/// ```
+/// # use qd::{Double, DoubleAccumulator};
+/// let mut acc = DoubleAccumulator::new();
+/// for _ in 0..1000 {
+///     acc.push(1e20);
+///     acc.push(0.1);
+///     acc.push(-1e20);
+/// }
+/// // Exactly 1000 times the f64 nearest 0.1
+/// assert!(acc.finish() == Double::from_sum(&[0.1; 1000]));
+/// ```
+///
+/// [`Double::from_sum`]: struct.Double.html#method.from_sum
+/// [`finish`]: #method.finish
+/// [`merge`]: #method.merge
+#[derive(Clone, Debug)]
+pub struct DoubleAccumulator(Partials);
+
+impl DoubleAccumulator {
+    /// Creates an accumulator whose sum is 0.
+    #[inline]
+    pub fn new() -> DoubleAccumulator {
+        DoubleAccumulator(Partials::new())
+    }
+
+    /// Adds an `f64` to the sum.
+    #[inline]
+    pub fn push(&mut self, x: f64) {
+        self.0.add(x);
+    }
+
+    /// Adds a `Double` to the sum.
+    ///
+    /// Each of its components is added exactly, so this is the same as pushing both
+    /// of them.
+    #[inline]
+    pub fn push_double(&mut self, x: Double) {
+        for &part in &[x.0, x.1] {
+            self.0.add(part);
+        }
+    }
+
+    /// Adds the sum of another accumulator to this one.
+    ///
+    /// This is exact, so splitting a stream of numbers up between accumulators and merging
+    /// them afterwards gives the same result as pushing every number onto one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::DoubleAccumulator;
+    /// let values: Vec<f64> = (1..=1000).map(|n| 1.0 / n as f64).collect();
+    ///
+    /// let mut whole = DoubleAccumulator::new();
+    /// values.iter().for_each(|&x| whole.push(x));
+    ///
+    /// let mut halves = [DoubleAccumulator::new(), DoubleAccumulator::new()];
+    /// for (i, &x) in values.iter().enumerate() {
+    ///     halves[i % 2].push(x);
+    /// }
+    /// let [mut a, b] = halves;
+    /// a.merge(b);
+    ///
+    /// assert!(a.finish() == whole.finish());
+    /// ```
+    #[inline]
+    pub fn merge(&mut self, other: DoubleAccumulator) {
+        self.0.merge(&other.0);
+    }
+
+    /// Returns the sum as the `Double` nearest to it.
+    ///
+    /// Each component is the `f64` nearest to what's left of the sum after the components
+    /// before it are subtracted. Since the sum is exact, the result depends only on which
+    /// numbers were pushed and not on their order.
+    #[inline]
+    pub fn finish(self) -> Double {
+        let parts = self.0.components(2);
+        c::from_components(parts[0], parts[1])
+    }
+}
+
+impl Default for DoubleAccumulator {
+    #[inline]
+    fn default() -> DoubleAccumulator {
+        DoubleAccumulator::new()
+    }
+}
+
+impl Extend<f64> for DoubleAccumulator {
+    #[inline]
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|x| self.push(x));
+    }
+}
+
+impl Extend<Double> for DoubleAccumulator {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Double>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|x| self.push_double(x));
+    }
+}
+
+impl FromIterator<f64> for Double {
+    /// Sums an iterator of `f64`s exactly, with a [`DoubleAccumulator`], and returns the
+    /// `Double` nearest to the sum.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let x: Double = vec![1e100, 1.0, -1e100, 1e-20].into_iter().collect();
+    /// assert!(x.to_array() == [1.0, 1e-20]);
+    /// ```
+    ///
+    /// [`DoubleAccumulator`]: struct.DoubleAccumulator.html
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Double {
+        let mut acc = DoubleAccumulator::new();
+        acc.extend(iter);
+        acc.finish()
+    }
+}
+
+impl FromIterator<Double> for Double {
+    /// Sums an iterator of `Double`s exactly, with a [`DoubleAccumulator`], and returns the
+    /// `Double` nearest to the sum.
+    ///
+    /// Unlike [`sum`], which adds the `Double`s one at a time and rounds after each one, this
+    /// only rounds once, at the end.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let big = dd!("1e30") / dd!(3);
+    /// let x: Double = vec![big, dd!(1) / dd!(7), -big].into_iter().collect();
+    /// assert!(x == dd!(1) / dd!(7));
+    /// ```
+    ///
+    /// [`DoubleAccumulator`]: struct.DoubleAccumulator.html
+    /// [`sum`]: #impl-Sum%3CDouble%3E
+    fn from_iter<I: IntoIterator<Item = Double>>(iter: I) -> Double {
+        let mut acc = DoubleAccumulator::new();
+        acc.extend(iter);
+        acc.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::random::Rng;
+
+    fn same(a: Double, b: Double) -> bool {
+        a.to_bits() == b.to_bits()
+    }
+
+    test!(residuals: {
+        // Alternating +1e16 and -1e16, each with a small residual that's lost when it's
+        // added to 1e16 in f64 arithmetic. The residuals are multiples of 2^-40, so their
+        // exact sum is easy to find.
+        let mut acc = DoubleAccumulator::new();
+        let mut expected = 0u64;
+        for i in 0..10_000_000u64 {
+            let big = if i % 2 == 0 { 1e16 } else { -1e16 };
+            let residual = (i % 1000 + 1) as f64 * 2f64.powi(-40);
+            acc.push(big);
+            acc.push(residual);
+            expected += i % 1000 + 1;
+        }
+        let sum = acc.finish();
+        assert!(same(sum, Double::new(expected as f64 * 2f64.powi(-40), 0.0)));
+    });
+
+    test!(random_residuals: {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        let mut acc = DoubleAccumulator::new();
+        let mut residuals = vec![];
+        for i in 0..100_000 {
+            let big = if i % 2 == 0 { 1e16 } else { -1e16 };
+            let residual = rng.next_f64() * 1e-10;
+            acc.push(big);
+            acc.push(residual);
+            residuals.push(residual);
+        }
+        assert!(same(acc.finish(), Double::from_sum(&residuals)));
+    });
+
+    test!(merge: {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        let values: Vec<f64> = (0..100_000)
+            .map(|_| {
+                let n = rng.next_u64();
+                let x = (n >> 11) as f64 * 2f64.powi(-53);
+                let exp = (n % 200) as i32 - 100;
+                if n & 1 == 0 { x * 2f64.powi(exp) } else { -x * 2f64.powi(exp) }
+            })
+            .collect();
+
+        let mut whole = DoubleAccumulator::new();
+        whole.extend(values.iter().copied());
+        let expected = whole.finish();
+        // `from_sum` can be off by one ULP in its last component
+        assert!((expected - Double::from_sum(&values)).abs() <= expected.abs() * dd!(1e-31));
+
+        let mut parts: Vec<DoubleAccumulator> = (0..7).map(|_| DoubleAccumulator::new()).collect();
+        for (i, &x) in values.iter().enumerate() {
+            parts[i * 31 % 7].push(x);
+        }
+        let mut merged = DoubleAccumulator::new();
+        parts.into_iter().rev().for_each(|p| merged.merge(p));
+        assert!(same(merged.finish(), expected));
+
+        let reversed: Double = values.iter().rev().copied().collect();
+        assert!(same(reversed, expected));
+    });
+
+    test!(push_double: {
+        let mut acc = DoubleAccumulator::new();
+        acc.push_double(Double::PI);
+        acc.push_double(Double::E);
+        acc.push_double(-Double::PI);
+        assert!(same(acc.finish(), Double::E));
+
+        let x: Double = vec![Double::PI, dd!(1e300), Double::E, dd!(-1e300), -Double::PI]
+            .into_iter()
+            .collect();
+        assert!(same(x, Double::E));
+    });
+
+    test!(overflow: {
+        let x: Double = vec![f64::MAX, f64::MAX, 1.0, -f64::MAX].into_iter().collect();
+        assert!(same(x, Double::from_sum(&[f64::MAX, 1.0])));
+
+        let mut a = DoubleAccumulator::new();
+        a.extend(vec![f64::MAX, f64::MAX]);
+        let mut b = DoubleAccumulator::new();
+        b.extend(vec![-f64::MAX, 1e-300]);
+        a.merge(b.clone());
+        a.merge(b);
+        assert!(same(a.finish(), Double::new(2e-300, 0.0)));
+
+        let y: Double = vec![f64::MAX, f64::MAX, 1.0].into_iter().collect();
+        assert!(y == Double::INFINITY);
+        let z: Double = vec![-f64::MAX, -f64::MAX].into_iter().collect();
+        assert!(z == Double::NEG_INFINITY);
+    });
+
+    test!(special: {
+        let x: Double = vec![1.0, f64::INFINITY, 2.0].into_iter().collect();
+        assert!(x == Double::INFINITY);
+        let y: Double = vec![f64::NEG_INFINITY, 1.0].into_iter().collect();
+        assert!(y == Double::NEG_INFINITY);
+        let z: Double = vec![f64::INFINITY, f64::NEG_INFINITY].into_iter().collect();
+        assert!(z.is_nan());
+        let w: Double = vec![1.0, f64::NAN].into_iter().collect();
+        assert!(w.is_nan());
+
+        let mut a = DoubleAccumulator::new();
+        a.push(f64::INFINITY);
+        let mut b = DoubleAccumulator::new();
+        b.push(f64::NEG_INFINITY);
+        a.merge(b);
+        assert!(a.finish().is_nan());
+    });
+
+    test!(zeros: {
+        assert!(same(DoubleAccumulator::new().finish(), Double::ZERO));
+        assert!(same(Vec::<f64>::new().into_iter().collect::<Double>(), Double::ZERO));
+        let x: Double = vec![1.0, -1.0].into_iter().collect();
+        assert!(x.is_zero());
+        let y: Double = vec![-0.0, -0.0].into_iter().collect();
+        assert!(y.is_zero() && y.is_sign_negative());
+    });
+}
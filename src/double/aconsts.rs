@@ -17,20 +17,84 @@ impl Double {
     /// Approximate number of significant digits in base 10.
     pub const DIGITS: u32 = 31;
 
-    /// [Machine epsilon] value for `Double`.
+    /// [Machine epsilon] value for `Double`, 2<sup>-105</sup>.
     ///
-    /// This is the difference between `1.0` and the next largest representable number.
+    /// This is the difference between `1.0` and the next larger number with
+    /// [`MANTISSA_DIGITS`] significant bits, so it's the relative spacing of `Double`s at
+    /// full precision, just as `f64::EPSILON` is for `f64`s.
+    ///
+    /// Unlike with `f64`, it isn't the gap to the very next representable number. A `Double`
+    /// is the sum of two components, which can be much farther apart than 53 bits, so
+    /// `1.0` plus half of `EPSILON` is a `Double` too, and so is `Double(1.0, 1e-300)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!((Double::ONE + Double::EPSILON) - Double::ONE == Double::EPSILON);
+    /// assert!(Double::ONE + Double::EPSILON / dd!(2) > Double::ONE);
+    /// assert!(Double::EPSILON == dd!(2).powi(1 - Double::MANTISSA_DIGITS as i32));
+    /// ```
     ///
     /// [Machine epsilon]: https://en.wikipedia.org/wiki/Machine_epsilon
-    pub const EPSILON: Double = Double(4.93038065763132e-32, 0.0); // 2^-104
+    /// [`MANTISSA_DIGITS`]: #associatedconstant.MANTISSA_DIGITS
+    pub const EPSILON: Double = Double(2.465190328815662e-32, 0.0); // 2^-105
 
-    /// Smallest finite `Double` value.
+    /// Smallest finite `Double` value, the negative of [`MAX`].
+    ///
+    /// [`MAX`]: #associatedconstant.MAX
     pub const MIN: Double = Double(-1.7976931348623157e308, -9.979201547673598e291);
 
-    /// Smallest positive normal `Double` value.
+    /// Smallest positive normal `Double` value, 2<sup>-1022</sup>.
+    ///
+    /// This is the same as `f64::MIN_POSITIVE`, and like it, it's where [`classify`] stops
+    /// calling numbers normal. A `Double` this small has only its first component, though,
+    /// since anything that could go in the second would be below the smallest subnormal
+    /// `f64`. Numbers have all [`MANTISSA_DIGITS`] of their bits from
+    /// [`MIN_POSITIVE_FULL_PRECISION`] on up.
+    ///
+    /// [`classify`]: #method.classify
+    /// [`MANTISSA_DIGITS`]: #associatedconstant.MANTISSA_DIGITS
+    /// [`MIN_POSITIVE_FULL_PRECISION`]: #associatedconstant.MIN_POSITIVE_FULL_PRECISION
     pub const MIN_POSITIVE: Double = Double(2.2250738585072014e-308, 0e0);
 
+    /// Smallest positive `Double` value with full precision, 2<sup>-969</sup>.
+    ///
+    /// Below this, the last of a number's [`MANTISSA_DIGITS`] bits would be smaller than the
+    /// smallest subnormal `f64`, so the second component can't hold all of them. Numbers
+    /// from here down to [`MIN_POSITIVE`] are still normal, but they have fewer significant
+    /// bits the smaller they are.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::MIN_POSITIVE_FULL_PRECISION;
+    /// assert!(x * (Double::ONE + Double::EPSILON) - x == x * Double::EPSILON);
+    ///
+    /// // Half as large, the last bit is lost
+    /// let y = x / dd!(2);
+    /// assert!(y * (Double::ONE + Double::EPSILON) - y == Double::ZERO);
+    /// ```
+    ///
+    /// [`MANTISSA_DIGITS`]: #associatedconstant.MANTISSA_DIGITS
+    /// [`MIN_POSITIVE`]: #associatedconstant.MIN_POSITIVE
+    pub const MIN_POSITIVE_FULL_PRECISION: Double = Double(2.004168360008973e-292, 0.0); // 2^-969
+
     /// Largest finite `Double` value.
+    ///
+    /// The first component is `f64::MAX`, and the second is the largest `f64` that's less
+    /// than half of the first's ULP, since half an ULP would round the sum up to infinity.
+    /// That leaves only about 2<sup>917</sup> between `MAX` and the numbers that round to
+    /// infinity, so adding a number much smaller than that leaves `MAX` unchanged, and adding
+    /// one much larger overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::MAX[0] == f64::MAX);
+    /// assert!(Double::MAX + dd!(1e275) == Double::MAX);
+    /// assert!(Double::MAX + dd!(1e277) == Double::INFINITY);
+    /// assert!(Double::MAX.next_up() == Double::INFINITY);
+    /// ```
     pub const MAX: Double = Double(1.7976931348623157e308, 9.979201547673598e291);
 
     /// One greater than the minimum possible normal power of 2 exponent.
@@ -45,6 +109,12 @@ impl Double {
     /// Maximum possible power of 10 exponent.
     pub const MAX_10_EXP: i32 = 308;
 
+    /// Largest integer `n` such that `n` and every integer between `-n` and `n` can be
+    /// represented exactly by a `Double` (2<sup>106</sup>).
+    ///
+    /// Some larger integers can be represented exactly as well, but not all of them.
+    pub const MAX_SAFE_INTEGER: Double = Double(8.112963841460668e31, 0.0); // 2^106
+
     /// Not a Number (NaN).
     pub const NAN: Double = Double(f64::NAN, 0.0);
 
@@ -70,40 +140,40 @@ impl Double {
     pub const PI: Double = Double(3.141592653589793e0, 1.2246467991473532e-16);
 
     /// The full circle constant (τ), or 2π
-    pub const TAU: Double = Double(6.283185307179586e0, 2.449293598294704e-16);
+    pub const TAU: Double = Double(6.283185307179586e0, 2.4492935982947064e-16);
 
     /// π/2
-    pub const FRAC_PI_2: Double = Double(1.5707963267948966e0, 6.12323399573676e-17);
+    pub const FRAC_PI_2: Double = Double(1.5707963267948966e0, 6.123233995736766e-17);
 
     /// π/3
-    pub const FRAC_PI_3: Double = Double(1.0471975511965979e0, -1.0720817664510912e-16);
+    pub const FRAC_PI_3: Double = Double(1.0471975511965979e0, -1.072081766451091e-16);
 
     /// π/4
-    pub const FRAC_PI_4: Double = Double(7.853981633974483e-1, 3.0616169978683836e-17);
+    pub const FRAC_PI_4: Double = Double(7.853981633974483e-1, 3.061616997868383e-17);
 
     /// π/6
     pub const FRAC_PI_6: Double = Double(5.235987755982989e-1, -5.360408832255455e-17);
 
     /// π/8
-    pub const FRAC_PI_8: Double = Double(3.9269908169872414e-1, 1.5308084989341906e-17);
+    pub const FRAC_PI_8: Double = Double(3.9269908169872414e-1, 1.5308084989341915e-17);
 
     /// π/16
-    pub const FRAC_PI_16: Double = Double(1.9634954084936207e-1, 7.654042494670953e-18);
+    pub const FRAC_PI_16: Double = Double(1.9634954084936207e-1, 7.654042494670958e-18);
 
     /// 3π/2
-    pub const FRAC_3_PI_2: Double = Double(4.71238898038469e0, 1.8369701987210292e-16);
+    pub const FRAC_3_PI_2: Double = Double(4.71238898038469e0, 1.8369701987210297e-16);
 
     /// 3π/4
-    pub const FRAC_3_PI_4: Double = Double(2.356194490192345e0, 9.184850993605146e-17);
+    pub const FRAC_3_PI_4: Double = Double(2.356194490192345e0, 9.184850993605148e-17);
 
     /// 5π/4
-    pub const FRAC_5_PI_4: Double = Double(3.9269908169872414e0, 1.5308084989341908e-16);
+    pub const FRAC_5_PI_4: Double = Double(3.9269908169872414e0, 1.5308084989341916e-16);
 
     /// 7π/4
-    pub const FRAC_7_PI_4: Double = Double(5.497787143782138e0, 2.143131898507869e-16);
+    pub const FRAC_7_PI_4: Double = Double(5.497787143782138e0, 2.143131898507868e-16);
 
     /// 1/π
-    pub const FRAC_1_PI: Double = Double(3.183098861837907e-1, -1.967867667518248e-17);
+    pub const FRAC_1_PI: Double = Double(3.183098861837907e-1, -1.9678676675182486e-17);
 
     /// 2/π
     pub const FRAC_2_PI: Double = Double(6.366197723675814e-1, -3.935735335036497e-17);
@@ -112,29 +182,188 @@ impl Double {
     pub const FRAC_2_SQRT_PI: Double = Double(1.1283791670955126e0, 1.533545961316588e-17);
 
     /// √2
-    pub const SQRT_2: Double = Double(1.4142135623730951e0, -9.667293313452915e-17);
+    pub const SQRT_2: Double = Double(1.4142135623730951e0, -9.667293313452913e-17);
 
     /// 1/√2
     pub const FRAC_1_SQRT_2: Double = Double(7.071067811865476e-1, -4.833646656726457e-17);
 
+    /// √3
+    pub const SQRT_3: Double = Double(1.7320508075688772e0, 1.0035084221806903e-16);
+
+    /// 1/√3
+    pub const FRAC_1_SQRT_3: Double = Double(5.773502691896257e-1, 3.3450280739356345e-17);
+
     /// Euler's number (*e*)
-    pub const E: Double = Double(2.718281828459045e0, 1.4456468917292497e-16);
+    pub const E: Double = Double(2.718281828459045e0, 1.4456468917292502e-16);
 
     /// log<sub>2</sub> 10
-    pub const LOG2_10: Double = Double(3.321928094887362e0, 1.6616175169735918e-16);
+    pub const LOG2_10: Double = Double(3.321928094887362e0, 1.661617516973592e-16);
 
     /// log<sub>2</sub> *e*
-    pub const LOG2_E: Double = Double(1.4426950408889634e0, 2.035527374093102e-17);
+    pub const LOG2_E: Double = Double(1.4426950408889634e0, 2.0355273740931033e-17);
 
     /// log<sub>10</sub> 2
-    pub const LOG10_2: Double = Double(3.010299956639812e-1, -2.8037281277851654e-18);
+    pub const LOG10_2: Double = Double(3.010299956639812e-1, -2.8037281277851704e-18);
 
     /// log<sub>10</sub> *e*
-    pub const LOG10_E: Double = Double(4.342944819032518e-1, 1.0983196502167654e-17);
+    pub const LOG10_E: Double = Double(4.342944819032518e-1, 1.098319650216765e-17);
 
     /// log<sub>*e*</sub> 2
-    pub const LN_2: Double = Double(6.931471805599453e-1, 2.319046813846301e-17);
+    pub const LN_2: Double = Double(6.931471805599453e-1, 2.3190468138462996e-17);
 
     /// log<sub>*e*</sub> 10
-    pub const LN_10: Double = Double(2.302585092994046e0, -2.1707562233822496e-16);
+    pub const LN_10: Double = Double(2.302585092994046e0, -2.1707562233822494e-16);
+
+    /// The golden ratio (φ)
+    pub const PHI: Double = Double(1.618033988749895e0, -5.432115203682506e-17);
+
+    /// The Euler-Mascheroni constant (γ)
+    pub const EULER_GAMMA: Double = Double(5.772156649015329e-1, -4.942915152430645e-18);
+
+    /// Catalan's constant (*G*)
+    pub const CATALAN: Double = Double(9.15965594177219e-1, 3.747558421514984e-18);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::reference::references;
+    use crate::double::common as c;
+
+    // Each component has to be no more than half a unit in the last place of the one
+    // before it, or the constant isn't the sum that it's supposed to be
+    fn normalized(x: Double) -> bool {
+        let c = x.to_array();
+        c.windows(2).all(|w| w[0] + w[1] == w[0])
+    }
+
+    // Every mathematical constant, exactly as it should be rounded
+    test!(constants_match_references: {
+        let refs = references();
+        for &(name, x) in &[
+            ("PI", Double::PI),
+            ("TAU", Double::TAU),
+            ("FRAC_PI_2", Double::FRAC_PI_2),
+            ("FRAC_PI_3", Double::FRAC_PI_3),
+            ("FRAC_PI_4", Double::FRAC_PI_4),
+            ("FRAC_PI_6", Double::FRAC_PI_6),
+            ("FRAC_PI_8", Double::FRAC_PI_8),
+            ("FRAC_PI_16", Double::FRAC_PI_16),
+            ("FRAC_3_PI_2", Double::FRAC_3_PI_2),
+            ("FRAC_3_PI_4", Double::FRAC_3_PI_4),
+            ("FRAC_5_PI_4", Double::FRAC_5_PI_4),
+            ("FRAC_7_PI_4", Double::FRAC_7_PI_4),
+            ("FRAC_1_PI", Double::FRAC_1_PI),
+            ("FRAC_2_PI", Double::FRAC_2_PI),
+            ("FRAC_2_SQRT_PI", Double::FRAC_2_SQRT_PI),
+            ("SQRT_2", Double::SQRT_2),
+            ("FRAC_1_SQRT_2", Double::FRAC_1_SQRT_2),
+            ("SQRT_3", Double::SQRT_3),
+            ("FRAC_1_SQRT_3", Double::FRAC_1_SQRT_3),
+            ("E", Double::E),
+            ("LOG2_10", Double::LOG2_10),
+            ("LOG2_E", Double::LOG2_E),
+            ("LOG10_2", Double::LOG10_2),
+            ("LOG10_E", Double::LOG10_E),
+            ("LN_2", Double::LN_2),
+            ("LN_10", Double::LN_10),
+            ("PHI", Double::PHI),
+            ("EULER_GAMMA", Double::EULER_GAMMA),
+            ("CATALAN", Double::CATALAN),
+        ] {
+            c::assert_reference(&refs, name, x);
+        }
+    });
+
+    // Parsing can be off in the last bit, so these are checked to full precision rather
+    // than exactly
+    test_all_near!(
+        frac_pi_8:
+            dd!("0.392699081698724154807830422909937860524646174921888227621868074038477051"),
+            Double::FRAC_PI_8;
+        frac_1_pi:
+            dd!("0.318309886183790671537767526745028724068919291480912897495334688117793595"),
+            Double::FRAC_1_PI;
+        frac_2_pi:
+            dd!("0.636619772367581343075535053490057448137838582961825794990669376235587191"),
+            Double::FRAC_2_PI;
+        frac_2_sqrt_pi:
+            dd!("1.12837916709551257389615890312154517168810125865799771368817144342128494"),
+            Double::FRAC_2_SQRT_PI;
+        sqrt_3:
+            dd!("1.73205080756887729352744634150587236694280525381038062805580697945193302"),
+            Double::SQRT_3;
+        frac_1_sqrt_3:
+            dd!("0.577350269189625764509148780501957455647601751270126876018602326483977672"),
+            Double::FRAC_1_SQRT_3;
+        phi:
+            dd!("1.61803398874989484820458683436563811772030917980576286213544862270526046"),
+            Double::PHI;
+        euler_gamma:
+            dd!("0.577215664901532860606512090082402431042159335939923598805767234884867727"),
+            Double::EULER_GAMMA;
+        catalan:
+            dd!("0.915965594177219015054603514932384110774149374281672134266498119621763020"),
+            Double::CATALAN;
+    );
+    test_all_assert!(
+        frac_pi_8_normalized:
+            normalized(Double::FRAC_PI_8);
+        frac_1_pi_normalized:
+            normalized(Double::FRAC_1_PI);
+        frac_2_pi_normalized:
+            normalized(Double::FRAC_2_PI);
+        frac_2_sqrt_pi_normalized:
+            normalized(Double::FRAC_2_SQRT_PI);
+        sqrt_3_normalized:
+            normalized(Double::SQRT_3);
+        frac_1_sqrt_3_normalized:
+            normalized(Double::FRAC_1_SQRT_3);
+        phi_normalized:
+            normalized(Double::PHI);
+        euler_gamma_normalized:
+            normalized(Double::EULER_GAMMA);
+        catalan_normalized:
+            normalized(Double::CATALAN);
+    );
+
+    // Half of the ULP of a positive f64 that isn't a power of 2 (measured downward, since
+    // f64::MAX has nothing above it)
+    fn half_ulp(x: f64) -> f64 {
+        (x - f64::from_bits(x.to_bits() - 1)) / 2.0
+    }
+
+    test_all_assert!(
+        max_normalized:
+            normalized(Double::MAX) && Double::MAX.is_normalized();
+        max_components:
+            Double::MAX[0] == f64::MAX
+                && Double::MAX.to_array().windows(2).all(|w| {
+                    w[1] < half_ulp(w[0]) && f64::from_bits(w[1].to_bits() + 1) == half_ulp(w[0])
+                });
+        max_next_up:
+            Double::MAX.next_up() == Double::INFINITY;
+        max_absorbs:
+            Double::MAX + dd!(1e275) == Double::MAX && Double::MAX - dd!(1e277) < Double::MAX;
+        max_overflows:
+            Double::MAX + dd!(1e277) == Double::INFINITY;
+        min_is_neg_max:
+            Double::MIN == -Double::MAX && Double::MIN.next_down() == Double::NEG_INFINITY;
+        epsilon_round_trip:
+            (Double::ONE + Double::EPSILON) - Double::ONE == Double::EPSILON;
+        epsilon_mantissa:
+            Double::EPSILON == dd!(2).powi(1 - Double::MANTISSA_DIGITS as i32)
+                && (Double::ONE + Double::EPSILON).next_down() > Double::ONE;
+        min_positive_normal:
+            Double::MIN_POSITIVE == Double::from(f64::MIN_POSITIVE)
+                && Double::MIN_POSITIVE.is_normal()
+                && (Double::MIN_POSITIVE / dd!(2)).is_subnormal();
+        min_positive_full_precision:
+            {
+                let x = Double::MIN_POSITIVE_FULL_PRECISION;
+                let y = x / dd!(2);
+                x * (Double::ONE + Double::EPSILON) - x == x * Double::EPSILON
+                    && y * (Double::ONE + Double::EPSILON) == y
+            };
+    );
 }
@@ -4,7 +4,9 @@
 // https://opensource.org/licenses/MIT
 
 use crate::common::primitive as p;
+use crate::common::strict as s;
 use crate::common::utils as u;
+use crate::double::common as c;
 use crate::double::Double;
 use std::ops::{Add, AddAssign};
 
@@ -26,16 +28,7 @@ impl Add for Double {
     /// assert!(diff < dd!(1e-30));
     /// ```
     fn add(self, other: Double) -> Double {
-        match self.pre_add(&other) {
-            Some(r) => r,
-            None => {
-                let (s0, e0) = p::two_sum(self.0, other.0);
-                let (s1, e1) = p::two_sum(self.1, other.1);
-                let (s2, e2) = p::quick_two_sum(s0, s1 + e0);
-                let (a, b) = u::renorm2(s2, e1 + e2);
-                Double(a, b)
-            }
-        }
+        s::guard("Double + Double", &[self.0, other.0], self.add_inner(other))
     }
 }
 
@@ -155,6 +148,49 @@ impl AddAssign<&Double> for Double {
 }
 
 impl Double {
+    /// Adds another `Double` to this one. This is the `+` operator as a `const fn`, so it
+    /// can be used to compute constants and `static` tables at compile time, which trait
+    /// methods like `Add::add` can't yet be.
+    ///
+    /// The result is bit for bit the same as that of `+`. Constant evaluation does `f64`
+    /// arithmetic exactly the way that the processor does at runtime, with the same
+    /// rounding to nearest and the same fused multiply-add, so the same operations give the
+    /// same answer either way. The only difference is that with the `strict` feature, a
+    /// non-finite result isn't reported.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// const TWO_PI: Double = Double::PI.add_const(Double::PI);
+    /// assert!(TWO_PI == Double::PI + Double::PI);
+    /// assert!(TWO_PI == Double::TAU);
+    /// ```
+    pub const fn add_const(self, other: Double) -> Double {
+        self.add_inner(other)
+    }
+
+    // The `+` operator without the strict-mode check. Parsing uses this (and the other
+    // `_inner` operations) so that it can be done in a `const fn`.
+    pub(super) const fn add_inner(self, other: Double) -> Double {
+        match self.pre_add(&other) {
+            Some(r) => r,
+            None => {
+                let (s0, e0) = p::two_sum(self.0, other.0);
+                let (s1, e1) = p::two_sum(self.1, other.1);
+                let (s2, e2) = p::quick_two_sum(s0, s1 + e0);
+                let (a, b) = u::renorm2(s2, e1 + e2);
+                if a.is_finite() {
+                    Double(a, b)
+                } else {
+                    c::redo_halved!(
+                        0.5 * self.0 + 0.5 * other.0,
+                        c::mul_pwr2(self, 0.5).add_inner(c::mul_pwr2(other, 0.5))
+                    )
+                }
+            }
+        }
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -165,7 +201,7 @@ impl Double {
     // rather than calculation.
 
     #[inline]
-    fn pre_add(&self, other: &Double) -> Option<Double> {
+    const fn pre_add(&self, other: &Double) -> Option<Double> {
         if self.is_nan() || other.is_nan() {
             Some(Double::NAN)
         } else if self.is_infinite() {
@@ -192,6 +228,13 @@ impl Double {
             } else {
                 Some(Double::NEG_INFINITY)
             }
+        } else if self.is_zero() && other.is_zero() {
+            // Zeros only add to a negative zero when both are negative
+            if self.is_sign_negative() && other.is_sign_negative() {
+                Some(Double::NEG_ZERO)
+            } else {
+                Some(Double::ZERO)
+            }
         } else {
             None
         }
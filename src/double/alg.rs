@@ -4,10 +4,37 @@
 // https://opensource.org/licenses/MIT
 
 use crate::common::primitive as p;
+use crate::common::strict as s;
 use crate::common::utils as u;
 use crate::double::common as c;
 use crate::double::Double;
 
+// 2^512. `sqrt` and `recip_sqrt` take a power of 2 out of arguments larger than this or
+// smaller than its reciprocal before doing anything else.
+const SCALE_ABOVE: f64 = 1.3407807929942597e154;
+
+// Once a square in `powi` is larger than 2^this or smaller than 2^-this, the result can't
+// be represented.
+const POW_LIMIT: i64 = 1100;
+
+// `hypot` squares its arguments as they are when the larger one is within 2^this of 1, and
+// scales them first otherwise. Either way the squares neither overflow nor lose precision
+// to the subnormal range.
+const HYPOT_DIRECT: i32 = 400;
+
+// When the larger argument of `hypot` is more than 2^this times the smaller, the square of
+// the smaller is too small to affect the result, which is just the larger.
+const HYPOT_NEGLIGIBLE: i32 = 60;
+
+// `agm` takes the geometric mean as √a·√b while its arguments are more than 2^this apart,
+// and as √(ab) once they're close enough that scaling them both toward 1 keeps ab and
+// every component of the arguments normal.
+const AGM_DIRECT: i32 = 1000;
+
+// The most iterations that `agm` runs once its arguments are within `AGM_DIRECT` of each
+// other. Convergence is quadratic, so this is far more than it needs.
+const AGM_MAX_ITER: usize = 20;
+
 impl Double {
     /// Calculates x · 2<sup>n</sup>, where *x* is the `Double` and *n* is an integer.
     ///
@@ -16,25 +43,106 @@ impl Double {
     /// implementation that is much more efficient than calculating it through
     /// multiplication and [`powi`] is offered despite it not being part of the `f64` API.
     ///
+    /// Each component is multiplied by 2<sup>n</sup> exactly, for any *n*, so the result
+    /// is exact unless it's too large or too small to represent. For *n* from -1022 to
+    /// 1023, that's a single `f64` multiplication per component. A result too large is
+    /// infinite, and one too small is zero, with the sign of the `Double`. A component that
+    /// ends up in the subnormal range is rounded, which loses only the bits of it that are
+    /// below the smallest subnormal; that is as close as a `Double` can get.
+    ///
     /// # Examples
     /// ```
-    /// # use qd::dd;
+    /// # use qd::{dd, Double};
     /// let x = dd!(5);
     /// assert!(x.ldexp(3) == dd!(40)); // 5 * 2^3
+    ///
+    /// // Powers of 2 that an `f64` can't hold are fine as long as the result fits
+    /// let tiny = dd!(3).ldexp(-1074);
+    /// assert!(tiny.ldexp(1074) == dd!(3));
+    /// assert!(dd!(1).ldexp(1024) == Double::INFINITY);
     /// ```
     ///
     /// [`powi`]: #method.powi
     #[inline]
     pub fn ldexp(self, n: i32) -> Double {
-        let factor = 2f64.powi(n);
-        Double(self.0 * factor, self.1 * factor)
+        // When 2^n is an `f64` itself, which it is for every n that leaves a `Double` of
+        // ordinary size within range, each component takes only a single multiplication
+        let r = if (-1022..=1023).contains(&n) {
+            c::mul_pwr2(self, u::pow2(n))
+        } else {
+            Double(u::ldexp(self.0, n), u::ldexp(self.1, n))
+        };
+        // Every other component is smaller than the first, so when the first stays finite
+        // they do too. Normalization keeps each one within half an ULP of the one before
+        // it, which also holds after they're scaled and rounded, so they stay normalized.
+        if r.0.is_finite() {
+            r
+        } else {
+            Double(r.0, 0.0)
+        }
+    }
+
+    /// Breaks the `Double` into a mantissa and a power of 2, the inverse of [`ldexp`].
+    ///
+    /// The exponent *e* is chosen so that the mantissa *m* is at least 0.5 and less than 1
+    /// in absolute value (the mantissa has the sign of the `Double`). That's usually the
+    /// exponent that puts the first component of *m* in that range too, but when the first
+    /// component of the `Double` is a power of 2 and the rest of it has the opposite sign,
+    /// the `Double` is a little smaller than that power of 2, so *e* is one less and the
+    /// first component of *m* is ±1. Then `m.ldexp(e)` is exactly the original `Double`, as
+    /// long as none of its nonzero components is more than 2<sup>1021</sup> times smaller
+    /// than the first. Otherwise the mantissa would need that component to be smaller than
+    /// the smallest subnormal, which only happens to a `Double` that's put together from
+    /// components directly.
+    ///
+    /// Zero is returned as it is with an exponent of 0, as are infinity and NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let (m, e) = dd!(40).frexp();
+    /// assert!(m == dd!(0.625) && e == 6); // 0.625 * 2^6
+    ///
+    /// let (m, e) = (-Double::PI).frexp();
+    /// assert!(m == -Double::PI / dd!(4) && e == 2);
+    /// assert!(m.ldexp(e) == -Double::PI);
+    ///
+    /// // A little less than 1, so the mantissa is too
+    /// let (m, e) = Double::new(1.0, -2f64.powi(-60)).frexp();
+    /// assert!(m == Double::new(1.0, -2f64.powi(-60)) && e == 0);
+    ///
+    /// assert!(Double::ZERO.frexp() == (Double::ZERO, 0));
+    /// assert!(Double::NEG_INFINITY.frexp() == (Double::NEG_INFINITY, 0));
+    /// ```
+    ///
+    /// [`ldexp`]: #method.ldexp
+    #[inline]
+    pub fn frexp(self) -> (Double, i32) {
+        if self.0 == 0.0 || !self.0.is_finite() {
+            (self, 0)
+        } else {
+            let mut e = u::exponent(self.0) + 1;
+            // A first component of exactly 2^(e - 1) with a tail of the opposite sign is a
+            // number just below 2^(e - 1), whose mantissa is just below 1 at the exponent
+            // under it
+            let tail = self.1;
+            if self.0.abs() == u::ldexp(1.0, e - 1)
+                && tail != 0.0
+                && tail.is_sign_negative() != self.0.is_sign_negative()
+            {
+                e -= 1;
+            }
+            (self.ldexp(-e), e)
+        }
     }
 
     /// Calculates the square of the `Double`.
     ///
     /// This method takes advantage of optimizations in multiplication that are available
     /// when the two numbers being multiplied are the same, so it is more efficient than
-    /// bare multiplication.
+    /// bare multiplication. The cross terms of the product are equal in pairs, so each pair
+    /// is calculated once with one of its factors doubled. The result is at least as
+    /// accurate as `self * self`.
     ///
     /// # Examples
     /// ```
@@ -44,18 +152,54 @@ impl Double {
     /// ```
     #[inline]
     pub fn sqr(self) -> Double {
+        match self.pre_sqr() {
+            Some(r) => s::guard("Double::sqr", &[self.0], r),
+            None => self.sqr_inner(),
+        }
+    }
+
+    // `sqr` without the strict-mode check, for `pow_int` and `cube`.
+    const fn sqr_inner(self) -> Double {
         match self.pre_sqr() {
             Some(r) => r,
             None => {
                 let (p, e) = p::two_sqr(self.0);
                 let (a, b) = u::renorm2(p, e + 2.0 * self.0 * self.1 + self.1 * self.1);
-                Double(a, b)
+                if a.is_finite() {
+                    Double(a, b)
+                } else {
+                    c::redo_halved!(
+                        0.5 * self.0 * self.0,
+                        c::mul_pwr2(self, 0.5).mul_inner(self)
+                    )
+                }
             }
         }
     }
 
+    /// Calculates the cube of the `Double`.
+    ///
+    /// This is the square from [`sqr`] multiplied by the `Double` once more, which is faster
+    /// than `self * self * self`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// assert!(dd!(3).cube() == dd!(27));
+    /// assert!(dd!(-0.5).cube() == dd!(-0.125));
+    /// ```
+    ///
+    /// [`sqr`]: #method.sqr
+    #[inline]
+    pub fn cube(self) -> Double {
+        s::guard("Double::cube", &[self.0], self.sqr_inner().mul_inner(self))
+    }
+
     /// Calculates the square root of the `Double`.
     ///
+    /// The result is accurate for every finite, non-negative `Double`, up to and including
+    /// [`MAX`].
+    ///
     /// # Examples
     /// ```
     /// # use qd::{dd, Double};
@@ -63,29 +207,276 @@ impl Double {
     /// let diff = (x - Double::SQRT_2).abs();
     /// assert!(diff < dd!(1e-30));
     /// ```
+    ///
+    /// [`MAX`]: #associatedconstant.MAX
     pub fn sqrt(self) -> Double {
         match self.pre_sqrt() {
-            Some(r) => r,
+            Some(r) => s::guard("Double::sqrt", &[self.0], r),
             None => {
                 // Strategy: use a method developed by Alan Karp and Peter Markstein at HP
                 // https://cr.yp.to/bib/1997/karp.pdf
                 //
-                // If x is an approximation of sqrt(a), then
+                // If x is an approximation of 1/sqrt(a) and s is one of sqrt(a), then
+                //
+                //      sqrt(a) ≈ s + (a - s^2)x / 2
+                //
+                // The approximation is accurate to twice the accuracy of s and x. This can
+                // be repeated an arbitrary number of times, but this method when used on
+                // double-doubles only requires one iteration. s is the square root of the
+                // first component, which is exact when that's a perfect square, so the
+                // correction is zero and the root of a perfect square is exact too.
+                //
+                // (ax)^2 is about a, so it can round past f64::MAX when a is near overflow,
+                // and the low component of the correction term falls into the subnormals
+                // (or all the way to zero) when a is small. So outside of 2^±512, a is first
+                // scaled by 2^-2k, which brings it to within a factor of 4 of 1 and is
+                // exact, and the root is scaled back by 2^k.
+                let k = if self.0 > SCALE_ABOVE || self.0 < 1.0 / SCALE_ABOVE {
+                    u::exponent(self.0) / 2
+                } else {
+                    0
+                };
+                let a = c::scale(self, -2 * k);
+                let r = a.0.sqrt();
+                let s = Double(r, 0.0);
+                let x = Double(1.0 / r, 0.0);
+                (s + (a - s.sqr()) * c::mul_pwr2(x, 0.5)).ldexp(k)
+            }
+        }
+    }
+
+    /// Calculates the reciprocal of the square root of the `Double`, 1/√*x*.
+    ///
+    /// This is the same number as `Double::ONE / x.sqrt()`, but it's computed directly with
+    /// Newton's iteration for the reciprocal square root, which needs no division at all,
+    /// so it's considerably faster. That makes it the better choice for work like
+    /// normalizing vectors. It's more accurate too: the result is within 2 × [`EPSILON`]
+    /// (relative) of the exact reciprocal square root, while rounding twice, once for the
+    /// root and once for the division, can leave `Double::ONE / x.sqrt()` off by more than
+    /// twice that.
+    ///
+    /// Following IEEE 754's `rSqrt`, the reciprocal square root of 0 is ∞ and that of -0 is
+    /// -∞. The reciprocal square root of ∞ is 0, and that of any other negative number is
+    /// [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!(2).recip_sqrt();
+    /// let expected = dd!("0.70710678118654752440084436210484903");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    ///
+    /// assert!(Double::ZERO.recip_sqrt() == Double::INFINITY);
+    /// assert!(Double::NEG_ZERO.recip_sqrt() == Double::NEG_INFINITY);
+    /// assert!(dd!(-1).recip_sqrt().is_nan());
+    /// ```
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn recip_sqrt(self) -> Double {
+        match self.pre_recip_sqrt() {
+            Some(r) => s::guard("Double::recip_sqrt", &[self.0], r),
+            None => {
+                // Strategy: use Newton's iteration.
+                //
+                // Perform the following Newton iteration
                 //
-                //      sqrt(a) ≈ ax + (a - (ax)^2)x / 2
+                //      r' = r + (1 - ar²) * r / 2
                 //
-                // The approximation is accurate to twice the accuracy of x. This can be
-                // repeated an arbitrary number of times, but this method when used on
-                // double-doubles only requires one iteration.
-                let x = Double::from(1.0 / self.0.sqrt());
-                let ax = self * x;
-                ax + (self - ax.sqr()) * c::mul_pwr2(x, 0.5)
+                // which is r(3 - ar²) / 2 rearranged so that the correction is added to r
+                // rather than rounded along with it, and which converges to 1/√a. It
+                // starts with the `f64` reciprocal square root of the first component.
+                // Each pass of Newton's iteration doubles the number of correct bits, so two
+                // passes take the 53 bits of the `f64` seed past the 106 of a `Double`.
+                //
+                // As in `sqrt`, a is scaled by 2^-2k outside of 2^±512, which brings it to
+                // within a factor of 4 of 1 and is exact, and the result is scaled back by
+                // 2^-k.
+                let k = if self.0 > SCALE_ABOVE || self.0 < 1.0 / SCALE_ABOVE {
+                    u::exponent(self.0) / 2
+                } else {
+                    0
+                };
+                let a = c::scale(self, -2 * k);
+
+                let mut r = Double(1.0 / a.0.sqrt(), 0.0);
+                let h = c::mul_pwr2(a, 0.5);
+                let half = Double(0.5, 0.0);
+
+                r += (half - h * r.sqr()) * r;
+                r += (half - h * r.sqr()) * r;
+
+                r.ldexp(-k)
+            }
+        }
+    }
+
+    /// Calculates the length of the hypotenuse of a right triangle whose legs have the
+    /// lengths of the `Double` and the argument, √(*x*² + *y*²).
+    ///
+    /// When the squares of the arguments would overflow or lose precision to the subnormal
+    /// range, both arguments are first scaled by the same power of 2, one that brings the
+    /// larger of them near 1, and the result is scaled back afterwards. So the result is
+    /// infinite only when it's too large to represent. For arguments between about 10⁻¹²⁰
+    /// and 10¹²⁰ that are within a factor of 2⁶⁰ of each other, the result is the same as
+    /// `(x.sqr() + y.sqr()).sqrt()`; when one is smaller than that, the result is the
+    /// magnitude of the other.
+    ///
+    /// As with `f64::hypot`, the result is ∞ if either argument is infinite, even if the
+    /// other is NaN, and otherwise NaN if either argument is.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!("3e200").hypot(dd!("4e200"));
+    /// let diff = (x - dd!("5e200")).abs();
+    /// assert!(diff < dd!(1e170));
+    ///
+    /// assert!(Double::MAX.hypot(Double::MAX) == Double::INFINITY);
+    /// assert!(Double::NAN.hypot(Double::NEG_INFINITY) == Double::INFINITY);
+    /// ```
+    pub fn hypot(self, other: Double) -> Double {
+        match self.pre_hypot(&[other]) {
+            Some(r) => r,
+            None => {
+                let (big, small) = if self.0.abs() < other.0.abs() {
+                    (other.abs(), self.abs())
+                } else {
+                    (self.abs(), other.abs())
+                };
+                let k = u::exponent(big.0);
+                let r = if small.is_zero() || k - u::exponent(small.0) > HYPOT_NEGLIGIBLE {
+                    // small² is too small to change big² at this precision
+                    big
+                } else if (-HYPOT_DIRECT..=HYPOT_DIRECT).contains(&k) {
+                    (big.sqr() + small.sqr()).sqrt()
+                } else {
+                    let (x, y) = (c::scale(big, -k), c::scale(small, -k));
+                    let r = c::scale((x.sqr() + y.sqr()).sqrt(), k);
+                    // Scaling can round trailing components separately into the subnormal
+                    // range, or leave a finite one behind an infinite first component
+                    c::from_components(r.0, r.1)
+                };
+                s::guard("Double::hypot", &[self.0, other.0], r)
+            }
+        }
+    }
+
+    /// Calculates the length of the vector whose components are the `Double` and the two
+    /// arguments, √(*x*² + *y*² + *z*²).
+    ///
+    /// This is the three-dimensional version of [`hypot`], and it avoids overflow and
+    /// underflow in the same way. The result is ∞ if any argument is infinite and otherwise
+    /// NaN if any argument is.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!(2).hypot3(dd!(-3), dd!(6));
+    /// assert!((x - dd!(7)).abs() < dd!(1e-30));
+    ///
+    /// let big = Double::MAX / dd!(2);
+    /// assert!(big.hypot3(big, big).is_finite());
+    /// ```
+    ///
+    /// [`hypot`]: #method.hypot
+    pub fn hypot3(self, y: Double, z: Double) -> Double {
+        match self.pre_hypot(&[y, z]) {
+            Some(r) => r,
+            None => {
+                let r = if self.is_zero() {
+                    y.hypot(z)
+                } else if y.is_zero() {
+                    self.hypot(z)
+                } else if z.is_zero() {
+                    self.hypot(y)
+                } else {
+                    let k = u::exponent(self.0.abs().max(y.0.abs()).max(z.0.abs()));
+                    if (-HYPOT_DIRECT..=HYPOT_DIRECT).contains(&k) {
+                        (self.sqr() + y.sqr() + z.sqr()).sqrt()
+                    } else {
+                        let (a, b, d) = (c::scale(self, -k), c::scale(y, -k), c::scale(z, -k));
+                        let r = c::scale((a.sqr() + b.sqr() + d.sqr()).sqrt(), k);
+                        c::from_components(r.0, r.1)
+                    }
+                };
+                s::guard("Double::hypot3", &[self.0, y.0, z.0], r)
+            }
+        }
+    }
+
+    /// Calculates the arithmetic-geometric mean of the `Double` and the argument.
+    ///
+    /// Starting from *a* and *b*, the arithmetic mean (*a* + *b*) / 2 and the geometric
+    /// mean √(*ab*) are taken over and over, and the two means converge (quadratically) on
+    /// the result. That makes this the fast route to complete elliptic integrals, since
+    /// *K*(*m*) = π / (2 agm(1, √(1 - *m*))), and to logarithms and π itself. The
+    /// iteration stops as soon as the means agree to within a few units of
+    /// [`EPSILON`].
+    ///
+    /// The arguments are put in order first, so `x.agm(y)` is exactly the same as
+    /// `y.agm(x)`. The mean of two numbers is the same as the number itself. Arguments of
+    /// wildly different magnitudes are fine: while their product could overflow or
+    /// underflow, the geometric mean is taken as √*a*·√*b*, and after that both are scaled
+    /// by the same power of 2 toward 1.
+    ///
+    /// The mean of anything with 0 is 0 and of anything else with ∞ is ∞, except that the
+    /// mean of 0 and ∞ is NaN. The mean is NaN if either argument is negative or NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// // Gauss's constant is 1 / agm(1, √2)
+    /// let g = dd!(1).agm(dd!(2).sqrt()).recip();
+    /// let expected = dd!("0.83462684167407318628142973279904681");
+    /// assert!((g - expected).abs() < dd!(1e-30));
+    ///
+    /// assert!(dd!(3).agm(dd!(3)) == dd!(3));
+    /// assert!(dd!(-1).agm(dd!(2)).is_nan());
+    /// ```
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    pub fn agm(self, other: Double) -> Double {
+        match self.pre_agm(&other) {
+            Some(r) => s::guard("Double::agm", &[self.0, other.0], r),
+            None => {
+                let (mut a, mut b) = if self > other {
+                    (self, other)
+                } else {
+                    (other, self)
+                };
+                while u::exponent(a.0) - u::exponent(b.0) > AGM_DIRECT {
+                    let next = c::mul_pwr2(a, 0.5) + c::mul_pwr2(b, 0.5);
+                    b = a.sqrt() * b.sqrt();
+                    a = next;
+                }
+                // Centering the arguments on 1 keeps their product near 1
+                let k = (u::exponent(a.0) + u::exponent(b.0)) / 2;
+                let (mut a, mut b) = (c::scale(a, -k), c::scale(b, -k));
+                for _ in 0..AGM_MAX_ITER {
+                    if (a - b).abs() <= c::mul_pwr2(a * Double::EPSILON, 4.0) {
+                        break;
+                    }
+                    let next = c::mul_pwr2(a + b, 0.5);
+                    b = (a * b).sqrt();
+                    a = next;
+                }
+                let r = c::scale(c::mul_pwr2(a + b, 0.5), k);
+                // Scaling down can round the second component separately into the
+                // subnormal range
+                let r = c::from_components(r.0, r.1);
+                s::guard("Double::agm", &[self.0, other.0], r)
             }
         }
     }
 
     /// Calculates the *n*th root of the `Double`.
     ///
+    /// The result is accurate for every finite `Double` that has an *n*th root, including
+    /// those near [`MAX`] and those near the smallest normal `f64`.
+    ///
     /// # Examples
     /// ```
     /// # use qd::dd;
@@ -95,9 +486,11 @@ impl Double {
     /// let diff = (x - expected).abs();
     /// assert!(diff < dd!(1e-30));
     /// ```
+    ///
+    /// [`MAX`]: #associatedconstant.MAX
     pub fn nroot(self, n: i32) -> Double {
         match self.pre_nroot(n) {
-            Some(r) => r,
+            Some(r) => s::guard("Double::nroot", &[self.0, n as f64], r),
             None => {
                 // Strategy: the square root method is specialized for square roots, but the
                 // traditional way of finding roots is using Newton's iteration for the
@@ -112,21 +505,37 @@ impl Double {
                 // This converges quadratically, which is pretty fast. We can then find
                 // a^(1/n) by taking the reciprocal.
 
-                let r = self.abs();
-                // a^(-1/n) = exp(-ln(a) / n)
-                let mut x = Double::from((-(r.0.ln()) / n as f64).exp());
+                //
+                // A power of 2 that's a multiple of n is always taken out of a first, which
+                // leaves an r within a factor of 2^n of 1, and its nth root is put back into
+                // the result at the end. Otherwise x^n, which is about 1/a, would overflow or
+                // go subnormal when a is far enough from 1. And with -1/n rounded, the error
+                // of the f64 seed grows with |ln a|; far from 1 it's more than a single step
+                // of Newton's iteration can make up for.
+                let a = self.abs();
+                let m = a.0.log2().floor() as i32 / n;
+                let r = c::scale(a, -m * n);
+                // The starting approximation of a^(-1/n) comes from f64's own roots, which
+                // are more accurate than exp(-ln(a) / n) would be.
+                let mut x = Double::from(if n == 3 {
+                    r.0.cbrt().recip()
+                } else {
+                    r.0.powf(-1.0 / n as f64)
+                });
 
                 x += x * (Double::ONE - r * x.powi(n)) / Double(n.into(), 0.0);
                 if self.is_sign_negative() {
                     x = -x;
                 }
-                x.recip()
+                c::scale(x.recip(), m)
             }
         }
     }
 
     /// Calculates the cube root of the `Double`.
     ///
+    /// Like [`nroot`], this is accurate for every finite `Double`.
+    ///
     /// # Examples
     /// ```
     /// # use qd::dd;
@@ -136,6 +545,8 @@ impl Double {
     /// let diff = (x - expected).abs();
     /// assert!(diff < dd!(1e-30));
     /// ```
+    ///
+    /// [`nroot`]: #method.nroot
     #[inline]
     pub fn cbrt(self) -> Double {
         self.nroot(3)
@@ -152,49 +563,173 @@ impl Double {
     /// * `x.powi(n)` is ±0 for `x` = ±0 and any odd positive `n`
     /// * `x.powi(n)` is +0 for `x` = ±0 and any even positive `n`
     ///
+    /// Nothing overflows or underflows along the way, so a result is ±∞ or ±0 only if it
+    /// really is too large or too small to represent, and every exponent works, including
+    /// `i32::MIN`. [`powi64`] does the same for an `i64` exponent.
+    ///
     /// # Examples
     /// ```
     /// # use qd::dd;
     /// let x = dd!(3);
     /// assert!(x.powi(3) == dd!(27));
+    /// assert!(dd!(0.5).powi(1074).to_array() == [f64::from_bits(1), 0.0]);
+    /// assert!(dd!(-1).powi(i32::MIN) == dd!(1));
     /// ```
+    ///
+    /// [`powi64`]: #method.powi64
+    #[inline]
     pub fn powi(self, n: i32) -> Double {
-        match self.pre_powi(n) {
-            Some(r) => r,
-            None => {
-                let mut r = self;
-                let mut s = Double::ONE;
-                let mut i = n.abs();
-
-                if i > 1 {
-                    while i > 0 {
-                        if i % 2 == 1 {
-                            s *= r;
-                        }
-                        i /= 2;
-                        if i > 0 {
-                            r = r.sqr();
-                        }
-                    }
-                } else {
-                    s = r;
-                }
+        s::guard("Double::powi", &[self.0, n as f64], self.pow_int(n.into()))
+    }
 
-                if n < 0 {
-                    s.recip()
-                } else {
-                    s
-                }
+    /// Calculates the `Double` raised to an `i64` power.
+    ///
+    /// This is the same as [`powi`], including its special cases, except that it takes an
+    /// `i64`. Exponents that large are only useful for bases very close to 1 (or equal to
+    /// ±1); anything else overflows or underflows, and this finds that out in at most 63
+    /// squarings, without calculating anything too large or too small to represent.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(-1).powi64(i64::MAX) == dd!(-1));
+    /// assert!(dd!(2).powi64(-1074).to_array() == [f64::from_bits(1), 0.0]);
+    /// assert!(dd!(1.5).powi64(1 << 40) == Double::INFINITY);
+    /// assert!(Double::NEG_ZERO.powi64(i64::MIN) == Double::INFINITY);
+    /// ```
+    ///
+    /// [`powi`]: #method.powi
+    #[inline]
+    pub fn powi64(self, n: i64) -> Double {
+        s::guard("Double::powi64", &[self.0, n as f64], self.pow_int(n))
+    }
+
+    // Binary exponentiation, keeping the power of 2 of each intermediate result in an
+    // integer and its significand near 1, so that nothing overflows or underflows until
+    // the end. Each square would only be multiplied into the result, so once one is too
+    // large or too small for the result to be representable, the result is known.
+    //
+    // This is a `const fn` because parsing, which can happen at compile time, scales by
+    // powers of ten with it.
+    pub(super) const fn pow_int(self, n: i64) -> Double {
+        if let Some(r) = self.pre_powi(n) {
+            return r;
+        }
+        let negative = n % 2 != 0 && self.is_sign_negative();
+        let k = u::exponent(self.0);
+        let mut r = c::scale(self, -k);
+        let mut re = k as i64;
+        let mut s = Double::ONE;
+        let mut se = 0i64;
+        let mut i = n.unsigned_abs();
+
+        loop {
+            if i % 2 == 1 {
+                s = s.mul_inner(r);
+                let k = u::exponent(s.0);
+                s = c::scale(s, -k);
+                se += re + k as i64;
+            }
+            i /= 2;
+            if i == 0 {
+                break;
+            }
+            r = r.sqr_inner();
+            let k = u::exponent(r.0);
+            r = c::scale(r, -k);
+            re = 2 * re + k as i64;
+            if re.abs() > POW_LIMIT {
+                // Too large if the base is larger than 1 and the power positive, or the
+                // base is smaller than 1 and the power negative
+                return match ((re > 0) == (n > 0), negative) {
+                    (true, false) => Double::INFINITY,
+                    (true, true) => Double::NEG_INFINITY,
+                    (false, false) => Double::ZERO,
+                    (false, true) => Double::NEG_ZERO,
+                };
+            }
+        }
+
+        if n < 0 {
+            s = Double::ONE.div_inner(s);
+            se = -se;
+        }
+        // s is in (1/2, 2), so past these the result is certainly infinite or zero
+        if se > 1024 {
+            if negative {
+                Double::NEG_INFINITY
+            } else {
+                Double::INFINITY
             }
+        } else if se < -1076 {
+            if negative {
+                Double::NEG_ZERO
+            } else {
+                Double::ZERO
+            }
+        } else {
+            // Scaling into the subnormal range rounds each component separately, which can
+            // leave them overlapping, and scaling to infinity leaves a finite second one
+            let r = c::scale(s, se as i32);
+            c::from_components(r.0, r.1)
         }
     }
 
+    /// Calculates the `Double` raised to an integer power.
+    ///
+    /// This is exactly the same as [`powi`]; it's here because `pow` is the name that
+    /// integer types and the `num-traits` crate use for the same thing.
+    ///
+    /// Note that `^` is *not* exponentiation. It isn't implemented for `Double` at all, so
+    /// trying to use it as a power operator is a compile error rather than a silent XOR.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// assert!(dd!(2).pow(10) == dd!(1024));
+    /// assert!(dd!(2).pow(-2) == dd!(0.25));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use qd::dd;
+    /// let x = dd!(2) ^ 10; // use `dd!(2).powi(10)` instead
+    /// ```
+    ///
+    /// [`powi`]: #method.powi
+    #[inline]
+    pub fn pow(self, n: i32) -> Double {
+        self.powi(n)
+    }
+
+    /// Calculates the `Double` raised to an unsigned integer power.
+    ///
+    /// This is the same as [`powi`] except that it takes a `u32`, which means that there is
+    /// never a reciprocal to take. The special cases are the same as those for [`powi`]
+    /// with a positive exponent.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// assert!(dd!(3).powu(3) == dd!(27));
+    /// assert!(dd!(-1).powu(u32::MAX) == dd!(-1));
+    /// ```
+    ///
+    /// [`powi`]: #method.powi
+    pub fn powu(self, n: u32) -> Double {
+        self.powi64(n.into())
+    }
+
     /// Calculates the `Double` raised to a `Quad` power.
     ///
     /// In general, x<sup>n</sup> is equal to *e*<sup>n ln x</sup>. This precludes raising
     /// a negative `Double` to a fractional or irrational power because *ln x* is undefined
     /// when *x* is negative. In that case, this function returns [`NAN`].
     ///
+    /// A finite negative `Double` *can* be raised to an integer power, and the result is
+    /// negative when that power is odd. The whole exponent is checked for this, not just its
+    /// first component, so an exponent just short of 3 is not mistaken for 3 and an odd
+    /// exponent larger than 2<sup>53</sup> is still recognized as odd.
+    ///
     /// It's actually more complex than that; if the exponent can be expressed as a fraction
     /// with an odd denominator, then there is an answer (a cube root, which is defined for
     /// negative numbers, is the same as a power of 1/3). Therefore, something like
@@ -203,6 +738,17 @@ impl Double {
     /// while using floating-point numbers, so no attempt is made to make this work. If you
     /// need a fifth root of -4, use `dd!(-4).nroot(5)`.
     ///
+    /// Special cases follow the same conventions as `f64::powf`:
+    ///
+    /// * Anything to the power of 0, and 1 to the power of anything, is 1, even if the
+    ///   other number is [`NAN`]. Otherwise, if either number is [`NAN`], so is the result.
+    /// * 0 to a negative power and ∞ to a positive power are ∞, while 0 to a positive power
+    ///   and ∞ to a negative power are 0. If the base is -0 or -∞, the result is negative
+    ///   when the power is an odd integer.
+    /// * Numbers with a magnitude less than 1 go to 0 when raised to the power of ∞ and to
+    ///   ∞ when raised to the power of -∞. Numbers with a magnitude greater than 1 do the
+    ///   opposite, and -1 to the power of either infinity is 1.
+    ///
     /// # Examples
     /// ```
     /// # use qd::dd;
@@ -217,13 +763,69 @@ impl Double {
     #[inline]
     pub fn powf(self, n: Double) -> Double {
         match self.pre_powf(&n) {
-            Some(r) => r,
-            None => (n * self.ln()).exp(),
+            Some(r) => s::guard("Double::powf", &[self.0, n.0], r),
+            None => {
+                if self.is_sign_negative() {
+                    // `pre_powf` has already made sure that `n` is an integer. If it's small
+                    // enough, its first component is the whole thing.
+                    if n.0.abs() <= i32::MAX as f64 {
+                        self.powi(n.0 as i32)
+                    } else {
+                        let r = (-self).powf(n);
+                        if c::is_odd(n) {
+                            -r
+                        } else {
+                            r
+                        }
+                    }
+                } else {
+                    (n * self.ln()).exp()
+                }
+            }
         }
     }
 
+    /// Calculates `self * a + b` as a fused multiply-add.
+    ///
+    /// Writing `self * a + b` with the operators rounds twice: the product is rounded to a
+    /// `Double` before it's added to `b`. When the product and `b` nearly cancel, the error
+    /// from that first rounding can be as large as the result itself, so that the whole
+    /// low component of the result (or more) is wrong. `mul_add` keeps every error term of
+    /// the product until it has been added to `b` and rounds only once in that case, so its
+    /// relative error is no more than a few [`EPSILON`]s however much cancellation there
+    /// is. That makes it the right tool for differences of products, residuals, and
+    /// polynomial evaluation with Horner's method.
+    ///
+    /// When there's no close cancellation, the product's rounding error is already
+    /// negligible, and this is only slightly slower than the operators.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// // x² - 1 is exactly 2^-59 + 2^-120
+    /// let x = dd!(1) + dd!(2).powi(-60);
+    /// let exact = dd!(2).powi(-59) + dd!(2).powi(-120);
+    ///
+    /// // The operators round x² to 1 + 2^-59, losing the 2^-120 entirely
+    /// assert!(x * x - dd!(1) == dd!(2).powi(-59));
+    /// assert!(x.mul_add(x, dd!(-1)) == exact);
+    /// ```
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    #[inline]
+    pub fn mul_add(self, a: Double, b: Double) -> Double {
+        s::guard(
+            "Double::mul_add",
+            &[self.0, a.0, b.0],
+            c::mul_add(self, a, b),
+        )
+    }
+
     /// Calculates the reciprocal of the `Double`.
     ///
+    /// The reciprocal of a `Double` larger than about 10<sup>308</sup> is subnormal, and
+    /// like any subnormal number it has fewer digits of precision than a normal one.
+    ///
     /// # Examples
     /// ```
     /// # use qd::{dd, Double};
@@ -248,8 +850,10 @@ impl Double {
     // rather than calculation.
 
     #[inline]
-    fn pre_sqr(&self) -> Option<Double> {
-        if self.is_infinite() {
+    const fn pre_sqr(&self) -> Option<Double> {
+        if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.is_infinite() {
             Some(Double::INFINITY)
         } else {
             None
@@ -269,6 +873,56 @@ impl Double {
         }
     }
 
+    #[inline]
+    fn pre_recip_sqrt(&self) -> Option<Double> {
+        if self.is_zero() {
+            // IEEE 754 has the reciprocal square root of -0 be -∞
+            if self.is_sign_negative() {
+                Some(Double::NEG_INFINITY)
+            } else {
+                Some(Double::INFINITY)
+            }
+        } else if self.is_sign_negative() {
+            Some(Double::NAN)
+        } else if self.is_infinite() {
+            Some(Double::ZERO)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_hypot(&self, others: &[Double]) -> Option<Double> {
+        if self.is_infinite() || others.iter().any(|x| x.is_infinite()) {
+            Some(Double::INFINITY)
+        } else if self.is_nan() || others.iter().any(|x| x.is_nan()) {
+            Some(Double::NAN)
+        } else if self.is_zero() && others.iter().all(|x| x.is_zero()) {
+            Some(Double::ZERO)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_agm(&self, other: &Double) -> Option<Double> {
+        if self.is_nan() || other.is_nan() || *self < Double::ZERO || *other < Double::ZERO {
+            Some(Double::NAN)
+        } else if self.is_zero() || other.is_zero() {
+            if self.is_infinite() || other.is_infinite() {
+                Some(Double::NAN)
+            } else {
+                Some(Double::ZERO)
+            }
+        } else if self.is_infinite() || other.is_infinite() {
+            Some(Double::INFINITY)
+        } else if self == other {
+            Some(*self)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn pre_nroot(&self, n: i32) -> Option<Double> {
         if n == 0 {
@@ -311,7 +965,7 @@ impl Double {
     }
 
     #[inline]
-    fn pre_powi(&self, n: i32) -> Option<Double> {
+    const fn pre_powi(&self, n: i64) -> Option<Double> {
         if n == 0 {
             Some(Double::ONE)
         } else if self.is_nan() {
@@ -347,22 +1001,34 @@ impl Double {
 
     #[inline]
     fn pre_powf(&self, n: &Double) -> Option<Double> {
-        if self.is_zero() {
-            if n.is_zero() {
-                Some(Double::NAN)
-            } else if n.is_sign_positive() {
+        if n.is_zero() || *self == Double::ONE {
+            // Even if the other one is NaN
+            Some(Double::ONE)
+        } else if self.is_nan() || n.is_nan() {
+            Some(Double::NAN)
+        } else if n.is_infinite() {
+            let abs = self.abs();
+            if abs == Double::ONE {
+                Some(Double::ONE)
+            } else if (abs < Double::ONE) == n.is_sign_positive() {
                 Some(Double::ZERO)
             } else {
                 Some(Double::INFINITY)
             }
-        } else if n.is_infinite() {
-            if *self == Double::ONE {
-                Some(Double::NAN)
-            } else if n.is_sign_positive() {
-                Some(Double::INFINITY)
-            } else {
-                Some(Double::ZERO)
-            }
+        } else if self.is_zero() || self.is_infinite() {
+            // Zero to a negative power and infinity to a positive one are infinite, and the
+            // other two are zero. Either way, the sign of a negative base only survives an
+            // odd integer power.
+            let infinite = self.is_infinite() == n.is_sign_positive();
+            let negative = self.is_sign_negative() && c::is_integer(*n) && c::is_odd(*n);
+            Some(match (infinite, negative) {
+                (true, false) => Double::INFINITY,
+                (true, true) => Double::NEG_INFINITY,
+                (false, false) => Double::ZERO,
+                (false, true) => Double::NEG_ZERO,
+            })
+        } else if self.is_sign_negative() && !c::is_integer(*n) {
+            Some(Double::NAN)
         } else {
             None
         }
@@ -372,6 +1038,8 @@ impl Double {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::random::Rng;
+    use crate::quad::Quad;
 
     // ldexp_tests
     test_all_near!(
@@ -441,6 +1109,170 @@ mod tests {
             Double::NAN.ldexp(5);
     );
 
+    test_all_exact!(
+        ldexp_past_f64_range:
+            dd!(3),
+            dd!(3).ldexp(-1074).ldexp(1074);
+        ldexp_large_exp:
+            dd!(1.5),
+            dd!(1.5).ldexp(-1050).ldexp(2000).ldexp(-950);
+        ldexp_overflow:
+            Double::INFINITY,
+            dd!(1).ldexp(1024);
+        ldexp_overflow_large_exp:
+            Double::NEG_INFINITY,
+            dd!(-1).ldexp(i32::MAX);
+        ldexp_underflow:
+            Double::ZERO,
+            dd!(1).ldexp(-1075);
+        ldexp_underflow_large_exp:
+            Double::ZERO,
+            dd!(1).ldexp(i32::MIN);
+        ldexp_subnormal_tail:
+            Double::from_array([2f64.powi(-1020), f64::from_bits(16)]),
+            Double::from_array([1.0, 2f64.powi(-50)]).ldexp(-1020);
+        ldexp_rounded_tail:
+            Double::from_array([2f64.powi(-1020), f64::from_bits(2)]),
+            Double::from_array([1.0, 3.0 * 2f64.powi(-55)]).ldexp(-1020);
+        ldexp_flushed_tail:
+            Double::from_array([2f64.powi(-1020), 0.0]),
+            Double::from_array([1.0, 2f64.powi(-60)]).ldexp(-1020);
+    );
+    test_all_assert!(
+        ldexp_underflow_neg:
+            dd!(-1).ldexp(-5000).is_sign_negative();
+        ldexp_overflow_nan:
+            Double::NAN.ldexp(5000).is_nan();
+    );
+    // Scaling by a power of 2 is exact, so it's the same as multiplying by that power and
+    // undone by scaling back
+    test!(ldexp_exact: {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        let mut next = || rng.next_f64() + 0.5;
+        for i in 0..1000 {
+            let x = Double::from_sum(&[next(), next() * 1e-17]);
+            let x = if i % 2 == 0 { x } else { -x };
+            let k = (i % 1600) as i32 - 800;
+            let r = x.ldexp(k);
+            assert!(r.to_bits() == (x * Double(2f64.powi(k), 0.0)).to_bits(), "{:?}.ldexp({})", x, k);
+            assert!(r.ldexp(-k).to_bits() == x.to_bits(), "{:?}.ldexp({})", x, k);
+        }
+    });
+    // On either side of the exponents where 2^n stops being an `f64`, every component is
+    // scaled just as it would be on its own
+    test!(ldexp_boundaries: {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        let mut next = || rng.next_f64() + 0.5;
+        for _ in 0..200 {
+            let x = Double::from_sum(&[next(), next() * 1e-17]);
+            for &n in &[-1076, -1075, -1074, -1023, -1022, -1021, 1022, 1023, 1024, 1025] {
+                let r = x.ldexp(n);
+                let a = u::ldexp(x.0, n);
+                let expected: Vec<f64> = if a.is_finite() {
+                    [x.0, x.1].iter().map(|&c| u::ldexp(c, n)).collect()
+                } else {
+                    let mut e = vec![0.0; [r.0, r.1].len()];
+                    e[0] = a;
+                    e
+                };
+                assert!(
+                    [r.0, r.1].iter().zip(&expected).all(|(a, b)| a.to_bits() == b.to_bits()),
+                    "{:?}.ldexp({})",
+                    x,
+                    n
+                );
+            }
+        }
+    });
+
+    // frexp tests
+    test_all_eq!(
+        frexp_40:
+            (dd!(0.625), 6),
+            dd!(40).frexp();
+        frexp_neg_pi:
+            (-Double::PI / dd!(4), 2),
+            (-Double::PI).frexp();
+        frexp_small:
+            (Double::PI / dd!(4), -798),
+            Double::PI.ldexp(-800).frexp();
+        frexp_subnormal:
+            (dd!(0.5), -1073),
+            Double::from_array([f64::from_bits(1), 0.0]).frexp();
+        frexp_max:
+            (Double::MAX.ldexp(-1024), 1024),
+            Double::MAX.frexp();
+        frexp_zero:
+            (Double::ZERO, 0),
+            Double::ZERO.frexp();
+        frexp_inf:
+            (Double::INFINITY, 0),
+            Double::INFINITY.frexp();
+        frexp_neg_inf:
+            (Double::NEG_INFINITY, 0),
+            Double::NEG_INFINITY.frexp();
+    );
+    test_all_assert!(
+        frexp_neg_zero:
+            Double::NEG_ZERO.frexp().0.is_sign_negative();
+        frexp_nan:
+            Double::NAN.frexp().0.is_nan() && Double::NAN.frexp().1 == 0;
+    );
+    // A power of 2 in the first component with a tail of the opposite sign is just below
+    // that power, so its mantissa is just below 1
+    test_all_eq!(
+        frexp_just_below_one:
+            (Double(1.0, -2f64.powi(-60)), 0),
+            Double(1.0, -2f64.powi(-60)).frexp();
+        frexp_neg_just_below_one:
+            (Double(-1.0, 2f64.powi(-59)), -2),
+            Double(-0.25, 2f64.powi(-61)).frexp();
+        frexp_just_below_power:
+            (Double(1.0, -2f64.powi(-60)), 40),
+            Double(2f64.powi(40), -2f64.powi(-20)).frexp();
+        frexp_just_above_power:
+            (Double(0.5, 2f64.powi(-60)), 1),
+            Double(1.0, 2f64.powi(-59)).frexp();
+        frexp_just_below_tiny_power:
+            (Double(1.0, -2f64.powi(-53)), -1020),
+            Double(2f64.powi(-1020), -f64::from_bits(2)).frexp();
+    );
+
+    // Random values across the whole range, with tails that are subnormal whenever the
+    // first component is small enough
+    test!(frexp_ldexp_round_trip: {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        // A random f64 between -1 and 1
+        let unit = |r: u64| (r >> 11) as f64 / 2f64.powi(52) - 1.0;
+
+        for _ in 0..100000 {
+            let k = rng.range(-1074, 1024);
+            // Every eighth first component is a power of 2, where the sign of the tail
+            // decides the exponent
+            let f = if rng.next_u64() % 8 == 0 {
+                1.0
+            } else {
+                1.0 + unit(rng.next_u64()).abs()
+            };
+            let h = u::ldexp(f, k);
+            let t1 = h * unit(rng.next_u64()) * 2f64.powi(-53);
+            let x = Double::from_array([h, t1]);
+
+            let (m, e) = x.frexp();
+            assert!(m.abs() >= 0.5 && m.abs() < 1.0, "{:?}", x);
+            assert!(m.ldexp(e).to_bits() == x.to_bits(), "{:?}", x);
+
+            // Scaling up and back down is always exact as long as it doesn't overflow
+            let n = (rng.next_u64() % 2200) as i32;
+            let y = x.ldexp(n);
+            if y.is_finite() {
+                assert!(y.ldexp(-n).to_bits() == x.to_bits(), "{:?} {}", x, n);
+            } else {
+                assert!(u::exponent(x.0) + n > 1023, "{:?} {}", x, n);
+            }
+        }
+    });
+
     // sqr tests
     test_all_near!(
         sqr_pi:
@@ -494,6 +1326,67 @@ mod tests {
             Double::NAN.sqr();
     );
 
+    // cube tests
+    test_all_near!(
+        cube_pi:
+            dd!("31.006276680299820175476315067101484"),
+            Double::PI.cube();
+        cube_e:
+            dd!("20.085536923187667740928529654581765"),
+            Double::E.cube();
+        cube_sqrt_2:
+            dd!("2.8284271247461900976033774484193713"),
+            Double::SQRT_2.cube();
+        cube_neg_pi_2:
+            dd!("-3.8757845850374775219345393833876855"),
+            (-Double::FRAC_PI_2).cube();
+    );
+    test_all_exact!(
+        cube_3:
+            dd!(27),
+            dd!(3).cube();
+        cube_neg_half:
+            dd!(-0.125),
+            dd!(-0.5).cube();
+        cube_zero:
+            Double::ZERO,
+            Double::ZERO.cube();
+        cube_neg_zero:
+            Double::NEG_ZERO,
+            Double::NEG_ZERO.cube();
+        cube_inf:
+            Double::INFINITY,
+            Double::INFINITY.cube();
+        cube_neg_inf:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.cube();
+        cube_nan:
+            Double::NAN,
+            Double::NAN.cube();
+        cube_overflow:
+            Double::INFINITY,
+            dd!(1e103).cube();
+    );
+
+    // `sqr` keeps the a₁² term that multiplication drops, so the two can differ by a unit
+    // or two in the last place of the low component.
+    test!(sqr_matches_mul: {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        for i in 0..100_000 {
+            let e = (i % 80) - 40;
+            let x = (Double::ONE / Double::from(rng.next_f64() + 0.5)).ldexp(e);
+            let expected = x * x;
+            let diff = (x.sqr() - expected).abs();
+            assert!(
+                diff <= expected * Double::EPSILON.ldexp(1),
+                "{:?}.sqr() = {:?}, expected {:?}",
+                x,
+                x.sqr(),
+                expected
+            );
+        }
+    });
+
     // sqrt tests
     test_all_near!(
         sqrt_pi:
@@ -518,6 +1411,42 @@ mod tests {
             dd!("12.247448713915890490986420373529453"),
             dd!(150).sqrt();
     );
+    // The expected values are the roots of the numbers that are actually parsed, which
+    // for 4e-320, a subnormal, is quite a bit less precise than the decimal.
+    test_all_prec!(
+        sqrt_1e_300:
+            dd!("9.99999999999999999999999143490470976574e-151"),
+            dd!("1e-300").sqrt(),
+            30;
+        sqrt_1e_290:
+            dd!("1.000000000000000000000000000000073845399e-145"),
+            dd!("1e-290").sqrt(),
+            30;
+        sqrt_4e_320:
+            dd!("1.999988867151697927584136068550375195653e-160"),
+            dd!("4e-320").sqrt(),
+            30;
+    );
+    test!(sqrt_sqr_round_trip: {
+        for n in (-960..=1022).step_by(3) {
+            let x = c::scale(Double::PI, n);
+            prec!(x, x.sqrt().sqr(), 30);
+        }
+    });
+    test_all_exact!(
+        sqrt_9:
+            dd!(3),
+            dd!(9).sqrt();
+        sqrt_25:
+            dd!(5),
+            dd!(25).sqrt();
+        sqrt_2_25:
+            dd!(1.5),
+            dd!(2.25).sqrt();
+        sqrt_1e10:
+            dd!(100_000),
+            dd!(10_000_000_000u64).sqrt();
+    );
     test_all_exact!(
         sqrt_neg_pi:
             Double::NAN,
@@ -542,6 +1471,264 @@ mod tests {
             Double::NAN.sqrt();
     );
 
+    // recip_sqrt tests
+    test_all_prec!(
+        recip_sqrt_of_2:
+            dd!("0.7071067811865475244008443621048490"),
+            dd!(2).recip_sqrt(),
+            31;
+        recip_sqrt_of_3:
+            dd!("0.5773502691896257645091487805019575"),
+            dd!(3).recip_sqrt(),
+            31;
+        recip_sqrt_of_10:
+            dd!("0.3162277660168379331998893544432719"),
+            dd!(10).recip_sqrt(),
+            31;
+        recip_sqrt_huge:
+            dd!("0.5773502691896257645091487805019575").ldexp(-500),
+            dd!(3).ldexp(1000).recip_sqrt(),
+            31;
+        recip_sqrt_tiny:
+            dd!("0.4082482904638630163662140124509819").ldexp(501),
+            dd!(3).ldexp(-1001).recip_sqrt(),
+            31;
+    );
+    test_all_exact!(
+        recip_sqrt_of_4:
+            dd!(0.5),
+            dd!(4).recip_sqrt();
+        recip_sqrt_quarter:
+            dd!(2),
+            dd!(0.25).recip_sqrt();
+        recip_sqrt_min_subnormal:
+            dd!(2).ldexp(536),
+            dd!(1).ldexp(-1074).recip_sqrt();
+        recip_sqrt_neg:
+            Double::NAN,
+            (-Double::PI).recip_sqrt();
+        recip_sqrt_zero:
+            Double::INFINITY,
+            Double::ZERO.recip_sqrt();
+        recip_sqrt_neg_zero:
+            Double::NEG_INFINITY,
+            Double::NEG_ZERO.recip_sqrt();
+        recip_sqrt_inf:
+            Double::ZERO,
+            Double::INFINITY.recip_sqrt();
+        recip_sqrt_neg_inf:
+            Double::NAN,
+            Double::NEG_INFINITY.recip_sqrt();
+        recip_sqrt_nan:
+            Double::NAN,
+            Double::NAN.recip_sqrt();
+    );
+    test!(recip_sqrt_random: {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        let mut next = || rng.next_f64();
+        for i in 0..20_000 {
+            let e = (i % 2000) - 1000;
+            let m = 1.0 + next();
+            let x = Double::from_components(m, next() * 1e-16 * m).ldexp(e);
+            // The Quad reciprocal square root is exact to well past the last bit of a
+            // Double, while the composed version rounds twice and can be several EPSILON
+            // off
+            let expected = Quad::ONE / Quad::from(x).sqrt();
+            let diff = ((Quad::from(x.recip_sqrt()) - expected) / expected).abs();
+            assert!(
+                diff <= Quad::from(Double::EPSILON.ldexp(1)),
+                "{:?}.recip_sqrt() = {:?}, expected {:?}",
+                x,
+                x.recip_sqrt(),
+                expected
+            );
+        }
+    });
+
+    // hypot tests
+    test_all_prec!(
+        hypot_3_4_e200:
+            dd!("5e200"),
+            dd!("3e200").hypot(dd!("4e200")),
+            31;
+        hypot_3_4_pwr2:
+            c::scale(dd!(5), 600),
+            c::scale(dd!(3), 600).hypot(c::scale(dd!(-4), 600)),
+            31;
+        hypot_tiny:
+            dd!("1.4142135623730950488016887242096980785697e-200"),
+            dd!("1e-200").hypot(dd!("1e-200")),
+            30;
+        hypot_near_max:
+            Double::MAX * Double::FRAC_1_SQRT_2,
+            c::mul_pwr2(Double::MAX, 0.5).hypot(c::mul_pwr2(Double::MAX, 0.5)),
+            30;
+        hypot3_1_2_2:
+            dd!(3),
+            dd!(1).hypot3(dd!(-2), dd!(2)),
+            31;
+        hypot3_tiny:
+            dd!("1.7320508075688772935274463415058723669428e-200"),
+            dd!("1e-200").hypot3(dd!("-1e-200"), dd!("1e-200")),
+            30;
+    );
+    test_all_exact!(
+        hypot_inf_nan:
+            Double::INFINITY,
+            Double::INFINITY.hypot(Double::NAN);
+        hypot_nan_neg_inf:
+            Double::INFINITY,
+            Double::NAN.hypot(Double::NEG_INFINITY);
+        hypot_nan:
+            Double::NAN,
+            Double::NAN.hypot(Double::ONE);
+        hypot_one_nan:
+            Double::NAN,
+            Double::ONE.hypot(Double::NAN);
+        hypot_zeros:
+            Double::ZERO,
+            Double::NEG_ZERO.hypot(Double::NEG_ZERO);
+        hypot_zero:
+            dd!(3),
+            dd!(-3).hypot(Double::ZERO);
+        hypot_max_one:
+            Double::MAX,
+            Double::MAX.hypot(Double::ONE);
+        hypot_overflow:
+            Double::INFINITY,
+            Double::MAX.hypot(-Double::MAX);
+        hypot_subnormal:
+            Double(1.14e-322, 0.0),
+            Double(f64::from_bits(16), 0.0).hypot(Double(f64::from_bits(16), 0.0));
+        hypot3_inf_nan:
+            Double::INFINITY,
+            Double::NAN.hypot3(Double::ONE, Double::NEG_INFINITY);
+        hypot3_nan:
+            Double::NAN,
+            Double::ONE.hypot3(Double::NAN, Double::ONE);
+        hypot3_zeros:
+            Double::ZERO,
+            Double::NEG_ZERO.hypot3(Double::ZERO, Double::NEG_ZERO);
+        hypot3_one_nonzero:
+            dd!(5),
+            Double::ZERO.hypot3(dd!(-5), Double::NEG_ZERO);
+        hypot3_overflow:
+            Double::INFINITY,
+            Double::MAX.hypot3(Double::MAX, Double::MAX);
+    );
+    test_all_assert!(
+        hypot_zeros_sign:
+            Double::NEG_ZERO.hypot(Double::NEG_ZERO).is_sign_positive();
+        hypot3_near_max:
+            c::mul_pwr2(Double::MAX, 0.5)
+                .hypot3(c::mul_pwr2(Double::MAX, 0.5), c::mul_pwr2(Double::MAX, 0.5))
+                .is_finite();
+    );
+    // Inside the direct range, the result is exactly the naive formula's
+    test!(hypot_matches_direct: {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        let mut next = || {
+            let n = rng.next_u64();
+            Double::from((n >> 11) as f64) / Double::from((n % 1000 + 1) as f64)
+        };
+        for _ in 0..1000 {
+            let (x, y) = (next().ldexp(-30), -next());
+            assert!(x.hypot(y) == (x.sqr() + y.sqr()).sqrt());
+        }
+    });
+
+    // agm tests
+    test_all_prec!(
+        agm_gauss:
+            dd!("1.1981402347355922074399224922803239"),
+            dd!(1).agm(dd!(2).sqrt()),
+            30;
+        agm_gauss_constant:
+            dd!("0.83462684167407318628142973279904681"),
+            dd!(1).agm(dd!(2).sqrt()).recip(),
+            30;
+        agm_1_2:
+            dd!("1.4567910310469068691864323832650820"),
+            dd!(1).agm(dd!(2)),
+            30;
+        agm_elliptic_k:
+            dd!("1.8540746773013719184338503471952600"),
+            Double::FRAC_PI_2 / dd!(1).agm(dd!(0.5).sqrt()),
+            30;
+        agm_tiny:
+            dd!("0.0022616567573987993062014385956669994"),
+            dd!(1).agm(dd!(2).powi(-1000)),
+            30;
+        agm_huge_ratio:
+            dd!("1.2129028228860662974187704609671613e298"),
+            dd!(2).powi(1000).agm(dd!(2).powi(-1000)),
+            30;
+        agm_max:
+            dd!("2.4233846820537971331660463701160250e298"),
+            dd!(1).agm(dd!(2).powi(1000)),
+            30;
+    );
+    test_all_exact!(
+        agm_same:
+            dd!(7),
+            dd!(7).agm(dd!(7));
+        agm_same_pi:
+            Double::PI,
+            Double::PI.agm(Double::PI);
+        agm_zero:
+            Double::ZERO,
+            dd!(5).agm(Double::ZERO);
+        agm_neg_zero:
+            Double::ZERO,
+            Double::NEG_ZERO.agm(dd!(5));
+        agm_inf:
+            Double::INFINITY,
+            Double::INFINITY.agm(dd!(5));
+        agm_zero_inf:
+            Double::NAN,
+            Double::ZERO.agm(Double::INFINITY);
+        agm_neg:
+            Double::NAN,
+            dd!(-1).agm(dd!(2));
+        agm_neg_inf:
+            Double::NAN,
+            dd!(2).agm(Double::NEG_INFINITY);
+        agm_nan:
+            Double::NAN,
+            Double::NAN.agm(dd!(2));
+        agm_max_max:
+            Double::MAX,
+            Double::MAX.agm(Double::MAX);
+    );
+    test_all_assert!(
+        agm_max_min:
+            Double::MAX.agm(Double::MIN_POSITIVE).is_finite();
+        agm_between:
+            dd!(1).agm(dd!(100)) > dd!(10) && dd!(1).agm(dd!(100)) < dd!("50.5");
+    );
+    test!(agm_symmetric: {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        for _ in 0..200 {
+            let mut arg = || {
+                let (h, t) = ((rng.next_u64() >> 11) as f64, (rng.next_u64() >> 11) as f64);
+                Double::from_sum(&[h, t * 1e-20]).ldexp(rng.range(-200, 200))
+            };
+            let (a, b) = (arg(), arg());
+            let (x, y) = (a.agm(b), b.agm(a));
+            assert!(x.to_bits() == y.to_bits(), "{:?}.agm({:?})", a, b);
+            // The mean is between the geometric and arithmetic means of the arguments
+            assert!(x >= (a * b).sqrt() * (Double::ONE - Double::EPSILON));
+            assert!(x <= (a + b) / dd!(2) * (Double::ONE + Double::EPSILON));
+        }
+    });
+    // Scaling the arguments by a power of 2 scales the mean by the same power
+    test!(agm_homogeneous: {
+        let x = dd!(3).agm(Double::PI);
+        for &k in &[-1000, -500, -1, 1, 500, 1000] {
+            assert!(dd!(3).ldexp(k).agm(Double::PI.ldexp(k)) == x.ldexp(k), "2^{}", k);
+        }
+    });
+
     // nroot tests
     test_all_near!(
         nroot_pi_one:
@@ -598,7 +1785,52 @@ mod tests {
         nroot_neg_140_odd:
             dd!("-0.6381127926744792012439998824149406"),
             dd!(-140).nroot(-11);
+        nroot_2_fifth:
+            dd!("1.1486983549970350067986269467779276"),
+            dd!(2).nroot(5);
+        nroot_1e60_fifth:
+            dd!("1e12"),
+            dd!("1e60").nroot(5);
+        nroot_1e60_seventh:
+            dd!("372759372.0314940166172490609473041"),
+            dd!("1e60").nroot(7);
+        nroot_1e_neg_60_fifth:
+            dd!("1e-12"),
+            dd!("1e-60").nroot(5);
+        nroot_1e_neg_60_seventh:
+            dd!("2.682695795279725747698802680627625e-9"),
+            dd!("1e-60").nroot(7);
+        nroot_1e100_fifth:
+            dd!("1e20"),
+            dd!("1e100").nroot(5);
+        nroot_1e100_seventh:
+            dd!("193069772888325.0167007074799840189"),
+            dd!("1e100").nroot(7);
+        nroot_1e_neg_100_fifth:
+            dd!("1e-20"),
+            dd!("1e-100").nroot(5);
+        nroot_1e_neg_100_seventh:
+            dd!("5.1794746792312111347551746779609534e-15"),
+            dd!("1e-100").nroot(7);
+        nroot_1e150_fifth:
+            dd!("1e30"),
+            dd!("1e150").nroot(5);
+        nroot_1e150_seventh:
+            dd!("2682695795279725747698.802680627625"),
+            dd!("1e150").nroot(7);
+        nroot_1e_neg_150_fifth:
+            dd!("1e-30"),
+            dd!("1e-150").nroot(5);
+        nroot_1e_neg_150_seventh:
+            dd!("3.727593720314940166172490609473041e-22"),
+            dd!("1e-150").nroot(7);
     );
+    test!(nroot_powi_round_trip: {
+        for x in [dd!(2), Double::PI, dd!(-1000), dd!(1e-250), dd!(1e250)] {
+            prec!(x, x.nroot(7).powi(7), 30);
+            prec!(x, x.nroot(-7).powi(-7), 30);
+        }
+    });
     test_all_exact!(
         nroot_neg_pi_even:
             Double::NAN,
@@ -700,8 +1932,14 @@ mod tests {
         cbrt_neg_140:
             dd!("-5.1924941018511040261944552486014427"),
             dd!(-140).cbrt();
+        cbrt_27:
+            dd!(3),
+            dd!(27).cbrt();
     );
     test_all_exact!(
+        cbrt_neg_8:
+            dd!(-2),
+            dd!(-8).cbrt();
         cbrt_zero:
             Double::ZERO,
             Double::ZERO.cbrt();
@@ -819,6 +2057,183 @@ mod tests {
             Double::NAN.powi(2);
     );
 
+    // pow and powu tests
+    fn same(a: Double, b: Double) -> bool {
+        a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan())
+    }
+
+    test!(pow_matches_powi: {
+        for &x in &[Double::PI, -Double::E, dd!(0.5), dd!(-2), Double::ZERO, Double::NEG_ZERO,
+                    Double::INFINITY, Double::NEG_INFINITY, Double::NAN] {
+            for n in -1100..=1100 {
+                assert!(same(x.pow(n), x.powi(n)));
+            }
+            for &n in &[i32::MIN, i32::MIN + 1, i32::MAX - 1, i32::MAX] {
+                assert!(same(x.pow(n), x.powi(n)));
+            }
+        }
+    });
+    test!(powu_matches_powi: {
+        for &x in &[Double::PI, -Double::E, dd!(0.5), dd!(-2), Double::ZERO, Double::NEG_ZERO,
+                    Double::INFINITY, Double::NEG_INFINITY, Double::NAN] {
+            for n in 0..=1100 {
+                assert!(same(x.powu(n), x.powi(n as i32)));
+            }
+            assert!(same(x.powu(i32::MAX as u32), x.powi(i32::MAX)));
+        }
+    });
+    // Each of the 32 squarings can double the relative error, just as it does in `powi`
+    test_all_prec!(
+        powu_large:
+            dd!("1.000000003725290304533446232188528139598"),
+            (dd!(1) + dd!(2).powi(-60)).powu(u32::MAX),
+            24;
+    );
+    test_all_exact!(
+        powu_neg_one_odd:
+            dd!(-1),
+            dd!(-1).powu(u32::MAX);
+        powu_neg_one_even:
+            dd!(1),
+            dd!(-1).powu(u32::MAX - 1);
+        powu_overflow:
+            Double::INFINITY,
+            dd!(2).powu(u32::MAX);
+        powu_neg_overflow:
+            Double::NEG_INFINITY,
+            dd!(-2).powu(u32::MAX);
+        powu_underflow:
+            Double::ZERO,
+            dd!(0.5).powu(u32::MAX);
+        powu_inf_large:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.powu(u32::MAX);
+        powu_nan_zero:
+            Double::ONE,
+            Double::NAN.powu(0);
+    );
+    test_all_assert!(
+        powu_neg_zero_odd_sign:
+            Double::NEG_ZERO.powu(u32::MAX).is_sign_negative();
+        powu_neg_zero_even_sign:
+            Double::NEG_ZERO.powu(u32::MAX - 1).is_sign_positive();
+    );
+
+    // powi with large exponents and powi64 tests
+    test!(powi_small_exponents: {
+        let x = Double::PI;
+        assert!(same(x.powi(1), x));
+        assert!(same(x.powi(-1), x.recip()));
+        assert!(same(x.powi(2), x.sqr()));
+        assert!(same(x.powi(-2), x.sqr().recip()));
+    });
+    test_all_prec!(
+        powi_4096:
+            dd!("2.7179500811896658725414143932781391865615"),
+            (dd!(1) + dd!(2).powi(-12)).powi(4096),
+            27;
+        powi_neg_4096:
+            dd!("0.36792434376215363180951807755980522487410"),
+            (dd!(1) + dd!(2).powi(-12)).powi(-4096),
+            27;
+        powi_min:
+            dd!("0.99999999813735485250376644445754257109340"),
+            (dd!(1) + dd!(2).powi(-60)).powi(i32::MIN),
+            21;
+        powi64_large:
+            dd!("1.0000009536747711537454463746588561671291"),
+            (dd!(1) + dd!(2).powi(-60)).powi64(1 << 40),
+            18;
+    );
+    test_all_exact!(
+        powi_1023:
+            Double(2f64.powi(1023), 0.0),
+            dd!(2).powi(1023);
+        powi_neg_1023:
+            Double(2f64.powi(-1023), 0.0),
+            dd!(2).powi(-1023);
+        powi_1024:
+            Double::INFINITY,
+            dd!(2).powi(1024);
+        powi_neg_1025:
+            Double::NEG_INFINITY,
+            dd!(-2).powi(1025);
+        powi_half_4096:
+            Double::ZERO,
+            dd!(0.5).powi(4096);
+        powi_half_neg_4096:
+            Double::INFINITY,
+            dd!(0.5).powi(-4096);
+        powi_min_subnormal:
+            Double(f64::from_bits(1), 0.0),
+            dd!(2).powi(-1074);
+        powi_below_subnormal:
+            Double::ZERO,
+            dd!(2).powi(-1075);
+        powi_one_min:
+            Double::ONE,
+            Double::ONE.powi(i32::MIN);
+        powi_neg_one_min:
+            Double::ONE,
+            Double::NEG_ONE.powi(i32::MIN);
+        powi_two_min:
+            Double::ZERO,
+            dd!(2).powi(i32::MIN);
+        powi_half_min:
+            Double::INFINITY,
+            dd!(0.5).powi(i32::MIN);
+        powi_neg_zero_min:
+            Double::INFINITY,
+            Double::NEG_ZERO.powi(i32::MIN);
+        powi_nan_min:
+            Double::NAN,
+            Double::NAN.powi(i32::MIN);
+        powi64_neg_one_max:
+            Double::NEG_ONE,
+            Double::NEG_ONE.powi64(i64::MAX);
+        powi64_neg_one_min:
+            Double::ONE,
+            Double::NEG_ONE.powi64(i64::MIN);
+        powi64_overflow:
+            Double::INFINITY,
+            dd!(1.5).powi64(1 << 40);
+        powi64_neg_overflow:
+            Double::NEG_INFINITY,
+            dd!(-1.5).powi64((1 << 40) + 1);
+        powi64_underflow:
+            Double::ZERO,
+            dd!(1.5).powi64(-(1 << 40));
+        powi64_neg_underflow:
+            Double::NEG_ZERO,
+            dd!(-1.5).powi64(-(1 << 40) - 1);
+        powi64_zero_min:
+            Double::INFINITY,
+            Double::ZERO.powi64(i64::MIN);
+    );
+    test_all_assert!(
+        powi_neg_underflow_sign:
+            dd!(-2).powi(-1075).is_sign_negative();
+        powi64_neg_underflow_sign:
+            dd!(-1.5).powi64(-(1 << 40) - 1).is_sign_negative();
+    );
+    // The power itself can't be represented, but the result can
+    test!(powi_subnormal: {
+        let x = dd!(3).powi(-650);
+        assert!((x[0] - 7.433347434966866668478949944540770687364e-311).abs() <= f64::from_bits(1));
+        assert!(x[1] == 0.0);
+    });
+    test!(powi64_matches_powi: {
+        for &x in &[Double::PI, -Double::E, dd!(0.5), dd!(-2), Double::ZERO, Double::NEG_ZERO,
+                    Double::INFINITY, Double::NAN] {
+            for n in -1100..=1100 {
+                assert!(same(x.powi64(n.into()), x.powi(n)));
+            }
+            for &n in &[i32::MIN, i32::MAX] {
+                assert!(same(x.powi64(n.into()), x.powi(n)));
+            }
+        }
+    });
+
     // powf_tests
     test_all_near!(
         powf_e:
@@ -836,6 +2251,21 @@ mod tests {
         powf_1_sqrt_2:
             dd!("1.2715371297141403818925670498911262"),
             Double::FRAC_1_SQRT_2.powf(-Double::LN_2);
+        powf_frac_neg:
+            dd!("2.0528009571186693849214178862291821"),
+            dd!(0.75).powf(dd!(-2.5));
+        powf_tenth:
+            dd!("1.5856838616782966099137007832322975"),
+            dd!(100.5).powf(dd!(0.1));
+        powf_half:
+            dd!("1.4142135623730950488016887242096981"),
+            dd!(2).powf(dd!(0.5));
+        powf_small_result:
+            dd!("1.7661613150638955702158820397198090e-8"),
+            dd!(12.25).powf(dd!(-7.125));
+        powf_small_base:
+            dd!("5.6234132519034908039495103977648123e-6"),
+            dd!(0.001).powf(dd!(1.75));
     );
     test_all_prec!(
         powf_pi:
@@ -852,7 +2282,7 @@ mod tests {
             Double::ZERO,
             Double::ZERO.powf(dd!(3));
         powf_neg_zero:
-            Double::ZERO,
+            Double::NEG_ZERO,
             Double::NEG_ZERO.powf(dd!(3));
         powf_zero_inf:
             Double::ZERO,
@@ -880,29 +2310,29 @@ mod tests {
             Double::ONE,
             dd!(2).powf(Double::NEG_ZERO);
         powf_zero_zero:
-            Double::NAN,
+            Double::ONE,
             Double::ZERO.powf(Double::ZERO);
         powf_neg_zero_zero:
-            Double::NAN,
+            Double::ONE,
             Double::NEG_ZERO.powf(Double::ZERO);
         powf_zero_neg_zero:
-            Double::NAN,
+            Double::ONE,
             Double::ZERO.powf(Double::NEG_ZERO);
         powf_neg_zero_neg_zero:
-            Double::NAN,
+            Double::ONE,
             Double::NEG_ZERO.powf(Double::NEG_ZERO);
 
         powf_inf_zero:
-            Double::NAN,
+            Double::ONE,
             Double::INFINITY.powf(Double::ZERO);
         powf_inf_neg_zero:
-            Double::NAN,
+            Double::ONE,
             Double::INFINITY.powf(Double::NEG_ZERO);
         powf_neg_inf_zero:
-            Double::NAN,
+            Double::ONE,
             Double::NEG_INFINITY.powf(Double::ZERO);
         powf_neg_inf_neg_zero:
-            Double::NAN,
+            Double::ONE,
             Double::NEG_INFINITY.powf(Double::NEG_ZERO);
 
         powf_exp_inf:
@@ -912,10 +2342,10 @@ mod tests {
             Double::ZERO,
             dd!(2).powf(Double::NEG_INFINITY);
         powf_one_inf:
-            Double::NAN,
+            Double::ONE,
             dd!(1).powf(Double::INFINITY);
         powf_one_neg_inf:
-            Double::NAN,
+            Double::ONE,
             dd!(1).powf(Double::NEG_INFINITY);
 
         powf_nan:
@@ -925,10 +2355,177 @@ mod tests {
             Double::NAN,
             dd!(3).powf(Double::NAN);
         powf_neg:
-            Double::NAN,
+            Double::NEG_ONE,
             dd!(-1).powf(dd!(1));
+
+        powf_one_nan:
+            Double::ONE,
+            dd!(1).powf(Double::NAN);
+        powf_nan_zero:
+            Double::ONE,
+            Double::NAN.powf(Double::ZERO);
+        powf_small_inf:
+            Double::ZERO,
+            dd!(0.5).powf(Double::INFINITY);
+        powf_small_neg_inf:
+            Double::INFINITY,
+            dd!(-0.5).powf(Double::NEG_INFINITY);
+        powf_neg_one_inf:
+            Double::ONE,
+            Double::NEG_ONE.powf(Double::INFINITY);
+        powf_neg_one_neg_inf:
+            Double::ONE,
+            Double::NEG_ONE.powf(Double::NEG_INFINITY);
+        powf_neg_big_inf:
+            Double::INFINITY,
+            dd!(-2).powf(Double::INFINITY);
+        powf_neg_zero_neg_odd:
+            Double::NEG_INFINITY,
+            Double::NEG_ZERO.powf(dd!(-3));
+        powf_neg_zero_frac:
+            Double::ZERO,
+            Double::NEG_ZERO.powf(dd!(0.5));
+        powf_inf_pos:
+            Double::INFINITY,
+            Double::INFINITY.powf(dd!(0.5));
+        powf_inf_neg:
+            Double::ZERO,
+            Double::INFINITY.powf(dd!(-2));
+        powf_neg_inf_odd:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.powf(dd!(3));
+        powf_neg_inf_even:
+            Double::INFINITY,
+            Double::NEG_INFINITY.powf(dd!(2));
+        powf_neg_inf_frac:
+            Double::INFINITY,
+            Double::NEG_INFINITY.powf(dd!(0.5));
+        powf_neg_inf_neg_odd:
+            Double::NEG_ZERO,
+            Double::NEG_INFINITY.powf(dd!(-3));
+    );
+    test_all_assert!(
+        // `exact` doesn't tell the zeros apart
+        powf_neg_zero_odd_sign:
+            Double::NEG_ZERO.powf(dd!(3)).is_sign_negative();
+        powf_neg_zero_even_sign:
+            Double::NEG_ZERO.powf(dd!(2)).is_sign_positive();
+        powf_neg_inf_neg_odd_sign:
+            Double::NEG_INFINITY.powf(dd!(-3)).is_sign_negative();
+        powf_neg_inf_neg_even_sign:
+            Double::NEG_INFINITY.powf(dd!(-2)).is_sign_positive();
+    );
+
+    // powf tests for negative bases, which need an exponent that's an integer all the way
+    // down to its last component, and whose parity can live in any component
+    test_all_exact!(
+        powf_neg_odd:
+            dd!(-8),
+            dd!(-2).powf(dd!(3));
+        powf_neg_even:
+            dd!(16),
+            dd!(-2).powf(dd!(4));
+        powf_neg_odd_recip:
+            dd!(-0.125),
+            dd!(-2).powf(dd!(-3));
+        powf_neg_computed:
+            dd!(-8),
+            dd!(-2).powf(dd!(6) / dd!(2));
+        powf_neg_below_int:
+            Double::NAN,
+            dd!(-2).powf(dd!("2.99999999999999999999999999999"));
+        powf_neg_above_int:
+            Double::NAN,
+            dd!(-2).powf(dd!("3.00000000000000000000000000001"));
+        powf_neg_frac:
+            Double::NAN,
+            dd!(-2).powf(dd!(0.5));
+        powf_neg_huge_even:
+            Double::ONE,
+            Double::NEG_ONE.powf(Double(2f64.powi(200), 0.0));
+        powf_neg_huge_odd:
+            Double::NEG_ONE,
+            Double::NEG_ONE.powf(Double(2f64.powi(60), 1.0));
+        powf_neg_huge_odd_neg_tail:
+            Double::NEG_ONE,
+            Double::NEG_ONE.powf(Double(2f64.powi(60), -1.0));
+        powf_neg_huge_frac:
+            Double::NAN,
+            Double::NEG_ONE.powf(Double(2f64.powi(60), 0.5));
+        powf_neg_huge_overflow:
+            Double::NEG_INFINITY,
+            dd!(-2).powf(Double(2f64.powi(60), 1.0));
     );
 
+    // mul_add tests
+    test_all_exact!(
+        mul_add_simple:
+            dd!(7),
+            dd!(2).mul_add(dd!(3), dd!(1));
+        // x² - y² where the rounding of x² is all that's left
+        mul_add_square_cancel:
+            dd!(2).powi(-59) + dd!(2).powi(-120),
+            (dd!(1) + dd!(2).powi(-60)).mul_add(dd!(1) + dd!(2).powi(-60), -(dd!(1) * dd!(1)));
+        mul_add_product_error:
+            Double(3.0 * 2f64.powi(-54), 2f64.powi(-110)),
+            Double(3.0, 2f64.powi(-55)).mul_add(Double(3.0, 2f64.powi(-55)), dd!(-9));
+        mul_add_exact_cancel:
+            Double::ZERO,
+            Double::PI.mul_add(dd!(2), -Double::TAU);
+        mul_add_zero_factor:
+            Double::E,
+            Double::ZERO.mul_add(Double::PI, Double::E);
+
+        mul_add_inf:
+            Double::INFINITY,
+            Double::INFINITY.mul_add(dd!(2), dd!(1));
+        mul_add_neg_inf:
+            Double::NEG_INFINITY,
+            Double::INFINITY.mul_add(dd!(-2), dd!(1));
+        mul_add_inf_addend:
+            Double::INFINITY,
+            Double::PI.mul_add(Double::E, Double::INFINITY);
+        mul_add_inf_zero:
+            Double::NAN,
+            Double::INFINITY.mul_add(Double::ZERO, dd!(1));
+        mul_add_inf_cancel:
+            Double::NAN,
+            Double::INFINITY.mul_add(dd!(1), Double::NEG_INFINITY);
+        mul_add_nan:
+            Double::NAN,
+            Double::NAN.mul_add(dd!(1), dd!(1));
+        mul_add_nan_addend:
+            Double::NAN,
+            dd!(1).mul_add(dd!(1), Double::NAN);
+    );
+    test_all_assert!(
+        mul_add_neg_zero:
+            Double::NEG_ZERO.mul_add(Double::PI, Double::NEG_ZERO).is_sign_negative();
+        mul_add_mixed_zero:
+            Double::NEG_ZERO.mul_add(Double::PI, Double::ZERO).is_sign_positive();
+        mul_add_cancel_zero:
+            dd!(-2).mul_add(dd!(3), dd!(6)).is_sign_positive();
+        // The separate operations lose the second component of the result
+        mul_add_beats_operators:
+            {
+                let x = Double(3.0, 2f64.powi(-55));
+                let naive = x * x - dd!(9);
+                let fused = x.mul_add(x, dd!(-9));
+                naive[1] == 0.0 && fused[1] == 2f64.powi(-110)
+            };
+    );
+    test!(mul_add_matches_operators: {
+        // Without cancellation, the operators are already accurate
+        for &(x, a, b) in &[
+            (Double::PI, Double::E, Double::LN_2),
+            (-Double::PI, Double::E, Double::LN_10),
+            (Double::SQRT_2, Double::SQRT_2, Double::ONE),
+        ] {
+            let diff = (x.mul_add(a, b) - (x * a + b)).abs();
+            assert!(diff <= (x * a + b).abs() * Double::EPSILON * dd!(4));
+        }
+    });
+
     // recip tests
     test_all_near!(
         recip_pi:
@@ -0,0 +1,607 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::reduction as r;
+use crate::common::strict as s;
+use crate::double::common as c;
+use crate::double::Double;
+
+// The argument below which the Bessel functions are calculated with their power series.
+// The largest term of the series for J0 at x = 4 is 4, so less than a digit is lost to
+// cancellation below it. The backward recurrence takes over from here.
+const SERIES_MAX: f64 = 4.0;
+
+// The argument at and above which the Bessel functions are calculated with their
+// asymptotic expansions. The smallest term of those expansions is about e^(-2x), which is
+// less than a `Double`'s epsilon once x is more than about 37.
+const ASYMPTOTIC_MIN: f64 = 40.0;
+
+impl Double {
+    /// Computes the Bessel function of the first kind of order zero, J<sub>0</sub>(*x*),
+    /// where *x* is this `Double`.
+    ///
+    /// For |*x*| < 4, this is calculated with the power series
+    ///
+    /// J<sub>0</sub>(*x*) = Σ (-*x*²/4)<sup>*k*</sup> / (*k*!)²,
+    ///
+    /// and up to 40 with Miller's algorithm, running the recurrence
+    /// J<sub>*n*-1</sub>(*x*) = (2*n* / *x*) J<sub>*n*</sub>(*x*) - J<sub>*n*+1</sub>(*x*)
+    /// backwards from an order high enough that the starting values don't matter, then
+    /// scaling the results so that J<sub>0</sub> + 2J<sub>2</sub> + 2J<sub>4</sub> + ⋯ = 1.
+    /// Past 40, it's calculated with Hankel's asymptotic expansion
+    ///
+    /// J<sub>0</sub>(*x*) = √(2 / π*x*) (*P*(*x*) cos(*x* - π/4) - *Q*(*x*) sin(*x* - π/4)),
+    ///
+    /// where the phase is reduced exactly, the same way that [`sin`] and [`cos`] reduce
+    /// their arguments, so nothing is lost however large *x* is.
+    ///
+    /// The error is about 10<sup>-32</sup> at most in absolute terms, shrinking as the
+    /// amplitude of the function does like 1/√*x*. That's about 31 digits wherever the
+    /// function isn't close to zero, but since J<sub>0</sub> crosses zero infinitely many
+    /// times, the relative error near those crossings is larger by however much smaller
+    /// than its amplitude the function is. At *x* = 2.4048, within 3 × 10<sup>-5</sup> of
+    /// the first zero, it's still about 27 digits.
+    ///
+    /// J<sub>0</sub> is even, and J<sub>0</sub>(±∞) = 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(1).j0();
+    /// let expected = dd!("0.76519768655796655144971752610266322");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`sin`]: #method.sin
+    /// [`cos`]: #method.cos
+    pub fn j0(self) -> Double {
+        match self.pre_j0() {
+            Some(r) => s::guard("Double::j0", &[self.0], r),
+            None => {
+                let x = self.abs();
+                if x.0 < SERIES_MAX {
+                    series(x, 0).0
+                } else if x.0 < ASYMPTOTIC_MIN {
+                    recurrence(x).0
+                } else {
+                    asymptotic(x, 0).0
+                }
+            }
+        }
+    }
+
+    /// Computes the Bessel function of the first kind of order one, J<sub>1</sub>(*x*),
+    /// where *x* is this `Double`.
+    ///
+    /// This is calculated the same way as [`j0`], with the power series
+    ///
+    /// J<sub>1</sub>(*x*) = (*x*/2) Σ (-*x*²/4)<sup>*k*</sup> / (*k*! (*k* + 1)!)
+    ///
+    /// for |*x*| < 4, Miller's algorithm up to 40, and Hankel's asymptotic expansion, with
+    /// the phase *x* - 3π/4 reduced exactly, past that. Its precision is the same as that
+    /// of `j0`: about 10<sup>-32</sup> in absolute terms, which is about 31 digits except
+    /// close to the function's zeros.
+    ///
+    /// J<sub>1</sub> is odd, and J<sub>1</sub>(±∞) = 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(1).j1();
+    /// let expected = dd!("0.44005058574493351595968220371891491");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`j0`]: #method.j0
+    pub fn j1(self) -> Double {
+        match self.pre_j1() {
+            Some(r) => s::guard("Double::j1", &[self.0], r),
+            None => {
+                let x = self.abs();
+                let r = if x.0 < SERIES_MAX {
+                    c::mul_pwr2(x, 0.5) * series(x, 1).0
+                } else if x.0 < ASYMPTOTIC_MIN {
+                    recurrence(x).1
+                } else {
+                    asymptotic(x, 1).0
+                };
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
+    /// Computes the Bessel function of the second kind of order zero, Y<sub>0</sub>(*x*),
+    /// where *x* is this `Double`.
+    ///
+    /// For *x* < 4, this is calculated with the series
+    ///
+    /// Y<sub>0</sub>(*x*) = (2/π) (ln(*x*/2) + γ) J<sub>0</sub>(*x*) - (2/π) Σ
+    /// *H*<sub>*k*</sub> (-*x*²/4)<sup>*k*</sup> / (*k*!)²,
+    ///
+    /// where *H*<sub>*k*</sub> is the *k*th harmonic number and γ is Euler's constant. Up to
+    /// 40, the values of J<sub>2*k*</sub> that Miller's algorithm finds for [`j0`] are summed
+    /// in Neumann's series
+    ///
+    /// Y<sub>0</sub>(*x*) = (2/π) ((ln(*x*/2) + γ) J<sub>0</sub>(*x*) - 2 Σ
+    /// (-1)<sup>*k*</sup> J<sub>2*k*</sub>(*x*) / *k*),
+    ///
+    /// and past that, Hankel's expansion is used as it is for `j0`. The precision is the
+    /// same as that of `j0`, about 10<sup>-32</sup> in absolute terms, and like it, the
+    /// relative error grows close to the function's zeros.
+    ///
+    /// Y<sub>0</sub> is only defined for positive arguments; this returns NaN for negative
+    /// ones. Y<sub>0</sub>(0) = -∞ and Y<sub>0</sub>(∞) = 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(1).y0();
+    /// let expected = dd!("0.088256964215676957982926766023515163");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`j0`]: #method.j0
+    pub fn y0(self) -> Double {
+        match self.pre_y0() {
+            Some(r) => s::guard("Double::y0", &[self.0], r),
+            None => {
+                if self.0 < SERIES_MAX {
+                    let (j, h) = series(self, 0);
+                    Double::FRAC_2_PI * (log_term(self) * j - c::mul_pwr2(h, 0.5))
+                } else if self.0 < ASYMPTOTIC_MIN {
+                    let (j, _, s, _) = recurrence(self);
+                    Double::FRAC_2_PI * (log_term(self) * j - c::mul_pwr2(s, 2.0))
+                } else {
+                    asymptotic(self, 0).1
+                }
+            }
+        }
+    }
+
+    /// Computes the Bessel function of the second kind of order one, Y<sub>1</sub>(*x*),
+    /// where *x* is this `Double`.
+    ///
+    /// This is calculated the same way as [`y0`]: with the series
+    ///
+    /// Y<sub>1</sub>(*x*) = (2/π) (ln(*x*/2) + γ) J<sub>1</sub>(*x*) - 2 / π*x* - (*x* / 2π)
+    /// Σ (*H*<sub>*k*</sub> + *H*<sub>*k*+1</sub>) (-*x*²/4)<sup>*k*</sup> / (*k*! (*k* +
+    /// 1)!)
+    ///
+    /// for *x* < 4, with Neumann's series
+    ///
+    /// Y<sub>1</sub>(*x*) = (2/π) ((ln(*x*/2) + γ - 1) J<sub>1</sub>(*x*) - J<sub>0</sub>(*x*)
+    /// / *x* - Σ (-1)<sup>*k*</sup> (2*k* + 1) J<sub>2*k*+1</sub>(*x*) / *k*(*k* + 1))
+    ///
+    /// up to 40, and with Hankel's expansion past that. The precision is the same as that
+    /// of `y0`.
+    ///
+    /// Y<sub>1</sub> is only defined for positive arguments; this returns NaN for negative
+    /// ones. Y<sub>1</sub>(0) = -∞ and Y<sub>1</sub>(∞) = 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(1).y1();
+    /// let expected = dd!("-0.78121282130028871654715000004796482");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`y0`]: #method.y0
+    pub fn y1(self) -> Double {
+        match self.pre_y1() {
+            Some(r) => s::guard("Double::y1", &[self.0], r),
+            None => {
+                if self.0 < SERIES_MAX {
+                    let (a, h) = series(self, 1);
+                    let half = c::mul_pwr2(self, 0.5);
+                    let r = log_term(self) * half * a - c::mul_pwr2(half * h, 0.5);
+                    Double::FRAC_2_PI * (r - self.recip())
+                } else if self.0 < ASYMPTOTIC_MIN {
+                    let (j0, j1, _, s) = recurrence(self);
+                    let r = (log_term(self) - Double::ONE) * j1 - j0 / self - s;
+                    Double::FRAC_2_PI * r
+                } else {
+                    asymptotic(self, 1).1
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn pre_j0(&self) -> Option<Double> {
+        if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.is_infinite() {
+            Some(Double::ZERO)
+        } else if self.is_zero() {
+            Some(Double::ONE)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_j1(&self) -> Option<Double> {
+        if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.is_infinite() {
+            Some(Double::ZERO)
+        } else if self.is_zero() {
+            // Includes both zeros, which keep their signs
+            Some(*self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_y0(&self) -> Option<Double> {
+        if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.is_zero() {
+            Some(Double::NEG_INFINITY)
+        } else if self.is_sign_negative() {
+            Some(Double::NAN)
+        } else if self.is_infinite() {
+            Some(Double::ZERO)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_y1(&self) -> Option<Double> {
+        self.pre_y0()
+    }
+}
+
+// ln(x/2) + γ, the factor that multiplies J0 and J1 in the series for Y0 and Y1.
+fn log_term(x: Double) -> Double {
+    c::mul_pwr2(x, 0.5).ln() + Double::EULER_GAMMA
+}
+
+// Computes the two sums
+//
+//      Σ (-x²/4)^k / (k! (k + n)!)   and   Σ (H_k + H_(k+n)) (-x²/4)^k / (k! (k + n)!),
+//
+// where H_k is the kth harmonic number (and H_0 = 0), for n = 0 or 1. The first is
+// J_n(x) / (x/2)^n, and the second is the part of the series for Y_n that isn't a multiple
+// of J_n. They're summed side by side so that they share their powers and factorials.
+fn series(x: Double, n: usize) -> (Double, Double) {
+    let z = -c::mul_pwr2(x.sqr(), 0.25);
+    let mut t = Double::ONE;
+    let mut sum = Double::ONE;
+    let mut h = if n == 0 { Double::ZERO } else { Double::ONE };
+    let mut hsum = h;
+    let mut k = 0;
+    loop {
+        k += 1;
+        t = t * z / Double((k * (k + n)) as f64, 0.0);
+        h += Double(k as f64, 0.0).recip() + Double((k + n) as f64, 0.0).recip();
+        let u = t * h;
+        sum += t;
+        hsum += u;
+        if t.0.abs() <= sum.0.abs() * Double::EPSILON.0
+            && u.0.abs() <= hsum.0.abs() * Double::EPSILON.0
+        {
+            break;
+        }
+    }
+    (sum, hsum)
+}
+
+// Runs the recurrence J_(n-1)(x) = (2n/x) J_n(x) - J_(n+1)(x) backwards, starting from
+// J_N = 1 and J_(N+1) = 0 for some large N, and scales the results so that
+//
+//      J_0(x) + 2 Σ J_2k(x) = 1.
+//
+// This is Miller's algorithm. The recurrence is stable in this direction, and whatever
+// error the made-up starting values bring dies out long before the low orders are
+// reached, as long as N is far enough past x. The N here was found by comparing results
+// against reference values, with some margin added.
+//
+// Besides J0 and J1, this returns the two sums in Neumann's series for Y0 and Y1,
+//
+//      Σ (-1)^k J_2k(x) / k   and   Σ (-1)^k (2k + 1) J_(2k+1)(x) / k(k + 1),
+//
+// over k >= 1, since they need every order that the recurrence runs through. At most
+// orders, the values are far too small to matter, but finding them is cheap next to the
+// divisions in the recurrence itself.
+fn recurrence(x: Double) -> (Double, Double, Double, Double) {
+    let top = (x.0 + 30.0 + 7.0 * x.0.sqrt()) as i32 & !1;
+    let r = c::mul_pwr2(x.recip(), 2.0);
+    let mut above = Double::ZERO;
+    let mut j = Double::ONE;
+    let mut norm = Double::ZERO;
+    let mut even = Double::ZERO;
+    let mut odd = Double::ZERO;
+    for n in (1..=top).rev() {
+        let k = n / 2;
+        let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+        if n % 2 == 0 {
+            norm += j;
+            even += j / Double(sign * k as f64, 0.0);
+        } else if n > 1 {
+            let t = j.mul_i32(2 * k + 1);
+            odd += t / Double(sign * (k * (k + 1)) as f64, 0.0);
+        }
+        let below = r.mul_i32(n) * j - above;
+        above = j;
+        j = below;
+    }
+    // j and above now hold J0 and J1, to within a common factor
+    let f = (c::mul_pwr2(norm, 2.0) + j).recip();
+    (j * f, above * f, even * f, odd * f)
+}
+
+// Computes J_n(x) and Y_n(x), for n = 0 or 1, with Hankel's asymptotic expansion
+//
+//      J_n(x) = √(2/πx) (P cos θ - Q sin θ),    Y_n(x) = √(2/πx) (P sin θ + Q cos θ),
+//
+// where θ = x - (2n + 1)π/4 and
+//
+//      P = Σ (-1)^k a_2k / x^2k,    Q = Σ (-1)^k a_(2k+1) / x^(2k+1),
+//      a_k = (4n² - 1²)(4n² - 3²) ··· (4n² - (2k - 1)²) / (k! 8^k).
+//
+// The expansion diverges, but its terms get smaller until k is about 2x, and by then
+// they're around e^(-2x), far smaller than needed.
+//
+// Subtracting (2n + 1)π/4 from x after rounding it would lose the phase's low bits just
+// where the functions cross zero, so x is reduced in quarter turns first, the same way
+// as it is for sin and cos, and the offset is taken out of the exact remainder.
+fn asymptotic(x: Double, n: i32) -> (Double, Double) {
+    let mu = (4 * n * n) as f64;
+    let y = c::mul_pwr2(x, 8.0);
+    let mut p = Double::ONE;
+    let mut q = Double::ZERO;
+    let mut t = Double::ONE;
+    let mut k = 1;
+    while t.0.abs() > Double::EPSILON.0 {
+        let d = (2 * k - 1) as f64;
+        t = t * Double(mu - d * d, 0.0) / y.mul_i32(k);
+        match k % 4 {
+            0 => p += t,
+            1 => q += t,
+            2 => p -= t,
+            _ => q -= t,
+        }
+        k += 1;
+    }
+
+    // x = (4m + j + k/2 + f)π/2, with |f| <= 1/4, and θ is x less n + 1/2 quarter turns.
+    // That leaves either f or f + 1/2 quarter turns once the whole ones are taken out,
+    // and in the second case, f + 1/2 is in [1/4, 3/4], so its sine and cosine are both
+    // far enough from zero that the rounding of the sum doesn't matter.
+    let mut f = [0.0; 2];
+    let (j, k) = r::quarter_turns(&x.to_array(), 2, &mut f);
+    let f = Double(f[0], f[1]);
+    let (turns, f) = match k {
+        0 => (j - n - 1, f + Double(0.5, 0.0)),
+        1 => (j - n, f),
+        _ => (j - n - 1, f),
+    };
+    let (s, c) = (f * Double::FRAC_PI_2).sin_cos();
+    let (sin, cos) = match turns & 3 {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    };
+    let a = (Double::FRAC_2_PI / x).sqrt();
+    (a * (p * cos - q * sin), a * (p * sin + q * cos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // j0 tests
+    test_all_prec!(
+        j0_half:
+            dd!("0.938469807240812904228404673599712626"),
+            dd!(0.5).j0(),
+            29;
+        j0_one:
+            dd!("0.765197686557966551449717526102663221"),
+            dd!(1).j0(),
+            29;
+        j0_five:
+            dd!("-0.177596771314338304347397013074758711"),
+            dd!(5).j0(),
+            29;
+        j0_zero_crossing:
+            dd!("0.0000132682843010815608935183344575654829"),
+            dd!(2.4048).j0(),
+            26;
+        j0_ten:
+            dd!("-0.245935764451348335197760862485328754"),
+            dd!(10).j0(),
+            29;
+        j0_thirty:
+            dd!("-0.086367983581040211335962324496063948"),
+            dd!(30).j0(),
+            29;
+        j0_hundred:
+            dd!("0.0199858503042231224242283909508489907"),
+            dd!(100).j0(),
+            29;
+        j0_million:
+            dd!("0.000331043013739873740987963042219625436"),
+            dd!(1e6).j0(),
+            29;
+    );
+    // j1 tests
+    test_all_prec!(
+        j1_half:
+            dd!("0.242268457674873886383954576141531641"),
+            dd!(0.5).j1(),
+            29;
+        j1_one:
+            dd!("0.440050585744933515959682203718914913"),
+            dd!(1).j1(),
+            29;
+        j1_five:
+            dd!("-0.327579137591465222037734321910169133"),
+            dd!(5).j1(),
+            29;
+        j1_ten:
+            dd!("0.0434727461688614366697487680258592883"),
+            dd!(10).j1(),
+            29;
+        j1_thirty:
+            dd!("-0.118751062616622936520234269240362891"),
+            dd!(30).j1(),
+            29;
+        j1_hundred:
+            dd!("-0.0771453520141121580326854949272344702"),
+            dd!(100).j1(),
+            29;
+        j1_million:
+            dd!("-0.000725968356813763041852523381708498456"),
+            dd!(1e6).j1(),
+            29;
+    );
+    // y0 tests
+    test_all_prec!(
+        y0_half:
+            dd!("-0.44451873350670655714839847506833191"),
+            dd!(0.5).y0(),
+            29;
+        y0_one:
+            dd!("0.0882569642156769579829267660235151628"),
+            dd!(1).y0(),
+            29;
+        y0_five:
+            dd!("-0.308517625249033780073648984212046611"),
+            dd!(5).y0(),
+            29;
+        y0_ten:
+            dd!("0.0556711672835993914244598774101900481"),
+            dd!(10).y0(),
+            29;
+        y0_thirty:
+            dd!("-0.117295731686664025251247878231888891"),
+            dd!(30).y0(),
+            29;
+        y0_hundred:
+            dd!("-0.0772443133650831522542282213671987705"),
+            dd!(100).y0(),
+            29;
+        y0_million:
+            dd!("-0.000725968522335179165682721743367686512"),
+            dd!(1e6).y0(),
+            29;
+    );
+    // y1 tests
+    test_all_prec!(
+        y1_half:
+            dd!("-1.47147239267024306918858463532329745"),
+            dd!(0.5).y1(),
+            29;
+        y1_one:
+            dd!("-0.781212821300288716547150000047964821"),
+            dd!(1).y1(),
+            29;
+        y1_five:
+            dd!("0.147863143391226844801050675488037205"),
+            dd!(5).y1(),
+            29;
+        y1_ten:
+            dd!("0.249015424206953883923283474663222803"),
+            dd!(10).y1(),
+            29;
+        y1_thirty:
+            dd!("0.0844255706617472348909229041496559142"),
+            dd!(30).y1(),
+            29;
+        y1_hundred:
+            dd!("-0.0203723120027597933047039326664145597"),
+            dd!(100).y1(),
+            29;
+        y1_million:
+            dd!("-0.000331043376724176288863517237849679523"),
+            dd!(1e6).y1(),
+            29;
+    );
+
+    test_all_exact!(
+        j0_zero:
+            Double::ONE,
+            Double::ZERO.j0();
+        j0_inf:
+            Double::ZERO,
+            Double::INFINITY.j0();
+        j0_neg_inf:
+            Double::ZERO,
+            Double::NEG_INFINITY.j0();
+        j0_nan:
+            Double::NAN,
+            Double::NAN.j0();
+        j1_zero:
+            Double::ZERO,
+            Double::ZERO.j1();
+        j1_neg_zero:
+            Double::NEG_ZERO,
+            Double::NEG_ZERO.j1();
+        j1_inf:
+            Double::ZERO,
+            Double::INFINITY.j1();
+        j1_nan:
+            Double::NAN,
+            Double::NAN.j1();
+        y0_zero:
+            Double::NEG_INFINITY,
+            Double::ZERO.y0();
+        y0_neg:
+            Double::NAN,
+            dd!(-1).y0();
+        y0_inf:
+            Double::ZERO,
+            Double::INFINITY.y0();
+        y0_nan:
+            Double::NAN,
+            Double::NAN.y0();
+        y1_zero:
+            Double::NEG_INFINITY,
+            Double::ZERO.y1();
+        y1_neg:
+            Double::NAN,
+            dd!(-1).y1();
+        y1_inf:
+            Double::ZERO,
+            Double::INFINITY.y1();
+        y1_nan:
+            Double::NAN,
+            Double::NAN.y1();
+    );
+    test!(j_symmetry: {
+        for &x in &[0.3, 2.4048, 3.99, 4.0, 17.5, 39.9, 79.9, 80.0, 1e5] {
+            let x = dd!(x);
+            assert!((-x).j0() == x.j0());
+            assert!((-x).j1() == -x.j1());
+        }
+    });
+    test!(wronskian: {
+        // J1(x) Y0(x) - J0(x) Y1(x) = 2 / πx, which checks the functions against each
+        // other on both sides of where the method changes
+        for &x in &[0.01, 1.7, 3.99, 4.0, 6.3, 25.1, 39.99, 40.0, 79.99, 80.0, 512.5, 1e9] {
+            let x = dd!(x);
+            let w = x.j1() * x.y0() - x.j0() * x.y1();
+            prec!(Double::FRAC_2_PI / x, w, 29);
+        }
+    });
+}
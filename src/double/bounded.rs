@@ -0,0 +1,425 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::primitive as p;
+use crate::common::utils as u;
+use crate::double::Double;
+
+// Relative inflation applied to the division bound to cover the rounding error of the f64
+// operations used to scale the remainder bound by the divisor, with room to spare.
+const INFLATE: f64 = 1.0 + 4.0 * f64::EPSILON;
+
+// The smallest positive subnormal f64. An error-free product is exact unless its error
+// term underflows, in which case it's off by no more than this.
+const ETA: f64 = f64::MIN_POSITIVE * f64::EPSILON;
+
+impl Double {
+    /// Adds this `Double` to another, returning the sum along with a rigorous bound on its
+    /// error.
+    ///
+    /// The first element of the returned tuple is exactly the same as `self + other`. The
+    /// second is an `f64` that is guaranteed to be at least as large as the absolute
+    /// difference between that sum and the exact sum of the two operands. Returning this
+    /// bound makes it possible to build interval or affine arithmetic on top of `Double`
+    /// without needing directed rounding.
+    ///
+    /// The error of the sum *s* of *a* and *b* is (*a* + *b*) - *s*. Since all three of
+    /// those are `Double`s, that's exactly the sum of six `f64`s. Those are distilled with
+    /// error-free sums until they no longer overlap, at which point the sum of their
+    /// magnitudes is a bound on the magnitude of the error that is within a couple of ULPs
+    /// of being exact. The proof of the bound therefore doesn't depend on the analysis of
+    /// the addition algorithm at all, only on the exactness of the error-free
+    /// transformations, and the bound tracks the actual error rather than the worst case
+    /// (which Joldes, Muller, and Popescu show in "Tight and rigorous error bounds for basic
+    /// building blocks of double-word arithmetic" to be 3u<sup>2</sup> / (1 - 4u) times the
+    /// magnitude of the sum, where u = 2<sup>-53</sup>). In particular, the bound is zero
+    /// when the sum is exact.
+    ///
+    /// If the sum isn't finite, the bound is [`INFINITY`] if the sum is infinite and `NaN`
+    /// if the sum is [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let (x, bound) = Double::E.add_bounded(Double::LN_10);
+    /// assert!(x == Double::E + Double::LN_10);
+    ///
+    /// // The error in a single operation is only a few parts in 10^32
+    /// assert!(bound > 0.0 && bound < 1e-30);
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn add_bounded(self, other: Double) -> (Double, f64) {
+        let r = self + other;
+        match r.pre_bounded() {
+            Some(b) => (r, b),
+            None => {
+                let mut terms = [self.0, self.1, other.0, other.1, -r.0, -r.1];
+                (r, u::sum_bound(&mut terms))
+            }
+        }
+    }
+
+    /// Multiplies this `Double` by another, returning the product along with a rigorous
+    /// bound on its error.
+    ///
+    /// The first element of the returned tuple is exactly the same as `self * other`. The
+    /// second is an `f64` that is guaranteed to be at least as large as the absolute
+    /// difference between that product and the exact product of the two operands.
+    ///
+    /// The error of the product *p* of *a* and *b* is *ab* - *p*. Writing each operand as
+    /// the sum of its components, *ab* is the sum of four products of `f64`s, each of which
+    /// is split into two `f64`s exactly by an error-free product. The error is therefore
+    /// exactly the sum of ten `f64`s, which are distilled with error-free sums until they
+    /// no longer overlap, at which point the sum of their magnitudes is a bound on the
+    /// magnitude of the error that is within a couple of ULPs of being exact. A small
+    /// absolute term is added to cover error-free products that underflow. Like with
+    /// [`add_bounded`], the bound tracks the actual error rather than the worst case, which
+    /// for `Double` multiplication is about 8u<sup>2</sup> times the magnitude of the
+    /// product.
+    ///
+    /// If the product isn't finite, the bound is [`INFINITY`] if the product is infinite
+    /// and `NaN` if the product is [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let (x, bound) = Double::PI.mul_bounded(Double::E);
+    /// assert!(x == Double::PI * Double::E);
+    ///
+    /// // The error in a single operation is only a few parts in 10^32
+    /// assert!(bound > 0.0 && bound < 1e-30);
+    /// ```
+    ///
+    /// [`add_bounded`]: #method.add_bounded
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn mul_bounded(self, other: Double) -> (Double, f64) {
+        let r = self * other;
+        match r.pre_bounded() {
+            Some(b) => (r, b),
+            None => {
+                let (h00, l00) = p::two_prod(self.0, other.0);
+                let (h01, l01) = p::two_prod(self.0, other.1);
+                let (h10, l10) = p::two_prod(self.1, other.0);
+                let (h11, l11) = p::two_prod(self.1, other.1);
+                let mut terms = [h00, l00, h01, l01, h10, l10, h11, l11, -r.0, -r.1];
+                (r, u::sum_bound(&mut terms) + 4.0 * ETA)
+            }
+        }
+    }
+
+    /// Divides this `Double` by another, returning the quotient along with a rigorous
+    /// bound on its error.
+    ///
+    /// The first element of the returned tuple is exactly the same as `self / other`. The
+    /// second is an `f64` that is guaranteed to be at least as large as the absolute
+    /// difference between that quotient and the exact quotient of the two operands.
+    ///
+    /// Division works by repeatedly calculating a correction term (the remainder divided
+    /// by the divisor) and adding it to the quotient. The error in the final quotient *q*
+    /// is exactly the next correction that would have been calculated, (*a* - *bq*) /
+    /// *b*. Rather than estimating that correction, this function bounds it rigorously:
+    /// the product *bq* is expanded into eight `f64`s with error-free products, so the
+    /// remainder *a* - *bq* is the exact sum of ten `f64`s. Those are distilled with
+    /// error-free sums until they no longer overlap, at which point the sum of their
+    /// magnitudes is a bound on the magnitude of the remainder that is within a couple of
+    /// ULPs of being exact. Dividing that by a lower bound on |*b*| gives the result. As
+    /// with [`mul_bounded`], a small absolute term covers error-free products that
+    /// underflow.
+    ///
+    /// Since the bound is based on the actual remainder rather than on a worst case, it's
+    /// generally very close to the true error.
+    ///
+    /// If the quotient isn't finite, the bound is [`INFINITY`] if the quotient is infinite
+    /// and `NaN` if the quotient is [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let (x, bound) = Double::PI.div_bounded(Double::E);
+    /// assert!(x == Double::PI / Double::E);
+    ///
+    /// // The error in a single operation is only a few parts in 10^32
+    /// assert!(bound > 0.0 && bound < 1e-30);
+    /// ```
+    ///
+    /// [`mul_bounded`]: #method.mul_bounded
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn div_bounded(self, other: Double) -> (Double, f64) {
+        let r = self / other;
+        match r.pre_bounded() {
+            Some(b) => (r, b),
+            None => {
+                if self.is_zero() || other.is_infinite() {
+                    // Both of these produce exact (signed) zeros
+                    (r, 0.0)
+                } else {
+                    let (h00, l00) = p::two_prod(other.0, r.0);
+                    let (h01, l01) = p::two_prod(other.0, r.1);
+                    let (h10, l10) = p::two_prod(other.1, r.0);
+                    let (h11, l11) = p::two_prod(other.1, r.1);
+                    let mut terms = [
+                        self.0, self.1, -h00, -l00, -h01, -l01, -h10, -l10, -h11, -l11,
+                    ];
+                    let rem = u::sum_bound(&mut terms) + 4.0 * ETA;
+
+                    // |other.1| is no more than half an ULP of other.0, so |other| can't be
+                    // less than |other.0| * (1 - ε/2)
+                    let divisor = other.0.abs() * (1.0 - f64::EPSILON / 2.0);
+                    (r, rem / divisor * INFLATE)
+                }
+            }
+        }
+    }
+
+    // Precalc functions
+    //
+    // This series of functions returns `Some` with a value that is to be returned, if it
+    // turns out that the function doesn't have to be calculated because a shortcut result
+    // is known. They return `None` if the value has to be calculated normally.
+    //
+    // This keeps the public functions from being mucked up with code that does validation
+    // rather than calculation.
+
+    #[inline]
+    fn pre_bounded(&self) -> Option<f64> {
+        if self.is_nan() {
+            Some(f64::NAN)
+        } else if self.is_infinite() {
+            Some(f64::INFINITY)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::random::Rng;
+
+    // Monte Carlo verification of the bounds. Each check generates random operands, runs
+    // the bounded operation, and compares the bound against the true error, which is
+    // calculated exactly (as a nonoverlapping expansion) by an algorithm independent of the
+    // one used inside the bounded operations.
+    //
+    // The regular tests use enough samples to run quickly. The ignored tests run ten
+    // million samples per operation; use `cargo test --release -- --ignored` to run them.
+    const SAMPLES: usize = 100_000;
+    const LONG_SAMPLES: usize = 10_000_000;
+
+    // A random normalized Double with its leading component scaled by 2^exp, where exp
+    // is in [lo, hi). The low component is sometimes zero and sometimes much less than
+    // half an ULP, so that all of the shapes that real Doubles take are represented.
+    fn random_double(rng: &mut Rng, lo: i32, hi: i32) -> Double {
+        let exp = rng.range(lo, hi);
+        let gap = rng.range(53, 63);
+        let hi = rng.float(exp);
+        let lo = match rng.next_u64() % 4 {
+            0 => 0.0,
+            _ => rng.float(exp - gap),
+        };
+        let (a, b) = p::two_sum(hi, lo);
+        Double(a, b)
+    }
+
+    // Shewchuk's Grow-Expansion with zero elimination. The result is a nonoverlapping
+    // expansion in increasing order of magnitude whose sum is exactly the sum of the terms.
+    // Adding its components up from the smallest gives that sum to within an ULP or so.
+    fn exact_sum(terms: &[f64]) -> f64 {
+        let mut e: Vec<f64> = vec![];
+        for &t in terms {
+            let mut q = t;
+            let mut h = vec![];
+            for &x in &e {
+                let (s, err) = p::two_sum(q, x);
+                if err != 0.0 {
+                    h.push(err);
+                }
+                q = s;
+            }
+            if q != 0.0 {
+                h.push(q);
+            }
+            e = h;
+        }
+        e.iter().sum()
+    }
+
+    fn add_error(a: Double, b: Double, r: Double) -> f64 {
+        exact_sum(&[a.0, a.1, b.0, b.1, -r.0, -r.1]).abs()
+    }
+
+    fn mul_error(a: Double, b: Double, r: Double) -> f64 {
+        let mut terms = vec![-r.0, -r.1];
+        for &x in &[a.0, a.1] {
+            for &y in &[b.0, b.1] {
+                let (h, l) = p::two_prod(x, y);
+                terms.push(h);
+                terms.push(l);
+            }
+        }
+        exact_sum(&terms).abs()
+    }
+
+    fn div_error(a: Double, b: Double, r: Double) -> f64 {
+        // The remainder is a - br, and the error is that divided by b
+        let mut terms = vec![a.0, a.1];
+        for &x in &[b.0, b.1] {
+            for &y in &[r.0, r.1] {
+                let (h, l) = p::two_prod(x, y);
+                terms.push(-h);
+                terms.push(-l);
+            }
+        }
+        exact_sum(&terms).abs() / (b.0 + b.1).abs()
+    }
+
+    // Runs `samples` operations, failing if any bound is smaller than the true error and
+    // reporting how tight the bounds were. Returns the geometric mean of bound / error over
+    // the samples that had a nonzero error.
+    fn check(
+        name: &str,
+        samples: usize,
+        gen: impl Fn(&mut Rng) -> (Double, Double),
+        op: impl Fn(Double, Double) -> (Double, f64),
+        error: impl Fn(Double, Double, Double) -> f64,
+    ) -> f64 {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        let mut log_sum = 0.0;
+        let mut max = 0f64;
+        let mut count = 0;
+
+        for _ in 0..samples {
+            let (a, b) = gen(&mut rng);
+            let (r, bound) = op(a, b);
+            let err = error(a, b, r);
+            // The true error is only known to within a couple of ULPs because of the final
+            // rounding of the exact sum (and the division, for div_bounded)
+            assert!(
+                bound >= err * (1.0 - 2.0 * f64::EPSILON),
+                "{}: bound {:e} < error {:e} for {:?} and {:?}",
+                name,
+                bound,
+                err,
+                a,
+                b
+            );
+            if err > 0.0 {
+                let ratio = bound / err;
+                log_sum += ratio.ln();
+                max = max.max(ratio);
+                count += 1;
+            }
+        }
+
+        let mean = (log_sum / count as f64).exp();
+        println!(
+            "{}: {} samples, {} inexact, bound / error geometric mean {:.3}, max {:.3e}",
+            name, samples, count, mean, max
+        );
+        mean
+    }
+
+    fn gen_add(rng: &mut Rng) -> (Double, Double) {
+        let a = random_double(rng, -200, 200);
+        let exp = a.0.abs().log2() as i32;
+        let b = match rng.next_u64() % 4 {
+            // unrelated magnitudes
+            0 => random_double(rng, -200, 200),
+            // similar magnitudes
+            1 => random_double(rng, exp - 3, exp + 3),
+            // cancellation of some number of leading bits
+            2 => {
+                let d = random_double(rng, exp - 120, exp - 1);
+                let (s, e) = p::two_sum(-a.0, -a.1 + d.0);
+                Double(s, e)
+            }
+            // total cancellation
+            _ => -a,
+        };
+        (a, b)
+    }
+
+    fn gen_mul(rng: &mut Rng) -> (Double, Double) {
+        (random_double(rng, -200, 200), random_double(rng, -200, 200))
+    }
+
+    fn add(a: Double, b: Double) -> (Double, f64) {
+        a.add_bounded(b)
+    }
+
+    fn mul(a: Double, b: Double) -> (Double, f64) {
+        a.mul_bounded(b)
+    }
+
+    fn div(a: Double, b: Double) -> (Double, f64) {
+        a.div_bounded(b)
+    }
+
+    test!(add_bound_holds: {
+        let mean = check("add_bounded", SAMPLES, gen_add, add, add_error);
+        assert!(mean < 2.0);
+    });
+
+    test!(mul_bound_holds: {
+        let mean = check("mul_bounded", SAMPLES, gen_mul, mul, mul_error);
+        assert!(mean < 2.0);
+    });
+
+    test!(div_bound_holds: {
+        let mean = check("div_bounded", SAMPLES, gen_mul, div, div_error);
+        assert!(mean < 2.0);
+    });
+
+    #[test]
+    #[ignore]
+    fn add_bound_holds_long() {
+        check("add_bounded", LONG_SAMPLES, gen_add, add, add_error);
+    }
+
+    #[test]
+    #[ignore]
+    fn mul_bound_holds_long() {
+        check("mul_bounded", LONG_SAMPLES, gen_mul, mul, mul_error);
+    }
+
+    #[test]
+    #[ignore]
+    fn div_bound_holds_long() {
+        check("div_bounded", LONG_SAMPLES, gen_mul, div, div_error);
+    }
+
+    test_all_eq!(
+        add_exact:
+            (dd!(3), 0.0),
+            dd!(1).add_bounded(dd!(2));
+        div_zero:
+            (Double::ZERO, 0.0),
+            Double::ZERO.div_bounded(Double::PI);
+        div_by_inf:
+            (Double::ZERO, 0.0),
+            Double::PI.div_bounded(Double::INFINITY);
+    );
+    test_all_assert!(
+        add_inf:
+            Double::INFINITY.add_bounded(Double::PI).1 == f64::INFINITY;
+        add_nan:
+            Double::NAN.add_bounded(Double::PI).1.is_nan();
+        mul_nan:
+            Double::INFINITY.mul_bounded(Double::ZERO).1.is_nan();
+        div_by_zero:
+            Double::PI.div_bounded(Double::ZERO).1 == f64::INFINITY;
+        div_nan:
+            Double::ZERO.div_bounded(Double::ZERO).1.is_nan();
+        mul_exact:
+            dd!(2).mul_bounded(dd!(3)).1 < 1e-300;
+        div_exact:
+            dd!(1).div_bounded(dd!(4)).1 < 1e-300;
+    );
+}
@@ -0,0 +1,264 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::strict as s;
+use crate::double::Double;
+
+// Runs an operation and reports whether it produced NaN or an infinity from operands that
+// were all finite. Results from non-finite operands are never flagged, since they're
+// whatever that operand made them rather than a new problem.
+#[inline]
+fn overflowing(operands: &[Double], op: impl FnOnce() -> Double) -> (Double, bool) {
+    let r = s::quietly(op);
+    (r, !r.is_finite() && operands.iter().all(|x| x.is_finite()))
+}
+
+#[inline]
+fn checked(operands: &[Double], op: impl FnOnce() -> Double) -> Option<Double> {
+    match overflowing(operands, op) {
+        (_, true) => None,
+        (r, false) => Some(r),
+    }
+}
+
+impl Double {
+    /// Adds another `Double` to this one, returning `None` if the sum of two finite
+    /// numbers is not finite.
+    ///
+    /// Whether the sum overflowed is decided by the sum itself, so this returns `None`
+    /// exactly when `self + other` would be infinite even though neither operand is.
+    /// Otherwise the result is `Some(self + other)`, which is infinite or NaN if an operand
+    /// was.
+    ///
+    /// This doesn't panic even if the `strict` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::MAX.checked_add(Double::MAX).is_none());
+    /// assert!(Double::MAX.checked_add(-Double::MAX) == Some(Double::ZERO));
+    /// assert!(Double::INFINITY.checked_add(dd!(1)) == Some(Double::INFINITY));
+    /// ```
+    pub fn checked_add(self, other: Double) -> Option<Double> {
+        checked(&[self, other], || self + other)
+    }
+
+    /// Subtracts another `Double` from this one, returning `None` if the difference of two
+    /// finite numbers is not finite.
+    ///
+    /// This is the same as [`checked_add`] but with subtraction.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// assert!((-Double::MAX).checked_sub(Double::MAX).is_none());
+    /// assert!(Double::MAX.checked_sub(Double::MAX) == Some(Double::ZERO));
+    /// ```
+    ///
+    /// [`checked_add`]: #method.checked_add
+    pub fn checked_sub(self, other: Double) -> Option<Double> {
+        checked(&[self, other], || self - other)
+    }
+
+    /// Multiplies this `Double` by another, returning `None` if the product of two finite
+    /// numbers is not finite.
+    ///
+    /// A product that underflows to zero isn't an error; it's returned as `Some`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::MAX.checked_mul(dd!(2)).is_none());
+    /// assert!(Double::MAX.checked_mul(dd!(0.5)).is_some());
+    /// assert!(Double::MIN_POSITIVE.checked_mul(Double::MIN_POSITIVE) == Some(Double::ZERO));
+    /// ```
+    pub fn checked_mul(self, other: Double) -> Option<Double> {
+        checked(&[self, other], || self * other)
+    }
+
+    /// Divides this `Double` by another, returning `None` if the quotient of two finite
+    /// numbers is not finite.
+    ///
+    /// That includes division by zero, whether it would have produced an infinity or (for
+    /// zero divided by zero) NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::MAX.checked_div(dd!(0.5)).is_none());
+    /// assert!(dd!(1).checked_div(Double::ZERO).is_none());
+    /// assert!(Double::ZERO.checked_div(Double::ZERO).is_none());
+    /// assert!(dd!(1).checked_div(Double::INFINITY) == Some(Double::ZERO));
+    /// ```
+    pub fn checked_div(self, other: Double) -> Option<Double> {
+        checked(&[self, other], || self / other)
+    }
+
+    /// Calculates the square root of the `Double`, returning `None` if the number is
+    /// finite and negative.
+    ///
+    /// Negative zero is not an error; its square root is negative zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(-1).checked_sqrt().is_none());
+    /// assert!(dd!(4).checked_sqrt() == Some(dd!(2)));
+    /// assert!(Double::NAN.checked_sqrt().unwrap().is_nan());
+    /// ```
+    pub fn checked_sqrt(self) -> Option<Double> {
+        checked(&[self], || self.sqrt())
+    }
+
+    /// Calculates the natural logarithm of the `Double`, returning `None` if the number is
+    /// finite and not positive.
+    ///
+    /// The logarithm of zero would be negative infinity, so it's `None` as well.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(-1).checked_ln().is_none());
+    /// assert!(Double::ZERO.checked_ln().is_none());
+    /// assert!(Double::E.checked_ln().is_some());
+    /// assert!(Double::INFINITY.checked_ln() == Some(Double::INFINITY));
+    /// ```
+    pub fn checked_ln(self) -> Option<Double> {
+        checked(&[self], || self.ln())
+    }
+
+    /// Adds another `Double` to this one, returning the sum along with whether the sum of
+    /// two finite numbers was not finite.
+    ///
+    /// The sum is the same as `self + other`. The flag is `true` exactly when
+    /// [`checked_add`] would return `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::MAX.overflowing_add(Double::MAX) == (Double::INFINITY, true));
+    /// assert!(dd!(1).overflowing_add(dd!(2)) == (dd!(3), false));
+    /// ```
+    ///
+    /// [`checked_add`]: #method.checked_add
+    pub fn overflowing_add(self, other: Double) -> (Double, bool) {
+        overflowing(&[self, other], || self + other)
+    }
+
+    /// Subtracts another `Double` from this one, returning the difference along with
+    /// whether the difference of two finite numbers was not finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// assert!((-Double::MAX).overflowing_sub(Double::MAX) == (Double::NEG_INFINITY, true));
+    /// ```
+    pub fn overflowing_sub(self, other: Double) -> (Double, bool) {
+        overflowing(&[self, other], || self - other)
+    }
+
+    /// Multiplies this `Double` by another, returning the product along with whether the
+    /// product of two finite numbers was not finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::MAX.overflowing_mul(dd!(-2)) == (Double::NEG_INFINITY, true));
+    /// ```
+    pub fn overflowing_mul(self, other: Double) -> (Double, bool) {
+        overflowing(&[self, other], || self * other)
+    }
+
+    /// Divides this `Double` by another, returning the quotient along with whether the
+    /// quotient of two finite numbers was not finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let (q, flag) = Double::ZERO.overflowing_div(Double::ZERO);
+    /// assert!(q.is_nan() && flag);
+    /// assert!(dd!(1).overflowing_div(dd!(4)) == (dd!(0.25), false));
+    /// ```
+    pub fn overflowing_div(self, other: Double) -> (Double, bool) {
+        overflowing(&[self, other], || self / other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // add tests
+    test_all_assert!(
+        add_max: Double::MAX.checked_add(Double::MAX).is_none();
+        add_neg_max: (-Double::MAX).checked_add(-Double::MAX).is_none();
+        add_boundary:
+            Double::MAX.checked_add(Double::new(1e292, 0.0)).is_none()
+                && Double::MAX.checked_add(Double::ONE) == Some(Double::MAX);
+        add_finite: Double::MAX.checked_add(-Double::ONE) == Some(Double::MAX - Double::ONE);
+        add_inf: Double::INFINITY.checked_add(Double::MAX) == Some(Double::INFINITY);
+        add_nan: Double::NAN.checked_add(Double::ONE).unwrap().is_nan();
+        add_inf_nan: (Double::INFINITY.checked_add(Double::NEG_INFINITY).unwrap()).is_nan();
+        add_overflowing:
+            Double::MAX.overflowing_add(Double::MAX) == (Double::INFINITY, true)
+                && Double::INFINITY.overflowing_add(Double::MAX) == (Double::INFINITY, false);
+    );
+
+    // sub tests
+    test_all_assert!(
+        sub_max: (-Double::MAX).checked_sub(Double::MAX).is_none();
+        sub_finite: Double::MAX.checked_sub(Double::MAX) == Some(Double::ZERO);
+        sub_inf: Double::ONE.checked_sub(Double::INFINITY) == Some(Double::NEG_INFINITY);
+        sub_overflowing:
+            Double::MAX.overflowing_sub(-Double::MAX) == (Double::INFINITY, true);
+    );
+
+    // mul tests
+    test_all_assert!(
+        mul_max: Double::MAX.checked_mul(dd!(2)).is_none();
+        mul_neg_max: Double::MAX.checked_mul(dd!(-1.5)).is_none();
+        mul_finite: Double::MAX.checked_mul(dd!(0.5)) == Some(Double::MAX * dd!(0.5));
+        mul_underflow: Double::MIN_POSITIVE.checked_mul(Double::MIN_POSITIVE) == Some(Double::ZERO);
+        mul_inf: Double::INFINITY.checked_mul(dd!(2)) == Some(Double::INFINITY);
+        mul_inf_zero: Double::INFINITY.checked_mul(Double::ZERO).unwrap().is_nan();
+        mul_overflowing: Double::MAX.overflowing_mul(dd!(2)) == (Double::INFINITY, true);
+    );
+
+    // div tests
+    test_all_assert!(
+        div_max: Double::MAX.checked_div(dd!(0.5)).is_none();
+        div_small: Double::MAX.checked_div(Double::MIN_POSITIVE).is_none();
+        div_zero: Double::ONE.checked_div(Double::ZERO).is_none();
+        div_neg_zero: Double::ONE.checked_div(Double::NEG_ZERO).is_none();
+        div_zero_zero: Double::ZERO.checked_div(Double::ZERO).is_none();
+        div_finite: Double::ONE.checked_div(dd!(3)) == Some(Double::ONE / dd!(3));
+        div_inf: Double::ONE.checked_div(Double::INFINITY) == Some(Double::ZERO);
+        div_inf_zero: Double::INFINITY.checked_div(Double::ZERO) == Some(Double::INFINITY);
+        div_overflowing:
+            Double::ONE.overflowing_div(Double::ZERO) == (Double::INFINITY, true)
+                && Double::ONE.overflowing_div(dd!(2)) == (dd!(0.5), false);
+    );
+
+    // sqrt tests
+    test_all_assert!(
+        sqrt_neg: dd!(-1).checked_sqrt().is_none();
+        sqrt_tiny_neg: (-Double::MIN_POSITIVE).checked_sqrt().is_none();
+        sqrt_neg_zero: Double::NEG_ZERO.checked_sqrt().unwrap().is_sign_negative();
+        sqrt_max: Double::MAX.checked_sqrt() == Some(Double::MAX.sqrt());
+        sqrt_inf: Double::INFINITY.checked_sqrt() == Some(Double::INFINITY);
+        sqrt_neg_inf: Double::NEG_INFINITY.checked_sqrt().unwrap().is_nan();
+    );
+
+    // ln tests
+    test_all_assert!(
+        ln_neg: dd!(-1).checked_ln().is_none();
+        ln_zero: Double::ZERO.checked_ln().is_none();
+        ln_neg_zero: Double::NEG_ZERO.checked_ln().is_none();
+        ln_min: Double::MIN_POSITIVE.checked_ln() == Some(Double::MIN_POSITIVE.ln());
+        ln_max: Double::MAX.checked_ln() == Some(Double::MAX.ln());
+        ln_inf: Double::INFINITY.checked_ln() == Some(Double::INFINITY);
+        ln_nan: Double::NAN.checked_ln().unwrap().is_nan();
+    );
+}
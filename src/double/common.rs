@@ -3,18 +3,63 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::primitive as p;
+use crate::common::utils as u;
 use crate::double::Double;
 
 /// Helper function that efficiently multiplies a Double by a power of 2. This is -much-
 /// faster than regular multiplication but only works with powers of 2.
 #[inline]
-pub fn mul_pwr2(a: Double, b: f64) -> Double {
+pub const fn mul_pwr2(a: Double, b: f64) -> Double {
     Double(a.0 * b, a.1 * b)
 }
 
+/// Multiplies a `Double` by 2<sup>*n*</sup>. The power is split into two factors, so unlike
+/// `ldexp` this works for any *n* that leaves the result finite and nonzero, even when
+/// 2<sup>*n*</sup> itself isn't representable as an `f64`.
+#[inline]
+pub const fn scale(a: Double, n: i32) -> Double {
+    let h = n / 2;
+    mul_pwr2(mul_pwr2(a, u::pow2(h)), u::pow2(n - h))
+}
+
+/// Finishes an operation on finite operands whose result came out infinite or NaN. That
+/// usually means that the result really is too large to represent, but intermediate values
+/// can overflow when the result itself is just short of that. So the operation is redone at
+/// half scale (by `half`) and the result doubled, unless `half_lead`, the first component
+/// of the operation at half scale, says that it overflows even then. Either way a result
+/// that doesn't fit is the infinity of the appropriate sign, never NaN.
+///
+/// This is a macro rather than a function that takes `half` as a closure because the
+/// operations that use it are `const fn`s, which can't call closures. `half` is still only
+/// evaluated when `half_lead` is finite.
+macro_rules! redo_halved {
+    ($half_lead:expr, $half:expr) => {{
+        let half_lead: f64 = $half_lead;
+        let r = if half_lead.is_finite() {
+            $crate::double::common::mul_pwr2($half, 2.0)
+        } else {
+            $crate::double::Double::NAN
+        };
+        if r.0.is_finite() {
+            r
+        } else if half_lead > 0.0 {
+            $crate::double::Double::INFINITY
+        } else {
+            $crate::double::Double::NEG_INFINITY
+        }
+    }};
+}
+pub(crate) use redo_halved;
+
 /// Table of the reciprocals of factorials. This starts with 1/3!, as the inverse factorials
 /// before that are trivial (1/1! is 1 and 1/2! is 1/2). These are used in Taylor series
-/// calculations for exp, sin, and cos. 
+/// calculations for exp, sin, and cos.
+///
+/// `INV_FACTS[k]` is 1/(k + 3)!, for k from 0 through 14, so the table ends with 1/17!.
+/// Each entry is the exact reciprocal rendered one component at a time, each component being
+/// the `f64` closest to what the components before it leave over. The series that use the
+/// table iterate over it rather than indexing into it, so none of them can run past its end.
 pub const INV_FACTS: [Double; 15] = [
     Double(1.6666666666666666e-1, 9.25185853854297e-18),
     Double(4.1666666666666664e-2, 2.3129646346357427e-18),
@@ -33,18 +78,561 @@ pub const INV_FACTS: [Double; 15] = [
     Double(2.8114572543455206e-15, 1.6508842730861433e-31),
 ];
 
+/// The number of steps that sin and cos divide π into when they reduce their arguments.
+/// Past a multiple of π/2, an argument is reduced to *t* + *k*π/16, where *k* is in
+/// [-4, 4] and *t* is about half of [`TRIG_STEP`] at most, and the sine and cosine of
+/// *k*π/16 are looked up in [`SINES`] and [`COSINES`].
+pub const TRIG_STEPS: usize = 16;
+
+/// The size of the steps that sin and cos reduce their arguments by, π/[`TRIG_STEPS`].
+///
+/// The multiple of the step is chosen from the quotient of the first components of the
+/// argument and the step. That quotient is no more than `TRIG_STEPS / 4`, so its rounding
+/// errors come to less than `TRIG_STEPS` units of 2<sup>-52</sup> of a step, and the
+/// reduced argument can land that far past half a step. Its magnitude is therefore never
+/// more than π/32 by more than `TRIG_STEPS * f64::EPSILON` times the step.
+pub const TRIG_STEP: Double = Double::FRAC_PI_16;
+
+/// π/180, the number of radians in a degree.
+pub const RAD_PER_DEG: Double = Double(1.7453292519943295e-2, 2.9486522708701687e-19);
+
+/// 180/π, the number of degrees in a radian.
+pub const DEG_PER_RAD: Double = Double(5.729577951308232e1, -1.9878495670576283e-15);
+
 /// Table of sines of kπ/16, where k is in [1, 4].
-pub const SINES: [Double; 4] = [
-    Double(1.9509032201612828e-1, -7.991079068461734e-18),
-    Double(3.826834323650898e-1, -1.005077269646159e-17),
-    Double(5.555702330196022e-1, 4.7094109405616756e-17),
-    Double(7.071067811865476e-1, -4.8336466567264573e-17),
+#[allow(clippy::approx_constant)]
+pub const SINES: [Double; TRIG_STEPS / 4] = [
+    Double(1.9509032201612828e-1, -7.991079068461731e-18),
+    Double(3.826834323650898e-1, -1.0050772696461588e-17),
+    Double(5.555702330196022e-1, 4.709410940561677e-17),
+    Double(7.071067811865476e-1, -4.833646656726457e-17),
 ];
 
 /// Table of cosines of kπ/16, where k is in [1, 4].
-pub const COSINES: [Double; 4] = [
-    Double(9.807852804032304e-1, 1.8546939997824996e-17),
-    Double(9.238795325112867e-1, 1.764504708433667e-17),
-    Double(8.314696123025452e-1, 1.4073856984728008e-18),
-    Double(7.071067811865476e-1, -4.8336466567264573e-17),
+#[allow(clippy::approx_constant)]
+pub const COSINES: [Double; TRIG_STEPS / 4] = [
+    Double(9.807852804032304e-1, 1.8546939997825006e-17),
+    Double(9.238795325112867e-1, 1.7645047084336677e-17),
+    Double(8.314696123025452e-1, 1.4073856984728024e-18),
+    Double(7.071067811865476e-1, -4.833646656726457e-17),
+];
+
+/// The powers of ten from 10<sup>0</sup> through 10<sup>308</sup>, the largest that's
+/// finite. `POWERS_OF_TEN[k]` is 10<sup>k</sup> rendered one component at a time, each
+/// component being the `f64` closest to what the components before it leave over, so the
+/// powers that a `Double` can hold exactly are exact, and the rest are as close as a `Double`
+/// gets. Building them by repeated multiplication instead piles up an error with every
+/// step.
+pub const POWERS_OF_TEN: [Double; 309] = [
+    Double(1e0, 0e0),
+    Double(1e1, 0e0),
+    Double(1e2, 0e0),
+    Double(1e3, 0e0),
+    Double(1e4, 0e0),
+    Double(1e5, 0e0),
+    Double(1e6, 0e0),
+    Double(1e7, 0e0),
+    Double(1e8, 0e0),
+    Double(1e9, 0e0),
+    Double(1e10, 0e0),
+    Double(1e11, 0e0),
+    Double(1e12, 0e0),
+    Double(1e13, 0e0),
+    Double(1e14, 0e0),
+    Double(1e15, 0e0),
+    Double(1e16, 0e0),
+    Double(1e17, 0e0),
+    Double(1e18, 0e0),
+    Double(1e19, 0e0),
+    Double(1e20, 0e0),
+    Double(1e21, 0e0),
+    Double(1e22, 0e0),
+    Double(1e23, 8.388608e6),
+    Double(1e24, 1.6777216e7),
+    Double(1e25, -9.05969664e8),
+    Double(1e26, -4.764729344e9),
+    Double(1e27, -1.3287555072e10),
+    Double(1e28, 4.16880263168e11),
+    Double(1e29, 8.566849142784e12),
+    Double(1e30, -1.9884624838656e13),
+    Double(1e31, 3.64103705034752e14),
+    Double(1e32, -5.366162204393472e15),
+    Double(1e33, 5.442476901295718e16),
+    Double(1e34, 5.4424769012957184e17),
+    Double(1e35, 3.1366338920820244e18),
+    Double(1e36, -4.242063737401796e19),
+    Double(1e37, 4.6123734179787886e20),
+    Double(1e38, 2.251190176543966e21),
+    Double(1e39, 6.029083362839682e22),
+    Double(1e40, -3.037860284270037e23),
+    Double(1e41, -6.200086450407783e23),
+    Double(1e42, -4.488571267807592e25),
+    Double(1e43, -1.393721169594141e26),
+    Double(1e44, -8.821361405306423e27),
+    Double(1e45, 7.024271097546445e28),
+    Double(1e46, 6.860180964052979e28),
+    Double(1e47, -4.38458430450762e30),
+    Double(1e48, -4.38458430450762e31),
+    Double(1e49, 5.3509723052451824e32),
+    Double(1e50, -7.629769841091887e33),
+    Double(1e51, 6.779051325638372e33),
+    Double(1e52, 6.779051325638372e34),
+    Double(1e53, 6.779051325638373e35),
+    Double(1e54, -7.829154040459625e37),
+    Double(1e55, -1.0235067020408552e38),
+    Double(1e56, -9.190283508143379e39),
+    Double(1e57, -4.834669211555366e40),
+    Double(1e58, 5.618805100255864e41),
+    Double(1e59, 2.831211950439536e42),
+    Double(1e60, 5.061286470292598e43),
+    Double(1e61, 5.061286470292598e44),
+    Double(1e62, -3.5021996859431613e45),
+    Double(1e63, -5.785795994272697e46),
+    Double(1e64, -2.1320419009454396e47),
+    Double(1e65, 7.909613737163662e47),
+    Double(1e66, 5.467766613175255e49),
+    Double(1e67, 1.726322421608144e50),
+    Double(1e68, 4.719477774861833e51),
+    Double(1e69, -7.253143638152923e52),
+    Double(1e70, -7.253143638152923e53),
+    Double(1e71, -4.1881525564211456e54),
+    Double(1e72, 5.619818905120543e55),
+    Double(1e73, 1.6966303205038675e56),
+    Double(1e74, 4.8351811881972075e57),
+    Double(1e75, 7.34602188235188e58),
+    Double(1e76, -4.706013449590547e59),
+    Double(1e77, 1.721738727445414e60),
+    Double(1e78, -8.493621433689703e60),
+    Double(1e79, 3.2643992499340446e62),
+    Double(1e80, -2.6609864708367274e61),
+    Double(1e81, 7.871812010433421e64),
+    Double(1e82, 3.6593203436911345e65),
+    Double(1e83, -3.0806663230965258e66),
+    Double(1e84, -5.77666098981159e67),
+    Double(1e85, -1.4630695230674873e68),
+    Double(1e86, -1.4630695230674873e69),
+    Double(1e87, 4.058327554364964e70),
+    Double(1e88, 4.0583275543649637e71),
+    Double(1e89, 5.246334248081951e71),
+    Double(1e90, 3.35158872845361e73),
+    Double(1e91, -7.95623248612805e74),
+    Double(1e92, -4.337729697461919e75),
+    Double(1e93, -4.3377296974619187e76),
+    Double(1e94, -2.0218879127155946e77),
+    Double(1e95, -2.0218879127155947e78),
+    Double(1e96, -4.9861653971908895e79),
+    Double(1e97, -7.357587384771125e80),
+    Double(1e98, 2.309629754856292e80),
+    Double(1e99, 3.266383119588331e82),
+    Double(1e100, -1.5902891109759918e83),
+    Double(1e101, 2.295048673475466e84),
+    Double(1e102, 2.2950486734754662e85),
+    Double(1e103, -1.915675085734669e85),
+    Double(1e104, -1.9156750857346687e86),
+    Double(1e105, 6.174169917471802e88),
+    Double(1e106, -9.103599905036844e89),
+    Double(1e107, 3.118615952970073e90),
+    Double(1e108, -3.399899171300283e91),
+    Double(1e109, 1.814912928116002e92),
+    Double(1e110, -2.3569367514170256e93),
+    Double(1e111, 4.318022735835818e94),
+    Double(1e112, 6.988006530736956e95),
+    Double(1e113, -1.5559416129466842e96),
+    Double(1e114, -1.5559416129466843e97),
+    Double(1e115, -1.5559416129466843e98),
+    Double(1e116, -1.5559416129466843e99),
+    Double(1e117, -5.0555427725995036e100),
+    Double(1e118, 3.343500010567262e101),
+    Double(1e119, 5.583244752745067e102),
+    Double(1e120, 1.9996531652605798e103),
+    Double(1e121, -3.734093374714599e104),
+    Double(1e122, -1.4405947587245274e105),
+    Double(1e123, 2.229003026859587e106),
+    Double(1e124, 5.1646812553268785e107),
+    Double(1e125, 7.513223838100712e108),
+    Double(1e126, 7.513223838100712e109),
+    Double(1e127, 4.5070893321502055e110),
+    Double(1e128, -7.51744869165182e111),
+    Double(1e129, 1.7825564358147585e111),
+    Double(1e130, -5.978307824605161e113),
+    Double(1e131, 8.797444499042768e114),
+    Double(1e132, 9.170432597638724e114),
+    Double(1e133, -2.235117235947686e116),
+    Double(1e134, 7.851796350329301e117),
+    Double(1e135, 3.817030915818506e118),
+    Double(1e136, -5.866406127007401e119),
+    Double(1e137, -3.2841562489204925e120),
+    Double(1e138, -3.2841562489204927e121),
+    Double(1e139, -3.2841562489204925e122),
+    Double(1e140, -5.928380124081487e123),
+    Double(1e141, -1.697621923823896e124),
+    Double(1e142, -5.082228484029969e125),
+    Double(1e143, -2.3745432358651105e126),
+    Double(1e144, -2.3745432358651106e127),
+    Double(1e145, 1.091293881785908e128),
+    Double(1e146, 6.636633270027537e129),
+    Double(1e147, 2.200361759434234e130),
+    Double(1e148, -4.897672657515052e131),
+    Double(1e149, -4.897672657515052e132),
+    Double(1e150, 1.9164403827562624e133),
+    Double(1e151, -1.717753238721772e134),
+    Double(1e152, -4.6251081359041995e135),
+    Double(1e153, 2.6659699587684626e134),
+    Double(1e154, -3.6947545688058227e137),
+    Double(1e155, -7.176231540910168e137),
+    Double(1e156, 1.6640819776808277e139),
+    Double(1e157, 1.6640819776808279e140),
+    Double(1e158, 4.712664546348789e141),
+    Double(1e159, 7.151530601283158e142),
+    Double(1e160, -6.528407745068227e142),
+    Double(1e161, -3.774589324822815e144),
+    Double(1e162, 6.21500603618836e145),
+    Double(1e163, 6.21500603618836e146),
+    Double(1e164, -1.7833499485879184e146),
+    Double(1e165, 1.0051010654816651e149),
+    Double(1e166, 5.959272394946475e149),
+    Double(1e167, -3.860899428741951e150),
+    Double(1e168, 6.613950516525703e151),
+    Double(1e169, 6.613950516525703e152),
+    Double(1e170, -3.441905430931245e153),
+    Double(1e171, 4.602779327034313e154),
+    Double(1e172, -8.2687162857105805e155),
+    Double(1e173, -1.4039186255799706e156),
+    Double(1e174, -6.895756753684458e157),
+    Double(1e175, 6.284654753766313e158),
+    Double(1e176, -7.44898050207432e158),
+    Double(1e177, -7.44898050207432e159),
+    Double(1e178, -5.243811844750628e161),
+    Double(1e179, 1.954450226518486e162),
+    Double(1e180, -9.248546019891598e162),
+    Double(1e181, 8.288920849235307e164),
+    Double(1e182, -6.453119872723839e165),
+    Double(1e183, 5.340512704843477e166),
+    Double(1e184, -1.735666841696913e167),
+    Double(1e185, 2.0382955831246285e168),
+    Double(1e186, 2.0382955831246284e169),
+    Double(1e187, 9.284303438781988e170),
+    Double(1e188, -2.309309130269787e171),
+    Double(1e189, -2.309309130269787e172),
+    Double(1e190, -7.255917159731878e173),
+    Double(1e191, -7.2559171597318776e174),
+    Double(1e192, -4.09008802087614e175),
+    Double(1e193, -6.622751331960731e176),
+    Double(1e194, 5.534032561245304e177),
+    Double(1e195, 2.292223523057028e178),
+    Double(1e196, 4.885670753607649e179),
+    Double(1e197, 4.885670753607649e180),
+    Double(1e198, -1.75355415660194e181),
+    Double(1e199, -9.720624048853447e182),
+    Double(1e200, 3.0266877787489637e183),
+    Double(1e201, -3.771878529305655e184),
+    Double(1e202, 9.825254086803583e185),
+    Double(1e203, 1.1230892124936706e186),
+    Double(1e204, 1.1230892124936706e187),
+    Double(1e205, -1.6616035472855014e188),
+    Double(1e206, -3.889357755108839e189),
+    Double(1e207, -3.889357755108839e190),
+    Double(1e208, 1.8136930169189052e191),
+    Double(1e209, -7.311188218325486e192),
+    Double(1e210, 7.288621758065539e193),
+    Double(1e211, 4.368659762787335e194),
+    Double(1e212, 9.040598955232462e195),
+    Double(1e213, 1.5654962473202578e196),
+    Double(1e214, 4.55553733048514e197),
+    Double(1e215, 9.33960306354895e198),
+    Double(1e216, -2.142154695804196e199),
+    Double(1e217, 3.9814494425174824e200),
+    Double(1e218, -8.265758834125874e201),
+    Double(1e219, 3.491561111451748e202),
+    Double(1e220, 3.562757926310489e202),
+    Double(1e221, -4.6601807174820695e204),
+    Double(1e222, -4.66018071748207e205),
+    Double(1e223, -4.6601807174820696e206),
+    Double(1e224, 3.0450964820516807e207),
+    Double(1e225, 7.154577655136347e208),
+    Double(1e226, 3.866992716668614e209),
+    Double(1e227, -9.28334703720232e210),
+    Double(1e228, 7.549087847752475e211),
+    Double(1e229, 8.161138937705572e211),
+    Double(1e230, -9.956644432600512e213),
+    Double(1e231, -5.647541102052084e214),
+    Double(1e232, -5.647541102052084e215),
+    Double(1e233, 2.6259372926008967e216),
+    Double(1e234, -1.7865845178806931e217),
+    Double(1e235, -5.316601966265965e218),
+    Double(1e236, -5.316601966265965e219),
+    Double(1e237, 5.979453868566905e220),
+    Double(1e238, -4.86475973287265e221),
+    Double(1e239, 9.188208545617794e221),
+    Double(1e240, -1.3946113804119925e223),
+    Double(1e241, -5.096102956370027e224),
+    Double(1e242, -5.0961029563700274e225),
+    Double(1e243, -7.46505756498317e226),
+    Double(1e244, -7.4650575649831695e227),
+    Double(1e245, -4.432795665958348e228),
+    Double(1e246, -6.858605185178205e229),
+    Double(1e247, 4.785280507077112e230),
+    Double(1e248, -4.529828046727142e231),
+    Double(1e249, 7.89031669167853e232),
+    Double(1e250, 7.89031669167853e233),
+    Double(1e251, -4.827911520448878e234),
+    Double(1e252, -9.915202805299841e235),
+    Double(1e253, 6.364129306223241e236),
+    Double(1e254, 6.364129306223241e237),
+    Double(1e255, 1.1547430305358546e238),
+    Double(1e256, -3.012765990014054e239),
+    Double(1e257, -3.0127659900140542e240),
+    Double(1e258, -5.679971763165996e241),
+    Double(1e259, 7.122615947963324e242),
+    Double(1e260, -6.5334776105746174e243),
+    Double(1e261, 7.122615947963324e244),
+    Double(1e262, -1.6172839295009582e245),
+    Double(1e263, -1.6172839295009584e246),
+    Double(1e264, -4.414051890289529e247),
+    Double(1e265, -6.651466258920385e248),
+    Double(1e266, -3.071603269111015e249),
+    Double(1e267, 2.6561775145839772e250),
+    Double(1e268, 2.6561775145839774e251),
+    Double(1e269, -4.675381888545613e252),
+    Double(1e270, -4.675381888545613e253),
+    Double(1e271, 4.709014147460262e254),
+    Double(1e272, -6.552261095746788e255),
+    Double(1e273, 5.459765830340733e256),
+    Double(1e274, 7.862171215558236e257),
+    Double(1e275, 4.01832259921023e258),
+    Double(1e276, -5.2069140800249854e259),
+    Double(1e277, -2.8678785109953724e259),
+    Double(1e278, 3.6493131320408215e261),
+    Double(1e279, -5.797329227496039e262),
+    Double(1e280, -3.27822459828621e263),
+    Double(1e281, -3.2782245982862097e264),
+    Double(1e282, -3.27822459828621e265),
+    Double(1e283, 4.460464822646387e266),
+    Double(1e284, -7.921438250845768e267),
+    Double(1e285, 1.9840842079479558e268),
+    Double(1e286, -3.2988611034086966e269),
+    Double(1e287, -7.525217352494019e270),
+    Double(1e288, -7.6304735395750355e270),
+    Double(1e289, -6.172783352786716e272),
+    Double(1e290, -6.172783352786716e273),
+    Double(1e291, 4.2139097649653716e274),
+    Double(1e292, -1.3256598978357416e275),
+    Double(1e293, 7.53765156264604e276),
+    Double(1e294, -6.64364677412481e277),
+    Double(1e295, 1.8651322279376996e278),
+    Double(1e296, 1.8651322279376996e279),
+    Double(1e297, -1.765280146275638e280),
+    Double(1e298, 4.043379652465702e281),
+    Double(1e299, -5.250476025520442e282),
+    Double(1e300, -5.250476025520442e283),
+    Double(1e301, -5.250476025520442e284),
+    Double(1e302, -7.629703079084895e285),
+    Double(1e303, -1.6176507678645645e284),
+    Double(1e304, 6.0746447494463536e287),
+    Double(1e305, 6.074644749446354e288),
+    Double(1e306, -1.7216064596736455e289),
+    Double(1e307, 1.3968940239743542e290),
+    Double(1e308, -1.0979063629440455e291),
 ];
+
+/// Multiplies a `Double` by 10<sup>*n*</sup>, multiplying or dividing by an entry of
+/// [`POWERS_OF_TEN`], so that the result has the error of that one operation. Powers past
+/// the end of the table take more than one step. This is a `const fn` because parsing,
+/// which can happen at compile time, scales by powers of ten with it.
+pub const fn mul_pow10(a: Double, n: i32) -> Double {
+    const TOP: i32 = POWERS_OF_TEN.len() as i32 - 1;
+    let mut a = a;
+    let mut n = n;
+    while n > TOP && a.is_finite() {
+        a = a.mul_inner(POWERS_OF_TEN[TOP as usize]);
+        n -= TOP;
+    }
+    while n < -TOP && !a.is_zero() {
+        a = a.div_inner(POWERS_OF_TEN[TOP as usize]);
+        n += TOP;
+    }
+    if n > TOP || n < -TOP {
+        // The loops stopped early because the result is already infinite or zero
+        a
+    } else if n >= 0 {
+        a.mul_inner(POWERS_OF_TEN[n as usize])
+    } else {
+        a.div_inner(POWERS_OF_TEN[-n as usize])
+    }
+}
+
+// Determines whether a `Double` is an integer. The components of a normalized `Double`
+// don't overlap, so the lowest set bit of the whole number is the lowest set bit of its
+// last nonzero component. That means that the number is an integer exactly when both of
+// its components are, even when its first component alone can't tell.
+#[inline]
+pub fn is_integer(a: Double) -> bool {
+    a.is_finite() && a.0.fract() == 0.0 && a.1.fract() == 0.0
+}
+
+// Determines whether a `Double` that is already known to be an integer is odd. The parity
+// of the whole number is the combined parity of its components. Any component with a
+// magnitude of 2^53 or more is even, so for large numbers it's the second component that
+// decides.
+#[inline]
+pub fn is_odd(a: Double) -> bool {
+    (a.0 % 2.0 != 0.0) ^ (a.1 % 2.0 != 0.0)
+}
+
+// Determines whether a pair of components is finite and already normalized. This doesn't
+// short-circuit, so that checking a lot of pairs in a row doesn't mean a lot of branches.
+#[inline]
+pub const fn is_normalized(a: f64, b: f64) -> bool {
+    a.is_finite() & b.is_finite() & (a + b == a)
+}
+
+// Creates a `Double` from a pair of components that may or may not be normalized. Pairs
+// that are already normalized are used as-is, so that every bit (including the sign of a
+// zero second component) survives.
+pub const fn from_components(a: f64, b: f64) -> Double {
+    if is_normalized(a, b) {
+        return Double(a, b);
+    }
+    if a.is_nan() || b.is_nan() {
+        return Double::NAN;
+    }
+    let sum = a + b;
+    if sum.is_infinite() {
+        return if sum > 0.0 {
+            Double::INFINITY
+        } else {
+            Double::NEG_INFINITY
+        };
+    }
+    if sum.is_nan() {
+        // Infinities of opposite signs
+        return Double::NAN;
+    }
+    let mut terms = [b, a];
+    u::distill(&mut terms);
+    Double(terms[1], terms[0])
+}
+
+// Calculates a * b + c with a relative error of no more than a few ULPs, however much the
+// product and c cancel.
+//
+// When they don't come close to cancelling, rounding the product before adding c costs
+// very little, so the ordinary operations are good enough. Otherwise all four products of
+// components are expanded into error-free terms, and those are distilled together with
+// the components of c. Nothing is rounded until the exact sum is cut down to two components
+// at the end, so the result is as accurate as an addition of two `Double`s. (The terms can
+// only stop being exact if some of them underflow.)
+//
+// Non-finite operands and products that overflow are left to the separate operations,
+// which already follow IEEE 754 for them.
+pub fn mul_add(a: Double, b: Double, c: Double) -> Double {
+    let (h0, l0) = p::two_prod(a.0, b.0);
+    if !(h0.is_finite() && a.is_finite() && b.is_finite() && c.is_finite()) {
+        return a * b + c;
+    }
+    if h0 == 0.0 {
+        // One of the factors is zero (or the product underflowed), which leaves c, except
+        // that a zero c takes its sign from both zeros
+        return Double(h0 + c.0, c.1);
+    }
+    // No close cancellation means that the result is at least a third of the product
+    let r = a * b + c;
+    if r.0.abs() >= h0.abs() / 3.0 {
+        return r;
+    }
+    let (h1, l1) = p::two_prod(a.0, b.1);
+    let (h2, l2) = p::two_prod(a.1, b.0);
+    let (h3, l3) = p::two_prod(a.1, b.1);
+
+    let mut terms = [l3, h3, l1, l2, l0, c.1, h1, h2, c.0, h0];
+    u::distill(&mut terms);
+    if terms[9] == 0.0 {
+        // Exact cancellation of a nonzero product
+        return Double::ZERO;
+    }
+    let rest: f64 = terms[..8].iter().sum();
+    from_components(terms[9], terms[8] + rest)
+}
+
+// Calculates the rounding error of the sum s of a and b, which is the exact value of
+// a + b - s. Everything is distilled together, so the error is accurate to within a few
+// ULPs of its own even though `Double` addition isn't correctly rounded. All three numbers
+// have to be finite.
+pub fn add_error(a: Double, b: Double, s: Double) -> Double {
+    let mut terms = [a.1, b.1, -s.1, a.0, b.0, -s.0];
+    u::distill(&mut terms);
+    let rest: f64 = terms[..4].iter().sum();
+    from_components(terms[5], terms[4] + rest)
+}
+
+// Asserts that `x` is exactly the reference value called `name`, rounded one component at a
+// time, and that it's normalized. The tests of every hard-coded `Double` use this; see
+// `crate::common::reference` for where the values come from.
+#[cfg(test)]
+pub fn assert_reference(refs: &std::collections::HashMap<String, String>, name: &str, x: Double) {
+    let s = refs
+        .get(name)
+        .unwrap_or_else(|| panic!("no reference value for {}", name));
+    let expected = Double::from_string_radix_any(s, 10).unwrap();
+    assert!(
+        x.to_array() == expected.to_array(),
+        "{} is {:?}, but it should be {:?}",
+        name,
+        x,
+        expected
+    );
+    assert!(is_normalized(x.0, x.1), "{} isn't normalized", name);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::reference::references;
+
+    test!(inv_facts: {
+        let mut fact = dd!(2);
+        for (k, &x) in INV_FACTS.iter().enumerate() {
+            fact *= Double((k + 3) as f64, 0.0);
+            near!(Double::ONE / fact, x);
+        }
+    });
+
+    test!(tables_match_references: {
+        let refs = references();
+        assert_reference(&refs, "RAD_PER_DEG", RAD_PER_DEG);
+        assert_reference(&refs, "DEG_PER_RAD", DEG_PER_RAD);
+        for (k, &x) in INV_FACTS.iter().enumerate() {
+            assert_reference(&refs, &format!("INV_FACTS[{}]", k), x);
+        }
+        // The references are for steps of π/1024, and kπ/16 is 64k of those
+        for (k, &x) in SINES.iter().enumerate() {
+            assert_reference(&refs, &format!("SINES[{}]", 64 * (k + 1) - 1), x);
+        }
+        for (k, &x) in COSINES.iter().enumerate() {
+            assert_reference(&refs, &format!("COSINES[{}]", 64 * (k + 1) - 1), x);
+        }
+    });
+
+    test!(powers_of_ten: {
+        // Parsing in an arbitrary radix builds the number exactly before splitting it into
+        // components, so it's an independent check on every entry of the table
+        for (k, &x) in POWERS_OF_TEN.iter().enumerate() {
+            let s = format!("1{}", "0".repeat(k));
+            exact!(x, Double::from_string_radix_any(&s, 10).unwrap());
+        }
+    });
+
+    test!(mul_pow10_range: {
+        exact!(mul_pow10(dd!(3), 0), dd!(3));
+        exact!(mul_pow10(dd!(3), 22), dd!(3e22));
+        exact!(mul_pow10(Double::ONE, 308), POWERS_OF_TEN[308]);
+        // Exponents beyond the table go through the largest entry more than once
+        near!(mul_pow10(dd!("1e-200"), 500), dd!("1e300"));
+        near!(mul_pow10(dd!("1e200"), -450), dd!("1e-250"));
+        assert!(mul_pow10(Double::ONE, 400).is_infinite());
+        assert!(mul_pow10(Double::ONE, -400).is_zero());
+        assert!(mul_pow10(Double::ONE, i32::MAX).is_infinite());
+        assert!(mul_pow10(Double::ONE, i32::MIN).is_zero());
+        assert!(mul_pow10(Double::ZERO, i32::MAX).is_zero());
+    });
+}
@@ -10,6 +10,7 @@
 
 use crate::double::Double;
 use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 
 impl PartialEq for Double {
     /// Implements the `==` and `!= operators, testing two `Double`s for equality and
@@ -35,6 +36,48 @@ impl PartialEq for Double {
     }
 }
 
+impl Hash for Double {
+    /// Feeds the `Double` into the given hasher.
+    ///
+    /// Equal `Double`s always hash equally. Zero and negative zero are equal but have different
+    /// bits, so each component is hashed with -0 turned into 0, and since a NaN isn't equal
+    /// to anything, all NaNs are hashed the same way.
+    ///
+    /// `Double` doesn't implement `Eq`, because NaN isn't equal to itself, so it can't be the
+    /// key of a `HashMap` directly. [`OrderedDouble`] wraps a `Double` that isn't NaN and
+    /// implements `Eq`, `Ord`, and `Hash` so that it can be.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// fn hash(x: Double) -> u64 {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     x.hash(&mut hasher);
+    ///     hasher.finish()
+    /// }
+    ///
+    /// assert!(hash(Double::ZERO) == hash(Double::NEG_ZERO));
+    /// assert!(hash(Double::NAN) == hash(-Double::NAN));
+    /// assert!(hash(dd!(1.5)) != hash(dd!(2.5)));
+    /// ```
+    ///
+    /// [`OrderedDouble`]: ordered/struct.OrderedDouble.html
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let components = if self.is_nan() {
+            [f64::NAN, 0.0]
+        } else {
+            // Adding 0 turns -0 into 0 and leaves everything else alone
+            [self.0 + 0.0, self.1 + 0.0]
+        };
+        for c in components.iter() {
+            c.to_bits().hash(state);
+        }
+    }
+}
+
 impl PartialOrd for Double {
     /// Implements the `<`, `>`, `<=`, and `>=` operators, testing two `Double`s for
     /// ordering.
@@ -61,6 +104,290 @@ impl PartialOrd for Double {
     }
 }
 
+impl PartialEq<f64> for Double {
+    /// Implements the `==` and `!=` operators between a `Double` and an `f64`.
+    ///
+    /// The `f64` is compared as exactly the number that it holds in binary, as if it were
+    /// the `Double` with it as the first component and zero as the second. Apart from
+    /// that, equality works as it does between two `Double`s: zero equals negative zero,
+    /// and `NaN` equals nothing.
+    ///
+    /// Be careful with `f64`s like `0.1` that aren't exactly representable in binary. The
+    /// `f64` written as `0.1` is only the `f64` nearest to 0.1, while [`dd!`]`(0.1)` and
+    /// `Double::from("0.1")` both read it as the decimal number 0.1 and are much closer to
+    /// it than the `f64` is, so they aren't equal to it. [`Double::from`] makes a `Double`
+    /// from the `f64`'s exact value, so `Double::from(0.1)` is.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(1.5) == 1.5);
+    /// assert!(Double::ZERO == -0.0);
+    /// assert!(Double::NAN != f64::NAN);
+    ///
+    /// assert!(dd!(0.1) != 0.1);
+    /// assert!(Double::from("0.1") != 0.1);
+    /// assert!(Double::from(0.1) == 0.1);
+    /// ```
+    ///
+    /// [`dd!`]: macro.dd.html
+    /// [`Double::from`]: #impl-From<f64>
+    #[inline]
+    fn eq(&self, other: &f64) -> bool {
+        *self == Double(*other, 0.0)
+    }
+}
+
+impl PartialEq<Double> for f64 {
+    /// Implements the `==` and `!=` operators between an `f64` and a `Double`.
+    ///
+    /// This is the same as comparing the `Double` to the `f64`, so the `f64` is compared as
+    /// exactly the number that it holds in binary. See the `PartialEq<f64>` implementation
+    /// of `Double` for what that means for an `f64` like `0.1`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(1.5 == dd!(1.5));
+    /// assert!(0.1 != dd!(0.1));
+    /// assert!(0.1 == Double::from(0.1));
+    /// ```
+    #[inline]
+    fn eq(&self, other: &Double) -> bool {
+        *other == *self
+    }
+}
+
+impl PartialOrd<f64> for Double {
+    /// Implements the `<`, `>`, `<=`, and `>=` operators between a `Double` and an `f64`.
+    ///
+    /// As with equality, the `f64` is compared as exactly the number that it holds in
+    /// binary, so a `Double` that differs from an `f64` only below the precision of an
+    /// `f64` is still ordered correctly against it. Ordering with `NaN` on either side is
+    /// always false.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::PI > 3.0);
+    /// assert!(Double::PI > std::f64::consts::PI);
+    /// assert!(Double::NEG_INFINITY < f64::MIN);
+    /// assert!(!(Double::NAN < 1.0));
+    ///
+    /// // The f64 written as 0.1 is a little more than 0.1
+    /// assert!(dd!(0.1) < 0.1);
+    /// ```
+    #[inline]
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        self.partial_cmp(&Double(*other, 0.0))
+    }
+}
+
+impl PartialOrd<Double> for f64 {
+    /// Implements the `<`, `>`, `<=`, and `>=` operators between an `f64` and a `Double`.
+    ///
+    /// The `f64` is compared as exactly the number that it holds in binary, just as it is
+    /// when the `Double` is on the left.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(3.0 < Double::PI);
+    /// assert!(std::f64::consts::PI < Double::PI);
+    /// assert!(0.1 > dd!(0.1));
+    /// ```
+    #[inline]
+    fn partial_cmp(&self, other: &Double) -> Option<Ordering> {
+        Double(*self, 0.0).partial_cmp(other)
+    }
+}
+
+impl Double {
+    /// Returns the ordering between the `Double` and the argument, a total order like the
+    /// one that `f64::total_cmp` uses (IEEE 754's totalOrder predicate).
+    ///
+    /// Unlike the ordering given by [`PartialOrd`], this orders every `Double`, NaN
+    /// included, so it can be used to sort. The order is
+    ///
+    /// * negative NaN
+    /// * negative infinity
+    /// * negative numbers
+    /// * negative zero
+    /// * positive zero
+    /// * positive numbers
+    /// * positive infinity
+    /// * positive NaN
+    ///
+    /// The first components are compared with `f64::total_cmp`, so NaNs are ordered by the
+    /// bits of their first components. When the first components are the same, the second
+    /// components break the tie. The sign of a zero second component doesn't count, since
+    /// it doesn't change the value of the `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut v = vec![dd!(1), Double::NAN, -Double::NAN, Double::ZERO, Double::NEG_ZERO];
+    /// v.sort_by(Double::total_cmp);
+    ///
+    /// assert!(v[0].is_nan() && v[0].is_sign_negative());
+    /// assert!(v[1] == dd!(0) && v[1].is_sign_negative());
+    /// assert!(v[2] == dd!(0) && v[2].is_sign_positive());
+    /// assert!(v[3] == dd!(1));
+    /// assert!(v[4].is_nan() && v[4].is_sign_positive());
+    /// ```
+    ///
+    /// [`PartialOrd`]: #impl-PartialOrd<Double>
+    #[inline]
+    pub fn total_cmp(&self, other: &Double) -> Ordering {
+        // Adding 0 turns -0 into 0 and leaves everything else (including NaN) alone
+        self.0
+            .total_cmp(&other.0)
+            .then_with(|| (self.1 + 0.0).total_cmp(&(other.1 + 0.0)))
+    }
+
+    /// Returns the larger of the `Double` and the argument.
+    ///
+    /// As with `f64::max`, NaN is ignored if the other argument is a number, and the result
+    /// is NaN only if both arguments are NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(1).max(dd!(2)) == dd!(2));
+    /// assert!(Double::NAN.max(dd!(2)) == dd!(2));
+    /// assert!(dd!(1).max(Double::NAN) == dd!(1));
+    /// ```
+    #[inline]
+    pub fn max(self, other: Double) -> Double {
+        if self.is_nan() || self < other {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the smaller of the `Double` and the argument.
+    ///
+    /// As with `f64::min`, NaN is ignored if the other argument is a number, and the result
+    /// is NaN only if both arguments are NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(1).min(dd!(2)) == dd!(1));
+    /// assert!(Double::NAN.min(dd!(2)) == dd!(2));
+    /// assert!(dd!(1).min(Double::NAN) == dd!(1));
+    /// ```
+    #[inline]
+    pub fn min(self, other: Double) -> Double {
+        if self.is_nan() || other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the larger of the `Double` and the argument, propagating NaN.
+    ///
+    /// Unlike [`max`], this returns NaN if either argument is NaN, and it considers
+    /// negative zero to be less than positive zero. This is the same as `f64::maximum`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(1).maximum(dd!(2)) == dd!(2));
+    /// assert!(Double::NAN.maximum(dd!(2)).is_nan());
+    /// assert!(Double::NEG_ZERO.maximum(Double::ZERO).is_sign_positive());
+    /// ```
+    ///
+    /// [`max`]: #method.max
+    #[inline]
+    pub fn maximum(self, other: Double) -> Double {
+        if self > other {
+            self
+        } else if other > self {
+            other
+        } else if self == other {
+            if self.is_sign_positive() && other.is_sign_negative() {
+                self
+            } else {
+                other
+            }
+        } else if self.is_nan() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns the smaller of the `Double` and the argument, propagating NaN.
+    ///
+    /// Unlike [`min`], this returns NaN if either argument is NaN, and it considers
+    /// negative zero to be less than positive zero. This is the same as `f64::minimum`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(1).minimum(dd!(2)) == dd!(1));
+    /// assert!(dd!(1).minimum(Double::NAN).is_nan());
+    /// assert!(Double::ZERO.minimum(Double::NEG_ZERO).is_sign_negative());
+    /// ```
+    ///
+    /// [`min`]: #method.min
+    #[inline]
+    pub fn minimum(self, other: Double) -> Double {
+        if self < other {
+            self
+        } else if other < self {
+            other
+        } else if self == other {
+            if self.is_sign_negative() && other.is_sign_positive() {
+                self
+            } else {
+                other
+            }
+        } else if self.is_nan() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Restricts the `Double` to the interval between `min` and `max`.
+    ///
+    /// The result is `max` if the `Double` is greater than `max` and `min` if it's less
+    /// than `min`. Otherwise it's the `Double` itself, which includes when it's NaN.
+    ///
+    /// # Panics
+    /// As with `f64::clamp`, this panics if `min` is greater than `max` or if either is
+    /// NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(-3).clamp(dd!(-2), dd!(1)) == dd!(-2));
+    /// assert!(dd!(0).clamp(dd!(-2), dd!(1)) == dd!(0));
+    /// assert!(dd!(2).clamp(dd!(-2), dd!(1)) == dd!(1));
+    /// assert!(Double::NAN.clamp(dd!(-2), dd!(1)).is_nan());
+    /// ```
+    #[inline]
+    pub fn clamp(self, min: Double, max: Double) -> Double {
+        assert!(
+            min <= max,
+            "min > max, or either was NaN. min = {:?}, max = {:?}",
+            min,
+            max
+        );
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -130,4 +457,202 @@ mod tests {
         gte_nan_nan:
             !(Double::NAN >= Double::NAN);
     );
+
+    // f64 comparison tests
+    test_all_assert!(
+        eq_f64_exact:
+            Double(1.5, 0.0) == 1.5 && 1.5 == Double(1.5, 0.0);
+        eq_f64_zero_neg_zero:
+            Double::ZERO == -0.0 && -0.0 == Double::ZERO;
+        eq_f64_inf:
+            Double::INFINITY == f64::INFINITY && f64::NEG_INFINITY == Double::NEG_INFINITY;
+        eq_f64_inf_neg_inf:
+            Double::INFINITY != f64::NEG_INFINITY;
+        eq_f64_nan:
+            Double::NAN != f64::NAN && f64::NAN != Double::NAN && Double::NAN != 1.0;
+        eq_f64_decimal:
+            dd!(0.1) != 0.1 && 0.1 != dd!(0.1) && dd!("0.1") != 0.1;
+        eq_f64_from:
+            Double::from(0.1) == 0.1
+                && 0.1 == Double::from(0.1)
+                && Double::from(0.1f32) == 0.1f32 as f64;
+        eq_f64_exact_decimal:
+            Double(0.1, 0.0) == 0.1 && 0.1 == Double(0.1, 0.0);
+        eq_f64_below_precision:
+            Double(1.0, 1e-30) != 1.0 && 1.0 != Double(1.0, -1e-30);
+    );
+    test_all_assert!(
+        ord_f64_below_precision:
+            Double(1.0, 1e-30) > 1.0 && Double(1.0, -1e-30) < 1.0;
+        ord_f64_below_precision_reversed:
+            1.0 < Double(1.0, 1e-30) && 1.0 > Double(1.0, -1e-30);
+        ord_f64_below_precision_neg:
+            Double(-1.0, 1e-30) > -1.0 && -1.0 > Double(-1.0, -1e-30);
+        ord_f64_decimal:
+            dd!(0.1) < 0.1 && 0.1 > dd!(0.1) && dd!(0.3) > 0.3;
+        ord_f64_pi:
+            Double::PI > std::f64::consts::PI && std::f64::consts::PI < Double::PI;
+        ord_f64_zero_neg_zero:
+            Double::ZERO <= -0.0 && Double::NEG_ZERO >= 0.0 && !(Double::ZERO < -0.0);
+        ord_f64_inf:
+            Double::INFINITY > f64::MAX && f64::NEG_INFINITY < Double::NEG_ONE;
+        ord_f64_inf_inf:
+            Double::INFINITY >= f64::INFINITY && !(Double::INFINITY > f64::INFINITY);
+        ord_f64_nan:
+            !(Double::NAN < 1.0) && !(Double::NAN >= 1.0) && !(1.0 <= Double::NAN);
+        ord_f64_nan_partial_cmp:
+            Double::ONE.partial_cmp(&f64::NAN).is_none();
+    );
+
+    // total_cmp tests
+    test_all!(
+        total_cmp_sort: {
+            let mut v = vec![
+                Double::NAN,
+                Double::ONE,
+                Double::NEG_INFINITY,
+                Double::ZERO,
+                -Double::PI,
+                Double::INFINITY,
+                -Double::NAN,
+                Double::NEG_ZERO,
+                Double::PI,
+                Double(1.0, 1e-20),
+                Double::NEG_ONE,
+                Double(1.0, -1e-20),
+            ];
+            v.sort_by(Double::total_cmp);
+
+            let expected = [
+                -Double::NAN,
+                Double::NEG_INFINITY,
+                -Double::PI,
+                Double::NEG_ONE,
+                Double::NEG_ZERO,
+                Double::ZERO,
+                Double(1.0, -1e-20),
+                Double::ONE,
+                Double(1.0, 1e-20),
+                Double::PI,
+                Double::INFINITY,
+                Double::NAN,
+            ];
+            for (a, b) in v.iter().zip(expected.iter()) {
+                assert_eq!(a.to_bits(), b.to_bits());
+            }
+        }
+        total_cmp_zero_second_component: {
+            assert_eq!(Double(1.0, -0.0).total_cmp(&Double::ONE), Ordering::Equal);
+            assert_eq!((-Double::ONE).total_cmp(&Double::NEG_ONE), Ordering::Equal);
+        }
+        total_cmp_nan_payload: {
+            let quiet = Double(f64::from_bits(0x7ff8_0000_0000_0001), 0.0);
+            assert_eq!(Double::NAN.total_cmp(&quiet), Ordering::Less);
+            assert_eq!((-quiet).total_cmp(&-Double::NAN), Ordering::Less);
+            assert_eq!(Double::NAN.total_cmp(&Double::NAN), Ordering::Equal);
+        }
+    );
+
+    // max and min tests
+    test_all_exact!(
+        max_pi_e:
+            Double::PI,
+            Double::PI.max(Double::E);
+        max_e_pi:
+            Double::PI,
+            Double::E.max(Double::PI);
+        max_nan_pi:
+            Double::PI,
+            Double::NAN.max(Double::PI);
+        max_pi_nan:
+            Double::PI,
+            Double::PI.max(Double::NAN);
+        max_nan_nan:
+            Double::NAN,
+            Double::NAN.max(Double::NAN);
+        min_pi_e:
+            Double::E,
+            Double::PI.min(Double::E);
+        min_e_pi:
+            Double::E,
+            Double::E.min(Double::PI);
+        min_nan_pi:
+            Double::PI,
+            Double::NAN.min(Double::PI);
+        min_pi_nan:
+            Double::PI,
+            Double::PI.min(Double::NAN);
+        min_nan_nan:
+            Double::NAN,
+            Double::NAN.min(Double::NAN);
+    );
+
+    // maximum and minimum tests
+    test_all_exact!(
+        maximum_pi_e:
+            Double::PI,
+            Double::PI.maximum(Double::E);
+        maximum_neg_inf_e:
+            Double::E,
+            Double::NEG_INFINITY.maximum(Double::E);
+        maximum_nan_pi:
+            Double::NAN,
+            Double::NAN.maximum(Double::PI);
+        maximum_pi_nan:
+            Double::NAN,
+            Double::PI.maximum(Double::NAN);
+        minimum_pi_e:
+            Double::E,
+            Double::PI.minimum(Double::E);
+        minimum_inf_e:
+            Double::E,
+            Double::INFINITY.minimum(Double::E);
+        minimum_nan_pi:
+            Double::NAN,
+            Double::NAN.minimum(Double::PI);
+        minimum_pi_nan:
+            Double::NAN,
+            Double::PI.minimum(Double::NAN);
+    );
+    test_all_assert!(
+        maximum_zero_neg_zero:
+            Double::ZERO.maximum(Double::NEG_ZERO).is_sign_positive();
+        maximum_neg_zero_zero:
+            Double::NEG_ZERO.maximum(Double::ZERO).is_sign_positive();
+        minimum_zero_neg_zero:
+            Double::ZERO.minimum(Double::NEG_ZERO).is_sign_negative();
+        minimum_neg_zero_zero:
+            Double::NEG_ZERO.minimum(Double::ZERO).is_sign_negative();
+    );
+
+    // clamp tests
+    test_all_exact!(
+        clamp_below:
+            Double::NEG_ONE,
+            (-Double::PI).clamp(Double::NEG_ONE, Double::ONE);
+        clamp_within:
+            Double::ZERO,
+            Double::ZERO.clamp(Double::NEG_ONE, Double::ONE);
+        clamp_above:
+            Double::ONE,
+            Double::PI.clamp(Double::NEG_ONE, Double::ONE);
+        clamp_inf:
+            Double::ONE,
+            Double::INFINITY.clamp(Double::NEG_ONE, Double::ONE);
+        clamp_nan:
+            Double::NAN,
+            Double::NAN.clamp(Double::NEG_ONE, Double::ONE);
+    );
+
+    #[test]
+    #[should_panic(expected = "min > max, or either was NaN")]
+    fn clamp_reversed() {
+        Double::ZERO.clamp(Double::ONE, Double::NEG_ONE);
+    }
+
+    #[test]
+    #[should_panic(expected = "min > max, or either was NaN")]
+    fn clamp_nan_bound() {
+        Double::ZERO.clamp(Double::NAN, Double::ONE);
+    }
 }
@@ -0,0 +1,492 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::primitive as p;
+use crate::common::utils as u;
+use crate::double::common as c;
+use crate::double::Double;
+
+impl Double {
+    /// Creates a `Double` from two components that don't have to be normalized.
+    ///
+    /// Unlike [`new`], this normalizes its arguments, so it's safe to use on any pair of
+    /// `f64`s. The result has the value of the (exact) sum of the two components. A pair
+    /// that is already normalized is used as-is, bit for bit.
+    ///
+    /// If either component is NaN, or if the components are infinities of opposite signs,
+    /// the result is [`NAN`]. If the sum is too large to represent, the result is
+    /// [`INFINITY`] or [`NEG_INFINITY`], depending on its sign.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let x = Double::from_components(1.0, 1.0);
+    /// assert!(x.as_components() == (2.0, 0.0));
+    ///
+    /// let y = Double::from_components(1e-20, 1.0);
+    /// assert!(y.as_components() == (1.0, 1e-20));
+    ///
+    /// assert!(Double::from_components(f64::MAX, f64::MAX) == Double::INFINITY);
+    /// assert!(Double::from_components(1.0, f64::NAN).is_nan());
+    /// ```
+    ///
+    /// [`new`]: #method.new
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    pub fn from_components(a: f64, b: f64) -> Double {
+        c::from_components(a, b)
+    }
+
+    /// Returns the components of the `Double` as a tuple.
+    ///
+    /// The first component is the closest `f64` to the number. The second is the rest of
+    /// the number, which includes the rounding error in the first.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let (a, b) = Double::PI.as_components();
+    /// assert!(a == 3.141592653589793e0);
+    /// assert!(b == 1.2246467991473532e-16);
+    /// ```
+    #[inline]
+    pub fn as_components(self) -> (f64, f64) {
+        (self.0, self.1)
+    }
+
+    /// Returns the components of the `Double` as an array.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let a = Double::PI.to_array();
+    /// assert!(a == [3.141592653589793e0, 1.2246467991473532e-16]);
+    /// ```
+    #[inline]
+    pub const fn to_array(self) -> [f64; 2] {
+        [self.0, self.1]
+    }
+
+    /// Creates a `Double` from an array of two components, normalizing them in the same
+    /// way as [`from_components`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let x = Double::from_array([3.141592653589793e0, 1.2246467991473532e-16]);
+    /// assert!(x == Double::PI);
+    ///
+    /// let y = Double::from_array([1.0, 1.0]);
+    /// assert!(y.to_array() == [2.0, 0.0]);
+    /// ```
+    ///
+    /// [`from_components`]: #method.from_components
+    #[inline]
+    pub fn from_array(a: [f64; 2]) -> Double {
+        c::from_components(a[0], a[1])
+    }
+
+    /// Creates a `Double` from the sum of a slice of `f64`s.
+    ///
+    /// The sum is calculated exactly, by distilling the slice with error-free additions
+    /// until its terms no longer overlap, and is only rounded when it's cut down to the
+    /// components of a `Double`. So the result is accurate whatever the order and magnitudes
+    /// of the `f64`s and however much they cancel, where adding them up from left to right
+    /// can lose everything. The result is the normalized `Double` nearest the exact sum, except
+    /// that its last component can be one ULP off.
+    ///
+    /// As with `f64` addition, the result is ∞ or -∞ if the slice holds infinities of only
+    /// that sign, and NaN if it holds a NaN or infinities of both signs. A sum too large
+    /// to represent is an infinity, even if adding from left to right would have overflowed
+    /// only partway through. An empty slice sums to 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let x = Double::from_sum(&[1e100, 1.0, -1e100, 1e-20]);
+    /// assert!(x.to_array() == [1.0, 1e-20]);
+    ///
+    /// // Left to right, the 1 is lost
+    /// assert!(1e100 + 1.0 - 1e100 + 1e-20 == 1e-20);
+    ///
+    /// assert!(Double::from_sum(&[f64::MAX, f64::MAX, -f64::MAX]).to_array() == [f64::MAX, 0.0]);
+    /// assert!(Double::from_sum(&[f64::INFINITY, 1.0]) == Double::INFINITY);
+    /// assert!(Double::from_sum(&[f64::INFINITY, f64::NEG_INFINITY]).is_nan());
+    /// ```
+    pub fn from_sum(parts: &[f64]) -> Double {
+        if parts.iter().any(|x| !x.is_finite()) {
+            let s: f64 = parts.iter().filter(|x| !x.is_finite()).sum();
+            return Double(s, 0.0);
+        }
+        let (terms, scale) = u::distill_sum(parts);
+        let n = terms.len();
+        let term = |i: usize| if i < n { terms[n - 1 - i] * scale } else { 0.0 };
+        c::from_components(term(0), term(1))
+    }
+
+    /// Creates a `Double` from the exact product of two `f64`s.
+    ///
+    /// The product of two `f64`s has up to 106 significant bits, which is more than an `f64`
+    /// holds but no more than the first two components of a `Double` do. The first component
+    /// of the result is the product rounded to an `f64`, exactly as `a * b` would be, and
+    /// the second is the error of that rounding. This uses FMA unless the `no_fma` feature
+    /// is enabled, and Dekker's splitting of the arguments into halves if it is.
+    ///
+    /// The result is exact for any two `f64`s whose product is finite, except for products
+    /// so small (under about 10<sup>-291</sup>) that their rounding error is below the
+    /// smallest subnormal `f64`. There the error is rounded. If the product overflows, the
+    /// result is [`INFINITY`] or [`NEG_INFINITY`], and it's [`NAN`] if either argument is
+    /// NaN or if the product is 0 × ∞.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let a = 1.0 + 2f64.powi(-30);
+    /// let b = 1.0 - 2f64.powi(-30);
+    /// assert!(a * b == 1.0);
+    ///
+    /// // 1 - 2^-60 exactly
+    /// let x = Double::from_product(a, b);
+    /// assert!(x.to_array() == [1.0, -2f64.powi(-60)]);
+    ///
+    /// assert!(Double::from_product(f64::MAX, 2.0) == Double::INFINITY);
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn from_product(a: f64, b: f64) -> Double {
+        let (p, e) = u::exact_prod(a, b);
+        Double(p, e)
+    }
+
+    /// Creates a `Double` from the exact sum of two `f64`s.
+    ///
+    /// Like [`from_product`], this is an error-free transformation: the first component is
+    /// `a + b` rounded to an `f64`, and the second is what that rounding lost. The sum of
+    /// two `f64`s always fits, so the result is exact unless the sum overflows, in which case
+    /// it's an infinity. As with `f64` addition, the result is [`NAN`] if either argument is
+    /// NaN or if they're infinities of opposite signs. For the sum of more than two `f64`s,
+    /// see [`from_sum`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let x = Double::from_sum2(1e100, 1.0);
+    /// assert!(x.to_array() == [1e100, 1.0]);
+    /// assert!(x - Double::from(1e100) == Double::ONE);
+    ///
+    /// assert!(Double::from_sum2(f64::MAX, f64::MAX) == Double::INFINITY);
+    /// ```
+    ///
+    /// [`from_product`]: #method.from_product
+    /// [`from_sum`]: #method.from_sum
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn from_sum2(a: f64, b: f64) -> Double {
+        let (s, e) = p::two_sum(a, b);
+        if s.is_finite() {
+            Double(s, e)
+        } else {
+            Double(s, 0.0)
+        }
+    }
+
+    /// Returns the raw bits of the components of the `Double`.
+    ///
+    /// Every bit survives, including the payloads of NaNs and the signs of zeros, so
+    /// [`from_bits`] turns the result back into exactly the same `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let bits = Double::PI.to_bits();
+    /// assert!(bits == [0x400921fb54442d18, 0x3ca1a62633145c07]);
+    /// assert!(Double::from_bits(bits) == Double::PI);
+    /// ```
+    ///
+    /// [`from_bits`]: #method.from_bits
+    #[inline]
+    pub fn to_bits(self) -> [u64; 2] {
+        [self.0.to_bits(), self.1.to_bits()]
+    }
+
+    /// Creates a `Double` from the raw bits of its components.
+    ///
+    /// This is the inverse of [`to_bits`]. Like [`new`], it does *not* normalize, so that
+    /// every bit of a `Double` survives the round trip. Bits that didn't come from a
+    /// normalized `Double` can be normalized afterwards by passing the resulting components
+    /// to [`from_components`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let x = Double::from_bits([0x8000000000000000, 0]);
+    /// assert!(x.is_zero() && x.is_sign_negative());
+    /// assert!(x.to_bits() == [0x8000000000000000, 0]);
+    /// ```
+    ///
+    /// [`to_bits`]: #method.to_bits
+    /// [`new`]: #method.new
+    /// [`from_components`]: #method.from_components
+    #[inline]
+    pub fn from_bits(bits: [u64; 2]) -> Double {
+        Double(f64::from_bits(bits[0]), f64::from_bits(bits[1]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::random::Rng;
+
+    // Bitwise equality, so that NaN equals NaN and the signs of zeros count
+    fn same(a: Double, b: Double) -> bool {
+        a.to_bits() == b.to_bits()
+    }
+
+    test!(from_components_normalized: {
+        assert!(same(Double::from_components(3.0, 1e-20), Double(3.0, 1e-20)));
+        assert!(same(Double::from_components(-0.0, 0.0), Double(-0.0, 0.0)));
+        assert!(same(Double::from_components(0.0, -0.0), Double(0.0, -0.0)));
+        let (a, b) = Double::E.as_components();
+        assert!(same(Double::from_components(a, b), Double::E));
+    });
+
+    test!(from_components_unnormalized: {
+        assert!(same(Double::from_components(1.0, 1.0), Double(2.0, 0.0)));
+        assert!(same(Double::from_components(-1.0, -1.0), Double(-2.0, 0.0)));
+        assert!(same(Double::from_components(1e-20, 1.0), Double(1.0, 1e-20)));
+        assert!(same(Double::from_components(0.0, 1.0), Double(1.0, 0.0)));
+        assert!(same(Double::from_components(1.0, -1.0), Double(0.0, 0.0)));
+        let x = Double::from_components(1.0, 3e-16);
+        assert!(x[0] + x[1] == x[0]);
+        assert!(x == Double(1.0, 0.0) + Double(3e-16, 0.0));
+    });
+
+    test!(from_components_overflow: {
+        assert!(same(Double::from_components(f64::MAX, f64::MAX), Double::INFINITY));
+        assert!(same(Double::from_components(-f64::MAX, -f64::MAX), Double::NEG_INFINITY));
+        assert!(same(Double::from_components(f64::MAX, -f64::MAX), Double(0.0, 0.0)));
+    });
+
+    test!(from_components_nonfinite: {
+        assert!(Double::from_components(1.0, f64::NAN).is_nan());
+        assert!(Double::from_components(f64::NAN, 1.0).is_nan());
+        assert!(Double::from_components(f64::INFINITY, f64::NEG_INFINITY).is_nan());
+        assert!(same(Double::from_components(f64::INFINITY, 1.0), Double::INFINITY));
+        assert!(same(Double::from_components(-1.0, f64::NEG_INFINITY), Double::NEG_INFINITY));
+    });
+
+    test!(arrays: {
+        assert!(Double::PI.to_array() == [Double::PI[0], Double::PI[1]]);
+        assert!(same(Double::from_array(Double::PI.to_array()), Double::PI));
+        assert!(same(Double::from_array([1.0, 1.0]), Double(2.0, 0.0)));
+        assert!(same(Double::from_array([f64::MAX, f64::MAX]), Double::INFINITY));
+    });
+
+    // Integer multiples of powers of 2, small enough that their exact sum, as a multiple of
+    // 2^-20, fits into an i128 and into a `Double`
+    fn parts(seed: &mut u64, count: usize) -> (Vec<f64>, i128) {
+        let mut next = || {
+            *seed ^= *seed << 13;
+            *seed ^= *seed >> 7;
+            *seed ^= *seed << 17;
+            *seed
+        };
+        let mut parts = vec![];
+        let mut exact = 0i128;
+        for _ in 0..count {
+            let m = (next() >> 14) as i64 - (1 << 49);
+            let e = (next() % 41) as i32 - 20;
+            parts.push(m as f64 * 2f64.powi(e));
+            exact += (m as i128) << (e + 20);
+        }
+        (parts, exact)
+    }
+
+    test!(from_sum_exact: {
+        let mut seed = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..200 {
+            let (mut parts, exact) = parts(&mut seed, 50);
+            let hi = (exact >> 48) as f64 * 2f64.powi(48);
+            let lo = (exact & ((1 << 48) - 1)) as f64;
+            let expected = Double::from_components(hi, lo).ldexp(-20);
+            assert!(same(Double::from_sum(&parts), expected));
+            parts.reverse();
+            assert!(same(Double::from_sum(&parts), expected));
+        }
+    });
+
+    test!(from_sum_cancellation: {
+        assert!(same(Double::from_sum(&[1e100, 1.0, -1e100, 1e-20]), Double(1.0, 1e-20)));
+        assert!(same(Double::from_sum(&[1.0, 1e-20, -1.0]), Double(1e-20, 0.0)));
+
+        let mut seed = 0x9e37_79b9_7f4a_7c15;
+        let (mut parts, _) = parts(&mut seed, 50);
+        let negated: Vec<f64> = parts.iter().map(|x| -x).collect();
+        parts.push(3e-300);
+        parts.extend(negated);
+        assert!(same(Double::from_sum(&parts), Double(3e-300, 0.0)));
+    });
+
+    test!(from_sum_rounded: {
+        let (a, b, c) = (2f64.powi(-60), 2f64.powi(-130), 2f64.powi(-200));
+        assert!(same(Double::from_sum(&[c, b, a, 1.0]), Double(1.0, a)));
+        assert!(same(Double::from_sum(&[1.0, -c]), Double(1.0, -c)));
+    });
+
+    test!(from_sum_zeros: {
+        assert!(same(Double::from_sum(&[]), Double(0.0, 0.0)));
+        assert!(same(Double::from_sum(&[-0.0, -0.0]), Double(-0.0, 0.0)));
+        assert!(Double::from_sum(&[1.0, -1.0]).is_sign_positive());
+        assert!(Double::from_sum(&[0.0, -0.0, 0.0]).is_zero());
+    });
+
+    test!(from_sum_extremes: {
+        let tiny = f64::from_bits(1);
+        assert!(same(Double::from_sum(&[tiny, tiny]), Double(f64::from_bits(2), 0.0)));
+        assert!(same(Double::from_sum(&[f64::MAX, f64::MAX, -f64::MAX]), Double(f64::MAX, 0.0)));
+        assert!(same(Double::from_sum(&[f64::MAX, 1.0, f64::MAX, -f64::MAX]), Double(f64::MAX, 1.0)));
+        assert!(same(Double::from_sum(&[f64::MAX, f64::MAX]), Double::INFINITY));
+        assert!(same(Double::from_sum(&[-f64::MAX, 1.0, -f64::MAX]), Double::NEG_INFINITY));
+    });
+
+    test!(from_sum_nonfinite: {
+        assert!(same(Double::from_sum(&[1.0, f64::INFINITY, f64::MAX]), Double::INFINITY));
+        assert!(same(Double::from_sum(&[f64::NEG_INFINITY, 1.0]), Double::NEG_INFINITY));
+        assert!(Double::from_sum(&[f64::INFINITY, 1.0, f64::NEG_INFINITY]).is_nan());
+        assert!(Double::from_sum(&[f64::INFINITY, f64::NAN]).is_nan());
+        assert!(Double::from_sum(&[1.0, f64::NAN]).is_nan());
+    });
+
+    // x * 2^e, in two steps so that neither factor overflows or underflows on its own
+    fn scale(x: f64, e: i32) -> f64 {
+        x * 2f64.powi(e / 2) * 2f64.powi(e - e / 2)
+    }
+
+    // Whether the product of ma * 2^ea and mb * 2^eb comes out exactly. Both components of
+    // the result have to be integer multiples of 2^(ea + eb), and they have to add up to
+    // ma * mb.
+    fn product_exact(ma: i64, ea: i32, mb: i64, eb: i32) -> bool {
+        let x = Double::from_product(scale(ma as f64, ea), scale(mb as f64, eb));
+        let k = ea + eb;
+        let (hi, lo) = (scale(x[0], -k), scale(x[1], -k));
+        hi.fract() == 0.0
+            && lo.fract() == 0.0
+            && hi as i128 + lo as i128 == ma as i128 * mb as i128
+            && x[0] == scale(ma as f64, ea) * scale(mb as f64, eb)
+    }
+
+    test!(from_product_adversarial: {
+        let (a, b) = (1.0 + 2f64.powi(-30), 1.0 - 2f64.powi(-30));
+        assert!(same(Double::from_product(a, b), Double(1.0, -2f64.powi(-60))));
+        assert!(same(Double::from_product(-a, a), Double(-(1.0 + 2f64.powi(-29)), -2f64.powi(-60))));
+
+        let m = (1i64 << 53) - 1;
+        assert!(product_exact(m, 0, m, 0));
+        assert!(product_exact(m, -30, -(m - 2), 12));
+        assert!(product_exact((1 << 52) + 1, 100, (1 << 52) - 1, -300));
+    });
+
+    test!(from_product_extremes: {
+        let m = (1i64 << 53) - 1;
+        // f64::MAX times numbers just under 1, where the halves of a Dekker split multiply
+        // to more than f64::MAX
+        assert!(product_exact(m, 971, m, -53));
+        assert!(product_exact(m, 971, -((1 << 52) + 1), -53));
+        assert!(product_exact(m, 600, m, 318));
+        assert!(product_exact(-m, 918, m, 0));
+        // The error is a multiple of 2^-1074, so it's just representable
+        assert!(product_exact(m, -537, m, -537));
+        assert!(product_exact(m, -1074, m, 0));
+        assert!(product_exact(3, -1074, m, 0));
+        assert!(product_exact(m, -1000, -m, -74));
+    });
+
+    test!(from_product_random: {
+        let mut rng = Rng::new(0x3c6e_f372_fe94_f82b);
+        for _ in 0..10000 {
+            let ma = (rng.next_u64() >> 11) as i64 | 1 << 52;
+            let mb = (rng.next_u64() >> 11) as i64 | 1 << 52;
+            let ma = if rng.next_u64() & 1 == 0 { ma } else { -ma };
+            // Every exponent of the product for which it's finite and its error representable
+            let k = rng.range(-1074, 919);
+            let lo = (k - 971).max(-1074);
+            let hi = (k + 1074).min(971);
+            let ea = lo + (rng.next_u64() % (hi - lo + 1) as u64) as i32;
+            assert!(product_exact(ma, ea, mb, k - ea), "{} {} {} {}", ma, ea, mb, k - ea);
+        }
+    });
+
+    test!(from_product_special: {
+        assert!(same(Double::from_product(f64::MAX, 2.0), Double::INFINITY));
+        assert!(same(Double::from_product(f64::MAX, -f64::MAX), Double::NEG_INFINITY));
+        assert!(same(Double::from_product(-0.0, 3.0), Double(-0.0, 0.0)));
+        assert!(same(Double::from_product(0.0, 0.0), Double(0.0, 0.0)));
+        assert!(Double::from_product(0.0, f64::INFINITY).is_nan());
+        assert!(Double::from_product(f64::NAN, 1.0).is_nan());
+        // Too small for anything but the rounded product, whose error is below 2^-1074
+        let a = scale(1.0 + 2f64.powi(-52), -537);
+        assert!(same(Double::from_product(a, a), Double(f64::from_bits(1), 0.0)));
+        assert!(same(Double::from_product(2f64.powi(-600), 2f64.powi(-500)), Double(0.0, 0.0)));
+        // An error of 3 * 2^-1076 rounds to the nearest subnormal
+        let b = scale(1.0 + 2f64.powi(-52), -486);
+        let c = scale(1.0 + 3.0 * 2f64.powi(-52), -486);
+        let x = Double::from_product(b, c);
+        assert!(x[0] == b * c && x[1] == f64::from_bits(1));
+    });
+
+    test!(from_sum2: {
+        assert!(same(Double::from_sum2(1e100, 1.0), Double(1e100, 1.0)));
+        assert!(same(Double::from_sum2(1.0, 2f64.powi(-80)), Double(1.0, 2f64.powi(-80))));
+        assert!(same(Double::from_sum2(1.0, -1.0), Double(0.0, 0.0)));
+        assert!(same(Double::from_sum2(-0.0, -0.0), Double(-0.0, 0.0)));
+        let half = f64::MAX * 2f64.powi(-54);
+        assert!(same(Double::from_sum2(f64::MAX, -half), Double(f64::MAX, -half)));
+        let tiny = f64::from_bits(1);
+        assert!(same(Double::from_sum2(tiny, 1.0), Double(1.0, tiny)));
+    });
+
+    test!(from_sum2_nonfinite: {
+        assert!(same(Double::from_sum2(f64::MAX, f64::MAX), Double::INFINITY));
+        assert!(same(Double::from_sum2(-f64::MAX, -f64::MAX), Double::NEG_INFINITY));
+        assert!(same(Double::from_sum2(f64::INFINITY, 1.0), Double::INFINITY));
+        assert!(Double::from_sum2(f64::INFINITY, f64::NEG_INFINITY).is_nan());
+        assert!(Double::from_sum2(f64::NAN, 1.0).is_nan());
+    });
+
+    test!(bits_round_trip: {
+        for &x in &[
+            Double::PI,
+            -Double::E,
+            Double::ZERO,
+            Double::NEG_ZERO,
+            Double(1.0, -0.0),
+            Double::MAX,
+            Double::MIN_POSITIVE,
+            Double(5e-324, 0.0),
+            Double::INFINITY,
+            Double::NEG_INFINITY,
+        ] {
+            assert!(same(Double::from_bits(x.to_bits()), x));
+        }
+    });
+
+    test!(bits_nan_payload: {
+        let bits = [0x7ff8_0000_dead_beef, 0xfff0_0000_0000_0001];
+        let x = Double::from_bits(bits);
+        assert!(x.is_nan());
+        assert!(x.to_bits() == bits);
+    });
+
+    test!(bits_unnormalized: {
+        let bits = [1f64.to_bits(), 1f64.to_bits()];
+        let x = Double::from_bits(bits);
+        assert!(x.as_components() == (1.0, 1.0));
+        let (a, b) = x.as_components();
+        assert!(same(Double::from_components(a, b), Double(2.0, 0.0)));
+    });
+}
@@ -4,23 +4,47 @@
 // https://opensource.org/licenses/MIT
 
 use crate::common::display as d;
+use crate::double::common as c;
 use crate::double::Double;
-use std::char;
 use std::fmt::{Debug, Display, Formatter, LowerExp, Result, UpperExp};
 
 const TEN: Double = Double(10.0, 0.0);
 const MAX_ACCURACY: usize = 31;
 
+// The most decimal digits that fit into an `f64` exactly, along with the powers of ten
+// that shift that many digits in front of the decimal point.
+const CHUNK: usize = 15;
+const POWERS: [f64; CHUNK + 1] = [
+    1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15,
+];
+
 impl Display for Double {
     /// Formats a `Double` for display.
     ///
     /// All formatting options that are shown in [`std::fmt`] are supported *except* for
     /// ones that are typically meant only for integers (hexadecimal, binary, octal, and
-    /// pointer formats). Because of this, the "alternate" (`#`) flag is only recognized
-    /// along with `?`, pretty-printing the `Debug` output.
+    /// pointer formats). With `?`, the "alternate" (`#`) flag pretty-prints the `Debug`
+    /// output.
     ///
     /// By default, `Double`s are printed with 31 digits but drop trailing zeros.
     ///
+    /// The alternate flag changes `Display` to print a fixed number of *significant*
+    /// digits, keeping trailing zeros, much like C's `%#g`. The precision is the number of
+    /// significant digits (never less than 1 and never more than 31), and it defaults to
+    /// 31. Numbers are written in fixed form unless their exponents are less than -4 or are
+    /// at least the number of significant digits, in which case they're written in
+    /// exponential form. This makes the width of the output predictable, which is useful
+    /// for lining up columns of numbers.
+    ///
+    /// Sign-aware zero fill puts zeros between the sign and the first digit, so it pads
+    /// the number without touching the exponent, just as it does for an `f64`. Unlike with
+    /// an `f64`, it applies only where there's a digit to put the zeros in front of.
+    /// Infinity, NaN, and zero in exponential form (where `00000000e0` doesn't read as a
+    /// single zero) are right-aligned with spaces instead.
+    ///
+    /// NaN is always written as `NaN`, as it is for an `f64`. It has no sign, even when its
+    /// sign bit is set or the `+` flag is given.
+    ///
     /// This function also provides the formatting for [`to_string`], which renders the
     /// `Double` as if formatted with an empty format specifier (`"{}"`).
     ///
@@ -71,26 +95,41 @@ impl Display for Double {
     /// assert!(format!("{:0>10}", -value) == "000-123456");
     /// assert!(format!("{:010}", -value) == "-000123456");
     /// assert!(format!("{:+012e}", value) == "+001.23456e5");
+    /// assert!(format!("{:010e}", Double::ZERO) == "       0e0");
+    /// assert!(format!("{:010}", Double::NEG_INFINITY) == "      -inf");
+    /// assert!(format!("{:+}", -Double::NAN) == "NaN");
+    ///
+    /// // significant digits
+    /// assert!(format!("{:#.6}", dd!(1.5)) == "1.50000");
+    /// assert!(format!("{:#.6}", dd!(0.0001234)) == "0.000123400");
+    /// assert!(format!("{:#.6}", dd!(0.00001234)) == "1.23400e-5");
+    /// assert!(format!("{:#.6}", dd!(123_456)) == "123456");
+    /// assert!(format!("{:#.6}", dd!(1_234_567)) == "1.23457e6");
+    /// assert!(format!("{:>#12.6}", dd!(-2.5)) == "    -2.50000");
+    /// assert!(format!("{:#}", Double::PI) == "3.141592653589793238462643383280");
     /// ```
     ///
     /// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
     /// [`to_string`]: #tymethod.to_string
     fn fmt(&self, f: &mut Formatter) -> Result {
-        let mut result = vec![];
-        let signed = push_sign(&mut result, self, f);
+        let sign = d::sign(self.is_sign_negative(), f);
 
         if self.is_nan() {
-            d::push_nan(&mut result);
+            d::write_nan(f)
         } else if self.is_infinite() {
-            d::push_inf(&mut result);
+            d::write_inf(f, sign)
+        } else if f.alternate() {
+            let prec = sig_digits(f);
+            if self.is_zero() {
+                d::write_zero_sig(f, sign, prec)
+            } else {
+                d::write_sig(f, sign, extract_digits(&self.abs()), prec, false)
+            }
         } else if self.is_zero() {
-            d::push_zero(&mut result, f);
+            d::write_zero(f, sign, None)
         } else {
-            push_digits_fixed(&mut result, self, f);
+            d::write_fixed(f, sign, extract_digits(&self.abs()))
         }
-        d::align_and_fill(&mut result, signed, f);
-
-        write!(f, "{}", result.into_iter().collect::<String>())
     }
 }
 
@@ -99,31 +138,17 @@ impl LowerExp for Double {
     ///
     /// See [`Display::fmt`](#method.fmt-1) for more information.
     fn fmt(&self, f: &mut Formatter) -> Result {
-        let mut result = vec![];
-        let signed = push_sign(&mut result, self, f);
+        let sign = d::sign(self.is_sign_negative(), f);
 
         if self.is_nan() {
-            d::push_nan(&mut result);
+            d::write_nan(f)
         } else if self.is_infinite() {
-            d::push_inf(&mut result);
+            d::write_inf(f, sign)
         } else if self.is_zero() {
-            d::push_zero(&mut result, f);
+            d::write_zero(f, sign, Some('e'))
         } else {
-            push_digits_exp(&mut result, self, f);
+            d::write_exp(f, sign, extract_digits(&self.abs()), 'e')
         }
-
-        if self.is_finite() {
-            let exp = if self.is_zero() {
-                0
-            } else {
-                self.0.abs().log10().floor() as i32
-            };
-            d::push_exp(&mut result, 'e', exp)
-        }
-
-        d::align_and_fill(&mut result, signed, f);
-
-        write!(f, "{}", result.into_iter().collect::<String>())
     }
 }
 
@@ -132,130 +157,285 @@ impl UpperExp for Double {
     ///
     /// See [`Display::fmt`](#method.fmt-1) for more information.
     fn fmt(&self, f: &mut Formatter) -> Result {
-        let mut result = vec![];
-        let signed = push_sign(&mut result, self, f);
+        let sign = d::sign(self.is_sign_negative(), f);
 
         if self.is_nan() {
-            d::push_nan(&mut result);
+            d::write_nan(f)
         } else if self.is_infinite() {
-            d::push_inf(&mut result);
+            d::write_inf(f, sign)
         } else if self.is_zero() {
-            d::push_zero(&mut result, f);
+            d::write_zero(f, sign, Some('E'))
         } else {
-            push_digits_exp(&mut result, self, f);
-        }
-
-        if self.is_finite() {
-            let exp = if self.is_zero() {
-                0
-            } else {
-                self.0.abs().log10().floor() as i32
-            };
-            d::push_exp(&mut result, 'E', exp)
+            d::write_exp(f, sign, extract_digits(&self.abs()), 'E')
         }
-
-        d::align_and_fill(&mut result, signed, f);
-
-        write!(f, "{}", result.into_iter().collect::<String>())
     }
 }
 
 impl Debug for Double {
     /// Formats a `Double` for display when the "`?`" formatting option is specified.
     ///
-    /// See [`Display::fmt`](#method.fmt-1) for more information.
+    /// This writes the components of the `Double`, each exactly, as `Double(a, b)`. The
+    /// alternate form, `{:#?}`, puts each component on its own line. Either can be
+    /// parsed back into the same `Double` with [`from_repr`].
+    ///
+    /// [`from_repr`]: #method.from_repr
     fn fmt(&self, f: &mut Formatter) -> Result {
-        let alt = f.alternate();
-        let mut str = String::from("Double(");
-        if alt {
-            str.push_str("\n    ");
-        }
-        str.push_str(format!("{:e},", self.0).as_str());
-        if alt {
-            str.push_str("\n    ");
+        // The alternate form puts each component on its own line
+        let (open, sep, close) = if f.alternate() {
+            ("(\n    ", ",\n    ", "\n)")
         } else {
-            str.push(' ');
-        }
-        str.push_str(format!("{:e}", self.1).as_str());
-        if alt {
-            str.push('\n');
-        }
-        str.push(')');
-        write!(f, "{}", str)
+            ("(", ", ", ")")
+        };
+        write!(f, "Double{}{:e}{}{:e}{}", open, self.0, sep, self.1, close)
     }
 }
 
-fn push_sign(chars: &mut Vec<char>, value: &Double, f: &Formatter) -> bool {
-    if value.is_sign_negative() {
-        chars.push('-');
-        true
-    } else if f.sign_plus() {
-        chars.push('+');
-        true
-    } else {
-        false
+impl Double {
+    /// Writes the `Double` rounded to `digits` significant digits, with trailing zeros
+    /// removed.
+    ///
+    /// This is the equivalent of C's `%g`. The number is written in fixed form unless its
+    /// exponent is less than -4 or is at least `digits`, in which case it's written in
+    /// exponential form. Rounding can carry the number into the next power of ten, which
+    /// is taken into account when choosing the form, so 9.97 rounded to two digits is
+    /// written as `10`.
+    ///
+    /// A `Double` can't hold more than 31 meaningful significant digits, so a `digits`
+    /// greater than 31 is capped at 31 rather than padded with digits that don't mean
+    /// anything. A `digits` of 0 is treated as 1. Zero is written as `0` (or `-0`), and
+    /// infinity and NaN are written just as they are by [`Display`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::PI.to_string_sig(5) == "3.1416");
+    /// assert!(dd!(9.97).to_string_sig(2) == "10");
+    /// assert!(dd!(-1_234_567).to_string_sig(3) == "-1.23e6");
+    /// assert!(dd!(0.000_123_456).to_string_sig(3) == "0.000123");
+    /// assert!(dd!(0.000_012_345_6).to_string_sig(3) == "1.23e-5");
+    /// assert!(dd!(1.5).to_string_sig(10) == "1.5");
+    /// ```
+    ///
+    /// [`Display`]: #impl-Display
+    pub fn to_string_sig(self, digits: usize) -> String {
+        Sig(self, digits.clamp(1, MAX_ACCURACY)).to_string()
     }
-}
 
-fn push_digits_fixed(chars: &mut Vec<char>, value: &Double, f: &mut Formatter) {
-    let value = value.abs();
-    let exp = value.0.log10().floor() as i32;
-    let prec = f.precision();
+    /// Rounds the `Double` to `digits` significant decimal digits.
+    ///
+    /// The result is the `Double` closest to the decimal number that [`to_string_sig`] writes
+    /// for the same `digits`, so the same cap of 31 digits applies. Zero, infinity, and
+    /// NaN are returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::PI.round_sig(3) == dd!("3.14"));
+    /// assert!(dd!(-9.97).round_sig(2) == dd!(-10));
+    /// assert!(dd!(123_456).round_sig(2) == dd!(120_000));
+    /// ```
+    ///
+    /// [`to_string_sig`]: #method.to_string_sig
+    pub fn round_sig(self, digits: usize) -> Double {
+        if self.is_zero() || !self.is_finite() {
+            self
+        } else {
+            self.to_string_sig(digits).parse().unwrap()
+        }
+    }
 
-    let mut digits = extract_digits(&value, exp);
-    d::adjust_zeros(&mut digits, exp);
-    d::adjust_prec(&mut digits, exp, prec);
+    /// Returns the sign, the first `ndigits` significant decimal digits, and the decimal
+    /// exponent of the `Double`, so that it can be laid out by something other than the
+    /// formatting traits.
+    ///
+    /// The sign is `true` if the `Double` is negative (including negative zero). The digits
+    /// are numbers from 0 to 9, not ASCII characters, and there are always exactly
+    /// `ndigits` of them. The exponent is the power of ten that the first digit is
+    /// multiplied by, so the number is *d₀.d₁d₂...* × 10<sup>exponent</sup>.
+    ///
+    /// The digits are correctly rounded: they're the exact value of the `Double` rounded to
+    /// nearest, with ties going to an even last digit, just as the formatting traits round
+    /// for the same number of digits. If rounding carries into the next power of ten, the
+    /// digits are a 1 followed by zeros and the exponent goes up by one. Digits past the
+    /// 31 that [`Display`] stops at (or fewer, for numbers so small that their components
+    /// are subnormal) continue the exact binary value of the `Double`.
+    ///
+    /// Zero has `ndigits` zeros for digits and an exponent of 0. Infinity and NaN have no
+    /// digits at all and an exponent of 0; [`is_nan`] tells them apart.
+    ///
+    /// # Panics
+    /// Panics if `ndigits` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::PI.to_decimal_parts(5) == (false, vec![3, 1, 4, 1, 6], 0));
+    /// assert!(dd!(-0.009_996).to_decimal_parts(3) == (true, vec![1, 0, 0], -2));
+    /// assert!(dd!(0).to_decimal_parts(2) == (false, vec![0, 0], 0));
+    /// assert!(Double::NEG_INFINITY.to_decimal_parts(2) == (true, vec![], 0));
+    /// ```
+    ///
+    /// [`Display`]: #impl-Display
+    /// [`is_nan`]: #method.is_nan
+    pub fn to_decimal_parts(self, ndigits: usize) -> (bool, Vec<u8>, i32) {
+        assert!(ndigits > 0, "ndigits must be at least 1");
+        let negative = self.is_sign_negative();
+        if !self.is_finite() {
+            (negative, vec![], 0)
+        } else if self.is_zero() {
+            (negative, vec![0; ndigits], 0)
+        } else {
+            let (digits, exp) = extract_digits(&self.abs()).into_parts(ndigits);
+            (negative, digits, exp)
+        }
+    }
 
-    chars.append(&mut d::place_decimal(digits, exp));
+    /// Writes the `Double` in fixed form with `sep` between each group of three digits of
+    /// the integer part and `decimal_sep` as the decimal point.
+    ///
+    /// The digits are exactly the ones that [`Display`] writes: with a `precision`, the
+    /// number is rounded to that many places after the decimal point, and without one, every
+    /// digit is written except for trailing zeros after the decimal point. Only the integer
+    /// part is grouped, so an integer part of fewer than four digits has no separator. A
+    /// negative sign comes before the first group. Infinity and NaN are written just as they
+    /// are by [`Display`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// assert!(dd!(1_234_567.890_123).to_string_grouped('_', '.', None) == "1_234_567.890123");
+    /// assert!(dd!(-1_234_567.891).to_string_grouped(',', '.', Some(2)) == "-1,234,567.89");
+    /// assert!(dd!(1234.5).to_string_grouped('.', ',', Some(2)) == "1.234,50");
+    /// assert!(dd!(999.5).to_string_grouped(',', '.', Some(0)) == "1,000");
+    /// assert!(dd!(123.25).to_string_grouped(',', '.', None) == "123.25");
+    /// ```
+    ///
+    /// [`Display`]: #impl-Display
+    pub fn to_string_grouped(
+        self,
+        sep: char,
+        decimal_sep: char,
+        precision: Option<usize>,
+    ) -> String {
+        let s = match precision {
+            Some(p) => format!("{:.*}", p, self),
+            None => self.to_string(),
+        };
+        d::group(&s, sep, decimal_sep)
+    }
+
+    /// Writes the `Double` in engineering notation, rounded to `sig_digits` significant
+    /// digits.
+    ///
+    /// The exponent is always a multiple of 3, chosen so that one to three digits come
+    /// before the decimal point. The digits are rounded first, as in [`to_decimal_parts`],
+    /// and the exponent is chosen from the rounded number, so a number that rounds up to a
+    /// power of 1000 is written with a single 1 before the decimal point (`1.000e3`, not
+    /// `1000e0`). Trailing zeros are kept, since they're significant, and if there are
+    /// fewer significant digits than the integer part needs, it's filled out with zeros.
+    ///
+    /// As with [`to_string_sig`], `sig_digits` is capped at 31 and a `sig_digits` of 0 is
+    /// treated as 1. Zero has as many zeros as there are significant digits and an exponent
+    /// of 0 (`0.00e0` for three digits, with a sign for negative zero), and infinity and NaN
+    /// are written just as they are by [`Display`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(12_345).to_string_eng(3) == "12.3e3");
+    /// assert!(dd!(-0.000_123).to_string_eng(4) == "-123.0e-6");
+    /// assert!(dd!(1000).to_string_eng(2) == "1.0e3");
+    /// assert!(dd!(999.96).to_string_eng(4) == "1.000e3");
+    /// assert!(dd!(123_456).to_string_eng(1) == "100e3");
+    /// assert!(Double::PI.to_string_eng(5) == "3.1416e0");
+    /// ```
+    ///
+    /// [`Display`]: #impl-Display
+    /// [`to_decimal_parts`]: #method.to_decimal_parts
+    /// [`to_string_sig`]: #method.to_string_sig
+    pub fn to_string_eng(self, sig_digits: usize) -> String {
+        if self.is_finite() {
+            let (negative, digits, exp) = self.to_decimal_parts(sig_digits.clamp(1, MAX_ACCURACY));
+            d::eng(negative, &digits, exp)
+        } else {
+            self.to_string()
+        }
+    }
 }
 
-fn push_digits_exp(chars: &mut Vec<char>, value: &Double, f: &mut Formatter) {
-    let value = value.abs();
-    let exp = value.0.log10().floor() as i32;
-    let prec = f.precision();
+// A `Double` along with a number of significant digits, which displays with trailing zeros
+// trimmed. This is what lets `to_string_sig` share the layout code with `Display`.
+struct Sig(Double, usize);
 
-    let mut digits = extract_digits(&value, exp);
-    d::adjust_zeros(&mut digits, 0);
-    d::adjust_prec(&mut digits, 0, prec);
+impl Display for Sig {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let (value, prec) = (self.0, self.1);
+        let sign = d::sign(value.is_sign_negative(), f);
+
+        if value.is_nan() {
+            d::write_nan(f)
+        } else if value.is_infinite() {
+            d::write_inf(f, sign)
+        } else if value.is_zero() {
+            d::write_zero(f, sign, None)
+        } else {
+            d::write_sig(f, sign, extract_digits(&value.abs()), prec, true)
+        }
+    }
+}
 
-    chars.append(&mut d::place_decimal(digits, 0));
+// Determines the number of significant digits for the alternate form of `Display`. This is
+// the precision if there is one, but it's never less than 1 (as in C's `%g`) and never more
+// than the number of digits that a `Double` can actually represent.
+fn sig_digits(f: &Formatter) -> usize {
+    f.precision().unwrap_or(MAX_ACCURACY).clamp(1, MAX_ACCURACY)
 }
 
-// Extracts the decimal digits of `value` into an array of unsigned integers.
+// Extracts the decimal digits of `value`, along with the decimal exponent of the first of
+// them. There's one more digit than can be written, and the last few are only close to
+// right, so the digits have to be rounded before they're used.
 //
 // This function assumes that `value` is positive. Zero and non-finite values are handled
-// before we get to this function, and the sign is already pushed to the output vector. With
-// that assumption, this function will return a vector of numbers from 0-9 - digits.
-fn extract_digits(value: &Double, exp: i32) -> Vec<u8> {
-    // Normalize the number to have an exponent of 0 (i.e., one digit before the decimal
-    // point). We don't actually otherwise need the exponent in this function, as all we're
-    // doing is parsing digits from the mantissa. This normalization makes the math involved
-    // much faster. It also ensures that really large numbers don't overflow on
-    // multiplication by ten.
-    let divisor = TEN.powi(exp);
-
-    let mut value = value / divisor;
-    let mut digits = vec![];
+// before we get to this function, and the sign is handled separately.
+fn extract_digits(value: &Double) -> d::Digits {
+    let parts = [value.0, value.1];
 
-    for _ in 0..(MAX_ACCURACY + 1) {
-        let digit = value.0.trunc();
+    // This first estimate of the exponent only looks at the first component, so it's off by
+    // one when the rest of the components carry the number across a power of ten (as in
+    // 1 - 10^-20, whose first component is exactly 1). It can also be off when the `f64`
+    // logarithm rounds to an integer. Both are fixed up after the normalization below.
+    let mut exp = value.0.log10().floor() as i32;
 
-        value -= Double(digit, 0.0);
+    // Normalize the number to have an exponent of 0 (i.e., one digit before the decimal
+    // point). This normalization makes the math involved much faster. It also ensures that
+    // really large numbers don't overflow on multiplication by ten.
+    let mut value = c::mul_pow10(*value, -exp);
+    if value < Double::ONE {
         value *= TEN;
-
-        digits.push(digit as u8);
+        exp -= 1;
+    } else if value >= TEN {
+        value /= TEN;
+        exp += 1;
     }
+    let mut digits = d::Digits::new(exp, &parts, MAX_ACCURACY);
 
-    // We will not record digits after the 323rd (308 for the largest negative exponent,
-    // plus 15 digits for the `f64` accurate width) decimal place; since the `f64`s that
-    // make up `Double`s can't do that, it's just garbage after that point.
-    //
-    // The 324 is the 323 digits plus the one needed because the first digit of the number
-    // is at position exp + 1 in a negative exponent number.
+    // The first digit is the integer part. The rest come out in chunks of as many as an
+    // `f64` can hold exactly, which takes far fewer multiplications than one digit at a
+    // time.
     //
-    // If this isn't an issue, we still truncate by one because we produced an extra digit
-    // for rounding.
-    d::round_and_trunc(&mut digits, (324 + exp).min(MAX_ACCURACY as i32) as usize);
+    // Each of these has to be the floor of the whole number, not just the first component,
+    // which may be an integer even when the whole number is a bit less than it
+    let digit = value.floor().0;
+    value -= Double(digit, 0.0);
+    digits.push(digit as u64, 1);
+
+    while digits.len() < MAX_ACCURACY + 1 {
+        let count = (MAX_ACCURACY + 1 - digits.len()).min(CHUNK);
+        value *= Double(POWERS[count], 0.0);
+        let chunk = value.floor().0;
+        value -= Double(chunk, 0.0);
+        digits.push(chunk as u64, count);
+    }
 
     digits
 }
@@ -263,6 +443,7 @@ fn extract_digits(value: &Double, exp: i32) -> Vec<u8> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::random::Rng;
 
     // debug tests
     test_all_eq!(
@@ -286,13 +467,13 @@ mod tests {
             "NaN",
             format!("{}", Double::NAN);
         neg_nan:
-            "-NaN",
+            "NaN",
             format!("{}", -Double::NAN);
         plus_nan:
-            "+NaN",
+            "NaN",
             format!("{:+}", Double::NAN);
         plus_neg_nan:
-            "-NaN",
+            "NaN",
             format!("{:+}", -Double::NAN);
         inf:
             "inf",
@@ -320,6 +501,29 @@ mod tests {
             format!("{:+}", Double::NEG_ZERO);
     );
 
+    // Whatever its sign and payload, a NaN is written as plain "NaN", which parses back
+    test!(nan_round_trip: {
+        let payload = Double::from_bits([0xfff8_0000_dead_beef, 0]);
+        for &x in &[Double::NAN, -Double::NAN, payload] {
+            let strings = [
+                format!("{}", x),
+                format!("{:+}", x),
+                format!("{:.3}", x),
+                format!("{:#}", x),
+                format!("{:+#.5}", x),
+                format!("{:e}", x),
+                format!("{:+E}", x),
+                format!("{:+010}", x),
+                format!("{:<+8e}", x),
+                x.to_string_sig(5),
+            ];
+            for s in strings.iter() {
+                assert_eq!(s.trim(), "NaN");
+                assert!(s.trim().parse::<Double>().unwrap().is_nan());
+            }
+        }
+    });
+
     // Basic fixed number tests
     test_all_eq!(
         pi:
@@ -681,13 +885,13 @@ mod tests {
             "-000000000",
             format!("{:010}", Double::NEG_ZERO);
         inf_zero_pad:
-            "0000000inf",
+            "       inf",
             format!("{:010}", Double::INFINITY);
         neg_inf_zero_pad:
-            "-000000inf",
+            "      -inf",
             format!("{:010}", Double::NEG_INFINITY);
         nan_zero_pad:
-            "0000000NaN",
+            "       NaN",
             format!("{:010}", Double::NAN);
         one_zero_pad:
             "0000000001",
@@ -706,19 +910,315 @@ mod tests {
             format!("{:010.5}", Double::PI);
     );
 
+    // power of ten boundary tests
+    test_all_eq!(
+        below_one:
+            "0.9999999999999999999999999",
+            format!("{}", dd!(1) - dd!("1e-25"));
+        below_one_exp:
+            "9.999999999999999999999999e-1",
+            format!("{:e}", dd!(1) - dd!("1e-25"));
+        below_one_prec:
+            "1.000",
+            format!("{:.3}", dd!(1) - dd!("1e-25"));
+        below_one_prec_exp:
+            "1.000e0",
+            format!("{:.3e}", dd!(1) - dd!("1e-25"));
+        above_one:
+            "1.0000000000000000000000001",
+            format!("{}", dd!(1) + dd!("1e-25"));
+        above_one_exp:
+            "1.0000000000000000000000001e0",
+            format!("{:e}", dd!(1) + dd!("1e-25"));
+        above_one_prec:
+            "1.000",
+            format!("{:.3}", dd!(1) + dd!("1e-25"));
+        below_ten:
+            "9.9999999999999999999999999",
+            format!("{}", dd!(10) - dd!("1e-25"));
+        below_ten_exp:
+            "9.9999999999999999999999999e0",
+            format!("{:e}", dd!(10) - dd!("1e-25"));
+        below_ten_prec:
+            "10.000",
+            format!("{:.3}", dd!(10) - dd!("1e-25"));
+        below_ten_prec_exp:
+            "1.000e1",
+            format!("{:.3e}", dd!(10) - dd!("1e-25"));
+        above_ten:
+            "10.0000000000000000000000001",
+            format!("{}", dd!(10) + dd!("1e-25"));
+        above_ten_exp:
+            "1.00000000000000000000000001e1",
+            format!("{:e}", dd!(10) + dd!("1e-25"));
+        below_tenth:
+            "0.0999999999999999999999999",
+            format!("{}", dd!("0.1") - dd!("1e-25"));
+        below_tenth_exp:
+            "9.99999999999999999999999e-2",
+            format!("{:e}", dd!("0.1") - dd!("1e-25"));
+        below_tenth_prec:
+            "0.100",
+            format!("{:.3}", dd!("0.1") - dd!("1e-25"));
+        below_tenth_prec_exp:
+            "1.000e-1",
+            format!("{:.3e}", dd!("0.1") - dd!("1e-25"));
+        above_tenth:
+            "0.1000000000000000000000001",
+            format!("{}", dd!("0.1") + dd!("1e-25"));
+        above_tenth_exp:
+            "1.000000000000000000000001e-1",
+            format!("{:e}", dd!("0.1") + dd!("1e-25"));
+        carry_exp:
+            "1.00e1",
+            format!("{:.2e}", dd!(9.999));
+        carry_exp_upper:
+            "1.00E1",
+            format!("{:.2E}", dd!(9.999));
+    );
+
+    // For some powers of ten, the closest `f64` (and so the head) is on the other side of
+    // the power from the value itself
+    test_all_eq!(
+        head_below_power:
+            "100000000000000000000000",
+            format!("{}", dd!("1e23"));
+        head_below_power_exp:
+            "1e23",
+            format!("{:e}", dd!("1e23"));
+        head_below_power_upper:
+            "1E23",
+            format!("{:E}", dd!("1e23"));
+        head_below_power_neg_exp:
+            "-1e23",
+            format!("{:e}", -dd!("1e23"));
+        head_below_power_minus:
+            "99999999999999999999999",
+            format!("{}", dd!("1e23") - dd!(1));
+        head_below_power_minus_exp:
+            "9.9999999999999999999999e22",
+            format!("{:e}", dd!("1e23") - dd!(1));
+        head_below_power_plus:
+            "100000000000000000000001",
+            format!("{}", dd!("1e23") + dd!(1));
+        head_below_power_plus_exp:
+            "1.00000000000000000000001e23",
+            format!("{:e}", dd!("1e23") + dd!(1));
+        head_above_power:
+            "10000000000000000000000000",
+            format!("{}", dd!("1e25"));
+        head_above_power_exp:
+            "1e25",
+            format!("{:e}", dd!("1e25"));
+        head_above_power_minus_exp:
+            "9.999999999999999999999999e24",
+            format!("{:e}", dd!("1e25") - dd!(1));
+        head_below_small_power:
+            "0.0000001",
+            format!("{}", dd!("1e-7"));
+        head_below_small_power_exp:
+            "1e-7",
+            format!("{:e}", dd!("1e-7"));
+        head_below_small_power_minus_exp:
+            "9.99999999999999999999999e-8",
+            format!("{:e}", dd!("1e-7") - dd!("1e-31"));
+    );
+
+    test!(nines_every_prec: {
+        let x = dd!("0.99999999999999999999");
+        for p in 0..=60 {
+            let fixed = if p == 0 {
+                String::from("1")
+            } else if p < 20 {
+                format!("1.{}", "0".repeat(p))
+            } else {
+                format!("0.{}{}", "9".repeat(20), "0".repeat(p - 20))
+            };
+            assert_eq!(format!("{:.*}", p, x), fixed);
+
+            let exp = if p == 0 {
+                String::from("1e0")
+            } else if p < 19 {
+                format!("1.{}e0", "0".repeat(p))
+            } else {
+                format!("9.{}{}e-1", "9".repeat(19), "0".repeat(p - 19))
+            };
+            assert_eq!(format!("{:.*e}", p, x), exp);
+        }
+    });
+
+    // width tests where rounding carries into a new digit, which has to happen before the
+    // padding is worked out
+    test_all_eq!(
+        carry_width:
+            "    1000.000",
+            format!("{:12.3}", dd!(999.9996));
+        carry_width_below_one:
+            "       1.000",
+            format!("{:12.3}", dd!(0.99995));
+        carry_width_zero_prec:
+            "          10",
+            format!("{:12.0}", dd!(9.5));
+        carry_width_zero_prec_narrow:
+            "   10",
+            format!("{:5.0}", dd!(9.5));
+        carry_width_too_narrow:
+            "10",
+            format!("{:1.0}", dd!(9.5));
+        carry_width_too_narrow_frac:
+            "1000.000",
+            format!("{:3.3}", dd!(999.9996));
+        carry_width_million:
+            " 1000000.000",
+            format!("{:12.3}", dd!(999999.9996));
+        carry_width_left:
+            "1000.000    ",
+            format!("{:<12.3}", dd!(999.9996));
+        carry_width_center:
+            "  1000.000  ",
+            format!("{:^12.3}", dd!(999.9996));
+        carry_width_fill:
+            "****1000.000",
+            format!("{:*>12.3}", dd!(999.9996));
+        carry_width_zero_pad:
+            "-0001000.000",
+            format!("{:+012.3}", dd!(-999.9996));
+        carry_width_exp:
+            "      1.00e1",
+            format!("{:12.2e}", dd!(9.996));
+        carry_width_exp_zero_pad:
+            "-00001.0e2",
+            format!("{:010.1e}", dd!(-99.96));
+        no_carry_width:
+            "   0.000",
+            format!("{:8.3}", dd!(0.0004999));
+        no_carry_width_int:
+            "  123456.000",
+            format!("{:12.3}", dd!(123456));
+        round_width_int:
+            "      123457",
+            format!("{:12.0}", dd!(123456.7));
+    );
+
+    // Rounding that carries out of the leading nines of a number less than 1 moves its
+    // first digit one place to the left, past any zeros after the decimal point and, for
+    // numbers just under 1, out in front of it
+    test_all_eq!(
+        carry_lead_nines:
+            "1.000",
+            format!("{:.3}", dd!("0.9996"));
+        carry_lead_nines_zero_prec:
+            "1",
+            format!("{:.0}", dd!("0.9996"));
+        carry_lead_nines_exact:
+            "0.9996",
+            format!("{:.4}", dd!("0.9996"));
+        carry_tenths:
+            "0.100",
+            format!("{:.3}", dd!("0.09996"));
+        carry_tenths_one_place:
+            "0.1",
+            format!("{:.1}", dd!("0.09996"));
+        carry_tenths_zero_prec:
+            "0",
+            format!("{:.0}", dd!("0.09996"));
+        carry_tenths_more_places:
+            "0.1000",
+            format!("{:.4}", dd!("0.09996"));
+        carry_tenths_exact:
+            "0.09996",
+            format!("{:.5}", dd!("0.09996"));
+        carry_tenths_neg:
+            "-0.100",
+            format!("{:.3}", dd!("-0.09996"));
+        carry_hundredths:
+            "0.010",
+            format!("{:.3}", dd!("0.009995"));
+        carry_hundredths_two_places:
+            "0.01",
+            format!("{:.2}", dd!("0.009995"));
+        carry_hundredths_one_place:
+            "0.0",
+            format!("{:.1}", dd!("0.009995"));
+        carry_hundredths_width:
+            "   0.0100",
+            format!("{:9.4}", dd!("0.0099996"));
+        carry_hundredths_zero_pad:
+            "-0000.010",
+            format!("{:09.3}", dd!("-0.009996"));
+        carry_lead_nines_exp:
+            "1.00e0",
+            format!("{:.2e}", dd!("0.9996"));
+        carry_tenths_exp:
+            "1.00e-1",
+            format!("{:.2e}", dd!("0.09996"));
+        carry_tenths_exp_zero_prec:
+            "1e-1",
+            format!("{:.0e}", dd!("0.09996"));
+        carry_tenths_exp_exact:
+            "9.996e-2",
+            format!("{:.3e}", dd!("0.09996"));
+        carry_hundredths_exp:
+            "1.00E-2",
+            format!("{:.2E}", dd!("0.009996"));
+        carry_hundredths_alt:
+            "0.0100",
+            format!("{:#.3}", dd!("0.009996"));
+        carry_tenths_alt:
+            "0.100",
+            format!("{:#.3}", dd!("0.09996"));
+    );
+    // Every precision of 0.0…09…96, with z zeros and n nines, in both fixed and exponential
+    // form
+    test!(carry_lead_nines_every_prec: {
+        for z in 0..6 {
+            for n in 1..8 {
+                let x: Double = format!("0.{}{}6", "0".repeat(z), "9".repeat(n)).parse().unwrap();
+                for p in 0..(z + n + 4) {
+                    let fixed = if p < z {
+                        // Rounded away entirely
+                        if p == 0 { String::from("0") } else { format!("0.{}", "0".repeat(p)) }
+                    } else if p <= z + n {
+                        // Carried all the way into a 1 at the place of the last leading zero
+                        if z == 0 && p == 0 {
+                            String::from("1")
+                        } else if z == 0 {
+                            format!("1.{}", "0".repeat(p))
+                        } else {
+                            format!("0.{}1{}", "0".repeat(z - 1), "0".repeat(p - z))
+                        }
+                    } else {
+                        format!("0.{}{}6{}", "0".repeat(z), "9".repeat(n), "0".repeat(p - z - n - 1))
+                    };
+                    assert_eq!(format!("{:.*}", p, x), fixed, "{:.*}", p, x);
+                    assert_eq!(format!("{:.*}", p, -x), format!("-{}", fixed), "{:.*}", p, -x);
+
+                    let exp = if p == 0 {
+                        format!("1e-{}", z)
+                    } else if p < n {
+                        format!("1.{}e-{}", "0".repeat(p), z)
+                    } else {
+                        format!("9.{}6{}e-{}", "9".repeat(n - 1), "0".repeat(p - n), z + 1)
+                    };
+                    assert_eq!(format!("{:.*e}", p, x), exp.replace("e-0", "e0"), "{:.*e}", p, x);
+                }
+            }
+        }
+    });
+
     // special number exp tests
     test_all_eq!(
         nan_lexp:
             "NaN",
             format!("{:e}", Double::NAN);
         neg_nan_lexp:
-            "-NaN",
+            "NaN",
             format!("{:e}", -Double::NAN);
         plus_nan_lexp:
-            "+NaN",
+            "NaN",
             format!("{:+e}", Double::NAN);
         plus_neg_nan_lexp:
-            "-NaN",
+            "NaN",
             format!("{:+e}", -Double::NAN);
         inf_lexp:
             "inf",
@@ -752,13 +1252,13 @@ mod tests {
             "NaN",
             format!("{:E}", Double::NAN);
         neg_nan_uexp:
-            "-NaN",
+            "NaN",
             format!("{:E}", -Double::NAN);
         plus_nan_uexp:
-            "+NaN",
+            "NaN",
             format!("{:+E}", Double::NAN);
         plus_neg_nan_uexp:
-            "-NaN",
+            "NaN",
             format!("{:+E}", -Double::NAN);
         inf_uexp:
             "inf",
@@ -1206,19 +1706,19 @@ mod tests {
     // sign-aware zero-poading tests
     test_all_eq!(
         zero_zero_pad_exp:
-            "00000000e0",
+            "       0e0",
             format!("{:010e}", Double::ZERO);
         neg_zero_zero_pad_exp:
-            "-0000000e0",
+            "      -0e0",
             format!("{:010e}", Double::NEG_ZERO);
         inf_zero_pad_exp:
-            "0000000inf",
+            "       inf",
             format!("{:010e}", Double::INFINITY);
         neg_inf_zero_pad_exp:
-            "-000000inf",
+            "      -inf",
             format!("{:010e}", Double::NEG_INFINITY);
         nan_zero_pad_exp:
-            "0000000NaN",
+            "       NaN",
             format!("{:010e}", Double::NAN);
         one_zero_pad_exp:
             "00000001e0",
@@ -1235,5 +1735,583 @@ mod tests {
         pi_width_zero_pad_prec_exp:
             "03.14159e0",
             format!("{:010.5e}", Double::PI);
+        zero_zero_pad_prec_exp:
+            "  0.000e0",
+            format!("{:09.3e}", Double::ZERO);
+        neg_zero_pad_exp:
+            "-002.5e0",
+            format!("{:08e}", dd!(-2.5));
+        neg_zero_pad_upper_exp:
+            "-001.234E-5",
+            format!("{:011E}", dd!(-0.00001234));
+        neg_zero_zero_pad_upper_exp:
+            "     -0E0",
+            format!("{:09E}", Double::NEG_ZERO);
+        plus_inf_zero_pad_exp:
+            "      +inf",
+            format!("{:+010e}", Double::INFINITY);
+    );
+
+    // alternate (significant digit) tests
+    test_all_eq!(
+        alt_pi:
+            "3.141592653589793238462643383280",
+            format!("{:#}", Double::PI);
+        alt_default:
+            "1.500000000000000000000000000000",
+            format!("{:#}", dd!(1.5));
+        alt_pi_prec:
+            "3.142",
+            format!("{:#.4}", Double::PI);
+        alt_one:
+            "1.000000",
+            format!("{:#.7}", Double::ONE);
+        alt_neg:
+            "-1.500",
+            format!("{:#.4}", dd!(-1.5));
+        alt_prec_zero:
+            "3",
+            format!("{:#.0}", Double::PI);
+        alt_integer:
+            "1234",
+            format!("{:#.4}", dd!(1234));
+        alt_too_big:
+            "1.234e4",
+            format!("{:#.4}", dd!(12344));
+        alt_small:
+            "0.0001000",
+            format!("{:#.4}", dd!(0.0001));
+        alt_too_small:
+            "1.000e-5",
+            format!("{:#.4}", dd!(0.00001));
+        alt_large:
+            "1.797693e308",
+            format!("{:#.7}", dd!(1.797693e308));
+    );
+
+    // alternate rounding tests
+    test_all_eq!(
+        alt_round_down:
+            "0.1",
+            format!("{:#.1}", dd!(0.14));
+        alt_round_up:
+            "0.2",
+            format!("{:#.1}", dd!(0.16));
+        alt_round_tie:
+            "1.234e4",
+            format!("{:#.4}", dd!(12345));
+        alt_round_tie_up:
+            "1.236e4",
+            format!("{:#.4}", dd!(12355));
+        alt_round_carry:
+            "10.00",
+            format!("{:#.4}", dd!(9.9996));
+        alt_round_carry_exp:
+            "1.000e4",
+            format!("{:#.4}", dd!(9999.6));
+        alt_round_carry_fixed:
+            "0.0001000",
+            format!("{:#.4}", dd!(0.000099996));
+        alt_round_carry_prec_zero:
+            "1e1",
+            format!("{:#.0}", dd!(9.9));
+    );
+
+    // alternate zero and special number tests
+    test_all_eq!(
+        alt_zero:
+            "0.000000000000000000000000000000",
+            format!("{:#}", Double::ZERO);
+        alt_zero_prec:
+            "0.00",
+            format!("{:#.3}", Double::ZERO);
+        alt_zero_prec_one:
+            "0",
+            format!("{:#.1}", Double::ZERO);
+        alt_neg_zero:
+            "-0.00",
+            format!("{:#.3}", Double::NEG_ZERO);
+        alt_nan:
+            "NaN",
+            format!("{:#.3}", Double::NAN);
+        alt_inf:
+            "inf",
+            format!("{:#}", Double::INFINITY);
+        alt_neg_inf:
+            "-inf",
+            format!("{:#.3}", Double::NEG_INFINITY);
+        alt_inf_width:
+            "     inf",
+            format!("{:>#8.3}", Double::INFINITY);
+        alt_zero_pad:
+            "+0000001.500",
+            format!("{:+#012.4}", dd!(1.5));
+    );
+
+    test!(alt_width_sweep: {
+        for e in -300..300 {
+            for &x in &[Double::PI, -Double::E, Double::ONE] {
+                let value = x * dd!(10).powi(e);
+                let s = format!("{:>#20.10}", value);
+                assert_eq!(s.chars().count(), 20, "{}", s);
+                let significand = s.trim_start().split('e').next().unwrap();
+                let digits = significand.trim_start_matches(&['-', '0', '.'][..]);
+                assert_eq!(digits.chars().filter(char::is_ascii_digit).count(), 10, "{}", s);
+            }
+        }
+    });
+
+    // Runs of zeros are written without being buffered first, so check some that are
+    // longer than any buffer would be
+    test!(long_zero_runs: {
+        let s = format!("{:.200}", dd!(1.5));
+        assert_eq!(s.len(), 202);
+        assert!(s.starts_with("1.5") && s[3..].bytes().all(|b| b == b'0'));
+
+        let s = format!("{:.3}", dd!(1e300));
+        assert_eq!(s.len(), 305);
+        assert!(s.starts_with('1') && s.ends_with(".000"));
+
+        let s = format!("{:.305}", dd!(1e-300));
+        assert_eq!(s.len(), 307);
+        assert!(s.starts_with("0.") && s.ends_with("100000"));
+        assert!(s[2..301].bytes().all(|b| b == b'0'));
+    });
+
+    test_all_eq!(
+        round_into_leading_zeros:
+            "0.010",
+            format!("{:.3}", dd!(0.0096));
+        round_to_nothing:
+            "0.00",
+            format!("{:.2}", dd!(0.0004));
+        round_to_one_place:
+            "0.01",
+            format!("{:.2}", dd!(0.005));
+        round_to_integer:
+            "1",
+            format!("{:.0}", dd!(0.6));
+        multibyte_fill:
+            "ππ1.5ππ",
+            format!("{:π^7}", dd!(1.5));
+        zero_pad_long_exp:
+            "-001e-300",
+            format!("{:09e}", -dd!(1e-300));
     );
+
+    // subnormal range tests
+    //
+    // Only the digits above the smallest subnormal mean anything, so there are fewer of
+    // them the smaller the number is (or the smaller its last nonzero component is)
+    test_all_eq!(
+        subnormal_exp:
+            "1e-310",
+            format!("{:e}", dd!("1e-310"));
+        subnormal_fixed:
+            format!("0.{}1", "0".repeat(309)),
+            format!("{}", dd!("1e-310"));
+        subnormal_prec:
+            format!("0.{}100000", "0".repeat(309)),
+            format!("{:.315}", dd!("1e-310"));
+        subnormal_prec_exp:
+            "1.000e-310",
+            format!("{:.3e}", dd!("1e-310"));
+        min_subnormal_exp:
+            "5e-324",
+            format!("{:e}", Double::from_array([5e-324, 0.0]));
+        min_subnormal_fixed:
+            format!("0.{}5", "0".repeat(323)),
+            format!("{}", Double::from_array([5e-324, 0.0]));
+        min_subnormal_prec:
+            format!("0.{}500", "0".repeat(323)),
+            format!("{:.326}", Double::from_array([5e-324, 0.0]));
+        min_subnormal_prec_exp:
+            "-5.0e-324",
+            format!("{:.1e}", -Double::from_array([5e-324, 0.0]));
+        subnormal_tail_exp:
+            "1.00000000000000002506909e-300",
+            format!("{:e}", Double::from_array([1e-300, 1e-320]));
+        subnormal_tail_fixed:
+            format!("0.{}100000000000000002506909", "0".repeat(299)),
+            format!("{}", Double::from_array([1e-300, 1e-320]));
+        subnormal_tail_prec:
+            format!("0.{}1000000000000000025069090000000", "0".repeat(299)),
+            format!("{:.330}", Double::from_array([1e-300, 1e-320]));
+        subnormal_tail_prec_exp:
+            "1.000e-300",
+            format!("{:.3e}", Double::from_array([1e-300, 1e-320]));
+        small_tail_exp:
+            "1.0000000000000001600189724914e-295",
+            format!("{:e}", Double::from_array([1e-295, 1e-311]));
+        small_parsed_exp:
+            "1.2e-300",
+            format!("{:e}", dd!("1.2e-300"));
+    );
+
+    // How many digits a number keeps depends on how far its first digit is from the last
+    // place that a subnormal can reach, not on its type's precision alone, so a large
+    // exponent keeps every digit and a small one keeps only those above 10^-323
+    test!(digits_by_exp: {
+        let s = format!("{:e}", Double::ONE / dd!(3) * dd!("1e300"));
+        assert_eq!(s, format!("3.{}e299", "3".repeat(30)));
+
+        let s = format!("{:e}", Double::ONE / dd!(3) * dd!("1e-295"));
+        assert_eq!(s, format!("3.{}e-296", "3".repeat(27)));
+    });
+
+    // significant digit string tests
+    test_all_eq!(
+        sig_string_fixed:
+            "3.1416",
+            Double::PI.to_string_sig(5);
+        sig_string_carry:
+            "10",
+            dd!(9.97).to_string_sig(2);
+        sig_string_carry_exp:
+            "1e3",
+            dd!(999.7).to_string_sig(3);
+        sig_string_carry_small:
+            "0.0001",
+            dd!(0.000_099_99).to_string_sig(3);
+        sig_string_small_exp:
+            "1.23e-5",
+            dd!(0.000_012_345_6).to_string_sig(3);
+        sig_string_small_fixed:
+            "0.000123",
+            dd!(0.000_123_456).to_string_sig(3);
+        sig_string_large_exp:
+            "-1.2e5",
+            dd!(-123_456).to_string_sig(2);
+        sig_string_integer:
+            "123456",
+            dd!(123_456).to_string_sig(6);
+        sig_string_trim:
+            "1.5",
+            dd!(1.5).to_string_sig(10);
+        sig_string_zero_digits:
+            "2",
+            dd!(1.5).to_string_sig(0);
+        sig_string_zero:
+            "0",
+            Double::ZERO.to_string_sig(5);
+        sig_string_neg_zero:
+            "-0",
+            Double::NEG_ZERO.to_string_sig(5);
+        sig_string_inf:
+            "-inf",
+            Double::NEG_INFINITY.to_string_sig(5);
+        sig_string_nan:
+            "NaN",
+            Double::NAN.to_string_sig(5);
+        sig_string_cap:
+            format!("{:#}", Double::E),
+            Double::E.to_string_sig(81);
+    );
+
+    test_all_exact!(
+        round_sig_pi:
+            dd!("3.14"),
+            Double::PI.round_sig(3);
+        round_sig_carry:
+            dd!(-10),
+            dd!(-9.97).round_sig(2);
+        round_sig_integer:
+            dd!(120_000),
+            dd!(123_456).round_sig(2);
+        round_sig_zero:
+            Double::ZERO,
+            Double::ZERO.round_sig(3);
+        round_sig_inf:
+            Double::INFINITY,
+            Double::INFINITY.round_sig(3);
+        round_sig_nan:
+            Double::NAN,
+            Double::NAN.round_sig(3);
+    );
+
+    // to_decimal_parts tests
+    test_all_eq!(
+        parts_pi:
+            (false, vec![3, 1, 4, 1, 6], 0),
+            Double::PI.to_decimal_parts(5);
+        parts_negative:
+            (true, vec![1, 2, 3], 2),
+            dd!(-123.4).to_decimal_parts(3);
+        parts_small:
+            (false, vec![1, 2, 5], -3),
+            dd!(0.00125).to_decimal_parts(3);
+        parts_padded:
+            (false, vec![1, 5, 0, 0], 0),
+            dd!(1.5).to_decimal_parts(4);
+        parts_tie_even:
+            (false, vec![1, 2], 0),
+            dd!(1.25).to_decimal_parts(2);
+        parts_tie_odd:
+            (false, vec![1, 3, 8], 0),
+            dd!(1.375).to_decimal_parts(3);
+        parts_carry:
+            (false, vec![1, 0, 0], 1),
+            dd!(9.996).to_decimal_parts(3);
+        parts_carry_all:
+            (false, [vec![1], vec![0; 28]].concat(), 0),
+            Double(1.0, -2f64.powi(-100)).to_decimal_parts(29);
+        parts_no_carry:
+            (false, [vec![9; 30], vec![2]].concat(), -1),
+            Double(1.0, -2f64.powi(-100)).to_decimal_parts(31);
+        parts_past_accuracy:
+            (false, b"1000000000000000055511151231257827021182".iter().map(|b| b - b'0').collect(), -1),
+            Double(0.1, 0.0).to_decimal_parts(40);
+        parts_zero:
+            (false, vec![0, 0, 0], 0),
+            Double::ZERO.to_decimal_parts(3);
+        parts_neg_zero:
+            (true, vec![0], 0),
+            Double::NEG_ZERO.to_decimal_parts(1);
+        parts_inf:
+            (false, vec![], 0),
+            Double::INFINITY.to_decimal_parts(3);
+        parts_neg_inf:
+            (true, vec![], 0),
+            Double::NEG_INFINITY.to_decimal_parts(3);
+        parts_nan:
+            (false, vec![], 0),
+            Double::NAN.to_decimal_parts(3);
+    );
+
+    // to_string_grouped tests
+    test_all_eq!(
+        grouped_underscore:
+            "1_234_567.890123",
+            dd!(1_234_567.890_123).to_string_grouped('_', '.', None);
+        grouped_comma:
+            "1,234,567.89",
+            dd!(1_234_567.891).to_string_grouped(',', '.', Some(2));
+        grouped_negative:
+            "-12,345,678",
+            dd!(-12_345_678).to_string_grouped(',', '.', None);
+        grouped_european:
+            "1.234,50",
+            dd!(1234.5).to_string_grouped('.', ',', Some(2));
+        grouped_wide_sep:
+            "1\u{202f}000\u{202f}000",
+            dd!(1_000_000).to_string_grouped('\u{202f}', '.', None);
+        grouped_one_digit:
+            "7.25",
+            dd!(7.25).to_string_grouped(',', '.', None);
+        grouped_three_digits:
+            "-999",
+            dd!(-999).to_string_grouped(',', '.', None);
+        grouped_four_digits:
+            "1,000",
+            dd!(1000).to_string_grouped(',', '.', None);
+        grouped_carry:
+            "1,000",
+            dd!(999.5).to_string_grouped(',', '.', Some(0));
+        grouped_carry_fraction:
+            "10,000.0",
+            dd!(9999.96).to_string_grouped(',', '.', Some(1));
+        grouped_small:
+            "0.000123",
+            dd!(0.000_123).to_string_grouped(',', '.', None);
+        grouped_rounded_away:
+            "-0.00",
+            dd!(-0.000_123).to_string_grouped(',', '.', Some(2));
+        grouped_large:
+            "1,000,000,000,000,000,000,000,000,000",
+            dd!("1e27").to_string_grouped(',', '.', None);
+        grouped_zero:
+            "0",
+            Double::ZERO.to_string_grouped(',', '.', None);
+        grouped_zero_prec:
+            "0,000",
+            Double::ZERO.to_string_grouped('.', ',', Some(3));
+        grouped_neg_zero:
+            "-0",
+            Double::NEG_ZERO.to_string_grouped(',', '.', None);
+        grouped_inf:
+            "inf",
+            Double::INFINITY.to_string_grouped(',', '.', Some(2));
+        grouped_neg_inf:
+            "-inf",
+            Double::NEG_INFINITY.to_string_grouped(',', '.', None);
+        grouped_nan:
+            "NaN",
+            Double::NAN.to_string_grouped(',', '.', None);
+    );
+
+    // to_string_eng tests
+    test_all_eq!(
+        eng_units:
+            "3.1416e0",
+            Double::PI.to_string_eng(5);
+        eng_tens:
+            "31.42e0",
+            (Double::PI * dd!(10)).to_string_eng(4);
+        eng_hundreds:
+            "314.2e0",
+            (Double::PI * dd!(100)).to_string_eng(4);
+        eng_thousands:
+            "3.142e3",
+            (Double::PI * dd!(1000)).to_string_eng(4);
+        eng_large:
+            "123.5e27",
+            dd!("1.23456e29").to_string_eng(4);
+        eng_tie_even:
+            "123.4e27",
+            dd!("1.2345e29").to_string_eng(4);
+        eng_small:
+            "-123.0e-6",
+            dd!(-0.000_123).to_string_eng(4);
+        eng_smaller:
+            "12.3e-9",
+            dd!(1.23e-8).to_string_eng(3);
+        eng_tiny:
+            "4.94e-324",
+            Double(5e-324, 0.0).to_string_eng(3);
+        eng_pad:
+            "100e3",
+            dd!(123_456).to_string_eng(1);
+        eng_pad_two:
+            "-20e-3",
+            dd!(-0.0249).to_string_eng(1);
+        eng_1000:
+            "1.0e3",
+            dd!(1000).to_string_eng(2);
+        eng_1000_one_digit:
+            "1e3",
+            dd!(1000).to_string_eng(1);
+        eng_million:
+            "1.00e6",
+            dd!(1_000_000).to_string_eng(3);
+        eng_thousandth:
+            "1.00e-3",
+            dd!(0.001).to_string_eng(3);
+        eng_millionth:
+            "1e-6",
+            dd!(0.000_001).to_string_eng(1);
+        eng_carry:
+            "1.000e3",
+            dd!(999.96).to_string_eng(4);
+        eng_carry_small:
+            "1.0e-3",
+            dd!(0.000_999_7).to_string_eng(2);
+        eng_no_carry:
+            "999.9e0",
+            dd!(999.94).to_string_eng(4);
+        eng_zero_digits:
+            "2e0",
+            dd!(1.5).to_string_eng(0);
+        eng_zero:
+            "0.00e0",
+            Double::ZERO.to_string_eng(3);
+        eng_zero_one_digit:
+            "0e0",
+            Double::ZERO.to_string_eng(1);
+        eng_neg_zero:
+            "-0.0e0",
+            Double::NEG_ZERO.to_string_eng(2);
+        eng_inf:
+            "inf",
+            Double::INFINITY.to_string_eng(3);
+        eng_neg_inf:
+            "-inf",
+            Double::NEG_INFINITY.to_string_eng(3);
+        eng_nan:
+            "NaN",
+            Double::NAN.to_string_eng(3);
+        eng_cap:
+            Double::E.to_string_eng(31),
+            Double::E.to_string_eng(31 + 50);
+    );
+
+    // Splits exponential output like `-1.25e-3` into the same parts that
+    // `to_decimal_parts` returns.
+    fn exp_parts(s: &str) -> (bool, Vec<u8>, i32) {
+        let (mantissa, exp) = s.split_once('e').unwrap();
+        let digits = mantissa
+            .bytes()
+            .filter(u8::is_ascii_digit)
+            .map(|b| b - b'0')
+            .collect();
+        (s.starts_with('-'), digits, exp.parse().unwrap())
+    }
+
+    // Random numbers with random exponents, most of them using both components
+    fn random_doubles(count: usize) -> Vec<Double> {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        (0..count)
+            .map(|_| {
+                let m = rng.next_f64() + 0.5;
+                let hi = m * 2f64.powi(rng.range(-1000, 1000));
+                let lo = hi * (rng.next_f64() - 0.5) * 2f64.powi(-53);
+                let x = Double(hi, 0.0) + Double(lo, 0.0);
+                if rng.next_u64() % 2 == 0 {
+                    -x
+                } else {
+                    x
+                }
+            })
+            .collect()
+    }
+
+    // The digits are the exact value of the number rounded once, for any number of them
+    test!(parts_exact: {
+        for (i, x) in random_doubles(3_000).into_iter().enumerate() {
+            let n = i % 40 + 1;
+            let exact = x.to_string_radix_any(10, n);
+            let mut digits: Vec<u8> = exact.digits().bytes().map(|b| b - b'0').collect();
+            digits.resize(n, 0);
+            assert_eq!(
+                x.to_decimal_parts(n),
+                (x.is_sign_negative(), digits, exact.exponent()),
+                "{:?} to {} digits",
+                x,
+                n
+            );
+        }
+    });
+
+    // Formatting rounds the same way, at every precision it supports. That leaves out
+    // numbers small enough that formatting stops short of the full precision.
+    test!(parts_match_format: {
+        let values = random_doubles(3_000).into_iter().filter(|x| x.0.abs() > 1e-290);
+        for (i, x) in values.enumerate() {
+            let n = i % MAX_ACCURACY + 1;
+            assert_eq!(
+                x.to_decimal_parts(n),
+                exp_parts(&format!("{:.*e}", n - 1, x)),
+                "{:?} to {} digits",
+                x,
+                n
+            );
+        }
+    });
+
+    // Numbers that are also `f64`s come out just as `f64` formatting has them
+    test!(parts_match_f64: {
+        for (i, x) in random_doubles(3_000).into_iter().enumerate() {
+            let n = i % MAX_ACCURACY + 1;
+            assert_eq!(
+                Double(x.0, 0.0).to_decimal_parts(n),
+                exp_parts(&format!("{:.*e}", n - 1, x.0)),
+                "{:e} to {} digits",
+                x.0,
+                n
+            );
+        }
+    });
+
+    // Rounding that carries through every digit, from numbers with more nines than are
+    // kept
+    test!(parts_nines: {
+        for nines in 2..=MAX_ACCURACY {
+            let x: Double = "9".repeat(nines).parse().unwrap();
+            for n in 1..nines {
+                let (_, digits, exp) = x.to_decimal_parts(n);
+                assert_eq!(digits[0], 1, "{} nines to {} digits", nines, n);
+                assert!(digits[1..].iter().all(|&d| d == 0));
+                assert_eq!(exp, nines as i32, "{} nines to {} digits", nines, n);
+            }
+        }
+    });
 }
@@ -4,7 +4,9 @@
 // https://opensource.org/licenses/MIT
 
 use crate::common::primitive as p;
+use crate::common::strict as s;
 use crate::common::utils as u;
+use crate::double::common as c;
 use crate::double::Double;
 use std::ops::{Div, DivAssign};
 
@@ -12,7 +14,7 @@ use std::ops::{Div, DivAssign};
 // be called on a non-tuple, non-integer number. With the current parsing of floats, calling
 // Double::from this way in the basic arithmetic would cause a stack overflow.
 #[inline]
-fn mul_f64(a: Double, b: f64) -> Double {
+const fn mul_f64(a: Double, b: f64) -> Double {
     let (p, e) = p::two_prod(a.0, b);
     let (a, b) = u::renorm2(p, e + a.1 * b);
     Double(a, b)
@@ -36,21 +38,7 @@ impl Div for Double {
     /// assert!(diff < dd!(1e-30));
     /// ```
     fn div(self, other: Double) -> Double {
-        match self.pre_div(&other) {
-            Some(r) => r,
-            None => {
-                let q1 = self.0 / other.0;
-                let mut r = self - mul_f64(other, q1);
-
-                let q2 = r.0 / other.0;
-                r -= mul_f64(other, q2);
-
-                let q3 = r.0 / other.0;
-
-                let (a, b) = u::renorm3(q1, q2, q3);
-                Double(a, b)
-            }
-        }
+        s::guard("Double / Double", &[self.0, other.0], self.div_inner(other))
     }
 }
 
@@ -169,6 +157,49 @@ impl DivAssign<&Double> for Double {
 }
 
 impl Double {
+    /// Divides this `Double` by another in a `const fn`. Like [`add_const`], this gives
+    /// exactly what the `/` operator does, but it can also be used at compile time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// const PI_OVER_3: Double = Double::PI.div_const(Double::from_f64_const(3.0));
+    /// assert!(PI_OVER_3 == Double::FRAC_PI_3);
+    /// ```
+    ///
+    /// [`add_const`]: #method.add_const
+    pub const fn div_const(self, other: Double) -> Double {
+        self.div_inner(other)
+    }
+
+    // `/` without the strict-mode check, for `pow_int`.
+    pub(super) const fn div_inner(self, other: Double) -> Double {
+        match self.pre_div(&other) {
+            Some(r) => r,
+            None => {
+                let q1 = self.0 / other.0;
+                let mut r = self.sub_inner(mul_f64(other, q1));
+
+                let q2 = r.0 / other.0;
+                r = r.sub_inner(mul_f64(other, q2));
+
+                let q3 = r.0 / other.0;
+
+                let (a, b) = u::renorm3(q1, q2, q3);
+                if a.is_finite() {
+                    Double(a, b)
+                } else {
+                    // This includes quotients within a factor of 2 of MAX, where multiplying
+                    // q1 back by `other` can round past f64::MAX.
+                    c::redo_halved!(
+                        0.5 * self.0 / other.0,
+                        c::mul_pwr2(self, 0.5).div_inner(other)
+                    )
+                }
+            }
+        }
+    }
+
     // precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -179,7 +210,7 @@ impl Double {
     // rather than calculation.
 
     #[inline]
-    fn pre_div(&self, other: &Double) -> Option<Double> {
+    const fn pre_div(&self, other: &Double) -> Option<Double> {
         if self.is_nan() || other.is_nan() {
             Some(Double::NAN)
         } else if other.is_zero() {
@@ -198,7 +229,7 @@ impl Double {
             } else {
                 Some(Double::NEG_INFINITY)
             }
-        } else if other.is_infinite() {
+        } else if other.is_infinite() || self.is_zero() {
             if self.is_sign_positive() == other.is_sign_positive() {
                 Some(Double::ZERO)
             } else {
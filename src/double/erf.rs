@@ -0,0 +1,387 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::strict as s;
+use crate::double::common as c;
+use crate::double::Double;
+
+// The argument below which erf is calculated with its series. Above it, erf is calculated
+// as 1 - erfc, and since erfc is then less than 2.3 × 10^-5, nothing is lost to
+// cancellation. This is also where erfc switches from the trapezoid rule to the continued
+// fraction, which is the faster of the two from here up.
+const SERIES_MAX: f64 = 3.0;
+
+// The argument at and above which erfc is calculated with the trapezoid rule. Below it,
+// erfc is calculated as 1 - erf, and since erfc is then more than 0.47, less than half a
+// digit is lost to cancellation.
+const TRAPEZOID_MIN: f64 = 0.5;
+
+// The step of the trapezoid rule. The rule's relative error is about e^(-π²/h²), which for
+// h = 1/3 is about 10^-38.
+const STEP: f64 = 1.0 / 3.0;
+
+// The argument above which erfc underflows to zero and erf is exactly 1.
+const ERFC_MAX: f64 = 27.3;
+
+impl Double {
+    /// Computes the error function, erf(*x*), where *x* is this `Double`.
+    ///
+    /// For |*x*| < 3, this is calculated with the series
+    ///
+    /// erf(*x*) = (2 / √π) *x e*<sup>-*x*²</sup> Σ (2*x*²)<sup>*n*</sup> / (1 · 3 · 5 ⋯
+    /// (2*n* + 1)),
+    ///
+    /// whose terms are all positive, so nothing is lost to cancellation. Past that, it's
+    /// calculated as 1 - [`erfc`]\(*x*), where the subtraction can't cancel anything
+    /// either. The result is precise to about 30 digits.
+    ///
+    /// erf(±∞) is ±1, and the result is within a `Double`'s precision of ±1 long before
+    /// that, once |*x*| is more than about 8.8.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(0.5).erf();
+    /// let expected = dd!("0.52049987781304653768274665389196453");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`erfc`]: #method.erfc
+    pub fn erf(self) -> Double {
+        match self.pre_erf() {
+            Some(r) => s::guard("Double::erf", &[self.0], r),
+            None => {
+                let x = self.abs();
+                let r = if x.0 < SERIES_MAX {
+                    series(x)
+                } else {
+                    Double::ONE - fraction(x)
+                };
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
+    /// Computes the complementary error function, erfc(*x*) = 1 - erf(*x*), where *x* is
+    /// this `Double`.
+    ///
+    /// erfc(*x*) gets very small as *x* grows, and calculating it as `1 - x.erf()` would
+    /// lose all of its digits to cancellation. Instead, for *x* >= 3, it's calculated with
+    /// the continued fraction
+    ///
+    /// erfc(*x*) = (2*x* / √π) *e*<sup>-*x*²</sup> / (2*x*² + 1 - 1 · 2 / (2*x*² + 5 -
+    /// 3 · 4 / (2*x*² + 9 - ⋯))),
+    ///
+    /// which keeps its relative precision all the way down to where the result underflows
+    /// at *x* of about 27.2. The fraction converges too slowly to be used much below 3, so
+    /// for 1/2 <= *x* < 3, erfc is calculated by applying the trapezoid rule to an integral
+    /// for it, which also keeps its relative precision. Below 1/2, it's 1 - [`erf`]\(*x*),
+    /// which loses less than half a digit to cancellation.
+    ///
+    /// The result is precise to about 30 digits for small *x*, dropping to about 28 digits
+    /// for large *x* along with the precision of *e*<sup>-*x*²</sup>. Once *x* is more than
+    /// about 24.5, the second component is subnormal, and fewer digits are left.
+    ///
+    /// erfc(∞) is 0 and erfc(-∞) is 2.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(10).erfc();
+    /// let expected = dd!("2.0884875837625447570007862949577886e-45");
+    ///
+    /// let diff = ((x - expected) / expected).abs();
+    /// assert!(diff < dd!(1e-29));
+    /// ```
+    ///
+    /// [`erf`]: #method.erf
+    pub fn erfc(self) -> Double {
+        match self.pre_erfc() {
+            Some(r) => s::guard("Double::erfc", &[self.0], r),
+            None => {
+                if self.0 >= SERIES_MAX {
+                    fraction(self)
+                } else if self.0 >= TRAPEZOID_MIN {
+                    trapezoid(self)
+                } else if self.0 > -SERIES_MAX {
+                    // The series is odd, so this works for negative x, for which erf(x) is
+                    // negative and there's no cancellation at all
+                    Double::ONE - series(self)
+                } else {
+                    Double(2.0, 0.0) - fraction(-self)
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn pre_erf(&self) -> Option<Double> {
+        if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.is_zero() {
+            // Includes both zeros, which keep their signs
+            Some(*self)
+        } else if self.0 > ERFC_MAX {
+            Some(Double::ONE)
+        } else if self.0 < -ERFC_MAX {
+            Some(Double::NEG_ONE)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_erfc(&self) -> Option<Double> {
+        if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.0 > ERFC_MAX {
+            Some(Double::ZERO)
+        } else if self.0 < -ERFC_MAX {
+            Some(Double(2.0, 0.0))
+        } else {
+            None
+        }
+    }
+}
+
+// Computes erf(x) for |x| < SERIES_MAX with the series
+//
+//      erf(x) = 2/√π · x e^(-x²) · Σ (2x²)^n / (1 · 3 · 5 ··· (2n + 1))
+//
+// This is the Maclaurin series of e^(x²) erf(x). Unlike the Maclaurin series of erf(x)
+// itself, its terms don't alternate in sign, so none of them cancel however large x is.
+fn series(x: Double) -> Double {
+    let x2 = x.sqr();
+    let z = c::mul_pwr2(x2, 2.0);
+    let mut t = Double::ONE;
+    let mut sum = Double::ONE;
+    let mut d = 1.0;
+    while t.0 > sum.0 * Double::EPSILON.0 {
+        d += 2.0;
+        t = t * z / Double(d, 0.0);
+        sum += t;
+    }
+    Double::FRAC_2_SQRT_PI * x * sum * (-x2).exp()
+}
+
+// Computes erfc(x) for x >= SERIES_MAX with the continued fraction
+//
+//      erfc(x) = 2x e^(-x²) / √π · 1 / (2x² + 1 - 1·2 / (2x² + 5 - 3·4 / (2x² + 9 - ...)))
+//
+// evaluated from the bottom up. The fraction converges more slowly for smaller x, and the
+// number of terms is just over what full precision needs at each x. At x = 3, that's 61.
+fn fraction(x: Double) -> Double {
+    let x2 = x.sqr();
+    let z = c::mul_pwr2(x2, 2.0);
+    let n = (21.0 / x.0).powi(2) as i32 + 12;
+    let mut t = z + Double((4 * n + 1) as f64, 0.0);
+    for k in (1..=n).rev() {
+        let a = Double(((2 * k - 1) * 2 * k) as f64, 0.0);
+        t = z + Double((4 * k - 3) as f64, 0.0) - a / t;
+    }
+    // The exponential is the only part that can underflow, so it's multiplied in last
+    Double::FRAC_2_SQRT_PI * x / t * (-x2).exp()
+}
+
+// Computes erfc(x) for TRAPEZOID_MIN <= x < SERIES_MAX by applying the trapezoid rule to
+//
+//      erfc(x) = (2x e^(-x²) / π) ∫ e^(-t²) / (t² + x²) dt, over t in [0, ∞),
+//
+// and correcting for the poles of the integrand at t = ±ix (Chiarella and Reichel, 1968):
+//
+//      erfc(x) ≈ (2hx e^(-x²) / π) (1 / 2x² + Σ e^(-k²h²) / (k²h² + x²))
+//                  + 2 / (1 - e^(2πx / h))
+//
+// The rule converges very quickly for an integrand like this one, and its relative error is
+// about e^(-π²/h²) whatever x is. The terms of the sum fall off so fast that about 30 of
+// them are enough for any x. The factors e^(-k²h²) are built up one from the other, so
+// each has a rounding error of a few units per term before it, but the terms that have
+// the larger errors are too small for them to matter.
+fn trapezoid(x: Double) -> Double {
+    let x2 = x.sqr();
+    let h2 = Double(STEP, 0.0).sqr();
+    let r = (-c::mul_pwr2(h2, 2.0)).exp();
+    // e^(-(2k - 1)h²) and e^(-k²h²), for k = 1
+    let mut m = (-h2).exp();
+    let mut q = m;
+    let mut sum = c::mul_pwr2(x2, 2.0).recip();
+    let mut k = 1.0;
+    loop {
+        let t = q / (h2 * Double(k * k, 0.0) + x2);
+        sum += t;
+        if t.0 <= sum.0 * Double::EPSILON.0 {
+            break;
+        }
+        k += 1.0;
+        m *= r;
+        q *= m;
+    }
+    // 2 / (1 - e^y) = -2e^(-y) / (1 - e^(-y)), which doesn't overflow
+    let e = (-Double::TAU * x / Double(STEP, 0.0)).exp();
+    let pole = c::mul_pwr2(e / (Double::ONE - e), 2.0);
+    c::mul_pwr2(x * sum, 2.0) * Double(STEP, 0.0) * Double::FRAC_1_PI * (-x2).exp() - pole
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // erf tests
+    test_all_prec!(
+        erf_tiny:
+            dd!("1.12837916709551257389239763923122679644179201277259685e-10"),
+            dd!(1e-10).erf(),
+            30;
+        erf_eighth:
+            dd!("0.140316204801333817393029446521623398186979583149845473"),
+            dd!(0.125).erf(),
+            30;
+        erf_half:
+            dd!("0.520499877813046537682746653891964528736451575757963700"),
+            dd!(0.5).erf(),
+            30;
+        erf_one:
+            dd!("0.842700792949714869341220635082609259296066997966302908"),
+            dd!(1).erf(),
+            30;
+        erf_2_5:
+            dd!("0.999593047982555041060435784260025087279651322596286580"),
+            dd!(2.5).erf(),
+            30;
+        erf_3_5:
+            dd!("0.999999256901627658587254476316243904364279339907827203"),
+            dd!(3.5).erf(),
+            30;
+        erf_five:
+            dd!("0.999999999998462540205571965149811656514616621109881950"),
+            dd!(5).erf(),
+            30;
+        erf_ten:
+            dd!("0.999999999999999999999999999999999999999999997911512416"),
+            dd!(10).erf(),
+            30;
+        erf_neg_half:
+            dd!("-0.520499877813046537682746653891964528736451575757963700"),
+            dd!(-0.5).erf(),
+            30;
+        erf_neg_five:
+            dd!("-0.999999999998462540205571965149811656514616621109881950"),
+            dd!(-5).erf(),
+            30;
+    );
+    test_all_exact!(
+        erf_zero:
+            Double::ZERO,
+            Double::ZERO.erf();
+        erf_neg_zero:
+            Double::NEG_ZERO,
+            Double::NEG_ZERO.erf();
+        erf_28:
+            Double::ONE,
+            dd!(28).erf();
+        erf_inf:
+            Double::ONE,
+            Double::INFINITY.erf();
+        erf_neg_inf:
+            Double::NEG_ONE,
+            Double::NEG_INFINITY.erf();
+        erf_nan:
+            Double::NAN,
+            Double::NAN.erf();
+    );
+
+    // erfc tests
+    test_all_prec!(
+        erfc_tiny:
+            dd!("0.999999999887162083290448742610760236076877320355820799"),
+            dd!(1e-10).erfc(),
+            30;
+        erfc_half:
+            dd!("0.479500122186953462317253346108035471263548424242036300"),
+            dd!(0.5).erfc(),
+            30;
+        erfc_0_99:
+            dd!("0.161491930444630196420209769470076703729183988591868685"),
+            dd!(0.99).erfc(),
+            30;
+        erfc_one:
+            dd!("0.157299207050285130658779364917390740703933002033697092"),
+            dd!(1).erfc(),
+            30;
+        erfc_1_5:
+            dd!("0.0338948535246892729330237383540521413185895207423632196"),
+            dd!(1.5).erfc(),
+            30;
+        erfc_four:
+            dd!("1.54172579002800188521596734868840485721452535891911683e-8"),
+            dd!(4).erfc(),
+            30;
+        erfc_five:
+            dd!("1.53745979442803485018834348538337889011805031472337993e-12"),
+            dd!(5).erfc(),
+            30;
+        erfc_ten:
+            dd!("2.08848758376254475700078629495778861156081811932116373e-45"),
+            dd!(10).erfc(),
+            30;
+        erfc_neg_half:
+            dd!("1.52049987781304653768274665389196452873645157575796370"),
+            dd!(-0.5).erfc(),
+            30;
+        erfc_neg_one:
+            dd!("1.84270079294971486934122063508260925929606699796630291"),
+            dd!(-1).erfc(),
+            30;
+        erfc_neg_five:
+            dd!("1.99999999999846254020557196514981165651461662110988195"),
+            dd!(-5).erfc(),
+            30;
+    );
+    test_all_exact!(
+        erfc_zero:
+            Double::ONE,
+            Double::ZERO.erfc();
+        erfc_28:
+            Double::ZERO,
+            dd!(28).erfc();
+        erfc_inf:
+            Double::ZERO,
+            Double::INFINITY.erfc();
+        erfc_neg_inf:
+            dd!(2),
+            Double::NEG_INFINITY.erfc();
+        erfc_nan:
+            Double::NAN,
+            Double::NAN.erfc();
+    );
+    test!(erfc_26: {
+        // The result is near enough to underflow that the second component is subnormal,
+        // so it's scaled up by 2^1000 to compare it
+        let expected = dd!("606815.941024128776283331358990213943431949732563617786962");
+        prec!(expected, dd!(26).erfc().ldexp(1000), 27);
+    });
+    test!(erf_26: {
+        prec!(Double::ONE, dd!(26).erf(), 30);
+        assert!(dd!(26).erf() == Double::ONE - dd!(26).erfc());
+    });
+    test!(erfc_27: {
+        // Subnormal, so only the first component is left, and it has only a few bits
+        let x = dd!(27).erfc();
+        assert!(x.1 == 0.0);
+        assert!((x.0 / 5.237048923789255e-319 - 1.0).abs() < 1e-3);
+    });
+    test!(erf_erfc_sum: {
+        for &x in &[-4.0, -2.2, -0.7, 0.01, 0.3, 0.9, 1.0, 1.1, 2.9, 3.0, 3.1, 6.0] {
+            let x = dd!(x);
+            prec!(Double::ONE, x.erf() + x.erfc(), 30);
+        }
+    });
+}
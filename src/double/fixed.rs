@@ -0,0 +1,443 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::fixed as f;
+use crate::common::primitive as p;
+use crate::double::Double;
+use crate::error::{ErrorKind, FormatErrorKind, FormatFixedError, ParseDoubleError};
+
+// 2^106. Every integer smaller than this can be represented exactly by a `Double`.
+const EXACT_LIMIT: u128 = 1 << 106;
+
+// 2^127. A scaled value has to be smaller than this for its rounded value to fit into an
+// `i128`.
+const SCALED_LIMIT: f64 = 170_141_183_460_469_231_731_687_303_715_884_105_728.0;
+
+// Converts a `u128` into a `Double`. This is exact as long as the significant bits of the
+// `u128` span no more than 106 bits, which is true of everything smaller than 2^106 and of
+// every power of ten that fits into a `u128`.
+fn from_u128(n: u128) -> Double {
+    let (a, b, c) = f::split_u128(n);
+    let (s, e) = p::two_sum(b, c);
+    let (a, b) = p::two_sum(a, s);
+    let (a, b) = p::quick_two_sum(a, b + e);
+    Double(a, b)
+}
+
+impl Double {
+    /// Parses a fixed-point number with an implied decimal point from a field of ASCII
+    /// bytes.
+    ///
+    /// This is meant for the kinds of fixed-width fields found in market data feeds, where
+    /// a price like 1234.5678 might be sent as `b"12345678"` with four implied decimals.
+    /// The field may be padded with spaces on either side and with leading zeros, and the
+    /// number may have a leading `+` or `-`. Nothing else is allowed; in particular, there
+    /// can be no decimal point or exponent. Because the input is bytes, no UTF-8 validation
+    /// is done, and nothing is allocated.
+    ///
+    /// The digits are accumulated into an integer, which is then converted into a `Double`
+    /// and divided by the power of ten given by `implied_decimals`. As long as
+    /// `implied_decimals` is no more than 38, that power of ten is exact, so the only
+    /// rounding in the whole process is in that one division. Larger numbers of implied
+    /// decimals are handled by dividing more than once.
+    ///
+    /// Failure will return a [`ParseDoubleError`]. Its kind will be `Empty` if the field
+    /// is empty or holds only spaces, `Overflow` if the integer is too large for a
+    /// `Double` to represent exactly (2<sup>106</sup> or larger), and `Invalid` for
+    /// anything else.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::from_ascii_fixed(b"  -0012345678", 4).unwrap();
+    /// assert!(x == dd!(-12345678) / dd!(10000));
+    ///
+    /// let diff = (x - dd!("-1234.5678")).abs();
+    /// assert!(diff < dd!(1e-28));
+    /// ```
+    ///
+    /// [`ParseDoubleError`]: error/struct.ParseDoubleError.html
+    pub fn from_ascii_fixed(
+        bytes: &[u8],
+        implied_decimals: u32,
+    ) -> Result<Double, ParseDoubleError> {
+        let (negative, n) = f::parse_fixed(bytes).map_err(|kind| ParseDoubleError { kind })?;
+        if n >= EXACT_LIMIT {
+            return Err(ParseDoubleError {
+                kind: ErrorKind::Overflow,
+            });
+        }
+
+        let mut result = from_u128(n);
+        let mut decimals = implied_decimals;
+        while decimals > f::MAX_EXACT_DECIMALS && !result.is_zero() {
+            result /= from_u128(f::pow10(f::MAX_EXACT_DECIMALS));
+            decimals -= f::MAX_EXACT_DECIMALS;
+        }
+        // A zero (or a number that has underflowed to zero) stops the loop early with more
+        // than `MAX_EXACT_DECIMALS` still to go, but dividing it further wouldn't change it
+        if decimals > 0 && !result.is_zero() {
+            result /= from_u128(f::pow10(decimals));
+        }
+        Ok(if negative { -result } else { result })
+    }
+
+    /// Writes this `Double` as a fixed-point number with an implied decimal point into a
+    /// field of ASCII bytes.
+    ///
+    /// This is the inverse of [`from_ascii_fixed`]. The number is multiplied by the power
+    /// of ten given by `implied_decimals` and rounded to an integer, which is written into
+    /// `buf` right-aligned and padded with zeros. A negative number has a `-` in the first
+    /// byte of the field. The whole buffer is always written, so the returned number of
+    /// bytes written is always the length of the buffer.
+    ///
+    /// The rounding is exact, not just the rounding of a scaled `Double`. If the number
+    /// is exactly halfway between two integers once it's scaled, it's rounded to the even
+    /// one (as with the default rounding mode of IEEE-754 arithmetic), so 0.125 with two
+    /// implied decimals is written as 12 and 0.375 as 38.
+    ///
+    /// Failure will return a [`FormatFixedError`]. Its kind will be `NotFinite` if this
+    /// number is infinite or `NaN`, `Overflow` if `implied_decimals` is larger than 38 or
+    /// the scaled number is 2<sup>127</sup> or larger, and `BufferTooSmall` if the buffer
+    /// doesn't have room for the sign and all of the digits. The contents of the buffer
+    /// are unspecified if an error is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut buf = [0u8; 10];
+    /// let n = dd!("-1234.5678").to_ascii_fixed(4, &mut buf).unwrap();
+    /// assert!(n == 10);
+    /// assert!(&buf == b"-012345678");
+    ///
+    /// dd!(0.125).to_ascii_fixed(2, &mut buf[..4]).unwrap();
+    /// assert!(&buf[..4] == b"0012");
+    /// ```
+    ///
+    /// [`from_ascii_fixed`]: #method.from_ascii_fixed
+    /// [`FormatFixedError`]: error/struct.FormatFixedError.html
+    pub fn to_ascii_fixed(
+        &self,
+        implied_decimals: u32,
+        buf: &mut [u8],
+    ) -> Result<usize, FormatFixedError> {
+        if !self.is_finite() {
+            return Err(FormatFixedError {
+                kind: FormatErrorKind::NotFinite,
+            });
+        }
+        if implied_decimals > f::MAX_EXACT_DECIMALS {
+            return Err(FormatFixedError {
+                kind: FormatErrorKind::Overflow,
+            });
+        }
+
+        let scale = f::pow10(implied_decimals);
+        let guess = (*self * from_u128(scale)).round();
+        if guess.abs() >= Double(SCALED_LIMIT, 0.0) {
+            return Err(FormatFixedError {
+                kind: FormatErrorKind::Overflow,
+            });
+        }
+
+        let (s0, s1, s2) = f::split_u128(scale);
+        let products = [
+            (self.0, s0),
+            (self.0, s1),
+            (self.0, s2),
+            (self.1, s0),
+            (self.1, s1),
+            (self.1, s2),
+        ];
+        let mut terms = [0.0; 16];
+        let k = f::round_half_even(&products, guess.0 as i128 + guess.1 as i128, &mut terms);
+
+        f::write_fixed(k < 0, k.unsigned_abs(), buf).map_err(|kind| FormatFixedError { kind })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::random::Rng;
+
+    fn parse(bytes: &[u8], decimals: u32) -> Double {
+        Double::from_ascii_fixed(bytes, decimals).unwrap()
+    }
+
+    fn parse_err(bytes: &[u8], decimals: u32) -> ErrorKind {
+        Double::from_ascii_fixed(bytes, decimals).unwrap_err().kind
+    }
+
+    fn write(x: Double, decimals: u32, width: usize) -> String {
+        let mut buf = vec![0u8; width];
+        let n = x.to_ascii_fixed(decimals, &mut buf).unwrap();
+        assert_eq!(n, width);
+        String::from_utf8(buf).unwrap()
+    }
+
+    fn write_err(x: Double, decimals: u32, width: usize) -> FormatErrorKind {
+        let mut buf = vec![0u8; width];
+        x.to_ascii_fixed(decimals, &mut buf).unwrap_err().kind
+    }
+
+    // Every power of ten that the parser and writer use has to convert exactly.
+    test!(exact_powers: {
+        for d in 0..=f::MAX_EXACT_DECIMALS {
+            let n = f::pow10(d);
+            let x = from_u128(n);
+            let (a, b, c) = f::split_u128(n);
+            let mut terms = [x.0, x.1, -a, -b, -c];
+            crate::common::utils::distill(&mut terms);
+            assert!(terms[4] == 0.0, "10^{} is not exact", d);
+        }
+    });
+
+    test_all_exact!(
+        parse_plain:
+            dd!(12_345_678) / dd!(10_000),
+            parse(b"12345678", 4);
+        parse_leading_spaces:
+            dd!(12_345_678) / dd!(10_000),
+            parse(b"    12345678", 4);
+        parse_trailing_spaces:
+            dd!(12_345_678) / dd!(10_000),
+            parse(b"12345678    ", 4);
+        parse_leading_zeros:
+            dd!(12_345_678) / dd!(10_000),
+            parse(b"000012345678", 4);
+        parse_spaces_and_zeros:
+            dd!(12_345_678) / dd!(10_000),
+            parse(b"  0012345678", 4);
+        parse_plus:
+            dd!(12_345_678) / dd!(10_000),
+            parse(b"+0012345678", 4);
+        parse_minus:
+            dd!(-12_345_678) / dd!(10_000),
+            parse(b" -0012345678 ", 4);
+        parse_no_decimals:
+            dd!(12_345_678),
+            parse(b"12345678", 0);
+        parse_all_decimals:
+            dd!(12_345_678) / dd!(100_000_000),
+            parse(b"12345678", 8);
+        parse_zero:
+            Double::ZERO,
+            parse(b"0000", 2);
+        parse_neg_zero:
+            Double::NEG_ZERO,
+            parse(b"-0000", 2);
+        parse_max_exact:
+            dd!("81129638414606681695789005144063"),
+            parse(b"81129638414606681695789005144063", 0);
+        parse_zero_many_decimals:
+            Double::ZERO,
+            parse(b"0", 50);
+        parse_underflow:
+            Double::ZERO,
+            parse(b"1", 400);
+        parse_neg_underflow:
+            Double::NEG_ZERO,
+            parse(b"-1", 400);
+    );
+
+    test!(parse_underflow_sign: {
+        assert!(parse(b"-0", 50).is_sign_negative());
+        assert!(parse(b"-1", 400).is_sign_negative());
+        assert!(parse(b"1", 400).is_sign_positive());
+        assert!(parse(b"12345", u32::MAX).is_sign_positive());
+    });
+
+    test_all_near!(
+        parse_fraction:
+            dd!("1234.5678"),
+            parse(b"12345678", 4);
+        parse_cents:
+            dd!("-0.01"),
+            parse(b"-00000001", 2);
+        parse_long:
+            dd!("0.1234567890123456789012345678901"),
+            parse(b"1234567890123456789012345678901", 31);
+        parse_many_decimals:
+            dd!("1.2345e-45"),
+            parse(b"12345", 49);
+    );
+
+    test_all_eq!(
+        parse_empty:
+            ErrorKind::Empty,
+            parse_err(b"", 2);
+        parse_only_spaces:
+            ErrorKind::Empty,
+            parse_err(b"     ", 2);
+        parse_sign_only:
+            ErrorKind::Invalid,
+            parse_err(b"  -  ", 2);
+        parse_double_sign:
+            ErrorKind::Invalid,
+            parse_err(b"--1234", 2);
+        parse_sign_after_zeros:
+            ErrorKind::Invalid,
+            parse_err(b"00-1234", 2);
+        parse_inner_space:
+            ErrorKind::Invalid,
+            parse_err(b"12 34", 2);
+        parse_space_after_sign:
+            ErrorKind::Invalid,
+            parse_err(b"- 1234", 2);
+        parse_point:
+            ErrorKind::Invalid,
+            parse_err(b"12.34", 2);
+        parse_letter:
+            ErrorKind::Invalid,
+            parse_err(b"12a4", 2);
+        parse_non_ascii:
+            ErrorKind::Invalid,
+            parse_err(&[b'1', 0xc3, 0xa9], 2);
+        parse_exact_overflow:
+            ErrorKind::Overflow,
+            parse_err(b"81129638414606681695789005144064", 0);
+        parse_u128_overflow:
+            ErrorKind::Overflow,
+            parse_err(b"340282366920938463463374607431768211456", 0);
+    );
+
+    test_all_eq!(
+        write_plain:
+            "0012345678",
+            write(dd!("1234.5678"), 4, 10);
+        write_exact_width:
+            "12345678",
+            write(dd!("1234.5678"), 4, 8);
+        write_negative:
+            "-012345678",
+            write(dd!("-1234.5678"), 4, 10);
+        write_negative_exact_width:
+            "-12345678",
+            write(dd!("-1234.5678"), 4, 9);
+        write_zero:
+            "00000",
+            write(Double::ZERO, 2, 5);
+        write_neg_zero:
+            "00000",
+            write(Double::NEG_ZERO, 2, 5);
+        write_tiny_negative:
+            "00000",
+            write(dd!(-1e-10), 2, 5);
+        write_no_decimals:
+            "0042",
+            write(dd!(42), 0, 4);
+        write_large:
+            "81129638414606681695789005144063",
+            write(dd!("81129638414606681695789005144063"), 0, 32);
+        write_near_limit:
+            "170141183460469231731687303715879911424",
+            write(dd!(2).powi(127) - dd!(2).powi(22), 0, 39);
+    );
+
+    // Ties go to the even integer, and anything even a little bit away from a tie goes to
+    // the nearer one. The tie values are all exactly representable.
+    test_all_eq!(
+        round_tie_down:
+            "12",
+            write(dd!(0.125), 2, 2);
+        round_tie_up:
+            "38",
+            write(dd!(0.375), 2, 2);
+        round_tie_zero:
+            "0",
+            write(dd!(0.5), 0, 1);
+        round_tie_one:
+            "2",
+            write(dd!(1.5), 0, 1);
+        round_tie_two:
+            "2",
+            write(dd!(2.5), 0, 1);
+        round_tie_neg:
+            "-2",
+            write(dd!(-2.5), 0, 2);
+        round_tie_neg_odd:
+            "-4",
+            write(dd!(-3.5), 0, 2);
+        round_tie_in_tail:
+            "10000000000000000000000000000000",
+            write(parse(b"10000000000000000000000000000000", 0) + dd!(0.5), 0, 32);
+        round_tie_in_tail_odd:
+            "10000000000000000000000000000002",
+            write(parse(b"10000000000000000000000000000000", 0) + dd!(1.5), 0, 32);
+        round_above_tie:
+            "13",
+            write(dd!(0.125) + dd!(1e-30), 2, 2);
+        round_below_tie:
+            "37",
+            write(dd!(0.375) - dd!(1e-30), 2, 2);
+        round_above_tie_neg:
+            "-13",
+            write(dd!(-0.125) - dd!(1e-30), 2, 3);
+        round_nearest_down:
+            "1",
+            write(dd!("0.0149"), 2, 1);
+        round_nearest_up:
+            "2",
+            write(dd!("0.0151"), 2, 1);
+    );
+
+    test_all_eq!(
+        write_nan:
+            FormatErrorKind::NotFinite,
+            write_err(Double::NAN, 2, 10);
+        write_inf:
+            FormatErrorKind::NotFinite,
+            write_err(Double::INFINITY, 2, 10);
+        write_neg_inf:
+            FormatErrorKind::NotFinite,
+            write_err(Double::NEG_INFINITY, 2, 10);
+        write_too_many_decimals:
+            FormatErrorKind::Overflow,
+            write_err(dd!(1), 39, 50);
+        write_too_large:
+            FormatErrorKind::Overflow,
+            write_err(dd!(2).powi(127), 0, 50);
+        write_too_large_scaled:
+            FormatErrorKind::Overflow,
+            write_err(dd!(1e20), 20, 50);
+        write_buffer_too_small:
+            FormatErrorKind::BufferTooSmall,
+            write_err(dd!("1234.5678"), 4, 7);
+        write_buffer_too_small_sign:
+            FormatErrorKind::BufferTooSmall,
+            write_err(dd!("-1234.5678"), 4, 8);
+        write_empty_buffer:
+            FormatErrorKind::BufferTooSmall,
+            write_err(Double::ZERO, 0, 0);
+    );
+
+    // Random integers of up to 30 digits with random implied decimals should survive a
+    // round trip through parsing and writing unchanged.
+    test!(round_trip: {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..20_000 {
+            let digits = (rng.next_u64() % 30) as usize + 1;
+            let decimals = (rng.next_u64() % (digits as u64 + 3)) as u32;
+            let mut field = vec![b'0'; 32];
+            for b in field.iter_mut().skip(32 - digits) {
+                *b = b'0' + (rng.next_u64() % 10) as u8;
+            }
+            if rng.next_u64() % 2 == 0 {
+                field[0] = b'-';
+            }
+
+            let x = parse(&field, decimals);
+            let s = write(x, decimals, 32);
+            let expected = if field.iter().skip(1).all(|&b| b == b'0') {
+                // Negative zero is written without a sign
+                "0".repeat(32)
+            } else {
+                String::from_utf8(field.clone()).unwrap()
+            };
+            assert_eq!(s, expected, "decimals = {}", decimals);
+        }
+    });
+}
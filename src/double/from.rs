@@ -3,24 +3,39 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::literal as l;
+use crate::common::literal::{Conversion, IntSuffix, Literal, LiteralError};
+use crate::common::rounding as r;
+use crate::common::rounding::RoundingMode;
 use crate::common::utils as u;
+use crate::double::common as c;
+use crate::double::from_str::parse_decimal;
 use crate::double::Double;
+use crate::error::{ParseDoubleError, ToIntError, ToIntErrorKind};
+use crate::quad::Quad;
+use std::convert::TryFrom;
 use std::f64;
 
+// 2^63, 2^64, 2^127, and 2^128, the bounds of the 64- and 128-bit integer types.
+const TWO_63: f64 = 9223372036854775808.0;
+const TWO_64: f64 = 18446744073709551616.0;
+const TWO_127: f64 = 1.7014118346046923e38;
+const TWO_128: f64 = 3.402823669209385e38;
+
 #[inline]
-fn split_u64(a: u64) -> (u32, u32) {
+const fn split_u64(a: u64) -> (u32, u32) {
     let x = (a >> 32) as u32;
     let y = a as u32;
     (x, y)
 }
 
-fn from_u64(a: u64) -> Double {
+pub(super) const fn from_u64(a: u64) -> Double {
     let (x, y) = split_u64(a);
-    let (a, b) = u::renorm2(x as f64 * 2f64.powi(32), y as f64);
+    let (a, b) = u::renorm2(x as f64 * u::pow2(32), y as f64);
     Double(a, b)
 }
 
-fn from_i64(a: i64) -> Double {
+const fn from_i64(a: i64) -> Double {
     let sign = a.signum();
     // The first part prevents a.abs() from failing with overflow because the absolute
     // value of i64::MIN is i64::MAX + 1
@@ -30,7 +45,7 @@ fn from_i64(a: i64) -> Double {
         a.abs() as u64
     };
     let (x, y) = split_u64(a);
-    let (a, b) = u::renorm2(x as f64 * 2f64.powi(32), y as f64);
+    let (a, b) = u::renorm2(x as f64 * u::pow2(32), y as f64);
     if sign == -1 {
         Double(-a, -b)
     } else {
@@ -38,6 +53,92 @@ fn from_i64(a: i64) -> Double {
     }
 }
 
+impl Double {
+    // Converts a literal, given as the text that `stringify!` makes of it, at compile time.
+    // This is what `dd!` does with a literal, and it's only public so that the macro can
+    // call it. A float literal gives the same number as parsing its digits from a string
+    // would, and is an error if it has more digits than an `f64` can hold (see
+    // `common::literal`). Anything else gives the same number as calling `from` with it, or
+    // parsing it if it's a string.
+    #[doc(hidden)]
+    pub const fn __from_literal(text: &str) -> Result<Double, LiteralError> {
+        match l::lex(text) {
+            Ok(Literal::Str(s)) => Ok(match Double::from_str_const(s) {
+                Ok(x) => x,
+                Err(_) => Double::NAN,
+            }),
+            Ok(Literal::Int {
+                negative,
+                value,
+                suffix,
+            }) => {
+                if let Err(e) = l::check_int(negative, value, suffix) {
+                    return Err(e);
+                }
+                // Negating in the integer type keeps -0 from becoming -0.0
+                let signed = if negative {
+                    (value as u64).wrapping_neg() as i64
+                } else {
+                    value as i64
+                };
+                match suffix {
+                    IntSuffix::Signed(128) | IntSuffix::Unsigned(128) | IntSuffix::Size => {
+                        Err(LiteralError::Invalid(
+                            "only integers of 64 bits or less convert to `Double`",
+                        ))
+                    }
+                    IntSuffix::Signed(64) => Ok(from_i64(signed)),
+                    IntSuffix::Unsigned(64) => Ok(from_u64(value as u64)),
+                    // Without a suffix, an integer is an `i32` if it fits into one
+                    IntSuffix::None if negative && value > i32::MAX as u128 + 1 => {
+                        if value > i64::MAX as u128 + 1 {
+                            Err(LiteralError::Invalid(
+                                "integer literal is too large for `i64`",
+                            ))
+                        } else {
+                            Ok(from_i64(signed))
+                        }
+                    }
+                    IntSuffix::None if !negative && value > i32::MAX as u128 => {
+                        if value > u64::MAX as u128 {
+                            Err(LiteralError::Invalid(
+                                "integer literal is too large for `u64`",
+                            ))
+                        } else {
+                            Ok(from_u64(value as u64))
+                        }
+                    }
+                    _ => Ok(Double(signed as f64, 0.0)),
+                }
+            }
+            Ok(Literal::Float(dec)) => {
+                let parsed = parse_decimal(dec.digits, dec.exp, dec.negative);
+                match l::convert(&dec, parsed.0.abs()) {
+                    Ok(Conversion::Exact(x)) => Ok(Double(x, 0.0)),
+                    Ok(Conversion::Digits) => Ok(parsed),
+                    Err(e) => Err(e),
+                }
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    // What `try_dd!` does with a literal. The only difference from `__from_literal` is in
+    // strings, which give back the error from parsing them rather than NaN.
+    #[doc(hidden)]
+    pub const fn __try_from_literal(
+        text: &str,
+    ) -> Result<Result<Double, ParseDoubleError>, LiteralError> {
+        match l::lex(text) {
+            Ok(Literal::Str(s)) => Ok(Double::from_str_const(s)),
+            _ => match Double::__from_literal(text) {
+                Ok(x) => Ok(Ok(x)),
+                Err(e) => Err(e),
+            },
+        }
+    }
+}
+
 // FROM INTEGER IMPLEMENTATIONS
 //
 // These are simple enough - since integers are inherently dyadic (as long as they fit into
@@ -161,15 +262,9 @@ impl From<i64> for Double {
 
 // FROM FLOAT IMPLEMENTATIONS
 //
-// The Rust conversion from f32 to f64 is a bit-for-bit translation. It does not attempt to
-// account for floating point rounding error, so the parsed f64 is different from the
-// "equivalent" parsed f32. So rather than having a helper function that takes an f64, we
-// put the entire function into this macro so that `a.to_string().parse().unwrap()` calls
-// the f32 parser if an f32 is being converted.
-//
-// is_dyadic is still fine to convert for, because a dyadic f32 will convert accurately
-// into an f64 (and still return true) while a non-dyadic f32 may not convert accurately,
-// but it'll still be non-dyadic after the conversion.
+// Every `f32` and `f64` is a number that a single `f64` holds exactly, so converting one
+// means nothing more than making it the first component. Zeros, infinities, and NaN come
+// through the same way, as the first component with zeros after it.
 macro_rules! from_float_impl {
     ($(
         $(#[$m:meta])*
@@ -178,30 +273,7 @@ macro_rules! from_float_impl {
         $(#[$m])*
         impl From<$t> for Double {
             fn from(a: $t) -> Double {
-                if a == 0.0 {
-                    if a.is_sign_negative() {
-                        Double::NEG_ZERO
-                    } else {
-                        Double::ZERO
-                    }
-                } else if a.is_nan() {
-                    Double::NAN
-                } else if a.is_infinite() {
-                    if a.is_sign_negative() {
-                        Double::NEG_INFINITY
-                    } else {
-                        Double::INFINITY
-                    }
-                } else if u::is_dyadic(a as f64) {
-                    Double(a.into(), 0.0)
-                } else {
-                    // Parsing digit-by-digit from a string is the only way to do this
-                    // accurately.
-                    //
-                    // `unwrap` is safe because `a.to_string` will never return a string
-                    // that can't be parsed into a Double.
-                    a.to_string().parse().unwrap()
-                }
+                Double(a.into(), 0.0)
             }
         }
     )*);
@@ -210,58 +282,85 @@ macro_rules! from_float_impl {
 from_float_impl! {
     /// Generates a `Double` from an `f32`.
     ///
-    /// This function *does* account for floating point rounding error. Even though the
-    /// first component of a `Double` is enough to fit an `f32`, if that `f32` is not
-    /// exactly representable in binary, then the second component of the `Double` will
-    /// account for the rounding error.
-    ///
-    /// Note that in order to do this, the `f32` needs to be parsed digit by digit. While
-    /// the parser does work quite fast with integers or any `f32` that is represented
-    /// perfectly in binary (any number that can be represented as a fraction with a power
-    /// of 2 in the denominator), it's not a particularly fast operation otherwise.
+    /// The `Double` is exactly the number that the `f32` holds, which is its first component
+    /// with zeros after it. An `f32` written as a decimal number that isn't exactly
+    /// representable in binary, like `0.1f32`, only holds the nearest number that is, and
+    /// so does the `Double` made from it. To get the `Double` nearest to the decimal number
+    /// itself, parse it from a string or give it to [`dd!`] as a literal.
     ///
     /// # Examples
     /// ```
-    /// # use qd::Double;
+    /// # use qd::{dd, Double};
     /// // Exactly representable in binary
     /// let x = 0.9921875f32;
     /// let a = Double::from(x);
     /// assert!(a.to_string() == "0.9921875");
     ///
-    /// // Xot exactly representable in binary
-    /// let x = 0.9921876f32;
+    /// // Not exactly representable in binary
+    /// let x = 0.1f32;
     /// let a = Double::from(x);
-    /// assert!(a.to_string() == "0.9921876");
+    /// assert!(a == x as f64);
+    /// assert!(a != dd!(0.1));
     /// ```
+    ///
+    /// [`dd!`]: macro.dd.html
     f32
     /// Generates a `Double` from an `f64`.
     ///
-    /// This function *does* account for floating point rounding error. Even though the
-    /// first component of a `Double` is enough to fit an `f64`, if that `f64` is not
-    /// exactly representable in binary, then the second component of the `Double` will
-    /// account for the rounding error.
-    ///
-    /// Note that in order to do this, the `f64` needs to be parsed digit by digit. While
-    /// the parser does work quite fast with integers or any `f64` that is represented
-    /// perfectly in binary (any number that can be represented as a fraction with a power
-    /// of 2 in the denominator), it's not a particularly fast operation otherwise.
+    /// The `Double` is exactly the number that the `f64` holds, which is its first component
+    /// with zeros after it, so it compares equal to the `f64`. An `f64` written as a
+    /// decimal number that isn't exactly representable in binary, like `0.1`, only holds
+    /// the nearest number that is, and so does the `Double` made from it. To get the `Double`
+    /// nearest to the decimal number itself, parse it from a string or give it to [`dd!`]
+    /// as a literal.
     ///
     /// # Examples
     /// ```
-    /// # use qd::Double;
+    /// # use qd::{dd, Double};
     /// // Exactly representable in binary
     /// let x = 0.999969482421875f64;
     /// let a = Double::from(x);
     /// assert!(a.to_string() == "0.999969482421875");
     ///
     /// // Not exactly representable in binary
-    /// let x = 0.999969482421876f64;
+    /// let x = 0.1;
     /// let a = Double::from(x);
-    /// assert!(a.to_string() == "0.999969482421876");
+    /// assert!(a == x);
+    /// assert!(a != dd!(0.1));
     /// ```
+    ///
+    /// [`dd!`]: macro.dd.html
     f64
 }
 
+impl Double {
+    /// Creates a `Double` from an `f64` in a `const fn`, for use in constants along with
+    /// [`add_const`] and the other `const` arithmetic.
+    ///
+    /// This is the same conversion as `Double::from`, which can't be called at compile
+    /// time: the `Double` is exactly the number that the `f64` holds, with a second
+    /// component of zero. For decimal constants like 0.1, which an `f64` can't hold
+    /// exactly, use [`dd!`] instead, which is also evaluated at compile time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// const HALF: Double = Double::from_f64_const(0.5);
+    /// assert!(HALF == dd!(0.5));
+    ///
+    /// // 0.1 isn't a binary fraction, so this is the f64 0.1 exactly, and it isn't one tenth
+    /// const TENTH: Double = Double::from_f64_const(0.1);
+    /// assert!(TENTH != dd!("0.1"));
+    /// assert!(TENTH[0] == 0.1);
+    /// ```
+    ///
+    /// [`add_const`]: #method.add_const
+    /// [`dd!`]: macro.dd.html
+    pub const fn from_f64_const(a: f64) -> Double {
+        Double(a, 0.0)
+    }
+}
+
 impl From<(f64, f64)> for Double {
     /// Generates a `Double` from a 2-tuple of `f64`s.
     ///
@@ -320,16 +419,44 @@ impl From<&str> for Double {
     }
 }
 
+impl From<Quad> for Double {
+    /// Converts a `Quad` into the nearest `Double`.
+    ///
+    /// The first two components of the `Quad` are usually already the nearest `Double`,
+    /// but not always; when the second component was rounded from a point exactly halfway
+    /// between two `f64`s, the last two components decide which way it should have gone.
+    /// They're taken into account, so the result is correctly rounded, with ties going to
+    /// an even last bit.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, qd, Double, Quad};
+    /// assert!(Double::from(Quad::PI) == Double::PI);
+    /// assert!(Double::from(qd!(1) / qd!(3)) == dd!(1) / dd!(3));
+    ///
+    /// // The third component carries the second past halfway to its next f64
+    /// let half = 2f64.powi(-113);
+    /// let x = Quad::new(1.0, 2f64.powi(-60), half, 1e-50);
+    /// assert!(Double::from(x) == Double::new(1.0, 2f64.powi(-60) + 2.0 * half));
+    /// ```
+    fn from(a: Quad) -> Double {
+        let (a, b) = u::round2(a[0], a[1], a[2], a[3]);
+        Double(a, b)
+    }
+}
+
 impl From<Double> for f64 {
     /// Converts a `Double` into an `f64`.
     ///
     /// This will lose precision if the second component of the `Double` is not 0, but it
-    /// will not lose range.
+    /// will not lose range. The result is the first component, which is the `Double`
+    /// rounded to the nearest `f64`. [`to_f64_round`] can round in other directions.
     ///
-    /// No other conversions from `Double` to numeric types are provided, as every other one
-    /// has the capability of losing range (for example, no other type could be used to
-    /// represent `dd!(1e308)`). Casts can be made from the `f64` provided by this function
-    /// to other numeric types as needed.
+    /// The only other conversions from `Double` to numeric types are the `TryFrom`
+    /// conversions to the 64- and 128-bit integers, which succeed only when the `Double` is an
+    /// integer in range. Every other type has the capability of losing range (for example,
+    /// no other type could be used to represent `dd!(1e308)`). Casts can be made from the
+    /// `f64` provided by this function to other numeric types as needed.
     ///
     /// # Examples
     /// ```
@@ -340,6 +467,8 @@ impl From<Double> for f64 {
     /// let diff = (x - std::f64::consts::PI).abs();
     /// assert!(diff < 1e-15);
     /// ```
+    ///
+    /// [`to_f64_round`]: #method.to_f64_round
     #[inline]
     fn from(a: Double) -> f64 {
         a.0
@@ -368,9 +497,179 @@ impl From<Double> for (f64, f64) {
     }
 }
 
+// TRY FROM IMPLEMENTATIONS
+//
+// Converting to an integer type never rounds. Every component of a `Double` that's an integer
+// is an integer itself, so the components can be added up as integers, and as long as the
+// whole number is in range, that sum is exact. It's done modulo 2^128, which is enough to
+// hold any of the results, so the components don't all have to fit the type on their own:
+// the largest `u128` has a first component of 2^128, for instance.
+
+// Checks that `a` is an integer from `min` up to but not including `max`, and returns it
+// modulo 2^128.
+fn to_int(a: Double, min: f64, max: f64) -> Result<u128, ToIntError> {
+    let kind = if !a.is_finite() {
+        ToIntErrorKind::NotFinite
+    } else if !c::is_integer(a) {
+        ToIntErrorKind::NotInteger
+    } else if a < Double::from(min) || a >= Double::from(max) {
+        ToIntErrorKind::OutOfRange
+    } else {
+        return Ok(u::wrapping_sum(&[a.0, a.1]));
+    };
+    Err(ToIntError { kind })
+}
+
+macro_rules! try_from_impl {
+    ($(
+        $(#[$m:meta])*
+        $t:ident $min:expr, $max:expr
+    )*) => ($(
+        $(#[$m])*
+        impl TryFrom<Double> for $t {
+            type Error = ToIntError;
+
+            #[inline]
+            fn try_from(a: Double) -> Result<$t, ToIntError> {
+                to_int(a, $min, $max).map(|n| n as $t)
+            }
+        }
+    )*);
+}
+
+try_from_impl! {
+    /// Converts a `Double` into an `i64`, if it's an integer that fits.
+    ///
+    /// This never rounds: a number with a fractional part is an error of the kind
+    /// [`NotInteger`], and [`trunc`] or [`round`] can be used first to convert it anyway.
+    /// An integer too large or too small for an `i64` is an error of the kind
+    /// [`OutOfRange`], and NaN and the infinities are errors of the kind [`NotFinite`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use qd::error::ToIntErrorKind;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(i64::try_from(dd!(-42)) == Ok(-42));
+    /// assert!(i64::try_from(Double::from(i64::MIN)) == Ok(i64::MIN));
+    /// assert!(i64::try_from(dd!(2.5)).unwrap_err().kind == ToIntErrorKind::NotInteger);
+    /// assert!(i64::try_from(dd!(2).powi(63)).unwrap_err().kind == ToIntErrorKind::OutOfRange);
+    /// ```
+    ///
+    /// [`NotInteger`]: error/enum.ToIntErrorKind.html#variant.NotInteger
+    /// [`OutOfRange`]: error/enum.ToIntErrorKind.html#variant.OutOfRange
+    /// [`NotFinite`]: error/enum.ToIntErrorKind.html#variant.NotFinite
+    /// [`trunc`]: #method.trunc
+    /// [`round`]: #method.round
+    i64 -TWO_63, TWO_63
+    /// Converts a `Double` into a `u64`, if it's an integer that fits.
+    ///
+    /// Like the other integer conversions, this never rounds, and it's an error if the
+    /// number has a fractional part, if it's negative or too large, or if it isn't finite.
+    /// Negative zero converts to 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(u64::try_from(Double::from(u64::MAX)) == Ok(u64::MAX));
+    /// assert!(u64::try_from(Double::NEG_ZERO) == Ok(0));
+    /// assert!(u64::try_from(dd!(-1)).is_err());
+    /// ```
+    u64 0.0, TWO_64
+    /// Converts a `Double` into an `i128`, if it's an integer that fits.
+    ///
+    /// All of the components are used, so an integer that needs more bits than the first
+    /// component has still comes out exactly. Like the other integer conversions, this
+    /// never rounds, and it's an error if the number has a fractional part, if it's out of
+    /// range, or if it isn't finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use std::convert::TryFrom;
+    ///
+    /// let x = dd!(2).powi(100) + dd!(1);
+    /// assert!(i128::try_from(x) == Ok((1 << 100) + 1));
+    /// assert!(i128::try_from(-x) == Ok(-(1 << 100) - 1));
+    /// assert!(i128::try_from(x / dd!(2)).is_err());
+    /// assert!(i128::try_from(Double::NAN).is_err());
+    /// ```
+    i128 -TWO_127, TWO_127
+    /// Converts a `Double` into a `u128`, if it's an integer that fits.
+    ///
+    /// All of the components are used, so an integer that needs more bits than the first
+    /// component has still comes out exactly. Like the other integer conversions, this
+    /// never rounds, and it's an error if the number has a fractional part, if it's
+    /// negative or too large, or if it isn't finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use std::convert::TryFrom;
+    ///
+    /// let x = dd!(2).powi(127) + dd!(3);
+    /// assert!(u128::try_from(x) == Ok((1 << 127) + 3));
+    /// assert!(u128::try_from(dd!(2).powi(128)).is_err());
+    /// ```
+    u128 0.0, TWO_128
+}
+
+impl Double {
+    /// Converts the `Double` into an `f64`, rounding in the direction given by `mode`.
+    ///
+    /// The second component is taken into account, so with [`TowardPosInf`], for example,
+    /// the result is the smallest `f64` that's no smaller than the `Double`. Rounding to
+    /// nearest gives the first component, the same as [`f64::from`]. A `Double` too large
+    /// for an `f64` rounds to infinity or to `f64::MAX` as it would in IEEE arithmetic.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{Double, RoundingMode};
+    /// let third = Double::ONE / Double::from(3);
+    /// let down = third.to_f64_round(RoundingMode::TowardNegInf);
+    /// let up = third.to_f64_round(RoundingMode::TowardPosInf);
+    /// assert!(down < up);
+    /// assert!(Double::from(down) < third && third < Double::from(up));
+    /// ```
+    ///
+    /// [`TowardPosInf`]: enum.RoundingMode.html#variant.TowardPosInf
+    /// [`f64::from`]: #impl-From%3CDouble%3E-for-f64
+    #[inline]
+    pub fn to_f64_round(self, mode: RoundingMode) -> f64 {
+        r::to_f64(&[self.0, self.1], mode)
+    }
+
+    /// Converts the `Double` into an `f32`, rounding in the direction given by `mode`.
+    ///
+    /// The whole `Double` is rounded at once, so rounding to nearest gives the `f32`
+    /// nearest to the `Double`, which isn't always the one nearest to its first component.
+    /// A `Double` too large for an `f32` rounds to infinity or to `f32::MAX` as it would
+    /// in IEEE arithmetic.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{Double, RoundingMode};
+    /// // Just above the point halfway between 1 and the next f32. Its first component is
+    /// // exactly on that point and would round down to the even one.
+    /// let x = Double::new(1.0 + 2f64.powi(-24), 1e-30);
+    /// assert!(x.to_f32_round(RoundingMode::Nearest) == 1.0 + f32::EPSILON);
+    /// assert!((x[0] as f32) == 1.0);
+    ///
+    /// assert!(Double::PI.to_f32_round(RoundingMode::Nearest) == std::f32::consts::PI);
+    /// ```
+    #[inline]
+    pub fn to_f32_round(self, mode: RoundingMode) -> f32 {
+        r::to_f32(&[self.0, self.1], mode)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::random::Rng;
 
     // f32 tests
     test_all_exact!(
@@ -442,4 +741,346 @@ mod tests {
         i64_min: i64::MIN.to_string(), dd!(i64::MIN).to_string();
         u64_max: u64::MAX.to_string(), dd!(u64::MAX).to_string();
     );
+
+    // Quad tests
+    test_all_exact!(
+        quad_pi:
+            Double::PI,
+            Double::from(Quad::PI);
+        quad_inf:
+            Double::INFINITY,
+            Double::from(Quad::INFINITY);
+        quad_neg_inf:
+            Double::NEG_INFINITY,
+            Double::from(Quad::NEG_INFINITY);
+        quad_nan:
+            Double::NAN,
+            Double::from(Quad::NAN);
+    );
+    test_all_assert!(
+        quad_tie_even:
+            Double::from(Quad::new(1.0, 2f64.powi(-60), 2f64.powi(-113), 0.0))
+                == Double::new(1.0, 2f64.powi(-60));
+        quad_tie_up:
+            Double::from(Quad::new(1.0, 2f64.powi(-60), 2f64.powi(-113), 1e-60))
+                == Double::new(1.0, 2f64.powi(-60) + 2f64.powi(-112));
+        quad_tie_down:
+            Double::from(Quad::new(1.0, 2f64.powi(-60), 2f64.powi(-113), -1e-60))
+                == Double::new(1.0, 2f64.powi(-60));
+        quad_round_trip:
+            Double::from(Quad::from(Double::PI)) == Double::PI
+                && Double::from(Quad::from(Double::ONE / dd!(3))) == Double::ONE / dd!(3);
+        quad_neg_zero:
+            Double::from(Quad::NEG_ZERO)[0].is_sign_negative();
+    );
+
+    // literal tests
+    //
+    // These call the function that `dd!` uses for literals directly, since the macro would
+    // do it at compile time and so could only be tried with literals written out here.
+    fn same(a: Double, b: Double) -> bool {
+        a.to_bits() == b.to_bits()
+    }
+
+    test!(literal_const: {
+        const TAU_OVER_7: Double = dd!("0.8975979010256552108570956133638");
+        static HALF: Double = dd!(0.5);
+        static TENTH: Double = dd!(-0.1e-2_f64);
+        static BIG: Double = dd!(12_345_678_901_234_567_890u64);
+        static TAUS: [Double; 2] = [TAU_OVER_7, dd!(r"0.8975979010256552108570956133638")];
+
+        assert!(same(TAUS[0], "0.8975979010256552108570956133638".parse().unwrap()));
+        assert!(same(TAUS[1], TAUS[0]));
+        assert!(same(HALF, Double::from(0.5)));
+        assert!(same(TENTH, Double::from("-0.001")));
+        assert!(same(BIG, Double::from(12_345_678_901_234_567_890u64)));
+    });
+
+    test!(literal_str: {
+        assert!(same(Double::__from_literal("\"1.5e-3\"").unwrap(), Double::from("1.5e-3")));
+        assert!(Double::__from_literal("\" -inf \"").unwrap() == Double::NEG_INFINITY);
+        assert!(Double::__from_literal("\"1.5.3\"").unwrap().is_nan());
+        assert!(Double::__from_literal("r#\"\"\"#").unwrap().is_nan());
+        assert!(Double::__from_literal("\"\\u{31}\"").is_err());
+        assert!(Double::__from_literal("b\"1\"").is_err());
+        assert!(Double::__from_literal("true").is_err());
+    });
+
+    test!(literal_int: {
+        let lit = |s| Double::__from_literal(s).unwrap();
+        assert!(same(lit("5"), Double::from(5)));
+        assert!(same(lit("-5"), Double::from(-5)));
+        assert!(same(lit("-0"), Double::from(0)));
+        assert!(same(lit("- 5i64"), Double::from(-5i64)));
+        assert!(same(lit("0x_ff_u8"), Double::from(255u8)));
+        assert!(same(lit("-0b1000_0000i8"), Double::from(-128i8)));
+        assert!(same(lit("4294967296"), Double::from(4_294_967_296u64)));
+        assert!(same(lit("-9223372036854775808i64"), Double::from(i64::MIN)));
+        assert!(same(lit("18446744073709551615"), Double::from(u64::MAX)));
+        assert!(Double::__from_literal("18446744073709551616").is_err());
+        assert!(Double::__from_literal("1i128").is_err());
+        assert!(Double::__from_literal("256u8").is_err());
+        assert!(Double::__from_literal("-1u32").is_err());
+        assert!(Double::__from_literal("1usize").is_err());
+    });
+
+    test!(literal_float: {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..20_000 {
+            let len = 1 + (rng.next_u64() % 18) as usize;
+            let digits: String = (0..len)
+                .map(|_| (b'0' + (rng.next_u64() % 10) as u8) as char)
+                .collect();
+            let point = (rng.next_u64() % len as u64) as usize + 1;
+            let mut text = format!("{}.{}", &digits[..point], &digits[point..]);
+            if point == len {
+                text.push('0');
+            }
+            let mut exp = 0;
+            if rng.next_u64() % 2 == 0 {
+                exp = rng.range(-330, 310);
+                text.push_str(&format!("e{}", exp));
+            }
+            if rng.next_u64() % 4 == 0 {
+                text.insert(0, '-');
+            }
+            let single = rng.next_u64() % 10 == 0;
+
+            let significant = digits.trim_start_matches('0').trim_end_matches('0').len();
+            // The literal's digits are parsed without their leading and trailing zeros
+            let trimmed = digits.trim_end_matches('0');
+            let exp = exp + point as i32 - trimmed.len() as i32;
+            let trimmed = match trimmed.trim_start_matches('0') {
+                "" => "0",
+                t => t,
+            };
+            let sign = if text.starts_with('-') { "-" } else { "" };
+            let expected: Double = format!("{}{}e{}", sign, trimmed, exp).parse().unwrap();
+            let (infinite, tiny, held) = if single {
+                let x: f32 = text.parse().unwrap();
+                (x.is_infinite(), x.abs() < f32::MIN_POSITIVE, 6)
+            } else {
+                let x: f64 = text.parse().unwrap();
+                (x.is_infinite(), x.abs() < f64::MIN_POSITIVE, 15)
+            };
+            if single {
+                text.push_str("f32");
+            }
+            // Parsing can leave a negative zero below the first component, where an exact
+            // conversion doesn't
+            match Double::__from_literal(&text) {
+                Ok(x) => assert!(
+                    x == expected && x.is_sign_negative() == expected.is_sign_negative(),
+                    "{}",
+                    text
+                ),
+                Err(LiteralError::Lossy) => assert!(significant > held || tiny, "{}", text),
+                Err(LiteralError::Invalid(_)) => assert!(infinite, "{}", text),
+            }
+            if significant <= held && !tiny && !infinite {
+                assert!(Double::__from_literal(&text).is_ok(), "{}", text);
+            }
+        }
+    });
+
+    test!(literal_whole: {
+        let lit = |s| Double::__from_literal(s).unwrap();
+        // The f64s nearest these aren't the numbers that were written
+        assert!(same(lit("1e23"), dd!("1e23")));
+        assert!(same(lit("3e200"), dd!("3e200")));
+        assert!(same(lit("-9.5e40"), dd!("-9.5e40")));
+        assert!(same(lit("12345e300f64"), dd!("1.2345e304")));
+        assert!(same(lit("1e20f32"), dd!("1e20")));
+        assert!(lit("1e23") != 1e23);
+        // These ones are
+        assert!(same(lit("1e22"), Double::from(1e22)));
+        assert!(same(lit("9007199254740992.0"), Double::from(9007199254740992.0)));
+    });
+
+    test!(literal_subnormal: {
+        let lit = |s| Double::__from_literal(s).unwrap();
+        // One significant digit is all that the smallest subnormals have room for, and it's
+        // enough, since it's what printing them gives
+        assert!(same(lit("5e-324"), Double::from(5e-324)));
+        assert!(same(lit("1e-323"), Double::from(1e-323)));
+        // An f32 subnormal is a normal f64, so its digits are parsed like any others
+        assert!(same(lit("1e-45f32"), dd!("1e-45")));
+        assert!(Double::__from_literal("4.9e-324") == Err(LiteralError::Lossy));
+    });
+
+    test!(literal_lossy: {
+        let lossy = |s| Double::__from_literal(s) == Err(LiteralError::Lossy);
+        assert!(lossy("0.1234567890123456"));
+        assert!(lossy("3.14159265358979323846"));
+        assert!(lossy("1.1234567f32"));
+        assert!(lossy("1e-400"));
+        // Exactly equal to the f64, so nothing is lost
+        assert!(!lossy("1.0005645751953125"));
+        assert!(!lossy("1_234_567_890_123_456.0"));
+        assert!(!lossy("123_456_789_012_345.5"));
+        assert!(!lossy("1.203125f32"));
+    });
+
+    // rounding mode tests
+    test_all_assert!(
+        f64_round_pos_tail:
+            Double::new(1.0, 1e-20).to_f64_round(RoundingMode::Nearest) == 1.0
+                && Double::new(1.0, 1e-20).to_f64_round(RoundingMode::TowardZero) == 1.0
+                && Double::new(1.0, 1e-20).to_f64_round(RoundingMode::TowardPosInf)
+                    == 1.0 + f64::EPSILON
+                && Double::new(1.0, 1e-20).to_f64_round(RoundingMode::TowardNegInf) == 1.0;
+        f64_round_neg_tail:
+            Double::new(1.0, -1e-20).to_f64_round(RoundingMode::Nearest) == 1.0
+                && Double::new(1.0, -1e-20).to_f64_round(RoundingMode::TowardZero)
+                    == 1.0 - f64::EPSILON / 2.0
+                && Double::new(1.0, -1e-20).to_f64_round(RoundingMode::TowardPosInf) == 1.0
+                && Double::new(1.0, -1e-20).to_f64_round(RoundingMode::TowardNegInf)
+                    == 1.0 - f64::EPSILON / 2.0;
+        f64_round_negative:
+            Double::new(-1.0, 1e-20).to_f64_round(RoundingMode::Nearest) == -1.0
+                && Double::new(-1.0, 1e-20).to_f64_round(RoundingMode::TowardZero)
+                    == -1.0 + f64::EPSILON / 2.0
+                && Double::new(-1.0, 1e-20).to_f64_round(RoundingMode::TowardPosInf)
+                    == -1.0 + f64::EPSILON / 2.0
+                && Double::new(-1.0, 1e-20).to_f64_round(RoundingMode::TowardNegInf) == -1.0;
+        f64_round_exact:
+            dd!(1.5).to_f64_round(RoundingMode::TowardZero) == 1.5
+                && dd!(1.5).to_f64_round(RoundingMode::TowardPosInf) == 1.5
+                && dd!(1.5).to_f64_round(RoundingMode::TowardNegInf) == 1.5;
+        f64_round_tie:
+            Double::new(1.0, 2f64.powi(-53)).to_f64_round(RoundingMode::Nearest) == 1.0
+                && Double::new(-1.0, -2f64.powi(-53)).to_f64_round(RoundingMode::Nearest)
+                    == -1.0;
+        f64_round_max:
+            Double::MAX.to_f64_round(RoundingMode::Nearest) == f64::MAX
+                && Double::MAX.to_f64_round(RoundingMode::TowardZero) == f64::MAX
+                && Double::MAX.to_f64_round(RoundingMode::TowardPosInf) == f64::INFINITY
+                && (-Double::MAX).to_f64_round(RoundingMode::TowardNegInf) == f64::NEG_INFINITY;
+        f64_round_nan:
+            Double::NAN.to_f64_round(RoundingMode::TowardPosInf).is_nan();
+        f64_round_inf:
+            Double::INFINITY.to_f64_round(RoundingMode::TowardZero) == f64::INFINITY;
+        f32_round_pi:
+            Double::PI.to_f32_round(RoundingMode::Nearest) == std::f32::consts::PI
+                && Double::PI.to_f32_round(RoundingMode::TowardPosInf) == std::f32::consts::PI
+                && Double::PI.to_f32_round(RoundingMode::TowardNegInf)
+                    == f32::from_bits(std::f32::consts::PI.to_bits() - 1);
+        f32_round_twice:
+            Double::new(1.0 + 2f64.powi(-24), 1e-30).to_f32_round(RoundingMode::Nearest)
+                == 1.0 + f32::EPSILON
+                && Double::new(1.0 + 2f64.powi(-24), -1e-30).to_f32_round(RoundingMode::Nearest)
+                    == 1.0
+                && Double::new(1.0 + 2f64.powi(-24), 0.0).to_f32_round(RoundingMode::Nearest)
+                    == 1.0;
+        f32_round_directed:
+            Double::new(1.0 + 2f64.powi(-30), 0.0).to_f32_round(RoundingMode::TowardPosInf)
+                == 1.0 + f32::EPSILON
+                && Double::new(-1.0, -1e-30).to_f32_round(RoundingMode::TowardZero) == -1.0
+                && Double::new(-1.0, -1e-30).to_f32_round(RoundingMode::TowardNegInf)
+                    == -1.0 - f32::EPSILON;
+        f32_round_overflow:
+            dd!(f64::MAX).to_f32_round(RoundingMode::Nearest) == f32::INFINITY
+                && dd!(f64::MAX).to_f32_round(RoundingMode::TowardZero) == f32::MAX
+                && dd!(f64::MAX).to_f32_round(RoundingMode::TowardNegInf) == f32::MAX
+                && dd!(-f64::MAX).to_f32_round(RoundingMode::TowardPosInf) == -f32::MAX
+                && dd!(-f64::MAX).to_f32_round(RoundingMode::TowardNegInf) == f32::NEG_INFINITY;
+        f32_round_subnormal:
+            Double::new(1e-46, 0.0).to_f32_round(RoundingMode::Nearest) == 0.0
+                && Double::new(1e-46, 0.0).to_f32_round(RoundingMode::TowardPosInf)
+                    == f32::from_bits(1)
+                && Double::new(-1e-46, 0.0).to_f32_round(RoundingMode::TowardNegInf)
+                    == -f32::from_bits(1);
+    );
+
+    // integer conversion tests
+    fn int_error<T: std::fmt::Debug>(r: Result<T, ToIntError>) -> ToIntErrorKind {
+        r.unwrap_err().kind
+    }
+
+    test_all_assert!(
+        try_from_i64_bounds:
+            i64::try_from(Double::from(i64::MAX)) == Ok(i64::MAX)
+                && i64::try_from(Double::from(i64::MIN)) == Ok(i64::MIN);
+        try_from_u64_bounds:
+            u64::try_from(Double::from(u64::MAX)) == Ok(u64::MAX)
+                && u64::try_from(Double::ZERO) == Ok(0);
+        try_from_i128_bounds:
+            i128::try_from(Double(2f64.powi(127), -1.0)) == Ok(i128::MAX)
+                && i128::try_from(-dd!(2).powi(127)) == Ok(i128::MIN);
+        try_from_u128_bounds:
+            u128::try_from(Double(2f64.powi(128), -1.0)) == Ok(u128::MAX)
+                && u128::try_from(Double::ZERO) == Ok(0);
+        try_from_neg_zero:
+            u128::try_from(Double::NEG_ZERO) == Ok(0) && i64::try_from(Double::NEG_ZERO) == Ok(0);
+        try_from_i64_out:
+            int_error(i64::try_from(Double::from(i64::MAX) + Double::ONE)) == ToIntErrorKind::OutOfRange
+                && int_error(i64::try_from(Double::from(i64::MIN) - Double::ONE))
+                    == ToIntErrorKind::OutOfRange;
+        try_from_u64_out:
+            int_error(u64::try_from(Double::from(u64::MAX) + Double::ONE)) == ToIntErrorKind::OutOfRange
+                && int_error(u64::try_from(Double::NEG_ONE)) == ToIntErrorKind::OutOfRange;
+        try_from_i128_out:
+            int_error(i128::try_from(dd!(2).powi(127))) == ToIntErrorKind::OutOfRange
+                && int_error(i128::try_from(Double(-2f64.powi(127), -1.0)))
+                    == ToIntErrorKind::OutOfRange;
+        try_from_u128_out:
+            int_error(u128::try_from(dd!(2).powi(128))) == ToIntErrorKind::OutOfRange
+                && int_error(u128::try_from(Double::NEG_ONE)) == ToIntErrorKind::OutOfRange;
+        try_from_huge:
+            int_error(i128::try_from(dd!(2).powi(200) + dd!(1))) == ToIntErrorKind::OutOfRange
+                && int_error(u64::try_from(Double::MAX)) == ToIntErrorKind::OutOfRange;
+        try_from_fraction:
+            int_error(i64::try_from(dd!(2.5))) == ToIntErrorKind::NotInteger
+                && int_error(u128::try_from(dd!(0.5))) == ToIntErrorKind::NotInteger;
+        try_from_small_fraction:
+            int_error(i128::try_from(Double(2f64.powi(60), 0.5)))
+                == ToIntErrorKind::NotInteger;
+        try_from_nan:
+            int_error(i128::try_from(Double::NAN)) == ToIntErrorKind::NotFinite;
+        try_from_inf:
+            int_error(u64::try_from(Double::INFINITY)) == ToIntErrorKind::NotFinite
+                && int_error(i64::try_from(Double::NEG_INFINITY)) == ToIntErrorKind::NotFinite;
+    );
+
+    // Converting a 64-bit integer to a `Double` and back never loses anything, and neither
+    // does converting a 128-bit one, as long as it fits into the two components
+    test!(try_from_int_round_trip: {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        for _ in 0..10_000 {
+            let n = rng.next_u64();
+            assert_eq!(u64::try_from(Double::from(n)), Ok(n));
+            assert_eq!(i64::try_from(Double::from(n as i64)), Ok(n as i64));
+            // 52 bits in each of the two components
+            let hi = (rng.next_u64() >> 12) as i128;
+            let lo = (rng.next_u64() >> 12) as i128;
+            let shift = (rng.next_u64() % 23) as i32;
+            let n = (hi << (shift + 52)) + (lo << shift);
+            let x = Double::from(hi as f64).ldexp(shift + 52) + Double::from(lo as f64).ldexp(shift);
+            assert_eq!(i128::try_from(x), Ok(n));
+            assert_eq!(i128::try_from(-x), Ok(-n));
+            assert_eq!(u128::try_from(x), Ok(n as u128));
+        }
+    });
+
+    // Integers built out of shifted pieces, so that their bits are spread over several
+    // components, some of them negative
+    test!(try_from_shifted_sums: {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..10_000 {
+            // Two 52-bit pieces no more than 52 bits apart fit into 106 bits
+            let (a, b) = (rng.next_u64() >> 12, rng.next_u64() >> 12);
+            let sb = (rng.next_u64() % 22) as i32;
+            let sa = sb + (rng.next_u64() % 53) as i32;
+            let exact = ((a as i128) << sa) - ((b as i128) << sb);
+            let x = Double::from(a as f64).ldexp(sa) - Double::from(b as f64).ldexp(sb);
+            assert_eq!(i128::try_from(x), Ok(exact));
+            assert_eq!(i128::try_from(-x), Ok(-exact));
+            assert_eq!(u128::try_from(x.abs()), Ok(exact.unsigned_abs()));
+            // A half is only there to find if there are bits left to hold it
+            if exact.unsigned_abs() < 1 << 100 {
+                let half = x + dd!(0.5);
+                assert_eq!(int_error(i128::try_from(half)), ToIntErrorKind::NotInteger);
+            }
+        }
+    });
 }
@@ -3,12 +3,14 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::repr;
+use crate::common::utils as u;
+use crate::double::common as c;
+use crate::double::from::from_u64;
 use crate::double::Double;
 use crate::error::{ErrorKind, ParseDoubleError};
 use std::str::FromStr;
 
-const TEN: Double = Double(10.0, 0.0);
-
 impl FromStr for Double {
     type Err = ParseDoubleError;
 
@@ -20,7 +22,24 @@ impl FromStr for Double {
     /// wrong places; two decimal points or a negative sign after the number will both be
     /// rejected, for instance.
     ///
-    /// Failure will return a [`ParseDoubleError`] of some kind.
+    /// More precisely, the string must consist of an optional sign, one or more digits
+    /// with at most one decimal point among them, and an optional exponent. The exponent
+    /// is an `e` or `E` followed by an optional sign and at least one digit. Underscores
+    /// are ignored anywhere after the sign (including in the exponent), so that digits
+    /// can be grouped as they are in Rust literals, and leading and trailing whitespace is
+    /// trimmed. The words `inf`, `infinity`, and `nan`, with or without a sign and in any
+    /// case, are also accepted.
+    ///
+    /// Numbers too large for a `Double` parse as infinity, and numbers too small parse as
+    /// zero, just as they do for `f64`.
+    ///
+    /// Failure will return a [`ParseDoubleError`]. Its kind will be:
+    ///
+    /// * [`Empty`] if the string is empty or contains only whitespace;
+    /// * [`InvalidDigit`] if there is a character that can't appear where it does, along
+    ///   with the byte offset of that character in `s`;
+    /// * [`ExponentOverflow`] if the exponent doesn't fit into an `i32`;
+    /// * [`Invalid`] if digits are missing, as in `"-"` or `"1e"`.
     ///
     /// # Examples
     /// ```
@@ -44,120 +63,463 @@ impl FromStr for Double {
     /// ```
     ///
     /// [`ParseDoubleError`]: error/struct.ParseDoubleError.html
+    /// [`Empty`]: error/enum.ErrorKind.html#variant.Empty
+    /// [`InvalidDigit`]: error/enum.ErrorKind.html#variant.InvalidDigit
+    /// [`ExponentOverflow`]: error/enum.ErrorKind.html#variant.ExponentOverflow
+    /// [`Invalid`]: error/enum.ErrorKind.html#variant.Invalid
     fn from_str(s: &str) -> Result<Double, ParseDoubleError> {
-        let mut result = Double::ZERO;
+        Double::from_str_const(s)
+    }
+}
+
+impl Double {
+    /// Parses a string to create a `Double`, in a way that can be done at compile time.
+    ///
+    /// This is the same parser as [`from_str`], with the same results and the same
+    /// errors. The difference is that this is a `const fn`, so it can be used to define
+    /// constants:
+    ///
+    /// ```
+    /// # use qd::Double;
+    /// const TAU_OVER_7: Double = match Double::from_str_const("0.8975979010256551") {
+    ///     Ok(x) => x,
+    ///     Err(_) => panic!("not a number"),
+    /// };
+    /// assert!(TAU_OVER_7 == "0.8975979010256551".parse::<Double>().unwrap());
+    /// ```
+    ///
+    /// The [`dd!`] macro does this itself when it's given a literal, so
+    /// `dd!("0.8975979010256551")` is a constant expression too.
+    ///
+    /// [`from_str`]: #method.from_str
+    /// [`dd!`]: macro.dd.html
+    pub const fn from_str_const(s: &str) -> Result<Double, ParseDoubleError> {
+        let mut acc = Digits::new();
         let mut digits = 0;
         let mut point = -1;
         let mut sign = 0;
         let mut exp = 0;
 
-        let s = s.trim().to_ascii_lowercase();
-
-        match pre_from_str(&s) {
-            Some(r) => r,
-            None => {
-                for (index, ch) in s.chars().enumerate() {
-                    match ch.to_digit(10) {
-                        Some(d) => {
-                            result *= TEN;
-                            result += Double(d as f64, 0.0);
-                            digits += 1;
-                        }
-                        None => match ch {
-                            '.' => {
-                                if point >= 0 {
-                                    return Err(ParseDoubleError {
-                                        kind: ErrorKind::Invalid,
-                                    });
-                                }
-                                point = digits;
-                            }
-                            '-' => {
-                                if sign != 0 || digits > 0 {
-                                    return Err(ParseDoubleError {
-                                        kind: ErrorKind::Invalid,
-                                    });
-                                }
-                                sign = -1;
-                            }
-                            '+' => {
-                                if sign != 0 || digits > 0 {
-                                    return Err(ParseDoubleError {
-                                        kind: ErrorKind::Invalid,
-                                    });
-                                }
-                                sign = 1;
-                            }
-                            'e' => {
-                                let end = &s[(index + 1)..];
-                                match end.parse::<i32>() {
-                                    Ok(e) => {
-                                        exp = e;
-                                        break;
-                                    }
-                                    Err(_) => {
-                                        return Err(ParseDoubleError {
-                                            kind: ErrorKind::Invalid,
-                                        });
-                                    }
-                                }
-                            }
-                            '_' => {
-                                // just continue; _ is a no-op but not an error
-                            }
-                            _ => {
-                                return Err(ParseDoubleError {
-                                    kind: ErrorKind::Invalid,
-                                });
-                            }
-                        },
-                    }
-                }
+        // Error positions are offsets into the string as it was passed in, so the indexes
+        // are into the whole string, just kept between the trimmed ends
+        let (start, end) = u::trim(s);
+        let bytes = s.as_bytes();
 
-                if point >= 0 {
-                    exp -= digits - point;
+        if let Some(r) = pre_from_str(s, start, end) {
+            return r;
+        }
+        let mut index = start;
+        while index < end {
+            match bytes[index] {
+                d @ b'0'..=b'9' => {
+                    acc.push((d - b'0') as u32);
+                    digits += 1;
                 }
-                if exp != 0 {
-                    // Do this in two stages if the exponent is too small. For exmaple, a
-                    // number with 30 digits could have an exponent as low as -337 and still
-                    // not overflow, but doing the -337 all at once WOULD overflow
-                    if exp < -307 {
-                        let adjust = exp + 307;
-                        result *= TEN.powi(adjust);
-                        exp -= adjust;
-                    }
-                    result *= TEN.powi(exp);
+                b'.' if point < 0 => point = digits,
+                // A sign is only legal as the very first character; this also rejects
+                // things like "--3", "+-2", and ".-5"
+                b'-' if index == start => sign = -1,
+                b'+' if index == start => sign = 1,
+                // The exponent has to follow at least one digit
+                b'e' | b'E' if digits > 0 => {
+                    exp = match parse_exp(bytes, index + 1, end) {
+                        Ok(e) => e,
+                        Err(e) => return Err(e),
+                    };
+                    break;
                 }
-                if sign == -1 {
-                    result = -result;
+                b'_' => {
+                    // just continue; _ is a no-op but not an error
                 }
-
-                Ok(result)
+                _ => return Err(invalid_digit(index)),
             }
+            index += 1;
+        }
+
+        // There has to be at least one digit in the mantissa. Without this check, strings
+        // like "-", ".", and "_" would parse as zero.
+        if digits == 0 {
+            return Err(ParseDoubleError {
+                kind: ErrorKind::Invalid,
+            });
         }
+
+        if point >= 0 {
+            exp = exp.saturating_sub(digits - point);
+        }
+        Ok(acc.finish(exp, sign == -1))
     }
 }
 
+// Handles the empty string and the words that name special values. `start` and `end` are
+// the bounds of the string with whitespace trimmed off.
 #[inline]
-fn pre_from_str(s: &str) -> Option<Result<Double, ParseDoubleError>> {
-    if s.is_empty() {
-        Some(Err(ParseDoubleError {
+const fn pre_from_str(
+    s: &str,
+    start: usize,
+    end: usize,
+) -> Option<Result<Double, ParseDoubleError>> {
+    if start == end {
+        return Some(Err(ParseDoubleError {
             kind: ErrorKind::Empty,
-        }))
-    } else if s == "nan" {
-        Some(Ok(Double::NAN))
-    } else if s == "inf" || s == "infinity" {
-        Some(Ok(Double::INFINITY))
-    } else if s == "-inf" || s == "-infinity" {
-        Some(Ok(Double::NEG_INFINITY))
+        }));
+    }
+    let (negative, from) = match s.as_bytes()[start] {
+        b'-' => (true, start + 1),
+        b'+' => (false, start + 1),
+        _ => (false, start),
+    };
+    let (_, word) = s.split_at(from);
+    let (word, _) = word.split_at(end - from);
+    let value = if word.eq_ignore_ascii_case("nan") {
+        Double::NAN
+    } else if word.eq_ignore_ascii_case("inf") || word.eq_ignore_ascii_case("infinity") {
+        Double::INFINITY
+    } else {
+        return None;
+    };
+    Some(Ok(if negative {
+        Double(-value.0, -value.1)
     } else {
-        None
+        value
+    }))
+}
+
+// Parses the exponent, which runs from byte `start` of `bytes` (just past the `e`) to byte
+// `end`. The exponent is read in full before an overflow is reported, so that a bad
+// character after too many digits is still reported as a bad character.
+const fn parse_exp(bytes: &[u8], start: usize, end: usize) -> Result<i32, ParseDoubleError> {
+    let mut exp: i32 = 0;
+    let mut negative = false;
+    let mut digits = 0;
+    let mut overflow = false;
+
+    let mut index = start;
+    while index < end {
+        match bytes[index] {
+            d @ b'0'..=b'9' => {
+                digits += 1;
+                // Accumulating negative numbers lets i32::MIN through
+                let d = if negative {
+                    -((d - b'0') as i32)
+                } else {
+                    (d - b'0') as i32
+                };
+                match exp.checked_mul(10) {
+                    Some(e) => match e.checked_add(d) {
+                        Some(e) => exp = e,
+                        None => overflow = true,
+                    },
+                    None => overflow = true,
+                }
+            }
+            b'-' if index == start => negative = true,
+            b'+' if index == start => {}
+            b'_' => {}
+            _ => return Err(invalid_digit(index)),
+        }
+        index += 1;
+    }
+
+    if digits == 0 {
+        Err(ParseDoubleError {
+            kind: ErrorKind::Invalid,
+        })
+    } else if overflow {
+        Err(ParseDoubleError {
+            kind: ErrorKind::ExponentOverflow,
+        })
+    } else {
+        Ok(exp)
+    }
+}
+
+#[inline]
+const fn invalid_digit(position: usize) -> ParseDoubleError {
+    ParseDoubleError {
+        kind: ErrorKind::InvalidDigit { position },
+    }
+}
+
+impl Double {
+    /// Parses the output of [`Debug`] back into the `Double` that was formatted.
+    ///
+    /// The string must be `Double` followed by the 2 components in parentheses, separated by
+    /// commas, as `{:?}` writes them. Whitespace (including line breaks) is allowed around
+    /// each piece, so the multi-line output of `{:#?}` parses as well, and so does a comma
+    /// after the last component. Each component can be anything that parses as an `f64`,
+    /// including `inf`, `-inf`, and `NaN`.
+    ///
+    /// `Debug` writes each component in the shortest form that parses back to the same
+    /// `f64`, so this reproduces a formatted `Double` bit for bit, down to the sign of a zero
+    /// and any subnormal components. The one exception is NaN, whose sign `Debug` doesn't
+    /// write; it comes back as [`NAN`]. Components that weren't written by `Debug` don't
+    /// have to be normalized. If they aren't, they're normalized in the same way as by
+    /// [`from_components`], so the result is the `Double` nearest to their sum.
+    ///
+    /// Failure will return a [`ParseDoubleError`]. Its kind will be:
+    ///
+    /// * [`Empty`] if the string is empty or contains only whitespace;
+    /// * [`InvalidDigit`] if there is a character that can't appear where it does, along
+    ///   with its byte offset in `s`, or if a component isn't an `f64`, along with the
+    ///   offset of the component's first character;
+    /// * [`Invalid`] if the string ends before all of the components and the closing
+    ///   parenthesis have been read.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::from_repr("Double(3.141592653589793e0, 1.2246467991473532e-16)").unwrap();
+    /// assert!(x == Double::PI);
+    ///
+    /// let y = dd!(1) / dd!(3);
+    /// assert!(Double::from_repr(&format!("{:?}", y)).unwrap() == y);
+    /// assert!(Double::from_repr(&format!("{:#?}", -y)).unwrap() == -y);
+    ///
+    /// assert!(Double::from_repr("Double(1e0)").is_err());
+    /// ```
+    ///
+    /// [`Debug`]: #impl-Debug
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`from_components`]: #method.from_components
+    /// [`ParseDoubleError`]: error/struct.ParseDoubleError.html
+    /// [`Empty`]: error/enum.ErrorKind.html#variant.Empty
+    /// [`InvalidDigit`]: error/enum.ErrorKind.html#variant.InvalidDigit
+    /// [`Invalid`]: error/enum.ErrorKind.html#variant.Invalid
+    pub fn from_repr(s: &str) -> Result<Double, ParseDoubleError> {
+        let [a, b] = repr::parse(s, "Double").map_err(|kind| ParseDoubleError { kind })?;
+        // Components that are already normalized are kept as they are, even where
+        // normalizing would pick other ones with the same sum
+        let x = Double(a, b);
+        Ok(if x.is_normalized() {
+            x
+        } else {
+            Double::from_components(a, b)
+        })
+    }
+
+    /// Creates a `Double` from an integer part, the digits of a fractional part, and a
+    /// power-of-ten exponent.
+    ///
+    /// The result is the number that would be produced by parsing the string made by
+    /// gluing the parts together, i.e., `Double::from_parts(-3, "14159", 2)` produces
+    /// exactly the same `Double` as parsing `"-3.14159e2"`. This is guaranteed rather than
+    /// approximated: the digits of both parts go through the same code that [`from_str`]
+    /// uses, in the same order, so they're rounded in exactly the same way. In particular
+    /// the integer and fractional parts are never rounded separately and then added, which
+    /// could round a second time. No string is built along the way and nothing is
+    /// allocated.
+    ///
+    /// The parts are interpreted as follows:
+    ///
+    /// * The sign of the result is the sign of `int_part`, and the fraction adds to the
+    ///   magnitude of the integer part, moving the result *away* from zero. This is the
+    ///   same as the way that `"-3.14"` is read: `from_parts(-3, "14", 0)` is -3.14, not
+    ///   -2.86. Since an `i64` zero has no sign, a number between -1 and 0 has to be made
+    ///   by negating the result of `from_parts(0, ...)`.
+    /// * `fraction_digits` must contain only the ASCII digits `0` through `9`, plus any
+    ///   number of underscores, which are ignored just as they are by [`from_str`]. It may
+    ///   be empty, in which case the result is just the integer part (scaled by the
+    ///   exponent). It may also be very long; digits beyond the precision of a `Double`
+    ///   still take part in rounding.
+    /// * `exponent10` scales the *whole* number, integer and fraction together, just as
+    ///   the exponent does in `"3.14e2"`. It is applied last.
+    ///
+    /// Failure will return a [`ParseDoubleError`]. Its kind will be [`InvalidDigit`] if
+    /// `fraction_digits` contains anything other than digits and underscores, with the
+    /// position of the first such character in `fraction_digits`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::from_parts(3, "14159265358979323846264338327950", 0).unwrap();
+    /// assert!(x == dd!("3.14159265358979323846264338327950"));
+    ///
+    /// let y = Double::from_parts(-2, "5", -3).unwrap();
+    /// assert!(y == dd!("-0.0025"));
+    /// assert!(Double::from_parts(0, "25", 0).unwrap() == dd!("0.25"));
+    /// assert!(Double::from_parts(1, "2.5", 0).is_err());
+    /// ```
+    ///
+    /// [`from_str`]: #method.from_str
+    /// [`ParseDoubleError`]: error/struct.ParseDoubleError.html
+    /// [`InvalidDigit`]: error/enum.ErrorKind.html#variant.InvalidDigit
+    pub fn from_parts(
+        int_part: i64,
+        fraction_digits: &str,
+        exponent10: i32,
+    ) -> Result<Double, ParseDoubleError> {
+        let mut acc = Digits::new();
+
+        // The digits of the integer part, most significant first. An i64 has at most 19.
+        let mut buf = [0u8; 20];
+        let mut n = int_part.unsigned_abs();
+        let mut start = buf.len();
+        loop {
+            start -= 1;
+            buf[start] = (n % 10) as u8;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        for &d in &buf[start..] {
+            acc.push(d as u32);
+        }
+
+        let mut digits: i32 = 0;
+        for (index, b) in fraction_digits.bytes().enumerate() {
+            match b {
+                b'0'..=b'9' => {
+                    acc.push((b - b'0') as u32);
+                    digits = digits.saturating_add(1);
+                }
+                b'_' => {}
+                _ => return Err(invalid_digit(index)),
+            }
+        }
+
+        Ok(acc.finish(exponent10.saturating_sub(digits), int_part < 0))
+    }
+}
+
+// Parses the decimal digits of an integer, scaled by `10^exp`, exactly as the string of
+// those digits with that exponent would be parsed.
+pub(super) const fn parse_decimal(digits: u128, exp: i32, negative: bool) -> Double {
+    // A u128 has at most 39 digits
+    let mut buf = [0u8; 39];
+    let mut n = digits;
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    let mut acc = Digits::new();
+    while start < buf.len() {
+        acc.push(buf[start] as u32);
+        start += 1;
+    }
+    acc.finish(exp, negative)
+}
+
+// The number of significant digits that are accumulated into a number while parsing. This
+// is several more than a `Double` can hold, so that rounding happens on digits past the
+// end of its precision. Any digits beyond this are only tracked as being zero or not.
+const MAX_DIGITS: i32 = 40;
+
+// The number of digits in a chunk. This is the most that a `u64` always has room for.
+const CHUNK_DIGITS: usize = 19;
+
+// Turns a sequence of decimal digits into a `Double`. This is the one place in parsing
+// where digits become a number; both `from_str` and `from_parts` push their digits through
+// it, which is what guarantees that they agree with each other.
+//
+// Digits are gathered 19 at a time into a `u64`, which holds any 19 digits exactly.
+// Each full chunk is then folded into the running value with one multiplication by
+// 10^19 and one addition, rather than with a multiplication by 10 and an addition for
+// every digit. 10^19 is exact as an `f64`, so is every chunk as a `Double`, and so the
+// value is exact for as long as it fits into a `Double` at all; after that it takes one
+// rounding per chunk instead of one per digit.
+//
+// Leading zeros are not significant and are not counted toward `MAX_DIGITS`. Once that
+// many significant digits have been accumulated, the rest are dropped, but their count is
+// kept (so that the exponent still comes out right) along with a sticky flag that records
+// whether any of them were non-zero. That flag is turned into a single trailing `1` digit
+// when the number is finished, which is enough to break what would otherwise look like an
+// exact tie in the retained digits. Without the limit, the accumulated value would
+// overflow to infinity after a few hundred digits.
+struct Digits {
+    value: Double,
+    chunk: u64,
+    chunk_len: usize,
+    significant: i32,
+    dropped: i32,
+    sticky: bool,
+}
+
+impl Digits {
+    const fn new() -> Digits {
+        Digits {
+            value: Double::ZERO,
+            chunk: 0,
+            chunk_len: 0,
+            significant: 0,
+            dropped: 0,
+            sticky: false,
+        }
+    }
+
+    // Adds a digit to the end of the number.
+    #[inline]
+    const fn push(&mut self, d: u32) {
+        if self.significant < MAX_DIGITS {
+            if self.significant > 0 || d != 0 {
+                self.append(d);
+                self.significant += 1;
+            }
+        } else {
+            self.dropped = self.dropped.saturating_add(1);
+            self.sticky = self.sticky || d != 0;
+        }
+    }
+
+    // Adds a digit to the current chunk, folding the chunk into the value once it's full.
+    #[inline]
+    const fn append(&mut self, d: u32) {
+        self.chunk = self.chunk * 10 + d as u64;
+        self.chunk_len += 1;
+        if self.chunk_len == CHUNK_DIGITS {
+            self.flush();
+        }
+    }
+
+    // Folds whatever digits are in the current chunk into the value.
+    #[inline]
+    const fn flush(&mut self) {
+        if self.chunk_len > 0 {
+            self.value = self
+                .value
+                .mul_inner(c::POWERS_OF_TEN[self.chunk_len])
+                .add_inner(from_u64(self.chunk));
+            self.chunk = 0;
+            self.chunk_len = 0;
+        }
+    }
+
+    // Produces the final number, scaled by `10^exp` and negated if necessary. `exp` is the
+    // exponent that applies to the digits as pushed, without regard to any that were
+    // dropped.
+    const fn finish(self, exp: i32, negative: bool) -> Double {
+        let mut digits = self;
+        let mut exp = exp.saturating_add(digits.dropped);
+        if digits.sticky {
+            digits.append(1);
+            exp -= 1;
+        }
+        digits.flush();
+
+        let mut result = digits.value;
+        // Zero stays zero no matter the exponent; scaling it by an infinite power of ten
+        // would make it NaN instead
+        if exp != 0 && !result.is_zero() {
+            result = c::mul_pow10(result, exp);
+        }
+        if negative {
+            result = Double(-result.0, -result.1);
+        }
+        result
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::decimal::Decimal;
+    use crate::common::random::Rng;
 
     macro_rules! single {
         ($e:expr, $a:expr) => {
@@ -170,8 +532,12 @@ mod tests {
         s.parse().unwrap()
     }
 
+    fn parse_error(s: &str) -> ParseDoubleError {
+        s.parse::<Double>().unwrap_err()
+    }
+
     fn parse_err(s: &str) -> ErrorKind {
-        s.parse::<Double>().unwrap_err().kind
+        parse_error(s).kind
     }
 
     // error tests
@@ -180,22 +546,310 @@ mod tests {
             ErrorKind::Empty,
             parse_err("");
         double_sign:
-            ErrorKind::Invalid,
+            ErrorKind::InvalidDigit { position: 1 },
             parse_err("++2317");
         double_point:
-            ErrorKind::Invalid,
+            ErrorKind::InvalidDigit { position: 4 },
             parse_err("2.31.7");
         mid_sign:
-            ErrorKind::Invalid,
+            ErrorKind::InvalidDigit { position: 1 },
             parse_err("2-317");
         end_letter:
-            ErrorKind::Invalid,
+            ErrorKind::InvalidDigit { position: 6 },
             parse_err("2.317err");
         mid_letter:
-            ErrorKind::Invalid,
+            ErrorKind::InvalidDigit { position: 3 },
             parse_err("2.3j7");
     );
 
+    // malformed input tests
+    //
+    // Every one of these is also rejected by `f64`'s parser. They're here because earlier
+    // versions of the parser either silently ignored the malformed part or produced a value
+    // (usually zero) out of a string with no digits in it.
+    test_all_eq!(
+        bad_dangling_exp:
+            ErrorKind::Invalid,
+            parse_err("1.2e");
+        bad_dangling_exp_sign:
+            ErrorKind::Invalid,
+            parse_err("1.2e-");
+        bad_double_neg:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("--3");
+        bad_plus_neg:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("+-2");
+        bad_neg_plus:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("-+2");
+        bad_adjacent_points:
+            ErrorKind::InvalidDigit { position: 2 },
+            parse_err("1..5");
+        bad_leading_points:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("..5");
+        bad_double_exp:
+            ErrorKind::InvalidDigit { position: 3 },
+            parse_err("3e5e2");
+        bad_point_in_exp:
+            ErrorKind::InvalidDigit { position: 3 },
+            parse_err("3e5.2");
+        bad_double_exp_sign:
+            ErrorKind::InvalidDigit { position: 3 },
+            parse_err("3e--2");
+        bad_sign_after_point:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err(".-5");
+        bad_sign_after_underscore:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("_-5");
+        bad_trailing_sign:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("5-");
+        bad_sign_only:
+            ErrorKind::Invalid,
+            parse_err("-");
+        bad_plus_only:
+            ErrorKind::Invalid,
+            parse_err("+");
+        bad_point_only:
+            ErrorKind::Invalid,
+            parse_err(".");
+        bad_sign_point:
+            ErrorKind::Invalid,
+            parse_err("-.");
+        bad_exp_only:
+            ErrorKind::InvalidDigit { position: 0 },
+            parse_err("e5");
+        bad_point_exp:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err(".e5");
+        bad_sign_exp:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("-e5");
+        bad_underscore_only:
+            ErrorKind::Invalid,
+            parse_err("_");
+        bad_inner_space:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("1 2");
+        bad_exp_space:
+            ErrorKind::InvalidDigit { position: 2 },
+            parse_err("1e 2");
+        bad_hex:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("0x1f");
+        bad_exp_letter:
+            ErrorKind::InvalidDigit { position: 3 },
+            parse_err("1e5f");
+        bad_comma:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("1,5");
+        bad_inf_suffix:
+            ErrorKind::InvalidDigit { position: 0 },
+            parse_err("infinite");
+        bad_nan_suffix:
+            ErrorKind::InvalidDigit { position: 0 },
+            parse_err("nana");
+    );
+
+    // error position tests
+    //
+    // Positions are byte offsets into the string as it was given, before any trimming.
+    test_all_eq!(
+        position_after_whitespace:
+            ErrorKind::InvalidDigit { position: 3 },
+            parse_err("  1x5");
+        position_in_exp:
+            ErrorKind::InvalidDigit { position: 4 },
+            parse_err("1e1_x");
+        position_after_multibyte:
+            ErrorKind::InvalidDigit { position: 3 },
+            parse_err("\u{a0}1x");
+        whitespace_only:
+            ErrorKind::Empty,
+            parse_err("  \t ");
+    );
+
+    // exponent overflow tests
+    test_all_eq!(
+        exp_overflow:
+            ErrorKind::ExponentOverflow,
+            parse_err("1e2147483648");
+        exp_overflow_neg:
+            ErrorKind::ExponentOverflow,
+            parse_err("1e-2147483649");
+        exp_overflow_long:
+            ErrorKind::ExponentOverflow,
+            parse_err("1e99_999_999_999_999_999_999");
+        exp_overflow_zero:
+            ErrorKind::ExponentOverflow,
+            parse_err("0e99999999999");
+        exp_overflow_then_letter:
+            ErrorKind::InvalidDigit { position: 13 },
+            parse_err("1e99999999999x");
+    );
+
+    // Numbers out of range parse as infinity or zero, as they do with `f64`
+    test_all_exact!(
+        range_overflow:
+            Double::INFINITY,
+            parse("1e400");
+        range_overflow_neg:
+            Double::NEG_INFINITY,
+            parse("-1e400");
+        range_overflow_max_exp:
+            Double::INFINITY,
+            parse("1e2147483647");
+        range_underflow:
+            Double::ZERO,
+            parse("1e-400");
+        range_underflow_neg:
+            Double::NEG_ZERO,
+            parse("-1e-400");
+        range_underflow_min_exp:
+            Double::ZERO,
+            parse("1e-2147483648");
+        range_zero_max_exp:
+            Double::ZERO,
+            parse("0e2147483647");
+    );
+
+    // accepted syntax tests
+    test_all_exact!(
+        underscores:
+            parse("1000000.000001"),
+            parse("1_000_000.000_001");
+        underscores_exp:
+            parse("1e10"),
+            parse("1e1_0");
+        underscores_exp_sign:
+            parse("1e-10"),
+            parse("1e-_10");
+        leading_plus:
+            parse("2.5"),
+            parse("+2.5");
+        plus_exp:
+            parse("2.5e3"),
+            parse("2.5E+3");
+        upper_inf:
+            Double::INFINITY,
+            parse("INF");
+        plus_inf:
+            Double::INFINITY,
+            parse("+inf");
+        mixed_infinity:
+            Double::INFINITY,
+            parse("+InFiNiTy");
+        neg_infinity:
+            Double::NEG_INFINITY,
+            parse("-Infinity");
+        mixed_nan:
+            Double::NAN,
+            parse("NaN");
+        plus_nan:
+            Double::NAN,
+            parse("+nan");
+    );
+
+    test!(neg_nan: {
+        let x = parse("-NAN");
+        assert!(x.is_nan());
+        assert!(x.is_sign_negative());
+    });
+
+    test!(error_display: {
+        assert_eq!(
+            parse_error("").to_string(),
+            "cannot parse double-double from empty string"
+        );
+        assert_eq!(parse_error("-").to_string(), "invalid double-double literal");
+        assert_eq!(
+            parse_error(" 1x").to_string(),
+            "invalid character at position 2 in double-double literal"
+        );
+        assert_eq!(
+            parse_error("1e9999999999").to_string(),
+            "exponent too large in double-double literal"
+        );
+        // It's a real `Error`, so it can be boxed and passed along with `?`
+        let boxed: Box<dyn std::error::Error> = Box::new(parse_error("1.5.2"));
+        assert_eq!(
+            boxed.to_string(),
+            "invalid character at position 3 in double-double literal"
+        );
+    });
+
+    // Strings already written the way that `Display` writes them come back unchanged
+    test!(round_trip: {
+        let strings = [
+            "0",
+            "-0",
+            "-42",
+            "3.14159",
+            "-0.000001",
+            "1000000.000001",
+            "123456789012345678901234567890",
+            "0.1",
+            "2.718281828459045235360287471353",
+            "602214076000000000000000",
+            "inf",
+            "-inf",
+            "NaN",
+        ];
+        for &s in strings.iter() {
+            assert_eq!(parse(s).to_string(), s);
+        }
+    });
+
+    // Values at the ends of the exponent range, where the power of ten does the most work.
+    // The reference parses the same digits written out in full, which doesn't involve a
+    // power of ten at all. Formatting the result in scientific notation and parsing it
+    // again has to give back the same value.
+    test!(extreme_exponents: {
+        let mantissas = ["1", "5", "1.5", "9.87654321", "3.14159265358979323846"];
+        for &m in mantissas.iter() {
+            let (int, frac) = m.split_once('.').unwrap_or((m, ""));
+            for &e in [-300i32, -295, -290, 290, 295, 300, 305, 307].iter() {
+                let x = parse(&format!("{}e{}", m, e));
+                let expanded = if e >= 0 {
+                    format!("{}{}{}", int, frac, "0".repeat(e as usize - frac.len()))
+                } else {
+                    format!("0.{}{}{}", "0".repeat(-e as usize - 1), int, frac)
+                };
+                let reference = Double::from_string_radix_any(&expanded, 10).unwrap();
+                assert!((x - reference).abs() <= reference * dd!(1e-30), "{}e{}", m, e);
+                exact!(x, parse(&format!("{:e}", x)));
+            }
+        }
+    });
+
+    // Anything accepted by this parser must also be accepted by `f64`'s parser (aside from
+    // the underscores, which are a deliberate extension, and surrounding whitespace, which
+    // is trimmed). Strings are generated pseudo-randomly from an alphabet of every
+    // character that means something to either parser.
+    test!(accepted_by_f64: {
+        const ALPHABET: &[u8] = b"0123456789.+-e_";
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        for _ in 0..20_000 {
+            let seed = rng.next_u64();
+            let len = (seed % 7) as usize + 1;
+            let s: String = (0..len)
+                .map(|i| ALPHABET[((seed >> (i * 4 + 8)) % ALPHABET.len() as u64) as usize] as char)
+                .collect();
+
+            if s.parse::<Double>().is_ok() {
+                let stripped = s.replace('_', "");
+                assert!(
+                    stripped.parse::<f64>().is_ok(),
+                    "\"{}\" was accepted but is not a valid f64",
+                    s
+                );
+            }
+        }
+    });
+
     // zero tests
     test_all_exact!(
         zero_int:
@@ -295,4 +949,366 @@ mod tests {
         let x = ((t - dd!(1)) / t) * dd!(10).powi(-100);
         near!(x, s);
     });
+
+    // from_parts tests
+
+    // Bitwise equality, so that the sign of zero counts and NaN equals NaN
+    fn same(a: Double, b: Double) -> bool {
+        a[0].to_bits() == b[0].to_bits() && a[1].to_bits() == b[1].to_bits()
+    }
+
+    // Parses the string that from_parts should be equivalent to
+    fn glued(int_part: i64, fraction: &str, exp: i32) -> Double {
+        if fraction.is_empty() {
+            parse(&format!("{}e{}", int_part, exp))
+        } else {
+            parse(&format!("{}.{}e{}", int_part, fraction, exp))
+        }
+    }
+
+    fn parts_err(int_part: i64, fraction: &str) -> ErrorKind {
+        Double::from_parts(int_part, fraction, 0).unwrap_err().kind
+    }
+
+    test_all_eq!(
+        parts_err_point:
+            ErrorKind::InvalidDigit { position: 1 },
+            parts_err(1, "2.5");
+        parts_err_sign:
+            ErrorKind::InvalidDigit { position: 0 },
+            parts_err(1, "-25");
+        parts_err_space:
+            ErrorKind::InvalidDigit { position: 0 },
+            parts_err(1, " 25");
+        parts_err_exp:
+            ErrorKind::InvalidDigit { position: 2 },
+            parts_err(1, "25e3");
+        parts_err_letter:
+            ErrorKind::InvalidDigit { position: 1 },
+            parts_err(1, "2x5");
+    );
+
+    test!(parts_macro: {
+        exact!(dd!("3.14159"), dd!(integer: 3, fraction: "14159"));
+        exact!(dd!("-3.14159e-7"), dd!(integer: -3, fraction: "14159", exponent: -7));
+        assert!(dd!(integer: 3, fraction: "1.4").is_nan());
+    });
+
+    // try_dd! tests
+    fn try_err(r: Result<Double, ParseDoubleError>) -> ErrorKind {
+        r.unwrap_err().kind
+    }
+
+    test_all_eq!(
+        try_macro_literal:
+            Ok(dd!("3.14159")),
+            try_dd!("3.14159");
+        try_macro_number:
+            Ok(dd!(-7)),
+            try_dd!(-7);
+        try_macro_float:
+            Ok(dd!(0.1)),
+            try_dd!(0.1);
+        try_macro_string:
+            Ok(dd!("-2.5e-3")),
+            try_dd!(String::from("-2.5e-3"));
+        try_macro_string_ref:
+            Ok(dd!("1e10")),
+            try_dd!(&String::from("1e10"));
+        try_macro_expr:
+            Ok(dd!(6)),
+            try_dd!(2u64 * 3);
+        try_macro_neg_expr:
+            Ok(dd!(-3)),
+            try_dd!(-dd!(3));
+        try_macro_parts:
+            Ok(dd!("-3.14159e-7")),
+            try_dd!(integer: -3, fraction: "14159", exponent: -7);
+        try_macro_bad_literal:
+            ErrorKind::InvalidDigit { position: 3 },
+            try_err(try_dd!("1.2x3"));
+        try_macro_bad_string:
+            ErrorKind::InvalidDigit { position: 0 },
+            try_err(try_dd!(String::from("not a number")));
+        try_macro_bad_str:
+            ErrorKind::Invalid,
+            try_err(try_dd!("1e"));
+        try_macro_empty:
+            ErrorKind::Empty,
+            try_err(try_dd!(""));
+        try_macro_bad_parts:
+            ErrorKind::InvalidDigit { position: 1 },
+            try_err(try_dd!(integer: 3, fraction: "1.4"));
+    );
+
+    test!(try_macro_const: {
+        const BAD: Result<Double, ParseDoubleError> = try_dd!("0x12");
+        const GOOD: Result<Double, ParseDoubleError> = try_dd!(-1.5);
+        assert_eq!(try_err(BAD), ErrorKind::InvalidDigit { position: 1 });
+        assert_eq!(GOOD, Ok(dd!(-1.5)));
+    });
+
+    test!(try_macro_nan: {
+        // "nan" is a number, not an error, which is what tells it apart from a typo
+        assert!(try_dd!("nan").unwrap().is_nan());
+        assert!(dd!("nan").is_nan());
+        assert!(dd!("nam").is_nan());
+    });
+
+    // The fraction adds to the magnitude, away from zero, just as it does in "-3.14"
+    test!(parts_negative: {
+        let x = Double::from_parts(-3, "14", 0).unwrap();
+        assert!(same(x, parse("-3.14")));
+        assert!(x < dd!(-3));
+        assert!(same(Double::from_parts(-3, "", 0).unwrap(), parse("-3")));
+        assert!(same(-Double::from_parts(0, "14", 0).unwrap(), parse("-0.14")));
+        assert!(same(Double::from_parts(0, "", 0).unwrap(), Double::ZERO));
+        assert!(same(
+            Double::from_parts(i64::MIN, "5", 0).unwrap(),
+            parse("-9223372036854775808.5")
+        ));
+    });
+
+    // The exponent applies to the whole number, after the fraction is attached
+    test!(parts_exponent: {
+        assert!(same(Double::from_parts(3, "14", 2).unwrap(), dd!(314)));
+        assert!(same(Double::from_parts(314, "", -2).unwrap(), parse("3.14")));
+        assert!(same(Double::from_parts(1, "5", 300).unwrap(), parse("1.5e300")));
+        assert!(same(Double::from_parts(15, "", -301).unwrap(), parse("1.5e-300")));
+        assert!(same(Double::from_parts(0, "000", 400).unwrap(), Double::ZERO));
+        let tiny = Double::from_parts(-1, "5", -400).unwrap();
+        assert!(same(tiny, glued(-1, "5", -400)));
+        assert!(tiny.is_zero() && tiny.is_sign_negative());
+    });
+
+    test!(parts_long_fraction: {
+        let threes = "3".repeat(3000);
+        let x = Double::from_parts(0, &threes, 0).unwrap();
+        assert!(same(x, glued(0, &threes, 0)));
+        near!(dd!(1) / dd!(3), x);
+
+        let zeros = format!("{}1", "0".repeat(2000));
+        let y = Double::from_parts(1, &zeros, 2001).unwrap();
+        assert!(same(y, glued(1, &zeros, 2001)));
+        exact!(dd!(10).powi(2001 - 2000) * dd!(10).powi(2000) + dd!(1), y);
+    });
+
+    // 1 + 2⁻⁶⁰ + 2⁻¹¹³ and 1 + 2⁻⁶⁰ + 2⁻¹¹² + 2⁻¹¹³ lie exactly halfway between two
+    // adjacent `Double`s. The parser isn't exact to the last bit of the last component, so
+    // this doesn't pin down which way they round. It does pin down that from_parts and
+    // parsing agree on them, that they're as close as the precision allows, and that a
+    // non-zero digit far past the end of the precision doesn't ever make them smaller.
+    test!(parts_halfway: {
+        for frac in &[
+            concat!(
+                "000000000000000000867361737988403643502459460057746021939522129246365926",
+                "90508241076940976199693977832794189453125",
+            ),
+            concat!(
+                "000000000000000000867361737988403836095453898781331327537316387739097780",
+                "71524723230822928599081933498382568359375",
+            ),
+        ] {
+            let tie = Double::from_parts(1, frac, 0).unwrap();
+            assert!(same(tie, glued(1, frac, 0)));
+            near!(Double(1.0, 2f64.powi(-60)), tie);
+
+            let sticky = format!("{}{}1", frac, "0".repeat(500));
+            let x = Double::from_parts(1, &sticky, 0).unwrap();
+            assert!(same(x, glued(1, &sticky, 0)));
+            assert!(x >= tie);
+        }
+    });
+
+    // Pseudo-random integer parts, fractions, and exponents, with the fractions built mostly
+    // out of long runs of 0s and 9s (plus some 4s and 5s) to hit carries and near-ties
+    test!(parts_equivalence: {
+        const INTS: [i64; 8] = [0, 1, -1, 9, -10, i64::MAX, i64::MIN, 4_503_599_627_370_497];
+        const FRAC_DIGITS: &[u8] = b"0000999945123";
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..5_000 {
+            let r = rng.next_u64();
+            let int_part = if r % 3 == 0 {
+                INTS[(r >> 8) as usize % INTS.len()]
+            } else {
+                (rng.next_u64() as i64) >> (r % 64)
+            };
+            let len = match r % 5 {
+                0 => 0,
+                1 => (r >> 16) as usize % 20,
+                2 => (r >> 16) as usize % 60,
+                _ => (r >> 16) as usize % 200,
+            };
+            let fraction: String = (0..len)
+                .map(|_| FRAC_DIGITS[rng.next_u64() as usize % FRAC_DIGITS.len()] as char)
+                .collect();
+            let exp = if r % 7 == 0 {
+                rng.range(-350, 350)
+            } else {
+                rng.range(-10, 10)
+            };
+
+            let x = Double::from_parts(int_part, &fraction, exp).unwrap();
+            let y = glued(int_part, &fraction, exp);
+            assert!(
+                same(x, y),
+                "from_parts({}, \"{}\", {}) = {:?}, but parsing gives {:?}",
+                int_part,
+                fraction,
+                exp,
+                x,
+                y
+            );
+        }
+    });
+
+    // The way that digits were accumulated before they were taken in chunks: one
+    // multiplication by ten and one addition for every digit. `digits` is an integer with no
+    // leading zeros.
+    fn per_digit(digits: &str, exp: i32) -> Double {
+        let mut value = Double::ZERO;
+        let mut exp = exp;
+        let mut sticky = false;
+        for (i, d) in digits.bytes().enumerate() {
+            if (i as i32) < MAX_DIGITS {
+                value = value * Double(10.0, 0.0) + Double::from((d - b'0') as u32);
+            } else {
+                exp += 1;
+                sticky |= d != b'0';
+            }
+        }
+        if sticky {
+            value = value * Double(10.0, 0.0) + Double::ONE;
+            exp -= 1;
+        }
+        c::mul_pow10(value, exp)
+    }
+
+    // The relative error of a parsed number, measured exactly
+    fn parse_error_of(s: &str, x: Double) -> f64 {
+        let approx = Decimal::from_f64(x.0).add(&Decimal::from_f64(x.1));
+        (Decimal::parse(s).add(&approx.neg()).to_f64() / x.0).abs()
+    }
+
+    fn random_digits(rng: &mut Rng, len: usize) -> String {
+        (0..len)
+            .map(|i| {
+                let d = rng.next_u64() % 10;
+                (b'0' + if i == 0 { d % 9 + 1 } else { d } as u8) as char
+            })
+            .collect()
+    }
+
+    // Every step of both accumulations is exact for as long as the digits fit into a
+    // `Double` (through 31 digits), so up to there they can't disagree at all
+    test!(chunks_match_per_digit: {
+        let mut rng = Rng::new(0x853c_49e6_748f_ea9b);
+        for _ in 0..3_000 {
+            let len = (rng.next_u64() % 31) as usize + 1;
+            let digits = random_digits(&mut rng, len);
+            let exp = rng.range(-330, 310);
+            let x = parse(&format!("{}e{}", digits, exp));
+            let y = per_digit(&digits, exp);
+            assert!(same(x, y), "{}e{}: {:?} != {:?}", digits, exp, x, y);
+        }
+    });
+
+    // Past that, the two round differently, but the chunked accumulation rounds once per
+    // chunk rather than once per digit and shouldn't come out any worse
+    test!(chunks_at_least_as_accurate: {
+        let mut rng = Rng::new(0xda3e_39cb_94b9_5bdb);
+        let mut chunked = 0.0;
+        let mut old = 0.0;
+        for _ in 0..500 {
+            let len = (rng.next_u64() % 49) as usize + 32;
+            let digits = random_digits(&mut rng, len);
+            // Keeps the number between 10^-250 and 10^250, where none of the components
+            // are subnormal
+            let exp = rng.range(-250, 250) - len as i32;
+            let s = format!("{}e{}", digits, exp);
+            let x = parse_error_of(&s, parse(&s));
+            assert!(x < 3.0 * Double::EPSILON.0, "{}: error {:e}", s, x);
+            chunked += x;
+            old += parse_error_of(&s, per_digit(&digits, exp));
+        }
+        assert!(chunked <= old, "total error {:e}, was {:e}", chunked, old);
+    });
+
+    fn repr_err(s: &str) -> ErrorKind {
+        Double::from_repr(s).unwrap_err().kind
+    }
+
+    // Parsing what `Debug` writes, in either form, gives back the same components bit for
+    // bit
+    test!(repr_round_trip: {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        let mut values = vec![
+            Double::ZERO,
+            Double::NEG_ZERO,
+            Double::INFINITY,
+            Double::NEG_INFINITY,
+            Double::PI,
+            -Double::E,
+            Double::MAX,
+            Double::MIN_POSITIVE,
+            Double(1.0, 5e-324),
+            Double(-3.0, -2.5e-320),
+            Double(5e-324, 0.0),
+            Double(-f64::MIN_POSITIVE, 0.0),
+        ];
+        for i in 0..2000 {
+            let x = Double::ONE / Double::from(rng.next_f64() + 0.5);
+            // Far enough from the subnormal range that every component is normal
+            let x = x.ldexp((i % 1700) - 800);
+            values.push(if i % 2 == 0 { x } else { -x });
+        }
+        for &x in &values {
+            let bits = |y: Double| y.to_array().map(f64::to_bits);
+            let short = Double::from_repr(&format!("{:?}", x)).unwrap();
+            let pretty = Double::from_repr(&format!("{:#?}", x)).unwrap();
+            assert!(bits(short) == bits(x), "{:?} came back as {:?}", x, short);
+            assert!(bits(pretty) == bits(x), "{:#?} came back as {:#?}", x, pretty);
+        }
+        assert!(Double::from_repr(&format!("{:?}", Double::NAN)).unwrap().is_nan());
+        assert!(Double::from_repr(&format!("{:#?}", -Double::NAN)).unwrap().is_nan());
+    });
+
+    test!(repr_lenient: {
+        assert!(Double::from_repr("  Double ( 1e0 ,\n\t2e-17 )  ").unwrap() == Double(1.0, 2e-17));
+        assert!(Double::from_repr("Double(1e0, 0e0,)").unwrap() == Double::ONE);
+        assert!(Double::from_repr("Double(1e0, 1e0)").unwrap().to_array() == [2.0, 0.0]);
+    });
+
+    test_all_eq!(
+        repr_empty:
+            ErrorKind::Empty,
+            repr_err(" \n ");
+        repr_wrong_type:
+            ErrorKind::InvalidDigit { position: 0 },
+            repr_err("Quad(1e0, 0e0)");
+        repr_no_paren:
+            ErrorKind::InvalidDigit { position: 6 },
+            repr_err("Double[1e0]");
+        repr_too_few:
+            ErrorKind::InvalidDigit { position: 10 },
+            repr_err("Double(1e0)");
+        repr_too_many:
+            ErrorKind::InvalidDigit { position: 17 },
+            repr_err("Double(1e0, 0e0, 0e0)");
+        repr_bad_component:
+            ErrorKind::InvalidDigit { position: 12 },
+            repr_err("Double(1e0, 1x0)");
+        repr_missing_component:
+            ErrorKind::InvalidDigit { position: 7 },
+            repr_err("Double(, 0e0)");
+        repr_trailing:
+            ErrorKind::InvalidDigit { position: 17 },
+            repr_err("Double(1e0, 0e0) x");
+        repr_unclosed:
+            ErrorKind::Invalid,
+            repr_err("Double(1e0, ");
+        repr_name_only:
+            ErrorKind::Invalid,
+            repr_err("Double");
+    );
 }
@@ -0,0 +1,477 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::strict as s;
+use crate::double::common as c;
+use crate::double::Double;
+
+// The smallest argument that the Stirling series is evaluated at. Smaller arguments are
+// shifted up to at least this with the recurrence Γ(x + 1) = xΓ(x).
+const STIRLING_MIN: f64 = 20.0;
+
+// Coefficients of the Stirling series for ln Γ(x), B<sub>2k</sub> / 2k(2k - 1) for k in
+// [1, 16], where B<sub>2k</sub> is a Bernoulli number. The series diverges, but for
+// x >= 20 the first term left out is less than 10^-34.
+const STIRLING: [Double; 16] = [
+    Double(8.333333333333333e-2, 4.625929269271485e-18),
+    Double(-2.777777777777778e-3, 1.0601087908747154e-19),
+    Double(7.936507936507937e-4, 6.883823317368282e-22),
+    Double(-5.952380952380953e-4, 5.36938218754726e-20),
+    Double(8.417508417508417e-4, 3.6870174889237694e-20),
+    Double(-1.9175269175269176e-3, 1.0675702776872475e-19),
+    Double(6.41025641025641e-3, 2.2240044563805217e-19),
+    Double(-2.9550653594771242e-2, 4.861760957508855e-19),
+    Double(1.7964437236883057e-1, -6.401600482710946e-19),
+    Double(-1.3924322169059011e0, 1.5837056989230303e-17),
+    Double(1.3402864044168393e1, -6.154114101993966e-16),
+    Double(-1.5684828462600203e2, 9.391823141715389e-15),
+    Double(2.1931033333333335e3, -1.3339255626002948e-13),
+    Double(-3.610877125372499e4, 5.897583353514365e-13),
+    Double(6.91472268851313e5, 2.5585296305158e-11),
+    Double(-1.5238221539407415e7, -8.76774522490625e-10),
+];
+
+// ln(2π) / 2
+const LN_SQRT_2PI: Double = Double(9.189385332046728e-1, -3.8782941580672414e-17);
+
+impl Double {
+    /// Computes the gamma function, Γ(*x*), where *x* is this `Double`.
+    ///
+    /// For positive integers, Γ(*n*) is (*n* - 1)!, and those results are exact as long as
+    /// the factorial fits in a `Double`. Otherwise the result comes from the Stirling series
+    /// for ln Γ, after arguments less than 20 are shifted up to at least 20 with the
+    /// recurrence Γ(*x* + 1) = *x*Γ(*x*). Negative arguments use the reflection formula
+    /// Γ(*x*)Γ(1 - *x*) = π / sin(π*x*). The result is precise to about 29 digits, except
+    /// for results so small that their lower components are subnormal.
+    ///
+    /// The result is too large to represent once *x* exceeds about 171.6, and this
+    /// function then returns [`INFINITY`]. The pole at zero returns [`INFINITY`] or
+    /// [`NEG_INFINITY`] depending on the sign of the zero, as the limit from that side
+    /// would, but there's no single limit at the poles at the negative integers, so they
+    /// return [`NAN`], as does [`NEG_INFINITY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(5).gamma() == dd!(24));
+    ///
+    /// let x = dd!(0.5).gamma();
+    /// let diff = (x - Double::PI.sqrt()).abs();
+    /// assert!(diff < dd!(1e-29));
+    ///
+    /// assert!(dd!(-1).gamma().is_nan());
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn gamma(self) -> Double {
+        match self.pre_gamma() {
+            Some(r) => s::guard("Double::gamma", &[self.0], r),
+            None => {
+                if self.0.abs() < 1.0 {
+                    // Dividing by x on its own, rather than as part of the shift or the
+                    // reflection, keeps all of its digits even when it's tiny. Division by
+                    // a subnormal doesn't work, so x is scaled up (exactly) for the
+                    // division and the result is scaled back afterwards.
+                    let g = (self + Double::ONE).gamma();
+                    c::scale(g / c::scale(self, 64), 64)
+                } else if self.is_sign_negative() {
+                    let a = Double::ONE - self;
+                    let sin = sin_pi(self);
+                    if a.0 < 170.0 {
+                        Double::PI / (sin * a.gamma())
+                    } else {
+                        // Γ(1 - x) overflows, even though Γ(x) may still be well within
+                        // range, so the division is done with logarithms instead
+                        let r = ((Double::PI / sin.abs()).ln() - a.ln_gamma()).exp();
+                        if sin.is_sign_negative() {
+                            -r
+                        } else {
+                            r
+                        }
+                    }
+                } else if c::is_integer(self) && self.0 <= 171.0 {
+                    let mut r = Double::ONE;
+                    for i in 2..self.0 as i32 {
+                        r = r.mul_i32(i);
+                    }
+                    r
+                } else {
+                    let (z, p) = shift(self);
+                    ln_stirling(z).exp() / p
+                }
+            }
+        }
+    }
+
+    /// Computes the natural logarithm of the absolute value of the gamma function,
+    /// ln |Γ(*x*)|, where *x* is this `Double`.
+    ///
+    /// Γ(*x*) overflows for *x* much past 171, but its logarithm doesn't, so this is the
+    /// function to use for ratios of gamma functions with large arguments, such as
+    /// binomial coefficients and the beta function. The sign of Γ(*x*) is lost; it's
+    /// negative when *x* is between -2*k* - 1 and -2*k* for some integer *k* >= 0.
+    ///
+    /// This is calculated in the same way as [`gamma`], without the final exponential.
+    /// The result is precise to about 29 digits, but near 1 and 2, where ln Γ(*x*) is zero,
+    /// that precision is relative to the size of the terms that cancel to give the result
+    /// rather than to the result itself.
+    ///
+    /// The poles at zero and the negative integers return [`INFINITY`], as do both
+    /// infinities.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(100.5).ln_gamma();
+    /// let expected = dd!("361.43554046777762155525191270252076");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-28));
+    /// ```
+    ///
+    /// [`gamma`]: #method.gamma
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn ln_gamma(self) -> Double {
+        match self.pre_ln_gamma() {
+            Some(r) => s::guard("Double::ln_gamma", &[self.0], r),
+            None => {
+                if self.0.abs() < 1.0 {
+                    // As in gamma, a subnormal x is scaled up to take its logarithm
+                    let x = self.abs();
+                    let ln = if x.0 < f64::MIN_POSITIVE {
+                        c::scale(x, 64).ln() - Double::LN_2.mul_i32(64)
+                    } else {
+                        x.ln()
+                    };
+                    (self + Double::ONE).ln_gamma() - ln
+                } else if self.is_sign_negative() {
+                    let a = Double::ONE - self;
+                    (Double::PI / sin_pi(self).abs()).ln() - a.ln_gamma()
+                } else {
+                    let (z, p) = shift(self);
+                    ln_stirling(z) - p.ln()
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn pre_gamma(&self) -> Option<Double> {
+        if self.is_nan() || *self == Double::NEG_INFINITY {
+            Some(Double::NAN)
+        } else if self.is_infinite() || self.0 > 171.625 {
+            Some(Double::INFINITY)
+        } else if (1.0 / self.0).is_infinite() {
+            // Includes both zeros. Γ(x) is about 1/x near zero.
+            if self.is_sign_negative() {
+                Some(Double::NEG_INFINITY)
+            } else {
+                Some(Double::INFINITY)
+            }
+        } else if self.is_sign_negative() && c::is_integer(*self) {
+            Some(Double::NAN)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_ln_gamma(&self) -> Option<Double> {
+        if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.is_infinite() || (c::is_integer(*self) && *self <= Double::ZERO) {
+            // Both infinities and all of the poles
+            Some(Double::INFINITY)
+        } else if *self == Double::ONE || *self == Double(2.0, 0.0) {
+            Some(Double::ZERO)
+        } else {
+            None
+        }
+    }
+}
+
+// Computes ln Γ(z) with the Stirling series, for z >= STIRLING_MIN.
+//
+//      ln Γ(z) = (z - 1/2) ln z - z + ln(2π) / 2 + Σ B_2k / 2k(2k - 1)z^(2k - 1)
+fn ln_stirling(z: Double) -> Double {
+    let series = Double::polyval(&STIRLING, z.recip().sqr()) / z;
+    (z - Double(0.5, 0.0)) * z.ln() - z + LN_SQRT_2PI + series
+}
+
+// Shifts an x of at least 1 up to z = x + n >= STIRLING_MIN, returning z along with the
+// product x(x + 1)...(x + n - 1), so that Γ(x) = Γ(z) / product. If x is already large
+// enough, the product is 1.
+fn shift(x: Double) -> (Double, Double) {
+    let mut z = x;
+    let mut p = Double::ONE;
+    while z.0 < STIRLING_MIN {
+        p *= z;
+        z += Double::ONE;
+    }
+    (z, p)
+}
+
+// Computes sin(πx) for a non-integer x < -1. Multiplying x by π directly would round
+// away the digits that matter near the integers, so x is first split into an integer n and
+// a remainder r with |r| <= 1/2, which is exact, and then sin(πx) = (-1)^n sin(πr).
+fn sin_pi(x: Double) -> Double {
+    let n = x.round();
+    let r = (Double::PI * (x - n)).sin();
+    if c::is_odd(n) {
+        -r
+    } else {
+        r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::reference::references;
+
+    test!(constants_match_references: {
+        let refs = references();
+        c::assert_reference(&refs, "LN_SQRT_2PI", LN_SQRT_2PI);
+        for (k, &x) in STIRLING.iter().enumerate() {
+            c::assert_reference(&refs, &format!("STIRLING[{}]", k), x);
+        }
+    });
+
+    // gamma tests
+    test_all_prec!(
+        gamma_half:
+            dd!("1.77245385090551602729816748334114518"),
+            dd!(0.5).gamma(),
+            29;
+        gamma_half_sqrt_pi:
+            Double::PI.sqrt(),
+            dd!(0.5).gamma(),
+            29;
+        gamma_3_halves:
+            dd!("0.886226925452758013649083741670572591"),
+            dd!(1.5).gamma(),
+            29;
+        gamma_5_halves:
+            dd!("1.32934038817913702047362561250585889"),
+            dd!(2.5).gamma(),
+            29;
+        gamma_eighth:
+            dd!("7.53394159879761190469922984121513362"),
+            dd!(0.125).gamma(),
+            29;
+        gamma_3_75:
+            dd!("4.422988410460250562887839188700433"),
+            dd!(3.75).gamma(),
+            29;
+        gamma_10_25:
+            dd!("639232.598779576794283758401876084967"),
+            dd!(10.25).gamma(),
+            29;
+        gamma_27_375:
+            dd!("1381951088941485409909683567.61864603"),
+            dd!(27.375).gamma(),
+            29;
+        gamma_100_5:
+            dd!("9.32096310408271660834910980914191044e+156"),
+            dd!(100.5).gamma(),
+            29;
+        gamma_150_25:
+            dd!("1.33215077619516348430117503937064314e+261"),
+            dd!(150.25).gamma(),
+            29;
+        gamma_171_5:
+            dd!("9.48336756682479933625340546920495159e+307"),
+            dd!(171.5).gamma(),
+            29;
+        gamma_neg_half:
+            dd!("-3.54490770181103205459633496668229037"),
+            dd!(-0.5).gamma(),
+            29;
+        gamma_neg_3_halves:
+            dd!("2.36327180120735470306422331112152691"),
+            dd!(-1.5).gamma(),
+            29;
+        gamma_neg_2_25:
+            dd!("-1.74281486572825265085027314256055542"),
+            dd!(-2.25).gamma(),
+            29;
+        gamma_neg_10_125:
+            dd!("-1.68483126205251745621688232788989548e-6"),
+            dd!(-10.125).gamma(),
+            29;
+        gamma_neg_100_5:
+            dd!("-3.35369081980767864220809969271459241e-159"),
+            dd!(-100.5).gamma(),
+            29;
+        gamma_tiny:
+            dd!("8589934591.42278433521360841138215454"),
+            dd!(1).ldexp(-33).gamma(),
+            29;
+        gamma_neg_tiny:
+            dd!("-8589934592.57721566501667413261977599"),
+            dd!(-1).ldexp(-33).gamma(),
+            29;
+        gamma_subnormal:
+            dd!("8.98846567431157953864652595394512367e+307"),
+            Double(2f64.powi(-1023), 0.0).gamma(),
+            29;
+    );
+    test_all_exact!(
+        gamma_one:
+            Double::ONE,
+            Double::ONE.gamma();
+        gamma_two:
+            Double::ONE,
+            dd!(2).gamma();
+        gamma_five:
+            dd!(24),
+            dd!(5).gamma();
+        gamma_21:
+            dd!(2432902008176640000u64),
+            dd!(21).gamma();
+        gamma_37:
+            dd!("371993326789901217467999448150835200000000"),
+            dd!(37).gamma();
+        gamma_zero:
+            Double::INFINITY,
+            Double::ZERO.gamma();
+        gamma_neg_zero:
+            Double::NEG_INFINITY,
+            Double::NEG_ZERO.gamma();
+        gamma_neg_one:
+            Double::NAN,
+            dd!(-1).gamma();
+        gamma_neg_large_int:
+            Double::NAN,
+            dd!(-1e20).gamma();
+        gamma_inf:
+            Double::INFINITY,
+            Double::INFINITY.gamma();
+        gamma_neg_inf:
+            Double::NAN,
+            Double::NEG_INFINITY.gamma();
+        gamma_nan:
+            Double::NAN,
+            Double::NAN.gamma();
+        gamma_overflow:
+            Double::INFINITY,
+            dd!(172).gamma();
+        gamma_overflow_tiny:
+            Double::INFINITY,
+            Double(1e-320, 0.0).gamma();
+        gamma_underflow:
+            Double::NEG_ZERO,
+            dd!(-180.5).gamma();
+    );
+    test_prec!(
+        gamma_171:
+            dd!("7.25741561530799896739672821112926311e+306"),
+            dd!(171).gamma(),
+            29
+    );
+    test!(gamma_recurrence: {
+        for &x in &[0.3, 1.7, 4.1, 19.5, 38.6, 39.5, 64.2, -0.6, -3.3, -41.7] {
+            let x = dd!(x);
+            prec!(x * x.gamma(), (x + Double::ONE).gamma(), 28);
+        }
+    });
+    test!(gamma_reflection: {
+        for &x in &[0.3, 0.5, 0.9, 2.7, 13.2] {
+            let x = dd!(x);
+            let expected = Double::PI / (Double::PI * x).sin();
+            prec!(expected, x.gamma() * (Double::ONE - x).gamma(), 28);
+        }
+    });
+
+    // ln_gamma tests
+    test_all_prec!(
+        ln_gamma_half:
+            dd!("0.572364942924700087071713675676529356"),
+            dd!(0.5).ln_gamma(),
+            29;
+        ln_gamma_3_75:
+            dd!("1.48681557859341705554058180144420503"),
+            dd!(3.75).ln_gamma(),
+            29;
+        ln_gamma_100_5:
+            dd!("361.435540467777621555251912702520763"),
+            dd!(100.5).ln_gamma(),
+            29;
+        ln_gamma_1000_5:
+            dd!("5908.67417584867748868387473406262488"),
+            dd!(1000.5).ln_gamma(),
+            29;
+        ln_gamma_1e10:
+            dd!("220258509288.810581470041923123460127"),
+            dd!(1e10).ln_gamma(),
+            29;
+        ln_gamma_2_200:
+            dd!("2.21161976898267353633709268127168299e+62"),
+            dd!(1).ldexp(200).ln_gamma(),
+            29;
+        ln_gamma_neg_2_25:
+            dd!("0.555501545020647470593575893540250059"),
+            dd!(-2.25).ln_gamma(),
+            29;
+        ln_gamma_neg_10_125:
+            dd!("-13.2938451403895384842367850898231917"),
+            dd!(-10.125).ln_gamma(),
+            29;
+        ln_gamma_neg_1000_5:
+            dd!("-5914.43770111685187660969934469090828"),
+            dd!(-1000.5).ln_gamma(),
+            29;
+        ln_gamma_tiny:
+            dd!("22.8738569584109984633867464048103046"),
+            dd!(1).ldexp(-33).ln_gamma(),
+            29;
+        ln_gamma_subnormal:
+            dd!("741.667483199141481076438369960248928"),
+            Double(f64::from_bits(1 << 4), 0.0).ln_gamma(),
+            29;
+        ln_gamma_3_quarters:
+            dd!("0.2032809514312953714814329718624297"),
+            dd!(0.75).ln_gamma(),
+            29;
+        ln_gamma_5_quarters:
+            dd!("-0.0982718364218131614638538026966358402"),
+            dd!(1.25).ln_gamma(),
+            28;
+    );
+    test_all_exact!(
+        ln_gamma_one:
+            Double::ZERO,
+            Double::ONE.ln_gamma();
+        ln_gamma_two:
+            Double::ZERO,
+            dd!(2).ln_gamma();
+        ln_gamma_zero:
+            Double::INFINITY,
+            Double::ZERO.ln_gamma();
+        ln_gamma_neg_zero:
+            Double::INFINITY,
+            Double::NEG_ZERO.ln_gamma();
+        ln_gamma_neg_three:
+            Double::INFINITY,
+            dd!(-3).ln_gamma();
+        ln_gamma_inf:
+            Double::INFINITY,
+            Double::INFINITY.ln_gamma();
+        ln_gamma_neg_inf:
+            Double::INFINITY,
+            Double::NEG_INFINITY.ln_gamma();
+        ln_gamma_nan:
+            Double::NAN,
+            Double::NAN.ln_gamma();
+    );
+    test!(ln_gamma_matches_gamma: {
+        for &x in &[0.1, 0.7, 3.2, 17.9, 45.5, 120.3, -0.4, -1.2, -7.6, -50.1] {
+            let x = dd!(x);
+            prec!(x.gamma().abs().ln(), x.ln_gamma(), 27);
+        }
+    });
+}
@@ -0,0 +1,378 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::hex as h;
+use crate::double::common as c;
+use crate::double::Double;
+use crate::error::ParseDoubleError;
+
+impl Double {
+    /// Writes the exact value of the `Double` as a string of hexadecimal floats, one for
+    /// each component.
+    ///
+    /// Each component is written in the form of C's `%a` format: `0x`, a leading `1` (or
+    /// `0` for zero and subnormals), the rest of the mantissa in hexadecimal without
+    /// trailing zeros, and a `p` followed by a power-of-two exponent in decimal. The
+    /// components are separated by ` + ` or ` - ` according to the sign of the second one,
+    /// and the first one is preceded by a `-` if it's negative. Since no decimal
+    /// conversion is involved, every bit of every finite `Double` is preserved, including
+    /// the signs of zeros and subnormal second components, and [`from_hex_str`] turns the
+    /// string back into exactly the same `Double`.
+    ///
+    /// Infinities are written as `inf` or `-inf`, which parse as [`INFINITY`] and
+    /// [`NEG_INFINITY`], and NaNs are written as `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let s = Double::PI.to_hex_string();
+    /// assert!(s == "0x1.921fb54442d18p+1 + 0x1.1a62633145c07p-53");
+    /// assert!(Double::from_hex_str(&s).unwrap() == Double::PI);
+    ///
+    /// assert!(dd!(-0.75).to_hex_string() == "-0x1.8p-1 + 0x0p+0");
+    /// ```
+    ///
+    /// [`from_hex_str`]: #method.from_hex_str
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    pub fn to_hex_string(self) -> String {
+        h::to_hex_string(&[self.0, self.1])
+    }
+
+    /// Parses a string of hexadecimal floats, as written by [`to_hex_string`], into a
+    /// `Double`.
+    ///
+    /// The string must have one or two components separated by `+` or `-`, with the first
+    /// optionally preceded by a sign. Whitespace is allowed around the separators and
+    /// trimmed from the ends. Each component is `0x`, hexadecimal digits with at most one
+    /// point among them, and a `p` followed by an optionally signed decimal exponent. Case
+    /// doesn't matter. The words `inf`, `infinity`, and `nan`, with or without a sign, are
+    /// also accepted on their own.
+    ///
+    /// Every component must be exactly representable as an `f64`; nothing is rounded.
+    /// Components that are already normalized (which includes anything written by
+    /// [`to_hex_string`]) produce exactly those components, bit for bit. Components that
+    /// aren't are normalized in the same way as [`from_components`], and a missing second
+    /// component is zero.
+    ///
+    /// Failure will return a [`ParseDoubleError`]. Its kind will be:
+    ///
+    /// * [`Empty`] if the string is empty or contains only whitespace;
+    /// * [`InvalidDigit`] if there is a character that can't appear where it does,
+    ///   including a third component, along with the byte offset of that character in `s`;
+    /// * [`ExponentOverflow`] if an exponent doesn't fit into an `i32`;
+    /// * [`Invalid`] if something is missing at the end of the string, as in `"0x1.8"`, or
+    ///   if a component has too many bits or is too large or too small to be an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::from_hex_str("0x1.8p+1 - 0x1p-60").unwrap();
+    /// assert!(x.as_components() == (3.0, -(2f64.powi(-60))));
+    ///
+    /// // Not normalized, so the components are added together
+    /// let y = Double::from_hex_str("0x1p+0 + 0x1p+0").unwrap();
+    /// assert!(y == dd!(2));
+    ///
+    /// // 2^-1075 is too small to be an f64
+    /// assert!(Double::from_hex_str("0x1p-1075").is_err());
+    /// ```
+    ///
+    /// [`to_hex_string`]: #method.to_hex_string
+    /// [`from_components`]: #method.from_components
+    /// [`ParseDoubleError`]: error/struct.ParseDoubleError.html
+    /// [`Empty`]: error/enum.ErrorKind.html#variant.Empty
+    /// [`InvalidDigit`]: error/enum.ErrorKind.html#variant.InvalidDigit
+    /// [`ExponentOverflow`]: error/enum.ErrorKind.html#variant.ExponentOverflow
+    /// [`Invalid`]: error/enum.ErrorKind.html#variant.Invalid
+    pub fn from_hex_str(s: &str) -> Result<Double, ParseDoubleError> {
+        let mut a = [0.0; 2];
+        h::parse(s, &mut a).map_err(|kind| ParseDoubleError { kind })?;
+        Ok(c::from_components(a[0], a[1]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::random::Rng;
+    use crate::error::ErrorKind;
+
+    // Bitwise equality, so that the sign of zero counts and NaN equals NaN
+    fn same(a: Double, b: Double) -> bool {
+        a.to_bits() == b.to_bits()
+    }
+
+    fn parse(s: &str) -> Double {
+        Double::from_hex_str(s).unwrap()
+    }
+
+    fn parse_err(s: &str) -> ErrorKind {
+        Double::from_hex_str(s).unwrap_err().kind
+    }
+
+    fn round_trip(x: Double) {
+        let s = x.to_hex_string();
+        let y = parse(&s);
+        assert!(same(x, y), "{:?} became {:?} through {}", x, y, s);
+    }
+
+    test_all_eq!(
+        hex_pi:
+            "0x1.921fb54442d18p+1 + 0x1.1a62633145c07p-53",
+            Double::PI.to_hex_string();
+        hex_neg:
+            "-0x1.921fb54442d18p+1 - 0x1.1a62633145c07p-53",
+            (-Double::PI).to_hex_string();
+        hex_one:
+            "0x1p+0 + 0x0p+0",
+            Double::ONE.to_hex_string();
+        hex_zero:
+            "0x0p+0 + 0x0p+0",
+            Double::ZERO.to_hex_string();
+        hex_neg_zero:
+            "-0x0p+0 + 0x0p+0",
+            Double::NEG_ZERO.to_hex_string();
+        hex_neg_zero_second:
+            "0x1p+0 - 0x0p+0",
+            Double(1.0, -0.0).to_hex_string();
+        hex_subnormal:
+            "0x0.0000000000001p-1022 + 0x0p+0",
+            Double(5e-324, 0.0).to_hex_string();
+        hex_subnormal_second:
+            "0x1p-1000 + 0x0.0000000004p-1022",
+            Double(2f64.powi(-1000), f64::from_bits(1 << 14)).to_hex_string();
+        hex_max:
+            "0x1.fffffffffffffp+1023 + 0x1.fffffffffffffp+969",
+            Double::MAX.to_hex_string();
+        hex_inf:
+            "inf",
+            Double::INFINITY.to_hex_string();
+        hex_neg_inf:
+            "-inf",
+            Double::NEG_INFINITY.to_hex_string();
+        hex_nan:
+            "NaN",
+            Double::NAN.to_hex_string();
+        hex_nan_second:
+            "NaN",
+            Double(1.0, f64::NAN).to_hex_string();
+    );
+
+    test!(round_trip_constants: {
+        for &x in &[
+            Double::EPSILON,
+            Double::MIN,
+            Double::MIN_POSITIVE,
+            Double::MAX,
+            Double::MAX_SAFE_INTEGER,
+            Double::INFINITY,
+            Double::NEG_INFINITY,
+            Double::ZERO,
+            Double::NEG_ZERO,
+            Double::ONE,
+            Double::NEG_ONE,
+            Double::PI,
+            Double::TAU,
+            Double::FRAC_PI_2,
+            Double::FRAC_PI_3,
+            Double::FRAC_PI_4,
+            Double::FRAC_PI_6,
+            Double::FRAC_PI_8,
+            Double::FRAC_PI_16,
+            Double::FRAC_3_PI_2,
+            Double::FRAC_3_PI_4,
+            Double::FRAC_5_PI_4,
+            Double::FRAC_7_PI_4,
+            Double::FRAC_1_PI,
+            Double::FRAC_2_PI,
+            Double::FRAC_2_SQRT_PI,
+            Double::SQRT_2,
+            Double::FRAC_1_SQRT_2,
+            Double::E,
+            Double::LOG2_10,
+            Double::LOG2_E,
+            Double::LOG10_2,
+            Double::LOG10_E,
+            Double::LN_2,
+            Double::LN_10,
+        ] {
+            round_trip(x);
+            if x.is_finite() {
+                round_trip(-x);
+            }
+        }
+        assert!(parse(&Double::NAN.to_hex_string()).is_nan());
+    });
+
+    test!(round_trip_random: {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        for _ in 0..10_000 {
+            // Any finite first component, and a second component anywhere from a full ulp
+            // of the first down to far below it, which makes plenty of subnormals when the
+            // first is small
+            let a = f64::from_bits(rng.next_u64() % 0x7ff0_0000_0000_0000);
+            let r = f64::from_bits(rng.next_u64() >> 12 | 0x3ff0_0000_0000_0000) - 1.5;
+            let b = a * r * 2f64.powi(-52 - (rng.next_u64() % 80) as i32);
+            let x = c::from_components(a, b);
+            round_trip(x);
+            round_trip(-x);
+        }
+    });
+
+    test!(round_trip_subnormals: {
+        round_trip(Double(5e-324, 0.0));
+        round_trip(Double(-5e-324, -0.0));
+        round_trip(Double(1e-300, 5e-324));
+        round_trip(Double(2f64.powi(-1000), -(f64::from_bits(1 << 14))));
+        round_trip(Double(f64::from_bits(0x000f_ffff_ffff_ffff), 0.0));
+    });
+
+    test_all_exact!(
+        parse_one_component:
+            dd!(3),
+            parse("0x1.8p+1");
+        parse_upper:
+            dd!(3),
+            parse("0X1.8P+1");
+        parse_no_lead:
+            dd!(3),
+            parse("0x.cp+2");
+        parse_no_point:
+            dd!(3),
+            parse("0x3p0");
+        parse_trailing_point:
+            dd!(3),
+            parse("0x3.p0");
+        parse_long:
+            dd!(1),
+            parse("0x1.000000000000000000000000000p+0");
+        parse_long_int:
+            dd!(1),
+            parse("0x10000000000000000000000000000p-112");
+        parse_leading_zeros:
+            dd!(1),
+            parse("0x00000000000000000000000000001p+0");
+        parse_no_spaces:
+            Double(1.0, 2f64.powi(-60)),
+            parse("0x1p+0+0x1p-60");
+        parse_spaces:
+            Double(1.0, -(2f64.powi(-60))),
+            parse("  0x1p+0   -\t0x1p-60  ");
+        parse_plus:
+            Double(1.0, 2f64.powi(-60)),
+            parse("+0x1p+0 + 0x1p-60");
+        parse_smallest:
+            Double(5e-324, 0.0),
+            parse("0x1p-1074");
+        parse_smallest_subnormal_form:
+            Double(5e-324, 0.0),
+            parse("0x0.0000000000001p-1022");
+        parse_largest:
+            Double(f64::MAX, 0.0),
+            parse("0x1.fffffffffffffp+1023");
+        parse_huge_zero:
+            Double::ZERO,
+            parse("0x0p+999999");
+
+        parse_renormalize:
+            dd!(2),
+            parse("0x1p+0 + 0x1p+0");
+        parse_renormalize_order:
+            Double(1.0, 2f64.powi(-60)),
+            parse("0x1p-60 + 0x1p+0");
+
+        parse_inf:
+            Double::INFINITY,
+            parse("inf");
+        parse_neg_inf:
+            Double::NEG_INFINITY,
+            parse("-Infinity");
+        parse_nan:
+            Double::NAN,
+            parse("nan");
+        parse_neg_nan:
+            Double::NAN,
+            parse("-NaN");
+    );
+
+    test!(parse_signed_zeros: {
+        assert!(same(parse("-0x0p+0"), Double::NEG_ZERO));
+        assert!(same(parse("-0x0p+0 - 0x0p+0"), Double(-0.0, -0.0)));
+        assert!(same(parse("0x1p+0 - 0x0p+0"), Double(1.0, -0.0)));
+    });
+
+    test_all_eq!(
+        parse_empty:
+            ErrorKind::Empty,
+            parse_err("");
+        parse_blank:
+            ErrorKind::Empty,
+            parse_err("   ");
+        parse_decimal:
+            ErrorKind::InvalidDigit { position: 0 },
+            parse_err("1.5");
+        parse_no_x:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("01p+0");
+        parse_no_digits:
+            ErrorKind::InvalidDigit { position: 2 },
+            parse_err("0xp+0");
+        parse_point_only:
+            ErrorKind::InvalidDigit { position: 3 },
+            parse_err("0x.p+0");
+        parse_two_points:
+            ErrorKind::InvalidDigit { position: 5 },
+            parse_err("0x1.8.p+0");
+        parse_bad_digit:
+            ErrorKind::InvalidDigit { position: 5 },
+            parse_err("0x1.8g+0");
+        parse_offset:
+            ErrorKind::InvalidDigit { position: 7 },
+            parse_err("  0x1.8g+0");
+        parse_no_exp:
+            ErrorKind::Invalid,
+            parse_err("0x1.8");
+        parse_empty_exp:
+            ErrorKind::Invalid,
+            parse_err("0x1.8p");
+        parse_bad_exp:
+            ErrorKind::InvalidDigit { position: 7 },
+            parse_err("0x1.8p+x");
+        parse_exp_overflow:
+            ErrorKind::ExponentOverflow,
+            parse_err("0x1p+99999999999");
+        parse_three_components:
+            ErrorKind::InvalidDigit { position: 17 },
+            parse_err("0x1p+0 + 0x1p-60 + 0x1p-120");
+        parse_no_separator:
+            ErrorKind::InvalidDigit { position: 7 },
+            parse_err("0x1p+0 0x1p-60");
+        parse_double_sign:
+            ErrorKind::InvalidDigit { position: 9 },
+            parse_err("0x1p+0 + -0x1p-60");
+        parse_dangling_separator:
+            ErrorKind::Invalid,
+            parse_err("0x1p+0 +");
+        parse_too_many_bits:
+            ErrorKind::Invalid,
+            parse_err("0x1.00000000000008p+0");
+        parse_too_many_bits_long:
+            ErrorKind::Invalid,
+            parse_err("0x1.00000000000000000000001p+0");
+        parse_too_large:
+            ErrorKind::Invalid,
+            parse_err("0x1p+1024");
+        parse_too_small:
+            ErrorKind::Invalid,
+            parse_err("0x1p-1075");
+        parse_subnormal_too_many_bits:
+            ErrorKind::Invalid,
+            parse_err("0x1.8p-1074");
+        parse_bad_word:
+            ErrorKind::InvalidDigit { position: 0 },
+            parse_err("infinite");
+    );
+}
@@ -8,6 +8,7 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::strict as s;
 use crate::double::common as c;
 use crate::double::Double;
 
@@ -74,7 +75,7 @@ impl Double {
     /// ```
     pub fn sinh(self) -> Double {
         match self.pre_sinh() {
-            Some(r) => r,
+            Some(r) => s::guard("Double::sinh", &[self.0], r),
             None => {
                 if self.abs().0 > 0.05 {
                     let a = self.exp();
@@ -118,7 +119,7 @@ impl Double {
     /// ```
     pub fn cosh(self) -> Double {
         match self.pre_cosh() {
-            Some(r) => r,
+            Some(r) => s::guard("Double::cosh", &[self.0], r),
             None => {
                 let a = self.exp();
                 c::mul_pwr2(a + a.recip(), 0.5)
@@ -141,7 +142,7 @@ impl Double {
     /// ```
     pub fn tanh(self) -> Double {
         match self.pre_tanh() {
-            Some(r) => r,
+            Some(r) => s::guard("Double::tanh", &[self.0], r),
             None => {
                 if self.abs().0 > 0.05 {
                     let a = self.exp();
@@ -170,8 +171,25 @@ impl Double {
     /// ```
     pub fn asinh(self) -> Double {
         match self.pre_asinh() {
-            Some(r) => r,
-            None => (self + (self.sqr() + Double::ONE).sqrt()).ln(),
+            Some(r) => s::guard("Double::asinh", &[self.0], r),
+            None => {
+                // asinh x = ln(x + sqrt(x^2 + 1)) loses everything to cancellation for
+                // small x, so that's rewritten as ln_1p(x + x^2 / (1 + sqrt(1 + x^2))). The
+                // function is odd, so this is done with |x| and the sign is put back after.
+                let a = self.abs();
+                let r = if a.0 > 1e20 {
+                    // x^2 would overflow, but here asinh x = ln 2x to full precision
+                    a.ln() + Double::LN_2
+                } else {
+                    let a2 = a.sqr();
+                    (a + a2 / (Double::ONE + (Double::ONE + a2).sqrt())).ln_1p()
+                };
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
         }
     }
 
@@ -193,15 +211,27 @@ impl Double {
     /// [`NAN`]: #associatedconstant.NAN
     pub fn acosh(self) -> Double {
         match self.pre_acosh() {
-            Some(r) => r,
-            None => (self + (self.sqr() - Double::ONE).sqrt()).ln(),
+            Some(r) => s::guard("Double::acosh", &[self.0], r),
+            None => {
+                if self.0 > 1e20 {
+                    // x^2 would overflow, but here acosh x = ln 2x to full precision
+                    self.ln() + Double::LN_2
+                } else {
+                    // acosh x = ln(x + sqrt(x^2 - 1)) loses precision near 1, where x^2 - 1
+                    // cancels. With t = x - 1 (which is exact that close to 1), it's
+                    // ln_1p(t + sqrt(t(t + 2))) instead.
+                    let t = self - Double::ONE;
+                    (t + (t * (t + Double::from(2.0))).sqrt()).ln_1p()
+                }
+            }
         }
     }
 
     /// Calculates the inverse hyperbolic tangent (tanh<sup>-1</sup>) of the `Double`.
     ///
-    /// The domain of the function is (-1, 1) and the range is (-∞, ∞). Any argument whose
-    /// absolute value is greater than or equal to 1 will result in [`NAN`].
+    /// The domain of the function is [-1, 1] and the range is [-∞, ∞]. 1 and -1 result in
+    /// [`INFINITY`] and [`NEG_INFINITY`] respectively, and any argument whose absolute
+    /// value is greater than 1 will result in [`NAN`].
     ///
     /// # Examples
     /// ```
@@ -213,11 +243,25 @@ impl Double {
     /// assert!(diff < dd!(1e-30));
     /// ```
     ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
     /// [`NAN`]: #associatedconstant.NAN
     pub fn atanh(self) -> Double {
         match self.pre_atanh() {
-            Some(r) => r,
-            None => c::mul_pwr2(((Double::ONE + self) / (Double::ONE - self)).ln(), 0.5),
+            Some(r) => s::guard("Double::atanh", &[self.0], r),
+            None => {
+                // atanh x = ln((1 + x) / (1 - x)) / 2, but that quotient is so close to 1 for
+                // small x that its logarithm loses precision. ln_1p(2x / (1 - x)) / 2 is the
+                // same thing without the problem. The function is odd, so this is done with
+                // |x| and the sign is put back after.
+                let a = self.abs();
+                let r = c::mul_pwr2((c::mul_pwr2(a, 2.0) / (Double::ONE - a)).ln_1p(), 0.5);
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
         }
     }
 
@@ -235,7 +279,9 @@ impl Double {
         if self.is_nan() {
             Some((Double::NAN, Double::NAN))
         } else if self.is_zero() {
-            Some((Double::ZERO, Double::ONE))
+            Some((*self, Double::ONE))
+        } else if self.is_infinite() {
+            Some((*self, Double::INFINITY))
         } else {
             None
         }
@@ -272,8 +318,10 @@ impl Double {
         if self.is_nan() {
             Some(Double::NAN)
         } else if self.is_zero() {
-            Some(Double::ZERO)
-        } else if self.is_infinite() {
+            Some(*self)
+        } else if self.0.abs() > 40.0 {
+            // This includes infinity. Past 40, 1 - |tanh x| (about 2e^(-2|x|)) is too small
+            // to show up in a `Double`, and exp(x) would eventually overflow anyway.
             Some(self.signum())
         } else {
             None
@@ -310,7 +358,15 @@ impl Double {
 
     #[inline]
     fn pre_atanh(&self) -> Option<Double> {
-        if self.abs() >= Double::ONE {
+        if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.is_zero() {
+            Some(*self)
+        } else if *self == Double::ONE {
+            Some(Double::INFINITY)
+        } else if *self == Double::NEG_ONE {
+            Some(Double::NEG_INFINITY)
+        } else if self.abs() > Double::ONE {
             Some(Double::NAN)
         } else {
             None
@@ -638,6 +694,18 @@ mod tests {
         asinh_150:
             dd!("5.703793585582131557697502799400447"),
             dd!(150).asinh();
+        asinh_tiny:
+            dd!("1e-20"),
+            dd!("1e-20").asinh();
+        asinh_neg_tiny:
+            dd!("-1e-20"),
+            dd!("-1e-20").asinh();
+        asinh_large:
+            dd!("461.21016577936908211301552305833101809"),
+            dd!("1e200").asinh();
+        asinh_neg_large:
+            dd!("-461.21016577936908211301552305833101809"),
+            dd!("-1e200").asinh();
     );
     test_all_prec!(
         asinh_neg_140:
@@ -691,10 +759,18 @@ mod tests {
         acosh_150:
             dd!("5.7037713633599001905278554895391333"),
             dd!(150).acosh();
+        acosh_near_one:
+            dd!("0.0000013486991523485067976218947732603534909"),
+            (Double::ONE + dd!(2).powi(-40)).acosh();
+        acosh_large:
+            dd!("461.21016577936908211301552305833101809"),
+            dd!("1e200").acosh();
     );
     test_all_prec!(
         acosh_small:
-            dd!("0.0044721322282280021231284466331917651"),
+            // acosh(dd!("1.00001")), not acosh(1.00001): the parsed argument is a bit off
+            // from 1.00001, and near 1 that makes a difference in the 28th digit
+            dd!("0.004472132228228002123128446631813685705012"),
             dd!("1.00001").acosh(),
             28;
     );
@@ -754,6 +830,18 @@ mod tests {
         atanh_neg_99:
             dd!("-2.6466524123622461977050606459342783"),
             dd!("-0.99").atanh();
+        atanh_tiny:
+            dd!("1e-20"),
+            dd!("1e-20").atanh();
+        atanh_neg_tiny:
+            dd!("-1e-20"),
+            dd!("-1e-20").atanh();
+        atanh_near_one:
+            dd!("10.743781298446321652259018145054382551"),
+            (Double::ONE - dd!(2).powi(-30)).atanh();
+        atanh_near_neg_one:
+            dd!("-10.743781298446321652259018145054382551"),
+            (Double::NEG_ONE + dd!(2).powi(-30)).atanh();
     );
     test_all_exact!(
         atanh_pi:
@@ -777,5 +865,14 @@ mod tests {
         atanh_nan:
             Double::NAN,
             Double::NAN.atanh();
+        atanh_one:
+            Double::INFINITY,
+            Double::ONE.atanh();
+        atanh_neg_one:
+            Double::NEG_INFINITY,
+            Double::NEG_ONE.atanh();
+        atanh_past_one:
+            Double::NAN,
+            (Double::ONE + Double::EPSILON).atanh();
     );
 }
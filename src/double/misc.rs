@@ -3,7 +3,9 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::rounding as r;
 use crate::common::utils as u;
+use crate::double::common as c;
 use crate::double::Double;
 use std::f64;
 use std::num::FpCategory;
@@ -43,9 +45,10 @@ impl Double {
     pub fn floor(self) -> Double {
         let hi = self.0.floor();
 
-        if (hi - self.0).abs() < f64::EPSILON {
+        if hi - self.0 == 0.0 {
             let (a, b) = u::renorm2(hi, self.1.floor());
-            Double(a, b)
+            // A zero result keeps the sign of the number, as it does with `f64`
+            Double(if a == 0.0 { 0f64.copysign(self.0) } else { a }, b)
         } else {
             Double(hi, 0.0)
         }
@@ -68,9 +71,10 @@ impl Double {
     pub fn ceil(self) -> Double {
         let hi = self.0.ceil();
 
-        if (hi - self.0).abs() < f64::EPSILON {
+        if hi - self.0 == 0.0 {
             let (a, b) = u::renorm2(hi, self.1.ceil());
-            Double(a, b)
+            // See `floor`
+            Double(if a == 0.0 { 0f64.copysign(self.0) } else { a }, b)
         } else {
             Double(hi, 0.0)
         }
@@ -79,6 +83,10 @@ impl Double {
     /// Returns the nearest integer value to the `Double`. Half-way cases are rounded away
     /// from `0.0`, per the behavior of `f64`'s `round` method.
     ///
+    /// The whole number is rounded, not just its first component, so a number just below or
+    /// above a half-way point rounds the way that it should even when its first component
+    /// is exactly on that point.
+    ///
     /// # Examples
     /// ```
     /// # use qd::dd;
@@ -89,20 +97,37 @@ impl Double {
     /// assert!(f.round() == dd!(3));
     /// assert!(g.round() == dd!(4));
     /// assert!(h.round() == dd!(-3));
+    ///
+    /// // Just below 2.5, though its first component is 2.5 exactly
+    /// let x = dd!(2.5) - dd!(1e-20);
+    /// assert!(x[0] == 2.5 && x.round() == dd!(2));
     /// ```
-    #[inline]
     pub fn round(self) -> Double {
-        let hi = self.0.round();
-
-        if (hi - self.0).abs() < f64::EPSILON {
-            let lo = self.1.round();
-            let (a, b) = u::renorm2(hi, lo);
-            Double(a, b)
-        } else if ((hi - self.0).abs() - 0.5).abs() < f64::EPSILON && self.1 < 0.0 {
-            Double(hi - 1.0, 0.0)
-        } else {
-            Double(hi, 0.0)
-        }
+        let mut p = [self.0, self.1];
+        r::round_to_integer(&mut p, false);
+        c::from_components(p[0], p[1])
+    }
+
+    /// Returns the nearest integer value to the `Double`. Half-way cases are rounded to the
+    /// even integer, per the behavior of `f64`'s `round_ties_even` method.
+    ///
+    /// Like [`round`], this rounds the whole number, so only a number that's exactly
+    /// half-way between two integers is a tie.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// assert!(dd!(2.5).round_ties_even() == dd!(2));
+    /// assert!(dd!(3.5).round_ties_even() == dd!(4));
+    /// assert!(dd!(-2.5).round_ties_even() == dd!(-2));
+    /// assert!((dd!(2.5) + dd!(1e-20)).round_ties_even() == dd!(3));
+    /// ```
+    ///
+    /// [`round`]: #method.round
+    pub fn round_ties_even(self) -> Double {
+        let mut p = [self.0, self.1];
+        r::round_to_integer(&mut p, true);
+        c::from_components(p[0], p[1])
     }
 
     /// Returns the integer part of the `Double`.
@@ -144,6 +169,50 @@ impl Double {
         self - self.trunc()
     }
 
+    /// Splits the `Double` into its integer and fractional parts, returned in that order.
+    ///
+    /// The integer part is the same as [`trunc`], and the fractional part is what's left
+    /// over, so both have the sign of the `Double` and the fractional part is in (-1, 1).
+    /// Unlike the subtraction in [`fract`], which can round away the last bit, the
+    /// fractional part is calculated exactly: it's made up of bits of the `Double` itself, so
+    /// it can always be represented, and the two parts add up exactly to the `Double`.
+    ///
+    /// The special cases are those of C's `modf`. A fractional part of zero has the sign of
+    /// the `Double`, so `-3` splits into -3 and -0. An infinity splits into itself and a zero
+    /// of the same sign, and NaN splits into two NaNs.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let (int, frac) = dd!(-3.75).modf();
+    /// assert!(int == dd!(-3));
+    /// assert!(frac == dd!(-0.75));
+    ///
+    /// let (int, frac) = Double::PI.modf();
+    /// assert!(int == dd!(3));
+    /// assert!((frac - (Double::PI - dd!(3))).abs() < dd!(1e-30));
+    ///
+    /// let (int, frac) = Double::NEG_INFINITY.modf();
+    /// assert!(int == Double::NEG_INFINITY);
+    /// assert!(frac.is_zero() && frac.is_sign_negative());
+    /// ```
+    ///
+    /// [`trunc`]: #method.trunc
+    /// [`fract`]: #method.fract
+    pub fn modf(self) -> (Double, Double) {
+        if self.is_nan() {
+            (Double::NAN, Double::NAN)
+        } else if self.is_infinite() {
+            (self, Double::ZERO.copysign(self))
+        } else {
+            // The integer part is exact, so the difference is the exact sum of the
+            // components of both. A nonzero difference already has the right sign.
+            let int = self.trunc();
+            let frac = Double::from_sum(&[self.0, self.1, -int.0, -int.1]);
+            (int, frac.copysign(self))
+        }
+    }
+
     /// Returns a number that represents the sign of the `Double`.
     ///
     /// * `1.0` if the number is positive, including `+0.0` and [`INFINITY`]
@@ -172,6 +241,30 @@ impl Double {
         }
     }
 
+    /// Returns a `Double` with the magnitude of this one and the sign of `sign`.
+    ///
+    /// Only the sign changes. When it has to flip, every component is negated, so the
+    /// result is exactly the negation of the `Double`. The sign that's copied is the sign bit
+    /// of `sign`, so `sign` can be a signed zero or even a NaN. A NaN stays a NaN, taking
+    /// the new sign.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(3.5).copysign(dd!(-0.25)) == dd!(-3.5));
+    /// assert!(dd!(-3.5).copysign(Double::ZERO) == dd!(3.5));
+    /// assert!(Double::ONE.copysign(Double::NEG_ZERO) == Double::NEG_ONE);
+    /// assert!(Double::NAN.copysign(Double::NEG_ONE).is_sign_negative());
+    /// ```
+    #[inline]
+    pub fn copysign(self, sign: Double) -> Double {
+        if self.is_sign_negative() == sign.is_sign_negative() {
+            self
+        } else {
+            -self
+        }
+    }
+
     /// Returns the floating point category of the `Double`.
     ///
     /// The possible return values are the members of [`FpCategory`], as follows:
@@ -179,10 +272,13 @@ impl Double {
     /// * `FpCategory::Zero` if the number is ±0;
     /// * `FpCategory::Infinite` if the number is ±∞;
     /// * `FpCategory::Nan` if the number is not a number;
-    /// * `FpCategory::Subnormal` if the number is ±[`MIN_POSITIVE`] (numbers this small can
-    ///     be represented, but they lose some accuracy);
+    /// * `FpCategory::Subnormal` if the number's magnitude is less than [`MIN_POSITIVE`]
+    ///     (numbers this small can be represented, but they lose some accuracy);
     /// * `FpCategory::Normal` if the number is anything else.
     ///
+    /// The category is that of the first component, which is the number rounded to an
+    /// `f64`. The second component doesn't affect it, even when it's subnormal itself.
+    ///
     /// # Examples
     /// ```
     /// # use qd::{dd, Double};
@@ -235,7 +331,7 @@ impl Double {
     /// assert!(!Double::PI.is_zero());
     /// ```
     #[inline]
-    pub fn is_zero(self) -> bool {
+    pub const fn is_zero(self) -> bool {
         self.0 == 0.0
     }
 
@@ -252,7 +348,7 @@ impl Double {
     /// assert!(!dd!(7.0).is_sign_negative());
     /// ```
     #[inline]
-    pub fn is_sign_negative(self) -> bool {
+    pub const fn is_sign_negative(self) -> bool {
         self.0.is_sign_negative()
     }
 
@@ -269,7 +365,7 @@ impl Double {
     /// assert!(!dd!(-7.0).is_sign_positive());
     /// ```
     #[inline]
-    pub fn is_sign_positive(self) -> bool {
+    pub const fn is_sign_positive(self) -> bool {
         self.0.is_sign_positive()
     }
 
@@ -285,7 +381,7 @@ impl Double {
     /// assert!(!dd!(7.0).is_nan());
     /// ```
     #[inline]
-    pub fn is_nan(self) -> bool {
+    pub const fn is_nan(self) -> bool {
         self.0.is_nan()
     }
 
@@ -300,7 +396,7 @@ impl Double {
     /// assert!(!dd!(7.0).is_infinite());
     /// ```
     #[inline]
-    pub fn is_infinite(self) -> bool {
+    pub const fn is_infinite(self) -> bool {
         self.0.is_infinite()
     }
 
@@ -315,7 +411,7 @@ impl Double {
     /// assert!(dd!(7.0).is_finite());
     /// ```
     #[inline]
-    pub fn is_finite(self) -> bool {
+    pub const fn is_finite(self) -> bool {
         self.0.is_finite()
     }
 
@@ -338,11 +434,305 @@ impl Double {
     pub fn is_subnormal(self) -> bool {
         self.classify() == FpCategory::Subnormal
     }
+
+    /// Returns `true` if the components of the `Double` are normalized.
+    ///
+    /// A `Double` is the sum of its components, and it's normalized when each component is
+    /// no more than half an ULP of the one before it. Then the components don't overlap,
+    /// the first is the whole number rounded to an `f64`, and any zero components come
+    /// last. Only the first component may be infinite or NaN, and when it is, the rest are
+    /// zero.
+    ///
+    /// Every `Double` that comes out of this library is normalized, so this is mostly a
+    /// diagnostic for `Double`s that are put together from components with [`new`], which
+    /// doesn't check. The functions in this library assume that their arguments are
+    /// normalized and may return nonsense for ones that aren't.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::PI.is_normalized());
+    /// assert!((dd!(1) / dd!(3)).is_normalized());
+    /// assert!(Double::NAN.is_normalized());
+    ///
+    /// // Components that overlap, a zero out of place, and a NaN out of place
+    /// assert!(!Double::new(1.0, 1.0).is_normalized());
+    /// assert!(!Double::new(0.0, 1e-20).is_normalized());
+    /// assert!(!Double::new(1.0, f64::NAN).is_normalized());
+    /// ```
+    ///
+    /// [`new`]: #method.new
+    #[inline]
+    pub fn is_normalized(self) -> bool {
+        if self.0.is_finite() {
+            c::is_normalized(self.0, self.1)
+        } else {
+            self.1 == 0.0
+        }
+    }
+
+    /// Returns `true` if the `Double` is an integer with an absolute value no greater than
+    /// [`MAX_SAFE_INTEGER`].
+    ///
+    /// Every integer in that range is represented exactly, so arithmetic on safe integers
+    /// is exact as long as the result is also safe. Some larger integers, like
+    /// 2<sup>106</sup> + 1, are represented exactly as well, but they are not safe because
+    /// some of their neighbors are not.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(-42).is_safe_integer());
+    /// assert!(Double::MAX_SAFE_INTEGER.is_safe_integer());
+    /// assert!(!(Double::MAX_SAFE_INTEGER + dd!(1)).is_safe_integer());
+    /// assert!(!dd!(0.5).is_safe_integer());
+    /// assert!(!Double::INFINITY.is_safe_integer());
+    /// ```
+    ///
+    /// [`MAX_SAFE_INTEGER`]: #associatedconstant.MAX_SAFE_INTEGER
+    pub fn is_safe_integer(self) -> bool {
+        // In a normalized `Double`, the head has no fractional part unless the number is
+        // smaller than 2^52, and in that case the tail is too small to cancel it out. So
+        // the number is an integer only if both components are.
+        self.is_finite()
+            && self.0.fract() == 0.0
+            && self.1.fract() == 0.0
+            && self.abs() <= Double::MAX_SAFE_INTEGER
+    }
+
+    /// Creates a `Double` with exactly the value of an `i128`, as long as that value is
+    /// between -[`MAX_SAFE_INTEGER`] and [`MAX_SAFE_INTEGER`]. Otherwise, returns `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let n = (1i128 << 106) - 1;
+    /// let x = Double::checked_from_safe_integer(n).unwrap();
+    /// assert!(x == Double::MAX_SAFE_INTEGER - dd!(1));
+    ///
+    /// assert!(Double::checked_from_safe_integer(-(1 << 106)) == Some(-Double::MAX_SAFE_INTEGER));
+    /// assert!(Double::checked_from_safe_integer((1 << 106) + 1).is_none());
+    /// ```
+    ///
+    /// [`MAX_SAFE_INTEGER`]: #associatedconstant.MAX_SAFE_INTEGER
+    pub fn checked_from_safe_integer(n: i128) -> Option<Double> {
+        if n.unsigned_abs() > 1 << 106 {
+            None
+        } else {
+            // Casting rounds to the nearest `f64`, which leaves a difference of no more
+            // than 2^53. That difference is also an integer, so it's represented exactly
+            // and the two parts are already normalized.
+            let a = n as f64;
+            let b = (n - a as i128) as f64;
+            Some(Double(a, b))
+        }
+    }
+
+    /// Returns the smallest `Double` that's larger than this one.
+    ///
+    /// The neighbors of a `Double` aren't evenly spaced the way that the neighbors of an
+    /// `f64` are. A normalized `Double` is an `f64` head plus an `f64` tail of no more than
+    /// half the head's ULP, and the next one up usually has the same head and the next
+    /// `f64` up as its tail. When that tail would be too large for the head, the next
+    /// `Double` carries into the head instead. So the gap depends on the tail: a `Double`
+    /// with a tail of zero, like 1, is only 2<sup>-1074</sup> (the smallest subnormal
+    /// `f64`) from its neighbors, while one with a tail as large as it can be is roughly
+    /// 2<sup>-105</sup> times its own size from them. The gap is always a power of 2, and
+    /// [`ulp`] returns it.
+    ///
+    /// The next `Double` up from zero (of either sign) is that smallest subnormal `f64`,
+    /// about 4.94 &times; 10<sup>-324</sup>, which is far smaller than [`MIN_POSITIVE`].
+    /// The next one up from [`MAX`] is infinity, the next one up from negative infinity is
+    /// -[`MAX`], and positive infinity and NaN are returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let tiny = Double::new(f64::from_bits(1), 0.0);
+    /// assert!(Double::ZERO.next_up() == tiny);
+    /// assert!(Double::ONE.next_up() == Double::ONE + tiny);
+    ///
+    /// let x = dd!(1) / dd!(3);
+    /// assert!(x.next_up() > x && x.next_up().next_down() == x);
+    /// assert!(Double::MAX.next_up() == Double::INFINITY);
+    /// ```
+    ///
+    /// [`ulp`]: #method.ulp
+    /// [`MIN_POSITIVE`]: #associatedconstant.MIN_POSITIVE
+    /// [`MAX`]: #associatedconstant.MAX
+    pub fn next_up(self) -> Double {
+        if self.is_nan() || self == Double::INFINITY {
+            self
+        } else if self == Double::NEG_INFINITY {
+            -Double::MAX
+        } else {
+            let (parts, _) = u::next_up(&[self.0, self.1]);
+            if parts[0] == 0.0 {
+                // Only a negative number can have zero as its next number up, and zero
+                // keeps the sign of the number it came from, as it does for `f64`s
+                Double::NEG_ZERO
+            } else {
+                Double(parts[0], parts[1])
+            }
+        }
+    }
+
+    /// Returns the largest `Double` that's smaller than this one.
+    ///
+    /// This mirrors [`next_up`], so `x.next_down()` is the same as `-(-x).next_up()`. The
+    /// next `Double` down from zero is the negative of the smallest subnormal `f64`, the
+    /// next one down from -[`MAX`] is negative infinity, and negative infinity and NaN are
+    /// returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::PI;
+    /// assert!(x.next_down() < x && x.next_down().next_up() == x);
+    /// assert!(Double::ZERO.next_down() == -Double::ZERO.next_up());
+    /// assert!(Double::INFINITY.next_down() == Double::MAX);
+    /// ```
+    ///
+    /// [`next_up`]: #method.next_up
+    /// [`MAX`]: #associatedconstant.MAX
+    #[inline]
+    pub fn next_down(self) -> Double {
+        -(-self).next_up()
+    }
+
+    /// Returns the distance from this `Double` to the next larger one, [`next_up`].
+    ///
+    /// This is a power of 2, and it's set by the last nonzero component: it's that
+    /// component's ULP as an `f64`, or half of that, unless the tail is zero, in which case
+    /// it's the smallest subnormal `f64`. See [`next_up`] for why the spacing is so uneven.
+    /// For the spacing that the precision of a `Double` allows, rather than the spacing of
+    /// the values it can represent, multiply its absolute value by [`EPSILON`].
+    ///
+    /// The ULP of [`MAX`] and of either infinity is infinite, and the ULP of NaN is NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::ONE.ulp() == Double::new(f64::from_bits(1), 0.0));
+    ///
+    /// let x = dd!(1) / dd!(3);
+    /// assert!(x.ulp() == Double::new(2f64.powi(-108), 0.0));
+    /// assert!(x + x.ulp() == x.next_up());
+    /// ```
+    ///
+    /// [`next_up`]: #method.next_up
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    /// [`MAX`]: #associatedconstant.MAX
+    pub fn ulp(self) -> Double {
+        if self.is_nan() {
+            Double::NAN
+        } else if self.is_infinite() {
+            Double::INFINITY
+        } else {
+            let (parts, gap) = u::next_up(&[self.0, self.1]);
+            if parts[0].is_infinite() {
+                Double::INFINITY
+            } else {
+                Double(gap, 0.0)
+            }
+        }
+    }
+
+    /// Calculates the absolute value of the difference between this `Double` and another.
+    ///
+    /// This is the same as `(self - other).abs()`, so it's NaN if either argument is NaN
+    /// or if both are infinities of the same sign.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(3).abs_diff(dd!(5)) == dd!(2));
+    /// assert!(dd!(5).abs_diff(dd!(3)) == dd!(2));
+    /// assert!(Double::NAN.abs_diff(dd!(1)).is_nan());
+    /// ```
+    #[inline]
+    pub fn abs_diff(self, other: Double) -> Double {
+        (self - other).abs()
+    }
+
+    /// Returns the positive difference between this `Double` and another: `self - other` if
+    /// that's greater than zero, and zero otherwise.
+    ///
+    /// This is C's `fdim`, which is what the deprecated `f64::abs_sub` computes despite its
+    /// name; for the absolute value of the difference, use [`abs_diff`]. It's NaN if either
+    /// argument is NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(5).abs_sub(dd!(3)) == dd!(2));
+    /// assert!(dd!(3).abs_sub(dd!(5)) == Double::ZERO);
+    /// assert!(Double::NAN.abs_sub(dd!(1)).is_nan());
+    /// ```
+    ///
+    /// [`abs_diff`]: #method.abs_diff
+    #[inline]
+    pub fn abs_sub(self, other: Double) -> Double {
+        if self <= other {
+            Double::ZERO
+        } else {
+            self - other
+        }
+    }
+
+    /// Determines whether this `Double` and another are equal to within `ulps` units of
+    /// [`EPSILON`], relative to their size.
+    ///
+    /// The two are compared as if both were scaled by the same power of 2, the one that
+    /// brings the larger of them to between 1 and 2, so `ulps` means the same thing at
+    /// every magnitude: `x.approx_eq(y, n)` gives the same answer as
+    /// `x.ldexp(k).approx_eq(y.ldexp(k), n)` for any `k` that keeps both exact. That makes
+    /// this a better test for computed values than comparing `(x - y).abs()` to a fixed
+    /// tolerance, which is too loose for small numbers and too strict for large ones. An
+    /// `ulps` of 0 is exact equality.
+    ///
+    /// Zero has no size for the other number to be relative to, so a comparison with zero
+    /// measures the other number against 1 instead; it's equal to zero if its absolute
+    /// value is no more than `ulps` times [`EPSILON`]. Infinities are equal only to
+    /// infinities of the same sign, and NaN isn't equal to anything.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let third = dd!(1) / dd!(3);
+    /// assert!((third * dd!(3)).approx_eq(dd!(1), 1));
+    /// assert!(!(third * dd!(3)).approx_eq(dd!(1.01), 1000));
+    ///
+    /// // The tolerance scales with the numbers being compared
+    /// let big = dd!("1e300");
+    /// assert!((third * big).approx_eq(big / dd!(3), 2));
+    ///
+    /// assert!((Double::PI.sin()).approx_eq(Double::ZERO, 4));
+    /// assert!(!Double::NAN.approx_eq(Double::NAN, 4));
+    /// ```
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    pub fn approx_eq(self, other: Double, ulps: u32) -> bool {
+        let tolerance = Double::EPSILON * Double::from(ulps);
+        if self.is_nan() || other.is_nan() {
+            false
+        } else if self.is_infinite() || other.is_infinite() {
+            self == other
+        } else if self.is_zero() || other.is_zero() {
+            (self - other).abs() <= tolerance
+        } else {
+            // Scaling by a power of 2 is exact (aside from bits of the smaller number that
+            // are too small to matter), and unlike scaling the tolerance, it can't underflow
+            let e = -u::exponent(self.0.abs().max(other.0.abs()));
+            (self.ldexp(e) - other.ldexp(e)).abs() <= tolerance
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::random::Rng;
     use std::num::FpCategory::*;
 
     // abs tests
@@ -387,6 +777,12 @@ mod tests {
         floor_int:
             dd!(2),
             dd!(2).floor();
+        floor_tiny:
+            dd!(0),
+            Double(1e-40, -1e-57).floor();
+        floor_int_neg_tail:
+            dd!(4),
+            Double(5.0, -1e-40).floor();
 
         floor_zero:
             Double::ZERO,
@@ -422,6 +818,12 @@ mod tests {
         ceil_int:
             dd!(2),
             dd!(2).ceil();
+        ceil_tiny:
+            dd!(0),
+            Double(-1e-40, 1e-57).ceil();
+        ceil_int_pos_tail:
+            dd!(6),
+            Double(5.0, 1e-40).ceil();
 
         ceil_zero:
             Double::ZERO,
@@ -565,6 +967,124 @@ mod tests {
             Double::NAN.fract();
     );
 
+    // modf tests
+    test_all_eq!(
+        modf_pi:
+            (dd!(3), Double::PI - dd!(3)),
+            Double::PI.modf();
+        modf_neg:
+            (dd!(-3), dd!(-0.75)),
+            dd!(-3.75).modf();
+        modf_small:
+            (Double::ZERO, dd!(0.25)),
+            dd!(0.25).modf();
+        modf_int:
+            (dd!(2), Double::ZERO),
+            dd!(2).modf();
+        modf_huge:
+            (Double(2f64.powi(110), 0.0), dd!(0.5)),
+            Double(2f64.powi(110), 0.5).modf();
+        modf_huge_int:
+            (dd!(1e200), Double::ZERO),
+            dd!(1e200).modf();
+    );
+    test!(modf_special: {
+        let (int, frac) = dd!(-3).modf();
+        assert!(int == dd!(-3) && frac.is_zero() && frac.is_sign_negative());
+        let (int, frac) = dd!(-0.5).modf();
+        assert!(int.is_zero() && int.is_sign_negative() && frac == dd!(-0.5));
+        let (int, frac) = Double::NEG_ZERO.modf();
+        assert!(int.is_sign_negative() && frac.is_zero() && frac.is_sign_negative());
+        let (int, frac) = Double::INFINITY.modf();
+        assert!(int == Double::INFINITY && frac.is_zero() && frac.is_sign_positive());
+        let (int, frac) = Double::NEG_INFINITY.modf();
+        assert!(int == Double::NEG_INFINITY && frac.is_zero() && frac.is_sign_negative());
+        let (int, frac) = Double::NAN.modf();
+        assert!(int.is_nan() && frac.is_nan());
+    });
+    // The parts are checked by adding their components exactly, since `+` is allowed to
+    // round away the last bit even of a sum that can be represented
+    test!(modf_random: {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        for i in 0..100_000 {
+            let e = (i % 240) - 20;
+            let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
+            let x = Double::ONE / Double::from(sign * (rng.next_f64() + 0.5));
+            let x = x.ldexp(e);
+            let (int, frac) = x.modf();
+            assert!(Double::from_sum(&[int[0], int[1], frac[0], frac[1]]) == x, "{:?}.modf() = ({:?}, {:?})", x, int, frac);
+            assert!(int == x.trunc() && int.fract().is_zero());
+            assert!(frac.abs() < Double::ONE && (frac.is_zero() || frac.is_sign_negative() == x.is_sign_negative()));
+        }
+    });
+
+    // round_ties_even tests
+    test_all_exact!(
+        round_ties_even_pi:
+            dd!(3),
+            Double::PI.round_ties_even();
+        round_ties_even_half_down:
+            dd!(2),
+            dd!(2.5).round_ties_even();
+        round_ties_even_half_up:
+            dd!(4),
+            dd!(3.5).round_ties_even();
+        round_ties_even_neg_half:
+            dd!(-2),
+            dd!(-2.5).round_ties_even();
+        round_ties_even_neg_half_up:
+            dd!(-4),
+            dd!(-3.5).round_ties_even();
+        round_ties_even_inf:
+            Double::INFINITY,
+            Double::INFINITY.round_ties_even();
+        round_ties_even_nan:
+            Double::NAN,
+            Double::NAN.round_ties_even();
+    );
+
+    // Numbers whose first components are exactly on an integer or a half-way point, while
+    // their second components put them just above or below it
+    const B: f64 = 8.673617379884035e-19; // 2^-60
+
+    test_all_assert!(
+        boundary_floor:
+            Double(3.0, -B).floor() == dd!(2) && Double(3.0, B).floor() == dd!(3);
+        boundary_floor_neg:
+            Double(-3.0, -B).floor() == dd!(-4) && Double(-3.0, B).floor() == dd!(-3);
+        boundary_ceil:
+            Double(3.0, -B).ceil() == dd!(3) && Double(3.0, B).ceil() == dd!(4);
+        boundary_ceil_neg:
+            Double(-3.0, -B).ceil() == dd!(-3) && Double(-3.0, B).ceil() == dd!(-2);
+        boundary_trunc:
+            Double(3.0, -B).trunc() == dd!(2) && Double(-3.0, B).trunc() == dd!(-2);
+        boundary_fract:
+            Double(3.0, -B).fract() == Double(1.0, -B) && Double(-3.0, B).fract() == Double(-1.0, B);
+        boundary_round_tie:
+            Double(2.5, -B).round() == dd!(2) && Double(2.5, B).round() == dd!(3);
+        boundary_round_neg_tie:
+            Double(-2.5, B).round() == dd!(-2) && Double(-2.5, -B).round() == dd!(-3);
+        boundary_round_below_half:
+            same(Double(0.5 - f64::EPSILON / 4.0, -B).round(), Double::ZERO)
+                && same(Double(-0.5 + f64::EPSILON / 4.0, B).round(), Double::NEG_ZERO);
+        boundary_round_neg_zero:
+            same(dd!(-0.3).round(), Double::NEG_ZERO) && same(dd!(-0.3).round_ties_even(), Double::NEG_ZERO);
+        boundary_round_tail_tie:
+            Double(2f64.powi(60), -0.5).round() == Double(2f64.powi(60), 0.0)
+                && Double(2f64.powi(60), 0.5).round() == Double(2f64.powi(60), 1.0);
+        boundary_round_neg_tail_tie:
+            Double(-2f64.powi(60), 0.5).round() == Double(-2f64.powi(60), 0.0)
+                && Double(-2f64.powi(60), -0.5).round() == Double(-2f64.powi(60), -1.0);
+        boundary_round_ties_even:
+            Double(2.5, -B).round_ties_even() == dd!(2) && Double(2.5, B).round_ties_even() == dd!(3)
+                && Double(-2.5, -B).round_ties_even() == dd!(-3);
+        boundary_round_ties_even_tail:
+            Double(2f64.powi(60), 0.5).round_ties_even() == Double(2f64.powi(60), 0.0)
+                && Double(2f64.powi(60), 1.5).round_ties_even() == Double(2f64.powi(60), 2.0)
+                && Double(2f64.powi(60), -0.5).round_ties_even() == Double(2f64.powi(60), 0.0)
+                && Double(2f64.powi(60), -1.5).round_ties_even() == Double(2f64.powi(60), -2.0);
+    );
+
     // signum tests
     test_all_exact!(
         signum_pi:
@@ -629,6 +1149,18 @@ mod tests {
         classify_sub:
             dd!(1e-308).classify(),
             Subnormal;
+        classify_min_positive:
+            Double::MIN_POSITIVE.classify(),
+            Normal;
+        classify_half_min_positive:
+            (Double::MIN_POSITIVE / dd!(2)).classify(),
+            Subnormal;
+        classify_subnormal_tail:
+            Double::new(1e-300, 1e-320).classify(),
+            Normal;
+        classify_third:
+            (dd!(1) / dd!(3)).classify(),
+            Normal;
     );
 
     // is_normal tests
@@ -790,4 +1322,341 @@ mod tests {
         is_subnormal_sub:
             dd!(1e-308).is_subnormal();
     );
+
+    // is_normalized tests
+    test_all_assert!(
+        is_normalized_pi:
+            Double::PI.is_normalized();
+        is_normalized_e:
+            Double::E.is_normalized();
+        is_normalized_ln_2:
+            Double::LN_2.is_normalized();
+        is_normalized_max:
+            Double::MAX.is_normalized();
+        is_normalized_min:
+            Double::MIN.is_normalized();
+        is_normalized_min_positive:
+            Double::MIN_POSITIVE.is_normalized();
+        is_normalized_epsilon:
+            Double::EPSILON.is_normalized();
+        is_normalized_zero:
+            Double::ZERO.is_normalized();
+        is_normalized_neg_zero:
+            Double::NEG_ZERO.is_normalized();
+        is_normalized_inf:
+            Double::INFINITY.is_normalized();
+        is_normalized_neg_inf:
+            Double::NEG_INFINITY.is_normalized();
+        is_normalized_nan:
+            Double::NAN.is_normalized();
+        is_normalized_half_ulp_even:
+            Double::new(1.0, f64::EPSILON / 2.0).is_normalized();
+        is_normalized_subnormal_tail:
+            Double::new(1e-300, 1e-320).is_normalized();
+        is_normalized_neg_zero_tail:
+            Double::new(1.0, -0.0).is_normalized();
+
+        is_normalized_third:
+            (dd!(1) / dd!(3)).is_normalized();
+        is_normalized_sqrt:
+            Double::PI.sqrt().is_normalized();
+        is_normalized_exp:
+            dd!(-7.5).exp().is_normalized();
+        is_normalized_cancellation:
+            (Double::PI - dd!(3)).is_normalized();
+        is_normalized_small_quotient:
+            (dd!(1e-300) / dd!(3)).is_normalized();
+        is_normalized_overflow:
+            (Double::MAX * dd!(2)).is_normalized();
+        is_normalized_invalid:
+            (Double::ZERO / Double::ZERO).is_normalized();
+
+        is_normalized_overlap:
+            !Double::new(1.0, 1.0).is_normalized();
+        is_normalized_full_ulp:
+            !Double::new(1.0, f64::EPSILON).is_normalized();
+        is_normalized_half_ulp_odd:
+            !Double::new(1.0 + f64::EPSILON, f64::EPSILON / 2.0).is_normalized();
+        is_normalized_zero_first:
+            !Double::new(0.0, 1e-20).is_normalized();
+        is_normalized_nan_tail:
+            !Double::new(1.0, f64::NAN).is_normalized();
+        is_normalized_inf_tail:
+            !Double::new(1.0, f64::INFINITY).is_normalized();
+        is_normalized_nan_with_tail:
+            !Double::new(f64::NAN, 1.0).is_normalized();
+        is_normalized_inf_with_tail:
+            !Double::new(f64::INFINITY, 1.0).is_normalized();
+    );
+
+    // is_safe_integer tests
+    test_all_assert!(
+        is_safe_integer_zero:
+            Double::ZERO.is_safe_integer();
+        is_safe_integer_neg_zero:
+            Double::NEG_ZERO.is_safe_integer();
+        is_safe_integer_max:
+            Double::MAX_SAFE_INTEGER.is_safe_integer();
+        is_safe_integer_neg_max:
+            (-Double::MAX_SAFE_INTEGER).is_safe_integer();
+        is_safe_integer_max_minus_one:
+            (Double::MAX_SAFE_INTEGER - Double::ONE).is_safe_integer();
+        is_safe_integer_two_components:
+            Double(4503599627370497.0, -0.0).is_safe_integer();
+        is_safe_integer_max_plus_one:
+            !(Double::MAX_SAFE_INTEGER + Double::ONE).is_safe_integer();
+        is_safe_integer_neg_max_minus_one:
+            !(-Double::MAX_SAFE_INTEGER - Double::ONE).is_safe_integer();
+        is_safe_integer_tiny_fraction:
+            !(Double::from(1u64 << 60) + Double(0.5, 0.0)).is_safe_integer();
+        is_safe_integer_fraction_in_tail:
+            !(Double::from(1u64 << 53) + Double(0.5, 0.0)).is_safe_integer();
+        is_safe_integer_half:
+            !Double(0.5, 0.0).is_safe_integer();
+        is_safe_integer_pi:
+            !Double::PI.is_safe_integer();
+        is_safe_integer_inf:
+            !Double::INFINITY.is_safe_integer();
+        is_safe_integer_neg_inf:
+            !Double::NEG_INFINITY.is_safe_integer();
+        is_safe_integer_nan:
+            !Double::NAN.is_safe_integer();
+        is_safe_integer_max_value:
+            !Double::MAX.is_safe_integer();
+    );
+
+    test!(max_safe_integer: {
+        // 2^106 - 1 needs both components, with the tail pulling the head down
+        let below = Double::MAX_SAFE_INTEGER - Double::ONE;
+        assert_eq!(below[0], 2f64.powi(106));
+        assert_eq!(below[1], -1.0);
+        // 2^106 + 1 can be represented exactly too, it just isn't safe
+        let above = Double::MAX_SAFE_INTEGER + Double::ONE;
+        assert_eq!(above[0], 2f64.powi(106));
+        assert_eq!(above[1], 1.0);
+        assert!(above.is_finite() && above > Double::MAX_SAFE_INTEGER);
+    });
+
+    test!(checked_from_safe_integer: {
+        let max = 1i128 << 106;
+        assert_eq!(
+            Double::checked_from_safe_integer(max),
+            Some(Double::MAX_SAFE_INTEGER)
+        );
+        assert_eq!(
+            Double::checked_from_safe_integer(-max),
+            Some(-Double::MAX_SAFE_INTEGER)
+        );
+        assert_eq!(
+            Double::checked_from_safe_integer(max - 1),
+            Some(Double::MAX_SAFE_INTEGER - Double::ONE)
+        );
+        assert_eq!(
+            Double::checked_from_safe_integer(1 - max),
+            Some(Double::ONE - Double::MAX_SAFE_INTEGER)
+        );
+        assert_eq!(Double::checked_from_safe_integer(max + 1), None);
+        assert_eq!(Double::checked_from_safe_integer(-max - 1), None);
+        assert_eq!(Double::checked_from_safe_integer(i128::MAX), None);
+        assert_eq!(Double::checked_from_safe_integer(i128::MIN), None);
+        assert_eq!(Double::checked_from_safe_integer(0), Some(Double::ZERO));
+        assert_eq!(
+            Double::checked_from_safe_integer(-7),
+            Some(Double::from(-7))
+        );
+
+        // Every integer in range comes out exact, normalized, and safe
+        let values = [
+            (1i128 << 53) + 1,
+            (1 << 54) + 1,
+            (1 << 105) + (1 << 52) + 1,
+            (1 << 106) - (1 << 53) - 1,
+            (1 << 106) - (1 << 53),
+            0x2aa_aaaa_aaaa_aaaa_aaaa_aaaa_aaab,
+            i64::MAX as i128,
+            u64::MAX as i128,
+        ];
+        for &n in values.iter() {
+            for &n in [n, -n].iter() {
+                let x = Double::checked_from_safe_integer(n).unwrap();
+                assert_eq!(x[0] as i128 + x[1] as i128, n);
+                assert_eq!(x[0] + x[1], x[0]);
+                assert!(x.is_safe_integer());
+            }
+        }
+    });
+
+    // next_up, next_down, and ulp tests
+
+    // The same components, with the sign of a zero head (but not of a zero tail) mattering
+    fn same(a: Double, b: Double) -> bool {
+        a[0].to_bits() == b[0].to_bits() && a[1] == b[1]
+    }
+
+    const TINY: Double = Double(5e-324, 0.0);
+
+    test_all_assert!(
+        next_up_zero: same(Double::ZERO.next_up(), TINY) && same(Double::NEG_ZERO.next_up(), TINY);
+        next_down_zero: same(Double::ZERO.next_down(), -TINY) && same(Double::NEG_ZERO.next_down(), -TINY);
+        next_up_to_zero: same((-TINY).next_up(), Double::NEG_ZERO);
+        next_down_to_zero: TINY.next_down().is_zero() && TINY.next_down().is_sign_positive();
+        next_up_zero_tail: same(Double::ONE.next_up(), Double(1.0, 5e-324));
+        next_down_zero_tail: same(Double::ONE.next_down(), Double(1.0, -5e-324));
+        next_up_max: Double::MAX.next_up() == Double::INFINITY;
+        next_down_max: Double::MAX.next_down() < Double::MAX && Double::MAX.next_down().next_up() == Double::MAX;
+        next_up_inf: Double::INFINITY.next_up() == Double::INFINITY;
+        next_down_inf: Double::INFINITY.next_down() == Double::MAX;
+        next_up_neg_inf: Double::NEG_INFINITY.next_up() == -Double::MAX;
+        next_down_neg_inf: Double::NEG_INFINITY.next_down() == Double::NEG_INFINITY;
+        next_up_nan: Double::NAN.next_up().is_nan();
+        next_down_nan: Double::NAN.next_down().is_nan();
+        ulp_zero: same(Double::ZERO.ulp(), TINY) && same(Double::NEG_ZERO.ulp(), TINY);
+        ulp_one: same(Double::ONE.ulp(), TINY);
+        ulp_max: Double::MAX.ulp() == Double::INFINITY;
+        ulp_inf: Double::INFINITY.ulp() == Double::INFINITY && Double::NEG_INFINITY.ulp() == Double::INFINITY;
+        ulp_nan: Double::NAN.ulp().is_nan();
+        abs_diff_pos: Double::PI.abs_diff(Double::E) == Double::PI - Double::E;
+        abs_diff_neg: Double::E.abs_diff(Double::PI) == Double::PI - Double::E;
+        abs_diff_inf: Double::INFINITY.abs_diff(Double::ONE) == Double::INFINITY;
+        abs_diff_inf_inf: Double::INFINITY.abs_diff(Double::INFINITY).is_nan();
+        abs_diff_nan: Double::ONE.abs_diff(Double::NAN).is_nan();
+        copysign_flip: same((-Double::PI).copysign(Double::ONE), Double::PI);
+        copysign_flip_neg: same(Double::PI.copysign(-Double::E), -Double::PI);
+        copysign_keep: same(Double::PI.copysign(Double::E), Double::PI);
+        copysign_neg_zero_sign: same(Double::ONE.copysign(Double::NEG_ZERO), Double::NEG_ONE);
+        copysign_zero_sign: same(Double::NEG_ONE.copysign(Double::ZERO), Double::ONE);
+        copysign_zero: same(Double::ZERO.copysign(Double::NEG_ONE), Double::NEG_ZERO);
+        copysign_neg_zero: same(Double::NEG_ZERO.copysign(Double::ONE), Double::ZERO);
+        copysign_inf: Double::INFINITY.copysign(Double::NEG_ZERO) == Double::NEG_INFINITY;
+        copysign_nan_sign: same(Double::ONE.copysign(-Double::NAN), Double::NEG_ONE) && same(Double::NEG_ONE.copysign(Double::NAN), Double::ONE);
+        copysign_nan: Double::NAN.copysign(Double::NEG_ONE).is_nan() && Double::NAN.copysign(Double::NEG_ONE).is_sign_negative();
+        copysign_neg_nan: (-Double::NAN).copysign(Double::ONE).is_nan() && (-Double::NAN).copysign(Double::ONE).is_sign_positive();
+        abs_sub_pos: Double::PI.abs_sub(Double::E) == Double::PI - Double::E;
+        abs_sub_neg: same(Double::E.abs_sub(Double::PI), Double::ZERO);
+        abs_sub_equal: same(Double::PI.abs_sub(Double::PI), Double::ZERO);
+        abs_sub_inf: Double::INFINITY.abs_sub(Double::ONE) == Double::INFINITY;
+        abs_sub_inf_inf: same(Double::INFINITY.abs_sub(Double::INFINITY), Double::ZERO);
+        abs_sub_nan: Double::ONE.abs_sub(Double::NAN).is_nan() && Double::NAN.abs_sub(Double::ONE).is_nan();
+        approx_eq_same: Double::PI.approx_eq(Double::PI, 0);
+        approx_eq_exact: !Double::PI.approx_eq(Double::PI.next_up(), 0);
+        approx_eq_within: Double::ONE.approx_eq(Double::ONE + Double::EPSILON * dd!(4), 4);
+        approx_eq_beyond: !Double::ONE.approx_eq(Double::ONE + Double::EPSILON * dd!(5), 4);
+        approx_eq_sign: !Double::ONE.approx_eq(Double::NEG_ONE, u32::MAX);
+        approx_eq_zero: Double::ZERO.approx_eq(Double::NEG_ZERO, 0);
+        approx_eq_zero_within: Double::ZERO.approx_eq(-Double::EPSILON * dd!(3), 3);
+        approx_eq_zero_beyond: !(Double::EPSILON * dd!(4)).approx_eq(Double::ZERO, 3);
+        approx_eq_inf: Double::INFINITY.approx_eq(Double::INFINITY, 0);
+        approx_eq_inf_neg_inf: !Double::INFINITY.approx_eq(Double::NEG_INFINITY, u32::MAX);
+        approx_eq_inf_max: !Double::MAX.approx_eq(Double::INFINITY, u32::MAX);
+        approx_eq_max: Double::MAX.approx_eq(Double::MAX.next_down(), 1) && Double::MAX.approx_eq(-Double::MIN, 0);
+        approx_eq_nan: !Double::NAN.approx_eq(Double::NAN, u32::MAX) && !Double::ONE.approx_eq(Double::NAN, u32::MAX);
+    );
+
+    // The same differences, relative to the numbers being compared, give the same answers
+    // anywhere that a `Double` has its full precision. Below that (like at 1e-300), numbers
+    // that are as close as the type can make them are still equal.
+    test!(approx_eq_scale: {
+        let x = Double::PI / dd!(2);
+        let near = x + Double::EPSILON * dd!(3);
+        let far = x + Double::EPSILON * dd!(5);
+        for k in (-960..=1000).step_by(20) {
+            let (x, near, far) = (x.ldexp(k), near.ldexp(k), far.ldexp(k));
+            assert!(x.approx_eq(near, 4) && near.approx_eq(x, 4), "2^{}", k);
+            assert!(!x.approx_eq(far, 4) && !far.approx_eq(x, 4), "2^{}", k);
+            assert!(
+                (-x).approx_eq(-near, 4) && !(-x).approx_eq(-far, 4),
+                "2^{}",
+                k
+            );
+        }
+
+        let third = dd!(1) / dd!(3);
+        for &s in ["1e-300", "1e-200", "1e-100", "1", "1e100", "1e200", "1e300"].iter() {
+            let s = dd!(s);
+            assert!((third * s).approx_eq(s / dd!(3), 2), "{}", s);
+            assert!(
+                !(third * s).approx_eq(third * s * dd!("1.000000000001"), 1000),
+                "{}",
+                s
+            );
+        }
+    });
+
+    test!(next_up_carry: {
+        // 1 + 2^-53 is halfway between 1 and the next f64, and it's represented with a head
+        // of 1 because 1 is even. Anything larger carries into the head, and the tail of
+        // what's left is then in a lower binade, where the f64s are twice as close.
+        let x = Double(1.0, 2f64.powi(-53));
+        let y = x.next_up();
+        assert!(same(
+            y,
+            Double(1.0 + f64::EPSILON, -2f64.powi(-53) + 2f64.powi(-106))
+        ));
+        assert!(same(x.ulp(), Double(2f64.powi(-106), 0.0)));
+        assert!(same(y.next_down(), x));
+        assert!(same(
+            x.next_down(),
+            Double(1.0, 2f64.powi(-53) - 2f64.powi(-106))
+        ));
+        assert!(same(x.next_down().next_up(), x));
+
+        // A negative tail that's a power of 2 moves toward zero, into a lower binade
+        let z = Double(1.0, -2f64.powi(-60));
+        assert!(same(
+            z.next_up(),
+            Double(1.0, -2f64.powi(-60) + 2f64.powi(-113))
+        ));
+        assert!(same(
+            z.next_down(),
+            Double(1.0, -2f64.powi(-60) - 2f64.powi(-112))
+        ));
+    });
+
+    test!(next_up_round_trip: {
+        let mut rng = Rng::new(0x5851_f42d_4c95_7f2d);
+        for i in 0..10_000 {
+            let a = rng.next_f64() + 0.5;
+            let b = rng.next_f64() + 0.5;
+            let exp = rng.range(-1000, 1000);
+            let sign = if rng.next_u64() & 1 == 0 { 1.0 } else { -1.0 };
+            let x = match i % 3 {
+                0 => Double(a, 0.0) / Double(b, 0.0),
+                1 => Double(a, 0.0),
+                _ => Double(1.0, 0.0) + Double(a, 0.0).ldexp(-53),
+            }
+            .ldexp(exp)
+                * Double(sign, 0.0);
+
+            let up = x.next_up();
+            let down = x.next_down();
+            assert!(up > x && down < x, "{:?}", x);
+            assert!(up.is_normalized() && down.is_normalized(), "{:?}", x);
+            assert!(same(up.next_down(), x), "{:?}", x);
+            assert!(same(down.next_up(), x), "{:?}", x);
+            assert!(same(x.ulp(), up - x), "{:?}", x);
+            assert!(same((-x).next_up(), -down), "{:?}", x);
+
+            let gap = x.ulp()[0];
+            assert!(gap.to_bits() & ((1 << 52) - 1) == 0 || gap.to_bits() < 1 << 52);
+
+            // Nothing is in between: the tail of `x` can't go up to the next `f64` without
+            // leaving a `Double` that isn't normalized or that's no smaller than `up`, and
+            // the same goes for the tail of `up` going down
+            assert!(up[0] == x[0] || up[0] == f64_up(x[0]), "{:?}", x);
+            let above = Double(x[0], f64_up(x[1]));
+            assert!(!above.is_normalized() || above >= up, "{:?}", x);
+            let below = Double(up[0], -f64_up(-up[1]));
+            assert!(!below.is_normalized() || below <= x, "{:?}", x);
+        }
+    });
+
+    fn f64_up(x: f64) -> f64 {
+        if x == 0.0 {
+            f64::from_bits(1)
+        } else if x > 0.0 {
+            f64::from_bits(x.to_bits() + 1)
+        } else {
+            f64::from_bits(x.to_bits() - 1)
+        }
+    }
 }
@@ -4,7 +4,9 @@
 // https://opensource.org/licenses/MIT
 
 use crate::common::primitive as p;
+use crate::common::strict as s;
 use crate::common::utils as u;
+use crate::double::common as c;
 use crate::double::Double;
 use std::ops::{Mul, MulAssign};
 
@@ -26,14 +28,7 @@ impl Mul for Double {
     /// assert!(diff < dd!(1e-30));
     /// ```
     fn mul(self, other: Double) -> Double {
-        match self.pre_mul(&other) {
-            Some(r) => r,
-            None => {
-                let (p, e) = p::two_prod(self.0, other.0);
-                let (a, b) = u::renorm2(p, e + self.0 * other.1 + self.1 * other.0);
-                Double(a, b)
-            }
-        }
+        s::guard("Double * Double", &[self.0, other.0], self.mul_inner(other))
     }
 }
 
@@ -154,6 +149,138 @@ impl MulAssign<&Double> for Double {
 }
 
 impl Double {
+    /// Multiplies the `Double` by a small integer exactly, returning `None` if the product
+    /// can't be guaranteed to be exact.
+    ///
+    /// The product is exact when each component times `k` is itself an `f64`, with nothing
+    /// rounded away. For a component *c* with its odd part *m* (its significand with the
+    /// trailing zero bits removed) and `k` with its odd part *j*, that's when *m* × *j* <
+    /// 2<sup>53</sup> and the product doesn't overflow. Put in terms of bit lengths, if
+    /// *m* has *s* bits and |`k`| has *b*, the product is always exact when *s* + *b* ≤ 53
+    /// and never exact when *s* + *b* > 54; in between, it depends on the bits. For
+    /// example, every component of a `Double` holding an integer less than 2<sup>53 −
+    /// *b*</sup> passes. Multiplying by zero or by a power of two is exact unless it
+    /// overflows.
+    ///
+    /// When the product is exact, it only takes two multiplications and a renormalization,
+    /// and the result is exactly what the `*` operator would produce. A `Double` that isn't
+    /// finite always returns `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!(3).powi(30); // 3^30, 48 bits
+    /// assert!(x.mul_exact_i32(31) == Some(x * dd!(31))); // 5 more bits fits
+    /// assert!(x.mul_exact_i32(63) == None); // 6 more bits doesn't
+    /// assert!(Double::PI.mul_exact_i32(-4) == Some(Double::PI * dd!(-4)));
+    /// ```
+    #[inline]
+    pub fn mul_exact_i32(self, k: i32) -> Option<Double> {
+        if !self.is_finite() {
+            return None;
+        }
+        let (a, b) = match k {
+            // A zero product takes its sign from the signs of both factors, as it does for
+            // `f64`
+            _ if k == 0 || self.is_zero() => (self.0 * k as f64, 0.0),
+            1 => (self.0, self.1),
+            -1 => (-self.0, -self.1),
+            _ => {
+                let k = k as f64;
+                let (a, ea) = p::two_prod(self.0, k);
+                let (b, eb) = p::two_prod(self.1, k);
+                // The errors are both zero exactly when the products are exact, and an
+                // overflow shows up as an error of infinity or NaN
+                if ea != 0.0 || eb != 0.0 {
+                    return None;
+                }
+                u::renorm2(a, b)
+            }
+        };
+        // Adding zero turns a negative zero positive, which is what `*` leaves in the
+        // second component
+        Some(Double(a, b + 0.0))
+    }
+
+    /// Multiplies the `Double` by an integer.
+    ///
+    /// This produces the same result as multiplying by the integer converted to a
+    /// `Double`, but it's a little faster, since the integer is known to have only one
+    /// component. Multiplication of a `Double` is already cheap enough that checking for
+    /// an exact product first (see [`mul_exact_i32`]) wouldn't save anything; when the
+    /// product is exact, this returns it exactly anyway.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(1.5).mul_i32(-6) == dd!(-9));
+    /// assert!(Double::PI.mul_i32(3) == Double::PI * dd!(3));
+    /// ```
+    ///
+    /// [`mul_exact_i32`]: #method.mul_exact_i32
+    #[inline]
+    pub fn mul_i32(self, k: i32) -> Double {
+        let k = k as f64;
+        let r = match self.pre_mul(&Double(k, 0.0)) {
+            Some(r) => r,
+            None => {
+                // This is `*` with the products of the integer's zero second component
+                // left out
+                let (p, e) = p::two_prod(self.0, k);
+                let (a, b) = u::renorm2(p, e + self.1 * k);
+                if a.is_finite() {
+                    Double(a, b)
+                } else {
+                    c::redo_halved!(0.5 * self.0 * k, c::mul_pwr2(self, 0.5) * Double(k, 0.0))
+                }
+            }
+        };
+        s::guard("Double::mul_i32", &[self.0, k], r)
+    }
+}
+
+impl Double {
+    /// Multiplies this `Double` by another in a `const fn`, for products that are needed at
+    /// compile time. The result is the same as that of the `*` operator; see
+    /// [`add_const`] for details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// // Twiddle factor angles for an FFT of size 16
+    /// const ANGLES: [Double; 4] = [
+    ///     Double::ZERO,
+    ///     Double::PI.mul_const(Double::from_f64_const(0.125)),
+    ///     Double::PI.mul_const(Double::from_f64_const(0.25)),
+    ///     Double::PI.mul_const(Double::from_f64_const(0.375)),
+    /// ];
+    /// assert!(ANGLES[3] == Double::PI * dd!(0.375));
+    /// ```
+    ///
+    /// [`add_const`]: #method.add_const
+    pub const fn mul_const(self, other: Double) -> Double {
+        self.mul_inner(other)
+    }
+
+    // The `*` operator without the strict-mode check.
+    pub(super) const fn mul_inner(self, other: Double) -> Double {
+        match self.pre_mul(&other) {
+            Some(r) => r,
+            None => {
+                let (p, e) = p::two_prod(self.0, other.0);
+                let (a, b) = u::renorm2(p, e + self.0 * other.1 + self.1 * other.0);
+                if a.is_finite() {
+                    Double(a, b)
+                } else {
+                    c::redo_halved!(
+                        0.5 * self.0 * other.0,
+                        c::mul_pwr2(self, 0.5).mul_inner(other)
+                    )
+                }
+            }
+        }
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -164,7 +291,7 @@ impl Double {
     // rather than calculation.
 
     #[inline]
-    fn pre_mul(&self, other: &Double) -> Option<Double> {
+    const fn pre_mul(&self, other: &Double) -> Option<Double> {
         if self.is_nan() || other.is_nan() {
             Some(Double::NAN)
         } else if self.is_zero() {
@@ -189,6 +316,12 @@ impl Double {
             } else {
                 Some(Double::NEG_INFINITY)
             }
+        } else if other.is_zero() {
+            if self.is_sign_positive() == other.is_sign_positive() {
+                Some(Double::ZERO)
+            } else {
+                Some(Double::NEG_ZERO)
+            }
         } else {
             None
         }
@@ -198,6 +331,7 @@ impl Double {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::random::Rng;
 
     // mul tests
     test_all_near!(
@@ -381,4 +515,180 @@ mod tests {
         value *= ten;
         near!("693147180559945309417232121458.18", value);
     });
+
+    // mul_exact_i32 and mul_i32 tests
+    fn general(x: Double, k: i32) -> Double {
+        x * Double(k as f64, 0.0)
+    }
+
+    fn exact(x: Double, k: i32) -> bool {
+        x.mul_exact_i32(k).is_some()
+    }
+
+    // The smallest and largest odd integers that are s bits long
+    fn narrow(s: i32) -> f64 {
+        2f64.powi(s - 1) + 1.0
+    }
+
+    fn wide(s: i32) -> f64 {
+        2f64.powi(s) - 1.0
+    }
+
+    test!(exact_matches_general: {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        let mut count = 0;
+        for i in 0..20000 {
+            let r = rng.next_u64();
+            let x = match i % 3 {
+                0 => Double::from((r >> 11) as f64) / dd!(7),
+                1 => Double::from((r >> (r % 64)) as f64) * dd!(3).powi((r % 20) as i32),
+                _ => -Double((r >> 11) as f64, 0.0) * dd!(1e-200),
+            };
+            let k = match rng.next_u64() % 4 {
+                0 => (r % 5) as i32 - 2,
+                1 => (r % 4096) as i32 - 2048,
+                _ => (rng.next_u64() >> 32) as i32,
+            };
+            if let Some(y) = x.mul_exact_i32(k) {
+                count += 1;
+                assert!(y.to_bits() == general(x, k).to_bits(), "{:?} * {}", x, k);
+            }
+            assert!(x.mul_i32(k).to_bits() == general(x, k).to_bits(), "{:?} * {}", x, k);
+        }
+        // Make sure that the exact path was actually taken a reasonable number of times
+        assert!(count > 5000);
+    });
+
+    test_all_assert!(
+        exact_boundary_under:
+            exact(dd!(wide(50)), 7);
+        exact_boundary_between:
+            exact(dd!(narrow(51)), 7);
+        exact_boundary_between_not:
+            !exact(dd!(wide(51)), 7);
+        exact_boundary_over:
+            !exact(dd!(narrow(50)), 31);
+        exact_boundary_neg:
+            exact(dd!(wide(50)), -7) && !exact(dd!(narrow(50)), -31);
+        exact_boundary_even:
+            exact(dd!(wide(50)), 7 << 20) && !exact(dd!(narrow(50)), 31 << 20);
+        exact_boundary_lower:
+            exact(Double(2f64.powi(120), wide(50)), 7)
+                && !exact(Double(2f64.powi(120), wide(51)), 7);
+        exact_pi_pwr2:
+            exact(Double::PI, 1 << 30) && exact(Double::PI, i32::MIN);
+        exact_pi_odd:
+            !exact(Double::PI, 3);
+        exact_overflow:
+            !exact(Double::MAX, 2) && exact(Double::MAX, -1);
+        exact_nan:
+            !exact(Double::NAN, 1);
+        exact_inf:
+            !exact(Double::INFINITY, 1) && !exact(Double::NEG_INFINITY, 0);
+    );
+
+    test_all_exact!(
+        exact_product:
+            Double(3.0 * 2f64.powi(60), 3.0),
+            Double(2f64.powi(60), 1.0).mul_exact_i32(3).unwrap();
+        exact_neg:
+            Double(-15.0 * 2f64.powi(60), -15.0),
+            Double(2f64.powi(60), 1.0).mul_exact_i32(-15).unwrap();
+        exact_zero:
+            Double::ZERO,
+            Double::PI.mul_exact_i32(0).unwrap();
+        exact_neg_zero:
+            Double::ZERO,
+            (-Double::PI).mul_exact_i32(0).unwrap();
+        exact_zero_neg_zero:
+            Double::NEG_ZERO,
+            Double::NEG_ZERO.mul_exact_i32(0).unwrap();
+        exact_one:
+            Double::PI,
+            Double::PI.mul_exact_i32(1).unwrap();
+        exact_neg_one:
+            -Double::PI,
+            Double::PI.mul_exact_i32(-1).unwrap();
+        exact_two:
+            Double::TAU,
+            Double::PI.mul_exact_i32(2).unwrap();
+        mul_i32_inexact:
+            general(Double::PI, 3),
+            Double::PI.mul_i32(3);
+        mul_i32_nan:
+            Double::NAN,
+            Double::NAN.mul_i32(2);
+        mul_i32_inf:
+            Double::NEG_INFINITY,
+            Double::INFINITY.mul_i32(-3);
+        mul_i32_inf_zero:
+            Double::NAN,
+            Double::INFINITY.mul_i32(0);
+    );
+
+    test!(exact_zero_signs: {
+        for &x in &[Double::ZERO, Double::NEG_ZERO, Double::PI, -Double::PI] {
+            for &k in &[0, 1, -1] {
+                assert!(x.mul_exact_i32(k).unwrap().to_bits() == general(x, k).to_bits());
+            }
+        }
+    });
+
+    // Steps of π/16, and the first two terms of the Taylor series for their sines, worked
+    // out at compile time with the `const` arithmetic and then again at runtime with the
+    // operators. Both have to come out the same, bit for bit.
+    const fn angles() -> [Double; 16] {
+        let mut t = [Double::ZERO; 16];
+        let step = Double::PI.div_const(Double::from_f64_const(16.0));
+        let mut k = 1;
+        while k < 16 {
+            t[k] = t[k - 1].add_const(step);
+            k += 1;
+        }
+        t
+    }
+
+    const fn cubics(angles: [Double; 16]) -> [Double; 16] {
+        let sixth = Double::ONE.div_const(Double::from_f64_const(6.0));
+        let mut t = [Double::ZERO; 16];
+        let mut k = 0;
+        while k < 16 {
+            let x = angles[k];
+            t[k] = x.sub_const(x.mul_const(x).mul_const(x).mul_const(sixth));
+            k += 1;
+        }
+        t
+    }
+
+    const ANGLES: [Double; 16] = angles();
+    const CUBICS: [Double; 16] = cubics(ANGLES);
+    const SPECIAL: [Double; 5] = [
+        Double::MAX.add_const(Double::MAX),
+        Double::NEG_ZERO.mul_const(Double::ZERO),
+        Double::INFINITY.sub_const(Double::INFINITY),
+        Double::ONE.div_const(Double::NEG_ZERO),
+        Double::MIN_POSITIVE.mul_const(Double::from_f64_const(0.5)),
+    ];
+
+    test!(const_matches_runtime: {
+        let step = Double::PI / Double(16.0, 0.0);
+        let sixth = Double::ONE / Double::from(6);
+        let mut x = Double::ZERO;
+        for k in 0..16 {
+            assert!(ANGLES[k].to_bits() == x.to_bits(), "angle {}", k);
+            let cubic = x - x * x * x * sixth;
+            assert!(CUBICS[k].to_bits() == cubic.to_bits(), "cubic {}", k);
+            x += step;
+        }
+        let special = [
+            Double::MAX + Double::MAX,
+            Double::NEG_ZERO * Double::ZERO,
+            Double::INFINITY - Double::INFINITY,
+            Double::ONE / Double::NEG_ZERO,
+            Double::MIN_POSITIVE * Double::from(0.5),
+        ];
+        for (k, x) in special.iter().enumerate() {
+            assert!(SPECIAL[k].to_bits() == x.to_bits(), "special {}", k);
+        }
+    });
 }
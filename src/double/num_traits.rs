@@ -0,0 +1,727 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::common as c;
+use crate::double::Double;
+use crate::error::ParseDoubleError;
+use num_traits::{Float, FloatConst, FromPrimitive, Num, One, Signed, ToPrimitive, Zero};
+use std::convert::TryFrom;
+use std::num::FpCategory;
+
+// 2^64, the factor between the two halves of a 128-bit integer.
+const TWO_64: f64 = 18446744073709551616.0;
+
+impl Zero for Double {
+    /// Returns zero. This is only available with the `num-traits` feature.
+    #[inline]
+    fn zero() -> Double {
+        Double::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        Double::is_zero(*self)
+    }
+}
+
+impl One for Double {
+    /// Returns one. This is only available with the `num-traits` feature.
+    #[inline]
+    fn one() -> Double {
+        Double::ONE
+    }
+}
+
+impl Num for Double {
+    type FromStrRadixErr = ParseDoubleError;
+
+    /// Parses a string in the given radix. This is only available with the `num-traits`
+    /// feature.
+    ///
+    /// A radix of 10 parses exactly as [`from_str`] does, and any other radix parses as
+    /// [`from_string_radix_any`] does.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range 2 to 36.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use num_traits::Num;
+    ///
+    /// assert!(Double::from_str_radix("-101.11", 2).unwrap() == dd!(-5.75));
+    /// assert!(Double::from_str_radix("1e3", 10).unwrap() == dd!(1000));
+    /// ```
+    ///
+    /// [`from_str`]: #method.from_str
+    /// [`from_string_radix_any`]: #method.from_string_radix_any
+    fn from_str_radix(s: &str, radix: u32) -> Result<Double, ParseDoubleError> {
+        if radix == 10 {
+            s.parse()
+        } else {
+            Double::from_string_radix_any(s, radix)
+        }
+    }
+}
+
+impl ToPrimitive for Double {
+    /// Converts the `Double` to an `i64`, truncating any fractional part. This is only
+    /// available with the `num-traits` feature.
+    ///
+    /// Like the other integer conversions, this returns `None` if the truncated number
+    /// doesn't fit in the integer type, and that includes NaN and the infinities. Unlike
+    /// converting the first component alone, this takes the second component into account,
+    /// so that every integer that a `Double` can hold comes out exactly.
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.to_i128().and_then(|n| i64::try_from(n).ok())
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.to_u128().and_then(|n| u64::try_from(n).ok())
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(self.trunc()).ok()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(self.trunc()).ok()
+    }
+
+    /// Converts the `Double` to the nearest `f64`. This is only available with the
+    /// `num-traits` feature.
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.0)
+    }
+}
+
+impl FromPrimitive for Double {
+    /// Converts an `i64` to a `Double`. This is only available with the `num-traits`
+    /// feature.
+    ///
+    /// All of the conversions from integers are exact, except those of 128-bit integers
+    /// with more significant bits than a `Double` can hold, which are rounded.
+    #[inline]
+    fn from_i64(n: i64) -> Option<Double> {
+        Some(Double::from(n))
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Double> {
+        Some(Double::from(n))
+    }
+
+    fn from_i128(n: i128) -> Option<Double> {
+        let hi = c::mul_pwr2(Double::from((n >> 64) as i64), TWO_64);
+        Some(hi + Double::from(n as u64))
+    }
+
+    fn from_u128(n: u128) -> Option<Double> {
+        let hi = c::mul_pwr2(Double::from((n >> 64) as u64), TWO_64);
+        Some(hi + Double::from(n as u64))
+    }
+
+    #[inline]
+    fn from_f32(n: f32) -> Option<Double> {
+        Some(Double::from(n))
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<Double> {
+        Some(Double::from(n))
+    }
+}
+
+// `NumCast` is left unimported, since its `from` would clash with `From::from`
+impl num_traits::NumCast for Double {
+    /// Converts any primitive number to a `Double`. This is only available with the
+    /// `num-traits` feature.
+    ///
+    /// Integers are converted as exactly as a `Double` can hold them. Anything else is
+    /// converted by way of `f64`, since that's all that [`ToPrimitive`] offers for numbers
+    /// with fractional parts.
+    ///
+    /// [`ToPrimitive`]: https://docs.rs/num-traits/0.2/num_traits/cast/trait.ToPrimitive.html
+    fn from<T: ToPrimitive>(n: T) -> Option<Double> {
+        let f = n.to_f64()?;
+        if f.fract() == 0.0 && f != 0.0 {
+            if let Some(i) = n.to_i128() {
+                return Double::from_i128(i);
+            }
+            if let Some(u) = n.to_u128() {
+                return Double::from_u128(u);
+            }
+        }
+        Double::from_f64(f)
+    }
+}
+
+impl Signed for Double {
+    /// Returns the absolute value of the `Double`. This is only available with the
+    /// `num-traits` feature.
+    #[inline]
+    fn abs(&self) -> Double {
+        Double::abs(*self)
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Double) -> Double {
+        Float::abs_sub(*self, *other)
+    }
+
+    #[inline]
+    fn signum(&self) -> Double {
+        Double::signum(*self)
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        Double::is_sign_positive(*self)
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        Double::is_sign_negative(*self)
+    }
+}
+
+impl Float for Double {
+    /// Returns NaN. This is only available with the `num-traits` feature.
+    ///
+    /// Every method of this trait with a counterpart in `Double`'s own methods or
+    /// constants is that counterpart, and the documentation of the counterpart describes
+    /// it. The rest are described individually.
+    #[inline]
+    fn nan() -> Double {
+        Double::NAN
+    }
+
+    #[inline]
+    fn infinity() -> Double {
+        Double::INFINITY
+    }
+
+    #[inline]
+    fn neg_infinity() -> Double {
+        Double::NEG_INFINITY
+    }
+
+    #[inline]
+    fn neg_zero() -> Double {
+        Double::NEG_ZERO
+    }
+
+    #[inline]
+    fn min_value() -> Double {
+        Double::MIN
+    }
+
+    #[inline]
+    fn min_positive_value() -> Double {
+        Double::MIN_POSITIVE
+    }
+
+    #[inline]
+    fn epsilon() -> Double {
+        Double::EPSILON
+    }
+
+    #[inline]
+    fn max_value() -> Double {
+        Double::MAX
+    }
+
+    #[inline]
+    fn is_nan(self) -> bool {
+        Double::is_nan(self)
+    }
+
+    #[inline]
+    fn is_infinite(self) -> bool {
+        Double::is_infinite(self)
+    }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        Double::is_finite(self)
+    }
+
+    #[inline]
+    fn is_normal(self) -> bool {
+        Double::is_normal(self)
+    }
+
+    #[inline]
+    fn is_subnormal(self) -> bool {
+        Double::is_subnormal(self)
+    }
+
+    #[inline]
+    fn classify(self) -> FpCategory {
+        Double::classify(self)
+    }
+
+    #[inline]
+    fn floor(self) -> Double {
+        Double::floor(self)
+    }
+
+    #[inline]
+    fn ceil(self) -> Double {
+        Double::ceil(self)
+    }
+
+    #[inline]
+    fn round(self) -> Double {
+        Double::round(self)
+    }
+
+    #[inline]
+    fn trunc(self) -> Double {
+        Double::trunc(self)
+    }
+
+    #[inline]
+    fn fract(self) -> Double {
+        Double::fract(self)
+    }
+
+    #[inline]
+    fn abs(self) -> Double {
+        Double::abs(self)
+    }
+
+    #[inline]
+    fn signum(self) -> Double {
+        Double::signum(self)
+    }
+
+    #[inline]
+    fn copysign(self, sign: Double) -> Double {
+        Double::copysign(self, sign)
+    }
+
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        Double::is_sign_positive(self)
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        Double::is_sign_negative(self)
+    }
+
+    #[inline]
+    fn mul_add(self, a: Double, b: Double) -> Double {
+        Double::mul_add(self, a, b)
+    }
+
+    #[inline]
+    fn recip(self) -> Double {
+        Double::recip(self)
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Double {
+        Double::powi(self, n)
+    }
+
+    #[inline]
+    fn powf(self, n: Double) -> Double {
+        Double::powf(self, n)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Double {
+        Double::sqrt(self)
+    }
+
+    #[inline]
+    fn exp(self) -> Double {
+        Double::exp(self)
+    }
+
+    #[inline]
+    fn exp2(self) -> Double {
+        Double::exp2(self)
+    }
+
+    #[inline]
+    fn ln(self) -> Double {
+        Double::ln(self)
+    }
+
+    #[inline]
+    fn log(self, base: Double) -> Double {
+        Double::log(self, base)
+    }
+
+    #[inline]
+    fn log2(self) -> Double {
+        Double::log2(self)
+    }
+
+    #[inline]
+    fn log10(self) -> Double {
+        Double::log10(self)
+    }
+
+    #[inline]
+    fn to_degrees(self) -> Double {
+        Double::to_degrees(self)
+    }
+
+    #[inline]
+    fn to_radians(self) -> Double {
+        Double::to_radians(self)
+    }
+
+    #[inline]
+    fn max(self, other: Double) -> Double {
+        Double::max(self, other)
+    }
+
+    #[inline]
+    fn min(self, other: Double) -> Double {
+        Double::min(self, other)
+    }
+
+    #[inline]
+    fn abs_sub(self, other: Double) -> Double {
+        Double::abs_sub(self, other)
+    }
+
+    #[inline]
+    fn cbrt(self) -> Double {
+        Double::cbrt(self)
+    }
+
+    #[inline]
+    fn hypot(self, other: Double) -> Double {
+        Double::hypot(self, other)
+    }
+
+    #[inline]
+    fn sin(self) -> Double {
+        Double::sin(self)
+    }
+
+    #[inline]
+    fn cos(self) -> Double {
+        Double::cos(self)
+    }
+
+    #[inline]
+    fn tan(self) -> Double {
+        Double::tan(self)
+    }
+
+    #[inline]
+    fn asin(self) -> Double {
+        Double::asin(self)
+    }
+
+    #[inline]
+    fn acos(self) -> Double {
+        Double::acos(self)
+    }
+
+    #[inline]
+    fn atan(self) -> Double {
+        Double::atan(self)
+    }
+
+    #[inline]
+    fn atan2(self, other: Double) -> Double {
+        Double::atan2(self, other)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Double, Double) {
+        Double::sin_cos(self)
+    }
+
+    #[inline]
+    fn exp_m1(self) -> Double {
+        Double::exp_m1(self)
+    }
+
+    #[inline]
+    fn ln_1p(self) -> Double {
+        Double::ln_1p(self)
+    }
+
+    #[inline]
+    fn sinh(self) -> Double {
+        Double::sinh(self)
+    }
+
+    #[inline]
+    fn cosh(self) -> Double {
+        Double::cosh(self)
+    }
+
+    #[inline]
+    fn tanh(self) -> Double {
+        Double::tanh(self)
+    }
+
+    #[inline]
+    fn asinh(self) -> Double {
+        Double::asinh(self)
+    }
+
+    #[inline]
+    fn acosh(self) -> Double {
+        Double::acosh(self)
+    }
+
+    #[inline]
+    fn atanh(self) -> Double {
+        Double::atanh(self)
+    }
+
+    /// Returns the mantissa, base 2 exponent, and sign of the first component of the
+    /// `Double`. This is only available with the `num-traits` feature.
+    ///
+    /// The trait's return type only has room for 64 bits of mantissa, so the second
+    /// component isn't included at all. The original number can't be rebuilt from the
+    /// result, though the first component can.
+    #[inline]
+    fn integer_decode(self) -> (u64, i16, i8) {
+        Float::integer_decode(self.0)
+    }
+}
+
+impl FloatConst for Double {
+    /// Returns *e*. This is only available with the `num-traits` feature.
+    ///
+    /// Each of these is the associated constant of the same name.
+    #[inline]
+    fn E() -> Double {
+        Double::E
+    }
+
+    #[inline]
+    fn FRAC_1_PI() -> Double {
+        Double::FRAC_1_PI
+    }
+
+    #[inline]
+    fn FRAC_1_SQRT_2() -> Double {
+        Double::FRAC_1_SQRT_2
+    }
+
+    #[inline]
+    fn FRAC_2_PI() -> Double {
+        Double::FRAC_2_PI
+    }
+
+    #[inline]
+    fn FRAC_2_SQRT_PI() -> Double {
+        Double::FRAC_2_SQRT_PI
+    }
+
+    #[inline]
+    fn FRAC_PI_2() -> Double {
+        Double::FRAC_PI_2
+    }
+
+    #[inline]
+    fn FRAC_PI_3() -> Double {
+        Double::FRAC_PI_3
+    }
+
+    #[inline]
+    fn FRAC_PI_4() -> Double {
+        Double::FRAC_PI_4
+    }
+
+    #[inline]
+    fn FRAC_PI_6() -> Double {
+        Double::FRAC_PI_6
+    }
+
+    #[inline]
+    fn FRAC_PI_8() -> Double {
+        Double::FRAC_PI_8
+    }
+
+    #[inline]
+    fn LN_10() -> Double {
+        Double::LN_10
+    }
+
+    #[inline]
+    fn LN_2() -> Double {
+        Double::LN_2
+    }
+
+    #[inline]
+    fn LOG10_E() -> Double {
+        Double::LOG10_E
+    }
+
+    #[inline]
+    fn LOG2_E() -> Double {
+        Double::LOG2_E
+    }
+
+    #[inline]
+    fn PI() -> Double {
+        Double::PI
+    }
+
+    #[inline]
+    fn SQRT_2() -> Double {
+        Double::SQRT_2
+    }
+
+    #[inline]
+    fn TAU() -> Double {
+        Double::TAU
+    }
+
+    #[inline]
+    fn LOG10_2() -> Double {
+        Double::LOG10_2
+    }
+
+    #[inline]
+    fn LOG2_10() -> Double {
+        Double::LOG2_10
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Calls the trait's methods rather than the inherent ones, which share their names
+    fn float<T: Float>(x: T) -> T {
+        x
+    }
+
+    test!(constants: {
+        assert!(<Double as Zero>::zero() == Double::ZERO);
+        assert!(<Double as One>::one() == Double::ONE);
+        assert!(<Double as Float>::max_value() == Double::MAX);
+        assert!(<Double as Float>::min_value() == Double::MIN);
+        assert!(<Double as Float>::min_positive_value() == Double::MIN_POSITIVE);
+        assert!(<Double as Float>::epsilon() == Double::EPSILON);
+        assert!(<Double as Float>::nan().is_nan());
+        assert!(<Double as FloatConst>::PI() == Double::PI);
+        assert!(<Double as FloatConst>::TAU() == Double::TAU);
+    });
+
+    test!(to_integers: {
+        let big = Double::from(1u64 << 62) + Double::from(12345);
+        assert!(big.to_i64() == Some((1 << 62) + 12345));
+        assert!((-big).to_i64() == Some(-(1 << 62) - 12345));
+        assert!(big.to_u64() == Some((1 << 62) + 12345));
+        assert!((-big).to_u64() == None);
+        assert!(dd!(-2.75).to_i32() == Some(-2));
+        assert!(dd!(-0.75).to_u32() == Some(0));
+        assert!(dd!(300).to_u8() == None);
+        assert!(Double::NAN.to_i64() == None);
+        assert!(Double::INFINITY.to_u64() == None);
+
+        let n = (1i128 << 100) + 1;
+        assert!(Double::from_i128(n).unwrap().to_i128() == Some(n));
+        assert!(Double::from_i128(-n).unwrap().to_i128() == Some(-n));
+        assert!(Double::from_u128(n as u128).unwrap().to_u128() == Some(n as u128));
+        let max = Double::from_u128(u128::MAX).unwrap();
+        assert!(max.to_u128() == Some(u128::MAX));
+        assert!(max.to_i128() == None);
+        assert!(Double::from_i128(i128::MIN).unwrap().to_i128() == Some(i128::MIN));
+    });
+
+    test!(num_cast: {
+        assert!(<Double as num_traits::NumCast>::from(u64::MAX).unwrap() == Double::from(u64::MAX));
+        assert!(<Double as num_traits::NumCast>::from(-7i8).unwrap() == dd!(-7));
+        assert!(<Double as num_traits::NumCast>::from(0.1f64).unwrap() == Double::from(0.1));
+        assert!(<Double as num_traits::NumCast>::from(-0.0f64).unwrap().is_sign_negative());
+        assert!(<Double as num_traits::NumCast>::from(f64::NAN).unwrap().is_nan());
+        let n = (1i128 << 100) + 1;
+        assert!(<Double as num_traits::NumCast>::from(n).unwrap() == Double::from_i128(n).unwrap());
+        assert!(<f64 as num_traits::NumCast>::from(Double::PI).unwrap() == std::f64::consts::PI);
+    });
+
+    test!(from_str_radix: {
+        assert!(Double::from_str_radix("ff.8", 16).unwrap() == dd!(255.5));
+        assert!(Double::from_str_radix("1e3", 10).unwrap() == dd!(1000));
+        assert!(Double::from_str_radix("", 10).is_err());
+        assert!(Double::from_str_radix("12", 2).is_err());
+    });
+
+    test!(min_max: {
+        let (a, b) = (dd!(1), dd!(2));
+        assert!(Float::max(a, b) == b);
+        assert!(Float::min(a, b) == a);
+        assert!(Float::max(Double::NAN, a) == a);
+        assert!(Float::max(a, Double::NAN) == a);
+        assert!(Float::min(Double::NAN, a) == a);
+        assert!(Float::min(a, Double::NAN) == a);
+        assert!(Float::abs_sub(a, b) == Double::ZERO);
+        assert!(Float::abs_sub(b, a) == Double::ONE);
+        assert!(Float::clamp(dd!(5), a, b) == b);
+    });
+
+    test!(exp2: {
+        assert!(float(dd!(10)).exp2() == dd!(1024));
+        assert!(float(dd!(-1074)).exp2() == Double(f64::from_bits(1), 0.0));
+        assert!(float(dd!(1024)).exp2() == Double::INFINITY);
+        assert!(float(dd!(-1100)).exp2() == Double::ZERO);
+        assert!(float(Double::NAN).exp2().is_nan());
+        let x = float(dd!(0.5)).exp2();
+        assert!((x - Double::SQRT_2).abs() < dd!(1e-30));
+        let x = float(dd!(100.5)).exp2();
+        assert!((x - Double::SQRT_2 * dd!(2).powi(100)).abs() < dd!(1e-30) * x);
+    });
+
+    test!(hypot: {
+        assert!(float(dd!(3)).hypot(dd!(-4)) == dd!(5));
+        assert!(float(Double::ZERO).hypot(Double::ZERO) == Double::ZERO);
+        assert!(float(Double::MAX / dd!(2)).hypot(Double::MAX / dd!(2)).is_finite());
+        assert!(float(Double::NAN).hypot(Double::INFINITY) == Double::INFINITY);
+        assert!(float(Double::NAN).hypot(Double::ONE).is_nan());
+        let x = float(dd!(1e-200)).hypot(dd!(1e-200));
+        assert!((x - dd!(1e-200) * Double::SQRT_2).abs() < dd!(1e-230));
+    });
+
+    test!(degrees: {
+        assert!((float(Double::PI).to_degrees() - dd!(180)).abs() < dd!(1e-29));
+        assert!((float(dd!(90)).to_radians() - Double::FRAC_PI_2).abs() < dd!(1e-31));
+    });
+
+    test!(integer_decode: {
+        assert!(Float::integer_decode(Double::ONE) == Float::integer_decode(1f64));
+        assert!(Float::integer_decode(Double::PI) == Float::integer_decode(Double::PI[0]));
+    });
+
+    test!(signed: {
+        assert!(Signed::abs(&dd!(-2)) == dd!(2));
+        assert!(Signed::is_negative(&dd!(-2)));
+        assert!(Signed::is_positive(&Double::ZERO));
+        assert!(Signed::signum(&dd!(-0.5)) == Double::NEG_ONE);
+    });
+
+    test!(matches_inherent: {
+        let x = dd!(0.7);
+        assert!(float(x).sin() == x.sin());
+        assert!(float(x).sin_cos() == x.sin_cos());
+        assert!(float(x).atan2(dd!(2)) == x.atan2(dd!(2)));
+        assert!(float(x).powf(dd!(1.5)) == x.powf(dd!(1.5)));
+        assert!(float(x).ln_1p() == x.ln_1p());
+        assert!(float(x).mul_add(dd!(3), dd!(4)) == x.mul_add(dd!(3), dd!(4)));
+        assert!(float(x).classify() == x.classify());
+    });
+}
@@ -0,0 +1,240 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::common as c;
+use crate::double::Double;
+
+impl Double {
+    /// Evaluates a polynomial at *x* using Horner's method.
+    ///
+    /// The coefficients start with the constant term, so `coeffs[i]` is the coefficient of
+    /// *x*<sup>i</sup>. An empty slice is the zero polynomial and evaluates to zero, and a
+    /// single coefficient evaluates to that coefficient. If *x* or any of the coefficients
+    /// is [`NAN`], so is the result (unless the slice is empty).
+    ///
+    /// Each step of the evaluation is a [`mul_add`], but the rounding errors of the steps
+    /// still add up, and near a root of the polynomial they can be much larger than the
+    /// result. When that matters, [`polyval_compensated`] is much more accurate.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// // 1 + 2x + 3x²
+    /// let coeffs = [dd!(1), dd!(2), dd!(3)];
+    /// assert!(Double::polyval(&coeffs, dd!(2)) == dd!(17));
+    /// assert!(Double::polyval(&[], dd!(2)) == dd!(0));
+    /// ```
+    ///
+    /// [`mul_add`]: #method.mul_add
+    /// [`polyval_compensated`]: #method.polyval_compensated
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn polyval(coeffs: &[Double], x: Double) -> Double {
+        match coeffs.split_last() {
+            None => Double::ZERO,
+            Some(_) if x.is_nan() => Double::NAN,
+            Some((&last, rest)) => rest.iter().rev().fold(last, |r, &a| r.mul_add(x, a)),
+        }
+    }
+
+    /// Evaluates a polynomial at *x* using a compensated version of Horner's method.
+    ///
+    /// This works like [`polyval`], but it also calculates the rounding error of every
+    /// product and sum in the evaluation and runs those errors through a second Horner
+    /// recurrence of their own. Adding that to the result at the end makes it as accurate
+    /// as if it had been evaluated with twice the precision of a `Double` and then rounded.
+    /// In particular, polynomials evaluated near their roots, where the terms cancel each
+    /// other, keep nearly full precision in cases where [`polyval`] loses most of it.
+    ///
+    /// This is several times slower than [`polyval`]. Its handling of empty slices, single
+    /// coefficients, and NaNs is the same. If any step of the evaluation overflows, there's
+    /// no error to compensate for, and the result is the same as [`polyval`]'s.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// // (x - 1)^7, expanded, evaluated close to its root
+    /// let coeffs: Vec<Double> = [-1, 7, -21, 35, -35, 21, -7, 1]
+    ///     .iter()
+    ///     .map(|&a| dd!(a))
+    ///     .collect();
+    /// let x = dd!(1) + Double::PI.ldexp(-14);
+    /// let expected = (x - dd!(1)).powi(7);
+    ///
+    /// // Horner's method gets barely five digits right, while the compensated version gets
+    /// // nearly all of them
+    /// let naive = Double::polyval(&coeffs, x);
+    /// let compensated = Double::polyval_compensated(&coeffs, x);
+    /// assert!(((naive - expected) / expected).abs() > dd!(1e-6));
+    /// assert!(((compensated - expected) / expected).abs() < dd!(1e-30));
+    /// ```
+    ///
+    /// [`polyval`]: #method.polyval
+    pub fn polyval_compensated(coeffs: &[Double], x: Double) -> Double {
+        let (&last, rest) = match coeffs.split_last() {
+            None => return Double::ZERO,
+            Some(split) => split,
+        };
+        if x.is_nan() {
+            return Double::NAN;
+        }
+        let mut r = last;
+        let mut err = Double::ZERO;
+        for &a in rest.iter().rev() {
+            let p = r * x;
+            let s = p + a;
+            if !s.is_finite() {
+                // Either overflow or a NaN coefficient, and neither has an error term
+                return Double::polyval(coeffs, x);
+            }
+            let e = c::mul_add(r, x, -p) + c::add_error(p, a, s);
+            err = err.mul_add(x, e);
+            r = s;
+        }
+        r + err
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The coefficients of the Chebyshev polynomial T_n, constant term first
+    fn chebyshev(n: usize) -> Vec<Double> {
+        let mut prev = vec![1i64];
+        let mut curr = vec![0i64, 1];
+        for _ in 1..n {
+            let mut next = vec![0i64; curr.len() + 1];
+            for (i, &a) in curr.iter().enumerate() {
+                next[i + 1] += 2 * a;
+            }
+            for (i, &a) in prev.iter().enumerate() {
+                next[i] -= a;
+            }
+            prev = curr;
+            curr = next;
+        }
+        curr.iter().map(|&a| Double::from(a)).collect()
+    }
+
+    // The coefficients of the first n terms of the Taylor series of e^x
+    fn inv_facts(n: usize) -> Vec<Double> {
+        let mut coeffs = vec![Double::ONE];
+        for i in 1..n {
+            coeffs.push(coeffs[i - 1] / Double::from(i as u32));
+        }
+        coeffs
+    }
+
+    // polyval tests
+    test_all_exact!(
+        polyval_empty:
+            Double::ZERO,
+            Double::polyval(&[], Double::PI);
+        polyval_empty_nan:
+            Double::ZERO,
+            Double::polyval(&[], Double::NAN);
+        polyval_constant:
+            Double::E,
+            Double::polyval(&[Double::E], Double::PI);
+        polyval_constant_inf:
+            Double::E,
+            Double::polyval(&[Double::E], Double::INFINITY);
+        polyval_quadratic:
+            dd!(17),
+            Double::polyval(&[dd!(1), dd!(2), dd!(3)], dd!(2));
+        polyval_neg:
+            dd!(-32),
+            Double::polyval(&[dd!(1), dd!(-2), dd!(-3)], dd!(3));
+        polyval_zero:
+            dd!(1),
+            Double::polyval(&[dd!(1), dd!(2), dd!(3)], Double::ZERO);
+        polyval_chebyshev_1:
+            dd!(1),
+            Double::polyval(&chebyshev(20), dd!(1));
+        polyval_chebyshev_neg_1:
+            dd!(1),
+            Double::polyval(&chebyshev(20), dd!(-1));
+
+        polyval_inf:
+            Double::INFINITY,
+            Double::polyval(&[dd!(1), dd!(2), dd!(3)], Double::INFINITY);
+        polyval_neg_inf:
+            Double::INFINITY,
+            Double::polyval(&[dd!(1), dd!(2), dd!(3)], Double::NEG_INFINITY);
+        polyval_nan:
+            Double::NAN,
+            Double::polyval(&[dd!(1), dd!(2)], Double::NAN);
+        polyval_nan_constant:
+            Double::NAN,
+            Double::polyval(&[dd!(1)], Double::NAN);
+        polyval_nan_coeff:
+            Double::NAN,
+            Double::polyval(&[dd!(1), Double::NAN, dd!(3)], dd!(2));
+        polyval_nan_last:
+            Double::NAN,
+            Double::polyval(&[dd!(1), dd!(2), Double::NAN], dd!(2));
+    );
+    test_all_near!(
+        polyval_pi:
+            dd!("36.89199851044766233342875976618746"),
+            Double::polyval(&[dd!(1), dd!(2), dd!(3)], Double::PI);
+        polyval_series:
+            dd!("2.718281828459045235360287471352660"),
+            Double::polyval(&inv_facts(30), dd!(1));
+    );
+
+    // polyval_compensated tests
+    test_all_exact!(
+        polyval_compensated_empty:
+            Double::ZERO,
+            Double::polyval_compensated(&[], Double::PI);
+        polyval_compensated_empty_nan:
+            Double::ZERO,
+            Double::polyval_compensated(&[], Double::NAN);
+        polyval_compensated_constant:
+            Double::E,
+            Double::polyval_compensated(&[Double::E], Double::PI);
+        polyval_compensated_quadratic:
+            dd!(17),
+            Double::polyval_compensated(&[dd!(1), dd!(2), dd!(3)], dd!(2));
+        polyval_compensated_chebyshev_1:
+            dd!(1),
+            Double::polyval_compensated(&chebyshev(20), dd!(1));
+
+        polyval_compensated_inf:
+            Double::INFINITY,
+            Double::polyval_compensated(&[dd!(1), dd!(2), dd!(3)], Double::INFINITY);
+        polyval_compensated_nan:
+            Double::NAN,
+            Double::polyval_compensated(&[dd!(1), dd!(2)], Double::NAN);
+        polyval_compensated_nan_constant:
+            Double::NAN,
+            Double::polyval_compensated(&[dd!(1)], Double::NAN);
+        polyval_compensated_nan_coeff:
+            Double::NAN,
+            Double::polyval_compensated(&[dd!(1), Double::NAN, dd!(3)], dd!(2));
+    );
+    test_all_near!(
+        polyval_compensated_pi:
+            dd!("36.89199851044766233342875976618746"),
+            Double::polyval_compensated(&[dd!(1), dd!(2), dd!(3)], Double::PI);
+    );
+
+    // T_20 has a root at cos(π/40), and this x is about 3e-8 away from it. The terms of
+    // the polynomial are as large as 10^7 there while its value is about 10^-5, so Horner's
+    // method loses about 11 of its 31 digits to cancellation.
+    test!(polyval_chebyshev_near_root: {
+        let coeffs = chebyshev(20);
+        let x = Double(0.9969173, 0.0);
+        let expected = dd!("-8.598884683929931707859413995825182524871606e-6");
+
+        let naive = Double::polyval(&coeffs, x);
+        let compensated = Double::polyval_compensated(&coeffs, x);
+        let naive_err = ((naive - expected) / expected).abs();
+        let compensated_err = ((compensated - expected) / expected).abs();
+        assert!(naive_err > dd!(1e-23), "naive error only {:e}", naive_err);
+        assert!(compensated_err < dd!(1e-30), "compensated error {:e}", compensated_err);
+    });
+}
@@ -0,0 +1,483 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::radix as r;
+use crate::common::radix::RadixString;
+use crate::double::common as c;
+use crate::double::Double;
+use crate::error::ParseDoubleError;
+
+impl Double {
+    /// Writes the exact value of the `Double` in any radix from 2 to 36, rounded to no more
+    /// than `max_digits` significant digits.
+    ///
+    /// The digits come from the exact value of the components, not from repeated
+    /// multiplication in `Double` arithmetic, so every digit is correct. If the expansion
+    /// has more than `max_digits` digits, it's rounded to nearest (with ties going to an
+    /// even last digit), and the result's [`is_exact`] is `false`. That always happens for
+    /// a number with a fractional part in an odd radix, since the value of a `Double` is
+    /// always a fraction with a power of two in its denominator. In an even radix, the
+    /// expansion always ends eventually.
+    ///
+    /// Digits past 9 are written as the letters `a` through `z`, or as `A` through `Z`
+    /// after [`to_uppercase`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range 2 to 36, or if `max_digits` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// // 0.1 can't be represented exactly, and its approximation shows in base 2
+    /// let r = dd!(0.1).to_string_radix_any(2, 24);
+    /// assert!(r.to_string() == "0.000110011001100110011001101...");
+    ///
+    /// // Integers are exact in any radix
+    /// let r = dd!(100).to_string_radix_any(7, 10);
+    /// assert!(r.to_string() == "202");
+    /// assert!(r.is_exact());
+    ///
+    /// let r = dd!(1) / dd!(3);
+    /// assert!(r.to_string_radix_any(3, 20).to_string() == "0.1...");
+    /// ```
+    ///
+    /// [`is_exact`]: struct.RadixString.html#method.is_exact
+    /// [`to_uppercase`]: struct.RadixString.html#method.to_uppercase
+    pub fn to_string_radix_any(&self, radix: u32, max_digits: usize) -> RadixString {
+        r::to_radix(&[self.0, self.1], radix, max_digits)
+    }
+
+    /// Parses a string of digits in any radix from 2 to 36 into a `Double`.
+    ///
+    /// The string may have a leading sign and a radix point, and leading and trailing
+    /// whitespace is trimmed. The letters `a` through `z` (in either case) are the digits
+    /// past 9. There's no exponent. This is the inverse of [`to_string_radix_any`]: the
+    /// string that a [`RadixString`] displays, if it's exact, parses back into exactly the
+    /// same `Double`.
+    ///
+    /// Strings that can't be represented exactly are rounded, one component at a time:
+    /// each component is the closest `f64` to what's left after the components before it.
+    /// Numbers too large for a `Double` parse as infinity, and numbers too small parse as
+    /// zero.
+    ///
+    /// Failure will return a [`ParseDoubleError`]. Its kind will be `Empty` if the string
+    /// is empty or contains only whitespace, `InvalidDigit` if there's a character that
+    /// isn't a digit in the radix or is in the wrong place, and `Invalid` if there are no
+    /// digits at all.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range 2 to 36.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::from_string_radix_any("-101.11", 2).unwrap();
+    /// assert!(x == dd!(-5.75));
+    ///
+    /// let s = Double::PI.to_string_radix_any(16, 100).to_string();
+    /// assert!(Double::from_string_radix_any(&s, 16).unwrap() == Double::PI);
+    /// ```
+    ///
+    /// [`to_string_radix_any`]: #method.to_string_radix_any
+    /// [`RadixString`]: struct.RadixString.html
+    /// [`ParseDoubleError`]: error/struct.ParseDoubleError.html
+    pub fn from_string_radix_any(s: &str, radix: u32) -> Result<Double, ParseDoubleError> {
+        let mut a = [0.0; 2];
+        r::parse(s, radix, &mut a).map_err(|kind| ParseDoubleError { kind })?;
+        Ok(c::from_components(a[0], a[1]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::random::Rng;
+    use crate::error::ErrorKind;
+
+    // Bitwise equality, except that the signs of zeros after the first component don't
+    // count, since digits have no way to carry them
+    fn same(a: Double, b: Double) -> bool {
+        let bits = |x: Double| {
+            let mut bits = x.to_bits();
+            for b in bits.iter_mut().skip(1) {
+                if *b == 1 << 63 {
+                    *b = 0;
+                }
+            }
+            bits
+        };
+        bits(a) == bits(b)
+    }
+
+    fn digits(x: Double, radix: u32, max_digits: usize) -> (String, i32, bool) {
+        let r = x.to_string_radix_any(radix, max_digits);
+        (String::from(r.digits()), r.exponent(), r.is_exact())
+    }
+
+    fn parse(s: &str, radix: u32) -> Double {
+        Double::from_string_radix_any(s, radix).unwrap()
+    }
+
+    fn parse_err(s: &str, radix: u32) -> ErrorKind {
+        Double::from_string_radix_any(s, radix).unwrap_err().kind
+    }
+
+    fn randoms() -> Vec<Double> {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        (0..100)
+            .map(|_| {
+                let n = rng.next_u64();
+                let x = Double::from((n >> 11) as f64) / Double::from(3.0);
+                x.powi((n % 40) as i32 - 20)
+            })
+            .collect()
+    }
+
+    // Rounds a complete expansion in an even radix to `n` digits, using the digits
+    // themselves to decide: the discarded part is exactly half when its first digit is
+    // radix / 2 and the rest are zeros.
+    fn round_digits(full: &str, exp: i32, radix: u32, n: usize) -> (String, i32) {
+        let value = |c: u8| (c as char).to_digit(radix).unwrap();
+        let bytes = full.as_bytes();
+        if bytes.len() <= n {
+            return (String::from(full), exp);
+        }
+        let next = value(bytes[n]);
+        let rest = bytes[n + 1..].iter().any(|&c| c != b'0');
+        let last = value(bytes[n - 1]);
+        let up = next > radix / 2 || (next == radix / 2 && (rest || last % 2 == 1));
+
+        let mut kept: Vec<u32> = bytes[..n].iter().map(|&c| value(c)).collect();
+        let mut exp = exp;
+        if up {
+            let mut i = n;
+            while i > 0 && kept[i - 1] == radix - 1 {
+                kept[i - 1] = 0;
+                i -= 1;
+            }
+            if i > 0 {
+                kept[i - 1] += 1;
+            } else {
+                kept = vec![1];
+                exp += 1;
+            }
+        }
+        while kept.len() > 1 && kept[kept.len() - 1] == 0 {
+            kept.pop();
+        }
+        let s = kept
+            .iter()
+            .map(|&d| std::char::from_digit(d, radix).unwrap())
+            .collect();
+        (s, exp)
+    }
+
+    // to_string_radix_any tests
+    test_all_eq!(
+        tenth_binary_10:
+            (String::from("1100110011"), -4, false),
+            digits(dd!(0.1), 2, 10);
+        tenth_binary_12:
+            (String::from("110011001101"), -4, false),
+            digits(dd!(0.1), 2, 12);
+        tenth_binary_24:
+            (String::from("110011001100110011001101"), -4, false),
+            digits(dd!(0.1), 2, 24);
+        tenth_binary_full:
+            (
+                String::from("1100110011001100110011001100110011001100110011001100110011001100110011001100110011001100110011001100110011"),
+                -4,
+                true
+            ),
+            digits(dd!(0.1), 2, 1000);
+        tenth_decimal:
+            (String::from("99999999999999999999999999999999691851208898042264"), -2, false),
+            digits(dd!(0.1), 10, 50);
+        tenth_decimal_short:
+            (String::from("1"), -1, false),
+            digits(dd!(0.1), 10, 20);
+        third_ternary:
+            (String::from("1"), -1, false),
+            digits(dd!(1) / dd!(3), 3, 20);
+        third_ternary_long:
+            (
+                String::from("22222222222222222222222222222222222222222222222222222222222222222222010212012201"),
+                -2,
+                false
+            ),
+            digits(dd!(1) / dd!(3), 3, 80);
+        pi_decimal:
+            (String::from("3141592653589793238462643383279505878967"), 0, false),
+            digits(Double::PI, 10, 40);
+        pi_decimal_33:
+            (String::from("314159265358979323846264338327951"), 0, false),
+            digits(Double::PI, 10, 33);
+        pi_ternary:
+            (String::from("10010211012222010211002111111"), 1, false),
+            digits(Double::PI, 3, 30);
+        pi_septenary:
+            (String::from("306636514320361341102634"), 0, false),
+            digits(Double::PI, 7, 25);
+        pi_base_36:
+            (String::from("353i5ab8p5fsa5jhk72i8c87vka5a8"), 0, false),
+            digits(Double::PI, 36, 30);
+        pi_hex:
+            (String::from("3243f6a8885a308d313198a2e038"), 0, true),
+            digits(Double::PI, 16, 100);
+        integer_septenary:
+            (String::from("202"), 2, true),
+            digits(dd!(100), 7, 10);
+        integer_septenary_short:
+            (String::from("2"), 2, false),
+            digits(dd!(100), 7, 2);
+        integer_base_36:
+            (String::from("z"), 0, true),
+            digits(dd!(35), 36, 5);
+        carry:
+            (String::from("1"), 3, false),
+            digits(dd!(999), 10, 2);
+        carry_ternary:
+            (String::from("1"), 3, false),
+            digits(dd!(26.75), 3, 3);
+        tie_down:
+            (String::from("2"), 0, false),
+            digits(dd!(2.5), 10, 1);
+        tie_up:
+            (String::from("4"), 0, false),
+            digits(dd!(3.5), 10, 1);
+        tie_ternary_even:
+            (String::from("222"), 2, false),
+            digits(dd!(26.5), 3, 3);
+        tie_ternary:
+            (String::from("2"), -1, false),
+            digits(dd!(0.5), 3, 1);
+        tie_ternary_int:
+            (String::from("12"), 1, false),
+            digits(dd!(4.5), 3, 2);
+        smallest_binary:
+            (String::from("1"), -1074, true),
+            digits(Double(5e-324, 0.0), 2, 5);
+        smallest_base_36:
+            (String::from("2jev3"), -208, false),
+            digits(Double(5e-324, 0.0), 36, 5);
+        largest_decimal:
+            (String::from("179769313486231580793728971405302307166"), 308, false),
+            digits(Double::MAX, 10, 40);
+        zero:
+            (String::from("0"), 0, true),
+            digits(Double::ZERO, 10, 5);
+        inf:
+            (String::from("inf"), 0, false),
+            digits(Double::INFINITY, 10, 5);
+        nan:
+            (String::from("NaN"), 0, false),
+            digits(Double::NAN, 10, 5);
+    );
+
+    test_all_eq!(
+        display_fraction:
+            "0.011",
+            dd!(0.375).to_string_radix_any(2, 10).to_string();
+        display_mixed:
+            "-101.11",
+            dd!(-5.75).to_string_radix_any(2, 10).to_string();
+        display_trailing_zeros:
+            "200...",
+            dd!(100).to_string_radix_any(7, 1).to_string();
+        display_inexact:
+            "0.000110011001101...",
+            dd!(0.1).to_string_radix_any(2, 12).to_string();
+        display_upper:
+            "Z.I",
+            dd!(35.5).to_string_radix_any(36, 5).to_uppercase().to_string();
+        display_lower:
+            "z.i",
+            dd!(35.5).to_string_radix_any(36, 5).to_string();
+        display_width:
+            "   0.011",
+            format!("{:>8}", dd!(0.375).to_string_radix_any(2, 10));
+        display_zero:
+            "0",
+            Double::ZERO.to_string_radix_any(3, 5).to_string();
+        display_neg_zero:
+            "-0",
+            Double::NEG_ZERO.to_string_radix_any(3, 5).to_string();
+        display_inf:
+            "inf",
+            Double::INFINITY.to_string_radix_any(3, 5).to_string();
+        display_neg_inf:
+            "-inf",
+            Double::NEG_INFINITY.to_string_radix_any(3, 5).to_string();
+        display_nan:
+            "NaN",
+            Double::NAN.to_string_radix_any(3, 5).to_string();
+    );
+
+    test_all_assert!(
+        neg_zero_sign:
+            Double::NEG_ZERO.to_string_radix_any(10, 5).is_sign_negative();
+        neg_sign:
+            (-Double::PI).to_string_radix_any(10, 5).is_sign_negative();
+        pos_sign:
+            !Double::PI.to_string_radix_any(10, 5).is_sign_negative();
+        inf_not_finite:
+            !Double::INFINITY.to_string_radix_any(10, 5).is_finite();
+        upper_inf:
+            Double::INFINITY.to_string_radix_any(36, 5).to_uppercase().digits() == "inf";
+    );
+
+    test!(rounding_boundaries: {
+        for x in randoms() {
+            for &radix in &[2, 6, 10, 16, 36] {
+                let full = x.to_string_radix_any(radix, 5000);
+                assert!(full.is_exact());
+                for n in 1..=60 {
+                    let r = x.to_string_radix_any(radix, n);
+                    assert!(r.digits().len() <= n);
+                    let expected = round_digits(full.digits(), full.exponent(), radix, n);
+                    assert_eq!((String::from(r.digits()), r.exponent()), expected);
+                    assert_eq!(r.is_exact(), full.digits().len() <= n);
+                }
+            }
+        }
+    });
+
+    test!(odd_radix_never_exact: {
+        for x in randoms() {
+            for &radix in &[3, 5, 7, 35] {
+                let r = x.to_string_radix_any(radix, 5000);
+                assert_eq!(r.is_exact(), x.fract().is_zero());
+            }
+        }
+    });
+
+    #[test]
+    #[should_panic(expected = "radix must be between 2 and 36, not 37")]
+    fn radix_too_large() {
+        Double::PI.to_string_radix_any(37, 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must be between 2 and 36, not 1")]
+    fn radix_too_small() {
+        let _ = Double::from_string_radix_any("1", 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "max_digits must be at least 1")]
+    fn no_digits() {
+        Double::PI.to_string_radix_any(10, 0);
+    }
+
+    // from_string_radix_any tests
+    test!(round_trip: {
+        let mut values = randoms();
+        values.extend_from_slice(&[
+            Double::PI,
+            -Double::E,
+            Double::MAX,
+            Double::MIN_POSITIVE,
+            Double(5e-324, 0.0),
+            Double(1.0, 5e-324),
+            Double(1.0, -(2f64.powi(-60))),
+        ]);
+        for x in values {
+            for &radix in &[2, 6, 10, 16, 36] {
+                let r = x.to_string_radix_any(radix, 5000);
+                assert!(r.is_exact());
+                let y = parse(&r.to_string(), radix);
+                assert!(same(x, y), "{:?} became {:?} in radix {}", x, y, radix);
+                let y = parse(&r.to_uppercase().to_string(), radix);
+                assert!(same(x, y));
+            }
+        }
+    });
+
+    test!(round_trip_integers: {
+        for &radix in &[3, 5, 7, 11, 35] {
+            for &x in &[dd!(100), -dd!(12345678901234567890u64), Double::MAX] {
+                let r = x.to_string_radix_any(radix, 5000);
+                assert!(r.is_exact());
+                assert!(same(parse(&r.to_string(), radix), x));
+            }
+        }
+    });
+
+    test_all_exact!(
+        parse_binary:
+            dd!(-5.75),
+            parse("-101.11", 2);
+        parse_plus:
+            dd!(5.75),
+            parse("+101.11", 2);
+        parse_spaces:
+            dd!(5.75),
+            parse("  101.11\n", 2);
+        parse_upper:
+            dd!(1295),
+            parse("ZZ", 36);
+        parse_mixed_case:
+            dd!(1295),
+            parse("zZ", 36);
+        parse_trailing_point:
+            dd!(5),
+            parse("101.", 2);
+        parse_leading_point:
+            dd!(0.75),
+            parse(".11", 2);
+        parse_ternary_third:
+            Double(3.333333333333333e-1, 1.850371707708594e-17),
+            parse("0.1", 3);
+        parse_decimal_tenth:
+            Double(1e-1, -5.551115123125783e-18),
+            parse("0.1", 10);
+        parse_tiny:
+            Double::ZERO,
+            parse(&format!("0.{}1", "0".repeat(400)), 10);
+        parse_huge:
+            Double::INFINITY,
+            parse(&"1".repeat(400), 10);
+        parse_neg_huge:
+            Double::NEG_INFINITY,
+            parse(&format!("-{}", "z".repeat(300)), 36);
+    );
+
+    test!(parse_zeros: {
+        assert!(same(parse("0", 7), Double::ZERO));
+        assert!(same(parse("-0.000", 7), Double::NEG_ZERO));
+    });
+
+    test_all_eq!(
+        parse_empty:
+            ErrorKind::Empty,
+            parse_err("", 10);
+        parse_blank:
+            ErrorKind::Empty,
+            parse_err("  ", 10);
+        parse_no_digits:
+            ErrorKind::Invalid,
+            parse_err("-.", 10);
+        parse_bad_digit:
+            ErrorKind::InvalidDigit { position: 2 },
+            parse_err("102", 2);
+        parse_bad_digit_offset:
+            ErrorKind::InvalidDigit { position: 4 },
+            parse_err("  10z", 35);
+        parse_two_points:
+            ErrorKind::InvalidDigit { position: 3 },
+            parse_err("1.0.1", 2);
+        parse_late_sign:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("1-0", 2);
+        parse_ellipsis:
+            ErrorKind::InvalidDigit { position: 4 },
+            parse_err("0.11...", 2);
+        parse_exponent:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("1e5", 10);
+    );
+}
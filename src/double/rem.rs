@@ -3,36 +3,56 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::strict as s;
+use crate::common::utils as u;
+use crate::double::common as c;
 use crate::double::Double;
-use std::ops::{Div, Rem, RemAssign};
+use std::ops::{Rem, RemAssign};
 
 impl Rem for Double {
     type Output = Double;
 
     /// Divides this `Double` by another, producing a new `Double` of the remainder as a
-    /// result. This operation uses floored division.
+    /// result.
     ///
-    /// This implements the `%` operator between two `Double`s.
+    /// This implements the `%` operator between two `Double`s, with the same semantics as
+    /// the `%` operator between two `f64`s. The quotient is truncated toward zero, so the
+    /// remainder has the sign of the dividend and is smaller in magnitude than the
+    /// divisor. The remainder of an infinite dividend or of division by zero is NaN, and
+    /// the remainder of a finite dividend divided by an infinity is the dividend itself.
+    ///
+    /// The quotient is never calculated. Instead the remainder is calculated exactly, by
+    /// long division, and then rounded once, so it's accurate even when the quotient is
+    /// far too large for every one of its digits to matter.
     ///
     /// # Examples
     /// ```
     /// # use qd::{dd, Double};
     /// let x = Double::PI % Double::E;
-    /// let xpected = dd!("0.4233108251307480031023559119268");
+    /// let xpected = dd!("0.42331082513074800310235591192684125");
     ///
     /// let diffx = (x - xpected).abs();
     /// assert!(diffx < dd!(1e-30));
     ///
-    /// let y = Double::PI % -Double::E;
-    /// let ypected = dd!("-2.2949710033282972322579315594258");
-    ///
-    /// let diffy = (y - ypected).abs();
+    /// // The remainder has the sign of the dividend
+    /// let y = -Double::PI % -Double::E;
+    /// let diffy = (y + xpected).abs();
     /// assert!(diffy < dd!(1e-30));
+    ///
+    /// // The quotient is about 3.2 × 10^29, but the remainder is still accurate
+    /// let z = -dd!("1e30") % Double::PI;
+    /// let zpected = dd!("-0.089286059060774930921005165361603668");
+    ///
+    /// let diffz = (z - zpected).abs();
+    /// assert!(diffz < dd!(1e-32));
     /// ```
     #[inline]
     fn rem(self, other: Double) -> Double {
-        let n = self.div(other).floor();
-        self - other * n
+        let r = match self.pre_rem(&other) {
+            Some(r) => r,
+            None => rem_trunc(self, other),
+        };
+        s::guard("Double % Double", &[self.0, other.0], r)
     }
 }
 
@@ -40,7 +60,7 @@ impl Rem for &Double {
     type Output = Double;
 
     /// Divides a reference to this `Double` by another, producing a new `Double` of the
-    /// remainder as a result. This operation uses floored division.
+    /// remainder as a result. The quotient is truncated toward zero, as it is for `f64`s.
     ///
     /// This implements the `%` operator between two references to `Double`s.
     ///
@@ -48,21 +68,19 @@ impl Rem for &Double {
     /// ```
     /// # use qd::{dd, Double};
     /// let x = &Double::PI % &Double::E;
-    /// let xpected = dd!("0.4233108251307480031023559119268");
+    /// let xpected = dd!("0.42331082513074800310235591192684125");
     ///
     /// let diffx = (x - xpected).abs();
     /// assert!(diffx < dd!(1e-30));
     ///
-    /// let y = &Double::PI % -Double::E;
-    /// let ypected = dd!("-2.2949710033282972322579315594258");
-    ///
-    /// let diffy = (y - ypected).abs();
+    /// // The remainder has the sign of the dividend
+    /// let y = &-Double::PI % &Double::E;
+    /// let diffy = (y + xpected).abs();
     /// assert!(diffy < dd!(1e-30));
     /// ```
     #[inline]
     fn rem(self, other: &Double) -> Double {
-        let n = self.div(*other).floor();
-        *self - *other * n
+        (*self).rem(*other)
     }
 }
 
@@ -70,7 +88,7 @@ impl Rem<&Double> for Double {
     type Output = Double;
 
     /// Divides this `Double` by a reference to another, producing a new `Double` of the
-    /// remainder as a result. This operation uses floored division.
+    /// remainder as a result. The quotient is truncated toward zero, as it is for `f64`s.
     ///
     /// This implements the `%` operator between a `Double` and a reference to a `Double`.
     ///
@@ -78,57 +96,54 @@ impl Rem<&Double> for Double {
     /// ```
     /// # use qd::{dd, Double};
     /// let x = Double::PI % &Double::E;
-    /// let xpected = dd!("0.4233108251307480031023559119268");
+    /// let xpected = dd!("0.42331082513074800310235591192684125");
     ///
     /// let diffx = (x - xpected).abs();
     /// assert!(diffx < dd!(1e-30));
     ///
-    /// let y = Double::PI % -&Double::E;
-    /// let ypected = dd!("-2.2949710033282972322579315594258");
-    ///
-    /// let diffy = (y - ypected).abs();
+    /// // The remainder has the sign of the dividend
+    /// let y = -Double::PI % -&Double::E;
+    /// let diffy = (y + xpected).abs();
     /// assert!(diffy < dd!(1e-30));
     /// ```
     #[inline]
     fn rem(self, other: &Double) -> Double {
-        let n = self.div(*other).floor();
-        self - *other * n
+        self.rem(*other)
     }
 }
 
 impl Rem<Double> for &Double {
     type Output = Double;
 
-    /// Divides a reference to this `Double` by another `Double`, producing a new `Double`
-    /// of the remainder as a result. This operation uses floored division.
+    /// Divides a reference to this `Double` by another `Double`, producing a new `Double` of
+    /// the remainder as a result. The quotient is truncated toward zero, as it is for
+    /// `f64`s.
     ///
-    /// This implements the `%` operator between a reference to a `Double`s and a `Double`.
+    /// This implements the `%` operator between a reference to a `Double` and a `Double`.
     ///
     /// # Examples
     /// ```
     /// # use qd::{dd, Double};
     /// let x = &Double::PI % Double::E;
-    /// let xpected = dd!("0.4233108251307480031023559119268");
+    /// let xpected = dd!("0.42331082513074800310235591192684125");
     ///
     /// let diffx = (x - xpected).abs();
     /// assert!(diffx < dd!(1e-30));
     ///
-    /// let y = &Double::PI % -&Double::E;
-    /// let ypected = dd!("-2.2949710033282972322579315594258");
-    ///
-    /// let diffy = (y - ypected).abs();
+    /// // The remainder has the sign of the dividend
+    /// let y = &-Double::PI % Double::E;
+    /// let diffy = (y + xpected).abs();
     /// assert!(diffy < dd!(1e-30));
     /// ```
     #[inline]
     fn rem(self, other: Double) -> Double {
-        let n = self.div(other).floor();
-        *self - other * n
+        (*self).rem(other)
     }
 }
 
 impl RemAssign for Double {
-    /// Divides this `Double` by another, modifying this one to equal the remainder. This
-    /// operation uses floored division.
+    /// Divides this `Double` by another, modifying this one to equal the remainder. The
+    /// quotient is truncated toward zero, as it is for `f64`s.
     ///
     /// This implements the `%=` operator between two `Double`s.
     ///
@@ -137,16 +152,15 @@ impl RemAssign for Double {
     /// # use qd::{dd, Double};
     /// let mut x = Double::PI;
     /// x %= Double::E;
-    /// let xpected = dd!("0.4233108251307480031023559119268");
+    /// let xpected = dd!("0.42331082513074800310235591192684125");
     ///
     /// let diffx = (x - xpected).abs();
     /// assert!(diffx < dd!(1e-30));
     ///
-    /// let mut y = Double::PI;
+    /// let mut y = -Double::PI;
     /// y %= -Double::E;
-    /// let ypected = dd!("-2.2949710033282972322579315594258");
     ///
-    /// let diffy = (y - ypected).abs();
+    /// let diffy = (y + xpected).abs();
     /// assert!(diffy < dd!(1e-30));
     /// ```
     #[inline]
@@ -159,7 +173,7 @@ impl RemAssign for Double {
 
 impl RemAssign<&Double> for Double {
     /// Divides this `Double` by a reference to another, modifying this one to equal the
-    /// remainder. This operation uses floored division.
+    /// remainder. The quotient is truncated toward zero, as it is for `f64`s.
     ///
     /// This implements the `%=` operator between a `Double` and a reference to a `Double`.
     ///
@@ -168,16 +182,15 @@ impl RemAssign<&Double> for Double {
     /// # use qd::{dd, Double};
     /// let mut x = Double::PI;
     /// x %= &Double::E;
-    /// let xpected = dd!("0.4233108251307480031023559119268");
+    /// let xpected = dd!("0.42331082513074800310235591192684125");
     ///
     /// let diffx = (x - xpected).abs();
     /// assert!(diffx < dd!(1e-30));
     ///
-    /// let mut y = Double::PI;
+    /// let mut y = -Double::PI;
     /// y %= -&Double::E;
-    /// let ypected = dd!("-2.2949710033282972322579315594258");
     ///
-    /// let diffy = (y - ypected).abs();
+    /// let diffy = (y + xpected).abs();
     /// assert!(diffy < dd!(1e-30));
     /// ```
     #[inline]
@@ -188,9 +201,187 @@ impl RemAssign<&Double> for Double {
     }
 }
 
+impl Double {
+    /// Calculates the quotient of Euclidean division of the `Double` by `rhs`.
+    ///
+    /// This has the same semantics as [`f64::div_euclid`]. The result is the integer *n*
+    /// such that `self` = *n* × `rhs` + *r*, where *r* is [`rem_euclid`] of the same
+    /// arguments and is in [0, |`rhs`|). In other words, it's `self / rhs` rounded toward
+    /// negative infinity if `rhs` is positive and toward positive infinity if `rhs` is
+    /// negative.
+    ///
+    /// The quotient is derived from the exact remainder, so it's the right integer even
+    /// when `self / rhs` is close enough to an integer to round onto it. Quotients larger
+    /// than about 10<sup>31</sup> can't all be represented, so those are rounded.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let a = dd!(7);
+    /// let b = dd!(4);
+    /// assert!(a.div_euclid(b) == dd!(1));
+    /// assert!((-a).div_euclid(b) == dd!(-2));
+    /// assert!(a.div_euclid(-b) == dd!(-1));
+    /// assert!((-a).div_euclid(-b) == dd!(2));
+    /// ```
+    ///
+    /// [`f64::div_euclid`]: https://doc.rust-lang.org/std/primitive.f64.html#method.div_euclid
+    /// [`rem_euclid`]: #method.rem_euclid
+    pub fn div_euclid(self, rhs: Double) -> Double {
+        match self.pre_div_euclid(&rhs) {
+            Some(r) => s::guard("Double::div_euclid", &[self.0, rhs.0], r),
+            None => {
+                // `self` minus the truncated remainder is a multiple of `rhs` (up to
+                // rounding), so dividing it by `rhs` only needs rounding to an integer
+                let r = rem_trunc(self, rhs);
+                let n = self - r;
+                let q = if n.is_zero() {
+                    if self.is_sign_negative() == rhs.is_sign_negative() {
+                        Double::ZERO
+                    } else {
+                        Double::NEG_ZERO
+                    }
+                } else {
+                    (n / rhs).round()
+                };
+                if r < Double::ZERO {
+                    if rhs > Double::ZERO {
+                        q - Double::ONE
+                    } else {
+                        q + Double::ONE
+                    }
+                } else {
+                    q
+                }
+            }
+        }
+    }
+
+    /// Calculates the least nonnegative remainder of the `Double` divided by `rhs`.
+    ///
+    /// This has the same semantics as [`f64::rem_euclid`]. The result is in
+    /// [0, |`rhs`|), and it differs from `self` by an integer multiple of `rhs`, namely
+    /// [`div_euclid`] of the same arguments.
+    ///
+    /// Like the `%` operator, this doesn't calculate the quotient first. The remainder is
+    /// calculated exactly, by long division, and then rounded once, so it's accurate even
+    /// when the quotient is far too large for every one of its digits to matter. As with
+    /// `f64`, a negative `self` that is tiny compared to `rhs` can produce |`rhs`| itself,
+    /// because that's what |`rhs`| minus a tiny number rounds to.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let a = dd!(7);
+    /// let b = dd!(4);
+    /// assert!(a.rem_euclid(b) == dd!(3));
+    /// assert!((-a).rem_euclid(b) == dd!(1));
+    /// assert!(a.rem_euclid(-b) == dd!(3));
+    /// assert!((-a).rem_euclid(-b) == dd!(1));
+    ///
+    /// // The quotient is about 3.2 × 10^29, but the remainder is still accurate
+    /// let x = dd!("1e30").rem_euclid(Double::PI);
+    /// let expected = dd!("0.089286059060774930921005165361603668");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-32));
+    /// ```
+    ///
+    /// [`f64::rem_euclid`]: https://doc.rust-lang.org/std/primitive.f64.html#method.rem_euclid
+    /// [`div_euclid`]: #method.div_euclid
+    pub fn rem_euclid(self, rhs: Double) -> Double {
+        match self.pre_rem_euclid(&rhs) {
+            Some(r) => s::guard("Double::rem_euclid", &[self.0, rhs.0], r),
+            None => {
+                let r = rem_trunc(self, rhs);
+                if r < Double::ZERO {
+                    r + rhs.abs()
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
+    // Precalc functions
+    //
+    // This series of functions returns `Some` with a value that is to be returned, if it
+    // turns out that the function doesn't have to be calculated because a shortcut result
+    // is known. They return `None` if the value has to be calculated normally.
+    //
+    // This keeps the public functions from being mucked up with code that does validation
+    // rather than calculation.
+
+    #[inline]
+    fn pre_rem(&self, other: &Double) -> Option<Double> {
+        if self.is_nan() || other.is_nan() || self.is_infinite() || other.is_zero() {
+            Some(Double::NAN)
+        } else if other.is_infinite() || self.is_zero() {
+            Some(*self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_div_euclid(&self, rhs: &Double) -> Option<Double> {
+        if self.is_nan() || rhs.is_nan() {
+            Some(Double::NAN)
+        } else if rhs.is_zero() || self.is_infinite() {
+            Some(*self / *rhs)
+        } else if rhs.is_infinite() {
+            if *self < Double::ZERO {
+                if rhs.is_sign_positive() {
+                    Some(Double::NEG_ONE)
+                } else {
+                    Some(Double::ONE)
+                }
+            } else {
+                Some(*self / *rhs)
+            }
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_rem_euclid(&self, rhs: &Double) -> Option<Double> {
+        if self.is_nan() || rhs.is_nan() || self.is_infinite() || rhs.is_zero() {
+            Some(Double::NAN)
+        } else if rhs.is_infinite() {
+            if *self < Double::ZERO {
+                Some(Double::INFINITY)
+            } else {
+                Some(*self)
+            }
+        } else {
+            None
+        }
+    }
+}
+
+// Calculates the remainder of the division of `a` by `b` with the quotient truncated toward
+// zero, like the `%` operator on `f64`s does, so the remainder has the sign of `a`. Both
+// have to be finite, and `b` can't be zero. The remainder is exact until it's rounded into
+// the returned `Double`.
+fn rem_trunc(a: Double, b: Double) -> Double {
+    let (x, y) = (a.abs(), b.abs());
+    let t = u::rem_terms(&[x.0, x.1], &[y.0, y.1]);
+    let n = t.len();
+    let rest: f64 = t[..n - 2].iter().sum();
+    let r = c::from_components(t[n - 1], t[n - 2] + rest);
+    if a.is_sign_negative() {
+        -r
+    } else {
+        r
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::random::Rng;
+    use crate::quad::Quad;
 
     // rem tests
     test_all_near!(
@@ -207,16 +398,16 @@ mod tests {
             dd!("0.42331082513074800310235591192684125"),
             &Double::PI % &Double::E;
         num_neg_num:
-            dd!("-2.2949710033282972322579315594258203"),
+            dd!("0.42331082513074800310235591192684125"),
             Double::PI % -Double::E;
         num_neg_ref:
-            dd!("-2.2949710033282972322579315594258203"),
+            dd!("0.42331082513074800310235591192684125"),
             Double::PI % -&Double::E;
         ref_neg_num:
-            dd!("-2.2949710033282972322579315594258203"),
+            dd!("0.42331082513074800310235591192684125"),
             &Double::PI % -Double::E;
         ref_neg_ref:
-            dd!("-2.2949710033282972322579315594258203"),
+            dd!("0.42331082513074800310235591192684125"),
             &Double::PI % -&Double::E;
         num_id:
             dd!("0.1415926535897932384626433832795028"),
@@ -245,26 +436,26 @@ mod tests {
             Double::NAN,
             Double::INFINITY % Double::ZERO;
         zero_inf:
-            Double::NAN,
+            Double::ZERO,
             Double::ZERO % Double::INFINITY;
         neg_inf_zero:
             Double::NAN,
             Double::NEG_INFINITY % Double::ZERO;
         zero_neg_inf:
-            Double::NAN,
+            Double::ZERO,
             Double::ZERO % Double::NEG_INFINITY;
 
         inf_one:
             Double::NAN,
             Double::INFINITY % Double::ONE;
         one_inf:
-            Double::NAN,
+            Double::ONE,
             Double::ONE % Double::INFINITY;
         neg_inf_one:
             Double::NAN,
             Double::NEG_INFINITY % Double::ONE;
         one_neg_inf:
-            Double::NAN,
+            Double::ONE,
             Double::ONE % Double::NEG_INFINITY;
         inf_inf:
             Double::NAN,
@@ -290,6 +481,290 @@ mod tests {
             Double::ONE % Double::NAN;
     );
 
+    test_all_exact!(
+        pos_pos:
+            dd!(3),
+            dd!(7) % dd!(4);
+        neg_pos:
+            dd!(-3),
+            dd!(-7) % dd!(4);
+        pos_neg:
+            dd!(3),
+            dd!(7) % dd!(-4);
+        neg_neg:
+            dd!(-3),
+            dd!(-7) % dd!(-4);
+        frac:
+            dd!(-1.25),
+            dd!(-7.25) % dd!(-2);
+        small_num:
+            dd!(-0.5),
+            dd!(-0.5) % dd!(4);
+        neg_one_inf:
+            Double::NEG_ONE,
+            Double::NEG_ONE % Double::INFINITY;
+        big_pos_pos:
+            dd!(2),
+            Double(2f64.powi(60), 1.0) % dd!(3);
+        big_neg_pos:
+            dd!(-2),
+            Double(-(2f64.powi(60)), -1.0) % dd!(3);
+        big_pos_neg:
+            dd!(2),
+            Double(2f64.powi(60), 1.0) % dd!(-3);
+        huger:
+            dd!(2),
+            Double(2f64.powi(100), 1.0) % dd!(5);
+        hugest:
+            dd!(-2),
+            Double(-(2f64.powi(1000)), 0.0) % dd!(-7);
+    );
+    test_all_assert!(
+        neg_multiple_sign:
+            (dd!(-8) % dd!(4)).is_sign_negative();
+        pos_multiple_sign:
+            (dd!(8) % dd!(-4)).is_sign_positive();
+        neg_zero_sign:
+            (Double::NEG_ZERO % Double::ONE).is_sign_negative();
+    );
+
+    // `%` on `f64`s is exact, so on `Double`s made from them it has to give exactly the same
+    // result, even when the quotient is far beyond 2^53
+    test!(matches_f64: {
+        let mut rng = Rng::new(0x0123_4567_89ab_cdef);
+        let mut random = |e: i32| {
+            let x = (1.0 + rng.next_f64()) * 2f64.powi(e);
+            if rng.next_u64() % 2 == 0 { x } else { -x }
+        };
+        for i in 0..20_000 {
+            let y = random(i % 200 - 100);
+            let x = random(i % 200 - 100 + i % 400);
+            let r = Double(x, 0.0) % Double(y, 0.0);
+            assert!(r == Double(x % y, 0.0), "{:?} % {:?} = {:?}", x, y, r);
+            assert!(r.is_sign_negative() == x.is_sign_negative());
+        }
+    });
+
+    // div_euclid tests
+    test_all_exact!(
+        div_euclid_pos_pos:
+            dd!(1),
+            dd!(7).div_euclid(dd!(4));
+        div_euclid_neg_pos:
+            dd!(-2),
+            dd!(-7).div_euclid(dd!(4));
+        div_euclid_pos_neg:
+            dd!(-1),
+            dd!(7).div_euclid(dd!(-4));
+        div_euclid_neg_neg:
+            dd!(2),
+            dd!(-7).div_euclid(dd!(-4));
+        div_euclid_multiple:
+            dd!(-2),
+            dd!(-8).div_euclid(dd!(4));
+        div_euclid_small:
+            Double::NEG_ONE,
+            dd!("-1e-40").div_euclid(dd!(4));
+        div_euclid_below_int:
+            dd!(2),
+            Double(9.42477796076938, 3.6739403974420594e-16).div_euclid(Double::PI);
+        div_euclid_above_int:
+            dd!(3),
+            Double(9.42477796076938, 3.67394039744206e-16).div_euclid(Double::PI);
+        div_euclid_neg_below_int:
+            dd!(-3),
+            Double(-9.42477796076938, -3.6739403974420594e-16).div_euclid(Double::PI);
+        div_euclid_big_pos_pos:
+            dd!(384_307_168_202_282_325i64),
+            Double(2f64.powi(60), 1.0).div_euclid(dd!(3));
+        div_euclid_big_neg_pos:
+            dd!(-384_307_168_202_282_326i64),
+            Double(-(2f64.powi(60)), -1.0).div_euclid(dd!(3));
+        div_euclid_big_pos_neg:
+            dd!(-384_307_168_202_282_325i64),
+            Double(2f64.powi(60), 1.0).div_euclid(dd!(-3));
+        div_euclid_big_neg_neg:
+            dd!(384_307_168_202_282_326i64),
+            Double(-(2f64.powi(60)), -1.0).div_euclid(dd!(-3));
+        div_euclid_huger:
+            dd!("253530120045645880299340641075"),
+            Double(2f64.powi(100), 1.0).div_euclid(dd!(5));
+        div_euclid_huge_pos_pos:
+            dd!("318309886183790671537767526745"),
+            dd!("1e30").div_euclid(Double::PI);
+        div_euclid_huge_neg_pos:
+            dd!("-318309886183790671537767526746"),
+            dd!("-1e30").div_euclid(Double::PI);
+        div_euclid_huge_pos_neg:
+            dd!("-318309886183790671537767526745"),
+            dd!("1e30").div_euclid(-Double::PI);
+        div_euclid_huge_neg_neg:
+            dd!("318309886183790671537767526746"),
+            dd!("-1e30").div_euclid(-Double::PI);
+    );
+    test_all_exact!(
+        div_euclid_zero_num:
+            Double::ZERO,
+            Double::ZERO.div_euclid(dd!(4));
+        div_euclid_neg_zero_num:
+            Double::NEG_ZERO,
+            Double::NEG_ZERO.div_euclid(dd!(4));
+        div_euclid_num_zero:
+            Double::INFINITY,
+            dd!(7).div_euclid(Double::ZERO);
+        div_euclid_neg_num_zero:
+            Double::NEG_INFINITY,
+            dd!(-7).div_euclid(Double::ZERO);
+        div_euclid_zero_zero:
+            Double::NAN,
+            Double::ZERO.div_euclid(Double::ZERO);
+        div_euclid_inf_num:
+            Double::INFINITY,
+            Double::INFINITY.div_euclid(dd!(4));
+        div_euclid_inf_neg_num:
+            Double::NEG_INFINITY,
+            Double::INFINITY.div_euclid(dd!(-4));
+        div_euclid_inf_inf:
+            Double::NAN,
+            Double::INFINITY.div_euclid(Double::INFINITY);
+        div_euclid_num_inf:
+            Double::ZERO,
+            dd!(7).div_euclid(Double::INFINITY);
+        div_euclid_neg_num_inf:
+            Double::NEG_ONE,
+            dd!(-7).div_euclid(Double::INFINITY);
+        div_euclid_neg_num_neg_inf:
+            Double::ONE,
+            dd!(-7).div_euclid(Double::NEG_INFINITY);
+        div_euclid_nan_num:
+            Double::NAN,
+            Double::NAN.div_euclid(dd!(4));
+        div_euclid_num_nan:
+            Double::NAN,
+            dd!(7).div_euclid(Double::NAN);
+    );
+    test_all_assert!(
+        div_euclid_pos_neg_zero_sign:
+            dd!(1).div_euclid(dd!(-4)).is_sign_negative();
+        div_euclid_num_neg_inf_sign:
+            dd!(7).div_euclid(Double::NEG_INFINITY).is_sign_negative();
+    );
+
+    // rem_euclid tests
+    test_all_exact!(
+        rem_euclid_pos_pos:
+            dd!(3),
+            dd!(7).rem_euclid(dd!(4));
+        rem_euclid_neg_pos:
+            dd!(1),
+            dd!(-7).rem_euclid(dd!(4));
+        rem_euclid_pos_neg:
+            dd!(3),
+            dd!(7).rem_euclid(dd!(-4));
+        rem_euclid_neg_neg:
+            dd!(1),
+            dd!(-7).rem_euclid(dd!(-4));
+        rem_euclid_frac:
+            dd!(0.75),
+            dd!(-7.25).rem_euclid(dd!(2));
+        rem_euclid_below_int:
+            Double(Double::PI[0], 1.224646799147353e-16),
+            Double(9.42477796076938, 3.6739403974420594e-16).rem_euclid(Double::PI);
+        rem_euclid_above_int:
+            Double(2.465190328815662e-32, 0.0),
+            Double(9.42477796076938, 3.67394039744206e-16).rem_euclid(Double::PI);
+        rem_euclid_neg_below_int:
+            Double(2.465190328815662e-32, 0.0),
+            Double(-9.42477796076938, -3.6739403974420594e-16).rem_euclid(Double::PI);
+        rem_euclid_big_pos_pos:
+            dd!(2),
+            Double(2f64.powi(60), 1.0).rem_euclid(dd!(3));
+        rem_euclid_big_neg_pos:
+            dd!(1),
+            Double(-(2f64.powi(60)), -1.0).rem_euclid(dd!(3));
+        rem_euclid_big_pos_neg:
+            dd!(2),
+            Double(2f64.powi(60), 1.0).rem_euclid(dd!(-3));
+        rem_euclid_big_neg_neg:
+            dd!(1),
+            Double(-(2f64.powi(60)), -1.0).rem_euclid(dd!(-3));
+        rem_euclid_huger:
+            dd!(2),
+            Double(2f64.powi(100), 1.0).rem_euclid(dd!(5));
+        rem_euclid_hugest:
+            dd!(2),
+            Double(2f64.powi(1000), 0.0).rem_euclid(dd!(7));
+    );
+    test_all_near!(
+        rem_euclid_huge_pos_pos:
+            dd!("0.089286059060774930921005165361603668"),
+            dd!("1e30").rem_euclid(Double::PI);
+        rem_euclid_huge_neg_pos:
+            dd!("3.0523065945290183075416382179179022"),
+            dd!("-1e30").rem_euclid(Double::PI);
+        rem_euclid_huge_pos_neg:
+            dd!("0.089286059060774930921005165361603668"),
+            dd!("1e30").rem_euclid(-Double::PI);
+        rem_euclid_huge_neg_neg:
+            dd!("3.0523065945290183075416382179179022"),
+            dd!("-1e30").rem_euclid(-Double::PI);
+    );
+    test_all_exact!(
+        rem_euclid_multiple:
+            Double::ZERO,
+            dd!(8).rem_euclid(dd!(4));
+        rem_euclid_neg_multiple:
+            Double::NEG_ZERO,
+            dd!(-8).rem_euclid(dd!(4));
+        rem_euclid_num_zero:
+            Double::NAN,
+            dd!(7).rem_euclid(Double::ZERO);
+        rem_euclid_inf_num:
+            Double::NAN,
+            Double::INFINITY.rem_euclid(dd!(4));
+        rem_euclid_num_inf:
+            dd!(7),
+            dd!(7).rem_euclid(Double::INFINITY);
+        rem_euclid_neg_num_inf:
+            Double::INFINITY,
+            dd!(-7).rem_euclid(Double::INFINITY);
+        rem_euclid_nan_num:
+            Double::NAN,
+            Double::NAN.rem_euclid(dd!(4));
+        rem_euclid_num_nan:
+            Double::NAN,
+            dd!(7).rem_euclid(Double::NAN);
+    );
+    test_all_assert!(
+        rem_euclid_neg_multiple_sign:
+            dd!(-8).rem_euclid(dd!(4)).is_sign_negative();
+    );
+
+    // For quotients with up to 90 bits, the quotient has to be exact and the remainder
+    // has to be in [0, |b|) and within an ulp or two of exact. The identity is checked in
+    // Quad, where the product of the quotient and the divisor is exact.
+    test!(euclid_identity: {
+        let mut rng = Rng::new(0x0123_4567_89ab_cdef);
+        let mut random = |e: i32| {
+            let hi = (1.0 + rng.next_f64()) * 2f64.powi(e);
+            let lo = (rng.next_u64() >> 11) as f64 * 2f64.powi(e - 106);
+            let x = Double(hi, 0.0) + Double(lo, 0.0);
+            if rng.next_u64() % 2 == 0 { x } else { -x }
+        };
+        let quad = |x: Double| Quad::from((x.0, x.1, 0.0, 0.0));
+        for i in 0..20_000 {
+            let b = random(i % 40 - 20);
+            let a = random(i % 40 - 20 + i % 90);
+            let (q, r) = (a.div_euclid(b), a.rem_euclid(b));
+            assert!(q == q.trunc(), "q = {:?} for {:?} / {:?}", q, a, b);
+            assert!(r >= Double::ZERO && r < b.abs(), "r = {:?} for {:?} % {:?}", r, a, b);
+            let dev = (quad(a) - quad(q) * quad(b) - quad(r)).abs();
+            assert!(
+                dev <= quad(b.abs() * Double::EPSILON),
+                "a - qb - r = {:e} for a = {:?}, b = {:?}", dev, a, b
+            );
+        }
+    });
     test_all!(
         assign_num: {
             let mut a = Double::PI;
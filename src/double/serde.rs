@@ -0,0 +1,274 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::decimal::{self as dec, Decimal};
+use crate::double::common::from_components;
+use crate::double::Double;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Unexpected, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+use std::fmt;
+
+impl Serialize for Double {
+    /// Serializes a `Double`. This is only available with the `serde` feature.
+    ///
+    /// Human-readable formats (like JSON) get a decimal string in scientific notation.
+    /// This string is long enough to bring back every bit of both components when
+    /// deserialized: it's the shortest decimal that lies within a quarter of an ULP of the
+    /// second component of the exact value. That's often a couple of digits longer than the
+    /// 31 or so that `Display` writes. It can also be read by [`from_str`], though that
+    /// function only promises the usual precision rather than the exact components.
+    /// Non-finite numbers are written as `"nan"`, `"inf"`, and `"-inf"`.
+    ///
+    /// Binary formats (like bincode) get the two components as a tuple of `f64`s, which
+    /// is both smaller and faster and which carries NaN and infinity without any help.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let json = serde_json::to_string(&Double::PI).unwrap();
+    /// assert!(json == "\"3.1415926535897932384626433832795e0\"");
+    ///
+    /// let pi: Double = serde_json::from_str(&json).unwrap();
+    /// assert!(pi[0] == Double::PI[0] && pi[1] == Double::PI[1]);
+    /// ```
+    ///
+    /// [`from_str`]: #method.from_str
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let s = if self.is_nan() {
+                String::from("nan")
+            } else if self.is_infinite() {
+                String::from(if self.0 > 0.0 { "inf" } else { "-inf" })
+            } else {
+                dec::to_exact_string(&[self.0, self.1])
+            };
+            serializer.serialize_str(&s)
+        } else {
+            let mut tuple = serializer.serialize_tuple(2)?;
+            tuple.serialize_element(&self.0)?;
+            tuple.serialize_element(&self.1)?;
+            tuple.end()
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Double {
+    /// Deserializes a `Double`. This is only available with the `serde` feature.
+    ///
+    /// Human-readable formats accept a string, a number, or a sequence of two numbers. A
+    /// string is split into components exactly (so the output of [`serialize`] comes back
+    /// bit for bit) after being checked by the same rules that [`from_str`] uses. A number
+    /// is converted with [`from`], and a sequence is treated like the tuple that binary
+    /// formats use.
+    ///
+    /// Binary formats expect a tuple of two `f64`s. If those components are not normalized,
+    /// they are normalized before the `Double` is created, so any pair of finite numbers
+    /// produces the `Double` closest to their sum. A NaN in either component produces
+    /// [`NAN`], and an infinite sum produces an infinity of the same sign.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x: Double = serde_json::from_str("\"2.5e-1\"").unwrap();
+    /// assert!(x == dd!(0.25));
+    ///
+    /// let y: Double = serde_json::from_str("[1.0, 1.0]").unwrap();
+    /// assert!(y == dd!(2));
+    /// ```
+    ///
+    /// [`serialize`]: #method.serialize
+    /// [`from_str`]: #method.from_str
+    /// [`from`]: #impl-From%3Cf64%3E
+    /// [`NAN`]: #associatedconstant.NAN
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Double, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(DoubleVisitor)
+        } else {
+            deserializer.deserialize_tuple(2, DoubleVisitor)
+        }
+    }
+}
+
+struct DoubleVisitor;
+
+impl<'de> Visitor<'de> for DoubleVisitor {
+    type Value = Double;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a double-double as a string, a number, or a pair of numbers")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Double, E> {
+        let parsed = s
+            .parse::<Double>()
+            .map_err(|_| E::invalid_value(Unexpected::Str(s), &self))?;
+        // Only "nan", "inf", and the like have no digits in them
+        if !s.bytes().any(|b| b.is_ascii_digit()) {
+            return Ok(parsed);
+        }
+        let mut c = [0.0; 2];
+        dec::split(&Decimal::parse(s), &mut c);
+        Ok(Double(c[0], c[1]))
+    }
+
+    fn visit_f64<E: de::Error>(self, x: f64) -> Result<Double, E> {
+        Ok(Double::from(x))
+    }
+
+    fn visit_i64<E: de::Error>(self, x: i64) -> Result<Double, E> {
+        Ok(Double::from(x))
+    }
+
+    fn visit_u64<E: de::Error>(self, x: u64) -> Result<Double, E> {
+        Ok(Double::from(x))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Double, A::Error> {
+        let a = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let b = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        if seq.next_element::<f64>()?.is_some() {
+            return Err(de::Error::invalid_length(3, &self));
+        }
+        Ok(from_components(a, b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::random::Rng;
+    use serde_test::{assert_tokens, Configure, Token};
+
+    // Bitwise equality, so that NaN equals NaN and the sign of a zero first component counts.
+    // The sign of a zero second component isn't preserved by human-readable formats.
+    fn same(a: Double, b: Double) -> bool {
+        a[0].to_bits() == b[0].to_bits() && (a[1].to_bits() == b[1].to_bits() || a[1] == b[1])
+    }
+
+    fn json(x: Double) -> Double {
+        serde_json::from_str(&serde_json::to_string(&x).unwrap()).unwrap()
+    }
+
+    fn bincode(x: Double) -> Double {
+        bincode::deserialize(&bincode::serialize(&x).unwrap()).unwrap()
+    }
+
+    fn from_json(s: &str) -> Double {
+        serde_json::from_str(s).unwrap()
+    }
+
+    test!(tokens: {
+        assert_tokens(
+            &Double::PI.readable(),
+            &[Token::Str("3.1415926535897932384626433832795e0")],
+        );
+        assert_tokens(
+            &Double::PI.compact(),
+            &[
+                Token::Tuple { len: 2 },
+                Token::F64(Double::PI[0]),
+                Token::F64(Double::PI[1]),
+                Token::TupleEnd,
+            ],
+        );
+        assert_tokens(&Double::ZERO.readable(), &[Token::Str("0")]);
+        assert_tokens(&Double::NEG_INFINITY.readable(), &[Token::Str("-inf")]);
+    });
+
+    test!(json_constants: {
+        for &x in &[
+            Double::PI,
+            Double::E,
+            Double::LN_2,
+            Double::FRAC_1_SQRT_2,
+            Double::MAX,
+            Double::MIN,
+            Double::MIN_POSITIVE,
+            Double::ZERO,
+            Double::ONE,
+            -Double::TAU,
+        ] {
+            assert!(same(x, json(x)), "{:?} came back as {:?}", x, json(x));
+        }
+    });
+
+    test!(json_nonfinite: {
+        assert!(json(Double::NAN).is_nan());
+        assert!(json(Double::NEG_ZERO).is_sign_negative());
+        assert!(same(json(Double::INFINITY), Double::INFINITY));
+        assert!(same(json(Double::NEG_INFINITY), Double::NEG_INFINITY));
+    });
+
+    test!(json_random: {
+        let mut rng = Rng::new(0x853c_49e6_748f_ea9b);
+        for _ in 0..2_000 {
+            let scale = dd!(2).powi(rng.range(-1000, 1000));
+            let x = dd!(rng.next_f64()) / dd!(3) * scale;
+            let x = if rng.next_u64() % 2 == 0 { x } else { -x };
+            let y = json(x);
+            assert!(same(x, y), "{:?} came back as {:?}", x, y);
+        }
+    });
+
+    test!(json_input: {
+        assert!(same(from_json("\"0.1\""), dd!("0.1")));
+        assert!(same(from_json("\"-2.5e-3\""), dd!("-2.5e-3")));
+        assert!(same(from_json("\"1_000\""), dd!(1000)));
+        assert!(same(from_json("\"NaN\""), Double::NAN));
+        assert!(same(from_json("1.5"), dd!(1.5)));
+        assert!(same(from_json("-7"), dd!(-7)));
+        assert!(same(from_json("12345678901234567890"), dd!(12_345_678_901_234_567_890u64)));
+        assert!(same(from_json("[3.0, 1e-20]"), Double(3.0, 1e-20)));
+        assert!(serde_json::from_str::<Double>("\"2.5.3\"").is_err());
+        assert!(serde_json::from_str::<Double>("\"\"").is_err());
+        assert!(serde_json::from_str::<Double>("[1.0]").is_err());
+        assert!(serde_json::from_str::<Double>("[1.0, 2.0, 3.0]").is_err());
+        assert!(serde_json::from_str::<Double>("true").is_err());
+    });
+
+    // Exact decimal splitting is correctly rounded, so it's at least as close as `from_str`
+    test!(json_input_accuracy: {
+        let x = from_json("\"0.9999999303082806237436760862691\"");
+        let expected = (dd!(3).powi(15) - dd!(1)) / dd!(3).powi(15);
+        near!(expected, x);
+    });
+
+    test!(bincode_round_trip: {
+        for &x in &[
+            Double::PI,
+            -Double::E,
+            Double::MAX,
+            Double::MIN_POSITIVE,
+            Double::NEG_ZERO,
+            Double::INFINITY,
+            Double::NEG_INFINITY,
+            Double(-3.0, -0.0),
+        ] {
+            assert!(same(x, bincode(x)));
+        }
+        assert!(bincode(Double::NAN).is_nan());
+    });
+
+    test!(normalization: {
+        assert!(same(from_components(1.0, 1.0), Double(2.0, 0.0)));
+        assert!(same(from_components(1e-20, 1.0), Double(1.0, 1e-20)));
+        assert!(same(from_components(0.0, 1.0), Double(1.0, 0.0)));
+        let x = from_components(1.0, 3e-16);
+        assert!(x[0] + x[1] == x[0]);
+        assert!(Double(1.0, 0.0) + Double(3e-16, 0.0) == x);
+        assert!(from_components(1.0, f64::NAN).is_nan());
+        assert!(from_components(f64::INFINITY, f64::NEG_INFINITY).is_nan());
+        assert!(same(from_components(f64::MAX, f64::MAX), Double::INFINITY));
+        assert!(same(from_components(-1.0, f64::NEG_INFINITY), Double::NEG_INFINITY));
+
+        let bytes = bincode::serialize(&(1e-20f64, 1.0f64)).unwrap();
+        let y: Double = bincode::deserialize(&bytes).unwrap();
+        assert!(same(y, Double(1.0, 1e-20)));
+    });
+}
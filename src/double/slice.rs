@@ -0,0 +1,38 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::common as c;
+use crate::double::Double;
+use crate::slice::Sealed;
+
+impl Sealed<2> for Double {
+    const ZERO: Double = Double::ZERO;
+    const ONE: Double = Double::ONE;
+
+    #[inline]
+    fn components(self) -> [f64; 2] {
+        [self.0, self.1]
+    }
+
+    #[inline]
+    fn is_normalized(c: &[f64; 2]) -> bool {
+        c::is_normalized(c[0], c[1])
+    }
+
+    #[inline]
+    fn from_raw(c: [f64; 2]) -> Double {
+        Double(c[0], c[1])
+    }
+
+    #[inline]
+    fn from_normalizing(c: [f64; 2]) -> Double {
+        c::from_components(c[0], c[1])
+    }
+
+    #[inline]
+    fn fused_mul_add(a: Double, b: Double, c: Double) -> Double {
+        c::mul_add(a, b, c)
+    }
+}
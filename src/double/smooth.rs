@@ -0,0 +1,560 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::strict as s;
+use crate::double::Double;
+
+impl Double {
+    /// Calculates the softplus function, ln(1 + *e*<sup>x</sup>), where *x* is this
+    /// `Double`.
+    ///
+    /// Evaluating this formula directly overflows for large *x* (*e*<sup>800</sup> is
+    /// [`INFINITY`] even though the answer is just 800) and loses everything for negative
+    /// *x*, where 1 + *e*<sup>x</sup> rounds to 1. Instead, the calculation branches at
+    /// zero:
+    ///
+    /// * for *x* > 0, it's calculated as *x* + ln(1 + *e*<sup>-x</sup>), and
+    /// * for *x* <= 0, it's calculated as ln(1 + *e*<sup>x</sup>),
+    ///
+    /// with the logarithms done by [`ln_1p`]. Only non-positive arguments are ever passed
+    /// to [`exp`] that way, so nothing overflows, and the only precision lost is in the
+    /// underflow of *e*<sup>x</sup> itself, the same as in [`exp`]. That starts when *x* is
+    /// below about -670, where its second component becomes subnormal, and past about -745
+    /// *e*<sup>x</sup> is 0; there softplus(*x*) returns *x* for positive arguments and 0
+    /// for negative ones.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(1).softplus();
+    /// let expected = dd!("1.313261687518222834048995494967856");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    ///
+    /// assert!(dd!(800).softplus() == dd!(800));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    /// [`ln_1p`]: #method.ln_1p
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn softplus(self) -> Double {
+        match self.pre_softplus() {
+            Some(r) => s::guard("Double::softplus", &[self.0], r),
+            None => {
+                if self.is_sign_positive() {
+                    self + (-self).exp().ln_1p()
+                } else {
+                    self.exp().ln_1p()
+                }
+            }
+        }
+    }
+
+    /// Calculates the logistic function, 1 / (1 + *e*<sup>-x</sup>), where *x* is this
+    /// `Double`.
+    ///
+    /// The result is always between 0 and 1. Like [`softplus`], this branches at zero so
+    /// that [`exp`] is only ever called with a non-positive argument:
+    ///
+    /// * for *x* >= 0, it's calculated as 1 / (1 + *e*<sup>-x</sup>), and
+    /// * for *x* < 0, it's calculated as *e*<sup>x</sup> / (1 + *e*<sup>x</sup>).
+    ///
+    /// Both branches are accurate to full relative precision, including for very negative
+    /// arguments where the result is tiny. Because *x* and -*x* use the same value of
+    /// *e*<sup>-|x|</sup>, logistic(*x*) + logistic(-*x*) is 1 to within rounding. Below about
+    /// -745 the result underflows to 0, and for large positive *x* it rounds to exactly 1.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(1).logistic();
+    /// let expected = dd!("0.7310585786300048792511592418218363");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    /// [`softplus`]: #method.softplus
+    pub fn logistic(self) -> Double {
+        match self.pre_logistic() {
+            Some(r) => s::guard("Double::logistic", &[self.0], r),
+            None => {
+                if self.is_sign_positive() {
+                    Double::ONE / (Double::ONE + (-self).exp())
+                } else {
+                    let t = self.exp();
+                    t / (Double::ONE + t)
+                }
+            }
+        }
+    }
+
+    /// Calculates the natural logarithm of the logistic function, where *x* is this
+    /// `Double`.
+    ///
+    /// This is calculated as -[`softplus`] of -*x*, and it has the same branch point (at zero) and limits.
+    /// Calculating it as `x.logistic().ln()` instead would lose all precision for large
+    /// positive *x*, where the logistic function rounds to 1.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(10).log_logistic();
+    /// let expected = dd!("-4.539889921686464676948782930710560e-5");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-34));
+    /// ```
+    ///
+    /// [`softplus`]: #method.softplus
+    #[inline]
+    pub fn log_logistic(self) -> Double {
+        -(-self).softplus()
+    }
+
+    /// Calculates a smooth approximation of the minimum of this `Double` and another,
+    /// -ln(*e*<sup>-ka</sup> + *e*<sup>-kb</sup>) / *k*.
+    ///
+    /// The sharpness parameter *k* must be positive; larger values bring the result closer
+    /// to the true minimum, which the result never exceeds. The direct formula overflows as
+    /// soon as *ka* or *kb* is very negative, so the smaller argument *m* is factored out
+    /// first:
+    ///
+    /// smooth_min(*a*, *b*, *k*) = *m* - ln(1 + *e*<sup>-k|a - b|</sup>) / *k*
+    ///
+    /// The logarithm is done by [`ln_1p`]. This never passes a positive argument to
+    /// [`exp`], so it stays accurate for any finite arguments. When *k*|*a* - *b*| is
+    /// greater than about 745, the correction term underflows and the result is exactly *m*.
+    ///
+    /// If either argument is [`NAN`], or if *k* is not positive, the result is [`NAN`]. An
+    /// infinite *k* produces the hard minimum.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(1).smooth_min(dd!(2), dd!(10));
+    /// let expected = dd!("0.9999954601100783135353230512170693");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    /// [`ln_1p`]: #method.ln_1p
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn smooth_min(self, other: Double, k: Double) -> Double {
+        match self.pre_smooth_min(&other, &k) {
+            Some(r) => s::guard("Double::smooth_min", &[self.0, other.0, k.0], r),
+            None => {
+                let m = if self < other { self } else { other };
+                m - (-k * self.smooth_gap(other)).exp().ln_1p() / k
+            }
+        }
+    }
+
+    /// Calculates a smooth approximation of the maximum of this `Double` and another,
+    /// ln(*e*<sup>ka</sup> + *e*<sup>kb</sup>) / *k*.
+    ///
+    /// This is the mirror image of [`smooth_min`]; the larger argument *m* is factored out
+    /// and the result is calculated as
+    ///
+    /// smooth_max(*a*, *b*, *k*) = *m* + ln(1 + *e*<sup>-k|a - b|</sup>) / *k*
+    ///
+    /// using [`ln_1p`] for the logarithm, with the same limits and special cases. The
+    /// result is never less than the true maximum.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(1).smooth_max(dd!(2), dd!(10));
+    /// let expected = dd!("2.000004539889921686464676948782931");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`ln_1p`]: #method.ln_1p
+    /// [`smooth_min`]: #method.smooth_min
+    pub fn smooth_max(self, other: Double, k: Double) -> Double {
+        match self.pre_smooth_max(&other, &k) {
+            Some(r) => s::guard("Double::smooth_max", &[self.0, other.0, k.0], r),
+            None => {
+                let m = if self > other { self } else { other };
+                m + (-k * self.smooth_gap(other)).exp().ln_1p() / k
+            }
+        }
+    }
+
+    // |a - b|, except that it's zero if the two are equal. That distinction only matters
+    // when they're the same infinity, where subtracting them would produce NaN.
+    #[inline]
+    fn smooth_gap(self, other: Double) -> Double {
+        if self == other {
+            Double::ZERO
+        } else {
+            (self - other).abs()
+        }
+    }
+
+    // Precalc functions
+    //
+    // This series of functions returns `Some` with a value that is to be returned, if it
+    // turns out that the function doesn't have to be calculated because a shortcut result
+    // is known. They return `None` if the value has to be calculated normally.
+    //
+    // This keeps the public functions from being mucked up with code that does validation
+    // rather than calculation.
+
+    #[inline]
+    fn pre_softplus(&self) -> Option<Double> {
+        if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.is_infinite() {
+            Some(if self.is_sign_positive() {
+                Double::INFINITY
+            } else {
+                Double::ZERO
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_logistic(&self) -> Option<Double> {
+        if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.is_zero() {
+            Some(Double(0.5, 0.0))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_smooth_min(&self, other: &Double, k: &Double) -> Option<Double> {
+        if self.is_nan() || other.is_nan() || k.is_nan() || *k <= Double::ZERO {
+            Some(Double::NAN)
+        } else if k.is_infinite() {
+            Some(if self < other { *self } else { *other })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_smooth_max(&self, other: &Double, k: &Double) -> Option<Double> {
+        if self.is_nan() || other.is_nan() || k.is_nan() || *k <= Double::ZERO {
+            Some(Double::NAN)
+        } else if k.is_infinite() {
+            Some(if self > other { *self } else { *other })
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // softplus tests
+    test_all_near!(
+        softplus_zero:
+            Double::LN_2,
+            Double::ZERO.softplus();
+        softplus_1:
+            dd!("1.313261687518222834048995494967855641915"),
+            dd!(1).softplus();
+        softplus_neg_1:
+            dd!("0.3132616875182228340489954949678556419153"),
+            dd!(-1).softplus();
+        softplus_half:
+            dd!("0.9740769841801066808729973550811707497556"),
+            dd!(0.5).softplus();
+        softplus_2_5:
+            dd!("2.578889734292549623344043916717550997259"),
+            dd!(2.5).softplus();
+        softplus_neg_2_5:
+            dd!("0.07888973429254962334404391671755099725908"),
+            dd!(-2.5).softplus();
+        softplus_10:
+            dd!("10.0000453988992168646467694878293071056"),
+            dd!(10).softplus();
+        softplus_30:
+            dd!("30.00000000000009357622968839736779377697"),
+            dd!(30).softplus();
+        softplus_tiny:
+            dd!("0.6931471805599453094172321214586765680755"),
+            dd!(1e-30).softplus();
+        softplus_neg_tiny:
+            dd!("0.6931471805599453094172321214576765680755"),
+            dd!(-1e-30).softplus();
+    );
+    test_all_prec!(
+        softplus_neg_10:
+            dd!("0.0000453988992168646467694878293071055967815"),
+            dd!(-10).softplus(),
+            30;
+        softplus_neg_30:
+            dd!("9.357622968839736779377697424675157721618e-14"),
+            dd!(-30).softplus(),
+            30;
+        softplus_neg_limit:
+            dd!("2.6503965530043108163386794472695827e-261"),
+            dd!(-600).softplus(),
+            29;
+    );
+    test_all_exact!(
+        softplus_800:
+            dd!(800),
+            dd!(800).softplus();
+        softplus_neg_800:
+            Double::ZERO,
+            dd!(-800).softplus();
+        softplus_inf:
+            Double::INFINITY,
+            Double::INFINITY.softplus();
+        softplus_neg_inf:
+            Double::ZERO,
+            Double::NEG_INFINITY.softplus();
+        softplus_nan:
+            Double::NAN,
+            Double::NAN.softplus();
+    );
+    test!(softplus_symmetry: {
+        for x in &[dd!(1e-30), dd!(0.5), Double::PI, dd!(10), dd!(30), dd!(100)] {
+            let x = *x;
+            // The difference can only be as accurate as the larger of the two terms
+            let diff = (x.softplus() - (-x).softplus() - x).abs();
+            assert!(diff <= Double::EPSILON * x.softplus(), "diff = {:e}", diff);
+        }
+    });
+
+    // logistic tests
+    test_all_near!(
+        logistic_1:
+            dd!("0.7310585786300048792511592418218362743651"),
+            dd!(1).logistic();
+        logistic_neg_1:
+            dd!("0.2689414213699951207488407581781637256349"),
+            dd!(-1).logistic();
+        logistic_half:
+            dd!("0.6224593312018545646389005657455084787533"),
+            dd!(0.5).logistic();
+        logistic_2_5:
+            dd!("0.9241418199787564488066938233537522268693"),
+            dd!(2.5).logistic();
+        logistic_neg_2_5:
+            dd!("0.07585818002124355119330617664624777313071"),
+            dd!(-2.5).logistic();
+        logistic_10:
+            dd!("0.9999546021312975656054952237672365105449"),
+            dd!(10).logistic();
+        logistic_tiny:
+            dd!("0.50000000000000000000000000000025"),
+            dd!(1e-30).logistic();
+        logistic_neg_tiny:
+            dd!("0.49999999999999999999999999999975"),
+            dd!(-1e-30).logistic();
+    );
+    test_all_prec!(
+        // The error of exp(-10) carries through into the quotient, which leaves this just
+        // short of 31 digits
+        logistic_neg_10:
+            dd!("0.00004539786870243439450477623276348945509369"),
+            dd!(-10).logistic(),
+            30;
+        logistic_neg_30:
+            dd!("9.357622968839298953839562653284984111576e-14"),
+            dd!(-30).logistic(),
+            30;
+        logistic_neg_limit:
+            dd!("2.6503965530043108163386794472695827e-261"),
+            dd!(-600).logistic(),
+            29;
+    );
+    test_all_exact!(
+        logistic_zero:
+            dd!(0.5),
+            Double::ZERO.logistic();
+        logistic_800:
+            Double::ONE,
+            dd!(800).logistic();
+        logistic_neg_800:
+            Double::ZERO,
+            dd!(-800).logistic();
+        logistic_inf:
+            Double::ONE,
+            Double::INFINITY.logistic();
+        logistic_neg_inf:
+            Double::ZERO,
+            Double::NEG_INFINITY.logistic();
+        logistic_nan:
+            Double::NAN,
+            Double::NAN.logistic();
+    );
+    test!(logistic_symmetry: {
+        for x in &[dd!(1e-30), dd!(0.5), Double::PI, dd!(10), dd!(30), dd!(100)] {
+            let x = *x;
+            let diff = (x.logistic() + (-x).logistic() - Double::ONE).abs();
+            assert!(diff <= Double::EPSILON, "diff = {:e}", diff);
+        }
+    });
+
+    // log_logistic tests
+    test_all_near!(
+        log_logistic_1:
+            dd!("-0.3132616875182228340489954949678556419153"),
+            dd!(1).log_logistic();
+        log_logistic_neg_1:
+            dd!("-1.313261687518222834048995494967855641915"),
+            dd!(-1).log_logistic();
+        log_logistic_neg_10:
+            dd!("-10.0000453988992168646467694878293071056"),
+            dd!(-10).log_logistic();
+    );
+    test_all_prec!(
+        log_logistic_10:
+            dd!("-0.0000453988992168646467694878293071055967815"),
+            dd!(10).log_logistic(),
+            30;
+        log_logistic_30:
+            dd!("-9.357622968839736779377697424675157721618e-14"),
+            dd!(30).log_logistic(),
+            30;
+    );
+    test_all_exact!(
+        log_logistic_800:
+            Double::NEG_ZERO,
+            dd!(800).log_logistic();
+        log_logistic_neg_800:
+            dd!(-800),
+            dd!(-800).log_logistic();
+        log_logistic_nan:
+            Double::NAN,
+            Double::NAN.log_logistic();
+    );
+
+    // smooth_min tests
+    test_all_near!(
+        smooth_min_1:
+            dd!("0.6867383124817771659510045050321443580847"),
+            dd!(1).smooth_min(dd!(2), dd!(1));
+        smooth_min_10:
+            dd!("0.9999954601100783135353230512170692894403"),
+            dd!(1).smooth_min(dd!(2), dd!(10));
+        smooth_min_swapped:
+            dd!("0.9999954601100783135353230512170692894403"),
+            dd!(2).smooth_min(dd!(1), dd!(10));
+        smooth_min_neg:
+            dd!("-3.000455733226887122345851246901707330894"),
+            dd!(-3).smooth_min(dd!(0.5), dd!(2));
+        smooth_min_large:
+            dd!("999.6867383124817771659510045050321443581"),
+            dd!(1000).smooth_min(dd!(1001), dd!(1));
+        smooth_min_equal:
+            dd!("4.768950939813351563527589292847274477308"),
+            dd!(5).smooth_min(dd!(5), dd!(3));
+    );
+    test_all_exact!(
+        smooth_min_far:
+            dd!(1),
+            dd!(1).smooth_min(dd!(2), dd!(1000));
+        smooth_min_inf_k:
+            dd!(1),
+            dd!(2).smooth_min(dd!(1), Double::INFINITY);
+        smooth_min_neg_inf:
+            Double::NEG_INFINITY,
+            dd!(2).smooth_min(Double::NEG_INFINITY, dd!(1));
+        smooth_min_inf:
+            dd!(2),
+            dd!(2).smooth_min(Double::INFINITY, dd!(1));
+        smooth_min_zero_k:
+            Double::NAN,
+            dd!(1).smooth_min(dd!(2), Double::ZERO);
+        smooth_min_neg_k:
+            Double::NAN,
+            dd!(1).smooth_min(dd!(2), dd!(-1));
+        smooth_min_nan:
+            Double::NAN,
+            Double::NAN.smooth_min(dd!(2), dd!(1));
+        smooth_min_other_nan:
+            Double::NAN,
+            dd!(1).smooth_min(Double::NAN, dd!(1));
+    );
+    test!(smooth_min_converges: {
+        let a = Double::PI;
+        let b = Double::E;
+        let mut k = Double::ONE;
+        let mut last = a.smooth_min(b, k);
+        for _ in 0..10 {
+            k *= dd!(4);
+            let next = a.smooth_min(b, k);
+            assert!(next <= b);
+            assert!(next >= last);
+            last = next;
+        }
+        near!(b, last);
+    });
+
+    // smooth_max tests
+    test_all_near!(
+        smooth_max_1:
+            dd!("2.313261687518222834048995494967855641915"),
+            dd!(1).smooth_max(dd!(2), dd!(1));
+        smooth_max_10:
+            dd!("2.00000453988992168646467694878293071056"),
+            dd!(1).smooth_max(dd!(2), dd!(10));
+        smooth_max_swapped:
+            dd!("2.00000453988992168646467694878293071056"),
+            dd!(2).smooth_max(dd!(1), dd!(10));
+        smooth_max_neg:
+            dd!("0.5004557332268871223458512469017073308939"),
+            dd!(-3).smooth_max(dd!(0.5), dd!(2));
+        smooth_max_large:
+            dd!("1001.313261687518222834048995494967855642"),
+            dd!(1000).smooth_max(dd!(1001), dd!(1));
+        smooth_max_equal:
+            dd!("5.231049060186648436472410707152725522692"),
+            dd!(5).smooth_max(dd!(5), dd!(3));
+    );
+    test_all_exact!(
+        smooth_max_far:
+            dd!(2),
+            dd!(1).smooth_max(dd!(2), dd!(1000));
+        smooth_max_inf_k:
+            dd!(2),
+            dd!(2).smooth_max(dd!(1), Double::INFINITY);
+        smooth_max_inf:
+            Double::INFINITY,
+            dd!(2).smooth_max(Double::INFINITY, dd!(1));
+        smooth_max_neg_inf:
+            dd!(2),
+            dd!(2).smooth_max(Double::NEG_INFINITY, dd!(1));
+        smooth_max_zero_k:
+            Double::NAN,
+            dd!(1).smooth_max(dd!(2), Double::ZERO);
+        smooth_max_nan:
+            Double::NAN,
+            dd!(1).smooth_max(Double::NAN, dd!(1));
+    );
+    test!(smooth_max_converges: {
+        let a = Double::PI;
+        let b = Double::E;
+        let mut k = Double::ONE;
+        let mut last = a.smooth_max(b, k);
+        for _ in 0..10 {
+            k *= dd!(4);
+            let next = a.smooth_max(b, k);
+            assert!(next >= a);
+            assert!(next <= last);
+            last = next;
+        }
+        near!(a, last);
+    });
+}
@@ -4,7 +4,9 @@
 // https://opensource.org/licenses/MIT
 
 use crate::common::primitive as p;
+use crate::common::strict as s;
 use crate::common::utils as u;
+use crate::double::common as c;
 use crate::double::Double;
 use std::ops::{Sub, SubAssign};
 
@@ -26,16 +28,7 @@ impl Sub for Double {
     /// assert!(diff < dd!(1e-30));
     /// ```
     fn sub(self, other: Double) -> Double {
-        match self.pre_sub(&other) {
-            Some(r) => r,
-            None => {
-                let (s0, e0) = p::two_diff(self.0, other.0);
-                let (s1, e1) = p::two_diff(self.1, other.1);
-                let (s2, e2) = p::quick_two_sum(s0, s1 + e0);
-                let (a, b) = u::renorm2(s2, e1 + e2);
-                Double(a, b)
-            }
-        }
+        s::guard("Double - Double", &[self.0, other.0], self.sub_inner(other))
     }
 }
 
@@ -158,6 +151,43 @@ impl SubAssign<&Double> for Double {
 }
 
 impl Double {
+    /// Subtracts another `Double` from this one in a `const fn`. The result is the same as that
+    /// of the `-` operator, which can't be used in constants; see [`add_const`] for
+    /// details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// const PI_MINUS_E: Double = Double::PI.sub_const(Double::E);
+    /// assert!(PI_MINUS_E == Double::PI - Double::E);
+    /// ```
+    ///
+    /// [`add_const`]: #method.add_const
+    pub const fn sub_const(self, other: Double) -> Double {
+        self.sub_inner(other)
+    }
+
+    // The `-` operator without the strict-mode check.
+    pub(super) const fn sub_inner(self, other: Double) -> Double {
+        match self.pre_sub(&other) {
+            Some(r) => r,
+            None => {
+                let (s0, e0) = p::two_diff(self.0, other.0);
+                let (s1, e1) = p::two_diff(self.1, other.1);
+                let (s2, e2) = p::quick_two_sum(s0, s1 + e0);
+                let (a, b) = u::renorm2(s2, e1 + e2);
+                if a.is_finite() {
+                    Double(a, b)
+                } else {
+                    c::redo_halved!(
+                        0.5 * self.0 - 0.5 * other.0,
+                        c::mul_pwr2(self, 0.5).sub_inner(c::mul_pwr2(other, 0.5))
+                    )
+                }
+            }
+        }
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -168,7 +198,7 @@ impl Double {
     // rather than calculation.
 
     #[inline]
-    fn pre_sub(&self, other: &Double) -> Option<Double> {
+    const fn pre_sub(&self, other: &Double) -> Option<Double> {
         if self.is_nan() || other.is_nan() {
             Some(Double::NAN)
         } else if self.is_infinite() {
@@ -195,6 +225,14 @@ impl Double {
             } else {
                 Some(Double::INFINITY)
             }
+        } else if self.is_zero() && other.is_zero() {
+            // A negative zero less a positive one is the only difference of zeros that is
+            // negative
+            if self.is_sign_negative() && other.is_sign_positive() {
+                Some(Double::NEG_ZERO)
+            } else {
+                Some(Double::ZERO)
+            }
         } else {
             None
         }
@@ -3,23 +3,50 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::strict as s;
+use crate::common::utils as u;
 use crate::double::common as c;
 use crate::double::Double;
 
+// The natural log of MAX, rounded up. exp returns infinity for anything larger.
+const LN_MAX: f64 = 709.79;
+
+// The natural log of half of the smallest subnormal f64, rounded down. exp returns 0 for
+// anything smaller.
+const LN_MIN: f64 = -745.14;
+
+// The base-10 log of MAX, rounded up. exp10 returns infinity for anything larger.
+const LOG10_MAX: f64 = 308.26;
+
+// The base-10 log of half of the smallest subnormal f64, rounded down. exp10 returns 0 for
+// anything smaller.
+const LOG10_MIN: f64 = -323.61;
+
 const INV_K: Double = Double(0.001953125, 0.0); //   1/512, used for exp
 
+// The number of times that exp and exp_m1 double their reduced result, which undoes the
+// reduction by INV_K (2^-9).
+const EXP_DOUBLINGS: usize = 9;
+
+// The most terms from INV_FACTS that the series for exp and exp_m1 uses, which takes it
+// through the term with 1/8!.
+const EXP_TERMS: usize = 6;
+
 impl Double {
     /// Computes the exponential function, *e*<sup>x</sup>, where *x* is this `Double`.
     ///
-    /// The result of this function grows rapidly. Once *x* exceeds 708, the result is too
-    /// large to represent with a `Double`; at that point the function begins to return
-    /// [`INFINITY`]. The limit on the low end is less due to the fact that the second
-    /// component needs to fit in an `f64` rather than the first, along with extra bits used
-    /// in argument reduction; this function begins to return 0 at -600.
+    /// The result of this function grows rapidly. Once *x* exceeds ln [`MAX`] (about
+    /// 709.78), the result is too large to represent with a `Double`; at that point the
+    /// function begins to return [`INFINITY`]. On the low end, results go all the way down
+    /// into the subnormal range, and the function only returns 0 below about -745.13, where
+    /// the result is less than half of the smallest subnormal `f64`.
     ///
     /// As *x* grows this function does lose a bit of precision. It's precise to at least 30
     /// digits up to values of -140 <= x <= 150, and from then until the limits, it's
-    /// precise to at least 29 digits.
+    /// precise to at least 29 digits, except that below about -670 the result's second
+    /// component is subnormal. A subnormal component holds fewer bits the smaller it is, so
+    /// precision falls off from there: at -700 the result has about 64 bits (19 digits),
+    /// and below about -708 it's a single subnormal `f64`.
     ///
     /// # Examples
     /// ```
@@ -32,10 +59,10 @@ impl Double {
     /// ```
     ///
     /// [`INFINITY`]: #associatedconstant.INFINITY
-    #[allow(clippy::many_single_char_names)]
+    /// [`MAX`]: #associatedconstant.MAX
     pub fn exp(self) -> Double {
         match self.pre_exp() {
-            Some(r) => r,
+            Some(r) => s::guard("Double::exp", &[self.0], r),
             None => {
                 // Strategy:
                 //
@@ -61,73 +88,157 @@ impl Double {
                 // Once we have executed the Taylor series to produce an intermediate
                 // answer, we expand it to compensate for the earlier reduction.
 
-                // k = 512 is chosen; INV_K is defined above as that reciprocal
-                let eps = c::mul_pwr2(Double::EPSILON, INV_K.0);
                 // m doesn't need to be *that* accurate, so we calculate it with f64
                 // arithmetic instead of the more expensive Double arithmetic
                 let m = (self.0 / Double::LN_2.0 + 0.5).floor();
 
-                // solving for x in exp(kx + m * ln(2)). INV_K is a power of 2 so we could
-                // use mul_exp2, but on larger numbers that causes a loss of precision when
-                // used with negative powers of two because bits are being shifted to the
-                // right without accounting for the ones that are lost off the right.
-                let x = (self - Double::LN_2 * Double(m, 0.0)) * INV_K;
-
-                // This is the "x + x^2/2! + x^3/3!" part of the Taylor series.
-                let mut p = x.sqr();
-                let mut r = x + c::mul_pwr2(p, 0.5);
-                p *= x;
-                let mut t = p * c::INV_FACTS[0];
-                let mut i = 0;
+                // This is everything but the "1 +" part of the Taylor series, including the
+                // reduction by k and the expansion back out of it
+                let mut r = expm1_reduced(self - Double::LN_2.mul_i32(m as i32));
 
-                // This is the rest of the Taylor series. We perform it as many times as
-                // we need to reach our desired precision.
-                loop {
-                    r += t;
-                    p *= x;
-                    i += 1;
-                    t = p * c::INV_FACTS[i];
-                    if i >= 5 || t.abs() <= eps {
-                        break;
-                    }
+                // Finally, add the "1 +" part of the Taylor series.
+                r += Double::ONE;
+
+                // Final step of expansion, this is the "* 2^m" part. At the ends of the
+                // range 2^m isn't an f64, so this is done in two steps. Results that still
+                // don't fit become infinity, and at the bottom of the range the components
+                // become subnormal one at a time, each rounded on its own.
+                let r = c::scale(r, m as i32);
+                if r.0.is_infinite() {
+                    Double::INFINITY
+                } else {
+                    r
                 }
+            }
+        }
+    }
 
-                // Add the Taylor series parts together, then expand by the same number of
-                // times that we reduced earlier.
-                r += t;
-
-                // mul_pwr2 can be used here because multiplication doesn't lose precision
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
+    /// Computes *e*<sup>x</sup> - 1, where *x* is this `Double`.
+    ///
+    /// Calculating `x.exp() - 1` directly loses precision when *x* is near zero, because
+    /// nearly all of the digits of *e*<sup>x</sup> are then cancelled out by the
+    /// subtraction. This function avoids that by never adding the 1 in the first place
+    /// for arguments of magnitude up to ln(2) / 2 (about 0.347). Outside of that range
+    /// there is no significant cancellation, and the result is simply calculated as
+    /// [`exp`] minus one, with all of that function's limits; in particular, it will
+    /// return -1 for any *x* below about -745.13 and [`INFINITY`] for any *x* above about
+    /// 709.78.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(1e-10).exp_m1();
+    /// let expected = dd!("1.000000000050000000001666666666708333e-10");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-40));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn exp_m1(self) -> Double {
+        match self.pre_exp_m1() {
+            Some(r) => s::guard("Double::exp_m1", &[self.0], r),
+            None => {
+                if self.0.abs() <= c::mul_pwr2(Double::LN_2, 0.5).0 {
+                    expm1_reduced(self)
+                } else {
+                    self.exp() - Double::ONE
+                }
+            }
+        }
+    }
 
-                // Finally, add the "1 +" part of the Taylor series.
-                r += Double::ONE;
+    /// Computes 2<sup>x</sup>, where *x* is this `Double`.
+    ///
+    /// The integer part of *x* is split off and applied as an exact power of 2, so that
+    /// only the fractional part, times ln 2, goes through [`exp`]. That keeps the error the
+    /// same size no matter how large *x* is, and it makes the result exact whenever *x* is
+    /// an integer, subnormal results included.
+    ///
+    /// The result overflows to [`INFINITY`] once *x* reaches 1024 and underflows to 0 once
+    /// *x* reaches -1075, where the result is no more than half of the smallest subnormal
+    /// `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// assert!(dd!(-3).exp2() == dd!(0.125));
+    ///
+    /// let x = dd!(0.5).exp2();
+    /// let expected = dd!("1.4142135623730950488016887242096981");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn exp2(self) -> Double {
+        match self.pre_exp2() {
+            Some(r) => s::guard("Double::exp2", &[self.0], r),
+            None => {
+                // The rounded integer n is exact, so is x - n, which lies in [-0.5, 0.5]
+                let n = self.round();
+                let r = c::scale(((self - n) * Double::LN_2).exp(), n.0 as i32);
+                if r.0.is_infinite() {
+                    Double::INFINITY
+                } else {
+                    r
+                }
+            }
+        }
+    }
 
-                // Final step of expansion, this is the "* 2^m" part
-                r.ldexp(m as i32)
+    /// Computes 10<sup>x</sup>, where *x* is this `Double`.
+    ///
+    /// As with [`exp2`], the integer part *n* of *x* is split off, and only the fractional
+    /// part, times ln 10, goes through [`exp`]. The result of that is then multiplied by
+    /// 10<sup>*n*</sup> from a table of correctly rounded powers of ten. An integer *x*
+    /// gives exactly the power of ten that parsing `1e`*x* would, so the result is exact
+    /// when the power is one that a `Double` can hold (through 10<sup>45</sup>).
+    ///
+    /// The result overflows to [`INFINITY`] above log<sub>10</sub> [`MAX`] (about
+    /// 308.2547) and underflows to 0 below about -323.607, where the result is less than
+    /// half of the smallest subnormal `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// assert!(dd!(3).exp10() == dd!(1000));
+    ///
+    /// let x = dd!(0.5).exp10();
+    /// let expected = dd!("3.1622776601683793319988935444327185");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    /// [`exp2`]: #method.exp2
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`MAX`]: #associatedconstant.MAX
+    pub fn exp10(self) -> Double {
+        match self.pre_exp10() {
+            Some(r) => s::guard("Double::exp10", &[self.0], r),
+            None => {
+                let n = self.round();
+                let r = c::mul_pow10(((self - n) * Double::LN_10).exp(), n.0 as i32);
+                if r.0.is_infinite() {
+                    Double::INFINITY
+                } else {
+                    r
+                }
             }
         }
     }
 
     /// Calculates the natural logarithm, log<sub>*e*</sub>, of the `Double`.
     ///
-    /// This calculation relies upon the [`exp`] calculation, in the opposite direction. A
-    /// large positive logarithm, for example, will require the calculation of a large
-    /// negative exponential.
-    ///
-    /// For the same reasons that negative values of [`exp`] are limited to -600, the
-    /// accurate results of this function are limited to the number whose logarithm is 600,
-    /// which is around 2.65 &times; 10<sup>261</sup>. Take care with this; unlike in
-    /// [`exp`], [`INFINITY`] is *not* returned. In that function, exceeding the maximum
-    /// refers to actually overflowing an `f64`, which is appropriate to call [`INFINITY`];
-    /// here, it means `601`.
+    /// This calculation relies upon the [`exp`] calculation, in the opposite direction.
+    /// Numbers far from 1 are first scaled by a power of 2, which brings them close enough
+    /// to 1 for that to work and adds a multiple of ln 2 to the result. So the result is
+    /// accurate for every positive `Double`, from the smallest subnormal up to [`MAX`].
     ///
     /// # Examples
     /// ```
@@ -140,11 +251,23 @@ impl Double {
     /// ```
     ///
     /// [`exp`]: #method.exp
-    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`MAX`]: #associatedconstant.MAX
     pub fn ln(self) -> Double {
         match self.pre_ln() {
-            Some(r) => r,
+            Some(r) => s::guard("Double::ln", &[self.0], r),
             None => {
+                let n = u::exponent(self.0);
+                if n.abs() > 512 {
+                    // Far from 1, the iteration below would need the exponential of numbers
+                    // too large for exp to handle. But with x = 2^n * m,
+                    //
+                    //      ln(x) = ln(m) + n * ln(2)
+                    //
+                    // and scaling by 2^-n is exact, so the iteration only has to run on m,
+                    // which is in [1, 2).
+                    return c::scale(self, -n).ln() + Double::LN_2.mul_i32(n);
+                }
+
                 // Strategy:
                 //
                 // The Taylor series for logarithms converges much more slowly than that of
@@ -181,12 +304,72 @@ impl Double {
         }
     }
 
+    /// Calculates ln(1 + *x*), where *x* is this `Double`.
+    ///
+    /// When *x* is near zero, 1 + *x* can't be formed without losing the low digits of
+    /// *x*, and those are exactly the digits that the logarithm depends on. For
+    /// -0.5 < *x* < 0.5, this function instead uses Newton's iteration on
+    /// [`exp_m1`], which never has to form 1 + *x* at all. Outside of that range, the
+    /// loss from forming 1 + *x* is at most a bit or so, and the result is calculated as
+    /// [`ln`] of that sum.
+    ///
+    /// The function returns [`NEG_INFINITY`] if *x* is -1 and [`NAN`] if it's less than
+    /// that.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(1e-10).ln_1p();
+    /// let expected = dd!("9.999999999500000000033333333330833333e-11");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-40));
+    /// ```
+    ///
+    /// [`exp_m1`]: #method.exp_m1
+    /// [`ln`]: #method.ln
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn ln_1p(self) -> Double {
+        match self.pre_ln_1p() {
+            Some(r) => s::guard("Double::ln_1p", &[self.0], r),
+            None => {
+                if self.0.abs() < 0.5 {
+                    // Strategy:
+                    //
+                    // This finds the root of
+                    //
+                    //      f(y) = exp(y) - 1 - x
+                    //
+                    // with Newton's iteration, which in this case is
+                    //
+                    //      y' = y - (expm1(y) - x) / (expm1(y) + 1)
+                    //
+                    // Since expm1(y) and x are both accurate to full relative precision
+                    // near zero, so is their difference. Each iteration doubles the number
+                    // of correct bits, so starting from an f64 estimate, two iterations
+                    // suffice.
+                    let mut y = Double(self.0.ln_1p(), 0.0);
+                    for _ in 0..2 {
+                        let e = y.exp_m1();
+                        y -= (e - self) / (e + Double::ONE);
+                    }
+                    y
+                } else {
+                    (Double::ONE + self).ln()
+                }
+            }
+        }
+    }
+
     /// Calculates the base-10 logarithm, log<sub>10</sub>, of the `Double`.
     ///
-    /// As with [`ln`], this has an upper usable range less than the size of the numbers
-    /// themselves. In this case, that upper limit is around 10<sup>261</sup>. Over this
-    /// number, the output is not reliable, but it does not return [`INFINITY`] because the
-    /// number 261 is so plainly not infinite.
+    /// Like [`ln`], this is accurate for every positive number.
+    ///
+    /// Powers of ten up to 10<sup>22</sup> (the ones that are exact as `f64`s) produce
+    /// exact integers, and for numbers from about 0.3 up to that same limit, the floor of
+    /// the result is always the correct decimal exponent, even for numbers that are
+    /// just on one side or the other of a power of ten.
     ///
     /// # Examples
     /// ```
@@ -198,18 +381,39 @@ impl Double {
     /// assert!(diff < dd!(1e-30));
     /// ```
     ///
-    /// [`INFINITY`]: #associatedconstant.INFINITY
     /// [`ln`]: #method.ln
-    #[inline]
     pub fn log10(self) -> Double {
-        self.ln() / Double::LN_10
+        match self.pre_ln() {
+            Some(r) => s::guard("Double::log10", &[self.0], r),
+            None => {
+                let n = self.0.log10().round();
+                if (0.0..=22.0).contains(&n) {
+                    // Strategy:
+                    //
+                    // With p = 10^n, which is exact for these n,
+                    //
+                    //      log10(x) = n + ln(1 + (x - p) / p) / ln(10)
+                    //
+                    // The second term has the same sign as x - p, so the result is exactly
+                    // n when x is a power of ten, and it's on the correct side of n when
+                    // it's not, no matter how close x is to p.
+                    let n = n as i32;
+                    let p = Double(10f64.powi(n), 0.0);
+                    Double::from(n) + ((self - p) / p).ln_1p() / Double::LN_10
+                } else {
+                    self.ln() / Double::LN_10
+                }
+            }
+        }
     }
 
     /// Calculates the base-2 logarithm, log<sub>2</sub>, of the `Double`.
     ///
-    /// Since 2 is smaller than *e*, this function is constrained even more than [`ln`]. It
-    /// will start returning [`NEG_INFINITY`] at around 10<sup>-213</sup> and will start
-    /// to fail on the positive side at around 2.6 &times; 10<sup>180</sup>.
+    /// As with [`ln`], the whole range of positive numbers (subnormals included)
+    /// produces accurate results. Exact powers of two produce exact integers, and the
+    /// floor of the result is always the correct binary exponent of the number, even for
+    /// numbers that are just on one side or the other of a power of two. That makes it
+    /// safe to use `x.log2().floor()` for things like bit lengths.
     ///
     /// # Examples
     /// ```
@@ -222,16 +426,47 @@ impl Double {
     /// ```
     ///
     /// [`ln`]: #method.ln
-    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
-    #[inline]
     pub fn log2(self) -> Double {
-        self.ln() / Double::LN_2
+        match self.pre_ln() {
+            Some(r) => s::guard("Double::log2", &[self.0], r),
+            None => {
+                // Strategy:
+                //
+                // With x = 2^k * m,
+                //
+                //      log2(x) = k + ln(1 + (m - 1)) / ln(2)
+                //
+                // k is read out of the bits of the first component, adjusted so that
+                // 1/sqrt(2) < m <= sqrt(2). That makes both the scaling and the subtraction
+                // of 1 exact. The second term has the same sign as m - 1, which is the
+                // sign of x - 2^k, so the result is exactly k for a power of two and on the
+                // correct side of k for anything else.
+                let mut k = u::exponent(self.0);
+                let mut m = c::scale(self, -k);
+                if m.0 > std::f64::consts::SQRT_2 {
+                    k += 1;
+                    m = c::scale(self, -k);
+                }
+                // A tail far enough below the first component can underflow when the number
+                // is scaled down. Its value doesn't matter then, but its sign does.
+                if m.1 == 0.0 && self.1 != 0.0 {
+                    m.1 = f64::from_bits(1).copysign(self.1);
+                }
+                Double::from(k) + (m - Double::ONE).ln_1p() / Double::LN_2
+            }
+        }
     }
 
     /// Calculates the base `b` logarithm (log<sub>`b`</sub>) of the `Double`.
     ///
-    /// This function will have limits at extreme arguments like the other logarithm
-    /// functions. The difference is that those limits will depend on the base argument.
+    /// This is the ratio of the natural logarithms of `self` and `b`. When both are near 1,
+    /// the logarithms are taken with [`ln_1p`] instead so that their tiny values still have
+    /// full relative precision, and the ratio keeps it too.
+    ///
+    /// A base of 1 gives NaN, since every number but 1 would have an infinite logarithm and
+    /// 1 itself could have any. A base of ∞ gives 0 for any positive finite `self` (-0 if
+    /// `self` is less than 1), and NaN if `self` is 0 or ∞. Any number is its own logarithm
+    /// to the base of itself, so `x.log(x)` is exactly 1 for every positive finite `x`.
     ///
     /// If the goal is to calculate the base *e*, base 2, or base 10 logarithms of `self`,
     /// the specialized functions for those purposes([`ln`], [`log2`], and [`log10`]
@@ -248,13 +483,23 @@ impl Double {
     /// ```
     ///
     /// [`ln`]: #method.ln
+    /// [`ln_1p`]: #method.ln_1p
     /// [`log2`]: #method.log2
     /// [`log10`]: #method.log10
     #[inline]
     pub fn log(self, b: Double) -> Double {
         match self.pre_log(&b) {
-            Some(r) => r,
-            None => self.ln() / b.ln(),
+            Some(r) => s::guard("Double::log", &[self.0, b.0], r),
+            None => {
+                let (x, y) = (self - Double::ONE, b - Double::ONE);
+                if x.0.abs() < 0.5 && y.0.abs() < 0.5 {
+                    // Both x and y are exact here, and ln_1p doesn't lose any of their
+                    // digits to the leading 1 the way that ln does
+                    x.ln_1p() / y.ln_1p()
+                } else {
+                    self.ln() / b.ln()
+                }
+            }
         }
     }
 
@@ -269,9 +514,9 @@ impl Double {
 
     #[inline]
     fn pre_exp(&self) -> Option<Double> {
-        if self.0 < -600.0 {
+        if self.0 < LN_MIN {
             Some(Double::ZERO)
-        } else if self.0 > 708.0 {
+        } else if self.0 > LN_MAX {
             Some(Double::INFINITY)
         } else if self.is_nan() {
             Some(Double::NAN)
@@ -285,13 +530,53 @@ impl Double {
     }
 
     #[inline]
-    fn pre_ln(&self) -> Option<Double> {
+    fn pre_exp_m1(&self) -> Option<Double> {
         if self.is_nan() {
             Some(Double::NAN)
-        } else if self.is_sign_negative() {
+        } else if self.0.abs() < Double::EPSILON.0 {
+            // Includes both zeros, which keep their signs. Past this point the x^2/2 term is
+            // too small to affect the result.
+            Some(*self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_exp2(&self) -> Option<Double> {
+        if self.0 > 1024.0 {
+            Some(Double::INFINITY)
+        } else if self.0 < -1075.0 {
+            Some(Double::ZERO)
+        } else if self.is_nan() {
+            Some(Double::NAN)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_exp10(&self) -> Option<Double> {
+        if self.0 > LOG10_MAX {
+            Some(Double::INFINITY)
+        } else if self.0 < LOG10_MIN {
+            Some(Double::ZERO)
+        } else if self.is_nan() {
+            Some(Double::NAN)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_ln(&self) -> Option<Double> {
+        if self.is_nan() {
             Some(Double::NAN)
         } else if self.is_zero() {
+            // Either zero, as with `f64`
             Some(Double::NEG_INFINITY)
+        } else if self.is_sign_negative() {
+            Some(Double::NAN)
         } else if self.is_infinite() {
             Some(Double::INFINITY)
         } else if *self == Double::ONE {
@@ -302,17 +587,99 @@ impl Double {
     }
 
     #[inline]
-    fn pre_log(&self, b: &Double) -> Option<Double> {
-        if self.is_nan() {
+    fn pre_ln_1p(&self) -> Option<Double> {
+        if self.is_nan() || *self < -Double::ONE {
             Some(Double::NAN)
-        } else if b.is_sign_negative() || b.is_zero() {
+        } else if *self == -Double::ONE {
+            Some(Double::NEG_INFINITY)
+        } else if self.is_infinite() {
+            Some(Double::INFINITY)
+        } else if self.0.abs() < Double::EPSILON.0 {
+            // Includes both zeros, which keep their signs
+            Some(*self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_log(&self, b: &Double) -> Option<Double> {
+        if self.is_nan()
+            || b.is_nan()
+            || (self.is_sign_negative() && !self.is_zero())
+            || b.is_sign_negative()
+            || b.is_zero()
+            || *b == Double::ONE
+        {
             Some(Double::NAN)
+        } else if b.is_infinite() {
+            // ln(self) / ∞, which has no value when ln(self) is itself infinite
+            if self.is_zero() || self.is_infinite() {
+                Some(Double::NAN)
+            } else if *self < Double::ONE {
+                Some(Double::NEG_ZERO)
+            } else {
+                Some(Double::ZERO)
+            }
+        } else if self == b {
+            Some(Double::ONE)
         } else {
             None
         }
     }
 }
 
+// Computes exp(x) - 1 for an argument that has already been reduced so that |x| <= ln(2) / 2.
+// This is the heart of both `exp` and `exp_m1`; the former adds the 1 back in and scales by
+// the power of 2 that it took out in reduction, while the latter uses the result as is so
+// that there's no cancellation when x is near zero.
+#[allow(clippy::many_single_char_names)]
+fn expm1_reduced(a: Double) -> Double {
+    // k = 512 is chosen; INV_K is defined above as that reciprocal
+    let eps = c::mul_pwr2(Double::EPSILON, INV_K.0);
+
+    // solving for x in exp(kx + m * ln(2)). INV_K is a power of 2 so we could use mul_exp2,
+    // but on larger numbers that causes a loss of precision when used with negative powers
+    // of two because bits are being shifted to the right without accounting for the ones
+    // that are lost off the right.
+    let x = a * INV_K;
+
+    // Sum the Taylor series, then expand by the same number of times that we reduced
+    // earlier. Each of these steps is exp(2x) - 1 = 2(exp(x) - 1) + (exp(x) - 1)^2, so the
+    // result never has the 1 added to it.
+    let mut r = expm1_taylor(x, eps, EXP_TERMS);
+
+    // Doubling with ldexp is exact, so only the squares and sums round
+    for _ in 0..EXP_DOUBLINGS {
+        r = r.ldexp(1) + r.sqr();
+    }
+    r
+}
+
+// Sums the Taylor series of exp(x) - 1 for a reduced x, up to the term with the reciprocal
+// factorial INV_FACTS[terms - 1]. The sum stops early once a term is no larger than
+// `threshold`, and it also stops at the end of INV_FACTS, so asking for more terms than the
+// table has is harmless.
+fn expm1_taylor(x: Double, threshold: Double, terms: usize) -> Double {
+    // This is the "x + x^2/2! + x^3/3!" part of the Taylor series.
+    let mut p = x.sqr();
+    let mut r = x + c::mul_pwr2(p, 0.5);
+    p *= x;
+    let mut t = p * c::INV_FACTS[0];
+
+    // This is the rest of the Taylor series. We perform it as many times as we need to
+    // reach our desired precision.
+    for f in c::INV_FACTS.iter().take(terms).skip(1) {
+        r += t;
+        p *= x;
+        t = p * *f;
+        if t.abs() <= threshold {
+            break;
+        }
+    }
+    r + t
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -406,6 +773,17 @@ mod tests {
             dd!("2.6503965530043108163386794472695841e-261"),
             dd!(-600).exp(),
             29;
+        // Decimal strings this small don't parse to full precision, so these results are
+        // scaled up by 2^900 to compare them.
+        exp_neg_650:
+            dd!("4.3209860126411406279814499136755674e-12"),
+            dd!(-650).exp().ldexp(900),
+            29;
+        // The second component is subnormal, so only about 64 bits are left
+        exp_neg_700:
+            dd!("8.3341011149358153098787017561880437e-34"),
+            dd!(-700).exp().ldexp(900),
+            18;
         exp_700:
             dd!("1.0142320547350045094553295952312673e+304"),
             dd!(700).exp(),
@@ -416,9 +794,12 @@ mod tests {
             29;
     );
     test_all_exact!(
+        exp_subnormal:
+            Double(f64::from_bits(85), 0.0),
+            dd!(-740).exp();
         exp_below:
             Double::ZERO,
-            dd!(-710).exp();
+            dd!(-746).exp();
         exp_above:
             Double::INFINITY,
             dd!(710).exp();
@@ -439,6 +820,212 @@ mod tests {
             Double::NAN.exp();
     );
 
+    // exp_m1 tests
+    test_all_near!(
+        exp_m1_tiny:
+            dd!("1.000000000000000000005e-20"),
+            dd!("1e-20").exp_m1();
+        exp_m1_small:
+            dd!("1.000000000050000000001666666666708333333e-10"),
+            dd!("1e-10").exp_m1();
+        exp_m1_neg_small:
+            dd!("-9.99999999950000000001666666666625e-11"),
+            dd!("-1e-10").exp_m1();
+        exp_m1_sixteenth:
+            dd!("0.06449445891785942956339059464288967310073"),
+            dd!(0.0625).exp_m1();
+        exp_m1_neg_quarter:
+            dd!("-0.2211992169285951317548297330216793527032"),
+            dd!(-0.25).exp_m1();
+        exp_m1_point_3:
+            dd!("0.3498588075760031039837443133280073303783"),
+            dd!("0.3").exp_m1();
+        exp_m1_neg_point_3:
+            dd!("-0.2591817793182821339331262206821831278177"),
+            dd!("-0.3").exp_m1();
+        exp_m1_half:
+            dd!("0.6487212707001281468486507878141635716538"),
+            dd!(0.5).exp_m1();
+        exp_m1_2:
+            dd!("6.38905609893065022723042746057500781318"),
+            dd!(2).exp_m1();
+        exp_m1_neg_2:
+            dd!("-0.8646647167633873081060005050275155965924"),
+            dd!(-2).exp_m1();
+        exp_m1_10:
+            dd!("22025.46579480671651695790064528424436635"),
+            dd!(10).exp_m1();
+        exp_m1_neg_50:
+            dd!("-0.9999999999999999999998071250152036082217"),
+            dd!(-50).exp_m1();
+    );
+    test_all_exact!(
+        exp_m1_0:
+            Double::ZERO,
+            Double::ZERO.exp_m1();
+        exp_m1_neg_0:
+            Double::NEG_ZERO,
+            Double::NEG_ZERO.exp_m1();
+        exp_m1_below:
+            -Double::ONE,
+            dd!(-746).exp_m1();
+        exp_m1_above:
+            Double::INFINITY,
+            dd!(710).exp_m1();
+        exp_m1_inf:
+            Double::INFINITY,
+            Double::INFINITY.exp_m1();
+        exp_m1_neg_inf:
+            -Double::ONE,
+            Double::NEG_INFINITY.exp_m1();
+        exp_m1_nan:
+            Double::NAN,
+            Double::NAN.exp_m1();
+    );
+
+    // exp2 tests
+    test_all_near!(
+        exp2_small:
+            dd!("1.231144413344916284499393069167743109876137761100817794337065538246101"),
+            dd!("0.3").exp2();
+        exp2_neg_small:
+            dd!("0.6155722066724581422496965345838715549380688805504088971685327691230504"),
+            dd!("-0.7").exp2();
+        exp2_sixteenth:
+            dd!("1.044273782427413840321966478739929008784603129662713322017997784754322"),
+            dd!(0.0625).exp2();
+        exp2_half:
+            Double::SQRT_2,
+            dd!(0.5).exp2();
+        exp2_100:
+            dd!("1507499113128880389969770996485.782697605844487267829404458786340366155"),
+            dd!(100.25).exp2();
+        exp2_neg_200:
+            dd!("4.035126180763936091282268280662604639300952260567070931558646562584834e-61"),
+            dd!(-200.625).exp2();
+        exp2_1000:
+            dd!("1.515342004482324461532259326246123136395804159203502817973050762667717e+301"),
+            dd!(1000.5).exp2();
+        exp2_neg_900:
+            dd!("9.122579442644652488998101552983621248514038546642964018281044308296387e-272"),
+            dd!(-900.375).exp2();
+    );
+    test_all_exact!(
+        exp2_0:
+            Double::ONE,
+            Double::ZERO.exp2();
+        exp2_10:
+            dd!(1024),
+            dd!(10).exp2();
+        exp2_neg_3:
+            dd!(0.125),
+            dd!(-3).exp2();
+        exp2_top:
+            Double(2f64.powi(1023), 0.0),
+            dd!(1023).exp2();
+        exp2_smallest:
+            Double(f64::from_bits(1), 0.0),
+            dd!(-1074).exp2();
+        exp2_overflow:
+            Double::INFINITY,
+            dd!(1024).exp2();
+        exp2_underflow:
+            Double::ZERO,
+            dd!(-1075).exp2();
+        exp2_far_below:
+            Double::ZERO,
+            dd!(-5000).exp2();
+        exp2_inf:
+            Double::INFINITY,
+            Double::INFINITY.exp2();
+        exp2_neg_inf:
+            Double::ZERO,
+            Double::NEG_INFINITY.exp2();
+        exp2_nan:
+            Double::NAN,
+            Double::NAN.exp2();
+    );
+    test!(exp2_integers: {
+        for k in -1074..=1023 {
+            assert!(dd!(k).exp2() == c::scale(Double::ONE, k), "2^{}", k);
+        }
+    });
+    test!(exp2_thresholds: {
+        // Just under 1024, the result is finite as long as it rounds below MAX
+        assert!(Double(1024.0, -1e-14).exp2().is_finite());
+        assert!(dd!(1023.9999).exp2().is_finite());
+        // Just over -1075, the result rounds up to the smallest subnormal
+        assert!(dd!(-1074.9999).exp2() == Double(f64::from_bits(1), 0.0));
+    });
+
+    // exp10 tests
+    test_all_near!(
+        exp10_small:
+            dd!("1.995262314968879601352455396739535557986274315405346099229913667004931"),
+            dd!("0.3").exp10();
+        exp10_neg_small:
+            dd!("0.1995262314968879601352455396739535557986274315405346099229913667004931"),
+            dd!("-0.7").exp10();
+        exp10_sixteenth:
+            dd!("1.154781984689458179666482887295508281566948041479611129977426848717929"),
+            dd!(0.0625).exp10();
+        exp10_25:
+            dd!("31622776601683793319988935.44432718533719555139325216826857504852792594"),
+            dd!(25.5).exp10();
+        exp10_neg_25:
+            dd!("3.162277660168379331998893544432718533719555139325216826857504852792594e-26"),
+            dd!(-25.5).exp10();
+        exp10_300:
+            dd!("1.778279410038922801225421195192684844735790526402255358011830722776302e+300"),
+            dd!(300.25).exp10();
+        exp10_neg_280:
+            dd!("1.778279410038922801225421195192684844735790526402255358011830722776302e-281"),
+            dd!(-280.75).exp10();
+        exp10_top:
+            dd!("1.778279410038922801225421195192684844735790526402255358011830722776302e+308"),
+            dd!(308.25).exp10();
+    );
+    test_all_exact!(
+        exp10_0:
+            Double::ONE,
+            Double::ZERO.exp10();
+        exp10_3:
+            dd!(1000),
+            dd!(3).exp10();
+        exp10_neg_3:
+            dd!("0.001"),
+            dd!(-3).exp10();
+        exp10_overflow:
+            Double::INFINITY,
+            dd!(308.26).exp10();
+        exp10_underflow:
+            Double::ZERO,
+            dd!(-323.61).exp10();
+        exp10_inf:
+            Double::INFINITY,
+            Double::INFINITY.exp10();
+        exp10_neg_inf:
+            Double::ZERO,
+            Double::NEG_INFINITY.exp10();
+        exp10_nan:
+            Double::NAN,
+            Double::NAN.exp10();
+    );
+    test!(exp10_integers: {
+        for k in -323..=308 {
+            let expected: Double = format!("1e{}", k).parse().unwrap();
+            assert!(dd!(k).exp10() == expected, "10^{}", k);
+        }
+    });
+    test!(exp10_thresholds: {
+        // log10(MAX) is about 308.25471555991674
+        assert!(dd!(308.2547).exp10().is_finite());
+        assert!(dd!(308.2548).exp10().is_infinite());
+        // log10 of half of the smallest subnormal is about -323.60724533877978
+        assert!(dd!(-323.607).exp10() == Double(f64::from_bits(1), 0.0));
+        assert!(dd!(-323.608).exp10() == Double::ZERO);
+    });
+
     // ln tests
     test_all_near!(
         ln_pi:
@@ -471,6 +1058,23 @@ mod tests {
         ln_neg_290:
             dd!("-667.7496769682732483652175218584658"),
             dd!("1e-290").ln();
+        ln_300:
+            dd!("690.77552789821370520539743640530926"),
+            dd!("1e300").ln();
+        // A Double this small can only hold about 80 bits, so this is the logarithm of the
+        // number actually stored rather than of 10^-300
+        ln_neg_300:
+            dd!("-690.77552789821370520539743811832832"),
+            dd!("1e-300").ln();
+        ln_old_limit:
+            dd!("601.94926891144405436940501523550558"),
+            dd!("2.65e261").ln();
+        ln_308:
+            dd!("709.19620864216607067754136804278418"),
+            dd!("1e308").ln();
+        ln_min_subnormal:
+            dd!("-744.44007192138126231410729844608163"),
+            Double(f64::from_bits(1), 0.0).ln();
     );
     test_all_exact!(
         ln_neg_pi:
@@ -486,7 +1090,7 @@ mod tests {
             Double::NEG_INFINITY,
             Double::ZERO.ln();
         ln_neg_0:
-            Double::NAN,
+            Double::NEG_INFINITY,
             Double::NEG_ZERO.ln();
         ln_inf:
             Double::INFINITY,
@@ -499,6 +1103,66 @@ mod tests {
             Double::NAN.ln();
     );
 
+    // ln_1p tests
+    test_all_near!(
+        ln_1p_tiny:
+            dd!("9.99999999999999999995e-21"),
+            dd!("1e-20").ln_1p();
+        ln_1p_small:
+            dd!("9.999999999500000000033333333330833333334e-11"),
+            dd!("1e-10").ln_1p();
+        ln_1p_neg_small:
+            dd!("-1.000000000050000000003333333333583333333e-10"),
+            dd!("-1e-10").ln_1p();
+        ln_1p_sixteenth:
+            dd!("0.0606246218164348425806061320404202632862"),
+            dd!(0.0625).ln_1p();
+        ln_1p_neg_quarter:
+            dd!("-0.2876820724517809274392190059938274315035"),
+            dd!(-0.25).ln_1p();
+        ln_1p_point_3:
+            dd!("0.2623642644674910520354959868809543972042"),
+            dd!("0.3").ln_1p();
+        ln_1p_neg_point_3:
+            dd!("-0.356674943938732378912638711241184477964"),
+            dd!("-0.3").ln_1p();
+        ln_1p_half:
+            dd!("0.405465108108164381978013115464349136572"),
+            dd!(0.5).ln_1p();
+        ln_1p_2:
+            dd!("1.098612288668109691395245236922525704647"),
+            dd!(2).ln_1p();
+        ln_1p_neg_three_quarters:
+            dd!("-1.386294361119890618834464242916353136151"),
+            dd!(-0.75).ln_1p();
+        ln_1p_10:
+            dd!("23.02585093004045684017491454684397540934"),
+            dd!(1e10).ln_1p();
+    );
+    test_all_exact!(
+        ln_1p_0:
+            Double::ZERO,
+            Double::ZERO.ln_1p();
+        ln_1p_neg_0:
+            Double::NEG_ZERO,
+            Double::NEG_ZERO.ln_1p();
+        ln_1p_neg_1:
+            Double::NEG_INFINITY,
+            dd!(-1).ln_1p();
+        ln_1p_below:
+            Double::NAN,
+            dd!(-2).ln_1p();
+        ln_1p_inf:
+            Double::INFINITY,
+            Double::INFINITY.ln_1p();
+        ln_1p_neg_inf:
+            Double::NAN,
+            Double::NEG_INFINITY.ln_1p();
+        ln_1p_nan:
+            Double::NAN,
+            Double::NAN.ln_1p();
+    );
+
     // log10 tests
     test_all_near!(
         log10_pi:
@@ -546,7 +1210,7 @@ mod tests {
             Double::NEG_INFINITY,
             Double::ZERO.log10();
         log10_neg_0:
-            Double::NAN,
+            Double::NEG_INFINITY,
             Double::NEG_ZERO.log10();
         log10_inf:
             Double::INFINITY,
@@ -559,6 +1223,23 @@ mod tests {
             Double::NAN.log10();
     );
 
+    test!(log10_powers_of_ten: {
+        for n in 0..=22 {
+            let p = 10f64.powi(n);
+            let x = Double(p, 0.0);
+            assert!(x.log10() == Double::from(n), "log10(10^{}) = {:?}", n, x.log10());
+
+            let floor = |x: Double| x.log10().floor();
+            let up = f64::from_bits(p.to_bits() + 1);
+            let down = f64::from_bits(p.to_bits() - 1);
+            let tiny = p * 1e-40;
+            assert!(floor(Double(up, 0.0)) == Double::from(n), "10^{} + 1 ulp", n);
+            assert!(floor(Double(down, 0.0)) == Double::from(n - 1), "10^{} - 1 ulp", n);
+            assert!(floor(Double(p, tiny)) == Double::from(n), "10^{} + tiny", n);
+            assert!(floor(Double(p, -tiny)) == Double::from(n - 1), "10^{} - tiny", n);
+        }
+    });
+
     // log2 tests
     test_all_near!(
         log2_pi:
@@ -606,7 +1287,7 @@ mod tests {
             Double::NEG_INFINITY,
             Double::ZERO.log2();
         log2_neg_0:
-            Double::NAN,
+            Double::NEG_INFINITY,
             Double::NEG_ZERO.log2();
         log2_inf:
             Double::INFINITY,
@@ -619,6 +1300,30 @@ mod tests {
             Double::NAN.log2();
     );
 
+    test!(log2_powers_of_two: {
+        for k in -1074..=1023 {
+            let x = c::scale(Double::ONE, k);
+            assert!(x.log2() == Double::from(k), "log2(2^{}) = {:?}", k, x.log2());
+        }
+    });
+    test!(log2_floor_neighbors: {
+        let up = |x: f64| f64::from_bits(x.to_bits() + 1);
+        let down = |x: f64| f64::from_bits(x.to_bits() - 1);
+        let tiny = f64::from_bits(1);
+        for k in -1074..=1023 {
+            let p = c::scale(Double::ONE, k).0;
+            let floor = |x: Double| x.log2().floor();
+            if k > -1074 {
+                assert!(floor(Double(up(p), 0.0)) == Double::from(k), "2^{} + 1 ulp", k);
+                assert!(floor(Double(down(p), 0.0)) == Double::from(k - 1), "2^{} - 1 ulp", k);
+            }
+            if k > -1020 {
+                assert!(floor(Double(p, tiny)) == Double::from(k), "2^{} + tiny", k);
+                assert!(floor(Double(p, -tiny)) == Double::from(k - 1), "2^{} - tiny", k);
+            }
+        }
+    });
+
     // log tests
     test_all_near!(
         log_pi:
@@ -652,6 +1357,15 @@ mod tests {
             dd!("-213.0"),
             dd!("1e-213").log(dd!(10.0));
     );
+    test_all_prec!(
+        // Both logarithms are near 1e-25 and lose relative precision if taken with `ln`.
+        // The reference is for the Double values that the sums round to, not for 1 + 1e-25
+        // and 1 + 3e-25 themselves.
+        log_near_1:
+            dd!("0.33333333333333337159812343249634107"),
+            (Double::ONE + dd!(1e-25)).log(Double::ONE + dd!(3e-25)),
+            30;
+    );
     test_all_exact!(
         log_neg_pi:
             Double::NAN,
@@ -666,7 +1380,7 @@ mod tests {
             Double::NEG_INFINITY,
             Double::ZERO.log(dd!(10.0));
         log_neg_0:
-            Double::NAN,
+            Double::NEG_INFINITY,
             Double::NEG_ZERO.log(dd!(3.2));
         log_inf:
             Double::INFINITY,
@@ -680,5 +1394,61 @@ mod tests {
         log_base_nan:
             Double::NAN,
             Double::PI.log(Double::NAN);
+        log_1_base_nan:
+            Double::NAN,
+            Double::ONE.log(Double::NAN);
+        log_base_1:
+            Double::NAN,
+            Double::PI.log(Double::ONE);
+        log_1_base_1:
+            Double::NAN,
+            Double::ONE.log(Double::ONE);
+        log_0_base_1:
+            Double::NAN,
+            Double::ZERO.log(Double::ONE);
+        log_base_0:
+            Double::NAN,
+            Double::PI.log(Double::ZERO);
+        log_base_neg:
+            Double::NAN,
+            Double::PI.log(-Double::E);
+        log_base_inf:
+            Double::ZERO,
+            Double::PI.log(Double::INFINITY);
+        log_small_base_inf:
+            Double::NEG_ZERO,
+            Double::FRAC_1_PI.log(Double::INFINITY);
+        log_1_base_inf:
+            Double::ZERO,
+            Double::ONE.log(Double::INFINITY);
+        log_0_base_inf:
+            Double::NAN,
+            Double::ZERO.log(Double::INFINITY);
+        log_inf_base_inf:
+            Double::NAN,
+            Double::INFINITY.log(Double::INFINITY);
+        log_0_small_base:
+            Double::INFINITY,
+            Double::ZERO.log(Double::FRAC_1_PI);
+        log_inf_small_base:
+            Double::NEG_INFINITY,
+            Double::INFINITY.log(Double::FRAC_1_PI);
+        log_self:
+            Double::ONE,
+            Double::PI.log(Double::PI);
+        log_self_near_1:
+            Double::ONE,
+            (Double::ONE + Double::EPSILON).log(Double::ONE + Double::EPSILON);
     );
+
+    // series tests
+    test!(expm1_taylor_past_table: {
+        // With no threshold and an argument far too large for the series to converge, the
+        // sum uses every term in the table, and asking for more doesn't change that
+        let x = dd!(0.5);
+        let all = expm1_taylor(x, Double::ZERO, c::INV_FACTS.len());
+        exact!(all, expm1_taylor(x, Double::ZERO, c::INV_FACTS.len() + 1));
+        exact!(all, expm1_taylor(x, Double::ZERO, usize::MAX));
+        assert!(all != expm1_taylor(x, Double::ZERO, EXP_TERMS));
+    });
 }
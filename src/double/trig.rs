@@ -3,9 +3,20 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::reduction as r;
+use crate::common::strict as s;
+use crate::common::utils as u;
 use crate::double::common as c;
 use crate::double::Double;
 
+// The most Newton iterations that `atan2` runs from its `f64` starting point.
+const ATAN2_MAX_ITER: usize = 3;
+
+// How far outside of [-1, 1] an argument to `asin_clamped` or `acos_clamped` can be and
+// still be treated as ±1: four times `EPSILON`, the most that the rounding of a few
+// operations can push a number that should be ±1.
+const CLAMP_TOLERANCE: Double = Double(4.0 * Double::EPSILON.0, 0.0);
+
 impl Double {
     /// Simultaneously computes the sine (sin) and the cosine (cos) of the `Double`. This is
     /// more efficient than calling the separate [`sin`] and [`cos`] functions if you need
@@ -31,6 +42,14 @@ impl Double {
         match self.pre_sin_cos() {
             Some(r) => r,
             None => {
+                // The reduction isn't symmetric about 0, so negative arguments are taken
+                // through their absolute values. That makes sine exactly odd and cosine
+                // exactly even.
+                if self.is_sign_negative() {
+                    let (s, c) = (-self).sin_cos();
+                    return (-s, c);
+                }
+
                 let (j, k, t) = reduce(self);
                 let abs_k = k.abs() as usize;
 
@@ -62,6 +81,13 @@ impl Double {
     ///
     /// The domain of this function is (-∞, ∞), and the range is [-1, 1].
     ///
+    /// The argument is reduced exactly, as it is by [`rem_2pi`], so the result is just as
+    /// accurate for huge arguments and for arguments near the zeros of the function.
+    ///
+    /// Sine is odd, and `(-x).sin()` is exactly `-x.sin()` for every *x*. Identities that
+    /// involve π, like sin(π - *x*) = sin *x*, only hold to within the precision of the
+    /// result, because π can't be represented exactly and neither can π - *x*.
+    ///
     /// # Examples
     /// ```
     /// # use qd::{dd, Double};
@@ -71,19 +97,27 @@ impl Double {
     /// let diff = (x - expected).abs();
     /// assert!(diff < dd!(1e-30));
     /// ```
+    ///
+    /// [`rem_2pi`]: #method.rem_2pi
     #[allow(clippy::many_single_char_names)]
     pub fn sin(self) -> Double {
         match self.pre_sin() {
-            Some(r) => r,
+            Some(r) => s::guard("Double::sin", &[self.0], r),
             None => {
+                // Sine is odd; see sin_cos
+                if self.is_sign_negative() {
+                    return -(-self).sin();
+                }
+
                 // Strategy:
                 //
                 // We choose integers a and b so that
                 //
                 //      x = s + aπ/2 + bπ/16
                 //
-                // where |s| <= π/32. Using a precomputed table of sin (kπ/16) and cos
-                // (kπ/16), we can compute sin x from sin s and cos s. This greatly
+                // where |s| <= π/32, give or take the few ulps described at c::TRIG_STEP.
+                // Using a precomputed table of sin (kπ/16) and cos (kπ/16), we can
+                // compute sin x from sin s and cos s. This greatly
                 // increases the convergence of the Taylor series for sine and cosine.
                 let (j, k, t) = reduce(self);
                 let abs_k = k.abs() as usize;
@@ -124,6 +158,11 @@ impl Double {
     ///
     /// The domain of this function is (-∞, ∞), and the range is [-1, 1].
     ///
+    /// The argument is reduced exactly, as it is by [`rem_2pi`], so the result is just as
+    /// accurate for huge arguments and for arguments near the zeros of the function.
+    ///
+    /// Cosine is even, and `(-x).cos()` is exactly `x.cos()` for every *x*.
+    ///
     /// # Examples
     /// ```
     /// # use qd::{dd, Double};
@@ -133,11 +172,18 @@ impl Double {
     /// let diff = (x - expected).abs();
     /// assert!(diff < dd!(1e-30));
     /// ```
+    ///
+    /// [`rem_2pi`]: #method.rem_2pi
     #[allow(clippy::many_single_char_names)]
     pub fn cos(self) -> Double {
         match self.pre_cos() {
-            Some(r) => r,
+            Some(r) => s::guard("Double::cos", &[self.0], r),
             None => {
+                // Cosine is even; see sin_cos
+                if self.is_sign_negative() {
+                    return (-self).cos();
+                }
+
                 let (j, k, t) = reduce(self);
                 let abs_k = k.abs() as usize;
 
@@ -177,6 +223,8 @@ impl Double {
     ///
     /// The domain and range of this function are both (-∞, ∞).
     ///
+    /// Tangent is odd, and `(-x).tan()` is exactly `-x.tan()` for every *x*.
+    ///
     /// # Examples
     /// ```
     /// # use qd::{dd, Double};
@@ -191,6 +239,317 @@ impl Double {
         s / c
     }
 
+    /// Computes the remainder of the `Double` divided by 2π, the angle in [-π, π] that's
+    /// the same as the `Double` in radians.
+    ///
+    /// The multiple of 2π nearest to the `Double` is subtracted exactly, with as many bits
+    /// of π as it takes, so the result has the full precision of a `Double` no matter how
+    /// large the `Double` is. [`sin`], [`cos`], and [`tan`] reduce their arguments the same
+    /// way.
+    ///
+    /// The remainder of an infinity or NaN is NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!("1e30").rem_2pi();
+    /// let expected = dd!("-3.0513533296917402104314617953741");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// assert!(Double::PI.rem_2pi() == Double::PI);
+    /// ```
+    ///
+    /// [`sin`]: #method.sin
+    /// [`cos`]: #method.cos
+    /// [`tan`]: #method.tan
+    pub fn rem_2pi(self) -> Double {
+        if !self.is_finite() {
+            s::guard("Double::rem_2pi", &[self.0], Double::NAN)
+        } else if self.abs() <= Double::PI {
+            self
+        } else {
+            let mut r = [0.0; 2];
+            r::half_turns(&[self.0, self.1], &mut r);
+            Double(r[0], r[1]) * Double::FRAC_PI_2
+        }
+    }
+
+    /// Computes the sine (sin) of π times the `Double`, so the `Double` is an angle in
+    /// half-turns rather than radians.
+    ///
+    /// The argument is reduced modulo 2 before it's multiplied by π, and that reduction is
+    /// exact, so unlike `(x * Double::PI).sin()` this is accurate for arguments of any size.
+    /// The sine of every integer is exactly zero (with the sign of the argument), and the
+    /// sine of every odd multiple of 1/2 is exactly 1 or -1.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(1).sin_pi() == dd!(0));
+    /// assert!(dd!(0.5).sin_pi() == dd!(1));
+    ///
+    /// let x = dd!(0.25).sin_pi();
+    /// let diff = (x - Double::FRAC_1_SQRT_2).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    pub fn sin_pi(self) -> Double {
+        match self.pre_sin() {
+            Some(r) => s::guard("Double::sin_pi", &[self.0], r),
+            None => {
+                let (k, t) = reduce_half_turns(self);
+                if t.is_zero() {
+                    return match k {
+                        0 | 2 if self.is_sign_negative() => Double::NEG_ZERO,
+                        0 | 2 => Double::ZERO,
+                        1 => Double::ONE,
+                        _ => Double::NEG_ONE,
+                    };
+                }
+                let (sin_t, cos_t) = (Double::PI * t).sin_cos();
+                match k {
+                    0 => sin_t,
+                    1 => cos_t,
+                    2 => -sin_t,
+                    _ => -cos_t,
+                }
+            }
+        }
+    }
+
+    /// Computes the cosine (cos) of π times the `Double`, so the `Double` is an angle in
+    /// half-turns rather than radians.
+    ///
+    /// The argument is reduced modulo 2 before it's multiplied by π, and that reduction is
+    /// exact, so unlike `(x * Double::PI).cos()` this is accurate for arguments of any size.
+    /// The cosine of every integer is exactly 1 or -1, and the cosine of every odd multiple
+    /// of 1/2 is exactly zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(1).cos_pi() == dd!(-1));
+    /// assert!(dd!(0.5).cos_pi() == dd!(0));
+    ///
+    /// // (x * π).cos() loses about 20 digits here
+    /// let x = Double::new(1e20, 0.25).cos_pi();
+    /// let diff = (x - Double::FRAC_1_SQRT_2).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    pub fn cos_pi(self) -> Double {
+        match self.pre_cos() {
+            Some(r) => s::guard("Double::cos_pi", &[self.0], r),
+            None => {
+                let (k, t) = reduce_half_turns(self);
+                if t.is_zero() {
+                    return match k {
+                        0 => Double::ONE,
+                        2 => Double::NEG_ONE,
+                        _ => Double::ZERO,
+                    };
+                }
+                let (sin_t, cos_t) = (Double::PI * t).sin_cos();
+                match k {
+                    0 => cos_t,
+                    1 => -sin_t,
+                    2 => -cos_t,
+                    _ => sin_t,
+                }
+            }
+        }
+    }
+
+    /// Computes the tangent (tan) of π times the `Double`, so the `Double` is an angle in
+    /// half-turns rather than radians.
+    ///
+    /// This is [`sin_pi`] divided by [`cos_pi`], and it shares their exact reduction. The
+    /// tangent of every integer is exactly zero, and the tangent of every odd multiple of
+    /// 1/2 is infinite: ∞ for 1/2, -∞ for 3/2, and so on alternately.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(2).tan_pi() == dd!(0));
+    /// assert!(dd!(0.5).tan_pi() == Double::INFINITY);
+    ///
+    /// let x = dd!(0.25).tan_pi();
+    /// let diff = (x - dd!(1)).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`sin_pi`]: #method.sin_pi
+    /// [`cos_pi`]: #method.cos_pi
+    pub fn tan_pi(self) -> Double {
+        match self.pre_sin() {
+            Some(r) => s::guard("Double::tan_pi", &[self.0], r),
+            None => {
+                let (sin_x, cos_x) = (self.sin_pi(), self.cos_pi());
+                if cos_x.is_zero() {
+                    if sin_x.is_sign_positive() {
+                        Double::INFINITY
+                    } else {
+                        Double::NEG_INFINITY
+                    }
+                } else {
+                    sin_x / cos_x
+                }
+            }
+        }
+    }
+
+    /// Converts the `Double` from degrees to radians.
+    ///
+    /// The `Double` is multiplied by π/180 to the full precision of a `Double`, so this is more
+    /// accurate than `x * Double::PI / dd!(180)`, which rounds twice.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let diff = (dd!(90).to_radians() - Double::FRAC_PI_2).abs();
+    /// assert!(diff < dd!(1e-31));
+    /// ```
+    #[inline]
+    pub fn to_radians(self) -> Double {
+        self * c::RAD_PER_DEG
+    }
+
+    /// Converts the `Double` from radians to degrees.
+    ///
+    /// The `Double` is multiplied by 180/π to the full precision of a `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let diff = (Double::FRAC_PI_3.to_degrees() - dd!(60)).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    pub fn to_degrees(self) -> Double {
+        self * c::DEG_PER_RAD
+    }
+
+    /// Computes the sine (sin) of the `Double`, taking it to be an angle in degrees.
+    ///
+    /// The argument is reduced modulo 360 and then to within 45 degrees of a multiple of
+    /// 90 before it's converted to radians, and that reduction is exact. So unlike
+    /// `x.to_radians().sin()`, this is just as accurate for huge arguments as for small
+    /// ones. The sine of every multiple of 180 is exactly zero (with the sign of the
+    /// argument), the sine of every odd multiple of 90 is exactly 1 or -1, and the sine of
+    /// ±30 is exactly ±1/2 (as is the sine of anything that reduces to one of those).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(180).sin_deg() == dd!(0));
+    /// assert!(dd!(-270).sin_deg() == dd!(1));
+    /// assert!(dd!(150).sin_deg() == dd!(0.5));
+    ///
+    /// // 10^20 + 45 degrees is 325 degrees past a multiple of 360
+    /// let x = (dd!("1e20") + dd!(45)).sin_deg();
+    /// assert!(x == dd!(325).sin_deg());
+    /// let diff = (x + dd!(35).to_radians().sin()).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    pub fn sin_deg(self) -> Double {
+        match self.pre_sin() {
+            Some(r) => s::guard("Double::sin_deg", &[self.0], r),
+            None => {
+                let (k, t) = reduce_degrees(self);
+                if t.is_zero() {
+                    return match k {
+                        0 | 2 if self.is_sign_negative() => Double::NEG_ZERO,
+                        0 | 2 => Double::ZERO,
+                        1 => Double::ONE,
+                        _ => Double::NEG_ONE,
+                    };
+                }
+                let (sin_t, cos_t) = sin_cos_degrees(t);
+                match k {
+                    0 => sin_t,
+                    1 => cos_t,
+                    2 => -sin_t,
+                    _ => -cos_t,
+                }
+            }
+        }
+    }
+
+    /// Computes the cosine (cos) of the `Double`, taking it to be an angle in degrees.
+    ///
+    /// This shares the exact reduction of [`sin_deg`]. The cosine of every multiple of 360
+    /// is exactly 1, the cosine of every odd multiple of 180 is exactly -1, the cosine of
+    /// every odd multiple of 90 is exactly zero, and the cosine of ±60 is exactly 1/2.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(540).cos_deg() == dd!(-1));
+    /// assert!(dd!(90).cos_deg() == dd!(0));
+    /// assert!(dd!(-60).cos_deg() == dd!(0.5));
+    ///
+    /// let diff = (dd!(45).cos_deg() - Double::FRAC_1_SQRT_2).abs();
+    /// assert!(diff < dd!(1e-31));
+    /// ```
+    ///
+    /// [`sin_deg`]: #method.sin_deg
+    pub fn cos_deg(self) -> Double {
+        match self.pre_cos() {
+            Some(r) => s::guard("Double::cos_deg", &[self.0], r),
+            None => {
+                let (k, t) = reduce_degrees(self);
+                if t.is_zero() {
+                    return match k {
+                        0 => Double::ONE,
+                        2 => Double::NEG_ONE,
+                        _ => Double::ZERO,
+                    };
+                }
+                let (sin_t, cos_t) = sin_cos_degrees(t);
+                match k {
+                    0 => cos_t,
+                    1 => -sin_t,
+                    2 => -cos_t,
+                    _ => sin_t,
+                }
+            }
+        }
+    }
+
+    /// Computes the tangent (tan) of the `Double`, taking it to be an angle in degrees.
+    ///
+    /// This is [`sin_deg`] divided by [`cos_deg`], and it shares their exact reduction.
+    /// The tangent of every multiple of 180 is exactly zero, the tangent of every odd
+    /// multiple of 45 is exactly 1 or -1, and the tangent of every odd multiple of 90 is
+    /// infinite: ∞ for 90, -∞ for 270, and so on alternately.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(360).tan_deg() == dd!(0));
+    /// assert!(dd!(135).tan_deg() == dd!(-1));
+    /// assert!(dd!(90).tan_deg() == Double::INFINITY);
+    /// ```
+    ///
+    /// [`sin_deg`]: #method.sin_deg
+    /// [`cos_deg`]: #method.cos_deg
+    pub fn tan_deg(self) -> Double {
+        match self.pre_sin() {
+            Some(r) => s::guard("Double::tan_deg", &[self.0], r),
+            None => {
+                let (sin_x, cos_x) = (self.sin_deg(), self.cos_deg());
+                if cos_x.is_zero() {
+                    if sin_x.is_sign_positive() {
+                        Double::INFINITY
+                    } else {
+                        Double::NEG_INFINITY
+                    }
+                } else {
+                    sin_x / cos_x
+                }
+            }
+        }
+    }
+
     /// Computes the 2-argument inverse tangent (tan<sup>-1</sup>) of this `Double` and
     /// another `Double`.
     ///
@@ -210,6 +569,13 @@ impl Double {
     ///
     /// This function extends the range of the result to [-π, π].
     ///
+    /// Only the ratio of the arguments matters, and the result is accurate for any finite
+    /// arguments, from those near [`MAX`] down to the smallest normal `f64`.
+    ///
+    /// Zeros and infinities are handled as they are by `f64::atan2`. The result has the
+    /// sign of the `Double` that this is called on, so `dd!(-0.0).atan2(dd!(-1))` is -π
+    /// rather than π, and both arguments being zero gives ±0 or ±π rather than NaN.
+    ///
     /// Because this function deals with angles around the origin and Cartesian coordinates,
     /// it's very useful for converting between Cartesian and polar coordinates.
     ///
@@ -236,40 +602,56 @@ impl Double {
     /// ```
     ///
     /// [`atan`]: #method.atan
+    /// [`MAX`]: #associatedconstant.MAX
     pub fn atan2(self, other: Double) -> Double {
         match self.pre_atan2(&other) {
-            Some(r) => r,
+            Some(r) => s::guard("Double::atan2", &[self.0, other.0], r),
             None => {
                 // Strategy:
                 //
-                // Use Newton's iteration to solve one of the following equations
+                // Use Newton's iteration to solve
                 //
-                //      sin z = y / r
-                //      cos z = x / r
+                //      f(z) = y cos z - x sin z = 0
                 //
-                // where r = √(x² + y²).
+                // which holds exactly when z is the angle of (x, y). The iteration is
                 //
-                // The iteration is given by
-                //      z' = z + (y - sin z) / cos z   (for the first equation)
-                //      z' = z - (x - cos z) / sin z   (for the second equation)
+                //      z' = z + (y cos z - x sin z) / (y sin z + x cos z)
                 //
-                // Here, x and y are normalized so that x² + y² = 1. If |x| > |y|, the first
-                // iteration is used since the denominator is larger. Otherwise the second
-                // is used.
-                let r = (self.sqr() + other.sqr()).sqrt();
-                let x = other / r;
-                let y = self / r;
+                // The denominator is r cos(z - θ), where r = √(x² + y²) and θ is the true
+                // angle, so it's never far from r. Unlike solving sin z = y / r or
+                // cos z = x / r, this doesn't have to calculate r or divide by it, and so
+                // doesn't pick up the rounding errors of either. It also works the same way
+                // in every direction, so there's no choosing between equations.
+                //
+                // The products overflow if the arguments are near MAX, and they lose
+                // precision to the subnormal range if the arguments are much below 1e-150.
+                // The angle depends only on the ratio of the arguments, so in those cases
+                // both are first scaled by the same power of 2, one that brings the larger
+                // of them near 1.
+                let m = self.0.abs().max(other.0.abs());
+                let (y, x) = if !(1e-150..=1e150).contains(&m) {
+                    let k = -(m.log2().floor() as i32);
+                    (c::scale(self, k), c::scale(other, k))
+                } else {
+                    (self, other)
+                };
 
                 // Compute f64 approximation to atan
                 let mut z = Double::from(self.0.atan2(other.0));
-                let (sin_z, cos_z) = z.sin_cos();
 
-                if x.0.abs() > y.0.abs() {
-                    // Use first iteration above
-                    z += (y - sin_z) / cos_z;
-                } else {
-                    // Use second iteration above
-                    z -= (x - cos_z) / sin_z;
+                // One iteration about doubles the number of correct digits, which in theory
+                // is enough. But it works right at the limit of the precision of sin_cos,
+                // so iterations continue until a correction is below the precision of z,
+                // up to two more times.
+                let eps = c::mul_pwr2(Double::EPSILON, 2f64.powi(u::exponent(z.0) + 1));
+
+                for _ in 0..ATAN2_MAX_ITER {
+                    let (sin_z, cos_z) = z.sin_cos();
+                    let d = (y * cos_z - x * sin_z) / (y * sin_z + x * cos_z);
+                    z += d;
+                    if d.abs() < eps {
+                        break;
+                    }
                 }
                 z
             }
@@ -293,7 +675,7 @@ impl Double {
     /// [`NAN`]: #associatedconstant.NAN
     pub fn asin(self) -> Double {
         match self.pre_asin() {
-            Some(r) => r,
+            Some(r) => s::guard("Double::asin", &[self.0], r),
             None => self.atan2((Double::ONE - self.sqr()).sqrt()),
         }
     }
@@ -315,11 +697,67 @@ impl Double {
     /// [`NAN`]: #associatedconstant.NAN
     pub fn acos(self) -> Double {
         match self.pre_acos() {
-            Some(r) => r,
+            Some(r) => s::guard("Double::acos", &[self.0], r),
             None => (Double::ONE - self.sqr()).sqrt().atan2(self),
         }
     }
 
+    /// Computes the inverse sine (sin<sup>-1</sup>) of the `Double`, treating arguments that
+    /// are barely outside of [-1, 1] as ±1.
+    ///
+    /// A number that should be ±1, like the cosine of an angle worked out from a dot
+    /// product, can come out a few ulps past it because of rounding, and [`asin`] returns
+    /// [`NAN`] for it. This function first moves any argument that is no more than 4 ×
+    /// [`EPSILON`] outside of [-1, 1] to the nearer of -1 and 1, so those arguments give
+    /// ±π/2 instead. Arguments farther out than that are still out of the domain and still
+    /// result in [`NAN`]. Inside of the domain, this is exactly the same as [`asin`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::ONE + Double::EPSILON * dd!(2);
+    /// assert!(x.asin().is_nan());
+    /// assert!(x.asin_clamped() == Double::FRAC_PI_2);
+    ///
+    /// assert!(dd!(1.001).asin_clamped().is_nan());
+    /// assert!(dd!(0.5).asin_clamped() == dd!(0.5).asin());
+    /// ```
+    ///
+    /// [`asin`]: #method.asin
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn asin_clamped(self) -> Double {
+        self.clamp_unit().asin()
+    }
+
+    /// Computes the inverse cosine (cos<sup>-1</sup>) of the `Double`, treating arguments
+    /// that are barely outside of [-1, 1] as ±1.
+    ///
+    /// This is to [`acos`] what [`asin_clamped`] is to [`asin`]. Any argument that is no
+    /// more than 4 × [`EPSILON`] outside of [-1, 1] is moved to the nearer of -1 and 1
+    /// first, so it gives 0 or π instead of [`NAN`]. Arguments farther out than that still
+    /// result in [`NAN`], and inside of the domain this is exactly the same as [`acos`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = -Double::ONE - Double::EPSILON * dd!(2);
+    /// assert!(x.acos().is_nan());
+    /// assert!(x.acos_clamped() == Double::PI);
+    ///
+    /// assert!(dd!(-1.001).acos_clamped().is_nan());
+    /// assert!(dd!(0.5).acos_clamped() == dd!(0.5).acos());
+    /// ```
+    ///
+    /// [`acos`]: #method.acos
+    /// [`asin`]: #method.asin
+    /// [`asin_clamped`]: #method.asin_clamped
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn acos_clamped(self) -> Double {
+        self.clamp_unit().acos()
+    }
+
     /// Computes the inverse tangent (tan<sup>-1</sup>) of the `Double`. The domain of this
     /// function is [-∞, ∞] and the range is [-π/2, π/2].
     ///
@@ -348,7 +786,7 @@ impl Double {
     #[inline]
     fn pre_sin_cos(&self) -> Option<(Double, Double)> {
         if self.is_zero() {
-            Some((Double::ZERO, Double::ONE))
+            Some((*self, Double::ONE))
         } else if !self.is_finite() {
             Some((Double::NAN, Double::NAN))
         } else {
@@ -380,32 +818,35 @@ impl Double {
 
     #[inline]
     fn pre_atan2(&self, other: &Double) -> Option<Double> {
-        if other.is_zero() {
-            if self.is_zero() {
-                Some(Double::NAN)
-            } else if self.is_sign_positive() {
-                Some(Double::FRAC_PI_2)
+        // The special cases are those of `f64::atan2`. The result always has the sign of
+        // `self`, which is how an angle of 0 or π still tells which side of the x-axis it
+        // came from.
+        if self.is_nan() || other.is_nan() {
+            Some(Double::NAN)
+        } else if self.is_infinite() {
+            let r = if other.is_infinite() {
+                if other.is_sign_positive() {
+                    Double::FRAC_PI_4
+                } else {
+                    Double::FRAC_3_PI_4
+                }
             } else {
-                Some(-Double::FRAC_PI_2)
-            }
-        } else if self.is_zero() {
-            if other.is_sign_positive() {
-                Some(Double::ZERO)
+                Double::FRAC_PI_2
+            };
+            Some(if self.is_sign_positive() { r } else { -r })
+        } else if self.is_zero() || other.is_infinite() {
+            let r = if other.is_sign_positive() {
+                Double::ZERO
             } else {
-                Some(Double::PI)
-            }
-        } else if self.is_infinite() {
-            if other.is_infinite() {
-                Some(Double::NAN)
-            } else if self.is_sign_positive() {
+                Double::PI
+            };
+            Some(if self.is_sign_positive() { r } else { -r })
+        } else if other.is_zero() {
+            if self.is_sign_positive() {
                 Some(Double::FRAC_PI_2)
             } else {
                 Some(-Double::FRAC_PI_2)
             }
-        } else if other.is_infinite() {
-            Some(Double::ZERO)
-        } else if self.is_nan() || other.is_nan() {
-            Some(Double::NAN)
         } else if *self == *other {
             if self.is_sign_positive() {
                 Some(Double::FRAC_PI_4)
@@ -448,6 +889,67 @@ impl Double {
             None
         }
     }
+
+    // Returns ±1 in place of an argument that's outside of [-1, 1] by no more than
+    // `CLAMP_TOLERANCE`, and the argument itself otherwise (NaN included).
+    #[inline]
+    fn clamp_unit(self) -> Double {
+        let a = self.abs();
+        if a > Double::ONE && a - Double::ONE <= CLAMP_TOLERANCE {
+            Double::ONE.copysign(self)
+        } else {
+            self
+        }
+    }
+}
+
+// Splits `x`, an angle in half-turns, into 2n + k/2 + t for some integer n, with k in
+// [0, 3] and |t| at most 1/4. Each component minus the even integer nearest to it is
+// exact, and so is the sum of those differences, so every step of this is exact and t
+// has all of the precision of the fractional part of `x`.
+fn reduce_half_turns(x: Double) -> (i32, Double) {
+    let frac = |a: f64| a - 2.0 * (a / 2.0).round();
+    // The first pass leaves a sum in [-2, 2], and the second brings that into [-1, 1]
+    let r = Double::from_sum(&[frac(x.0), frac(x.1)]);
+    let r = Double::from_sum(&[frac(r.0), frac(r.1)]);
+    let k = (2.0 * r.0).round();
+    let t = Double::from_sum(&[r.0 - k / 2.0, r.1]);
+    ((k as i32).rem_euclid(4), t)
+}
+
+// Splits `x`, an angle in degrees, into 360n + 90k + t for some integer n, with k in
+// [0, 3] and |t| at most 45 (or a hair more, if the lower components push it over). The
+// remainder of an `f64` divided by 360 is always exact, and so is the difference between
+// a number no larger than 360 and the multiple of 90 nearest to it, so like
+// `reduce_half_turns`, this is exact.
+fn reduce_degrees(x: Double) -> (i32, Double) {
+    // The first pass leaves a sum in (-720, 720), and the second brings that into
+    // (-360, 360) give or take the lower components
+    let r = Double::from_sum(&[x.0 % 360.0, x.1 % 360.0]);
+    let r = Double::from_sum(&[r.0 % 360.0, r.1 % 360.0]);
+    let k = (r.0 / 90.0).round();
+    let t = Double::from_sum(&[r.0 - 90.0 * k, r.1]);
+    ((k as i32).rem_euclid(4), t)
+}
+
+// Computes the sine and cosine of `t` degrees, where `t` is nonzero and no more than about
+// 45 in magnitude. The angles whose sines or cosines are exactly 1/2 are handled
+// separately so that those come out exact, and ±45 so that its sine and cosine are the
+// same.
+fn sin_cos_degrees(t: Double) -> (Double, Double) {
+    let a = t.abs();
+    let (sin_a, cos_a) = if a == Double(30.0, 0.0) {
+        (Double(0.5, 0.0), c::mul_pwr2(Double::from(3).sqrt(), 0.5))
+    } else if a == Double(45.0, 0.0) {
+        (Double::FRAC_1_SQRT_2, Double::FRAC_1_SQRT_2)
+    } else {
+        (a * c::RAD_PER_DEG).sin_cos()
+    };
+    if t.is_sign_negative() {
+        (-sin_a, cos_a)
+    } else {
+        (sin_a, cos_a)
+    }
 }
 
 // Compute sin a using the Taylor series. This assumes that |a| <= π/32.
@@ -460,14 +962,13 @@ fn sin_taylor(a: Double) -> Double {
         let x = -a.sqr();
         let mut s = a;
         let mut r = a;
-        let mut i = 0;
 
-        loop {
+        // 1/3!, 1/5!, 1/7!, ...
+        for f in c::INV_FACTS.iter().step_by(2) {
             r *= x;
-            let t = r * c::INV_FACTS[i];
+            let t = r * *f;
             s += t;
-            i += 2;
-            if i >= c::INV_FACTS.len() || t.abs() <= threshold {
+            if t.abs() <= threshold {
                 break;
             }
         }
@@ -485,14 +986,13 @@ fn cos_taylor(a: Double) -> Double {
         let x = -a.sqr();
         let mut r = x;
         let mut s = Double::ONE + c::mul_pwr2(r, 0.5);
-        let mut i = 1;
 
-        loop {
+        // 1/4!, 1/6!, 1/8!, ...
+        for f in c::INV_FACTS.iter().skip(1).step_by(2) {
             r *= x;
-            let t = r * c::INV_FACTS[i];
+            let t = r * *f;
             s += t;
-            i += 2;
-            if i >= c::INV_FACTS.len() || t.abs() <= threshold {
+            if t.abs() <= threshold {
                 break;
             }
         }
@@ -500,45 +1000,66 @@ fn cos_taylor(a: Double) -> Double {
     }
 }
 
-// Computes both the sine and cosine of a using the Taylor series. This is a bit quicker
-// than calling the two functions above separately, since if you have one of them you can
-// calculate the other more efficiently.
+// Computes both the sine and cosine of a using the Taylor series. The two series are
+// summed side by side, sharing the powers of a between them. This is quicker than calling
+// the two functions above separately, and unlike deriving one from the other with
+// √(1 - x²), it doesn't compound the error of the square root into the second result, so
+// sin² + cos² stays as close to 1 as the two separate series would get it.
 fn sincos_taylor(a: Double) -> (Double, Double) {
     if a.is_zero() {
         (Double::ZERO, Double::ONE)
     } else {
-        let sin_a = sin_taylor(a);
-        (sin_a, (Double::ONE - sin_a.sqr()).sqrt())
+        let sin_threshold = c::mul_pwr2(a.abs() * Double::EPSILON, 0.5);
+        let cos_threshold = c::mul_pwr2(Double::EPSILON, 0.5);
+        let x = -a.sqr();
+        let mut r = x;
+        let mut sin_a = a + a * r * c::INV_FACTS[0];
+        let mut cos_a = Double::ONE + c::mul_pwr2(r, 0.5);
+
+        // (1/4!, 1/5!), (1/6!, 1/7!), ...
+        for f in c::INV_FACTS[1..].chunks_exact(2) {
+            r *= x;
+            let tc = r * f[0];
+            let ts = a * r * f[1];
+            cos_a += tc;
+            sin_a += ts;
+            if tc.abs() <= cos_threshold && ts.abs() <= sin_threshold {
+                break;
+            }
+        }
+        (sin_a, cos_a)
     }
 }
 
 // Helper function to reduce the input to a value whose sin/cos can be calculated via Taylor
-// series. It firsts reduces modulo 2π, then π/2, then π/16. Aside from returning the
-// reduced value (`t`), it also returns the group within the next higher modulo in which the
-// value fell (`j` and `k`, this is the quadrant for `j`).
+// series. It finds the multiple of π/2 nearest to the argument, and then the multiple of
+// π/16 nearest to what's left. Aside from returning the reduced value (`t`), it also
+// returns the multiples (`j` and `k`, where `j` is the quadrant).
+//
+// `j` is in [-1, 2] and `k` is in [-4, 4]. |t| is π/32 at most, give or take the few ulps
+// described at c::TRIG_STEP.
+//
+// Past π/4, the argument is reduced with the exact bits of 2/π (see `common::reduction`),
+// so `t` has the full precision of a `Double` however large the argument is.
 #[inline]
 #[allow(clippy::many_single_char_names)]
 fn reduce(a: Double) -> (i32, i32, Double) {
-    // reduce modulo 2π
-    let z = (a / Double::TAU).round();
-    let r = a - z * Double::TAU;
-
-    // reduce modulo π/2
-    let mut q = (r.0 / Double::FRAC_PI_2.0 + 0.5).floor();
-    let mut t = r - Double(q, 0.0) * Double::FRAC_PI_2;
-    let j = q as i32;
-
-    // reduce modulo π/16
-    q = (t.0 / Double::FRAC_PI_16.0 + 0.5).floor();
-    t -= Double(q, 0.0) * Double::FRAC_PI_16;
-    let k = q as i32;
+    if a.0.abs() < Double::FRAC_PI_4.0 {
+        let k = (a.0 / c::TRIG_STEP.0 + 0.5).floor() as i32;
+        return (0, k, a - c::TRIG_STEP.mul_i32(k));
+    }
 
-    (j, k, t)
+    let mut f = [0.0; 2];
+    let (j, k) = r::quarter_turns(&[a.0, a.1], c::TRIG_STEPS as u32 / 2, &mut f);
+    let t = Double(f[0], f[1]) * Double::FRAC_PI_2;
+    (if j == 3 { -1 } else { j }, k, t)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::random::Rng;
+    use crate::quad::Quad;
 
     // sin tests
     test_all_near!(
@@ -836,6 +1357,325 @@ mod tests {
             Double::NAN.sin_cos().1;
     );
 
+    // sin² + cos² should be 1 to within 6 ulps (Double::EPSILON is 2^-105, one unit in the
+    // last place of a 106-bit significand), and sin_cos should agree with sin and cos to
+    // within an ulp, for arguments across a wide range of magnitudes.
+    test!(sin_cos_identity: {
+        let mut rng = Rng::new(0x1234_5678_9abc_def1);
+        for _ in 0..100_000 {
+            let m = 1.0 + rng.next_f64();
+            let e = rng.range(-30, 41);
+            let hi = m * 2f64.powi(e);
+            let lo = (rng.next_u64() >> 11) as f64 * 2f64.powi(e - 106);
+            let x = Double(hi, 0.0) + Double(lo, 0.0);
+            let x = if rng.next_u64() % 2 == 0 { x } else { -x };
+            let (s, c) = x.sin_cos();
+            // The identity is checked in Quad so that the check itself doesn't add error
+            let qs = Quad::from((s.0, s.1, 0.0, 0.0));
+            let qc = Quad::from((c.0, c.1, 0.0, 0.0));
+            let dev = (qs.sqr() + qc.sqr() - Quad::ONE).abs();
+            assert!(
+                dev <= Quad::from((6.0 * Double::EPSILON.0, 0.0, 0.0, 0.0)),
+                "sin² + cos² - 1 = {:e} for x = {:?}", dev, x
+            );
+
+            let (sin_x, cos_x) = (x.sin(), x.cos());
+            assert!(
+                (s - sin_x).abs() <= Double::EPSILON * sin_x.abs(),
+                "sin_cos(x).0 = {:?}, sin(x) = {:?} for x = {:?}", s, sin_x, x
+            );
+            assert!(
+                (c - cos_x).abs() <= Double::EPSILON * cos_x.abs(),
+                "sin_cos(x).1 = {:?}, cos(x) = {:?} for x = {:?}", c, cos_x, x
+            );
+        }
+    });
+
+    // midpoint tests, arguments halfway between two multiples of the reduction step, where
+    // the reduced argument is as large as it gets
+    test_all_near!(
+        sin_mid_1:
+            dd!("0.098017140329560601994195563888641939"),
+            (Double::PI * dd!(1) / dd!(32)).sin();
+        sin_mid_3:
+            dd!("0.29028467725446236763619237581739481"),
+            (Double::PI * dd!(3) / dd!(32)).sin();
+        sin_mid_7:
+            dd!("0.63439328416364549821517161322549328"),
+            (Double::PI * dd!(7) / dd!(32)).sin();
+        sin_mid_9:
+            dd!("0.77301045336273696081090660975847082"),
+            (Double::PI * dd!(9) / dd!(32)).sin();
+        sin_mid_17:
+            dd!("0.99518472667219688624483695310948029"),
+            (Double::PI * dd!(17) / dd!(32)).sin();
+        sin_mid_33:
+            dd!("-0.098017140329560601994195563888639553"),
+            (Double::PI * dd!(33) / dd!(32)).sin();
+        sin_mid_neg_25:
+            dd!("-0.63439328416364549821517161322549097"),
+            (Double::PI * dd!(-25) / dd!(32)).sin();
+        sin_mid_65:
+            dd!("0.098017140329560601994195563888691600"),
+            (Double::PI * dd!(65) / dd!(32)).sin();
+        sin_mid_101:
+            dd!("-0.47139673682599764855638762590526068"),
+            (Double::PI * dd!(101) / dd!(32)).sin();
+        cos_mid_1:
+            dd!("0.99518472667219688624483695310947991"),
+            (Double::PI * dd!(1) / dd!(32)).cos();
+        cos_mid_5:
+            dd!("0.88192126434835502971275686366038777"),
+            (Double::PI * dd!(5) / dd!(32)).cos();
+        cos_mid_15:
+            dd!("0.098017140329560601994195563888622816"),
+            (Double::PI * dd!(15) / dd!(32)).cos();
+        cos_mid_31:
+            dd!("-0.99518472667219688624483695310948315"),
+            (Double::PI * dd!(31) / dd!(32)).cos();
+        cos_mid_neg_7:
+            dd!("0.77301045336273696081090660975846987"),
+            (Double::PI * dd!(-7) / dd!(32)).cos();
+        cos_mid_63:
+            dd!("0.99518472667219688624483695310948103"),
+            (Double::PI * dd!(63) / dd!(32)).cos();
+        cos_mid_101:
+            dd!("-0.88192126434835502971275686366038498"),
+            (Double::PI * dd!(101) / dd!(32)).cos();
+    );
+
+    // Reduction tests. These check the argument reduction against sines and cosines of
+    // kπ/16 that don't come from the tables, but from Taylor series summed in Quad
+    // precision. Rounding those to Double precision has to give the table entries exactly.
+    fn taylor(x: Quad) -> (Quad, Quad) {
+        let mut sums = [Quad::ZERO, Quad::ZERO];
+        let mut term = Quad::ONE;
+        for n in 0..50 {
+            sums[n % 2] += if n % 4 < 2 { term } else { -term };
+            term = term * x / Quad::from(n as i32 + 1);
+        }
+        (sums[1], sums[0])
+    }
+
+    fn step(k: i32) -> (Quad, Quad) {
+        taylor(Quad::PI * Quad::from(k) / Quad::from(c::TRIG_STEPS as i32))
+    }
+
+    fn round(x: Quad) -> Double {
+        Double(x[0], x[1])
+    }
+
+    // A dense grid over several turns in both directions, plus the midpoints between
+    // multiples of the step and the f64s on either side of them, where the reduced argument
+    // is at its largest.
+    fn grid() -> Vec<Double> {
+        let mut xs: Vec<Double> = (-5000..=5000)
+            .map(|i| Double(i as f64 / 256.0, 0.0) + Double(1e-17 * i as f64, 0.0))
+            .collect();
+        for m in -100..100 {
+            let x = Double::PI * Double::from(2 * m + 1) / Double::from(c::TRIG_STEPS as i32 * 2);
+            let bits = x.0.to_bits();
+            xs.extend(&[
+                x,
+                Double(f64::from_bits(bits - 1), 0.0),
+                Double(f64::from_bits(bits + 1), 0.0),
+            ]);
+        }
+        xs
+    }
+
+    test!(reduce_constants: {
+        assert!(c::SINES.len() == c::TRIG_STEPS / 4);
+        assert!(c::COSINES.len() == c::TRIG_STEPS / 4);
+        assert!(c::TRIG_STEP == round(Quad::PI / Quad::from(c::TRIG_STEPS as i32)));
+        assert!(Double::FRAC_PI_2 == round(Quad::FRAC_PI_2));
+        assert!(Double::TAU == round(Quad::TAU));
+    });
+
+    test!(reduce_tables: {
+        for k in 1..=c::TRIG_STEPS / 4 {
+            let (s, c) = step(k as i32);
+            assert!(c::SINES[k - 1] == round(s), "sin({}π/{}) = {:?}", k, c::TRIG_STEPS, s);
+            assert!(c::COSINES[k - 1] == round(c), "cos({}π/{}) = {:?}", k, c::TRIG_STEPS, c);
+        }
+    });
+
+    test!(reduce_bound: {
+        let max_k = c::TRIG_STEPS as i32 / 4;
+        let bound = c::TRIG_STEP.0 * (0.5 + c::TRIG_STEPS as f64 * f64::EPSILON);
+        for x in grid() {
+            let (j, k, t) = reduce(x);
+            assert!(j.abs() <= 2, "j = {} for x = {:?}", j, x);
+            assert!(k.abs() <= max_k, "k = {} for x = {:?}", k, x);
+            assert!(t.abs() <= Double(bound, 0.0), "t = {:?} for x = {:?}", t, x);
+        }
+    });
+
+    // The sine and cosine of every point on the grid have to be what the reduced argument
+    // and the independent values of sin and cos of kπ/16 say they are, to within an ulp or
+    // two. The recombination is done in Quad precision so that it adds no error of its own.
+    test!(reduce_recombination: {
+        let tolerance = Quad::from((2.0 * Double::EPSILON.0, 0.0, 0.0, 0.0));
+        for x in grid() {
+            let (j, k, t) = reduce(x);
+            let (sin_t, cos_t) = taylor(Quad::from((t.0, t.1, 0.0, 0.0)));
+            let (v, u) = step(k);
+            let (s, c) = (u * sin_t + v * cos_t, u * cos_t - v * sin_t);
+            let (s, c) = match j {
+                0 => (s, c),
+                1 => (c, -s),
+                -1 => (-c, s),
+                _ => (-s, -c),
+            };
+            let (sin_x, cos_x) = (x.sin(), x.cos());
+            let sin_dev = (Quad::from((sin_x.0, sin_x.1, 0.0, 0.0)) - s).abs();
+            let cos_dev = (Quad::from((cos_x.0, cos_x.1, 0.0, 0.0)) - c).abs();
+            assert!(sin_dev <= tolerance, "sin(x) is off by {:e} for x = {:?}", sin_dev, x);
+            assert!(cos_dev <= tolerance, "cos(x) is off by {:e} for x = {:?}", cos_dev, x);
+        }
+    });
+
+    // Negating the argument has to negate the sine and the tangent and leave the cosine
+    // alone, bit for bit. The reduction rounds to the nearest multiple of π/2 and then of
+    // the step, so the arguments most likely to break this are the near-ties of those
+    // roundings: the midpoints in the grid, and the odd multiples of π/4 and the f64s
+    // within a few ulps of them.
+    fn assert_symmetric(x: Double) {
+        let (s, c) = x.sin_cos();
+        assert!(
+            (-x).sin_cos() == (-s, c),
+            "sin_cos isn't symmetric at {:?}",
+            x
+        );
+        assert!((-x).sin() == -x.sin(), "sin isn't odd at {:?}", x);
+        assert!((-x).cos() == x.cos(), "cos isn't even at {:?}", x);
+        assert!((-x).tan() == -x.tan(), "tan isn't odd at {:?}", x);
+    }
+
+    // Pseudo-random arguments with full-precision tails, spread over magnitudes from
+    // 2^-20 to 2^20.
+    fn check_symmetry_random(samples: usize) {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..samples {
+            let e = rng.range(-20, 21);
+            let mut x = Double::ZERO;
+            for i in 0..2 {
+                let m = (rng.next_u64() >> 11) as f64 * 2f64.powi(e - 53 * (i as i32 + 1));
+                x += Double::from(m);
+            }
+            assert_symmetric(x);
+        }
+    }
+
+    test!(symmetry_near_ties: {
+        let mut xs = grid();
+        for m in 0..64 {
+            let x = Double::FRAC_PI_4 * Double::from(2 * m + 1);
+            let bits = x.0.to_bits();
+            xs.push(x);
+            for d in 1..4 {
+                xs.push(Double::from(f64::from_bits(bits - d)));
+                xs.push(Double::from(f64::from_bits(bits + d)));
+            }
+        }
+        for x in xs {
+            assert_symmetric(x);
+        }
+    });
+
+    test!(symmetry_random: {
+        check_symmetry_random(20_000);
+    });
+
+    #[test]
+    #[ignore]
+    fn symmetry_random_long() {
+        check_symmetry_random(1_000_000);
+    }
+
+    // huge arguments and near multiples of π/2, where a reduction by a multiple of 2π that
+    // was only as precise as a Double would lose most or all of the digits
+    test_all_near!(
+        sin_1e10:
+            dd!("-0.48750602508751069152779429434811"),
+            dd!("1e10").sin();
+        cos_1e10:
+            dd!("0.87311962267685600117619134530770"),
+            dd!("1e10").cos();
+        tan_1e10:
+            dd!("-0.55834963781124184656189340731864"),
+            dd!("1e10").tan();
+        sin_1e20:
+            dd!("-0.64525128526578084420581171131252"),
+            dd!("1e20").sin();
+        cos_1e20:
+            dd!("0.76397040444172830040014680273788"),
+            dd!("1e20").cos();
+        tan_1e20:
+            dd!("-0.84460246301988425418409323400055"),
+            dd!("1e20").tan();
+        sin_1e30:
+            dd!("-0.090116901912138058030386428952987"),
+            dd!("1e30").sin();
+        cos_1e30:
+            dd!("-0.99593119440539570239424858799705"),
+            dd!("1e30").cos();
+        tan_1e30:
+            dd!("0.090485068063302172566223138050041"),
+            dd!("1e30").tan();
+        sin_1e22_pi:
+            dd!("3.3035230702304726456078154694954e-10"),
+            (dd!("1e22") * Double::PI).sin();
+        cos_1e22_pi:
+            dd!("0.99999999999999999994543367662228"),
+            (dd!("1e22") * Double::PI).cos();
+        tan_1e22_pi:
+            dd!("3.3035230702304726457880765776314e-10"),
+            (dd!("1e22") * Double::PI).tan();
+        sin_1e20_pi_2:
+            dd!("-9.4468292288785893962923747089105e-14"),
+            (dd!("1e20") * Double::FRAC_PI_2).sin();
+        tan_1e20_pi_2:
+            dd!("-9.4468292288785893962923747510635e-14"),
+            (dd!("1e20") * Double::FRAC_PI_2).tan();
+    );
+
+    // rem_2pi tests
+    test_all_near!(
+        rem_2pi_1e30:
+            dd!("-3.0513533296917402104314617953741"),
+            dd!("1e30").rem_2pi();
+        rem_2pi_1e300:
+            dd!("2.946266998303448728507181335149102"),
+            dd!("1e300").rem_2pi();
+        rem_2pi_1e22_pi:
+            dd!("3.3035230702304726456679025055408e-10"),
+            (dd!("1e22") * Double::PI).rem_2pi();
+        rem_2pi_7:
+            dd!("0.71681469282041352307471323344099"),
+            dd!(7).rem_2pi();
+    );
+    test_all_exact!(
+        rem_2pi_one:
+            Double::ONE,
+            Double::ONE.rem_2pi();
+        rem_2pi_pi:
+            Double::PI,
+            Double::PI.rem_2pi();
+        rem_2pi_neg_pi:
+            -Double::PI,
+            (-Double::PI).rem_2pi();
+        rem_2pi_inf:
+            Double::NAN,
+            Double::INFINITY.rem_2pi();
+        rem_2pi_nan:
+            Double::NAN,
+            Double::NAN.rem_2pi();
+    );
+    test!(rem_2pi_neg_zero: {
+        assert!(Double::NEG_ZERO.rem_2pi().is_sign_negative());
+    });
+
     // tan tests
     test_all_near!(
         tan_zero:
@@ -945,6 +1785,46 @@ mod tests {
             dd!("-0.48234790710102497548087851189637084"),
             (-Double::FRAC_PI_6).atan2(Double::ONE);
     );
+    test_all_prec!(
+        atan2_near_pos_y_axis_far:
+            dd!("9.99999999999999999999999999999666667e-16"),
+            dd!("1").atan2(dd!("1e15")),
+            30;
+        atan2_near_pos_x_axis:
+            dd!("3.33333333333333333333333333333333333e-21"),
+            dd!("1e-20").atan2(dd!("3")),
+            30;
+        atan2_near_neg_x_axis:
+            dd!("-3.14159265358979323845931004994616955"),
+            dd!("-1e-20").atan2(dd!("-3")),
+            30;
+        atan2_near_y_axis:
+            dd!("1.57079632679489761923132169163975144"),
+            dd!("1e15").atan2(dd!("-1")),
+            30;
+    );
+    test_all_prec!(
+        atan2_ratio_1e10:
+            dd!("9.99999999999999999996666666666666667e-11"),
+            dd!("1").atan2(dd!("1e10")),
+            31;
+        atan2_small_over_two:
+            dd!("4.99999999999999999999999999999958333e-16"),
+            dd!("1e-15").atan2(dd!("2")),
+            31;
+        atan2_neg_ratio_1e10:
+            dd!("-9.99999999999999999996666666666666667e-11"),
+            dd!("-1").atan2(dd!("1e10")),
+            31;
+        atan2_y_axis_1e10:
+            dd!("1.57079632689489661923132169163941811"),
+            dd!("1e10").atan2(dd!("-1")),
+            31;
+        atan2_neg_small_over_neg_two:
+            dd!("-3.14159265358979273846264338327950288"),
+            dd!("-1e-15").atan2(dd!("-2")),
+            31;
+    );
     test_all_near!(
         atan2_ones_pos_pos:
             Double::FRAC_PI_4,
@@ -981,14 +1861,23 @@ mod tests {
     );
     test_all_exact!(
         atan2_zero_zero:
-            Double::NAN,
+            Double::ZERO,
             Double::ZERO.atan2(Double::ZERO);
+        atan2_zero_neg_zero:
+            Double::PI,
+            Double::ZERO.atan2(Double::NEG_ZERO);
         atan2_inf_inf:
-            Double::NAN,
+            Double::FRAC_PI_4,
             Double::INFINITY.atan2(Double::INFINITY);
+        atan2_neg_inf_neg_inf:
+            -Double::FRAC_3_PI_4,
+            Double::NEG_INFINITY.atan2(Double::NEG_INFINITY);
         atan2_one_inf:
             Double::ZERO,
             Double::ONE.atan2(Double::INFINITY);
+        atan2_one_neg_inf:
+            Double::PI,
+            Double::ONE.atan2(Double::NEG_INFINITY);
         atan2_nan_one:
             Double::NAN,
             Double::NAN.atan2(Double::ONE);
@@ -1000,6 +1889,326 @@ mod tests {
             Double::NAN.atan2(Double::NAN);
     );
 
+    // Every combination of zeros and infinities of either sign, each of which should give
+    // the angle that `f64::atan2` does, with the same sign
+    test!(atan2_zeros_and_infinities: {
+        let specials = [0.0, -0.0, f64::INFINITY, f64::NEG_INFINITY];
+        let angles = [
+            Double::ZERO,
+            Double::FRAC_PI_4,
+            Double::FRAC_PI_2,
+            Double::FRAC_3_PI_4,
+            Double::PI,
+        ];
+        for &y in &specials {
+            for &x in &specials {
+                let f = y.atan2(x);
+                let angle = *angles.iter().find(|a| a.0 == f.abs()).unwrap();
+                let expected = if f.is_sign_negative() { -angle } else { angle };
+                let actual = Double(y, 0.0).atan2(Double(x, 0.0));
+                assert!(
+                    actual == expected && actual.is_sign_negative() == f.is_sign_negative(),
+                    "atan2({:?}, {:?}) = {:?}, expected {:?}",
+                    y,
+                    x,
+                    actual,
+                    expected
+                );
+            }
+        }
+    });
+
+    // sin_pi, cos_pi, and tan_pi tests
+    test_all_near!(
+        sin_pi_quarter:
+            Double::FRAC_1_SQRT_2,
+            dd!(0.25).sin_pi();
+        sin_pi_neg_three_quarters:
+            -Double::FRAC_1_SQRT_2,
+            dd!(-0.75).sin_pi();
+        sin_pi_sixth:
+            dd!(0.5),
+            (dd!(1) / dd!(6)).sin_pi();
+        cos_pi_third:
+            dd!(0.5),
+            (dd!(1) / dd!(3)).cos_pi();
+        cos_pi_five_quarters:
+            -Double::FRAC_1_SQRT_2,
+            dd!(1.25).cos_pi();
+        tan_pi_quarter:
+            dd!(1),
+            dd!(0.25).tan_pi();
+        tan_pi_neg_three_quarters:
+            dd!(1),
+            dd!(-0.75).tan_pi();
+        sin_pi_composed:
+            (dd!(0.3) * Double::PI).sin(),
+            dd!(0.3).sin_pi();
+        cos_pi_composed:
+            (dd!(-0.7) * Double::PI).cos(),
+            dd!(-0.7).cos_pi();
+        cos_pi_big:
+            Double::FRAC_1_SQRT_2,
+            Double(1e20, 0.25).cos_pi();
+        sin_pi_big:
+            -Double::FRAC_1_SQRT_2,
+            Double(1e20, -0.25).sin_pi();
+    );
+    test_all_exact!(
+        sin_pi_one:
+            Double::ZERO,
+            dd!(1).sin_pi();
+        sin_pi_neg_one:
+            Double::NEG_ZERO,
+            dd!(-1).sin_pi();
+        sin_pi_half:
+            Double::ONE,
+            dd!(0.5).sin_pi();
+        sin_pi_neg_half:
+            Double::NEG_ONE,
+            dd!(-0.5).sin_pi();
+        sin_pi_three_halves:
+            Double::NEG_ONE,
+            dd!(1.5).sin_pi();
+        sin_pi_zero:
+            Double::ZERO,
+            Double::ZERO.sin_pi();
+        sin_pi_huge_half:
+            Double::ONE,
+            Double(2f64.powi(60), 0.5).sin_pi();
+        sin_pi_inf:
+            Double::NAN,
+            Double::INFINITY.sin_pi();
+        cos_pi_one:
+            Double::NEG_ONE,
+            dd!(1).cos_pi();
+        cos_pi_two:
+            Double::ONE,
+            dd!(2).cos_pi();
+        cos_pi_half:
+            Double::ZERO,
+            dd!(0.5).cos_pi();
+        cos_pi_neg_half:
+            Double::ZERO,
+            dd!(-0.5).cos_pi();
+        cos_pi_huge:
+            Double::ONE,
+            Double(1e300, 0.0).cos_pi();
+        cos_pi_zero:
+            Double::ONE,
+            Double::NEG_ZERO.cos_pi();
+        cos_pi_nan:
+            Double::NAN,
+            Double::NAN.cos_pi();
+        tan_pi_two:
+            Double::ZERO,
+            dd!(2).tan_pi();
+        tan_pi_half:
+            Double::INFINITY,
+            dd!(0.5).tan_pi();
+        tan_pi_three_halves:
+            Double::NEG_INFINITY,
+            dd!(1.5).tan_pi();
+        tan_pi_neg_half:
+            Double::NEG_INFINITY,
+            dd!(-0.5).tan_pi();
+        tan_pi_inf:
+            Double::NAN,
+            Double::NEG_INFINITY.tan_pi();
+    );
+    test_all_assert!(
+        sin_pi_one_sign:
+            dd!(1).sin_pi().is_sign_positive();
+        sin_pi_neg_two_sign:
+            dd!(-2).sin_pi().is_sign_negative();
+        cos_pi_half_sign:
+            dd!(-0.5).cos_pi().is_sign_positive();
+    );
+    // Adding an even integer changes nothing, since the reduction is exact. The arguments
+    // are single `f64`s so that adding the integers to them is exact too.
+    test!(sin_pi_periodic: {
+        for &a in &[0.1, -0.3, Double::PI[0] / 4.0, -Double::E[0] / 3.0] {
+            let x = Double(a, 0.0);
+            for &e in &[2.0, -4.0, 2f64.powi(40)] {
+                let y = Double::from_sum(&[a, e]);
+                assert!(y.sin_pi() == x.sin_pi(), "{:?}.sin_pi()", y);
+                assert!(y.cos_pi() == x.cos_pi(), "{:?}.cos_pi()", y);
+            }
+            assert!((-x).sin_pi() == -x.sin_pi());
+            assert!((-x).cos_pi() == x.cos_pi());
+        }
+    });
+    // Multiplying by π first makes an error in the argument that's about as large as the
+    // argument times the precision, and that error goes straight into the result
+    test!(cos_pi_versus_composed: {
+        let x = Double(1e20, 0.25);
+        let pi = (x.cos_pi() - Double::FRAC_1_SQRT_2).abs();
+        let composed = ((x * Double::PI).cos() - Double::FRAC_1_SQRT_2).abs();
+        assert!(pi < Double::EPSILON);
+        assert!(composed > Double::EPSILON * dd!(1e15), "composed error {:?}", composed);
+    });
+
+    // sin_deg, cos_deg, tan_deg, to_radians, and to_degrees tests
+    test_all_near!(
+        sin_deg_45:
+            Double::FRAC_1_SQRT_2,
+            dd!(45).sin_deg();
+        sin_deg_neg_135:
+            -Double::FRAC_1_SQRT_2,
+            dd!(-135).sin_deg();
+        cos_deg_30:
+            dd!(3).sqrt() / dd!(2),
+            dd!(30).cos_deg();
+        sin_deg_240:
+            -dd!(3).sqrt() / dd!(2),
+            dd!(240).sin_deg();
+        tan_deg_60:
+            dd!(3).sqrt(),
+            dd!(60).tan_deg();
+        tan_deg_neg_30:
+            -dd!(3).sqrt() / dd!(3),
+            dd!(-30).tan_deg();
+        sin_deg_composed:
+            dd!(80).to_radians().sin(),
+            dd!(80).sin_deg();
+        cos_deg_composed:
+            dd!(-17.5).to_radians().cos(),
+            dd!(-17.5).cos_deg();
+        to_radians_180:
+            Double::PI,
+            dd!(180).to_radians();
+        to_radians_1:
+            Double::PI / dd!(180),
+            dd!(1).to_radians();
+        to_degrees_pi:
+            dd!(180),
+            Double::PI.to_degrees();
+        to_degrees_1:
+            dd!(180) / Double::PI,
+            dd!(1).to_degrees();
+    );
+    test_all_exact!(
+        sin_deg_30:
+            dd!(0.5),
+            dd!(30).sin_deg();
+        sin_deg_150:
+            dd!(0.5),
+            dd!(150).sin_deg();
+        sin_deg_neg_30:
+            dd!(-0.5),
+            dd!(-30).sin_deg();
+        sin_deg_210:
+            dd!(-0.5),
+            dd!(210).sin_deg();
+        sin_deg_90:
+            Double::ONE,
+            dd!(90).sin_deg();
+        sin_deg_270:
+            Double::NEG_ONE,
+            dd!(270).sin_deg();
+        sin_deg_neg_90:
+            Double::NEG_ONE,
+            dd!(-90).sin_deg();
+        sin_deg_180:
+            Double::ZERO,
+            dd!(180).sin_deg();
+        sin_deg_huge:
+            Double::ZERO,
+            dd!(1_000_000_080).sin_deg();
+        sin_deg_huge_80:
+            dd!(80).sin_deg(),
+            Double::from_sum(&[360.0 * 2f64.powi(70), 80.0]).sin_deg();
+        sin_deg_inf:
+            Double::NAN,
+            Double::INFINITY.sin_deg();
+        cos_deg_60:
+            dd!(0.5),
+            dd!(60).cos_deg();
+        cos_deg_300:
+            dd!(0.5),
+            dd!(300).cos_deg();
+        cos_deg_120:
+            dd!(-0.5),
+            dd!(120).cos_deg();
+        cos_deg_90:
+            Double::ZERO,
+            dd!(90).cos_deg();
+        cos_deg_180:
+            Double::NEG_ONE,
+            dd!(180).cos_deg();
+        cos_deg_720:
+            Double::ONE,
+            dd!(-720).cos_deg();
+        cos_deg_45:
+            dd!(45).sin_deg(),
+            dd!(45).cos_deg();
+        cos_deg_huge:
+            Double::ONE,
+            Double(1e300, 0.0).cos_deg();
+        cos_deg_nan:
+            Double::NAN,
+            Double::NAN.cos_deg();
+        tan_deg_45:
+            Double::ONE,
+            dd!(45).tan_deg();
+        tan_deg_135:
+            Double::NEG_ONE,
+            dd!(135).tan_deg();
+        tan_deg_neg_315:
+            Double::ONE,
+            dd!(-315).tan_deg();
+        tan_deg_180:
+            Double::ZERO,
+            dd!(180).tan_deg();
+        tan_deg_90:
+            Double::INFINITY,
+            dd!(90).tan_deg();
+        tan_deg_270:
+            Double::NEG_INFINITY,
+            dd!(270).tan_deg();
+        tan_deg_neg_inf:
+            Double::NAN,
+            Double::NEG_INFINITY.tan_deg();
+        to_radians_inf:
+            Double::INFINITY,
+            Double::INFINITY.to_radians();
+        to_degrees_max:
+            Double::INFINITY,
+            Double::MAX.to_degrees();
+    );
+    test_all_assert!(
+        sin_deg_360_sign:
+            dd!(360).sin_deg().is_sign_positive();
+        sin_deg_neg_180_sign:
+            dd!(-180).sin_deg().is_sign_negative();
+        cos_deg_90_sign:
+            dd!(-90).cos_deg().is_sign_positive();
+    );
+    // Adding a multiple of 360 changes nothing, since the reduction is exact. The
+    // arguments are single `f64`s so that adding the multiples to them is exact too.
+    test!(sin_deg_periodic: {
+        for &a in &[0.1, -37.3, Double::PI[0] * 20.0, -Double::E[0] * 50.0] {
+            let x = Double(a, 0.0);
+            for &e in &[360.0, -720.0, 360.0 * 2f64.powi(40), -45.0 * 2f64.powi(60)] {
+                let y = Double::from_sum(&[a, e]);
+                assert!(y.sin_deg() == x.sin_deg(), "{:?}.sin_deg()", y);
+                assert!(y.cos_deg() == x.cos_deg(), "{:?}.cos_deg()", y);
+            }
+            assert!((-x).sin_deg() == -x.sin_deg());
+            assert!((-x).cos_deg() == x.cos_deg());
+        }
+    });
+    // Converting to radians first makes an error in the argument that's about as large as
+    // the argument times the precision
+    test!(sin_deg_versus_composed: {
+        let x = Double::from_sum(&[360.0 * 2f64.powi(70), 80.0]);
+        let expected = dd!(80).to_radians().sin();
+        let deg = (x.sin_deg() - expected).abs();
+        let composed = (x.to_radians().sin() - expected).abs();
+        assert!(deg < Double::EPSILON);
+        assert!(composed > Double::EPSILON * dd!(1e15), "composed error {:?}", composed);
+    });
+
     // asin tests
     test_all_near!(
         asin_one:
@@ -1090,6 +2299,97 @@ mod tests {
             Double::NAN.acos();
     );
 
+    // asin_clamped and acos_clamped tests
+    test_all_exact!(
+        asin_clamped_two_eps_above_one:
+            Double::FRAC_PI_2,
+            Double(1.0, 2.0 * Double::EPSILON.0).asin_clamped();
+        asin_clamped_two_eps_below_neg_one:
+            -Double::FRAC_PI_2,
+            Double(-1.0, -2.0 * Double::EPSILON.0).asin_clamped();
+        asin_clamped_tolerance:
+            Double::FRAC_PI_2,
+            Double(1.0, 4.0 * Double::EPSILON.0).asin_clamped();
+        asin_clamped_neg_tolerance:
+            -Double::FRAC_PI_2,
+            Double(-1.0, -4.0 * Double::EPSILON.0).asin_clamped();
+        asin_clamped_past_tolerance:
+            Double::NAN,
+            Double(1.0, 5.0 * Double::EPSILON.0).asin_clamped();
+        asin_clamped_neg_past_tolerance:
+            Double::NAN,
+            Double(-1.0, -5.0 * Double::EPSILON.0).asin_clamped();
+        asin_clamped_1e_10_above_one:
+            Double::NAN,
+            dd!(1.0000000001).asin_clamped();
+        asin_clamped_1e_10_below_neg_one:
+            Double::NAN,
+            dd!(-1.0000000001).asin_clamped();
+        asin_clamped_far:
+            Double::NAN,
+            dd!(1.001).asin_clamped();
+        asin_clamped_inf:
+            Double::NAN,
+            Double::INFINITY.asin_clamped();
+        asin_clamped_nan:
+            Double::NAN,
+            Double::NAN.asin_clamped();
+        asin_clamped_one:
+            Double::FRAC_PI_2,
+            Double::ONE.asin_clamped();
+        asin_clamped_half:
+            dd!(0.5).asin(),
+            dd!(0.5).asin_clamped();
+        asin_clamped_below_one:
+            Double(1.0, -2.0 * Double::EPSILON.0).asin(),
+            Double(1.0, -2.0 * Double::EPSILON.0).asin_clamped();
+        acos_clamped_two_eps_above_one:
+            Double::ZERO,
+            Double(1.0, 2.0 * Double::EPSILON.0).acos_clamped();
+        acos_clamped_two_eps_below_neg_one:
+            Double::PI,
+            Double(-1.0, -2.0 * Double::EPSILON.0).acos_clamped();
+        acos_clamped_tolerance:
+            Double::ZERO,
+            Double(1.0, 4.0 * Double::EPSILON.0).acos_clamped();
+        acos_clamped_neg_tolerance:
+            Double::PI,
+            Double(-1.0, -4.0 * Double::EPSILON.0).acos_clamped();
+        acos_clamped_past_tolerance:
+            Double::NAN,
+            Double(1.0, 5.0 * Double::EPSILON.0).acos_clamped();
+        acos_clamped_neg_past_tolerance:
+            Double::NAN,
+            Double(-1.0, -5.0 * Double::EPSILON.0).acos_clamped();
+        acos_clamped_1e_10_above_one:
+            Double::NAN,
+            dd!(1.0000000001).acos_clamped();
+        acos_clamped_1e_10_below_neg_one:
+            Double::NAN,
+            dd!(-1.0000000001).acos_clamped();
+        acos_clamped_far:
+            Double::NAN,
+            dd!(-1.001).acos_clamped();
+        acos_clamped_neg_inf:
+            Double::NAN,
+            Double::NEG_INFINITY.acos_clamped();
+        acos_clamped_nan:
+            Double::NAN,
+            Double::NAN.acos_clamped();
+        acos_clamped_half:
+            dd!(0.5).acos(),
+            dd!(0.5).acos_clamped();
+        acos_clamped_below_one:
+            Double(1.0, -2.0 * Double::EPSILON.0).acos(),
+            Double(1.0, -2.0 * Double::EPSILON.0).acos_clamped();
+    );
+    test_all_assert!(
+        asin_unclamped_two_eps_above_one:
+            Double(1.0, 2.0 * Double::EPSILON.0).asin().is_nan();
+        acos_unclamped_two_eps_below_neg_one:
+            Double(-1.0, -2.0 * Double::EPSILON.0).acos().is_nan();
+    );
+
     // atan tests
     test_all_near!(
         atan_pi:
@@ -1140,4 +2440,15 @@ mod tests {
             Double::NAN,
             Double::NAN.atan();
     );
+
+    // series tests
+    test!(taylor_past_table: {
+        // Arguments this large never get a term under the threshold, so the series run to
+        // the end of the table and stop there
+        let x = dd!(3);
+        let (sin, cos) = sincos_taylor(x);
+        assert!(sin.is_finite() && cos.is_finite());
+        assert!(sin_taylor(x).is_finite());
+        assert!(cos_taylor(x).is_finite());
+    });
 }
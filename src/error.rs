@@ -3,15 +3,20 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-//! Errors that may occur while parsing a string into a [`Double`] or a [`Quad`].
-//! 
+//! Errors that may occur while parsing a string into a [`Double`] or a [`Quad`], while
+//! writing one of them as a fixed-point ASCII number, while converting one of them to an
+//! integer, or while wrapping one of them in an [`OrderedDouble`] or an [`OrderedQuad`].
+//!
 //! [`Double`]: struct.Double.html
 //! [`Quad`]: struct.Quad.html
+//! [`OrderedDouble`]: ../ordered/struct.OrderedDouble.html
+//! [`OrderedQuad`]: ../ordered/struct.OrderedQuad.html
 
+use std::error::Error;
 use std::fmt::{Display, Formatter, Result};
 
 /// An error generated when a problem is encountered parsing a string into a [`Double`].
-/// 
+///
 /// [`Double`]: struct.Double.html
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParseDoubleError {
@@ -19,7 +24,7 @@ pub struct ParseDoubleError {
 }
 
 /// An error generated when a problem is encountered parsing a string into a [`Quad`].
-/// 
+///
 /// [`Quad`]: struct.Quad.html
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParseQuadError {
@@ -31,28 +36,157 @@ pub struct ParseQuadError {
 pub enum ErrorKind {
     /// An error indicating that an attempt was made to parse an empty string.
     Empty,
-    /// An error indicating that the format of a parsed string is not a legal number.
+    /// An error indicating that the format of a parsed string is not a legal number,
+    /// without any one character being to blame. This happens when there are no digits
+    /// where some are required, as in `"-"`, `"."`, or `"1e"`.
     Invalid,
+    /// An error indicating that a parsed string contains a character that can't appear
+    /// where it does, like a letter, a second decimal point, or a sign anywhere but the
+    /// beginning of the number or its exponent. `position` is the byte offset of that
+    /// character in the string that was parsed.
+    InvalidDigit {
+        /// The byte offset of the offending character.
+        position: usize,
+    },
+    /// An error indicating that the exponent of a parsed string is too large (or too
+    /// small) to fit into an `i32`.
+    ///
+    /// Exponents that fit but that are still too large or too small for the type do not
+    /// cause an error; they produce infinity or zero, just as they do when parsing an
+    /// `f64`.
+    ExponentOverflow,
+    /// An error indicating that a parsed fixed-point number has an integer part too large
+    /// to be represented exactly.
+    Overflow,
 }
 
+/// An error generated when a problem is encountered writing a [`Double`] or a [`Quad`] as a
+/// fixed-point ASCII number.
+///
+/// [`Double`]: struct.Double.html
+/// [`Quad`]: struct.Quad.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatFixedError {
+    pub kind: FormatErrorKind,
+}
+
+/// The different kinds of errors that might be generated while writing a fixed-point
+/// number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatErrorKind {
+    /// An error indicating that the number to be written is infinite or `NaN`.
+    NotFinite,
+    /// An error indicating that the number, once scaled by its implied decimals, is too
+    /// large to be written as an integer.
+    Overflow,
+    /// An error indicating that the buffer doesn't have room for all of the digits.
+    BufferTooSmall,
+}
+
+/// An error generated when a [`Double`] or a [`Quad`] can't be converted exactly to an
+/// integer type with `TryFrom`.
+///
+/// [`Double`]: struct.Double.html
+/// [`Quad`]: struct.Quad.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToIntError {
+    pub kind: ToIntErrorKind,
+}
+
+/// The different kinds of errors that might be generated while converting to an integer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToIntErrorKind {
+    /// An error indicating that the number to be converted is infinite or `NaN`.
+    NotFinite,
+    /// An error indicating that the number to be converted has a fractional part. The
+    /// conversion never rounds; truncate or round the number first to convert it anyway.
+    NotInteger,
+    /// An error indicating that the number to be converted is an integer, but one that's
+    /// too large or too small for the integer type.
+    OutOfRange,
+}
+
+/// An error generated when NaN is wrapped in an [`OrderedDouble`] or an [`OrderedQuad`],
+/// which can hold any number but NaN.
+///
+/// [`OrderedDouble`]: ../ordered/struct.OrderedDouble.html
+/// [`OrderedQuad`]: ../ordered/struct.OrderedQuad.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NanError;
+
 impl Display for ParseDoubleError {
+    /// Displays an English-language message describing the kind of the error.
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self.kind {
+            ErrorKind::Empty => "cannot parse double-double from empty string".fmt(f),
+            ErrorKind::Invalid => "invalid double-double literal".fmt(f),
+            ErrorKind::InvalidDigit { position } => write!(
+                f,
+                "invalid character at position {} in double-double literal",
+                position
+            ),
+            ErrorKind::ExponentOverflow => "exponent too large in double-double literal".fmt(f),
+            ErrorKind::Overflow => "number too large to represent exactly in double-double".fmt(f),
+        }
+    }
+}
+
+impl Error for ParseDoubleError {}
+
+impl Display for ParseQuadError {
+    /// Displays an English-language message describing the kind of the error.
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self.kind {
+            ErrorKind::Empty => "cannot parse quad-double from empty string".fmt(f),
+            ErrorKind::Invalid => "invalid quad-double literal".fmt(f),
+            ErrorKind::InvalidDigit { position } => write!(
+                f,
+                "invalid character at position {} in quad-double literal",
+                position
+            ),
+            ErrorKind::ExponentOverflow => "exponent too large in quad-double literal".fmt(f),
+            ErrorKind::Overflow => "number too large to represent exactly in quad-double".fmt(f),
+        }
+    }
+}
+
+impl Error for ParseQuadError {}
+
+impl Display for FormatFixedError {
     /// Displays an English-language message describing the kind of the error.
     fn fmt(&self, f: &mut Formatter) -> Result {
         let description = match self.kind {
-            ErrorKind::Empty => "cannot parse double-double from empty string",
-            ErrorKind::Invalid => "invalid double-double literal",
+            FormatErrorKind::NotFinite => "cannot write a non-finite number as fixed-point",
+            FormatErrorKind::Overflow => "number too large to write as fixed-point",
+            FormatErrorKind::BufferTooSmall => "buffer too small for fixed-point number",
         };
         description.fmt(f)
     }
 }
 
-impl Display for ParseQuadError {
+impl Error for FormatFixedError {}
+
+impl Display for ToIntError {
     /// Displays an English-language message describing the kind of the error.
     fn fmt(&self, f: &mut Formatter) -> Result {
         let description = match self.kind {
-            ErrorKind::Empty => "cannot parse quad-double from empty string",
-            ErrorKind::Invalid => "invalid quad-double literal",
+            ToIntErrorKind::NotFinite => "cannot convert a non-finite number to an integer",
+            ToIntErrorKind::NotInteger => {
+                "cannot convert a number with a fractional part to an integer"
+            }
+            ToIntErrorKind::OutOfRange => "number out of range of the integer type",
         };
         description.fmt(f)
     }
 }
+
+impl Error for ToIntError {}
+
+impl Display for NanError {
+    /// Displays an English-language message describing the error.
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        "cannot order NaN".fmt(f)
+    }
+}
+
+impl Error for NanError {}
@@ -0,0 +1,1210 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::Double;
+use crate::quad::Quad;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, LowerExp, UpperExp};
+use std::iter::{Product, Sum};
+use std::num::FpCategory;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+mod private {
+    // Keeps `Float` from being implemented outside of this crate, so that methods can be
+    // added to it without breaking anyone.
+    pub trait Sealed {}
+
+    impl Sealed for crate::Double {}
+    impl Sealed for crate::Quad {}
+}
+
+/// The operations shared by [`Double`] and [`Quad`], for writing code once and using it
+/// with either precision.
+///
+/// Every constant and method here is the same as the inherent constant or method of the
+/// same name on the two types, which remain the place to look for details about domains,
+/// ranges, and accuracy. Since inherent methods take precedence over trait methods, having
+/// this trait in scope changes nothing about code that uses the concrete types.
+///
+/// The trait is sealed; it's implemented for `Double` and `Quad` and can't be implemented
+/// for anything else.
+///
+/// # Examples
+/// ```
+/// # use qd::{Double, Float, Quad};
+/// // Integrates f from a to b using Simpson's rule with n (even) intervals
+/// fn integrate<T: Float>(f: impl Fn(T) -> T, a: T, b: T, n: i32) -> T {
+///     let h = (b - a) / T::from_f64(n as f64);
+///     let mut sum = f(a) + f(b);
+///     for i in 1..n {
+///         let w = if i % 2 == 0 { 2.0 } else { 4.0 };
+///         sum += T::from_f64(w) * f(a + h * T::from_f64(i as f64));
+///     }
+///     sum * h / T::from_f64(3.0)
+/// }
+///
+/// let d = integrate(|x: Double| x.sin(), Double::ZERO, Double::PI, 1000);
+/// let q = integrate(|x: Quad| x.sin(), Quad::ZERO, Quad::PI, 1000);
+/// assert!((d.to_f64() - 2.0).abs() < 1e-10);
+/// assert!((q.to_f64() - 2.0).abs() < 1e-10);
+/// ```
+///
+/// [`Double`]: struct.Double.html
+/// [`Quad`]: struct.Quad.html
+pub trait Float:
+    private::Sealed
+    + Copy
+    + Clone
+    + Default
+    + Debug
+    + Display
+    + LowerExp
+    + UpperExp
+    + PartialEq
+    + PartialOrd
+    + FromStr
+    + From<f64>
+    + Neg<Output = Self>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + RemAssign
+    + Sum
+    + Product
+{
+    /// The radix or base of the internal representation.
+    const RADIX: u32;
+
+    /// Number of significant digits in base 2.
+    const MANTISSA_DIGITS: u32;
+
+    /// Approximate number of significant digits in base 10.
+    const DIGITS: u32;
+
+    /// One greater than the minimum possible normal power of 2 exponent.
+    const MIN_EXP: i32;
+
+    /// Maximum possible power of 2 exponent.
+    const MAX_EXP: i32;
+
+    /// Minimum possible normal power of 10 exponent.
+    const MIN_10_EXP: i32;
+
+    /// Maximum possible power of 10 exponent.
+    const MAX_10_EXP: i32;
+
+    /// Machine epsilon, 2<sup>1 - `MANTISSA_DIGITS`</sup>.
+    const EPSILON: Self;
+
+    /// Smallest finite value.
+    const MIN: Self;
+
+    /// Smallest positive normal value.
+    const MIN_POSITIVE: Self;
+
+    /// Largest finite value.
+    const MAX: Self;
+
+    /// Not a Number (NaN).
+    const NAN: Self;
+
+    /// Infinity (∞).
+    const INFINITY: Self;
+
+    /// Negative infinity (-∞).
+    const NEG_INFINITY: Self;
+
+    /// Zero (0)
+    const ZERO: Self;
+
+    /// Negative zero (-0)
+    const NEG_ZERO: Self;
+
+    /// One (1)
+    const ONE: Self;
+
+    /// Negative one (-1)
+    const NEG_ONE: Self;
+
+    /// Archimedes' constant (π)
+    const PI: Self;
+
+    /// The full circle constant (τ), or 2π
+    const TAU: Self;
+
+    /// π/2
+    const FRAC_PI_2: Self;
+
+    /// π/3
+    const FRAC_PI_3: Self;
+
+    /// π/4
+    const FRAC_PI_4: Self;
+
+    /// π/6
+    const FRAC_PI_6: Self;
+
+    /// π/8
+    const FRAC_PI_8: Self;
+
+    /// π/16
+    const FRAC_PI_16: Self;
+
+    /// 3π/2
+    const FRAC_3_PI_2: Self;
+
+    /// 3π/4
+    const FRAC_3_PI_4: Self;
+
+    /// 5π/4
+    const FRAC_5_PI_4: Self;
+
+    /// 7π/4
+    const FRAC_7_PI_4: Self;
+
+    /// 1/π
+    const FRAC_1_PI: Self;
+
+    /// 2/π
+    const FRAC_2_PI: Self;
+
+    /// 2/√π
+    const FRAC_2_SQRT_PI: Self;
+
+    /// √2
+    const SQRT_2: Self;
+
+    /// 1/√2
+    const FRAC_1_SQRT_2: Self;
+
+    /// √3
+    const SQRT_3: Self;
+
+    /// 1/√3
+    const FRAC_1_SQRT_3: Self;
+
+    /// Euler's number (*e*)
+    const E: Self;
+
+    /// log<sub>2</sub> 10
+    const LOG2_10: Self;
+
+    /// log<sub>2</sub> *e*
+    const LOG2_E: Self;
+
+    /// log<sub>10</sub> 2
+    const LOG10_2: Self;
+
+    /// log<sub>10</sub> *e*
+    const LOG10_E: Self;
+
+    /// log<sub>*e*</sub> 2
+    const LN_2: Self;
+
+    /// log<sub>*e*</sub> 10
+    const LN_10: Self;
+
+    /// The golden ratio (φ)
+    const PHI: Self;
+
+    /// The Euler-Mascheroni constant (γ)
+    const EULER_GAMMA: Self;
+
+    /// Catalan's constant (*G*)
+    const CATALAN: Self;
+
+    /// Converts an `f64` into this type. This is exact.
+    fn from_f64(a: f64) -> Self;
+
+    /// Converts this number into the nearest `f64`.
+    fn to_f64(self) -> f64;
+
+    /// Calculates the absolute value.
+    fn abs(self) -> Self;
+
+    /// Returns the largest integer less than or equal to the number.
+    fn floor(self) -> Self;
+
+    /// Returns the smallest integer greater than or equal to the number.
+    fn ceil(self) -> Self;
+
+    /// Returns the nearest integer, rounding half-way cases away from zero.
+    fn round(self) -> Self;
+
+    /// Returns the nearest integer, rounding half-way cases to the even one.
+    fn round_ties_even(self) -> Self;
+
+    /// Returns the integer part of the number.
+    fn trunc(self) -> Self;
+
+    /// Returns the fractional part of the number.
+    fn fract(self) -> Self;
+
+    /// Splits the number into its integer and fractional parts, which add up exactly to it.
+    fn modf(self) -> (Self, Self);
+
+    /// Returns a number that represents the sign of the number.
+    fn signum(self) -> Self;
+
+    /// Returns the floating point category of the number.
+    fn classify(self) -> FpCategory;
+
+    /// Returns `true` if the number is `NaN`.
+    fn is_nan(self) -> bool;
+
+    /// Returns `true` if the number is positive or negative infinity.
+    fn is_infinite(self) -> bool;
+
+    /// Returns `true` if the number is neither infinite nor `NaN`.
+    fn is_finite(self) -> bool;
+
+    /// Returns `true` if the number is neither zero, infinite, subnormal, nor `NaN`.
+    fn is_normal(self) -> bool;
+
+    /// Returns `true` if the number is subnormal.
+    fn is_subnormal(self) -> bool;
+
+    /// Returns `true` if the components of the number are normalized.
+    fn is_normalized(self) -> bool;
+
+    /// Returns `true` if the number is either positive or negative zero.
+    fn is_zero(self) -> bool;
+
+    /// Returns `true` if the number has a negative sign, including `-0.0`.
+    fn is_sign_negative(self) -> bool;
+
+    /// Returns `true` if the number has a positive sign, including `+0.0`.
+    fn is_sign_positive(self) -> bool;
+
+    /// Returns the smallest number of this type that's larger than this one.
+    fn next_up(self) -> Self;
+
+    /// Returns the largest number of this type that's smaller than this one.
+    fn next_down(self) -> Self;
+
+    /// Returns the distance from the number to the next larger one.
+    fn ulp(self) -> Self;
+
+    /// Calculates the absolute value of the difference between two numbers.
+    fn abs_diff(self, other: Self) -> Self;
+
+    /// Returns the ordering between the number and the argument under IEEE 754's totalOrder
+    /// predicate, which orders NaNs and distinguishes zeros.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Returns the larger of two numbers, ignoring NaN if the other is a number.
+    fn max(self, other: Self) -> Self;
+
+    /// Returns the smaller of two numbers, ignoring NaN if the other is a number.
+    fn min(self, other: Self) -> Self;
+
+    /// Returns the larger of two numbers, propagating NaN.
+    fn maximum(self, other: Self) -> Self;
+
+    /// Returns the smaller of two numbers, propagating NaN.
+    fn minimum(self, other: Self) -> Self;
+
+    /// Restricts the number to the interval between `min` and `max`.
+    fn clamp(self, min: Self, max: Self) -> Self;
+
+    /// Calculates `self * a + b` with only one rounding when the product and `b` nearly
+    /// cancel.
+    fn mul_add(self, a: Self, b: Self) -> Self;
+
+    /// Evaluates a polynomial, given its coefficients starting with the constant term,
+    /// using Horner's method.
+    fn polyval(coeffs: &[Self], x: Self) -> Self;
+
+    /// Evaluates a polynomial using a compensated version of Horner's method, which stays
+    /// accurate near the polynomial's roots.
+    fn polyval_compensated(coeffs: &[Self], x: Self) -> Self;
+
+    /// Calculates the reciprocal (1/x).
+    fn recip(self) -> Self;
+
+    /// Calculates the quotient of Euclidean division, the integer *n* for which
+    /// `rem_euclid` is the remainder.
+    fn div_euclid(self, rhs: Self) -> Self;
+
+    /// Calculates the least nonnegative remainder of division by `rhs`.
+    fn rem_euclid(self, rhs: Self) -> Self;
+
+    /// Calculates the square.
+    fn sqr(self) -> Self;
+
+    /// Calculates the cube.
+    fn cube(self) -> Self;
+
+    /// Calculates the square root.
+    fn sqrt(self) -> Self;
+
+    /// Calculates the reciprocal of the square root.
+    fn recip_sqrt(self) -> Self;
+
+    /// Calculates the cube root.
+    fn cbrt(self) -> Self;
+
+    /// Calculates the `n`th root.
+    fn nroot(self, n: i32) -> Self;
+
+    /// Raises the number to an integer power.
+    fn powi(self, n: i32) -> Self;
+
+    /// Raises the number to an unsigned integer power.
+    fn powu(self, n: u32) -> Self;
+
+    /// Raises the number to a floating-point power.
+    fn powf(self, n: Self) -> Self;
+
+    /// Multiplies the number by 2<sup>`n`</sup>.
+    fn ldexp(self, n: i32) -> Self;
+
+    /// Breaks the number into a mantissa and a power of 2, the inverse of `ldexp`.
+    fn frexp(self) -> (Self, i32);
+
+    /// Multiplies the number by an integer, with a shortcut when the product is exact.
+    fn mul_i32(self, k: i32) -> Self;
+
+    /// Calculates e<sup>x</sup>.
+    fn exp(self) -> Self;
+
+    /// Calculates e<sup>x</sup> - 1, accurately even for x near 0.
+    fn exp_m1(self) -> Self;
+
+    /// Calculates 2<sup>x</sup>, exactly when x is an integer.
+    fn exp2(self) -> Self;
+
+    /// Calculates 10<sup>x</sup>.
+    fn exp10(self) -> Self;
+
+    /// Calculates the natural logarithm.
+    fn ln(self) -> Self;
+
+    /// Calculates ln(1 + x), accurately even for x near 0.
+    fn ln_1p(self) -> Self;
+
+    /// Calculates the base-10 logarithm.
+    fn log10(self) -> Self;
+
+    /// Calculates the base-2 logarithm.
+    fn log2(self) -> Self;
+
+    /// Calculates the logarithm to the base `b`.
+    fn log(self, b: Self) -> Self;
+
+    /// Calculates the gamma function.
+    fn gamma(self) -> Self;
+
+    /// Calculates the natural logarithm of the absolute value of the gamma function.
+    fn ln_gamma(self) -> Self;
+
+    /// Calculates the error function.
+    fn erf(self) -> Self;
+
+    /// Calculates the complementary error function.
+    fn erfc(self) -> Self;
+
+    /// Calculates the Bessel function of the first kind of order zero.
+    fn j0(self) -> Self;
+
+    /// Calculates the Bessel function of the first kind of order one.
+    fn j1(self) -> Self;
+
+    /// Calculates the Bessel function of the second kind of order zero.
+    fn y0(self) -> Self;
+
+    /// Calculates the Bessel function of the second kind of order one.
+    fn y1(self) -> Self;
+
+    /// Calculates the sine.
+    fn sin(self) -> Self;
+
+    /// Calculates the cosine.
+    fn cos(self) -> Self;
+
+    /// Simultaneously calculates the sine and the cosine.
+    fn sin_cos(self) -> (Self, Self);
+
+    /// Calculates the tangent.
+    fn tan(self) -> Self;
+
+    /// Calculates the sine of π times the number.
+    fn sin_pi(self) -> Self;
+
+    /// Calculates the cosine of π times the number.
+    fn cos_pi(self) -> Self;
+
+    /// Calculates the tangent of π times the number.
+    fn tan_pi(self) -> Self;
+
+    /// Calculates the sine of the number taken as an angle in degrees.
+    fn sin_deg(self) -> Self;
+
+    /// Calculates the cosine of the number taken as an angle in degrees.
+    fn cos_deg(self) -> Self;
+
+    /// Calculates the tangent of the number taken as an angle in degrees.
+    fn tan_deg(self) -> Self;
+
+    /// Converts the number from degrees to radians.
+    fn to_radians(self) -> Self;
+
+    /// Converts the number from radians to degrees.
+    fn to_degrees(self) -> Self;
+
+    /// Calculates the inverse sine.
+    fn asin(self) -> Self;
+
+    /// Calculates the inverse cosine.
+    fn acos(self) -> Self;
+
+    /// Calculates the inverse sine, treating arguments barely outside of [-1, 1] as ±1.
+    fn asin_clamped(self) -> Self;
+
+    /// Calculates the inverse cosine, treating arguments barely outside of [-1, 1] as ±1.
+    fn acos_clamped(self) -> Self;
+
+    /// Calculates the inverse tangent.
+    fn atan(self) -> Self;
+
+    /// Calculates the four-quadrant inverse tangent of the number (as y) and `other` (as
+    /// x).
+    fn atan2(self, other: Self) -> Self;
+
+    /// Calculates the hyperbolic sine.
+    fn sinh(self) -> Self;
+
+    /// Calculates the hyperbolic cosine.
+    fn cosh(self) -> Self;
+
+    /// Simultaneously calculates the hyperbolic sine and the hyperbolic cosine.
+    fn sinh_cosh(self) -> (Self, Self);
+
+    /// Calculates the hyperbolic tangent.
+    fn tanh(self) -> Self;
+
+    /// Calculates the inverse hyperbolic sine.
+    fn asinh(self) -> Self;
+
+    /// Calculates the inverse hyperbolic cosine.
+    fn acosh(self) -> Self;
+
+    /// Calculates the inverse hyperbolic tangent.
+    fn atanh(self) -> Self;
+
+    /// Calculates the softplus function, ln(1 + e<sup>x</sup>).
+    fn softplus(self) -> Self;
+
+    /// Calculates the logistic function, 1 / (1 + e<sup>-x</sup>).
+    fn logistic(self) -> Self;
+
+    /// Calculates the log of the logistic function.
+    fn log_logistic(self) -> Self;
+
+    /// Calculates a smooth approximation of the minimum of the number and `other` with
+    /// sharpness `k`.
+    fn smooth_min(self, other: Self, k: Self) -> Self;
+
+    /// Calculates a smooth approximation of the maximum of the number and `other` with
+    /// sharpness `k`.
+    fn smooth_max(self, other: Self, k: Self) -> Self;
+}
+
+macro_rules! float_impl {
+    ($t:ident) => {
+        impl Float for $t {
+            const RADIX: u32 = $t::RADIX;
+            const MANTISSA_DIGITS: u32 = $t::MANTISSA_DIGITS;
+            const DIGITS: u32 = $t::DIGITS;
+            const MIN_EXP: i32 = $t::MIN_EXP;
+            const MAX_EXP: i32 = $t::MAX_EXP;
+            const MIN_10_EXP: i32 = $t::MIN_10_EXP;
+            const MAX_10_EXP: i32 = $t::MAX_10_EXP;
+            const EPSILON: $t = $t::EPSILON;
+            const MIN: $t = $t::MIN;
+            const MIN_POSITIVE: $t = $t::MIN_POSITIVE;
+            const MAX: $t = $t::MAX;
+            const NAN: $t = $t::NAN;
+            const INFINITY: $t = $t::INFINITY;
+            const NEG_INFINITY: $t = $t::NEG_INFINITY;
+            const ZERO: $t = $t::ZERO;
+            const NEG_ZERO: $t = $t::NEG_ZERO;
+            const ONE: $t = $t::ONE;
+            const NEG_ONE: $t = $t::NEG_ONE;
+            const PI: $t = $t::PI;
+            const TAU: $t = $t::TAU;
+            const FRAC_PI_2: $t = $t::FRAC_PI_2;
+            const FRAC_PI_3: $t = $t::FRAC_PI_3;
+            const FRAC_PI_4: $t = $t::FRAC_PI_4;
+            const FRAC_PI_6: $t = $t::FRAC_PI_6;
+            const FRAC_PI_8: $t = $t::FRAC_PI_8;
+            const FRAC_PI_16: $t = $t::FRAC_PI_16;
+            const FRAC_3_PI_2: $t = $t::FRAC_3_PI_2;
+            const FRAC_3_PI_4: $t = $t::FRAC_3_PI_4;
+            const FRAC_5_PI_4: $t = $t::FRAC_5_PI_4;
+            const FRAC_7_PI_4: $t = $t::FRAC_7_PI_4;
+            const FRAC_1_PI: $t = $t::FRAC_1_PI;
+            const FRAC_2_PI: $t = $t::FRAC_2_PI;
+            const FRAC_2_SQRT_PI: $t = $t::FRAC_2_SQRT_PI;
+            const SQRT_2: $t = $t::SQRT_2;
+            const FRAC_1_SQRT_2: $t = $t::FRAC_1_SQRT_2;
+            const SQRT_3: $t = $t::SQRT_3;
+            const FRAC_1_SQRT_3: $t = $t::FRAC_1_SQRT_3;
+            const E: $t = $t::E;
+            const LOG2_10: $t = $t::LOG2_10;
+            const LOG2_E: $t = $t::LOG2_E;
+            const LOG10_2: $t = $t::LOG10_2;
+            const LOG10_E: $t = $t::LOG10_E;
+            const LN_2: $t = $t::LN_2;
+            const LN_10: $t = $t::LN_10;
+            const PHI: $t = $t::PHI;
+            const EULER_GAMMA: $t = $t::EULER_GAMMA;
+            const CATALAN: $t = $t::CATALAN;
+
+            #[inline]
+            fn from_f64(a: f64) -> $t {
+                $t::from(a)
+            }
+
+            #[inline]
+            fn to_f64(self) -> f64 {
+                f64::from(self)
+            }
+
+            #[inline]
+            fn abs(self) -> $t {
+                $t::abs(self)
+            }
+
+            #[inline]
+            fn floor(self) -> $t {
+                $t::floor(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> $t {
+                $t::ceil(self)
+            }
+
+            #[inline]
+            fn round(self) -> $t {
+                $t::round(self)
+            }
+
+            #[inline]
+            fn round_ties_even(self) -> $t {
+                $t::round_ties_even(self)
+            }
+
+            #[inline]
+            fn trunc(self) -> $t {
+                $t::trunc(self)
+            }
+
+            #[inline]
+            fn fract(self) -> $t {
+                $t::fract(self)
+            }
+
+            #[inline]
+            fn modf(self) -> ($t, $t) {
+                $t::modf(self)
+            }
+
+            #[inline]
+            fn signum(self) -> $t {
+                $t::signum(self)
+            }
+
+            #[inline]
+            fn classify(self) -> FpCategory {
+                $t::classify(self)
+            }
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+
+            #[inline]
+            fn is_infinite(self) -> bool {
+                $t::is_infinite(self)
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+
+            #[inline]
+            fn is_normal(self) -> bool {
+                $t::is_normal(self)
+            }
+
+            #[inline]
+            fn is_subnormal(self) -> bool {
+                $t::is_subnormal(self)
+            }
+
+            #[inline]
+            fn is_normalized(self) -> bool {
+                $t::is_normalized(self)
+            }
+
+            #[inline]
+            fn is_zero(self) -> bool {
+                $t::is_zero(self)
+            }
+
+            #[inline]
+            fn is_sign_negative(self) -> bool {
+                $t::is_sign_negative(self)
+            }
+
+            #[inline]
+            fn is_sign_positive(self) -> bool {
+                $t::is_sign_positive(self)
+            }
+
+            #[inline]
+            fn next_up(self) -> $t {
+                $t::next_up(self)
+            }
+
+            #[inline]
+            fn next_down(self) -> $t {
+                $t::next_down(self)
+            }
+
+            #[inline]
+            fn ulp(self) -> $t {
+                $t::ulp(self)
+            }
+
+            #[inline]
+            fn abs_diff(self, other: $t) -> $t {
+                $t::abs_diff(self, other)
+            }
+
+            #[inline]
+            fn total_cmp(&self, other: &$t) -> Ordering {
+                $t::total_cmp(self, other)
+            }
+
+            #[inline]
+            fn max(self, other: $t) -> $t {
+                $t::max(self, other)
+            }
+
+            #[inline]
+            fn min(self, other: $t) -> $t {
+                $t::min(self, other)
+            }
+
+            #[inline]
+            fn maximum(self, other: $t) -> $t {
+                $t::maximum(self, other)
+            }
+
+            #[inline]
+            fn minimum(self, other: $t) -> $t {
+                $t::minimum(self, other)
+            }
+
+            #[inline]
+            fn clamp(self, min: $t, max: $t) -> $t {
+                $t::clamp(self, min, max)
+            }
+
+            #[inline]
+            fn mul_add(self, a: $t, b: $t) -> $t {
+                $t::mul_add(self, a, b)
+            }
+
+            #[inline]
+            fn polyval(coeffs: &[$t], x: $t) -> $t {
+                $t::polyval(coeffs, x)
+            }
+
+            #[inline]
+            fn polyval_compensated(coeffs: &[$t], x: $t) -> $t {
+                $t::polyval_compensated(coeffs, x)
+            }
+
+            #[inline]
+            fn recip(self) -> $t {
+                $t::recip(self)
+            }
+
+            #[inline]
+            fn div_euclid(self, rhs: $t) -> $t {
+                $t::div_euclid(self, rhs)
+            }
+
+            #[inline]
+            fn rem_euclid(self, rhs: $t) -> $t {
+                $t::rem_euclid(self, rhs)
+            }
+
+            #[inline]
+            fn sqr(self) -> $t {
+                $t::sqr(self)
+            }
+
+            #[inline]
+            fn cube(self) -> $t {
+                $t::cube(self)
+            }
+
+            #[inline]
+            fn sqrt(self) -> $t {
+                $t::sqrt(self)
+            }
+
+            #[inline]
+            fn recip_sqrt(self) -> $t {
+                $t::recip_sqrt(self)
+            }
+
+            #[inline]
+            fn cbrt(self) -> $t {
+                $t::cbrt(self)
+            }
+
+            #[inline]
+            fn nroot(self, n: i32) -> $t {
+                $t::nroot(self, n)
+            }
+
+            #[inline]
+            fn powi(self, n: i32) -> $t {
+                $t::powi(self, n)
+            }
+
+            #[inline]
+            fn powu(self, n: u32) -> $t {
+                $t::powu(self, n)
+            }
+
+            #[inline]
+            fn powf(self, n: $t) -> $t {
+                $t::powf(self, n)
+            }
+
+            #[inline]
+            fn ldexp(self, n: i32) -> $t {
+                $t::ldexp(self, n)
+            }
+
+            #[inline]
+            fn frexp(self) -> ($t, i32) {
+                $t::frexp(self)
+            }
+
+            #[inline]
+            fn mul_i32(self, k: i32) -> $t {
+                $t::mul_i32(self, k)
+            }
+
+            #[inline]
+            fn exp(self) -> $t {
+                $t::exp(self)
+            }
+
+            #[inline]
+            fn exp_m1(self) -> $t {
+                $t::exp_m1(self)
+            }
+
+            #[inline]
+            fn exp2(self) -> $t {
+                $t::exp2(self)
+            }
+
+            #[inline]
+            fn exp10(self) -> $t {
+                $t::exp10(self)
+            }
+
+            #[inline]
+            fn ln(self) -> $t {
+                $t::ln(self)
+            }
+
+            #[inline]
+            fn ln_1p(self) -> $t {
+                $t::ln_1p(self)
+            }
+
+            #[inline]
+            fn log10(self) -> $t {
+                $t::log10(self)
+            }
+
+            #[inline]
+            fn log2(self) -> $t {
+                $t::log2(self)
+            }
+
+            #[inline]
+            fn log(self, b: $t) -> $t {
+                $t::log(self, b)
+            }
+
+            #[inline]
+            fn gamma(self) -> $t {
+                $t::gamma(self)
+            }
+
+            #[inline]
+            fn ln_gamma(self) -> $t {
+                $t::ln_gamma(self)
+            }
+
+            #[inline]
+            fn erf(self) -> $t {
+                $t::erf(self)
+            }
+
+            #[inline]
+            fn erfc(self) -> $t {
+                $t::erfc(self)
+            }
+
+            #[inline]
+            fn j0(self) -> $t {
+                $t::j0(self)
+            }
+
+            #[inline]
+            fn j1(self) -> $t {
+                $t::j1(self)
+            }
+
+            #[inline]
+            fn y0(self) -> $t {
+                $t::y0(self)
+            }
+
+            #[inline]
+            fn y1(self) -> $t {
+                $t::y1(self)
+            }
+
+            #[inline]
+            fn sin(self) -> $t {
+                $t::sin(self)
+            }
+
+            #[inline]
+            fn cos(self) -> $t {
+                $t::cos(self)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> ($t, $t) {
+                $t::sin_cos(self)
+            }
+
+            #[inline]
+            fn tan(self) -> $t {
+                $t::tan(self)
+            }
+
+            #[inline]
+            fn sin_pi(self) -> $t {
+                $t::sin_pi(self)
+            }
+
+            #[inline]
+            fn cos_pi(self) -> $t {
+                $t::cos_pi(self)
+            }
+
+            #[inline]
+            fn tan_pi(self) -> $t {
+                $t::tan_pi(self)
+            }
+
+            #[inline]
+            fn sin_deg(self) -> $t {
+                $t::sin_deg(self)
+            }
+
+            #[inline]
+            fn cos_deg(self) -> $t {
+                $t::cos_deg(self)
+            }
+
+            #[inline]
+            fn tan_deg(self) -> $t {
+                $t::tan_deg(self)
+            }
+
+            #[inline]
+            fn to_radians(self) -> $t {
+                $t::to_radians(self)
+            }
+
+            #[inline]
+            fn to_degrees(self) -> $t {
+                $t::to_degrees(self)
+            }
+
+            #[inline]
+            fn asin(self) -> $t {
+                $t::asin(self)
+            }
+
+            #[inline]
+            fn acos(self) -> $t {
+                $t::acos(self)
+            }
+
+            #[inline]
+            fn asin_clamped(self) -> $t {
+                $t::asin_clamped(self)
+            }
+
+            #[inline]
+            fn acos_clamped(self) -> $t {
+                $t::acos_clamped(self)
+            }
+
+            #[inline]
+            fn atan(self) -> $t {
+                $t::atan(self)
+            }
+
+            #[inline]
+            fn atan2(self, other: $t) -> $t {
+                $t::atan2(self, other)
+            }
+
+            #[inline]
+            fn sinh(self) -> $t {
+                $t::sinh(self)
+            }
+
+            #[inline]
+            fn cosh(self) -> $t {
+                $t::cosh(self)
+            }
+
+            #[inline]
+            fn sinh_cosh(self) -> ($t, $t) {
+                $t::sinh_cosh(self)
+            }
+
+            #[inline]
+            fn tanh(self) -> $t {
+                $t::tanh(self)
+            }
+
+            #[inline]
+            fn asinh(self) -> $t {
+                $t::asinh(self)
+            }
+
+            #[inline]
+            fn acosh(self) -> $t {
+                $t::acosh(self)
+            }
+
+            #[inline]
+            fn atanh(self) -> $t {
+                $t::atanh(self)
+            }
+
+            #[inline]
+            fn softplus(self) -> $t {
+                $t::softplus(self)
+            }
+
+            #[inline]
+            fn logistic(self) -> $t {
+                $t::logistic(self)
+            }
+
+            #[inline]
+            fn log_logistic(self) -> $t {
+                $t::log_logistic(self)
+            }
+
+            #[inline]
+            fn smooth_min(self, other: $t, k: $t) -> $t {
+                $t::smooth_min(self, other, k)
+            }
+
+            #[inline]
+            fn smooth_max(self, other: $t, k: $t) -> $t {
+                $t::smooth_max(self, other, k)
+            }
+        }
+    };
+}
+
+float_impl!(Double);
+float_impl!(Quad);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Generic versions of a few computations, each checked against the concrete types
+
+    fn machin<T: Float>() -> T {
+        // π/4 = 4 atan(1/5) - atan(1/239)
+        let four = T::from_f64(4.0);
+        let a = (T::ONE / T::from_f64(5.0)).atan();
+        let b = (T::ONE / T::from_f64(239.0)).atan();
+        four * (four * a - b)
+    }
+
+    fn pythagoras<T: Float>(x: T) -> T {
+        let (s, c) = x.sin_cos();
+        s.sqr() + c.sqr()
+    }
+
+    fn newton_sqrt<T: Float>(x: T) -> T {
+        let half = T::from_f64(0.5);
+        let mut y = T::from_f64(x.to_f64().sqrt());
+        for _ in 0..3 {
+            y = half * (y + x / y);
+        }
+        y
+    }
+
+    fn classify_all<T: Float>() -> Vec<FpCategory> {
+        vec![
+            T::ZERO.classify(),
+            T::ONE.classify(),
+            T::INFINITY.classify(),
+            T::NAN.classify(),
+        ]
+    }
+
+    #[test]
+    fn constants() {
+        assert!(<Double as Float>::PI == Double::PI);
+        assert!(<Quad as Float>::PI == Quad::PI);
+        assert!(<Double as Float>::EPSILON == Double::EPSILON);
+        assert!(<Quad as Float>::EPSILON == Quad::EPSILON);
+        assert_eq!(<Double as Float>::MANTISSA_DIGITS, 106);
+        assert_eq!(<Quad as Float>::MANTISSA_DIGITS, 212);
+        assert_eq!(<Double as Float>::MAX_10_EXP, Double::MAX_10_EXP);
+        assert_eq!(<Quad as Float>::MIN_EXP, Quad::MIN_EXP);
+    }
+
+    #[test]
+    fn conversions() {
+        assert!(Double::from_f64(1.5) == Double::from(1.5));
+        assert!(Quad::from_f64(1.5) == Quad::from(1.5));
+        assert_eq!(Double::PI.to_f64(), std::f64::consts::PI);
+        assert_eq!(Quad::PI.to_f64(), std::f64::consts::PI);
+        assert!(Double::default() == Double::ZERO);
+        assert!(Quad::default() == Quad::ZERO);
+    }
+
+    #[test]
+    fn generic_machin() {
+        let d: Double = machin();
+        let q: Quad = machin();
+        assert!((d - Double::PI).abs() < Double::EPSILON * Double::from(8.0));
+        assert!((q - Quad::PI).abs() < Quad::EPSILON * Quad::from(8.0));
+    }
+
+    #[test]
+    fn generic_pythagoras() {
+        for &x in &[0.1, 1.0, 2.5, -7.0, 100.0] {
+            let d = pythagoras(Double::from(x));
+            let q = pythagoras(Quad::from(x));
+            assert!((d - Double::ONE).abs() <= Double::EPSILON * Double::from(3.0));
+            assert!((q - Quad::ONE).abs() <= Quad::EPSILON * Quad::from(2.0));
+        }
+    }
+
+    #[test]
+    fn generic_newton_sqrt() {
+        let d = newton_sqrt(Double::from(2.0));
+        let q = newton_sqrt(Quad::from(2.0));
+        assert!((d - Double::SQRT_2).abs() < Double::EPSILON * Double::from(2.0));
+        assert!((q - Quad::SQRT_2).abs() < Quad::EPSILON * Quad::from(2.0));
+    }
+
+    #[test]
+    fn generic_matches_inherent() {
+        let d = Double::from(0.75);
+        let q = Quad::from(0.75);
+        assert!(Float::exp(d) == d.exp());
+        assert!(Float::exp(q) == q.exp());
+        assert!(Float::exp10(d) == d.exp10());
+        assert!(Float::exp10(q) == q.exp10());
+        assert!(Float::atan2(d, Double::ONE) == d.atan2(Double::ONE));
+        assert!(Float::atan2(q, Quad::ONE) == q.atan2(Quad::ONE));
+        assert!(Float::powi(d, 7) == d.powi(7));
+        assert!(Float::powi(q, 7) == q.powi(7));
+        assert!(Float::mul_add(d, d, -Double::PI) == d.mul_add(d, -Double::PI));
+        assert!(Float::mul_add(q, q, -Quad::PI) == q.mul_add(q, -Quad::PI));
+        assert!(Float::polyval(&[d, d, d], d) == Double::polyval(&[d, d, d], d));
+        assert!(Float::polyval(&[q, q, q], q) == Quad::polyval(&[q, q, q], q));
+        assert!(
+            Float::polyval_compensated(&[d, -d, d], d)
+                == Double::polyval_compensated(&[d, -d, d], d)
+        );
+        assert!(
+            Float::polyval_compensated(&[q, -q, q], q) == Quad::polyval_compensated(&[q, -q, q], q)
+        );
+        assert!(Float::gamma(d) == d.gamma());
+        assert!(Float::ln_gamma(q) == q.ln_gamma());
+        assert!(Float::erf(d) == d.erf());
+        assert!(Float::erfc(q) == q.erfc());
+        assert!(Float::j0(d) == d.j0());
+        assert!(Float::j1(q) == q.j1());
+        assert!(Float::y0(q) == q.y0());
+        assert!(Float::y1(d) == d.y1());
+        assert!(Float::cube(d) == d.cube());
+        assert!(Float::modf(-d) == (-d).modf());
+        assert!(Float::modf(q) == q.modf());
+        assert!(Float::cube(q) == q.cube());
+        assert!(Float::asin_clamped(d) == d.asin_clamped());
+        assert!(Float::acos_clamped(-q) == (-q).acos_clamped());
+        assert!(Float::recip_sqrt(d) == d.recip_sqrt());
+        assert!(Float::recip_sqrt(q) == q.recip_sqrt());
+        assert!(Float::powu(d, 7) == d.powu(7));
+        assert!(Float::powu(q, 7) == q.powu(7));
+        assert!(Float::mul_i32(d, -12) == d.mul_i32(-12));
+        assert!(Float::mul_i32(q, -12) == q.mul_i32(-12));
+        assert!(Float::rem_euclid(-d, Double::PI) == (-d).rem_euclid(Double::PI));
+        assert!(Float::div_euclid(-q, Quad::PI) == (-q).div_euclid(Quad::PI));
+        assert!(Float::is_sign_negative(-d));
+        assert!(Float::is_sign_negative(-q));
+        assert!(Float::next_up(d) == d.next_up());
+        assert!(Float::next_down(q) == q.next_down());
+        assert!(Float::ulp(-d) == (-d).ulp());
+        assert!(Float::abs_diff(q, Quad::PI) == q.abs_diff(Quad::PI));
+    }
+
+    #[test]
+    fn generic_classify() {
+        let expected = vec![
+            FpCategory::Zero,
+            FpCategory::Normal,
+            FpCategory::Infinite,
+            FpCategory::Nan,
+        ];
+        assert_eq!(classify_all::<Double>(), expected);
+        assert_eq!(classify_all::<Quad>(), expected);
+    }
+}
@@ -55,7 +55,12 @@
 //! number type (`dd!` cannot take `u128` or `i128`, as there would be a *loss* of precision
 //! to turn those into double-doubles) or a string containing a number that can be
 //! represented (if the string contains more digits than can be accurately represented by
-//! the type, the extra digits will be ignored).
+//! the type, the extra digits will be ignored). Given a literal, either macro does its work
+//! at compile time, so it can be used to define constants. A float literal is converted
+//! from its digits, just as a string would be, but one with more significant digits than
+//! an `f64` holds is a compile error; digits like those have to be given in a string.
+//! Neither macro panics on a string that isn't a number; it produces NaN instead. Their
+//! counterparts [`try_dd!`][9] and [`try_qd!`][10] return the parsing error in a `Result`.
 //!
 //! Once you have a double-double or a quad-double, you can use it just like you would an
 //! `f64`: all of the mathematical operators work on them, the vast majority of methods
@@ -64,6 +69,16 @@
 //! `println!` and the like with all of the formatting options that are available with
 //! `f64`.
 //!
+//! The operators can't be used in constants, since trait methods can't yet be `const`. For
+//! tables of values that should be built at compile time, [`add_const`][11], `sub_const`,
+//! `mul_const`, and `div_const` do the same arithmetic as `const fn`s, with exactly the
+//! same results.
+//!
+//! As with `f64`, there is no exponentiation operator. In particular, `^` is not
+//! implemented, so `dd!(2) ^ 10` is a compile error. Powers are calculated with methods
+//! instead: `powi` (also available as `pow`) for `i32` exponents, `powu` for `u32`
+//! exponents, and `powf` for exponents of the same type.
+//!
 //! It's important to note that double-doubles and quad-doubles are incompatible with each
 //! other and with other numbers, short of options to directly convert one to another. In
 //! other words, you can't add an `f64` to a `Double` (though you can convert the `f64` to a
@@ -97,23 +112,72 @@
 //! efficiently, and generally everything works better.
 //!
 //! *Nearly* every function in qd normalizes when necessary. The sole exceptions are
-//! [`Double::raw`][4] and [`Quad::raw`][5], which specifically skip normalization and
+//! [`Double::new`][4] and [`Quad::new`][5], which specifically skip normalization and
 //! should only be used on numbers that are already known to be normalized.
 //!
+//! # Overflow
+//!
+//! The largest finite numbers are [`Double::MAX`][6] and [`Quad::MAX`][7]. Arithmetic
+//! (`+`, `-`, `*`, `/`, and `sqr`) on finite operands returns a finite, fully accurate
+//! result whenever the exact result is no larger in magnitude than `MAX`, even though the
+//! algorithms produce intermediate values that can overflow when the result is that close
+//! to it. When the exact result is larger, the result is infinity with the sign that the
+//! exact result has, never NaN. Within an ulp or two of `MAX`, rounding can go either
+//! way.
+//!
+//! The same holds for `sqrt`, `nroot`, `cbrt`, `ln`, `log2`, `log10`, `atan`, `atan2`,
+//! and `tanh` over their entire domains, and for `exp` right up to ln `MAX`. Where other functions stop short
+//! of `MAX`, their documentation says so. The sine and cosine of numbers that large are
+//! always finite, but see [`Double::sin`][8] for how much they mean.
+//!
 //! [1]: http://web.mit.edu/tabbott/Public/quaddouble-debian/qd-2.3.4-old/docs/qd.pdf
 //! [2]: macros.dd.html
 //! [3]: macros.qd.html
-//! [4]: struct.Double.html#methods.raw
-//! [5]: struct.Quad.html:methods.raw
+//! [4]: struct.Double.html#method.new
+//! [5]: struct.Quad.html#method.new
+//! [6]: struct.Double.html#associatedconstant.MAX
+//! [7]: struct.Quad.html#associatedconstant.MAX
+//! [8]: struct.Double.html#method.sin
+//! [9]: macro.try_dd.html
+//! [10]: macro.try_qd.html
+//! [11]: struct.Double.html#method.add_const
 
 #![warn(clippy::all)]
 #![allow(clippy::needless_doctest_main)]
 
 mod common;
 mod double;
+mod float;
 mod quad;
 
 pub mod error;
+pub mod mixed;
+pub mod ordered;
+pub mod prelude;
+pub mod slice;
+
+#[doc(hidden)]
+pub use self::common::input::TryInput;
+#[doc(hidden)]
+pub use self::common::literal::LiteralError;
+pub use self::common::radix::RadixString;
+pub use self::common::rounding::RoundingMode;
+pub use self::double::{Double, DoubleAccumulator};
+pub use self::float::Float;
+pub use self::ordered::{OrderedDouble, OrderedQuad};
+pub use self::quad::{Quad, QuadAccumulator};
+
+#[cfg(feature = "plotters")]
+pub use self::double::DoubleRange;
+#[cfg(feature = "plotters")]
+pub use self::quad::QuadRange;
+
+/// A short alias for [`Double`], for code that prefers the names used in the literature.
+///
+/// [`Double`]: struct.Double.html
+pub type DD = Double;
 
-pub use self::double::Double;
-pub use self::quad::Quad;
+/// A short alias for [`Quad`], for code that prefers the names used in the literature.
+///
+/// [`Quad`]: struct.Quad.html
+pub type QD = Quad;
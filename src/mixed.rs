@@ -0,0 +1,516 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Accumulating at `Quad` precision into numbers that are stored as `Double`s.
+//!
+//! Adding many small contributions to a number loses a little accuracy with every
+//! addition, and over enough additions a `Double` can lose much more than the last bit.
+//! Doing the additions in `Quad` fixes that, but a `Quad` takes twice the memory of a
+//! `Double`, which can be too much for a large state that has to stay in memory for a whole
+//! computation.
+//!
+//! An [`AccumView`] gets `Quad` accuracy without storing `Quad`s. It wraps a slice of
+//! `Double`s, and while it exists each element of that slice holds the leading half of a
+//! `Quad`. The trailing halves (the residuals) are kept in a scratch slice of the same
+//! length, which is either borrowed from the caller, so that one scratch slice can be
+//! reused across many views, or allocated by the view the first time it's needed. A `Quad`
+//! is exactly the sum of its two halves, so every addition through the view has exactly the
+//! result that it would have had if the whole accumulation had been done in `Quad`.
+//!
+//! When the view is flushed (or dropped), each residual is folded back into its element,
+//! leaving each element as the `Double` nearest to its `Quad` sum. If the full sums are
+//! wanted instead, [`flush_into_quads`] copies them out first.
+//!
+//! # Examples
+//! ```
+//! # use qd::Double;
+//! use qd::mixed::AccumView;
+//!
+//! // Each contribution is far below the last bit of π's second component
+//! let tiny = 2f64.powi(-116);
+//!
+//! let mut state = vec![Double::PI; 2];
+//! let mut view = AccumView::new(&mut state);
+//! for _ in 0..1 << 16 {
+//!     view.add_f64(0, tiny);
+//! }
+//! view.flush();
+//! assert!(state[0] == Double::PI + Double::from(2f64.powi(-100)));
+//! assert!(state[1] == Double::PI);
+//!
+//! // Adding in Double loses every one of them
+//! let mut naive = Double::PI;
+//! for _ in 0..1 << 16 {
+//!     naive += Double::from(tiny);
+//! }
+//! assert!(naive == Double::PI);
+//! ```
+//!
+//! [`AccumView`]: struct.AccumView.html
+//! [`flush_into_quads`]: struct.AccumView.html#method.flush_into_quads
+
+use crate::common::utils as u;
+use crate::double::Double;
+use crate::quad::Quad;
+
+// Where the residuals live. An owned scratch is allocated on the first addition, so that
+// a view that's never added to never allocates.
+enum Scratch<'a> {
+    Owned(Vec<Double>),
+    Borrowed(&'a mut [Double]),
+}
+
+/// A view over a slice of `Double`s that accumulates into them at `Quad` precision.
+///
+/// See the [module documentation] for how it works. The view holds the only reference to
+/// the slice while it exists, so nothing else can read the elements while they hold only
+/// half of their sums, and nothing can change them in a way that the residuals don't know
+/// about. Dropping the view flushes it, just as [`flush`] does.
+///
+/// # Examples
+/// ```compile_fail
+/// # use qd::Double;
+/// use qd::mixed::AccumView;
+///
+/// let mut state = [Double::ONE];
+/// let mut view = AccumView::new(&mut state);
+/// let x = state[0]; // `state` is still borrowed by the view
+/// view.add(0, x);
+/// ```
+///
+/// [module documentation]: index.html
+/// [`flush`]: #method.flush
+pub struct AccumView<'a> {
+    values: &'a mut [Double],
+    scratch: Scratch<'a>,
+}
+
+impl<'a> AccumView<'a> {
+    /// Creates a view over `values` that allocates its own scratch space for residuals.
+    ///
+    /// Nothing is allocated until the first addition.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use qd::mixed::AccumView;
+    ///
+    /// let mut state = [dd!(2), dd!(3)];
+    /// let mut view = AccumView::new(&mut state);
+    /// view.add(1, dd!(0.5));
+    /// view.flush();
+    /// assert!(state == [dd!(2), dd!(3.5)]);
+    /// ```
+    pub fn new(values: &'a mut [Double]) -> AccumView<'a> {
+        AccumView {
+            values,
+            scratch: Scratch::Owned(Vec::new()),
+        }
+    }
+
+    /// Creates a view over `values` that keeps its residuals in `scratch`.
+    ///
+    /// The scratch slice is cleared first, so whatever it held before doesn't matter. After
+    /// the view is flushed, it holds nothing of use and can be passed to the next view.
+    ///
+    /// # Panics
+    /// Panics if `scratch` has a different length than `values`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use qd::mixed::AccumView;
+    ///
+    /// let mut state = vec![Double::ZERO; 4];
+    /// let mut scratch = vec![Double::ZERO; 4];
+    /// for step in 0..3 {
+    ///     let mut view = AccumView::with_scratch(&mut state, &mut scratch);
+    ///     for i in 0..4 {
+    ///         view.add_f64(i, 0.25);
+    ///     }
+    ///     view.flush();
+    /// }
+    /// assert!(state == vec![dd!(0.75); 4]);
+    /// ```
+    pub fn with_scratch(values: &'a mut [Double], scratch: &'a mut [Double]) -> AccumView<'a> {
+        assert_eq!(
+            scratch.len(),
+            values.len(),
+            "scratch slice length does not match the number of values"
+        );
+        for r in scratch.iter_mut() {
+            *r = Double::ZERO;
+        }
+        AccumView {
+            values,
+            scratch: Scratch::Borrowed(scratch),
+        }
+    }
+
+    /// Returns the number of elements in the view.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the view has no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Adds `v` to element `i`.
+    ///
+    /// The sum is exactly what adding `v` to the element's full sum as a `Quad` would
+    /// produce.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    #[inline]
+    pub fn add(&mut self, i: usize, v: Double) {
+        let (a, b) = v.as_components();
+        self.accumulate(i, Quad::new(a, b, 0.0, 0.0));
+    }
+
+    /// Adds the `f64` `v` to element `i`.
+    ///
+    /// `v` is added exactly as it is, which is the same as passing `Double::from(v)` to
+    /// [`add`].
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    ///
+    /// [`add`]: #method.add
+    #[inline]
+    pub fn add_f64(&mut self, i: usize, v: f64) {
+        self.accumulate(i, Quad::new(v, 0.0, 0.0, 0.0));
+    }
+
+    /// Returns the full sum of element `i` as a `Quad`.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{Double, Quad};
+    /// use qd::mixed::AccumView;
+    ///
+    /// let mut state = [Double::ONE];
+    /// let mut view = AccumView::new(&mut state);
+    /// view.add_f64(0, 2f64.powi(-150));
+    /// assert!(view.get(0) == Quad::new(1.0, 2f64.powi(-150), 0.0, 0.0));
+    /// ```
+    pub fn get(&self, i: usize) -> Quad {
+        let (a, b) = self.values[i].as_components();
+        let (c, d) = match &self.scratch {
+            Scratch::Owned(r) if r.is_empty() => (0.0, 0.0),
+            Scratch::Owned(r) => r[i].as_components(),
+            Scratch::Borrowed(r) => r[i].as_components(),
+        };
+        Quad::new(a, b, c, d)
+    }
+
+    /// Copies the full sum of every element, as a `Quad`, into `out`.
+    ///
+    /// The view is left as it was, so accumulation can go on afterwards.
+    ///
+    /// # Panics
+    /// Panics if `out` has a different length than the view.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{Double, Quad};
+    /// use qd::mixed::AccumView;
+    ///
+    /// let mut state = [Double::ONE, Double::ZERO];
+    /// let mut view = AccumView::new(&mut state);
+    /// view.add_f64(0, 2f64.powi(-150));
+    ///
+    /// let mut sums = [Quad::ZERO; 2];
+    /// view.flush_into_quads(&mut sums);
+    /// assert!(sums == [Quad::new(1.0, 2f64.powi(-150), 0.0, 0.0), Quad::ZERO]);
+    /// ```
+    pub fn flush_into_quads(&self, out: &mut [Quad]) {
+        assert_eq!(
+            out.len(),
+            self.values.len(),
+            "output slice length does not match the number of values"
+        );
+        for (i, q) in out.iter_mut().enumerate() {
+            *q = self.get(i);
+        }
+    }
+
+    /// Folds the residuals back into the elements and ends the view.
+    ///
+    /// Each element becomes the `Double` nearest to its full sum. Dropping the view does
+    /// the same thing; this just makes it explicit.
+    #[inline]
+    pub fn flush(self) {}
+
+    fn accumulate(&mut self, i: usize, v: Quad) {
+        let n = self.values.len();
+        let residuals = match &mut self.scratch {
+            Scratch::Owned(r) => {
+                if r.is_empty() {
+                    *r = vec![Double::ZERO; n];
+                }
+                &mut r[..]
+            }
+            Scratch::Borrowed(r) => &mut r[..],
+        };
+        let (a, b) = self.values[i].as_components();
+        let (c, d) = residuals[i].as_components();
+        let sum = Quad::new(a, b, c, d) + v;
+        self.values[i] = Double::new(sum[0], sum[1]);
+        residuals[i] = Double::new(sum[2], sum[3]);
+    }
+}
+
+impl Drop for AccumView<'_> {
+    fn drop(&mut self) {
+        let residuals = match &self.scratch {
+            Scratch::Owned(r) if r.is_empty() => return,
+            Scratch::Owned(r) => &r[..],
+            Scratch::Borrowed(r) => &r[..],
+        };
+        for (v, r) in self.values.iter_mut().zip(residuals) {
+            *v = nearest(*v, *r);
+        }
+    }
+}
+
+// The Double nearest to the Quad whose halves are `hi` and `lo`.
+fn nearest(hi: Double, lo: Double) -> Double {
+    let (a, b) = u::round2(hi[0], hi[1], lo[0], lo[1]);
+    Double::new(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::random::Rng;
+
+    // Random numbers whose exponents cover about 2^-100 to 2^100, with random signs
+    fn randoms(count: usize, seed: u64) -> Vec<Double> {
+        let mut rng = Rng::new(seed);
+        (0..count)
+            .map(|_| {
+                let m = rng.next_f64() + 0.5;
+                let t = rng.next_f64();
+                let x = Double::from_components(m, t * m * f64::EPSILON * f64::EPSILON);
+                let x = x * Double::from(2.0).powi(rng.range(-100, 100));
+                if rng.next_u64() % 2 == 0 {
+                    x
+                } else {
+                    -x
+                }
+            })
+            .collect()
+    }
+
+    fn same_quad(a: Quad, b: Quad) -> bool {
+        a.to_bits() == b.to_bits()
+    }
+
+    // Accumulates `contributions` round-robin into a view over `start`, and into Quads
+    // directly, and checks that every sum is bit for bit the same
+    fn check_against_quad(start: &[Double], contributions: &[Double]) {
+        let n = start.len();
+        let mut quads: Vec<Quad> = start.iter().map(|&d| Quad::from(d)).collect();
+        let mut values = start.to_vec();
+        let mut view = AccumView::new(&mut values);
+        for (k, &c) in contributions.iter().enumerate() {
+            view.add(k % n, c);
+            quads[k % n] += Quad::from(c);
+        }
+        let mut sums = vec![Quad::NAN; n];
+        view.flush_into_quads(&mut sums);
+        for i in 0..n {
+            assert!(
+                same_quad(sums[i], quads[i]),
+                "{:?} should be {:?}",
+                sums[i],
+                quads[i]
+            );
+        }
+        view.flush();
+        for i in 0..n {
+            let err = (Quad::from(values[i]) - quads[i]).abs();
+            assert!(err <= Quad::new(values[i][1].abs() * f64::EPSILON, 0.0, 0.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn random_contributions() {
+        let start = randoms(7, 0x2545_f491_4f6c_dd1d);
+        check_against_quad(&start, &randoms(10_000, 0x9e37_79b9_7f4a_7c15));
+    }
+
+    #[test]
+    fn alternating_signs() {
+        // Each pair nearly cancels, leaving only the low bits of the two contributions
+        let xs = randoms(5000, 0x1234_5678_9abc_def1);
+        let contributions: Vec<Double> = xs
+            .iter()
+            .flat_map(|&x| {
+                let y = x * Double::from(1.0 + f64::EPSILON);
+                vec![x, -y]
+            })
+            .collect();
+        check_against_quad(&[Double::ZERO, Double::ONE], &contributions);
+    }
+
+    #[test]
+    fn huge_dynamic_range() {
+        let mut contributions = vec![];
+        for k in 0..2000 {
+            let big = Double::from(2.0).powi(300 - (k % 7) * 100);
+            contributions.push(big);
+            contributions.push(Double::from(1e-30) * Double::from(k));
+            contributions.push(-big);
+        }
+        check_against_quad(&[Double::ZERO, Double::PI, -Double::E], &contributions);
+    }
+
+    #[test]
+    fn recovers_lost_contributions() {
+        let mut values = [Double::ONE];
+        let mut view = AccumView::new(&mut values);
+        for _ in 0..1 << 20 {
+            view.add_f64(0, 2f64.powi(-120));
+        }
+        view.flush();
+        assert!(values[0] == Double::new(1.0, 2f64.powi(-100)));
+    }
+
+    #[test]
+    fn flush_rounding() {
+        // The residual's second component decides which way a halfway point rounds
+        let b = 2f64.powi(-60);
+        let ulp = 2f64.powi(-112);
+        let half = ulp / 2.0;
+        let tiny = half * 2f64.powi(-60);
+
+        // Exactly halfway, so the tie goes to the even neighbour
+        let flushed = nearest(Double::new(1.0, b + ulp), Double::new(half, 0.0));
+        assert!(flushed == Double::new(1.0, b + 2.0 * ulp));
+        let flushed = nearest(Double::new(1.0, b + 2.0 * ulp), Double::new(half, 0.0));
+        assert!(flushed == Double::new(1.0, b + 2.0 * ulp));
+
+        // Just past halfway in either direction
+        let flushed = nearest(Double::new(1.0, b + 2.0 * ulp), Double::new(half, tiny));
+        assert!(flushed == Double::new(1.0, b + 3.0 * ulp));
+        let flushed = nearest(Double::new(1.0, b + ulp), Double::new(half, -tiny));
+        assert!(flushed == Double::new(1.0, b + ulp));
+        let flushed = nearest(Double::new(1.0, b + ulp), Double::new(-half, -tiny));
+        assert!(flushed == Double::new(1.0, b));
+
+        // Rounding the second component up to half of the first one's last bit
+        let flushed = nearest(
+            Double::new(1.0, 2f64.powi(-53) - 2f64.powi(-106)),
+            Double::new(2f64.powi(-107), 2f64.powi(-167)),
+        );
+        assert!(flushed == Double::new(1.0, 2f64.powi(-53)));
+
+        assert!(nearest(Double::INFINITY, Double::ZERO) == Double::INFINITY);
+        assert!(nearest(Double::NAN, Double::ZERO).is_nan());
+    }
+
+    #[test]
+    fn flush_random() {
+        let xs = randoms(2000, 0x5555_aaaa_1234_4321);
+        let mut values = vec![Double::ONE; 10];
+        let mut view = AccumView::new(&mut values);
+        for (k, &x) in xs.iter().enumerate() {
+            view.add(k % 10, x);
+        }
+        let mut sums = vec![Quad::ZERO; 10];
+        view.flush_into_quads(&mut sums);
+        view.flush();
+        for i in 0..10 {
+            // Nearest means that neither neighbour of the second component is closer
+            let (a, b) = values[i].as_components();
+            let err = (Quad::from(values[i]) - sums[i]).abs();
+            for &nb in &[next_up(b), next_down(b)] {
+                let other = (Quad::from(Double::new(a, nb)) - sums[i]).abs();
+                assert!(err <= other);
+            }
+        }
+    }
+
+    fn next_up(x: f64) -> f64 {
+        if x == 0.0 {
+            f64::from_bits(1)
+        } else if x > 0.0 {
+            f64::from_bits(x.to_bits() + 1)
+        } else {
+            f64::from_bits(x.to_bits() - 1)
+        }
+    }
+
+    fn next_down(x: f64) -> f64 {
+        -next_up(-x)
+    }
+
+    #[test]
+    fn scratch_is_cleared_and_reused() {
+        let mut values = [Double::ONE, Double::ZERO];
+        let mut scratch = [Double::PI, Double::NAN];
+        let mut view = AccumView::with_scratch(&mut values, &mut scratch);
+        assert!(view.get(0) == Quad::ONE);
+        assert!(view.get(1) == Quad::ZERO);
+        view.add_f64(0, 2f64.powi(-140));
+        view.flush();
+        assert!(values[0] == Double::new(1.0, 2f64.powi(-140)));
+
+        let mut view = AccumView::with_scratch(&mut values, &mut scratch);
+        view.add_f64(1, 2.0);
+        drop(view);
+        assert!(values[1] == Double::from(2.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "scratch slice length")]
+    fn scratch_length() {
+        let mut values = [Double::ZERO; 3];
+        let mut scratch = [Double::ZERO; 2];
+        AccumView::with_scratch(&mut values, &mut scratch);
+    }
+
+    #[test]
+    fn no_allocation_without_additions() {
+        let mut values = [Double::PI];
+        let view = AccumView::new(&mut values);
+        assert!(matches!(&view.scratch, Scratch::Owned(r) if r.capacity() == 0));
+        assert!(view.get(0) == Quad::from(Double::PI));
+        view.flush();
+        assert!(values[0] == Double::PI);
+    }
+
+    #[test]
+    fn drop_flushes() {
+        let mut values = [Double::ZERO];
+        {
+            let mut view = AccumView::new(&mut values);
+            view.add_f64(0, 1.0);
+            view.add_f64(0, 2f64.powi(-140));
+            assert!(view.len() == 1 && !view.is_empty());
+        }
+        assert!(values[0] == Double::new(1.0, 2f64.powi(-140)));
+    }
+
+    #[test]
+    fn special_values() {
+        let mut values = [Double::ONE, Double::ONE];
+        let mut view = AccumView::new(&mut values);
+        view.add(0, Double::INFINITY);
+        view.add_f64(1, f64::NAN);
+        view.flush();
+        assert!(values[0] == Double::INFINITY);
+        assert!(values[1].is_nan());
+    }
+}
@@ -0,0 +1,338 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Wrappers for `Double`s and `Quad`s that are never NaN, so that they can be totally
+//! ordered and used as keys.
+//!
+//! [`Double`] and [`Quad`] implement `PartialEq`, `PartialOrd`, and `Hash`, but not `Eq` or
+//! `Ord`, because NaN isn't equal to itself and isn't ordered against anything. That keeps
+//! them out of `HashMap`s, `BTreeMap`s, and anything else that needs those traits.
+//!
+//! [`OrderedDouble`] and [`OrderedQuad`] fix that by refusing to hold NaN. Creating one from
+//! NaN fails with a [`NanError`], and every other number (infinities included) is wrapped
+//! as it is. Without NaN, equality is an equivalence relation and the usual ordering of
+//! numbers is total, so the wrappers implement `Eq` and `Ord` along with `Hash`. As with
+//! the wrapped types, zero and negative zero are equal and hash the same.
+//!
+//! # Examples
+//! ```
+//! # use qd::{dd, Double};
+//! use qd::ordered::OrderedDouble;
+//! use std::collections::HashMap;
+//!
+//! let mut memo = HashMap::new();
+//! let key = OrderedDouble::new(dd!(2)).unwrap();
+//! memo.insert(key, dd!(2).sqrt());
+//!
+//! assert!(memo[&OrderedDouble::new(dd!(2)).unwrap()] == dd!(2).sqrt());
+//! assert!(OrderedDouble::new(Double::NAN).is_err());
+//! ```
+//!
+//! [`Double`]: ../struct.Double.html
+//! [`Quad`]: ../struct.Quad.html
+//! [`OrderedDouble`]: struct.OrderedDouble.html
+//! [`OrderedQuad`]: struct.OrderedQuad.html
+//! [`NanError`]: ../error/struct.NanError.html
+
+use crate::double::Double;
+use crate::error::NanError;
+use crate::quad::Quad;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter, LowerExp, Result, UpperExp};
+use std::hash::{Hash, Hasher};
+
+/// A [`Double`] that is never NaN.
+///
+/// This implements `Eq`, `Ord`, and `Hash`, so it can be the key of a `HashMap` or a
+/// `BTreeMap`, and a `Vec` of them can be sorted with `sort`. See the [module
+/// documentation](index.html) for more.
+///
+/// # Examples
+/// ```
+/// # use qd::dd;
+/// use qd::ordered::OrderedDouble;
+///
+/// let mut v: Vec<OrderedDouble> = [3, 1, 2]
+///     .iter()
+///     .map(|&n| OrderedDouble::new(dd!(n)).unwrap())
+///     .collect();
+/// v.sort();
+/// assert!(v[0].get() == dd!(1));
+/// assert!(v[2].get() == dd!(3));
+/// ```
+///
+/// [`Double`]: ../struct.Double.html
+#[derive(Clone, Copy, Debug)]
+pub struct OrderedDouble(Double);
+
+impl OrderedDouble {
+    /// Wraps a `Double`, failing with a [`NanError`] if it's NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use qd::ordered::OrderedDouble;
+    ///
+    /// assert!(OrderedDouble::new(dd!(1.5)).is_ok());
+    /// assert!(OrderedDouble::new(Double::INFINITY).is_ok());
+    /// assert!(OrderedDouble::new(Double::NAN).is_err());
+    /// ```
+    ///
+    /// [`NanError`]: ../error/struct.NanError.html
+    #[inline]
+    pub fn new(value: Double) -> std::result::Result<OrderedDouble, NanError> {
+        if value.is_nan() {
+            Err(NanError)
+        } else {
+            Ok(OrderedDouble(value))
+        }
+    }
+
+    /// Returns the wrapped `Double`.
+    #[inline]
+    pub fn get(self) -> Double {
+        self.0
+    }
+}
+
+/// A [`Quad`] that is never NaN.
+///
+/// This implements `Eq`, `Ord`, and `Hash`, so it can be the key of a `HashMap` or a
+/// `BTreeMap`, and a `Vec` of them can be sorted with `sort`. See the [module
+/// documentation](index.html) for more.
+///
+/// # Examples
+/// ```
+/// # use qd::qd;
+/// use qd::ordered::OrderedQuad;
+///
+/// let mut v: Vec<OrderedQuad> = [3, 1, 2]
+///     .iter()
+///     .map(|&n| OrderedQuad::new(qd!(n)).unwrap())
+///     .collect();
+/// v.sort();
+/// assert!(v[0].get() == qd!(1));
+/// assert!(v[2].get() == qd!(3));
+/// ```
+///
+/// [`Quad`]: ../struct.Quad.html
+#[derive(Clone, Copy, Debug)]
+pub struct OrderedQuad(Quad);
+
+impl OrderedQuad {
+    /// Wraps a `Quad`, failing with a [`NanError`] if it's NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use qd::ordered::OrderedQuad;
+    ///
+    /// assert!(OrderedQuad::new(qd!(1.5)).is_ok());
+    /// assert!(OrderedQuad::new(Quad::INFINITY).is_ok());
+    /// assert!(OrderedQuad::new(Quad::NAN).is_err());
+    /// ```
+    ///
+    /// [`NanError`]: ../error/struct.NanError.html
+    #[inline]
+    pub fn new(value: Quad) -> std::result::Result<OrderedQuad, NanError> {
+        if value.is_nan() {
+            Err(NanError)
+        } else {
+            Ok(OrderedQuad(value))
+        }
+    }
+
+    /// Returns the wrapped `Quad`.
+    #[inline]
+    pub fn get(self) -> Quad {
+        self.0
+    }
+}
+
+// The trait implementations are the same for both types.
+macro_rules! ordered_impls {
+    ($name:ident, $t:ident) => {
+        impl TryFrom<$t> for $name {
+            type Error = NanError;
+
+            #[inline]
+            fn try_from(value: $t) -> std::result::Result<$name, NanError> {
+                $name::new(value)
+            }
+        }
+
+        impl From<$name> for $t {
+            #[inline]
+            fn from(value: $name) -> $t {
+                value.0
+            }
+        }
+
+        impl PartialEq for $name {
+            #[inline]
+            fn eq(&self, other: &$name) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl PartialOrd for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &$name) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            /// Compares the wrapped numbers. Neither can be NaN, so they're always ordered,
+            /// and zero and negative zero are equal, just as they are with `==`.
+            #[inline]
+            fn cmp(&self, other: &$name) -> Ordering {
+                self.0.partial_cmp(&other.0).unwrap()
+            }
+        }
+
+        impl Hash for $name {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.hash(state)
+            }
+        }
+
+        impl Display for $name {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter) -> Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+
+        impl LowerExp for $name {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter) -> Result {
+                LowerExp::fmt(&self.0, f)
+            }
+        }
+
+        impl UpperExp for $name {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter) -> Result {
+                UpperExp::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+ordered_impls!(OrderedDouble, Double);
+ordered_impls!(OrderedQuad, Quad);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{BTreeSet, HashMap};
+
+    fn hash<T: Hash>(x: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        x.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn double_hash_zeros() {
+        assert_eq!(hash(&Double::ZERO), hash(&Double::NEG_ZERO));
+        // These are (-1, 0) and (-1, -0)
+        assert_eq!(hash(&Double::NEG_ONE), hash(&-Double::ONE));
+        assert_ne!(hash(&Double::ONE), hash(&Double::from_array([1.0, 1e-20])));
+    }
+
+    #[test]
+    fn quad_hash_zeros() {
+        assert_eq!(hash(&Quad::ZERO), hash(&Quad::NEG_ZERO));
+        // These are (-1, 0, 0, 0) and (-1, -0, -0, -0)
+        assert_eq!(hash(&Quad::NEG_ONE), hash(&-Quad::ONE));
+        assert_ne!(
+            hash(&Quad::ONE),
+            hash(&Quad::from_array([1.0, 0.0, 0.0, 1e-60]))
+        );
+    }
+
+    #[test]
+    fn hash_nans() {
+        let payload = 0x7ff8_0000_0000_0001;
+        let one = 1f64.to_bits();
+        assert_eq!(hash(&Double::NAN), hash(&-Double::NAN));
+        assert_eq!(hash(&Double::NAN), hash(&Double::from_bits([payload, one])));
+        assert_eq!(hash(&Quad::NAN), hash(&-Quad::NAN));
+        assert_eq!(
+            hash(&Quad::NAN),
+            hash(&Quad::from_bits([payload, one, one, one]))
+        );
+    }
+
+    #[test]
+    fn rejects_nan() {
+        assert_eq!(OrderedDouble::new(Double::NAN), Err(NanError));
+        assert_eq!(OrderedDouble::try_from(-Double::NAN), Err(NanError));
+        assert_eq!(OrderedQuad::new(Quad::NAN), Err(NanError));
+        assert_eq!(OrderedQuad::try_from(-Quad::NAN), Err(NanError));
+        assert!(OrderedDouble::new(Double::INFINITY).is_ok());
+        assert!(OrderedQuad::new(Quad::NEG_INFINITY).is_ok());
+    }
+
+    #[test]
+    fn double_keys() {
+        let zero = OrderedDouble::new(Double::ZERO).unwrap();
+        let neg_zero = OrderedDouble::new(Double::NEG_ZERO).unwrap();
+        assert_eq!(zero, neg_zero);
+        assert_eq!(zero.cmp(&neg_zero), Ordering::Equal);
+        assert_eq!(hash(&zero), hash(&neg_zero));
+
+        let mut map = HashMap::new();
+        map.insert(zero, "zero");
+        map.insert(OrderedDouble::new(Double::PI).unwrap(), "pi");
+        assert_eq!(map[&neg_zero], "zero");
+        assert_eq!(map[&OrderedDouble::new(Double::PI).unwrap()], "pi");
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn quad_keys() {
+        let set: BTreeSet<OrderedQuad> = [
+            Quad::PI,
+            Quad::NEG_INFINITY,
+            Quad::ZERO,
+            Quad::NEG_ZERO,
+            Quad::E,
+            Quad::INFINITY,
+            -Quad::PI,
+        ]
+        .iter()
+        .map(|&x| OrderedQuad::new(x).unwrap())
+        .collect();
+        let sorted: Vec<Quad> = set.into_iter().map(Quad::from).collect();
+        assert_eq!(
+            sorted,
+            vec![
+                Quad::NEG_INFINITY,
+                -Quad::PI,
+                Quad::ZERO,
+                Quad::E,
+                Quad::PI,
+                Quad::INFINITY
+            ]
+        );
+    }
+
+    #[test]
+    fn formatting() {
+        let x = OrderedDouble::new(Double::PI).unwrap();
+        assert_eq!(format!("{:.5}", x), "3.14159");
+        assert_eq!(format!("{:.2e}", x), "3.14e0");
+        let y = OrderedQuad::new(Quad::E).unwrap();
+        assert_eq!(format!("{:.3E}", y), "2.718E0");
+    }
+}
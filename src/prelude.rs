@@ -0,0 +1,29 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! The most commonly used parts of qd, gathered up for glob importing.
+//!
+//! `use qd::prelude::*;` brings in the two number types, their short aliases, the macros
+//! that create them, and the [`Float`] trait for writing code that is generic over the two
+//! types. None of these names collide with anything in the standard library prelude.
+//!
+//! # Examples
+//! ```
+//! use qd::prelude::*;
+//!
+//! fn hypot<T: Float>(a: T, b: T) -> T {
+//!     (a.sqr() + b.sqr()).sqrt()
+//! }
+//!
+//! let x: DD = hypot(dd!(3), dd!(4));
+//! let y: QD = hypot(qd!(3), qd!(4));
+//! assert!((x - dd!(5)).abs() < dd!(1e-30));
+//! assert!((y - qd!(5)).abs() < qd!(1e-60));
+//! ```
+//!
+//! [`Float`]: ../trait.Float.html
+
+pub use crate::{dd, qd};
+pub use crate::{Double, Float, Quad, DD, QD};
@@ -13,6 +13,27 @@ mod macros {
     /// defines a `From` implementation for. This includes `&str`, `Double`, `Quad`, any
     /// primitive number, and 2-, 3-, and 4-tuples of any of those primitive number types.
     ///
+    /// The number can also be given as separate integer and fractional parts, with an
+    /// optional power-of-ten exponent, as `qd!(integer: 3, fraction: "14159")` or
+    /// `qd!(integer: 3, fraction: "14159", exponent: -2)`. These call [`from_parts`] and
+    /// produce exactly the same number as parsing `"3.14159"` or `"3.14159e-2"` would. Just
+    /// as with strings, [`NAN`] is produced if the fraction isn't made up of digits.
+    /// [`try_qd!`] returns the error instead, for strings and for parts alike.
+    ///
+    /// A literal is converted at compile time, so `qd!` can be used to define constants and
+    /// statics. That includes a string literal, which is parsed just as [`from_str`] would
+    /// parse it.
+    ///
+    /// A float literal is converted from its digits, just as the same digits in a string
+    /// would be, so `qd!(0.1)` is the `Quad` nearest to 0.1 and `qd!(1e23)` is exactly
+    /// 10<sup>23</sup>, even though the `f64`s nearest to them are neither. Anywhere else,
+    /// though, the compiler rounds a float literal to an `f64`, which keeps no more than 15
+    /// significant digits (6 for an `f32`; a number too small to be normal keeps fewer, but
+    /// always the ones that printing it gives, like the single digit of `5e-324`). A
+    /// float literal with more digits than that is a compile error unless it's exactly equal
+    /// to its `f64`, rather than `qd!` being the one place where the rest of them count.
+    /// Those digits can be given in a string instead.
+    ///
     /// # Examples
     /// ```
     /// # use qd::{qd, Quad};
@@ -22,38 +43,189 @@ mod macros {
     /// let expected = qd!("0.7071067811865475244008443621048490392848359376884740365883398690");
     /// let diff = (x - expected).abs();
     /// assert!(diff < qd!(1e-60));
+    ///
+    /// assert!(qd!(integer: -2, fraction: "5", exponent: -3) == qd!("-2.5e-3"));
+    ///
+    /// const TAU_OVER_7: Quad = qd!("0.8975979010256552108570956133638446188617134582222880564845302563");
+    /// static TENTH: Quad = qd!(0.1);
+    /// assert!(TENTH == qd!("0.1"));
+    /// assert!(qd!(1e23) == qd!("1e23"));
+    /// // qd!(3.14159265358979323846) doesn't compile
     /// ```
+    ///
+    /// [`from_parts`]: struct.Quad.html#method.from_parts
+    /// [`from_str`]: struct.Quad.html#method.from_str
+    /// [`NAN`]: struct.Quad.html#associatedconstant.NAN
+    /// [`try_qd!`]: macro.try_qd.html
     #[macro_export]
     macro_rules! qd {
+        (integer: $i:expr, fraction: $f:expr) => {
+            $crate::Quad::from_parts($i, $f, 0).unwrap_or($crate::Quad::NAN)
+        };
+        (integer: $i:expr, fraction: $f:expr, exponent: $e:expr) => {
+            $crate::Quad::from_parts($i, $f, $e).unwrap_or($crate::Quad::NAN)
+        };
+        (@literal $($x:tt)+) => {{
+            const VALUE: $crate::Quad = match $crate::Quad::__from_literal(stringify!($($x)+)) {
+                ::core::result::Result::Ok(x) => x,
+                ::core::result::Result::Err($crate::LiteralError::Lossy) => panic!(
+                    "{}",
+                    concat!(
+                        "`qd!(",
+                        stringify!($($x)+),
+                        ")` has more significant digits than the float it's rounded to can ",
+                        "hold, and would lose them anywhere else; to keep them all, pass ",
+                        "them in a string literal instead"
+                    )
+                ),
+                ::core::result::Result::Err($crate::LiteralError::Invalid(reason)) => {
+                    panic!("{}", reason)
+                }
+            };
+            VALUE
+        }};
+        // A `-` followed by something other than a literal has to be caught here; the
+        // `literal` fragment would otherwise take the `-` and then fail to parse the rest
+        (- $x:literal) => {
+            $crate::qd!(@literal - $x)
+        };
+        (- $($x:tt)+) => {
+            $crate::Quad::from(- $($x)+)
+        };
+        ($x:literal) => {
+            $crate::qd!(@literal $x)
+        };
         ($x:expr) => {
             $crate::Quad::from($x)
         };
     }
+
+    /// Creates a quad-double like [`qd!`] does, but returns a `Result` instead of turning a string
+    /// that can't be parsed into [`NAN`].
+    ///
+    /// The arguments are the same as for [`qd!`]. A string (whether a `&str`, a `String`, or
+    /// a `&String`) is parsed as [`from_str`] would parse it, and the result is
+    /// `Err` with the [`ParseQuadError`] that [`from_str`] gives if it can't be. The same goes for
+    /// the integer and fractional parts given as `qd!(integer: ..., fraction: ...)`. Any
+    /// other number always converts, so it comes back in `Ok`. This makes `try_qd!` the one
+    /// to use on input that might not be a number, where NaN would be hard to tell from the
+    /// string `"nan"`.
+    ///
+    /// Literals are converted at compile time, just as they are by [`qd!`], and literals
+    /// that [`qd!`] won't accept don't compile here either.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, try_qd, Quad};
+    /// # use qd::error::{ErrorKind, ParseQuadError};
+    /// assert!(try_qd!("2.5") == Ok(qd!(2.5)));
+    /// assert!(try_qd!(3u64) == Ok(qd!(3)));
+    ///
+    /// let input = String::from("1.2x3");
+    /// let err = try_qd!(input).unwrap_err();
+    /// assert!(err.kind == ErrorKind::InvalidDigit { position: 3 });
+    ///
+    /// assert!(try_qd!(integer: 3, fraction: "14159") == Ok(qd!("3.14159")));
+    /// assert!(try_qd!(integer: 3, fraction: "1.4").is_err());
+    ///
+    /// const HALF: Result<Quad, ParseQuadError> = try_qd!("0.5");
+    /// assert!(HALF == Ok(qd!(0.5)));
+    /// ```
+    ///
+    /// [`qd!`]: macro.qd.html
+    /// [`from_str`]: struct.Quad.html#method.from_str
+    /// [`ParseQuadError`]: error/struct.ParseQuadError.html
+    /// [`NAN`]: struct.Quad.html#associatedconstant.NAN
+    #[macro_export]
+    macro_rules! try_qd {
+        (integer: $i:expr, fraction: $f:expr) => {
+            $crate::Quad::from_parts($i, $f, 0)
+        };
+        (integer: $i:expr, fraction: $f:expr, exponent: $e:expr) => {
+            $crate::Quad::from_parts($i, $f, $e)
+        };
+        (@literal $($x:tt)+) => {{
+            const VALUE: ::core::result::Result<$crate::Quad, $crate::error::ParseQuadError> =
+                match $crate::Quad::__try_from_literal(stringify!($($x)+)) {
+                    ::core::result::Result::Ok(x) => x,
+                    ::core::result::Result::Err($crate::LiteralError::Lossy) => panic!(
+                        "{}",
+                        concat!(
+                            "`try_qd!(",
+                            stringify!($($x)+),
+                            ")` has more significant digits than the float it's rounded to ",
+                            "can hold, and would lose them anywhere else; to keep them all, ",
+                            "pass them in a string literal instead"
+                        )
+                    ),
+                    ::core::result::Result::Err($crate::LiteralError::Invalid(reason)) => {
+                        panic!("{}", reason)
+                    }
+                };
+            VALUE
+        }};
+        (- $x:literal) => {
+            $crate::try_qd!(@literal - $x)
+        };
+        (- $($x:tt)+) => {
+            $crate::TryInput::<$crate::Quad>::try_input(- $($x)+)
+        };
+        ($x:literal) => {
+            $crate::try_qd!(@literal $x)
+        };
+        ($x:expr) => {
+            $crate::TryInput::<$crate::Quad>::try_input($x)
+        };
+    }
 }
 
 #[cfg(test)]
 #[macro_use]
 mod test_macros;
 
+mod accumulator;
 mod aconsts; // "a" for "associated", or maybe just to make it appear first in docs
 mod add;
 mod alg;
+mod bessel;
+mod bounded;
+mod checked;
 mod common;
 mod comp;
+mod components;
 mod display;
 mod div;
+mod erf;
+mod fixed;
 mod from;
 mod from_str;
+mod gamma;
+mod hex;
 mod hyper;
 mod iter;
 mod misc;
 mod mul;
 mod neg;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+#[cfg(feature = "plotters")]
+mod plotters;
+mod poly;
+mod radix;
 mod rem;
+#[cfg(feature = "serde")]
+mod serde;
+mod slice;
+mod smooth;
 mod sub;
+mod time;
 mod trans;
 mod trig;
 
+pub use self::accumulator::QuadAccumulator;
+#[cfg(feature = "plotters")]
+pub use self::plotters::QuadRange;
+
 /// A 256-bit floating-point number implemented as the unevaluated sum of four 64-bit
 /// floating-point numbers. Discarding the bits used for exponents, this makes for about
 /// 212 bits of mantissa accuracy, or around 63 decimal digits.
@@ -91,7 +263,7 @@ mod trig;
 /// [`from_str`]: #method.from_str
 /// [`qd!`]: macro.qd.html
 /// [*dyadic*]: https://en.wikipedia.org/wiki/Dyadic_rational
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Default)]
 pub struct Quad(f64, f64, f64, f64);
 
 impl Quad {
@@ -0,0 +1,301 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::accumulator::Partials;
+use crate::quad::common as c;
+use crate::quad::Quad;
+use std::iter::FromIterator;
+
+/// An accumulator that sums a stream of `f64`s or `Quad`s exactly, producing a `Quad`.
+///
+/// This does what [`Quad::from_sum`] does without needing the numbers in a slice. Every
+/// number pushed is added with a cascade of error-free sums, and every nonzero error term is
+/// kept, so the running sum is always exact. Only [`finish`] rounds, to the `Quad` nearest
+/// the exact sum. The result therefore doesn't depend on the order of the numbers, on how
+/// much they cancel, or on how they were split up between accumulators that were later
+/// [`merge`]d, which makes accumulators suitable for parallel reductions.
+///
+/// The running sum takes no more memory than a few dozen `f64`s, however many numbers are
+/// pushed, and each push costs a pass over that sum. When the numbers are of similar
+/// magnitudes, that's only a few terms.
+///
+/// As with `f64` addition, the result is infinite if infinities of only one sign were
+/// pushed and NaN if a NaN or infinities of both signs were. A sum too large to represent
+/// is infinite even if partial sums were too, and a sum that comes back into range after
+/// its partial sums overflowed is still accurate.
+///
+/// # Examples
+/// ```
+/// # use qd::{Quad, QuadAccumulator};
+/// let mut acc = QuadAccumulator::new();
+/// for _ in 0..1000 {
+///     acc.push(1e20);
+///     acc.push(0.1);
+///     acc.push(-1e20);
+/// }
+/// // Exactly 1000 times the f64 nearest 0.1
+/// assert!(acc.finish() == Quad::from_sum(&[0.1; 1000]));
+/// ```
+///
+/// [`Quad::from_sum`]: struct.Quad.html#method.from_sum
+/// [`finish`]: #method.finish
+/// [`merge`]: #method.merge
+#[derive(Clone, Debug)]
+pub struct QuadAccumulator(Partials);
+
+impl QuadAccumulator {
+    /// Creates an accumulator whose sum is 0.
+    #[inline]
+    pub fn new() -> QuadAccumulator {
+        QuadAccumulator(Partials::new())
+    }
+
+    /// Adds an `f64` to the sum.
+    #[inline]
+    pub fn push(&mut self, x: f64) {
+        self.0.add(x);
+    }
+
+    /// Adds a `Quad` to the sum.
+    ///
+    /// Each of its components is added exactly, so this is the same as pushing all four
+    /// of them.
+    #[inline]
+    pub fn push_quad(&mut self, x: Quad) {
+        for &part in &[x.0, x.1, x.2, x.3] {
+            self.0.add(part);
+        }
+    }
+
+    /// Adds the sum of another accumulator to this one.
+    ///
+    /// This is exact, so splitting a stream of numbers up between accumulators and merging
+    /// them afterwards gives the same result as pushing every number onto one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::QuadAccumulator;
+    /// let values: Vec<f64> = (1..=1000).map(|n| 1.0 / n as f64).collect();
+    ///
+    /// let mut whole = QuadAccumulator::new();
+    /// values.iter().for_each(|&x| whole.push(x));
+    ///
+    /// let mut halves = [QuadAccumulator::new(), QuadAccumulator::new()];
+    /// for (i, &x) in values.iter().enumerate() {
+    ///     halves[i % 2].push(x);
+    /// }
+    /// let [mut a, b] = halves;
+    /// a.merge(b);
+    ///
+    /// assert!(a.finish() == whole.finish());
+    /// ```
+    #[inline]
+    pub fn merge(&mut self, other: QuadAccumulator) {
+        self.0.merge(&other.0);
+    }
+
+    /// Returns the sum as the `Quad` nearest to it.
+    ///
+    /// Each component is the `f64` nearest to what's left of the sum after the components
+    /// before it are subtracted. Since the sum is exact, the result depends only on which
+    /// numbers were pushed and not on their order.
+    #[inline]
+    pub fn finish(self) -> Quad {
+        let parts = self.0.components(4);
+        c::from_components(parts[0], parts[1], parts[2], parts[3])
+    }
+}
+
+impl Default for QuadAccumulator {
+    #[inline]
+    fn default() -> QuadAccumulator {
+        QuadAccumulator::new()
+    }
+}
+
+impl Extend<f64> for QuadAccumulator {
+    #[inline]
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|x| self.push(x));
+    }
+}
+
+impl Extend<Quad> for QuadAccumulator {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Quad>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|x| self.push_quad(x));
+    }
+}
+
+impl FromIterator<f64> for Quad {
+    /// Sums an iterator of `f64`s exactly, with a [`QuadAccumulator`], and returns the
+    /// `Quad` nearest to the sum.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let x: Quad = vec![1e100, 1.0, -1e100, 1e-50].into_iter().collect();
+    /// assert!(x.to_array() == [1.0, 1e-50, 0.0, 0.0]);
+    /// ```
+    ///
+    /// [`QuadAccumulator`]: struct.QuadAccumulator.html
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Quad {
+        let mut acc = QuadAccumulator::new();
+        acc.extend(iter);
+        acc.finish()
+    }
+}
+
+impl FromIterator<Quad> for Quad {
+    /// Sums an iterator of `Quad`s exactly, with a [`QuadAccumulator`], and returns the
+    /// `Quad` nearest to the sum.
+    ///
+    /// Unlike [`sum`], which adds the `Quad`s one at a time and rounds after each one, this
+    /// only rounds once, at the end.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let big = qd!("1e50") / qd!(3);
+    /// let x: Quad = vec![big, qd!(1) / qd!(7), -big].into_iter().collect();
+    /// assert!(x == qd!(1) / qd!(7));
+    /// ```
+    ///
+    /// [`QuadAccumulator`]: struct.QuadAccumulator.html
+    /// [`sum`]: #impl-Sum%3CQuad%3E
+    fn from_iter<I: IntoIterator<Item = Quad>>(iter: I) -> Quad {
+        let mut acc = QuadAccumulator::new();
+        acc.extend(iter);
+        acc.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::random::Rng;
+
+    fn same(a: Quad, b: Quad) -> bool {
+        a.to_bits() == b.to_bits()
+    }
+
+    test!(residuals: {
+        // Alternating +1e16 and -1e16, each with a small residual that's lost when it's
+        // added to 1e16 in f64 arithmetic. The residuals are multiples of 2^-40, so their
+        // exact sum is easy to find.
+        let mut acc = QuadAccumulator::new();
+        let mut expected = 0u64;
+        for i in 0..10_000_000u64 {
+            let big = if i % 2 == 0 { 1e16 } else { -1e16 };
+            let residual = (i % 1000 + 1) as f64 * 2f64.powi(-40);
+            acc.push(big);
+            acc.push(residual);
+            expected += i % 1000 + 1;
+        }
+        let sum = acc.finish();
+        assert!(same(sum, Quad::new(expected as f64 * 2f64.powi(-40), 0.0, 0.0, 0.0)));
+    });
+
+    test!(random_residuals: {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        let mut acc = QuadAccumulator::new();
+        let mut residuals = vec![];
+        for i in 0..100_000 {
+            let big = if i % 2 == 0 { 1e16 } else { -1e16 };
+            let residual = rng.next_f64() * 1e-10;
+            acc.push(big);
+            acc.push(residual);
+            residuals.push(residual);
+        }
+        assert!(same(acc.finish(), Quad::from_sum(&residuals)));
+    });
+
+    test!(merge: {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        let values: Vec<f64> = (0..100_000)
+            .map(|_| {
+                let n = rng.next_u64();
+                let x = (n >> 11) as f64 * 2f64.powi(-53);
+                let exp = (n % 200) as i32 - 100;
+                if n & 1 == 0 { x * 2f64.powi(exp) } else { -x * 2f64.powi(exp) }
+            })
+            .collect();
+
+        let mut whole = QuadAccumulator::new();
+        whole.extend(values.iter().copied());
+        let expected = whole.finish();
+        // `from_sum` can be off by one ULP in its last component
+        assert!((expected - Quad::from_sum(&values)).abs() <= expected.abs() * qd!(1e-63));
+
+        let mut parts: Vec<QuadAccumulator> = (0..7).map(|_| QuadAccumulator::new()).collect();
+        for (i, &x) in values.iter().enumerate() {
+            parts[i * 31 % 7].push(x);
+        }
+        let mut merged = QuadAccumulator::new();
+        parts.into_iter().rev().for_each(|p| merged.merge(p));
+        assert!(same(merged.finish(), expected));
+
+        let reversed: Quad = values.iter().rev().copied().collect();
+        assert!(same(reversed, expected));
+    });
+
+    test!(push_quad: {
+        let mut acc = QuadAccumulator::new();
+        acc.push_quad(Quad::PI);
+        acc.push_quad(Quad::E);
+        acc.push_quad(-Quad::PI);
+        assert!(same(acc.finish(), Quad::E));
+
+        let x: Quad = vec![Quad::PI, qd!(1e300), Quad::E, qd!(-1e300), -Quad::PI]
+            .into_iter()
+            .collect();
+        assert!(same(x, Quad::E));
+    });
+
+    test!(overflow: {
+        let x: Quad = vec![f64::MAX, f64::MAX, 1.0, -f64::MAX].into_iter().collect();
+        assert!(same(x, Quad::from_sum(&[f64::MAX, 1.0])));
+
+        let mut a = QuadAccumulator::new();
+        a.extend(vec![f64::MAX, f64::MAX]);
+        let mut b = QuadAccumulator::new();
+        b.extend(vec![-f64::MAX, 1e-300]);
+        a.merge(b.clone());
+        a.merge(b);
+        assert!(same(a.finish(), Quad::new(2e-300, 0.0, 0.0, 0.0)));
+
+        let y: Quad = vec![f64::MAX, f64::MAX, 1.0].into_iter().collect();
+        assert!(y == Quad::INFINITY);
+        let z: Quad = vec![-f64::MAX, -f64::MAX].into_iter().collect();
+        assert!(z == Quad::NEG_INFINITY);
+    });
+
+    test!(special: {
+        let x: Quad = vec![1.0, f64::INFINITY, 2.0].into_iter().collect();
+        assert!(x == Quad::INFINITY);
+        let y: Quad = vec![f64::NEG_INFINITY, 1.0].into_iter().collect();
+        assert!(y == Quad::NEG_INFINITY);
+        let z: Quad = vec![f64::INFINITY, f64::NEG_INFINITY].into_iter().collect();
+        assert!(z.is_nan());
+        let w: Quad = vec![1.0, f64::NAN].into_iter().collect();
+        assert!(w.is_nan());
+
+        let mut a = QuadAccumulator::new();
+        a.push(f64::INFINITY);
+        let mut b = QuadAccumulator::new();
+        b.push(f64::NEG_INFINITY);
+        a.merge(b);
+        assert!(a.finish().is_nan());
+    });
+
+    test!(zeros: {
+        assert!(same(QuadAccumulator::new().finish(), Quad::ZERO));
+        assert!(same(Vec::<f64>::new().into_iter().collect::<Quad>(), Quad::ZERO));
+        let x: Quad = vec![1.0, -1.0].into_iter().collect();
+        assert!(x.is_zero());
+        let y: Quad = vec![-0.0, -0.0].into_iter().collect();
+        assert!(y.is_zero() && y.is_sign_negative());
+    });
+}
@@ -17,14 +17,28 @@ impl Quad {
     /// Approximate number of significant digits in base 10.
     pub const DIGITS: u32 = 62;
 
-    /// [Machine epsilon] value for `Quad`.
+    /// [Machine epsilon] value for `Quad`, 2<sup>-211</sup>.
     ///
-    /// This is the difference between `1.0` and the next largest representable number.
+    /// This is the gap between `1.0` and the next larger number with [`MANTISSA_DIGITS`]
+    /// significant bits, the same relation that `f64::EPSILON` has to `f64`. It isn't the
+    /// gap to the next larger `Quad`, though. The components of a `Quad` can be spread out
+    /// much farther than 53 bits apart, so numbers like `Quad(1.0, 1e-100, 0.0, 0.0)` fall
+    /// between `1.0` and `1.0 + EPSILON`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!((Quad::ONE + Quad::EPSILON) - Quad::ONE == Quad::EPSILON);
+    /// assert!(Quad::EPSILON == qd!(2).powi(1 - Quad::MANTISSA_DIGITS as i32));
+    /// ```
     ///
     /// [Machine epsilon]: https://en.wikipedia.org/wiki/Machine_epsilon
-    pub const EPSILON: Quad = Quad(1.2154326714572542e-63, 4.2261844194902035e-129, 0e0, 0e0);
+    /// [`MANTISSA_DIGITS`]: #associatedconstant.MANTISSA_DIGITS
+    pub const EPSILON: Quad = Quad(3.0385816786431356e-64, 0.0, 0.0, 0.0); // 2^-211
 
-    /// Smallest finite `Quad` value.
+    /// Smallest finite `Quad` value, the negative of [`MAX`].
+    ///
+    /// [`MAX`]: #associatedconstant.MAX
     pub const MIN: Quad = Quad(
         -1.7976931348623157e308,
         -9.979201547673598e291,
@@ -32,10 +46,53 @@ impl Quad {
         -3.075078893078405e259,
     );
 
-    /// Smallest positive normal `Quad` value.
-    pub const MIN_POSITIVE: Quad = Quad(1.6259745436952323e-260, 0e0, 0e0, 0e0);
+    /// Smallest positive normal `Quad` value, 2<sup>-1022</sup>.
+    ///
+    /// This is `f64::MIN_POSITIVE`, the smallest number that [`classify`] calls normal.
+    /// Numbers this small have lost all but their first component, which is why the
+    /// smallest number that keeps all [`MANTISSA_DIGITS`] bits is a separate constant,
+    /// [`MIN_POSITIVE_FULL_PRECISION`].
+    ///
+    /// [`classify`]: #method.classify
+    /// [`MANTISSA_DIGITS`]: #associatedconstant.MANTISSA_DIGITS
+    /// [`MIN_POSITIVE_FULL_PRECISION`]: #associatedconstant.MIN_POSITIVE_FULL_PRECISION
+    pub const MIN_POSITIVE: Quad = Quad(2.2250738585072014e-308, 0.0, 0.0, 0.0);
+
+    /// Smallest positive `Quad` value with full precision, 2<sup>-863</sup>.
+    ///
+    /// A number smaller than this would need bits below the smallest subnormal `f64` to
+    /// have all [`MANTISSA_DIGITS`] of them, so it has fewer. Such numbers are still normal
+    /// down to [`MIN_POSITIVE`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::MIN_POSITIVE_FULL_PRECISION;
+    /// assert!(x * (Quad::ONE + Quad::EPSILON) - x == x * Quad::EPSILON);
+    ///
+    /// let y = x / qd!(2);
+    /// assert!(y * (Quad::ONE + Quad::EPSILON) - y == Quad::ZERO);
+    /// ```
+    ///
+    /// [`MANTISSA_DIGITS`]: #associatedconstant.MANTISSA_DIGITS
+    /// [`MIN_POSITIVE`]: #associatedconstant.MIN_POSITIVE
+    pub const MIN_POSITIVE_FULL_PRECISION: Quad = Quad(1.6259745436952323e-260, 0.0, 0.0, 0.0); // 2^-863
 
     /// Largest finite `Quad` value.
+    ///
+    /// Its first component is `f64::MAX`, and each of the others is the largest `f64` under
+    /// half the ULP of the one before it. Any more would round the sum up to infinity, which
+    /// is only about 2<sup>809</sup> above `MAX`. So `MAX` absorbs numbers much smaller than
+    /// that, and numbers much larger push it over.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::MAX[0] == f64::MAX);
+    /// assert!(Quad::MAX + qd!(1e240) == Quad::MAX);
+    /// assert!(Quad::MAX + qd!(1e245) == Quad::INFINITY);
+    /// assert!(Quad::MAX.next_up() == Quad::INFINITY);
+    /// ```
     pub const MAX: Quad = Quad(
         1.7976931348623157e308,
         9.979201547673598e291,
@@ -55,6 +112,12 @@ impl Quad {
     /// Maximum possible power of 10 exponent.
     pub const MAX_10_EXP: i32 = 308;
 
+    /// Largest integer `n` such that `n` and every integer between `-n` and `n` can be
+    /// represented exactly by a `Quad` (2<sup>212</sup>).
+    ///
+    /// Some larger integers can be represented exactly as well, but not all of them.
+    pub const MAX_SAFE_INTEGER: Quad = Quad(6.582018229284824e63, 0.0, 0.0, 0.0); // 2^212
+
     /// Not a Number (NaN).
     pub const NAN: Quad = Quad(f64::NAN, 0.0, 0.0, 0.0);
 
@@ -81,7 +144,7 @@ impl Quad {
         3.141592653589793e0,
         1.2246467991473532e-16,
         -2.9947698097183397e-33,
-        1.1124542208633655e-49,
+        1.1124542208633653e-49,
     );
 
     /// The full circle constant (τ), or 2π
@@ -89,7 +152,7 @@ impl Quad {
         6.283185307179586e0,
         2.4492935982947064e-16,
         -5.989539619436679e-33,
-        2.224908441726731e-49,
+        2.2249084417267306e-49,
     );
 
     /// π/2
@@ -97,7 +160,7 @@ impl Quad {
         1.5707963267948966e0,
         6.123233995736766e-17,
         -1.4973849048591698e-33,
-        5.562271104316827e-50,
+        5.562271104316826e-50,
     );
 
     /// π/3
@@ -105,7 +168,7 @@ impl Quad {
         1.0471975511965979e0,
         -1.072081766451091e-16,
         -9.982566032394464e-34,
-        -7.69561536018215e-50,
+        -7.6956153601821505e-50,
     );
 
     /// π/4
@@ -121,7 +184,7 @@ impl Quad {
         5.235987755982989e-1,
         -5.360408832255455e-17,
         -4.991283016197232e-34,
-        -3.847807680091076e-50,
+        -3.8478076800910752e-50,
     );
 
     /// π/8
@@ -145,7 +208,7 @@ impl Quad {
         4.71238898038469e0,
         1.8369701987210297e-16,
         7.8337969295008e-33,
-        -5.173596326540972e-49,
+        -5.173596326540973e-49,
     );
 
     /// 3π/4
@@ -153,7 +216,7 @@ impl Quad {
         2.356194490192345e0,
         9.184850993605148e-17,
         3.9168984647504e-33,
-        -2.586798163270486e-49,
+        -2.5867981632704864e-49,
     );
 
     /// 5π/4
@@ -161,7 +224,7 @@ impl Quad {
         3.9269908169872414e0,
         1.5308084989341916e-16,
         -9.90643808418708e-33,
-        4.811706604997218e-49,
+        4.811706604997217e-49,
     );
 
     /// 7π/4
@@ -169,7 +232,7 @@ impl Quad {
         5.497787143782138e0,
         2.143131898507868e-16,
         9.221286550320605e-34,
-        2.3622547205188384e-50,
+        2.3622547205188412e-50,
     );
 
     /// 1/π
@@ -193,7 +256,7 @@ impl Quad {
         1.1283791670955126e0,
         1.533545961316588e-17,
         -4.765684596693686e-34,
-        -2.0077946616552623e-50,
+        -2.007794661655263e-50,
     );
 
     /// √2
@@ -201,7 +264,7 @@ impl Quad {
         1.4142135623730951e0,
         -9.667293313452913e-17,
         4.1386753086994136e-33,
-        4.935546991468354e-50,
+        4.935546991468351e-50,
     );
 
     /// 1/√2
@@ -209,7 +272,23 @@ impl Quad {
         7.071067811865476e-1,
         -4.833646656726457e-17,
         2.0693376543497068e-33,
-        2.467773495734177e-50,
+        2.4677734957341755e-50,
+    );
+
+    /// √3
+    pub const SQRT_3: Quad = Quad(
+        1.7320508075688772e0,
+        1.0035084221806903e-16,
+        -1.4959542475733896e-33,
+        5.306147563296169e-50,
+    );
+
+    /// 1/√3
+    pub const FRAC_1_SQRT_3: Quad = Quad(
+        5.773502691896257e-1,
+        3.3450280739356345e-17,
+        -2.552976689870848e-33,
+        -1.5336978290157996e-49,
     );
 
     /// Euler's number (*e*)
@@ -217,7 +296,7 @@ impl Quad {
         2.718281828459045e0,
         1.4456468917292502e-16,
         -2.1277171080381768e-33,
-        1.5156301598412193e-49,
+        1.5156301598412191e-49,
     );
 
     /// log<sub>2</sub> 10
@@ -225,7 +304,7 @@ impl Quad {
         3.321928094887362e0,
         1.661617516973592e-16,
         1.2215512178458181e-32,
-        5.95511897027825e-49,
+        5.9551189702782496e-49,
     );
 
     /// log<sub>2</sub> *e*
@@ -233,7 +312,7 @@ impl Quad {
         1.4426950408889634e0,
         2.0355273740931033e-17,
         -1.0614659956117258e-33,
-        -1.3836716780181397e-50,
+        -1.3836716780181402e-50,
     );
 
     /// log<sub>10</sub> 2
@@ -241,7 +320,7 @@ impl Quad {
         3.010299956639812e-1,
         -2.8037281277851704e-18,
         5.471948402314639e-35,
-        5.105138983107093e-51,
+        5.1051389831070925e-51,
     );
 
     /// log<sub>10</sub> *e*
@@ -249,7 +328,7 @@ impl Quad {
         4.342944819032518e-1,
         1.098319650216765e-17,
         3.717181233110959e-34,
-        7.73448434650429e-51,
+        7.734484346504299e-51,
     );
 
     /// log<sub>*e*</sub> 2
@@ -257,7 +336,7 @@ impl Quad {
         6.931471805599453e-1,
         2.3190468138462996e-17,
         5.707708438416212e-34,
-        -3.582432210601812e-50,
+        -3.5824322106018114e-50,
     );
 
     /// log<sub>*e*</sub> 10
@@ -267,4 +346,172 @@ impl Quad {
         -9.984262454465777e-33,
         -4.023357454450206e-49,
     );
+
+    /// The golden ratio (φ)
+    pub const PHI: Quad = Quad(
+        1.618033988749895e0,
+        -5.432115203682506e-17,
+        2.6543252083815655e-33,
+        -3.304991997502108e-50,
+    );
+
+    /// The Euler-Mascheroni constant (γ)
+    pub const EULER_GAMMA: Quad = Quad(
+        5.772156649015329e-1,
+        -4.942915152430645e-18,
+        -2.322111740706957e-34,
+        1.7004947433810964e-50,
+    );
+
+    /// Catalan's constant (*G*)
+    pub const CATALAN: Quad = Quad(
+        9.15965594177219e-1,
+        3.747558421514984e-18,
+        -2.2812159066741463e-34,
+        4.7086394050266475e-51,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::reference::references;
+    use crate::quad::common as c;
+
+    // Each component has to be no more than half a unit in the last place of the one
+    // before it, or the constant isn't the sum that it's supposed to be
+    fn normalized(x: Quad) -> bool {
+        let c = x.to_array();
+        c.windows(2).all(|w| w[0] + w[1] == w[0])
+    }
+
+    // Every mathematical constant, exactly as it should be rounded
+    test!(constants_match_references: {
+        let refs = references();
+        for &(name, x) in &[
+            ("PI", Quad::PI),
+            ("TAU", Quad::TAU),
+            ("FRAC_PI_2", Quad::FRAC_PI_2),
+            ("FRAC_PI_3", Quad::FRAC_PI_3),
+            ("FRAC_PI_4", Quad::FRAC_PI_4),
+            ("FRAC_PI_6", Quad::FRAC_PI_6),
+            ("FRAC_PI_8", Quad::FRAC_PI_8),
+            ("FRAC_PI_16", Quad::FRAC_PI_16),
+            ("FRAC_3_PI_2", Quad::FRAC_3_PI_2),
+            ("FRAC_3_PI_4", Quad::FRAC_3_PI_4),
+            ("FRAC_5_PI_4", Quad::FRAC_5_PI_4),
+            ("FRAC_7_PI_4", Quad::FRAC_7_PI_4),
+            ("FRAC_1_PI", Quad::FRAC_1_PI),
+            ("FRAC_2_PI", Quad::FRAC_2_PI),
+            ("FRAC_2_SQRT_PI", Quad::FRAC_2_SQRT_PI),
+            ("SQRT_2", Quad::SQRT_2),
+            ("FRAC_1_SQRT_2", Quad::FRAC_1_SQRT_2),
+            ("SQRT_3", Quad::SQRT_3),
+            ("FRAC_1_SQRT_3", Quad::FRAC_1_SQRT_3),
+            ("E", Quad::E),
+            ("LOG2_10", Quad::LOG2_10),
+            ("LOG2_E", Quad::LOG2_E),
+            ("LOG10_2", Quad::LOG10_2),
+            ("LOG10_E", Quad::LOG10_E),
+            ("LN_2", Quad::LN_2),
+            ("LN_10", Quad::LN_10),
+            ("PHI", Quad::PHI),
+            ("EULER_GAMMA", Quad::EULER_GAMMA),
+            ("CATALAN", Quad::CATALAN),
+        ] {
+            c::assert_reference(&refs, name, x);
+        }
+    });
+
+    // Parsing can be off in the last bit, so these are checked to full precision rather
+    // than exactly
+    test_all_near!(
+        frac_pi_8:
+            qd!("0.392699081698724154807830422909937860524646174921888227621868074038477051"),
+            Quad::FRAC_PI_8;
+        frac_1_pi:
+            qd!("0.318309886183790671537767526745028724068919291480912897495334688117793595"),
+            Quad::FRAC_1_PI;
+        frac_2_pi:
+            qd!("0.636619772367581343075535053490057448137838582961825794990669376235587191"),
+            Quad::FRAC_2_PI;
+        frac_2_sqrt_pi:
+            qd!("1.12837916709551257389615890312154517168810125865799771368817144342128494"),
+            Quad::FRAC_2_SQRT_PI;
+        sqrt_3:
+            qd!("1.73205080756887729352744634150587236694280525381038062805580697945193302"),
+            Quad::SQRT_3;
+        frac_1_sqrt_3:
+            qd!("0.577350269189625764509148780501957455647601751270126876018602326483977672"),
+            Quad::FRAC_1_SQRT_3;
+        phi:
+            qd!("1.61803398874989484820458683436563811772030917980576286213544862270526046"),
+            Quad::PHI;
+        euler_gamma:
+            qd!("0.577215664901532860606512090082402431042159335939923598805767234884867727"),
+            Quad::EULER_GAMMA;
+        catalan:
+            qd!("0.915965594177219015054603514932384110774149374281672134266498119621763020"),
+            Quad::CATALAN;
+    );
+    test_all_assert!(
+        frac_pi_8_normalized:
+            normalized(Quad::FRAC_PI_8);
+        frac_1_pi_normalized:
+            normalized(Quad::FRAC_1_PI);
+        frac_2_pi_normalized:
+            normalized(Quad::FRAC_2_PI);
+        frac_2_sqrt_pi_normalized:
+            normalized(Quad::FRAC_2_SQRT_PI);
+        sqrt_3_normalized:
+            normalized(Quad::SQRT_3);
+        frac_1_sqrt_3_normalized:
+            normalized(Quad::FRAC_1_SQRT_3);
+        phi_normalized:
+            normalized(Quad::PHI);
+        euler_gamma_normalized:
+            normalized(Quad::EULER_GAMMA);
+        catalan_normalized:
+            normalized(Quad::CATALAN);
+    );
+
+    // Half of the ULP of a positive f64 that isn't a power of 2 (measured downward, since
+    // f64::MAX has nothing above it)
+    fn half_ulp(x: f64) -> f64 {
+        (x - f64::from_bits(x.to_bits() - 1)) / 2.0
+    }
+
+    test_all_assert!(
+        max_normalized:
+            normalized(Quad::MAX) && Quad::MAX.is_normalized();
+        max_components:
+            Quad::MAX[0] == f64::MAX
+                && Quad::MAX.to_array().windows(2).all(|w| {
+                    w[1] < half_ulp(w[0]) && f64::from_bits(w[1].to_bits() + 1) == half_ulp(w[0])
+                });
+        max_next_up:
+            Quad::MAX.next_up() == Quad::INFINITY;
+        max_absorbs:
+            Quad::MAX + qd!(1e240) == Quad::MAX && Quad::MAX - qd!(1e245) < Quad::MAX;
+        max_overflows:
+            Quad::MAX + qd!(1e245) == Quad::INFINITY;
+        min_is_neg_max:
+            Quad::MIN == -Quad::MAX && Quad::MIN.next_down() == Quad::NEG_INFINITY;
+        epsilon_round_trip:
+            (Quad::ONE + Quad::EPSILON) - Quad::ONE == Quad::EPSILON;
+        epsilon_mantissa:
+            Quad::EPSILON == qd!(2).powi(1 - Quad::MANTISSA_DIGITS as i32)
+                && (Quad::ONE + Quad::EPSILON).next_down() > Quad::ONE;
+        min_positive_normal:
+            Quad::MIN_POSITIVE == Quad::from(f64::MIN_POSITIVE)
+                && Quad::MIN_POSITIVE.is_normal()
+                && (Quad::MIN_POSITIVE / qd!(2)).is_subnormal();
+        min_positive_full_precision:
+            {
+                let x = Quad::MIN_POSITIVE_FULL_PRECISION;
+                let y = x / qd!(2);
+                x * (Quad::ONE + Quad::EPSILON) - x == x * Quad::EPSILON
+                    && y * (Quad::ONE + Quad::EPSILON) == y
+            };
+    );
 }
@@ -3,15 +3,17 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::strict as s;
 use crate::common::utils as u;
+use crate::quad::common as c;
 use crate::quad::Quad;
 use std::ops::{Add, AddAssign};
 
 // Utility function that returns the quad component with the specified index and then
 // increments the index. This is how we do `a[i++]` without the `++` operator.
 #[inline]
-fn index_and_inc(a: Quad, i: &mut usize) -> f64 {
-    let r = a[*i];
+const fn index_and_inc(a: Quad, i: &mut usize) -> f64 {
+    let r = a.to_array()[*i];
     *i += 1;
     r
 }
@@ -36,71 +38,9 @@ impl Add for Quad {
     /// let diff = (x - expected).abs();
     /// assert!(diff < qd!(1e-60));
     /// ```
-    #[allow(clippy::suspicious_arithmetic_impl, clippy::many_single_char_names)]
+    #[inline]
     fn add(self, other: Quad) -> Quad {
-        match self.pre_add(&other) {
-            Some(r) => r,
-            None => {
-                let mut i = 0;
-                let mut j = 0;
-                let mut k = 0;
-
-                let mut x = [0.0; 4];
-
-                // These two assignments, along with the reassignments of the same variables
-                // in the `accumulate` call below, act as a merge sort. The largest
-                // component between the two quads is operated on first, then the second
-                // largest, and so on.
-                let u = if self[i].abs() > other[j].abs() {
-                    index_and_inc(self, &mut i)
-                } else {
-                    index_and_inc(other, &mut j)
-                };
-                let v = if self[i].abs() > other[j].abs() {
-                    index_and_inc(self, &mut i)
-                } else {
-                    index_and_inc(other, &mut j)
-                };
-                let (mut u, mut v) = u::renorm2(u, v);
-
-                while k < 4 {
-                    if i >= 4 && j >= 4 {
-                        x[k] = u;
-                        if k < 3 {
-                            k += 1;
-                            x[k] = v;
-                        }
-                        break;
-                    }
-
-                    let t = if i >= 4 {
-                        index_and_inc(other, &mut j)
-                    } else if j >= 4 || self[i].abs() > other[j].abs() {
-                        index_and_inc(self, &mut i)
-                    } else {
-                        index_and_inc(other, &mut j)
-                    };
-
-                    let (s, y, z) = u::accumulate(u, v, t);
-                    u = y;
-                    v = z;
-
-                    if s != 0.0 {
-                        x[k] = s;
-                        k += 1;
-                    }
-                }
-
-                for k in i..4 {
-                    x[3] += self[k];
-                }
-                for k in j..4 {
-                    x[3] += other[k];
-                }
-                let (a, b, c, d) = u::renorm4(x[0], x[1], x[2], x[3]);
-                Quad(a, b, c, d)
-            }
-        }
+        s::guard("Quad + Quad", &[self.0, other.0], self.add_inner(other))
     }
 }
 
@@ -223,6 +163,104 @@ impl AddAssign<&Quad> for Quad {
 }
 
 impl Quad {
+    /// Adds another `Quad` to this one. This is the `+` operator as a `const fn`, so it
+    /// can be used to compute constants and `static` tables at compile time, which trait
+    /// methods like `Add::add` can't yet be.
+    ///
+    /// The result is bit for bit the same as that of `+`. Constant evaluation does `f64`
+    /// arithmetic exactly the way that the processor does at runtime, with the same
+    /// rounding to nearest and the same fused multiply-add, so the same operations give the
+    /// same answer either way. The only difference is that with the `strict` feature, a
+    /// non-finite result isn't reported.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// const TWO_PI: Quad = Quad::PI.add_const(Quad::PI);
+    /// assert!(TWO_PI == Quad::PI + Quad::PI);
+    /// assert!(TWO_PI == Quad::TAU);
+    /// ```
+    pub const fn add_const(self, other: Quad) -> Quad {
+        self.add_inner(other)
+    }
+
+    // The `+` operator without the strict-mode check. Subtraction is done by adding the
+    // negation, and this lets it check (and report) its own operands instead. Parsing,
+    // which can happen at compile time, uses it too, so it's a `const fn`.
+    #[allow(clippy::many_single_char_names)]
+    pub(super) const fn add_inner(self, other: Quad) -> Quad {
+        match self.pre_add(&other) {
+            Some(r) => r,
+            None => {
+                let mut i = 0;
+                let mut j = 0;
+                let mut k = 0;
+
+                let mut x = [0.0; 4];
+
+                // These two assignments, along with the reassignments of the same variables
+                // in the `accumulate` call below, act as a merge sort. The largest
+                // component between the two quads is operated on first, then the second
+                // largest, and so on.
+                let u = if self.to_array()[i].abs() > other.to_array()[j].abs() {
+                    index_and_inc(self, &mut i)
+                } else {
+                    index_and_inc(other, &mut j)
+                };
+                let v = if self.to_array()[i].abs() > other.to_array()[j].abs() {
+                    index_and_inc(self, &mut i)
+                } else {
+                    index_and_inc(other, &mut j)
+                };
+                let (mut u, mut v) = u::renorm2(u, v);
+
+                while k < 4 {
+                    if i >= 4 && j >= 4 {
+                        x[k] = u;
+                        if k < 3 {
+                            k += 1;
+                            x[k] = v;
+                        }
+                        break;
+                    }
+
+                    let t = if i >= 4 {
+                        index_and_inc(other, &mut j)
+                    } else if j >= 4 || self.to_array()[i].abs() > other.to_array()[j].abs() {
+                        index_and_inc(self, &mut i)
+                    } else {
+                        index_and_inc(other, &mut j)
+                    };
+
+                    let (s, y, z) = u::accumulate(u, v, t);
+                    u = y;
+                    v = z;
+
+                    if s != 0.0 {
+                        x[k] = s;
+                        k += 1;
+                    }
+                }
+
+                while i < 4 {
+                    x[3] += index_and_inc(self, &mut i);
+                }
+                while j < 4 {
+                    x[3] += index_and_inc(other, &mut j);
+                }
+                let (a, b, c, d) = u::renorm4(x[0], x[1], x[2], x[3]);
+                if a.is_finite() {
+                    Quad(a, b, c, d)
+                } else {
+                    c::redo_halved!(
+                        0.5 * self.0 + 0.5 * other.0,
+                        c::mul_pwr2(self, 0.5).add_inner(c::mul_pwr2(other, 0.5))
+                    )
+                }
+            }
+        }
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -233,7 +271,7 @@ impl Quad {
     // rather than calculation.
 
     #[inline]
-    fn pre_add(&self, other: &Quad) -> Option<Quad> {
+    const fn pre_add(&self, other: &Quad) -> Option<Quad> {
         if self.is_nan() || other.is_nan() {
             Some(Quad::NAN)
         } else if self.is_infinite() {
@@ -260,6 +298,13 @@ impl Quad {
             } else {
                 Some(Quad::NEG_INFINITY)
             }
+        } else if self.is_zero() && other.is_zero() {
+            // Zeros only add to a negative zero when both are negative
+            if self.is_sign_negative() && other.is_sign_negative() {
+                Some(Quad::NEG_ZERO)
+            } else {
+                Some(Quad::ZERO)
+            }
         } else {
             None
         }
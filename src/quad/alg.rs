@@ -4,10 +4,37 @@
 // https://opensource.org/licenses/MIT
 
 use crate::common::primitive as p;
+use crate::common::strict as s;
 use crate::common::utils as u;
 use crate::quad::common as c;
 use crate::quad::Quad;
 
+// 2^512. `sqrt` takes a power of 2 out of arguments larger than this, and `nroot` out of
+// arguments larger than this or smaller than its reciprocal, before doing anything else.
+const SCALE_ABOVE: f64 = 1.3407807929942597e154;
+
+// Once a square in `powi` is larger than 2^this or smaller than 2^-this, the result can't
+// be represented.
+const POW_LIMIT: i64 = 1100;
+
+// `hypot` squares its arguments as they are when the larger one is within 2^this of 1, and
+// scales them first otherwise. The last component of a square is about 2^-424 times the
+// first, so the range is narrower than for `Double`.
+const HYPOT_DIRECT: i32 = 250;
+
+// When the larger argument of `hypot` is more than 2^this times the smaller, the square of
+// the smaller is too small to affect the result, which is just the larger.
+const HYPOT_NEGLIGIBLE: i32 = 115;
+
+// `agm` takes the geometric mean as √a·√b while its arguments are more than 2^this apart,
+// and as √(ab) once they're close enough that scaling them both toward 1 keeps ab and
+// every component of the arguments normal.
+const AGM_DIRECT: i32 = 1000;
+
+// The most iterations that `agm` runs once its arguments are within `AGM_DIRECT` of each
+// other. Convergence is quadratic, so this is far more than it needs.
+const AGM_MAX_ITER: usize = 20;
+
 impl Quad {
     /// Calculates x · 2<sup>n</sup>, where *x* is the `Quad` and *n* is an integer.
     ///
@@ -16,30 +43,112 @@ impl Quad {
     /// implementation that is much more efficient than calculating it through
     /// multiplication and [`powi`] is offered despite it not being part of the `f64` API.
     ///
+    /// Each component is multiplied by 2<sup>n</sup> exactly, for any *n*, so the result
+    /// is exact unless it's too large or too small to represent. For *n* from -1022 to
+    /// 1023, that's a single `f64` multiplication per component. A result too large is
+    /// infinite, and one too small is zero, with the sign of the `Quad`. A component that
+    /// ends up in the subnormal range is rounded, which loses only the bits of it that are
+    /// below the smallest subnormal; that is as close as a `Quad` can get.
+    ///
     /// # Examples
     /// ```
-    /// # use qd::qd;
+    /// # use qd::{qd, Quad};
     /// let x = qd!(3);
     /// assert!(x.ldexp(5) == qd!(96)); // 3 * 2^5
+    ///
+    /// // Powers of 2 that an `f64` can't hold are fine as long as the result fits
+    /// let tiny = qd!(3).ldexp(-1074);
+    /// assert!(tiny.ldexp(1074) == qd!(3));
+    /// assert!(qd!(1).ldexp(1024) == Quad::INFINITY);
     /// ```
     ///
     /// [`powi`]: #method.powi
     #[inline]
     pub fn ldexp(self, n: i32) -> Quad {
-        let factor = 2f64.powi(n);
-        Quad(
-            self.0 * factor,
-            self.1 * factor,
-            self.2 * factor,
-            self.3 * factor,
-        )
+        // When 2^n is an `f64` itself, which it is for every n that leaves a `Quad` of
+        // ordinary size within range, each component takes only a single multiplication
+        let r = if (-1022..=1023).contains(&n) {
+            c::mul_pwr2(self, u::pow2(n))
+        } else {
+            Quad(
+                u::ldexp(self.0, n),
+                u::ldexp(self.1, n),
+                u::ldexp(self.2, n),
+                u::ldexp(self.3, n),
+            )
+        };
+        // Every other component is smaller than the first, so when the first stays finite
+        // they do too. Normalization keeps each one within half an ULP of the one before
+        // it, which also holds after they're scaled and rounded, so they stay normalized.
+        if r.0.is_finite() {
+            r
+        } else {
+            Quad(r.0, 0.0, 0.0, 0.0)
+        }
+    }
+
+    /// Breaks the `Quad` into a mantissa and a power of 2, the inverse of [`ldexp`].
+    ///
+    /// The exponent *e* is chosen so that the mantissa *m* is at least 0.5 and less than 1
+    /// in absolute value (the mantissa has the sign of the `Quad`). That's usually the
+    /// exponent that puts the first component of *m* in that range too, but when the first
+    /// component of the `Quad` is a power of 2 and the rest of it has the opposite sign,
+    /// the `Quad` is a little smaller than that power of 2, so *e* is one less and the
+    /// first component of *m* is ±1. Then `m.ldexp(e)` is exactly the original `Quad`, as
+    /// long as none of its nonzero components is more than 2<sup>1021</sup> times smaller
+    /// than the first. Otherwise the mantissa would need that component to be smaller than
+    /// the smallest subnormal, which only happens to a `Quad` that's put together from
+    /// components directly.
+    ///
+    /// Zero is returned as it is with an exponent of 0, as are infinity and NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let (m, e) = qd!(40).frexp();
+    /// assert!(m == qd!(0.625) && e == 6); // 0.625 * 2^6
+    ///
+    /// let (m, e) = (-Quad::PI).frexp();
+    /// assert!(m == -Quad::PI / qd!(4) && e == 2);
+    /// assert!(m.ldexp(e) == -Quad::PI);
+    ///
+    /// // A little less than 1, so the mantissa is too
+    /// let (m, e) = Quad::new(1.0, -2f64.powi(-60), 0.0, 0.0).frexp();
+    /// assert!(m == Quad::new(1.0, -2f64.powi(-60), 0.0, 0.0) && e == 0);
+    ///
+    /// assert!(Quad::ZERO.frexp() == (Quad::ZERO, 0));
+    /// assert!(Quad::NEG_INFINITY.frexp() == (Quad::NEG_INFINITY, 0));
+    /// ```
+    ///
+    /// [`ldexp`]: #method.ldexp
+    #[inline]
+    pub fn frexp(self) -> (Quad, i32) {
+        if self.0 == 0.0 || !self.0.is_finite() {
+            (self, 0)
+        } else {
+            let mut e = u::exponent(self.0) + 1;
+            // A first component of exactly 2^(e - 1) with a tail of the opposite sign is a
+            // number just below 2^(e - 1), whose mantissa is just below 1 at the exponent
+            // under it. The components don't overlap, so their sum has the sign of the
+            // first of them that isn't zero.
+            let tail = self.1 + self.2 + self.3;
+            if self.0.abs() == u::ldexp(1.0, e - 1)
+                && tail != 0.0
+                && tail.is_sign_negative() != self.0.is_sign_negative()
+            {
+                e -= 1;
+            }
+            (self.ldexp(-e), e)
+        }
     }
 
     /// Calculates the square of the `Quad`.
     ///
     /// This method takes advantage of optimizations in multiplication that are available
     /// when the two numbers being multiplied are the same, so it is more efficient than
-    /// bare multiplication.
+    /// bare multiplication. The cross terms of the product are equal in pairs, so each pair
+    /// is calculated once with one of its factors doubled. The result is at least as
+    /// accurate as `self * self`.
     ///
     /// # Examples
     /// ```
@@ -49,58 +158,92 @@ impl Quad {
     /// ```
     #[inline]
     pub fn sqr(self) -> Quad {
+        match self.pre_sqr() {
+            Some(r) => s::guard("Quad::sqr", &[self.0], r),
+            None => self.sqr_inner(),
+        }
+    }
+
+    // `sqr` without the strict-mode check, for `pow_int` and `cube`.
+    const fn sqr_inner(self) -> Quad {
         match self.pre_sqr() {
             Some(r) => r,
             None => {
-                // A considerable simplification over simply multiplying the number by
-                // itself, with the simplifications possible because the two numbers being
-                // multiplied are in fact equal.
-                //
-                // The result is a simpler calculation:
-                //
-                //      a0² + 2a0a1 + 2a0a2 + a1² + 2a0a3 + 2a1a2
-                //
-                // where any further terms, including the low words of the final two terms,
-                // are unnecessary to achieve the desired accuracy.
+                // This is the multiplication in `mul_inner` with both factors the same. The
+                // products a_i·a_j and a_j·a_i are then equal, so each pair of them is one
+                // product of 2a_i (which is exact) and a_j, and their high and low words are
+                // the sums of the pairs of words that `mul_inner` adds. That leaves six
+                // products instead of ten and shorter sums at every level, with the same
+                // terms kept and the same ones dropped.
+                let (d0, d1) = (2.0 * self.0, 2.0 * self.1);
 
+                // O(1) term
                 let (h0, l0) = p::two_sqr(self.0);
-                let (h1, l1) = p::two_prod(2.0 * self.0, self.1);
-                let (h2, l2) = p::two_prod(2.0 * self.0, self.2);
+                // O(ε) term
+                let (h1, l1) = p::two_prod(d0, self.1);
+                // O(ε²) terms
+                let (h2, l2) = p::two_prod(d0, self.2);
                 let (h3, l3) = p::two_sqr(self.1);
-                let h4 = 2.0 * self.0 * self.3;
-                let h5 = 2.0 * self.1 * self.2;
-
-                // Less primitive functions are not used here because there are steps in
-                // them that can be skipped.
+                // O(ε³) terms
+                let (h4, l4) = p::two_prod(d0, self.3);
+                let (h5, l5) = p::two_prod(d1, self.2);
+                // O(ε⁴) terms, whose low words aren't needed here any more than in mul
+                let h6 = d1 * self.3;
+                let h7 = self.2 * self.2;
 
+                // O(1) calculation (pass-through)
                 let r0 = h0;
-
-                let (r1, a1) = p::two_sum(h1, l0);
-
-                let (b0, b1) = p::two_sum(a1, l1);
-                let (c0, c1) = p::two_sum(h2, h3);
-                let (d0, d1) = p::two_sum(b0, c0);
-                let (e0, e1) = p::two_sum(b1, c1);
-                let (f0, f1) = p::two_sum(d1, e0);
-                let (i0, i1) = p::quick_two_sum(f0, e1 + f1);
-                let (r2, j1) = p::quick_two_sum(d0, i0);
-
-                let (k0, k1) = p::quick_two_sum(i1, j1);
-                let (m0, m1) = p::two_sum(h4, h5);
-                let (n0, n1) = p::two_sum(l2, l3);
-                let (o0, o1) = p::two_sum(m0, n0);
-                let (r3, q1) = p::two_sum(k0, o0);
-
-                let r4 = m1 + n1 + o1 + k1 + q1;
+                // O(ε) calculation
+                let (r1, t0) = p::two_sum(h1, l0);
+                // O(ε²) calculation
+                let (u0, u1, u2) = u::three_three_sum(t0, h2, h3);
+                let (r2, v0) = p::two_sum(u0, l1);
+                let (t1, v1) = p::two_sum(u1, v0);
+                let t2 = u2 + v1;
+                // O(ε³) calculation
+                let (w0, w1) = p::two_sum(t1, h4);
+                let (x0, x1) = p::two_sum(h5, l2);
+                let (y0, y1) = u::four_two_sum(w0, w1, x0, x1);
+                let (r3, z0) = p::two_sum(y0, l3);
+                // O(ε⁴) calculation
+                let r4 = t2 + z0 + y1 + h6 + h7 + l4 + l5;
 
                 let (a, b, c, d) = u::renorm5(r0, r1, r2, r3, r4);
-                Quad(a, b, c, d)
+                if a.is_finite() {
+                    Quad(a, b, c, d)
+                } else {
+                    c::redo_halved!(
+                        0.5 * self.0 * self.0,
+                        c::mul_pwr2(self, 0.5).mul_inner(self)
+                    )
+                }
             }
         }
     }
 
+    /// Calculates the cube of the `Quad`.
+    ///
+    /// This is the square from [`sqr`] multiplied by the `Quad` once more, which is faster
+    /// than `self * self * self`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// assert!(qd!(3).cube() == qd!(27));
+    /// assert!(qd!(-0.5).cube() == qd!(-0.125));
+    /// ```
+    ///
+    /// [`sqr`]: #method.sqr
+    #[inline]
+    pub fn cube(self) -> Quad {
+        s::guard("Quad::cube", &[self.0], self.sqr_inner().mul_inner(self))
+    }
+
     /// Calculates the square root of the `Quad`.
     ///
+    /// The result is accurate for every finite, non-negative `Quad`, up to and including
+    /// [`MAX`].
+    ///
     /// # Examples
     /// ```
     /// # use qd::qd;
@@ -110,9 +253,11 @@ impl Quad {
     /// let diff = (x - expected).abs();
     /// assert!(diff < qd!(1e-60));
     /// ```
+    ///
+    /// [`MAX`]: #associatedconstant.MAX
     pub fn sqrt(self) -> Quad {
         match self.pre_sqrt() {
-            Some(r) => r,
+            Some(r) => s::guard("Quad::sqrt", &[self.0], r),
             None => {
                 // Strategy: use Newton's iteration.
                 //
@@ -123,23 +268,263 @@ impl Quad {
                 // which converges to 1/√a, starting with a Quad-precision approximation of
                 // 1/√a. Newton's iteration more or less quadruples the precision with each
                 // pass, so performing it three times should be enough.
+                //
+                // x² is about 1/a, so once a passes 2^800 or so, the lower components of
+                // that fall into the subnormals and take precision with them, and the
+                // correction terms do the same when a is small. Well short of that, outside
+                // of 2^±512, a is scaled by 2^-2k, which brings it to within a factor of 4
+                // of 1 and is exact, and the root is scaled back by 2^k.
+                let k = if self.0 > SCALE_ABOVE || self.0 < 1.0 / SCALE_ABOVE {
+                    u::exponent(self.0) / 2
+                } else {
+                    0
+                };
+                let a = c::scale(self, -2 * k);
+
+                let mut r = Quad::ONE / Quad::from(a.0.sqrt());
+                let h = c::mul_pwr2(a, 0.5);
+                let half = Quad(0.5, 0.0, 0.0, 0.0);
 
-                let mut r = Quad::ONE / Quad::from(self.0.sqrt());
-                let h = c::mul_pwr2(self, 0.5);
-                let k = Quad(0.5, 0.0, 0.0, 0.0);
+                r += (half - h * r.sqr()) * r;
+                r += (half - h * r.sqr()) * r;
+                r += (half - h * r.sqr()) * r;
 
-                r += (k - h * r.sqr()) * r;
-                r += (k - h * r.sqr()) * r;
-                r += (k - h * r.sqr()) * r;
+                r *= a;
+                r.ldexp(k)
+            }
+        }
+    }
+
+    /// Calculates the reciprocal of the square root of the `Quad`, 1/√*x*.
+    ///
+    /// This is the same number as `Quad::ONE / x.sqrt()`, to within 2 × [`EPSILON`]
+    /// (relative), but it's computed directly with Newton's iteration for the reciprocal
+    /// square root, which needs no division at all, so it's considerably faster. That
+    /// makes it the better choice for work like normalizing vectors.
+    ///
+    /// Following IEEE 754's `rSqrt`, the reciprocal square root of 0 is ∞ and that of -0 is
+    /// -∞. The reciprocal square root of ∞ is 0, and that of any other negative number is
+    /// [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = qd!(2).recip_sqrt();
+    /// let expected = qd!("0.7071067811865475244008443621048490392848359376884740365883398690");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    ///
+    /// assert!(Quad::ZERO.recip_sqrt() == Quad::INFINITY);
+    /// assert!(Quad::NEG_ZERO.recip_sqrt() == Quad::NEG_INFINITY);
+    /// assert!(qd!(-1).recip_sqrt().is_nan());
+    /// ```
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn recip_sqrt(self) -> Quad {
+        match self.pre_recip_sqrt() {
+            Some(r) => s::guard("Quad::recip_sqrt", &[self.0], r),
+            None => {
+                // Strategy: use Newton's iteration.
+                //
+                // Perform the following Newton iteration
+                //
+                //      r' = r + (1 - ar²) * r / 2
+                //
+                // which is r(3 - ar²) / 2 rearranged so that the correction is added to r
+                // rather than rounded along with it, and which converges to 1/√a. It
+                // starts with the `f64` reciprocal square root of the first component.
+                // Each pass of Newton's iteration doubles the number of correct bits, so three
+                // passes take the 53 bits of the `f64` seed past the 212 of a `Quad`.
+                //
+                // As in `sqrt`, a is scaled by 2^-2k outside of 2^±512, which brings it to
+                // within a factor of 4 of 1 and is exact, and the result is scaled back by
+                // 2^-k.
+                let k = if self.0 > SCALE_ABOVE || self.0 < 1.0 / SCALE_ABOVE {
+                    u::exponent(self.0) / 2
+                } else {
+                    0
+                };
+                let a = c::scale(self, -2 * k);
+
+                let mut r = Quad(1.0 / a.0.sqrt(), 0.0, 0.0, 0.0);
+                let h = c::mul_pwr2(a, 0.5);
+                let half = Quad(0.5, 0.0, 0.0, 0.0);
+
+                r += (half - h * r.sqr()) * r;
+                r += (half - h * r.sqr()) * r;
+                r += (half - h * r.sqr()) * r;
+
+                r.ldexp(-k)
+            }
+        }
+    }
+
+    /// Calculates the length of the hypotenuse of a right triangle whose legs have the
+    /// lengths of the `Quad` and the argument, √(*x*² + *y*²).
+    ///
+    /// When the squares of the arguments would overflow or lose precision to the subnormal
+    /// range, both arguments are first scaled by the same power of 2, one that brings the
+    /// larger of them near 1, and the result is scaled back afterwards. So the result is
+    /// infinite only when it's too large to represent. For arguments between about 10⁻⁷⁵
+    /// and 10⁷⁵ that are within a factor of 2¹¹⁵ of each other, the result is the same as
+    /// `(x.sqr() + y.sqr()).sqrt()`; when one is smaller than that, the result is the
+    /// magnitude of the other.
+    ///
+    /// As with `f64::hypot`, the result is ∞ if either argument is infinite, even if the
+    /// other is NaN, and otherwise NaN if either argument is.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = qd!("3e200").hypot(qd!("4e200"));
+    /// let diff = (x - qd!("5e200")).abs();
+    /// assert!(diff < qd!(1e138));
+    ///
+    /// assert!(Quad::MAX.hypot(Quad::MAX) == Quad::INFINITY);
+    /// assert!(Quad::NAN.hypot(Quad::NEG_INFINITY) == Quad::INFINITY);
+    /// ```
+    pub fn hypot(self, other: Quad) -> Quad {
+        match self.pre_hypot(&[other]) {
+            Some(r) => r,
+            None => {
+                let (big, small) = if self.0.abs() < other.0.abs() {
+                    (other.abs(), self.abs())
+                } else {
+                    (self.abs(), other.abs())
+                };
+                let k = u::exponent(big.0);
+                let r = if small.is_zero() || k - u::exponent(small.0) > HYPOT_NEGLIGIBLE {
+                    // small² is too small to change big² at this precision
+                    big
+                } else if (-HYPOT_DIRECT..=HYPOT_DIRECT).contains(&k) {
+                    (big.sqr() + small.sqr()).sqrt()
+                } else {
+                    let (x, y) = (c::scale(big, -k), c::scale(small, -k));
+                    let r = c::scale((x.sqr() + y.sqr()).sqrt(), k);
+                    // Scaling can round trailing components separately into the subnormal
+                    // range, or leave a finite one behind an infinite first component
+                    c::from_components(r.0, r.1, r.2, r.3)
+                };
+                s::guard("Quad::hypot", &[self.0, other.0], r)
+            }
+        }
+    }
+
+    /// Calculates the length of the vector whose components are the `Quad` and the two
+    /// arguments, √(*x*² + *y*² + *z*²).
+    ///
+    /// This is the three-dimensional version of [`hypot`], and it avoids overflow and
+    /// underflow in the same way. The result is ∞ if any argument is infinite and otherwise
+    /// NaN if any argument is.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = qd!(2).hypot3(qd!(-3), qd!(6));
+    /// assert!((x - qd!(7)).abs() < qd!(1e-62));
+    ///
+    /// let big = Quad::MAX / qd!(2);
+    /// assert!(big.hypot3(big, big).is_finite());
+    /// ```
+    ///
+    /// [`hypot`]: #method.hypot
+    pub fn hypot3(self, y: Quad, z: Quad) -> Quad {
+        match self.pre_hypot(&[y, z]) {
+            Some(r) => r,
+            None => {
+                let r = if self.is_zero() {
+                    y.hypot(z)
+                } else if y.is_zero() {
+                    self.hypot(z)
+                } else if z.is_zero() {
+                    self.hypot(y)
+                } else {
+                    let k = u::exponent(self.0.abs().max(y.0.abs()).max(z.0.abs()));
+                    if (-HYPOT_DIRECT..=HYPOT_DIRECT).contains(&k) {
+                        (self.sqr() + y.sqr() + z.sqr()).sqrt()
+                    } else {
+                        let (a, b, d) = (c::scale(self, -k), c::scale(y, -k), c::scale(z, -k));
+                        let r = c::scale((a.sqr() + b.sqr() + d.sqr()).sqrt(), k);
+                        c::from_components(r.0, r.1, r.2, r.3)
+                    }
+                };
+                s::guard("Quad::hypot3", &[self.0, y.0, z.0], r)
+            }
+        }
+    }
 
-                r *= self;
-                r
+    /// Calculates the arithmetic-geometric mean of the `Quad` and the argument.
+    ///
+    /// Starting from *a* and *b*, the arithmetic mean (*a* + *b*) / 2 and the geometric
+    /// mean √(*ab*) are taken over and over, and the two means converge (quadratically) on
+    /// the result. That makes this the fast route to complete elliptic integrals, since
+    /// *K*(*m*) = π / (2 agm(1, √(1 - *m*))), and to logarithms and π itself. The
+    /// iteration stops as soon as the means agree to within a few units of
+    /// [`EPSILON`].
+    ///
+    /// The arguments are put in order first, so `x.agm(y)` is exactly the same as
+    /// `y.agm(x)`. The mean of two numbers is the same as the number itself. Arguments of
+    /// wildly different magnitudes are fine: while their product could overflow or
+    /// underflow, the geometric mean is taken as √*a*·√*b*, and after that both are scaled
+    /// by the same power of 2 toward 1.
+    ///
+    /// The mean of anything with 0 is 0 and of anything else with ∞ is ∞, except that the
+    /// mean of 0 and ∞ is NaN. The mean is NaN if either argument is negative or NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// // Gauss's constant is 1 / agm(1, √2)
+    /// let g = qd!(1).agm(qd!(2).sqrt()).recip();
+    /// let expected = qd!("0.8346268416740731862814297327990468089939930134903470024498273701");
+    /// assert!((g - expected).abs() < qd!(1e-63));
+    ///
+    /// assert!(qd!(3).agm(qd!(3)) == qd!(3));
+    /// assert!(qd!(-1).agm(qd!(2)).is_nan());
+    /// ```
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    pub fn agm(self, other: Quad) -> Quad {
+        match self.pre_agm(&other) {
+            Some(r) => s::guard("Quad::agm", &[self.0, other.0], r),
+            None => {
+                let (mut a, mut b) = if self > other {
+                    (self, other)
+                } else {
+                    (other, self)
+                };
+                while u::exponent(a.0) - u::exponent(b.0) > AGM_DIRECT {
+                    let next = c::mul_pwr2(a, 0.5) + c::mul_pwr2(b, 0.5);
+                    b = a.sqrt() * b.sqrt();
+                    a = next;
+                }
+                // Centering the arguments on 1 keeps their product near 1
+                let k = (u::exponent(a.0) + u::exponent(b.0)) / 2;
+                let (mut a, mut b) = (c::scale(a, -k), c::scale(b, -k));
+                for _ in 0..AGM_MAX_ITER {
+                    if (a - b).abs() <= c::mul_pwr2(a * Quad::EPSILON, 4.0) {
+                        break;
+                    }
+                    let next = c::mul_pwr2(a + b, 0.5);
+                    b = (a * b).sqrt();
+                    a = next;
+                }
+                let r = c::scale(c::mul_pwr2(a + b, 0.5), k);
+                // Scaling down can round trailing components separately into the
+                // subnormal range
+                let r = c::from_components(r.0, r.1, r.2, r.3);
+                s::guard("Quad::agm", &[self.0, other.0], r)
             }
         }
     }
 
     /// Calculates the *n*th root of the `Quad`.
     ///
+    /// The result is accurate for every finite `Quad` that has an *n*th root, including
+    /// those near [`MAX`] and those near the smallest normal `f64`.
+    ///
     /// # Examples
     /// ```
     /// # use qd::qd;
@@ -149,9 +534,11 @@ impl Quad {
     /// let diff = (x - expected).abs();
     /// assert!(diff < qd!(1e-60));
     /// ```
+    ///
+    /// [`MAX`]: #associatedconstant.MAX
     pub fn nroot(self, n: i32) -> Quad {
         match self.pre_nroot(n) {
-            Some(r) => r,
+            Some(r) => s::guard("Quad::nroot", &[self.0, n as f64], r),
             None => {
                 // Strategy: the traditional way of finding roots is using Newton's
                 // iteration for the function
@@ -166,9 +553,24 @@ impl Quad {
                 // small number of iterations, we can then find a^(1/n) by taking the
                 // reciprocal.
 
-                let r = self.abs();
-                // a^(-1/n) = exp(-ln(a) / n)
-                let mut x = Quad::from((-(r.0.ln()) / n as f64).exp());
+                //
+                // x^n is about 1/a, which overflows or goes subnormal when a is far enough
+                // from 1. A power of 2 that's a multiple of n is taken out of such an a
+                // first, and its nth root is put back into the result at the end.
+                let a = self.abs();
+                let m = if a.0 > SCALE_ABOVE || a.0 < 1.0 / SCALE_ABOVE {
+                    a.0.log2().floor() as i32 / n
+                } else {
+                    0
+                };
+                let r = c::scale(a, -m * n);
+                // The starting approximation of a^(-1/n) comes from f64's own roots, which
+                // are more accurate than exp(-ln(a) / n) would be.
+                let mut x = Quad::from(if n == 3 {
+                    r.0.cbrt().recip()
+                } else {
+                    r.0.powf(-1.0 / n as f64)
+                });
 
                 let qd_n = Quad(n.into(), 0.0, 0.0, 0.0);
                 x += x * (Quad::ONE - r * x.powi(n)) / qd_n;
@@ -177,13 +579,15 @@ impl Quad {
                 if self.0 < 0.0 {
                     x = -x
                 }
-                x.recip()
+                c::scale(x.recip(), m)
             }
         }
     }
 
     /// Calculates the cube root of the `Quad`.
     ///
+    /// Like [`nroot`], this is accurate for every finite `Quad`.
+    ///
     /// # Examples
     /// ```
     /// # use qd::qd;
@@ -193,6 +597,8 @@ impl Quad {
     /// let diff = (x - expected).abs();
     /// assert!(diff < qd!(1e-60));
     /// ```
+    ///
+    /// [`nroot`]: #method.nroot
     pub fn cbrt(self) -> Quad {
         self.nroot(3)
     }
@@ -208,41 +614,160 @@ impl Quad {
     /// * `x.powi(n)` is ±0 for `x` = ±0 and any odd positive `n`
     /// * `x.powi(n)` is +0 for `x` = ±0 and any even positive `n`
     ///
+    /// Nothing overflows or underflows along the way, so a result is ±∞ or ±0 only if it
+    /// really is too large or too small to represent, and every exponent works, including
+    /// `i32::MIN`. [`powi64`] does the same for an `i64` exponent.
+    ///
     /// # Examples
     /// ```
     /// # use qd::qd;
     /// let x = qd!(3);
     /// assert!(x.powi(3) == qd!(27));
+    /// assert!(qd!(0.5).powi(1074).to_array() == [f64::from_bits(1), 0.0, 0.0, 0.0]);
+    /// assert!(qd!(-1).powi(i32::MIN) == qd!(1));
     /// ```
+    ///
+    /// [`powi64`]: #method.powi64
+    #[inline]
     pub fn powi(self, n: i32) -> Quad {
-        match self.pre_powi(n) {
-            Some(r) => r,
-            None => {
-                let mut r = self;
-                let mut s = Quad::ONE;
-                let mut k = n.abs();
-
-                if k > 1 {
-                    while k > 0 {
-                        if k % 2 == 1 {
-                            s *= r;
-                        }
-                        k /= 2;
-                        if k > 0 {
-                            r = r.sqr();
-                        }
-                    }
-                } else {
-                    s = r;
-                }
+        s::guard("Quad::powi", &[self.0, n as f64], self.pow_int(n.into()))
+    }
 
-                if n < 0 {
-                    s.recip()
-                } else {
-                    s
-                }
+    /// Calculates the `Quad` raised to an `i64` power.
+    ///
+    /// This is the same as [`powi`], including its special cases, except that it takes an
+    /// `i64`. Exponents that large are only useful for bases very close to 1 (or equal to
+    /// ±1); anything else overflows or underflows, and this finds that out in at most 63
+    /// squarings, without calculating anything too large or too small to represent.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(-1).powi64(i64::MAX) == qd!(-1));
+    /// assert!(qd!(2).powi64(-1074).to_array() == [f64::from_bits(1), 0.0, 0.0, 0.0]);
+    /// assert!(qd!(1.5).powi64(1 << 40) == Quad::INFINITY);
+    /// assert!(Quad::NEG_ZERO.powi64(i64::MIN) == Quad::INFINITY);
+    /// ```
+    ///
+    /// [`powi`]: #method.powi
+    #[inline]
+    pub fn powi64(self, n: i64) -> Quad {
+        s::guard("Quad::powi64", &[self.0, n as f64], self.pow_int(n))
+    }
+
+    // Binary exponentiation, keeping the power of 2 of each intermediate result in an
+    // integer and its significand near 1, so that nothing overflows or underflows until
+    // the end. Each square would only be multiplied into the result, so once one is too
+    // large or too small for the result to be representable, the result is known.
+    //
+    // This is a `const fn` because parsing, which can happen at compile time, scales by
+    // powers of ten with it.
+    pub(super) const fn pow_int(self, n: i64) -> Quad {
+        if let Some(r) = self.pre_powi(n) {
+            return r;
+        }
+        let negative = n % 2 != 0 && self.is_sign_negative();
+        let k = u::exponent(self.0);
+        let mut r = c::scale(self, -k);
+        let mut re = k as i64;
+        let mut s = Quad::ONE;
+        let mut se = 0i64;
+        let mut i = n.unsigned_abs();
+
+        loop {
+            if i % 2 == 1 {
+                s = s.mul_inner(r);
+                let k = u::exponent(s.0);
+                s = c::scale(s, -k);
+                se += re + k as i64;
+            }
+            i /= 2;
+            if i == 0 {
+                break;
+            }
+            r = r.sqr_inner();
+            let k = u::exponent(r.0);
+            r = c::scale(r, -k);
+            re = 2 * re + k as i64;
+            if re.abs() > POW_LIMIT {
+                // Too large if the base is larger than 1 and the power positive, or the
+                // base is smaller than 1 and the power negative
+                return match ((re > 0) == (n > 0), negative) {
+                    (true, false) => Quad::INFINITY,
+                    (true, true) => Quad::NEG_INFINITY,
+                    (false, false) => Quad::ZERO,
+                    (false, true) => Quad::NEG_ZERO,
+                };
             }
         }
+
+        if n < 0 {
+            s = Quad::ONE.div_inner(s);
+            se = -se;
+        }
+        // s is in (1/2, 2), so past these the result is certainly infinite or zero
+        if se > 1024 {
+            if negative {
+                Quad::NEG_INFINITY
+            } else {
+                Quad::INFINITY
+            }
+        } else if se < -1076 {
+            if negative {
+                Quad::NEG_ZERO
+            } else {
+                Quad::ZERO
+            }
+        } else {
+            // Scaling into the subnormal range rounds each component separately, which can
+            // leave them overlapping, and scaling to infinity leaves finite later ones
+            let r = c::scale(s, se as i32);
+            c::from_components(r.0, r.1, r.2, r.3)
+        }
+    }
+
+    /// Calculates the `Quad` raised to an integer power.
+    ///
+    /// This is exactly the same as [`powi`]; it's here because `pow` is the name that
+    /// integer types and the `num-traits` crate use for the same thing.
+    ///
+    /// Note that `^` is *not* exponentiation. It isn't implemented for `Quad` at all, so
+    /// trying to use it as a power operator is a compile error rather than a silent XOR.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// assert!(qd!(2).pow(10) == qd!(1024));
+    /// assert!(qd!(2).pow(-2) == qd!(0.25));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use qd::qd;
+    /// let x = qd!(2) ^ 10; // use `qd!(2).powi(10)` instead
+    /// ```
+    ///
+    /// [`powi`]: #method.powi
+    #[inline]
+    pub fn pow(self, n: i32) -> Quad {
+        self.powi(n)
+    }
+
+    /// Calculates the `Quad` raised to an unsigned integer power.
+    ///
+    /// This is the same as [`powi`] except that it takes a `u32`, which means that there is
+    /// never a reciprocal to take. The special cases are the same as those for [`powi`]
+    /// with a positive exponent.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// assert!(qd!(3).powu(3) == qd!(27));
+    /// assert!(qd!(-1).powu(u32::MAX) == qd!(-1));
+    /// ```
+    ///
+    /// [`powi`]: #method.powi
+    pub fn powu(self, n: u32) -> Quad {
+        self.powi64(n.into())
     }
 
     /// Calculates the `Quad` raised to a `Quad` power.
@@ -251,6 +776,11 @@ impl Quad {
     /// a negative `Quad` to a fractional or irrational power because *ln x* is undefined
     /// when *x* is negative. In that case, this function returns [`NAN`].
     ///
+    /// A finite negative `Quad` *can* be raised to an integer power, and the result is
+    /// negative when that power is odd. The whole exponent is checked for this, not just its
+    /// first component, so an exponent just short of 3 is not mistaken for 3 and an odd
+    /// exponent larger than 2<sup>53</sup> is still recognized as odd.
+    ///
     /// It's actually more complex than that; if the exponent can be expressed as a fraction
     /// with an odd denominator, then there is an answer (a cube root, which is defined for
     /// negative numbers, is the same as a power of 1/3). Therefore, something like
@@ -259,6 +789,17 @@ impl Quad {
     /// while using floating-point numbers, so no attempt is made to make this work. If you
     /// need a fifth root of -4, use `qd!(-4).nroot(5)`.
     ///
+    /// Special cases follow the same conventions as `f64::powf`:
+    ///
+    /// * Anything to the power of 0, and 1 to the power of anything, is 1, even if the
+    ///   other number is [`NAN`]. Otherwise, if either number is [`NAN`], so is the result.
+    /// * 0 to a negative power and ∞ to a positive power are ∞, while 0 to a positive power
+    ///   and ∞ to a negative power are 0. If the base is -0 or -∞, the result is negative
+    ///   when the power is an odd integer.
+    /// * Numbers with a magnitude less than 1 go to 0 when raised to the power of ∞ and to
+    ///   ∞ when raised to the power of -∞. Numbers with a magnitude greater than 1 do the
+    ///   opposite, and -1 to the power of either infinity is 1.
+    ///
     /// # Examples
     /// ```
     /// # use qd::qd;
@@ -273,13 +814,67 @@ impl Quad {
     #[inline]
     pub fn powf(self, n: Quad) -> Quad {
         match self.pre_powf(&n) {
-            Some(r) => r,
-            None => (n * self.ln()).exp(),
+            Some(r) => s::guard("Quad::powf", &[self.0, n.0], r),
+            None => {
+                if self.is_sign_negative() {
+                    // `pre_powf` has already made sure that `n` is an integer. If it's small
+                    // enough, its first component is the whole thing.
+                    if n.0.abs() <= i32::MAX as f64 {
+                        self.powi(n.0 as i32)
+                    } else {
+                        let r = (-self).powf(n);
+                        if c::is_odd(n) {
+                            -r
+                        } else {
+                            r
+                        }
+                    }
+                } else {
+                    (n * self.ln()).exp()
+                }
+            }
         }
     }
 
+    /// Calculates `self * a + b` as a fused multiply-add.
+    ///
+    /// Writing `self * a + b` with the operators rounds twice: the product is rounded to a
+    /// `Quad` before it's added to `b`. When the product and `b` nearly cancel, the error
+    /// from that first rounding can be as large as the result itself, so that the low
+    /// components of the result are wrong. `mul_add` keeps every error term of the product
+    /// until it has been added to `b` and rounds only once in that case, so its relative
+    /// error is no more than a few [`EPSILON`]s however much cancellation there is. That
+    /// makes it the right tool for differences of products, residuals, and polynomial
+    /// evaluation with Horner's method.
+    ///
+    /// When there's no close cancellation, the product's rounding error is already
+    /// negligible, and this is only slightly slower than the operators.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = qd!(1) + qd!(2).powi(-60) + qd!(2).powi(-120) + qd!(2).powi(-180);
+    /// let exact = qd!(2).powi(-59)
+    ///     + qd!(3) * qd!(2).powi(-120)
+    ///     + qd!(2).powi(-178)
+    ///     + qd!(3) * qd!(2).powi(-240);
+    ///
+    /// // The operators round x² before subtracting, losing the last component
+    /// assert!(x * x - qd!(1) != exact);
+    /// assert!(x.mul_add(x, qd!(-1)) == exact);
+    /// ```
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    #[inline]
+    pub fn mul_add(self, a: Quad, b: Quad) -> Quad {
+        s::guard("Quad::mul_add", &[self.0, a.0, b.0], c::mul_add(self, a, b))
+    }
+
     /// Calculates the reciprocal of the `Quad`.
     ///
+    /// The reciprocal of a `Quad` larger than about 10<sup>308</sup> is subnormal, and
+    /// like any subnormal number it has fewer digits of precision than a normal one.
+    ///
     /// # Examples
     /// ```
     /// # use qd::{qd, Quad};
@@ -304,8 +899,10 @@ impl Quad {
     // rather than calculation.
 
     #[inline]
-    fn pre_sqr(&self) -> Option<Quad> {
-        if self.is_infinite() {
+    const fn pre_sqr(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_infinite() {
             Some(Quad::INFINITY)
         } else {
             None
@@ -315,7 +912,8 @@ impl Quad {
     #[inline]
     fn pre_sqrt(&self) -> Option<Quad> {
         if self.is_zero() {
-            Some(Quad::ZERO)
+            // IEEE 754 has the square root of -0 be -0
+            Some(*self)
         } else if self.is_sign_negative() {
             Some(Quad::NAN)
         } else if self.is_infinite() {
@@ -325,6 +923,56 @@ impl Quad {
         }
     }
 
+    #[inline]
+    fn pre_recip_sqrt(&self) -> Option<Quad> {
+        if self.is_zero() {
+            // IEEE 754 has the reciprocal square root of -0 be -∞
+            if self.is_sign_negative() {
+                Some(Quad::NEG_INFINITY)
+            } else {
+                Some(Quad::INFINITY)
+            }
+        } else if self.is_sign_negative() {
+            Some(Quad::NAN)
+        } else if self.is_infinite() {
+            Some(Quad::ZERO)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_hypot(&self, others: &[Quad]) -> Option<Quad> {
+        if self.is_infinite() || others.iter().any(|x| x.is_infinite()) {
+            Some(Quad::INFINITY)
+        } else if self.is_nan() || others.iter().any(|x| x.is_nan()) {
+            Some(Quad::NAN)
+        } else if self.is_zero() && others.iter().all(|x| x.is_zero()) {
+            Some(Quad::ZERO)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_agm(&self, other: &Quad) -> Option<Quad> {
+        if self.is_nan() || other.is_nan() || *self < Quad::ZERO || *other < Quad::ZERO {
+            Some(Quad::NAN)
+        } else if self.is_zero() || other.is_zero() {
+            if self.is_infinite() || other.is_infinite() {
+                Some(Quad::NAN)
+            } else {
+                Some(Quad::ZERO)
+            }
+        } else if self.is_infinite() || other.is_infinite() {
+            Some(Quad::INFINITY)
+        } else if self == other {
+            Some(*self)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn pre_nroot(&self, n: i32) -> Option<Quad> {
         if n == 0 {
@@ -367,7 +1015,7 @@ impl Quad {
     }
 
     #[inline]
-    fn pre_powi(&self, n: i32) -> Option<Quad> {
+    const fn pre_powi(&self, n: i64) -> Option<Quad> {
         if n == 0 {
             Some(Quad::ONE)
         } else if self.is_nan() {
@@ -403,22 +1051,34 @@ impl Quad {
 
     #[inline]
     fn pre_powf(&self, n: &Quad) -> Option<Quad> {
-        if self.is_zero() {
-            if n.is_zero() {
-                Some(Quad::NAN)
-            } else if n.is_sign_positive() {
+        if n.is_zero() || *self == Quad::ONE {
+            // Even if the other one is NaN
+            Some(Quad::ONE)
+        } else if self.is_nan() || n.is_nan() {
+            Some(Quad::NAN)
+        } else if n.is_infinite() {
+            let abs = self.abs();
+            if abs == Quad::ONE {
+                Some(Quad::ONE)
+            } else if (abs < Quad::ONE) == n.is_sign_positive() {
                 Some(Quad::ZERO)
             } else {
                 Some(Quad::INFINITY)
             }
-        } else if n.is_infinite() {
-            if *self == Quad::ONE {
-                Some(Quad::NAN)
-            } else if n.is_sign_positive() {
-                Some(Quad::INFINITY)
-            } else {
-                Some(Quad::ZERO)
-            }
+        } else if self.is_zero() || self.is_infinite() {
+            // Zero to a negative power and infinity to a positive one are infinite, and the
+            // other two are zero. Either way, the sign of a negative base only survives an
+            // odd integer power.
+            let infinite = self.is_infinite() == n.is_sign_positive();
+            let negative = self.is_sign_negative() && c::is_integer(*n) && c::is_odd(*n);
+            Some(match (infinite, negative) {
+                (true, false) => Quad::INFINITY,
+                (true, true) => Quad::NEG_INFINITY,
+                (false, false) => Quad::ZERO,
+                (false, true) => Quad::NEG_ZERO,
+            })
+        } else if self.is_sign_negative() && !c::is_integer(*n) {
+            Some(Quad::NAN)
         } else {
             None
         }
@@ -428,6 +1088,7 @@ impl Quad {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::random::Rng;
 
     // ldexp_tests
     test_all_near!(
@@ -497,6 +1158,175 @@ mod tests {
             Quad::NAN.ldexp(5);
     );
 
+    test_all_exact!(
+        ldexp_past_f64_range:
+            qd!(3),
+            qd!(3).ldexp(-1074).ldexp(1074);
+        ldexp_large_exp:
+            qd!(1.5),
+            qd!(1.5).ldexp(-1050).ldexp(2000).ldexp(-950);
+        ldexp_overflow:
+            Quad::INFINITY,
+            qd!(1).ldexp(1024);
+        ldexp_overflow_large_exp:
+            Quad::NEG_INFINITY,
+            qd!(-1).ldexp(i32::MAX);
+        ldexp_underflow:
+            Quad::ZERO,
+            qd!(1).ldexp(-1075);
+        ldexp_underflow_large_exp:
+            Quad::ZERO,
+            qd!(1).ldexp(i32::MIN);
+        ldexp_subnormal_tail:
+            Quad::from_array([2f64.powi(-1020), f64::from_bits(16), 0.0, 0.0]),
+            Quad::from_array([1.0, 2f64.powi(-50), 0.0, 0.0]).ldexp(-1020);
+        ldexp_rounded_tail:
+            Quad::from_array([2f64.powi(-1020), f64::from_bits(2), 0.0, 0.0]),
+            Quad::from_array([1.0, 3.0 * 2f64.powi(-55), 0.0, 0.0]).ldexp(-1020);
+        ldexp_flushed_tail:
+            Quad::from_array([2f64.powi(-1020), 0.0, 0.0, 0.0]),
+            Quad::from_array([1.0, 2f64.powi(-60), 0.0, 0.0]).ldexp(-1020);
+    );
+    test_all_assert!(
+        ldexp_underflow_neg:
+            qd!(-1).ldexp(-5000).is_sign_negative();
+        ldexp_overflow_nan:
+            Quad::NAN.ldexp(5000).is_nan();
+    );
+    // Scaling by a power of 2 is exact, so it's the same as multiplying by that power and
+    // undone by scaling back
+    test!(ldexp_exact: {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        let mut next = || rng.next_f64() + 0.5;
+        for i in 0..1000 {
+            let x = Quad::from_sum(&[next(), next() * 1e-17, next() * 1e-34, next() * 1e-51]);
+            let x = if i % 2 == 0 { x } else { -x };
+            let k = (i % 1600) as i32 - 800;
+            let r = x.ldexp(k);
+            assert!(r.to_bits() == (x * Quad(2f64.powi(k), 0.0, 0.0, 0.0)).to_bits(), "{:?}.ldexp({})", x, k);
+            assert!(r.ldexp(-k).to_bits() == x.to_bits(), "{:?}.ldexp({})", x, k);
+        }
+    });
+    // On either side of the exponents where 2^n stops being an `f64`, every component is
+    // scaled just as it would be on its own
+    test!(ldexp_boundaries: {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        let mut next = || rng.next_f64() + 0.5;
+        for _ in 0..200 {
+            let x = Quad::from_sum(&[next(), next() * 1e-17, next() * 1e-34, next() * 1e-51]);
+            for &n in &[-1076, -1075, -1074, -1023, -1022, -1021, 1022, 1023, 1024, 1025] {
+                let r = x.ldexp(n);
+                let a = u::ldexp(x.0, n);
+                let expected: Vec<f64> = if a.is_finite() {
+                    [x.0, x.1, x.2, x.3].iter().map(|&c| u::ldexp(c, n)).collect()
+                } else {
+                    let mut e = vec![0.0; [r.0, r.1, r.2, r.3].len()];
+                    e[0] = a;
+                    e
+                };
+                assert!(
+                    [r.0, r.1, r.2, r.3].iter().zip(&expected).all(|(a, b)| a.to_bits() == b.to_bits()),
+                    "{:?}.ldexp({})",
+                    x,
+                    n
+                );
+            }
+        }
+    });
+
+    // frexp tests
+    test_all_eq!(
+        frexp_40:
+            (qd!(0.625), 6),
+            qd!(40).frexp();
+        frexp_neg_pi:
+            (-Quad::PI / qd!(4), 2),
+            (-Quad::PI).frexp();
+        frexp_small:
+            (Quad::PI / qd!(4), -798),
+            Quad::PI.ldexp(-800).frexp();
+        frexp_subnormal:
+            (qd!(0.5), -1073),
+            Quad::from_array([f64::from_bits(1), 0.0, 0.0, 0.0]).frexp();
+        frexp_max:
+            (Quad::MAX.ldexp(-1024), 1024),
+            Quad::MAX.frexp();
+        frexp_zero:
+            (Quad::ZERO, 0),
+            Quad::ZERO.frexp();
+        frexp_inf:
+            (Quad::INFINITY, 0),
+            Quad::INFINITY.frexp();
+        frexp_neg_inf:
+            (Quad::NEG_INFINITY, 0),
+            Quad::NEG_INFINITY.frexp();
+    );
+    test_all_assert!(
+        frexp_neg_zero:
+            Quad::NEG_ZERO.frexp().0.is_sign_negative();
+        frexp_nan:
+            Quad::NAN.frexp().0.is_nan() && Quad::NAN.frexp().1 == 0;
+    );
+    // A power of 2 in the first component with a tail of the opposite sign is just below
+    // that power, so its mantissa is just below 1
+    test_all_eq!(
+        frexp_just_below_one:
+            (Quad(1.0, -2f64.powi(-60), 0.0, 0.0), 0),
+            Quad(1.0, -2f64.powi(-60), 0.0, 0.0).frexp();
+        frexp_neg_just_below_one:
+            (Quad(-1.0, 2f64.powi(-59), 0.0, 0.0), -2),
+            Quad(-0.25, 2f64.powi(-61), 0.0, 0.0).frexp();
+        frexp_just_below_power:
+            (Quad(1.0, -2f64.powi(-60), 0.0, 0.0), 40),
+            Quad(2f64.powi(40), -2f64.powi(-20), 0.0, 0.0).frexp();
+        frexp_just_above_power:
+            (Quad(0.5, 2f64.powi(-60), 0.0, 0.0), 1),
+            Quad(1.0, 2f64.powi(-59), 0.0, 0.0).frexp();
+        frexp_just_below_in_last_component:
+            (Quad(1.0, 0.0, 0.0, -2f64.powi(-249)), 2),
+            Quad(4.0, 0.0, 0.0, -2f64.powi(-247)).frexp();
+        frexp_just_below_tiny_power:
+            (Quad(1.0, -2f64.powi(-53), 0.0, 0.0), -1020),
+            Quad(2f64.powi(-1020), -f64::from_bits(2), 0.0, 0.0).frexp();
+    );
+
+    // Random values across the whole range, with tails that are subnormal whenever the
+    // first component is small enough
+    test!(frexp_ldexp_round_trip: {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        // A random f64 between -1 and 1
+        let unit = |r: u64| (r >> 11) as f64 / 2f64.powi(52) - 1.0;
+
+        for _ in 0..100000 {
+            let k = rng.range(-1074, 1024);
+            // Every eighth first component is a power of 2, where the sign of the tail
+            // decides the exponent
+            let f = if rng.next_u64() % 8 == 0 {
+                1.0
+            } else {
+                1.0 + unit(rng.next_u64()).abs()
+            };
+            let h = u::ldexp(f, k);
+            let t1 = h * unit(rng.next_u64()) * 2f64.powi(-53);
+            let t2 = t1 * unit(rng.next_u64()) * 2f64.powi(-53);
+            let t3 = t2 * unit(rng.next_u64()) * 2f64.powi(-53);
+            let x = Quad::from_array([h, t1, t2, t3]);
+
+            let (m, e) = x.frexp();
+            assert!(m.abs() >= 0.5 && m.abs() < 1.0, "{:?}", x);
+            assert!(m.ldexp(e).to_bits() == x.to_bits(), "{:?}", x);
+
+            // Scaling up and back down is always exact as long as it doesn't overflow
+            let n = (rng.next_u64() % 2200) as i32;
+            let y = x.ldexp(n);
+            if y.is_finite() {
+                assert!(y.ldexp(-n).to_bits() == x.to_bits(), "{:?} {}", x, n);
+            } else {
+                assert!(u::exponent(x.0) + n > 1023, "{:?} {}", x, n);
+            }
+        }
+    });
+
     // sqr tests
     test_all_near!(
         sqr_pi:
@@ -550,6 +1380,67 @@ mod tests {
             Quad::NAN.sqr();
     );
 
+    // cube tests
+    test_all_near!(
+        cube_pi:
+            qd!("31.0062766802998201754763150671013952022252885658851076941445381036"),
+            Quad::PI.cube();
+        cube_e:
+            qd!("20.0855369231876677409285296545817178969879078385541501443789342299"),
+            Quad::E.cube();
+        cube_sqrt_2:
+            qd!("2.82842712474619009760337744841939615713934375075389614635335947598"),
+            Quad::SQRT_2.cube();
+        cube_neg_pi_2:
+            qd!("-3.87578458503747752193453938338767440027816107073563846176806726295"),
+            (-Quad::FRAC_PI_2).cube();
+    );
+    test_all_exact!(
+        cube_3:
+            qd!(27),
+            qd!(3).cube();
+        cube_neg_half:
+            qd!(-0.125),
+            qd!(-0.5).cube();
+        cube_zero:
+            Quad::ZERO,
+            Quad::ZERO.cube();
+        cube_neg_zero:
+            Quad::NEG_ZERO,
+            Quad::NEG_ZERO.cube();
+        cube_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.cube();
+        cube_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.cube();
+        cube_nan:
+            Quad::NAN,
+            Quad::NAN.cube();
+        cube_overflow:
+            Quad::INFINITY,
+            qd!(1e103).cube();
+    );
+
+    // `sqr` keeps exactly the terms that multiplication does, so the two should agree to
+    // within the last place of the last component.
+    test!(sqr_matches_mul: {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        for i in 0..100_000 {
+            let e = (i % 80) - 40;
+            let x = (Quad::ONE / Quad::from(rng.next_f64() + 0.5)).ldexp(e);
+            let expected = x * x;
+            let diff = (x.sqr() - expected).abs();
+            assert!(
+                diff <= expected * Quad::EPSILON,
+                "{:?}.sqr() = {:?}, expected {:?}",
+                x,
+                x.sqr(),
+                expected
+            );
+        }
+    });
+
     // sqrt tests
     test_all_near!(
         sqrt_pi:
@@ -574,6 +1465,29 @@ mod tests {
             qd!("12.247448713915890490986420373529456959829737403283350642163462836256"),
             qd!(150).sqrt();
     );
+    // The last components of these arguments would be subnormal, so the arguments are
+    // less precise than a `Quad` usually is. The expected values are the roots of the
+    // numbers that are actually parsed, not of the decimals.
+    test_all_prec!(
+        sqrt_1e_300:
+            qd!("9.999999999999999999999991434904709765740338083659980186814495700839653e-151"),
+            qd!("1e-300").sqrt(),
+            60;
+        sqrt_1e_290:
+            qd!("9.999999999999999999999999999999999825852812046991242841564825512361102e-146"),
+            qd!("1e-290").sqrt(),
+            60;
+        sqrt_4e_320:
+            qd!("1.999988867151697927584136068550375195652592997878584971931836913093866e-160"),
+            qd!("4e-320").sqrt(),
+            60;
+    );
+    test!(sqrt_sqr_round_trip: {
+        for n in (-800..=1022).step_by(3) {
+            let x = c::scale(Quad::PI, n);
+            prec!(x, x.sqrt().sqr(), 60);
+        }
+    });
     test_all_exact!(
         sqrt_neg_pi:
             Quad::NAN,
@@ -598,6 +1512,250 @@ mod tests {
             Quad::NAN.sqrt();
     );
 
+    // recip_sqrt tests
+    test_all_prec!(
+        recip_sqrt_of_2:
+            qd!("0.707106781186547524400844362104849039284835937688474036588339868995"),
+            qd!(2).recip_sqrt(),
+            62;
+        recip_sqrt_of_3:
+            qd!("0.577350269189625764509148780501957455647601751270126876018602326484"),
+            qd!(3).recip_sqrt(),
+            62;
+        recip_sqrt_of_10:
+            qd!("0.316227766016837933199889354443271853371955513932521682685750485279"),
+            qd!(10).recip_sqrt(),
+            62;
+        recip_sqrt_huge:
+            qd!("0.577350269189625764509148780501957455647601751270126876018602326484").ldexp(-500),
+            qd!(3).ldexp(1000).recip_sqrt(),
+            62;
+        recip_sqrt_tiny:
+            qd!("0.408248290463863016366214012450981898660991246776111688072115427875").ldexp(501),
+            qd!(3).ldexp(-1001).recip_sqrt(),
+            62;
+    );
+    test_all_exact!(
+        recip_sqrt_of_4:
+            qd!(0.5),
+            qd!(4).recip_sqrt();
+        recip_sqrt_quarter:
+            qd!(2),
+            qd!(0.25).recip_sqrt();
+        recip_sqrt_min_subnormal:
+            qd!(2).ldexp(536),
+            qd!(1).ldexp(-1074).recip_sqrt();
+        recip_sqrt_neg:
+            Quad::NAN,
+            (-Quad::PI).recip_sqrt();
+        recip_sqrt_zero:
+            Quad::INFINITY,
+            Quad::ZERO.recip_sqrt();
+        recip_sqrt_neg_zero:
+            Quad::NEG_INFINITY,
+            Quad::NEG_ZERO.recip_sqrt();
+        recip_sqrt_inf:
+            Quad::ZERO,
+            Quad::INFINITY.recip_sqrt();
+        recip_sqrt_neg_inf:
+            Quad::NAN,
+            Quad::NEG_INFINITY.recip_sqrt();
+        recip_sqrt_nan:
+            Quad::NAN,
+            Quad::NAN.recip_sqrt();
+    );
+    test!(recip_sqrt_random: {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        let mut next = || rng.next_f64();
+        for i in 0..20_000 {
+            let e = (i % 2000) - 1000;
+            let m = 1.0 + next();
+            let (b, c, d) = (next() * 1e-16 * m, next() * 1e-33 * m, next() * 1e-49 * m);
+            let x = Quad::from_components(m, b, c, d).ldexp(e);
+            let expected = Quad::ONE / x.sqrt();
+            let diff = ((x.recip_sqrt() - expected) / expected).abs();
+            assert!(
+                diff <= Quad::EPSILON.ldexp(1),
+                "{:?}.recip_sqrt() = {:?}, expected {:?}",
+                x,
+                x.recip_sqrt(),
+                expected
+            );
+        }
+    });
+
+    // hypot tests
+    test_all_prec!(
+        hypot_3_4_e200:
+            qd!("5e200"),
+            qd!("3e200").hypot(qd!("4e200")),
+            62;
+        hypot_3_4_pwr2:
+            c::scale(qd!(5), 600),
+            c::scale(qd!(3), 600).hypot(c::scale(qd!(-4), 600)),
+            62;
+        hypot_tiny:
+            Quad::SQRT_2 * qd!("1e-200"),
+            qd!("1e-200").hypot(qd!("1e-200")),
+            60;
+        hypot_near_max:
+            Quad::MAX * Quad::FRAC_1_SQRT_2,
+            c::mul_pwr2(Quad::MAX, 0.5).hypot(c::mul_pwr2(Quad::MAX, 0.5)),
+            60;
+        hypot3_1_2_2:
+            qd!(3),
+            qd!(1).hypot3(qd!(-2), qd!(2)),
+            62;
+        hypot3_tiny:
+            qd!(3).sqrt() * qd!("1e-200"),
+            qd!("1e-200").hypot3(qd!("-1e-200"), qd!("1e-200")),
+            60;
+    );
+    test_all_exact!(
+        hypot_inf_nan:
+            Quad::INFINITY,
+            Quad::INFINITY.hypot(Quad::NAN);
+        hypot_nan_neg_inf:
+            Quad::INFINITY,
+            Quad::NAN.hypot(Quad::NEG_INFINITY);
+        hypot_nan:
+            Quad::NAN,
+            Quad::NAN.hypot(Quad::ONE);
+        hypot_one_nan:
+            Quad::NAN,
+            Quad::ONE.hypot(Quad::NAN);
+        hypot_zeros:
+            Quad::ZERO,
+            Quad::NEG_ZERO.hypot(Quad::NEG_ZERO);
+        hypot_zero:
+            qd!(3),
+            qd!(-3).hypot(Quad::ZERO);
+        hypot_max_one:
+            Quad::MAX,
+            Quad::MAX.hypot(Quad::ONE);
+        hypot_overflow:
+            Quad::INFINITY,
+            Quad::MAX.hypot(-Quad::MAX);
+        hypot_subnormal:
+            Quad(1.14e-322, 0.0, 0.0, 0.0),
+            Quad(f64::from_bits(16), 0.0, 0.0, 0.0).hypot(Quad(f64::from_bits(16), 0.0, 0.0, 0.0));
+        hypot3_inf_nan:
+            Quad::INFINITY,
+            Quad::NAN.hypot3(Quad::ONE, Quad::NEG_INFINITY);
+        hypot3_nan:
+            Quad::NAN,
+            Quad::ONE.hypot3(Quad::NAN, Quad::ONE);
+        hypot3_zeros:
+            Quad::ZERO,
+            Quad::NEG_ZERO.hypot3(Quad::ZERO, Quad::NEG_ZERO);
+        hypot3_one_nonzero:
+            qd!(5),
+            Quad::ZERO.hypot3(qd!(-5), Quad::NEG_ZERO);
+        hypot3_overflow:
+            Quad::INFINITY,
+            Quad::MAX.hypot3(Quad::MAX, Quad::MAX);
+    );
+    test_all_assert!(
+        hypot_zeros_sign:
+            Quad::NEG_ZERO.hypot(Quad::NEG_ZERO).is_sign_positive();
+        hypot3_near_max:
+            c::mul_pwr2(Quad::MAX, 0.5)
+                .hypot3(c::mul_pwr2(Quad::MAX, 0.5), c::mul_pwr2(Quad::MAX, 0.5))
+                .is_finite();
+    );
+
+    // agm tests
+    test_all_prec!(
+        agm_gauss:
+            qd!("1.198140234735592207439922492280323878227212663215651558263674953"),
+            qd!(1).agm(qd!(2).sqrt()),
+            62;
+        agm_gauss_constant:
+            qd!("0.8346268416740731862814297327990468089939930134903470024498273701"),
+            qd!(1).agm(qd!(2).sqrt()).recip(),
+            62;
+        agm_1_2:
+            qd!("1.456791031046906869186432383265081974973863943221305590794172383"),
+            qd!(1).agm(qd!(2)),
+            62;
+        agm_elliptic_k:
+            qd!("1.854074677301371918433850347195260046217598823521766905585928045"),
+            Quad::FRAC_PI_2 / qd!(1).agm(qd!(0.5).sqrt()),
+            62;
+        agm_tiny:
+            qd!("0.002261656757398799306201438595666999406273383965930037716659688896"),
+            qd!(1).agm(qd!(2).powi(-1000)),
+            61;
+        agm_huge_ratio:
+            qd!("1.212902822886066297418770460967161344558776901921022188067719382e298"),
+            qd!(2).powi(1000).agm(qd!(2).powi(-1000)),
+            61;
+        agm_max:
+            qd!("2.423384682053797133166046370116024961882905546552780858793986231e298"),
+            qd!(1).agm(qd!(2).powi(1000)),
+            61;
+    );
+    test_all_exact!(
+        agm_same:
+            qd!(7),
+            qd!(7).agm(qd!(7));
+        agm_same_pi:
+            Quad::PI,
+            Quad::PI.agm(Quad::PI);
+        agm_zero:
+            Quad::ZERO,
+            qd!(5).agm(Quad::ZERO);
+        agm_neg_zero:
+            Quad::ZERO,
+            Quad::NEG_ZERO.agm(qd!(5));
+        agm_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.agm(qd!(5));
+        agm_zero_inf:
+            Quad::NAN,
+            Quad::ZERO.agm(Quad::INFINITY);
+        agm_neg:
+            Quad::NAN,
+            qd!(-1).agm(qd!(2));
+        agm_neg_inf:
+            Quad::NAN,
+            qd!(2).agm(Quad::NEG_INFINITY);
+        agm_nan:
+            Quad::NAN,
+            Quad::NAN.agm(qd!(2));
+        agm_max_max:
+            Quad::MAX,
+            Quad::MAX.agm(Quad::MAX);
+    );
+    test_all_assert!(
+        agm_max_min:
+            Quad::MAX.agm(Quad::MIN_POSITIVE).is_finite();
+        agm_between:
+            qd!(1).agm(qd!(100)) > qd!(10) && qd!(1).agm(qd!(100)) < qd!("50.5");
+    );
+    test!(agm_symmetric: {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        for _ in 0..200 {
+            let mut arg = || {
+                let (h, t) = ((rng.next_u64() >> 11) as f64, (rng.next_u64() >> 11) as f64);
+                Quad::from_sum(&[h, t * 1e-20]).ldexp(rng.range(-200, 200))
+            };
+            let (a, b) = (arg(), arg());
+            let (x, y) = (a.agm(b), b.agm(a));
+            assert!(x.to_bits() == y.to_bits(), "{:?}.agm({:?})", a, b);
+            // The mean is between the geometric and arithmetic means of the arguments
+            assert!(x >= (a * b).sqrt() * (Quad::ONE - Quad::EPSILON));
+            assert!(x <= (a + b) / qd!(2) * (Quad::ONE + Quad::EPSILON));
+        }
+    });
+    // Scaling the arguments by a power of 2 scales the mean by the same power
+    test!(agm_homogeneous: {
+        let x = qd!(3).agm(Quad::PI);
+        for &k in &[-1000, -500, -1, 1, 500, 1000] {
+            assert!(qd!(3).ldexp(k).agm(Quad::PI.ldexp(k)) == x.ldexp(k), "2^{}", k);
+        }
+    });
+
     // nroot tests
     test_all_near!(
         nroot_pi_one:
@@ -654,7 +1812,16 @@ mod tests {
         nroot_neg_140_odd:
             qd!("-0.63811279267447920124399988241494030594315682697915110323602465090342"),
             qd!(-140).nroot(-11);
+        nroot_2_fifth:
+            qd!("1.148698354997035006798626946777927589443850889097797505513711118"),
+            qd!(2).nroot(5);
     );
+    test!(nroot_powi_round_trip: {
+        for x in [qd!(2), Quad::PI, qd!(-1000), qd!(1e-250), qd!(1e250)] {
+            prec!(x, x.nroot(7).powi(7), 60);
+            prec!(x, x.nroot(-7).powi(-7), 60);
+        }
+    });
     test_all_exact!(
         nroot_neg_pi_even:
             Quad::NAN,
@@ -758,6 +1925,12 @@ mod tests {
             qd!(-140).cbrt();
     );
     test_all_exact!(
+        cbrt_27:
+            qd!(3),
+            qd!(27).cbrt();
+        cbrt_neg_8:
+            qd!(-2),
+            qd!(-8).cbrt();
         cbrt_zero:
             Quad::ZERO,
             Quad::ZERO.cbrt();
@@ -871,6 +2044,181 @@ mod tests {
             Quad::NAN.powi(2);
     );
 
+    // pow and powu tests
+    fn same(a: Quad, b: Quad) -> bool {
+        a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan())
+    }
+
+    test!(pow_matches_powi: {
+        for &x in &[Quad::PI, -Quad::E, qd!(0.5), qd!(-2), Quad::ZERO, Quad::NEG_ZERO,
+                    Quad::INFINITY, Quad::NEG_INFINITY, Quad::NAN] {
+            for n in -1100..=1100 {
+                assert!(same(x.pow(n), x.powi(n)));
+            }
+            for &n in &[i32::MIN, i32::MIN + 1, i32::MAX - 1, i32::MAX] {
+                assert!(same(x.pow(n), x.powi(n)));
+            }
+        }
+    });
+    test!(powu_matches_powi: {
+        for &x in &[Quad::PI, -Quad::E, qd!(0.5), qd!(-2), Quad::ZERO, Quad::NEG_ZERO,
+                    Quad::INFINITY, Quad::NEG_INFINITY, Quad::NAN] {
+            for n in 0..=1100 {
+                assert!(same(x.powu(n), x.powi(n as i32)));
+            }
+            assert!(same(x.powu(i32::MAX as u32), x.powi(i32::MAX)));
+        }
+    });
+    test_all_near!(
+        powu_large:
+            qd!("1.000000000000000000000000003231174267032947970440176203518544583671738"),
+            (qd!(1) + qd!(2).powi(-120)).powu(u32::MAX);
+    );
+    test_all_exact!(
+        powu_neg_one_odd:
+            qd!(-1),
+            qd!(-1).powu(u32::MAX);
+        powu_neg_one_even:
+            qd!(1),
+            qd!(-1).powu(u32::MAX - 1);
+        powu_overflow:
+            Quad::INFINITY,
+            qd!(2).powu(u32::MAX);
+        powu_neg_overflow:
+            Quad::NEG_INFINITY,
+            qd!(-2).powu(u32::MAX);
+        powu_underflow:
+            Quad::ZERO,
+            qd!(0.5).powu(u32::MAX);
+        powu_inf_large:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.powu(u32::MAX);
+        powu_nan_zero:
+            Quad::ONE,
+            Quad::NAN.powu(0);
+    );
+    test_all_assert!(
+        powu_neg_zero_odd_sign:
+            Quad::NEG_ZERO.powu(u32::MAX).is_sign_negative();
+        powu_neg_zero_even_sign:
+            Quad::NEG_ZERO.powu(u32::MAX - 1).is_sign_positive();
+    );
+
+    // powi with large exponents and powi64 tests
+    test!(powi_small_exponents: {
+        let x = Quad::PI;
+        assert!(same(x.powi(1), x));
+        assert!(same(x.powi(-1), x.recip()));
+        assert!(same(x.powi(2), x.sqr()));
+        assert!(same(x.powi(-2), x.sqr().recip()));
+    });
+    test_all_prec!(
+        powi_4096:
+            qd!("2.717950081189665872541414393278139186561497984243892846209852744904246"),
+            (qd!(1) + qd!(2).powi(-12)).powi(4096),
+            58;
+        powi_neg_4096:
+            qd!("0.3679243437621536318095180775598052248740994526581886658937821670121092"),
+            (qd!(1) + qd!(2).powi(-12)).powi(-4096),
+            58;
+        powi_min:
+            qd!("0.9999999981373548525037664444575425710933972731903772292222408220589172"),
+            (qd!(1) + qd!(2).powi(-60)).powi(i32::MIN),
+            52;
+        powi64_large:
+            qd!("1.000000953674771153745446374658856167129125027160702780624348666522344"),
+            (qd!(1) + qd!(2).powi(-60)).powi64(1 << 40),
+            49;
+    );
+    test_all_exact!(
+        powi_1023:
+            Quad(2f64.powi(1023), 0.0, 0.0, 0.0),
+            qd!(2).powi(1023);
+        powi_neg_1023:
+            Quad(2f64.powi(-1023), 0.0, 0.0, 0.0),
+            qd!(2).powi(-1023);
+        powi_1024:
+            Quad::INFINITY,
+            qd!(2).powi(1024);
+        powi_neg_1025:
+            Quad::NEG_INFINITY,
+            qd!(-2).powi(1025);
+        powi_half_4096:
+            Quad::ZERO,
+            qd!(0.5).powi(4096);
+        powi_half_neg_4096:
+            Quad::INFINITY,
+            qd!(0.5).powi(-4096);
+        powi_min_subnormal:
+            Quad(f64::from_bits(1), 0.0, 0.0, 0.0),
+            qd!(2).powi(-1074);
+        powi_below_subnormal:
+            Quad::ZERO,
+            qd!(2).powi(-1075);
+        powi_one_min:
+            Quad::ONE,
+            Quad::ONE.powi(i32::MIN);
+        powi_neg_one_min:
+            Quad::ONE,
+            Quad::NEG_ONE.powi(i32::MIN);
+        powi_two_min:
+            Quad::ZERO,
+            qd!(2).powi(i32::MIN);
+        powi_half_min:
+            Quad::INFINITY,
+            qd!(0.5).powi(i32::MIN);
+        powi_neg_zero_min:
+            Quad::INFINITY,
+            Quad::NEG_ZERO.powi(i32::MIN);
+        powi_nan_min:
+            Quad::NAN,
+            Quad::NAN.powi(i32::MIN);
+        powi64_neg_one_max:
+            Quad::NEG_ONE,
+            Quad::NEG_ONE.powi64(i64::MAX);
+        powi64_neg_one_min:
+            Quad::ONE,
+            Quad::NEG_ONE.powi64(i64::MIN);
+        powi64_overflow:
+            Quad::INFINITY,
+            qd!(1.5).powi64(1 << 40);
+        powi64_neg_overflow:
+            Quad::NEG_INFINITY,
+            qd!(-1.5).powi64((1 << 40) + 1);
+        powi64_underflow:
+            Quad::ZERO,
+            qd!(1.5).powi64(-(1 << 40));
+        powi64_neg_underflow:
+            Quad::NEG_ZERO,
+            qd!(-1.5).powi64(-(1 << 40) - 1);
+        powi64_zero_min:
+            Quad::INFINITY,
+            Quad::ZERO.powi64(i64::MIN);
+    );
+    test_all_assert!(
+        powi_neg_underflow_sign:
+            qd!(-2).powi(-1075).is_sign_negative();
+        powi64_neg_underflow_sign:
+            qd!(-1.5).powi64(-(1 << 40) - 1).is_sign_negative();
+    );
+    // The power itself can't be represented, but the result can
+    test!(powi_subnormal: {
+        let x = qd!(3).powi(-650);
+        assert!((x[0] - 7.433347434966866668478949944540770687364e-311).abs() <= f64::from_bits(1));
+        assert!(x[1] == 0.0 && x[2] == 0.0 && x[3] == 0.0);
+    });
+    test!(powi64_matches_powi: {
+        for &x in &[Quad::PI, -Quad::E, qd!(0.5), qd!(-2), Quad::ZERO, Quad::NEG_ZERO,
+                    Quad::INFINITY, Quad::NAN] {
+            for n in -1100..=1100 {
+                assert!(same(x.powi64(n.into()), x.powi(n)));
+            }
+            for &n in &[i32::MIN, i32::MAX] {
+                assert!(same(x.powi64(n.into()), x.powi(n)));
+            }
+        }
+    });
+
     // powf_tests
     test_all_near!(
         powf_pi:
@@ -894,13 +2242,28 @@ mod tests {
         powf_150:
             qd!("0.0000097580874511463571772513415019480387518681397873542312835791633473171"),
             qd!(150).powf(-Quad::LN_10);
+        powf_frac_neg:
+            qd!("2.05280095711866938492141788622918206452480622673822889251058604972"),
+            qd!(0.75).powf(qd!(-2.5));
+        powf_tenth:
+            qd!("1.58568386167829660991370078323229752214240111068417194797840019082"),
+            qd!(100.5).powf(qd!(0.1));
+        powf_half:
+            qd!("1.41421356237309504880168872420969807856967187537694807317667973799"),
+            qd!(2).powf(qd!(0.5));
+        powf_small_result:
+            qd!("1.76616131506389557021588203971980903200452242307341113308950605541e-8"),
+            qd!(12.25).powf(qd!(-7.125));
+        powf_small_base:
+            qd!("5.62341325190349080394951039776481231468251043098691664081689423736e-6"),
+            qd!(0.001).powf(qd!(1.75));
     );
     test_all_exact!(
         powf_zero:
             Quad::ZERO,
             Quad::ZERO.powf(qd!(3));
         powf_neg_zero:
-            Quad::ZERO,
+            Quad::NEG_ZERO,
             Quad::NEG_ZERO.powf(qd!(3));
         powf_zero_inf:
             Quad::ZERO,
@@ -928,29 +2291,29 @@ mod tests {
             Quad::ONE,
             qd!(2).powf(Quad::NEG_ZERO);
         powf_zero_zero:
-            Quad::NAN,
+            Quad::ONE,
             Quad::ZERO.powf(Quad::ZERO);
         powf_neg_zero_zero:
-            Quad::NAN,
+            Quad::ONE,
             Quad::NEG_ZERO.powf(Quad::ZERO);
         powf_zero_neg_zero:
-            Quad::NAN,
+            Quad::ONE,
             Quad::ZERO.powf(Quad::NEG_ZERO);
         powf_neg_zero_neg_zero:
-            Quad::NAN,
+            Quad::ONE,
             Quad::NEG_ZERO.powf(Quad::NEG_ZERO);
 
         powf_inf_zero:
-            Quad::NAN,
+            Quad::ONE,
             Quad::INFINITY.powf(Quad::ZERO);
         powf_inf_neg_zero:
-            Quad::NAN,
+            Quad::ONE,
             Quad::INFINITY.powf(Quad::NEG_ZERO);
         powf_neg_inf_zero:
-            Quad::NAN,
+            Quad::ONE,
             Quad::NEG_INFINITY.powf(Quad::ZERO);
         powf_neg_inf_neg_zero:
-            Quad::NAN,
+            Quad::ONE,
             Quad::NEG_INFINITY.powf(Quad::NEG_ZERO);
 
         powf_exp_inf:
@@ -960,10 +2323,10 @@ mod tests {
             Quad::ZERO,
             qd!(2).powf(Quad::NEG_INFINITY);
         powf_one_inf:
-            Quad::NAN,
+            Quad::ONE,
             qd!(1).powf(Quad::INFINITY);
         powf_one_neg_inf:
-            Quad::NAN,
+            Quad::ONE,
             qd!(1).powf(Quad::NEG_INFINITY);
 
         powf_nan:
@@ -973,9 +2336,183 @@ mod tests {
             Quad::NAN,
             qd!(3).powf(Quad::NAN);
         powf_neg:
-            Quad::NAN,
+            Quad::NEG_ONE,
             qd!(-1).powf(qd!(1));
+
+        powf_one_nan:
+            Quad::ONE,
+            qd!(1).powf(Quad::NAN);
+        powf_nan_zero:
+            Quad::ONE,
+            Quad::NAN.powf(Quad::ZERO);
+        powf_small_inf:
+            Quad::ZERO,
+            qd!(0.5).powf(Quad::INFINITY);
+        powf_small_neg_inf:
+            Quad::INFINITY,
+            qd!(-0.5).powf(Quad::NEG_INFINITY);
+        powf_neg_one_inf:
+            Quad::ONE,
+            Quad::NEG_ONE.powf(Quad::INFINITY);
+        powf_neg_one_neg_inf:
+            Quad::ONE,
+            Quad::NEG_ONE.powf(Quad::NEG_INFINITY);
+        powf_neg_big_inf:
+            Quad::INFINITY,
+            qd!(-2).powf(Quad::INFINITY);
+        powf_neg_zero_neg_odd:
+            Quad::NEG_INFINITY,
+            Quad::NEG_ZERO.powf(qd!(-3));
+        powf_neg_zero_frac:
+            Quad::ZERO,
+            Quad::NEG_ZERO.powf(qd!(0.5));
+        powf_inf_pos:
+            Quad::INFINITY,
+            Quad::INFINITY.powf(qd!(0.5));
+        powf_inf_neg:
+            Quad::ZERO,
+            Quad::INFINITY.powf(qd!(-2));
+        powf_neg_inf_odd:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.powf(qd!(3));
+        powf_neg_inf_even:
+            Quad::INFINITY,
+            Quad::NEG_INFINITY.powf(qd!(2));
+        powf_neg_inf_frac:
+            Quad::INFINITY,
+            Quad::NEG_INFINITY.powf(qd!(0.5));
+        powf_neg_inf_neg_odd:
+            Quad::NEG_ZERO,
+            Quad::NEG_INFINITY.powf(qd!(-3));
     );
+    test_all_assert!(
+        // `exact` doesn't tell the zeros apart
+        powf_neg_zero_odd_sign:
+            Quad::NEG_ZERO.powf(qd!(3)).is_sign_negative();
+        powf_neg_zero_even_sign:
+            Quad::NEG_ZERO.powf(qd!(2)).is_sign_positive();
+        powf_neg_inf_neg_odd_sign:
+            Quad::NEG_INFINITY.powf(qd!(-3)).is_sign_negative();
+        powf_neg_inf_neg_even_sign:
+            Quad::NEG_INFINITY.powf(qd!(-2)).is_sign_positive();
+    );
+
+    // powf tests for negative bases, which need an exponent that's an integer all the way
+    // down to its last component, and whose parity can live in any component
+    test_all_exact!(
+        powf_neg_odd:
+            qd!(-8),
+            qd!(-2).powf(qd!(3));
+        powf_neg_even:
+            qd!(16),
+            qd!(-2).powf(qd!(4));
+        powf_neg_odd_recip:
+            qd!(-0.125),
+            qd!(-2).powf(qd!(-3));
+        powf_neg_computed:
+            qd!(-8),
+            qd!(-2).powf(qd!(6) / qd!(2));
+        powf_neg_below_int:
+            Quad::NAN,
+            qd!(-2).powf(qd!("2.9999999999999999999999999999999999999999999999999999999999999"));
+        powf_neg_above_int:
+            Quad::NAN,
+            qd!(-2).powf(qd!("3.0000000000000000000000000000000000000000000000000000000000001"));
+        powf_neg_frac:
+            Quad::NAN,
+            qd!(-2).powf(qd!(0.5));
+        powf_neg_huge_even:
+            Quad::ONE,
+            Quad::NEG_ONE.powf(Quad(2f64.powi(200), 0.0, 0.0, 0.0));
+        powf_neg_huge_odd:
+            Quad::NEG_ONE,
+            Quad::NEG_ONE.powf(Quad(2f64.powi(60), 1.0, 0.0, 0.0));
+        powf_neg_huge_odd_neg_tail:
+            Quad::NEG_ONE,
+            Quad::NEG_ONE.powf(Quad(2f64.powi(60), -1.0, 0.0, 0.0));
+        powf_neg_huge_frac:
+            Quad::NAN,
+            Quad::NEG_ONE.powf(Quad(2f64.powi(60), 0.5, 0.0, 0.0));
+        powf_neg_odd_third:
+            Quad::NEG_ONE,
+            Quad::NEG_ONE.powf(Quad(2f64.powi(120), 2f64.powi(60), 1.0, 0.0));
+        powf_neg_even_third:
+            Quad::ONE,
+            Quad::NEG_ONE.powf(Quad(2f64.powi(120), 2f64.powi(60), 2.0, 0.0));
+        powf_neg_huge_overflow:
+            Quad::NEG_INFINITY,
+            qd!(-2).powf(Quad(2f64.powi(60), 1.0, 0.0, 0.0));
+    );
+
+    // mul_add tests
+    fn unit_plus() -> Quad {
+        Quad(1.0, 2f64.powi(-60), 2f64.powi(-120), 2f64.powi(-180))
+    }
+
+    test_all_exact!(
+        mul_add_simple:
+            qd!(7),
+            qd!(2).mul_add(qd!(3), qd!(1));
+        // x² - y² where the rounding of x² is all that's left
+        mul_add_square_cancel:
+            Quad(2f64.powi(-59), 3.0 * 2f64.powi(-120), 2f64.powi(-178), 3.0 * 2f64.powi(-240)),
+            unit_plus().mul_add(unit_plus(), -(qd!(1) * qd!(1)));
+        mul_add_exact_cancel:
+            Quad::ZERO,
+            Quad::PI.mul_add(qd!(2), -Quad::TAU);
+        mul_add_zero_factor:
+            Quad::E,
+            Quad::ZERO.mul_add(Quad::PI, Quad::E);
+
+        mul_add_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.mul_add(qd!(2), qd!(1));
+        mul_add_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::INFINITY.mul_add(qd!(-2), qd!(1));
+        mul_add_inf_addend:
+            Quad::INFINITY,
+            Quad::PI.mul_add(Quad::E, Quad::INFINITY);
+        mul_add_inf_zero:
+            Quad::NAN,
+            Quad::INFINITY.mul_add(Quad::ZERO, qd!(1));
+        mul_add_inf_cancel:
+            Quad::NAN,
+            Quad::INFINITY.mul_add(qd!(1), Quad::NEG_INFINITY);
+        mul_add_nan:
+            Quad::NAN,
+            Quad::NAN.mul_add(qd!(1), qd!(1));
+        mul_add_nan_addend:
+            Quad::NAN,
+            qd!(1).mul_add(qd!(1), Quad::NAN);
+    );
+    test_all_assert!(
+        mul_add_neg_zero:
+            Quad::NEG_ZERO.mul_add(Quad::PI, Quad::NEG_ZERO).is_sign_negative();
+        mul_add_mixed_zero:
+            Quad::NEG_ZERO.mul_add(Quad::PI, Quad::ZERO).is_sign_positive();
+        mul_add_cancel_zero:
+            qd!(-2).mul_add(qd!(3), qd!(6)).is_sign_positive();
+        // The separate operations lose the last component of the result
+        mul_add_beats_operators:
+            {
+                let x = unit_plus();
+                let naive = x * x - qd!(1);
+                let fused = x.mul_add(x, qd!(-1));
+                naive[3] == 0.0 && fused[3] == 3.0 * 2f64.powi(-240)
+            };
+    );
+    test!(mul_add_matches_operators: {
+        // Without cancellation, the operators are already accurate
+        for &(x, a, b) in &[
+            (Quad::PI, Quad::E, Quad::LN_2),
+            (-Quad::PI, Quad::E, Quad::LN_10),
+            (Quad::SQRT_2, Quad::SQRT_2, Quad::ONE),
+        ] {
+            let diff = (x.mul_add(a, b) - (x * a + b)).abs();
+            assert!(diff <= (x * a + b).abs() * Quad::EPSILON * qd!(4));
+        }
+    });
 
     // recip tests
     test_all_near!(
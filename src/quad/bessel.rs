@@ -0,0 +1,606 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::reduction as r;
+use crate::common::strict as s;
+use crate::quad::common as c;
+use crate::quad::Quad;
+
+// The argument below which the Bessel functions are calculated with their power series.
+// The largest term of the series for J0 at x = 4 is 4, so less than a digit is lost to
+// cancellation below it. The backward recurrence takes over from here.
+const SERIES_MAX: f64 = 4.0;
+
+// The argument at and above which the Bessel functions are calculated with their
+// asymptotic expansions. The smallest term of those expansions is about e^(-2x), which is
+// less than a `Quad`'s epsilon once x is more than about 74.
+const ASYMPTOTIC_MIN: f64 = 80.0;
+
+impl Quad {
+    /// Computes the Bessel function of the first kind of order zero, J<sub>0</sub>(*x*),
+    /// where *x* is this `Quad`.
+    ///
+    /// For |*x*| < 4, this is calculated with the power series
+    ///
+    /// J<sub>0</sub>(*x*) = Σ (-*x*²/4)<sup>*k*</sup> / (*k*!)²,
+    ///
+    /// and up to 80 with Miller's algorithm, running the recurrence
+    /// J<sub>*n*-1</sub>(*x*) = (2*n* / *x*) J<sub>*n*</sub>(*x*) - J<sub>*n*+1</sub>(*x*)
+    /// backwards from an order high enough that the starting values don't matter, then
+    /// scaling the results so that J<sub>0</sub> + 2J<sub>2</sub> + 2J<sub>4</sub> + ⋯ = 1.
+    /// Past 80, it's calculated with Hankel's asymptotic expansion
+    ///
+    /// J<sub>0</sub>(*x*) = √(2 / π*x*) (*P*(*x*) cos(*x* - π/4) - *Q*(*x*) sin(*x* - π/4)),
+    ///
+    /// where the phase is reduced exactly, the same way that [`sin`] and [`cos`] reduce
+    /// their arguments, so nothing is lost however large *x* is.
+    ///
+    /// The error is about 10<sup>-63</sup> at most in absolute terms, shrinking as the
+    /// amplitude of the function does like 1/√*x*. That's about 62 digits wherever the
+    /// function isn't close to zero, but since J<sub>0</sub> crosses zero infinitely many
+    /// times, the relative error near those crossings is larger by however much smaller
+    /// than its amplitude the function is. At *x* = 2.4048, within 3 × 10<sup>-5</sup> of
+    /// the first zero, it's still more than 59 digits.
+    ///
+    /// J<sub>0</sub> is even, and J<sub>0</sub>(±∞) = 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1).j0();
+    /// let expected = qd!("0.7651976865579665514497175261026632209092742897553252418615475491193");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`sin`]: #method.sin
+    /// [`cos`]: #method.cos
+    pub fn j0(self) -> Quad {
+        match self.pre_j0() {
+            Some(r) => s::guard("Quad::j0", &[self.0], r),
+            None => {
+                let x = self.abs();
+                if x.0 < SERIES_MAX {
+                    series(x, 0).0
+                } else if x.0 < ASYMPTOTIC_MIN {
+                    recurrence(x).0
+                } else {
+                    asymptotic(x, 0).0
+                }
+            }
+        }
+    }
+
+    /// Computes the Bessel function of the first kind of order one, J<sub>1</sub>(*x*),
+    /// where *x* is this `Quad`.
+    ///
+    /// This is calculated the same way as [`j0`], with the power series
+    ///
+    /// J<sub>1</sub>(*x*) = (*x*/2) Σ (-*x*²/4)<sup>*k*</sup> / (*k*! (*k* + 1)!)
+    ///
+    /// for |*x*| < 4, Miller's algorithm up to 80, and Hankel's asymptotic expansion, with
+    /// the phase *x* - 3π/4 reduced exactly, past that. Its precision is the same as that
+    /// of `j0`: about 10<sup>-63</sup> in absolute terms, which is about 62 digits except
+    /// close to the function's zeros.
+    ///
+    /// J<sub>1</sub> is odd, and J<sub>1</sub>(±∞) = 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1).j1();
+    /// let expected = qd!("0.4400505857449335159596822037189149131273723019927652511367581717801");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`j0`]: #method.j0
+    pub fn j1(self) -> Quad {
+        match self.pre_j1() {
+            Some(r) => s::guard("Quad::j1", &[self.0], r),
+            None => {
+                let x = self.abs();
+                let r = if x.0 < SERIES_MAX {
+                    c::mul_pwr2(x, 0.5) * series(x, 1).0
+                } else if x.0 < ASYMPTOTIC_MIN {
+                    recurrence(x).1
+                } else {
+                    asymptotic(x, 1).0
+                };
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
+    /// Computes the Bessel function of the second kind of order zero, Y<sub>0</sub>(*x*),
+    /// where *x* is this `Quad`.
+    ///
+    /// For *x* < 4, this is calculated with the series
+    ///
+    /// Y<sub>0</sub>(*x*) = (2/π) (ln(*x*/2) + γ) J<sub>0</sub>(*x*) - (2/π) Σ
+    /// *H*<sub>*k*</sub> (-*x*²/4)<sup>*k*</sup> / (*k*!)²,
+    ///
+    /// where *H*<sub>*k*</sub> is the *k*th harmonic number and γ is Euler's constant. Up to
+    /// 80, the values of J<sub>2*k*</sub> that Miller's algorithm finds for [`j0`] are summed
+    /// in Neumann's series
+    ///
+    /// Y<sub>0</sub>(*x*) = (2/π) ((ln(*x*/2) + γ) J<sub>0</sub>(*x*) - 2 Σ
+    /// (-1)<sup>*k*</sup> J<sub>2*k*</sub>(*x*) / *k*),
+    ///
+    /// and past that, Hankel's expansion is used as it is for `j0`. The precision is the
+    /// same as that of `j0`, about 10<sup>-63</sup> in absolute terms, and like it, the
+    /// relative error grows close to the function's zeros.
+    ///
+    /// Y<sub>0</sub> is only defined for positive arguments; this returns NaN for negative
+    /// ones. Y<sub>0</sub>(0) = -∞ and Y<sub>0</sub>(∞) = 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1).y0();
+    /// let expected = qd!("0.08825696421567695798292676602351516282781752309067554671104384761199978");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`j0`]: #method.j0
+    pub fn y0(self) -> Quad {
+        match self.pre_y0() {
+            Some(r) => s::guard("Quad::y0", &[self.0], r),
+            None => {
+                if self.0 < SERIES_MAX {
+                    let (j, h) = series(self, 0);
+                    Quad::FRAC_2_PI * (log_term(self) * j - c::mul_pwr2(h, 0.5))
+                } else if self.0 < ASYMPTOTIC_MIN {
+                    let (j, _, s, _) = recurrence(self);
+                    Quad::FRAC_2_PI * (log_term(self) * j - c::mul_pwr2(s, 2.0))
+                } else {
+                    asymptotic(self, 0).1
+                }
+            }
+        }
+    }
+
+    /// Computes the Bessel function of the second kind of order one, Y<sub>1</sub>(*x*),
+    /// where *x* is this `Quad`.
+    ///
+    /// This is calculated the same way as [`y0`]: with the series
+    ///
+    /// Y<sub>1</sub>(*x*) = (2/π) (ln(*x*/2) + γ) J<sub>1</sub>(*x*) - 2 / π*x* - (*x* / 2π)
+    /// Σ (*H*<sub>*k*</sub> + *H*<sub>*k*+1</sub>) (-*x*²/4)<sup>*k*</sup> / (*k*! (*k* +
+    /// 1)!)
+    ///
+    /// for *x* < 4, with Neumann's series
+    ///
+    /// Y<sub>1</sub>(*x*) = (2/π) ((ln(*x*/2) + γ - 1) J<sub>1</sub>(*x*) - J<sub>0</sub>(*x*)
+    /// / *x* - Σ (-1)<sup>*k*</sup> (2*k* + 1) J<sub>2*k*+1</sub>(*x*) / *k*(*k* + 1))
+    ///
+    /// up to 80, and with Hankel's expansion past that. The precision is the same as that
+    /// of `y0`.
+    ///
+    /// Y<sub>1</sub> is only defined for positive arguments; this returns NaN for negative
+    /// ones. Y<sub>1</sub>(0) = -∞ and Y<sub>1</sub>(∞) = 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1).y1();
+    /// let expected = qd!("-0.7812128213002887165471500000479648205499063907164446078438332461277");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`y0`]: #method.y0
+    pub fn y1(self) -> Quad {
+        match self.pre_y1() {
+            Some(r) => s::guard("Quad::y1", &[self.0], r),
+            None => {
+                if self.0 < SERIES_MAX {
+                    let (a, h) = series(self, 1);
+                    let half = c::mul_pwr2(self, 0.5);
+                    let r = log_term(self) * half * a - c::mul_pwr2(half * h, 0.5);
+                    Quad::FRAC_2_PI * (r - self.recip())
+                } else if self.0 < ASYMPTOTIC_MIN {
+                    let (j0, j1, _, s) = recurrence(self);
+                    let r = (log_term(self) - Quad::ONE) * j1 - j0 / self - s;
+                    Quad::FRAC_2_PI * r
+                } else {
+                    asymptotic(self, 1).1
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn pre_j0(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_infinite() {
+            Some(Quad::ZERO)
+        } else if self.is_zero() {
+            Some(Quad::ONE)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_j1(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_infinite() {
+            Some(Quad::ZERO)
+        } else if self.is_zero() {
+            // Includes both zeros, which keep their signs
+            Some(*self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_y0(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(Quad::NEG_INFINITY)
+        } else if self.is_sign_negative() {
+            Some(Quad::NAN)
+        } else if self.is_infinite() {
+            Some(Quad::ZERO)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_y1(&self) -> Option<Quad> {
+        self.pre_y0()
+    }
+}
+
+// ln(x/2) + γ, the factor that multiplies J0 and J1 in the series for Y0 and Y1.
+fn log_term(x: Quad) -> Quad {
+    c::mul_pwr2(x, 0.5).ln() + Quad::EULER_GAMMA
+}
+
+// Computes the two sums
+//
+//      Σ (-x²/4)^k / (k! (k + n)!)   and   Σ (H_k + H_(k+n)) (-x²/4)^k / (k! (k + n)!),
+//
+// where H_k is the kth harmonic number (and H_0 = 0), for n = 0 or 1. The first is
+// J_n(x) / (x/2)^n, and the second is the part of the series for Y_n that isn't a multiple
+// of J_n. They're summed side by side so that they share their powers and factorials.
+fn series(x: Quad, n: usize) -> (Quad, Quad) {
+    let z = -c::mul_pwr2(x.sqr(), 0.25);
+    let mut t = Quad::ONE;
+    let mut sum = Quad::ONE;
+    let mut h = if n == 0 { Quad::ZERO } else { Quad::ONE };
+    let mut hsum = h;
+    let mut k = 0;
+    loop {
+        k += 1;
+        t = t * z / Quad((k * (k + n)) as f64, 0.0, 0.0, 0.0);
+        h += Quad(k as f64, 0.0, 0.0, 0.0).recip() + Quad((k + n) as f64, 0.0, 0.0, 0.0).recip();
+        let u = t * h;
+        sum += t;
+        hsum += u;
+        if t.0.abs() <= sum.0.abs() * Quad::EPSILON.0 && u.0.abs() <= hsum.0.abs() * Quad::EPSILON.0
+        {
+            break;
+        }
+    }
+    (sum, hsum)
+}
+
+// Runs the recurrence J_(n-1)(x) = (2n/x) J_n(x) - J_(n+1)(x) backwards, starting from
+// J_N = 1 and J_(N+1) = 0 for some large N, and scales the results so that
+//
+//      J_0(x) + 2 Σ J_2k(x) = 1.
+//
+// This is Miller's algorithm. The recurrence is stable in this direction, and whatever
+// error the made-up starting values bring dies out long before the low orders are
+// reached, as long as N is far enough past x. The N here was found by comparing results
+// against reference values, with some margin added.
+//
+// Besides J0 and J1, this returns the two sums in Neumann's series for Y0 and Y1,
+//
+//      Σ (-1)^k J_2k(x) / k   and   Σ (-1)^k (2k + 1) J_(2k+1)(x) / k(k + 1),
+//
+// over k >= 1, since they need every order that the recurrence runs through. At most
+// orders, the values are far too small to matter, but finding them is cheap next to the
+// divisions in the recurrence itself.
+fn recurrence(x: Quad) -> (Quad, Quad, Quad, Quad) {
+    let top = (x.0 + 50.0 + 11.0 * x.0.sqrt()) as i32 & !1;
+    let r = c::mul_pwr2(x.recip(), 2.0);
+    let mut above = Quad::ZERO;
+    let mut j = Quad::ONE;
+    let mut norm = Quad::ZERO;
+    let mut even = Quad::ZERO;
+    let mut odd = Quad::ZERO;
+    for n in (1..=top).rev() {
+        let k = n / 2;
+        let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+        if n % 2 == 0 {
+            norm += j;
+            even += j / Quad(sign * k as f64, 0.0, 0.0, 0.0);
+        } else if n > 1 {
+            let t = j.mul_i32(2 * k + 1);
+            odd += t / Quad(sign * (k * (k + 1)) as f64, 0.0, 0.0, 0.0);
+        }
+        let below = r.mul_i32(n) * j - above;
+        above = j;
+        j = below;
+    }
+    // j and above now hold J0 and J1, to within a common factor
+    let f = (c::mul_pwr2(norm, 2.0) + j).recip();
+    (j * f, above * f, even * f, odd * f)
+}
+
+// Computes J_n(x) and Y_n(x), for n = 0 or 1, with Hankel's asymptotic expansion
+//
+//      J_n(x) = √(2/πx) (P cos θ - Q sin θ),    Y_n(x) = √(2/πx) (P sin θ + Q cos θ),
+//
+// where θ = x - (2n + 1)π/4 and
+//
+//      P = Σ (-1)^k a_2k / x^2k,    Q = Σ (-1)^k a_(2k+1) / x^(2k+1),
+//      a_k = (4n² - 1²)(4n² - 3²) ··· (4n² - (2k - 1)²) / (k! 8^k).
+//
+// The expansion diverges, but its terms get smaller until k is about 2x, and by then
+// they're around e^(-2x), far smaller than needed.
+//
+// Subtracting (2n + 1)π/4 from x after rounding it would lose the phase's low bits just
+// where the functions cross zero, so x is reduced in quarter turns first, the same way
+// as it is for sin and cos, and the offset is taken out of the exact remainder.
+fn asymptotic(x: Quad, n: i32) -> (Quad, Quad) {
+    let mu = (4 * n * n) as f64;
+    let y = c::mul_pwr2(x, 8.0);
+    let mut p = Quad::ONE;
+    let mut q = Quad::ZERO;
+    let mut t = Quad::ONE;
+    let mut k = 1;
+    while t.0.abs() > Quad::EPSILON.0 {
+        let d = (2 * k - 1) as f64;
+        t = t * Quad(mu - d * d, 0.0, 0.0, 0.0) / y.mul_i32(k);
+        match k % 4 {
+            0 => p += t,
+            1 => q += t,
+            2 => p -= t,
+            _ => q -= t,
+        }
+        k += 1;
+    }
+
+    // x = (4m + j + k/2 + f)π/2, with |f| <= 1/4, and θ is x less n + 1/2 quarter turns.
+    // That leaves either f or f + 1/2 quarter turns once the whole ones are taken out,
+    // and in the second case, f + 1/2 is in [1/4, 3/4], so its sine and cosine are both
+    // far enough from zero that the rounding of the sum doesn't matter.
+    let mut f = [0.0; 4];
+    let (j, k) = r::quarter_turns(&x.to_array(), 2, &mut f);
+    let f = Quad(f[0], f[1], f[2], f[3]);
+    let (turns, f) = match k {
+        0 => (j - n - 1, f + Quad(0.5, 0.0, 0.0, 0.0)),
+        1 => (j - n, f),
+        _ => (j - n - 1, f),
+    };
+    let (s, c) = (f * Quad::FRAC_PI_2).sin_cos();
+    let (sin, cos) = match turns & 3 {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    };
+    let a = (Quad::FRAC_2_PI / x).sqrt();
+    (a * (p * cos - q * sin), a * (p * sin + q * cos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // j0 tests
+    test_all_prec!(
+        j0_half:
+            qd!("0.9384698072408129042284046735997126255689267970968215765547051680244834"),
+            qd!(0.5).j0(),
+            60;
+        j0_one:
+            qd!("0.7651976865579665514497175261026632209092742897553252418615475491192789"),
+            qd!(1).j0(),
+            60;
+        j0_five:
+            qd!("-0.1775967713143383043473970130747587110711303560085091289906582682081766"),
+            qd!(5).j0(),
+            60;
+        j0_zero_crossing:
+            qd!("0.00001326828430108156089351833445756548286514182296948710315168446086721732"),
+            qd!(2.4048).j0(),
+            58;
+        j0_ten:
+            qd!("-0.2459357644513483351977608624853287538296000728265665696991583936411653"),
+            qd!(10).j0(),
+            60;
+        j0_thirty:
+            qd!("-0.08636798358104021133596232449606394801664860872851939896178117088320021"),
+            qd!(30).j0(),
+            60;
+        j0_hundred:
+            qd!("0.01998585030422312242422839095084899068063357885902792955864211444722576"),
+            qd!(100).j0(),
+            60;
+        j0_million:
+            qd!("0.0003310430137398737409879630422196254358684414256256266263517419949960363"),
+            qd!(1e6).j0(),
+            60;
+    );
+    // j1 tests
+    test_all_prec!(
+        j1_half:
+            qd!("0.2422684576748738863839545761415316408006286544379597535069253058933598"),
+            qd!(0.5).j1(),
+            60;
+        j1_one:
+            qd!("0.4400505857449335159596822037189149131273723019927652511367581717801382"),
+            qd!(1).j1(),
+            60;
+        j1_five:
+            qd!("-0.3275791375914652220377343219101691327608499046240540186864806450648753"),
+            qd!(5).j1(),
+            60;
+        j1_ten:
+            qd!("0.04347274616886143666974876802585928830627286711859420813591432260098023"),
+            qd!(10).j1(),
+            60;
+        j1_thirty:
+            qd!("-0.1187510626166229365202342692403628911965218202283896287103206923462976"),
+            qd!(30).j1(),
+            60;
+        j1_hundred:
+            qd!("-0.07714535201411215803268549492723447021161166709924297160697339304148533"),
+            qd!(100).j1(),
+            60;
+        j1_million:
+            qd!("-0.0007259683568137630418525233817084984557759161252945989537015747447490473"),
+            qd!(1e6).j1(),
+            60;
+    );
+    // y0 tests
+    test_all_prec!(
+        y0_half:
+            qd!("-0.4445187335067065571483984750683319103735651244015110204148911793882397"),
+            qd!(0.5).y0(),
+            60;
+        y0_one:
+            qd!("0.08825696421567695798292676602351516282781752309067554671104384761199979"),
+            qd!(1).y0(),
+            60;
+        y0_five:
+            qd!("-0.3085176252490337800736489842120466113863470616273440437958514158982579"),
+            qd!(5).y0(),
+            60;
+        y0_ten:
+            qd!("0.05567116728359939142445987741019004814512811451690504408229791362192265"),
+            qd!(10).y0(),
+            60;
+        y0_thirty:
+            qd!("-0.1172957316866640252512478782318888905747373195833656892389737465315661"),
+            qd!(30).y0(),
+            60;
+        y0_hundred:
+            qd!("-0.07724431336508315225422822136719877050569898365663565477194277760765416"),
+            qd!(100).y0(),
+            60;
+        y0_million:
+            qd!("-0.0007259685223351791656827217433676865118005220235466405758355566515987695"),
+            qd!(1e6).y0(),
+            60;
+    );
+    // y1 tests
+    test_all_prec!(
+        y1_half:
+            qd!("-1.471472392670243069188584635323297453241088055435748322955922383406694"),
+            qd!(0.5).y1(),
+            60;
+        y1_one:
+            qd!("-0.7812128213002887165471500000479648205499063907164446078438332461277844"),
+            qd!(1).y1(),
+            60;
+        y1_five:
+            qd!("0.1478631433912268448010506754880372053734652184104756133006976595086883"),
+            qd!(5).y1(),
+            60;
+        y1_ten:
+            qd!("0.2490154242069538839232834746632228032604165430696584612469437410242888"),
+            qd!(10).y1(),
+            60;
+        y1_thirty:
+            qd!("0.08442557066174723489092290414965591415502932079085124666218363863386974"),
+            qd!(30).y1(),
+            60;
+        y1_hundred:
+            qd!("-0.02037231200275979330470393266641455974201865568903699045979928263155681"),
+            qd!(100).y1(),
+            60;
+        y1_million:
+            qd!("-0.0003310433767241762888635172378496795233716247934567466066273435319919048"),
+            qd!(1e6).y1(),
+            60;
+    );
+
+    test_all_exact!(
+        j0_zero:
+            Quad::ONE,
+            Quad::ZERO.j0();
+        j0_inf:
+            Quad::ZERO,
+            Quad::INFINITY.j0();
+        j0_neg_inf:
+            Quad::ZERO,
+            Quad::NEG_INFINITY.j0();
+        j0_nan:
+            Quad::NAN,
+            Quad::NAN.j0();
+        j1_zero:
+            Quad::ZERO,
+            Quad::ZERO.j1();
+        j1_neg_zero:
+            Quad::NEG_ZERO,
+            Quad::NEG_ZERO.j1();
+        j1_inf:
+            Quad::ZERO,
+            Quad::INFINITY.j1();
+        j1_nan:
+            Quad::NAN,
+            Quad::NAN.j1();
+        y0_zero:
+            Quad::NEG_INFINITY,
+            Quad::ZERO.y0();
+        y0_neg:
+            Quad::NAN,
+            qd!(-1).y0();
+        y0_inf:
+            Quad::ZERO,
+            Quad::INFINITY.y0();
+        y0_nan:
+            Quad::NAN,
+            Quad::NAN.y0();
+        y1_zero:
+            Quad::NEG_INFINITY,
+            Quad::ZERO.y1();
+        y1_neg:
+            Quad::NAN,
+            qd!(-1).y1();
+        y1_inf:
+            Quad::ZERO,
+            Quad::INFINITY.y1();
+        y1_nan:
+            Quad::NAN,
+            Quad::NAN.y1();
+    );
+    test!(j_symmetry: {
+        for &x in &[0.3, 2.4048, 3.99, 4.0, 17.5, 39.9, 79.9, 80.0, 1e5] {
+            let x = qd!(x);
+            assert!((-x).j0() == x.j0());
+            assert!((-x).j1() == -x.j1());
+        }
+    });
+    test!(wronskian: {
+        // J1(x) Y0(x) - J0(x) Y1(x) = 2 / πx, which checks the functions against each
+        // other on both sides of where the method changes
+        for &x in &[0.01, 1.7, 3.99, 4.0, 6.3, 25.1, 39.99, 40.0, 79.99, 80.0, 512.5, 1e9] {
+            let x = qd!(x);
+            let w = x.j1() * x.y0() - x.j0() * x.y1();
+            prec!(Quad::FRAC_2_PI / x, w, 60);
+        }
+    });
+}
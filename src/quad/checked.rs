@@ -0,0 +1,264 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::strict as s;
+use crate::quad::Quad;
+
+// Runs an operation and reports whether it produced NaN or an infinity from operands that
+// were all finite. Results from non-finite operands are never flagged, since they're
+// whatever that operand made them rather than a new problem.
+#[inline]
+fn overflowing(operands: &[Quad], op: impl FnOnce() -> Quad) -> (Quad, bool) {
+    let r = s::quietly(op);
+    (r, !r.is_finite() && operands.iter().all(|x| x.is_finite()))
+}
+
+#[inline]
+fn checked(operands: &[Quad], op: impl FnOnce() -> Quad) -> Option<Quad> {
+    match overflowing(operands, op) {
+        (_, true) => None,
+        (r, false) => Some(r),
+    }
+}
+
+impl Quad {
+    /// Adds another `Quad` to this one, returning `None` if the sum of two finite
+    /// numbers is not finite.
+    ///
+    /// Whether the sum overflowed is decided by the sum itself, so this returns `None`
+    /// exactly when `self + other` would be infinite even though neither operand is.
+    /// Otherwise the result is `Some(self + other)`, which is infinite or NaN if an operand
+    /// was.
+    ///
+    /// This doesn't panic even if the `strict` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::MAX.checked_add(Quad::MAX).is_none());
+    /// assert!(Quad::MAX.checked_add(-Quad::MAX) == Some(Quad::ZERO));
+    /// assert!(Quad::INFINITY.checked_add(qd!(1)) == Some(Quad::INFINITY));
+    /// ```
+    pub fn checked_add(self, other: Quad) -> Option<Quad> {
+        checked(&[self, other], || self + other)
+    }
+
+    /// Subtracts another `Quad` from this one, returning `None` if the difference of two
+    /// finite numbers is not finite.
+    ///
+    /// This is the same as [`checked_add`] but with subtraction.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!((-Quad::MAX).checked_sub(Quad::MAX).is_none());
+    /// assert!(Quad::MAX.checked_sub(Quad::MAX) == Some(Quad::ZERO));
+    /// ```
+    ///
+    /// [`checked_add`]: #method.checked_add
+    pub fn checked_sub(self, other: Quad) -> Option<Quad> {
+        checked(&[self, other], || self - other)
+    }
+
+    /// Multiplies this `Quad` by another, returning `None` if the product of two finite
+    /// numbers is not finite.
+    ///
+    /// A product that underflows to zero isn't an error; it's returned as `Some`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::MAX.checked_mul(qd!(2)).is_none());
+    /// assert!(Quad::MAX.checked_mul(qd!(0.5)).is_some());
+    /// assert!(Quad::MIN_POSITIVE.checked_mul(Quad::MIN_POSITIVE) == Some(Quad::ZERO));
+    /// ```
+    pub fn checked_mul(self, other: Quad) -> Option<Quad> {
+        checked(&[self, other], || self * other)
+    }
+
+    /// Divides this `Quad` by another, returning `None` if the quotient of two finite
+    /// numbers is not finite.
+    ///
+    /// That includes division by zero, whether it would have produced an infinity or (for
+    /// zero divided by zero) NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::MAX.checked_div(qd!(0.5)).is_none());
+    /// assert!(qd!(1).checked_div(Quad::ZERO).is_none());
+    /// assert!(Quad::ZERO.checked_div(Quad::ZERO).is_none());
+    /// assert!(qd!(1).checked_div(Quad::INFINITY) == Some(Quad::ZERO));
+    /// ```
+    pub fn checked_div(self, other: Quad) -> Option<Quad> {
+        checked(&[self, other], || self / other)
+    }
+
+    /// Calculates the square root of the `Quad`, returning `None` if the number is
+    /// finite and negative.
+    ///
+    /// Negative zero is not an error; its square root is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(-1).checked_sqrt().is_none());
+    /// assert!(qd!(4).checked_sqrt() == Some(qd!(2)));
+    /// assert!(Quad::NAN.checked_sqrt().unwrap().is_nan());
+    /// ```
+    pub fn checked_sqrt(self) -> Option<Quad> {
+        checked(&[self], || self.sqrt())
+    }
+
+    /// Calculates the natural logarithm of the `Quad`, returning `None` if the number is
+    /// finite and not positive.
+    ///
+    /// The logarithm of zero would be negative infinity, so it's `None` as well.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(-1).checked_ln().is_none());
+    /// assert!(Quad::ZERO.checked_ln().is_none());
+    /// assert!(Quad::E.checked_ln().is_some());
+    /// assert!(Quad::INFINITY.checked_ln() == Some(Quad::INFINITY));
+    /// ```
+    pub fn checked_ln(self) -> Option<Quad> {
+        checked(&[self], || self.ln())
+    }
+
+    /// Adds another `Quad` to this one, returning the sum along with whether the sum of
+    /// two finite numbers was not finite.
+    ///
+    /// The sum is the same as `self + other`. The flag is `true` exactly when
+    /// [`checked_add`] would return `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::MAX.overflowing_add(Quad::MAX) == (Quad::INFINITY, true));
+    /// assert!(qd!(1).overflowing_add(qd!(2)) == (qd!(3), false));
+    /// ```
+    ///
+    /// [`checked_add`]: #method.checked_add
+    pub fn overflowing_add(self, other: Quad) -> (Quad, bool) {
+        overflowing(&[self, other], || self + other)
+    }
+
+    /// Subtracts another `Quad` from this one, returning the difference along with
+    /// whether the difference of two finite numbers was not finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!((-Quad::MAX).overflowing_sub(Quad::MAX) == (Quad::NEG_INFINITY, true));
+    /// ```
+    pub fn overflowing_sub(self, other: Quad) -> (Quad, bool) {
+        overflowing(&[self, other], || self - other)
+    }
+
+    /// Multiplies this `Quad` by another, returning the product along with whether the
+    /// product of two finite numbers was not finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::MAX.overflowing_mul(qd!(-2)) == (Quad::NEG_INFINITY, true));
+    /// ```
+    pub fn overflowing_mul(self, other: Quad) -> (Quad, bool) {
+        overflowing(&[self, other], || self * other)
+    }
+
+    /// Divides this `Quad` by another, returning the quotient along with whether the
+    /// quotient of two finite numbers was not finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let (q, flag) = Quad::ZERO.overflowing_div(Quad::ZERO);
+    /// assert!(q.is_nan() && flag);
+    /// assert!(qd!(1).overflowing_div(qd!(4)) == (qd!(0.25), false));
+    /// ```
+    pub fn overflowing_div(self, other: Quad) -> (Quad, bool) {
+        overflowing(&[self, other], || self / other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // add tests
+    test_all_assert!(
+        add_max: Quad::MAX.checked_add(Quad::MAX).is_none();
+        add_neg_max: (-Quad::MAX).checked_add(-Quad::MAX).is_none();
+        add_boundary:
+            Quad::MAX.checked_add(Quad::new(1e292, 0.0, 0.0, 0.0)).is_none()
+                && Quad::MAX.checked_add(Quad::ONE) == Some(Quad::MAX);
+        add_finite: Quad::MAX.checked_add(-Quad::ONE) == Some(Quad::MAX - Quad::ONE);
+        add_inf: Quad::INFINITY.checked_add(Quad::MAX) == Some(Quad::INFINITY);
+        add_nan: Quad::NAN.checked_add(Quad::ONE).unwrap().is_nan();
+        add_inf_nan: (Quad::INFINITY.checked_add(Quad::NEG_INFINITY).unwrap()).is_nan();
+        add_overflowing:
+            Quad::MAX.overflowing_add(Quad::MAX) == (Quad::INFINITY, true)
+                && Quad::INFINITY.overflowing_add(Quad::MAX) == (Quad::INFINITY, false);
+    );
+
+    // sub tests
+    test_all_assert!(
+        sub_max: (-Quad::MAX).checked_sub(Quad::MAX).is_none();
+        sub_finite: Quad::MAX.checked_sub(Quad::MAX) == Some(Quad::ZERO);
+        sub_inf: Quad::ONE.checked_sub(Quad::INFINITY) == Some(Quad::NEG_INFINITY);
+        sub_overflowing:
+            Quad::MAX.overflowing_sub(-Quad::MAX) == (Quad::INFINITY, true);
+    );
+
+    // mul tests
+    test_all_assert!(
+        mul_max: Quad::MAX.checked_mul(qd!(2)).is_none();
+        mul_neg_max: Quad::MAX.checked_mul(qd!(-1.5)).is_none();
+        mul_finite: Quad::MAX.checked_mul(qd!(0.5)) == Some(Quad::MAX * qd!(0.5));
+        mul_underflow: Quad::MIN_POSITIVE.checked_mul(Quad::MIN_POSITIVE) == Some(Quad::ZERO);
+        mul_inf: Quad::INFINITY.checked_mul(qd!(2)) == Some(Quad::INFINITY);
+        mul_inf_zero: Quad::INFINITY.checked_mul(Quad::ZERO).unwrap().is_nan();
+        mul_overflowing: Quad::MAX.overflowing_mul(qd!(2)) == (Quad::INFINITY, true);
+    );
+
+    // div tests
+    test_all_assert!(
+        div_max: Quad::MAX.checked_div(qd!(0.5)).is_none();
+        div_small: Quad::MAX.checked_div(Quad::MIN_POSITIVE).is_none();
+        div_zero: Quad::ONE.checked_div(Quad::ZERO).is_none();
+        div_neg_zero: Quad::ONE.checked_div(Quad::NEG_ZERO).is_none();
+        div_zero_zero: Quad::ZERO.checked_div(Quad::ZERO).is_none();
+        div_finite: Quad::ONE.checked_div(qd!(3)) == Some(Quad::ONE / qd!(3));
+        div_inf: Quad::ONE.checked_div(Quad::INFINITY) == Some(Quad::ZERO);
+        div_inf_zero: Quad::INFINITY.checked_div(Quad::ZERO) == Some(Quad::INFINITY);
+        div_overflowing:
+            Quad::ONE.overflowing_div(Quad::ZERO) == (Quad::INFINITY, true)
+                && Quad::ONE.overflowing_div(qd!(2)) == (qd!(0.5), false);
+    );
+
+    // sqrt tests
+    test_all_assert!(
+        sqrt_neg: qd!(-1).checked_sqrt().is_none();
+        sqrt_tiny_neg: (-Quad::MIN_POSITIVE).checked_sqrt().is_none();
+        sqrt_neg_zero: Quad::NEG_ZERO.checked_sqrt() == Some(Quad::ZERO);
+        sqrt_max: Quad::MAX.checked_sqrt() == Some(Quad::MAX.sqrt());
+        sqrt_inf: Quad::INFINITY.checked_sqrt() == Some(Quad::INFINITY);
+        sqrt_neg_inf: Quad::NEG_INFINITY.checked_sqrt().unwrap().is_nan();
+    );
+
+    // ln tests
+    test_all_assert!(
+        ln_neg: qd!(-1).checked_ln().is_none();
+        ln_zero: Quad::ZERO.checked_ln().is_none();
+        ln_neg_zero: Quad::NEG_ZERO.checked_ln().is_none();
+        ln_min: Quad::MIN_POSITIVE.checked_ln() == Some(Quad::MIN_POSITIVE.ln());
+        ln_max: Quad::MAX.checked_ln() == Some(Quad::MAX.ln());
+        ln_inf: Quad::INFINITY.checked_ln() == Some(Quad::INFINITY);
+        ln_nan: Quad::NAN.checked_ln().unwrap().is_nan();
+    );
+}
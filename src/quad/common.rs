@@ -3,21 +3,66 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::primitive as p;
+use crate::common::utils as u;
 use crate::quad::Quad;
 
 #[inline]
-pub fn mul_pwr2(a: Quad, n: f64) -> Quad {
+pub const fn mul_pwr2(a: Quad, n: f64) -> Quad {
     Quad(a.0 * n, a.1 * n, a.2 * n, a.3 * n)
 }
 
+/// Multiplies a `Quad` by 2<sup>*n*</sup>. The power is split into two factors, so unlike
+/// `ldexp` this works for any *n* that leaves the result finite and nonzero, even when
+/// 2<sup>*n*</sup> itself isn't representable as an `f64`.
+#[inline]
+pub const fn scale(a: Quad, n: i32) -> Quad {
+    let h = n / 2;
+    mul_pwr2(mul_pwr2(a, u::pow2(h)), u::pow2(n - h))
+}
+
+/// Finishes an operation on finite operands whose result came out infinite or NaN. That
+/// usually means that the result really is too large to represent, but intermediate values
+/// can overflow when the result itself is just short of that. So the operation is redone at
+/// half scale (by `half`) and the result doubled, unless `half_lead`, the first component
+/// of the operation at half scale, says that it overflows even then. Either way a result
+/// that doesn't fit is the infinity of the appropriate sign, never NaN.
+///
+/// This is a macro rather than a function that takes `half` as a closure because the
+/// operations that use it are `const fn`s, which can't call closures. `half` is still only
+/// evaluated when `half_lead` is finite.
+macro_rules! redo_halved {
+    ($half_lead:expr, $half:expr) => {{
+        let half_lead: f64 = $half_lead;
+        let r = if half_lead.is_finite() {
+            $crate::quad::common::mul_pwr2($half, 2.0)
+        } else {
+            $crate::quad::Quad::NAN
+        };
+        if r.0.is_finite() {
+            r
+        } else if half_lead > 0.0 {
+            $crate::quad::Quad::INFINITY
+        } else {
+            $crate::quad::Quad::NEG_INFINITY
+        }
+    }};
+}
+pub(crate) use redo_halved;
+
 /// Reciprocals of factorials, rendered as Quads. These are used in Taylor series
 /// calculations.
+///
+/// `INV_FACTS[k]` is 1/(k + 3)!, for k from 0 through 14, so the table ends with 1/17!.
+/// Each entry is the exact reciprocal rendered one component at a time, each component being
+/// the `f64` closest to what the components before it leave over. The series that use the
+/// table iterate over it rather than indexing into it, so none of them can run past its end.
 pub const INV_FACTS: [Quad; 15] = [
     Quad(
         1.6666666666666666e-1,
         9.25185853854297e-18,
         5.135813185032629e-34,
-        2.8509490240983423e-50,
+        2.850949024098342e-50,
     ),
     Quad(
         4.1666666666666664e-2,
@@ -29,7 +74,7 @@ pub const INV_FACTS: [Quad; 15] = [
         8.333333333333333e-3,
         1.1564823173178714e-19,
         1.6049416203226965e-36,
-        2.22730392507682e-53,
+        2.2273039250768297e-53,
     ),
     Quad(
         1.388888888888889e-3,
@@ -41,131 +86,168 @@ pub const INV_FACTS: [Quad; 15] = [
         1.984126984126984e-4,
         1.7209558293420705e-22,
         1.4926912391394127e-40,
-        1.2947032674766573e-58,
+        1.2947032674600247e-58,
     ),
     Quad(
         2.48015873015873e-5,
         2.1511947866775882e-23,
         1.865864048924266e-41,
-        1.6183790843843425e-59,
+        1.6183790843250309e-59,
     ),
     Quad(
         2.7557319223985893e-6,
         -1.858393274046472e-22,
         8.491754604881993e-39,
-        -5.726616407894298e-55,
+        -5.726616407894296e-55,
     ),
     Quad(
         2.755731922398589e-7,
         2.3767714622250297e-23,
         -3.263188903340883e-40,
-        1.6143511186040432e-56,
+        1.6143511186040442e-56,
     ),
     Quad(
         2.505210838544172e-8,
         -1.448814070935912e-24,
         2.0426735146714455e-41,
-        -8.49632672007163e-58,
+        -8.496326720071632e-58,
     ),
     Quad(
         2.08767569878681e-9,
         -1.20734505911326e-25,
         1.702227928892871e-42,
-        1.4160953215039672e-58,
+        1.416095321503967e-58,
     ),
     Quad(
         1.6059043836821613e-10,
         1.2585294588752098e-26,
         -5.31334602762985e-43,
-        3.540214725976055e-59,
+        3.5402147259760553e-59,
     ),
     Quad(
         1.1470745597729725e-11,
         2.0655512752830745e-28,
         6.889079232466646e-45,
-        5.7292000265510916e-61,
+        5.729200026551091e-61,
     ),
     Quad(
         7.647163731819816e-13,
         7.03872877733453e-30,
         -7.827539277162583e-48,
-        1.9213864944378356e-64,
+        1.9213864944379024e-64,
     ),
     Quad(
         4.779477332387385e-14,
         4.399205485834081e-31,
         -4.892212048226615e-49,
-        1.2008665590236055e-65,
+        1.200866559023689e-65,
     ),
     Quad(
         2.8114572543455206e-15,
         1.6508842730861433e-31,
         -2.877771793074479e-50,
-        4.271106892562081e-67,
+        4.2711068925629355e-67,
     ),
 ];
 
+/// The number of steps that sin and cos divide π into when they reduce their arguments.
+/// Past a multiple of π/2, an argument is reduced to *t* + *k*π/1024, where *k* is in
+/// [-256, 256] and *t* is about half of [`TRIG_STEP`] at most, and the sine and cosine of
+/// *k*π/1024 are looked up in [`SINES`] and [`COSINES`].
+pub const TRIG_STEPS: usize = 1024;
+
+/// The size of the steps that sin and cos reduce their arguments by, π/[`TRIG_STEPS`].
+///
+/// The multiple of the step is chosen from the quotient of the first components of the
+/// argument and the step. That quotient is no more than `TRIG_STEPS / 4`, so its rounding
+/// errors come to less than `TRIG_STEPS` units of 2<sup>-52</sup> of a step, and the
+/// reduced argument can land that far past half a step. Its magnitude is therefore never
+/// more than π/2048 by more than `TRIG_STEPS * f64::EPSILON` times the step.
+pub const TRIG_STEP: Quad = Quad(
+    3.067_961_575_771_282_3e-3,
+    1.195_944_139_792_337e-19,
+    -2.924_579_892_303_066e-36,
+    1.086_381_075_061_880_2e-52,
+);
+
+/// π/180, the number of radians in a degree.
+pub const RAD_PER_DEG: Quad = Quad(
+    1.7453292519943295e-2,
+    2.9486522708701687e-19,
+    -1.3427726813345382e-35,
+    1.4287195201881262e-52,
+);
+
+/// 180/π, the number of degrees in a radian.
+pub const DEG_PER_RAD: Quad = Quad(
+    5.729577951308232e1,
+    -1.9878495670576283e-15,
+    -1.6833394980391744e-31,
+    -5.5659577936984464e-49,
+);
+
 // Table of sin(kπ/1024), for k in [1, 256]
-pub const SINES: [Quad; 256] = [
+#[allow(clippy::approx_constant)]
+pub const SINES: [Quad; TRIG_STEPS / 4] = [
     Quad(
         3.067956762965976e-3,
         1.2690279085455925e-19,
         5.287946424532839e-36,
-        -1.7820334081955295e-52,
+        -1.78203340819553e-52,
     ),
     Quad(
         6.135884649154475e-3,
         9.054525748247493e-20,
         1.626011313374532e-37,
-        -9.749200120876422e-55,
+        -9.749200120876741e-55,
     ),
     Quad(
         9.20375478205982e-3,
         -1.2136591693535934e-19,
         5.569690394942557e-36,
-        1.2505635791936973e-52,
+        1.250563579193695e-52,
     ),
     Quad(
         1.2271538285719925e-2,
         6.919790764028317e-19,
         -4.0203726713435555e-36,
-        -2.0688703606952494e-52,
+        -2.0688703606952816e-52,
     ),
     Quad(
         1.5339206284988102e-2,
         -8.44625788654017e-19,
         4.653589750505863e-35,
-        -1.392368297857049e-51,
+        -1.3923682978570467e-51,
     ),
     Quad(
         1.840672990580482e-2,
         7.419553381283316e-19,
         3.9068476486787607e-35,
-        3.6393321292898124e-52,
+        3.6393321292898614e-52,
     ),
     Quad(
         2.1474080275469508e-2,
         -4.540796020768857e-19,
         -2.2031770119723005e-35,
-        1.2709814654833732e-51,
+        1.2709814654833741e-51,
     ),
     Quad(
         2.4541228522912288e-2,
         -9.186849012577878e-20,
         4.870614870446706e-36,
-        -2.8153947855469075e-52,
+        -2.8153947855469224e-52,
     ),
     Quad(
         2.7608145778965743e-2,
         -1.593235883138927e-18,
         -7.047541624277603e-35,
-        -2.751849417660274e-51,
+        -2.7518494176602744e-51,
     ),
     Quad(
         3.0674803176636626e-2,
         -1.693605484410792e-20,
         1.0053112316608014e-36,
-        4.3727432941767413e-54,
+        4.372743294180265e-54,
     ),
     Quad(
         3.374117185137759e-2,
@@ -177,385 +259,385 @@ pub const SINES: [Quad; 256] = [
         3.680722294135883e-2,
         6.106008880352984e-19,
         -4.0448721259852727e-35,
-        -2.1111056765671487e-51,
+        -2.1111056765671495e-51,
     ),
     Quad(
         3.987292758773981e-2,
         4.665745348118329e-19,
         3.4119333562288684e-35,
-        2.40075347261875e-51,
+        2.400753472618751e-51,
     ),
     Quad(
         4.293825693494082e-2,
         2.8351940588660907e-18,
         1.6991309601186475e-34,
-        6.802653609867265e-51,
+        6.802653609867263e-51,
     ),
     Quad(
         4.600318213091463e-2,
         -1.1182813940157788e-18,
         7.523502027037895e-35,
-        4.1187304955493796e-52,
+        4.118730495549372e-52,
     ),
     Quad(
         4.9067674327418015e-2,
         -6.79610372051828e-19,
         -4.4318868124718325e-35,
-        -9.937662813252524e-52,
+        -9.937662813252532e-52,
     ),
     Quad(
         5.2131704680283324e-2,
         -2.424369529195378e-18,
         -1.3675405320092298e-34,
-        -8.393813762114509e-51,
+        -8.393813762114507e-51,
     ),
     Quad(
         5.519524434968994e-2,
         -1.3340299860891103e-18,
         -3.435957412566561e-35,
-        1.191146275540942e-51,
+        1.1911462755409369e-51,
     ),
     Quad(
         5.825826450043576e-2,
         2.329990549607749e-19,
         1.937610899062866e-36,
-        -5.127377571009318e-53,
+        -5.12737757100953e-53,
     ),
     Quad(
         6.132073630220858e-2,
         -5.118113406463811e-19,
         -4.2726335866706313e-35,
-        2.6368495557440717e-51,
+        2.636849555744069e-51,
     ),
     Quad(
         6.438263092985747e-2,
         -4.2325997000052705e-18,
         3.3260117711855937e-35,
-        1.4736267706718376e-51,
+        1.4736267706718352e-51,
     ),
     Quad(
         6.744391956366406e-2,
         -6.9221796556983636e-18,
         1.590928635891104e-34,
-        -7.882894689183518e-51,
+        -7.882894689183522e-51,
     ),
     Quad(
         7.050457338961387e-2,
         -6.855279110734288e-18,
         -1.996117763084158e-34,
-        2.0127129580485298e-50,
+        2.01271295804853e-50,
     ),
     Quad(
         7.356456359966743e-2,
         -2.7784941506273593e-18,
         -9.124037548985282e-35,
-        -1.9589752023546822e-51,
+        -1.9589752023546795e-51,
     ),
     Quad(
         7.662386139203149e-2,
         2.32537002879588e-19,
         -1.318608392121344e-36,
-        -4.992787260809975e-53,
+        -4.992787260809967e-53,
     ),
     Quad(
         7.968243797143013e-2,
         -4.486766431137304e-18,
         2.8540789143650264e-34,
-        2.8491348583262765e-51,
+        2.849134858326274e-51,
     ),
     Quad(
         8.274026454937569e-2,
         1.473598353087776e-18,
         3.7284093452233713e-35,
-        2.9024430036723684e-52,
+        2.9024430036724088e-52,
     ),
     Quad(
         8.57973123444399e-2,
         -3.388189383068403e-18,
         -1.6135529531508258e-34,
-        7.729465162058808e-51,
+        7.729465162058805e-51,
     ),
     Quad(
         8.88535525825246e-2,
         -3.750177583029069e-18,
         3.7543606373911578e-34,
-        -2.0530533507023269e-50,
+        -2.053053350702327e-50,
     ),
     Quad(
         9.190895649713272e-2,
         4.7631594854274564e-18,
         1.5722874642939344e-34,
-        -4.8464145447831486e-51,
+        -4.8464145447831456e-51,
     ),
     Quad(
         9.4963495329639e-2,
         -6.5885886400417564e-18,
         -2.1371116991641965e-34,
-        1.3819370559249302e-50,
+        1.38193705592493e-50,
     ),
     Quad(
         9.80171403295606e-2,
         -1.634582362244256e-18,
         -1.3209238810006454e-35,
-        -3.569106004911789e-52,
+        -3.569106004911794e-52,
     ),
     Quad(
         1.0106986275482782e-1,
         3.3164325719308656e-18,
         -1.2004224885132282e-34,
-        7.202882849541882e-51,
+        7.202882849541863e-51,
     ),
     Quad(
         1.0412163387205457e-1,
         6.57602540853851e-18,
         1.7066246171219214e-34,
-        -4.949934099689357e-51,
+        -4.9499340996893514e-51,
     ),
     Quad(
         1.0717242495680884e-1,
         6.44240442790262e-18,
         -8.395697649969814e-35,
-        -4.066773021331873e-51,
+        -4.066773021331832e-51,
     ),
     Quad(
         1.1022220729388306e-1,
         -5.678950353782323e-19,
         1.0380274792383233e-35,
-        1.5213997918457891e-52,
+        1.5213997918456695e-52,
     ),
     Quad(
         1.1327095217756435e-1,
         2.71004810121329e-18,
         1.532329299949162e-35,
-        4.956443281036479e-52,
+        4.956443281036088e-52,
     ),
     Quad(
         1.1631863091190477e-1,
         1.0294914877509705e-18,
         -9.397573494899304e-35,
-        1.3534827323718545e-52,
+        1.3534827323719708e-52,
     ),
     Quad(
         1.1936521481099137e-1,
         -3.9500089391898506e-18,
         3.531734997822731e-34,
-        1.8856046807012723e-51,
+        1.8856046807012275e-51,
     ),
     Quad(
         1.224106751992162e-1,
         2.8354501489965335e-18,
         1.8151655751493305e-34,
-        -2.8716592177915257e-51,
+        -2.871659217791519e-51,
     ),
     Quad(
         1.2545498341154623e-1,
         4.8686751763148235e-18,
         5.987810525809794e-35,
-        -3.353462909872179e-51,
+        -3.3534629098722107e-51,
     ),
     Quad(
         1.2849811079379317e-1,
         3.81986039549888e-18,
         -1.8627501455947798e-34,
-        -2.4308161133527975e-51,
+        -2.430816113352779e-51,
     ),
     Quad(
         1.3154002870288312e-1,
         -5.003970826221381e-18,
         -1.2983004159245552e-34,
-        -4.687203491579388e-51,
+        -4.687203491579412e-51,
     ),
     Quad(
         1.345807085071262e-1,
         -9.16703591714807e-18,
         1.5916493007073973e-34,
-        4.023700248436679e-51,
+        4.023700248436683e-51,
     ),
     Quad(
         1.3762012158648604e-1,
         6.625325586677448e-18,
         -2.374658303140146e-34,
-        -9.370387617309003e-52,
+        -9.370387617309325e-52,
     ),
     Quad(
         1.4065823933284924e-1,
         -7.919393296552474e-18,
         6.09724642021084e-34,
-        2.4566623241035754e-50,
+        2.4566623241035797e-50,
     ),
     Quad(
         1.4369503315029444e-1,
         1.1472723016618666e-17,
         -5.188495455757643e-35,
-        -4.2220684832186815e-51,
+        -4.2220684832186607e-51,
     ),
     Quad(
         1.4673047445536175e-1,
         3.726947147046568e-18,
         3.7352398151250827e-34,
-        -4.0881822289508557e-51,
+        -4.0881822289508634e-51,
     ),
     Quad(
         1.497645346773215e-1,
         8.081211413128515e-18,
         1.2979142554917325e-34,
-        9.93806674877366e-51,
+        9.938066748773625e-51,
     ),
     Quad(
         1.5279718525844344e-1,
         -7.631357393841684e-18,
         5.7714690450284125e-34,
-        -3.7731132582986663e-50,
+        -3.7731132582986687e-50,
     ),
     Quad(
         1.5582839765426523e-1,
         3.035130718767822e-18,
         -1.0976942315176184e-34,
-        7.873464768525745e-51,
+        7.873464768525787e-51,
     ),
     Quad(
         1.5885814333386145e-1,
         -4.016320057385908e-18,
         -9.284058025762881e-35,
-        -2.856742002927449e-51,
+        -2.8567420029274875e-51,
     ),
     Quad(
         1.6188639378011183e-1,
         1.1850519643573528e-17,
         -5.044099051916296e-34,
-        3.051002870792798e-50,
+        3.051002870792801e-50,
     ),
     Quad(
         1.6491312048996992e-1,
         -7.040528831916674e-19,
         3.3211107491245527e-35,
-        8.666329925468717e-52,
+        8.666329925468603e-52,
     ),
     Quad(
         1.6793829497473117e-1,
         5.428453372155814e-18,
         -3.326333933618137e-34,
-        -1.8536367335123897e-50,
+        -1.8536367335123848e-50,
     ),
     Quad(
         1.7096188876030122e-1,
         9.19199801817591e-18,
         -6.7688743940982606e-34,
-        -1.0377711384318362e-50,
+        -1.0377711384318389e-50,
     ),
     Quad(
         1.7398387338746382e-1,
         5.815199461810793e-18,
         -1.6751014298301606e-34,
-        -6.698225979716456e-51,
+        -6.698225979716496e-51,
     ),
     Quad(
         1.7700422041214875e-1,
         6.732930063540817e-18,
         2.8042736644246623e-34,
-        3.678688823279387e-51,
+        3.67868882327936e-51,
     ),
     Quad(
         1.8002290140569951e-1,
         7.970182604739214e-18,
         -7.076592011052498e-34,
-        1.9622512608461807e-50,
+        1.9622512608461784e-50,
     ),
     Quad(
         1.8303988795514095e-1,
         7.734991868863738e-18,
         -4.480376996814508e-34,
-        1.120114879332894e-50,
+        1.120114879332889e-50,
     ),
     Quad(
         1.8605515166344666e-1,
         -1.2564893007679552e-17,
         7.595384424853081e-34,
-        -3.847169513241551e-51,
+        -3.847169513241504e-51,
     ),
     Quad(
         1.8906866414980622e-1,
         -7.620895580352778e-18,
         -4.479229865666299e-34,
-        4.139164662630522e-50,
+        4.1391646626305254e-50,
     ),
     Quad(
         1.9208039704989244e-1,
         4.33483439411749e-18,
         -2.3404121848139937e-34,
-        1.5789970962611875e-50,
+        1.5789970962611856e-50,
     ),
     Quad(
         1.9509032201612828e-1,
         -7.991079068461731e-18,
         6.184627002422071e-34,
-        -3.584027091803293e-50,
+        -3.5840270918032937e-50,
     ),
     Quad(
         1.980984107179536e-1,
         -1.8434411800689445e-18,
         1.4139031318237285e-34,
-        1.0542811125343775e-50,
+        1.0542811125343809e-50,
     ),
     Quad(
         2.011046348420919e-1,
         1.1010032669300739e-17,
         -3.912357675741379e-34,
-        2.4084852500063748e-51,
+        2.408485250006353e-51,
     ),
     Quad(
         2.0410896609281687e-1,
         6.094129777395775e-18,
         -2.827540997044964e-34,
-        4.610100856353328e-51,
+        4.610100856353299e-51,
     ),
     Quad(
         2.0711137619221856e-1,
         -1.0613362528971356e-17,
         2.2456805112690884e-34,
-        1.3483736125280926e-50,
+        1.3483736125280904e-50,
     ),
     Quad(
         2.101118368804696e-1,
         1.1561548476512844e-17,
         6.035590561040125e-34,
-        3.3329909618405684e-50,
+        3.3329909618405675e-50,
     ),
     Quad(
         2.1311031991609136e-1,
         1.203187382106386e-17,
         -3.4142699719695635e-34,
-        -1.243626278024175e-50,
+        -1.2436262780241778e-50,
     ),
     Quad(
         2.1610679707621952e-1,
         -1.0111196082609117e-17,
         7.278954533518964e-34,
-        -2.9347540365258595e-50,
+        -2.934754036525861e-50,
     ),
     Quad(
         2.191012401568698e-1,
         -3.6513812299150776e-19,
         -2.3359499418606442e-35,
-        3.1785298198457637e-52,
+        3.1785298198458653e-52,
     ),
     Quad(
         2.2209362097320354e-1,
         -3.0337210995812162e-18,
         6.6654668033633e-35,
-        2.0110862322656835e-51,
+        2.0110862322656942e-51,
     ),
     Quad(
         2.2508391135979283e-1,
         3.950704082255651e-18,
         2.4287993958305375e-35,
-        5.6662797513025196e-52,
+        5.666279751302032e-52,
     ),
     Quad(
         2.2807208317088573e-1,
         8.236183733925801e-18,
         6.978678131639794e-34,
-        -6.412296248263911e-51,
+        -6.41229624826395e-51,
     ),
     Quad(
         2.310581082806711e-1,
@@ -567,31 +649,31 @@ pub const SINES: [Quad; 256] = [
         2.3404195858354343e-1,
         -6.992240269610117e-18,
         -5.732303192275028e-34,
-        5.309257996687299e-51,
+        5.3092579966872727e-51,
     ),
     Quad(
         2.370236059943672e-1,
         8.854485228503992e-18,
         1.3588480826354134e-34,
-        1.0381022520213889e-50,
+        1.0381022520213867e-50,
     ),
     Quad(
         2.400030224487415e-1,
         -1.2137758975632164e-17,
         -2.644880773170389e-34,
-        -1.992973380067015e-51,
+        -1.9929733800670473e-51,
     ),
     Quad(
         2.429801799032639e-1,
         -8.751431529719663e-18,
         -6.572326037307943e-34,
-        -1.0333158083172172e-50,
+        -1.0333158083172177e-50,
     ),
     Quad(
         2.4595505033579462e-1,
         -1.1129044052741832e-17,
         4.38059982028834e-34,
-        1.2219399554686294e-50,
+        1.221939955468629e-50,
     ),
     Quad(
         2.4892760574572018e-1,
@@ -603,25 +685,25 @@ pub const SINES: [Quad; 256] = [
         2.5189781815421697e-1,
         -1.759143603251704e-17,
         -1.0959681232525285e-33,
-        5.620942602023243e-50,
+        5.620942602023246e-50,
     ),
     Quad(
         2.5486565960451457e-1,
         -1.3602299806901461e-19,
         -6.0073844642762535e-36,
-        -3.007275131189635e-52,
+        -3.007275131189388e-52,
     ),
     Quad(
         2.57831102162159e-1,
         1.8480038630879957e-17,
         3.32016647140476e-34,
-        -5.554781929057646e-51,
+        -5.554781929057676e-51,
     ),
     Quad(
         2.607941179152755e-1,
         4.2721420983550075e-18,
         5.678212693477792e-35,
-        3.142833808436547e-51,
+        3.1428338084365397e-51,
     ),
     Quad(
         2.637546789748314e-1,
@@ -633,109 +715,109 @@ pub const SINES: [Quad; 256] = [
         2.6671275747489837e-1,
         2.0941222578826688e-17,
         -1.130346652472799e-33,
-        1.9954224050508953e-50,
+        1.9954224050508963e-50,
     ),
     Quad(
         2.696683255729151e-1,
         1.576565761813326e-17,
         -6.969614217337009e-34,
-        -4.0455346879146766e-50,
+        -4.0455346879146776e-50,
     ),
     Quad(
         2.72621355449949e-1,
         7.869716607638785e-18,
         6.617938860293337e-35,
-        -2.764290369638619e-51,
+        -2.7642903696386267e-51,
     ),
     Quad(
         2.7557181931095814e-1,
         1.9320328962556582e-17,
         1.393209418010028e-33,
-        1.361725392001808e-50,
+        1.3617253920018116e-50,
     ),
     Quad(
         2.785196893850531e-1,
         -1.0030273719543544e-17,
         7.259211532568925e-34,
-        -1.006851629665586e-50,
+        -1.0068516296655851e-50,
     ),
     Quad(
         2.81464937925758e-1,
         -1.232229964127401e-17,
         -1.0564788706386435e-34,
-        7.513742425126599e-51,
+        7.513742425126589e-51,
     ),
     Quad(
         2.844075372112718e-1,
         2.2209268510661475e-17,
         -9.18230956295237e-34,
-        -5.219287530889219e-50,
+        -5.219287530889222e-50,
     ),
     Quad(
         2.873474595447295e-1,
         1.5461117367645717e-17,
         -6.326397366344408e-34,
-        -2.2982538416476248e-50,
+        -2.2982538416476214e-50,
     ),
     Quad(
         2.902846772544624e-1,
         -1.892797870777425e-17,
         1.1522953157142315e-33,
-        7.473865565471657e-50,
+        7.47386556547166e-50,
     ),
     Quad(
         2.9321916269425863e-1,
         2.2385430811901833e-17,
         1.366248464653968e-33,
-        -4.245132525399692e-50,
+        -4.245132525399694e-50,
     ),
     Quad(
         2.9615088824362384e-1,
         -2.0220736360876938e-17,
         -7.925221253392041e-35,
-        -2.899057772957201e-51,
+        -2.899057772957247e-51,
     ),
     Quad(
         2.990798263080405e-1,
         1.6701181609219447e-18,
         8.609115111731629e-35,
-        3.993128623001396e-52,
+        3.99312862300121e-52,
     ),
     Quad(
         3.020059493192281e-1,
         -1.7167666235262474e-17,
         2.333618214900807e-34,
-        8.302533455521957e-51,
+        8.302533455522e-51,
     ),
     Quad(
         3.0492922973540243e-1,
         -2.2989033898191262e-17,
         -1.4598901099661133e-34,
-        3.7760487693121697e-51,
+        3.776048769312183e-51,
     ),
     Quad(
         3.0784964004153487e-1,
         2.7074088527245185e-17,
         1.2568858206899284e-33,
-        7.293181510590166e-50,
+        7.2931815105901645e-50,
     ),
     Quad(
         3.107671527496115e-1,
         2.0887076364048513e-17,
         -3.0130590791065942e-34,
-        1.3876739009935514e-51,
+        1.387673900993518e-51,
     ),
     Quad(
         3.1368174039889146e-1,
         1.4560447299968912e-17,
         3.6564186898011595e-34,
-        1.165426473499937e-50,
+        1.1654264734999375e-50,
     ),
     Quad(
         3.1659337555616585e-1,
         2.1435292512726283e-17,
         1.2338169231377316e-33,
-        3.3963542100989274e-50,
+        3.3963542100989293e-50,
     ),
     Quad(
         3.195020308160157e-1,
@@ -747,217 +829,217 @@ pub const SINES: [Quad; 256] = [
         3.2240767880106985e-1,
         -4.05190399379594e-18,
         3.7438302780296796e-34,
-        8.793673104663928e-51,
+        8.79367310466392e-51,
     ),
     Quad(
         3.253102921622629e-1,
         7.91712494637659e-18,
         -6.757662206814639e-35,
-        2.302165506692977e-51,
+        2.3021655066929538e-51,
     ),
     Quad(
         3.2820984357909255e-1,
         -2.6693140719641896e-17,
         7.892885144753479e-34,
-        2.5525163821987572e-51,
+        2.552516382198781e-51,
     ),
     Quad(
         3.3110630575987643e-1,
         -2.7469465474778694e-17,
         -1.3401245916610206e-33,
-        6.553176248997621e-50,
+        6.553176248997616e-50,
     ),
     Quad(
         3.339996514420094e-1,
         2.2598986806288142e-17,
         7.806305719258612e-34,
-        2.042760089548672e-50,
+        2.0427600895486683e-50,
     ),
     Quad(
         3.3688985339222005e-1,
         -4.200094003347509e-19,
         -2.9178652969985438e-36,
-        -1.159737643703204e-52,
+        -1.1597376437036749e-52,
     ),
     Quad(
         3.3977688440682685e-1,
         6.602867949941828e-18,
         1.2575009988669683e-34,
-        2.5569067699008188e-51,
+        2.5569067699008304e-51,
     ),
     Quad(
         3.426607173119944e-1,
         1.926151844930632e-17,
         -9.275418913599087e-34,
-        8.543999668739021e-50,
+        8.543999668739017e-50,
     ),
     Quad(
         3.4554132496398904e-1,
         2.7251143672916123e-17,
         7.013816360194174e-34,
-        -1.4176292197453965e-50,
+        -1.4176292197454015e-50,
     ),
     Quad(
         3.4841868024943456e-1,
         3.697442051420492e-18,
         3.5532146878499996e-34,
-        1.956546254450134e-50,
+        1.9565462544501322e-50,
     ),
     Quad(
         3.5129275608556715e-1,
         -2.2670712098795844e-17,
         -1.6994216673139633e-34,
-        9.110561603453039e-51,
+        9.110561603453049e-51,
     ),
     Quad(
         3.541635254204904e-1,
         -1.695176330576486e-17,
         1.2772331777814617e-33,
-        -3.370378543584333e-50,
+        -3.370378543584331e-50,
     ),
     Quad(
         3.5703096123343003e-1,
         -4.821819113791917e-19,
         -4.167243699449236e-35,
-        -7.15311671493684e-52,
+        -7.153116714936435e-52,
     ),
     Quad(
         3.5989503653498817e-1,
         -1.7601687123839282e-17,
         1.3375125473046791e-33,
-        7.946781559358436e-50,
+        7.946781559358434e-50,
     ),
     Quad(
         3.627557243673972e-1,
         -9.166835266374985e-18,
         -7.4317843956936735e-34,
-        -2.0199582511804567e-50,
+        -2.0199582511804564e-50,
     ),
     Quad(
         3.6561299780477385e-1,
         1.6217898770457546e-17,
         1.1286970151961055e-33,
-        -7.182528731813896e-50,
+        -7.182528731813901e-50,
     ),
     Quad(
         3.684668299533723e-1,
         1.0463640796159268e-17,
         2.0554984738517304e-35,
-        1.0441861305618895e-51,
+        1.0441861305618769e-51,
     ),
     Quad(
         3.7131719395183754e-1,
         3.4749239648238266e-19,
         -7.515105304286667e-37,
-        -2.8153468438618943e-53,
+        -2.815346843865085e-53,
     ),
     Quad(
         3.74164062971458e-1,
         8.011410376196212e-18,
         5.342959981340605e-34,
-        1.0351378796539212e-50,
+        1.035137879653921e-50,
     ),
     Quad(
         3.7700741021641826e-1,
         -2.725530204195693e-18,
         6.364658644501814e-35,
-        8.304865717650216e-52,
+        8.304865717650356e-52,
     ),
     Quad(
         3.7984720892405116e-1,
         9.915130585517237e-18,
         4.8761409697224886e-34,
-        1.4025084000776738e-50,
+        1.4025084000776705e-50,
     ),
     Quad(
         3.826834323650898e-1,
         -1.0050772696461588e-17,
         -2.0605316302806695e-34,
-        -1.2717724698085234e-50,
+        -1.2717724698085205e-50,
     ),
     Quad(
         3.8551605384391885e-1,
         1.5177665396472313e-17,
         1.4198230518016535e-33,
-        5.895516715990422e-50,
+        5.8955167159904235e-50,
     ),
     Quad(
         3.883450466988263e-1,
         -1.0053770598398717e-17,
         7.594299925505713e-34,
-        -3.1967974046654205e-50,
+        -3.196797404665422e-50,
     ),
     Quad(
         3.9117038430225387e-1,
         1.7997787858243995e-17,
         -1.0613482402609856e-33,
-        -5.458214881779101e-50,
+        -5.458214881779103e-50,
     ),
     Quad(
         3.939920400610481e-1,
         9.764924164123934e-18,
         -2.1233599441284617e-34,
-        -5.552983679534047e-51,
+        -5.552983679534082e-51,
     ),
     Quad(
         3.968099874167103e-1,
         2.0545063670840126e-17,
         6.134705880192284e-34,
-        1.0733788150636435e-50,
+        1.073378815063643e-50,
     ),
     Quad(
         3.9962419984564684e-1,
         -1.5065497476189372e-17,
         -9.96532588818673e-34,
-        -5.752432371272538e-50,
+        -5.7524323712725355e-50,
     ),
     Quad(
         4.0243465085941843e-1,
         1.090261933932827e-17,
         7.3998528125989765e-34,
-        2.274578480682347e-50,
+        2.27457848068235e-50,
     ),
     Quad(
         4.0524131400498986e-1,
         9.911140194289988e-18,
         -2.5169070895434648e-34,
-        9.277298481839648e-53,
+        9.277298481843657e-53,
     ),
     Quad(
         4.080441628649787e-1,
         -7.000601513735131e-18,
         -1.4108207334268228e-34,
-        1.5175546997581244e-52,
+        1.5175546997577136e-52,
     ),
     Quad(
         4.1084317105790397e-1,
         -2.42198351903555e-17,
         -1.1418902925313314e-33,
-        -2.0996843165093447e-50,
+        -2.0996843165093468e-50,
     ),
     Quad(
         4.1363831223843456e-1,
         -1.0393984940597871e-17,
         -1.148168117450388e-34,
-        -2.0281052851028834e-51,
+        -2.028105285102868e-51,
     ),
     Quad(
         4.164295600976372e-1,
         -2.5475580413131732e-17,
         -3.4482678506112824e-34,
-        7.178861935186544e-51,
+        7.178861935186548e-51,
     ),
     Quad(
         4.1921688836322396e-1,
         -4.223246375011059e-18,
         -3.6053023045255794e-34,
-        2.055456215144949e-50,
+        2.0554562151449497e-50,
     ),
     Quad(
         4.220002707997997e-1,
         4.354326699412853e-18,
         3.173431027225119e-34,
-        -1.35732479807387e-50,
+        -1.3573247980738668e-50,
     ),
     Quad(
         4.247796812091088e-1,
@@ -969,55 +1051,55 @@ pub const SINES: [Quad; 256] = [
         4.275550934302821e-1,
         9.411189816295473e-18,
         -1.74466824265988e-34,
-        -2.2054492626479695e-51,
+        -2.205449262648017e-51,
     ),
     Quad(
         4.303264813400826e-1,
         2.225968697409269e-17,
         8.597259131408508e-34,
-        -2.9420897889002997e-50,
+        -2.942089788900302e-50,
     ),
     Quad(
         4.3309381885315196e-1,
         1.1224283329847517e-17,
         5.322374804107566e-35,
-        -5.298439577667404e-51,
+        -5.2984395776673614e-51,
     ),
     Quad(
         4.358570799222555e-1,
         1.6230515450644527e-17,
         -6.437144906357944e-35,
-        3.7808151922300826e-51,
+        3.780815192230107e-51,
     ),
     Quad(
         4.3861623853852766e-1,
         -2.088331583107509e-17,
         -1.4259583540891877e-34,
-        6.386476359065959e-52,
+        6.386476359065708e-52,
     ),
     Quad(
         4.4137126873171667e-1,
         2.236078388696497e-17,
         1.186476960351577e-34,
-        -3.808700326618951e-51,
+        -3.808700326618923e-51,
     ),
     Quad(
         4.4412214457042926e-1,
         -2.4218874422178315e-17,
         2.2205230838703907e-34,
-        9.213303591135644e-51,
+        9.213303591135626e-51,
     ),
     Quad(
         4.468688401623742e-1,
         -1.9222136142309382e-17,
         -4.442567858973205e-35,
-        -1.3673609292149568e-51,
+        -1.3673609292149535e-51,
     ),
     Quad(
         4.496113296546066e-1,
         4.883192423203524e-18,
         2.715108449819138e-34,
-        -1.565399317161318e-50,
+        -1.5653993171613154e-50,
     ),
     Quad(
         4.523495872337709e-1,
@@ -1029,205 +1111,205 @@ pub const SINES: [Quad; 256] = [
         4.5508358712634384e-1,
         -1.2379906758116472e-17,
         5.528968895554264e-34,
-        -8.538231284020904e-51,
+        -8.538231284020939e-51,
     ),
     Quad(
         4.5781330359887723e-1,
         -8.455425492229595e-18,
         -6.377039424676426e-34,
-        3.1778253575564297e-50,
+        3.177825357556425e-50,
     ),
     Quad(
         4.6053871095824e-1,
         1.8488777492177872e-17,
         -1.0527732154209725e-33,
-        3.3235593490947117e-50,
+        3.3235593490947102e-50,
     ),
     Quad(
         4.632597835518602e-1,
         -7.35149245332317e-18,
         6.7175396881707035e-34,
-        3.9594127612123412e-50,
+        3.959412761212338e-50,
     ),
     Quad(
         4.659764957679662e-1,
         -3.3023547778235135e-18,
         3.490467705047689e-35,
-        -1.8971438937970005e-51,
+        -1.8971438937969667e-51,
     ),
     Quad(
         4.6868882203582796e-1,
         -2.2949251681845054e-17,
         -1.1364757641823658e-33,
-        6.884052250191858e-50,
+        6.884052250191861e-50,
     ),
     Quad(
         4.7139673682599764e-1,
         6.516678136069013e-18,
         2.9457546966235984e-34,
-        -6.215971773883671e-51,
+        -6.215971773883663e-51,
     ),
     Quad(
         4.7410021465055e-1,
         -8.145160154897808e-18,
         -3.4789448555614422e-34,
-        -1.1681943974658491e-50,
+        -1.1681943974658508e-50,
     ),
     Quad(
         4.7679923006332214e-1,
         -1.0293515338305794e-17,
         -3.658204500836995e-34,
-        1.742413147917649e-50,
+        1.7424131479176475e-50,
     ),
     Quad(
         4.79493757660153e-1,
         1.841999966268477e-17,
         -1.3040838621273312e-33,
-        1.0977131822246452e-50,
+        1.097713182224647e-50,
     ),
     Quad(
         4.821837720791228e-1,
         -2.5861500925520442e-17,
         -6.291319760650001e-36,
-        4.0802359808682775e-52,
+        4.0802359808684726e-52,
     ),
     Quad(
         4.848692480007911e-1,
         -1.8034004203262245e-17,
         -3.5244276906958044e-34,
-        -1.7138318654749265e-50,
+        -1.7138318654749246e-50,
     ),
     Quad(
         4.8755016014843594e-1,
         1.4231090931273653e-17,
         -1.8277733073262697e-34,
-        -1.5208291790429067e-51,
+        -1.5208291790429557e-51,
     ),
     Quad(
         4.9022648328829116e-1,
         -5.1496145643440404e-18,
         -3.6903027405284104e-34,
-        1.517294009515126e-50,
+        1.5172940095151304e-50,
     ),
     Quad(
         4.9289819222978404e-1,
         -1.0257831676562186e-18,
         6.952081776088507e-35,
-        -2.426096121409026e-51,
+        -2.426096121409039e-51,
     ),
     Quad(
         4.9556526182577254e-1,
         -9.432324194236536e-18,
         3.1212918657699143e-35,
-        4.2009072375238366e-52,
+        4.2009072375242736e-52,
     ),
     Quad(
         4.9822766697278187e-1,
         -1.6126383830540798e-17,
         -1.509289731929887e-33,
-        1.1049298890895924e-50,
+        1.1049298890895917e-50,
     ),
     Quad(
         5.008853826112408e-1,
         -3.960401514707464e-17,
         -2.2208395201898007e-33,
-        1.3648202735839415e-49,
+        1.3648202735839417e-49,
     ),
     Quad(
         5.035383837257176e-1,
         -1.6731308204967497e-17,
         -1.0140233644074786e-33,
-        4.095307193767152e-50,
+        4.0953071937671477e-50,
     ),
     Quad(
         5.061866453451553e-1,
         -4.832159298649371e-17,
         9.285810722664225e-34,
-        4.269980240103704e-50,
+        4.2699802401037005e-50,
     ),
     Quad(
         5.08830142543107e-1,
         4.783696826801413e-17,
         -1.0727022928806035e-33,
-        2.7309374513672715e-50,
+        2.7309374513672757e-50,
     ),
     Quad(
         5.114688504379704e-1,
         -1.3088001221007579e-17,
         4.09290333633669e-34,
-        -3.7952190153477893e-50,
+        -3.7952190153477926e-50,
     ),
     Quad(
         5.141027441932218e-1,
         -4.5712707523615624e-17,
         1.5488279442238283e-33,
-        -2.5853959305521116e-50,
+        -2.585395930552113e-50,
     ),
     Quad(
         5.167317990176499e-1,
         8.301861723383652e-18,
         5.82510274676952e-34,
-        -2.281239719053509e-50,
+        -2.2812397190535076e-50,
     ),
     Quad(
         5.193559901655896e-1,
         -5.5331248144171145e-17,
         -3.1628375609769026e-35,
-        -2.409197205118826e-51,
+        -2.409197205118857e-51,
     ),
     Quad(
         5.219752929371544e-1,
         -4.655579569208888e-17,
         4.637898093685043e-34,
-        -3.3470542934689544e-51,
+        -3.347054293468953e-51,
     ),
     Quad(
         5.24589682678469e-1,
         -4.3068869040082345e-17,
         -4.2013155291932055e-34,
-        -1.5096069926700272e-50,
+        -1.5096069926700274e-50,
     ),
     Quad(
         5.271991347819014e-1,
         -4.220298348056062e-17,
         8.55859161848673e-34,
-        7.997433933673227e-50,
+        7.997433933673231e-50,
     ),
     Quad(
         5.298036246862947e-1,
         -4.806784170648234e-17,
         5.83097210466303e-34,
-        -8.974076152175692e-51,
+        -8.974076152175666e-51,
     ),
     Quad(
         5.32403127877198e-1,
         -4.1020306135800895e-17,
         -1.923999637423082e-33,
-        -1.5326987913812186e-49,
+        -1.5326987913812184e-49,
     ),
     Quad(
         5.349976198870973e-1,
         -5.3683132708358134e-17,
         -1.3900569918838112e-33,
-        2.7154084726474073e-50,
+        2.715408472647409e-50,
     ),
     Quad(
         5.375870762956455e-1,
         -2.2617365388403054e-17,
         -5.9787279033447075e-34,
-        3.1204419729043204e-51,
+        3.1204419729043625e-51,
     ),
     Quad(
         5.401714727298929e-1,
         2.707244796593584e-17,
         1.169879970921383e-33,
-        -5.909466851588148e-50,
+        -5.90946685158815e-50,
     ),
     Quad(
         5.427507848645159e-1,
         1.71482610047571e-17,
         -1.352590592520087e-33,
-        4.972441129072729e-50,
+        4.972441129072732e-50,
     ),
     Quad(
         5.453249884220465e-1,
@@ -1239,19 +1321,19 @@ pub const SINES: [Quad; 256] = [
         5.478940591731002e-1,
         -2.4065878297113363e-17,
         -3.5639213669362606e-36,
-        -2.6013270854276044e-52,
+        -2.6013270854271645e-52,
     ),
     Quad(
         5.504579729366048e-1,
         -8.331990301580766e-18,
         -2.3058454035767638e-34,
-        2.1152944929106175e-50,
+        2.1152944929106123e-50,
     ),
     Quad(
         5.530167055800276e-1,
         -4.7061536623798204e-17,
         -1.0617111545918056e-33,
-        -1.619631614440733e-50,
+        -1.619631614440738e-50,
     ),
     Quad(
         5.555702330196022e-1,
@@ -1263,19 +1345,19 @@ pub const SINES: [Quad; 256] = [
         5.581185312205561e-1,
         1.3481176324765226e-17,
         -5.501674387301144e-34,
-        -2.3484822739335397e-50,
+        -2.3484822739335416e-50,
     ),
     Quad(
         5.60661576197336e-1,
         -7.395641815347615e-18,
         3.968062061173119e-34,
-        3.199595220083625e-50,
+        3.1995952200836223e-50,
     ),
     Quad(
         5.631993440138341e-1,
         2.383577514685483e-17,
         1.3511793173769814e-34,
-        9.320131158124818e-51,
+        9.320131158124814e-51,
     ),
     Quad(
         5.657318107836132e-1,
@@ -1287,13 +1369,13 @@ pub const SINES: [Quad; 256] = [
         5.682589526701316e-1,
         -5.093567364276925e-17,
         -1.627435635102825e-33,
-        9.818315156170268e-51,
+        9.818315156170297e-51,
     ),
     Quad(
         5.707807458869673e-1,
         2.4568151455566208e-17,
         -1.284448124756035e-33,
-        -1.8037634376936246e-50,
+        -1.803763437693626e-50,
     ),
     Quad(
         5.732971666980422e-1,
@@ -1305,109 +1387,109 @@ pub const SINES: [Quad; 256] = [
         5.758081914178453e-1,
         -3.7909495458942734e-17,
         -2.743373804685431e-33,
-        1.1130841524216792e-49,
+        1.1130841524216797e-49,
     ),
     Quad(
         5.783137964116556e-1,
         -2.623769151237283e-17,
         1.3679051680738167e-33,
-        -3.1409808935335943e-50,
+        -3.14098089353359e-50,
     ),
     Quad(
         5.808139580957645e-1,
         1.8585338586613408e-17,
         2.767384311454918e-34,
-        1.9605349619836932e-50,
+        1.9605349619836937e-50,
     ),
     Quad(
         5.833086529376983e-1,
         3.451660107904486e-18,
         1.8065977478946306e-34,
-        -6.395395803854505e-51,
+        -6.395395803854465e-51,
     ),
     Quad(
         5.857978574564389e-1,
         -3.748550196431129e-18,
         2.7965403775536614e-34,
-        -7.181693602415737e-51,
+        -7.18169360241572e-51,
     ),
     Quad(
         5.882815482226453e-1,
         -2.9292166725006846e-17,
         -2.3744954603693934e-33,
-        -1.1571631191512478e-50,
+        -1.157163119151248e-50,
     ),
     Quad(
         5.907597018588743e-1,
         -4.701358417065954e-17,
         2.4808417611768356e-33,
-        1.2598907673643214e-50,
+        1.2598907673643198e-50,
     ),
     Quad(
         5.932322950397998e-1,
         1.2892320944189053e-17,
         5.305836477635958e-34,
-        4.1141674699390024e-50,
+        4.114167469939005e-50,
     ),
     Quad(
         5.956993044924334e-1,
         -1.3438641936579467e-17,
         -6.787768790772106e-35,
-        5.08636508720028e-51,
+        5.0863650872002936e-51,
     ),
     Quad(
         5.981607069963423e-1,
         3.880188578300066e-17,
         -1.2084165858094663e-33,
-        -4.045661084343004e-50,
+        -4.045661084343006e-50,
     ),
     Quad(
         6.00616479383869e-1,
         -4.639819822946193e-17,
         -1.66734930037108e-33,
-        5.198282437849149e-50,
+        5.1982824378491445e-50,
     ),
     Quad(
         6.030665985403482e-1,
         3.732335768055965e-17,
         2.7771920866974305e-33,
-        -1.619422964974246e-49,
+        -1.6194229649742458e-49,
     ),
     Quad(
         6.055110414043255e-1,
         -3.120267249330568e-17,
         1.2761267338680916e-33,
-        -4.085936859837968e-50,
+        -4.0859368598379647e-50,
     ),
     Quad(
         6.079497849677736e-1,
         3.516083236209666e-17,
         -2.5546242776778394e-34,
-        -1.4085313551220675e-50,
+        -1.4085313551220694e-50,
     ),
     Quad(
         6.103828062763095e-1,
         -2.256326564822917e-17,
         1.318557501122673e-33,
-        8.23166914200635e-50,
+        8.231669142006346e-50,
     ),
     Quad(
         6.128100824294097e-1,
         -4.2693476568409685e-18,
         2.583996588665032e-34,
-        1.688441200562252e-50,
+        1.6884412005622537e-50,
     ),
     Quad(
         6.152315905806268e-1,
         2.623141776726695e-17,
         -1.4095366621106716e-33,
-        7.205869049130455e-50,
+        7.205869049130456e-50,
     ),
     Quad(
         6.17647307937804e-1,
         -4.747859451090245e-17,
         -7.2986558263124e-34,
-        -3.015232751743918e-50,
+        -3.0152327517439154e-50,
     ),
     Quad(
         6.200572117632892e-1,
@@ -1419,19 +1501,19 @@ pub const SINES: [Quad; 256] = [
         6.2246127937415e-1,
         5.2940728606573e-18,
         -4.848641121594583e-35,
-        1.2696527641976689e-52,
+        1.2696527641980109e-52,
     ),
     Quad(
         6.248594881423863e-1,
         3.36718460372439e-17,
         -2.7846053391012096e-33,
-        5.610271812001211e-50,
+        5.61027181200121e-50,
     ),
     Quad(
         6.272518154951441e-1,
         3.0763585181253225e-17,
         2.706893027349814e-34,
-        -1.117224030928648e-50,
+        -1.1172240309286484e-50,
     ),
     Quad(
         6.29638238914927e-1,
@@ -1443,115 +1525,115 @@ pub const SINES: [Quad; 256] = [
         6.320187359398091e-1,
         -4.016494229646361e-17,
         -7.220864364173672e-34,
-        3.782892047054432e-50,
+        3.7828920470544344e-50,
     ),
     Quad(
         6.343932841636455e-1,
         1.0420901929280035e-17,
         4.117455892928049e-34,
-        -1.446415298663068e-51,
+        -1.4464152986630705e-51,
     ),
     Quad(
         6.367618612362842e-1,
         3.141904871190161e-17,
         -2.269373841512645e-33,
-        -1.6023584204297392e-49,
+        -1.602358420429739e-49,
     ),
     Quad(
         6.391244448637757e-1,
         1.2416796312271043e-17,
         -6.2095419626356605e-34,
-        2.776206599950661e-50,
+        2.7762065999506603e-50,
     ),
     Quad(
         6.414810128085832e-1,
         -9.988343011594331e-18,
         4.196923037673013e-34,
-        5.698054379925748e-51,
+        5.69805437992576e-51,
     ),
     Quad(
         6.438315428897915e-1,
         -3.2084798795046886e-17,
         -1.2595311907053305e-33,
-        -4.020588523084152e-50,
+        -4.0205885230841536e-50,
     ),
     Quad(
         6.461760129833164e-1,
         -2.9756137382280815e-17,
         -1.0275370077518259e-33,
-        8.085247866589343e-51,
+        8.085247866589301e-51,
     ),
     Quad(
         6.485144010221124e-1,
         3.987027031338683e-18,
         1.9408388509540788e-34,
-        -5.179842063619337e-51,
+        -5.179842063619319e-51,
     ),
     Quad(
         6.508466849963809e-1,
         3.971467071050026e-17,
         2.9178546787002963e-34,
-        3.814063550829358e-51,
+        3.814063550829328e-51,
     ),
     Quad(
         6.531728429537768e-1,
         8.569564206002624e-18,
         -6.916532230507063e-34,
-        2.3873751224185414e-50,
+        2.3873751224185395e-50,
     ),
     Quad(
         6.554928529996153e-1,
         3.5638734426385005e-17,
         1.2695365790889811e-33,
-        4.398495286541201e-50,
+        4.398495286541205e-50,
     ),
     Quad(
         6.578066932970786e-1,
         1.9580943058468545e-17,
         -1.1944272256627192e-33,
-        2.855640261643684e-50,
+        2.855640261643686e-50,
     ),
     Quad(
         6.601143420674205e-1,
         -1.3960054386823638e-19,
         6.151577793149405e-36,
-        5.3510498875626214e-52,
+        5.351049887562266e-52,
     ),
     Quad(
         6.624157775901718e-1,
         -2.261550888576459e-17,
         5.017705031812686e-34,
-        2.9162532399530814e-50,
+        2.916253239953076e-50,
     ),
     Quad(
         6.647109782033449e-1,
         -3.622779359803437e-17,
         -9.060793476554043e-34,
-        3.091703634238017e-50,
+        3.0917036342380213e-50,
     ),
     Quad(
         6.669999223036375e-1,
         3.5284364997428166e-17,
         -1.0382057232458238e-33,
-        7.381275655016762e-50,
+        7.381275655016763e-50,
     ),
     Quad(
         6.692825883466361e-1,
         -5.459265241744791e-17,
         -2.518101470969515e-33,
-        -1.686787599943717e-49,
+        -1.6867875999437174e-49,
     ),
     Quad(
         6.715589548470184e-1,
         -4.048903774929669e-17,
         3.199583562535568e-34,
-        -1.4044414655670971e-50,
+        -1.404441465567096e-50,
     ),
     Quad(
         6.73829000378756e-1,
         2.3091901236161086e-17,
         5.742803719288132e-34,
-        1.1240668354625932e-50,
+        1.1240668354625977e-50,
     ),
     Quad(
         6.760927035753159e-1,
@@ -1563,19 +1645,19 @@ pub const SINES: [Quad; 256] = [
         6.783500431298615e-1,
         1.8302093041863122e-17,
         9.524167574681307e-34,
-        5.032810111613354e-50,
+        5.03281011161335e-50,
     ),
     Quad(
         6.80600997795453e-1,
         2.8473293354522047e-17,
         4.13318059772709e-34,
-        4.257903051074859e-50,
+        4.2579030510748576e-50,
     ),
     Quad(
         6.828455463852481e-1,
         -1.2958058061524531e-17,
         1.8292386959330698e-34,
-        3.4536209116044084e-51,
+        3.453620911604449e-51,
     ),
     Quad(
         6.850836677727004e-1,
@@ -1587,7 +1669,7 @@ pub const SINES: [Quad; 256] = [
         6.873153408917592e-1,
         -5.515615871491717e-17,
         1.1896489854266829e-33,
-        -7.85058962182207e-51,
+        -7.850589621822066e-51,
     ),
     Quad(
         6.895405447370669e-1,
@@ -1599,251 +1681,252 @@ pub const SINES: [Quad; 256] = [
         6.917592583641577e-1,
         2.7406078472410668e-17,
         1.3286508943202092e-33,
-        1.0651869129579751e-51,
+        1.0651869129580079e-51,
     ),
     Quad(
         6.93971460889654e-1,
         7.434507695628014e-18,
         7.506152838819746e-34,
-        -1.5928000240686557e-50,
+        -1.5928000240686583e-50,
     ),
     Quad(
         6.96177131491463e-1,
         -4.122408121358289e-17,
         -3.1838716762083296e-35,
-        1.3829706789724612e-51,
+        1.3829706789724782e-51,
     ),
     Quad(
         6.983762494089728e-1,
         4.898828243566777e-17,
         1.9134010413244152e-33,
-        2.6161153243794036e-50,
+        2.616115324379399e-50,
     ),
     Quad(
         7.005687939432483e-1,
         3.102796019299292e-17,
         9.563825050918e-34,
-        4.5896916138107285e-51,
+        4.589691613810705e-51,
     ),
     Quad(
         7.027547444572253e-1,
         2.527829438362982e-18,
         -8.698556121067495e-35,
-        5.001072609587458e-51,
+        5.001072609587484e-51,
     ),
     Quad(
         7.049340803759049e-1,
         2.7608725585748502e-17,
         2.9816599471629137e-34,
-        1.1533044185111213e-50,
+        1.1533044185111206e-50,
     ),
     Quad(
         7.071067811865476e-1,
         -4.833646656726457e-17,
         2.0693376543497068e-33,
-        2.467773495734176e-50,
+        2.4677734957341755e-50,
     ),
 ];
 
 // Table of cos(kπ/1024), for k in [1, 256]
-pub const COSINES: [Quad; 256] = [
+#[allow(clippy::approx_constant)]
+pub const COSINES: [Quad; TRIG_STEPS / 4] = [
     Quad(
         9.999952938095762e-1,
         -1.966806428532219e-17,
         -6.305395509588348e-34,
-        5.326611085572218e-52,
+        5.326611085572673e-52,
     ),
     Quad(
         9.999811752826011e-1,
         3.3568103522895585e-17,
         -1.4740132559368063e-35,
-        9.860309759475735e-52,
+        9.86030975947556e-52,
     ),
     Quad(
         9.999576445519639e-1,
         -3.152783686664729e-17,
         2.6363251186638437e-33,
-        1.0007504815488404e-49,
+        1.00075048154884e-49,
     ),
     Quad(
         9.999247018391445e-1,
         3.793108251266801e-17,
         -8.509991866050148e-35,
-        -4.9956973223295165e-51,
+        -4.9956973223295153e-51,
     ),
     Quad(
         9.998823474542126e-1,
         -3.547781487240854e-17,
         1.7102001035303974e-33,
-        -1.0725388519026538e-49,
+        -1.0725388519026542e-49,
     ),
     Quad(
         9.998305817958234e-1,
         1.882514051755112e-17,
         -5.138351345761694e-34,
-        -3.8378827995403777e-50,
+        -3.8378827995403787e-50,
     ),
     Quad(
         9.997694053512153e-1,
         4.268117703228901e-17,
         1.90623023597371e-33,
-        -6.022115326288111e-50,
+        -6.022115326288116e-50,
     ),
     Quad(
         9.996988186962042e-1,
         -2.985148640379975e-17,
         -1.9084787370733737e-33,
-        5.598026034402892e-51,
+        5.59802603440292e-51,
     ),
     Quad(
         9.996188224951786e-1,
         -4.1181965521424734e-17,
         2.0915365593699916e-33,
-        8.1403390920903705e-50,
+        8.140339092090373e-50,
     ),
     Quad(
         9.995294175010931e-1,
         2.051791782375559e-17,
         -4.767380258570652e-34,
-        -2.9443604198656734e-50,
+        -2.944360419865677e-50,
     ),
     Quad(
         9.994306045554617e-1,
         3.96444977522578e-17,
         -2.375722371672243e-34,
-        -1.2856759011361572e-51,
+        -1.2856759011361726e-51,
     ),
     Quad(
         9.993223845883495e-1,
         -4.285853844084568e-17,
         3.3235101605146565e-34,
-        -8.355427237705765e-51,
+        -8.355427237705754e-51,
     ),
     Quad(
         9.992047586183639e-1,
         9.17963171103857e-18,
         5.541620818586857e-34,
-        8.026704671761995e-52,
+        8.026704671761531e-52,
     ),
     Quad(
         9.990777277526454e-1,
         2.1419007653587032e-17,
         -7.904820331852962e-34,
-        -5.316629618111275e-50,
+        -5.316629618111271e-50,
     ),
     Quad(
         9.989412931868569e-1,
         -2.061064191005864e-17,
         -1.2546525485913485e-33,
-        -7.517588880615705e-50,
+        -7.517588880615706e-50,
     ),
     Quad(
         9.987954562051724e-1,
         -1.2291693337075465e-17,
         2.446844678649127e-34,
-        1.0723891085210282e-50,
+        1.0723891085210268e-50,
     ),
     Quad(
         9.986402181802653e-1,
         -4.86902543129233e-17,
         -2.9470881967909147e-34,
-        -1.3000650761346857e-50,
+        -1.3000650761346907e-50,
     ),
     Quad(
         9.984755805732948e-1,
         -2.2002931182778795e-17,
         -1.2371509454944992e-33,
-        -2.4911225131232046e-50,
+        -2.4911225131232065e-50,
     ),
     Quad(
         9.983015449338929e-1,
         -5.186940270279228e-17,
         1.0480195493633452e-33,
-        -2.8995649143155554e-50,
+        -2.899564914315551e-50,
     ),
     Quad(
         9.981181129001492e-1,
         2.793548755811383e-17,
         2.4423341255830345e-33,
-        -6.764669917533444e-50,
+        -6.764669917533442e-50,
     ),
     Quad(
         9.97925286198596e-1,
         1.7143659778886362e-17,
         5.788584090288746e-34,
-        -9.260143260389495e-51,
+        -9.26014326038946e-51,
     ),
     Quad(
         9.977230666441916e-1,
         -2.639447527489872e-17,
         -1.6176223087661783e-34,
-        -9.992494288936241e-51,
+        -9.992494288936228e-51,
     ),
     Quad(
         9.975114561403035e-1,
         5.600720591980694e-18,
         -5.947767351468569e-35,
-        -1.4166807162473617e-54,
+        -1.4166807162743627e-54,
     ),
     Quad(
         9.972904566786902e-1,
         9.164769537110173e-18,
         6.78241343097393e-34,
-        -8.619139279553858e-52,
+        -8.619139279554336e-52,
     ),
     Quad(
         9.97060070339483e-1,
         1.6734093546241963e-17,
         -1.3169951440780028e-33,
-        1.0311048767952451e-50,
+        1.0311048767952477e-50,
     ),
     Quad(
         9.968202992911657e-1,
         4.7062820708615655e-17,
         2.8412041076474937e-33,
-        -8.000615567026358e-50,
+        -8.000615567026362e-50,
     ),
     Quad(
         9.965711457905548e-1,
         1.1707179088390986e-17,
         -7.593441326302466e-34,
-        2.8474848436925975e-50,
+        2.847484843692601e-50,
     ),
     Quad(
         9.96312612182778e-1,
         1.1336497891624735e-17,
         3.400245867441436e-34,
-        7.741907592154426e-52,
+        7.74190759215449e-52,
     ),
     Quad(
         9.96044700901252e-1,
         2.2870031707670695e-17,
         -3.918483940501315e-34,
-        -3.7081260416246323e-50,
+        -3.7081260416246375e-50,
     ),
     Quad(
         9.957674144676598e-1,
         -2.315190832309436e-17,
         -1.6306512931944591e-34,
-        -1.592542078386364e-51,
+        -1.5925420783863192e-51,
     ),
     Quad(
         9.954807554919269e-1,
         3.2084621412226554e-18,
         -4.950129214601302e-36,
-        -2.7811428850873264e-52,
+        -2.7811428850878516e-52,
     ),
     Quad(
         9.951847266721969e-1,
         -4.248691367830441e-17,
         1.3315510772504614e-33,
-        6.792798741705191e-50,
+        6.792798741705189e-50,
     ),
     Quad(
         9.948793307948056e-1,
         4.213081328494366e-18,
         -4.206259748828845e-35,
-        2.5157064556087756e-51,
+        2.515706455608762e-51,
     ),
     Quad(
         9.945645707342554e-1,
@@ -1855,115 +1938,115 @@ pub const COSINES: [Quad; 256] = [
         9.942404494531879e-1,
         4.4129423472462673e-17,
         -3.0107231708238066e-33,
-        7.420158290686193e-50,
+        7.420158290686189e-50,
     ),
     Quad(
         9.939069700023561e-1,
         -1.8964849471123746e-17,
         -1.5980853777937752e-35,
-        -8.537480715059655e-52,
+        -8.537480715059708e-52,
     ),
     Quad(
         9.935641355205953e-1,
         2.975230992779743e-17,
         -4.506670733113423e-34,
-        -3.354819163380503e-50,
+        -3.3548191633805036e-50,
     ),
     Quad(
         9.932119492347945e-1,
         3.309690626127226e-17,
         1.5592811973249567e-33,
-        1.4373977733253573e-50,
+        1.4373977733253592e-50,
     ),
     Quad(
         9.928504144598651e-1,
         -1.4094517733693302e-17,
         -1.1954558131616916e-33,
-        1.8761873742867948e-50,
+        1.8761873742867983e-50,
     ),
     Quad(
         9.9247953459871e-1,
         3.1093055095428906e-17,
         -1.837959475781802e-33,
-        -3.9885758559381824e-51,
+        -3.9885758559381314e-51,
     ),
     Quad(
         9.920993131421918e-1,
         -3.943192614958878e-17,
         -6.275806291104723e-34,
-        -1.296092955921242e-50,
+        -1.296092955921239e-50,
     ),
     Quad(
         9.917097536690995e-1,
         -2.337289131188366e-18,
         2.707329882496859e-35,
-        -1.2569459441802728e-51,
+        -1.2569459441802872e-51,
     ),
     Quad(
         9.913108598461154e-1,
         -2.5192111583372105e-17,
         -1.2852471567380887e-33,
-        5.238521258431044e-50,
+        5.238521258431048e-50,
     ),
     Quad(
         9.9090263542778e-1,
         1.5394565094566704e-17,
         -1.0799984133184567e-33,
-        2.745111596013394e-51,
+        2.7451115960133595e-51,
     ),
     Quad(
         9.904850842564571e-1,
         -5.5411437553780867e-17,
         -1.4614017210753585e-33,
-        -3.8339374397387644e-50,
+        -3.833937439738762e-50,
     ),
     Quad(
         9.900582102622971e-1,
         -1.7055485906233963e-17,
         1.3454939685758777e-33,
-        7.311758913730006e-50,
+        7.311758913730004e-50,
     ),
     Quad(
         9.896220174632009e-1,
         -5.239821796813253e-17,
         1.346318921145622e-33,
-        5.802164055489491e-50,
+        5.802164055489487e-50,
     ),
     Quad(
         9.89176509964781e-1,
         -4.098730993704711e-17,
         -4.485756055204844e-34,
-        -3.941450450287111e-50,
+        -3.9414504502871125e-50,
     ),
     Quad(
         9.887216919603238e-1,
         -1.0976227206656125e-17,
         3.2311342577653764e-34,
-        9.636794658357516e-51,
+        9.636794658357504e-51,
     ),
     Quad(
         9.882575677307495e-1,
         2.703060778437263e-17,
         7.751486648860138e-35,
-        2.1019644956865235e-51,
+        2.1019644956864938e-51,
     ),
     Quad(
         9.877841416445722e-1,
         -2.360069339715902e-17,
         -1.232328376970786e-33,
-        3.013090071680331e-50,
+        3.013090071680334e-50,
     ),
     Quad(
         9.873014181578584e-1,
         -5.233226125571565e-17,
         -2.7937644333152473e-33,
-        1.2074160567958412e-49,
+        1.2074160567958408e-49,
     ),
     Quad(
         9.868094018141855e-1,
         -5.0287214351061075e-17,
         -2.268152623814446e-33,
-        4.4003694320169095e-50,
+        4.400369432016913e-50,
     ),
     Quad(
         9.863080972445987e-1,
@@ -1975,145 +2058,145 @@ pub const COSINES: [Quad; 256] = [
         9.857975091675675e-1,
         -5.143945297995301e-17,
         2.6276439309996725e-33,
-        7.542355278328632e-50,
+        7.542355278328635e-50,
     ),
     Quad(
         9.852776423889412e-1,
         2.3155637027900207e-17,
         -7.527597154576483e-34,
-        1.0582231660456088e-50,
+        1.0582231660456094e-50,
     ),
     Quad(
         9.847485018019042e-1,
         1.0548144061829957e-17,
         2.8786145266267306e-34,
-        -3.6782210081466076e-51,
+        -3.678221008146611e-51,
     ),
     Quad(
         9.84210092386929e-1,
         4.798392262705069e-17,
         2.2597419645070588e-34,
-        1.7573875814863422e-50,
+        1.75738758148634e-50,
     ),
     Quad(
         9.836624192117303e-1,
         1.9864948201635255e-17,
         -1.0743046281211033e-35,
-        1.797566279655703e-52,
+        1.79756627965581e-52,
     ),
     Quad(
         9.831054874312163e-1,
         4.217000752288863e-17,
         8.23962656564409e-34,
-        -8.080370013909653e-50,
+        -8.080370013909656e-50,
     ),
     Quad(
         9.825393022874412e-1,
         1.5149580813777224e-17,
         -4.180277142218624e-34,
-        -2.21501743262262e-50,
+        -2.215017432622616e-50,
     ),
     Quad(
         9.819638691095552e-1,
         2.1108443711513084e-17,
         -1.5253013442896054e-33,
-        -6.838808207933793e-50,
+        -6.838808207933797e-50,
     ),
     Quad(
         9.813791933137546e-1,
         1.3428163260355633e-17,
         -6.529429046996299e-34,
-        2.796541228745605e-51,
+        2.796541228745627e-51,
     ),
     Quad(
         9.807852804032304e-1,
         1.8546939997825006e-17,
         -1.0696564445530757e-33,
-        6.666817447526491e-50,
+        6.666817447526496e-50,
     ),
     Quad(
         9.801821359681174e-1,
         -3.680178696385616e-17,
         6.324517138799284e-34,
-        1.8600176137175948e-50,
+        1.8600176137175971e-50,
     ),
     Quad(
         9.795697656854405e-1,
         1.557399158499042e-17,
         -1.340106602978299e-33,
-        -1.726370219986218e-50,
+        -1.726370219986215e-50,
     ),
     Quad(
         9.789481753190622e-1,
         -2.3817727961148053e-18,
         -1.0694750370381661e-34,
-        -8.229304719608728e-51,
+        -8.229304719608746e-51,
     ),
     Quad(
         9.783173707196277e-1,
         -2.1623082233344895e-17,
         1.0970403012028032e-33,
-        7.709192309936935e-50,
+        7.709192309936934e-50,
     ),
     Quad(
         9.776773578245099e-1,
         5.051413616705963e-17,
         -1.3254751701428788e-33,
-        7.01612543121245e-50,
+        7.016125431212454e-50,
     ),
     Quad(
         9.770281426577544e-1,
         -4.3353875751555997e-17,
         5.494883983153548e-34,
-        -9.275526310537764e-51,
+        -9.27552631053773e-51,
     ),
     Quad(
         9.763697313300211e-1,
         9.309393152621378e-18,
         -4.1184949155685665e-34,
-        -3.191392603139366e-50,
+        -3.191392603139369e-50,
     ),
     Quad(
         9.757021300385286e-1,
         -2.5572556081259686e-17,
         -9.317424450894222e-34,
-        -8.367586321164737e-51,
+        -8.367586321164686e-51,
     ),
     Quad(
         9.750253450669941e-1,
         2.6642660651899135e-17,
         1.7819392739353853e-34,
-        -3.315962538564879e-51,
+        -3.315962538564895e-51,
     ),
     Quad(
         9.743393827855759e-1,
         2.3041221476151512e-18,
         1.075868600503143e-34,
-        5.107411643280983e-51,
+        5.107411643280948e-51,
     ),
     Quad(
         9.73644249650812e-1,
         -5.172980869100587e-17,
         -1.5508473005989887e-33,
-        -1.6505125917675405e-49,
+        -1.6505125917675403e-49,
     ),
     Quad(
         9.729399522055602e-1,
         -3.13112111222818e-17,
         -2.687408778900614e-33,
-        -2.1652434818822018e-51,
+        -2.1652434818822145e-51,
     ),
     Quad(
         9.722264970789363e-1,
         3.646116978593822e-17,
         3.0309636883883133e-33,
-        -1.2702716907967761e-51,
+        -1.2702716907967306e-51,
     ),
     Quad(
         9.715038909862518e-1,
         -7.986542112228905e-18,
         -4.362841721126338e-34,
-        3.4307517798758936e-51,
+        3.430751779875935e-51,
     ),
     Quad(
         9.707721407289504e-1,
@@ -2125,103 +2208,103 @@ pub const COSINES: [Quad; 256] = [
         9.70031253194544e-1,
         1.8365300348428844e-17,
         -1.4311097571944918e-33,
-        8.584678199874089e-51,
+        8.58467819987407e-51,
     ),
     Quad(
         9.692812353565485e-1,
         -4.5663660261927896e-17,
         9.614752691723939e-34,
-        8.126760520787177e-51,
+        8.126760520787133e-51,
     ),
     Quad(
         9.685220942744173e-1,
         4.947507491824477e-17,
         2.855873835191124e-33,
-        6.294842231650748e-50,
+        6.294842231650746e-50,
     ),
     Quad(
         9.677538370934755e-1,
         -4.551213282551582e-17,
         -1.4127617988719093e-33,
-        -8.462060908970456e-50,
+        -8.462060908970458e-50,
     ),
     Quad(
         9.669764710448521e-1,
         3.8496228837337864e-17,
         -5.388163154274565e-34,
-        -3.522186317145893e-50,
+        -3.522186317145896e-50,
     ),
     Quad(
         9.661900034454125e-1,
         5.129884040166549e-17,
         1.4564075904769808e-34,
-        1.0095973971377442e-50,
+        1.0095973971377432e-50,
     ),
     Quad(
         9.653944416976894e-1,
         -2.3745389918392156e-17,
         5.922151559005386e-34,
-        -3.8811192556231103e-50,
+        -3.8811192556231094e-50,
     ),
     Quad(
         9.645897932898128e-1,
         -3.4189470735959786e-17,
         2.2982074155463522e-33,
-        -4.512879104560768e-50,
+        -4.512879104560763e-50,
     ),
     Quad(
         9.637760657954398e-1,
         2.646395056122003e-17,
         -2.9073234590199323e-36,
-        -1.2938328629395878e-52,
+        -1.2938328629395601e-52,
     ),
     Quad(
         9.629532668736839e-1,
         8.934196040431363e-18,
         -3.9071244661020126e-34,
-        1.6212091116847368e-50,
+        1.6212091116847394e-50,
     ),
     Quad(
         9.621214042690416e-1,
         1.5236770453846305e-17,
         -1.3050173525597142e-33,
-        7.9016122394092685e-50,
+        7.901612239409267e-50,
     ),
     Quad(
         9.612804858113206e-1,
         2.093395521667404e-18,
         1.0768607469015692e-34,
-        -5.945363930436221e-51,
+        -5.945363930436177e-51,
     ),
     Quad(
         9.604305194155658e-1,
         2.4653904815317185e-17,
         -1.3792169410906322e-33,
-        -4.772659837850652e-51,
+        -4.7726598378506903e-51,
     ),
     Quad(
         9.595715130819845e-1,
         1.1000640085000957e-17,
         -4.2036030828223975e-34,
-        4.002370484260642e-51,
+        4.0023704842606573e-51,
     ),
     Quad(
         9.587034748958716e-1,
         -4.3685014392372053e-17,
         2.200180066272913e-33,
-        -1.0553721324358075e-49,
+        -1.0553721324358077e-49,
     ),
     Quad(
         9.578264130275329e-1,
         -1.7696710075371263e-17,
         1.916403411038219e-34,
-        8.148923507175447e-51,
+        8.148923507175481e-51,
     ),
     Quad(
         9.569403357322088e-1,
         4.05538698618757e-17,
         -1.714701336430215e-33,
-        2.573674529532947e-50,
+        2.5736745295329455e-50,
     ),
     Quad(
         9.560452513499964e-1,
@@ -2233,169 +2316,169 @@ pub const COSINES: [Quad; 256] = [
         9.551411683057707e-1,
         5.008865295501467e-17,
         -2.698318183805921e-33,
-        1.0102323575596497e-49,
+        1.0102323575596493e-49,
     ),
     Quad(
         9.542280951091057e-1,
         -3.7545901690626874e-17,
         1.4951619241257764e-33,
-        -8.271733315139502e-50,
+        -8.271733315139497e-50,
     ),
     Quad(
         9.533060403541939e-1,
         -2.5190738779919934e-17,
         -1.4272239821134379e-33,
-        -4.671728680928317e-50,
+        -4.671728680928316e-50,
     ),
     Quad(
         9.523750127197659e-1,
         -2.0269300462299272e-17,
         -1.0635956887246246e-33,
-        -3.5514537666487615e-50,
+        -3.551453766648762e-50,
     ),
     Quad(
         9.514350209690083e-1,
         3.1350584123266695e-17,
         -2.4824833452737813e-33,
-        9.545033552538072e-51,
+        9.545033552538061e-51,
     ),
     Quad(
         9.504860739494817e-1,
         1.9410097562630436e-17,
         -8.155939394981679e-34,
-        -1.0501209720164549e-50,
+        -1.0501209720164562e-50,
     ),
     Quad(
         9.495281805930367e-1,
         -7.55441519280433e-18,
         -5.1260245024046686e-34,
-        1.8093643389040408e-50,
+        1.8093643389040406e-50,
     ),
     Quad(
         9.485613499157303e-1,
         2.0668262262333232e-17,
         -5.944073024366731e-34,
-        1.4268853111554324e-50,
+        1.42688531115543e-50,
     ),
     Quad(
         9.475855910177411e-1,
         4.341799385212599e-17,
         -2.7728667889840373e-34,
-        5.570916019652006e-51,
+        5.570916019651997e-51,
     ),
     Quad(
         9.466009130832835e-1,
         3.505680021068073e-17,
         9.857853694031812e-34,
-        6.6035911064585235e-50,
+        6.60359110645852e-50,
     ),
     Quad(
         9.456073253805213e-1,
         4.601910247852374e-17,
         -6.253438476945206e-34,
-        1.5758941215779988e-50,
+        1.5758941215779961e-50,
     ),
     Quad(
         9.446048372614803e-1,
         8.810054547664117e-18,
         5.229169560275784e-34,
-        -3.3487256018407157e-50,
+        -3.3487256018407123e-50,
     ),
     Quad(
         9.435934581619604e-1,
         -2.4093127844404214e-17,
         1.028327985680394e-34,
-        -2.3398232614530868e-51,
+        -2.3398232614531355e-51,
     ),
     Quad(
         9.425731976014469e-1,
         1.3235564806436886e-17,
         -5.704826288538691e-35,
-        3.994705044275339e-51,
+        3.9947050442753744e-51,
     ),
     Quad(
         9.415440651830208e-1,
         -2.789637954769834e-17,
         1.62732363567339e-33,
-        -5.3075944708470906e-51,
+        -5.30759447084712e-51,
     ),
     Quad(
         9.405060705932683e-1,
         2.861042156711627e-17,
         2.9261501147538827e-33,
-        -2.68498676908969e-50,
+        -2.6849867690896925e-50,
     ),
     Quad(
         9.394592236021899e-1,
         -7.015286794309865e-18,
         -5.639569381801121e-34,
-        3.55681426789876e-50,
+        3.556814267898765e-50,
     ),
     Quad(
         9.384035340631081e-1,
         5.424254504479549e-17,
         -1.903996660785976e-33,
-        -1.5627792988341221e-49,
+        -1.5627792988341215e-49,
     ),
     Quad(
         9.37339011912575e-1,
         -3.6570926284362776e-17,
         -1.1902940071273247e-33,
-        -1.1215082331583266e-50,
+        -1.1215082331583223e-50,
     ),
     Quad(
         9.362656671702783e-1,
         -1.3013766145497654e-17,
         5.2229870061990595e-34,
-        -3.3972777075633675e-51,
+        -3.397277707563411e-51,
     ),
     Quad(
         9.351835099389476e-1,
         -3.2609395302485065e-17,
         -8.181301521887525e-34,
-        5.564214002492817e-50,
+        5.564214002492814e-50,
     ),
     Quad(
         9.340925504042589e-1,
         4.466282436076751e-17,
         -2.5903243047396916e-33,
-        8.150520900434308e-50,
+        8.150520900434304e-50,
     ),
     Quad(
         9.329927988347388e-1,
         4.2041415555384355e-17,
         9.028589649552128e-34,
-        5.301998497766128e-50,
+        5.301998497766126e-50,
     ),
     Quad(
         9.318842655816681e-1,
         -4.0785944377318095e-17,
         1.763145029875417e-33,
-        2.57764033055074e-50,
+        2.5776403305507453e-50,
     ),
     Quad(
         9.307669610789837e-1,
         1.970377510283833e-17,
         6.5657908718278205e-34,
-        -1.9480347966259367e-51,
+        -1.9480347966259524e-51,
     ),
     Quad(
         9.296408958431812e-1,
         5.128253001686411e-17,
         2.371973989191626e-34,
-        -1.7230065426917108e-50,
+        -1.7230065426917127e-50,
     ),
     Quad(
         9.285060804732156e-1,
         -2.3306639848485943e-17,
         -7.77990843332085e-34,
-        -5.859755800930029e-50,
+        -5.859755800930031e-50,
     ),
     Quad(
         9.273625256504011e-1,
         -2.7677111692155437e-17,
         2.2110293450199576e-34,
-        2.0349190819680565e-50,
+        2.0349190819680613e-50,
     ),
     Quad(
         9.262102421383114e-1,
@@ -2407,19 +2490,19 @@ pub const COSINES: [Quad; 256] = [
         9.250492407826776e-1,
         6.052944741257616e-18,
         -8.825651776027854e-35,
-        1.82854621223887e-51,
+        1.8285462122388328e-51,
     ),
     Quad(
         9.238795325112867e-1,
         1.7645047084336677e-17,
         -5.044253732158682e-34,
-        -4.047867771682387e-50,
+        -4.047867771682389e-50,
     ),
     Quad(
         9.227011283338785e-1,
         5.2963798918539814e-17,
         -5.7135699628876685e-34,
-        3.016367179721905e-50,
+        3.0163671797219087e-50,
     ),
     Quad(
         9.215140393420419e-1,
@@ -2431,13 +2514,13 @@ pub const COSINES: [Quad; 256] = [
         9.203182767091106e-1,
         -2.780688877903684e-17,
         2.7011013677071274e-33,
-        1.1998578792455505e-49,
+        1.1998578792455499e-49,
     ),
     Quad(
         9.191138516900578e-1,
         -2.6496484622344718e-17,
         6.540360476346192e-34,
-        -2.899718020118612e-50,
+        -2.899718020118608e-50,
     ),
     Quad(
         9.179007756213905e-1,
@@ -2449,79 +2532,79 @@ pub const COSINES: [Quad; 256] = [
         9.166790599210427e-1,
         -4.173397869828757e-17,
         1.2094444804381172e-33,
-        4.93569168260978e-50,
+        4.935691682609782e-50,
     ),
     Quad(
         9.154487160882678e-1,
         -1.3591056692900894e-17,
         5.9923027475594735e-34,
-        2.140329592596286e-50,
+        2.140329592596288e-50,
     ),
     Quad(
         9.142097557035307e-1,
         -3.631618252781442e-17,
         -1.9438819777122554e-33,
-        2.834067928772836e-50,
+        2.8340679287728316e-50,
     ),
     Quad(
         9.129621904283982e-1,
         -4.793250522803947e-17,
         -1.775355188942864e-33,
-        4.060778290386794e-51,
+        4.060778290386816e-51,
     ),
     Quad(
         9.117060320054299e-1,
         -2.691327317503413e-17,
         -5.192810191616253e-35,
-        1.1338175936090927e-51,
+        1.133817593609063e-51,
     ),
     Quad(
         9.104412922580672e-1,
         -5.043304167331382e-17,
         1.0938746257404305e-33,
-        9.537827208417024e-51,
+        9.537827208417073e-51,
     ),
     Quad(
         9.091679830905224e-1,
         -3.6878564091359894e-18,
         2.9951330310507693e-34,
-        -1.2225666136919918e-50,
+        -1.2225666136919926e-50,
     ),
     Quad(
         9.078861164876663e-1,
         -4.945996430122584e-17,
         -1.6599682707075313e-33,
-        -5.1925202712634725e-50,
+        -5.192520271263472e-50,
     ),
     Quad(
         9.065957045149153e-1,
         3.0506718955442023e-17,
         -1.4478836557141204e-33,
-        1.8906373784448763e-50,
+        1.8906373784448725e-50,
     ),
     Quad(
         9.052967593181188e-1,
         -4.11530998268899e-17,
         2.9859368705184223e-33,
-        5.114529391743917e-50,
+        5.114529391743921e-50,
     ),
     Quad(
         9.039892931234433e-1,
         -6.609754468748431e-18,
         1.2728013034680357e-34,
-        -4.302609723401478e-51,
+        -4.302609723401482e-51,
     ),
     Quad(
         9.026733182372588e-1,
         -1.9250787033961483e-17,
         1.3242128993244527e-33,
-        -5.297103068870367e-50,
+        -5.297103068870366e-50,
     ),
     Quad(
         9.01348847046022e-1,
         -1.3524789367698682e-17,
         6.360535311588009e-34,
-        3.622740065457378e-50,
+        3.622740065457383e-50,
     ),
     Quad(
         9.000158920161603e-1,
@@ -2533,199 +2616,199 @@ pub const COSINES: [Quad; 256] = [
         8.986744656939538e-1,
         2.6316906461033013e-17,
         3.700313704779684e-35,
-        -2.3447719900465505e-51,
+        -2.3447719900465938e-51,
     ),
     Quad(
         8.973245807054183e-1,
         -3.639628331486729e-17,
         -2.3611649895474815e-33,
-        1.1837247047900086e-49,
+        1.1837247047900082e-49,
     ),
     Quad(
         8.959662497561851e-1,
         4.9025099114811813e-17,
         -1.9440489814795326e-33,
-        -1.7070486667767029e-49,
+        -1.7070486667767033e-49,
     ),
     Quad(
         8.945994856313827e-1,
         -1.751622639681492e-17,
         -1.3200670047246923e-33,
-        -1.595300988432466e-50,
+        -1.5953009884324695e-50,
     ),
     Quad(
         8.932243011955153e-1,
         -4.116123915190891e-18,
         2.5380253805716e-34,
-        4.2849455510515997e-51,
+        4.284945551051619e-51,
     ),
     Quad(
         8.918407093923427e-1,
         4.669022813712455e-18,
         1.6150254286841982e-34,
-        -3.961744882072459e-51,
+        -3.961744882072501e-51,
     ),
     Quad(
         8.904487232447579e-1,
         1.1781931459051803e-17,
         -1.334614220957193e-34,
-        -9.498237353073309e-51,
+        -9.498237353073343e-51,
     ),
     Quad(
         8.890483558546646e-1,
         -1.1164514966766675e-17,
         -3.4797636107798736e-34,
-        -1.5605079997040665e-50,
+        -1.5605079997040631e-50,
     ),
     Quad(
         8.876396204028539e-1,
         1.280509191858796e-17,
         3.9948742059584464e-35,
-        -1.4514577876506005e-51,
+        -1.4514577876505774e-51,
     ),
     Quad(
         8.862225301488806e-1,
         -6.7307369600274315e-18,
         1.2385593432917413e-34,
-        2.0364014759133513e-51,
+        2.036401475913332e-51,
     ),
     Quad(
         8.847970984309378e-1,
         -9.433146962897269e-18,
         -5.710654147870144e-34,
-        1.8260134111907352e-50,
+        1.8260134111907397e-50,
     ),
     Quad(
         8.833633386657316e-1,
         1.5822643380255127e-17,
         -7.892132000758825e-34,
-        -1.4782321016179826e-50,
+        -1.4782321016179836e-50,
     ),
     Quad(
         8.81921264348355e-1,
         -1.9843248405890562e-17,
         -7.041211400767383e-34,
-        -1.0636770169389118e-50,
+        -1.0636770169389104e-50,
     ),
     Quad(
         8.804708890521608e-1,
         1.631109660299635e-17,
         -5.754136059472417e-34,
-        -4.012861186216998e-50,
+        -4.012861186217002e-50,
     ),
     Quad(
         8.790122264286335e-1,
         -4.735683729111801e-17,
         1.4388771297975192e-33,
-        -2.908555430447914e-50,
+        -2.9085554304479134e-50,
     ),
     Quad(
         8.775452902072612e-1,
         5.011331184649955e-17,
         2.8382769008739543e-34,
-        1.555064039316411e-50,
+        1.555064039316414e-50,
     ),
     Quad(
         8.760700941954066e-1,
         5.872902423514768e-18,
         2.7941144391738458e-34,
-        -1.8536073846509857e-50,
+        -1.8536073846509828e-50,
     ),
     Quad(
         8.745866522781761e-1,
         -5.7216617730397065e-19,
         -2.9705811503689596e-35,
-        8.738959396979377e-52,
+        8.738959396979675e-52,
     ),
     Quad(
         8.730949784182901e-1,
         7.84246729901299e-18,
         -4.8685015839797165e-34,
-        -2.281557058747757e-50,
+        -2.2815570587477527e-50,
     ),
     Quad(
         8.715950866559511e-1,
         -5.527299803855105e-17,
         -2.2104090204984907e-33,
-        -9.774976318764323e-50,
+        -9.77497631876432e-50,
     ),
     Quad(
         8.700869911087115e-1,
         -4.188851086854997e-17,
         7.0900185861878415e-34,
-        3.760025111515731e-50,
+        3.760025111515726e-50,
     ),
     Quad(
         8.685707059713409e-1,
         2.7192781689782903e-19,
         -1.6710140396932428e-35,
-        -1.2625514734637684e-51,
+        -1.2625514734637969e-51,
     ),
     Quad(
         8.670462455156926e-1,
         3.0267859550930567e-18,
         -1.1559438782171572e-34,
-        -5.3580556397813034e-52,
+        -5.358055639780801e-52,
     ),
     Quad(
         8.655136240905691e-1,
         -6.37231135496289e-18,
         2.3725520321746832e-34,
-        1.5911880348395177e-50,
+        1.5911880348395175e-50,
     ),
     Quad(
         8.639728561215867e-1,
         4.148635595736161e-17,
         2.2709976932210266e-33,
-        -8.122838565947999e-50,
+        -8.122838565947998e-50,
     ),
     Quad(
         8.624239561110405e-1,
         3.700899252738313e-17,
         5.212841154270157e-34,
-        2.694560008102689e-50,
+        2.694560008102686e-50,
     ),
     Quad(
         8.608669386377673e-1,
         -3.0050048898573656e-17,
         -8.870618309089211e-34,
-        1.5005320558097258e-50,
+        1.50053205580973e-50,
     ),
     Quad(
         8.593018183570084e-1,
         4.243565581685069e-17,
         7.6181814059912025e-34,
-        -3.9592127850658727e-50,
+        -3.959212785065871e-50,
     ),
     Quad(
         8.577286100002721e-1,
         -4.818344793633662e-17,
         -1.1044130517687533e-33,
-        8.365670800125491e-50,
+        8.365670800125496e-50,
     ),
     Quad(
         8.561473283751945e-1,
         9.180692561660626e-18,
         5.632864978595147e-34,
-        2.332664611321735e-51,
+        2.3326646113217378e-51,
     ),
     Quad(
         8.545579883654005e-1,
         -1.2991124236396092e-17,
         1.289340772294808e-34,
-        -3.650692574758651e-52,
+        -3.650692574758305e-52,
     ),
     Quad(
         8.529606049303636e-1,
         2.715298425198137e-17,
         7.433648328312072e-34,
-        4.2162417622350716e-50,
+        4.216241762235067e-50,
     ),
     Quad(
         8.513551931052652e-1,
         -5.327987444601621e-17,
         2.228115638091994e-33,
-        -4.0281886404138467e-50,
+        -4.0281886404138477e-50,
     ),
     Quad(
         8.497417680008524e-1,
@@ -2737,7 +2820,7 @@ pub const COSINES: [Quad; 256] = [
         8.481203448032972e-1,
         1.876256341523998e-17,
         1.4048773307919617e-33,
-        -2.4915221509958706e-50,
+        -2.491522150995869e-50,
     ),
     Quad(
         8.464909387740521e-1,
@@ -2749,61 +2832,61 @@ pub const COSINES: [Quad; 256] = [
         8.448535652497071e-1,
         -4.363136029687964e-17,
         -2.0307726853367547e-33,
-        4.309722981985171e-50,
+        4.309722981985176e-50,
     ),
     Quad(
         8.432082396418454e-1,
         9.653670700595908e-19,
         2.8995142431556364e-36,
-        9.671507681146747e-53,
+        9.671507681148028e-53,
     ),
     Quad(
         8.415549774368984e-1,
         -3.4095465391321557e-17,
         -8.41302086075796e-34,
-        -4.944728396056864e-50,
+        -4.944728396056869e-50,
     ),
     Quad(
         8.398937941959995e-1,
         -1.667369488151141e-17,
         -1.475918414175029e-33,
-        -7.579509816191407e-50,
+        -7.579509816191406e-50,
     ),
     Quad(
         8.382247055548381e-1,
         -3.5560085052855026e-17,
         1.1689791577022643e-33,
-        -5.862734735972339e-50,
+        -5.862734735972341e-50,
     ),
     Quad(
         8.36547727223512e-1,
         -2.0899059027066533e-17,
         -9.810409782100259e-35,
-        -3.1609177868229444e-51,
+        -3.1609177868229853e-51,
     ),
     Quad(
         8.3486287498638e-1,
         4.604843060915966e-17,
         -5.182742326523991e-34,
-        -7.050534343550388e-51,
+        -7.050534343550411e-51,
     ),
     Quad(
         8.331701647019132e-1,
         1.3275129507229764e-18,
         4.858916411537086e-35,
-        4.5422281300507203e-51,
+        4.542228130050686e-51,
     ),
     Quad(
         8.314696123025452e-1,
         1.4073856984728024e-18,
         4.6951315383980835e-35,
-        -2.023388151938547e-52,
+        -2.023388151938257e-52,
     ),
     Quad(
         8.29761233794523e-1,
         -2.9349109376485597e-18,
         1.1496917934149818e-34,
-        3.5186665544980245e-51,
+        3.518666554498023e-51,
     ),
     Quad(
         8.280450452577558e-1,
@@ -2815,313 +2898,313 @@ pub const COSINES: [Quad; 256] = [
         8.263210628456635e-1,
         -5.39574854536129e-17,
         6.897689613013855e-34,
-        3.8106164274199186e-50,
+        3.8106164274199196e-50,
     ),
     Quad(
         8.245893027850253e-1,
         -2.6512360488868275e-17,
         1.6916964350914386e-34,
-        6.76939748135625e-51,
+        6.769397481356265e-51,
     ),
     Quad(
         8.228497813758263e-1,
         1.5193019034505495e-17,
         9.689054724652168e-34,
-        5.699456292365327e-50,
+        5.699456292365326e-50,
     ),
     Quad(
         8.211025149911046e-1,
         3.071513160969768e-17,
         -1.703716832585588e-33,
-        -1.114986244328385e-49,
+        -1.1149862443283853e-49,
     ),
     Quad(
         8.19347520076797e-1,
         -4.8200736995191133e-17,
         -1.557448964667278e-35,
-        -9.564785361451192e-53,
+        -9.564785361452222e-53,
     ),
     Quad(
         8.175848131515837e-1,
         -1.4883149812426772e-17,
         -7.827326277129892e-34,
-        4.1332149161031623e-50,
+        4.1332149161031594e-50,
     ),
     Quad(
         8.158144108067338e-1,
         8.265269378213087e-18,
         -2.302877813517947e-34,
-        1.5102071387249817e-50,
+        1.5102071387249843e-50,
     ),
     Quad(
         8.140363297059484e-1,
         -5.2127351877042624e-17,
         -1.904767061131636e-33,
-        -1.693726958594151e-49,
+        -1.6937269585941509e-49,
     ),
     Quad(
         8.122505865852039e-1,
         3.10545456092148e-17,
         2.264954192270725e-34,
-        -7.422168415464942e-51,
+        -7.42216841546494e-51,
     ),
     Quad(
         8.104571982525948e-1,
         2.35203673498405e-17,
         -7.753007090484634e-34,
-        -7.27926163571971e-50,
+        -7.279261635719714e-50,
     ),
     Quad(
         8.08656181588175e-1,
         9.325159787972167e-18,
         -7.182330193306839e-34,
-        2.392544084613215e-50,
+        2.3925440846132106e-50,
     ),
     Quad(
         8.068475535437992e-1,
         4.9220603766095546e-17,
         2.9796016899903487e-33,
-        1.5220754223615785e-49,
+        1.5220754223615788e-49,
     ),
     Quad(
         8.050313311429635e-1,
         5.136828956821215e-17,
         6.308280740225652e-34,
-        7.327764608512981e-51,
+        7.327764608512983e-51,
     ),
     Quad(
         8.032075314806449e-1,
         -3.306060980481491e-17,
         -1.2242726252420433e-33,
-        2.841367326863007e-50,
+        2.8413673268630117e-50,
     ),
     Quad(
         8.013761717231402e-1,
         -2.0958013413495834e-17,
         -4.379816219800693e-34,
-        2.023569049775252e-50,
+        2.0235690497752515e-50,
     ),
     Quad(
         7.99537269107905e-1,
         2.035672382200543e-17,
         -9.744851369689636e-34,
-        5.360810959969726e-52,
+        5.360810959969601e-52,
     ),
     Quad(
         7.976908409433912e-1,
         -4.6730759884788944e-17,
         2.3075897077191757e-33,
-        3.160556777464022e-51,
+        3.1605567774640253e-51,
     ),
     Quad(
         7.958369046088836e-1,
         -3.006272485191072e-17,
         -2.2496210832042235e-33,
-        -6.588177411718306e-50,
+        -6.588177411718304e-50,
     ),
     Quad(
         7.939754775543372e-1,
         -7.419463175992142e-18,
         2.412434130463107e-34,
-        -4.9956808616245025e-51,
+        -4.995680861624497e-51,
     ),
     Quad(
         7.921065773002124e-1,
         -3.7087850202326467e-17,
         -1.4874457267228264e-33,
-        2.932309728915352e-50,
+        2.9323097289153505e-50,
     ),
     Quad(
         7.9023022143731e-1,
         2.3056905954954492e-17,
         1.4481080533260193e-33,
-        -7.672523705720347e-50,
+        -7.672523705720349e-50,
     ),
     Quad(
         7.883464276266062e-1,
         3.439699315405971e-17,
         1.771062374673717e-33,
-        1.7084159098417406e-49,
+        1.7084159098417402e-49,
     ),
     Quad(
         7.864552135990858e-1,
         -9.784142993930527e-18,
         3.3906063272445472e-34,
-        5.726950532038297e-51,
+        5.726950532038258e-51,
     ),
     Quad(
         7.845565971555752e-1,
         -8.562796542317348e-18,
         -2.110683445900185e-34,
-        -1.689032218246961e-50,
+        -1.6890322182469603e-50,
     ),
     Quad(
         7.826505961665757e-1,
         9.074586697580882e-18,
         6.762384740427867e-34,
-        -1.7173237731987254e-50,
+        -1.717323773198727e-50,
     ),
     Quad(
         7.807372285720945e-1,
         -9.91987820666788e-18,
         -2.1265794012162715e-36,
-        3.0772165599031686e-54,
+        3.0772165598957647e-54,
     ),
     Quad(
         7.78816512381476e-1,
         -2.4891385579973807e-17,
         6.766549702480798e-35,
-        -6.521859428169817e-52,
+        -6.521859428170133e-52,
     ),
     Quad(
         7.768884656732324e-1,
         7.741860257067286e-18,
         -5.99865178721579e-34,
-        3.0566548232958976e-50,
+        3.056654823295897e-50,
     ),
     Quad(
         7.749531065948739e-1,
         -5.220908318982643e-17,
         -9.665359339368661e-34,
-        3.7027750076562617e-50,
+        3.702775007656257e-50,
     ),
     Quad(
         7.73010453362737e-1,
         -3.256590703364977e-17,
         1.386080725152393e-33,
-        -3.997132991758601e-50,
+        -3.997132991758602e-50,
     ),
     Quad(
         7.710605242618138e-1,
         -4.4558442347769265e-17,
         -2.9863565614083783e-33,
-        -6.879526208359629e-50,
+        -6.879526208359624e-50,
     ),
     Quad(
         7.691033376455796e-1,
         5.1546455184564817e-17,
         2.614282955352429e-33,
-        -1.6199023632773295e-49,
+        -1.6199023632773298e-49,
     ),
     Quad(
         7.671389119358204e-1,
         -1.8885903683750782e-17,
         -1.3659359331495433e-33,
-        -2.2538834962921967e-50,
+        -2.2538834962921934e-50,
     ),
     Quad(
         7.65167265622459e-1,
         -3.27072256125346e-17,
         1.1177117747079528e-33,
-        -3.700518228017568e-50,
+        -3.7005182280175715e-50,
     ),
     Quad(
         7.631884172633813e-1,
         2.631474841675075e-18,
         1.404803906309591e-34,
-        8.960188662663182e-52,
+        8.960188662663032e-52,
     ),
     Quad(
         7.612023854842618e-1,
         3.531551088169055e-17,
         1.2833566381864359e-33,
-        -8.483550626500987e-50,
+        -8.483550626500991e-50,
     ),
     Quad(
         7.592091889783881e-1,
         -3.855884217552312e-17,
         2.972024120833276e-34,
-        -1.2521388928220154e-50,
+        -1.2521388928220163e-50,
     ),
     Quad(
         7.572088465064846e-1,
         -1.9909098777335502e-17,
         3.940928326615848e-34,
-        2.0744254207803002e-50,
+        2.0744254207802976e-50,
     ),
     Quad(
         7.552013768965365e-1,
         -1.9402238001823017e-17,
         -3.7756206444727573e-34,
-        -2.1212242308178273e-50,
+        -2.1212242308178287e-50,
     ),
     Quad(
         7.531867990436125e-1,
         -3.793778983873654e-17,
         -6.700953992023156e-34,
-        -6.712856211505067e-51,
+        -6.712856211505021e-51,
     ),
     Quad(
         7.511651319096864e-1,
         4.349976115864587e-17,
         2.5227718971102212e-33,
-        -6.596970921275714e-50,
+        -6.59697092127571e-50,
     ),
     Quad(
         7.491363945234594e-1,
         -4.472907844701189e-17,
         -2.4206025249983768e-33,
-        1.1336681351116422e-49,
+        1.1336681351116424e-49,
     ),
     Quad(
         7.471006059801801e-1,
         1.187482487596543e-17,
         2.1992523849833518e-34,
-        1.1025018564644497e-50,
+        1.1025018564644483e-50,
     ),
     Quad(
         7.45057785441466e-1,
         1.5078686911877863e-17,
         8.089898721294247e-34,
-        8.267795876532348e-50,
+        8.267795876532353e-50,
     ),
     Quad(
         7.430079521351217e-1,
         -2.5144629669719265e-17,
         7.112898951252616e-34,
-        3.018162907782123e-50,
+        3.018162907782122e-50,
     ),
     Quad(
         7.409511253549591e-1,
         -1.4708616952297345e-17,
         -4.955043382714203e-34,
-        3.1434132533735623e-50,
+        3.143413253373567e-50,
     ),
     Quad(
         7.388873244606151e-1,
         3.432487480822509e-17,
         -1.370663944471761e-33,
-        -3.3520827530719466e-51,
+        -3.352082753071894e-51,
     ),
     Quad(
         7.368165688773699e-1,
         -2.8932468101656295e-17,
         -3.464988712620238e-34,
-        -1.8484474476291473e-50,
+        -1.8484474476291476e-50,
     ),
     Quad(
         7.347388780959635e-1,
         -3.450759597626394e-17,
         -2.371800067666641e-33,
-        -3.9696090387165364e-50,
+        -3.96960903871654e-50,
     ),
     Quad(
         7.326542716724128e-1,
         1.891867348157352e-17,
         -1.5123719544119886e-33,
-        -9.792215201162556e-51,
+        -9.792215201162573e-51,
     ),
     Quad(
         7.305627692278276e-1,
         -2.968995990447693e-17,
         -1.1276871244239744e-33,
-        -3.0531520961538974e-50,
+        -3.0531520961539007e-50,
     ),
     Quad(
         7.284643904482252e-1,
         1.1924642323370718e-19,
         5.900189231661101e-36,
-        1.2178089069507088e-52,
+        1.2178089069502704e-52,
     ),
     Quad(
         7.26359155084346e-1,
@@ -3133,54 +3216,1805 @@ pub const COSINES: [Quad; 256] = [
         7.242470829514669e-1,
         2.9198471334403004e-17,
         2.3027324968739464e-33,
-        -1.2928820533891935e-51,
+        -1.2928820533892183e-51,
     ),
     Quad(
         7.221281939292153e-1,
         -2.3871262053452047e-17,
         1.0636125432862273e-33,
-        -4.4598638837802555e-50,
+        -4.459863883780252e-50,
     ),
     Quad(
         7.200025079613817e-1,
         -2.5689658854462333e-17,
         -9.149256694856793e-34,
-        4.440378080126777e-50,
+        4.440378080126779e-50,
     ),
     Quad(
         7.178700450557317e-1,
         2.7006476062511453e-17,
         -2.285495658021535e-34,
-        9.172690389028812e-51,
+        9.172690389028787e-51,
     ),
     Quad(
         7.157308252838187e-1,
         -5.158101847641026e-17,
         -1.3736271349300261e-34,
-        8.647506336626293e-51,
+        8.647506336626268e-51,
     ),
     Quad(
         7.135848687807936e-1,
         -4.2342504403133584e-17,
         -4.269036610161727e-34,
-        -2.6352370883066546e-50,
+        -2.635237088306652e-50,
     ),
     Quad(
         7.114321957452164e-1,
         7.964329861385681e-18,
         2.948823951072147e-34,
-        1.6985236437666341e-50,
+        1.6985236437666356e-50,
     ),
     Quad(
         7.092728264388657e-1,
         -3.759735911024573e-17,
         1.061312595464512e-34,
-        8.946548018548639e-51,
+        8.946548018548603e-51,
     ),
     Quad(
         7.071067811865476e-1,
         -4.833646656726457e-17,
         2.0693376543497068e-33,
-        2.467773495734176e-50,
+        2.4677734957341755e-50,
     ),
 ];
+
+/// The powers of ten from 10<sup>0</sup> through 10<sup>308</sup>, the largest that's
+/// finite. `POWERS_OF_TEN[k]` is 10<sup>k</sup> rendered one component at a time, each
+/// component being the `f64` closest to what the components before it leave over, so the
+/// powers that a `Quad` can hold exactly are exact, and the rest are as close as a `Quad`
+/// gets. Building them by repeated multiplication instead piles up an error with every
+/// step.
+pub const POWERS_OF_TEN: [Quad; 309] = [
+    Quad(1e0, 0e0, 0e0, 0e0),
+    Quad(1e1, 0e0, 0e0, 0e0),
+    Quad(1e2, 0e0, 0e0, 0e0),
+    Quad(1e3, 0e0, 0e0, 0e0),
+    Quad(1e4, 0e0, 0e0, 0e0),
+    Quad(1e5, 0e0, 0e0, 0e0),
+    Quad(1e6, 0e0, 0e0, 0e0),
+    Quad(1e7, 0e0, 0e0, 0e0),
+    Quad(1e8, 0e0, 0e0, 0e0),
+    Quad(1e9, 0e0, 0e0, 0e0),
+    Quad(1e10, 0e0, 0e0, 0e0),
+    Quad(1e11, 0e0, 0e0, 0e0),
+    Quad(1e12, 0e0, 0e0, 0e0),
+    Quad(1e13, 0e0, 0e0, 0e0),
+    Quad(1e14, 0e0, 0e0, 0e0),
+    Quad(1e15, 0e0, 0e0, 0e0),
+    Quad(1e16, 0e0, 0e0, 0e0),
+    Quad(1e17, 0e0, 0e0, 0e0),
+    Quad(1e18, 0e0, 0e0, 0e0),
+    Quad(1e19, 0e0, 0e0, 0e0),
+    Quad(1e20, 0e0, 0e0, 0e0),
+    Quad(1e21, 0e0, 0e0, 0e0),
+    Quad(1e22, 0e0, 0e0, 0e0),
+    Quad(1e23, 8.388608e6, 0e0, 0e0),
+    Quad(1e24, 1.6777216e7, 0e0, 0e0),
+    Quad(1e25, -9.05969664e8, 0e0, 0e0),
+    Quad(1e26, -4.764729344e9, 0e0, 0e0),
+    Quad(1e27, -1.3287555072e10, 0e0, 0e0),
+    Quad(1e28, 4.16880263168e11, 0e0, 0e0),
+    Quad(1e29, 8.566849142784e12, 0e0, 0e0),
+    Quad(1e30, -1.9884624838656e13, 0e0, 0e0),
+    Quad(1e31, 3.64103705034752e14, 0e0, 0e0),
+    Quad(1e32, -5.366162204393472e15, 0e0, 0e0),
+    Quad(1e33, 5.442476901295718e16, 0e0, 0e0),
+    Quad(1e34, 5.4424769012957184e17, 0e0, 0e0),
+    Quad(1e35, 3.1366338920820244e18, 0e0, 0e0),
+    Quad(1e36, -4.242063737401796e19, 0e0, 0e0),
+    Quad(1e37, 4.6123734179787886e20, 0e0, 0e0),
+    Quad(1e38, 2.251190176543966e21, 0e0, 0e0),
+    Quad(1e39, 6.029083362839682e22, 0e0, 0e0),
+    Quad(1e40, -3.037860284270037e23, 0e0, 0e0),
+    Quad(1e41, -6.200086450407783e23, 0e0, 0e0),
+    Quad(1e42, -4.488571267807592e25, 0e0, 0e0),
+    Quad(1e43, -1.393721169594141e26, 0e0, 0e0),
+    Quad(1e44, -8.821361405306423e27, 0e0, 0e0),
+    Quad(1e45, 7.024271097546445e28, 0e0, 0e0),
+    Quad(1e46, 6.860180964052979e28, 0e0, 0e0),
+    Quad(1e47, -4.38458430450762e30, 1.40737488355328e14, 0e0),
+    Quad(1e48, -4.38458430450762e31, 2.81474976710656e14, 0e0),
+    Quad(1e49, 5.3509723052451824e32, -3.321404725185741e16, 0e0),
+    Quad(1e50, -7.629769841091887e33, 3.884354678607053e17, 0e0),
+    Quad(1e51, 6.779051325638372e33, 4.255901647865119e17, 0e0),
+    Quad(1e52, 6.779051325638372e34, -2.661627379775963e18, 0e0),
+    Quad(1e53, 6.779051325638373e35, -6.3509761945178735e19, 0e0),
+    Quad(1e54, -7.829154040459625e37, 2.316381432341741e21, 0e0),
+    Quad(1e55, -1.0235067020408552e38, 4.2743483919388283e21, 0e0),
+    Quad(1e56, -9.190283508143379e39, 3.449749388230456e23, 0e0),
+    Quad(1e57, -4.834669211555366e40, 3.449749388230456e24, 0e0),
+    Quad(1e58, 5.618805100255864e41, -2.3530945459197643e25, 0e0),
+    Quad(1e59, 2.831211950439536e42, -2.3530945459197643e26, 0e0),
+    Quad(1e60, 5.061286470292598e43, -3.5910345852051445e27, 0e0),
+    Quad(1e61, 5.061286470292598e44, -1.610330522348536e28, 0e0),
+    Quad(1e62, -3.5021996859431613e45, 1.5587959782220374e29, 0e0),
+    Quad(1e63, -5.785795994272697e46, 2.826446578450267e30, 0e0),
+    Quad(1e64, -2.1320419009454396e47, -1.2300353422800673e31, 0e0),
+    Quad(1e65, 7.909613737163662e47, -4.187389581340005e31, 0e0),
+    Quad(1e66, 5.467766613175255e49, 3.800002239425547e33, 0e0),
+    Quad(1e67, 1.726322421608144e50, -3.538352474023151e33, 0e0),
+    Quad(1e68, 4.719477774861833e51, 2.9692347420599746e35, 0e0),
+    Quad(1e69, -7.253143638152923e52, -3.676905236864605e36, 0e0),
+    Quad(
+        1e70,
+        -7.253143638152923e53,
+        -3.676905236864605e37,
+        1.1805916207174113e21,
+    ),
+    Quad(
+        1e71,
+        -4.1881525564211456e54,
+        -1.9754934022599124e38,
+        -7.083549724304468e21,
+    ),
+    Quad(
+        1e72,
+        5.619818905120543e55,
+        -6.1436393457615864e38,
+        4.722366482869645e21,
+    ),
+    Quad(
+        1e73,
+        1.6966303205038675e56,
+        -6.143639345761586e39,
+        -2.5500779007496084e23,
+    ),
+    Quad(
+        1e74,
+        4.8351811881972075e57,
+        2.870127502694251e41,
+        9.539180295396683e24,
+    ),
+    Quad(
+        1e75,
+        7.34602188235188e58,
+        -4.0988553718465687e42,
+        1.727630554093031e26,
+    ),
+    Quad(
+        1e76,
+        -4.706013449590547e59,
+        2.5913681877126184e43,
+        4.896905148076507e26,
+    ),
+    Quad(
+        1e77,
+        1.721738727445414e60,
+        8.073085718301685e43,
+        4.8969051480765073e27,
+    ),
+    Quad(
+        1e78,
+        -8.493621433689703e60,
+        9.368472547718864e43,
+        9.354970223632904e27,
+    ),
+    Quad(
+        1e79,
+        3.2643992499340446e62,
+        1.8063819567243405e46,
+        2.916201085219899e29,
+    ),
+    Quad(
+        1e80,
+        -2.6609864708367274e61,
+        -2.0495089939288144e45,
+        6.398723470638271e28,
+    ),
+    Quad(
+        1e81,
+        7.871812010433421e64,
+        4.912072936052509e48,
+        2.015624924832382e32,
+    ),
+    Quad(
+        1e82,
+        3.6593203436911345e65,
+        -2.1031349231358247e49,
+        -5.8052350443503194e32,
+    ),
+    Quad(
+        1e83,
+        -3.0806663230965258e66,
+        7.029482205395088e49,
+        -6.129381858154918e32,
+    ),
+    Quad(
+        1e84,
+        -5.77666098981159e67,
+        -2.2902071327141802e51,
+        -1.515136938971301e35,
+    ),
+    Quad(
+        1e85,
+        -1.4630695230674873e68,
+        1.0431714988877097e51,
+        -1.9755443713270558e34,
+    ),
+    Quad(
+        1e86,
+        -1.4630695230674873e69,
+        -3.745877066318193e52,
+        2.1285945554908972e36,
+    ),
+    Quad(
+        1e87,
+        4.058327554364964e70,
+        -1.9070832474977082e54,
+        7.445506538630561e37,
+    ),
+    Quad(
+        1e88,
+        4.0583275543649637e71,
+        5.44909617887714e54,
+        6.398592002117914e37,
+    ),
+    Quad(
+        1e89,
+        5.246334248081951e71,
+        -4.3588752826645485e55,
+        -7.212702674719624e38,
+    ),
+    Quad(
+        1e90,
+        3.35158872845361e73,
+        1.1333879055802153e57,
+        3.63434402911605e40,
+    ),
+    Quad(
+        1e91,
+        -7.95623248612805e74,
+        -1.2203244149712082e57,
+        1.4985259184564003e40,
+    ),
+    Quad(
+        1e92,
+        -4.337729697461919e75,
+        3.8953126691503547e59,
+        1.8966106353105853e43,
+    ),
+    Quad(
+        1e93,
+        -4.3377296974619187e76,
+        6.814365806323743e59,
+        1.1255101942813548e43,
+    ),
+    Quad(
+        1e94,
+        -2.0218879127155946e77,
+        -6.041138547748179e60,
+        1.1255101942813548e44,
+    ),
+    Quad(
+        1e95,
+        -2.0218879127155947e78,
+        4.2432649355093585e61,
+        2.5527578869873148e45,
+    ),
+    Quad(
+        1e96,
+        -4.9861653971908895e79,
+        2.069831050872142e63,
+        -1.571601257964897e47,
+    ),
+    Quad(
+        1e97,
+        -7.357587384771125e80,
+        -4.5121871784126824e64,
+        1.3514020166969086e48,
+    ),
+    Quad(
+        1e98,
+        2.309629754856292e80,
+        -3.641478249900182e63,
+        3.605054309909603e47,
+    ),
+    Quad(
+        1e99,
+        3.266383119588331e82,
+        -2.8271905117157673e66,
+        1.8220070683218631e49,
+    ),
+    Quad(
+        1e100,
+        -1.5902891109759918e83,
+        -1.3119584500070328e66,
+        -4.8715027461692524e48,
+    ),
+    Quad(
+        1e101,
+        2.295048673475466e84,
+        2.0255998883713479e68,
+        3.318584744948708e51,
+    ),
+    Quad(
+        1e102,
+        2.2950486734754662e85,
+        1.1628815950225274e69,
+        3.318584744948708e52,
+    ),
+    Quad(
+        1e103,
+        -1.915675085734669e85,
+        1.2761964300394286e69,
+        -5.126541072160143e52,
+    ),
+    Quad(
+        1e104,
+        -1.9156750857346687e86,
+        -7.943274739977405e69,
+        -5.126541072160144e53,
+    ),
+    Quad(
+        1e105,
+        6.174169917471802e88,
+        -1.791065841403834e72,
+        7.456322705286608e55,
+    ),
+    Quad(
+        1e106,
+        -9.103599905036844e89,
+        1.035889462241581e73,
+        7.4563227052866075e56,
+    ),
+    Quad(
+        1e107,
+        3.118615952970073e90,
+        1.035889462241581e74,
+        1.1792209698999269e57,
+    ),
+    Quad(
+        1e108,
+        -3.399899171300283e91,
+        2.8451408565746464e75,
+        1.8755105828982632e59,
+    ),
+    Quad(
+        1e109,
+        1.814912928116002e92,
+        -4.966137435825828e74,
+        -3.272834465928764e58,
+    ),
+    Quad(
+        1e110,
+        -2.3569367514170256e93,
+        -4.966137435825828e75,
+        2.7531832000424494e59,
+    ),
+    Quad(
+        1e111,
+        4.318022735835818e94,
+        1.8030120534388008e78,
+        8.470702345725095e61,
+    ),
+    Quad(
+        1e112,
+        6.988006530736956e95,
+        1.8030120534388007e79,
+        1.258446373902811e63,
+    ),
+    Quad(
+        1e113,
+        -1.5559416129466842e96,
+        -5.684099341414348e79,
+        -5.795727195415379e62,
+    ),
+    Quad(
+        1e114,
+        -1.5559416129466843e97,
+        -9.41255366253877e79,
+        -5.795727195415379e63,
+    ),
+    Quad(
+        1e115,
+        -1.5559416129466843e98,
+        2.8530198138745e81,
+        -5.795727195415379e64,
+    ),
+    Quad(
+        1e116,
+        -1.5559416129466843e99,
+        2.8530198138745e82,
+        1.105423947155377e66,
+    ),
+    Quad(
+        1e117,
+        -5.0555427725995036e100,
+        1.7423036505567468e84,
+        5.149415947227973e67,
+    ),
+    Quad(
+        1e118,
+        3.343500010567262e101,
+        1.742303650556747e85,
+        -1.6418541386492538e69,
+    ),
+    Quad(
+        1e119,
+        5.583244752745067e102,
+        -7.443125314921864e85,
+        4.286697653879152e69,
+    ),
+    Quad(
+        1e120,
+        1.9996531652605798e103,
+        1.2449804141469603e87,
+        -9.516795039701975e70,
+    ),
+    Quad(
+        1e121,
+        -3.734093374714599e104,
+        -1.1421711206200157e88,
+        -6.825597158100533e70,
+    ),
+    Quad(
+        1e122,
+        -1.4405947587245274e105,
+        1.309763645890381e88,
+        -6.825597158100534e71,
+    ),
+    Quad(
+        1e123,
+        2.229003026859587e106,
+        -8.875416235782049e89,
+        -2.8027761935441146e73,
+    ),
+    Quad(
+        1e124,
+        5.1646812553268785e107,
+        7.420871574893839e90,
+        3.981916535204881e74,
+    ),
+    Quad(
+        1e125,
+        7.513223838100712e108,
+        -3.1690219170728295e92,
+        1.4837424901203275e76,
+    ),
+    Quad(
+        1e126,
+        7.513223838100712e109,
+        1.0028277624601981e93,
+        3.258215977471655e76,
+    ),
+    Quad(
+        1e127,
+        4.5070893321502055e110,
+        -2.334651981166224e94,
+        1.252158311645695e78,
+    ),
+    Quad(
+        1e128,
+        -7.51744869165182e111,
+        -5.0046357760673614e95,
+        -2.2998043059195224e78,
+    ),
+    Quad(
+        1e129,
+        1.7825564358147585e111,
+        6.833343424479997e94,
+        6.644731785557723e78,
+    ),
+    Quad(
+        1e130,
+        -5.978307824605161e113,
+        -2.4414513329622694e97,
+        4.814461656821184e80,
+    ),
+    Quad(
+        1e131,
+        8.797444499042768e114,
+        3.0266754789952604e98,
+        4.8144616568211844e81,
+    ),
+    Quad(
+        1e132,
+        9.170432597638724e114,
+        -2.5420060817925743e98,
+        -1.2563786313842186e82,
+    ),
+    Quad(
+        1e133,
+        -2.235117235947686e116,
+        8.394247542122485e99,
+        2.3861255415390235e83,
+    ),
+    Quad(
+        1e134,
+        7.851796350329301e117,
+        -2.6601764054405705e101,
+        -1.121255670404108e85,
+    ),
+    Quad(
+        1e135,
+        3.817030915818506e118,
+        -4.2043166326276637e101,
+        1.220524206203586e85,
+    ),
+    Quad(
+        1e136,
+        -5.866406127007401e119,
+        -1.3163295601338881e103,
+        8.680372752350385e86,
+    ),
+    Quad(
+        1e137,
+        -3.2841562489204925e120,
+        -1.3163295601338881e104,
+        4.701786861072092e87,
+    ),
+    Quad(
+        1e138,
+        -3.2841562489204927e121,
+        9.771690558561835e104,
+        1.518918148049458e88,
+    ),
+    Quad(
+        1e139,
+        -3.2841562489204925e122,
+        -8.576298369358737e105,
+        -1.0273768223686497e89,
+    ),
+    Quad(
+        1e140,
+        -5.928380124081487e123,
+        2.0780483915314177e107,
+        5.083731106634808e90,
+    ),
+    Quad(
+        1e141,
+        -1.697621923823896e124,
+        -2.704941912424154e107,
+        -1.4347840176355469e91,
+    ),
+    Quad(
+        1e142,
+        -5.082228484029969e125,
+        3.0174654246409512e109,
+        -1.5775517291030329e93,
+    ),
+    Quad(
+        1e143,
+        -2.3745432358651105e126,
+        1.133091869044456e108,
+        -7.106902960686853e89,
+    ),
+    Quad(
+        1e144,
+        -2.3745432358651106e127,
+        6.125578198805459e110,
+        -4.094338188337852e94,
+    ),
+    Quad(
+        1e145,
+        1.091293881785908e128,
+        6.125578198805458e111,
+        3.915613196365561e95,
+    ),
+    Quad(
+        1e146,
+        6.636633270027537e129,
+        4.845195204258859e113,
+        1.2459561340049202e97,
+    ),
+    Quad(
+        1e147,
+        2.200361759434234e130,
+        -8.005557029045084e112,
+        4.980339388921048e96,
+    ),
+    Quad(
+        1e148,
+        -4.897672657515052e131,
+        1.397519662074342e115,
+        4.2573711221129063e98,
+    ),
+    Quad(
+        1e149,
+        -4.897672657515052e132,
+        -1.7856058578143704e115,
+        -1.1713032745311734e98,
+    ),
+    Quad(
+        1e150,
+        1.9164403827562624e133,
+        1.0823036125031862e117,
+        6.882071991852521e100,
+    ),
+    Quad(
+        1e151,
+        -1.717753238721772e134,
+        7.361225387548763e116,
+        -1.1713032745311734e100,
+    ),
+    Quad(
+        1e152,
+        -4.6251081359041995e135,
+        4.770887973265671e118,
+        3.5224548785858145e102,
+    ),
+    Quad(
+        1e153,
+        2.6659699587684626e134,
+        -7.083054814728264e117,
+        5.0850528210217967e101,
+    ),
+    Quad(
+        1e154,
+        -3.6947545688058227e137,
+        -3.9361178290814624e119,
+        2.9722244984977644e103,
+    ),
+    Quad(
+        1e155,
+        -7.176231540910168e137,
+        -3.936117829081462e120,
+        -2.7615220414774144e104,
+    ),
+    Quad(
+        1e156,
+        1.6640819776808277e139,
+        7.869587826969961e122,
+        1.3292968270453087e106,
+    ),
+    Quad(
+        1e157,
+        1.6640819776808279e140,
+        -2.707307673674016e123,
+        -1.606381401421983e107,
+    ),
+    Quad(
+        1e158,
+        4.712664546348789e141,
+        2.267724152787153e125,
+        6.613517638286433e108,
+    ),
+    Quad(
+        1e159,
+        7.151530601283158e142,
+        3.621566776869582e126,
+        6.6135176382864335e109,
+    ),
+    Quad(
+        1e160,
+        -6.528407745068227e142,
+        3.723444790717522e126,
+        6.012486263854203e109,
+    ),
+    Quad(
+        1e161,
+        -3.774589324822815e144,
+        1.5572965921856355e127,
+        -6.012051759947823e110,
+    ),
+    Quad(
+        1e162,
+        6.21500603618836e145,
+        1.542064506278971e129,
+        1.2385295889711405e113,
+    ),
+    Quad(
+        1e163,
+        6.21500603618836e146,
+        1.542064506278971e130,
+        -6.084394514848506e113,
+    ),
+    Quad(
+        1e164,
+        -1.7833499485879184e146,
+        -1.0630522428685347e129,
+        7.216895333813116e112,
+    ),
+    Quad(
+        1e165,
+        1.0051010654816651e149,
+        4.7361799939061496e132,
+        1.1646508273529009e116,
+    ),
+    Quad(
+        1e166,
+        5.959272394946475e149,
+        -4.3493040597889364e133,
+        -9.621337093172239e115,
+    ),
+    Quad(
+        1e167,
+        -3.860899428741951e150,
+        -2.5322072490499193e134,
+        9.124779876959763e117,
+    ),
+    Quad(
+        1e168,
+        6.613950516525703e151,
+        -1.0785298004587054e135,
+        1.0552490079381735e118,
+    ),
+    Quad(
+        1e169,
+        6.613950516525703e152,
+        -3.4044137182046477e136,
+        2.0422123093589987e120,
+    ),
+    Quad(
+        1e170,
+        -3.441905430931245e153,
+        -3.4044137182046477e137,
+        2.042212309358999e121,
+    ),
+    Quad(
+        1e171,
+        4.602779327034313e154,
+        -1.9158480108472446e138,
+        3.895723873833773e121,
+    ),
+    Quad(
+        1e172,
+        -8.2687162857105805e155,
+        2.847562252696445e139,
+        -2.254651487777617e123,
+    ),
+    Quad(
+        1e173,
+        -1.4039186255799706e156,
+        9.42198147278969e139,
+        -1.3927238764882158e123,
+    ),
+    Quad(
+        1e174,
+        -6.895756753684458e157,
+        9.42198147278969e140,
+        2.838034323769375e124,
+    ),
+    Quad(
+        1e175,
+        6.284654753766313e158,
+        -3.935533962589769e142,
+        1.6376460564593668e126,
+    ),
+    Quad(
+        1e176,
+        -7.44898050207432e158,
+        -3.334827469477855e141,
+        1.3034907560451684e125,
+    ),
+    Quad(
+        1e177,
+        -7.44898050207432e159,
+        7.470888628842195e143,
+        -3.118873222193313e127,
+    ),
+    Quad(
+        1e178,
+        -5.243811844750628e161,
+        -1.7503099773685743e145,
+        2.079882454283215e128,
+    ),
+    Quad(
+        1e179,
+        1.954450226518486e162,
+        -1.7503099773685744e146,
+        7.625221842524845e129,
+    ),
+    Quad(
+        1e180,
+        -9.248546019891598e162,
+        -1.5197471960678626e146,
+        9.708145766348889e129,
+    ),
+    Quad(
+        1e181,
+        8.288920849235307e164,
+        -2.3896440804732894e148,
+        -1.011986419984837e132,
+    ),
+    Quad(
+        1e182,
+        -6.453119872723839e165,
+        -2.3896440804732896e149,
+        1.2593845934389344e133,
+    ),
+    Quad(
+        1e183,
+        5.340512704843477e166,
+        -7.529487765252186e149,
+        3.5083618806942587e133,
+    ),
+    Quad(
+        1e184,
+        -1.735666841696913e167,
+        5.5640746663323816e150,
+        3.5083618806942586e134,
+    ),
+    Quad(
+        1e185,
+        2.0382955831246285e168,
+        -4.910775278935272e151,
+        2.054684432103045e135,
+    ),
+    Quad(
+        1e186,
+        2.0382955831246284e169,
+        1.1848984633492974e153,
+        7.8693942264679e136,
+    ),
+    Quad(
+        1e187,
+        9.284303438781988e170,
+        -3.5078343121306114e154,
+        -1.4459091383893143e138,
+    ),
+    Quad(
+        1e188,
+        -2.309309130269787e171,
+        -1.362585043339796e155,
+        -2.5505657250339203e138,
+    ),
+    Quad(
+        1e189,
+        -2.309309130269787e172,
+        -5.044853358234698e155,
+        -2.5505657250339206e139,
+    ),
+    Quad(
+        1e190,
+        -7.255917159731878e173,
+        1.5549539622157132e157,
+        -8.266658041286348e140,
+    ),
+    Quad(
+        1e191,
+        -7.2559171597318776e174,
+        -2.838516540267877e158,
+        -2.1694929039504253e141,
+    ),
+    Quad(
+        1e192,
+        -4.09008802087614e175,
+        -1.081128339274441e159,
+        7.58597131578705e142,
+    ),
+    Quad(
+        1e193,
+        -6.622751331960731e176,
+        4.5425139039045543e160,
+        1.148815700368204e144,
+    ),
+    Quad(
+        1e194,
+        5.534032561245304e177,
+        4.360010936135808e159,
+        -2.1840006000293013e143,
+    ),
+    Quad(
+        1e195,
+        2.292223523057028e178,
+        4.360010936135808e160,
+        2.498622225444687e144,
+    ),
+    Quad(
+        1e196,
+        4.885670753607649e179,
+        -2.8357047191462876e163,
+        1.998041410721424e146,
+    ),
+    Quad(
+        1e197,
+        4.885670753607649e180,
+        -2.835704719146288e164,
+        1.478472347281573e148,
+    ),
+    Quad(
+        1e198,
+        -1.75355415660194e181,
+        8.498054613434988e164,
+        4.5553778231402854e148,
+    ),
+    Quad(
+        1e199,
+        -9.720624048853447e182,
+        1.586907497441456e166,
+        1.0692985212945552e150,
+    ),
+    Quad(
+        1e200,
+        3.0266877787489637e183,
+        1.586907497441456e167,
+        1.0692985212945551e151,
+    ),
+    Quad(
+        1e201,
+        -3.771878529305655e184,
+        -3.000737149693145e167,
+        2.181352676778972e150,
+    ),
+    Quad(
+        1e202,
+        9.825254086803583e185,
+        -3.3192436548265476e169,
+        5.455560240311725e152,
+    ),
+    Quad(
+        1e203,
+        1.1230892124936706e186,
+        -9.039077029407612e169,
+        5.455560240311724e153,
+    ),
+    Quad(
+        1e204,
+        1.1230892124936706e187,
+        -9.039077029407611e170,
+        -5.2706861036423536e154,
+    ),
+    Quad(
+        1e205,
+        -1.6616035472855014e188,
+        2.5545355396441637e171,
+        1.1650617027300932e155,
+    ),
+    Quad(
+        1e206,
+        -3.889357755108839e189,
+        1.492105561329939e173,
+        2.8812611177627454e156,
+    ),
+    Quad(
+        1e207,
+        -3.889357755108839e190,
+        1.492105561329939e174,
+        2.8812611177627454e157,
+    ),
+    Quad(
+        1e208,
+        1.8136930169189052e191,
+        -9.080900809792996e173,
+        -4.138417590999471e157,
+    ),
+    Quad(
+        1e209,
+        -7.311188218325486e192,
+        3.7081859585289557e176,
+        -4.1482916862109984e159,
+    ),
+    Quad(
+        1e210,
+        7.288621758065539e193,
+        3.7081859585289557e177,
+        -4.148291686210999e160,
+    ),
+    Quad(
+        1e211,
+        4.368659762787335e194,
+        -2.775432117847596e178,
+        4.849535902875394e161,
+    ),
+    Quad(
+        1e212,
+        9.040598955232462e195,
+        3.7081859585289554e179,
+        2.6444322116682736e163,
+    ),
+    Quad(
+        1e213,
+        1.5654962473202578e196,
+        -4.4132961035203726e179,
+        -2.3487261683937204e163,
+    ),
+    Quad(
+        1e214,
+        4.55553733048514e197,
+        -1.2712327241282359e181,
+        -1.197004236990662e164,
+    ),
+    Quad(
+        1e215,
+        9.33960306354895e198,
+        4.040147204039435e182,
+        9.859526304478698e165,
+    ),
+    Quad(
+        1e216,
+        -2.142154695804196e199,
+        1.9155952327723667e183,
+        -1.9341062730886193e166,
+    ),
+    Quad(
+        1e217,
+        3.9814494425174824e200,
+        -6.338671327481153e183,
+        -1.9341062730886193e167,
+    ),
+    Quad(
+        1e218,
+        -8.265758834125874e201,
+        3.4452726520846557e185,
+        2.637061191307294e169,
+    ),
+    Quad(
+        1e219,
+        3.491561111451748e202,
+        -9.058097850703001e185,
+        2.217252394215076e169,
+    ),
+    Quad(
+        1e220,
+        3.562757926310489e202,
+        -3.5593297639308896e185,
+        -1.9808355767071023e169,
+    ),
+    Quad(
+        1e221,
+        -4.6601807174820695e204,
+        -2.1241128674736877e188,
+        -1.1791696126722486e172,
+    ),
+    Quad(
+        1e222,
+        -4.66018071748207e205,
+        3.445272652084656e189,
+        6.758083983760355e172,
+    ),
+    Quad(
+        1e223,
+        -4.6601807174820696e206,
+        -1.901337446691354e190,
+        6.758083983760354e173,
+    ),
+    Quad(
+        1e224,
+        3.0450964820516807e207,
+        -1.901337446691354e191,
+        6.758083983760355e174,
+    ),
+    Quad(
+        1e225,
+        7.154577655136347e208,
+        3.7988286211974354e191,
+        4.264257060488787e174,
+    ),
+    Quad(
+        1e226,
+        3.866992716668614e209,
+        3.7988286211974354e192,
+        4.264257060488787e175,
+    ),
+    Quad(
+        1e227,
+        -9.28334703720232e210,
+        3.798828621197436e193,
+        -2.6127702672526298e177,
+    ),
+    Quad(
+        1e228,
+        7.549087847752475e211,
+        3.798828621197435e194,
+        2.2499432900297838e178,
+    ),
+    Quad(
+        1e229,
+        8.161138937705572e211,
+        3.7988286211974354e195,
+        9.532196747544736e178,
+    ),
+    Quad(
+        1e230,
+        -9.956644432600512e213,
+        2.2486585390977946e197,
+        1.2364387489177204e181,
+    ),
+    Quad(
+        1e231,
+        -5.647541102052084e214,
+        -2.535407193966016e198,
+        -7.553287241451562e181,
+    ),
+    Quad(
+        1e232,
+        -5.647541102052084e215,
+        -6.217809007404919e198,
+        3.06947261488378e182,
+    ),
+    Quad(
+        1e233,
+        2.6259372926008967e216,
+        9.091201338399276e199,
+        7.318576557417917e183,
+    ),
+    Quad(
+        1e234,
+        -1.7865845178806931e217,
+        9.091201338399277e200,
+        -2.8792729046640117e184,
+    ),
+    Quad(
+        1e235,
+        -5.316601966265965e218,
+        -2.030209852554478e202,
+        -2.879272904664012e185,
+    ),
+    Quad(
+        1e236,
+        -5.316601966265965e219,
+        -4.625671931441282e202,
+        -2.8792729046640117e186,
+    ),
+    Quad(
+        1e237,
+        5.979453868566905e220,
+        4.5538893169689904e204,
+        6.015930450599533e186,
+    ),
+    Quad(
+        1e238,
+        -4.86475973287265e221,
+        2.547306712923743e205,
+        6.015930450599533e187,
+    ),
+    Quad(
+        1e239,
+        9.188208545617794e221,
+        -6.63225453548653e205,
+        6.015930450599533e188,
+    ),
+    Quad(
+        1e240,
+        -1.3946113804119925e223,
+        2.999341963930658e206,
+        -1.1806103211987167e190,
+    ),
+    Quad(
+        1e241,
+        -5.096102956370027e224,
+        -3.2958618300560177e208,
+        1.6709150648151552e191,
+    ),
+    Quad(
+        1e242,
+        -5.0961029563700274e225,
+        1.6355155776455825e209,
+        1.6709150648151554e192,
+    ),
+    Quad(
+        1e243,
+        -7.46505756498317e226,
+        1.6355155776455826e210,
+        -5.628989923380357e193,
+    ),
+    Quad(
+        1e244,
+        -7.4650575649831695e227,
+        -4.685387829737669e210,
+        2.1093406717605282e193,
+    ),
+    Quad(
+        1e245,
+        -4.432795665958348e228,
+        2.897948194017192e212,
+        2.546903663398617e195,
+    ),
+    Quad(
+        1e246,
+        -6.858605185178205e229,
+        1.5513534032208086e213,
+        -1.2403301752425792e197,
+    ),
+    Quad(
+        1e247,
+        4.785280507077112e230,
+        -6.031982620534053e213,
+        -4.431374197662651e196,
+    ),
+    Quad(
+        1e248,
+        -4.529828046727142e231,
+        1.1204430701659658e215,
+        -2.8351702862981703e198,
+    ),
+    Quad(
+        1e249,
+        7.89031669167853e232,
+        2.4993561359414627e216,
+        -1.4316928045651317e200,
+    ),
+    Quad(
+        1e250,
+        7.89031669167853e233,
+        -4.119426579780922e217,
+        -2.0440532183972994e201,
+    ),
+    Quad(
+        1e251,
+        -4.827911520448878e234,
+        1.1755995927969856e218,
+        8.95276767997106e201,
+    ),
+    Quad(
+        1e252,
+        -9.915202805299841e235,
+        -3.060421345265341e219,
+        2.854830092260043e203,
+    ),
+    Quad(
+        1e253,
+        6.364129306223241e236,
+        -3.060421345265341e220,
+        1.6007159647317633e204,
+    ),
+    Quad(
+        1e254,
+        6.364129306223241e237,
+        5.543165218811778e220,
+        -4.058666393134842e204,
+    ),
+    Quad(
+        1e255,
+        1.1547430305358546e238,
+        5.5431652188117775e221,
+        3.967664023046148e205,
+    ),
+    Quad(
+        1e256,
+        -3.012765990014054e239,
+        -3.132205662339866e222,
+        -8.48134226662446e205,
+    ),
+    Quad(
+        1e257,
+        -3.0127659900140542e240,
+        -3.132205662339866e223,
+        -8.481342266624459e206,
+    ),
+    Quad(
+        1e258,
+        -5.679971763165996e241,
+        2.6479726945324414e225,
+        -1.214920745264528e209,
+    ),
+    Quad(
+        1e259,
+        7.122615947963324e242,
+        2.6479726945324415e226,
+        -2.5299547206516214e210,
+    ),
+    Quad(
+        1e260,
+        -6.5334776105746174e243,
+        7.528090076419275e226,
+        -4.259003600322719e210,
+    ),
+    Quad(
+        1e261,
+        7.122615947963324e244,
+        3.7850709066667493e228,
+        2.0989648727109475e212,
+    ),
+    Quad(
+        1e262,
+        -1.6172839295009582e245,
+        -1.0665481317729658e229,
+        -5.9422470888181986e212,
+    ),
+    Quad(
+        1e263,
+        -1.6172839295009584e246,
+        3.8893757975894873e229,
+        2.1373216559601028e213,
+    ),
+    Quad(
+        1e264,
+        -4.414051890289529e247,
+        1.9414556720596576e231,
+        1.1832804149694064e215,
+    ),
+    Quad(
+        1e265,
+        -6.651466258920385e248,
+        3.1834701459002247e232,
+        1.1832804149694065e216,
+    ),
+    Quad(
+        1e266,
+        -3.071603269111015e249,
+        -7.9097617038959e232,
+        8.014996234900903e215,
+    ),
+    Quad(
+        1e267,
+        2.6561775145839772e250,
+        7.98802356126336e233,
+        -3.6110221869914994e217,
+    ),
+    Quad(
+        1e268,
+        2.6561775145839774e251,
+        -1.1089318756927752e235,
+        -3.611022186991499e218,
+    ),
+    Quad(
+        1e269,
+        -4.675381888545613e252,
+        9.2598463824761e235,
+        2.0370057304249353e219,
+    ),
+    Quad(
+        1e270,
+        -4.675381888545613e253,
+        -7.019485729046982e236,
+        4.2962168973915095e220,
+    ),
+    Quad(
+        1e271,
+        4.709014147460262e254,
+        1.902744564938995e238,
+        -1.125889903328268e221,
+    ),
+    Quad(
+        1e272,
+        -6.552261095746788e255,
+        1.9027445649389948e239,
+        4.6576906841061614e222,
+    ),
+    Quad(
+        1e273,
+        5.459765830340733e256,
+        -3.0982662597208957e240,
+        -9.22290272573647e223,
+    ),
+    Quad(
+        1e274,
+        7.862171215558236e257,
+        9.025424000070168e240,
+        -1.8199195738203997e224,
+    ),
+    Quad(
+        1e275,
+        4.01832259921023e258,
+        -3.36498683703609e242,
+        -1.9587079138418968e226,
+    ),
+    Quad(
+        1e276,
+        -5.2069140800249854e259,
+        -1.6579751422188473e243,
+        -6.354422695138286e225,
+    ),
+    Quad(
+        1e277,
+        -2.8678785109953724e259,
+        4.90365525983954e242,
+        3.1213957393142837e226,
+    ),
+    Quad(
+        1e278,
+        3.6493131320408215e261,
+        -5.654876575358119e244,
+        -2.909638693782445e228,
+    ),
+    Quad(
+        1e279,
+        -5.797329227496039e262,
+        -1.4394776452822402e246,
+        -5.335448213002303e229,
+    ),
+    Quad(
+        1e280,
+        -3.27822459828621e263,
+        1.3572903155063303e247,
+        -5.335448213002303e230,
+    ),
+    Quad(
+        1e281,
+        -3.2782245982862097e264,
+        -8.801240531245261e247,
+        -5.335448213002303e231,
+    ),
+    Quad(
+        1e282,
+        -3.27822459828621e265,
+        -1.7750898005768687e249,
+        -3.673903176400343e231,
+    ),
+    Quad(
+        1e283,
+        4.460464822646387e266,
+        -1.7750898005768688e250,
+        7.5815023149395955e233,
+    ),
+    Quad(
+        1e284,
+        -7.921438250845768e267,
+        -6.295336438378702e250,
+        1.2223882088758917e234,
+    ),
+    Quad(
+        1e285,
+        1.9840842079479558e268,
+        -6.295336438378702e251,
+        -3.8649030759750715e235,
+    ),
+    Quad(
+        1e286,
+        -3.2988611034086966e269,
+        -1.3626895841508293e253,
+        6.309679493726855e236,
+    ),
+    Quad(
+        1e287,
+        -7.525217352494019e270,
+        -1.8964007965009482e253,
+        -2.0205335088237797e236,
+    ),
+    Quad(
+        1e288,
+        -7.6304735395750355e270,
+        -1.8964007965009483e254,
+        4.491199335785453e237,
+    ),
+    Quad(
+        1e289,
+        -6.172783352786716e272,
+        -1.896400796500948e255,
+        -1.1136959491276705e239,
+    ),
+    Quad(
+        1e290,
+        -6.172783352786716e273,
+        -1.8964007965009483e256,
+        5.53307659092293e239,
+    ),
+    Quad(
+        1e291,
+        4.2139097649653716e274,
+        -1.8964007965009482e257,
+        -7.802952274836778e240,
+    ),
+    Quad(
+        1e292,
+        -1.3256598978357416e275,
+        -1.8964007965009483e258,
+        2.865870817770988e241,
+    ),
+    Quad(
+        1e293,
+        7.53765156264604e276,
+        -4.187242640652022e260,
+        2.0770727419584012e244,
+    ),
+    Quad(
+        1e294,
+        -6.64364677412481e277,
+        -2.511416575116629e260,
+        -1.0790222740766953e244,
+    ),
+    Quad(
+        1e295,
+        1.8651322279376996e278,
+        -2.5114165751166294e261,
+        1.1059526952893754e245,
+    ),
+    Quad(
+        1e296,
+        1.8651322279376996e279,
+        -2.5114165751166292e262,
+        -6.420272802034812e245,
+    ),
+    Quad(
+        1e297,
+        -1.765280146275638e280,
+        -2.5114165751166293e263,
+        5.716470999366143e245,
+    ),
+    Quad(
+        1e298,
+        4.043379652465702e281,
+        -1.4603118795323812e265,
+        5.650700631570803e248,
+    ),
+    Quad(
+        1e299,
+        -5.250476025520442e282,
+        -2.750093449687814e266,
+        5.6507006315708026e249,
+    ),
+    Quad(
+        1e300,
+        -5.250476025520442e283,
+        -6.864429374391215e266,
+        5.6507006315708026e250,
+    ),
+    Quad(
+        1e301,
+        -5.250476025520442e284,
+        2.6153978821587864e268,
+        -8.095973249297179e251,
+    ),
+    Quad(
+        1e302,
+        -7.629703079084895e285,
+        1.2946615543196232e269,
+        -7.64413846167589e251,
+    ),
+    Quad(
+        1e303,
+        -1.6176507678645645e284,
+        6.943634676439159e267,
+        -3.125790585463002e251,
+    ),
+    Quad(
+        1e304,
+        6.0746447494463536e287,
+        2.1399328041366875e271,
+        1.0966081198839756e255,
+    ),
+    Quad(
+        1e305,
+        6.074644749446354e288,
+        -1.9173691949852216e272,
+        1.0966081198839756e256,
+    ),
+    Quad(
+        1e306,
+        -1.7216064596736455e289,
+        2.4652520454646336e272,
+        -1.0459457272477653e256,
+    ),
+    Quad(
+        1e307,
+        1.3968940239743542e290,
+        -1.862536753598736e273,
+        -1.0459457272477653e257,
+    ),
+    Quad(
+        1e308,
+        -1.0979063629440455e291,
+        -5.324767792849432e274,
+        3.278383966143742e258,
+    ),
+];
+
+/// Multiplies a `Quad` by 10<sup>*n*</sup>, multiplying or dividing by an entry of
+/// [`POWERS_OF_TEN`], so that the result has the error of that one operation. Powers past
+/// the end of the table take more than one step. This is a `const fn` because parsing,
+/// which can happen at compile time, scales by powers of ten with it.
+pub const fn mul_pow10(a: Quad, n: i32) -> Quad {
+    const TOP: i32 = POWERS_OF_TEN.len() as i32 - 1;
+    let mut a = a;
+    let mut n = n;
+    while n > TOP && a.is_finite() {
+        a = a.mul_inner(POWERS_OF_TEN[TOP as usize]);
+        n -= TOP;
+    }
+    while n < -TOP && !a.is_zero() {
+        a = a.div_inner(POWERS_OF_TEN[TOP as usize]);
+        n += TOP;
+    }
+    if n > TOP || n < -TOP {
+        // The loops stopped early because the result is already infinite or zero
+        a
+    } else if n >= 0 {
+        a.mul_inner(POWERS_OF_TEN[n as usize])
+    } else {
+        a.div_inner(POWERS_OF_TEN[-n as usize])
+    }
+}
+
+// Determines whether a `Quad` is an integer. The components of a normalized `Quad` don't
+// overlap, so the lowest set bit of the whole number is the lowest set bit of its last
+// nonzero component. That means that the number is an integer exactly when every one of
+// its components is, even when its first component alone can't tell.
+#[inline]
+pub fn is_integer(a: Quad) -> bool {
+    a.is_finite()
+        && a.0.fract() == 0.0
+        && a.1.fract() == 0.0
+        && a.2.fract() == 0.0
+        && a.3.fract() == 0.0
+}
+
+// Determines whether a `Quad` that is already known to be an integer is odd. The parity of
+// the whole number is the combined parity of its components. Any component with a
+// magnitude of 2^53 or more is even, so for large numbers the deciding component can be
+// any of them.
+#[inline]
+pub fn is_odd(a: Quad) -> bool {
+    (a.0 % 2.0 != 0.0) ^ (a.1 % 2.0 != 0.0) ^ (a.2 % 2.0 != 0.0) ^ (a.3 % 2.0 != 0.0)
+}
+
+// Determines whether four components are finite and already normalized. This doesn't
+// short-circuit, so that checking a lot of quadruples in a row doesn't mean a lot of
+// branches.
+#[inline]
+pub const fn is_normalized(a: f64, b: f64, c: f64, d: f64) -> bool {
+    let finite = a.is_finite() & b.is_finite() & c.is_finite() & d.is_finite();
+    finite & (a + b == a) & (b + c == b) & (c + d == c)
+}
+
+// Creates a `Quad` from four components that may or may not be normalized. Components that
+// are already normalized are used as-is, so that every bit (including the signs of zero
+// components) survives.
+pub const fn from_components(a: f64, b: f64, c: f64, d: f64) -> Quad {
+    if is_normalized(a, b, c, d) {
+        return Quad(a, b, c, d);
+    }
+    let finite = a.is_finite() && b.is_finite() && c.is_finite() && d.is_finite();
+    if a.is_nan() || b.is_nan() || c.is_nan() || d.is_nan() {
+        return Quad::NAN;
+    }
+    let sum = a + b + c + d;
+    if !finite || sum.is_infinite() {
+        return if sum.is_nan() {
+            // Infinities of opposite signs
+            Quad::NAN
+        } else if sum > 0.0 {
+            Quad::INFINITY
+        } else {
+            Quad::NEG_INFINITY
+        };
+    }
+    let mut terms = [d, c, b, a];
+    u::distill(&mut terms);
+    if terms[3].is_infinite() {
+        return if terms[3] > 0.0 {
+            Quad::INFINITY
+        } else {
+            Quad::NEG_INFINITY
+        };
+    }
+    Quad(terms[3], terms[2], terms[1], terms[0])
+}
+
+// Calculates a * b + c with a relative error of no more than a few ULPs, however much the
+// product and c cancel.
+//
+// When they don't come close to cancelling, rounding the product before adding c costs
+// very little, so the ordinary operations are good enough. Otherwise all sixteen
+// products of components are expanded into error-free terms, and those are distilled
+// together with the components of c. Nothing is rounded until the exact sum is cut down
+// to four components at the end, so the result is as accurate as an addition of two
+// `Quad`s. (The terms can only stop being exact if some of them underflow.)
+//
+// Non-finite operands and products that overflow are left to the separate operations,
+// which already follow IEEE 754 for them.
+pub fn mul_add(a: Quad, b: Quad, c: Quad) -> Quad {
+    let (h0, l0) = p::two_prod(a.0, b.0);
+    if !(h0.is_finite() && a.is_finite() && b.is_finite() && c.is_finite()) {
+        return a * b + c;
+    }
+    if h0 == 0.0 {
+        // One of the factors is zero (or the product underflowed), which leaves c, except
+        // that a zero c takes its sign from both zeros
+        return Quad(h0 + c.0, c.1, c.2, c.3);
+    }
+    // No close cancellation means that the result is at least a third of the product
+    let r = a * b + c;
+    if r.0.abs() >= h0.abs() / 3.0 {
+        return r;
+    }
+    let x = [a.0, a.1, a.2, a.3];
+    let y = [b.0, b.1, b.2, b.3];
+
+    // Highest-order terms first and the zeroth-order term last, which is roughly the order
+    // of their sizes and lets distillation finish sooner
+    let mut terms = [0.0; 36];
+    terms[0] = c.3;
+    terms[1] = c.2;
+    terms[2] = c.1;
+    let mut k = 3;
+    for order in (1..7).rev() {
+        for i in order.max(3) - 3..=order.min(3) {
+            let (h, l) = p::two_prod(x[i], y[order - i]);
+            terms[k] = l;
+            terms[k + 1] = h;
+            k += 2;
+        }
+    }
+    terms[33] = l0;
+    terms[34] = c.0;
+    terms[35] = h0;
+
+    u::distill(&mut terms);
+    if terms[35] == 0.0 {
+        // Exact cancellation of a nonzero product
+        return Quad::ZERO;
+    }
+    let rest: f64 = terms[..32].iter().sum();
+    from_components(terms[35], terms[34], terms[33], terms[32] + rest)
+}
+
+// Calculates the rounding error of the sum s of a and b, which is the exact value of
+// a + b - s. Everything is distilled together, so the error is accurate to within a few
+// ULPs of its own even though `Quad` addition isn't correctly rounded. All three numbers
+// have to be finite.
+pub fn add_error(a: Quad, b: Quad, s: Quad) -> Quad {
+    let mut terms = [
+        a.3, b.3, -s.3, a.2, b.2, -s.2, a.1, b.1, -s.1, a.0, b.0, -s.0,
+    ];
+    u::distill(&mut terms);
+    let rest: f64 = terms[..8].iter().sum();
+    from_components(terms[11], terms[10], terms[9], terms[8] + rest)
+}
+
+// Asserts that `x` is exactly the reference value called `name`, rounded one component at a
+// time, and that it's normalized. The tests of every hard-coded `Quad` use this; see
+// `crate::common::reference` for where the values come from.
+#[cfg(test)]
+pub fn assert_reference(refs: &std::collections::HashMap<String, String>, name: &str, x: Quad) {
+    let s = refs
+        .get(name)
+        .unwrap_or_else(|| panic!("no reference value for {}", name));
+    let expected = Quad::from_string_radix_any(s, 10).unwrap();
+    assert!(
+        x.to_array() == expected.to_array(),
+        "{} is {:?}, but it should be {:?}",
+        name,
+        x,
+        expected
+    );
+    assert!(
+        is_normalized(x.0, x.1, x.2, x.3),
+        "{} isn't normalized",
+        name
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::reference::references;
+
+    test!(inv_facts: {
+        let mut fact = qd!(2);
+        for (k, &x) in INV_FACTS.iter().enumerate() {
+            fact *= Quad((k + 3) as f64, 0.0, 0.0, 0.0);
+            near!(Quad::ONE / fact, x);
+        }
+    });
+
+    test!(tables_match_references: {
+        let refs = references();
+        assert_reference(&refs, "TRIG_STEP", TRIG_STEP);
+        assert_reference(&refs, "RAD_PER_DEG", RAD_PER_DEG);
+        assert_reference(&refs, "DEG_PER_RAD", DEG_PER_RAD);
+        for (k, &x) in INV_FACTS.iter().enumerate() {
+            assert_reference(&refs, &format!("INV_FACTS[{}]", k), x);
+        }
+        for (k, &x) in SINES.iter().enumerate() {
+            assert_reference(&refs, &format!("SINES[{}]", k), x);
+        }
+        for (k, &x) in COSINES.iter().enumerate() {
+            assert_reference(&refs, &format!("COSINES[{}]", k), x);
+        }
+    });
+
+    test!(powers_of_ten: {
+        // Parsing in an arbitrary radix builds the number exactly before splitting it into
+        // components, so it's an independent check on every entry of the table
+        for (k, &x) in POWERS_OF_TEN.iter().enumerate() {
+            let s = format!("1{}", "0".repeat(k));
+            exact!(x, Quad::from_string_radix_any(&s, 10).unwrap());
+        }
+    });
+
+    test!(mul_pow10_range: {
+        exact!(mul_pow10(qd!(3), 0), qd!(3));
+        exact!(mul_pow10(qd!(3), 22), qd!(3e22));
+        exact!(mul_pow10(Quad::ONE, 308), POWERS_OF_TEN[308]);
+        // Exponents beyond the table go through the largest entry more than once
+        near!(mul_pow10(qd!("1e-200"), 500), qd!("1e300"));
+        near!(mul_pow10(qd!("1e200"), -450), qd!("1e-250"));
+        assert!(mul_pow10(Quad::ONE, 400).is_infinite());
+        assert!(mul_pow10(Quad::ONE, -400).is_zero());
+        assert!(mul_pow10(Quad::ONE, i32::MAX).is_infinite());
+        assert!(mul_pow10(Quad::ONE, i32::MIN).is_zero());
+        assert!(mul_pow10(Quad::ZERO, i32::MAX).is_zero());
+    });
+}
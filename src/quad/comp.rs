@@ -3,8 +3,10 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::double::Double;
 use crate::quad::Quad;
 use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 
 impl PartialEq for Quad {
     /// Implements the `==` and `!= operators, testing two `Quad`s for equality and
@@ -30,6 +32,48 @@ impl PartialEq for Quad {
     }
 }
 
+impl Hash for Quad {
+    /// Feeds the `Quad` into the given hasher.
+    ///
+    /// Equal `Quad`s always hash equally. Zero and negative zero are equal but have different
+    /// bits, so each component is hashed with -0 turned into 0, and since a NaN isn't equal
+    /// to anything, all NaNs are hashed the same way.
+    ///
+    /// `Quad` doesn't implement `Eq`, because NaN isn't equal to itself, so it can't be the
+    /// key of a `HashMap` directly. [`OrderedQuad`] wraps a `Quad` that isn't NaN and
+    /// implements `Eq`, `Ord`, and `Hash` so that it can be.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// fn hash(x: Quad) -> u64 {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     x.hash(&mut hasher);
+    ///     hasher.finish()
+    /// }
+    ///
+    /// assert!(hash(Quad::ZERO) == hash(Quad::NEG_ZERO));
+    /// assert!(hash(Quad::NAN) == hash(-Quad::NAN));
+    /// assert!(hash(qd!(1.5)) != hash(qd!(2.5)));
+    /// ```
+    ///
+    /// [`OrderedQuad`]: ordered/struct.OrderedQuad.html
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let components = if self.is_nan() {
+            [f64::NAN, 0.0, 0.0, 0.0]
+        } else {
+            // Adding 0 turns -0 into 0 and leaves everything else alone
+            [self.0 + 0.0, self.1 + 0.0, self.2 + 0.0, self.3 + 0.0]
+        };
+        for c in components.iter() {
+            c.to_bits().hash(state);
+        }
+    }
+}
+
 impl PartialOrd for Quad {
     /// Implements the `<`, `>`, `<=`, and `>=` operators, testing two `Quad`s for ordering.
     ///
@@ -60,6 +104,376 @@ impl PartialOrd for Quad {
     }
 }
 
+impl PartialEq<f64> for Quad {
+    /// Implements the `==` and `!=` operators between a `Quad` and an `f64`.
+    ///
+    /// The `f64` is compared as exactly the number that it holds in binary, as if it were
+    /// the `Quad` with it as the first component and zero as the others. Apart from that,
+    /// equality works as it does between two `Quad`s: zero equals negative zero, and `NaN`
+    /// equals nothing.
+    ///
+    /// Be careful with `f64`s like `0.1` that aren't exactly representable in binary. The
+    /// `f64` written as `0.1` is only the `f64` nearest to 0.1, while [`qd!`]`(0.1)` and
+    /// `Quad::from("0.1")` both read it as the decimal number 0.1 and are much closer to
+    /// it than the `f64` is, so they aren't equal to it. [`Quad::from`] makes a `Quad` from
+    /// the `f64`'s exact value, so `Quad::from(0.1)` is.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(1.5) == 1.5);
+    /// assert!(Quad::ZERO == -0.0);
+    /// assert!(Quad::NAN != f64::NAN);
+    ///
+    /// assert!(qd!(0.1) != 0.1);
+    /// assert!(Quad::from("0.1") != 0.1);
+    /// assert!(Quad::from(0.1) == 0.1);
+    /// ```
+    ///
+    /// [`qd!`]: macro.qd.html
+    /// [`Quad::from`]: #impl-From<f64>
+    #[inline]
+    fn eq(&self, other: &f64) -> bool {
+        *self == Quad(*other, 0.0, 0.0, 0.0)
+    }
+}
+
+impl PartialEq<Quad> for f64 {
+    /// Implements the `==` and `!=` operators between an `f64` and a `Quad`.
+    ///
+    /// This is the same as comparing the `Quad` to the `f64`, so the `f64` is compared as
+    /// exactly the number that it holds in binary. See the `PartialEq<f64>` implementation
+    /// of `Quad` for what that means for an `f64` like `0.1`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(1.5 == qd!(1.5));
+    /// assert!(0.1 != qd!(0.1));
+    /// assert!(0.1 == Quad::from(0.1));
+    /// ```
+    #[inline]
+    fn eq(&self, other: &Quad) -> bool {
+        *other == *self
+    }
+}
+
+impl PartialEq<Double> for Quad {
+    /// Implements the `==` and `!=` operators between a `Quad` and a `Double`.
+    ///
+    /// The `Double` is compared as exactly the number that it holds, which is the `Quad`
+    /// that [`Quad::from`] makes from it. As with `f64`s, that means that a `Double` and a
+    /// `Quad` that were both made from the same decimal number usually aren't equal,
+    /// because the `Quad` is closer to that number than the `Double` could be.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, qd, Double, Quad};
+    /// assert!(qd!(1.5) == dd!(1.5));
+    /// assert!(Quad::from(Double::PI) == Double::PI);
+    ///
+    /// assert!(qd!(0.1) != dd!(0.1));
+    /// assert!(Quad::PI != Double::PI);
+    /// ```
+    ///
+    /// [`Quad::from`]: #impl-From<Double>
+    #[inline]
+    fn eq(&self, other: &Double) -> bool {
+        *self == Quad::from(*other)
+    }
+}
+
+impl PartialEq<Quad> for Double {
+    /// Implements the `==` and `!=` operators between a `Double` and a `Quad`.
+    ///
+    /// This is the same as comparing the `Quad` to the `Double`, so the `Double` is
+    /// compared as exactly the number that it holds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, qd, Double, Quad};
+    /// assert!(dd!(1.5) == qd!(1.5));
+    /// assert!(Double::PI == Quad::from(Double::PI));
+    /// assert!(dd!(0.1) != qd!(0.1));
+    /// ```
+    #[inline]
+    fn eq(&self, other: &Quad) -> bool {
+        *other == *self
+    }
+}
+
+impl PartialOrd<f64> for Quad {
+    /// Implements the `<`, `>`, `<=`, and `>=` operators between a `Quad` and an `f64`.
+    ///
+    /// As with equality, the `f64` is compared as exactly the number that it holds in
+    /// binary, so a `Quad` that differs from an `f64` only below the precision of an `f64`
+    /// is still ordered correctly against it. Ordering with `NaN` on either side is always
+    /// false.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::PI > 3.0);
+    /// assert!(Quad::PI > std::f64::consts::PI);
+    /// assert!(Quad::NEG_INFINITY < f64::MIN);
+    /// assert!(!(Quad::NAN < 1.0));
+    ///
+    /// // The f64 written as 0.1 is a little more than 0.1
+    /// assert!(qd!(0.1) < 0.1);
+    /// ```
+    #[inline]
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        self.partial_cmp(&Quad(*other, 0.0, 0.0, 0.0))
+    }
+}
+
+impl PartialOrd<Quad> for f64 {
+    /// Implements the `<`, `>`, `<=`, and `>=` operators between an `f64` and a `Quad`.
+    ///
+    /// The `f64` is compared as exactly the number that it holds in binary, just as it is
+    /// when the `Quad` is on the left.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(3.0 < Quad::PI);
+    /// assert!(std::f64::consts::PI < Quad::PI);
+    /// assert!(0.1 > qd!(0.1));
+    /// ```
+    #[inline]
+    fn partial_cmp(&self, other: &Quad) -> Option<Ordering> {
+        Quad(*self, 0.0, 0.0, 0.0).partial_cmp(other)
+    }
+}
+
+impl PartialOrd<Double> for Quad {
+    /// Implements the `<`, `>`, `<=`, and `>=` operators between a `Quad` and a `Double`.
+    ///
+    /// The `Double` is compared as exactly the number that it holds, so a `Quad` that
+    /// differs from a `Double` only below the precision of a `Double` is still ordered
+    /// correctly against it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, qd, Double, Quad};
+    /// assert!(Quad::PI > Double::E);
+    /// assert!(!(Quad::NAN > Double::ZERO));
+    ///
+    /// // Double::PI is a little more than π
+    /// assert!(Quad::PI < Double::PI);
+    /// ```
+    #[inline]
+    fn partial_cmp(&self, other: &Double) -> Option<Ordering> {
+        self.partial_cmp(&Quad::from(*other))
+    }
+}
+
+impl PartialOrd<Quad> for Double {
+    /// Implements the `<`, `>`, `<=`, and `>=` operators between a `Double` and a `Quad`.
+    ///
+    /// The `Double` is compared as exactly the number that it holds, just as it is when
+    /// the `Quad` is on the left.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, qd, Double, Quad};
+    /// assert!(Double::E < Quad::PI);
+    /// assert!(Double::PI > Quad::PI);
+    /// ```
+    #[inline]
+    fn partial_cmp(&self, other: &Quad) -> Option<Ordering> {
+        Quad::from(*self).partial_cmp(other)
+    }
+}
+
+impl Quad {
+    /// Returns the ordering between the `Quad` and the argument, a total order like the
+    /// one that `f64::total_cmp` uses (IEEE 754's totalOrder predicate).
+    ///
+    /// Unlike the ordering given by [`PartialOrd`], this orders every `Quad`, NaN
+    /// included, so it can be used to sort. The order is
+    ///
+    /// * negative NaN
+    /// * negative infinity
+    /// * negative numbers
+    /// * negative zero
+    /// * positive zero
+    /// * positive numbers
+    /// * positive infinity
+    /// * positive NaN
+    ///
+    /// The first components are compared with `f64::total_cmp`, so NaNs are ordered by the
+    /// bits of their first components. When the first components are the same, the later
+    /// components break the tie, in order. The sign of a zero in a later component doesn't
+    /// count, since it doesn't change the value of the `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut v = vec![qd!(1), Quad::NAN, -Quad::NAN, Quad::ZERO, Quad::NEG_ZERO];
+    /// v.sort_by(Quad::total_cmp);
+    ///
+    /// assert!(v[0].is_nan() && v[0].is_sign_negative());
+    /// assert!(v[1] == qd!(0) && v[1].is_sign_negative());
+    /// assert!(v[2] == qd!(0) && v[2].is_sign_positive());
+    /// assert!(v[3] == qd!(1));
+    /// assert!(v[4].is_nan() && v[4].is_sign_positive());
+    /// ```
+    ///
+    /// [`PartialOrd`]: #impl-PartialOrd<Quad>
+    #[inline]
+    pub fn total_cmp(&self, other: &Quad) -> Ordering {
+        // Adding 0 turns -0 into 0 and leaves everything else (including NaN) alone
+        self.0
+            .total_cmp(&other.0)
+            .then_with(|| (self.1 + 0.0).total_cmp(&(other.1 + 0.0)))
+            .then_with(|| (self.2 + 0.0).total_cmp(&(other.2 + 0.0)))
+            .then_with(|| (self.3 + 0.0).total_cmp(&(other.3 + 0.0)))
+    }
+
+    /// Returns the larger of the `Quad` and the argument.
+    ///
+    /// As with `f64::max`, NaN is ignored if the other argument is a number, and the result
+    /// is NaN only if both arguments are NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(1).max(qd!(2)) == qd!(2));
+    /// assert!(Quad::NAN.max(qd!(2)) == qd!(2));
+    /// assert!(qd!(1).max(Quad::NAN) == qd!(1));
+    /// ```
+    #[inline]
+    pub fn max(self, other: Quad) -> Quad {
+        if self.is_nan() || self < other {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the smaller of the `Quad` and the argument.
+    ///
+    /// As with `f64::min`, NaN is ignored if the other argument is a number, and the result
+    /// is NaN only if both arguments are NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(1).min(qd!(2)) == qd!(1));
+    /// assert!(Quad::NAN.min(qd!(2)) == qd!(2));
+    /// assert!(qd!(1).min(Quad::NAN) == qd!(1));
+    /// ```
+    #[inline]
+    pub fn min(self, other: Quad) -> Quad {
+        if self.is_nan() || other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the larger of the `Quad` and the argument, propagating NaN.
+    ///
+    /// Unlike [`max`], this returns NaN if either argument is NaN, and it considers
+    /// negative zero to be less than positive zero. This is the same as `f64::maximum`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(1).maximum(qd!(2)) == qd!(2));
+    /// assert!(Quad::NAN.maximum(qd!(2)).is_nan());
+    /// assert!(Quad::NEG_ZERO.maximum(Quad::ZERO).is_sign_positive());
+    /// ```
+    ///
+    /// [`max`]: #method.max
+    #[inline]
+    pub fn maximum(self, other: Quad) -> Quad {
+        if self > other {
+            self
+        } else if other > self {
+            other
+        } else if self == other {
+            if self.is_sign_positive() && other.is_sign_negative() {
+                self
+            } else {
+                other
+            }
+        } else if self.is_nan() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns the smaller of the `Quad` and the argument, propagating NaN.
+    ///
+    /// Unlike [`min`], this returns NaN if either argument is NaN, and it considers
+    /// negative zero to be less than positive zero. This is the same as `f64::minimum`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(1).minimum(qd!(2)) == qd!(1));
+    /// assert!(qd!(1).minimum(Quad::NAN).is_nan());
+    /// assert!(Quad::ZERO.minimum(Quad::NEG_ZERO).is_sign_negative());
+    /// ```
+    ///
+    /// [`min`]: #method.min
+    #[inline]
+    pub fn minimum(self, other: Quad) -> Quad {
+        if self < other {
+            self
+        } else if other < self {
+            other
+        } else if self == other {
+            if self.is_sign_negative() && other.is_sign_positive() {
+                self
+            } else {
+                other
+            }
+        } else if self.is_nan() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Restricts the `Quad` to the interval between `min` and `max`.
+    ///
+    /// The result is `max` if the `Quad` is greater than `max` and `min` if it's less
+    /// than `min`. Otherwise it's the `Quad` itself, which includes when it's NaN.
+    ///
+    /// # Panics
+    /// As with `f64::clamp`, this panics if `min` is greater than `max` or if either is
+    /// NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(-3).clamp(qd!(-2), qd!(1)) == qd!(-2));
+    /// assert!(qd!(0).clamp(qd!(-2), qd!(1)) == qd!(0));
+    /// assert!(qd!(2).clamp(qd!(-2), qd!(1)) == qd!(1));
+    /// assert!(Quad::NAN.clamp(qd!(-2), qd!(1)).is_nan());
+    /// ```
+    #[inline]
+    pub fn clamp(self, min: Quad, max: Quad) -> Quad {
+        assert!(
+            min <= max,
+            "min > max, or either was NaN. min = {:?}, max = {:?}",
+            min,
+            max
+        );
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -129,4 +543,242 @@ mod tests {
         gte_nan_nan:
             !(Quad::NAN >= Quad::NAN);
     );
+
+    // f64 comparison tests
+    test_all_assert!(
+        eq_f64_exact:
+            Quad(1.5, 0.0, 0.0, 0.0) == 1.5 && 1.5 == Quad(1.5, 0.0, 0.0, 0.0);
+        eq_f64_zero_neg_zero:
+            Quad::ZERO == -0.0 && -0.0 == Quad::ZERO;
+        eq_f64_inf:
+            Quad::INFINITY == f64::INFINITY && f64::NEG_INFINITY == Quad::NEG_INFINITY;
+        eq_f64_inf_neg_inf:
+            Quad::INFINITY != f64::NEG_INFINITY;
+        eq_f64_nan:
+            Quad::NAN != f64::NAN && f64::NAN != Quad::NAN && Quad::NAN != 1.0;
+        eq_f64_decimal:
+            qd!(0.1) != 0.1 && 0.1 != qd!(0.1) && qd!("0.1") != 0.1;
+        eq_f64_from:
+            Quad::from(0.1) == 0.1
+                && 0.1 == Quad::from(0.1)
+                && Quad::from(0.1f32) == 0.1f32 as f64;
+        eq_f64_exact_decimal:
+            Quad(0.1, 0.0, 0.0, 0.0) == 0.1 && 0.1 == Quad(0.1, 0.0, 0.0, 0.0);
+        eq_f64_below_precision:
+            Quad(1.0, 0.0, 0.0, 1e-60) != 1.0 && 1.0 != Quad(1.0, -1e-30, 0.0, 0.0);
+    );
+    test_all_assert!(
+        ord_f64_below_precision:
+            Quad(1.0, 0.0, 0.0, 1e-60) > 1.0 && Quad(1.0, 0.0, -1e-40, 0.0) < 1.0;
+        ord_f64_below_precision_reversed:
+            1.0 < Quad(1.0, 0.0, 0.0, 1e-60) && 1.0 > Quad(1.0, 0.0, -1e-40, 0.0);
+        ord_f64_below_precision_neg:
+            Quad(-1.0, 0.0, 0.0, 1e-60) > -1.0 && -1.0 > Quad(-1.0, -1e-30, 0.0, 0.0);
+        ord_f64_decimal:
+            qd!(0.1) < 0.1 && 0.1 > qd!(0.1) && qd!(0.3) > 0.3;
+        ord_f64_pi:
+            Quad::PI > std::f64::consts::PI && std::f64::consts::PI < Quad::PI;
+        ord_f64_zero_neg_zero:
+            Quad::ZERO <= -0.0 && Quad::NEG_ZERO >= 0.0 && !(Quad::ZERO < -0.0);
+        ord_f64_inf:
+            Quad::INFINITY > f64::MAX && f64::NEG_INFINITY < Quad::NEG_ONE;
+        ord_f64_inf_inf:
+            Quad::INFINITY >= f64::INFINITY && !(Quad::INFINITY > f64::INFINITY);
+        ord_f64_nan:
+            !(Quad::NAN < 1.0) && !(Quad::NAN >= 1.0) && !(1.0 <= Quad::NAN);
+        ord_f64_nan_partial_cmp:
+            Quad::ONE.partial_cmp(&f64::NAN).is_none();
+    );
+
+    // Double comparison tests
+    test_all_assert!(
+        eq_double_exact:
+            Quad::from(Double::PI) == Double::PI && Double::PI == Quad::from(Double::PI);
+        eq_double_zero_neg_zero:
+            Quad::ZERO == Double::NEG_ZERO && Double::ZERO == Quad::NEG_ZERO;
+        eq_double_inf:
+            Quad::INFINITY == Double::INFINITY && Double::NEG_INFINITY == Quad::NEG_INFINITY;
+        eq_double_nan:
+            Quad::NAN != Double::NAN && Double::NAN != Quad::NAN && Quad::NAN != Double::ONE;
+        eq_double_decimal:
+            qd!(0.1) != Double::from("0.1")
+                && Double::from("0.1") != qd!(0.1)
+                && Quad::PI != Double::PI;
+        eq_double_below_precision:
+            Quad(1.0, 1e-20, 1e-40, 0.0) != Double::new(1.0, 1e-20);
+    );
+    test_all_assert!(
+        ord_double_below_precision:
+            Quad(1.0, 1e-20, 1e-40, 0.0) > Double::new(1.0, 1e-20)
+                && Quad(1.0, 1e-20, -1e-40, 0.0) < Double::new(1.0, 1e-20);
+        ord_double_below_precision_reversed:
+            Double::new(1.0, 1e-20) < Quad(1.0, 1e-20, 1e-40, 0.0)
+                && Double::new(1.0, 1e-20) > Quad(1.0, 1e-20, -1e-40, 0.0);
+        ord_double_decimal:
+            qd!(0.1) > Double::from("0.1") && Double::from("0.1") < qd!(0.1);
+        ord_double_pi:
+            Quad::PI < Double::PI && Double::PI > Quad::PI;
+        ord_double_inf:
+            Quad::INFINITY > Double::MAX && Double::NEG_INFINITY < Quad::MIN;
+        ord_double_nan:
+            !(Quad::NAN < Double::ONE) && !(Double::ONE >= Quad::NAN);
+    );
+
+    // total_cmp tests
+    test_all!(
+        total_cmp_sort: {
+            let mut v = vec![
+                Quad::NAN,
+                Quad::ONE,
+                Quad::NEG_INFINITY,
+                Quad::ZERO,
+                -Quad::PI,
+                Quad::INFINITY,
+                -Quad::NAN,
+                Quad::NEG_ZERO,
+                Quad::PI,
+                Quad(1.0, 1e-20, 0.0, 0.0),
+                Quad::NEG_ONE,
+                Quad(1.0, -1e-20, 0.0, 0.0),
+            ];
+            v.sort_by(Quad::total_cmp);
+
+            let expected = [
+                -Quad::NAN,
+                Quad::NEG_INFINITY,
+                -Quad::PI,
+                Quad::NEG_ONE,
+                Quad::NEG_ZERO,
+                Quad::ZERO,
+                Quad(1.0, -1e-20, 0.0, 0.0),
+                Quad::ONE,
+                Quad(1.0, 1e-20, 0.0, 0.0),
+                Quad::PI,
+                Quad::INFINITY,
+                Quad::NAN,
+            ];
+            for (a, b) in v.iter().zip(expected.iter()) {
+                assert_eq!(a.to_bits(), b.to_bits());
+            }
+        }
+        total_cmp_zero_second_component: {
+            assert_eq!(Quad(1.0, 0.0, -0.0, 0.0).total_cmp(&Quad::ONE), Ordering::Equal);
+            assert_eq!((-Quad::ONE).total_cmp(&Quad::NEG_ONE), Ordering::Equal);
+        }
+        total_cmp_later_components: {
+            let a = Quad(1.0, 1e-20, 1e-40, 0.0);
+            let b = Quad(1.0, 1e-20, 1e-40, 1e-60);
+            assert_eq!(a.total_cmp(&b), Ordering::Less);
+            assert_eq!(b.total_cmp(&a), Ordering::Greater);
+        }
+        total_cmp_nan_payload: {
+            let quiet = Quad(f64::from_bits(0x7ff8_0000_0000_0001), 0.0, 0.0, 0.0);
+            assert_eq!(Quad::NAN.total_cmp(&quiet), Ordering::Less);
+            assert_eq!((-quiet).total_cmp(&-Quad::NAN), Ordering::Less);
+            assert_eq!(Quad::NAN.total_cmp(&Quad::NAN), Ordering::Equal);
+        }
+    );
+
+    // max and min tests
+    test_all_exact!(
+        max_pi_e:
+            Quad::PI,
+            Quad::PI.max(Quad::E);
+        max_e_pi:
+            Quad::PI,
+            Quad::E.max(Quad::PI);
+        max_nan_pi:
+            Quad::PI,
+            Quad::NAN.max(Quad::PI);
+        max_pi_nan:
+            Quad::PI,
+            Quad::PI.max(Quad::NAN);
+        max_nan_nan:
+            Quad::NAN,
+            Quad::NAN.max(Quad::NAN);
+        min_pi_e:
+            Quad::E,
+            Quad::PI.min(Quad::E);
+        min_e_pi:
+            Quad::E,
+            Quad::E.min(Quad::PI);
+        min_nan_pi:
+            Quad::PI,
+            Quad::NAN.min(Quad::PI);
+        min_pi_nan:
+            Quad::PI,
+            Quad::PI.min(Quad::NAN);
+        min_nan_nan:
+            Quad::NAN,
+            Quad::NAN.min(Quad::NAN);
+    );
+
+    // maximum and minimum tests
+    test_all_exact!(
+        maximum_pi_e:
+            Quad::PI,
+            Quad::PI.maximum(Quad::E);
+        maximum_neg_inf_e:
+            Quad::E,
+            Quad::NEG_INFINITY.maximum(Quad::E);
+        maximum_nan_pi:
+            Quad::NAN,
+            Quad::NAN.maximum(Quad::PI);
+        maximum_pi_nan:
+            Quad::NAN,
+            Quad::PI.maximum(Quad::NAN);
+        minimum_pi_e:
+            Quad::E,
+            Quad::PI.minimum(Quad::E);
+        minimum_inf_e:
+            Quad::E,
+            Quad::INFINITY.minimum(Quad::E);
+        minimum_nan_pi:
+            Quad::NAN,
+            Quad::NAN.minimum(Quad::PI);
+        minimum_pi_nan:
+            Quad::NAN,
+            Quad::PI.minimum(Quad::NAN);
+    );
+    test_all_assert!(
+        maximum_zero_neg_zero:
+            Quad::ZERO.maximum(Quad::NEG_ZERO).is_sign_positive();
+        maximum_neg_zero_zero:
+            Quad::NEG_ZERO.maximum(Quad::ZERO).is_sign_positive();
+        minimum_zero_neg_zero:
+            Quad::ZERO.minimum(Quad::NEG_ZERO).is_sign_negative();
+        minimum_neg_zero_zero:
+            Quad::NEG_ZERO.minimum(Quad::ZERO).is_sign_negative();
+    );
+
+    // clamp tests
+    test_all_exact!(
+        clamp_below:
+            Quad::NEG_ONE,
+            (-Quad::PI).clamp(Quad::NEG_ONE, Quad::ONE);
+        clamp_within:
+            Quad::ZERO,
+            Quad::ZERO.clamp(Quad::NEG_ONE, Quad::ONE);
+        clamp_above:
+            Quad::ONE,
+            Quad::PI.clamp(Quad::NEG_ONE, Quad::ONE);
+        clamp_inf:
+            Quad::ONE,
+            Quad::INFINITY.clamp(Quad::NEG_ONE, Quad::ONE);
+        clamp_nan:
+            Quad::NAN,
+            Quad::NAN.clamp(Quad::NEG_ONE, Quad::ONE);
+    );
+
+    #[test]
+    #[should_panic(expected = "min > max, or either was NaN")]
+    fn clamp_reversed() {
+        Quad::ZERO.clamp(Quad::ONE, Quad::NEG_ONE);
+    }
+
+    #[test]
+    #[should_panic(expected = "min > max, or either was NaN")]
+    fn clamp_nan_bound() {
+        Quad::ZERO.clamp(Quad::NAN, Quad::ONE);
+    }
 }
@@ -0,0 +1,533 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::primitive as p;
+use crate::common::utils as u;
+use crate::quad::common as c;
+use crate::quad::Quad;
+
+impl Quad {
+    /// Creates a `Quad` from four components that don't have to be normalized.
+    ///
+    /// Unlike [`new`], this normalizes its arguments, so it's safe to use on any four
+    /// `f64`s. The result has the value of the sum of the four components, rounded to
+    /// `Quad` precision if necessary. Components that are already normalized are used
+    /// as-is, bit for bit.
+    ///
+    /// If any component is NaN, or if the components include infinities of opposite signs,
+    /// the result is [`NAN`]. If the sum is too large to represent, the result is
+    /// [`INFINITY`] or [`NEG_INFINITY`], depending on its sign.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let x = Quad::from_components(1.0, 1.0, 1.0, 1.0);
+    /// assert!(x.as_components() == (4.0, 0.0, 0.0, 0.0));
+    ///
+    /// let y = Quad::from_components(1e-60, 1e-40, 1e-20, 1.0);
+    /// assert!(y.as_components() == (1.0, 1e-20, 1e-40, 1e-60));
+    ///
+    /// assert!(Quad::from_components(f64::MAX, f64::MAX, 0.0, 0.0) == Quad::INFINITY);
+    /// assert!(Quad::from_components(1.0, 0.0, f64::NAN, 0.0).is_nan());
+    /// ```
+    ///
+    /// [`new`]: #method.new
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    pub fn from_components(a: f64, b: f64, c: f64, d: f64) -> Quad {
+        c::from_components(a, b, c, d)
+    }
+
+    /// Returns the components of the `Quad` as a tuple.
+    ///
+    /// The first component is the closest `f64` to the number. Each component after that
+    /// is the rest of the number that the components before it don't account for, so each
+    /// of them includes the rounding error in the one before.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let (a, b, c, d) = Quad::PI.as_components();
+    /// assert!(a == 3.141592653589793e0);
+    /// assert!(b == 1.2246467991473532e-16);
+    /// assert!(c == -2.9947698097183397e-33);
+    /// assert!(d == 1.1124542208633653e-49);
+    /// ```
+    #[inline]
+    pub fn as_components(self) -> (f64, f64, f64, f64) {
+        (self.0, self.1, self.2, self.3)
+    }
+
+    /// Returns the components of the `Quad` as an array.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let a = Quad::PI.to_array();
+    /// assert!(a == [
+    ///     3.141592653589793e0,
+    ///     1.2246467991473532e-16,
+    ///     -2.9947698097183397e-33,
+    ///     1.1124542208633653e-49,
+    /// ]);
+    /// ```
+    #[inline]
+    pub const fn to_array(self) -> [f64; 4] {
+        [self.0, self.1, self.2, self.3]
+    }
+
+    /// Creates a `Quad` from an array of four components, normalizing them in the same way
+    /// as [`from_components`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let x = Quad::from_array([
+    ///     3.141592653589793e0,
+    ///     1.2246467991473532e-16,
+    ///     -2.9947698097183397e-33,
+    ///     1.1124542208633653e-49,
+    /// ]);
+    /// assert!(x == Quad::PI);
+    ///
+    /// let y = Quad::from_array([1.0, 1.0, 0.0, 0.0]);
+    /// assert!(y.to_array() == [2.0, 0.0, 0.0, 0.0]);
+    /// ```
+    ///
+    /// [`from_components`]: #method.from_components
+    #[inline]
+    pub fn from_array(a: [f64; 4]) -> Quad {
+        c::from_components(a[0], a[1], a[2], a[3])
+    }
+
+    /// Creates a `Quad` from the sum of a slice of `f64`s.
+    ///
+    /// The sum is calculated exactly, by distilling the slice with error-free additions
+    /// until its terms no longer overlap, and is only rounded when it's cut down to the
+    /// components of a `Quad`. So the result is accurate whatever the order and magnitudes
+    /// of the `f64`s and however much they cancel, where adding them up from left to right
+    /// can lose everything. The result is the normalized `Quad` nearest the exact sum, except
+    /// that its last component can be one ULP off.
+    ///
+    /// As with `f64` addition, the result is ∞ or -∞ if the slice holds infinities of only
+    /// that sign, and NaN if it holds a NaN or infinities of both signs. A sum too large
+    /// to represent is an infinity, even if adding from left to right would have overflowed
+    /// only partway through. An empty slice sums to 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let x = Quad::from_sum(&[1e100, 1.0, -1e100, 1e-50]);
+    /// assert!(x.to_array() == [1.0, 1e-50, 0.0, 0.0]);
+    ///
+    /// // Left to right, the 1 is lost
+    /// assert!(1e100 + 1.0 - 1e100 + 1e-50 == 1e-50);
+    ///
+    /// let y = Quad::from_sum(&[f64::MAX, f64::MAX, -f64::MAX]);
+    /// assert!(y.to_array() == [f64::MAX, 0.0, 0.0, 0.0]);
+    /// assert!(Quad::from_sum(&[f64::INFINITY, 1.0]) == Quad::INFINITY);
+    /// assert!(Quad::from_sum(&[f64::INFINITY, f64::NEG_INFINITY]).is_nan());
+    /// ```
+    pub fn from_sum(parts: &[f64]) -> Quad {
+        if parts.iter().any(|x| !x.is_finite()) {
+            let s: f64 = parts.iter().filter(|x| !x.is_finite()).sum();
+            return Quad(s, 0.0, 0.0, 0.0);
+        }
+        let (terms, scale) = u::distill_sum(parts);
+        let n = terms.len();
+        let term = |i: usize| if i < n { terms[n - 1 - i] * scale } else { 0.0 };
+        c::from_components(term(0), term(1), term(2), term(3))
+    }
+
+    /// Creates a `Quad` from the exact product of two `f64`s.
+    ///
+    /// The product of two `f64`s has up to 106 significant bits, which is more than an `f64`
+    /// holds but no more than the first two components of a `Quad` do. The first component
+    /// of the result is the product rounded to an `f64`, exactly as `a * b` would be, and
+    /// the second is the error of that rounding. This uses FMA unless the `no_fma` feature
+    /// is enabled, and Dekker's splitting of the arguments into halves if it is.
+    ///
+    /// The result is exact for any two `f64`s whose product is finite, except for products
+    /// so small (under about 10<sup>-291</sup>) that their rounding error is below the
+    /// smallest subnormal `f64`. There the error is rounded. If the product overflows, the
+    /// result is [`INFINITY`] or [`NEG_INFINITY`], and it's [`NAN`] if either argument is
+    /// NaN or if the product is 0 × ∞.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let a = 1.0 + 2f64.powi(-30);
+    /// let b = 1.0 - 2f64.powi(-30);
+    /// assert!(a * b == 1.0);
+    ///
+    /// // 1 - 2^-60 exactly
+    /// let x = Quad::from_product(a, b);
+    /// assert!(x.to_array() == [1.0, -2f64.powi(-60), 0.0, 0.0]);
+    ///
+    /// assert!(Quad::from_product(f64::MAX, 2.0) == Quad::INFINITY);
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn from_product(a: f64, b: f64) -> Quad {
+        let (p, e) = u::exact_prod(a, b);
+        Quad(p, e, 0.0, 0.0)
+    }
+
+    /// Creates a `Quad` from the exact sum of two `f64`s.
+    ///
+    /// Like [`from_product`], this is an error-free transformation: the first component is
+    /// `a + b` rounded to an `f64`, and the second is what that rounding lost. The sum of
+    /// two `f64`s always fits, so the result is exact unless the sum overflows, in which case
+    /// it's an infinity. As with `f64` addition, the result is [`NAN`] if either argument is
+    /// NaN or if they're infinities of opposite signs. For the sum of more than two `f64`s,
+    /// see [`from_sum`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let x = Quad::from_sum2(1e100, 1.0);
+    /// assert!(x.to_array() == [1e100, 1.0, 0.0, 0.0]);
+    /// assert!(x - Quad::from(1e100) == Quad::ONE);
+    ///
+    /// assert!(Quad::from_sum2(f64::MAX, f64::MAX) == Quad::INFINITY);
+    /// ```
+    ///
+    /// [`from_product`]: #method.from_product
+    /// [`from_sum`]: #method.from_sum
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn from_sum2(a: f64, b: f64) -> Quad {
+        let (s, e) = p::two_sum(a, b);
+        if s.is_finite() {
+            Quad(s, e, 0.0, 0.0)
+        } else {
+            Quad(s, 0.0, 0.0, 0.0)
+        }
+    }
+
+    /// Returns the raw bits of the components of the `Quad`.
+    ///
+    /// Every bit survives, including the payloads of NaNs and the signs of zeros, so
+    /// [`from_bits`] turns the result back into exactly the same `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let bits = Quad::PI.to_bits();
+    /// assert!(bits == [
+    ///     0x400921fb54442d18,
+    ///     0x3ca1a62633145c07,
+    ///     0xb92f1976b7ed8fbc,
+    ///     0x35c4cf98e804177d,
+    /// ]);
+    /// assert!(Quad::from_bits(bits) == Quad::PI);
+    /// ```
+    ///
+    /// [`from_bits`]: #method.from_bits
+    #[inline]
+    pub fn to_bits(self) -> [u64; 4] {
+        [
+            self.0.to_bits(),
+            self.1.to_bits(),
+            self.2.to_bits(),
+            self.3.to_bits(),
+        ]
+    }
+
+    /// Creates a `Quad` from the raw bits of its components.
+    ///
+    /// This is the inverse of [`to_bits`]. Like [`new`], it does *not* normalize, so that
+    /// every bit of a `Quad` survives the round trip. Bits that didn't come from a
+    /// normalized `Quad` can be normalized afterwards by passing the resulting components
+    /// to [`from_components`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let x = Quad::from_bits([0x8000000000000000, 0, 0, 0]);
+    /// assert!(x.is_zero() && x.is_sign_negative());
+    /// assert!(x.to_bits() == [0x8000000000000000, 0, 0, 0]);
+    /// ```
+    ///
+    /// [`to_bits`]: #method.to_bits
+    /// [`new`]: #method.new
+    /// [`from_components`]: #method.from_components
+    #[inline]
+    pub fn from_bits(bits: [u64; 4]) -> Quad {
+        Quad(
+            f64::from_bits(bits[0]),
+            f64::from_bits(bits[1]),
+            f64::from_bits(bits[2]),
+            f64::from_bits(bits[3]),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::random::Rng;
+
+    // Bitwise equality, so that NaN equals NaN and the signs of zeros count
+    fn same(a: Quad, b: Quad) -> bool {
+        a.to_bits() == b.to_bits()
+    }
+
+    test!(from_components_normalized: {
+        assert!(same(Quad::from_components(3.0, 1e-20, 1e-40, 0.0), Quad(3.0, 1e-20, 1e-40, 0.0)));
+        assert!(same(Quad::from_components(-0.0, 0.0, -0.0, 0.0), Quad(-0.0, 0.0, -0.0, 0.0)));
+        let (a, b, c, d) = Quad::E.as_components();
+        assert!(same(Quad::from_components(a, b, c, d), Quad::E));
+    });
+
+    test!(from_components_unnormalized: {
+        assert!(same(Quad::from_components(1.0, 1.0, 0.0, 0.0), Quad(2.0, 0.0, 0.0, 0.0)));
+        assert!(same(Quad::from_components(1.0, 1.0, 1.0, 1.0), Quad(4.0, 0.0, 0.0, 0.0)));
+        assert!(same(Quad::from_components(-1.0, -1.0, 0.0, 0.0), Quad(-2.0, 0.0, 0.0, 0.0)));
+        assert!(same(
+            Quad::from_components(1e-60, 1e-40, 1e-20, 1.0),
+            Quad(1.0, 1e-20, 1e-40, 1e-60)
+        ));
+        assert!(same(Quad::from_components(0.0, 0.0, 0.0, 1.0), Quad(1.0, 0.0, 0.0, 0.0)));
+        let x = Quad::from_components(1.0, 3e-16, 0.0, 0.0);
+        assert!(x[0] + x[1] == x[0]);
+        assert!(x == Quad(1.0, 0.0, 0.0, 0.0) + Quad(3e-16, 0.0, 0.0, 0.0));
+    });
+
+    test!(from_components_overflow: {
+        assert!(same(Quad::from_components(f64::MAX, f64::MAX, 0.0, 0.0), Quad::INFINITY));
+        assert!(same(
+            Quad::from_components(-f64::MAX, 0.0, 0.0, -f64::MAX),
+            Quad::NEG_INFINITY
+        ));
+    });
+
+    test!(from_components_nonfinite: {
+        assert!(Quad::from_components(1.0, 0.0, f64::NAN, 0.0).is_nan());
+        assert!(Quad::from_components(f64::NAN, 0.0, 0.0, 0.0).is_nan());
+        assert!(Quad::from_components(f64::INFINITY, f64::NEG_INFINITY, 0.0, 0.0).is_nan());
+        assert!(same(Quad::from_components(f64::INFINITY, 1.0, 0.0, 0.0), Quad::INFINITY));
+        assert!(same(
+            Quad::from_components(-1.0, 0.0, 0.0, f64::NEG_INFINITY),
+            Quad::NEG_INFINITY
+        ));
+    });
+
+    test!(arrays: {
+        assert!(Quad::PI.to_array() == [Quad::PI[0], Quad::PI[1], Quad::PI[2], Quad::PI[3]]);
+        assert!(same(Quad::from_array(Quad::PI.to_array()), Quad::PI));
+        assert!(same(Quad::from_array([1.0, 1.0, 0.0, 0.0]), Quad(2.0, 0.0, 0.0, 0.0)));
+        assert!(same(Quad::from_array([f64::MAX, f64::MAX, 0.0, 0.0]), Quad::INFINITY));
+    });
+
+    // Integer multiples of powers of 2, small enough that their exact sum, as a multiple of
+    // 2^-30, fits into an i128
+    fn parts(seed: &mut u64, count: usize) -> (Vec<f64>, i128) {
+        let mut next = || {
+            *seed ^= *seed << 13;
+            *seed ^= *seed >> 7;
+            *seed ^= *seed << 17;
+            *seed
+        };
+        let mut parts = vec![];
+        let mut exact = 0i128;
+        for _ in 0..count {
+            let m = (next() >> 12) as i64 - (1 << 51);
+            let e = (next() % 61) as i32 - 30;
+            parts.push(m as f64 * 2f64.powi(e));
+            exact += (m as i128) << (e + 30);
+        }
+        (parts, exact)
+    }
+
+    test!(from_sum_exact: {
+        let mut seed = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..200 {
+            let (mut parts, exact) = parts(&mut seed, 50);
+            let chunk = |k: i32| ((exact >> (48 * k)) & ((1 << 48) - 1)) as f64 * 2f64.powi(48 * k);
+            let top = (exact >> 96) as f64 * 2f64.powi(96);
+            let expected = Quad::from_components(top, chunk(1), chunk(0), 0.0).ldexp(-30);
+            assert!(same(Quad::from_sum(&parts), expected));
+            parts.reverse();
+            assert!(same(Quad::from_sum(&parts), expected));
+        }
+    });
+
+    test!(from_sum_cancellation: {
+        let x = Quad::from_sum(&[1e100, 1.0, -1e100, 1e-50]);
+        assert!(same(x, Quad(1.0, 1e-50, 0.0, 0.0)));
+        assert!(same(Quad::from_sum(&[1.0, 1e-50, -1.0]), Quad(1e-50, 0.0, 0.0, 0.0)));
+
+        let mut seed = 0x9e37_79b9_7f4a_7c15;
+        let (mut parts, _) = parts(&mut seed, 50);
+        let negated: Vec<f64> = parts.iter().map(|x| -x).collect();
+        parts.push(3e-300);
+        parts.extend(negated);
+        assert!(same(Quad::from_sum(&parts), Quad(3e-300, 0.0, 0.0, 0.0)));
+    });
+
+    test!(from_sum_rounded: {
+        let p = |n: i32| 2f64.powi(n);
+        let x = Quad::from_sum(&[p(-300), p(-250), p(-180), p(-120), p(-60), 1.0]);
+        assert!(same(x, Quad(1.0, p(-60), p(-120), p(-180))));
+        assert!(same(Quad::from_sum(&[1.0, -p(-300)]), Quad(1.0, -p(-300), 0.0, 0.0)));
+    });
+
+    test!(from_sum_zeros: {
+        assert!(same(Quad::from_sum(&[]), Quad(0.0, 0.0, 0.0, 0.0)));
+        assert!(same(Quad::from_sum(&[-0.0, -0.0]), Quad(-0.0, 0.0, 0.0, 0.0)));
+        assert!(Quad::from_sum(&[1.0, -1.0]).is_sign_positive());
+        assert!(Quad::from_sum(&[0.0, -0.0, 0.0]).is_zero());
+    });
+
+    test!(from_sum_extremes: {
+        let tiny = f64::from_bits(1);
+        assert!(same(Quad::from_sum(&[tiny, tiny]), Quad(f64::from_bits(2), 0.0, 0.0, 0.0)));
+        let x = Quad::from_sum(&[f64::MAX, f64::MAX, -f64::MAX]);
+        assert!(same(x, Quad(f64::MAX, 0.0, 0.0, 0.0)));
+        let x = Quad::from_sum(&[f64::MAX, 1.0, f64::MAX, -f64::MAX]);
+        assert!(same(x, Quad(f64::MAX, 1.0, 0.0, 0.0)));
+        assert!(same(Quad::from_sum(&[f64::MAX, f64::MAX]), Quad::INFINITY));
+        assert!(same(Quad::from_sum(&[-f64::MAX, 1.0, -f64::MAX]), Quad::NEG_INFINITY));
+    });
+
+    test!(from_sum_nonfinite: {
+        assert!(same(Quad::from_sum(&[1.0, f64::INFINITY, f64::MAX]), Quad::INFINITY));
+        assert!(same(Quad::from_sum(&[f64::NEG_INFINITY, 1.0]), Quad::NEG_INFINITY));
+        assert!(Quad::from_sum(&[f64::INFINITY, 1.0, f64::NEG_INFINITY]).is_nan());
+        assert!(Quad::from_sum(&[f64::INFINITY, f64::NAN]).is_nan());
+        assert!(Quad::from_sum(&[1.0, f64::NAN]).is_nan());
+    });
+
+    // x * 2^e, in two steps so that neither factor overflows or underflows on its own
+    fn scale(x: f64, e: i32) -> f64 {
+        x * 2f64.powi(e / 2) * 2f64.powi(e - e / 2)
+    }
+
+    // Whether the product of ma * 2^ea and mb * 2^eb comes out exactly. Both components of
+    // the result have to be integer multiples of 2^(ea + eb), and they have to add up to
+    // ma * mb.
+    fn product_exact(ma: i64, ea: i32, mb: i64, eb: i32) -> bool {
+        let x = Quad::from_product(scale(ma as f64, ea), scale(mb as f64, eb));
+        let k = ea + eb;
+        let (hi, lo) = (scale(x[0], -k), scale(x[1], -k));
+        hi.fract() == 0.0
+            && lo.fract() == 0.0
+            && hi as i128 + lo as i128 == ma as i128 * mb as i128
+            && x[0] == scale(ma as f64, ea) * scale(mb as f64, eb)
+    }
+
+    test!(from_product_adversarial: {
+        let (a, b) = (1.0 + 2f64.powi(-30), 1.0 - 2f64.powi(-30));
+        assert!(same(Quad::from_product(a, b), Quad(1.0, -2f64.powi(-60), 0.0, 0.0)));
+        assert!(same(Quad::from_product(-a, a), Quad(-(1.0 + 2f64.powi(-29)), -2f64.powi(-60), 0.0, 0.0)));
+
+        let m = (1i64 << 53) - 1;
+        assert!(product_exact(m, 0, m, 0));
+        assert!(product_exact(m, -30, -(m - 2), 12));
+        assert!(product_exact((1 << 52) + 1, 100, (1 << 52) - 1, -300));
+    });
+
+    test!(from_product_extremes: {
+        let m = (1i64 << 53) - 1;
+        // f64::MAX times numbers just under 1, where the halves of a Dekker split multiply
+        // to more than f64::MAX
+        assert!(product_exact(m, 971, m, -53));
+        assert!(product_exact(m, 971, -((1 << 52) + 1), -53));
+        assert!(product_exact(m, 600, m, 318));
+        assert!(product_exact(-m, 918, m, 0));
+        // The error is a multiple of 2^-1074, so it's just representable
+        assert!(product_exact(m, -537, m, -537));
+        assert!(product_exact(m, -1074, m, 0));
+        assert!(product_exact(3, -1074, m, 0));
+        assert!(product_exact(m, -1000, -m, -74));
+    });
+
+    test!(from_product_random: {
+        let mut rng = Rng::new(0x3c6e_f372_fe94_f82b);
+        for _ in 0..10000 {
+            let ma = (rng.next_u64() >> 11) as i64 | 1 << 52;
+            let mb = (rng.next_u64() >> 11) as i64 | 1 << 52;
+            let ma = if rng.next_u64() & 1 == 0 { ma } else { -ma };
+            // Every exponent of the product for which it's finite and its error representable
+            let k = rng.range(-1074, 919);
+            let lo = (k - 971).max(-1074);
+            let hi = (k + 1074).min(971);
+            let ea = lo + (rng.next_u64() % (hi - lo + 1) as u64) as i32;
+            assert!(product_exact(ma, ea, mb, k - ea), "{} {} {} {}", ma, ea, mb, k - ea);
+        }
+    });
+
+    test!(from_product_special: {
+        assert!(same(Quad::from_product(f64::MAX, 2.0), Quad::INFINITY));
+        assert!(same(Quad::from_product(f64::MAX, -f64::MAX), Quad::NEG_INFINITY));
+        assert!(same(Quad::from_product(-0.0, 3.0), Quad(-0.0, 0.0, 0.0, 0.0)));
+        assert!(same(Quad::from_product(0.0, 0.0), Quad(0.0, 0.0, 0.0, 0.0)));
+        assert!(Quad::from_product(0.0, f64::INFINITY).is_nan());
+        assert!(Quad::from_product(f64::NAN, 1.0).is_nan());
+        // Too small for anything but the rounded product, whose error is below 2^-1074
+        let a = scale(1.0 + 2f64.powi(-52), -537);
+        assert!(same(Quad::from_product(a, a), Quad(f64::from_bits(1), 0.0, 0.0, 0.0)));
+        assert!(same(Quad::from_product(2f64.powi(-600), 2f64.powi(-500)), Quad(0.0, 0.0, 0.0, 0.0)));
+        // An error of 3 * 2^-1076 rounds to the nearest subnormal
+        let b = scale(1.0 + 2f64.powi(-52), -486);
+        let c = scale(1.0 + 3.0 * 2f64.powi(-52), -486);
+        let x = Quad::from_product(b, c);
+        assert!(x[0] == b * c && x[1] == f64::from_bits(1));
+    });
+
+    test!(from_sum2: {
+        assert!(same(Quad::from_sum2(1e100, 1.0), Quad(1e100, 1.0, 0.0, 0.0)));
+        assert!(same(Quad::from_sum2(1.0, 2f64.powi(-80)), Quad(1.0, 2f64.powi(-80), 0.0, 0.0)));
+        assert!(same(Quad::from_sum2(1.0, -1.0), Quad(0.0, 0.0, 0.0, 0.0)));
+        assert!(same(Quad::from_sum2(-0.0, -0.0), Quad(-0.0, 0.0, 0.0, 0.0)));
+        let half = f64::MAX * 2f64.powi(-54);
+        assert!(same(Quad::from_sum2(f64::MAX, -half), Quad(f64::MAX, -half, 0.0, 0.0)));
+        let tiny = f64::from_bits(1);
+        assert!(same(Quad::from_sum2(tiny, 1.0), Quad(1.0, tiny, 0.0, 0.0)));
+    });
+
+    test!(from_sum2_nonfinite: {
+        assert!(same(Quad::from_sum2(f64::MAX, f64::MAX), Quad::INFINITY));
+        assert!(same(Quad::from_sum2(-f64::MAX, -f64::MAX), Quad::NEG_INFINITY));
+        assert!(same(Quad::from_sum2(f64::INFINITY, 1.0), Quad::INFINITY));
+        assert!(Quad::from_sum2(f64::INFINITY, f64::NEG_INFINITY).is_nan());
+        assert!(Quad::from_sum2(f64::NAN, 1.0).is_nan());
+    });
+
+    test!(bits_round_trip: {
+        for &x in &[
+            Quad::PI,
+            -Quad::E,
+            Quad::ZERO,
+            Quad::NEG_ZERO,
+            Quad(1.0, -0.0, 0.0, -0.0),
+            Quad::MAX,
+            Quad::MIN_POSITIVE,
+            Quad(5e-324, 0.0, 0.0, 0.0),
+            Quad::INFINITY,
+            Quad::NEG_INFINITY,
+        ] {
+            assert!(same(Quad::from_bits(x.to_bits()), x));
+        }
+    });
+
+    test!(bits_nan_payload: {
+        let bits = [0x7ff8_0000_dead_beef, 0xfff0_0000_0000_0001, 0, 0x8000_0000_0000_0000];
+        let x = Quad::from_bits(bits);
+        assert!(x.is_nan());
+        assert!(x.to_bits() == bits);
+    });
+
+    test!(bits_unnormalized: {
+        let bits = [1f64.to_bits(), 1f64.to_bits(), 0, 0];
+        let x = Quad::from_bits(bits);
+        assert!(x.as_components() == (1.0, 1.0, 0.0, 0.0));
+        let (a, b, c, d) = x.as_components();
+        assert!(same(Quad::from_components(a, b, c, d), Quad(2.0, 0.0, 0.0, 0.0)));
+    });
+}
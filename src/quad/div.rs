@@ -4,7 +4,9 @@
 // https://opensource.org/licenses/MIT
 
 use crate::common::primitive as p;
+use crate::common::strict as s;
 use crate::common::utils as u;
+use crate::quad::common as c;
 use crate::quad::Quad;
 use std::ops::{Div, DivAssign};
 
@@ -17,7 +19,7 @@ use std::ops::{Div, DivAssign};
 // Division is the only place where this is necessary, so this multiplication function is
 // dropped nearby.
 #[inline]
-fn mul_f64(a: Quad, b: f64) -> Quad {
+const fn mul_f64(a: Quad, b: f64) -> Quad {
     let (h0, l0) = p::two_prod(a.0, b);
     let (h1, l1) = p::two_prod(a.1, b);
     let (h2, l2) = p::two_prod(a.2, b);
@@ -51,34 +53,7 @@ impl Div for Quad {
     /// ```
     #[allow(clippy::suspicious_arithmetic_impl)]
     fn div(self, other: Quad) -> Quad {
-        match self.pre_div(&other) {
-            Some(r) => r,
-            None => {
-                // Strategy:
-                //
-                // Divide the first component of `self` by the first component of `other`.
-                // Then divide the first component of the remainder by the first component
-                // of `other`, then the first component of -that- remainder by the first
-                // component of `other`, and so on until we have five terms we can
-                // renormalize.
-                let q0 = self.0 / other.0;
-                let mut r = self - mul_f64(other, q0);
-
-                let q1 = r.0 / other.0;
-                r -= mul_f64(other, q1);
-
-                let q2 = r.0 / other.0;
-                r -= mul_f64(other, q2);
-
-                let q3 = r.0 / other.0;
-                r -= mul_f64(other, q3);
-
-                let q4 = r.0 / other.0;
-
-                let (a, b, c, d) = u::renorm5(q0, q1, q2, q3, q4);
-                Quad(a, b, c, d)
-            }
-        }
+        s::guard("Quad / Quad", &[self.0, other.0], self.div_inner(other))
     }
 }
 
@@ -201,6 +176,62 @@ impl DivAssign<&Quad> for Quad {
 }
 
 impl Quad {
+    /// Divides this `Quad` by another in a `const fn`. Like [`add_const`], this gives
+    /// exactly what the `/` operator does, but it can also be used at compile time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// const PI_OVER_3: Quad = Quad::PI.div_const(Quad::from_f64_const(3.0));
+    /// assert!(PI_OVER_3 == Quad::FRAC_PI_3);
+    /// ```
+    ///
+    /// [`add_const`]: #method.add_const
+    pub const fn div_const(self, other: Quad) -> Quad {
+        self.div_inner(other)
+    }
+
+    // `/` without the strict-mode check, for `pow_int`.
+    pub(super) const fn div_inner(self, other: Quad) -> Quad {
+        match self.pre_div(&other) {
+            Some(r) => r,
+            None => {
+                // Strategy:
+                //
+                // Divide the first component of `self` by the first component of `other`.
+                // Then divide the first component of the remainder by the first component
+                // of `other`, then the first component of -that- remainder by the first
+                // component of `other`, and so on until we have five terms we can
+                // renormalize.
+                let q0 = self.0 / other.0;
+                let mut r = self.sub_inner(mul_f64(other, q0));
+
+                let q1 = r.0 / other.0;
+                r = r.sub_inner(mul_f64(other, q1));
+
+                let q2 = r.0 / other.0;
+                r = r.sub_inner(mul_f64(other, q2));
+
+                let q3 = r.0 / other.0;
+                r = r.sub_inner(mul_f64(other, q3));
+
+                let q4 = r.0 / other.0;
+
+                let (a, b, c, d) = u::renorm5(q0, q1, q2, q3, q4);
+                if a.is_finite() {
+                    Quad(a, b, c, d)
+                } else {
+                    // This includes quotients within a factor of 2 of MAX, where multiplying
+                    // q0 back by `other` can round past f64::MAX.
+                    c::redo_halved!(
+                        0.5 * self.0 / other.0,
+                        c::mul_pwr2(self, 0.5).div_inner(other)
+                    )
+                }
+            }
+        }
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -211,7 +242,7 @@ impl Quad {
     // rather than calculation.
 
     #[inline]
-    fn pre_div(&self, other: &Quad) -> Option<Quad> {
+    const fn pre_div(&self, other: &Quad) -> Option<Quad> {
         if self.is_nan() || other.is_nan() {
             Some(Quad::NAN)
         } else if other.is_zero() {
@@ -230,7 +261,7 @@ impl Quad {
             } else {
                 Some(Quad::NEG_INFINITY)
             }
-        } else if other.is_infinite() {
+        } else if other.is_infinite() || self.is_zero() {
             if self.is_sign_positive() == other.is_sign_positive() {
                 Some(Quad::ZERO)
             } else {
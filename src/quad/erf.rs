@@ -0,0 +1,387 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::strict as s;
+use crate::quad::common as c;
+use crate::quad::Quad;
+
+// The argument below which erf is calculated with its series. Above it, erf is calculated
+// as 1 - erfc, and since erfc is then less than 1.6 × 10^-8, nothing is lost to
+// cancellation. This is also where erfc switches from the trapezoid rule to the continued
+// fraction, which is the faster of the two from here up.
+const SERIES_MAX: f64 = 4.0;
+
+// The argument at and above which erfc is calculated with the trapezoid rule. Below it,
+// erfc is calculated as 1 - erf, and since erfc is then more than 0.47, less than half a
+// digit is lost to cancellation.
+const TRAPEZOID_MIN: f64 = 0.5;
+
+// The step of the trapezoid rule. The rule's relative error is about e^(-π²/h²), which for
+// h = 1/4 is about 10^-69.
+const STEP: f64 = 0.25;
+
+// The argument above which erfc underflows to zero and erf is exactly 1.
+const ERFC_MAX: f64 = 27.3;
+
+impl Quad {
+    /// Computes the error function, erf(*x*), where *x* is this `Quad`.
+    ///
+    /// For |*x*| < 4, this is calculated with the series
+    ///
+    /// erf(*x*) = (2 / √π) *x e*<sup>-*x*²</sup> Σ (2*x*²)<sup>*n*</sup> / (1 · 3 · 5 ⋯
+    /// (2*n* + 1)),
+    ///
+    /// whose terms are all positive, so nothing is lost to cancellation. Past that, it's
+    /// calculated as 1 - [`erfc`]\(*x*), where the subtraction can't cancel anything
+    /// either. The result is precise to about 62 digits.
+    ///
+    /// erf(±∞) is ±1, and the result is within a `Quad`'s precision of ±1 long before
+    /// that, once |*x*| is more than about 12.4.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(0.5).erf();
+    /// let expected = qd!("0.520499877813046537682746653891964528736451575757963700058805725647");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`erfc`]: #method.erfc
+    pub fn erf(self) -> Quad {
+        match self.pre_erf() {
+            Some(r) => s::guard("Quad::erf", &[self.0], r),
+            None => {
+                let x = self.abs();
+                let r = if x.0 < SERIES_MAX {
+                    series(x)
+                } else {
+                    Quad::ONE - fraction(x)
+                };
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
+    /// Computes the complementary error function, erfc(*x*) = 1 - erf(*x*), where *x* is
+    /// this `Quad`.
+    ///
+    /// erfc(*x*) gets very small as *x* grows, and calculating it as `1 - x.erf()` would
+    /// lose all of its digits to cancellation. Instead, for *x* >= 4, it's calculated with
+    /// the continued fraction
+    ///
+    /// erfc(*x*) = (2*x* / √π) *e*<sup>-*x*²</sup> / (2*x*² + 1 - 1 · 2 / (2*x*² + 5 -
+    /// 3 · 4 / (2*x*² + 9 - ⋯))),
+    ///
+    /// which keeps its relative precision all the way down to where the result underflows
+    /// at *x* of about 27.2. The fraction converges too slowly to be used much below 4, so
+    /// for 1/2 <= *x* < 4, erfc is calculated by applying the trapezoid rule to an integral
+    /// for it, which also keeps its relative precision. Below 1/2, it's 1 - [`erf`]\(*x*),
+    /// which loses less than half a digit to cancellation.
+    ///
+    /// The result is precise to about 62 digits, until the lower components underflow as
+    /// the result gets smaller. Once *x* is more than about 24, fewer digits are left.
+    ///
+    /// erfc(∞) is 0 and erfc(-∞) is 2.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(10).erfc();
+    /// let expected = qd!("2.088487583762544757000786294957788611560818119321163727012213713938e-45");
+    ///
+    /// let diff = ((x - expected) / expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`erf`]: #method.erf
+    pub fn erfc(self) -> Quad {
+        match self.pre_erfc() {
+            Some(r) => s::guard("Quad::erfc", &[self.0], r),
+            None => {
+                if self.0 >= SERIES_MAX {
+                    fraction(self)
+                } else if self.0 >= TRAPEZOID_MIN {
+                    trapezoid(self)
+                } else if self.0 > -SERIES_MAX {
+                    // The series is odd, so this works for negative x, for which erf(x) is
+                    // negative and there's no cancellation at all
+                    Quad::ONE - series(self)
+                } else {
+                    Quad(2.0, 0.0, 0.0, 0.0) - fraction(-self)
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn pre_erf(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            // Includes both zeros, which keep their signs
+            Some(*self)
+        } else if self.0 > ERFC_MAX {
+            Some(Quad::ONE)
+        } else if self.0 < -ERFC_MAX {
+            Some(Quad::NEG_ONE)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_erfc(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.0 > ERFC_MAX {
+            Some(Quad::ZERO)
+        } else if self.0 < -ERFC_MAX {
+            Some(Quad(2.0, 0.0, 0.0, 0.0))
+        } else {
+            None
+        }
+    }
+}
+
+// Computes erf(x) for |x| < SERIES_MAX with the series
+//
+//      erf(x) = 2/√π · x e^(-x²) · Σ (2x²)^n / (1 · 3 · 5 ··· (2n + 1))
+//
+// This is the Maclaurin series of e^(x²) erf(x). Unlike the Maclaurin series of erf(x)
+// itself, its terms don't alternate in sign, so none of them cancel however large x is.
+fn series(x: Quad) -> Quad {
+    let x2 = x.sqr();
+    let z = c::mul_pwr2(x2, 2.0);
+    let mut t = Quad::ONE;
+    let mut sum = Quad::ONE;
+    let mut d = 1.0;
+    while t.0 > sum.0 * Quad::EPSILON.0 {
+        d += 2.0;
+        t = t * z / Quad(d, 0.0, 0.0, 0.0);
+        sum += t;
+    }
+    Quad::FRAC_2_SQRT_PI * x * sum * (-x2).exp()
+}
+
+// Computes erfc(x) for x >= SERIES_MAX with the continued fraction
+//
+//      erfc(x) = 2x e^(-x²) / √π · 1 / (2x² + 1 - 1·2 / (2x² + 5 - 3·4 / (2x² + 9 - ...)))
+//
+// evaluated from the bottom up. The fraction converges more slowly for smaller x, and the
+// number of terms is just over what full precision needs at each x. At x = 4, that's 125.
+fn fraction(x: Quad) -> Quad {
+    let x2 = x.sqr();
+    let z = c::mul_pwr2(x2, 2.0);
+    let n = (40.0 / x.0).powi(2) as i32 + 25;
+    let mut t = z + Quad((4 * n + 1) as f64, 0.0, 0.0, 0.0);
+    for k in (1..=n).rev() {
+        let a = Quad(((2 * k - 1) * 2 * k) as f64, 0.0, 0.0, 0.0);
+        t = z + Quad((4 * k - 3) as f64, 0.0, 0.0, 0.0) - a / t;
+    }
+    // The exponential is the only part that can underflow, so it's multiplied in last
+    Quad::FRAC_2_SQRT_PI * x / t * (-x2).exp()
+}
+
+// Computes erfc(x) for TRAPEZOID_MIN <= x < SERIES_MAX by applying the trapezoid rule to
+//
+//      erfc(x) = (2x e^(-x²) / π) ∫ e^(-t²) / (t² + x²) dt, over t in [0, ∞),
+//
+// and correcting for the poles of the integrand at t = ±ix (Chiarella and Reichel, 1968):
+//
+//      erfc(x) ≈ (2hx e^(-x²) / π) (1 / 2x² + Σ e^(-k²h²) / (k²h² + x²))
+//                  + 2 / (1 - e^(2πx / h))
+//
+// The rule converges very quickly for an integrand like this one, and its relative error is
+// about e^(-π²/h²) whatever x is. The terms of the sum fall off so fast that about 50 of
+// them are enough for any x. The factors e^(-k²h²) are built up one from the other, so
+// each has a rounding error of a few units per term before it, but the terms that have
+// the larger errors are too small for them to matter.
+fn trapezoid(x: Quad) -> Quad {
+    let x2 = x.sqr();
+    let h2 = Quad(STEP, 0.0, 0.0, 0.0).sqr();
+    let r = (-c::mul_pwr2(h2, 2.0)).exp();
+    // e^(-(2k - 1)h²) and e^(-k²h²), for k = 1
+    let mut m = (-h2).exp();
+    let mut q = m;
+    let mut sum = c::mul_pwr2(x2, 2.0).recip();
+    let mut k = 1.0;
+    loop {
+        let t = q / (h2 * Quad(k * k, 0.0, 0.0, 0.0) + x2);
+        sum += t;
+        if t.0 <= sum.0 * Quad::EPSILON.0 {
+            break;
+        }
+        k += 1.0;
+        m *= r;
+        q *= m;
+    }
+    // 2 / (1 - e^y) = -2e^(-y) / (1 - e^(-y)), which doesn't overflow
+    let e = (-Quad::TAU * x / Quad(STEP, 0.0, 0.0, 0.0)).exp();
+    let pole = c::mul_pwr2(e / (Quad::ONE - e), 2.0);
+    c::mul_pwr2(x * sum, 2.0) * Quad(STEP, 0.0, 0.0, 0.0) * Quad::FRAC_1_PI * (-x2).exp() - pole
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // erf tests
+    test_all_prec!(
+        erf_tiny:
+            qd!("1.128379167095512573892397639231226796441792012772596851574950040615286e-10"),
+            qd!(1e-10).erf(),
+            62;
+        erf_eighth:
+            qd!("0.1403162048013338173930294465216233981869795831498454731141730264276365"),
+            qd!(0.125).erf(),
+            62;
+        erf_half:
+            qd!("0.5204998778130465376827466538919645287364515757579637000588057256471935"),
+            qd!(0.5).erf(),
+            62;
+        erf_one:
+            qd!("0.8427007929497148693412206350826092592960669979663029084599378978347173"),
+            qd!(1).erf(),
+            62;
+        erf_2_5:
+            qd!("0.999593047982555041060435784260025087279651322596286579860879221230903"),
+            qd!(2.5).erf(),
+            62;
+        erf_3_5:
+            qd!("0.9999992569016276585872544763162439043642793399078272025374088904300565"),
+            qd!(3.5).erf(),
+            62;
+        erf_five:
+            qd!("0.9999999999984625402055719651498116565146166211098819496852766200693121"),
+            qd!(5).erf(),
+            62;
+        erf_ten:
+            qd!("0.9999999999999999999999999999999999999999999979115124162374552429992137"),
+            qd!(10).erf(),
+            62;
+        erf_neg_half:
+            qd!("-0.5204998778130465376827466538919645287364515757579637000588057256471935"),
+            qd!(-0.5).erf(),
+            62;
+        erf_neg_five:
+            qd!("-0.9999999999984625402055719651498116565146166211098819496852766200693121"),
+            qd!(-5).erf(),
+            62;
+    );
+    test_all_exact!(
+        erf_zero:
+            Quad::ZERO,
+            Quad::ZERO.erf();
+        erf_neg_zero:
+            Quad::NEG_ZERO,
+            Quad::NEG_ZERO.erf();
+        erf_28:
+            Quad::ONE,
+            qd!(28).erf();
+        erf_inf:
+            Quad::ONE,
+            Quad::INFINITY.erf();
+        erf_neg_inf:
+            Quad::NEG_ONE,
+            Quad::NEG_INFINITY.erf();
+        erf_nan:
+            Quad::NAN,
+            Quad::NAN.erf();
+    );
+    test!(erf_26: {
+        prec!(Quad::ONE, qd!(26).erf(), 62);
+        assert!(qd!(26).erf() == Quad::ONE - qd!(26).erfc());
+    });
+
+    // erfc tests
+    test_all_prec!(
+        erfc_tiny:
+            qd!("0.999999999887162083290448742610760236076877320355820798722740314842505"),
+            qd!(1e-10).erfc(),
+            62;
+        erfc_half:
+            qd!("0.4795001221869534623172533461080354712635484242420362999411942743528065"),
+            qd!(0.5).erfc(),
+            62;
+        erfc_0_99:
+            qd!("0.1614919304446301964202097694700767037291839885918686853181343520817571"),
+            qd!(0.99).erfc(),
+            62;
+        erfc_one:
+            qd!("0.1572992070502851306587793649173907407039330020336970915400621021652827"),
+            qd!(1).erfc(),
+            62;
+        erfc_1_5:
+            qd!("0.03389485352468927293302373835405214131858952074236321955003215355786715"),
+            qd!(1.5).erfc(),
+            62;
+        erfc_four:
+            qd!("1.541725790028001885215967348688404857214525358919116834290499421304103e-8"),
+            qd!(4).erfc(),
+            62;
+        erfc_five:
+            qd!("1.537459794428034850188343485383378890118050314723379930687914055920391e-12"),
+            qd!(5).erfc(),
+            62;
+        erfc_ten:
+            qd!("2.088487583762544757000786294957788611560818119321163727012213713938175e-45"),
+            qd!(10).erfc(),
+            62;
+        erfc_neg_half:
+            qd!("1.520499877813046537682746653891964528736451575757963700058805725647194"),
+            qd!(-0.5).erfc(),
+            62;
+        erfc_neg_one:
+            qd!("1.842700792949714869341220635082609259296066997966302908459937897834717"),
+            qd!(-1).erfc(),
+            62;
+        erfc_neg_five:
+            qd!("1.999999999998462540205571965149811656514616621109881949685276620069312"),
+            qd!(-5).erfc(),
+            62;
+    );
+    test_all_exact!(
+        erfc_zero:
+            Quad::ONE,
+            Quad::ZERO.erfc();
+        erfc_28:
+            Quad::ZERO,
+            qd!(28).erfc();
+        erfc_inf:
+            Quad::ZERO,
+            Quad::INFINITY.erfc();
+        erfc_neg_inf:
+            qd!(2),
+            Quad::NEG_INFINITY.erfc();
+        erfc_nan:
+            Quad::NAN,
+            Quad::NAN.erfc();
+    );
+    test!(erfc_26: {
+        // The result is near enough to underflow that the lower components are subnormal
+        // or zero, so it's scaled up by 2^1000 to compare it
+        let expected =
+            qd!("606815.9410241287762833313589902139434319497325636177869621231152606431");
+        prec!(expected, qd!(26).erfc().ldexp(1000), 27);
+    });
+    test!(erfc_27: {
+        // Subnormal, so only the first component is left, and it has only a few bits
+        let x = qd!(27).erfc();
+        assert!(x.1 == 0.0);
+        assert!((x.0 / 5.237048923789255e-319 - 1.0).abs() < 1e-3);
+    });
+    test!(erf_erfc_sum: {
+        for &x in &[-5.0, -2.2, -0.7, 0.01, 0.3, 0.9, 1.0, 1.1, 3.9, 4.0, 4.1, 6.0] {
+            let x = qd!(x);
+            prec!(Quad::ONE, x.erf() + x.erfc(), 62);
+        }
+    });
+}
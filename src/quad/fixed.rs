@@ -0,0 +1,419 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::fixed as f;
+use crate::error::{FormatErrorKind, FormatFixedError, ParseQuadError};
+use crate::quad::Quad;
+
+// 2^127. A scaled value has to be smaller than this for its rounded value to fit into an
+// `i128`.
+const SCALED_LIMIT: f64 = 170_141_183_460_469_231_731_687_303_715_884_105_728.0;
+
+impl Quad {
+    /// Parses a fixed-point number with an implied decimal point from a field of ASCII
+    /// bytes.
+    ///
+    /// This is meant for the kinds of fixed-width fields found in market data feeds, where
+    /// a price like 1234.5678 might be sent as `b"12345678"` with four implied decimals.
+    /// The field may be padded with spaces on either side and with leading zeros, and the
+    /// number may have a leading `+` or `-`. Nothing else is allowed; in particular, there
+    /// can be no decimal point or exponent. Because the input is bytes, no UTF-8 validation
+    /// is done, and nothing is allocated.
+    ///
+    /// The digits are accumulated into a `u128`, which is then converted into a `Quad` and
+    /// divided by the power of ten given by `implied_decimals`. Every `u128` can be
+    /// represented exactly by a `Quad`, and as long as `implied_decimals` is no more than
+    /// 38, that power of ten is exact too, so the only rounding in the whole process is in
+    /// that one division. Larger numbers of implied decimals are handled by dividing more
+    /// than once.
+    ///
+    /// Failure will return a [`ParseQuadError`]. Its kind will be `Empty` if the field is
+    /// empty or holds only spaces, `Overflow` if the integer is too large to fit into a
+    /// `u128`, and `Invalid` for anything else.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::from_ascii_fixed(b"  -0012345678", 4).unwrap();
+    /// assert!(x == qd!(-12345678) / qd!(10000));
+    ///
+    /// let diff = (x - qd!("-1234.5678")).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`ParseQuadError`]: error/struct.ParseQuadError.html
+    pub fn from_ascii_fixed(bytes: &[u8], implied_decimals: u32) -> Result<Quad, ParseQuadError> {
+        let (negative, n) = f::parse_fixed(bytes).map_err(|kind| ParseQuadError { kind })?;
+
+        let mut result = Quad::from(n);
+        let mut decimals = implied_decimals;
+        while decimals > f::MAX_EXACT_DECIMALS && !result.is_zero() {
+            result /= Quad::from(f::pow10(f::MAX_EXACT_DECIMALS));
+            decimals -= f::MAX_EXACT_DECIMALS;
+        }
+        // A zero (or a number that has underflowed to zero) stops the loop early with more
+        // than `MAX_EXACT_DECIMALS` still to go, but dividing it further wouldn't change it
+        if decimals > 0 && !result.is_zero() {
+            result /= Quad::from(f::pow10(decimals));
+        }
+        Ok(if negative { -result } else { result })
+    }
+
+    /// Writes this `Quad` as a fixed-point number with an implied decimal point into a
+    /// field of ASCII bytes.
+    ///
+    /// This is the inverse of [`from_ascii_fixed`]. The number is multiplied by the power
+    /// of ten given by `implied_decimals` and rounded to an integer, which is written into
+    /// `buf` right-aligned and padded with zeros. A negative number has a `-` in the first
+    /// byte of the field. The whole buffer is always written, so the returned number of
+    /// bytes written is always the length of the buffer.
+    ///
+    /// The rounding is exact, not just the rounding of a scaled `Quad`. If the number
+    /// is exactly halfway between two integers once it's scaled, it's rounded to the even
+    /// one (as with the default rounding mode of IEEE-754 arithmetic), so 0.125 with two
+    /// implied decimals is written as 12 and 0.375 as 38.
+    ///
+    /// Failure will return a [`FormatFixedError`]. Its kind will be `NotFinite` if this
+    /// number is infinite or `NaN`, `Overflow` if `implied_decimals` is larger than 38 or
+    /// the scaled number is 2<sup>127</sup> or larger, and `BufferTooSmall` if the buffer
+    /// doesn't have room for the sign and all of the digits. The contents of the buffer
+    /// are unspecified if an error is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut buf = [0u8; 10];
+    /// let n = qd!("-1234.5678").to_ascii_fixed(4, &mut buf).unwrap();
+    /// assert!(n == 10);
+    /// assert!(&buf == b"-012345678");
+    ///
+    /// qd!(0.125).to_ascii_fixed(2, &mut buf[..4]).unwrap();
+    /// assert!(&buf[..4] == b"0012");
+    /// ```
+    ///
+    /// [`from_ascii_fixed`]: #method.from_ascii_fixed
+    /// [`FormatFixedError`]: error/struct.FormatFixedError.html
+    pub fn to_ascii_fixed(
+        &self,
+        implied_decimals: u32,
+        buf: &mut [u8],
+    ) -> Result<usize, FormatFixedError> {
+        if !self.is_finite() {
+            return Err(FormatFixedError {
+                kind: FormatErrorKind::NotFinite,
+            });
+        }
+        if implied_decimals > f::MAX_EXACT_DECIMALS {
+            return Err(FormatFixedError {
+                kind: FormatErrorKind::Overflow,
+            });
+        }
+
+        let scale = f::pow10(implied_decimals);
+        let guess = (*self * Quad::from(scale)).round();
+        if guess.abs() >= Quad(SCALED_LIMIT, 0.0, 0.0, 0.0) {
+            return Err(FormatFixedError {
+                kind: FormatErrorKind::Overflow,
+            });
+        }
+
+        let (s0, s1, s2) = f::split_u128(scale);
+        let products = [
+            (self.0, s0),
+            (self.0, s1),
+            (self.0, s2),
+            (self.1, s0),
+            (self.1, s1),
+            (self.1, s2),
+            (self.2, s0),
+            (self.2, s1),
+            (self.2, s2),
+            (self.3, s0),
+            (self.3, s1),
+            (self.3, s2),
+        ];
+        let guess = guess.0 as i128 + guess.1 as i128 + guess.2 as i128 + guess.3 as i128;
+        let mut terms = [0.0; 28];
+        let k = f::round_half_even(&products, guess, &mut terms);
+
+        f::write_fixed(k < 0, k.unsigned_abs(), buf).map_err(|kind| FormatFixedError { kind })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::random::Rng;
+    use crate::error::ErrorKind;
+
+    fn parse(bytes: &[u8], decimals: u32) -> Quad {
+        Quad::from_ascii_fixed(bytes, decimals).unwrap()
+    }
+
+    fn parse_err(bytes: &[u8], decimals: u32) -> ErrorKind {
+        Quad::from_ascii_fixed(bytes, decimals).unwrap_err().kind
+    }
+
+    fn write(x: Quad, decimals: u32, width: usize) -> String {
+        let mut buf = vec![0u8; width];
+        let n = x.to_ascii_fixed(decimals, &mut buf).unwrap();
+        assert_eq!(n, width);
+        String::from_utf8(buf).unwrap()
+    }
+
+    fn write_err(x: Quad, decimals: u32, width: usize) -> FormatErrorKind {
+        let mut buf = vec![0u8; width];
+        x.to_ascii_fixed(decimals, &mut buf).unwrap_err().kind
+    }
+
+    test_all_exact!(
+        parse_plain:
+            qd!(12_345_678) / qd!(10_000),
+            parse(b"12345678", 4);
+        parse_leading_spaces:
+            qd!(12_345_678) / qd!(10_000),
+            parse(b"    12345678", 4);
+        parse_trailing_spaces:
+            qd!(12_345_678) / qd!(10_000),
+            parse(b"12345678    ", 4);
+        parse_leading_zeros:
+            qd!(12_345_678) / qd!(10_000),
+            parse(b"000012345678", 4);
+        parse_spaces_and_zeros:
+            qd!(12_345_678) / qd!(10_000),
+            parse(b"  0012345678", 4);
+        parse_plus:
+            qd!(12_345_678) / qd!(10_000),
+            parse(b"+0012345678", 4);
+        parse_minus:
+            qd!(-12_345_678) / qd!(10_000),
+            parse(b" -0012345678 ", 4);
+        parse_no_decimals:
+            qd!(12_345_678),
+            parse(b"12345678", 0);
+        parse_all_decimals:
+            qd!(12_345_678) / qd!(100_000_000),
+            parse(b"12345678", 8);
+        parse_zero:
+            Quad::ZERO,
+            parse(b"0000", 2);
+        parse_neg_zero:
+            Quad::NEG_ZERO,
+            parse(b"-0000", 2);
+        parse_u128_max:
+            Quad::from(u128::MAX),
+            parse(b"340282366920938463463374607431768211455", 0);
+        parse_zero_many_decimals:
+            Quad::ZERO,
+            parse(b"0", 50);
+        parse_underflow:
+            Quad::ZERO,
+            parse(b"1", 400);
+        parse_neg_underflow:
+            Quad::NEG_ZERO,
+            parse(b"-1", 400);
+    );
+
+    test!(parse_underflow_sign: {
+        assert!(parse(b"-0", 50).is_sign_negative());
+        assert!(parse(b"-1", 400).is_sign_negative());
+        assert!(parse(b"1", 400).is_sign_positive());
+        assert!(parse(b"12345", u32::MAX).is_sign_positive());
+    });
+
+    test_all_near!(
+        parse_fraction:
+            qd!("1234.5678"),
+            parse(b"12345678", 4);
+        parse_cents:
+            qd!("-0.01"),
+            parse(b"-00000001", 2);
+        parse_long:
+            qd!("0.12345678901234567890123456789012345678"),
+            parse(b"12345678901234567890123456789012345678", 38);
+        parse_many_decimals:
+            qd!("1.2345e-45"),
+            parse(b"12345", 49);
+    );
+
+    test_all_eq!(
+        parse_empty:
+            ErrorKind::Empty,
+            parse_err(b"", 2);
+        parse_only_spaces:
+            ErrorKind::Empty,
+            parse_err(b"     ", 2);
+        parse_sign_only:
+            ErrorKind::Invalid,
+            parse_err(b"  -  ", 2);
+        parse_double_sign:
+            ErrorKind::Invalid,
+            parse_err(b"--1234", 2);
+        parse_sign_after_zeros:
+            ErrorKind::Invalid,
+            parse_err(b"00-1234", 2);
+        parse_inner_space:
+            ErrorKind::Invalid,
+            parse_err(b"12 34", 2);
+        parse_space_after_sign:
+            ErrorKind::Invalid,
+            parse_err(b"- 1234", 2);
+        parse_point:
+            ErrorKind::Invalid,
+            parse_err(b"12.34", 2);
+        parse_letter:
+            ErrorKind::Invalid,
+            parse_err(b"12a4", 2);
+        parse_non_ascii:
+            ErrorKind::Invalid,
+            parse_err(&[b'1', 0xc3, 0xa9], 2);
+        parse_u128_overflow:
+            ErrorKind::Overflow,
+            parse_err(b"340282366920938463463374607431768211456", 0);
+        parse_long_overflow:
+            ErrorKind::Overflow,
+            parse_err(b"1000000000000000000000000000000000000000000", 40);
+    );
+
+    test_all_eq!(
+        write_plain:
+            "0012345678",
+            write(qd!("1234.5678"), 4, 10);
+        write_exact_width:
+            "12345678",
+            write(qd!("1234.5678"), 4, 8);
+        write_negative:
+            "-012345678",
+            write(qd!("-1234.5678"), 4, 10);
+        write_negative_exact_width:
+            "-12345678",
+            write(qd!("-1234.5678"), 4, 9);
+        write_zero:
+            "00000",
+            write(Quad::ZERO, 2, 5);
+        write_neg_zero:
+            "00000",
+            write(Quad::NEG_ZERO, 2, 5);
+        write_tiny_negative:
+            "00000",
+            write(qd!(-1e-10), 2, 5);
+        write_no_decimals:
+            "0042",
+            write(qd!(42), 0, 4);
+        write_large:
+            "12345678901234567890123456789012345678",
+            write(parse(b"12345678901234567890123456789012345678", 0), 0, 38);
+        write_i128_max:
+            "170141183460469231731687303715884105727",
+            write(Quad::from(i128::MAX), 0, 39);
+        write_i128_max_neg:
+            "-170141183460469231731687303715884105727",
+            write(-Quad::from(i128::MAX), 0, 40);
+    );
+
+    // Ties go to the even integer, and anything even a little bit away from a tie goes to
+    // the nearer one. The tie values are all exactly representable.
+    test_all_eq!(
+        round_tie_down:
+            "12",
+            write(qd!(0.125), 2, 2);
+        round_tie_up:
+            "38",
+            write(qd!(0.375), 2, 2);
+        round_tie_zero:
+            "0",
+            write(qd!(0.5), 0, 1);
+        round_tie_one:
+            "2",
+            write(qd!(1.5), 0, 1);
+        round_tie_two:
+            "2",
+            write(qd!(2.5), 0, 1);
+        round_tie_neg:
+            "-2",
+            write(qd!(-2.5), 0, 2);
+        round_tie_neg_odd:
+            "-4",
+            write(qd!(-3.5), 0, 2);
+        round_tie_in_tail:
+            "10000000000000000000000000000000000000",
+            write(parse(b"10000000000000000000000000000000000000", 0) + qd!(0.5), 0, 38);
+        round_tie_in_tail_odd:
+            "10000000000000000000000000000000000002",
+            write(parse(b"10000000000000000000000000000000000000", 0) + qd!(1.5), 0, 38);
+        round_above_tie:
+            "13",
+            write(qd!(0.125) + qd!(1e-60), 2, 2);
+        round_below_tie:
+            "37",
+            write(qd!(0.375) - qd!(1e-60), 2, 2);
+        round_above_tie_neg:
+            "-13",
+            write(qd!(-0.125) - qd!(1e-60), 2, 3);
+        round_nearest_down:
+            "1",
+            write(qd!("0.0149"), 2, 1);
+        round_nearest_up:
+            "2",
+            write(qd!("0.0151"), 2, 1);
+    );
+
+    test_all_eq!(
+        write_nan:
+            FormatErrorKind::NotFinite,
+            write_err(Quad::NAN, 2, 10);
+        write_inf:
+            FormatErrorKind::NotFinite,
+            write_err(Quad::INFINITY, 2, 10);
+        write_neg_inf:
+            FormatErrorKind::NotFinite,
+            write_err(Quad::NEG_INFINITY, 2, 10);
+        write_too_many_decimals:
+            FormatErrorKind::Overflow,
+            write_err(qd!(1), 39, 50);
+        write_too_large:
+            FormatErrorKind::Overflow,
+            write_err(qd!(2).powi(127), 0, 50);
+        write_too_large_scaled:
+            FormatErrorKind::Overflow,
+            write_err(qd!(1e20), 20, 50);
+        write_buffer_too_small:
+            FormatErrorKind::BufferTooSmall,
+            write_err(qd!("1234.5678"), 4, 7);
+        write_buffer_too_small_sign:
+            FormatErrorKind::BufferTooSmall,
+            write_err(qd!("-1234.5678"), 4, 8);
+        write_empty_buffer:
+            FormatErrorKind::BufferTooSmall,
+            write_err(Quad::ZERO, 0, 0);
+    );
+
+    // Random integers of up to 38 digits with random implied decimals should survive a
+    // round trip through parsing and writing unchanged.
+    test!(round_trip: {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..20_000 {
+            let digits = (rng.next_u64() % 38) as usize + 1;
+            let decimals = (rng.next_u64() % (digits as u64 + 1)) as u32;
+            let mut field = vec![b'0'; 40];
+            for b in field.iter_mut().skip(40 - digits) {
+                *b = b'0' + (rng.next_u64() % 10) as u8;
+            }
+            if rng.next_u64() % 2 == 0 {
+                field[0] = b'-';
+            }
+
+            let x = parse(&field, decimals);
+            let s = write(x, decimals, 40);
+            let expected = if field.iter().skip(1).all(|&b| b == b'0') {
+                // Negative zero is written without a sign
+                "0".repeat(40)
+            } else {
+                String::from_utf8(field.clone()).unwrap()
+            };
+            assert_eq!(s, expected, "decimals = {}", decimals);
+        }
+    });
+}
@@ -3,13 +3,27 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::literal as l;
+use crate::common::literal::{Conversion, IntSuffix, Literal, LiteralError};
+use crate::common::rounding as r;
+use crate::common::rounding::RoundingMode;
 use crate::common::utils as u;
 use crate::double::Double;
+use crate::error::{ParseQuadError, ToIntError, ToIntErrorKind};
+use crate::quad::common as c;
+use crate::quad::from_str::parse_decimal;
 use crate::quad::Quad;
+use std::convert::TryFrom;
 use std::f64;
 
+// 2^63, 2^64, 2^127, and 2^128, the bounds of the 64- and 128-bit integer types.
+const TWO_63: f64 = 9223372036854775808.0;
+const TWO_64: f64 = 18446744073709551616.0;
+const TWO_127: f64 = 1.7014118346046923e38;
+const TWO_128: f64 = 3.402823669209385e38;
+
 #[inline]
-fn split_u64(a: u64) -> (u32, u32) {
+const fn split_u64(a: u64) -> (u32, u32) {
     let x = (a >> 32) as u32;
     let y = a as u32;
     (x, y)
@@ -17,7 +31,7 @@ fn split_u64(a: u64) -> (u32, u32) {
 
 #[inline]
 #[allow(clippy::many_single_char_names)]
-fn split_u128(a: u128) -> (u32, u32, u32, u32) {
+const fn split_u128(a: u128) -> (u32, u32, u32, u32) {
     let w = (a >> 96) as u32;
     let x = (a >> 64) as u32;
     let y = (a >> 32) as u32;
@@ -25,13 +39,13 @@ fn split_u128(a: u128) -> (u32, u32, u32, u32) {
     (w, x, y, z)
 }
 
-fn from_u64(a: u64) -> Quad {
+pub(super) const fn from_u64(a: u64) -> Quad {
     let (x, y) = split_u64(a);
-    let (a, b, c, d) = u::renorm4(x as f64 * 2f64.powi(32), y as f64, 0.0, 0.0);
+    let (a, b, c, d) = u::renorm4(x as f64 * u::pow2(32), y as f64, 0.0, 0.0);
     Quad(a, b, c, d)
 }
 
-fn from_i64(a: i64) -> Quad {
+const fn from_i64(a: i64) -> Quad {
     let sign = a.signum();
     // The first part prevents a.abs() from failing with overflow because the absolute
     // value of i64::MIN is i64::MAX + 1
@@ -41,7 +55,7 @@ fn from_i64(a: i64) -> Quad {
         a.abs() as u64
     };
     let (x, y) = split_u64(a);
-    let (a, b, c, d) = u::renorm4(x as f64 * 2f64.powi(32), y as f64, 0.0, 0.0);
+    let (a, b, c, d) = u::renorm4(x as f64 * u::pow2(32), y as f64, 0.0, 0.0);
     if sign == -1 {
         Quad(-a, -b, -c, -d)
     } else {
@@ -50,19 +64,19 @@ fn from_i64(a: i64) -> Quad {
 }
 
 #[allow(clippy::many_single_char_names)]
-fn from_u128(a: u128) -> Quad {
+const fn from_u128(a: u128) -> Quad {
     let (w, x, y, z) = split_u128(a);
     let (a, b, c, d) = u::renorm4(
-        w as f64 * 2f64.powi(96),
-        x as f64 * 2f64.powi(64),
-        y as f64 * 2f64.powi(32),
+        w as f64 * u::pow2(96),
+        x as f64 * u::pow2(64),
+        y as f64 * u::pow2(32),
         z as f64,
     );
     Quad(a, b, c, d)
 }
 
 #[allow(clippy::many_single_char_names)]
-fn from_i128(a: i128) -> Quad {
+const fn from_i128(a: i128) -> Quad {
     let sign = a.signum();
     // The first part prevents a.abs() from failing with overflow because the absolute
     // value of i128::MIN is i128::MAX + 1
@@ -73,9 +87,9 @@ fn from_i128(a: i128) -> Quad {
     };
     let (w, x, y, z) = split_u128(a);
     let (a, b, c, d) = u::renorm4(
-        w as f64 * 2f64.powi(96),
-        x as f64 * 2f64.powi(64),
-        y as f64 * 2f64.powi(32),
+        w as f64 * u::pow2(96),
+        x as f64 * u::pow2(64),
+        y as f64 * u::pow2(32),
         z as f64,
     );
     if sign == -1 {
@@ -85,6 +99,92 @@ fn from_i128(a: i128) -> Quad {
     }
 }
 
+impl Quad {
+    // Converts a literal, given as the text that `stringify!` makes of it, at compile time.
+    // This is what `qd!` does with a literal, and it's only public so that the macro can
+    // call it. As with `Double::__from_literal`, a float literal gives the same number as
+    // parsing its digits and is an error if it has too many of them, and anything else gives
+    // the same number as calling `from` or parsing.
+    #[doc(hidden)]
+    pub const fn __from_literal(text: &str) -> Result<Quad, LiteralError> {
+        match l::lex(text) {
+            Ok(Literal::Str(s)) => Ok(match Quad::from_str_const(s) {
+                Ok(x) => x,
+                Err(_) => Quad::NAN,
+            }),
+            Ok(Literal::Int {
+                negative,
+                value,
+                suffix,
+            }) => {
+                if let Err(e) = l::check_int(negative, value, suffix) {
+                    return Err(e);
+                }
+                if negative && value > i128::MAX as u128 + 1 {
+                    return Err(LiteralError::Invalid(
+                        "integer literal is too large for `i128`",
+                    ));
+                }
+                // Negating in the integer type keeps -0 from becoming -0.0
+                let signed = if negative {
+                    value.wrapping_neg() as i128
+                } else {
+                    value as i128
+                };
+                match suffix {
+                    IntSuffix::Size => Err(LiteralError::Invalid(
+                        "`isize` and `usize` don't convert to `Quad`",
+                    )),
+                    IntSuffix::Signed(64) => Ok(from_i64(signed as i64)),
+                    IntSuffix::Unsigned(64) => Ok(from_u64(value as u64)),
+                    IntSuffix::Signed(128) => Ok(from_i128(signed)),
+                    IntSuffix::Unsigned(128) => Ok(from_u128(value)),
+                    // Without a suffix, an integer is an `i32` if it fits into one
+                    IntSuffix::None if negative && value > i32::MAX as u128 + 1 => {
+                        Ok(if value > i64::MAX as u128 + 1 {
+                            from_i128(signed)
+                        } else {
+                            from_i64(signed as i64)
+                        })
+                    }
+                    IntSuffix::None if !negative && value > i32::MAX as u128 => {
+                        Ok(if value > u64::MAX as u128 {
+                            from_u128(value)
+                        } else {
+                            from_u64(value as u64)
+                        })
+                    }
+                    _ => Ok(Quad(signed as f64, 0.0, 0.0, 0.0)),
+                }
+            }
+            Ok(Literal::Float(dec)) => {
+                let parsed = parse_decimal(dec.digits, dec.exp, dec.negative);
+                match l::convert(&dec, parsed.0.abs()) {
+                    Ok(Conversion::Exact(x)) => Ok(Quad(x, 0.0, 0.0, 0.0)),
+                    Ok(Conversion::Digits) => Ok(parsed),
+                    Err(e) => Err(e),
+                }
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    // What `try_qd!` does with a literal, which is what `__from_literal` does, except that a
+    // string that doesn't parse gives its error rather than NaN.
+    #[doc(hidden)]
+    pub const fn __try_from_literal(
+        text: &str,
+    ) -> Result<Result<Quad, ParseQuadError>, LiteralError> {
+        match l::lex(text) {
+            Ok(Literal::Str(s)) => Ok(Quad::from_str_const(s)),
+            _ => match Quad::__from_literal(text) {
+                Ok(x) => Ok(Ok(x)),
+                Err(e) => Err(e),
+            },
+        }
+    }
+}
+
 // FROM INTEGER IMPLEMENTATIONS
 //
 // These are simple enough - since integers are inherently dyadic (as long as they fit into
@@ -235,15 +335,9 @@ from_long_int_impl! {
 
 // FROM FLOAT IMPLEMENTATIONS
 //
-// The Rust conversion from f32 to f64 is a bit-for-bit translation. It does not attempt to
-// account for floating point rounding error, so the parsed f64 is different from the
-// "equivalent" parsed f32. So rather than having a helper function that takes an f64, we
-// put the entire function into this macro so that `a.to_string().parse().unwrap()` calls
-// the f32 parser if an f32 is being converted.
-//
-// is_dyadic is still fine to convert for, because a dyadic f32 will convert accurately
-// into an f64 (and still return true) while a non-dyadic f32 may not convert accurately,
-// but it'll still be non-dyadic after the conversion.
+// Every `f32` and `f64` is a number that a single `f64` holds exactly, so converting one
+// means nothing more than making it the first component. Zeros, infinities, and NaN come
+// through the same way, as the first component with zeros after it.
 macro_rules! from_float_impl {
     ($(
         $(#[$m:meta])*
@@ -252,30 +346,7 @@ macro_rules! from_float_impl {
         $(#[$m])*
         impl From<$t> for Quad {
             fn from(a: $t) -> Quad {
-                if a == 0.0 {
-                    if a.is_sign_negative() {
-                        Quad::NEG_ZERO
-                    } else {
-                        Quad::ZERO
-                    }
-                } else if a.is_nan() {
-                    Quad::NAN
-                } else if a.is_infinite() {
-                    if a.is_sign_negative() {
-                        Quad::NEG_INFINITY
-                    } else {
-                        Quad::INFINITY
-                    }
-                } else if u::is_dyadic(a as f64) {
-                    Quad(a.into(), 0.0, 0.0, 0.0)
-                } else {
-                    // Parsing digit-by-digit from a string is the only way to do this
-                    // accurately.
-                    //
-                    // `unwrap` is safe because `a.to_string` will never return a string
-                    // that can't be parsed into a Quad.
-                    a.to_string().parse().unwrap()
-                }
+                Quad(a.into(), 0.0, 0.0, 0.0)
             }
         }
     )*);
@@ -284,58 +355,85 @@ macro_rules! from_float_impl {
 from_float_impl! {
     /// Generates a `Quad` from an `f32`.
     ///
-    /// This function *does* account for floating point rounding error. Even though the
-    /// first component of a `Quad` is enough to fit an `f32`, if that `f32` is not exactly
-    /// representable in binary, then the second component of the `Quad` will account for
-    /// the rounding error.
-    ///
-    /// Note that in order to do this, the `f32` needs to be parsed digit by digit. While
-    /// the parser does work quite fast with integers or any `f32` that is represented
-    /// perfectly in binary (any number that can be represented as a fraction with a power
-    /// of 2 in the denominator), it's not a particularly fast operation otherwise.
+    /// The `Quad` is exactly the number that the `f32` holds, which is its first component
+    /// with zeros after it. An `f32` written as a decimal number that isn't exactly
+    /// representable in binary, like `0.1f32`, only holds the nearest number that is, and
+    /// so does the `Quad` made from it. To get the `Quad` nearest to the decimal number
+    /// itself, parse it from a string or give it to [`qd!`] as a literal.
     ///
     /// # Examples
     /// ```
-    /// # use qd::Quad;
+    /// # use qd::{qd, Quad};
     /// // Exactly representable in binary
     /// let x = 0.9921875f32;
     /// let a = Quad::from(x);
     /// assert!(a.to_string() == "0.9921875");
     ///
-    /// // Xot exactly representable in binary
-    /// let x = 0.9921876f32;
+    /// // Not exactly representable in binary
+    /// let x = 0.1f32;
     /// let a = Quad::from(x);
-    /// assert!(a.to_string() == "0.9921876");
+    /// assert!(a == x as f64);
+    /// assert!(a != qd!(0.1));
     /// ```
+    ///
+    /// [`qd!`]: macro.qd.html
     f32
     /// Generates a `Quad` from an `f64`.
     ///
-    /// This function *does* account for floating point rounding error. Even though the
-    /// first component of a `Quad` is enough to fit an `f64`, if that `f64` is not exactly
-    /// representable in binary, then the second component of the `Quad` will account for
-    /// the rounding error.
-    ///
-    /// Note that in order to do this, the `f64` needs to be parsed digit by digit. While
-    /// the parser does work quite fast with integers or any `f64` that is represented
-    /// perfectly in binary (any number that can be represented as a fraction with a power
-    /// of 2 in the denominator), it's not a particularly fast operation otherwise.
+    /// The `Quad` is exactly the number that the `f64` holds, which is its first component
+    /// with zeros after it, so it compares equal to the `f64`. An `f64` written as a
+    /// decimal number that isn't exactly representable in binary, like `0.1`, only holds
+    /// the nearest number that is, and so does the `Quad` made from it. To get the `Quad`
+    /// nearest to the decimal number itself, parse it from a string or give it to [`qd!`]
+    /// as a literal.
     ///
     /// # Examples
     /// ```
-    /// # use qd::Quad;
+    /// # use qd::{qd, Quad};
     /// // Exactly representable in binary
     /// let x = 0.999969482421875f64;
     /// let a = Quad::from(x);
     /// assert!(a.to_string() == "0.999969482421875");
     ///
     /// // Not exactly representable in binary
-    /// let x = 0.999969482421876f64;
+    /// let x = 0.1;
     /// let a = Quad::from(x);
-    /// assert!(a.to_string() == "0.999969482421876");
+    /// assert!(a == x);
+    /// assert!(a != qd!(0.1));
     /// ```
+    ///
+    /// [`qd!`]: macro.qd.html
     f64
 }
 
+impl Quad {
+    /// Creates a `Quad` from an `f64` in a `const fn`, for use in constants along with
+    /// [`add_const`] and the other `const` arithmetic.
+    ///
+    /// This is the same conversion as `Quad::from`, which can't be called at compile time: the
+    /// `Quad` is exactly the number that the `f64` holds, with the rest of the components
+    /// zero. For decimal constants like 0.1, which an `f64` can't hold exactly, use [`qd!`]
+    /// instead, which is also evaluated at compile time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// const HALF: Quad = Quad::from_f64_const(0.5);
+    /// assert!(HALF == qd!(0.5));
+    ///
+    /// // 0.1 isn't a binary fraction, so this is the f64 0.1 exactly, and it isn't one tenth
+    /// const TENTH: Quad = Quad::from_f64_const(0.1);
+    /// assert!(TENTH != qd!("0.1"));
+    /// assert!(TENTH[0] == 0.1);
+    /// ```
+    ///
+    /// [`add_const`]: #method.add_const
+    /// [`qd!`]: macro.qd.html
+    pub const fn from_f64_const(a: f64) -> Quad {
+        Quad(a, 0.0, 0.0, 0.0)
+    }
+}
+
 impl From<(f64, f64)> for Quad {
     /// Generates a `Quad` from a 2-tuple of `f64`s.
     ///
@@ -416,7 +514,7 @@ impl From<(f64, f64, f64, f64)> for Quad {
     ///     3.141592653589793e0,
     ///     1.2246467991473532e-16,
     ///     -2.9947698097183397e-33,
-    ///     1.1124542208633655e-49,
+    ///     1.1124542208633653e-49,
     /// ));
     /// assert!(a == Quad::PI);
     /// ```
@@ -429,24 +527,24 @@ impl From<(f64, f64, f64, f64)> for Quad {
 }
 
 impl From<Double> for Quad {
-    /// Generates a `Quad` from a `Double`.
+    /// Converts a `Double` into a `Quad` exactly.
     ///
-    /// The new `Quad`'s third and fourth components will be used to account for
-    /// floating-point rounding error at the end of the `Double`, but it will of course
-    /// otherwise only have the precision of the `Double` used to make it.
+    /// The components of the `Double` become the first two components of the `Quad`, and
+    /// the last two are zero. A normalized `Double` makes a normalized `Quad` this way, so
+    /// nothing has to be rounded. The `Quad` has exactly the value of the `Double`, which
+    /// means that it only has the precision of the `Double` used to make it.
     ///
     /// # Examples
     /// ```
-    /// # use qd::{dd, qd, Quad};
-    /// let expected = Quad::from("0.9999999303082806237436760862691");
+    /// # use qd::{dd, Double, Quad};
     /// let a = (dd!(3).powi(15) - dd!(1)) / dd!(3).powi(15);
     /// let x = Quad::from(a);
-    ///
-    /// let diff = (x - expected).abs();
-    /// assert!(diff < qd!(1e-60));
+    /// assert!(x.to_array() == [a[0], a[1], 0.0, 0.0]);
+    /// assert!(Double::from(x) == a);
     /// ```
+    #[inline]
     fn from(a: Double) -> Quad {
-        a.to_string().parse().unwrap()
+        Quad(a[0], a[1], 0.0, 0.0)
     }
 }
 
@@ -485,12 +583,16 @@ impl From<Quad> for f64 {
     /// Converts a `Quad` into an `f64`.
     ///
     /// This will lose precision if the second component of the `Quad` is not 0, but it
-    /// will not lose range.
+    /// will not lose range. The result is the first component, which is the `Quad` rounded
+    /// to the nearest `f64` except in the rare case that the second component is exactly
+    /// half of the way to the next `f64` and the rest of the components carry the `Quad`
+    /// past it. [`to_f64_round`] always rounds correctly, in any direction.
     ///
-    /// No other conversions from `Quad` to numeric types are provided, as every other one
-    /// has the capability of losing range (for example, no other type could be used to
-    /// represent `qd!(1e308)`). Casts can be made from the `f64` provided by this function
-    /// to other numeric types as needed.
+    /// The only other conversions from `Quad` to numeric types are the `TryFrom`
+    /// conversions to the 64- and 128-bit integers, which succeed only when the `Quad` is an
+    /// integer in range. Every other type has the capability of losing range (for example,
+    /// no other type could be used to represent `qd!(1e308)`). Casts can be made from the
+    /// `f64` provided by this function to other numeric types as needed.
     ///
     /// # Examples
     /// ```
@@ -501,6 +603,8 @@ impl From<Quad> for f64 {
     /// let diff = (x - std::f64::consts::PI).abs();
     /// assert!(diff < 1e-15);
     /// ```
+    ///
+    /// [`to_f64_round`]: #method.to_f64_round
     #[inline]
     fn from(a: Quad) -> f64 {
         a.0
@@ -568,7 +672,7 @@ impl From<Quad> for (f64, f64, f64, f64) {
     /// assert!(a == 3.141592653589793e0);
     /// assert!(b == 1.2246467991473532e-16);
     /// assert!(c == -2.9947698097183397e-33);
-    /// assert!(d == 1.1124542208633655e-49);
+    /// assert!(d == 1.1124542208633653e-49);
     /// ```
     #[inline]
     fn from(a: Quad) -> (f64, f64, f64, f64) {
@@ -576,9 +680,183 @@ impl From<Quad> for (f64, f64, f64, f64) {
     }
 }
 
+// TRY FROM IMPLEMENTATIONS
+//
+// Converting to an integer type never rounds. Every component of a `Quad` that's an integer
+// is an integer itself, so the components can be added up as integers, and as long as the
+// whole number is in range, that sum is exact. It's done modulo 2^128, which is enough to
+// hold any of the results, so the components don't all have to fit the type on their own:
+// the largest `u128` has a first component of 2^128, for instance.
+
+// Checks that `a` is an integer from `min` up to but not including `max`, and returns it
+// modulo 2^128.
+fn to_int(a: Quad, min: f64, max: f64) -> Result<u128, ToIntError> {
+    let kind = if !a.is_finite() {
+        ToIntErrorKind::NotFinite
+    } else if !c::is_integer(a) {
+        ToIntErrorKind::NotInteger
+    } else if a < Quad::from(min) || a >= Quad::from(max) {
+        ToIntErrorKind::OutOfRange
+    } else {
+        return Ok(u::wrapping_sum(&[a.0, a.1, a.2, a.3]));
+    };
+    Err(ToIntError { kind })
+}
+
+macro_rules! try_from_impl {
+    ($(
+        $(#[$m:meta])*
+        $t:ident $min:expr, $max:expr
+    )*) => ($(
+        $(#[$m])*
+        impl TryFrom<Quad> for $t {
+            type Error = ToIntError;
+
+            #[inline]
+            fn try_from(a: Quad) -> Result<$t, ToIntError> {
+                to_int(a, $min, $max).map(|n| n as $t)
+            }
+        }
+    )*);
+}
+
+try_from_impl! {
+    /// Converts a `Quad` into an `i64`, if it's an integer that fits.
+    ///
+    /// This never rounds: a number with a fractional part is an error of the kind
+    /// [`NotInteger`], and [`trunc`] or [`round`] can be used first to convert it anyway.
+    /// An integer too large or too small for an `i64` is an error of the kind
+    /// [`OutOfRange`], and NaN and the infinities are errors of the kind [`NotFinite`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use qd::error::ToIntErrorKind;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(i64::try_from(qd!(-42)) == Ok(-42));
+    /// assert!(i64::try_from(Quad::from(i64::MIN)) == Ok(i64::MIN));
+    /// assert!(i64::try_from(qd!(2.5)).unwrap_err().kind == ToIntErrorKind::NotInteger);
+    /// assert!(i64::try_from(qd!(2).powi(63)).unwrap_err().kind == ToIntErrorKind::OutOfRange);
+    /// ```
+    ///
+    /// [`NotInteger`]: error/enum.ToIntErrorKind.html#variant.NotInteger
+    /// [`OutOfRange`]: error/enum.ToIntErrorKind.html#variant.OutOfRange
+    /// [`NotFinite`]: error/enum.ToIntErrorKind.html#variant.NotFinite
+    /// [`trunc`]: #method.trunc
+    /// [`round`]: #method.round
+    i64 -TWO_63, TWO_63
+    /// Converts a `Quad` into a `u64`, if it's an integer that fits.
+    ///
+    /// Like the other integer conversions, this never rounds, and it's an error if the
+    /// number has a fractional part, if it's negative or too large, or if it isn't finite.
+    /// Negative zero converts to 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(u64::try_from(Quad::from(u64::MAX)) == Ok(u64::MAX));
+    /// assert!(u64::try_from(Quad::NEG_ZERO) == Ok(0));
+    /// assert!(u64::try_from(qd!(-1)).is_err());
+    /// ```
+    u64 0.0, TWO_64
+    /// Converts a `Quad` into an `i128`, if it's an integer that fits.
+    ///
+    /// All of the components are used, so an integer that needs more bits than the first
+    /// component has still comes out exactly. Like the other integer conversions, this
+    /// never rounds, and it's an error if the number has a fractional part, if it's out of
+    /// range, or if it isn't finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use std::convert::TryFrom;
+    ///
+    /// let x = qd!(2).powi(100) + qd!(1);
+    /// assert!(i128::try_from(x) == Ok((1 << 100) + 1));
+    /// assert!(i128::try_from(-x) == Ok(-(1 << 100) - 1));
+    /// assert!(i128::try_from(x / qd!(2)).is_err());
+    /// assert!(i128::try_from(Quad::NAN).is_err());
+    /// ```
+    i128 -TWO_127, TWO_127
+    /// Converts a `Quad` into a `u128`, if it's an integer that fits.
+    ///
+    /// All of the components are used, so an integer that needs more bits than the first
+    /// component has still comes out exactly. Like the other integer conversions, this
+    /// never rounds, and it's an error if the number has a fractional part, if it's
+    /// negative or too large, or if it isn't finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use std::convert::TryFrom;
+    ///
+    /// let x = qd!(2).powi(127) + qd!(3);
+    /// assert!(u128::try_from(x) == Ok((1 << 127) + 3));
+    /// assert!(u128::try_from(qd!(2).powi(128)).is_err());
+    /// ```
+    u128 0.0, TWO_128
+}
+
+impl Quad {
+    /// Converts the `Quad` into an `f64`, rounding in the direction given by `mode`.
+    ///
+    /// All of the components are taken into account, so with [`TowardPosInf`], for
+    /// example, the result is the smallest `f64` that's no smaller than the `Quad`, and
+    /// rounding to nearest is correct even in the rare case where the first component
+    /// isn't the nearest `f64`. A `Quad` too large for an `f64` rounds to infinity or to
+    /// `f64::MAX` as it would in IEEE arithmetic.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{Quad, RoundingMode};
+    /// let third = Quad::ONE / Quad::from(3);
+    /// let down = third.to_f64_round(RoundingMode::TowardNegInf);
+    /// let up = third.to_f64_round(RoundingMode::TowardPosInf);
+    /// assert!(down < up);
+    /// assert!(Quad::from(down) < third && third < Quad::from(up));
+    ///
+    /// // Exactly halfway between 1 and the next f64, and then a little more
+    /// let x = Quad::new(1.0, 2f64.powi(-53), 1e-50, 0.0);
+    /// assert!(x.to_f64_round(RoundingMode::Nearest) == 1.0 + f64::EPSILON);
+    /// ```
+    ///
+    /// [`TowardPosInf`]: enum.RoundingMode.html#variant.TowardPosInf
+    #[inline]
+    pub fn to_f64_round(self, mode: RoundingMode) -> f64 {
+        r::to_f64(&[self.0, self.1, self.2, self.3], mode)
+    }
+
+    /// Converts the `Quad` into an `f32`, rounding in the direction given by `mode`.
+    ///
+    /// The whole `Quad` is rounded at once, so rounding to nearest gives the `f32` nearest
+    /// to the `Quad`, which isn't always the one nearest to its first component. A `Quad`
+    /// too large for an `f32` rounds to infinity or to `f32::MAX` as it would in IEEE
+    /// arithmetic.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{Quad, RoundingMode};
+    /// // Just below the point halfway between 1 and the next f32. Its first component is
+    /// // exactly on that point and would round up to the even one.
+    /// let x = Quad::new(1.0 + 3.0 * 2f64.powi(-24), -1e-30, 0.0, 0.0);
+    /// assert!(x.to_f32_round(RoundingMode::Nearest) == 1.0 + f32::EPSILON);
+    /// assert!((x[0] as f32) == 1.0 + 2.0 * f32::EPSILON);
+    ///
+    /// assert!(Quad::PI.to_f32_round(RoundingMode::Nearest) == std::f32::consts::PI);
+    /// ```
+    #[inline]
+    pub fn to_f32_round(self, mode: RoundingMode) -> f32 {
+        r::to_f32(&[self.0, self.1, self.2, self.3], mode)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::random::Rng;
 
     // f32 tests
     test_all_exact!(
@@ -652,4 +930,297 @@ mod tests {
         i128_min: i128::MIN.to_string(), qd!(i128::MIN).to_string();
         u128_max: u128::MAX.to_string(), qd!(u128::MAX).to_string();
     );
+
+    // Double tests
+    test_all_assert!(
+        double_exact:
+            Quad::from(Double::PI).to_array() == [Double::PI[0], Double::PI[1], 0.0, 0.0];
+        double_third:
+            Quad::from(Double::ONE / Double::from(3)) * qd!(3) != Quad::ONE;
+        double_inf:
+            Quad::from(Double::INFINITY) == Quad::INFINITY
+                && Quad::from(Double::NEG_INFINITY) == Quad::NEG_INFINITY;
+        double_nan:
+            Quad::from(Double::NAN).is_nan();
+        double_neg_zero:
+            Quad::from(Double::NEG_ZERO)[0].is_sign_negative();
+    );
+
+    // literal tests
+    //
+    // These call the function that `qd!` uses for literals directly, since the macro would
+    // do it at compile time and so could only be tried with literals written out here.
+    fn same(a: Quad, b: Quad) -> bool {
+        a.to_bits() == b.to_bits()
+    }
+
+    test!(literal_const: {
+        const TAU_OVER_7: Quad = qd!("0.8975979010256552108570956133638446188617134582222880564845302563");
+        static HALF: Quad = qd!(0.5);
+        static TENTH: Quad = qd!(-0.1e-2_f64);
+        static BIG: Quad = qd!(12_345_678_901_234_567_890u64);
+        static TAUS: [Quad; 2] = [TAU_OVER_7, qd!(r"0.8975979010256552108570956133638446188617134582222880564845302563")];
+
+        assert!(same(TAUS[0], "0.8975979010256552108570956133638446188617134582222880564845302563".parse().unwrap()));
+        assert!(same(TAUS[1], TAUS[0]));
+        assert!(same(HALF, Quad::from(0.5)));
+        assert!(same(TENTH, Quad::from("-0.001")));
+        assert!(same(BIG, Quad::from(12_345_678_901_234_567_890u64)));
+    });
+
+    test!(literal_str: {
+        assert!(same(Quad::__from_literal("\"1.5e-3\"").unwrap(), Quad::from("1.5e-3")));
+        assert!(Quad::__from_literal("\" -inf \"").unwrap() == Quad::NEG_INFINITY);
+        assert!(Quad::__from_literal("\"1.5.3\"").unwrap().is_nan());
+        assert!(Quad::__from_literal("r#\"\"\"#").unwrap().is_nan());
+        assert!(Quad::__from_literal("\"\\u{31}\"").is_err());
+        assert!(Quad::__from_literal("b\"1\"").is_err());
+        assert!(Quad::__from_literal("true").is_err());
+    });
+
+    test!(literal_int: {
+        let lit = |s| Quad::__from_literal(s).unwrap();
+        assert!(same(lit("5"), Quad::from(5)));
+        assert!(same(lit("-5"), Quad::from(-5)));
+        assert!(same(lit("-0"), Quad::from(0)));
+        assert!(same(lit("- 5i64"), Quad::from(-5i64)));
+        assert!(same(lit("0x_ff_u8"), Quad::from(255u8)));
+        assert!(same(lit("-0b1000_0000i8"), Quad::from(-128i8)));
+        assert!(same(lit("4294967296"), Quad::from(4_294_967_296u64)));
+        assert!(same(lit("-9223372036854775808i64"), Quad::from(i64::MIN)));
+        assert!(same(lit("18446744073709551615"), Quad::from(u64::MAX)));
+        assert!(same(lit("18446744073709551616"), Quad::from(18_446_744_073_709_551_616u128)));
+        assert!(same(lit("-1i128"), Quad::from(-1i128)));
+        assert!(same(lit("-170141183460469231731687303715884105728"), Quad::from(i128::MIN)));
+        assert!(Quad::__from_literal("-170141183460469231731687303715884105729").is_err());
+        assert!(Quad::__from_literal("256u8").is_err());
+        assert!(Quad::__from_literal("-1u32").is_err());
+        assert!(Quad::__from_literal("1usize").is_err());
+    });
+
+    test!(literal_float: {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..20_000 {
+            let len = 1 + (rng.next_u64() % 18) as usize;
+            let digits: String = (0..len)
+                .map(|_| (b'0' + (rng.next_u64() % 10) as u8) as char)
+                .collect();
+            let point = (rng.next_u64() % len as u64) as usize + 1;
+            let mut text = format!("{}.{}", &digits[..point], &digits[point..]);
+            if point == len {
+                text.push('0');
+            }
+            let mut exp = 0;
+            if rng.next_u64() % 2 == 0 {
+                exp = rng.range(-330, 310);
+                text.push_str(&format!("e{}", exp));
+            }
+            if rng.next_u64() % 4 == 0 {
+                text.insert(0, '-');
+            }
+            let single = rng.next_u64() % 10 == 0;
+
+            let significant = digits.trim_start_matches('0').trim_end_matches('0').len();
+            // The literal's digits are parsed without their leading and trailing zeros
+            let trimmed = digits.trim_end_matches('0');
+            let exp = exp + point as i32 - trimmed.len() as i32;
+            let trimmed = match trimmed.trim_start_matches('0') {
+                "" => "0",
+                t => t,
+            };
+            let sign = if text.starts_with('-') { "-" } else { "" };
+            let expected: Quad = format!("{}{}e{}", sign, trimmed, exp).parse().unwrap();
+            let (infinite, tiny, held) = if single {
+                let x: f32 = text.parse().unwrap();
+                (x.is_infinite(), x.abs() < f32::MIN_POSITIVE, 6)
+            } else {
+                let x: f64 = text.parse().unwrap();
+                (x.is_infinite(), x.abs() < f64::MIN_POSITIVE, 15)
+            };
+            if single {
+                text.push_str("f32");
+            }
+            // Parsing can leave a negative zero below the first component, where an exact
+            // conversion doesn't
+            match Quad::__from_literal(&text) {
+                Ok(x) => assert!(
+                    x == expected && x.is_sign_negative() == expected.is_sign_negative(),
+                    "{}",
+                    text
+                ),
+                Err(LiteralError::Lossy) => assert!(significant > held || tiny, "{}", text),
+                Err(LiteralError::Invalid(_)) => assert!(infinite, "{}", text),
+            }
+            if significant <= held && !tiny && !infinite {
+                assert!(Quad::__from_literal(&text).is_ok(), "{}", text);
+            }
+        }
+    });
+
+    test!(literal_whole: {
+        let lit = |s| Quad::__from_literal(s).unwrap();
+        // The f64s nearest these aren't the numbers that were written
+        assert!(same(lit("1e23"), qd!("1e23")));
+        assert!(same(lit("3e200"), qd!("3e200")));
+        assert!(same(lit("-9.5e40"), qd!("-9.5e40")));
+        assert!(same(lit("12345e300f64"), qd!("1.2345e304")));
+        assert!(same(lit("1e20f32"), qd!("1e20")));
+        assert!(lit("1e23") != 1e23);
+        // These ones are
+        assert!(same(lit("1e22"), Quad::from(1e22)));
+        assert!(same(lit("9007199254740992.0"), Quad::from(9007199254740992.0)));
+    });
+
+    test!(literal_subnormal: {
+        let lit = |s| Quad::__from_literal(s).unwrap();
+        // One significant digit is all that the smallest subnormals have room for, and it's
+        // enough, since it's what printing them gives
+        assert!(same(lit("5e-324"), Quad::from(5e-324)));
+        assert!(same(lit("1e-323"), Quad::from(1e-323)));
+        // An f32 subnormal is a normal f64, so its digits are parsed like any others
+        assert!(same(lit("1e-45f32"), qd!("1e-45")));
+        assert!(Quad::__from_literal("4.9e-324") == Err(LiteralError::Lossy));
+    });
+
+    test!(literal_lossy: {
+        let lossy = |s| Quad::__from_literal(s) == Err(LiteralError::Lossy);
+        assert!(lossy("0.1234567890123456"));
+        assert!(lossy("3.14159265358979323846"));
+        assert!(lossy("1.1234567f32"));
+        assert!(lossy("1e-400"));
+        // Exactly equal to the f64, so nothing is lost
+        assert!(!lossy("1.0005645751953125"));
+        assert!(!lossy("1_234_567_890_123_456.0"));
+        assert!(!lossy("123_456_789_012_345.5"));
+        assert!(!lossy("1.203125f32"));
+    });
+
+    // rounding mode tests
+    test_all_assert!(
+        f64_round_tie_even:
+            Quad::new(1.0, 2f64.powi(-53), 0.0, 0.0).to_f64_round(RoundingMode::Nearest)
+                == 1.0;
+        f64_round_tie_up:
+            Quad::new(1.0, 2f64.powi(-53), 2f64.powi(-120), 0.0)
+                .to_f64_round(RoundingMode::Nearest)
+                == 1.0 + f64::EPSILON
+                && Quad::new(1.0, 2f64.powi(-53), 0.0, 1e-70).to_f64_round(RoundingMode::Nearest)
+                    == 1.0 + f64::EPSILON;
+        f64_round_tie_down:
+            Quad::new(1.0, 2f64.powi(-53), -2f64.powi(-120), 0.0)
+                .to_f64_round(RoundingMode::Nearest)
+                == 1.0;
+        f64_round_last_tail:
+            Quad::new(1.0, 0.0, 0.0, -1e-60).to_f64_round(RoundingMode::TowardZero)
+                == 1.0 - f64::EPSILON / 2.0
+                && Quad::new(1.0, 0.0, 0.0, -1e-60).to_f64_round(RoundingMode::TowardPosInf)
+                    == 1.0
+                && Quad::new(1.0, 0.0, 0.0, -1e-60).to_f64_round(RoundingMode::TowardNegInf)
+                    == 1.0 - f64::EPSILON / 2.0;
+        f64_round_third:
+            (Quad::ONE / qd!(3)).to_f64_round(RoundingMode::TowardNegInf) == 1.0 / 3.0
+                && Quad::from((Quad::ONE / qd!(3)).to_f64_round(RoundingMode::TowardNegInf))
+                    < Quad::ONE / qd!(3)
+                && Quad::from((Quad::ONE / qd!(3)).to_f64_round(RoundingMode::TowardPosInf))
+                    > Quad::ONE / qd!(3);
+        f64_round_max:
+            Quad::MAX.to_f64_round(RoundingMode::TowardPosInf) == f64::INFINITY
+                && Quad::MAX.to_f64_round(RoundingMode::TowardZero) == f64::MAX;
+        f64_round_nan:
+            Quad::NAN.to_f64_round(RoundingMode::Nearest).is_nan();
+        f32_round_pi:
+            Quad::PI.to_f32_round(RoundingMode::Nearest) == std::f32::consts::PI
+                && Quad::PI.to_f32_round(RoundingMode::TowardZero)
+                    == f32::from_bits(std::f32::consts::PI.to_bits() - 1);
+        f32_round_twice:
+            Quad::new(1.0 + 2f64.powi(-24), 0.0, 0.0, 1e-60).to_f32_round(RoundingMode::Nearest)
+                == 1.0 + f32::EPSILON
+                && Quad::new(1.0 + 2f64.powi(-24), 0.0, 0.0, -1e-60)
+                    .to_f32_round(RoundingMode::Nearest)
+                    == 1.0;
+    );
+
+    // integer conversion tests
+    fn int_error<T: std::fmt::Debug>(r: Result<T, ToIntError>) -> ToIntErrorKind {
+        r.unwrap_err().kind
+    }
+
+    test_all_assert!(
+        try_from_i64_bounds:
+            i64::try_from(Quad::from(i64::MAX)) == Ok(i64::MAX)
+                && i64::try_from(Quad::from(i64::MIN)) == Ok(i64::MIN);
+        try_from_u64_bounds:
+            u64::try_from(Quad::from(u64::MAX)) == Ok(u64::MAX)
+                && u64::try_from(Quad::ZERO) == Ok(0);
+        try_from_i128_bounds:
+            i128::try_from(Quad::from(i128::MAX)) == Ok(i128::MAX)
+                && i128::try_from(Quad::from(i128::MIN)) == Ok(i128::MIN);
+        try_from_u128_bounds:
+            u128::try_from(Quad::from(u128::MAX)) == Ok(u128::MAX)
+                && u128::try_from(Quad::ZERO) == Ok(0);
+        try_from_neg_zero:
+            u128::try_from(Quad::NEG_ZERO) == Ok(0) && i64::try_from(Quad::NEG_ZERO) == Ok(0);
+        try_from_i64_out:
+            int_error(i64::try_from(Quad::from(i64::MAX) + Quad::ONE)) == ToIntErrorKind::OutOfRange
+                && int_error(i64::try_from(Quad::from(i64::MIN) - Quad::ONE))
+                    == ToIntErrorKind::OutOfRange;
+        try_from_u64_out:
+            int_error(u64::try_from(Quad::from(u64::MAX) + Quad::ONE)) == ToIntErrorKind::OutOfRange
+                && int_error(u64::try_from(Quad::NEG_ONE)) == ToIntErrorKind::OutOfRange;
+        try_from_i128_out:
+            int_error(i128::try_from(Quad::from(i128::MAX) + Quad::ONE))
+                == ToIntErrorKind::OutOfRange
+                && int_error(i128::try_from(Quad::from(i128::MIN) - Quad::ONE))
+                    == ToIntErrorKind::OutOfRange;
+        try_from_u128_out:
+            int_error(u128::try_from(Quad::from(u128::MAX) + Quad::ONE))
+                == ToIntErrorKind::OutOfRange
+                && int_error(u128::try_from(Quad::NEG_ONE)) == ToIntErrorKind::OutOfRange;
+        try_from_huge:
+            int_error(i128::try_from(qd!(2).powi(200) + qd!(1))) == ToIntErrorKind::OutOfRange
+                && int_error(u64::try_from(Quad::MAX)) == ToIntErrorKind::OutOfRange;
+        try_from_fraction:
+            int_error(i64::try_from(qd!(2.5))) == ToIntErrorKind::NotInteger
+                && int_error(u128::try_from(qd!(0.5))) == ToIntErrorKind::NotInteger;
+        try_from_small_fraction:
+            int_error(i128::try_from(Quad(2f64.powi(100), 1.0, 2f64.powi(-60), 0.0)))
+                == ToIntErrorKind::NotInteger;
+        try_from_nan:
+            int_error(i128::try_from(Quad::NAN)) == ToIntErrorKind::NotFinite;
+        try_from_inf:
+            int_error(u64::try_from(Quad::INFINITY)) == ToIntErrorKind::NotFinite
+                && int_error(i64::try_from(Quad::NEG_INFINITY)) == ToIntErrorKind::NotFinite;
+    );
+
+    // Converting a 128-bit integer to a `Quad` and back never loses anything
+    test!(try_from_int_round_trip: {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        for _ in 0..10_000 {
+            let bits = (rng.next_u64() as u128) << 64 | rng.next_u64() as u128;
+            let n = bits >> (rng.next_u64() % 128);
+            assert_eq!(u128::try_from(Quad::from(n)), Ok(n));
+            let n = bits as i128 >> (rng.next_u64() % 128);
+            assert_eq!(i128::try_from(Quad::from(n)), Ok(n));
+            assert_eq!(i64::try_from(Quad::from(n as i64)), Ok(n as i64));
+            assert_eq!(u64::try_from(Quad::from(n as u64)), Ok(n as u64));
+        }
+    });
+
+    // Integers built out of shifted pieces, so that their bits are spread over several
+    // components, some of them negative
+    test!(try_from_shifted_sums: {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..10_000 {
+            let (a, b, c) = (rng.next_u64() >> 11, rng.next_u64() >> 11, rng.next_u64() >> 11);
+            let (sa, sb) = ((rng.next_u64() % 74) as i32, (rng.next_u64() % 40) as i32);
+            let exact = ((a as i128) << sa) - ((b as i128) << sb) + c as i128;
+            let x = Quad::from(a as f64).ldexp(sa) - Quad::from(b as f64).ldexp(sb)
+                + Quad::from(c as f64);
+            assert_eq!(i128::try_from(x), Ok(exact));
+            assert_eq!(i128::try_from(-x), Ok(-exact));
+            assert_eq!(u128::try_from(x.abs()), Ok(exact.unsigned_abs()));
+            let half = x + qd!(0.5);
+            assert_eq!(int_error(i128::try_from(half)), ToIntErrorKind::NotInteger);
+        }
+    });
 }
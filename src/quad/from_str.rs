@@ -3,12 +3,14 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::repr;
+use crate::common::utils as u;
 use crate::error::{ErrorKind, ParseQuadError};
+use crate::quad::common as c;
+use crate::quad::from::from_u64;
 use crate::quad::Quad;
 use std::str::FromStr;
 
-const TEN: Quad = Quad(10.0, 0.0, 0.0, 0.0);
-
 impl FromStr for Quad {
     type Err = ParseQuadError;
 
@@ -20,7 +22,24 @@ impl FromStr for Quad {
     /// wrong places; two decimal points or a negative sign after the number will both be
     /// rejected, for instance.
     ///
-    /// Failure will return a [`ParseQuadError`] of some kind.
+    /// More precisely, the string must consist of an optional sign, one or more digits
+    /// with at most one decimal point among them, and an optional exponent. The exponent
+    /// is an `e` or `E` followed by an optional sign and at least one digit. Underscores
+    /// are ignored anywhere after the sign (including in the exponent), so that digits
+    /// can be grouped as they are in Rust literals, and leading and trailing whitespace is
+    /// trimmed. The words `inf`, `infinity`, and `nan`, with or without a sign and in any
+    /// case, are also accepted.
+    ///
+    /// Numbers too large for a `Quad` parse as infinity, and numbers too small parse as
+    /// zero, just as they do for `f64`.
+    ///
+    /// Failure will return a [`ParseQuadError`]. Its kind will be:
+    ///
+    /// * [`Empty`] if the string is empty or contains only whitespace;
+    /// * [`InvalidDigit`] if there is a character that can't appear where it does, along
+    ///   with the byte offset of that character in `s`;
+    /// * [`ExponentOverflow`] if the exponent doesn't fit into an `i32`;
+    /// * [`Invalid`] if digits are missing, as in `"-"` or `"1e"`.
     ///
     /// # Examples
     /// ```
@@ -48,120 +67,464 @@ impl FromStr for Quad {
     /// ```
     ///
     /// [`ParseQuadError`]: error/struct.ParseQuadError.html
+    /// [`Empty`]: error/enum.ErrorKind.html#variant.Empty
+    /// [`InvalidDigit`]: error/enum.ErrorKind.html#variant.InvalidDigit
+    /// [`ExponentOverflow`]: error/enum.ErrorKind.html#variant.ExponentOverflow
+    /// [`Invalid`]: error/enum.ErrorKind.html#variant.Invalid
     fn from_str(s: &str) -> Result<Quad, ParseQuadError> {
-        let mut result = Quad::ZERO;
+        Quad::from_str_const(s)
+    }
+}
+
+impl Quad {
+    /// Parses a string to create a `Quad`, in a way that can be done at compile time.
+    ///
+    /// This is the same parser as [`from_str`], with the same results and the same
+    /// errors. The difference is that this is a `const fn`, so it can be used to define
+    /// constants:
+    ///
+    /// ```
+    /// # use qd::Quad;
+    /// const TAU_OVER_7: Quad = match Quad::from_str_const("0.8975979010256551") {
+    ///     Ok(x) => x,
+    ///     Err(_) => panic!("not a number"),
+    /// };
+    /// assert!(TAU_OVER_7 == "0.8975979010256551".parse::<Quad>().unwrap());
+    /// ```
+    ///
+    /// The [`qd!`] macro does this itself when it's given a literal, so
+    /// `qd!("0.8975979010256551")` is a constant expression too.
+    ///
+    /// [`from_str`]: #method.from_str
+    /// [`qd!`]: macro.qd.html
+    pub const fn from_str_const(s: &str) -> Result<Quad, ParseQuadError> {
+        let mut acc = Digits::new();
         let mut digits = 0;
         let mut point = -1;
         let mut sign = 0;
         let mut exp = 0;
 
-        let s = s.trim().to_ascii_lowercase();
-
-        match pre_from_str(&s) {
-            Some(r) => r,
-            None => {
-                for (index, ch) in s.chars().enumerate() {
-                    match ch.to_digit(10) {
-                        Some(d) => {
-                            result *= TEN;
-                            result += Quad(d as f64, 0.0, 0.0, 0.0);
-                            digits += 1;
-                        }
-                        None => match ch {
-                            '.' => {
-                                if point >= 0 {
-                                    return Err(ParseQuadError {
-                                        kind: ErrorKind::Invalid,
-                                    });
-                                }
-                                point = digits;
-                            }
-                            '-' => {
-                                if sign != 0 || digits > 0 {
-                                    return Err(ParseQuadError {
-                                        kind: ErrorKind::Invalid,
-                                    });
-                                }
-                                sign = -1;
-                            }
-                            '+' => {
-                                if sign != 0 || digits > 0 {
-                                    return Err(ParseQuadError {
-                                        kind: ErrorKind::Invalid,
-                                    });
-                                }
-                                sign = 1;
-                            }
-                            'e' => {
-                                let end = &s[(index + 1)..];
-                                match end.parse::<i32>() {
-                                    Ok(e) => {
-                                        exp = e;
-                                        break;
-                                    }
-                                    Err(_) => {
-                                        return Err(ParseQuadError {
-                                            kind: ErrorKind::Invalid,
-                                        });
-                                    }
-                                }
-                            }
-                            '_' => {
-                                // just continue; _ is a no-op but not an error
-                            }
-                            _ => {
-                                return Err(ParseQuadError {
-                                    kind: ErrorKind::Invalid,
-                                });
-                            }
-                        },
-                    }
-                }
+        // Error positions are offsets into the string as it was passed in, so the indexes
+        // are into the whole string, just kept between the trimmed ends
+        let (start, end) = u::trim(s);
+        let bytes = s.as_bytes();
 
-                if point >= 0 {
-                    exp -= digits - point;
+        if let Some(r) = pre_from_str(s, start, end) {
+            return r;
+        }
+        let mut index = start;
+        while index < end {
+            match bytes[index] {
+                d @ b'0'..=b'9' => {
+                    acc.push((d - b'0') as u32);
+                    digits += 1;
                 }
-                if exp != 0 {
-                    // Do this in two stages if the exponent is too small For exmaple, a
-                    // number with 30 digits could have an exponent as low as -337 and still
-                    // not overflow, but doing the -337 all at once WOULD overflow
-                    if exp < -307 {
-                        let adjust = exp + 307;
-                        result *= TEN.powi(adjust);
-                        exp -= adjust;
-                    }
-                    result *= TEN.powi(exp);
+                b'.' if point < 0 => point = digits,
+                // A sign is only legal as the very first character; this also rejects
+                // things like "--3", "+-2", and ".-5"
+                b'-' if index == start => sign = -1,
+                b'+' if index == start => sign = 1,
+                // The exponent has to follow at least one digit
+                b'e' | b'E' if digits > 0 => {
+                    exp = match parse_exp(bytes, index + 1, end) {
+                        Ok(e) => e,
+                        Err(e) => return Err(e),
+                    };
+                    break;
                 }
-                if sign == -1 {
-                    result = -result;
+                b'_' => {
+                    // just continue; _ is a no-op but not an error
                 }
-
-                Ok(result)
+                _ => return Err(invalid_digit(index)),
             }
+            index += 1;
+        }
+
+        // There has to be at least one digit in the mantissa. Without this check, strings
+        // like "-", ".", and "_" would parse as zero.
+        if digits == 0 {
+            return Err(ParseQuadError {
+                kind: ErrorKind::Invalid,
+            });
         }
+
+        if point >= 0 {
+            exp = exp.saturating_sub(digits - point);
+        }
+        Ok(acc.finish(exp, sign == -1))
     }
 }
 
+// Handles the empty string and the words that name special values. `start` and `end` are
+// the bounds of the string with whitespace trimmed off.
 #[inline]
-fn pre_from_str(s: &str) -> Option<Result<Quad, ParseQuadError>> {
-    if s.is_empty() {
-        Some(Err(ParseQuadError {
+const fn pre_from_str(s: &str, start: usize, end: usize) -> Option<Result<Quad, ParseQuadError>> {
+    if start == end {
+        return Some(Err(ParseQuadError {
             kind: ErrorKind::Empty,
-        }))
-    } else if s == "nan" {
-        Some(Ok(Quad::NAN))
-    } else if s == "inf" || s == "infinity" {
-        Some(Ok(Quad::INFINITY))
-    } else if s == "-inf" || s == "-infinity" {
-        Some(Ok(Quad::NEG_INFINITY))
+        }));
+    }
+    let (negative, from) = match s.as_bytes()[start] {
+        b'-' => (true, start + 1),
+        b'+' => (false, start + 1),
+        _ => (false, start),
+    };
+    let (_, word) = s.split_at(from);
+    let (word, _) = word.split_at(end - from);
+    let value = if word.eq_ignore_ascii_case("nan") {
+        Quad::NAN
+    } else if word.eq_ignore_ascii_case("inf") || word.eq_ignore_ascii_case("infinity") {
+        Quad::INFINITY
+    } else {
+        return None;
+    };
+    Some(Ok(if negative {
+        Quad(-value.0, -value.1, -value.2, -value.3)
+    } else {
+        value
+    }))
+}
+
+// Parses the exponent, which runs from byte `start` of `bytes` (just past the `e`) to byte
+// `end`. The exponent is read in full before an overflow is reported, so that a bad
+// character after too many digits is still reported as a bad character.
+const fn parse_exp(bytes: &[u8], start: usize, end: usize) -> Result<i32, ParseQuadError> {
+    let mut exp: i32 = 0;
+    let mut negative = false;
+    let mut digits = 0;
+    let mut overflow = false;
+
+    let mut index = start;
+    while index < end {
+        match bytes[index] {
+            d @ b'0'..=b'9' => {
+                digits += 1;
+                // Accumulating negative numbers lets i32::MIN through
+                let d = if negative {
+                    -((d - b'0') as i32)
+                } else {
+                    (d - b'0') as i32
+                };
+                match exp.checked_mul(10) {
+                    Some(e) => match e.checked_add(d) {
+                        Some(e) => exp = e,
+                        None => overflow = true,
+                    },
+                    None => overflow = true,
+                }
+            }
+            b'-' if index == start => negative = true,
+            b'+' if index == start => {}
+            b'_' => {}
+            _ => return Err(invalid_digit(index)),
+        }
+        index += 1;
+    }
+
+    if digits == 0 {
+        Err(ParseQuadError {
+            kind: ErrorKind::Invalid,
+        })
+    } else if overflow {
+        Err(ParseQuadError {
+            kind: ErrorKind::ExponentOverflow,
+        })
     } else {
-        None
+        Ok(exp)
+    }
+}
+
+#[inline]
+const fn invalid_digit(position: usize) -> ParseQuadError {
+    ParseQuadError {
+        kind: ErrorKind::InvalidDigit { position },
+    }
+}
+
+impl Quad {
+    /// Parses the output of [`Debug`] back into the `Quad` that was formatted.
+    ///
+    /// The string must be `Quad` followed by the 4 components in parentheses, separated by
+    /// commas, as `{:?}` writes them. Whitespace (including line breaks) is allowed around
+    /// each piece, so the multi-line output of `{:#?}` parses as well, and so does a comma
+    /// after the last component. Each component can be anything that parses as an `f64`,
+    /// including `inf`, `-inf`, and `NaN`.
+    ///
+    /// `Debug` writes each component in the shortest form that parses back to the same
+    /// `f64`, so this reproduces a formatted `Quad` bit for bit, down to the sign of a zero
+    /// and any subnormal components. The one exception is NaN, whose sign `Debug` doesn't
+    /// write; it comes back as [`NAN`]. Components that weren't written by `Debug` don't
+    /// have to be normalized. If they aren't, they're normalized in the same way as by
+    /// [`from_components`], so the result is the `Quad` nearest to their sum.
+    ///
+    /// Failure will return a [`ParseQuadError`]. Its kind will be:
+    ///
+    /// * [`Empty`] if the string is empty or contains only whitespace;
+    /// * [`InvalidDigit`] if there is a character that can't appear where it does, along
+    ///   with its byte offset in `s`, or if a component isn't an `f64`, along with the
+    ///   offset of the component's first character;
+    /// * [`Invalid`] if the string ends before all of the components and the closing
+    ///   parenthesis have been read.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::from_repr(concat!(
+    ///     "Quad(3.141592653589793e0, 1.2246467991473532e-16, ",
+    ///     "-2.9947698097183397e-33, 1.1124542208633653e-49)"
+    /// ))
+    /// .unwrap();
+    /// assert!(x == Quad::PI);
+    ///
+    /// let y = qd!(1) / qd!(3);
+    /// assert!(Quad::from_repr(&format!("{:?}", y)).unwrap() == y);
+    /// assert!(Quad::from_repr(&format!("{:#?}", -y)).unwrap() == -y);
+    ///
+    /// assert!(Quad::from_repr("Quad(1e0)").is_err());
+    /// ```
+    ///
+    /// [`Debug`]: #impl-Debug
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`from_components`]: #method.from_components
+    /// [`ParseQuadError`]: error/struct.ParseQuadError.html
+    /// [`Empty`]: error/enum.ErrorKind.html#variant.Empty
+    /// [`InvalidDigit`]: error/enum.ErrorKind.html#variant.InvalidDigit
+    /// [`Invalid`]: error/enum.ErrorKind.html#variant.Invalid
+    pub fn from_repr(s: &str) -> Result<Quad, ParseQuadError> {
+        let [a, b, c, d] = repr::parse(s, "Quad").map_err(|kind| ParseQuadError { kind })?;
+        // Components that are already normalized are kept as they are, even where
+        // normalizing would pick other ones with the same sum
+        let x = Quad(a, b, c, d);
+        Ok(if x.is_normalized() {
+            x
+        } else {
+            Quad::from_components(a, b, c, d)
+        })
+    }
+
+    /// Creates a `Quad` from an integer part, the digits of a fractional part, and a
+    /// power-of-ten exponent.
+    ///
+    /// The result is the number that would be produced by parsing the string made by
+    /// gluing the parts together, i.e., `Quad::from_parts(-3, "14159", 2)` produces
+    /// exactly the same `Quad` as parsing `"-3.14159e2"`. This is guaranteed rather than
+    /// approximated: the digits of both parts go through the same code that [`from_str`]
+    /// uses, in the same order, so they're rounded in exactly the same way. In particular
+    /// the integer and fractional parts are never rounded separately and then added, which
+    /// could round a second time. No string is built along the way and nothing is
+    /// allocated.
+    ///
+    /// The parts are interpreted as follows:
+    ///
+    /// * The sign of the result is the sign of `int_part`, and the fraction adds to the
+    ///   magnitude of the integer part, moving the result *away* from zero. This is the
+    ///   same as the way that `"-3.14"` is read: `from_parts(-3, "14", 0)` is -3.14, not
+    ///   -2.86. Since an `i64` zero has no sign, a number between -1 and 0 has to be made
+    ///   by negating the result of `from_parts(0, ...)`.
+    /// * `fraction_digits` must contain only the ASCII digits `0` through `9`, plus any
+    ///   number of underscores, which are ignored just as they are by [`from_str`]. It may
+    ///   be empty, in which case the result is just the integer part (scaled by the
+    ///   exponent). It may also be very long; digits beyond the precision of a `Quad`
+    ///   still take part in rounding.
+    /// * `exponent10` scales the *whole* number, integer and fraction together, just as
+    ///   the exponent does in `"3.14e2"`. It is applied last.
+    ///
+    /// Failure will return a [`ParseQuadError`]. Its kind will be [`InvalidDigit`] if
+    /// `fraction_digits` contains anything other than digits and underscores, with the
+    /// position of the first such character in `fraction_digits`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let frac = "1415926535897932384626433832795028841971693993751058209749445923";
+    /// let x = Quad::from_parts(3, frac, 0).unwrap();
+    /// assert!(x == qd!("3.1415926535897932384626433832795028841971693993751058209749445923"));
+    ///
+    /// let y = Quad::from_parts(-2, "5", -3).unwrap();
+    /// assert!(y == qd!("-0.0025"));
+    /// assert!(Quad::from_parts(0, "25", 0).unwrap() == qd!("0.25"));
+    /// assert!(Quad::from_parts(1, "2.5", 0).is_err());
+    /// ```
+    ///
+    /// [`from_str`]: #method.from_str
+    /// [`ParseQuadError`]: error/struct.ParseQuadError.html
+    /// [`InvalidDigit`]: error/enum.ErrorKind.html#variant.InvalidDigit
+    pub fn from_parts(
+        int_part: i64,
+        fraction_digits: &str,
+        exponent10: i32,
+    ) -> Result<Quad, ParseQuadError> {
+        let mut acc = Digits::new();
+
+        // The digits of the integer part, most significant first. An i64 has at most 19.
+        let mut buf = [0u8; 20];
+        let mut n = int_part.unsigned_abs();
+        let mut start = buf.len();
+        loop {
+            start -= 1;
+            buf[start] = (n % 10) as u8;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        for &d in &buf[start..] {
+            acc.push(d as u32);
+        }
+
+        let mut digits: i32 = 0;
+        for (index, b) in fraction_digits.bytes().enumerate() {
+            match b {
+                b'0'..=b'9' => {
+                    acc.push((b - b'0') as u32);
+                    digits = digits.saturating_add(1);
+                }
+                b'_' => {}
+                _ => return Err(invalid_digit(index)),
+            }
+        }
+
+        Ok(acc.finish(exponent10.saturating_sub(digits), int_part < 0))
+    }
+}
+
+// Parses the decimal digits of an integer, scaled by `10^exp`, exactly as the string of
+// those digits with that exponent would be parsed.
+pub(super) const fn parse_decimal(digits: u128, exp: i32, negative: bool) -> Quad {
+    // A u128 has at most 39 digits
+    let mut buf = [0u8; 39];
+    let mut n = digits;
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    let mut acc = Digits::new();
+    while start < buf.len() {
+        acc.push(buf[start] as u32);
+        start += 1;
+    }
+    acc.finish(exp, negative)
+}
+
+// The number of significant digits that are accumulated into a number while parsing. This
+// is several more than a `Quad` can hold, so that rounding happens on digits past the
+// end of its precision. Any digits beyond this are only tracked as being zero or not.
+const MAX_DIGITS: i32 = 72;
+
+// The number of digits in a chunk. This is the most that a `u64` always has room for.
+const CHUNK_DIGITS: usize = 19;
+
+// Turns a sequence of decimal digits into a `Quad`. This is the one place in parsing
+// where digits become a number; both `from_str` and `from_parts` push their digits through
+// it, which is what guarantees that they agree with each other.
+//
+// Digits are gathered 19 at a time into a `u64`, which holds any 19 digits exactly.
+// Each full chunk is then folded into the running value with one multiplication by
+// 10^19 and one addition, rather than with a multiplication by 10 and an addition for
+// every digit. 10^19 is exact as an `f64`, so is every chunk as a `Quad`, and so the
+// value is exact for as long as it fits into a `Quad` at all; after that it takes one
+// rounding per chunk instead of one per digit.
+//
+// Leading zeros are not significant and are not counted toward `MAX_DIGITS`. Once that
+// many significant digits have been accumulated, the rest are dropped, but their count is
+// kept (so that the exponent still comes out right) along with a sticky flag that records
+// whether any of them were non-zero. That flag is turned into a single trailing `1` digit
+// when the number is finished, which is enough to break what would otherwise look like an
+// exact tie in the retained digits. Without the limit, the accumulated value would
+// overflow to infinity after a few hundred digits.
+struct Digits {
+    value: Quad,
+    chunk: u64,
+    chunk_len: usize,
+    significant: i32,
+    dropped: i32,
+    sticky: bool,
+}
+
+impl Digits {
+    const fn new() -> Digits {
+        Digits {
+            value: Quad::ZERO,
+            chunk: 0,
+            chunk_len: 0,
+            significant: 0,
+            dropped: 0,
+            sticky: false,
+        }
+    }
+
+    // Adds a digit to the end of the number.
+    #[inline]
+    const fn push(&mut self, d: u32) {
+        if self.significant < MAX_DIGITS {
+            if self.significant > 0 || d != 0 {
+                self.append(d);
+                self.significant += 1;
+            }
+        } else {
+            self.dropped = self.dropped.saturating_add(1);
+            self.sticky = self.sticky || d != 0;
+        }
+    }
+
+    // Adds a digit to the current chunk, folding the chunk into the value once it's full.
+    #[inline]
+    const fn append(&mut self, d: u32) {
+        self.chunk = self.chunk * 10 + d as u64;
+        self.chunk_len += 1;
+        if self.chunk_len == CHUNK_DIGITS {
+            self.flush();
+        }
+    }
+
+    // Folds whatever digits are in the current chunk into the value.
+    #[inline]
+    const fn flush(&mut self) {
+        if self.chunk_len > 0 {
+            self.value = self
+                .value
+                .mul_inner(c::POWERS_OF_TEN[self.chunk_len])
+                .add_inner(from_u64(self.chunk));
+            self.chunk = 0;
+            self.chunk_len = 0;
+        }
+    }
+
+    // Produces the final number, scaled by `10^exp` and negated if necessary. `exp` is the
+    // exponent that applies to the digits as pushed, without regard to any that were
+    // dropped.
+    const fn finish(self, exp: i32, negative: bool) -> Quad {
+        let mut digits = self;
+        let mut exp = exp.saturating_add(digits.dropped);
+        if digits.sticky {
+            digits.append(1);
+            exp -= 1;
+        }
+        digits.flush();
+
+        let mut result = digits.value;
+        // Zero stays zero no matter the exponent; scaling it by an infinite power of ten
+        // would make it NaN instead
+        if exp != 0 && !result.is_zero() {
+            result = c::mul_pow10(result, exp);
+        }
+        if negative {
+            result = Quad(-result.0, -result.1, -result.2, -result.3);
+        }
+        result
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::decimal::Decimal;
+    use crate::common::random::Rng;
 
     macro_rules! single {
         ($e:expr, $a:expr) => {
@@ -176,8 +539,12 @@ mod tests {
         s.parse().unwrap()
     }
 
+    fn parse_error(s: &str) -> ParseQuadError {
+        s.parse::<Quad>().unwrap_err()
+    }
+
     fn parse_err(s: &str) -> ErrorKind {
-        s.parse::<Quad>().unwrap_err().kind
+        parse_error(s).kind
     }
 
     // error tests
@@ -186,22 +553,311 @@ mod tests {
             ErrorKind::Empty,
             parse_err("");
         double_sign:
-            ErrorKind::Invalid,
+            ErrorKind::InvalidDigit { position: 1 },
             parse_err("++2317");
         double_point:
-            ErrorKind::Invalid,
+            ErrorKind::InvalidDigit { position: 4 },
             parse_err("2.31.7");
         mid_sign:
-            ErrorKind::Invalid,
+            ErrorKind::InvalidDigit { position: 1 },
             parse_err("2-317");
         end_letter:
-            ErrorKind::Invalid,
+            ErrorKind::InvalidDigit { position: 6 },
             parse_err("2.317err");
         mid_letter:
-            ErrorKind::Invalid,
+            ErrorKind::InvalidDigit { position: 3 },
             parse_err("2.3j7");
     );
 
+    // malformed input tests
+    //
+    // Every one of these is also rejected by `f64`'s parser. They're here because earlier
+    // versions of the parser either silently ignored the malformed part or produced a value
+    // (usually zero) out of a string with no digits in it.
+    test_all_eq!(
+        bad_dangling_exp:
+            ErrorKind::Invalid,
+            parse_err("1.2e");
+        bad_dangling_exp_sign:
+            ErrorKind::Invalid,
+            parse_err("1.2e-");
+        bad_double_neg:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("--3");
+        bad_plus_neg:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("+-2");
+        bad_neg_plus:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("-+2");
+        bad_adjacent_points:
+            ErrorKind::InvalidDigit { position: 2 },
+            parse_err("1..5");
+        bad_leading_points:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("..5");
+        bad_double_exp:
+            ErrorKind::InvalidDigit { position: 3 },
+            parse_err("3e5e2");
+        bad_point_in_exp:
+            ErrorKind::InvalidDigit { position: 3 },
+            parse_err("3e5.2");
+        bad_double_exp_sign:
+            ErrorKind::InvalidDigit { position: 3 },
+            parse_err("3e--2");
+        bad_sign_after_point:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err(".-5");
+        bad_sign_after_underscore:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("_-5");
+        bad_trailing_sign:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("5-");
+        bad_sign_only:
+            ErrorKind::Invalid,
+            parse_err("-");
+        bad_plus_only:
+            ErrorKind::Invalid,
+            parse_err("+");
+        bad_point_only:
+            ErrorKind::Invalid,
+            parse_err(".");
+        bad_sign_point:
+            ErrorKind::Invalid,
+            parse_err("-.");
+        bad_exp_only:
+            ErrorKind::InvalidDigit { position: 0 },
+            parse_err("e5");
+        bad_point_exp:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err(".e5");
+        bad_sign_exp:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("-e5");
+        bad_underscore_only:
+            ErrorKind::Invalid,
+            parse_err("_");
+        bad_inner_space:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("1 2");
+        bad_exp_space:
+            ErrorKind::InvalidDigit { position: 2 },
+            parse_err("1e 2");
+        bad_hex:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("0x1f");
+        bad_exp_letter:
+            ErrorKind::InvalidDigit { position: 3 },
+            parse_err("1e5f");
+        bad_comma:
+            ErrorKind::InvalidDigit { position: 1 },
+            parse_err("1,5");
+        bad_inf_suffix:
+            ErrorKind::InvalidDigit { position: 0 },
+            parse_err("infinite");
+        bad_nan_suffix:
+            ErrorKind::InvalidDigit { position: 0 },
+            parse_err("nana");
+    );
+
+    // error position tests
+    //
+    // Positions are byte offsets into the string as it was given, before any trimming.
+    test_all_eq!(
+        position_after_whitespace:
+            ErrorKind::InvalidDigit { position: 3 },
+            parse_err("  1x5");
+        position_in_exp:
+            ErrorKind::InvalidDigit { position: 4 },
+            parse_err("1e1_x");
+        position_after_multibyte:
+            ErrorKind::InvalidDigit { position: 3 },
+            parse_err("\u{a0}1x");
+        whitespace_only:
+            ErrorKind::Empty,
+            parse_err("  \t ");
+    );
+
+    // exponent overflow tests
+    test_all_eq!(
+        exp_overflow:
+            ErrorKind::ExponentOverflow,
+            parse_err("1e2147483648");
+        exp_overflow_neg:
+            ErrorKind::ExponentOverflow,
+            parse_err("1e-2147483649");
+        exp_overflow_long:
+            ErrorKind::ExponentOverflow,
+            parse_err("1e99_999_999_999_999_999_999");
+        exp_overflow_zero:
+            ErrorKind::ExponentOverflow,
+            parse_err("0e99999999999");
+        exp_overflow_then_letter:
+            ErrorKind::InvalidDigit { position: 13 },
+            parse_err("1e99999999999x");
+    );
+
+    // Numbers out of range parse as infinity or zero, as they do with `f64`
+    test_all_exact!(
+        range_overflow:
+            Quad::INFINITY,
+            parse("1e400");
+        range_overflow_neg:
+            Quad::NEG_INFINITY,
+            parse("-1e400");
+        range_overflow_max_exp:
+            Quad::INFINITY,
+            parse("1e2147483647");
+        range_underflow:
+            Quad::ZERO,
+            parse("1e-400");
+        range_underflow_neg:
+            Quad::NEG_ZERO,
+            parse("-1e-400");
+        range_underflow_min_exp:
+            Quad::ZERO,
+            parse("1e-2147483648");
+        range_zero_max_exp:
+            Quad::ZERO,
+            parse("0e2147483647");
+    );
+
+    // accepted syntax tests
+    test_all_exact!(
+        underscores:
+            parse("1000000.000001"),
+            parse("1_000_000.000_001");
+        underscores_exp:
+            parse("1e10"),
+            parse("1e1_0");
+        underscores_exp_sign:
+            parse("1e-10"),
+            parse("1e-_10");
+        leading_plus:
+            parse("2.5"),
+            parse("+2.5");
+        plus_exp:
+            parse("2.5e3"),
+            parse("2.5E+3");
+        upper_inf:
+            Quad::INFINITY,
+            parse("INF");
+        plus_inf:
+            Quad::INFINITY,
+            parse("+inf");
+        mixed_infinity:
+            Quad::INFINITY,
+            parse("+InFiNiTy");
+        neg_infinity:
+            Quad::NEG_INFINITY,
+            parse("-Infinity");
+        mixed_nan:
+            Quad::NAN,
+            parse("NaN");
+        plus_nan:
+            Quad::NAN,
+            parse("+nan");
+    );
+
+    test!(neg_nan: {
+        let x = parse("-NAN");
+        assert!(x.is_nan());
+        assert!(x.is_sign_negative());
+    });
+
+    test!(error_display: {
+        assert_eq!(
+            parse_error("").to_string(),
+            "cannot parse quad-double from empty string"
+        );
+        assert_eq!(parse_error("-").to_string(), "invalid quad-double literal");
+        assert_eq!(
+            parse_error(" 1x").to_string(),
+            "invalid character at position 2 in quad-double literal"
+        );
+        assert_eq!(
+            parse_error("1e9999999999").to_string(),
+            "exponent too large in quad-double literal"
+        );
+        // It's a real `Error`, so it can be boxed and passed along with `?`
+        let boxed: Box<dyn std::error::Error> = Box::new(parse_error("1.5.2"));
+        assert_eq!(
+            boxed.to_string(),
+            "invalid character at position 3 in quad-double literal"
+        );
+    });
+
+    // Strings already written the way that `Display` writes them come back unchanged
+    test!(round_trip: {
+        let strings = [
+            "0",
+            "-0",
+            "-42",
+            "3.14159",
+            "-0.000001",
+            "1000000.000001",
+            "123456789012345678901234567890",
+            "0.1",
+            "2.718281828459045235360287471352662497757247093699959574966967",
+            "0.30000000000000000000000000000000000000000000000000000000000001",
+            "602214076000000000000000",
+            "inf",
+            "-inf",
+            "NaN",
+        ];
+        for &s in strings.iter() {
+            assert_eq!(parse(s).to_string(), s);
+        }
+    });
+
+    // Values at the ends of the exponent range, where the power of ten does the most work.
+    // The reference parses the same digits written out in full, which doesn't involve a
+    // power of ten at all. Formatting the result in scientific notation and parsing it
+    // again has to give back the same value.
+    test!(extreme_exponents: {
+        let mantissas = ["1", "5", "1.5", "9.87654321", "3.14159265358979323846"];
+        for &m in mantissas.iter() {
+            let (int, frac) = m.split_once('.').unwrap_or((m, ""));
+            for &e in [-300i32, -295, -290, 290, 295, 300, 305, 307].iter() {
+                let x = parse(&format!("{}e{}", m, e));
+                let expanded = if e >= 0 {
+                    format!("{}{}{}", int, frac, "0".repeat(e as usize - frac.len()))
+                } else {
+                    format!("0.{}{}{}", "0".repeat(-e as usize - 1), int, frac)
+                };
+                let reference = Quad::from_string_radix_any(&expanded, 10).unwrap();
+                assert!((x - reference).abs() <= reference * qd!(1e-61), "{}e{}", m, e);
+                exact!(x, parse(&format!("{:e}", x)));
+            }
+        }
+    });
+
+    // Anything accepted by this parser must also be accepted by `f64`'s parser (aside from
+    // the underscores, which are a deliberate extension, and surrounding whitespace, which
+    // is trimmed). Strings are generated pseudo-randomly from an alphabet of every
+    // character that means something to either parser.
+    test!(accepted_by_f64: {
+        const ALPHABET: &[u8] = b"0123456789.+-e_";
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        for _ in 0..20_000 {
+            let seed = rng.next_u64();
+            let len = (seed % 7) as usize + 1;
+            let s: String = (0..len)
+                .map(|i| ALPHABET[((seed >> (i * 4 + 8)) % ALPHABET.len() as u64) as usize] as char)
+                .collect();
+
+            if s.parse::<Quad>().is_ok() {
+                let stripped = s.replace('_', "");
+                assert!(
+                    stripped.parse::<f64>().is_ok(),
+                    "\"{}\" was accepted but is not a valid f64",
+                    s
+                );
+            }
+        }
+    });
+
     // zero tests
     test_all_exact!(
         zero_int:
@@ -357,4 +1013,376 @@ mod tests {
         let x = ((t - qd!(1)) / t) * qd!(10).powi(-100);
         near!(x, s);
     });
+
+    // from_parts tests
+
+    // Bitwise equality, so that the sign of zero counts and NaN equals NaN
+    fn same(a: Quad, b: Quad) -> bool {
+        (0..4).all(|i| a[i].to_bits() == b[i].to_bits())
+    }
+
+    // Parses the string that from_parts should be equivalent to
+    fn glued(int_part: i64, fraction: &str, exp: i32) -> Quad {
+        if fraction.is_empty() {
+            parse(&format!("{}e{}", int_part, exp))
+        } else {
+            parse(&format!("{}.{}e{}", int_part, fraction, exp))
+        }
+    }
+
+    fn parts_err(int_part: i64, fraction: &str) -> ErrorKind {
+        Quad::from_parts(int_part, fraction, 0).unwrap_err().kind
+    }
+
+    test_all_eq!(
+        parts_err_point:
+            ErrorKind::InvalidDigit { position: 1 },
+            parts_err(1, "2.5");
+        parts_err_sign:
+            ErrorKind::InvalidDigit { position: 0 },
+            parts_err(1, "-25");
+        parts_err_space:
+            ErrorKind::InvalidDigit { position: 0 },
+            parts_err(1, " 25");
+        parts_err_exp:
+            ErrorKind::InvalidDigit { position: 2 },
+            parts_err(1, "25e3");
+        parts_err_letter:
+            ErrorKind::InvalidDigit { position: 1 },
+            parts_err(1, "2x5");
+    );
+
+    test!(parts_macro: {
+        exact!(qd!("3.14159"), qd!(integer: 3, fraction: "14159"));
+        exact!(qd!("-3.14159e-7"), qd!(integer: -3, fraction: "14159", exponent: -7));
+        assert!(qd!(integer: 3, fraction: "1.4").is_nan());
+    });
+
+    // try_qd! tests
+    fn try_err(r: Result<Quad, ParseQuadError>) -> ErrorKind {
+        r.unwrap_err().kind
+    }
+
+    test_all_eq!(
+        try_macro_literal:
+            Ok(qd!("3.14159")),
+            try_qd!("3.14159");
+        try_macro_number:
+            Ok(qd!(-7)),
+            try_qd!(-7);
+        try_macro_float:
+            Ok(qd!(0.1)),
+            try_qd!(0.1);
+        try_macro_string:
+            Ok(qd!("-2.5e-3")),
+            try_qd!(String::from("-2.5e-3"));
+        try_macro_string_ref:
+            Ok(qd!("1e10")),
+            try_qd!(&String::from("1e10"));
+        try_macro_expr:
+            Ok(qd!(6)),
+            try_qd!(2u64 * 3);
+        try_macro_neg_expr:
+            Ok(qd!(-3)),
+            try_qd!(-qd!(3));
+        try_macro_parts:
+            Ok(qd!("-3.14159e-7")),
+            try_qd!(integer: -3, fraction: "14159", exponent: -7);
+        try_macro_bad_literal:
+            ErrorKind::InvalidDigit { position: 3 },
+            try_err(try_qd!("1.2x3"));
+        try_macro_bad_string:
+            ErrorKind::InvalidDigit { position: 0 },
+            try_err(try_qd!(String::from("not a number")));
+        try_macro_bad_str:
+            ErrorKind::Invalid,
+            try_err(try_qd!("1e"));
+        try_macro_empty:
+            ErrorKind::Empty,
+            try_err(try_qd!(""));
+        try_macro_bad_parts:
+            ErrorKind::InvalidDigit { position: 1 },
+            try_err(try_qd!(integer: 3, fraction: "1.4"));
+    );
+
+    test!(try_macro_const: {
+        const BAD: Result<Quad, ParseQuadError> = try_qd!("0x12");
+        const GOOD: Result<Quad, ParseQuadError> = try_qd!(-1.5);
+        assert_eq!(try_err(BAD), ErrorKind::InvalidDigit { position: 1 });
+        assert_eq!(GOOD, Ok(qd!(-1.5)));
+    });
+
+    test!(try_macro_nan: {
+        // "nan" is a number, not an error, which is what tells it apart from a typo
+        assert!(try_qd!("nan").unwrap().is_nan());
+        assert!(qd!("nan").is_nan());
+        assert!(qd!("nam").is_nan());
+    });
+
+    // The fraction adds to the magnitude, away from zero, just as it does in "-3.14"
+    test!(parts_negative: {
+        let x = Quad::from_parts(-3, "14", 0).unwrap();
+        assert!(same(x, parse("-3.14")));
+        assert!(x < qd!(-3));
+        assert!(same(Quad::from_parts(-3, "", 0).unwrap(), parse("-3")));
+        assert!(same(-Quad::from_parts(0, "14", 0).unwrap(), parse("-0.14")));
+        assert!(same(Quad::from_parts(0, "", 0).unwrap(), Quad::ZERO));
+        assert!(same(
+            Quad::from_parts(i64::MIN, "5", 0).unwrap(),
+            parse("-9223372036854775808.5")
+        ));
+    });
+
+    // The exponent applies to the whole number, after the fraction is attached
+    test!(parts_exponent: {
+        assert!(same(Quad::from_parts(3, "14", 2).unwrap(), qd!(314)));
+        assert!(same(Quad::from_parts(314, "", -2).unwrap(), parse("3.14")));
+        assert!(same(Quad::from_parts(1, "5", 300).unwrap(), parse("1.5e300")));
+        assert!(same(Quad::from_parts(15, "", -301).unwrap(), parse("1.5e-300")));
+        assert!(same(Quad::from_parts(0, "000", 400).unwrap(), Quad::ZERO));
+        let tiny = Quad::from_parts(-1, "5", -400).unwrap();
+        assert!(same(tiny, glued(-1, "5", -400)));
+        assert!(tiny.is_zero() && tiny.is_sign_negative());
+    });
+
+    test!(parts_long_fraction: {
+        let threes = "3".repeat(3000);
+        let x = Quad::from_parts(0, &threes, 0).unwrap();
+        assert!(same(x, glued(0, &threes, 0)));
+        near!(qd!(1) / qd!(3), x);
+
+        let zeros = format!("{}1", "0".repeat(2000));
+        let y = Quad::from_parts(1, &zeros, 2001).unwrap();
+        assert!(same(y, glued(1, &zeros, 2001)));
+        exact!(qd!(10).powi(2001 - 2000) * qd!(10).powi(2000) + qd!(1), y);
+    });
+
+    // 1 + 2⁻⁶⁰ + 2⁻¹²⁰ + 2⁻¹⁸⁰ + 2⁻²³³ and 1 + 2⁻⁶⁰ + 2⁻¹²⁰ + 2⁻¹⁸⁰ + 2⁻²³² + 2⁻²³³ lie
+    // exactly halfway between two adjacent `Quad`s. The parser isn't exact to the last bit
+    // of the last component, so this doesn't pin down which way they round. It does pin
+    // down that from_parts and parsing agree on them, that they're as close as the
+    // precision allows, and that a non-zero digit far past the end of the precision doesn't
+    // ever make them smaller.
+    test!(parts_halfway: {
+        for frac in &[
+            concat!(
+                "000000000000000000867361737988403547958278625222217374893146830622089758",
+                "920407997873489709408671844927417946287138621285077841120667742206300161",
+                "428147746077678435548600006813260139375022637247061862986186042689951136",
+                "70825958251953125",
+            ),
+            concat!(
+                "000000000000000000867361737988403547958278625222217374893146830622089903",
+                "811273259100887589554580499132086437207637791511288874442505981174321258",
+                "913669581910181666923929598076499168125067911741185588958558128069853410",
+                "12477874755859375",
+            ),
+        ] {
+            let tie = Quad::from_parts(1, frac, 0).unwrap();
+            assert!(same(tie, glued(1, frac, 0)));
+            near!(Quad(1.0, 2f64.powi(-60), 2f64.powi(-120), 2f64.powi(-180)), tie);
+
+            let sticky = format!("{}{}1", frac, "0".repeat(500));
+            let x = Quad::from_parts(1, &sticky, 0).unwrap();
+            assert!(same(x, glued(1, &sticky, 0)));
+            assert!(x >= tie);
+        }
+    });
+
+    // Pseudo-random integer parts, fractions, and exponents, with the fractions built mostly
+    // out of long runs of 0s and 9s (plus some 4s and 5s) to hit carries and near-ties
+    test!(parts_equivalence: {
+        const INTS: [i64; 8] = [0, 1, -1, 9, -10, i64::MAX, i64::MIN, 4_503_599_627_370_497];
+        const FRAC_DIGITS: &[u8] = b"0000999945123";
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..5_000 {
+            let r = rng.next_u64();
+            let int_part = if r % 3 == 0 {
+                INTS[(r >> 8) as usize % INTS.len()]
+            } else {
+                (rng.next_u64() as i64) >> (r % 64)
+            };
+            let len = match r % 5 {
+                0 => 0,
+                1 => (r >> 16) as usize % 20,
+                2 => (r >> 16) as usize % 60,
+                _ => (r >> 16) as usize % 200,
+            };
+            let fraction: String = (0..len)
+                .map(|_| FRAC_DIGITS[rng.next_u64() as usize % FRAC_DIGITS.len()] as char)
+                .collect();
+            let exp = if r % 7 == 0 {
+                rng.range(-350, 350)
+            } else {
+                rng.range(-10, 10)
+            };
+
+            let x = Quad::from_parts(int_part, &fraction, exp).unwrap();
+            let y = glued(int_part, &fraction, exp);
+            assert!(
+                same(x, y),
+                "from_parts({}, \"{}\", {}) = {:?}, but parsing gives {:?}",
+                int_part,
+                fraction,
+                exp,
+                x,
+                y
+            );
+        }
+    });
+
+    // The way that digits were accumulated before they were taken in chunks: one
+    // multiplication by ten and one addition for every digit. `digits` is an integer with no
+    // leading zeros.
+    fn per_digit(digits: &str, exp: i32) -> Quad {
+        let mut value = Quad::ZERO;
+        let mut exp = exp;
+        let mut sticky = false;
+        for (i, d) in digits.bytes().enumerate() {
+            if (i as i32) < MAX_DIGITS {
+                value = value * Quad(10.0, 0.0, 0.0, 0.0) + Quad::from((d - b'0') as u32);
+            } else {
+                exp += 1;
+                sticky |= d != b'0';
+            }
+        }
+        if sticky {
+            value = value * Quad(10.0, 0.0, 0.0, 0.0) + Quad::ONE;
+            exp -= 1;
+        }
+        c::mul_pow10(value, exp)
+    }
+
+    // The relative error of a parsed number, measured exactly
+    fn parse_error_of(s: &str, x: Quad) -> f64 {
+        let approx = Decimal::from_f64(x.0)
+            .add(&Decimal::from_f64(x.1))
+            .add(&Decimal::from_f64(x.2))
+            .add(&Decimal::from_f64(x.3));
+        (Decimal::parse(s).add(&approx.neg()).to_f64() / x.0).abs()
+    }
+
+    fn random_digits(rng: &mut Rng, len: usize) -> String {
+        (0..len)
+            .map(|i| {
+                let d = rng.next_u64() % 10;
+                (b'0' + if i == 0 { d % 9 + 1 } else { d } as u8) as char
+            })
+            .collect()
+    }
+
+    // Every step of both accumulations is exact for as long as the digits fit into a
+    // `Quad` (through 63 digits), so up to there they can't disagree at all
+    test!(chunks_match_per_digit: {
+        let mut rng = Rng::new(0x853c_49e6_748f_ea9b);
+        for _ in 0..3_000 {
+            let len = (rng.next_u64() % 63) as usize + 1;
+            let digits = random_digits(&mut rng, len);
+            let exp = rng.range(-330, 310);
+            let x = parse(&format!("{}e{}", digits, exp));
+            let y = per_digit(&digits, exp);
+            assert!(same(x, y), "{}e{}: {:?} != {:?}", digits, exp, x, y);
+        }
+    });
+
+    // Past that, the two round differently, but the chunked accumulation rounds once per
+    // chunk rather than once per digit and shouldn't come out any worse
+    test!(chunks_at_least_as_accurate: {
+        let mut rng = Rng::new(0xda3e_39cb_94b9_5bdb);
+        let mut chunked = 0.0;
+        let mut old = 0.0;
+        for _ in 0..500 {
+            let len = (rng.next_u64() % 57) as usize + 64;
+            let digits = random_digits(&mut rng, len);
+            // Keeps the number between 10^-250 and 10^250, where none of the components
+            // are subnormal
+            let exp = rng.range(-250, 250) - len as i32;
+            let s = format!("{}e{}", digits, exp);
+            let x = parse_error_of(&s, parse(&s));
+            assert!(x < 2.0 * Quad::EPSILON.0, "{}: error {:e}", s, x);
+            chunked += x;
+            old += parse_error_of(&s, per_digit(&digits, exp));
+        }
+        assert!(chunked <= old, "total error {:e}, was {:e}", chunked, old);
+    });
+
+    fn repr_err(s: &str) -> ErrorKind {
+        Quad::from_repr(s).unwrap_err().kind
+    }
+
+    // Parsing what `Debug` writes, in either form, gives back the same components bit for
+    // bit
+    test!(repr_round_trip: {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        let mut values = vec![
+            Quad::ZERO,
+            Quad::NEG_ZERO,
+            Quad::INFINITY,
+            Quad::NEG_INFINITY,
+            Quad::PI,
+            -Quad::E,
+            Quad::MAX,
+            Quad::MIN_POSITIVE,
+            Quad(1.0, 5e-324, 0.0, 0.0),
+            Quad(-3.0, -2.5e-320, 0.0, 0.0),
+            Quad(1.0, 1e-100, 1e-300, 5e-324),
+            Quad(5e-324, 0.0, 0.0, 0.0),
+        ];
+        for i in 0..2000 {
+            let x = Quad::ONE / Quad::from(rng.next_f64() + 0.5);
+            // Far enough from the subnormal range that every component is normal
+            let x = x.ldexp((i % 1700) - 800);
+            values.push(if i % 2 == 0 { x } else { -x });
+        }
+        for &x in &values {
+            let bits = |y: Quad| y.to_array().map(f64::to_bits);
+            let short = Quad::from_repr(&format!("{:?}", x)).unwrap();
+            let pretty = Quad::from_repr(&format!("{:#?}", x)).unwrap();
+            assert!(bits(short) == bits(x), "{:?} came back as {:?}", x, short);
+            assert!(bits(pretty) == bits(x), "{:#?} came back as {:#?}", x, pretty);
+        }
+        assert!(Quad::from_repr(&format!("{:?}", Quad::NAN)).unwrap().is_nan());
+        assert!(Quad::from_repr(&format!("{:#?}", -Quad::NAN)).unwrap().is_nan());
+    });
+
+    test!(repr_lenient: {
+        let x = Quad::from_repr("  Quad ( 1e0 ,\n\t2e-17, 0e0,0e0 )  ").unwrap();
+        assert!(x == Quad(1.0, 2e-17, 0.0, 0.0));
+        assert!(Quad::from_repr("Quad(1e0, 0e0, 0e0, 0e0,)").unwrap() == Quad::ONE);
+        let x = Quad::from_repr("Quad(1e0, 1e0, 0e0, 0e0)").unwrap();
+        assert!(x.to_array() == [2.0, 0.0, 0.0, 0.0]);
+    });
+
+    test_all_eq!(
+        repr_empty:
+            ErrorKind::Empty,
+            repr_err(" \n ");
+        repr_wrong_type:
+            ErrorKind::InvalidDigit { position: 0 },
+            repr_err("Double(1e0, 0e0)");
+        repr_no_paren:
+            ErrorKind::InvalidDigit { position: 4 },
+            repr_err("Quad[1e0]");
+        repr_too_few:
+            ErrorKind::InvalidDigit { position: 18 },
+            repr_err("Quad(1e0, 0e0, 0e0)");
+        repr_too_many:
+            ErrorKind::InvalidDigit { position: 25 },
+            repr_err("Quad(1e0, 0e0, 0e0, 0e0, 0e0)");
+        repr_bad_component:
+            ErrorKind::InvalidDigit { position: 15 },
+            repr_err("Quad(1e0, 0e0, 1x0, 0e0)");
+        repr_missing_component:
+            ErrorKind::InvalidDigit { position: 5 },
+            repr_err("Quad(, 0e0)");
+        repr_trailing:
+            ErrorKind::InvalidDigit { position: 25 },
+            repr_err("Quad(1e0, 0e0, 0e0, 0e0) x");
+        repr_unclosed:
+            ErrorKind::Invalid,
+            repr_err("Quad(1e0, 0e0, ");
+        repr_name_only:
+            ErrorKind::Invalid,
+            repr_err("Quad");
+    );
 }
@@ -0,0 +1,648 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::strict as s;
+use crate::quad::common as c;
+use crate::quad::Quad;
+
+// The smallest argument that the Stirling series is evaluated at. Smaller arguments are
+// shifted up to at least this with the recurrence Γ(x + 1) = xΓ(x).
+const STIRLING_MIN: f64 = 40.0;
+
+// Coefficients of the Stirling series for ln Γ(x), B<sub>2k</sub> / 2k(2k - 1) for k in
+// [1, 30], where B<sub>2k</sub> is a Bernoulli number. The series diverges, but for
+// x >= 40 the first term left out is less than 10^-65.
+const STIRLING: [Quad; 30] = [
+    Quad(
+        8.333333333333333e-2,
+        4.625929269271485e-18,
+        2.5679065925163143e-34,
+        1.425474512049171e-50,
+    ),
+    Quad(
+        -2.777777777777778e-3,
+        1.0601087908747154e-19,
+        3.4773735106991755e-36,
+        3.2667124234460168e-52,
+    ),
+    Quad(
+        7.936507936507937e-4,
+        6.883823317368282e-22,
+        5.970764956557651e-40,
+        5.178813069840099e-58,
+    ),
+    Quad(
+        -5.952380952380953e-4,
+        5.36938218754726e-20,
+        -1.8342189946545105e-36,
+        1.6545686300570736e-52,
+    ),
+    Quad(
+        8.417508417508417e-4,
+        3.6870174889237694e-20,
+        -6.889900895324708e-37,
+        3.768418257074434e-53,
+    ),
+    Quad(
+        -1.9175269175269176e-3,
+        1.0675702776872475e-19,
+        6.568342495426554e-37,
+        -2.0311261401341652e-53,
+    ),
+    Quad(
+        6.41025641025641e-3,
+        2.2240044563805217e-19,
+        1.975312763474088e-35,
+        6.853242846390245e-52,
+    ),
+    Quad(
+        -2.9550653594771242e-2,
+        4.861760957508855e-19,
+        1.316681517535326e-35,
+        2.7181842411133703e-52,
+    ),
+    Quad(
+        1.7964437236883057e-1,
+        -6.401600482710946e-19,
+        9.779977439678332e-36,
+        -1.6459873421448408e-52,
+    ),
+    Quad(
+        -1.3924322169059011e0,
+        1.5837056989230303e-17,
+        5.2056012685038854e-34,
+        2.858587930574395e-50,
+    ),
+    Quad(
+        1.3402864044168393e1,
+        -6.154114101993966e-16,
+        1.3610436598016077e-34,
+        -2.67092015197619e-51,
+    ),
+    Quad(
+        -1.5684828462600203e2,
+        9.391823141715389e-15,
+        1.6570392471086158e-31,
+        -4.3781278167020493e-48,
+    ),
+    Quad(
+        2.1931033333333335e3,
+        -1.3339255626002948e-13,
+        6.731613057885968e-31,
+        -4.3206702650015194e-47,
+    ),
+    Quad(
+        -3.610877125372499e4,
+        5.897583353514365e-13,
+        7.049709715793733e-31,
+        3.248966267062169e-47,
+    ),
+    Quad(
+        6.91472268851313e5,
+        2.5585296305158e-11,
+        -1.2521722821640843e-27,
+        -8.042857178972391e-44,
+    ),
+    Quad(
+        -1.5238221539407415e7,
+        -8.76774522490625e-10,
+        -1.9672353593923997e-26,
+        -1.1987697988365235e-42,
+    ),
+    Quad(
+        3.8290075139141417e8,
+        -2.4082684757733585e-8,
+        -4.344787055834085e-25,
+        4.2671038618864603e-41,
+    ),
+    Quad(
+        -1.0882266035784391e10,
+        3.141830930219749e-7,
+        -2.013934646419947e-23,
+        4.454869877644336e-41,
+    ),
+    Quad(
+        3.4732028376500226e11,
+        -6.048528997747748e-6,
+        5.341649216919011e-23,
+        4.871418030434705e-39,
+    ),
+    Quad(
+        -1.2369602142269275e13,
+        9.363732896507286e-4,
+        3.299942635958079e-20,
+        -2.2283267137789258e-36,
+    ),
+    Quad(
+        4.887880647930793e14,
+        2.2575815162518022e-2,
+        4.800971715392278e-19,
+        8.204517100444594e-36,
+    ),
+    Quad(
+        -2.1320333960919372e16,
+        -1.8969750589821368e0,
+        -3.047406913564973e-17,
+        -2.0306454882458636e-33,
+    ),
+    Quad(
+        1.0217752965257001e18,
+        -1.8434712371946414e1,
+        -1.7749570310161684e-16,
+        9.658728380513374e-33,
+    ),
+    Quad(
+        -5.35754721733002e19,
+        -9.08277091919692e1,
+        9.640642309952545e-16,
+        -5.349672583395236e-32,
+    ),
+    Quad(
+        3.0615782637048834e21,
+        -1.4332848948670377e4,
+        -6.839490150623876e-13,
+        6.61921135562071e-30,
+    ),
+    Quad(
+        -1.8999917426399204e23,
+        -1.2591611429306944e6,
+        9.979358553254276e-11,
+        2.6448689505304562e-27,
+    ),
+    Quad(
+        1.2763374033828835e25,
+        -6.442534326223022e8,
+        5.447179031400799e-10,
+        2.6336122557351365e-26,
+    ),
+    Quad(
+        -9.252847176120416e26,
+        -5.309275479483476e10,
+        2.952902543756531e-7,
+        -8.415011228861297e-24,
+    ),
+    Quad(
+        7.218822595185611e28,
+        -3.2364014534549834e12,
+        -2.223376015957974e-4,
+        -4.9916930232879055e-21,
+    ),
+    Quad(
+        -6.045183405995857e30,
+        2.2651486197154944e14,
+        1.5213933855604033e-2,
+        7.108135433655833e-20,
+    ),
+];
+
+// ln(2π) / 2
+const LN_SQRT_2PI: Quad = Quad(
+    9.189385332046728e-1,
+    -3.8782941580672414e-17,
+    -1.323971596849807e-33,
+    5.150860436871684e-50,
+);
+
+impl Quad {
+    /// Computes the gamma function, Γ(*x*), where *x* is this `Quad`.
+    ///
+    /// For positive integers, Γ(*n*) is (*n* - 1)!, and those results are exact as long as
+    /// the factorial fits in a `Quad`. Otherwise the result comes from the Stirling series
+    /// for ln Γ, after arguments less than 40 are shifted up to at least 40 with the
+    /// recurrence Γ(*x* + 1) = *x*Γ(*x*). Negative arguments use the reflection formula
+    /// Γ(*x*)Γ(1 - *x*) = π / sin(π*x*). The result is precise to about 60 digits, except
+    /// for results so small that their lower components are subnormal.
+    ///
+    /// The result is too large to represent once *x* exceeds about 171.6, and this
+    /// function then returns [`INFINITY`]. The pole at zero returns [`INFINITY`] or
+    /// [`NEG_INFINITY`] depending on the sign of the zero, as the limit from that side
+    /// would, but there's no single limit at the poles at the negative integers, so they
+    /// return [`NAN`], as does [`NEG_INFINITY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(5).gamma() == qd!(24));
+    ///
+    /// let x = qd!(0.5).gamma();
+    /// let diff = (x - Quad::PI.sqrt()).abs();
+    /// assert!(diff < qd!(1e-60));
+    ///
+    /// assert!(qd!(-1).gamma().is_nan());
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn gamma(self) -> Quad {
+        match self.pre_gamma() {
+            Some(r) => s::guard("Quad::gamma", &[self.0], r),
+            None => {
+                if self.0.abs() < 1.0 {
+                    // Dividing by x on its own, rather than as part of the shift or the
+                    // reflection, keeps all of its digits even when it's tiny. Division by
+                    // a subnormal doesn't work, so x is scaled up (exactly) for the
+                    // division and the result is scaled back afterwards.
+                    let g = (self + Quad::ONE).gamma();
+                    c::scale(g / c::scale(self, 64), 64)
+                } else if self.is_sign_negative() {
+                    let a = Quad::ONE - self;
+                    let sin = sin_pi(self);
+                    if a.0 < 170.0 {
+                        Quad::PI / (sin * a.gamma())
+                    } else {
+                        // Γ(1 - x) overflows, even though Γ(x) may still be well within
+                        // range, so the division is done with logarithms instead
+                        let r = ((Quad::PI / sin.abs()).ln() - a.ln_gamma()).exp();
+                        if sin.is_sign_negative() {
+                            -r
+                        } else {
+                            r
+                        }
+                    }
+                } else if c::is_integer(self) && self.0 <= 171.0 {
+                    let mut r = Quad::ONE;
+                    let mut i = 2.0;
+                    while i < self.0 {
+                        r *= Quad(i, 0.0, 0.0, 0.0);
+                        i += 1.0;
+                    }
+                    r
+                } else {
+                    let (z, p) = shift(self);
+                    ln_stirling(z).exp() / p
+                }
+            }
+        }
+    }
+
+    /// Computes the natural logarithm of the absolute value of the gamma function,
+    /// ln |Γ(*x*)|, where *x* is this `Quad`.
+    ///
+    /// Γ(*x*) overflows for *x* much past 171, but its logarithm doesn't, so this is the
+    /// function to use for ratios of gamma functions with large arguments, such as
+    /// binomial coefficients and the beta function. The sign of Γ(*x*) is lost; it's
+    /// negative when *x* is between -2*k* - 1 and -2*k* for some integer *k* >= 0.
+    ///
+    /// This is calculated in the same way as [`gamma`], without the final exponential.
+    /// The result is precise to about 60 digits, but near 1 and 2, where ln Γ(*x*) is zero,
+    /// that precision is relative to the size of the terms that cancel to give the result
+    /// rather than to the result itself.
+    ///
+    /// The poles at zero and the negative integers return [`INFINITY`], as do both
+    /// infinities.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(100.5).ln_gamma();
+    /// let expected = qd!("361.4355404677776215552519127025207628587788352472218475369797166563");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`gamma`]: #method.gamma
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn ln_gamma(self) -> Quad {
+        match self.pre_ln_gamma() {
+            Some(r) => s::guard("Quad::ln_gamma", &[self.0], r),
+            None => {
+                if self.0.abs() < 1.0 {
+                    // As in gamma, a subnormal x is scaled up to take its logarithm
+                    let x = self.abs();
+                    let ln = if x.0 < f64::MIN_POSITIVE {
+                        c::scale(x, 64).ln() - Quad::LN_2.mul_i32(64)
+                    } else {
+                        x.ln()
+                    };
+                    (self + Quad::ONE).ln_gamma() - ln
+                } else if self.is_sign_negative() {
+                    let a = Quad::ONE - self;
+                    (Quad::PI / sin_pi(self).abs()).ln() - a.ln_gamma()
+                } else {
+                    let (z, p) = shift(self);
+                    ln_stirling(z) - p.ln()
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn pre_gamma(&self) -> Option<Quad> {
+        if self.is_nan() || *self == Quad::NEG_INFINITY {
+            Some(Quad::NAN)
+        } else if self.is_infinite() || self.0 > 171.625 {
+            Some(Quad::INFINITY)
+        } else if (1.0 / self.0).is_infinite() {
+            // Includes both zeros. Γ(x) is about 1/x near zero.
+            if self.is_sign_negative() {
+                Some(Quad::NEG_INFINITY)
+            } else {
+                Some(Quad::INFINITY)
+            }
+        } else if self.is_sign_negative() && c::is_integer(*self) {
+            Some(Quad::NAN)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_ln_gamma(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_infinite() || (c::is_integer(*self) && *self <= Quad::ZERO) {
+            // Both infinities and all of the poles
+            Some(Quad::INFINITY)
+        } else if *self == Quad::ONE || *self == Quad(2.0, 0.0, 0.0, 0.0) {
+            Some(Quad::ZERO)
+        } else {
+            None
+        }
+    }
+}
+
+// Computes ln Γ(z) with the Stirling series, for z >= STIRLING_MIN.
+//
+//      ln Γ(z) = (z - 1/2) ln z - z + ln(2π) / 2 + Σ B_2k / 2k(2k - 1)z^(2k - 1)
+fn ln_stirling(z: Quad) -> Quad {
+    let series = Quad::polyval(&STIRLING, z.recip().sqr()) / z;
+    (z - Quad(0.5, 0.0, 0.0, 0.0)) * z.ln() - z + LN_SQRT_2PI + series
+}
+
+// Shifts an x of at least 1 up to z = x + n >= STIRLING_MIN, returning z along with the
+// product x(x + 1)...(x + n - 1), so that Γ(x) = Γ(z) / product. If x is already large
+// enough, the product is 1.
+fn shift(x: Quad) -> (Quad, Quad) {
+    let mut z = x;
+    let mut p = Quad::ONE;
+    while z.0 < STIRLING_MIN {
+        p *= z;
+        z += Quad::ONE;
+    }
+    (z, p)
+}
+
+// Computes sin(πx) for a non-integer x < -1. Multiplying x by π directly would round
+// away the digits that matter near the integers, so x is first split into an integer n and
+// a remainder r with |r| <= 1/2, which is exact, and then sin(πx) = (-1)^n sin(πr).
+fn sin_pi(x: Quad) -> Quad {
+    let n = x.round();
+    let r = (Quad::PI * (x - n)).sin();
+    if c::is_odd(n) {
+        -r
+    } else {
+        r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::reference::references;
+
+    test!(constants_match_references: {
+        let refs = references();
+        c::assert_reference(&refs, "LN_SQRT_2PI", LN_SQRT_2PI);
+        for (k, &x) in STIRLING.iter().enumerate() {
+            c::assert_reference(&refs, &format!("STIRLING[{}]", k), x);
+        }
+    });
+
+    // gamma tests
+    test_all_prec!(
+        gamma_half:
+            qd!("1.772453850905516027298167483341145182797549456122387128213807789852911"),
+            qd!(0.5).gamma(),
+            60;
+        gamma_half_sqrt_pi:
+            Quad::PI.sqrt(),
+            qd!(0.5).gamma(),
+            60;
+        gamma_3_halves:
+            qd!("0.8862269254527580136490837416705725913987747280611935641069038949264556"),
+            qd!(1.5).gamma(),
+            60;
+        gamma_5_halves:
+            qd!("1.329340388179137020473625612505858887098162092091790346160355842389683"),
+            qd!(2.5).gamma(),
+            60;
+        gamma_eighth:
+            qd!("7.533941598797611904699229841215133624610419588149075940983127897776664"),
+            qd!(0.125).gamma(),
+            60;
+        gamma_3_75:
+            qd!("4.422988410460250562887839188700432995353691661140067362408710542889829"),
+            qd!(3.75).gamma(),
+            60;
+        gamma_10_25:
+            qd!("639232.598779576794283758401876084967153425284996821146812097993778926"),
+            qd!(10.25).gamma(),
+            60;
+        gamma_27_375:
+            qd!("1381951088941485409909683567.618646028621044358265753622719709792970832"),
+            qd!(27.375).gamma(),
+            60;
+        gamma_100_5:
+            qd!("9.320963104082716608349109809141910437906497038162361154016117519412077e+156"),
+            qd!(100.5).gamma(),
+            60;
+        gamma_150_25:
+            qd!("1.332150776195163484301175039370643143242689361732953186180615284677157e+261"),
+            qd!(150.25).gamma(),
+            60;
+        gamma_171_5:
+            qd!("9.48336756682479933625340546920495158937563910974316253961413953077741e+307"),
+            qd!(171.5).gamma(),
+            60;
+        gamma_neg_half:
+            qd!("-3.544907701811032054596334966682290365595098912244774256427615579705823"),
+            qd!(-0.5).gamma(),
+            60;
+        gamma_neg_3_halves:
+            qd!("2.363271801207354703064223311121526910396732608163182837618410386470548"),
+            qd!(-1.5).gamma(),
+            60;
+        gamma_neg_2_25:
+            qd!("-1.742814865728252650850273142560555415966206930642589313749502490012193"),
+            qd!(-2.25).gamma(),
+            60;
+        gamma_neg_10_125:
+            qd!("-0.000001684831262052517456216882327889895477394156999133298413861303913754461"),
+            qd!(-10.125).gamma(),
+            60;
+        gamma_neg_100_5:
+            qd!("-3.353690819807678642208099692714592406181948500666472460085513091780635e-159"),
+            qd!(-100.5).gamma(),
+            60;
+        gamma_tiny:
+            qd!("8589934591.42278433521360841138215453639282914047157884932317481976724"),
+            qd!(1).ldexp(-33).gamma(),
+            60;
+        gamma_neg_tiny:
+            qd!("-8589934592.577215665016674132619775986201934884492334028576316001003341"),
+            qd!(-1).ldexp(-33).gamma(),
+            60;
+        gamma_subnormal:
+            qd!("8.988465674311579538646525953945123668089884894711532863671504057886634e+307"),
+            Quad(2f64.powi(-1023), 0.0, 0.0, 0.0).gamma(),
+            60;
+    );
+    test_all_exact!(
+        gamma_one:
+            Quad::ONE,
+            Quad::ONE.gamma();
+        gamma_two:
+            Quad::ONE,
+            qd!(2).gamma();
+        gamma_five:
+            qd!(24),
+            qd!(5).gamma();
+        gamma_21:
+            qd!(2432902008176640000u64),
+            qd!(21).gamma();
+        gamma_60:
+            qd!("138683118545689835737939019720389406345902876772687432540821294940160000000000000"),
+            qd!(60).gamma();
+        gamma_zero:
+            Quad::INFINITY,
+            Quad::ZERO.gamma();
+        gamma_neg_zero:
+            Quad::NEG_INFINITY,
+            Quad::NEG_ZERO.gamma();
+        gamma_neg_one:
+            Quad::NAN,
+            qd!(-1).gamma();
+        gamma_neg_large_int:
+            Quad::NAN,
+            qd!(-1e20).gamma();
+        gamma_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.gamma();
+        gamma_neg_inf:
+            Quad::NAN,
+            Quad::NEG_INFINITY.gamma();
+        gamma_nan:
+            Quad::NAN,
+            Quad::NAN.gamma();
+        gamma_overflow:
+            Quad::INFINITY,
+            qd!(172).gamma();
+        gamma_overflow_tiny:
+            Quad::INFINITY,
+            Quad(1e-320, 0.0, 0.0, 0.0).gamma();
+        gamma_underflow:
+            Quad::NEG_ZERO,
+            qd!(-180.5).gamma();
+    );
+    test_prec!(
+        gamma_171:
+            qd!("7257415615307998967396728211129263114716991681296451376543577798900561843401706157852350749242617459511490991237838520776666022565442753025328900773207510902400430280058295603966612599658257104398558294257568966313439612262571094946806711205568880457193340212661452800000000000000000000000000000000000000000"),
+            qd!(171).gamma(),
+            60
+    );
+    test!(gamma_recurrence: {
+        for &x in &[0.3, 1.7, 4.1, 19.5, 38.6, 39.5, 64.2, -0.6, -3.3, -41.7] {
+            let x = qd!(x);
+            prec!(x * x.gamma(), (x + Quad::ONE).gamma(), 59);
+        }
+    });
+    test!(gamma_reflection: {
+        for &x in &[0.3, 0.5, 0.9, 2.7, 13.2] {
+            let x = qd!(x);
+            let expected = Quad::PI / (Quad::PI * x).sin();
+            prec!(expected, x.gamma() * (Quad::ONE - x).gamma(), 59);
+        }
+    });
+
+    // ln_gamma tests
+    test_all_prec!(
+        ln_gamma_half:
+            qd!("0.5723649429247000870717136756765293558236474064576557857568115357360689"),
+            qd!(0.5).ln_gamma(),
+            60;
+        ln_gamma_3_75:
+            qd!("1.486815578593417055540581801444205025412948650163074938762548189430159"),
+            qd!(3.75).ln_gamma(),
+            60;
+        ln_gamma_100_5:
+            qd!("361.4355404677776215552519127025207628587788352472218475369797166562779"),
+            qd!(100.5).ln_gamma(),
+            60;
+        ln_gamma_1000_5:
+            qd!("5908.674175848677488683874734062624880497015468258619754689136365783636"),
+            qd!(1000.5).ln_gamma(),
+            60;
+        ln_gamma_1e10:
+            qd!("220258509288.8105814700419231234601265564272760202887431893822641928998"),
+            qd!(1e10).ln_gamma(),
+            60;
+        ln_gamma_2_200:
+            qd!("221161976898267353633709268127168298664132255716035357312258965.8317522"),
+            qd!(1).ldexp(200).ln_gamma(),
+            60;
+        ln_gamma_neg_2_25:
+            qd!("0.5555015450206474705935758935402500593920856512300935474160195518960675"),
+            qd!(-2.25).ln_gamma(),
+            60;
+        ln_gamma_neg_10_125:
+            qd!("-13.2938451403895384842367850898231917030033948118343738538907952813835"),
+            qd!(-10.125).ln_gamma(),
+            60;
+        ln_gamma_neg_1000_5:
+            qd!("-5914.437701116851876609699344690908278645877057097930117136157062765434"),
+            qd!(-1000.5).ln_gamma(),
+            60;
+        ln_gamma_tiny:
+            qd!("22.87385695841099846338674640481030460805991144134226855182224633933882"),
+            qd!(1).ldexp(-33).ln_gamma(),
+            60;
+        ln_gamma_subnormal:
+            qd!("741.6674831991414810764383699602489278407851437654731219091276101579312"),
+            Quad(f64::from_bits(1 << 4), 0.0, 0.0, 0.0).ln_gamma(),
+            60;
+        ln_gamma_3_quarters:
+            qd!("0.2032809514312953714814329718624296997596673149825786480739760536848733"),
+            qd!(0.75).ln_gamma(),
+            60;
+        ln_gamma_5_quarters:
+            qd!("-0.09827183642181316146385380269663584022562270360764995774137299645282598"),
+            qd!(1.25).ln_gamma(),
+            59;
+    );
+    test_all_exact!(
+        ln_gamma_one:
+            Quad::ZERO,
+            Quad::ONE.ln_gamma();
+        ln_gamma_two:
+            Quad::ZERO,
+            qd!(2).ln_gamma();
+        ln_gamma_zero:
+            Quad::INFINITY,
+            Quad::ZERO.ln_gamma();
+        ln_gamma_neg_zero:
+            Quad::INFINITY,
+            Quad::NEG_ZERO.ln_gamma();
+        ln_gamma_neg_three:
+            Quad::INFINITY,
+            qd!(-3).ln_gamma();
+        ln_gamma_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.ln_gamma();
+        ln_gamma_neg_inf:
+            Quad::INFINITY,
+            Quad::NEG_INFINITY.ln_gamma();
+        ln_gamma_nan:
+            Quad::NAN,
+            Quad::NAN.ln_gamma();
+    );
+    test!(ln_gamma_matches_gamma: {
+        for &x in &[0.1, 0.7, 3.2, 17.9, 45.5, 120.3, -0.4, -1.2, -7.6, -50.1] {
+            let x = qd!(x);
+            prec!(x.gamma().abs().ln(), x.ln_gamma(), 58);
+        }
+    });
+}
@@ -0,0 +1,327 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::hex as h;
+use crate::error::ParseQuadError;
+use crate::quad::common as c;
+use crate::quad::Quad;
+
+impl Quad {
+    /// Writes the exact value of the `Quad` as a string of hexadecimal floats, one for each
+    /// component.
+    ///
+    /// Each component is written in the form of C's `%a` format: `0x`, a leading `1` (or
+    /// `0` for zero and subnormals), the rest of the mantissa in hexadecimal without
+    /// trailing zeros, and a `p` followed by a power-of-two exponent in decimal. The
+    /// components after the first are each preceded by ` + ` or ` - ` according to their
+    /// signs, and the first one is preceded by a `-` if it's negative. Since no decimal
+    /// conversion is involved, every bit of every finite `Quad` is preserved, including the
+    /// signs of zeros and subnormal lower components, and [`from_hex_str`] turns the string
+    /// back into exactly the same `Quad`.
+    ///
+    /// Infinities are written as `inf` or `-inf`, which parse as [`INFINITY`] and
+    /// [`NEG_INFINITY`], and NaNs are written as `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = qd!(1) + qd!(2).powi(-60) - qd!(2).powi(-120) + qd!(2).powi(-180);
+    /// let s = x.to_hex_string();
+    /// assert!(s == "0x1p+0 + 0x1p-60 - 0x1p-120 + 0x1p-180");
+    /// assert!(Quad::from_hex_str(&s).unwrap() == x);
+    ///
+    /// assert!(qd!(-0.75).to_hex_string() == "-0x1.8p-1 + 0x0p+0 + 0x0p+0 + 0x0p+0");
+    /// ```
+    ///
+    /// [`from_hex_str`]: #method.from_hex_str
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    pub fn to_hex_string(self) -> String {
+        h::to_hex_string(&[self.0, self.1, self.2, self.3])
+    }
+
+    /// Parses a string of hexadecimal floats, as written by [`to_hex_string`], into a
+    /// `Quad`.
+    ///
+    /// The string must have one to four components separated by `+` or `-`, with the first
+    /// optionally preceded by a sign. Whitespace is allowed around the separators and
+    /// trimmed from the ends. Each component is `0x`, hexadecimal digits with at most one
+    /// point among them, and a `p` followed by an optionally signed decimal exponent. Case
+    /// doesn't matter. The words `inf`, `infinity`, and `nan`, with or without a sign, are
+    /// also accepted on their own.
+    ///
+    /// Every component must be exactly representable as an `f64`; nothing is rounded on
+    /// the way in. Components that are already normalized (which includes anything written
+    /// by [`to_hex_string`]) produce exactly those components, bit for bit. Components that
+    /// aren't are normalized in the same way as [`from_components`], and missing
+    /// components are zero.
+    ///
+    /// Failure will return a [`ParseQuadError`]. Its kind will be:
+    ///
+    /// * [`Empty`] if the string is empty or contains only whitespace;
+    /// * [`InvalidDigit`] if there is a character that can't appear where it does,
+    ///   including a fifth component, along with the byte offset of that character in `s`;
+    /// * [`ExponentOverflow`] if an exponent doesn't fit into an `i32`;
+    /// * [`Invalid`] if something is missing at the end of the string, as in `"0x1.8"`, or
+    ///   if a component has too many bits or is too large or too small to be an `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::from_hex_str("0x1.8p+1 - 0x1p-60").unwrap();
+    /// assert!(x.as_components() == (3.0, -(2f64.powi(-60)), 0.0, 0.0));
+    ///
+    /// // Not normalized, so the components are added together
+    /// let y = Quad::from_hex_str("0x1p+0 + 0x1p+0 + 0x1p+1").unwrap();
+    /// assert!(y == qd!(4));
+    ///
+    /// // 2^-1075 is too small to be an f64
+    /// assert!(Quad::from_hex_str("0x1p+0 + 0x1p-1075").is_err());
+    /// ```
+    ///
+    /// [`to_hex_string`]: #method.to_hex_string
+    /// [`from_components`]: #method.from_components
+    /// [`ParseQuadError`]: error/struct.ParseQuadError.html
+    /// [`Empty`]: error/enum.ErrorKind.html#variant.Empty
+    /// [`InvalidDigit`]: error/enum.ErrorKind.html#variant.InvalidDigit
+    /// [`ExponentOverflow`]: error/enum.ErrorKind.html#variant.ExponentOverflow
+    /// [`Invalid`]: error/enum.ErrorKind.html#variant.Invalid
+    pub fn from_hex_str(s: &str) -> Result<Quad, ParseQuadError> {
+        let mut a = [0.0; 4];
+        h::parse(s, &mut a).map_err(|kind| ParseQuadError { kind })?;
+        Ok(c::from_components(a[0], a[1], a[2], a[3]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::random::Rng;
+    use crate::error::ErrorKind;
+
+    // Bitwise equality, so that the sign of zero counts and NaN equals NaN
+    fn same(a: Quad, b: Quad) -> bool {
+        a.to_bits() == b.to_bits()
+    }
+
+    fn parse(s: &str) -> Quad {
+        Quad::from_hex_str(s).unwrap()
+    }
+
+    fn parse_err(s: &str) -> ErrorKind {
+        Quad::from_hex_str(s).unwrap_err().kind
+    }
+
+    fn round_trip(x: Quad) {
+        let s = x.to_hex_string();
+        let y = parse(&s);
+        assert!(same(x, y), "{:?} became {:?} through {}", x, y, s);
+    }
+
+    fn pow2(n: i32) -> f64 {
+        2f64.powi(n)
+    }
+
+    test_all_eq!(
+        hex_pi_prefix:
+            "0x1.921fb54442d18p+1 + 0x1.1a62633145c07p-53",
+            &Quad::PI.to_hex_string()[..44];
+        hex_powers:
+            "0x1p+0 + 0x1p-60 - 0x1p-120 + 0x1p-180",
+            Quad(1.0, pow2(-60), -pow2(-120), pow2(-180)).to_hex_string();
+        hex_neg:
+            "-0x1.8p+1 - 0x1p-60 + 0x1p-120 - 0x1p-180",
+            (-Quad(3.0, pow2(-60), -pow2(-120), pow2(-180))).to_hex_string();
+        hex_one:
+            "0x1p+0 + 0x0p+0 + 0x0p+0 + 0x0p+0",
+            Quad::ONE.to_hex_string();
+        hex_neg_zero:
+            "-0x0p+0 + 0x0p+0 + 0x0p+0 + 0x0p+0",
+            Quad::NEG_ZERO.to_hex_string();
+        hex_neg_zero_lower:
+            "0x1p+0 - 0x0p+0 + 0x0p+0 - 0x0p+0",
+            Quad(1.0, -0.0, 0.0, -0.0).to_hex_string();
+        hex_subnormal:
+            "0x0.0000000000001p-1022 + 0x0p+0 + 0x0p+0 + 0x0p+0",
+            Quad(5e-324, 0.0, 0.0, 0.0).to_hex_string();
+        hex_subnormal_lower:
+            "0x1p-900 + 0x1p-960 + 0x1p-1020 + 0x0.0000000004p-1022",
+            Quad(pow2(-900), pow2(-960), pow2(-1020), f64::from_bits(1 << 14)).to_hex_string();
+        hex_inf:
+            "inf",
+            Quad::INFINITY.to_hex_string();
+        hex_neg_inf:
+            "-inf",
+            Quad::NEG_INFINITY.to_hex_string();
+        hex_nan:
+            "NaN",
+            Quad::NAN.to_hex_string();
+        hex_nan_lower:
+            "NaN",
+            Quad(1.0, 0.0, 0.0, f64::NAN).to_hex_string();
+    );
+
+    test!(round_trip_constants: {
+        for &x in &[
+            Quad::EPSILON,
+            Quad::MIN,
+            Quad::MIN_POSITIVE,
+            Quad::MAX,
+            Quad::MAX_SAFE_INTEGER,
+            Quad::INFINITY,
+            Quad::NEG_INFINITY,
+            Quad::ZERO,
+            Quad::NEG_ZERO,
+            Quad::ONE,
+            Quad::NEG_ONE,
+            Quad::PI,
+            Quad::TAU,
+            Quad::FRAC_PI_2,
+            Quad::FRAC_PI_3,
+            Quad::FRAC_PI_4,
+            Quad::FRAC_PI_6,
+            Quad::FRAC_PI_8,
+            Quad::FRAC_PI_16,
+            Quad::FRAC_3_PI_2,
+            Quad::FRAC_3_PI_4,
+            Quad::FRAC_5_PI_4,
+            Quad::FRAC_7_PI_4,
+            Quad::FRAC_1_PI,
+            Quad::FRAC_2_PI,
+            Quad::FRAC_2_SQRT_PI,
+            Quad::SQRT_2,
+            Quad::FRAC_1_SQRT_2,
+            Quad::E,
+            Quad::LOG2_10,
+            Quad::LOG2_E,
+            Quad::LOG10_2,
+            Quad::LOG10_E,
+            Quad::LN_2,
+            Quad::LN_10,
+        ] {
+            round_trip(x);
+            if x.is_finite() {
+                round_trip(-x);
+            }
+        }
+        assert!(parse(&Quad::NAN.to_hex_string()).is_nan());
+    });
+
+    test!(round_trip_random: {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        for _ in 0..10_000 {
+            // Any finite first component, with each of the others somewhere below the
+            // last, which makes plenty of subnormals when the first is small
+            let a = f64::from_bits(rng.next_u64() % 0x7ff0_0000_0000_0000);
+            let mut parts = [a, 0.0, 0.0, 0.0];
+            for i in 1..4 {
+                let r = f64::from_bits(rng.next_u64() >> 12 | 0x3ff0_0000_0000_0000) - 1.5;
+                parts[i] = parts[i - 1] * r * pow2(-52 - (rng.next_u64() % 30) as i32);
+            }
+            let x = c::from_components(parts[0], parts[1], parts[2], parts[3]);
+            round_trip(x);
+            round_trip(-x);
+        }
+    });
+
+    test!(round_trip_subnormals: {
+        round_trip(Quad(5e-324, 0.0, 0.0, 0.0));
+        round_trip(Quad(-5e-324, -0.0, 0.0, -0.0));
+        round_trip(Quad(1e-290, 5e-324, 0.0, 0.0));
+        round_trip(Quad(1e-270, 1e-287, 1e-304, -1e-321));
+        round_trip(Quad(f64::from_bits(0x000f_ffff_ffff_ffff), 0.0, 0.0, 0.0));
+    });
+
+    test_all_exact!(
+        parse_one_component:
+            qd!(3),
+            parse("0x1.8p+1");
+        parse_two_components:
+            Quad(3.0, pow2(-60), 0.0, 0.0),
+            parse("0x1.8p+1 + 0x1p-60");
+        parse_upper:
+            qd!(3),
+            parse("0X1.8P+1");
+        parse_no_lead:
+            qd!(3),
+            parse("0x.cp+2");
+        parse_long:
+            qd!(1),
+            parse("0x1.000000000000000000000000000p+0");
+        parse_long_int:
+            qd!(1),
+            parse("0x10000000000000000000000000000p-112");
+        parse_no_spaces:
+            Quad(1.0, pow2(-60), -pow2(-120), pow2(-180)),
+            parse("0x1p+0+0x1p-60-0x1p-120+0x1p-180");
+        parse_spaces:
+            Quad(1.0, pow2(-60), -pow2(-120), pow2(-180)),
+            parse("  0x1p+0 +  0x1p-60\t- 0x1p-120 +\n0x1p-180 ");
+        parse_smallest:
+            Quad(5e-324, 0.0, 0.0, 0.0),
+            parse("0x1p-1074");
+        parse_largest:
+            Quad(f64::MAX, 0.0, 0.0, 0.0),
+            parse("0x1.fffffffffffffp+1023");
+
+        parse_renormalize:
+            qd!(4),
+            parse("0x1p+0 + 0x1p+0 + 0x1p+1");
+        parse_renormalize_order:
+            Quad(1.0, pow2(-60), pow2(-120), pow2(-180)),
+            parse("0x1p-180 + 0x1p-120 + 0x1p-60 + 0x1p+0");
+
+        parse_inf:
+            Quad::INFINITY,
+            parse("inf");
+        parse_neg_inf:
+            Quad::NEG_INFINITY,
+            parse("-Infinity");
+        parse_nan:
+            Quad::NAN,
+            parse("nan");
+    );
+
+    test!(parse_signed_zeros: {
+        assert!(same(parse("-0x0p+0"), Quad::NEG_ZERO));
+        assert!(same(
+            parse("0x1p+0 - 0x0p+0 + 0x0p+0 - 0x0p+0"),
+            Quad(1.0, -0.0, 0.0, -0.0)
+        ));
+    });
+
+    test_all_eq!(
+        parse_empty:
+            ErrorKind::Empty,
+            parse_err("");
+        parse_decimal:
+            ErrorKind::InvalidDigit { position: 0 },
+            parse_err("1.5");
+        parse_bad_digit:
+            ErrorKind::InvalidDigit { position: 5 },
+            parse_err("0x1.8g+0");
+        parse_offset:
+            ErrorKind::InvalidDigit { position: 7 },
+            parse_err("  0x1.8g+0");
+        parse_no_exp:
+            ErrorKind::Invalid,
+            parse_err("0x1p+0 + 0x1.8");
+        parse_exp_overflow:
+            ErrorKind::ExponentOverflow,
+            parse_err("0x1p+0 + 0x1p-99999999999");
+        parse_five_components:
+            ErrorKind::InvalidDigit { position: 39 },
+            parse_err("0x1p+0 + 0x1p-60 + 0x1p-120 + 0x1p-180 + 0x1p-240");
+        parse_double_sign:
+            ErrorKind::InvalidDigit { position: 9 },
+            parse_err("0x1p+0 + -0x1p-60");
+        parse_too_many_bits:
+            ErrorKind::Invalid,
+            parse_err("0x1p+0 + 0x1.00000000000008p-60");
+        parse_too_small:
+            ErrorKind::Invalid,
+            parse_err("0x1p+0 + 0x1p-1075");
+    );
+}
@@ -3,6 +3,7 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::strict as s;
 use crate::quad::common as c;
 use crate::quad::Quad;
 
@@ -38,8 +39,13 @@ impl Quad {
             Some(r) => r,
             None => {
                 if self.abs().0 <= 0.05 {
-                    let s = self.sinh();
-                    let c = (Quad::ONE + s.sqr()).sqrt();
+                    // With e = exp(x) - 1, sinh x = e(e + 2) / 2(e + 1) and
+                    // cosh x = 1 + e^2 / 2(e + 1). Neither of these subtracts nearly equal
+                    // numbers the way that the formulas using exp do.
+                    let e = self.exp_m1();
+                    let d = e + Quad::ONE;
+                    let s = c::mul_pwr2(e * (e + Quad::from(2.0)) / d, 0.5);
+                    let c = Quad::ONE + c::mul_pwr2(e.sqr() / d, 0.5);
                     (s, c)
                 } else {
                     let a = self.exp();
@@ -67,30 +73,17 @@ impl Quad {
     /// ```
     pub fn sinh(self) -> Quad {
         match self.pre_sinh() {
-            Some(r) => r,
+            Some(r) => s::guard("Quad::sinh", &[self.0], r),
             None => {
                 if self.abs().0 > 0.05 {
                     let a = self.exp();
                     c::mul_pwr2(a - a.recip(), 0.5)
                 } else {
-                    // The above formula is not accurate enough with very small numbers.
-                    // Use a Taylor series instead.
-                    let mut s = self;
-                    let mut t = self;
-                    let r = t.sqr();
-                    let mut m = 1.0;
-                    let threshold = (self * Quad::EPSILON).abs();
-
-                    loop {
-                        m += 2.0;
-                        t *= r;
-                        t /= Quad::from((m - 1.0) * m);
-                        s += t;
-                        if t.abs() <= threshold {
-                            break;
-                        }
-                    }
-                    s
+                    // The above formula is not accurate enough with very small numbers,
+                    // since exp(x) and exp(-x) are so close together. With e = exp(x) - 1,
+                    // calculated directly by exp_m1, sinh x = e(e + 2) / 2(e + 1) instead.
+                    let e = self.exp_m1();
+                    c::mul_pwr2(e * (e + Quad::from(2.0)) / (e + Quad::ONE), 0.5)
                 }
             }
         }
@@ -111,7 +104,7 @@ impl Quad {
     /// ```
     pub fn cosh(self) -> Quad {
         match self.pre_cosh() {
-            Some(r) => r,
+            Some(r) => s::guard("Quad::cosh", &[self.0], r),
             None => {
                 let a = self.exp();
                 c::mul_pwr2(a + a.recip(), 0.5)
@@ -134,15 +127,17 @@ impl Quad {
     /// ```
     pub fn tanh(self) -> Quad {
         match self.pre_tanh() {
-            Some(r) => r,
+            Some(r) => s::guard("Quad::tanh", &[self.0], r),
             None => {
                 if self.abs().0 > 0.05 {
                     let a = self.exp();
                     let inv_a = a.recip();
                     (a - inv_a) / (a + inv_a)
                 } else {
-                    let (s, c) = self.sinh_cosh();
-                    s / c
+                    // With e = exp(x) - 1, tanh x = e(e + 2) / (e(e + 2) + 2)
+                    let e = self.exp_m1();
+                    let t = e * (e + Quad::from(2.0));
+                    t / (t + Quad::from(2.0))
                 }
             }
         }
@@ -163,8 +158,25 @@ impl Quad {
     /// ```
     pub fn asinh(self) -> Quad {
         match self.pre_asinh() {
-            Some(r) => r,
-            None => (self + (self.sqr() + Quad::ONE).sqrt()).ln(),
+            Some(r) => s::guard("Quad::asinh", &[self.0], r),
+            None => {
+                // asinh x = ln(x + sqrt(x^2 + 1)) loses everything to cancellation for
+                // small x, so that's rewritten as ln_1p(x + x^2 / (1 + sqrt(1 + x^2))). The
+                // function is odd, so this is done with |x| and the sign is put back after.
+                let a = self.abs();
+                let r = if a.0 > 1e40 {
+                    // x^2 would overflow, but here asinh x = ln 2x to full precision
+                    a.ln() + Quad::LN_2
+                } else {
+                    let a2 = a.sqr();
+                    (a + a2 / (Quad::ONE + (Quad::ONE + a2).sqrt())).ln_1p()
+                };
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
         }
     }
 
@@ -186,15 +198,27 @@ impl Quad {
     /// [`NAN`]: #associatedconstant.NAN
     pub fn acosh(self) -> Quad {
         match self.pre_acosh() {
-            Some(r) => r,
-            None => (self + (self.sqr() - Quad::ONE).sqrt()).ln(),
+            Some(r) => s::guard("Quad::acosh", &[self.0], r),
+            None => {
+                if self.0 > 1e40 {
+                    // x^2 would overflow, but here acosh x = ln 2x to full precision
+                    self.ln() + Quad::LN_2
+                } else {
+                    // acosh x = ln(x + sqrt(x^2 - 1)) loses precision near 1, where x^2 - 1
+                    // cancels. With t = x - 1 (which is exact that close to 1), it's
+                    // ln_1p(t + sqrt(t(t + 2))) instead.
+                    let t = self - Quad::ONE;
+                    (t + (t * (t + Quad::from(2.0))).sqrt()).ln_1p()
+                }
+            }
         }
     }
 
     /// Calculates the inverse hyperbolic tangent (tanh<sup>-1</sup>) of the `Quad`.
     ///
-    /// The domain of the function is (-1, 1) and the range is (-∞, ∞). Any argument whose
-    /// absolute value is greater than or equal to 1 will result in [`NAN`].
+    /// The domain of the function is [-1, 1] and the range is [-∞, ∞]. 1 and -1 result in
+    /// [`INFINITY`] and [`NEG_INFINITY`] respectively, and any argument whose absolute
+    /// value is greater than 1 will result in [`NAN`].
     ///
     /// # Examples
     /// ```
@@ -206,11 +230,25 @@ impl Quad {
     /// assert!(diff < qd!(1e-60));
     /// ```
     ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
     /// [`NAN`]: #associatedconstant.NAN
     pub fn atanh(self) -> Quad {
         match self.pre_atanh() {
-            Some(r) => r,
-            None => c::mul_pwr2(((Quad::ONE + self) / (Quad::ONE - self)).ln(), 0.5),
+            Some(r) => s::guard("Quad::atanh", &[self.0], r),
+            None => {
+                // atanh x = ln((1 + x) / (1 - x)) / 2, but that quotient is so close to 1 for
+                // small x that its logarithm loses precision. ln_1p(2x / (1 - x)) / 2 is the
+                // same thing without the problem. The function is odd, so this is done with
+                // |x| and the sign is put back after.
+                let a = self.abs();
+                let r = c::mul_pwr2((c::mul_pwr2(a, 2.0) / (Quad::ONE - a)).ln_1p(), 0.5);
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
         }
     }
 
@@ -228,7 +266,9 @@ impl Quad {
         if self.is_nan() {
             Some((Quad::NAN, Quad::NAN))
         } else if self.is_zero() {
-            Some((Quad::ZERO, Quad::ONE))
+            Some((*self, Quad::ONE))
+        } else if self.is_infinite() {
+            Some((*self, Quad::INFINITY))
         } else {
             None
         }
@@ -238,8 +278,8 @@ impl Quad {
     fn pre_sinh(&self) -> Option<Quad> {
         if self.is_nan() {
             Some(Quad::NAN)
-        } else if self.is_zero() {
-            Some(Quad::ZERO)
+        } else if self.is_zero() || self.is_infinite() {
+            Some(*self)
         } else {
             None
         }
@@ -251,6 +291,8 @@ impl Quad {
             Some(Quad::NAN)
         } else if self.is_zero() {
             Some(Quad::ONE)
+        } else if self.is_infinite() {
+            Some(Quad::INFINITY)
         } else {
             None
         }
@@ -261,8 +303,10 @@ impl Quad {
         if self.is_nan() {
             Some(Quad::NAN)
         } else if self.is_zero() {
-            Some(Quad::ZERO)
-        } else if self.is_infinite() {
+            Some(*self)
+        } else if self.0.abs() > 75.0 {
+            // This includes infinity. Past 75, 1 - |tanh x| (about 2e^(-2|x|)) is too small
+            // to show up in a `Quad`, and exp(x) would eventually overflow anyway.
             Some(self.signum())
         } else {
             None
@@ -295,7 +339,15 @@ impl Quad {
 
     #[inline]
     fn pre_atanh(&self) -> Option<Quad> {
-        if self.abs() >= Quad::ONE {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(*self)
+        } else if *self == Quad::ONE {
+            Some(Quad::INFINITY)
+        } else if *self == Quad::NEG_ONE {
+            Some(Quad::NEG_INFINITY)
+        } else if self.abs() > Quad::ONE {
             Some(Quad::NAN)
         } else {
             None
@@ -345,6 +397,12 @@ mod tests {
         sinh_neg_140:
             qd!("-3163715853577792682171512256175572576556222994111693720356524.5634327"),
             qd!(-140).sinh();
+        sinh_tiny:
+            qd!("1.000000000000000000000000000000000000000000000000000000000000166666667e-30"),
+            qd!("1e-30").sinh();
+        sinh_neg_04:
+            qd!("-0.04001066752003250865891703329680442902750247829719773180525877612090294"),
+            qd!("-0.04").sinh();
     );
     test_all_exact!(
         sinh_zero:
@@ -393,6 +451,12 @@ mod tests {
         cosh_small:
             qd!("1.0000000000500000000004166666666680555555555580357142857170414462076"),
             qd!("0.00001").cosh();
+        cosh_tiny:
+            qd!("1.0000000000000000000000000000000000000000000000000000000000005"),
+            qd!("1e-30").cosh();
+        cosh_04:
+            qd!("1.000800106672355718098127724620050315055474572015114303428698409931945"),
+            qd!("0.04").cosh();
         cosh_neg_small:
             qd!("1.0000000000000000005000000000000000000416666666666666666680555555557"),
             qd!("-0.000000001").cosh();
@@ -495,6 +559,18 @@ mod tests {
         sinh_cosh_neg_140_cosh:
             qd!(-140).cosh(),
             qd!(-140).sinh_cosh().1;
+        sinh_cosh_tiny_sinh:
+            qd!("1.000000000000000000000000000000000000000000000000000000000000166666667e-30"),
+            qd!("1e-30").sinh_cosh().0;
+        sinh_cosh_tiny_cosh:
+            qd!("1.0000000000000000000000000000000000000000000000000000000000005"),
+            qd!("1e-30").sinh_cosh().1;
+        sinh_cosh_04_sinh:
+            qd!("0.04001066752003250865891703329680442902750247829719773180525877612090294"),
+            qd!("0.04").sinh_cosh().0;
+        sinh_cosh_04_cosh:
+            qd!("1.000800106672355718098127724620050315055474572015114303428698409931945"),
+            qd!("0.04").sinh_cosh().1;
     );
     test_all_exact!(
         sinh_cosh_zero_sinh:
@@ -567,6 +643,12 @@ mod tests {
         tanh_neg_small:
             qd!("-0.00000000099999999999999999966666666666666666679999999999999999994603174603225"),
             qd!("-0.000000001").tanh();
+        tanh_tiny:
+            qd!("9.999999999999999999999999999999999999999999999999999999999996666666667e-31"),
+            qd!("1e-30").tanh();
+        tanh_04:
+            qd!("0.03997868031116357050994274225116974971027334616632734102136681976255361"),
+            qd!("0.04").tanh();
     );
     test_all_exact!(
         tanh_zero:
@@ -581,6 +663,12 @@ mod tests {
         tanh_neg_inf:
             Quad::NEG_ONE,
             Quad::NEG_INFINITY.tanh();
+        tanh_800:
+            Quad::ONE,
+            qd!(800).tanh();
+        tanh_neg_800:
+            Quad::NEG_ONE,
+            qd!(-800).tanh();
         tanh_nan:
             Quad::NAN,
             Quad::NAN.tanh();
@@ -615,6 +703,18 @@ mod tests {
         asinh_150:
             qd!("5.7037935855821315576975027994004472827976444064946619017092033475062"),
             qd!(150).asinh();
+        asinh_tiny:
+            qd!("0.000000000000000000009999999999999999999999999999999999999999833333333333333333333333333333"),
+            qd!("1e-20").asinh();
+        asinh_neg_tiny:
+            qd!("-0.000000000000000000009999999999999999999999999999999999999999833333333333333333333333333333"),
+            qd!("-1e-20").asinh();
+        asinh_large:
+            qd!("461.2101657793690821130155230583310180882957978601148504607862602030079"),
+            qd!("1e200").asinh();
+        asinh_neg_large:
+            qd!("-461.2101657793690821130155230583310180882957978601148504607862602030079"),
+            qd!("-1e200").asinh();
         asinh_neg_140:
             qd!("-5.6348023580272583991488640099283811389909652342696970652712772047229"),
             qd!(-140).asinh();
@@ -667,6 +767,12 @@ mod tests {
         acosh_150:
             qd!("5.7037713633599001905278554895391354012923502543623403061858508464653"),
             qd!(150).acosh();
+        acosh_near_one:
+            qd!("0.00000134869915234850679762189477326035349090539162600881254020547415899929"),
+            (Quad::ONE + qd!(2).powi(-40)).acosh();
+        acosh_large:
+            qd!("461.2101657793690821130155230583310180882957978601148504607862602030079"),
+            qd!("1e200").acosh();
     );
     test_all_prec!(
         acosh_small:
@@ -730,6 +836,18 @@ mod tests {
         atanh_neg_99:
             qd!("-2.6466524123622461977050606459342686009455526402847362494532304939648"),
             qd!("-0.99").atanh();
+        atanh_tiny:
+            qd!("0.00000000000000000001000000000000000000000000000000000000000033333333333333333333333333333"),
+            qd!("1e-20").atanh();
+        atanh_neg_tiny:
+            qd!("-0.00000000000000000001000000000000000000000000000000000000000033333333333333333333333333333"),
+            qd!("-1e-20").atanh();
+        atanh_near_one:
+            qd!("10.7437812984463216522590181450543825508192870522687081775153507867022"),
+            (Quad::ONE - qd!(2).powi(-30)).atanh();
+        atanh_near_neg_one:
+            qd!("-10.7437812984463216522590181450543825508192870522687081775153507867022"),
+            (Quad::NEG_ONE + qd!(2).powi(-30)).atanh();
     );
     test_all_exact!(
         atanh_pi:
@@ -753,5 +871,14 @@ mod tests {
         atanh_nan:
             Quad::NAN,
             Quad::NAN.atanh();
+        atanh_one:
+            Quad::INFINITY,
+            Quad::ONE.atanh();
+        atanh_neg_one:
+            Quad::NEG_INFINITY,
+            Quad::NEG_ONE.atanh();
+        atanh_past_one:
+            Quad::NAN,
+            (Quad::ONE + Quad::EPSILON).atanh();
     );
 }
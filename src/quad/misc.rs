@@ -3,9 +3,10 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::rounding as r;
 use crate::common::utils as u;
+use crate::quad::common as c;
 use crate::quad::Quad;
-use std::f64;
 use std::num::FpCategory;
 
 impl Quad {
@@ -44,16 +45,17 @@ impl Quad {
         let mut c = 0.0;
         let mut d = 0.0;
 
-        if (a - self.0).abs() < f64::EPSILON {
+        if a - self.0 == 0.0 {
             b = self.1.floor();
-            if (b - self.1).abs() < f64::EPSILON {
+            if b - self.1 == 0.0 {
                 c = self.2.floor();
-                if (c - self.2).abs() < f64::EPSILON {
+                if c - self.2 == 0.0 {
                     d = self.3.floor();
                 }
             }
             let (a, b, c, d) = u::renorm4(a, b, c, d);
-            Quad(a, b, c, d)
+            // A zero result keeps the sign of the number, as it does with `f64`
+            Quad(if a == 0.0 { 0f64.copysign(self.0) } else { a }, b, c, d)
         } else {
             Quad(a, b, c, d)
         }
@@ -77,59 +79,70 @@ impl Quad {
         let mut c = 0.0;
         let mut d = 0.0;
 
-        if (a - self.0).abs() < f64::EPSILON {
+        if a - self.0 == 0.0 {
             b = self.1.ceil();
-            if (b - self.1).abs() < f64::EPSILON {
+            if b - self.1 == 0.0 {
                 c = self.2.ceil();
-                if (c - self.2).abs() < f64::EPSILON {
+                if c - self.2 == 0.0 {
                     d = self.3.ceil();
                 }
             }
             let (a, b, c, d) = u::renorm4(a, b, c, d);
-            Quad(a, b, c, d)
+            // See `floor`
+            Quad(if a == 0.0 { 0f64.copysign(self.0) } else { a }, b, c, d)
         } else {
             Quad(a, b, c, d)
         }
     }
 
-    /// Returns the nearest integer value to the `Double`. Half-way cases are rounded away
+    /// Returns the nearest integer value to the `Quad`. Half-way cases are rounded away
     /// from `0.0`, per the behavior of `f64`'s `round` method.
     ///
+    /// The whole number is rounded, not just its first component, so a number just below or
+    /// above a half-way point rounds the way that it should even when its first component
+    /// is exactly on that point.
+    ///
     /// # Examples
     /// ```
     /// # use qd::qd;
     /// let f = qd!(3.3);
-    /// let g = qd!(-3.3);
+    /// let g = qd!(3.5);
+    /// let h = qd!(-3.3);
     ///
     /// assert!(f.round() == qd!(3));
-    /// assert!(g.round() == qd!(-3));
+    /// assert!(g.round() == qd!(4));
+    /// assert!(h.round() == qd!(-3));
+    ///
+    /// // Just below 2.5, though its first component is 2.5 exactly
+    /// let x = qd!(2.5) - qd!(1e-20);
+    /// assert!(x[0] == 2.5 && x.round() == qd!(2));
     /// ```
-    #[inline]
     pub fn round(self) -> Quad {
-        let a = self.0.round();
-        if (a - self.0).abs() < f64::EPSILON {
-            let b = self.1.round();
-            if (b - self.1).abs() < f64::EPSILON {
-                let c = self.2.round();
-                if (c - self.2).abs() < f64::EPSILON {
-                    let d = self.3.round();
-                    let (a, b, c, d) = u::renorm4(a, b, c, d);
-                    Quad(a, b, c, d)
-                } else if ((c - self.2).abs() - 0.5).abs() < f64::EPSILON && self.3 < 0.0 {
-                    Quad(a, b, c - 1.0, 0.0)
-                } else {
-                    Quad(a, b, c, 0.0)
-                }
-            } else if ((b - self.1).abs() - 0.5).abs() < f64::EPSILON && self.2 < 0.0 {
-                Quad(a, b - 1.0, 0.0, 0.0)
-            } else {
-                Quad(a, b, 0.0, 0.0)
-            }
-        } else if ((a - self.0).abs() - 0.5).abs() < f64::EPSILON && self.1 < 0.0 {
-            Quad(a - 1.0, 0.0, 0.0, 0.0)
-        } else {
-            Quad(a, 0.0, 0.0, 0.0)
-        }
+        let mut p = [self.0, self.1, self.2, self.3];
+        r::round_to_integer(&mut p, false);
+        c::from_components(p[0], p[1], p[2], p[3])
+    }
+
+    /// Returns the nearest integer value to the `Quad`. Half-way cases are rounded to the
+    /// even integer, per the behavior of `f64`'s `round_ties_even` method.
+    ///
+    /// Like [`round`], this rounds the whole number, so only a number that's exactly
+    /// half-way between two integers is a tie.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// assert!(qd!(2.5).round_ties_even() == qd!(2));
+    /// assert!(qd!(3.5).round_ties_even() == qd!(4));
+    /// assert!(qd!(-2.5).round_ties_even() == qd!(-2));
+    /// assert!((qd!(2.5) + qd!(1e-20)).round_ties_even() == qd!(3));
+    /// ```
+    ///
+    /// [`round`]: #method.round
+    pub fn round_ties_even(self) -> Quad {
+        let mut p = [self.0, self.1, self.2, self.3];
+        r::round_to_integer(&mut p, true);
+        c::from_components(p[0], p[1], p[2], p[3])
     }
 
     /// Returns the integer part of the `Quad`.
@@ -171,6 +184,52 @@ impl Quad {
         self - self.trunc()
     }
 
+    /// Splits the `Quad` into its integer and fractional parts, returned in that order.
+    ///
+    /// The integer part is the same as [`trunc`], and the fractional part is what's left
+    /// over, so both have the sign of the `Quad` and the fractional part is in (-1, 1).
+    /// Unlike the subtraction in [`fract`], which can round away the last bit, the
+    /// fractional part is calculated exactly: it's made up of bits of the `Quad` itself, so
+    /// it can always be represented, and the two parts add up exactly to the `Quad`.
+    ///
+    /// The special cases are those of C's `modf`. A fractional part of zero has the sign of
+    /// the `Quad`, so `-3` splits into -3 and -0. An infinity splits into itself and a zero
+    /// of the same sign, and NaN splits into two NaNs.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let (int, frac) = qd!(-3.75).modf();
+    /// assert!(int == qd!(-3));
+    /// assert!(frac == qd!(-0.75));
+    ///
+    /// let (int, frac) = Quad::PI.modf();
+    /// assert!(int == qd!(3));
+    /// assert!((frac - (Quad::PI - qd!(3))).abs() < qd!(1e-60));
+    ///
+    /// let (int, frac) = Quad::NEG_INFINITY.modf();
+    /// assert!(int == Quad::NEG_INFINITY);
+    /// assert!(frac.is_zero() && frac.is_sign_negative());
+    /// ```
+    ///
+    /// [`trunc`]: #method.trunc
+    /// [`fract`]: #method.fract
+    pub fn modf(self) -> (Quad, Quad) {
+        if self.is_nan() {
+            (Quad::NAN, Quad::NAN)
+        } else if self.is_infinite() {
+            (self, Quad::ZERO.copysign(self))
+        } else {
+            // The integer part is exact, so the difference is the exact sum of the
+            // components of both. A nonzero difference already has the right sign.
+            let int = self.trunc();
+            let frac = Quad::from_sum(&[
+                self.0, self.1, self.2, self.3, -int.0, -int.1, -int.2, -int.3,
+            ]);
+            (int, frac.copysign(self))
+        }
+    }
+
     /// Returns a number that represents the sign of the `Quad`.
     ///
     /// * `1.0` if the number is positive, including `+0.0` and [`INFINITY`]
@@ -199,6 +258,30 @@ impl Quad {
         }
     }
 
+    /// Returns a `Quad` with the magnitude of this one and the sign of `sign`.
+    ///
+    /// Only the sign changes. When it has to flip, every component is negated, so the
+    /// result is exactly the negation of the `Quad`. The sign that's copied is the sign bit
+    /// of `sign`, so `sign` can be a signed zero or even a NaN. A NaN stays a NaN, taking
+    /// the new sign.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(3.5).copysign(qd!(-0.25)) == qd!(-3.5));
+    /// assert!(qd!(-3.5).copysign(Quad::ZERO) == qd!(3.5));
+    /// assert!(Quad::ONE.copysign(Quad::NEG_ZERO) == Quad::NEG_ONE);
+    /// assert!(Quad::NAN.copysign(Quad::NEG_ONE).is_sign_negative());
+    /// ```
+    #[inline]
+    pub fn copysign(self, sign: Quad) -> Quad {
+        if self.is_sign_negative() == sign.is_sign_negative() {
+            self
+        } else {
+            -self
+        }
+    }
+
     /// Returns the floating point category of the `Quad`.
     ///
     /// The possible return values are the members of [`FpCategory`], as follows:
@@ -206,10 +289,16 @@ impl Quad {
     /// * `FpCategory::Zero` if the number is ±0;
     /// * `FpCategory::Infinite` if the number is ±∞;
     /// * `FpCategory::Nan` if the number is not a number;
-    /// * `FpCategory::Subnormal` if the number is ±[`MIN_POSITIVE`] (numbers this small can
-    ///     be represented, but they lose some accuracy);
+    /// * `FpCategory::Subnormal` if the number's magnitude is less than `f64::MIN_POSITIVE`
+    ///     (numbers this small can be represented, but they lose some accuracy);
     /// * `FpCategory::Normal` if the number is anything else.
     ///
+    /// The category is that of the first component, which is the number rounded to an
+    /// `f64`. The other components don't affect it, even when they're subnormal
+    /// themselves. That means that a `Quad` smaller than [`MIN_POSITIVE`], whose last
+    /// components are too small to have their full precision, is still `Normal` as long as
+    /// its first component is.
+    ///
     /// # Examples
     /// ```
     /// # use qd::{qd, Quad};
@@ -262,7 +351,7 @@ impl Quad {
     /// assert!(!Quad::PI.is_zero());
     /// ```
     #[inline]
-    pub fn is_zero(self) -> bool {
+    pub const fn is_zero(self) -> bool {
         self.0 == 0.0
     }
 
@@ -279,7 +368,7 @@ impl Quad {
     /// assert!(!qd!(7.0).is_sign_negative());
     /// ```
     #[inline]
-    pub fn is_sign_negative(self) -> bool {
+    pub const fn is_sign_negative(self) -> bool {
         self.0.is_sign_negative()
     }
 
@@ -296,7 +385,7 @@ impl Quad {
     /// assert!(!qd!(-7.0).is_sign_positive());
     /// ```
     #[inline]
-    pub fn is_sign_positive(self) -> bool {
+    pub const fn is_sign_positive(self) -> bool {
         self.0.is_sign_positive()
     }
 
@@ -312,7 +401,7 @@ impl Quad {
     /// assert!(!qd!(7.0).is_nan());
     /// ```
     #[inline]
-    pub fn is_nan(self) -> bool {
+    pub const fn is_nan(self) -> bool {
         self.0.is_nan()
     }
 
@@ -327,7 +416,7 @@ impl Quad {
     /// assert!(!qd!(7.0).is_infinite());
     /// ```
     #[inline]
-    pub fn is_infinite(self) -> bool {
+    pub const fn is_infinite(self) -> bool {
         self.0.is_infinite()
     }
 
@@ -342,11 +431,12 @@ impl Quad {
     /// assert!(qd!(7.0).is_finite());
     /// ```
     #[inline]
-    pub fn is_finite(self) -> bool {
+    pub const fn is_finite(self) -> bool {
         self.0.is_finite()
     }
 
-    /// Returns `true` if the `Quad` has an absolute value of less than [`MIN_POSITIVE`].
+    /// Returns `true` if the `Quad` has an absolute value of less than `f64::MIN_POSITIVE`,
+    /// so that its first component is subnormal.
     ///
     /// Numbers this small can be represented by floating point numbers, but they are not as
     /// accurate. This inaccuracy is inherent in the IEEE-754 format for 64-bit numbers;
@@ -359,17 +449,308 @@ impl Quad {
     /// assert!(!Quad::PI.is_subnormal());
     /// assert!(qd!(1e-308).is_subnormal());
     /// ```
-    ///
-    /// [`MIN_POSITIVE`]: #associatedconstant.MIN_POSITIVE
     #[inline]
     pub fn is_subnormal(self) -> bool {
         self.classify() == FpCategory::Subnormal
     }
+
+    /// Returns `true` if the components of the `Quad` are normalized.
+    ///
+    /// A `Quad` is the sum of its components, and it's normalized when each component is
+    /// no more than half an ULP of the one before it. Then the components don't overlap,
+    /// the first is the whole number rounded to an `f64`, and any zero components come
+    /// last. Only the first component may be infinite or NaN, and when it is, the rest are
+    /// zero.
+    ///
+    /// Every `Quad` that comes out of this library is normalized, so this is mostly a
+    /// diagnostic for `Quad`s that are put together from components with [`new`], which
+    /// doesn't check. The functions in this library assume that their arguments are
+    /// normalized and may return nonsense for ones that aren't.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::PI.is_normalized());
+    /// assert!((qd!(1) / qd!(3)).is_normalized());
+    /// assert!(Quad::NAN.is_normalized());
+    ///
+    /// // Components that overlap, a zero out of place, and a NaN out of place
+    /// assert!(!Quad::new(1.0, 1.0, 0.0, 0.0).is_normalized());
+    /// assert!(!Quad::new(1.0, 0.0, 1e-40, 0.0).is_normalized());
+    /// assert!(!Quad::new(1.0, 1e-20, f64::NAN, 0.0).is_normalized());
+    /// ```
+    ///
+    /// [`new`]: #method.new
+    #[inline]
+    pub fn is_normalized(self) -> bool {
+        if self.0.is_finite() {
+            c::is_normalized(self.0, self.1, self.2, self.3)
+        } else {
+            self.1 == 0.0 && self.2 == 0.0 && self.3 == 0.0
+        }
+    }
+
+    /// Returns `true` if the `Quad` is an integer with an absolute value no greater than
+    /// [`MAX_SAFE_INTEGER`].
+    ///
+    /// Every integer in that range is represented exactly, so arithmetic on safe integers
+    /// is exact as long as the result is also safe. Some larger integers, like
+    /// 2<sup>212</sup> + 1, are represented exactly as well, but they are not safe because
+    /// some of their neighbors are not.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(-42).is_safe_integer());
+    /// assert!(Quad::MAX_SAFE_INTEGER.is_safe_integer());
+    /// assert!(!(Quad::MAX_SAFE_INTEGER + qd!(1)).is_safe_integer());
+    /// assert!(!qd!(0.5).is_safe_integer());
+    /// assert!(!Quad::INFINITY.is_safe_integer());
+    /// ```
+    ///
+    /// [`MAX_SAFE_INTEGER`]: #associatedconstant.MAX_SAFE_INTEGER
+    pub fn is_safe_integer(self) -> bool {
+        // In a normalized `Quad`, a component has no fractional part unless it's smaller
+        // than 2^52, and in that case the components after it are too small to cancel it
+        // out. So the number is an integer only if all of its components are.
+        self.is_finite()
+            && self.0.fract() == 0.0
+            && self.1.fract() == 0.0
+            && self.2.fract() == 0.0
+            && self.3.fract() == 0.0
+            && self.abs() <= Quad::MAX_SAFE_INTEGER
+    }
+
+    /// Creates a `Quad` with exactly the value of an `i128`, as long as that value is
+    /// between -[`MAX_SAFE_INTEGER`] and [`MAX_SAFE_INTEGER`]. Otherwise, returns `None`.
+    ///
+    /// Every `i128` is within that range, so this always succeeds. It exists to match
+    /// [`Double::checked_from_safe_integer`]; `Quad::from` does the same thing.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let x = Quad::checked_from_safe_integer(i128::MIN).unwrap();
+    /// assert!(x == Quad::from(i128::MIN));
+    /// assert!(x.is_safe_integer());
+    /// ```
+    ///
+    /// [`MAX_SAFE_INTEGER`]: #associatedconstant.MAX_SAFE_INTEGER
+    /// [`Double::checked_from_safe_integer`]: struct.Double.html#method.checked_from_safe_integer
+    pub fn checked_from_safe_integer(n: i128) -> Option<Quad> {
+        Some(Quad::from(n))
+    }
+
+    /// Returns the smallest `Quad` that's larger than this one.
+    ///
+    /// The neighbors of a `Quad` aren't evenly spaced the way that the neighbors of an
+    /// `f64` are. A normalized `Quad` is four `f64` components, each no more than half the
+    /// ULP of the one before it, and the next one up usually differs only in its last
+    /// nonzero component, which moves up to the next `f64`. When that component would be
+    /// too large for the one before it, the next `Quad` carries into the components before
+    /// it instead. So the gap depends on the last component: a `Quad` with a last component
+    /// of zero, like 1, is only 2<sup>-1074</sup> (the smallest subnormal `f64`) from its
+    /// neighbors, since that can take the place of its first zero component, while one
+    /// with all four components as large as they can be is roughly 2<sup>-211</sup> times
+    /// its own size from them. The gap is always a power of 2, and [`ulp`] returns it.
+    ///
+    /// Because of the way that normalization breaks ties, a few numbers have two sets of
+    /// normalized components. Their neighbors are found from the set that ends in more
+    /// zeros (and are closer for it), and going back from a neighbor gives the number with
+    /// that set of components.
+    ///
+    /// The next `Quad` up from zero (of either sign) is that smallest subnormal `f64`,
+    /// about 4.94 &times; 10<sup>-324</sup>, which is far smaller than [`MIN_POSITIVE`].
+    /// The next one up from [`MAX`] is infinity, the next one up from negative infinity is
+    /// -[`MAX`], and positive infinity and NaN are returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let tiny = Quad::new(f64::from_bits(1), 0.0, 0.0, 0.0);
+    /// assert!(Quad::ZERO.next_up() == tiny);
+    /// assert!(Quad::ONE.next_up() == Quad::ONE + tiny);
+    ///
+    /// let x = qd!(1) / qd!(3);
+    /// assert!(x.next_up() > x && x.next_up().next_down() == x);
+    /// assert!(Quad::MAX.next_up() == Quad::INFINITY);
+    /// ```
+    ///
+    /// [`ulp`]: #method.ulp
+    /// [`MIN_POSITIVE`]: #associatedconstant.MIN_POSITIVE
+    /// [`MAX`]: #associatedconstant.MAX
+    pub fn next_up(self) -> Quad {
+        if self.is_nan() || self == Quad::INFINITY {
+            self
+        } else if self == Quad::NEG_INFINITY {
+            -Quad::MAX
+        } else {
+            let (parts, _) = u::next_up(&[self.0, self.1, self.2, self.3]);
+            if parts[0] == 0.0 {
+                // Only a negative number can have zero as its next number up, and zero
+                // keeps the sign of the number it came from, as it does for `f64`s
+                Quad::NEG_ZERO
+            } else {
+                Quad(parts[0], parts[1], parts[2], parts[3])
+            }
+        }
+    }
+
+    /// Returns the largest `Quad` that's smaller than this one.
+    ///
+    /// This mirrors [`next_up`], so `x.next_down()` is the same as `-(-x).next_up()`. The
+    /// next `Quad` down from zero is the negative of the smallest subnormal `f64`, the next
+    /// one down from -[`MAX`] is negative infinity, and negative infinity and NaN are
+    /// returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::PI;
+    /// assert!(x.next_down() < x && x.next_down().next_up() == x);
+    /// assert!(Quad::ZERO.next_down() == -Quad::ZERO.next_up());
+    /// assert!(Quad::INFINITY.next_down() == Quad::MAX);
+    /// ```
+    ///
+    /// [`next_up`]: #method.next_up
+    /// [`MAX`]: #associatedconstant.MAX
+    #[inline]
+    pub fn next_down(self) -> Quad {
+        -(-self).next_up()
+    }
+
+    /// Returns the distance from this `Quad` to the next larger one, [`next_up`].
+    ///
+    /// This is a power of 2, and it's set by the last nonzero component: it's that
+    /// component's ULP as an `f64`, or half of that, unless the last component is zero, in
+    /// which case it's the smallest subnormal `f64`. See [`next_up`] for why the spacing is
+    /// so uneven. For the spacing that the precision of a `Quad` allows, rather than the
+    /// spacing of the values it can represent, multiply its absolute value by [`EPSILON`].
+    ///
+    /// The ULP of [`MAX`] and of either infinity is infinite, and the ULP of NaN is NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::ONE.ulp() == Quad::new(f64::from_bits(1), 0.0, 0.0, 0.0));
+    ///
+    /// let x = qd!(1) / qd!(3);
+    /// assert!(x + x.ulp() == x.next_up());
+    /// ```
+    ///
+    /// [`next_up`]: #method.next_up
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    /// [`MAX`]: #associatedconstant.MAX
+    pub fn ulp(self) -> Quad {
+        if self.is_nan() {
+            Quad::NAN
+        } else if self.is_infinite() {
+            Quad::INFINITY
+        } else {
+            let (parts, gap) = u::next_up(&[self.0, self.1, self.2, self.3]);
+            if parts[0].is_infinite() {
+                Quad::INFINITY
+            } else {
+                Quad(gap, 0.0, 0.0, 0.0)
+            }
+        }
+    }
+
+    /// Calculates the absolute value of the difference between this `Quad` and another.
+    ///
+    /// This is the same as `(self - other).abs()`, so it's NaN if either argument is NaN
+    /// or if both are infinities of the same sign.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(3).abs_diff(qd!(5)) == qd!(2));
+    /// assert!(qd!(5).abs_diff(qd!(3)) == qd!(2));
+    /// assert!(Quad::NAN.abs_diff(qd!(1)).is_nan());
+    /// ```
+    #[inline]
+    pub fn abs_diff(self, other: Quad) -> Quad {
+        (self - other).abs()
+    }
+
+    /// Returns the positive difference between this `Quad` and another: `self - other` if
+    /// that's greater than zero, and zero otherwise.
+    ///
+    /// This is C's `fdim`, which is what the deprecated `f64::abs_sub` computes despite its
+    /// name; for the absolute value of the difference, use [`abs_diff`]. It's NaN if either
+    /// argument is NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(5).abs_sub(qd!(3)) == qd!(2));
+    /// assert!(qd!(3).abs_sub(qd!(5)) == Quad::ZERO);
+    /// assert!(Quad::NAN.abs_sub(qd!(1)).is_nan());
+    /// ```
+    ///
+    /// [`abs_diff`]: #method.abs_diff
+    #[inline]
+    pub fn abs_sub(self, other: Quad) -> Quad {
+        if self <= other {
+            Quad::ZERO
+        } else {
+            self - other
+        }
+    }
+
+    /// Determines whether this `Quad` and another are equal to within `ulps` units of
+    /// [`EPSILON`], relative to their size.
+    ///
+    /// The two are compared as if both were scaled by the same power of 2, the one that
+    /// brings the larger of them to between 1 and 2, so `ulps` means the same thing at
+    /// every magnitude: `x.approx_eq(y, n)` gives the same answer as
+    /// `x.ldexp(k).approx_eq(y.ldexp(k), n)` for any `k` that keeps both exact. That makes
+    /// this a better test for computed values than comparing `(x - y).abs()` to a fixed
+    /// tolerance, which is too loose for small numbers and too strict for large ones. An
+    /// `ulps` of 0 is exact equality.
+    ///
+    /// Zero has no size for the other number to be relative to, so a comparison with zero
+    /// measures the other number against 1 instead; it's equal to zero if its absolute
+    /// value is no more than `ulps` times [`EPSILON`]. Infinities are equal only to
+    /// infinities of the same sign, and NaN isn't equal to anything.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let third = qd!(1) / qd!(3);
+    /// assert!((third * qd!(3)).approx_eq(qd!(1), 1));
+    /// assert!(!(third * qd!(3)).approx_eq(qd!(1.01), 1000));
+    ///
+    /// // The tolerance scales with the numbers being compared
+    /// let big = qd!("1e300");
+    /// assert!((third * big).approx_eq(big / qd!(3), 2));
+    ///
+    /// assert!((Quad::PI.sin()).approx_eq(Quad::ZERO, 4));
+    /// assert!(!Quad::NAN.approx_eq(Quad::NAN, 4));
+    /// ```
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    pub fn approx_eq(self, other: Quad, ulps: u32) -> bool {
+        let tolerance = Quad::EPSILON * Quad::from(ulps);
+        if self.is_nan() || other.is_nan() {
+            false
+        } else if self.is_infinite() || other.is_infinite() {
+            self == other
+        } else if self.is_zero() || other.is_zero() {
+            (self - other).abs() <= tolerance
+        } else {
+            // Scaling by a power of 2 is exact (aside from bits of the smaller number that
+            // are too small to matter), and unlike scaling the tolerance, it can't underflow
+            let e = -u::exponent(self.0.abs().max(other.0.abs()));
+            (self.ldexp(e) - other.ldexp(e)).abs() <= tolerance
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::random::Rng;
     use std::num::FpCategory::*;
 
     // abs tests
@@ -414,6 +795,12 @@ mod tests {
         floor_int:
             qd!(2),
             qd!(2).floor();
+        floor_tiny:
+            qd!(0),
+            Quad(1e-40, -1e-57, 0.0, 0.0).floor();
+        floor_tiny_tail:
+            qd!(5),
+            Quad(5.0, 1e-40, -1e-57, 0.0).floor();
 
         floor_zero:
             Quad::ZERO,
@@ -449,6 +836,12 @@ mod tests {
         ceil_int:
             qd!(2),
             qd!(2).ceil();
+        ceil_tiny:
+            qd!(0),
+            Quad(-1e-40, 1e-57, 0.0, 0.0).ceil();
+        ceil_tiny_tail:
+            qd!(-5),
+            Quad(-5.0, -1e-40, 1e-57, 0.0).ceil();
 
         ceil_zero:
             Quad::ZERO,
@@ -592,6 +985,139 @@ mod tests {
             Quad::NAN.fract();
     );
 
+    // modf tests
+    test_all_eq!(
+        modf_pi:
+            (qd!(3), Quad::PI - qd!(3)),
+            Quad::PI.modf();
+        modf_neg:
+            (qd!(-3), qd!(-0.75)),
+            qd!(-3.75).modf();
+        modf_small:
+            (Quad::ZERO, qd!(0.25)),
+            qd!(0.25).modf();
+        modf_int:
+            (qd!(2), Quad::ZERO),
+            qd!(2).modf();
+        modf_huge:
+            (Quad(2f64.powi(220), 2f64.powi(166), 2f64.powi(113), -1.0), qd!(0.75)),
+            Quad(2f64.powi(220), 2f64.powi(166), 2f64.powi(113), -0.25).modf();
+        modf_huge_int:
+            (qd!(1e200), Quad::ZERO),
+            qd!(1e200).modf();
+    );
+    test!(modf_special: {
+        let (int, frac) = qd!(-3).modf();
+        assert!(int == qd!(-3) && frac.is_zero() && frac.is_sign_negative());
+        let (int, frac) = qd!(-0.5).modf();
+        assert!(int.is_zero() && int.is_sign_negative() && frac == qd!(-0.5));
+        let (int, frac) = Quad::NEG_ZERO.modf();
+        assert!(int.is_sign_negative() && frac.is_zero() && frac.is_sign_negative());
+        let (int, frac) = Quad::INFINITY.modf();
+        assert!(int == Quad::INFINITY && frac.is_zero() && frac.is_sign_positive());
+        let (int, frac) = Quad::NEG_INFINITY.modf();
+        assert!(int == Quad::NEG_INFINITY && frac.is_zero() && frac.is_sign_negative());
+        let (int, frac) = Quad::NAN.modf();
+        assert!(int.is_nan() && frac.is_nan());
+    });
+    // The parts are checked by adding their components exactly, since `+` is allowed to
+    // round away the last bit even of a sum that can be represented
+    test!(modf_random: {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        for i in 0..100_000 {
+            let e = (i % 240) - 20;
+            let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
+            let x = Quad::ONE / Quad::from(sign * (rng.next_f64() + 0.5));
+            let x = x.ldexp(e);
+            let (int, frac) = x.modf();
+            assert!(Quad::from_sum(&[int[0], int[1], int[2], int[3], frac[0], frac[1], frac[2], frac[3]]) == x, "{:?}.modf() = ({:?}, {:?})", x, int, frac);
+            assert!(int == x.trunc() && int.fract().is_zero());
+            assert!(frac.abs() < Quad::ONE && (frac.is_zero() || frac.is_sign_negative() == x.is_sign_negative()));
+        }
+    });
+
+    // round_ties_even tests
+    test_all_exact!(
+        round_ties_even_pi:
+            qd!(3),
+            Quad::PI.round_ties_even();
+        round_ties_even_half_down:
+            qd!(2),
+            qd!(2.5).round_ties_even();
+        round_ties_even_half_up:
+            qd!(4),
+            qd!(3.5).round_ties_even();
+        round_ties_even_neg_half:
+            qd!(-2),
+            qd!(-2.5).round_ties_even();
+        round_ties_even_neg_half_up:
+            qd!(-4),
+            qd!(-3.5).round_ties_even();
+        round_ties_even_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.round_ties_even();
+        round_ties_even_nan:
+            Quad::NAN,
+            Quad::NAN.round_ties_even();
+    );
+
+    // Numbers with components exactly on an integer or a half-way point, while the
+    // components after them put the whole number just above or below it
+    const B: f64 = 8.673617379884035e-19; // 2^-60
+
+    fn big(tail: f64) -> Quad {
+        Quad(2f64.powi(120), 2f64.powi(60), 0.0, tail)
+    }
+
+    test_all_assert!(
+        boundary_floor:
+            Quad(3.0, -B, 0.0, 0.0).floor() == qd!(2) && Quad(3.0, B, 0.0, 0.0).floor() == qd!(3);
+        boundary_floor_neg:
+            Quad(-3.0, -B, 0.0, 0.0).floor() == qd!(-4) && Quad(-3.0, B, 0.0, 0.0).floor() == qd!(-3);
+        boundary_floor_last:
+            big(-B).floor() == big(0.0) - Quad::ONE && big(B).floor() == big(0.0);
+        boundary_ceil:
+            Quad(3.0, -B, 0.0, 0.0).ceil() == qd!(3) && Quad(3.0, B, 0.0, 0.0).ceil() == qd!(4);
+        boundary_ceil_neg:
+            Quad(-3.0, -B, 0.0, 0.0).ceil() == qd!(-3) && Quad(-3.0, B, 0.0, 0.0).ceil() == qd!(-2);
+        boundary_ceil_last:
+            big(-B).ceil() == big(0.0) && big(B).ceil() == big(0.0) + Quad::ONE;
+        boundary_trunc:
+            Quad(3.0, -B, 0.0, 0.0).trunc() == qd!(2) && Quad(-3.0, B, 0.0, 0.0).trunc() == qd!(-2)
+                && big(-B).trunc() == big(0.0) - Quad::ONE;
+        boundary_fract:
+            Quad(3.0, -B, 0.0, 0.0).fract() == Quad(1.0, -B, 0.0, 0.0)
+                && Quad(-3.0, B, 0.0, 0.0).fract() == Quad(-1.0, B, 0.0, 0.0)
+                && big(-B).fract() == Quad(1.0, -B, 0.0, 0.0);
+        boundary_round_tie:
+            Quad(2.5, -B, 0.0, 0.0).round() == qd!(2) && Quad(2.5, B, 0.0, 0.0).round() == qd!(3);
+        boundary_round_neg_tie:
+            Quad(-2.5, B, 0.0, 0.0).round() == qd!(-2) && Quad(-2.5, -B, 0.0, 0.0).round() == qd!(-3);
+        boundary_round_below_half:
+            same(Quad(0.5 - f64::EPSILON / 4.0, -B, 0.0, 0.0).round(), Quad::ZERO)
+                && same(Quad(-0.5 + f64::EPSILON / 4.0, B, 0.0, 0.0).round(), Quad::NEG_ZERO);
+        boundary_round_neg_zero:
+            same(qd!(-0.3).round(), Quad::NEG_ZERO) && same(qd!(-0.3).round_ties_even(), Quad::NEG_ZERO);
+        boundary_round_deep_tie:
+            Quad(2f64.powi(120), 2f64.powi(60), -0.5, B).round() == big(0.0)
+                && Quad(2f64.powi(120), 2f64.powi(60), -0.5, -B).round() == big(0.0) - Quad::ONE
+                && Quad(2f64.powi(120), 2f64.powi(60), 0.5, 0.0).round() == big(0.0) + Quad::ONE;
+        boundary_round_neg_deep_tie:
+            (-Quad(2f64.powi(120), 2f64.powi(60), 0.5, 0.0)).round() == -big(0.0) - Quad::ONE
+                && (-Quad(2f64.powi(120), 2f64.powi(60), -0.5, 0.0)).round() == -big(0.0);
+        boundary_round_ties_even:
+            Quad(2.5, -B, 0.0, 0.0).round_ties_even() == qd!(2)
+                && Quad(2.5, B, 0.0, 0.0).round_ties_even() == qd!(3)
+                && Quad(-2.5, -B, 0.0, 0.0).round_ties_even() == qd!(-3);
+        boundary_round_ties_even_deep:
+            Quad(2f64.powi(120), 2f64.powi(60), 0.5, 0.0).round_ties_even() == big(0.0)
+                && Quad(2f64.powi(120), 2f64.powi(60), 1.5, 0.0).round_ties_even()
+                    == big(0.0) + qd!(2)
+                && Quad(2f64.powi(120), 2f64.powi(60), -0.5, 0.0).round_ties_even() == big(0.0)
+                && Quad(2f64.powi(120), 2f64.powi(60), 0.5, B).round_ties_even()
+                    == big(0.0) + Quad::ONE;
+    );
+
     // signum tests
     test_all_exact!(
         signum_pi:
@@ -656,6 +1182,21 @@ mod tests {
         classify_sub:
             qd!(1e-308).classify(),
             Subnormal;
+        classify_min_positive:
+            Quad::MIN_POSITIVE.classify(),
+            Normal;
+        classify_half_min_positive:
+            Quad::from(f64::MIN_POSITIVE / 2.0).classify(),
+            Subnormal;
+        classify_below_min_positive:
+            qd!(1e-280).classify(),
+            Normal;
+        classify_subnormal_tail:
+            Quad::new(1e-290, 1e-307, 5e-324, 0.0).classify(),
+            Normal;
+        classify_third:
+            (qd!(1) / qd!(3)).classify(),
+            Normal;
     );
 
     // is_normal tests
@@ -817,4 +1358,330 @@ mod tests {
         is_subnormal_sub:
             qd!(1e-308).is_subnormal();
     );
+
+    // is_normalized tests
+    test_all_assert!(
+        is_normalized_pi:
+            Quad::PI.is_normalized();
+        is_normalized_e:
+            Quad::E.is_normalized();
+        is_normalized_ln_2:
+            Quad::LN_2.is_normalized();
+        is_normalized_max:
+            Quad::MAX.is_normalized();
+        is_normalized_min:
+            Quad::MIN.is_normalized();
+        is_normalized_min_positive:
+            Quad::MIN_POSITIVE.is_normalized();
+        is_normalized_epsilon:
+            Quad::EPSILON.is_normalized();
+        is_normalized_zero:
+            Quad::ZERO.is_normalized();
+        is_normalized_neg_zero:
+            Quad::NEG_ZERO.is_normalized();
+        is_normalized_inf:
+            Quad::INFINITY.is_normalized();
+        is_normalized_neg_inf:
+            Quad::NEG_INFINITY.is_normalized();
+        is_normalized_nan:
+            Quad::NAN.is_normalized();
+        is_normalized_half_ulp_even:
+            Quad::new(1.0, f64::EPSILON / 2.0, 0.0, 0.0).is_normalized();
+        is_normalized_subnormal_tail:
+            Quad::new(1e-290, 1e-307, 5e-324, 0.0).is_normalized();
+        is_normalized_neg_zero_tail:
+            Quad::new(1.0, 1e-20, -0.0, -0.0).is_normalized();
+
+        is_normalized_third:
+            (qd!(1) / qd!(3)).is_normalized();
+        is_normalized_sqrt:
+            Quad::PI.sqrt().is_normalized();
+        is_normalized_exp:
+            qd!(-7.5).exp().is_normalized();
+        is_normalized_cancellation:
+            (Quad::PI - qd!(3)).is_normalized();
+        is_normalized_small_quotient:
+            (qd!(1e-300) / qd!(3)).is_normalized();
+        is_normalized_overflow:
+            (Quad::MAX * qd!(2)).is_normalized();
+        is_normalized_invalid:
+            (Quad::ZERO / Quad::ZERO).is_normalized();
+
+        is_normalized_overlap:
+            !Quad::new(1.0, 1.0, 0.0, 0.0).is_normalized();
+        is_normalized_overlap_last:
+            !Quad::new(1.0, 1e-20, 1e-40, 1e-40).is_normalized();
+        is_normalized_full_ulp:
+            !Quad::new(1.0, f64::EPSILON, 0.0, 0.0).is_normalized();
+        is_normalized_over_half_ulp_last:
+            !Quad::new(1.0, 1e-20, 1e-40, 2e-56).is_normalized();
+        is_normalized_zero_first:
+            !Quad::new(0.0, 1e-20, 0.0, 0.0).is_normalized();
+        is_normalized_zero_between:
+            !Quad::new(1.0, 0.0, 1e-40, 0.0).is_normalized();
+        is_normalized_nan_tail:
+            !Quad::new(1.0, 1e-20, f64::NAN, 0.0).is_normalized();
+        is_normalized_inf_tail:
+            !Quad::new(1.0, 1e-20, 1e-40, f64::INFINITY).is_normalized();
+        is_normalized_nan_with_tail:
+            !Quad::new(f64::NAN, 0.0, 0.0, 1.0).is_normalized();
+        is_normalized_inf_with_tail:
+            !Quad::new(f64::INFINITY, 1.0, 0.0, 0.0).is_normalized();
+    );
+
+    // is_safe_integer tests
+    test_all_assert!(
+        is_safe_integer_zero:
+            Quad::ZERO.is_safe_integer();
+        is_safe_integer_neg_zero:
+            Quad::NEG_ZERO.is_safe_integer();
+        is_safe_integer_max:
+            Quad::MAX_SAFE_INTEGER.is_safe_integer();
+        is_safe_integer_neg_max:
+            (-Quad::MAX_SAFE_INTEGER).is_safe_integer();
+        is_safe_integer_max_minus_one:
+            (Quad::MAX_SAFE_INTEGER - Quad::ONE).is_safe_integer();
+        is_safe_integer_i128_max:
+            Quad::from(i128::MAX).is_safe_integer();
+        is_safe_integer_i128_min:
+            Quad::from(i128::MIN).is_safe_integer();
+        is_safe_integer_max_plus_one:
+            !(Quad::MAX_SAFE_INTEGER + Quad::ONE).is_safe_integer();
+        is_safe_integer_neg_max_minus_one:
+            !(-Quad::MAX_SAFE_INTEGER - Quad::ONE).is_safe_integer();
+        is_safe_integer_fraction_in_last:
+            !(Quad::from(1u128 << 120) + Quad(0.5, 0.0, 0.0, 0.0)).is_safe_integer();
+        is_safe_integer_half:
+            !Quad(0.5, 0.0, 0.0, 0.0).is_safe_integer();
+        is_safe_integer_pi:
+            !Quad::PI.is_safe_integer();
+        is_safe_integer_inf:
+            !Quad::INFINITY.is_safe_integer();
+        is_safe_integer_neg_inf:
+            !Quad::NEG_INFINITY.is_safe_integer();
+        is_safe_integer_nan:
+            !Quad::NAN.is_safe_integer();
+        is_safe_integer_max_value:
+            !Quad::MAX.is_safe_integer();
+    );
+
+    test!(max_safe_integer: {
+        // 2^212 - 1 needs all four components
+        let below = Quad::MAX_SAFE_INTEGER - Quad::ONE;
+        assert_eq!(below[0], 2f64.powi(212));
+        assert_eq!(below[1], -1.0);
+        assert!(below.is_safe_integer());
+        // 2^212 + 1 can be represented exactly too, it just isn't safe
+        let above = Quad::MAX_SAFE_INTEGER + Quad::ONE;
+        assert_eq!(above[0], 2f64.powi(212));
+        assert_eq!(above[1], 1.0);
+        assert!(above.is_finite() && above > Quad::MAX_SAFE_INTEGER);
+        // Integers just under the boundary that need several components are safe
+        let ones = Quad::MAX_SAFE_INTEGER - Quad::from(1u128 << 100) * Quad::from(1u128 << 100)
+            + Quad::from(u128::MAX);
+        assert!(ones.is_safe_integer());
+        assert!(ones < Quad::MAX_SAFE_INTEGER);
+    });
+
+    test!(checked_from_safe_integer: {
+        assert_eq!(Quad::checked_from_safe_integer(0), Some(Quad::ZERO));
+        assert_eq!(Quad::checked_from_safe_integer(-7), Some(Quad::from(-7)));
+        assert_eq!(
+            Quad::checked_from_safe_integer(i128::MAX),
+            Some(Quad::from(i128::MAX))
+        );
+        assert_eq!(
+            Quad::checked_from_safe_integer(i128::MIN),
+            Some(Quad::from(i128::MIN))
+        );
+        assert_eq!(
+            Quad::checked_from_safe_integer(1 << 106),
+            Some(Quad(2f64.powi(106), 0.0, 0.0, 0.0))
+        );
+    });
+
+    // next_up, next_down, and ulp tests
+
+    // The same components, with the sign of a zero head (but not of a zero tail) mattering
+    fn same(a: Quad, b: Quad) -> bool {
+        a[0].to_bits() == b[0].to_bits() && a[1] == b[1] && a[2] == b[2] && a[3] == b[3]
+    }
+
+    const TINY: Quad = Quad(5e-324, 0.0, 0.0, 0.0);
+
+    test_all_assert!(
+        next_up_zero: same(Quad::ZERO.next_up(), TINY) && same(Quad::NEG_ZERO.next_up(), TINY);
+        next_down_zero: same(Quad::ZERO.next_down(), -TINY) && same(Quad::NEG_ZERO.next_down(), -TINY);
+        next_up_to_zero: same((-TINY).next_up(), Quad::NEG_ZERO);
+        next_down_to_zero: TINY.next_down().is_zero() && TINY.next_down().is_sign_positive();
+        next_up_zero_tail: same(Quad::ONE.next_up(), Quad(1.0, 5e-324, 0.0, 0.0));
+        next_down_zero_tail: same(Quad::ONE.next_down(), Quad(1.0, -5e-324, 0.0, 0.0));
+        next_up_zero_last: same(
+            Quad(1.0, 1e-20, 1e-40, 0.0).next_up(),
+            Quad(1.0, 1e-20, 1e-40, 5e-324)
+        );
+        next_up_max: Quad::MAX.next_up() == Quad::INFINITY;
+        next_down_max: Quad::MAX.next_down() < Quad::MAX && Quad::MAX.next_down().next_up() == Quad::MAX;
+        next_up_inf: Quad::INFINITY.next_up() == Quad::INFINITY;
+        next_down_inf: Quad::INFINITY.next_down() == Quad::MAX;
+        next_up_neg_inf: Quad::NEG_INFINITY.next_up() == -Quad::MAX;
+        next_down_neg_inf: Quad::NEG_INFINITY.next_down() == Quad::NEG_INFINITY;
+        next_up_nan: Quad::NAN.next_up().is_nan();
+        next_down_nan: Quad::NAN.next_down().is_nan();
+        ulp_zero: same(Quad::ZERO.ulp(), TINY) && same(Quad::NEG_ZERO.ulp(), TINY);
+        ulp_one: same(Quad::ONE.ulp(), TINY);
+        ulp_max: Quad::MAX.ulp() == Quad::INFINITY;
+        ulp_inf: Quad::INFINITY.ulp() == Quad::INFINITY && Quad::NEG_INFINITY.ulp() == Quad::INFINITY;
+        ulp_nan: Quad::NAN.ulp().is_nan();
+        abs_diff_pos: Quad::PI.abs_diff(Quad::E) == Quad::PI - Quad::E;
+        abs_diff_neg: Quad::E.abs_diff(Quad::PI) == Quad::PI - Quad::E;
+        abs_diff_inf: Quad::INFINITY.abs_diff(Quad::ONE) == Quad::INFINITY;
+        abs_diff_inf_inf: Quad::INFINITY.abs_diff(Quad::INFINITY).is_nan();
+        abs_diff_nan: Quad::ONE.abs_diff(Quad::NAN).is_nan();
+        copysign_flip: same((-Quad::PI).copysign(Quad::ONE), Quad::PI);
+        copysign_flip_neg: same(Quad::PI.copysign(-Quad::E), -Quad::PI);
+        copysign_keep: same(Quad::PI.copysign(Quad::E), Quad::PI);
+        copysign_neg_zero_sign: same(Quad::ONE.copysign(Quad::NEG_ZERO), Quad::NEG_ONE);
+        copysign_zero_sign: same(Quad::NEG_ONE.copysign(Quad::ZERO), Quad::ONE);
+        copysign_zero: same(Quad::ZERO.copysign(Quad::NEG_ONE), Quad::NEG_ZERO);
+        copysign_neg_zero: same(Quad::NEG_ZERO.copysign(Quad::ONE), Quad::ZERO);
+        copysign_inf: Quad::INFINITY.copysign(Quad::NEG_ZERO) == Quad::NEG_INFINITY;
+        copysign_nan_sign: same(Quad::ONE.copysign(-Quad::NAN), Quad::NEG_ONE) && same(Quad::NEG_ONE.copysign(Quad::NAN), Quad::ONE);
+        copysign_nan: Quad::NAN.copysign(Quad::NEG_ONE).is_nan() && Quad::NAN.copysign(Quad::NEG_ONE).is_sign_negative();
+        copysign_neg_nan: (-Quad::NAN).copysign(Quad::ONE).is_nan() && (-Quad::NAN).copysign(Quad::ONE).is_sign_positive();
+        abs_sub_pos: Quad::PI.abs_sub(Quad::E) == Quad::PI - Quad::E;
+        abs_sub_neg: same(Quad::E.abs_sub(Quad::PI), Quad::ZERO);
+        abs_sub_equal: same(Quad::PI.abs_sub(Quad::PI), Quad::ZERO);
+        abs_sub_inf: Quad::INFINITY.abs_sub(Quad::ONE) == Quad::INFINITY;
+        abs_sub_inf_inf: same(Quad::INFINITY.abs_sub(Quad::INFINITY), Quad::ZERO);
+        abs_sub_nan: Quad::ONE.abs_sub(Quad::NAN).is_nan() && Quad::NAN.abs_sub(Quad::ONE).is_nan();
+        approx_eq_same: Quad::PI.approx_eq(Quad::PI, 0);
+        approx_eq_exact: !Quad::PI.approx_eq(Quad::PI.next_up(), 0);
+        approx_eq_within: Quad::ONE.approx_eq(Quad::ONE + Quad::EPSILON * qd!(4), 4);
+        approx_eq_beyond: !Quad::ONE.approx_eq(Quad::ONE + Quad::EPSILON * qd!(5), 4);
+        approx_eq_sign: !Quad::ONE.approx_eq(Quad::NEG_ONE, u32::MAX);
+        approx_eq_zero: Quad::ZERO.approx_eq(Quad::NEG_ZERO, 0);
+        approx_eq_zero_within: Quad::ZERO.approx_eq(-Quad::EPSILON * qd!(3), 3);
+        approx_eq_zero_beyond: !(Quad::EPSILON * qd!(4)).approx_eq(Quad::ZERO, 3);
+        approx_eq_inf: Quad::INFINITY.approx_eq(Quad::INFINITY, 0);
+        approx_eq_inf_neg_inf: !Quad::INFINITY.approx_eq(Quad::NEG_INFINITY, u32::MAX);
+        approx_eq_inf_max: !Quad::MAX.approx_eq(Quad::INFINITY, u32::MAX);
+        approx_eq_max: Quad::MAX.approx_eq(Quad::MAX.next_down(), 1) && Quad::MAX.approx_eq(-Quad::MIN, 0);
+        approx_eq_nan: !Quad::NAN.approx_eq(Quad::NAN, u32::MAX) && !Quad::ONE.approx_eq(Quad::NAN, u32::MAX);
+    );
+
+    // The same differences, relative to the numbers being compared, give the same answers
+    // anywhere that a `Quad` has its full precision. Below that (like at 1e-300), numbers
+    // that are as close as the type can make them are still equal.
+    test!(approx_eq_scale: {
+        let x = Quad::PI / qd!(2);
+        let near = x + Quad::EPSILON * qd!(3);
+        let far = x + Quad::EPSILON * qd!(5);
+        for k in (-800..=1000).step_by(20) {
+            let (x, near, far) = (x.ldexp(k), near.ldexp(k), far.ldexp(k));
+            assert!(x.approx_eq(near, 4) && near.approx_eq(x, 4), "2^{}", k);
+            assert!(!x.approx_eq(far, 4) && !far.approx_eq(x, 4), "2^{}", k);
+            assert!(
+                (-x).approx_eq(-near, 4) && !(-x).approx_eq(-far, 4),
+                "2^{}",
+                k
+            );
+        }
+
+        let third = qd!(1) / qd!(3);
+        for &s in ["1e-300", "1e-200", "1e-100", "1", "1e100", "1e200", "1e300"].iter() {
+            let s = qd!(s);
+            assert!((third * s).approx_eq(s / qd!(3), 2), "{}", s);
+            assert!(
+                !(third * s).approx_eq(third * s * qd!("1.000000000001"), 1000),
+                "{}",
+                s
+            );
+        }
+    });
+
+    test!(next_up_carry: {
+        // 2^-120 + 2^-173 is halfway between 2^-120 and the next f64, and it's represented
+        // with a third component of 2^-120 because 2^-120 is even. Anything larger carries
+        // into the third component, and the last component is then in a lower binade, where
+        // the f64s are twice as close.
+        let x = Quad(1.0, 2f64.powi(-60), 2f64.powi(-120), 2f64.powi(-173));
+        let y = x.next_up();
+        assert!(same(
+            y,
+            Quad(
+                1.0,
+                2f64.powi(-60),
+                2f64.powi(-120) + 2f64.powi(-172),
+                -2f64.powi(-173) + 2f64.powi(-226)
+            )
+        ));
+        assert!(same(x.ulp(), Quad(2f64.powi(-226), 0.0, 0.0, 0.0)));
+        assert!(same(y.next_down(), x));
+        assert!(same(x.next_down().next_up(), x));
+
+        // A negative last component that's a power of 2 moves toward zero, into a lower
+        // binade
+        let z = Quad(1.0, 2f64.powi(-60), 2f64.powi(-120), -2f64.powi(-180));
+        assert!(same(
+            z.next_up(),
+            Quad(
+                1.0,
+                2f64.powi(-60),
+                2f64.powi(-120),
+                -2f64.powi(-180) + 2f64.powi(-233)
+            )
+        ));
+        assert!(same(
+            z.next_down(),
+            Quad(
+                1.0,
+                2f64.powi(-60),
+                2f64.powi(-120),
+                -2f64.powi(-180) - 2f64.powi(-232)
+            )
+        ));
+
+        // With ties breaking toward 2^-60 and then 2^-113, both of them even, this has
+        // another set of normalized components that ends in a zero. The neighbors are the
+        // ones next to that set, so they're only the smallest subnormal away.
+        let w = Quad(1.0, 2f64.powi(-60), 2f64.powi(-113), 2f64.powi(-166));
+        let (b, c) = (
+            2f64.powi(-60) + 2f64.powi(-112),
+            -2f64.powi(-113) + 2f64.powi(-166),
+        );
+        assert!(same(w.next_up(), Quad(1.0, b, c, 5e-324)));
+        assert!(same(w.next_down(), Quad(1.0, b, c, -5e-324)));
+        assert!(same(w.next_up().next_down(), Quad(1.0, b, c, 0.0)));
+    });
+
+    test!(next_up_round_trip: {
+        let mut rng = Rng::new(0x5851_f42d_4c95_7f2d);
+        for i in 0..10_000 {
+            let a = rng.next_f64() + 0.5;
+            let b = rng.next_f64() + 0.5;
+            let exp = rng.range(-1000, 1000);
+            let sign = if rng.next_u64() & 1 == 0 { 1.0 } else { -1.0 };
+            let x = match i % 4 {
+                0 => Quad(a, 0.0, 0.0, 0.0) / Quad(b, 0.0, 0.0, 0.0),
+                1 => Quad(a, 0.0, 0.0, 0.0),
+                2 => Quad::ONE + Quad(a, 0.0, 0.0, 0.0).ldexp(-53),
+                _ => Quad::ONE / Quad(b, 0.0, 0.0, 0.0) + Quad(a, 0.0, 0.0, 0.0).ldexp(-212),
+            }
+            .ldexp(exp)
+                * Quad(sign, 0.0, 0.0, 0.0);
+
+            let up = x.next_up();
+            let down = x.next_down();
+            assert!(up > x && down < x, "{:?}", x);
+            assert!(up.is_normalized() && down.is_normalized(), "{:?}", x);
+            assert!(same(up.next_down(), x), "{:?}", x);
+            assert!(same(down.next_up(), x), "{:?}", x);
+            assert!(same(x.ulp(), up - x), "{:?}", x);
+            assert!(same((-x).next_up(), -down), "{:?}", x);
+
+            let gap = x.ulp()[0];
+            assert!(gap.to_bits() & ((1 << 52) - 1) == 0 || gap.to_bits() < 1 << 52);
+        }
+    });
 }
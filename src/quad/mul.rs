@@ -4,7 +4,9 @@
 // https://opensource.org/licenses/MIT
 
 use crate::common::primitive as p;
+use crate::common::strict as s;
 use crate::common::utils as u;
+use crate::quad::common as c;
 use crate::quad::Quad;
 use std::ops::{Mul, MulAssign};
 
@@ -52,52 +54,11 @@ impl Mul for Quad {
     /// ```
     #[allow(clippy::suspicious_arithmetic_impl)]
     fn mul(self, other: Quad) -> Quad {
-        match self.pre_mul(&other) {
-            Some(r) => r,
-            None => {
-                // O(1) term
-                let (h0, l0) = p::two_prod(self.0, other.0);
-
-                // O(ε) terms
-                let (h1, l1) = p::two_prod(self.0, other.1);
-                let (h2, l2) = p::two_prod(self.1, other.0);
-
-                // O(ε²) terms
-                let (h3, l3) = p::two_prod(self.0, other.2);
-                let (h4, l4) = p::two_prod(self.1, other.1);
-                let (h5, l5) = p::two_prod(self.2, other.0);
-
-                // O(ε³) terms
-                let (h6, l6) = p::two_prod(self.0, other.3);
-                let (h7, l7) = p::two_prod(self.1, other.2);
-                let (h8, l8) = p::two_prod(self.2, other.1);
-                let (h9, l9) = p::two_prod(self.3, other.0);
-
-                // O(ε⁴) terms - the low words aren't necessary for the accuracy we need
-                let ha = self.1 * other.3;
-                let hb = self.2 * other.2;
-                let hc = self.3 * other.1;
-
-                // Each calculation takes all of the high words for the terms of that level,
-                // whatever intermediate words are specified by the algorithm, and whatever
-                // low words fit in the remaining input space.
-
-                // O(1) calculation (pass-through)
-                let r0 = h0;
-                // O(ε) calculation
-                let (r1, t0, t1) = u::three_three_sum(h1, h2, l0);
-                // O(ε²) calculation
-                let (r2, t2, t3) = u::six_three_sum(t0, h3, h4, h5, l1, l2);
-                // O(ε³) calculation
-                let (r3, t4) = u::nine_two_sum(t1, t2, h6, h7, h8, h9, l3, l4, l5);
-                // O(ε⁴) calculation (nine_one_sum)
-                let r4 = t3 + t4 + ha + hb + hc + l6 + l7 + l8 + l9;
-
-                // Results of the prior calculations are renormalized into four f64s.
-                let (a, b, c, d) = u::renorm5(r0, r1, r2, r3, r4);
-                Quad(a, b, c, d)
-            }
-        }
+        #[cfg(not(feature = "sloppy"))]
+        let r = self.mul_inner(other);
+        #[cfg(feature = "sloppy")]
+        let r = self.sloppy_mul_inner(other);
+        s::guard("Quad * Quad", &[self.0, other.0], r)
     }
 }
 
@@ -222,6 +183,230 @@ impl MulAssign<&Quad> for Quad {
 }
 
 impl Quad {
+    /// Multiplies the `Quad` by a small integer exactly, returning `None` if the product
+    /// can't be guaranteed to be exact.
+    ///
+    /// The product is exact when each component times `k` is itself an `f64`, with nothing
+    /// rounded away. For a component *c* with its odd part *m* (its significand with the
+    /// trailing zero bits removed) and `k` with its odd part *j*, that's when *m* × *j* <
+    /// 2<sup>53</sup> and the product doesn't overflow. Put in terms of bit lengths, if
+    /// *m* has *s* bits and |`k`| has *b*, the product is always exact when *s* + *b* ≤ 53
+    /// and never exact when *s* + *b* > 54; in between, it depends on the bits. For
+    /// example, every component of a `Quad` holding an integer less than 2<sup>53 −
+    /// *b*</sup> passes. Multiplying by zero or by a power of two is exact unless it
+    /// overflows.
+    ///
+    /// When the product is exact, it only takes four multiplications and a renormalization,
+    /// and the result is exactly what the `*` operator would produce. A `Quad` that isn't
+    /// finite always returns `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = qd!(3).powi(30); // 3^30, 48 bits
+    /// assert!(x.mul_exact_i32(31) == Some(x * qd!(31))); // 5 more bits fits
+    /// assert!(x.mul_exact_i32(63) == None); // 6 more bits doesn't
+    /// assert!(Quad::PI.mul_exact_i32(-4) == Some(Quad::PI * qd!(-4)));
+    /// ```
+    #[inline]
+    pub fn mul_exact_i32(self, k: i32) -> Option<Quad> {
+        if !self.is_finite() {
+            return None;
+        }
+        let (a, b, c, d) = match k {
+            // A zero product takes its sign from the signs of both factors, as it does for
+            // `f64`
+            _ if k == 0 || self.is_zero() => (self.0 * k as f64, 0.0, 0.0, 0.0),
+            1 => (self.0, self.1, self.2, self.3),
+            -1 => (-self.0, -self.1, -self.2, -self.3),
+            _ => {
+                let k = k as f64;
+                let (a, ea) = p::two_prod(self.0, k);
+                let (b, eb) = p::two_prod(self.1, k);
+                let (c, ec) = p::two_prod(self.2, k);
+                let (d, ed) = p::two_prod(self.3, k);
+                // The errors are all zero exactly when the products are exact, and an
+                // overflow shows up as an error of infinity or NaN
+                if ea != 0.0 || eb != 0.0 || ec != 0.0 || ed != 0.0 {
+                    return None;
+                }
+                u::renorm4(a, b, c, d)
+            }
+        };
+        // Adding zero turns negative zeros positive, which is what `*` leaves in the lower
+        // components
+        Some(Quad(a, b + 0.0, c + 0.0, d + 0.0))
+    }
+
+    /// Multiplies the `Quad` by an integer.
+    ///
+    /// This is the same as multiplying by the integer converted to a `Quad`, and it
+    /// produces the same result, but when the product is exact (see [`mul_exact_i32`] for
+    /// when that is), it's calculated with just four multiplications. That covers
+    /// doubling, multiplying a `Quad` that holds a modest integer by a loop index, and
+    /// many similar cases. When the product isn't exact, checking for it adds a little to
+    /// the cost of `*`, so this is best where exact products are common.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(1.5).mul_i32(-6) == qd!(-9));
+    /// assert!(Quad::PI.mul_i32(3) == Quad::PI * qd!(3));
+    /// ```
+    ///
+    /// [`mul_exact_i32`]: #method.mul_exact_i32
+    #[inline]
+    pub fn mul_i32(self, k: i32) -> Quad {
+        match self.mul_exact_i32(k) {
+            Some(r) => r,
+            None => self * Quad(k as f64, 0.0, 0.0, 0.0),
+        }
+    }
+}
+
+impl Quad {
+    /// Multiplies this `Quad` by another in a `const fn`, for products that are needed at
+    /// compile time. The result is the same as that of the `*` operator (including the
+    /// `sloppy` feature's version of it, when that is enabled); see
+    /// [`add_const`] for details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// // Twiddle factor angles for an FFT of size 16
+    /// const ANGLES: [Quad; 4] = [
+    ///     Quad::ZERO,
+    ///     Quad::PI.mul_const(Quad::from_f64_const(0.125)),
+    ///     Quad::PI.mul_const(Quad::from_f64_const(0.25)),
+    ///     Quad::PI.mul_const(Quad::from_f64_const(0.375)),
+    /// ];
+    /// assert!(ANGLES[3] == Quad::PI * qd!(0.375));
+    /// ```
+    ///
+    /// [`add_const`]: #method.add_const
+    pub const fn mul_const(self, other: Quad) -> Quad {
+        #[cfg(not(feature = "sloppy"))]
+        let r = self.mul_inner(other);
+        #[cfg(feature = "sloppy")]
+        let r = self.sloppy_mul_inner(other);
+        r
+    }
+
+    // The `*` operator without the strict-mode check.
+    #[allow(clippy::many_single_char_names)]
+    pub(super) const fn mul_inner(self, other: Quad) -> Quad {
+        match self.pre_mul(&other) {
+            Some(r) => r,
+            None => {
+                // O(1) term
+                let (h0, l0) = p::two_prod(self.0, other.0);
+
+                // O(ε) terms
+                let (h1, l1) = p::two_prod(self.0, other.1);
+                let (h2, l2) = p::two_prod(self.1, other.0);
+
+                // O(ε²) terms
+                let (h3, l3) = p::two_prod(self.0, other.2);
+                let (h4, l4) = p::two_prod(self.1, other.1);
+                let (h5, l5) = p::two_prod(self.2, other.0);
+
+                // O(ε³) terms
+                let (h6, l6) = p::two_prod(self.0, other.3);
+                let (h7, l7) = p::two_prod(self.1, other.2);
+                let (h8, l8) = p::two_prod(self.2, other.1);
+                let (h9, l9) = p::two_prod(self.3, other.0);
+
+                // O(ε⁴) terms - the low words aren't necessary for the accuracy we need
+                let ha = self.1 * other.3;
+                let hb = self.2 * other.2;
+                let hc = self.3 * other.1;
+
+                // Each calculation takes all of the high words for the terms of that level,
+                // whatever intermediate words are specified by the algorithm, and whatever
+                // low words fit in the remaining input space.
+
+                // O(1) calculation (pass-through)
+                let r0 = h0;
+                // O(ε) calculation
+                let (r1, t0, t1) = u::three_three_sum(h1, h2, l0);
+                // O(ε²) calculation
+                let (r2, t2, t3) = u::six_three_sum(t0, h3, h4, h5, l1, l2);
+                // O(ε³) calculation
+                let (r3, t4) = u::nine_two_sum(t1, t2, h6, h7, h8, h9, l3, l4, l5);
+                // O(ε⁴) calculation (nine_one_sum)
+                let r4 = t3 + t4 + ha + hb + hc + l6 + l7 + l8 + l9;
+
+                // Results of the prior calculations are renormalized into four f64s.
+                let (a, b, c, d) = u::renorm5(r0, r1, r2, r3, r4);
+                if a.is_finite() {
+                    Quad(a, b, c, d)
+                } else {
+                    c::redo_halved!(
+                        0.5 * self.0 * other.0,
+                        c::mul_pwr2(self, 0.5).mul_inner(other)
+                    )
+                }
+            }
+        }
+    }
+
+    // The `*` operator with the `sloppy` feature. This is the "sloppy" multiplication from
+    // the same paper. It skips the O(ε³) low words and all of the O(ε⁴) terms, adding the
+    // O(ε³) high words in plain `f64` arithmetic, and it splits the six O(ε²) words into
+    // two three-sums that don't depend on each other, so there's less of a chain of
+    // dependent additions for the processor to wait on.
+    //
+    // What's given up is the last few bits. Relative to the product, the error of the
+    // accurate multiplication is under 10% of `Quad::EPSILON`, and the error of this one
+    // is under twice `Quad::EPSILON`.
+    #[cfg(feature = "sloppy")]
+    #[allow(clippy::many_single_char_names)]
+    const fn sloppy_mul_inner(self, other: Quad) -> Quad {
+        match self.pre_mul(&other) {
+            Some(r) => r,
+            None => {
+                let (p0, q0) = p::two_prod(self.0, other.0);
+                let (p1, q1) = p::two_prod(self.0, other.1);
+                let (p2, q2) = p::two_prod(self.1, other.0);
+                let (p3, q3) = p::two_prod(self.0, other.2);
+                let (p4, q4) = p::two_prod(self.1, other.1);
+                let (p5, q5) = p::two_prod(self.2, other.0);
+
+                // O(ε) calculation
+                let (p1, p2, q0) = u::three_three_sum(p1, p2, q0);
+
+                // O(ε²) calculation, as the sum of two three-sums
+                let (p2, q1, q2) = u::three_three_sum(p2, q1, q2);
+                let (p3, p4, p5) = u::three_three_sum(p3, p4, p5);
+                let (s0, t0) = p::two_sum(p2, p3);
+                let (s1, t1) = p::two_sum(q1, p4);
+                let (s1, t0) = p::two_sum(s1, t0);
+                let s2 = q2 + p5 + (t0 + t1);
+
+                // O(ε³) calculation
+                let s1 = s1
+                    + (self.0 * other.3
+                        + self.1 * other.2
+                        + self.2 * other.1
+                        + self.3 * other.0
+                        + q0
+                        + q3
+                        + q4
+                        + q5);
+
+                let (a, b, c, d) = u::renorm5(p0, p1, s0, s1, s2);
+                if a.is_finite() {
+                    Quad(a, b, c, d)
+                } else {
+                    c::redo_halved!(
+                        0.5 * self.0 * other.0,
+                        c::mul_pwr2(self, 0.5).sloppy_mul_inner(other)
+                    )
+                }
+            }
+        }
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -232,7 +417,7 @@ impl Quad {
     // rather than calculation.
 
     #[inline]
-    fn pre_mul(&self, other: &Quad) -> Option<Quad> {
+    const fn pre_mul(&self, other: &Quad) -> Option<Quad> {
         if self.is_nan() || other.is_nan() {
             Some(Quad::NAN)
         } else if self.is_zero() {
@@ -257,6 +442,12 @@ impl Quad {
             } else {
                 Some(Quad::NEG_INFINITY)
             }
+        } else if other.is_zero() {
+            if self.is_sign_positive() == other.is_sign_positive() {
+                Some(Quad::ZERO)
+            } else {
+                Some(Quad::NEG_ZERO)
+            }
         } else {
             None
         }
@@ -266,6 +457,7 @@ impl Quad {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::random::Rng;
 
     // mul tests
     test_all_near!(
@@ -382,4 +574,207 @@ mod tests {
             near!(qd!("8.5397342226735670654635508695465744950348885357651149618796011301762"), b);
         }
     );
+
+    // mul_exact_i32 and mul_i32 tests
+    fn general(x: Quad, k: i32) -> Quad {
+        x * Quad(k as f64, 0.0, 0.0, 0.0)
+    }
+
+    fn exact(x: Quad, k: i32) -> bool {
+        x.mul_exact_i32(k).is_some()
+    }
+
+    // The smallest and largest odd integers that are s bits long
+    fn narrow(s: i32) -> f64 {
+        2f64.powi(s - 1) + 1.0
+    }
+
+    fn wide(s: i32) -> f64 {
+        2f64.powi(s) - 1.0
+    }
+
+    test!(exact_matches_general: {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        let mut count = 0;
+        for i in 0..20000 {
+            let r = rng.next_u64();
+            let x = match i % 3 {
+                0 => Quad::from((r >> 11) as f64) / qd!(7),
+                1 => Quad::from((r >> (r % 64)) as f64) * qd!(3).powi((r % 20) as i32),
+                _ => -Quad((r >> 11) as f64, 0.0, 0.0, 0.0) * qd!(1e-200),
+            };
+            let k = match rng.next_u64() % 4 {
+                0 => (r % 5) as i32 - 2,
+                1 => (r % 4096) as i32 - 2048,
+                _ => (rng.next_u64() >> 32) as i32,
+            };
+            if let Some(y) = x.mul_exact_i32(k) {
+                count += 1;
+                assert!(y.to_bits() == general(x, k).to_bits(), "{:?} * {}", x, k);
+            }
+            assert!(x.mul_i32(k).to_bits() == general(x, k).to_bits(), "{:?} * {}", x, k);
+        }
+        // Make sure that the exact path was actually taken a reasonable number of times
+        assert!(count > 5000);
+    });
+
+    test_all_assert!(
+        exact_boundary_under:
+            exact(qd!(wide(50)), 7);
+        exact_boundary_between:
+            exact(qd!(narrow(51)), 7);
+        exact_boundary_between_not:
+            !exact(qd!(wide(51)), 7);
+        exact_boundary_over:
+            !exact(qd!(narrow(50)), 31);
+        exact_boundary_neg:
+            exact(qd!(wide(50)), -7) && !exact(qd!(narrow(50)), -31);
+        exact_boundary_even:
+            exact(qd!(wide(50)), 7 << 20) && !exact(qd!(narrow(50)), 31 << 20);
+        exact_boundary_lower:
+            exact(Quad(2f64.powi(120), wide(50), 0.0, 0.0), 7)
+                && !exact(Quad(2f64.powi(120), wide(51), 0.0, 0.0), 7);
+        exact_pi_pwr2:
+            exact(Quad::PI, 1 << 30) && exact(Quad::PI, i32::MIN);
+        exact_pi_odd:
+            !exact(Quad::PI, 3);
+        exact_overflow:
+            !exact(Quad::MAX, 2) && exact(Quad::MAX, -1);
+        exact_nan:
+            !exact(Quad::NAN, 1);
+        exact_inf:
+            !exact(Quad::INFINITY, 1) && !exact(Quad::NEG_INFINITY, 0);
+    );
+
+    test_all_exact!(
+        exact_product:
+            Quad(3.0 * 2f64.powi(60), 3.0, 0.0, 0.0),
+            Quad(2f64.powi(60), 1.0, 0.0, 0.0).mul_exact_i32(3).unwrap();
+        exact_neg:
+            Quad(-15.0 * 2f64.powi(60), -15.0, 0.0, 0.0),
+            Quad(2f64.powi(60), 1.0, 0.0, 0.0).mul_exact_i32(-15).unwrap();
+        exact_zero:
+            Quad::ZERO,
+            Quad::PI.mul_exact_i32(0).unwrap();
+        exact_neg_zero:
+            Quad::ZERO,
+            (-Quad::PI).mul_exact_i32(0).unwrap();
+        exact_zero_neg_zero:
+            Quad::NEG_ZERO,
+            Quad::NEG_ZERO.mul_exact_i32(0).unwrap();
+        exact_one:
+            Quad::PI,
+            Quad::PI.mul_exact_i32(1).unwrap();
+        exact_neg_one:
+            -Quad::PI,
+            Quad::PI.mul_exact_i32(-1).unwrap();
+        exact_two:
+            Quad::TAU,
+            Quad::PI.mul_exact_i32(2).unwrap();
+        mul_i32_inexact:
+            general(Quad::PI, 3),
+            Quad::PI.mul_i32(3);
+        mul_i32_nan:
+            Quad::NAN,
+            Quad::NAN.mul_i32(2);
+        mul_i32_inf:
+            Quad::NEG_INFINITY,
+            Quad::INFINITY.mul_i32(-3);
+        mul_i32_inf_zero:
+            Quad::NAN,
+            Quad::INFINITY.mul_i32(0);
+    );
+
+    test!(exact_zero_signs: {
+        for &x in &[Quad::ZERO, Quad::NEG_ZERO, Quad::PI, -Quad::PI] {
+            for &k in &[0, 1, -1] {
+                assert!(x.mul_exact_i32(k).unwrap().to_bits() == general(x, k).to_bits());
+            }
+        }
+    });
+
+    // The sloppy product of random full-precision operands, compared to their exact product
+    // (the sum of the exact products of all of their components)
+    #[cfg(feature = "sloppy")]
+    #[test]
+    fn sloppy_error() {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        let mut next = || Quad(rng.next_f64() + 0.5, 0.0, 0.0, 0.0);
+        for _ in 0..20_000 {
+            let x = Quad::ONE / next() * (next() * qd!(4)).sqrt();
+            let y = Quad::ONE / next() + next().exp();
+            let r = x * y;
+
+            let mut terms = vec![];
+            for a in x.to_array() {
+                for b in y.to_array() {
+                    let (h, l) = p::two_prod(a, b);
+                    terms.push(h);
+                    terms.push(l);
+                }
+            }
+            terms.extend(r.to_array().iter().map(|c| -c));
+            let (t, scale) = u::distill_sum(&terms);
+            let error = (t[t.len() - 1] * scale / r.0).abs();
+            assert!(error < 2.0 * Quad::EPSILON.0, "{:?} * {:?}", x, y);
+        }
+    }
+
+    // Steps of π/16, and the first two terms of the Taylor series for their sines, worked
+    // out at compile time with the `const` arithmetic and then again at runtime with the
+    // operators. Both have to come out the same, bit for bit.
+    const fn angles() -> [Quad; 16] {
+        let mut t = [Quad::ZERO; 16];
+        let step = Quad::PI.div_const(Quad::from_f64_const(16.0));
+        let mut k = 1;
+        while k < 16 {
+            t[k] = t[k - 1].add_const(step);
+            k += 1;
+        }
+        t
+    }
+
+    const fn cubics(angles: [Quad; 16]) -> [Quad; 16] {
+        let sixth = Quad::ONE.div_const(Quad::from_f64_const(6.0));
+        let mut t = [Quad::ZERO; 16];
+        let mut k = 0;
+        while k < 16 {
+            let x = angles[k];
+            t[k] = x.sub_const(x.mul_const(x).mul_const(x).mul_const(sixth));
+            k += 1;
+        }
+        t
+    }
+
+    const ANGLES: [Quad; 16] = angles();
+    const CUBICS: [Quad; 16] = cubics(ANGLES);
+    const SPECIAL: [Quad; 5] = [
+        Quad::MAX.add_const(Quad::MAX),
+        Quad::NEG_ZERO.mul_const(Quad::ZERO),
+        Quad::INFINITY.sub_const(Quad::INFINITY),
+        Quad::ONE.div_const(Quad::NEG_ZERO),
+        Quad::MIN_POSITIVE.mul_const(Quad::from_f64_const(0.5)),
+    ];
+
+    test!(const_matches_runtime: {
+        let step = Quad::PI / Quad(16.0, 0.0, 0.0, 0.0);
+        let sixth = Quad::ONE / Quad::from(6);
+        let mut x = Quad::ZERO;
+        for k in 0..16 {
+            assert!(ANGLES[k].to_bits() == x.to_bits(), "angle {}", k);
+            let cubic = x - x * x * x * sixth;
+            assert!(CUBICS[k].to_bits() == cubic.to_bits(), "cubic {}", k);
+            x += step;
+        }
+        let special = [
+            Quad::MAX + Quad::MAX,
+            Quad::NEG_ZERO * Quad::ZERO,
+            Quad::INFINITY - Quad::INFINITY,
+            Quad::ONE / Quad::NEG_ZERO,
+            Quad::MIN_POSITIVE * Quad::from(0.5),
+        ];
+        for (k, x) in special.iter().enumerate() {
+            assert!(SPECIAL[k].to_bits() == x.to_bits(), "special {}", k);
+        }
+    });
 }
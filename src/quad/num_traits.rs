@@ -0,0 +1,727 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::error::ParseQuadError;
+use crate::quad::common as c;
+use crate::quad::Quad;
+use num_traits::{Float, FloatConst, FromPrimitive, Num, One, Signed, ToPrimitive, Zero};
+use std::convert::TryFrom;
+use std::num::FpCategory;
+
+// 2^64, the factor between the two halves of a 128-bit integer.
+const TWO_64: f64 = 18446744073709551616.0;
+
+impl Zero for Quad {
+    /// Returns zero. This is only available with the `num-traits` feature.
+    #[inline]
+    fn zero() -> Quad {
+        Quad::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        Quad::is_zero(*self)
+    }
+}
+
+impl One for Quad {
+    /// Returns one. This is only available with the `num-traits` feature.
+    #[inline]
+    fn one() -> Quad {
+        Quad::ONE
+    }
+}
+
+impl Num for Quad {
+    type FromStrRadixErr = ParseQuadError;
+
+    /// Parses a string in the given radix. This is only available with the `num-traits`
+    /// feature.
+    ///
+    /// A radix of 10 parses exactly as [`from_str`] does, and any other radix parses as
+    /// [`from_string_radix_any`] does.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range 2 to 36.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use num_traits::Num;
+    ///
+    /// assert!(Quad::from_str_radix("-101.11", 2).unwrap() == qd!(-5.75));
+    /// assert!(Quad::from_str_radix("1e3", 10).unwrap() == qd!(1000));
+    /// ```
+    ///
+    /// [`from_str`]: #method.from_str
+    /// [`from_string_radix_any`]: #method.from_string_radix_any
+    fn from_str_radix(s: &str, radix: u32) -> Result<Quad, ParseQuadError> {
+        if radix == 10 {
+            s.parse()
+        } else {
+            Quad::from_string_radix_any(s, radix)
+        }
+    }
+}
+
+impl ToPrimitive for Quad {
+    /// Converts the `Quad` to an `i64`, truncating any fractional part. This is only
+    /// available with the `num-traits` feature.
+    ///
+    /// Like the other integer conversions, this returns `None` if the truncated number
+    /// doesn't fit in the integer type, and that includes NaN and the infinities. Unlike
+    /// converting the first component alone, this takes the other components into account,
+    /// so that every integer that a `Quad` can hold comes out exactly.
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.to_i128().and_then(|n| i64::try_from(n).ok())
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.to_u128().and_then(|n| u64::try_from(n).ok())
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(self.trunc()).ok()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(self.trunc()).ok()
+    }
+
+    /// Converts the `Quad` to the nearest `f64`. This is only available with the
+    /// `num-traits` feature.
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.0)
+    }
+}
+
+impl FromPrimitive for Quad {
+    /// Converts an `i64` to a `Quad`. This is only available with the `num-traits`
+    /// feature.
+    ///
+    /// All of the conversions from integers are exact, except those of 128-bit integers
+    /// with more significant bits than a `Quad` can hold, which are rounded.
+    #[inline]
+    fn from_i64(n: i64) -> Option<Quad> {
+        Some(Quad::from(n))
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Quad> {
+        Some(Quad::from(n))
+    }
+
+    fn from_i128(n: i128) -> Option<Quad> {
+        let hi = c::mul_pwr2(Quad::from((n >> 64) as i64), TWO_64);
+        Some(hi + Quad::from(n as u64))
+    }
+
+    fn from_u128(n: u128) -> Option<Quad> {
+        let hi = c::mul_pwr2(Quad::from((n >> 64) as u64), TWO_64);
+        Some(hi + Quad::from(n as u64))
+    }
+
+    #[inline]
+    fn from_f32(n: f32) -> Option<Quad> {
+        Some(Quad::from(n))
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<Quad> {
+        Some(Quad::from(n))
+    }
+}
+
+// `NumCast` is left unimported, since its `from` would clash with `From::from`
+impl num_traits::NumCast for Quad {
+    /// Converts any primitive number to a `Quad`. This is only available with the
+    /// `num-traits` feature.
+    ///
+    /// Integers are converted as exactly as a `Quad` can hold them. Anything else is
+    /// converted by way of `f64`, since that's all that [`ToPrimitive`] offers for numbers
+    /// with fractional parts.
+    ///
+    /// [`ToPrimitive`]: https://docs.rs/num-traits/0.2/num_traits/cast/trait.ToPrimitive.html
+    fn from<T: ToPrimitive>(n: T) -> Option<Quad> {
+        let f = n.to_f64()?;
+        if f.fract() == 0.0 && f != 0.0 {
+            if let Some(i) = n.to_i128() {
+                return Quad::from_i128(i);
+            }
+            if let Some(u) = n.to_u128() {
+                return Quad::from_u128(u);
+            }
+        }
+        Quad::from_f64(f)
+    }
+}
+
+impl Signed for Quad {
+    /// Returns the absolute value of the `Quad`. This is only available with the
+    /// `num-traits` feature.
+    #[inline]
+    fn abs(&self) -> Quad {
+        Quad::abs(*self)
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Quad) -> Quad {
+        Float::abs_sub(*self, *other)
+    }
+
+    #[inline]
+    fn signum(&self) -> Quad {
+        Quad::signum(*self)
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        Quad::is_sign_positive(*self)
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        Quad::is_sign_negative(*self)
+    }
+}
+
+impl Float for Quad {
+    /// Returns NaN. This is only available with the `num-traits` feature.
+    ///
+    /// Every method of this trait with a counterpart in `Quad`'s own methods or
+    /// constants is that counterpart, and the documentation of the counterpart describes
+    /// it. The rest are described individually.
+    #[inline]
+    fn nan() -> Quad {
+        Quad::NAN
+    }
+
+    #[inline]
+    fn infinity() -> Quad {
+        Quad::INFINITY
+    }
+
+    #[inline]
+    fn neg_infinity() -> Quad {
+        Quad::NEG_INFINITY
+    }
+
+    #[inline]
+    fn neg_zero() -> Quad {
+        Quad::NEG_ZERO
+    }
+
+    #[inline]
+    fn min_value() -> Quad {
+        Quad::MIN
+    }
+
+    #[inline]
+    fn min_positive_value() -> Quad {
+        Quad::MIN_POSITIVE
+    }
+
+    #[inline]
+    fn epsilon() -> Quad {
+        Quad::EPSILON
+    }
+
+    #[inline]
+    fn max_value() -> Quad {
+        Quad::MAX
+    }
+
+    #[inline]
+    fn is_nan(self) -> bool {
+        Quad::is_nan(self)
+    }
+
+    #[inline]
+    fn is_infinite(self) -> bool {
+        Quad::is_infinite(self)
+    }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        Quad::is_finite(self)
+    }
+
+    #[inline]
+    fn is_normal(self) -> bool {
+        Quad::is_normal(self)
+    }
+
+    #[inline]
+    fn is_subnormal(self) -> bool {
+        Quad::is_subnormal(self)
+    }
+
+    #[inline]
+    fn classify(self) -> FpCategory {
+        Quad::classify(self)
+    }
+
+    #[inline]
+    fn floor(self) -> Quad {
+        Quad::floor(self)
+    }
+
+    #[inline]
+    fn ceil(self) -> Quad {
+        Quad::ceil(self)
+    }
+
+    #[inline]
+    fn round(self) -> Quad {
+        Quad::round(self)
+    }
+
+    #[inline]
+    fn trunc(self) -> Quad {
+        Quad::trunc(self)
+    }
+
+    #[inline]
+    fn fract(self) -> Quad {
+        Quad::fract(self)
+    }
+
+    #[inline]
+    fn abs(self) -> Quad {
+        Quad::abs(self)
+    }
+
+    #[inline]
+    fn signum(self) -> Quad {
+        Quad::signum(self)
+    }
+
+    #[inline]
+    fn copysign(self, sign: Quad) -> Quad {
+        Quad::copysign(self, sign)
+    }
+
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        Quad::is_sign_positive(self)
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        Quad::is_sign_negative(self)
+    }
+
+    #[inline]
+    fn mul_add(self, a: Quad, b: Quad) -> Quad {
+        Quad::mul_add(self, a, b)
+    }
+
+    #[inline]
+    fn recip(self) -> Quad {
+        Quad::recip(self)
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Quad {
+        Quad::powi(self, n)
+    }
+
+    #[inline]
+    fn powf(self, n: Quad) -> Quad {
+        Quad::powf(self, n)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Quad {
+        Quad::sqrt(self)
+    }
+
+    #[inline]
+    fn exp(self) -> Quad {
+        Quad::exp(self)
+    }
+
+    #[inline]
+    fn exp2(self) -> Quad {
+        Quad::exp2(self)
+    }
+
+    #[inline]
+    fn ln(self) -> Quad {
+        Quad::ln(self)
+    }
+
+    #[inline]
+    fn log(self, base: Quad) -> Quad {
+        Quad::log(self, base)
+    }
+
+    #[inline]
+    fn log2(self) -> Quad {
+        Quad::log2(self)
+    }
+
+    #[inline]
+    fn log10(self) -> Quad {
+        Quad::log10(self)
+    }
+
+    #[inline]
+    fn to_degrees(self) -> Quad {
+        Quad::to_degrees(self)
+    }
+
+    #[inline]
+    fn to_radians(self) -> Quad {
+        Quad::to_radians(self)
+    }
+
+    #[inline]
+    fn max(self, other: Quad) -> Quad {
+        Quad::max(self, other)
+    }
+
+    #[inline]
+    fn min(self, other: Quad) -> Quad {
+        Quad::min(self, other)
+    }
+
+    #[inline]
+    fn abs_sub(self, other: Quad) -> Quad {
+        Quad::abs_sub(self, other)
+    }
+
+    #[inline]
+    fn cbrt(self) -> Quad {
+        Quad::cbrt(self)
+    }
+
+    #[inline]
+    fn hypot(self, other: Quad) -> Quad {
+        Quad::hypot(self, other)
+    }
+
+    #[inline]
+    fn sin(self) -> Quad {
+        Quad::sin(self)
+    }
+
+    #[inline]
+    fn cos(self) -> Quad {
+        Quad::cos(self)
+    }
+
+    #[inline]
+    fn tan(self) -> Quad {
+        Quad::tan(self)
+    }
+
+    #[inline]
+    fn asin(self) -> Quad {
+        Quad::asin(self)
+    }
+
+    #[inline]
+    fn acos(self) -> Quad {
+        Quad::acos(self)
+    }
+
+    #[inline]
+    fn atan(self) -> Quad {
+        Quad::atan(self)
+    }
+
+    #[inline]
+    fn atan2(self, other: Quad) -> Quad {
+        Quad::atan2(self, other)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Quad, Quad) {
+        Quad::sin_cos(self)
+    }
+
+    #[inline]
+    fn exp_m1(self) -> Quad {
+        Quad::exp_m1(self)
+    }
+
+    #[inline]
+    fn ln_1p(self) -> Quad {
+        Quad::ln_1p(self)
+    }
+
+    #[inline]
+    fn sinh(self) -> Quad {
+        Quad::sinh(self)
+    }
+
+    #[inline]
+    fn cosh(self) -> Quad {
+        Quad::cosh(self)
+    }
+
+    #[inline]
+    fn tanh(self) -> Quad {
+        Quad::tanh(self)
+    }
+
+    #[inline]
+    fn asinh(self) -> Quad {
+        Quad::asinh(self)
+    }
+
+    #[inline]
+    fn acosh(self) -> Quad {
+        Quad::acosh(self)
+    }
+
+    #[inline]
+    fn atanh(self) -> Quad {
+        Quad::atanh(self)
+    }
+
+    /// Returns the mantissa, base 2 exponent, and sign of the first component of the
+    /// `Quad`. This is only available with the `num-traits` feature.
+    ///
+    /// The trait's return type only has room for 64 bits of mantissa, so the other
+    /// components aren't included at all. The original number can't be rebuilt from the
+    /// result, though the first component can.
+    #[inline]
+    fn integer_decode(self) -> (u64, i16, i8) {
+        Float::integer_decode(self.0)
+    }
+}
+
+impl FloatConst for Quad {
+    /// Returns *e*. This is only available with the `num-traits` feature.
+    ///
+    /// Each of these is the associated constant of the same name.
+    #[inline]
+    fn E() -> Quad {
+        Quad::E
+    }
+
+    #[inline]
+    fn FRAC_1_PI() -> Quad {
+        Quad::FRAC_1_PI
+    }
+
+    #[inline]
+    fn FRAC_1_SQRT_2() -> Quad {
+        Quad::FRAC_1_SQRT_2
+    }
+
+    #[inline]
+    fn FRAC_2_PI() -> Quad {
+        Quad::FRAC_2_PI
+    }
+
+    #[inline]
+    fn FRAC_2_SQRT_PI() -> Quad {
+        Quad::FRAC_2_SQRT_PI
+    }
+
+    #[inline]
+    fn FRAC_PI_2() -> Quad {
+        Quad::FRAC_PI_2
+    }
+
+    #[inline]
+    fn FRAC_PI_3() -> Quad {
+        Quad::FRAC_PI_3
+    }
+
+    #[inline]
+    fn FRAC_PI_4() -> Quad {
+        Quad::FRAC_PI_4
+    }
+
+    #[inline]
+    fn FRAC_PI_6() -> Quad {
+        Quad::FRAC_PI_6
+    }
+
+    #[inline]
+    fn FRAC_PI_8() -> Quad {
+        Quad::FRAC_PI_8
+    }
+
+    #[inline]
+    fn LN_10() -> Quad {
+        Quad::LN_10
+    }
+
+    #[inline]
+    fn LN_2() -> Quad {
+        Quad::LN_2
+    }
+
+    #[inline]
+    fn LOG10_E() -> Quad {
+        Quad::LOG10_E
+    }
+
+    #[inline]
+    fn LOG2_E() -> Quad {
+        Quad::LOG2_E
+    }
+
+    #[inline]
+    fn PI() -> Quad {
+        Quad::PI
+    }
+
+    #[inline]
+    fn SQRT_2() -> Quad {
+        Quad::SQRT_2
+    }
+
+    #[inline]
+    fn TAU() -> Quad {
+        Quad::TAU
+    }
+
+    #[inline]
+    fn LOG10_2() -> Quad {
+        Quad::LOG10_2
+    }
+
+    #[inline]
+    fn LOG2_10() -> Quad {
+        Quad::LOG2_10
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Calls the trait's methods rather than the inherent ones, which share their names
+    fn float<T: Float>(x: T) -> T {
+        x
+    }
+
+    test!(constants: {
+        assert!(<Quad as Zero>::zero() == Quad::ZERO);
+        assert!(<Quad as One>::one() == Quad::ONE);
+        assert!(<Quad as Float>::max_value() == Quad::MAX);
+        assert!(<Quad as Float>::min_value() == Quad::MIN);
+        assert!(<Quad as Float>::min_positive_value() == Quad::MIN_POSITIVE);
+        assert!(<Quad as Float>::epsilon() == Quad::EPSILON);
+        assert!(<Quad as Float>::nan().is_nan());
+        assert!(<Quad as FloatConst>::PI() == Quad::PI);
+        assert!(<Quad as FloatConst>::TAU() == Quad::TAU);
+    });
+
+    test!(to_integers: {
+        let big = Quad::from(1u64 << 62) + Quad::from(12345);
+        assert!(big.to_i64() == Some((1 << 62) + 12345));
+        assert!((-big).to_i64() == Some(-(1 << 62) - 12345));
+        assert!(big.to_u64() == Some((1 << 62) + 12345));
+        assert!((-big).to_u64() == None);
+        assert!(qd!(-2.75).to_i32() == Some(-2));
+        assert!(qd!(-0.75).to_u32() == Some(0));
+        assert!(qd!(300).to_u8() == None);
+        assert!(Quad::NAN.to_i64() == None);
+        assert!(Quad::INFINITY.to_u64() == None);
+
+        let n = (1i128 << 100) + 1;
+        assert!(Quad::from_i128(n).unwrap().to_i128() == Some(n));
+        assert!(Quad::from_i128(-n).unwrap().to_i128() == Some(-n));
+        assert!(Quad::from_u128(n as u128).unwrap().to_u128() == Some(n as u128));
+        let max = Quad::from_u128(u128::MAX).unwrap();
+        assert!(max.to_u128() == Some(u128::MAX));
+        assert!(max.to_i128() == None);
+        assert!(Quad::from_i128(i128::MIN).unwrap().to_i128() == Some(i128::MIN));
+    });
+
+    test!(num_cast: {
+        assert!(<Quad as num_traits::NumCast>::from(u64::MAX).unwrap() == Quad::from(u64::MAX));
+        assert!(<Quad as num_traits::NumCast>::from(-7i8).unwrap() == qd!(-7));
+        assert!(<Quad as num_traits::NumCast>::from(0.1f64).unwrap() == Quad::from(0.1));
+        assert!(<Quad as num_traits::NumCast>::from(-0.0f64).unwrap().is_sign_negative());
+        assert!(<Quad as num_traits::NumCast>::from(f64::NAN).unwrap().is_nan());
+        let n = (1i128 << 100) + 1;
+        assert!(<Quad as num_traits::NumCast>::from(n).unwrap() == Quad::from_i128(n).unwrap());
+        assert!(<f64 as num_traits::NumCast>::from(Quad::PI).unwrap() == std::f64::consts::PI);
+    });
+
+    test!(from_str_radix: {
+        assert!(Quad::from_str_radix("ff.8", 16).unwrap() == qd!(255.5));
+        assert!(Quad::from_str_radix("1e3", 10).unwrap() == qd!(1000));
+        assert!(Quad::from_str_radix("", 10).is_err());
+        assert!(Quad::from_str_radix("12", 2).is_err());
+    });
+
+    test!(min_max: {
+        let (a, b) = (qd!(1), qd!(2));
+        assert!(Float::max(a, b) == b);
+        assert!(Float::min(a, b) == a);
+        assert!(Float::max(Quad::NAN, a) == a);
+        assert!(Float::max(a, Quad::NAN) == a);
+        assert!(Float::min(Quad::NAN, a) == a);
+        assert!(Float::min(a, Quad::NAN) == a);
+        assert!(Float::abs_sub(a, b) == Quad::ZERO);
+        assert!(Float::abs_sub(b, a) == Quad::ONE);
+        assert!(Float::clamp(qd!(5), a, b) == b);
+    });
+
+    test!(exp2: {
+        assert!(float(qd!(10)).exp2() == qd!(1024));
+        assert!(float(qd!(-1074)).exp2() == Quad(f64::from_bits(1), 0.0, 0.0, 0.0));
+        assert!(float(qd!(1024)).exp2() == Quad::INFINITY);
+        assert!(float(qd!(-1100)).exp2() == Quad::ZERO);
+        assert!(float(Quad::NAN).exp2().is_nan());
+        let x = float(qd!(0.5)).exp2();
+        assert!((x - Quad::SQRT_2).abs() < qd!(1e-62));
+        let x = float(qd!(100.5)).exp2();
+        assert!((x - Quad::SQRT_2 * qd!(2).powi(100)).abs() < qd!(1e-62) * x);
+    });
+
+    test!(hypot: {
+        assert!((float(qd!(3)).hypot(qd!(-4)) - qd!(5)).abs() < qd!(1e-62));
+        assert!(float(Quad::ZERO).hypot(Quad::ZERO) == Quad::ZERO);
+        assert!(float(Quad::MAX / qd!(2)).hypot(Quad::MAX / qd!(2)).is_finite());
+        assert!(float(Quad::NAN).hypot(Quad::INFINITY) == Quad::INFINITY);
+        assert!(float(Quad::NAN).hypot(Quad::ONE).is_nan());
+        let x = float(qd!(1e-200)).hypot(qd!(1e-200));
+        assert!((x - qd!(1e-200) * Quad::SQRT_2).abs() < qd!(1e-262));
+    });
+
+    test!(degrees: {
+        assert!((float(Quad::PI).to_degrees() - qd!(180)).abs() < qd!(1e-60));
+        assert!((float(qd!(90)).to_radians() - Quad::FRAC_PI_2).abs() < qd!(1e-63));
+    });
+
+    test!(integer_decode: {
+        assert!(Float::integer_decode(Quad::ONE) == Float::integer_decode(1f64));
+        assert!(Float::integer_decode(Quad::PI) == Float::integer_decode(Quad::PI[0]));
+    });
+
+    test!(signed: {
+        assert!(Signed::abs(&qd!(-2)) == qd!(2));
+        assert!(Signed::is_negative(&qd!(-2)));
+        assert!(Signed::is_positive(&Quad::ZERO));
+        assert!(Signed::signum(&qd!(-0.5)) == Quad::NEG_ONE);
+    });
+
+    test!(matches_inherent: {
+        let x = qd!(0.7);
+        assert!(float(x).sin() == x.sin());
+        assert!(float(x).sin_cos() == x.sin_cos());
+        assert!(float(x).atan2(qd!(2)) == x.atan2(qd!(2)));
+        assert!(float(x).powf(qd!(1.5)) == x.powf(qd!(1.5)));
+        assert!(float(x).ln_1p() == x.ln_1p());
+        assert!(float(x).mul_add(qd!(3), qd!(4)) == x.mul_add(qd!(3), qd!(4)));
+        assert!(float(x).classify() == x.classify());
+    });
+}
@@ -0,0 +1,369 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::quad::Quad;
+use plotters::coord::ranged1d::{
+    KeyPointHint, NoDefaultFormatting, Ranged, ReversibleRanged, ValueFormatter,
+};
+use std::ops::Range;
+
+/// A range of `Quad`s that can be used as an axis of a [plotters] chart. This is only
+/// available with the `plotters` feature.
+///
+/// Values are mapped to pixels by way of `f64`, since pixels don't need any more precision
+/// than that, but the positions along the axis are worked out in full `Quad` precision
+/// first. This means that an axis can span a range that's too narrow to be represented
+/// well by `f64`s, like `1 + 1e-20` to `1 + 2e-20`.
+///
+/// The axis's key points (the values that get ticks, labels, and grid lines) are chosen in
+/// `Quad` precision as well. They're the multiples of the step that lie within the range,
+/// where the step is the smallest of 1, 2, or 5 times a power of ten that doesn't produce
+/// more key points than plotters asks for. Each key point is exactly the number that its
+/// label parses to, so ticks land precisely on round numbers no matter how large or small
+/// they are. Labels are written with as few digits as it takes to parse back to the key
+/// points they label.
+///
+/// # Examples
+/// ```
+/// # use qd::{qd, Quad, QuadRange};
+/// use plotters::prelude::*;
+///
+/// let mut svg = String::new();
+/// {
+///     let root = SVGBackend::with_string(&mut svg, (320, 240)).into_drawing_area();
+///     let x = QuadRange::from(qd!(0)..qd!(1));
+///     let y = QuadRange::from(qd!("1e-250")..qd!("1.00000000000000000001e-250"));
+///
+///     let mut chart = ChartBuilder::on(&root)
+///         .x_label_area_size(20)
+///         .y_label_area_size(60)
+///         .build_cartesian_2d(x, y)
+///         .unwrap();
+///     chart.configure_mesh().draw().unwrap();
+///     chart
+///         .draw_series(LineSeries::new(
+///             (0..=10).map(|i| {
+///                 let x = qd!(i) / qd!(10);
+///                 (x, qd!("1e-250") * (qd!(1) + x * qd!("1e-20")))
+///             }),
+///             &BLUE,
+///         ))
+///         .unwrap();
+///     root.present().unwrap();
+/// }
+/// assert!(svg.contains("<polyline"));
+/// ```
+///
+/// [plotters]: https://docs.rs/plotters
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuadRange {
+    start: Quad,
+    end: Quad,
+}
+
+impl QuadRange {
+    /// Creates a new range that goes from `start` to `end`. If `end` is less than `start`,
+    /// the axis runs backwards.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, QuadRange};
+    /// let range = QuadRange::new(qd!(1), qd!(2));
+    /// assert!(range == QuadRange::from(qd!(1)..qd!(2)));
+    /// ```
+    pub fn new(start: Quad, end: Quad) -> QuadRange {
+        QuadRange { start, end }
+    }
+}
+
+impl From<Range<Quad>> for QuadRange {
+    /// Creates a new `QuadRange` with the same start and end as a `Range`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, QuadRange};
+    /// use plotters::coord::ranged1d::Ranged;
+    ///
+    /// let range = QuadRange::from(qd!(-1)..qd!(1));
+    /// assert!(range.range() == (qd!(-1)..qd!(1)));
+    /// ```
+    fn from(range: Range<Quad>) -> QuadRange {
+        QuadRange::new(range.start, range.end)
+    }
+}
+
+impl Ranged for QuadRange {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = Quad;
+
+    fn map(&self, value: &Quad, limit: (i32, i32)) -> i32 {
+        // A range with only one value puts everything in the middle
+        if self.start == self.end {
+            return (limit.1 - limit.0) / 2;
+        }
+        let length = limit.1 - limit.0;
+        if length == 0 {
+            return limit.1;
+        }
+
+        let logical = ((*value - self.start) / (self.end - self.start)).0;
+        if logical.is_infinite() {
+            return if logical > 0.0 { limit.1 } else { limit.0 };
+        }
+        // The small nudge keeps values that land exactly on a pixel from being floored
+        // to the one before it, which is the same thing that plotters does for `f64`s
+        if length > 0 {
+            limit.0 + (length as f64 * logical + 1e-3).floor() as i32
+        } else {
+            limit.0 + (length as f64 * logical - 1e-3).ceil() as i32
+        }
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Quad> {
+        key_points(self.start, self.end, hint.max_num_points())
+    }
+
+    fn range(&self) -> Range<Quad> {
+        self.start..self.end
+    }
+}
+
+impl ReversibleRanged for QuadRange {
+    fn unmap(&self, input: i32, (min, max): (i32, i32)) -> Option<Quad> {
+        if input < min.min(max) || input > min.max(max) || min == max {
+            None
+        } else {
+            let logical = Quad::from(input - min) / Quad::from(max - min);
+            Some(self.start + (self.end - self.start) * logical)
+        }
+    }
+}
+
+impl ValueFormatter<Quad> for QuadRange {
+    /// Writes a label for a value on the axis. The label has the fewest digits that parse
+    /// back to exactly the same value, so key points (which are round numbers) get short
+    /// labels like `0.25` or `3e-250`. Like `f64`'s `Debug` output, the label is written in
+    /// scientific notation if its exponent is less than -5 or greater than 16. Values that
+    /// don't parse back exactly with any number of digits are written with `Display`.
+    fn format(value: &Quad) -> String {
+        if !value.is_finite() || value.is_zero() {
+            return value.to_string();
+        }
+        for digits in 0..=(Quad::DIGITS as usize + 2) {
+            let sci = format!("{:.*e}", digits, value);
+            if sci.parse::<Quad>().ok() != Some(*value) {
+                continue;
+            }
+            let exp: i32 = sci[(sci.find('e').unwrap() + 1)..].parse().unwrap();
+            if (-5..17).contains(&exp) {
+                let fixed = format!("{:.*}", (digits as i32 - exp).max(0) as usize, value);
+                if fixed.parse::<Quad>().ok() == Some(*value) {
+                    return fixed;
+                }
+            }
+            return sci;
+        }
+        value.to_string()
+    }
+}
+
+// The largest multiple of a step that will be used as a key point. These are kept well
+// within `i128` so that multiplying by the step's mantissa can't overflow; a range that
+// needs anything larger is too narrow for its magnitude to have any key points that
+// `Quad` can tell apart.
+const MAX_MULTIPLE: i128 = 1_000_000_000_000_000_000_000_000_000_000_000_000;
+
+// Returns the key points of the range between `a` and `b` (in either order), no more than
+// `max` of them, in increasing order.
+fn key_points(a: Quad, b: Quad, max: usize) -> Vec<Quad> {
+    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+    if max == 0 || !lo.is_finite() || !hi.is_finite() {
+        return vec![];
+    }
+    if lo == hi {
+        return vec![lo];
+    }
+    let span = hi - lo;
+    if !span.is_finite() {
+        return vec![];
+    }
+
+    // An estimate of the power of ten of the step. The first component is plenty for
+    // this; the estimate may be off by one in either direction anyway, so steps start a
+    // power lower and go up until one fits.
+    let exp = (span.0 / max as f64).log10().floor() as i32;
+    for e in (exp - 1)..=(exp + 2) {
+        for &m in [1, 2, 5].iter() {
+            if let Some((first, last)) = multiples(lo, hi, m, e) {
+                if last - first < max as i128 {
+                    return (first..=last).map(|k| point(k * m, e)).collect();
+                }
+            }
+        }
+    }
+    vec![]
+}
+
+// Returns the first and last integers `k` for which `k·m·10^e` is in `[lo, hi]`, or `None`
+// if they're too big to handle. If no multiples are in the range, `first` is `last + 1`.
+fn multiples(lo: Quad, hi: Quad, m: i128, e: i32) -> Option<(i128, i128)> {
+    let step = point(m, e);
+    let first = to_i128((lo / step).ceil())?;
+    let last = to_i128((hi / step).floor())?;
+    // The divisions may have rounded across an integer, so the answers are checked
+    // against the key points themselves
+    let first = if point((first - 1) * m, e) >= lo {
+        first - 1
+    } else if point(first * m, e) < lo {
+        first + 1
+    } else {
+        first
+    };
+    let last = if point((last + 1) * m, e) <= hi {
+        last + 1
+    } else if point(last * m, e) > hi {
+        last - 1
+    } else {
+        last
+    };
+    Some((first, last))
+}
+
+// Converts an integer-valued `Quad` to an `i128`, as long as it's no larger than
+// `MAX_MULTIPLE`.
+fn to_i128(x: Quad) -> Option<i128> {
+    if x.is_finite() && x.abs() <= Quad::from(MAX_MULTIPLE as f64) {
+        Some(x.0 as i128 + x.1 as i128 + x.2 as i128 + x.3 as i128)
+    } else {
+        None
+    }
+}
+
+// Returns `n·10^e`. This is done by parsing so that the result is exactly the number that
+// its label parses back to. Parsing isn't exact to the last bit, so "10e-1" and "1" don't
+// necessarily parse to the same number; trailing zeros are moved into the exponent to
+// make sure that the string is always written the way that the label would be.
+fn point(mut n: i128, mut e: i32) -> Quad {
+    while n != 0 && n % 10 == 0 {
+        n /= 10;
+        e += 1;
+    }
+    format!("{}e{}", n, e).parse().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(start: &str, end: &str, max: usize) -> Vec<String> {
+        QuadRange::from(qd!(start)..qd!(end))
+            .key_points(max)
+            .iter()
+            .map(QuadRange::format)
+            .collect()
+    }
+
+    test!(key_points_unit: {
+        assert_eq!(
+            points("0", "1", 11),
+            vec!["0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1"]
+        );
+        assert_eq!(points("0", "1", 10), vec!["0", "0.2", "0.4", "0.6", "0.8", "1"]);
+        assert_eq!(points("0", "1", 5), vec!["0", "0.5", "1"]);
+        assert_eq!(points("0", "1", 2), vec!["0", "1"]);
+        assert_eq!(points("0", "1", 1), vec!["0"]);
+        assert_eq!(points("0", "1", 0), Vec::<String>::new());
+    });
+
+    test!(key_points_offset: {
+        assert_eq!(points("-0.35", "0.72", 6), vec!["-0.2", "0", "0.2", "0.4", "0.6"]);
+        assert_eq!(points("0.3", "0.7", 5), vec!["0.3", "0.4", "0.5", "0.6", "0.7"]);
+        assert_eq!(points("17", "23", 3), vec!["18", "20", "22"]);
+        assert_eq!(points("17", "23", 2), vec!["20"]);
+        // Backwards ranges have the same key points
+        assert_eq!(points("0.7", "0.3", 5), vec!["0.3", "0.4", "0.5", "0.6", "0.7"]);
+    });
+
+    test!(key_points_extreme: {
+        assert_eq!(
+            points("1e-250", "5e-250", 5),
+            vec!["1e-250", "2e-250", "3e-250", "4e-250", "5e-250"]
+        );
+        assert_eq!(points("-3e300", "3e300", 4).len(), 3);
+        assert_eq!(
+            points("1", "1.0000000000000000000001", 2),
+            vec!["1", "1.0000000000000000000001"]
+        );
+        assert_eq!(
+            points("1e-300", "1e300", 3),
+            vec!["5e299", "1e300"]
+        );
+        // Ranges too narrow to have key points that fit into an `i128` have none
+        assert!(points("1e30", "1.000000000000000000000000000000000000000001e30", 5).is_empty());
+    });
+
+    test!(key_points_exact: {
+        // Every key point is exactly what its label parses to
+        let ranges = [
+            ("0", "1"),
+            ("-7.3", "12.9"),
+            ("1e-250", "9e-250"),
+            ("-1e-300", "3e-300"),
+            ("1e300", "1.7e308"),
+            ("0.1", "0.1000000000000000000000001"),
+            ("123456789", "123456789.000000000000000001"),
+        ];
+        for &(start, end) in ranges.iter() {
+            for max in 1..=12 {
+                let range = QuadRange::from(qd!(start)..qd!(end));
+                let keys = range.key_points(max);
+                assert!(keys.len() <= max);
+                for pair in keys.windows(2) {
+                    assert!(pair[0] < pair[1]);
+                }
+                for key in keys {
+                    assert!(key >= qd!(start) && key <= qd!(end));
+                    let label = QuadRange::format(&key);
+                    assert!(
+                        label.parse::<Quad>().unwrap() == key,
+                        "label {} doesn't round-trip",
+                        label
+                    );
+                }
+            }
+        }
+    });
+
+    test!(key_points_degenerate: {
+        assert_eq!(points("2.5", "2.5", 5), vec!["2.5"]);
+        assert_eq!(
+            QuadRange::new(Quad::NAN, Quad::ONE).key_points(5),
+            Vec::<Quad>::new()
+        );
+        assert_eq!(
+            QuadRange::new(Quad::MIN, Quad::MAX).key_points(5),
+            Vec::<Quad>::new()
+        );
+    });
+
+    test!(map: {
+        let range = QuadRange::from(qd!(1)..qd!("1.00000000000000000001"));
+        assert_eq!(range.map(&qd!(1), (0, 100)), 0);
+        assert_eq!(range.map(&qd!("1.000000000000000000005"), (0, 100)), 50);
+        assert_eq!(range.map(&qd!("1.00000000000000000001"), (0, 100)), 100);
+        assert_eq!(range.map(&qd!("1.0000000000000000000025"), (100, 0)), 75);
+        assert_eq!(QuadRange::from(qd!(1)..qd!(1)).map(&qd!(5), (0, 100)), 50);
+    });
+
+    test!(unmap: {
+        let range = QuadRange::from(qd!(1)..qd!("1.00000000000000000001"));
+        let x = range.unmap(50, (0, 100)).unwrap();
+        assert!((x - qd!("1.000000000000000000005")).abs() < qd!(1e-40));
+        assert_eq!(range.unmap(0, (100, 0)), Some(qd!("1.00000000000000000001")));
+        assert_eq!(range.unmap(100, (100, 0)), Some(qd!(1)));
+        assert_eq!(range.unmap(101, (0, 100)), None);
+        assert_eq!(range.unmap(5, (5, 5)), None);
+    });
+}
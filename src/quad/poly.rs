@@ -0,0 +1,240 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::quad::common as c;
+use crate::quad::Quad;
+
+impl Quad {
+    /// Evaluates a polynomial at *x* using Horner's method.
+    ///
+    /// The coefficients start with the constant term, so `coeffs[i]` is the coefficient of
+    /// *x*<sup>i</sup>. An empty slice is the zero polynomial and evaluates to zero, and a
+    /// single coefficient evaluates to that coefficient. If *x* or any of the coefficients
+    /// is [`NAN`], so is the result (unless the slice is empty).
+    ///
+    /// Each step of the evaluation is a [`mul_add`], but the rounding errors of the steps
+    /// still add up, and near a root of the polynomial they can be much larger than the
+    /// result. When that matters, [`polyval_compensated`] is much more accurate.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// // 1 + 2x + 3x²
+    /// let coeffs = [qd!(1), qd!(2), qd!(3)];
+    /// assert!(Quad::polyval(&coeffs, qd!(2)) == qd!(17));
+    /// assert!(Quad::polyval(&[], qd!(2)) == qd!(0));
+    /// ```
+    ///
+    /// [`mul_add`]: #method.mul_add
+    /// [`polyval_compensated`]: #method.polyval_compensated
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn polyval(coeffs: &[Quad], x: Quad) -> Quad {
+        match coeffs.split_last() {
+            None => Quad::ZERO,
+            Some(_) if x.is_nan() => Quad::NAN,
+            Some((&last, rest)) => rest.iter().rev().fold(last, |r, &a| r.mul_add(x, a)),
+        }
+    }
+
+    /// Evaluates a polynomial at *x* using a compensated version of Horner's method.
+    ///
+    /// This works like [`polyval`], but it also calculates the rounding error of every
+    /// product and sum in the evaluation and runs those errors through a second Horner
+    /// recurrence of their own. Adding that to the result at the end makes it as accurate
+    /// as if it had been evaluated with twice the precision of a `Quad` and then rounded.
+    /// In particular, polynomials evaluated near their roots, where the terms cancel each
+    /// other, keep nearly full precision in cases where [`polyval`] loses most of it.
+    ///
+    /// This is several times slower than [`polyval`]. Its handling of empty slices, single
+    /// coefficients, and NaNs is the same. If any step of the evaluation overflows, there's
+    /// no error to compensate for, and the result is the same as [`polyval`]'s.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// // (x - 1)^7, expanded, evaluated close to its root
+    /// let coeffs: Vec<Quad> = [-1, 7, -21, 35, -35, 21, -7, 1]
+    ///     .iter()
+    ///     .map(|&a| qd!(a))
+    ///     .collect();
+    /// let x = qd!(1) + Quad::PI.ldexp(-14);
+    /// let expected = (x - qd!(1)).powi(7);
+    ///
+    /// // Horner's method loses more than twenty digits, while the compensated version
+    /// // keeps nearly all of them
+    /// let naive = Quad::polyval(&coeffs, x);
+    /// let compensated = Quad::polyval_compensated(&coeffs, x);
+    /// assert!(((naive - expected) / expected).abs() > qd!(1e-45));
+    /// assert!(((compensated - expected) / expected).abs() < qd!(1e-60));
+    /// ```
+    ///
+    /// [`polyval`]: #method.polyval
+    pub fn polyval_compensated(coeffs: &[Quad], x: Quad) -> Quad {
+        let (&last, rest) = match coeffs.split_last() {
+            None => return Quad::ZERO,
+            Some(split) => split,
+        };
+        if x.is_nan() {
+            return Quad::NAN;
+        }
+        let mut r = last;
+        let mut err = Quad::ZERO;
+        for &a in rest.iter().rev() {
+            let p = r * x;
+            let s = p + a;
+            if !s.is_finite() {
+                // Either overflow or a NaN coefficient, and neither has an error term
+                return Quad::polyval(coeffs, x);
+            }
+            let e = c::mul_add(r, x, -p) + c::add_error(p, a, s);
+            err = err.mul_add(x, e);
+            r = s;
+        }
+        r + err
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The coefficients of the Chebyshev polynomial T_n, constant term first
+    fn chebyshev(n: usize) -> Vec<Quad> {
+        let mut prev = vec![1i64];
+        let mut curr = vec![0i64, 1];
+        for _ in 1..n {
+            let mut next = vec![0i64; curr.len() + 1];
+            for (i, &a) in curr.iter().enumerate() {
+                next[i + 1] += 2 * a;
+            }
+            for (i, &a) in prev.iter().enumerate() {
+                next[i] -= a;
+            }
+            prev = curr;
+            curr = next;
+        }
+        curr.iter().map(|&a| Quad::from(a)).collect()
+    }
+
+    // The coefficients of the first n terms of the Taylor series of e^x
+    fn inv_facts(n: usize) -> Vec<Quad> {
+        let mut coeffs = vec![Quad::ONE];
+        for i in 1..n {
+            coeffs.push(coeffs[i - 1] / Quad::from(i as u32));
+        }
+        coeffs
+    }
+
+    // polyval tests
+    test_all_exact!(
+        polyval_empty:
+            Quad::ZERO,
+            Quad::polyval(&[], Quad::PI);
+        polyval_empty_nan:
+            Quad::ZERO,
+            Quad::polyval(&[], Quad::NAN);
+        polyval_constant:
+            Quad::E,
+            Quad::polyval(&[Quad::E], Quad::PI);
+        polyval_constant_inf:
+            Quad::E,
+            Quad::polyval(&[Quad::E], Quad::INFINITY);
+        polyval_quadratic:
+            qd!(17),
+            Quad::polyval(&[qd!(1), qd!(2), qd!(3)], qd!(2));
+        polyval_neg:
+            qd!(-32),
+            Quad::polyval(&[qd!(1), qd!(-2), qd!(-3)], qd!(3));
+        polyval_zero:
+            qd!(1),
+            Quad::polyval(&[qd!(1), qd!(2), qd!(3)], Quad::ZERO);
+        polyval_chebyshev_1:
+            qd!(1),
+            Quad::polyval(&chebyshev(20), qd!(1));
+        polyval_chebyshev_neg_1:
+            qd!(1),
+            Quad::polyval(&chebyshev(20), qd!(-1));
+
+        polyval_inf:
+            Quad::INFINITY,
+            Quad::polyval(&[qd!(1), qd!(2), qd!(3)], Quad::INFINITY);
+        polyval_neg_inf:
+            Quad::INFINITY,
+            Quad::polyval(&[qd!(1), qd!(2), qd!(3)], Quad::NEG_INFINITY);
+        polyval_nan:
+            Quad::NAN,
+            Quad::polyval(&[qd!(1), qd!(2)], Quad::NAN);
+        polyval_nan_constant:
+            Quad::NAN,
+            Quad::polyval(&[qd!(1)], Quad::NAN);
+        polyval_nan_coeff:
+            Quad::NAN,
+            Quad::polyval(&[qd!(1), Quad::NAN, qd!(3)], qd!(2));
+        polyval_nan_last:
+            Quad::NAN,
+            Quad::polyval(&[qd!(1), qd!(2), Quad::NAN], qd!(2));
+    );
+    test_all_near!(
+        polyval_pi:
+            qd!("36.891998510447662333428759766187459174335437020472583521189937313"),
+            Quad::polyval(&[qd!(1), qd!(2), qd!(3)], Quad::PI);
+        polyval_series:
+            qd!("2.7182818284590452353602874713526624977572470936999595749669676277"),
+            Quad::polyval(&inv_facts(50), qd!(1));
+    );
+
+    // polyval_compensated tests
+    test_all_exact!(
+        polyval_compensated_empty:
+            Quad::ZERO,
+            Quad::polyval_compensated(&[], Quad::PI);
+        polyval_compensated_empty_nan:
+            Quad::ZERO,
+            Quad::polyval_compensated(&[], Quad::NAN);
+        polyval_compensated_constant:
+            Quad::E,
+            Quad::polyval_compensated(&[Quad::E], Quad::PI);
+        polyval_compensated_quadratic:
+            qd!(17),
+            Quad::polyval_compensated(&[qd!(1), qd!(2), qd!(3)], qd!(2));
+        polyval_compensated_chebyshev_1:
+            qd!(1),
+            Quad::polyval_compensated(&chebyshev(20), qd!(1));
+
+        polyval_compensated_inf:
+            Quad::INFINITY,
+            Quad::polyval_compensated(&[qd!(1), qd!(2), qd!(3)], Quad::INFINITY);
+        polyval_compensated_nan:
+            Quad::NAN,
+            Quad::polyval_compensated(&[qd!(1), qd!(2)], Quad::NAN);
+        polyval_compensated_nan_constant:
+            Quad::NAN,
+            Quad::polyval_compensated(&[qd!(1)], Quad::NAN);
+        polyval_compensated_nan_coeff:
+            Quad::NAN,
+            Quad::polyval_compensated(&[qd!(1), Quad::NAN, qd!(3)], qd!(2));
+    );
+    test_all_near!(
+        polyval_compensated_pi:
+            qd!("36.891998510447662333428759766187459174335437020472583521189937313"),
+            Quad::polyval_compensated(&[qd!(1), qd!(2), qd!(3)], Quad::PI);
+    );
+
+    // T_20 has a root at cos(π/40), and this x is about 3e-8 away from it. The terms of
+    // the polynomial are as large as 10^7 there while its value is about 10^-5, so Horner's
+    // method loses about 9 of its 62 digits to cancellation.
+    test!(polyval_chebyshev_near_root: {
+        let coeffs = chebyshev(20);
+        let x = Quad(0.9969173, 0.0, 0.0, 0.0);
+        let expected = qd!("-8.598884683929931707859413995825182524871605954167456064470241884585e-6");
+
+        let naive = Quad::polyval(&coeffs, x);
+        let compensated = Quad::polyval_compensated(&coeffs, x);
+        let naive_err = ((naive - expected) / expected).abs();
+        let compensated_err = ((compensated - expected) / expected).abs();
+        assert!(naive_err > qd!(1e-56), "naive error only {:e}", naive_err);
+        assert!(compensated_err < qd!(1e-61), "compensated error {:e}", compensated_err);
+    });
+}
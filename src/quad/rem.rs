@@ -3,16 +3,27 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::strict as s;
+use crate::common::utils as u;
+use crate::quad::common as c;
 use crate::quad::Quad;
-use std::ops::{Div, Rem, RemAssign};
+use std::ops::{Rem, RemAssign};
 
 impl Rem for Quad {
     type Output = Quad;
 
     /// Divides this `Quad` by another, producing a new `Quad` of the remainder as a
-    /// result. This operation uses floored division.
+    /// result.
     ///
-    /// This implements the `%` operator between two `Quad`s.
+    /// This implements the `%` operator between two `Quad`s, with the same semantics as
+    /// the `%` operator between two `f64`s. The quotient is truncated toward zero, so the
+    /// remainder has the sign of the dividend and is smaller in magnitude than the
+    /// divisor. The remainder of an infinite dividend or of division by zero is NaN, and
+    /// the remainder of a finite dividend divided by an infinity is the dividend itself.
+    ///
+    /// The quotient is never calculated. Instead the remainder is calculated exactly, by
+    /// long division, and then rounded once, so it's accurate even when the quotient is
+    /// far too large for every one of its digits to matter.
     ///
     /// # Examples
     /// ```
@@ -23,16 +34,25 @@ impl Rem for Quad {
     /// let diffx = (x - xpected).abs();
     /// assert!(diffx < qd!(1e-60));
     ///
-    /// let y = Quad::PI % -Quad::E;
-    /// let ypected = qd!("-2.2949710033282972322579315594258221113173247880248133289589906631");
-    ///
-    /// let diffy = (y - ypected).abs();
+    /// // The remainder has the sign of the dividend
+    /// let y = -Quad::PI % -Quad::E;
+    /// let diffy = (y + xpected).abs();
     /// assert!(diffy < qd!(1e-60));
+    ///
+    /// // The quotient is about 3.2 × 10^39, but the remainder is still accurate
+    /// let z = -qd!("1e40") % Quad::PI;
+    /// let zpected = qd!("-0.60605974712826489287916054445835430518160798783212848372844510102138");
+    ///
+    /// let diffz = (z - zpected).abs();
+    /// assert!(diffz < qd!(1e-64));
     /// ```
     #[inline]
     fn rem(self, other: Quad) -> Quad {
-        let n = self.div(other).floor();
-        self - other * n
+        let r = match self.pre_rem(&other) {
+            Some(r) => r,
+            None => rem_trunc(self, other),
+        };
+        s::guard("Quad % Quad", &[self.0, other.0], r)
     }
 }
 
@@ -40,7 +60,7 @@ impl Rem for &Quad {
     type Output = Quad;
 
     /// Divides a reference to this `Quad` by another, producing a new `Quad` of the
-    /// remainder as a result. This operation uses floored division.
+    /// remainder as a result. The quotient is truncated toward zero, as it is for `f64`s.
     ///
     /// This implements the `%` operator between two references to `Quad`s.
     ///
@@ -53,10 +73,9 @@ impl Rem for &Quad {
     /// let diffx = (x - xpected).abs();
     /// assert!(diffx < qd!(1e-60));
     ///
-    /// let y = &Quad::PI % -Quad::E;
-    /// let ypected = qd!("-2.2949710033282972322579315594258221113173247880248133289589906631");
-    ///
-    /// let diffy = (y - ypected).abs();
+    /// // The remainder has the sign of the dividend
+    /// let y = &-Quad::PI % &Quad::E;
+    /// let diffy = (y + xpected).abs();
     /// assert!(diffy < qd!(1e-60));
     /// ```
     #[inline]
@@ -69,7 +88,7 @@ impl Rem<&Quad> for Quad {
     type Output = Quad;
 
     /// Divides this `Quad` by a reference to another, producing a new `Quad` of the
-    /// remainder as a result. This operation uses floored division.
+    /// remainder as a result. The quotient is truncated toward zero, as it is for `f64`s.
     ///
     /// This implements the `%` operator between a `Quad` and a reference to a `Quad`.
     ///
@@ -82,10 +101,9 @@ impl Rem<&Quad> for Quad {
     /// let diffx = (x - xpected).abs();
     /// assert!(diffx < qd!(1e-60));
     ///
-    /// let y = Quad::PI % -&Quad::E;
-    /// let ypected = qd!("-2.2949710033282972322579315594258221113173247880248133289589906631");
-    ///
-    /// let diffy = (y - ypected).abs();
+    /// // The remainder has the sign of the dividend
+    /// let y = -Quad::PI % -&Quad::E;
+    /// let diffy = (y + xpected).abs();
     /// assert!(diffy < qd!(1e-60));
     /// ```
     #[inline]
@@ -97,8 +115,9 @@ impl Rem<&Quad> for Quad {
 impl Rem<Quad> for &Quad {
     type Output = Quad;
 
-    /// Divides a reference to this `Quad` by another `Quad`, producing a new `Quad` of the
-    /// remainder as a result. This operation uses floored division.
+    /// Divides a reference to this `Quad` by another `Quad`, producing a new `Quad` of
+    /// the remainder as a result. The quotient is truncated toward zero, as it is for
+    /// `f64`s.
     ///
     /// This implements the `%` operator between a reference to a `Quad` and a `Quad`.
     ///
@@ -111,10 +130,9 @@ impl Rem<Quad> for &Quad {
     /// let diffx = (x - xpected).abs();
     /// assert!(diffx < qd!(1e-60));
     ///
-    /// let y = &Quad::PI % -&Quad::E;
-    /// let ypected = qd!("-2.2949710033282972322579315594258221113173247880248133289589906631");
-    ///
-    /// let diffy = (y - ypected).abs();
+    /// // The remainder has the sign of the dividend
+    /// let y = &-Quad::PI % Quad::E;
+    /// let diffy = (y + xpected).abs();
     /// assert!(diffy < qd!(1e-60));
     /// ```
     #[inline]
@@ -124,8 +142,8 @@ impl Rem<Quad> for &Quad {
 }
 
 impl RemAssign for Quad {
-    /// Divides this `Quad` by another, modifying this one to equal the remainder. This
-    /// operation uses floored division.
+    /// Divides this `Quad` by another, modifying this one to equal the remainder. The
+    /// quotient is truncated toward zero, as it is for `f64`s.
     ///
     /// This implements the `%=` operator between two `Quad`s.
     ///
@@ -139,11 +157,10 @@ impl RemAssign for Quad {
     /// let diffx = (x - xpected).abs();
     /// assert!(diffx < qd!(1e-60));
     ///
-    /// let mut y = Quad::PI;
+    /// let mut y = -Quad::PI;
     /// y %= -Quad::E;
-    /// let ypected = qd!("-2.2949710033282972322579315594258221113173247880248133289589906631");
     ///
-    /// let diffy = (y - ypected).abs();
+    /// let diffy = (y + xpected).abs();
     /// assert!(diffy < qd!(1e-60));
     /// ```
     #[inline]
@@ -158,7 +175,7 @@ impl RemAssign for Quad {
 
 impl RemAssign<&Quad> for Quad {
     /// Divides this `Quad` by a reference to another, modifying this one to equal the
-    /// remainder. This operation uses floored division.
+    /// remainder. The quotient is truncated toward zero, as it is for `f64`s.
     ///
     /// This implements the `%=` operator between a `Quad` and a reference to a `Quad`.
     ///
@@ -172,11 +189,10 @@ impl RemAssign<&Quad> for Quad {
     /// let diffx = (x - xpected).abs();
     /// assert!(diffx < qd!(1e-60));
     ///
-    /// let mut y = Quad::PI;
+    /// let mut y = -Quad::PI;
     /// y %= -&Quad::E;
-    /// let ypected = qd!("-2.2949710033282972322579315594258221113173247880248133289589906631");
     ///
-    /// let diffy = (y - ypected).abs();
+    /// let diffy = (y + xpected).abs();
     /// assert!(diffy < qd!(1e-60));
     /// ```
     #[inline]
@@ -189,9 +205,186 @@ impl RemAssign<&Quad> for Quad {
     }
 }
 
+impl Quad {
+    /// Calculates the quotient of Euclidean division of the `Quad` by `rhs`.
+    ///
+    /// This has the same semantics as [`f64::div_euclid`]. The result is the integer *n*
+    /// such that `self` = *n* × `rhs` + *r*, where *r* is [`rem_euclid`] of the same
+    /// arguments and is in [0, |`rhs`|). In other words, it's `self / rhs` rounded toward
+    /// negative infinity if `rhs` is positive and toward positive infinity if `rhs` is
+    /// negative.
+    ///
+    /// The quotient is derived from the exact remainder, so it's the right integer even
+    /// when `self / rhs` is close enough to an integer to round onto it. Quotients larger
+    /// than about 10<sup>63</sup> can't all be represented, so those are rounded.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let a = qd!(7);
+    /// let b = qd!(4);
+    /// assert!(a.div_euclid(b) == qd!(1));
+    /// assert!((-a).div_euclid(b) == qd!(-2));
+    /// assert!(a.div_euclid(-b) == qd!(-1));
+    /// assert!((-a).div_euclid(-b) == qd!(2));
+    /// ```
+    ///
+    /// [`f64::div_euclid`]: https://doc.rust-lang.org/std/primitive.f64.html#method.div_euclid
+    /// [`rem_euclid`]: #method.rem_euclid
+    pub fn div_euclid(self, rhs: Quad) -> Quad {
+        match self.pre_div_euclid(&rhs) {
+            Some(r) => s::guard("Quad::div_euclid", &[self.0, rhs.0], r),
+            None => {
+                // `self` minus the truncated remainder is a multiple of `rhs` (up to
+                // rounding), so dividing it by `rhs` only needs rounding to an integer
+                let r = rem_trunc(self, rhs);
+                let n = self - r;
+                let q = if n.is_zero() {
+                    if self.is_sign_negative() == rhs.is_sign_negative() {
+                        Quad::ZERO
+                    } else {
+                        Quad::NEG_ZERO
+                    }
+                } else {
+                    (n / rhs).round()
+                };
+                if r < Quad::ZERO {
+                    if rhs > Quad::ZERO {
+                        q - Quad::ONE
+                    } else {
+                        q + Quad::ONE
+                    }
+                } else {
+                    q
+                }
+            }
+        }
+    }
+
+    /// Calculates the least nonnegative remainder of the `Quad` divided by `rhs`.
+    ///
+    /// This has the same semantics as [`f64::rem_euclid`]. The result is in
+    /// [0, |`rhs`|), and it differs from `self` by an integer multiple of `rhs`, namely
+    /// [`div_euclid`] of the same arguments.
+    ///
+    /// Like the `%` operator, this doesn't calculate the quotient first. The remainder is
+    /// calculated exactly, by long division, and then rounded once, so it's accurate even
+    /// when the quotient is far too large for every one of its digits to matter. As with
+    /// `f64`, a negative `self` that is tiny compared to `rhs` can produce |`rhs`| itself,
+    /// because that's what |`rhs`| minus a tiny number rounds to.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let a = qd!(7);
+    /// let b = qd!(4);
+    /// assert!(a.rem_euclid(b) == qd!(3));
+    /// assert!((-a).rem_euclid(b) == qd!(1));
+    /// assert!(a.rem_euclid(-b) == qd!(3));
+    /// assert!((-a).rem_euclid(-b) == qd!(1));
+    ///
+    /// // The quotient is about 3.2 × 10^39, but the remainder is still accurate
+    /// let x = qd!("1e40").rem_euclid(Quad::PI);
+    /// let expected = qd!("0.60605974712826489287916054445835430518160798783212848372844510102138");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-64));
+    /// ```
+    ///
+    /// [`f64::rem_euclid`]: https://doc.rust-lang.org/std/primitive.f64.html#method.rem_euclid
+    /// [`div_euclid`]: #method.div_euclid
+    pub fn rem_euclid(self, rhs: Quad) -> Quad {
+        match self.pre_rem_euclid(&rhs) {
+            Some(r) => s::guard("Quad::rem_euclid", &[self.0, rhs.0], r),
+            None => {
+                let r = rem_trunc(self, rhs);
+                if r < Quad::ZERO {
+                    r + rhs.abs()
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
+    // Precalc functions
+    //
+    // This series of functions returns `Some` with a value that is to be returned, if it
+    // turns out that the function doesn't have to be calculated because a shortcut result
+    // is known. They return `None` if the value has to be calculated normally.
+    //
+    // This keeps the public functions from being mucked up with code that does validation
+    // rather than calculation.
+
+    #[inline]
+    fn pre_rem(&self, other: &Quad) -> Option<Quad> {
+        if self.is_nan() || other.is_nan() || self.is_infinite() || other.is_zero() {
+            Some(Quad::NAN)
+        } else if other.is_infinite() || self.is_zero() {
+            Some(*self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_div_euclid(&self, rhs: &Quad) -> Option<Quad> {
+        if self.is_nan() || rhs.is_nan() {
+            Some(Quad::NAN)
+        } else if rhs.is_zero() || self.is_infinite() {
+            Some(*self / *rhs)
+        } else if rhs.is_infinite() {
+            if *self < Quad::ZERO {
+                if rhs.is_sign_positive() {
+                    Some(Quad::NEG_ONE)
+                } else {
+                    Some(Quad::ONE)
+                }
+            } else {
+                Some(*self / *rhs)
+            }
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_rem_euclid(&self, rhs: &Quad) -> Option<Quad> {
+        if self.is_nan() || rhs.is_nan() || self.is_infinite() || rhs.is_zero() {
+            Some(Quad::NAN)
+        } else if rhs.is_infinite() {
+            if *self < Quad::ZERO {
+                Some(Quad::INFINITY)
+            } else {
+                Some(*self)
+            }
+        } else {
+            None
+        }
+    }
+}
+
+// Calculates the remainder of the division of `a` by `b` with the quotient truncated toward
+// zero, like the `%` operator on `f64`s does, so the remainder has the sign of `a`. Both
+// have to be finite, and `b` can't be zero. The remainder is exact until it's rounded into
+// the returned `Quad`.
+fn rem_trunc(a: Quad, b: Quad) -> Quad {
+    let (x, y) = (a.abs(), b.abs());
+    let t = u::rem_terms(&[x.0, x.1, x.2, x.3], &[y.0, y.1, y.2, y.3]);
+    let n = t.len();
+    let rest: f64 = t[..n - 4].iter().sum();
+    let r = c::from_components(t[n - 1], t[n - 2], t[n - 3], t[n - 4] + rest);
+    if a.is_sign_negative() {
+        -r
+    } else {
+        r
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::random::Rng;
 
     // rem tests
     test_all_near!(
@@ -208,16 +401,16 @@ mod tests {
             qd!("0.42331082513074800310235591192684038643992230567514624600797696458298"),
             &Quad::PI % &Quad::E;
         num_neg_num:
-            qd!("-2.2949710033282972322579315594258221113173247880248133289589906631409"),
+            qd!("0.42331082513074800310235591192684038643992230567514624600797696458298"),
             Quad::PI % -Quad::E;
         num_neg_ref:
-            qd!("-2.2949710033282972322579315594258221113173247880248133289589906631409"),
+            qd!("0.42331082513074800310235591192684038643992230567514624600797696458298"),
             Quad::PI % -&Quad::E;
         ref_neg_num:
-            qd!("-2.2949710033282972322579315594258221113173247880248133289589906631409"),
+            qd!("0.42331082513074800310235591192684038643992230567514624600797696458298"),
             &Quad::PI % -Quad::E;
         ref_neg_ref:
-            qd!("-2.2949710033282972322579315594258221113173247880248133289589906631409"),
+            qd!("0.42331082513074800310235591192684038643992230567514624600797696458298"),
             &Quad::PI % -&Quad::E;
         num_id:
             qd!("0.14159265358979323846264338327950288419716939937510582097494459230689"),
@@ -246,26 +439,26 @@ mod tests {
             Quad::NAN,
             Quad::INFINITY % Quad::ZERO;
         zero_inf:
-            Quad::NAN,
+            Quad::ZERO,
             Quad::ZERO % Quad::INFINITY;
         neg_inf_zero:
             Quad::NAN,
             Quad::NEG_INFINITY % Quad::ZERO;
         zero_neg_inf:
-            Quad::NAN,
+            Quad::ZERO,
             Quad::ZERO % Quad::NEG_INFINITY;
 
         inf_one:
             Quad::NAN,
             Quad::INFINITY % Quad::ONE;
         one_inf:
-            Quad::NAN,
+            Quad::ONE,
             Quad::ONE % Quad::INFINITY;
         neg_inf_one:
             Quad::NAN,
             Quad::NEG_INFINITY % Quad::ONE;
         one_neg_inf:
-            Quad::NAN,
+            Quad::ONE,
             Quad::ONE % Quad::NEG_INFINITY;
         inf_inf:
             Quad::NAN,
@@ -291,6 +484,283 @@ mod tests {
             Quad::ONE % Quad::NAN;
     );
 
+    test_all_exact!(
+        pos_pos:
+            qd!(3),
+            qd!(7) % qd!(4);
+        neg_pos:
+            qd!(-3),
+            qd!(-7) % qd!(4);
+        pos_neg:
+            qd!(3),
+            qd!(7) % qd!(-4);
+        neg_neg:
+            qd!(-3),
+            qd!(-7) % qd!(-4);
+        frac:
+            qd!(-1.25),
+            qd!(-7.25) % qd!(-2);
+        small_num:
+            qd!(-0.5),
+            qd!(-0.5) % qd!(4);
+        neg_one_inf:
+            Quad::NEG_ONE,
+            Quad::NEG_ONE % Quad::INFINITY;
+        big_pos_pos:
+            qd!(2),
+            Quad(2f64.powi(60), 1.0, 0.0, 0.0) % qd!(3);
+        big_neg_pos:
+            qd!(-2),
+            Quad(-(2f64.powi(60)), -1.0, 0.0, 0.0) % qd!(3);
+        big_pos_neg:
+            qd!(2),
+            Quad(2f64.powi(60), 1.0, 0.0, 0.0) % qd!(-3);
+        huger:
+            qd!(2),
+            Quad(2f64.powi(100), 1.0, 0.0, 0.0) % qd!(5);
+        hugest:
+            qd!(-2),
+            Quad(-(2f64.powi(1000)), 0.0, 0.0, 0.0) % qd!(-7);
+    );
+    test_all_assert!(
+        neg_multiple_sign:
+            (qd!(-8) % qd!(4)).is_sign_negative();
+        pos_multiple_sign:
+            (qd!(8) % qd!(-4)).is_sign_positive();
+        neg_zero_sign:
+            (Quad::NEG_ZERO % Quad::ONE).is_sign_negative();
+    );
+
+    // `%` on `f64`s is exact, so on `Quad`s made from them it has to give exactly the same
+    // result, even when the quotient is far beyond 2^53
+    test!(matches_f64: {
+        let mut rng = Rng::new(0x0123_4567_89ab_cdef);
+        let mut random = |e: i32| {
+            let x = (1.0 + rng.next_f64()) * 2f64.powi(e);
+            if rng.next_u64() % 2 == 0 { x } else { -x }
+        };
+        for i in 0..20_000 {
+            let y = random(i % 200 - 100);
+            let x = random(i % 200 - 100 + i % 400);
+            let r = Quad(x, 0.0, 0.0, 0.0) % Quad(y, 0.0, 0.0, 0.0);
+            assert!(r == Quad(x % y, 0.0, 0.0, 0.0), "{:?} % {:?} = {:?}", x, y, r);
+            assert!(r.is_sign_negative() == x.is_sign_negative());
+        }
+    });
+
+    // div_euclid tests
+    test_all_exact!(
+        div_euclid_pos_pos:
+            qd!(1),
+            qd!(7).div_euclid(qd!(4));
+        div_euclid_neg_pos:
+            qd!(-2),
+            qd!(-7).div_euclid(qd!(4));
+        div_euclid_pos_neg:
+            qd!(-1),
+            qd!(7).div_euclid(qd!(-4));
+        div_euclid_neg_neg:
+            qd!(2),
+            qd!(-7).div_euclid(qd!(-4));
+        div_euclid_multiple:
+            qd!(-2),
+            qd!(-8).div_euclid(qd!(4));
+        div_euclid_small:
+            Quad::NEG_ONE,
+            qd!("-1e-70").div_euclid(qd!(4));
+        div_euclid_below_int:
+            qd!(2),
+            Quad(9.42477796076938, 3.6739403974420594e-16, 1.56675938590016e-32, -1.0347192653081947e-48).div_euclid(Quad::PI);
+        div_euclid_above_int:
+            qd!(3),
+            Quad(9.42477796076938, 3.6739403974420594e-16, 1.56675938590016e-32, -1.0347192653081946e-48).div_euclid(Quad::PI);
+        div_euclid_neg_below_int:
+            qd!(-3),
+            Quad(-9.42477796076938, -3.6739403974420594e-16, -1.56675938590016e-32, 1.0347192653081947e-48).div_euclid(Quad::PI);
+        div_euclid_big_pos_pos:
+            qd!("535646014752996758513987364113720867507400997927597611767125"),
+            Quad(2f64.powi(200), 1.0, 0.0, 0.0).div_euclid(qd!(3));
+        div_euclid_big_neg_pos:
+            qd!("-535646014752996758513987364113720867507400997927597611767126"),
+            Quad(-(2f64.powi(200)), -1.0, 0.0, 0.0).div_euclid(qd!(3));
+        div_euclid_big_pos_neg:
+            qd!("-535646014752996758513987364113720867507400997927597611767125"),
+            Quad(2f64.powi(200), 1.0, 0.0, 0.0).div_euclid(qd!(-3));
+        div_euclid_big_neg_neg:
+            qd!("535646014752996758513987364113720867507400997927597611767126"),
+            Quad(-(2f64.powi(200)), -1.0, 0.0, 0.0).div_euclid(qd!(-3));
+        div_euclid_huge_pos_pos:
+            qd!("3183098861837906715377675267450287240689"),
+            qd!("1e40").div_euclid(Quad::PI);
+        div_euclid_huge_neg_pos:
+            qd!("-3183098861837906715377675267450287240690"),
+            qd!("-1e40").div_euclid(Quad::PI);
+        div_euclid_huge_pos_neg:
+            qd!("-3183098861837906715377675267450287240689"),
+            qd!("1e40").div_euclid(-Quad::PI);
+        div_euclid_huge_neg_neg:
+            qd!("3183098861837906715377675267450287240690"),
+            qd!("-1e40").div_euclid(-Quad::PI);
+    );
+    test_all_exact!(
+        div_euclid_zero_num:
+            Quad::ZERO,
+            Quad::ZERO.div_euclid(qd!(4));
+        div_euclid_neg_zero_num:
+            Quad::NEG_ZERO,
+            Quad::NEG_ZERO.div_euclid(qd!(4));
+        div_euclid_num_zero:
+            Quad::INFINITY,
+            qd!(7).div_euclid(Quad::ZERO);
+        div_euclid_neg_num_zero:
+            Quad::NEG_INFINITY,
+            qd!(-7).div_euclid(Quad::ZERO);
+        div_euclid_zero_zero:
+            Quad::NAN,
+            Quad::ZERO.div_euclid(Quad::ZERO);
+        div_euclid_inf_num:
+            Quad::INFINITY,
+            Quad::INFINITY.div_euclid(qd!(4));
+        div_euclid_inf_neg_num:
+            Quad::NEG_INFINITY,
+            Quad::INFINITY.div_euclid(qd!(-4));
+        div_euclid_inf_inf:
+            Quad::NAN,
+            Quad::INFINITY.div_euclid(Quad::INFINITY);
+        div_euclid_num_inf:
+            Quad::ZERO,
+            qd!(7).div_euclid(Quad::INFINITY);
+        div_euclid_neg_num_inf:
+            Quad::NEG_ONE,
+            qd!(-7).div_euclid(Quad::INFINITY);
+        div_euclid_neg_num_neg_inf:
+            Quad::ONE,
+            qd!(-7).div_euclid(Quad::NEG_INFINITY);
+        div_euclid_nan_num:
+            Quad::NAN,
+            Quad::NAN.div_euclid(qd!(4));
+        div_euclid_num_nan:
+            Quad::NAN,
+            qd!(7).div_euclid(Quad::NAN);
+    );
+    test_all_assert!(
+        div_euclid_pos_neg_zero_sign:
+            qd!(1).div_euclid(qd!(-4)).is_sign_negative();
+        div_euclid_num_neg_inf_sign:
+            qd!(7).div_euclid(Quad::NEG_INFINITY).is_sign_negative();
+    );
+
+    // rem_euclid tests
+    test_all_exact!(
+        rem_euclid_pos_pos:
+            qd!(3),
+            qd!(7).rem_euclid(qd!(4));
+        rem_euclid_neg_pos:
+            qd!(1),
+            qd!(-7).rem_euclid(qd!(4));
+        rem_euclid_pos_neg:
+            qd!(3),
+            qd!(7).rem_euclid(qd!(-4));
+        rem_euclid_neg_neg:
+            qd!(1),
+            qd!(-7).rem_euclid(qd!(-4));
+        rem_euclid_frac:
+            qd!(0.75),
+            qd!(-7.25).rem_euclid(qd!(2));
+        rem_euclid_below_int:
+            Quad(Quad::PI[0], 1.2246467991473532e-16, -2.9947698097183397e-33, 1.112454220863364e-49),
+            Quad(9.42477796076938, 3.6739403974420594e-16, 1.56675938590016e-32, -1.0347192653081947e-48).rem_euclid(Quad::PI);
+        rem_euclid_above_int:
+            Quad(1.8991135491519597e-65, 0.0, 0.0, 0.0),
+            Quad(9.42477796076938, 3.6739403974420594e-16, 1.56675938590016e-32, -1.0347192653081946e-48).rem_euclid(Quad::PI);
+        rem_euclid_neg_below_int:
+            Quad(1.3293794844063718e-64, 0.0, 0.0, 0.0),
+            Quad(-9.42477796076938, -3.6739403974420594e-16, -1.56675938590016e-32, 1.0347192653081947e-48).rem_euclid(Quad::PI);
+        rem_euclid_big_pos_pos:
+            qd!(2),
+            Quad(2f64.powi(200), 1.0, 0.0, 0.0).rem_euclid(qd!(3));
+        rem_euclid_big_neg_pos:
+            qd!(1),
+            Quad(-(2f64.powi(200)), -1.0, 0.0, 0.0).rem_euclid(qd!(3));
+        rem_euclid_big_pos_neg:
+            qd!(2),
+            Quad(2f64.powi(200), 1.0, 0.0, 0.0).rem_euclid(qd!(-3));
+        rem_euclid_big_neg_neg:
+            qd!(1),
+            Quad(-(2f64.powi(200)), -1.0, 0.0, 0.0).rem_euclid(qd!(-3));
+        rem_euclid_hugest:
+            qd!(2),
+            Quad(2f64.powi(1000), 0.0, 0.0, 0.0).rem_euclid(qd!(7));
+    );
+    test_all_near!(
+        rem_euclid_huge_pos_pos:
+            qd!("0.606059747128264892879160544458354305181607987832128483728445101021384533"),
+            qd!("1e40").rem_euclid(Quad::PI);
+        rem_euclid_huge_neg_pos:
+            qd!("2.53553290646152834558348283882114857901556141154297733724649949128075964"),
+            qd!("-1e40").rem_euclid(Quad::PI);
+        rem_euclid_huge_pos_neg:
+            qd!("0.606059747128264892879160544458354305181607987832128483728445101021384533"),
+            qd!("1e40").rem_euclid(-Quad::PI);
+        rem_euclid_huge_neg_neg:
+            qd!("2.53553290646152834558348283882114857901556141154297733724649949128075964"),
+            qd!("-1e40").rem_euclid(-Quad::PI);
+    );
+    test_all_exact!(
+        rem_euclid_multiple:
+            Quad::ZERO,
+            qd!(8).rem_euclid(qd!(4));
+        rem_euclid_neg_multiple:
+            Quad::NEG_ZERO,
+            qd!(-8).rem_euclid(qd!(4));
+        rem_euclid_num_zero:
+            Quad::NAN,
+            qd!(7).rem_euclid(Quad::ZERO);
+        rem_euclid_inf_num:
+            Quad::NAN,
+            Quad::INFINITY.rem_euclid(qd!(4));
+        rem_euclid_num_inf:
+            qd!(7),
+            qd!(7).rem_euclid(Quad::INFINITY);
+        rem_euclid_neg_num_inf:
+            Quad::INFINITY,
+            qd!(-7).rem_euclid(Quad::INFINITY);
+        rem_euclid_nan_num:
+            Quad::NAN,
+            Quad::NAN.rem_euclid(qd!(4));
+        rem_euclid_num_nan:
+            Quad::NAN,
+            qd!(7).rem_euclid(Quad::NAN);
+    );
+    test_all_assert!(
+        rem_euclid_neg_multiple_sign:
+            qd!(-8).rem_euclid(qd!(4)).is_sign_negative();
+    );
+
+    // For quotients with up to 90 bits, the quotient has to be exact and the remainder has
+    // to be in [0, |b|) and within an ulp or two of exact. The operands only have 106 bits,
+    // so the product of the quotient and the divisor fits in a Quad without rounding.
+    test!(euclid_identity: {
+        let mut rng = Rng::new(0x0123_4567_89ab_cdef);
+        let mut random = |e: i32| {
+            let hi = (1.0 + rng.next_f64()) * 2f64.powi(e);
+            let lo = (rng.next_u64() >> 11) as f64 * 2f64.powi(e - 106);
+            let x = Quad(hi, 0.0, 0.0, 0.0) + Quad(lo, 0.0, 0.0, 0.0);
+            if rng.next_u64() % 2 == 0 { x } else { -x }
+        };
+        for i in 0..20_000 {
+            let b = random(i % 40 - 20);
+            let a = random(i % 40 - 20 + i % 90);
+            let (q, r) = (a.div_euclid(b), a.rem_euclid(b));
+            assert!(q == q.trunc(), "q = {:?} for {:?} / {:?}", q, a, b);
+            assert!(r >= Quad::ZERO && r < b.abs(), "r = {:?} for {:?} % {:?}", r, a, b);
+            let dev = (a - q * b - r).abs();
+            assert!(
+                dev <= b.abs() * Quad::EPSILON,
+                "a - qb - r = {:e} for a = {:?}, b = {:?}", dev, a, b
+            );
+        }
+    });
     test_all!(
         assign_num: {
             let mut a = Quad::PI;
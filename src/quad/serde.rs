@@ -0,0 +1,290 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::decimal::{self as dec, Decimal};
+use crate::quad::common::from_components;
+use crate::quad::Quad;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Unexpected, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+use std::fmt;
+
+impl Serialize for Quad {
+    /// Serializes a `Quad`. This is only available with the `serde` feature.
+    ///
+    /// Human-readable formats (like JSON) get a decimal string in scientific notation.
+    /// This string is long enough to bring back every bit of all four components when
+    /// deserialized: it's the shortest decimal that lies within a quarter of an ULP of the
+    /// fourth component of the exact value. That's often a couple of digits longer than the
+    /// 62 or so that `Display` writes. It can also be read by [`from_str`], though that
+    /// function only promises the usual precision rather than the exact components.
+    /// Non-finite numbers are written as `"nan"`, `"inf"`, and `"-inf"`.
+    ///
+    /// Binary formats (like bincode) get the four components as a tuple of `f64`s, which
+    /// is both smaller and faster and which carries NaN and infinity without any help.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let json = serde_json::to_string(&Quad::PI).unwrap();
+    /// let expected = "3.1415926535897932384626433832795028841971693993751058209749445923e0";
+    /// assert!(json == format!("\"{}\"", expected));
+    ///
+    /// let pi: Quad = serde_json::from_str(&json).unwrap();
+    /// assert!((0..4).all(|i| pi[i] == Quad::PI[i]));
+    /// ```
+    ///
+    /// [`from_str`]: #method.from_str
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let s = if self.is_nan() {
+                String::from("nan")
+            } else if self.is_infinite() {
+                String::from(if self.0 > 0.0 { "inf" } else { "-inf" })
+            } else {
+                dec::to_exact_string(&[self.0, self.1, self.2, self.3])
+            };
+            serializer.serialize_str(&s)
+        } else {
+            let mut tuple = serializer.serialize_tuple(4)?;
+            tuple.serialize_element(&self.0)?;
+            tuple.serialize_element(&self.1)?;
+            tuple.serialize_element(&self.2)?;
+            tuple.serialize_element(&self.3)?;
+            tuple.end()
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Quad {
+    /// Deserializes a `Quad`. This is only available with the `serde` feature.
+    ///
+    /// Human-readable formats accept a string, a number, or a sequence of one to four
+    /// numbers. A
+    /// string is split into components exactly (so the output of [`serialize`] comes back
+    /// bit for bit) after being checked by the same rules that [`from_str`] uses. A number
+    /// is converted with [`from`], and a sequence is treated like the tuple that binary
+    /// formats use, with any missing components taken to be zero.
+    ///
+    /// Binary formats expect a tuple of four `f64`s. If those components are not
+    /// normalized, they are normalized before the `Quad` is created, so any four finite
+    /// numbers produce the `Quad` closest to their sum. A NaN in any component produces
+    /// [`NAN`], and an infinite sum produces an infinity of the same sign.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x: Quad = serde_json::from_str("\"2.5e-1\"").unwrap();
+    /// assert!(x == qd!(0.25));
+    ///
+    /// let y: Quad = serde_json::from_str("[1.0, 1.0, 1.0, 1.0]").unwrap();
+    /// assert!(y == qd!(4));
+    /// ```
+    ///
+    /// [`serialize`]: #method.serialize
+    /// [`from_str`]: #method.from_str
+    /// [`from`]: #impl-From%3Cf64%3E
+    /// [`NAN`]: #associatedconstant.NAN
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Quad, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(QuadVisitor)
+        } else {
+            deserializer.deserialize_tuple(4, QuadVisitor)
+        }
+    }
+}
+
+struct QuadVisitor;
+
+impl<'de> Visitor<'de> for QuadVisitor {
+    type Value = Quad;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a quad-double as a string, a number, or a sequence of up to four numbers")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Quad, E> {
+        let parsed = s
+            .parse::<Quad>()
+            .map_err(|_| E::invalid_value(Unexpected::Str(s), &self))?;
+        // Only "nan", "inf", and the like have no digits in them
+        if !s.bytes().any(|b| b.is_ascii_digit()) {
+            return Ok(parsed);
+        }
+        let mut c = [0.0; 4];
+        dec::split(&Decimal::parse(s), &mut c);
+        Ok(Quad(c[0], c[1], c[2], c[3]))
+    }
+
+    fn visit_f64<E: de::Error>(self, x: f64) -> Result<Quad, E> {
+        Ok(Quad::from(x))
+    }
+
+    fn visit_i64<E: de::Error>(self, x: i64) -> Result<Quad, E> {
+        Ok(Quad::from(x))
+    }
+
+    fn visit_u64<E: de::Error>(self, x: u64) -> Result<Quad, E> {
+        Ok(Quad::from(x))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Quad, A::Error> {
+        let mut c = [0.0; 4];
+        let mut len = 0;
+        while let Some(x) = seq.next_element()? {
+            if len == 4 {
+                return Err(de::Error::invalid_length(5, &self));
+            }
+            c[len] = x;
+            len += 1;
+        }
+        if len == 0 {
+            return Err(de::Error::invalid_length(0, &self));
+        }
+        Ok(from_components(c[0], c[1], c[2], c[3]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::random::Rng;
+    use serde_test::{assert_tokens, Configure, Token};
+
+    // Bitwise equality, so that NaN equals NaN and the sign of a zero first component counts.
+    // The signs of zero later components aren't preserved by human-readable formats.
+    fn same(a: Quad, b: Quad) -> bool {
+        a[0].to_bits() == b[0].to_bits()
+            && (1..4).all(|i| a[i].to_bits() == b[i].to_bits() || a[i] == b[i])
+    }
+
+    fn json(x: Quad) -> Quad {
+        serde_json::from_str(&serde_json::to_string(&x).unwrap()).unwrap()
+    }
+
+    fn bincode(x: Quad) -> Quad {
+        bincode::deserialize(&bincode::serialize(&x).unwrap()).unwrap()
+    }
+
+    fn from_json(s: &str) -> Quad {
+        serde_json::from_str(s).unwrap()
+    }
+
+    test!(tokens: {
+        assert_tokens(
+            &Quad::PI.readable(),
+            &[Token::Str("3.1415926535897932384626433832795028841971693993751058209749445923e0")],
+        );
+        assert_tokens(
+            &Quad::PI.compact(),
+            &[
+                Token::Tuple { len: 4 },
+                Token::F64(Quad::PI[0]),
+                Token::F64(Quad::PI[1]),
+                Token::F64(Quad::PI[2]),
+                Token::F64(Quad::PI[3]),
+                Token::TupleEnd,
+            ],
+        );
+        assert_tokens(&Quad::ZERO.readable(), &[Token::Str("0")]);
+        assert_tokens(&Quad::NEG_INFINITY.readable(), &[Token::Str("-inf")]);
+    });
+
+    test!(json_constants: {
+        for &x in &[
+            Quad::PI,
+            Quad::E,
+            Quad::LN_2,
+            Quad::FRAC_1_SQRT_2,
+            Quad::MAX,
+            Quad::MIN,
+            Quad::MIN_POSITIVE,
+            Quad::ZERO,
+            Quad::ONE,
+            -Quad::TAU,
+        ] {
+            assert!(same(x, json(x)), "{:?} came back as {:?}", x, json(x));
+        }
+    });
+
+    test!(json_nonfinite: {
+        assert!(json(Quad::NAN).is_nan());
+        assert!(json(Quad::NEG_ZERO).is_sign_negative());
+        assert!(same(json(Quad::INFINITY), Quad::INFINITY));
+        assert!(same(json(Quad::NEG_INFINITY), Quad::NEG_INFINITY));
+    });
+
+    test!(json_random: {
+        let mut rng = Rng::new(0x853c_49e6_748f_ea9b);
+        for _ in 0..2_000 {
+            let scale = qd!(2).powi(rng.range(-1000, 1000));
+            let x = qd!(rng.next_f64()) / qd!(3) * scale;
+            let x = if rng.next_u64() % 2 == 0 { x } else { -x };
+            let y = json(x);
+            assert!(same(x, y), "{:?} came back as {:?}", x, y);
+        }
+    });
+
+    test!(json_input: {
+        assert!(same(from_json("\"0.1\""), qd!("0.1")));
+        assert!(same(from_json("\"-2.5e-3\""), qd!("-2.5e-3")));
+        assert!(same(from_json("\"1_000\""), qd!(1000)));
+        assert!(same(from_json("\"NaN\""), Quad::NAN));
+        assert!(same(from_json("1.5"), qd!(1.5)));
+        assert!(same(from_json("-7"), qd!(-7)));
+        assert!(same(from_json("12345678901234567890"), qd!(12_345_678_901_234_567_890u64)));
+        assert!(same(from_json("[3.0, 1e-20]"), Quad(3.0, 1e-20, 0.0, 0.0)));
+        assert!(serde_json::from_str::<Quad>("\"2.5.3\"").is_err());
+        assert!(serde_json::from_str::<Quad>("\"\"").is_err());
+        assert!(serde_json::from_str::<Quad>("[]").is_err());
+        assert!(serde_json::from_str::<Quad>("[1.0, 2.0, 3.0, 4.0, 5.0]").is_err());
+        assert!(serde_json::from_str::<Quad>("true").is_err());
+    });
+
+    // Exact decimal splitting is correctly rounded, so it's at least as close as `from_str`
+    test!(json_input_accuracy: {
+        let x = from_json("\"0.999999930308280623743676086269149280847663170442180718015664887\"");
+        let expected = (qd!(3).powi(15) - qd!(1)) / qd!(3).powi(15);
+        near!(expected, x);
+    });
+
+    test!(bincode_round_trip: {
+        for &x in &[
+            Quad::PI,
+            -Quad::E,
+            Quad::MAX,
+            Quad::MIN_POSITIVE,
+            Quad::NEG_ZERO,
+            Quad::INFINITY,
+            Quad::NEG_INFINITY,
+            Quad(-3.0, -0.0, -0.0, -0.0),
+        ] {
+            assert!(same(x, bincode(x)));
+        }
+        assert!(bincode(Quad::NAN).is_nan());
+    });
+
+    test!(normalization: {
+        assert!(same(from_components(1.0, 1.0, 1.0, 1.0), Quad(4.0, 0.0, 0.0, 0.0)));
+        assert!(same(
+            from_components(1e-60, 1e-40, 1e-20, 1.0),
+            Quad(1.0, 1e-20, 1e-40, 1e-60)
+        ));
+        assert!(same(from_components(0.0, 0.0, 0.0, 1.0), Quad(1.0, 0.0, 0.0, 0.0)));
+        let x = from_components(1.0, 3e-16, 0.0, 0.0);
+        assert!(x[0] + x[1] == x[0]);
+        assert!(Quad(1.0, 0.0, 0.0, 0.0) + Quad(3e-16, 0.0, 0.0, 0.0) == x);
+        assert!(from_components(1.0, 0.0, f64::NAN, 0.0).is_nan());
+        assert!(from_components(f64::INFINITY, f64::NEG_INFINITY, 0.0, 0.0).is_nan());
+        assert!(same(from_components(f64::MAX, f64::MAX, 0.0, 0.0), Quad::INFINITY));
+        assert!(same(
+            from_components(-1.0, 0.0, 0.0, f64::NEG_INFINITY),
+            Quad::NEG_INFINITY
+        ));
+
+        let bytes = bincode::serialize(&(1e-20f64, 1.0f64, 0.0f64, 0.0f64)).unwrap();
+        let y: Quad = bincode::deserialize(&bytes).unwrap();
+        assert!(same(y, Quad(1.0, 1e-20, 0.0, 0.0)));
+    });
+}
@@ -0,0 +1,38 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::quad::common as c;
+use crate::quad::Quad;
+use crate::slice::Sealed;
+
+impl Sealed<4> for Quad {
+    const ZERO: Quad = Quad::ZERO;
+    const ONE: Quad = Quad::ONE;
+
+    #[inline]
+    fn components(self) -> [f64; 4] {
+        [self.0, self.1, self.2, self.3]
+    }
+
+    #[inline]
+    fn is_normalized(c: &[f64; 4]) -> bool {
+        c::is_normalized(c[0], c[1], c[2], c[3])
+    }
+
+    #[inline]
+    fn from_raw(c: [f64; 4]) -> Quad {
+        Quad(c[0], c[1], c[2], c[3])
+    }
+
+    #[inline]
+    fn from_normalizing(c: [f64; 4]) -> Quad {
+        c::from_components(c[0], c[1], c[2], c[3])
+    }
+
+    #[inline]
+    fn fused_mul_add(a: Quad, b: Quad, c: Quad) -> Quad {
+        c::mul_add(a, b, c)
+    }
+}
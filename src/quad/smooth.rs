@@ -0,0 +1,558 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::strict as s;
+use crate::quad::Quad;
+
+impl Quad {
+    /// Calculates the softplus function, ln(1 + *e*<sup>x</sup>), where *x* is this
+    /// `Quad`.
+    ///
+    /// Evaluating this formula directly overflows for large *x* (*e*<sup>800</sup> is
+    /// [`INFINITY`] even though the answer is just 800) and loses everything for negative
+    /// *x*, where 1 + *e*<sup>x</sup> rounds to 1. Instead, the calculation branches at
+    /// zero:
+    ///
+    /// * for *x* > 0, it's calculated as *x* + ln(1 + *e*<sup>-x</sup>), and
+    /// * for *x* <= 0, it's calculated as ln(1 + *e*<sup>x</sup>),
+    ///
+    /// with the logarithms done by [`ln_1p`]. Only non-positive arguments are ever passed
+    /// to [`exp`] that way, so nothing overflows, and the only precision lost is in the
+    /// underflow of *e*<sup>x</sup> itself, the same as in [`exp`]. That starts when *x* is
+    /// below about -600, where its last components become subnormal, and past about -745
+    /// *e*<sup>x</sup> is 0; there softplus(*x*) returns *x* for positive arguments and 0
+    /// for negative ones.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1).softplus();
+    /// let expected = qd!("1.313261687518222834048995494967855641915280085670348374719063515");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    ///
+    /// assert!(qd!(800).softplus() == qd!(800));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    /// [`ln_1p`]: #method.ln_1p
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn softplus(self) -> Quad {
+        match self.pre_softplus() {
+            Some(r) => s::guard("Quad::softplus", &[self.0], r),
+            None => {
+                if self.is_sign_positive() {
+                    self + (-self).exp().ln_1p()
+                } else {
+                    self.exp().ln_1p()
+                }
+            }
+        }
+    }
+
+    /// Calculates the logistic function, 1 / (1 + *e*<sup>-x</sup>), where *x* is this
+    /// `Quad`.
+    ///
+    /// The result is always between 0 and 1. Like [`softplus`], this branches at zero so
+    /// that [`exp`] is only ever called with a non-positive argument:
+    ///
+    /// * for *x* >= 0, it's calculated as 1 / (1 + *e*<sup>-x</sup>), and
+    /// * for *x* < 0, it's calculated as *e*<sup>x</sup> / (1 + *e*<sup>x</sup>).
+    ///
+    /// Both branches are accurate to full relative precision, including for very negative
+    /// arguments where the result is tiny. Because *x* and -*x* use the same value of
+    /// *e*<sup>-|x|</sup>, logistic(*x*) + logistic(-*x*) is 1 to within rounding. Below about
+    /// -745 the result underflows to 0, and for large positive *x* it rounds to exactly 1.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1).logistic();
+    /// let expected = qd!("0.731058578630004879251159241821836274365144640165056519276365908");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    /// [`softplus`]: #method.softplus
+    pub fn logistic(self) -> Quad {
+        match self.pre_logistic() {
+            Some(r) => s::guard("Quad::logistic", &[self.0], r),
+            None => {
+                if self.is_sign_positive() {
+                    Quad::ONE / (Quad::ONE + (-self).exp())
+                } else {
+                    let t = self.exp();
+                    t / (Quad::ONE + t)
+                }
+            }
+        }
+    }
+
+    /// Calculates the natural logarithm of the logistic function, where *x* is this
+    /// `Quad`.
+    ///
+    /// This is calculated as -[`softplus`] of -*x*, and it has the same branch point (at zero) and limits.
+    /// Calculating it as `x.logistic().ln()` instead would lose all precision for large
+    /// positive *x*, where the logistic function rounds to 1.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(10).log_logistic();
+    /// let expected = qd!("-4.539889921686464676948782930710559678150228178755879426406319549e-5");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-65));
+    /// ```
+    ///
+    /// [`softplus`]: #method.softplus
+    #[inline]
+    pub fn log_logistic(self) -> Quad {
+        -(-self).softplus()
+    }
+
+    /// Calculates a smooth approximation of the minimum of this `Quad` and another,
+    /// -ln(*e*<sup>-ka</sup> + *e*<sup>-kb</sup>) / *k*.
+    ///
+    /// The sharpness parameter *k* must be positive; larger values bring the result closer
+    /// to the true minimum, which the result never exceeds. The direct formula overflows as
+    /// soon as *ka* or *kb* is very negative, so the smaller argument *m* is factored out
+    /// first:
+    ///
+    /// smooth_min(*a*, *b*, *k*) = *m* - ln(1 + *e*<sup>-k|a - b|</sup>) / *k*
+    ///
+    /// The logarithm is done by [`ln_1p`]. This never passes a positive argument to
+    /// [`exp`], so it stays accurate for any finite arguments. When *k*|*a* - *b*| is
+    /// greater than about 745, the correction term underflows and the result is exactly *m*.
+    ///
+    /// If either argument is [`NAN`], or if *k* is not positive, the result is [`NAN`]. An
+    /// infinite *k* produces the hard minimum.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1).smooth_min(qd!(2), qd!(10));
+    /// let expected = qd!("0.999995460110078313535323051217069289440321849771821244120573594");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    /// [`ln_1p`]: #method.ln_1p
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn smooth_min(self, other: Quad, k: Quad) -> Quad {
+        match self.pre_smooth_min(&other, &k) {
+            Some(r) => s::guard("Quad::smooth_min", &[self.0, other.0, k.0], r),
+            None => {
+                let m = if self < other { self } else { other };
+                m - (-k * self.smooth_gap(other)).exp().ln_1p() / k
+            }
+        }
+    }
+
+    /// Calculates a smooth approximation of the maximum of this `Quad` and another,
+    /// ln(*e*<sup>ka</sup> + *e*<sup>kb</sup>) / *k*.
+    ///
+    /// This is the mirror image of [`smooth_min`]; the larger argument *m* is factored out
+    /// and the result is calculated as
+    ///
+    /// smooth_max(*a*, *b*, *k*) = *m* + ln(1 + *e*<sup>-k|a - b|</sup>) / *k*
+    ///
+    /// using [`ln_1p`] for the logarithm, with the same limits and special cases. The
+    /// result is never less than the true maximum.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1).smooth_max(qd!(2), qd!(10));
+    /// let expected = qd!("2.000004539889921686464676948782930710559678150228178755879426406");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`ln_1p`]: #method.ln_1p
+    /// [`smooth_min`]: #method.smooth_min
+    pub fn smooth_max(self, other: Quad, k: Quad) -> Quad {
+        match self.pre_smooth_max(&other, &k) {
+            Some(r) => s::guard("Quad::smooth_max", &[self.0, other.0, k.0], r),
+            None => {
+                let m = if self > other { self } else { other };
+                m + (-k * self.smooth_gap(other)).exp().ln_1p() / k
+            }
+        }
+    }
+
+    // |a - b|, except that it's zero if the two are equal. That distinction only matters
+    // when they're the same infinity, where subtracting them would produce NaN.
+    #[inline]
+    fn smooth_gap(self, other: Quad) -> Quad {
+        if self == other {
+            Quad::ZERO
+        } else {
+            (self - other).abs()
+        }
+    }
+
+    // Precalc functions
+    //
+    // This series of functions returns `Some` with a value that is to be returned, if it
+    // turns out that the function doesn't have to be calculated because a shortcut result
+    // is known. They return `None` if the value has to be calculated normally.
+    //
+    // This keeps the public functions from being mucked up with code that does validation
+    // rather than calculation.
+
+    #[inline]
+    fn pre_softplus(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_infinite() {
+            Some(if self.is_sign_positive() {
+                Quad::INFINITY
+            } else {
+                Quad::ZERO
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_logistic(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            Some(Quad(0.5, 0.0, 0.0, 0.0))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_smooth_min(&self, other: &Quad, k: &Quad) -> Option<Quad> {
+        if self.is_nan() || other.is_nan() || k.is_nan() || *k <= Quad::ZERO {
+            Some(Quad::NAN)
+        } else if k.is_infinite() {
+            Some(if self < other { *self } else { *other })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_smooth_max(&self, other: &Quad, k: &Quad) -> Option<Quad> {
+        if self.is_nan() || other.is_nan() || k.is_nan() || *k <= Quad::ZERO {
+            Some(Quad::NAN)
+        } else if k.is_infinite() {
+            Some(if self > other { *self } else { *other })
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // softplus tests
+    test_all_near!(
+        softplus_zero:
+            Quad::LN_2,
+            Quad::ZERO.softplus();
+        softplus_1:
+            qd!("1.313261687518222834048995494967855641915280085670348374719063514837195"),
+            qd!(1).softplus();
+        softplus_neg_1:
+            qd!("0.3132616875182228340489954949678556419152800856703483747190635148371949"),
+            qd!(-1).softplus();
+        softplus_half:
+            qd!("0.9740769841801066808729973550811707497555961946678628412008152750991935"),
+            qd!(0.5).softplus();
+        softplus_2_5:
+            qd!("2.578889734292549623344043916717550997259079421066961476183422357091817"),
+            qd!(2.5).softplus();
+        softplus_neg_2_5:
+            qd!("0.07888973429254962334404391671755099725907942106696147618342235709181692"),
+            qd!(-2.5).softplus();
+        softplus_10:
+            qd!("10.00004539889921686464676948782930710559678150228178755879426406319549"),
+            qd!(10).softplus();
+        softplus_30:
+            qd!("30.00000000000009357622968839736779377697424675157721618086919819316249"),
+            qd!(30).softplus();
+        softplus_tiny:
+            qd!("0.6931471805599453094172321214581765680755001343602552541206805094933936"),
+            qd!("1e-60").softplus();
+        softplus_neg_tiny:
+            qd!("0.6931471805599453094172321214581765680755001343602552541206795094933936"),
+            qd!("-1e-60").softplus();
+    );
+    test_all_prec!(
+        softplus_neg_10:
+            qd!("4.539889921686464676948782930710559678150228178755879426406319548891805e-5"),
+            qd!(-10).softplus(),
+            60;
+        softplus_neg_30:
+            qd!("9.35762296883973677937769742467515772161808691981931624923951841128918e-14"),
+            qd!(-30).softplus(),
+            60;
+        softplus_neg_400:
+            qd!("1.915169596714005695019839778654264350742092776222447681551080371120882e-174"),
+            qd!(-400).softplus(),
+            60;
+    );
+    test_all_exact!(
+        softplus_800:
+            qd!(800),
+            qd!(800).softplus();
+        softplus_neg_800:
+            Quad::ZERO,
+            qd!(-800).softplus();
+        softplus_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.softplus();
+        softplus_neg_inf:
+            Quad::ZERO,
+            Quad::NEG_INFINITY.softplus();
+        softplus_nan:
+            Quad::NAN,
+            Quad::NAN.softplus();
+    );
+    test!(softplus_symmetry: {
+        for x in &[qd!(1e-60), qd!(0.5), Quad::PI, qd!(10), qd!(30), qd!(100)] {
+            let x = *x;
+            // The difference can only be as accurate as the larger of the two terms
+            let diff = (x.softplus() - (-x).softplus() - x).abs();
+            assert!(diff <= Quad::EPSILON * x.softplus(), "diff = {:e}", diff);
+        }
+    });
+
+    // logistic tests
+    test_all_near!(
+        logistic_1:
+            qd!("0.7310585786300048792511592418218362743651446401650565192763659079190405"),
+            qd!(1).logistic();
+        logistic_neg_1:
+            qd!("0.2689414213699951207488407581781637256348553598349434807236340920809595"),
+            qd!(-1).logistic();
+        logistic_half:
+            qd!("0.622459331201854564638900565745508478753279365308910163059437162658545"),
+            qd!(0.5).logistic();
+        logistic_2_5:
+            qd!("0.9241418199787564488066938233537522268692879603392157611636726415179167"),
+            qd!(2.5).logistic();
+        logistic_neg_2_5:
+            qd!("0.07585818002124355119330617664624777313071203966078423883632735848208332"),
+            qd!(-2.5).logistic();
+        logistic_10:
+            qd!("0.9999546021312975656054952237672365105449063079952739313682144361312805"),
+            qd!(10).logistic();
+        logistic_tiny:
+            qd!("0.50000000000000000000000000000000000000000000000000000000000025"),
+            qd!("1e-60").logistic();
+        logistic_neg_tiny:
+            qd!("0.49999999999999999999999999999999999999999999999999999999999975"),
+            qd!("-1e-60").logistic();
+    );
+    test_all_prec!(
+        logistic_neg_10:
+            qd!("4.539786870243439450477623276348945509369200472606863178556386871949183e-5"),
+            qd!(-10).logistic(),
+            60;
+        logistic_neg_30:
+            qd!("9.357622968839298953839562653284984111575735427869585557361737456340185e-14"),
+            qd!(-30).logistic(),
+            60;
+        logistic_neg_400:
+            qd!("1.915169596714005695019839778654264350742092776222447681551080371120882e-174"),
+            qd!(-400).logistic(),
+            60;
+    );
+    test_all_exact!(
+        logistic_zero:
+            qd!(0.5),
+            Quad::ZERO.logistic();
+        logistic_800:
+            Quad::ONE,
+            qd!(800).logistic();
+        logistic_neg_800:
+            Quad::ZERO,
+            qd!(-800).logistic();
+        logistic_inf:
+            Quad::ONE,
+            Quad::INFINITY.logistic();
+        logistic_neg_inf:
+            Quad::ZERO,
+            Quad::NEG_INFINITY.logistic();
+        logistic_nan:
+            Quad::NAN,
+            Quad::NAN.logistic();
+    );
+    test!(logistic_symmetry: {
+        for x in &[qd!(1e-60), qd!(0.5), Quad::PI, qd!(10), qd!(30), qd!(100)] {
+            let x = *x;
+            let diff = (x.logistic() + (-x).logistic() - Quad::ONE).abs();
+            assert!(diff <= Quad::EPSILON, "diff = {:e}", diff);
+        }
+    });
+
+    // log_logistic tests
+    test_all_near!(
+        log_logistic_1:
+            qd!("-0.3132616875182228340489954949678556419152800856703483747190635148371949"),
+            qd!(1).log_logistic();
+        log_logistic_neg_1:
+            qd!("-1.313261687518222834048995494967855641915280085670348374719063514837195"),
+            qd!(-1).log_logistic();
+        log_logistic_neg_10:
+            qd!("-10.00004539889921686464676948782930710559678150228178755879426406319549"),
+            qd!(-10).log_logistic();
+    );
+    test_all_prec!(
+        log_logistic_10:
+            qd!("-4.539889921686464676948782930710559678150228178755879426406319548891805e-5"),
+            qd!(10).log_logistic(),
+            60;
+        log_logistic_30:
+            qd!("-9.35762296883973677937769742467515772161808691981931624923951841128918e-14"),
+            qd!(30).log_logistic(),
+            60;
+    );
+    test_all_exact!(
+        log_logistic_800:
+            Quad::NEG_ZERO,
+            qd!(800).log_logistic();
+        log_logistic_neg_800:
+            qd!(-800),
+            qd!(-800).log_logistic();
+        log_logistic_nan:
+            Quad::NAN,
+            Quad::NAN.log_logistic();
+    );
+
+    // smooth_min tests
+    test_all_near!(
+        smooth_min_1:
+            qd!("0.6867383124817771659510045050321443580847199143296516252809364851628051"),
+            qd!(1).smooth_min(qd!(2), qd!(1));
+        smooth_min_10:
+            qd!("0.9999954601100783135353230512170692894403218497718212441205735936804511"),
+            qd!(1).smooth_min(qd!(2), qd!(10));
+        smooth_min_swapped:
+            qd!("0.9999954601100783135353230512170692894403218497718212441205735936804511"),
+            qd!(2).smooth_min(qd!(1), qd!(10));
+        smooth_min_neg:
+            qd!("-3.00045573322688712234585124690170733089389159214906882801030040815205"),
+            qd!(-3).smooth_min(qd!(0.5), qd!(2));
+        smooth_min_large:
+            qd!("999.6867383124817771659510045050321443580847199143296516252809364851628"),
+            qd!(1000).smooth_min(qd!(1001), qd!(1));
+        smooth_min_equal:
+            qd!("4.768950939813351563527589292847274477308166621879914915293106663502202"),
+            qd!(5).smooth_min(qd!(5), qd!(3));
+    );
+    test_all_exact!(
+        smooth_min_far:
+            qd!(1),
+            qd!(1).smooth_min(qd!(2), qd!(1000));
+        smooth_min_inf_k:
+            qd!(1),
+            qd!(2).smooth_min(qd!(1), Quad::INFINITY);
+        smooth_min_neg_inf:
+            Quad::NEG_INFINITY,
+            qd!(2).smooth_min(Quad::NEG_INFINITY, qd!(1));
+        smooth_min_inf:
+            qd!(2),
+            qd!(2).smooth_min(Quad::INFINITY, qd!(1));
+        smooth_min_zero_k:
+            Quad::NAN,
+            qd!(1).smooth_min(qd!(2), Quad::ZERO);
+        smooth_min_neg_k:
+            Quad::NAN,
+            qd!(1).smooth_min(qd!(2), qd!(-1));
+        smooth_min_nan:
+            Quad::NAN,
+            Quad::NAN.smooth_min(qd!(2), qd!(1));
+        smooth_min_other_nan:
+            Quad::NAN,
+            qd!(1).smooth_min(Quad::NAN, qd!(1));
+    );
+    test!(smooth_min_converges: {
+        let a = Quad::PI;
+        let b = Quad::E;
+        let mut k = Quad::ONE;
+        let mut last = a.smooth_min(b, k);
+        for _ in 0..10 {
+            k *= qd!(4);
+            let next = a.smooth_min(b, k);
+            assert!(next <= b);
+            assert!(next >= last);
+            last = next;
+        }
+        near!(b, last);
+    });
+
+    // smooth_max tests
+    test_all_near!(
+        smooth_max_1:
+            qd!("2.313261687518222834048995494967855641915280085670348374719063514837195"),
+            qd!(1).smooth_max(qd!(2), qd!(1));
+        smooth_max_10:
+            qd!("2.000004539889921686464676948782930710559678150228178755879426406319549"),
+            qd!(1).smooth_max(qd!(2), qd!(10));
+        smooth_max_swapped:
+            qd!("2.000004539889921686464676948782930710559678150228178755879426406319549"),
+            qd!(2).smooth_max(qd!(1), qd!(10));
+        smooth_max_neg:
+            qd!("0.5004557332268871223458512469017073308938915921490688280103004081520501"),
+            qd!(-3).smooth_max(qd!(0.5), qd!(2));
+        smooth_max_large:
+            qd!("1001.313261687518222834048995494967855641915280085670348374719063514837"),
+            qd!(1000).smooth_max(qd!(1001), qd!(1));
+        smooth_max_equal:
+            qd!("5.231049060186648436472410707152725522691833378120085084706893336497798"),
+            qd!(5).smooth_max(qd!(5), qd!(3));
+    );
+    test_all_exact!(
+        smooth_max_far:
+            qd!(2),
+            qd!(1).smooth_max(qd!(2), qd!(1000));
+        smooth_max_inf_k:
+            qd!(2),
+            qd!(2).smooth_max(qd!(1), Quad::INFINITY);
+        smooth_max_inf:
+            Quad::INFINITY,
+            qd!(2).smooth_max(Quad::INFINITY, qd!(1));
+        smooth_max_neg_inf:
+            qd!(2),
+            qd!(2).smooth_max(Quad::NEG_INFINITY, qd!(1));
+        smooth_max_zero_k:
+            Quad::NAN,
+            qd!(1).smooth_max(qd!(2), Quad::ZERO);
+        smooth_max_nan:
+            Quad::NAN,
+            qd!(1).smooth_max(Quad::NAN, qd!(1));
+    );
+    test!(smooth_max_converges: {
+        let a = Quad::PI;
+        let b = Quad::E;
+        let mut k = Quad::ONE;
+        let mut last = a.smooth_max(b, k);
+        for _ in 0..10 {
+            k *= qd!(4);
+            let next = a.smooth_max(b, k);
+            assert!(next >= a);
+            assert!(next <= last);
+            last = next;
+        }
+        near!(a, last);
+    });
+}
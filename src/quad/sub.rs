@@ -3,6 +3,7 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::strict as s;
 use crate::quad::Quad;
 use std::ops::{Add, Sub, SubAssign};
 
@@ -24,7 +25,7 @@ impl Sub for Quad {
     /// ```
     #[inline]
     fn sub(self, other: Quad) -> Quad {
-        self.add(-other)
+        s::guard("Quad - Quad", &[self.0, other.0], self.sub_inner(other))
     }
 }
 
@@ -150,6 +151,30 @@ impl SubAssign<&Quad> for Quad {
     }
 }
 
+impl Quad {
+    /// Subtracts another `Quad` from this one in a `const fn`. The result is the same as that
+    /// of the `-` operator, which can't be used in constants; see [`add_const`] for
+    /// details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// const PI_MINUS_E: Quad = Quad::PI.sub_const(Quad::E);
+    /// assert!(PI_MINUS_E == Quad::PI - Quad::E);
+    /// ```
+    ///
+    /// [`add_const`]: #method.add_const
+    pub const fn sub_const(self, other: Quad) -> Quad {
+        self.sub_inner(other)
+    }
+
+    // The `-` operator without the strict-mode check, for division.
+    #[inline]
+    pub(super) const fn sub_inner(self, other: Quad) -> Quad {
+        self.add_inner(Quad(-other.0, -other.1, -other.2, -other.3))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,396 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::quad::Quad;
+use std::convert::TryFrom;
+
+// The number of attoseconds in a second. As an `f64` this is exact, since it's 2^18 · 5^18
+// and 5^18 is less than 2^53.
+const ATTOS_PER_SEC: i128 = 1_000_000_000_000_000_000;
+
+impl Quad {
+    /// Creates a `Quad` number of seconds from a whole number of seconds and a number of
+    /// attoseconds (10<sup>-18</sup> seconds).
+    ///
+    /// `attos` is added to `secs` whether it's less than a second or not, so negative times
+    /// can be written either as a negative number of seconds plus a positive fraction (the
+    /// way that [`to_secs_parts`] splits them) or not.
+    ///
+    /// A `Quad` has more than enough precision to hold any time that can be written this
+    /// way to far better than an attosecond, but since 10<sup>-18</sup> is not a binary
+    /// fraction, most of those times can't be represented exactly. The result is the total
+    /// number of attoseconds divided by 10<sup>18</sup> in a single division, which has a
+    /// relative error of no more than 2<sup>-208</sup> (a few units in the last place of the
+    /// fourth component). For times that need to be represented exactly, see
+    /// [`from_secs_frac`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let t = Quad::from_secs_parts(1_600_000_000, 250_000_000_000_000_000);
+    /// assert!(t == qd!(1_600_000_000.25));
+    ///
+    /// let u = Quad::from_secs_parts(-2, 500_000_000_000_000_000);
+    /// assert!(u == qd!(-1.5));
+    /// ```
+    ///
+    /// [`to_secs_parts`]: #method.to_secs_parts
+    /// [`from_secs_frac`]: #method.from_secs_frac
+    pub fn from_secs_parts(secs: i64, attos: u64) -> Quad {
+        let total = secs as i128 * ATTOS_PER_SEC + attos as i128;
+        Quad::from(total) / Quad::from(ATTOS_PER_SEC as f64)
+    }
+
+    /// Splits a `Quad` number of seconds into a whole number of seconds and a number of
+    /// attoseconds (10<sup>-18</sup> seconds).
+    ///
+    /// The number is rounded to the nearest attosecond first, and then the seconds are the
+    /// floor of that, so the attoseconds are always between 0 and 10<sup>18</sup> - 1
+    /// (a time of -1.5 seconds is -2 seconds plus 500,000,000,000,000,000 attoseconds). A
+    /// fraction that rounds up to a full second is carried into the seconds.
+    ///
+    /// Splitting the result of [`from_secs_parts`] gives back exactly the same parts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `Quad` is not finite or if the number of seconds doesn't fit in an
+    /// `i64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(1.5).to_secs_parts() == (1, 500_000_000_000_000_000));
+    /// assert!(qd!(-1.5).to_secs_parts() == (-2, 500_000_000_000_000_000));
+    ///
+    /// // Rounds up to the next second
+    /// assert!(qd!("2.9999999999999999999").to_secs_parts() == (3, 0));
+    ///
+    /// let t = Quad::from_secs_parts(1_600_000_000, 123_456_789_012_345_678);
+    /// assert!(t.to_secs_parts() == (1_600_000_000, 123_456_789_012_345_678));
+    /// ```
+    ///
+    /// [`from_secs_parts`]: #method.from_secs_parts
+    pub fn to_secs_parts(self) -> (i64, u64) {
+        match attos(self).and_then(split_attos) {
+            Some(parts) => parts,
+            None => panic!("{} seconds can't be split into i64 seconds", self),
+        }
+    }
+
+    /// Calculates the time from the `Quad` to `end`, both numbers of seconds, and returns
+    /// it as a whole number of seconds and a number of attoseconds (10<sup>-18</sup>
+    /// seconds), split the same way as [`to_secs_parts`] does.
+    ///
+    /// Each time is rounded to the nearest attosecond before the difference is taken, and
+    /// the difference is taken exactly. This is the same as splitting `end - self`, except
+    /// that there's no rounding in the subtraction, so two times that split into the same
+    /// parts are always zero apart.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either time is not finite or if the number of seconds (in either time or
+    /// in the difference) doesn't fit in an `i64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let start = Quad::from_secs_parts(1_600_000_000, 750_000_000_000_000_000);
+    /// let end = Quad::from_secs_parts(1_600_000_002, 250_000_000_000_000_001);
+    ///
+    /// assert!(start.duration_between(end) == (1, 500_000_000_000_000_001));
+    /// assert!(end.duration_between(start) == (-2, 499_999_999_999_999_999));
+    /// ```
+    ///
+    /// [`to_secs_parts`]: #method.to_secs_parts
+    pub fn duration_between(self, end: Quad) -> (i64, u64) {
+        // Checking each time on its own keeps the difference well within an i128
+        let parts = |x: Quad| attos(x).filter(|&n| split_attos(n).is_some());
+        match (parts(self), parts(end)) {
+            (Some(a), Some(b)) => match split_attos(b - a) {
+                Some(d) => d,
+                None => panic!(
+                    "the time from {} to {} is too long for i64 seconds",
+                    self, end
+                ),
+            },
+            _ => panic!("{} and {} can't both be split into i64 seconds", self, end),
+        }
+    }
+
+    /// Creates a `Quad` number of seconds from a whole number of seconds and a fraction of
+    /// a second in units of 2<sup>-64</sup> seconds.
+    ///
+    /// Unlike [`from_secs_parts`], this is always exact, and [`to_secs_frac`] always
+    /// splits the result back into exactly the same parts.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let t = Quad::from_secs_frac(-2, 1 << 63);
+    /// assert!(t == qd!(-1.5));
+    ///
+    /// let u = Quad::from_secs_frac(i64::MAX, u64::MAX);
+    /// assert!(u.to_secs_frac() == (i64::MAX, u64::MAX));
+    /// ```
+    ///
+    /// [`from_secs_parts`]: #method.from_secs_parts
+    /// [`to_secs_frac`]: #method.to_secs_frac
+    pub fn from_secs_frac(secs: i64, frac: u64) -> Quad {
+        // secs · 2^64 + frac is at least -2^127 and less than 2^127, so it always fits
+        let total = ((secs as i128) << 64) + frac as i128;
+        Quad::from(total).ldexp(-64)
+    }
+
+    /// Splits a `Quad` number of seconds into a whole number of seconds and a fraction of a
+    /// second in units of 2<sup>-64</sup> seconds.
+    ///
+    /// The number is rounded to the nearest 2<sup>-64</sup> seconds first, and then it's
+    /// split in the same way as [`to_secs_parts`] splits a number, with the seconds being
+    /// the floor and the fraction never being negative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `Quad` is not finite or if the number of seconds doesn't fit in an
+    /// `i64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(-1.5).to_secs_frac() == (-2, 1 << 63));
+    /// assert!(qd!(0.1).to_secs_frac() == (0, 1_844_674_407_370_955_162));
+    /// ```
+    ///
+    /// [`to_secs_parts`]: #method.to_secs_parts
+    pub fn to_secs_frac(self) -> (i64, u64) {
+        match to_i128(self.ldexp(64).round()) {
+            Some(n) => ((n >> 64) as i64, n as u64),
+            None => panic!("{} seconds can't be split into i64 seconds", self),
+        }
+    }
+}
+
+// Converts a `Quad` holding an integer into an `i128`, or returns `None` if the `Quad`
+// isn't finite or if its value doesn't fit. Every component of an integer `Quad` is an
+// integer, and all of them but the first are too small to overflow on their own. The first
+// can be ±2^127 even when the whole number fits, so it's added in two halves, with the
+// other components in between.
+fn to_i128(x: Quad) -> Option<i128> {
+    if x.0.abs() <= 2f64.powi(127) {
+        let half = (x.0 / 2.0).trunc();
+        (half as i128)
+            .checked_add(x.1 as i128)?
+            .checked_add(x.2 as i128)?
+            .checked_add(x.3 as i128)?
+            .checked_add((x.0 - half) as i128)
+    } else {
+        None
+    }
+}
+
+// Rounds a number of seconds to the nearest whole number of attoseconds.
+fn attos(x: Quad) -> Option<i128> {
+    to_i128((x * Quad::from(ATTOS_PER_SEC as f64)).round())
+}
+
+// Splits a number of attoseconds into whole seconds and leftover attoseconds.
+fn split_attos(n: i128) -> Option<(i64, u64)> {
+    let secs = i64::try_from(n.div_euclid(ATTOS_PER_SEC)).ok()?;
+    Some((secs, n.rem_euclid(ATTOS_PER_SEC) as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPOCH_SECS: &[i64] = &[
+        i64::MIN,
+        -1_000_000_000_000_000_000,
+        -1_600_000_000,
+        -1,
+        0,
+        1,
+        1_600_000_000,
+        1_000_000_000_000_000_000,
+        i64::MAX,
+    ];
+    const ATTOS: &[u64] = &[
+        0,
+        1,
+        123_456_789_012_345_678,
+        500_000_000_000_000_000,
+        999_999_999_999_999_999,
+    ];
+
+    test!(secs_parts_round_trip: {
+        for &s in EPOCH_SECS {
+            for &a in ATTOS {
+                let x = Quad::from_secs_parts(s, a);
+                assert!(x.to_secs_parts() == (s, a), "{}, {} came back as {:?}", s, a, x.to_secs_parts());
+            }
+        }
+    });
+
+    test!(secs_parts_accuracy: {
+        // The result times 10^18 should be the total number of attoseconds to within the
+        // documented relative error
+        for &s in EPOCH_SECS {
+            for &a in ATTOS {
+                let total = Quad::from(s as i128 * ATTOS_PER_SEC + a as i128);
+                let x = Quad::from_secs_parts(s, a) * qd!(1e18);
+                assert!((x - total).abs() <= total.abs() * qd!(2).powi(-208));
+            }
+        }
+    });
+
+    test_all_exact!(
+        from_secs_parts_zero:
+            Quad::ZERO,
+            Quad::from_secs_parts(0, 0);
+        from_secs_parts_half:
+            qd!(0.5),
+            Quad::from_secs_parts(0, 500_000_000_000_000_000);
+        from_secs_parts_neg:
+            qd!(-0.5),
+            Quad::from_secs_parts(-1, 500_000_000_000_000_000);
+        from_secs_parts_unsplit:
+            qd!(-0.75),
+            Quad::from_secs_parts(-3, 2_250_000_000_000_000_000);
+        from_secs_parts_carry:
+            qd!(18.5),
+            Quad::from_secs_parts(1, 17_500_000_000_000_000_000);
+    );
+
+    test_all_eq!(
+        to_secs_parts_neg:
+            (-2, 500_000_000_000_000_000),
+            qd!(-1.5).to_secs_parts();
+        to_secs_parts_neg_small:
+            (-1, 999_999_999_999_999_999),
+            qd!(-1e-18).to_secs_parts();
+        to_secs_parts_carry:
+            (3, 0),
+            qd!("2.9999999999999999996").to_secs_parts();
+        to_secs_parts_carry_neg:
+            (-3, 0),
+            qd!("-3.0000000000000000004").to_secs_parts();
+        to_secs_parts_round_down:
+            (2, 999_999_999_999_999_999),
+            qd!("2.9999999999999999994").to_secs_parts();
+        to_secs_parts_neg_zero:
+            (0, 0),
+            qd!("-1e-19").to_secs_parts();
+        to_secs_parts_carry_after_from:
+            (1, 0),
+            (Quad::from_secs_parts(0, 999_999_999_999_999_999) + qd!(6e-19)).to_secs_parts();
+    );
+
+    test_all_eq!(
+        duration_between_pos:
+            (1, 500_000_000_000_000_001),
+            Quad::from_secs_parts(10, 750_000_000_000_000_000)
+                .duration_between(Quad::from_secs_parts(12, 250_000_000_000_000_001));
+        duration_between_neg:
+            (-2, 499_999_999_999_999_999),
+            Quad::from_secs_parts(12, 250_000_000_000_000_001)
+                .duration_between(Quad::from_secs_parts(10, 750_000_000_000_000_000));
+        duration_between_same:
+            (0, 0),
+            Quad::PI.duration_between(Quad::PI);
+        duration_between_epochs:
+            (0, 1),
+            Quad::from_secs_parts(i64::MAX, 999_999_999_999_999_998)
+                .duration_between(Quad::from_secs_parts(i64::MAX, 999_999_999_999_999_999));
+        duration_between_across_zero:
+            (3, 0),
+            Quad::from_secs_parts(-2, 500_000_000_000_000_000)
+                .duration_between(Quad::from_secs_parts(1, 500_000_000_000_000_000));
+    );
+
+    test!(drift: {
+        // A million steps of a little over 123 microseconds, starting in 2020, should land
+        // on exactly the same attosecond as counting them in integers
+        let step = (0, 123_456_789_123_456_789);
+        let start = (1_600_000_000, 987_654_321_987_654_321);
+        let mut t = Quad::from_secs_parts(start.0, start.1);
+        let dt = Quad::from_secs_parts(step.0, step.1);
+        let mut reference = start.0 as i128 * ATTOS_PER_SEC + start.1 as i128;
+
+        for i in 1..=1_000_000 {
+            t += dt;
+            reference += step.1 as i128;
+            if i % 100_000 == 0 {
+                assert!(
+                    t.to_secs_parts() == split_attos(reference).unwrap(),
+                    "drifted after {} steps: {:?} instead of {:?}",
+                    i,
+                    t.to_secs_parts(),
+                    split_attos(reference).unwrap()
+                );
+            }
+        }
+    });
+
+    test!(secs_frac_round_trip: {
+        for &s in EPOCH_SECS {
+            for &f in &[0, 1, 1 << 63, 0x1234_5678_9abc_def0, u64::MAX] {
+                let x = Quad::from_secs_frac(s, f);
+                assert!(x.to_secs_frac() == (s, f), "{}, {} came back as {:?}", s, f, x.to_secs_frac());
+            }
+        }
+    });
+
+    test_all_exact!(
+        from_secs_frac_half:
+            qd!(0.5),
+            Quad::from_secs_frac(0, 1 << 63);
+        from_secs_frac_neg:
+            qd!(-1.5),
+            Quad::from_secs_frac(-2, 1 << 63);
+        from_secs_frac_min:
+            qd!(i64::MIN),
+            Quad::from_secs_frac(i64::MIN, 0);
+        from_secs_frac_tiny:
+            qd!(2).powi(-64),
+            Quad::from_secs_frac(0, 1);
+    );
+
+    test_all_eq!(
+        to_secs_frac_round_up:
+            (1, 0),
+            (qd!(1) - qd!(2).powi(-66)).to_secs_frac();
+        to_secs_frac_round_down:
+            (0, u64::MAX),
+            (qd!(1) - qd!(2).powi(-64) - qd!(2).powi(-66)).to_secs_frac();
+        to_secs_frac_neg:
+            (-1, u64::MAX),
+            (-qd!(2).powi(-64)).to_secs_frac();
+    );
+
+    #[test]
+    #[should_panic(expected = "can't be split")]
+    fn to_secs_parts_nan() {
+        let _ = Quad::NAN.to_secs_parts();
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be split")]
+    fn to_secs_parts_overflow() {
+        let _ = qd!(1e19).to_secs_parts();
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be split")]
+    fn to_secs_frac_overflow() {
+        let _ = Quad::from_secs_frac(i64::MAX, u64::MAX)
+            .ceil()
+            .to_secs_frac();
+    }
+
+    #[test]
+    #[should_panic(expected = "too long")]
+    fn duration_between_overflow() {
+        let _ = Quad::from_secs_parts(i64::MIN, 0).duration_between(Quad::from_secs_parts(1, 0));
+    }
+}
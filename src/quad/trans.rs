@@ -3,24 +3,50 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::strict as s;
+use crate::common::utils as u;
 use crate::quad::common as c;
 use crate::quad::Quad;
 
+// The natural log of MAX, rounded up. exp returns infinity for anything larger.
+const LN_MAX: f64 = 709.79;
+
+// The natural log of half of the smallest subnormal f64, rounded down. exp returns 0 for
+// anything smaller.
+const LN_MIN: f64 = -745.14;
+
+// The base-10 log of MAX, rounded up. exp10 returns infinity for anything larger.
+const LOG10_MAX: f64 = 308.26;
+
+// The base-10 log of half of the smallest subnormal f64, rounded down. exp10 returns 0 for
+// anything smaller.
+const LOG10_MIN: f64 = -323.61;
+
 const INV_K: Quad = Quad(1.52587890625e-05, 0.0, 0.0, 0.0); //   1/65536, used for exp
 
+// The number of times that exp and exp_m1 double their reduced result, which undoes the
+// reduction by INV_K (2^-16).
+const EXP_DOUBLINGS: usize = 16;
+
+// The most terms from INV_FACTS that the series for exp and exp_m1 uses, which takes it
+// through the term with 1/12!.
+const EXP_TERMS: usize = 10;
+
 impl Quad {
     /// Computes the exponential function, *e*<sup>x</sup>, where *x* is this `Quad`.
     ///
-    /// The result of this function grows rapidly. Once *x* exceeds 708, the result is too
-    /// large to represent with a `Quad`; at that point the function begins to return
-    /// [`INFINITY`]. The limit on the low end is less due to the fact that the second,
-    /// third, and fourth components need to fit in an `f64` rather than the first, along
-    /// with extra bits used in argument reduction; this function begins to return 0 at
-    /// -460.
+    /// The result of this function grows rapidly. Once *x* exceeds ln [`MAX`] (about
+    /// 709.78), the result is too large to represent with a `Quad`; at that point the
+    /// function begins to return [`INFINITY`]. On the low end, results go all the way down
+    /// into the subnormal range, and the function only returns 0 below about -745.13, where
+    /// the result is less than half of the smallest subnormal `f64`.
     ///
     /// As *x* grows this function does lose a bit of precision. It's precise to at least 60
     /// digits up to values of -140 <= x <= 150, and from then until the limits, it's
-    /// precise to at least 59 digits.
+    /// precise to at least 59 digits, except that below about -600 the result's last
+    /// components are subnormal. A subnormal component holds fewer bits the smaller it is,
+    /// so precision falls off from there: at -700 the result has about 64 bits (19 digits),
+    /// and below about -708 it's a single subnormal `f64`.
     ///
     /// # Examples
     /// ```
@@ -33,10 +59,10 @@ impl Quad {
     /// ```
     ///
     /// [`INFINITY`]: #associatedconstant.INFINITY
-    #[allow(clippy::many_single_char_names)]
+    /// [`MAX`]: #associatedconstant.MAX
     pub fn exp(self) -> Quad {
         match self.pre_exp() {
-            Some(r) => r,
+            Some(r) => s::guard("Quad::exp", &[self.0], r),
             None => {
                 // Strategy:
                 //
@@ -62,80 +88,157 @@ impl Quad {
                 // Once we have executed the Taylor series to produce an intermediate
                 // answer, we expand it to compensate for the earlier reduction.
 
-                // k = 65536 is chosen; INV_K is defined above as that reciprocal
-                let threshold = c::mul_pwr2(Quad::EPSILON, INV_K.0);
                 // m doesn't need to be *that* accurate, so we calculate it with f64
                 // arithmetic instead of the more expensive Quad arithmetic
                 let m = (self.0 / Quad::LN_2.0 + 0.5).floor();
 
-                // solving for x in exp(kx + m * ln(2)). INV_K is a power of 2 so we could
-                // use mul_exp2, but on larger numbers that causes a loss of precision when
-                // used with negative powers of two because bits are being shifted to the
-                // right without accounting for the ones that are lost off the right.
-                let x = (self - Quad::LN_2 * Quad(m, 0.0, 0.0, 0.0)) * INV_K;
-
-                // This is the "x + x^2/2! + x^3/3!" part of the Taylor series.
-                let mut p = x.sqr();
-                let mut r = x + c::mul_pwr2(p, 0.5);
-                p *= x;
-                let mut t = p * c::INV_FACTS[0];
-                let mut i = 0;
+                // This is everything but the "1 +" part of the Taylor series, including the
+                // reduction by k and the expansion back out of it
+                let mut r = expm1_reduced(self - Quad::LN_2.mul_i32(m as i32));
 
-                // This is the rest of the Taylor series. We perform it as many times as
-                // we need to reach our desired precision.
-                loop {
-                    r += t;
-                    p *= x;
-                    i += 1;
-                    t = p * c::INV_FACTS[i];
-                    if i >= 9 || t.abs() <= threshold {
-                        break;
-                    }
+                // Finally, add the "1 +" part of the Taylor series.
+                r += Quad::ONE;
+
+                // Final step of expansion, this is the "* 2^m" part. At the ends of the
+                // range 2^m isn't an f64, so this is done in two steps. Results that still
+                // don't fit become infinity, and at the bottom of the range the components
+                // become subnormal one at a time, each rounded on its own.
+                let r = c::scale(r, m as i32);
+                if r.0.is_infinite() {
+                    Quad::INFINITY
+                } else {
+                    r
                 }
+            }
+        }
+    }
 
-                // Add the Taylor series parts together, then expand by the same number of
-                // times that we reduced earlier.
-                r += t;
-
-                // mul_pwr2 can be used here because multiplication doesn't lose precision
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
-                r = c::mul_pwr2(r, 2.0) + r.sqr();
+    /// Computes *e*<sup>x</sup> - 1, where *x* is this `Quad`.
+    ///
+    /// Calculating `x.exp() - 1` directly loses precision when *x* is near zero, because
+    /// nearly all of the digits of *e*<sup>x</sup> are then cancelled out by the
+    /// subtraction. This function avoids that by never adding the 1 in the first place
+    /// for arguments of magnitude up to ln(2) / 2 (about 0.347). Outside of that range
+    /// there is no significant cancellation, and the result is simply calculated as
+    /// [`exp`] minus one, with all of that function's limits; in particular, it will
+    /// return -1 for any *x* below about -745.13 and [`INFINITY`] for any *x* above about
+    /// 709.78.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1e-10).exp_m1();
+    /// let expected = qd!("1.00000000005000000000166666666670833333333416666666668055555555575e-10");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-70));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn exp_m1(self) -> Quad {
+        match self.pre_exp_m1() {
+            Some(r) => s::guard("Quad::exp_m1", &[self.0], r),
+            None => {
+                if self.0.abs() <= c::mul_pwr2(Quad::LN_2, 0.5).0 {
+                    expm1_reduced(self)
+                } else {
+                    self.exp() - Quad::ONE
+                }
+            }
+        }
+    }
 
-                // Finally, add the "1 +" part of the Taylor series.
-                r += Quad::ONE;
+    /// Computes 2<sup>x</sup>, where *x* is this `Quad`.
+    ///
+    /// The integer part of *x* is split off and applied as an exact power of 2, so that
+    /// only the fractional part, times ln 2, goes through [`exp`]. That keeps the error the
+    /// same size no matter how large *x* is, and it makes the result exact whenever *x* is
+    /// an integer, subnormal results included.
+    ///
+    /// The result overflows to [`INFINITY`] once *x* reaches 1024 and underflows to 0 once
+    /// *x* reaches -1075, where the result is no more than half of the smallest subnormal
+    /// `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// assert!(qd!(-3).exp2() == qd!(0.125));
+    ///
+    /// let x = qd!(0.5).exp2();
+    /// let expected = qd!("1.414213562373095048801688724209698078569671875376948073176679738");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn exp2(self) -> Quad {
+        match self.pre_exp2() {
+            Some(r) => s::guard("Quad::exp2", &[self.0], r),
+            None => {
+                // The rounded integer n is exact, so is x - n, which lies in [-0.5, 0.5]
+                let n = self.round();
+                let r = c::scale(((self - n) * Quad::LN_2).exp(), n.0 as i32);
+                if r.0.is_infinite() {
+                    Quad::INFINITY
+                } else {
+                    r
+                }
+            }
+        }
+    }
 
-                // Final step of expansion, this is the "* 2^m" part
-                r.ldexp(m as i32)
+    /// Computes 10<sup>x</sup>, where *x* is this `Quad`.
+    ///
+    /// As with [`exp2`], the integer part *n* of *x* is split off, and only the fractional
+    /// part, times ln 10, goes through [`exp`]. The result of that is then multiplied by
+    /// 10<sup>*n*</sup> from a table of correctly rounded powers of ten. An integer *x*
+    /// gives exactly the power of ten that parsing `1e`*x* would, so the result is exact
+    /// when the power is one that a `Quad` can hold (through 10<sup>91</sup>).
+    ///
+    /// The result overflows to [`INFINITY`] above log<sub>10</sub> [`MAX`] (about
+    /// 308.2547) and underflows to 0 below about -323.607, where the result is less than
+    /// half of the smallest subnormal `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// assert!(qd!(3).exp10() == qd!(1000));
+    ///
+    /// let x = qd!(0.5).exp10();
+    /// let expected = qd!("3.162277660168379331998893544432718533719555139325216826857504853");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    /// [`exp2`]: #method.exp2
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`MAX`]: #associatedconstant.MAX
+    pub fn exp10(self) -> Quad {
+        match self.pre_exp10() {
+            Some(r) => s::guard("Quad::exp10", &[self.0], r),
+            None => {
+                let n = self.round();
+                let r = c::mul_pow10(((self - n) * Quad::LN_10).exp(), n.0 as i32);
+                if r.0.is_infinite() {
+                    Quad::INFINITY
+                } else {
+                    r
+                }
             }
         }
     }
 
     /// Calculates the natural logarithm, log<sub>*e*</sub>, of the `Quad`.
     ///
-    /// This calculation relies upon the [`exp`] calculation, in the opposite direction. A
-    /// large positive logarithm, for example, will require the calculation of a large
-    /// negative exponential.
-    ///
-    /// For the same reasons that negative values of [`exp`] are limited to -470, the
-    /// accurate results of this function are limited to the number whose logarithm is 460,
-    /// which is around 2 &times; 10<sup>200</sup>. Take care with this; unlike in
-    /// [`exp`], [`INFINITY`] is *not* returned. In that function, exceeding the maximum
-    /// refers to actually overflowing an `f64`, which is appropriate to call [`INFINITY`];
-    /// here, it means `470`.
+    /// This calculation relies upon the [`exp`] calculation, in the opposite direction.
+    /// Numbers far from 1 are first scaled by a power of 2, which brings them close enough
+    /// to 1 for that to work and adds a multiple of ln 2 to the result. So the result is
+    /// accurate for every positive `Quad`, from the smallest subnormal up to [`MAX`].
     ///
     /// # Examples
     /// ```
@@ -148,11 +251,23 @@ impl Quad {
     /// ```
     ///
     /// [`exp`]: #method.exp
-    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`MAX`]: #associatedconstant.MAX
     pub fn ln(self) -> Quad {
         match self.pre_ln() {
-            Some(r) => r,
+            Some(r) => s::guard("Quad::ln", &[self.0], r),
             None => {
+                let n = u::exponent(self.0);
+                if n.abs() > 512 {
+                    // Far from 1, the iteration below would need the exponential of numbers
+                    // too large for exp to handle. But with x = 2^n * m,
+                    //
+                    //      ln(x) = ln(m) + n * ln(2)
+                    //
+                    // and scaling by 2^-n is exact, so the iteration only has to run on m,
+                    // which is in [1, 2).
+                    return c::scale(self, -n).ln() + Quad::LN_2.mul_i32(n);
+                }
+
                 // Strategy:
                 //
                 // The Taylor series for logarithms converges much more slowly than that of
@@ -189,12 +304,72 @@ impl Quad {
         }
     }
 
+    /// Calculates ln(1 + *x*), where *x* is this `Quad`.
+    ///
+    /// When *x* is near zero, 1 + *x* can't be formed without losing the low digits of
+    /// *x*, and those are exactly the digits that the logarithm depends on. For
+    /// -0.5 < *x* < 0.5, this function instead uses Newton's iteration on
+    /// [`exp_m1`], which never has to form 1 + *x* at all. Outside of that range, the
+    /// loss from forming 1 + *x* is at most a bit or so, and the result is calculated as
+    /// [`ln`] of that sum.
+    ///
+    /// The function returns [`NEG_INFINITY`] if *x* is -1 and [`NAN`] if it's less than
+    /// that.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(1e-10).ln_1p();
+    /// let expected = qd!("9.99999999950000000003333333333083333333353333333331666666666809524e-11");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-70));
+    /// ```
+    ///
+    /// [`exp_m1`]: #method.exp_m1
+    /// [`ln`]: #method.ln
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn ln_1p(self) -> Quad {
+        match self.pre_ln_1p() {
+            Some(r) => s::guard("Quad::ln_1p", &[self.0], r),
+            None => {
+                if self.0.abs() < 0.5 {
+                    // Strategy:
+                    //
+                    // This finds the root of
+                    //
+                    //      f(y) = exp(y) - 1 - x
+                    //
+                    // with Newton's iteration, which in this case is
+                    //
+                    //      y' = y - (expm1(y) - x) / (expm1(y) + 1)
+                    //
+                    // Since expm1(y) and x are both accurate to full relative precision
+                    // near zero, so is their difference. Each iteration doubles the number
+                    // of correct bits, so starting from an f64 estimate, three iterations
+                    // suffice.
+                    let mut y = Quad(self.0.ln_1p(), 0.0, 0.0, 0.0);
+                    for _ in 0..3 {
+                        let e = y.exp_m1();
+                        y -= (e - self) / (e + Quad::ONE);
+                    }
+                    y
+                } else {
+                    (Quad::ONE + self).ln()
+                }
+            }
+        }
+    }
+
     /// Calculates the base-10 logarithm, log<sub>10</sub>, of the `Quad`.
     ///
-    /// As with [`ln`], this has an upper usable range less than the size of the numbers
-    /// themselves. In this case, that upper limit is around 10<sup>200</sup>. Over this
-    /// number, the output is not reliable, but it does not return [`INFINITY`] because the
-    /// number 200 is so plainly not infinite.
+    /// Like [`ln`], this is accurate for every positive number.
+    ///
+    /// Powers of ten up to 10<sup>22</sup> (the ones that are exact as `f64`s) produce
+    /// exact integers, and for numbers from about 0.3 up to that same limit, the floor of
+    /// the result is always the correct decimal exponent, even for numbers that are
+    /// just on one side or the other of a power of ten.
     ///
     /// # Examples
     /// ```
@@ -206,18 +381,39 @@ impl Quad {
     /// assert!(diff < qd!(1e-60));
     /// ```
     ///
-    /// [`INFINITY`]: #associatedconstant.INFINITY
     /// [`ln`]: #method.ln
-    #[inline]
     pub fn log10(self) -> Quad {
-        self.ln() / Quad::LN_10
+        match self.pre_ln() {
+            Some(r) => s::guard("Quad::log10", &[self.0], r),
+            None => {
+                let n = self.0.log10().round();
+                if (0.0..=22.0).contains(&n) {
+                    // Strategy:
+                    //
+                    // With p = 10^n, which is exact for these n,
+                    //
+                    //      log10(x) = n + ln(1 + (x - p) / p) / ln(10)
+                    //
+                    // The second term has the same sign as x - p, so the result is exactly
+                    // n when x is a power of ten, and it's on the correct side of n when
+                    // it's not, no matter how close x is to p.
+                    let n = n as i32;
+                    let p = Quad(10f64.powi(n), 0.0, 0.0, 0.0);
+                    Quad::from(n) + ((self - p) / p).ln_1p() / Quad::LN_10
+                } else {
+                    self.ln() / Quad::LN_10
+                }
+            }
+        }
     }
 
     /// Calculates the base-2 logarithm, log<sub>2</sub>, of the `Quad`.
     ///
-    /// Since 2 is smaller than *e*, this function is constrained even more than [`ln`]. It
-    /// will start returning [`NEG_INFINITY`] at around 10<sup>-213</sup> and will start
-    /// to fail on the positive side at around 2.6 &times; 10<sup>180</sup>.
+    /// As with [`ln`], the whole range of positive numbers (subnormals included)
+    /// produces accurate results. Exact powers of two produce exact integers, and the
+    /// floor of the result is always the correct binary exponent of the number, even for
+    /// numbers that are just on one side or the other of a power of two. That makes it
+    /// safe to use `x.log2().floor()` for things like bit lengths.
     ///
     /// # Examples
     /// ```
@@ -230,16 +426,47 @@ impl Quad {
     /// ```
     ///
     /// [`ln`]: #method.ln
-    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
-    #[inline]
     pub fn log2(self) -> Quad {
-        self.ln() / Quad::LN_2
+        match self.pre_ln() {
+            Some(r) => s::guard("Quad::log2", &[self.0], r),
+            None => {
+                // Strategy:
+                //
+                // With x = 2^k * m,
+                //
+                //      log2(x) = k + ln(1 + (m - 1)) / ln(2)
+                //
+                // k is read out of the bits of the first component, adjusted so that
+                // 1/sqrt(2) < m <= sqrt(2). That makes both the scaling and the subtraction
+                // of 1 exact. The second term has the same sign as m - 1, which is the
+                // sign of x - 2^k, so the result is exactly k for a power of two and on the
+                // correct side of k for anything else.
+                let mut k = u::exponent(self.0);
+                let mut m = c::scale(self, -k);
+                if m.0 > std::f64::consts::SQRT_2 {
+                    k += 1;
+                    m = c::scale(self, -k);
+                }
+                // A tail far enough below the first component can underflow when the number
+                // is scaled down. Its value doesn't matter then, but its sign does.
+                if m.1 == 0.0 && self.1 != 0.0 {
+                    m.1 = f64::from_bits(1).copysign(self.1);
+                }
+                Quad::from(k) + (m - Quad::ONE).ln_1p() / Quad::LN_2
+            }
+        }
     }
 
     /// Calculates the base `b` logarithm (log<sub>`b`</sub>) of the `Quad`.
     ///
-    /// This function will have limits at extreme arguments like the other logarithm
-    /// functions. The difference is that those limits will depend on the base argument.
+    /// This is the ratio of the natural logarithms of `self` and `b`. When both are near 1,
+    /// the logarithms are taken with [`ln_1p`] instead so that their tiny values still have
+    /// full relative precision, and the ratio keeps it too.
+    ///
+    /// A base of 1 gives NaN, since every number but 1 would have an infinite logarithm and
+    /// 1 itself could have any. A base of ∞ gives 0 for any positive finite `self` (-0 if
+    /// `self` is less than 1), and NaN if `self` is 0 or ∞. Any number is its own logarithm
+    /// to the base of itself, so `x.log(x)` is exactly 1 for every positive finite `x`.
     ///
     /// If the goal is to calculate the base *e*, base 2, or base 10 logarithms of `self`,
     /// the specialized functions for those purposes([`ln`], [`log2`], and [`log10`]
@@ -256,13 +483,23 @@ impl Quad {
     /// ```
     ///
     /// [`ln`]: #method.ln
+    /// [`ln_1p`]: #method.ln_1p
     /// [`log2`]: #method.log2
     /// [`log10`]: #method.log10
     #[inline]
     pub fn log(self, b: Quad) -> Quad {
         match self.pre_log(&b) {
-            Some(r) => r,
-            None => self.ln() / b.ln(),
+            Some(r) => s::guard("Quad::log", &[self.0, b.0], r),
+            None => {
+                let (x, y) = (self - Quad::ONE, b - Quad::ONE);
+                if x.0.abs() < 0.5 && y.0.abs() < 0.5 {
+                    // Both x and y are exact here, and ln_1p doesn't lose any of their
+                    // digits to the leading 1 the way that ln does
+                    x.ln_1p() / y.ln_1p()
+                } else {
+                    self.ln() / b.ln()
+                }
+            }
         }
     }
 
@@ -277,9 +514,9 @@ impl Quad {
 
     #[inline]
     fn pre_exp(&self) -> Option<Quad> {
-        if self.0 < -470.0 {
+        if self.0 < LN_MIN {
             Some(Quad::ZERO)
-        } else if self.0 >= 709.0 {
+        } else if self.0 > LN_MAX {
             Some(Quad::INFINITY)
         } else if self.is_nan() {
             Some(Quad::NAN)
@@ -293,13 +530,53 @@ impl Quad {
     }
 
     #[inline]
-    fn pre_ln(&self) -> Option<Quad> {
+    fn pre_exp_m1(&self) -> Option<Quad> {
         if self.is_nan() {
             Some(Quad::NAN)
-        } else if self.is_sign_negative() {
+        } else if self.0.abs() < Quad::EPSILON.0 {
+            // Includes both zeros, which keep their signs. Past this point the x^2/2 term is
+            // too small to affect the result.
+            Some(*self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_exp2(&self) -> Option<Quad> {
+        if self.0 > 1024.0 {
+            Some(Quad::INFINITY)
+        } else if self.0 < -1075.0 {
+            Some(Quad::ZERO)
+        } else if self.is_nan() {
+            Some(Quad::NAN)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_exp10(&self) -> Option<Quad> {
+        if self.0 > LOG10_MAX {
+            Some(Quad::INFINITY)
+        } else if self.0 < LOG10_MIN {
+            Some(Quad::ZERO)
+        } else if self.is_nan() {
+            Some(Quad::NAN)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_ln(&self) -> Option<Quad> {
+        if self.is_nan() {
             Some(Quad::NAN)
         } else if self.is_zero() {
+            // Either zero, as with `f64`
             Some(Quad::NEG_INFINITY)
+        } else if self.is_sign_negative() {
+            Some(Quad::NAN)
         } else if self.is_infinite() {
             Some(Quad::INFINITY)
         } else if *self == Quad::ONE {
@@ -310,17 +587,99 @@ impl Quad {
     }
 
     #[inline]
-    fn pre_log(&self, b: &Quad) -> Option<Quad> {
-        if self.is_nan() {
+    fn pre_ln_1p(&self) -> Option<Quad> {
+        if self.is_nan() || *self < -Quad::ONE {
             Some(Quad::NAN)
-        } else if b.is_sign_negative() || b.is_zero() {
+        } else if *self == -Quad::ONE {
+            Some(Quad::NEG_INFINITY)
+        } else if self.is_infinite() {
+            Some(Quad::INFINITY)
+        } else if self.0.abs() < Quad::EPSILON.0 {
+            // Includes both zeros, which keep their signs
+            Some(*self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_log(&self, b: &Quad) -> Option<Quad> {
+        if self.is_nan()
+            || b.is_nan()
+            || (self.is_sign_negative() && !self.is_zero())
+            || b.is_sign_negative()
+            || b.is_zero()
+            || *b == Quad::ONE
+        {
             Some(Quad::NAN)
+        } else if b.is_infinite() {
+            // ln(self) / ∞, which has no value when ln(self) is itself infinite
+            if self.is_zero() || self.is_infinite() {
+                Some(Quad::NAN)
+            } else if *self < Quad::ONE {
+                Some(Quad::NEG_ZERO)
+            } else {
+                Some(Quad::ZERO)
+            }
+        } else if self == b {
+            Some(Quad::ONE)
         } else {
             None
         }
     }
 }
 
+// Computes exp(x) - 1 for an argument that has already been reduced so that |x| <= ln(2) / 2.
+// This is the heart of both `exp` and `exp_m1`; the former adds the 1 back in and scales by
+// the power of 2 that it took out in reduction, while the latter uses the result as is so
+// that there's no cancellation when x is near zero.
+#[allow(clippy::many_single_char_names)]
+fn expm1_reduced(a: Quad) -> Quad {
+    // k = 65536 is chosen; INV_K is defined above as that reciprocal
+    let threshold = c::mul_pwr2(Quad::EPSILON, INV_K.0);
+
+    // solving for x in exp(kx + m * ln(2)). INV_K is a power of 2 so we could use mul_exp2,
+    // but on larger numbers that causes a loss of precision when used with negative powers
+    // of two because bits are being shifted to the right without accounting for the ones
+    // that are lost off the right.
+    let x = a * INV_K;
+
+    // Sum the Taylor series, then expand by the same number of times that we reduced
+    // earlier. Each of these steps is exp(2x) - 1 = 2(exp(x) - 1) + (exp(x) - 1)^2, so the
+    // result never has the 1 added to it.
+    let mut r = expm1_taylor(x, threshold, EXP_TERMS);
+
+    // Doubling with ldexp is exact, so only the squares and sums round
+    for _ in 0..EXP_DOUBLINGS {
+        r = r.ldexp(1) + r.sqr();
+    }
+    r
+}
+
+// Sums the Taylor series of exp(x) - 1 for a reduced x, up to the term with the reciprocal
+// factorial INV_FACTS[terms - 1]. The sum stops early once a term is no larger than
+// `threshold`, and it also stops at the end of INV_FACTS, so asking for more terms than the
+// table has is harmless.
+fn expm1_taylor(x: Quad, threshold: Quad, terms: usize) -> Quad {
+    // This is the "x + x^2/2! + x^3/3!" part of the Taylor series.
+    let mut p = x.sqr();
+    let mut r = x + c::mul_pwr2(p, 0.5);
+    p *= x;
+    let mut t = p * c::INV_FACTS[0];
+
+    // This is the rest of the Taylor series. We perform it as many times as we need to
+    // reach our desired precision.
+    for f in c::INV_FACTS.iter().take(terms).skip(1) {
+        r += t;
+        p *= x;
+        t = p * *f;
+        if t.abs() <= threshold {
+            break;
+        }
+    }
+    r + t
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -414,15 +773,29 @@ mod tests {
             qd!("3.6938830684872562187934275245707479984912684809256483768734951290934e-196"),
             qd!(-450).exp(),
             60;
+        // Decimal strings this small don't parse to full precision, so these results are
+        // scaled up by 2^900 to compare them.
+        exp_neg_500:
+            qd!("602219960374337614656555532601380254163629299428938823.29821743958119077"),
+            qd!(-500).exp().ldexp(900),
+            60;
+        // The components after the first are subnormal, so only about 64 bits are left
+        exp_neg_700:
+            qd!("8.3341011149358153098787017561880436729614449350264309705655341579055e-34"),
+            qd!(-700).exp().ldexp(900),
+            18;
         exp_limit:
             qd!("3.0233831442760550147756219850967309958990319946798820666918417985884e+307"),
             qd!(708).exp(),
             61;
     );
     test_all_exact!(
+        exp_subnormal:
+            Quad(f64::from_bits(85), 0.0, 0.0, 0.0),
+            qd!(-740).exp();
         exp_below:
             Quad::ZERO,
-            qd!(-710).exp();
+            qd!(-746).exp();
         exp_above:
             Quad::INFINITY,
             qd!(710).exp();
@@ -443,6 +816,212 @@ mod tests {
             Quad::NAN.exp();
     );
 
+    // exp_m1 tests
+    test_all_near!(
+        exp_m1_tiny:
+            qd!("1.000000000000000000005000000000000000000016666666666666666666708333e-20"),
+            qd!("1e-20").exp_m1();
+        exp_m1_small:
+            qd!("1.000000000050000000001666666666708333333334166666666680555555555753968e-10"),
+            qd!("1e-10").exp_m1();
+        exp_m1_neg_small:
+            qd!("-9.999999999500000000016666666666250000000008333333333194444444446428571e-11"),
+            qd!("-1e-10").exp_m1();
+        exp_m1_sixteenth:
+            qd!("0.06449445891785942956339059464288967310072544364935330151930751063556394"),
+            qd!(0.0625).exp_m1();
+        exp_m1_neg_quarter:
+            qd!("-0.2211992169285951317548297330216793527032277095738585257586826337317544"),
+            qd!(-0.25).exp_m1();
+        exp_m1_point_3:
+            qd!("0.3498588075760031039837443133280073303782996973593658030499179899396126"),
+            qd!("0.3").exp_m1();
+        exp_m1_neg_point_3:
+            qd!("-0.2591817793182821339331262206821831278177487680009936517046899331919018"),
+            qd!("-0.3").exp_m1();
+        exp_m1_half:
+            qd!("0.648721270700128146848650787814163571653776100710148011575079311640661"),
+            qd!(0.5).exp_m1();
+        exp_m1_2:
+            qd!("6.389056098930650227230427460575007813180315570551847324087127822522574"),
+            qd!(2).exp_m1();
+        exp_m1_neg_2:
+            qd!("-0.8646647167633873081060005050275155965923684540904241185318411273459266"),
+            qd!(-2).exp_m1();
+        exp_m1_10:
+            qd!("22025.4657948067165169579006452842443663535126185567810742354263552252"),
+            qd!(10).exp_m1();
+        exp_m1_neg_50:
+            qd!("-0.999999999999999999999807125015203608221698265718347298742524716734877"),
+            qd!(-50).exp_m1();
+    );
+    test_all_exact!(
+        exp_m1_0:
+            Quad::ZERO,
+            Quad::ZERO.exp_m1();
+        exp_m1_neg_0:
+            Quad::NEG_ZERO,
+            Quad::NEG_ZERO.exp_m1();
+        exp_m1_below:
+            -Quad::ONE,
+            qd!(-746).exp_m1();
+        exp_m1_above:
+            Quad::INFINITY,
+            qd!(710).exp_m1();
+        exp_m1_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.exp_m1();
+        exp_m1_neg_inf:
+            -Quad::ONE,
+            Quad::NEG_INFINITY.exp_m1();
+        exp_m1_nan:
+            Quad::NAN,
+            Quad::NAN.exp_m1();
+    );
+
+    // exp2 tests
+    test_all_near!(
+        exp2_small:
+            qd!("1.231144413344916284499393069167743109876137761100817794337065538246101"),
+            qd!("0.3").exp2();
+        exp2_neg_small:
+            qd!("0.6155722066724581422496965345838715549380688805504088971685327691230504"),
+            qd!("-0.7").exp2();
+        exp2_sixteenth:
+            qd!("1.044273782427413840321966478739929008784603129662713322017997784754322"),
+            qd!(0.0625).exp2();
+        exp2_half:
+            Quad::SQRT_2,
+            qd!(0.5).exp2();
+        exp2_100:
+            qd!("1507499113128880389969770996485.782697605844487267829404458786340366155"),
+            qd!(100.25).exp2();
+        exp2_neg_200:
+            qd!("4.035126180763936091282268280662604639300952260567070931558646562584834e-61"),
+            qd!(-200.625).exp2();
+        exp2_1000:
+            qd!("1.515342004482324461532259326246123136395804159203502817973050762667717e+301"),
+            qd!(1000.5).exp2();
+        exp2_neg_850:
+            qd!("1.221447869002873573892044172948065559329756219419285703241783097449936e-256"),
+            qd!(-850.125).exp2();
+    );
+    test_all_exact!(
+        exp2_0:
+            Quad::ONE,
+            Quad::ZERO.exp2();
+        exp2_10:
+            qd!(1024),
+            qd!(10).exp2();
+        exp2_neg_3:
+            qd!(0.125),
+            qd!(-3).exp2();
+        exp2_top:
+            Quad(2f64.powi(1023), 0.0, 0.0, 0.0),
+            qd!(1023).exp2();
+        exp2_smallest:
+            Quad(f64::from_bits(1), 0.0, 0.0, 0.0),
+            qd!(-1074).exp2();
+        exp2_overflow:
+            Quad::INFINITY,
+            qd!(1024).exp2();
+        exp2_underflow:
+            Quad::ZERO,
+            qd!(-1075).exp2();
+        exp2_far_below:
+            Quad::ZERO,
+            qd!(-5000).exp2();
+        exp2_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.exp2();
+        exp2_neg_inf:
+            Quad::ZERO,
+            Quad::NEG_INFINITY.exp2();
+        exp2_nan:
+            Quad::NAN,
+            Quad::NAN.exp2();
+    );
+    test!(exp2_integers: {
+        for k in -1074..=1023 {
+            assert!(qd!(k).exp2() == c::scale(Quad::ONE, k), "2^{}", k);
+        }
+    });
+    test!(exp2_thresholds: {
+        // Just under 1024, the result is finite as long as it rounds below MAX
+        assert!(Quad(1024.0, -1e-14, 0.0, 0.0).exp2().is_finite());
+        assert!(qd!(1023.9999).exp2().is_finite());
+        // Just over -1075, the result rounds up to the smallest subnormal
+        assert!(qd!(-1074.9999).exp2() == Quad(f64::from_bits(1), 0.0, 0.0, 0.0));
+    });
+
+    // exp10 tests
+    test_all_near!(
+        exp10_small:
+            qd!("1.995262314968879601352455396739535557986274315405346099229913667004931"),
+            qd!("0.3").exp10();
+        exp10_neg_small:
+            qd!("0.1995262314968879601352455396739535557986274315405346099229913667004931"),
+            qd!("-0.7").exp10();
+        exp10_sixteenth:
+            qd!("1.154781984689458179666482887295508281566948041479611129977426848717929"),
+            qd!(0.0625).exp10();
+        exp10_25:
+            qd!("31622776601683793319988935.44432718533719555139325216826857504852792594"),
+            qd!(25.5).exp10();
+        exp10_neg_25:
+            qd!("3.162277660168379331998893544432718533719555139325216826857504852792594e-26"),
+            qd!(-25.5).exp10();
+        exp10_300:
+            qd!("1.778279410038922801225421195192684844735790526402255358011830722776302e+300"),
+            qd!(300.25).exp10();
+        exp10_neg_250:
+            qd!("1.778279410038922801225421195192684844735790526402255358011830722776302e-251"),
+            qd!(-250.75).exp10();
+        exp10_top:
+            qd!("1.778279410038922801225421195192684844735790526402255358011830722776302e+308"),
+            qd!(308.25).exp10();
+    );
+    test_all_exact!(
+        exp10_0:
+            Quad::ONE,
+            Quad::ZERO.exp10();
+        exp10_3:
+            qd!(1000),
+            qd!(3).exp10();
+        exp10_neg_3:
+            qd!("0.001"),
+            qd!(-3).exp10();
+        exp10_overflow:
+            Quad::INFINITY,
+            qd!(308.26).exp10();
+        exp10_underflow:
+            Quad::ZERO,
+            qd!(-323.61).exp10();
+        exp10_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.exp10();
+        exp10_neg_inf:
+            Quad::ZERO,
+            Quad::NEG_INFINITY.exp10();
+        exp10_nan:
+            Quad::NAN,
+            Quad::NAN.exp10();
+    );
+    test!(exp10_integers: {
+        for k in -323..=308 {
+            let expected: Quad = format!("1e{}", k).parse().unwrap();
+            assert!(qd!(k).exp10() == expected, "10^{}", k);
+        }
+    });
+    test!(exp10_thresholds: {
+        // log10(MAX) is about 308.25471555991674
+        assert!(qd!(308.2547).exp10().is_finite());
+        assert!(qd!(308.2548).exp10().is_infinite());
+        // log10 of half of the smallest subnormal is about -323.60724533877978
+        assert!(qd!(-323.607).exp10() == Quad(f64::from_bits(1), 0.0, 0.0, 0.0));
+        assert!(qd!(-323.608).exp10() == Quad::ZERO);
+    });
+
     // ln tests
     test_all_near!(
         ln_pi:
@@ -475,6 +1054,23 @@ mod tests {
         ln_neg_250:
             qd!("-575.64627324851142100449786367109105190027537215719324400833197524208"),
             qd!("1e-250").ln();
+        ln_300:
+            qd!("690.77552789821370520539743640530926228033044658863189280999837029027"),
+            qd!("1e300").ln();
+        // A Quad this small can only hold about 80 bits, so this is the logarithm of the
+        // number actually stored rather than of 10^-300
+        ln_neg_300:
+            qd!("-690.77552789821370520539743811832832032718237897190063038120877908107"),
+            qd!("1e-300").ln();
+        ln_old_limit:
+            qd!("461.21016577936908211301552305833101808829579786011485046078626020301"),
+            qd!("2e200").ln();
+        ln_308:
+            qd!("709.19620864216607067754136804278417594113925849766207661826499349801"),
+            qd!("1e308").ln();
+        ln_min_subnormal:
+            qd!("-744.44007192138126231410729844608163411308714430291414292561033019590"),
+            Quad(f64::from_bits(1), 0.0, 0.0, 0.0).ln();
     );
     test_all_exact!(
         ln_neg_pi:
@@ -490,7 +1086,7 @@ mod tests {
             Quad::NEG_INFINITY,
             Quad::ZERO.ln();
         ln_neg_0:
-            Quad::NAN,
+            Quad::NEG_INFINITY,
             Quad::NEG_ZERO.ln();
         ln_inf:
             Quad::INFINITY,
@@ -503,6 +1099,66 @@ mod tests {
             Quad::NAN.ln();
     );
 
+    // ln_1p tests
+    test_all_near!(
+        ln_1p_tiny:
+            qd!("9.999999999999999999950000000000000000000333333333333333333330833333e-21"),
+            qd!("1e-20").ln_1p();
+        ln_1p_small:
+            qd!("9.999999999500000000033333333330833333333533333333316666666668095238095e-11"),
+            qd!("1e-10").ln_1p();
+        ln_1p_neg_small:
+            qd!("-1.000000000050000000003333333333583333333353333333335000000000142857143e-10"),
+            qd!("-1e-10").ln_1p();
+        ln_1p_sixteenth:
+            qd!("0.06062462181643484258060613204042026328620247514472377081451769990871809"),
+            qd!(0.0625).ln_1p();
+        ln_1p_neg_quarter:
+            qd!("-0.287682072451780927439219005993827431503509710897761056506665685349293"),
+            qd!(-0.25).ln_1p();
+        ln_1p_point_3:
+            qd!("0.2623642644674910520354959868809543972041664561314341403857176096958921"),
+            qd!("0.3").ln_1p();
+        ln_1p_neg_point_3:
+            qd!("-0.3566749439387323789126387112411844779640167590469117875739377510299927"),
+            qd!("-0.3").ln_1p();
+        ln_1p_half:
+            qd!("0.4054651081081643819780131154643491365719904234624941976140143241441007"),
+            qd!(0.5).ln_1p();
+        ln_1p_2:
+            qd!("1.098612288668109691395245236922525704647490557822749451734694333637494"),
+            qd!(2).ln_1p();
+        ln_1p_neg_three_quarters:
+            qd!("-1.386294361119890618834464242916353136151000268720510508241360018986787"),
+            qd!(-0.75).ln_1p();
+        ln_1p_10:
+            qd!("23.02585093004045684017491454684397540934432321962106509366661217634239"),
+            qd!(1e10).ln_1p();
+    );
+    test_all_exact!(
+        ln_1p_0:
+            Quad::ZERO,
+            Quad::ZERO.ln_1p();
+        ln_1p_neg_0:
+            Quad::NEG_ZERO,
+            Quad::NEG_ZERO.ln_1p();
+        ln_1p_neg_1:
+            Quad::NEG_INFINITY,
+            qd!(-1).ln_1p();
+        ln_1p_below:
+            Quad::NAN,
+            qd!(-2).ln_1p();
+        ln_1p_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.ln_1p();
+        ln_1p_neg_inf:
+            Quad::NAN,
+            Quad::NEG_INFINITY.ln_1p();
+        ln_1p_nan:
+            Quad::NAN,
+            Quad::NAN.ln_1p();
+    );
+
     // log10 tests
     test_all_near!(
         log10_pi:
@@ -550,7 +1206,7 @@ mod tests {
             Quad::NEG_INFINITY,
             Quad::ZERO.log10();
         log10_neg_0:
-            Quad::NAN,
+            Quad::NEG_INFINITY,
             Quad::NEG_ZERO.log10();
         log10_inf:
             Quad::INFINITY,
@@ -563,6 +1219,23 @@ mod tests {
             Quad::NAN.log10();
     );
 
+    test!(log10_powers_of_ten: {
+        for n in 0..=22 {
+            let p = 10f64.powi(n);
+            let x = Quad(p, 0.0, 0.0, 0.0);
+            assert!(x.log10() == Quad::from(n), "log10(10^{}) = {:?}", n, x.log10());
+
+            let floor = |x: Quad| x.log10().floor();
+            let up = f64::from_bits(p.to_bits() + 1);
+            let down = f64::from_bits(p.to_bits() - 1);
+            let tiny = p * 1e-40;
+            assert!(floor(Quad(up, 0.0, 0.0, 0.0)) == Quad::from(n), "10^{} + 1 ulp", n);
+            assert!(floor(Quad(down, 0.0, 0.0, 0.0)) == Quad::from(n - 1), "10^{} - 1 ulp", n);
+            assert!(floor(Quad(p, tiny, 0.0, 0.0)) == Quad::from(n), "10^{} + tiny", n);
+            assert!(floor(Quad(p, -tiny, 0.0, 0.0)) == Quad::from(n - 1), "10^{} - tiny", n);
+        }
+    });
+
     // log2 tests
     test_all_near!(
         log2_pi:
@@ -610,7 +1283,7 @@ mod tests {
             Quad::NEG_INFINITY,
             Quad::ZERO.log2();
         log2_neg_0:
-            Quad::NAN,
+            Quad::NEG_INFINITY,
             Quad::NEG_ZERO.log2();
         log2_inf:
             Quad::INFINITY,
@@ -623,6 +1296,30 @@ mod tests {
             Quad::NAN.log2();
     );
 
+    test!(log2_powers_of_two: {
+        for k in -1074..=1023 {
+            let x = c::scale(Quad::ONE, k);
+            assert!(x.log2() == Quad::from(k), "log2(2^{}) = {:?}", k, x.log2());
+        }
+    });
+    test!(log2_floor_neighbors: {
+        let up = |x: f64| f64::from_bits(x.to_bits() + 1);
+        let down = |x: f64| f64::from_bits(x.to_bits() - 1);
+        let tiny = f64::from_bits(1);
+        for k in -1074..=1023 {
+            let p = c::scale(Quad::ONE, k).0;
+            let floor = |x: Quad| x.log2().floor();
+            if k > -1074 {
+                assert!(floor(Quad(up(p), 0.0, 0.0, 0.0)) == Quad::from(k), "2^{} + 1 ulp", k);
+                assert!(floor(Quad(down(p), 0.0, 0.0, 0.0)) == Quad::from(k - 1), "2^{} - 1 ulp", k);
+            }
+            if k > -1020 {
+                assert!(floor(Quad(p, tiny, 0.0, 0.0)) == Quad::from(k), "2^{} + tiny", k);
+                assert!(floor(Quad(p, -tiny, 0.0, 0.0)) == Quad::from(k - 1), "2^{} - tiny", k);
+            }
+        }
+    });
+
     // log tests
     test_all_near!(
         log_pi:
@@ -656,6 +1353,15 @@ mod tests {
             qd!("-213.0"),
             qd!("1e-213").log(qd!(10.0));
     );
+    test_all_prec!(
+        // Both logarithms are near 1e-25 and lose relative precision if taken with `ln`.
+        // The reference is for the Quad values that the sums round to, not for 1 + 1e-25
+        // and 1 + 3e-25 themselves.
+        log_near_1:
+            qd!("0.33333333333333333333333336666666666666666666666662347361510925906"),
+            (Quad::ONE + qd!(1e-25)).log(Quad::ONE + qd!(3e-25)),
+            62;
+    );
     test_all_exact!(
         log_neg_pi:
             Quad::NAN,
@@ -670,7 +1376,7 @@ mod tests {
             Quad::NEG_INFINITY,
             Quad::ZERO.log(qd!(10.0));
         log_neg_0:
-            Quad::NAN,
+            Quad::NEG_INFINITY,
             Quad::NEG_ZERO.log(qd!(3.2));
         log_inf:
             Quad::INFINITY,
@@ -684,5 +1390,61 @@ mod tests {
         log_base_nan:
             Quad::NAN,
             Quad::PI.log(Quad::NAN);
+        log_1_base_nan:
+            Quad::NAN,
+            Quad::ONE.log(Quad::NAN);
+        log_base_1:
+            Quad::NAN,
+            Quad::PI.log(Quad::ONE);
+        log_1_base_1:
+            Quad::NAN,
+            Quad::ONE.log(Quad::ONE);
+        log_0_base_1:
+            Quad::NAN,
+            Quad::ZERO.log(Quad::ONE);
+        log_base_0:
+            Quad::NAN,
+            Quad::PI.log(Quad::ZERO);
+        log_base_neg:
+            Quad::NAN,
+            Quad::PI.log(-Quad::E);
+        log_base_inf:
+            Quad::ZERO,
+            Quad::PI.log(Quad::INFINITY);
+        log_small_base_inf:
+            Quad::NEG_ZERO,
+            Quad::FRAC_1_PI.log(Quad::INFINITY);
+        log_1_base_inf:
+            Quad::ZERO,
+            Quad::ONE.log(Quad::INFINITY);
+        log_0_base_inf:
+            Quad::NAN,
+            Quad::ZERO.log(Quad::INFINITY);
+        log_inf_base_inf:
+            Quad::NAN,
+            Quad::INFINITY.log(Quad::INFINITY);
+        log_0_small_base:
+            Quad::INFINITY,
+            Quad::ZERO.log(Quad::FRAC_1_PI);
+        log_inf_small_base:
+            Quad::NEG_INFINITY,
+            Quad::INFINITY.log(Quad::FRAC_1_PI);
+        log_self:
+            Quad::ONE,
+            Quad::PI.log(Quad::PI);
+        log_self_near_1:
+            Quad::ONE,
+            (Quad::ONE + Quad::EPSILON).log(Quad::ONE + Quad::EPSILON);
     );
+
+    // series tests
+    test!(expm1_taylor_past_table: {
+        // With no threshold and an argument far too large for the series to converge, the
+        // sum uses every term in the table, and asking for more doesn't change that
+        let x = qd!(0.5);
+        let all = expm1_taylor(x, Quad::ZERO, c::INV_FACTS.len());
+        exact!(all, expm1_taylor(x, Quad::ZERO, c::INV_FACTS.len() + 1));
+        exact!(all, expm1_taylor(x, Quad::ZERO, usize::MAX));
+        assert!(all != expm1_taylor(x, Quad::ZERO, EXP_TERMS));
+    });
 }
@@ -3,9 +3,20 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::reduction as r;
+use crate::common::strict as s;
+use crate::common::utils as u;
 use crate::quad::common as c;
 use crate::quad::Quad;
 
+// The most Newton iterations that `atan2` runs from its `f64` starting point.
+const ATAN2_MAX_ITER: usize = 5;
+
+// How far outside of [-1, 1] an argument to `asin_clamped` or `acos_clamped` can be and
+// still be treated as ±1: four times `EPSILON`, the most that the rounding of a few
+// operations can push a number that should be ±1.
+const CLAMP_TOLERANCE: Quad = Quad(4.0 * Quad::EPSILON.0, 0.0, 0.0, 0.0);
+
 impl Quad {
     /// Simultaneously computes the sine (sin) and the cosine (cos) of the `Quad`. This is
     /// more efficient than calling the separate [`sin`] and [`cos`] functions if you need
@@ -31,6 +42,14 @@ impl Quad {
         match self.pre_sin_cos() {
             Some(r) => r,
             None => {
+                // The reduction isn't symmetric about 0, so negative arguments are taken
+                // through their absolute values. That makes sine exactly odd and cosine
+                // exactly even.
+                if self.is_sign_negative() {
+                    let (s, c) = (-self).sin_cos();
+                    return (-s, c);
+                }
+
                 let (j, k, t) = reduce(self);
                 let abs_k = k.abs() as usize;
 
@@ -62,6 +81,13 @@ impl Quad {
     ///
     /// The domain of this function is (-∞, ∞), and the range is [-1, 1].
     ///
+    /// The argument is reduced exactly, as it is by [`rem_2pi`], so the result is just as
+    /// accurate for huge arguments and for arguments near the zeros of the function.
+    ///
+    /// Sine is odd, and `(-x).sin()` is exactly `-x.sin()` for every *x*. Identities that
+    /// involve π, like sin(π - *x*) = sin *x*, only hold to within the precision of the
+    /// result, because π can't be represented exactly and neither can π - *x*.
+    ///
     /// # Examples
     /// ```
     /// # use qd::qd;
@@ -71,19 +97,27 @@ impl Quad {
     /// let diff = (x - expected).abs();
     /// assert!(diff < qd!(1e-60));
     /// ```
+    ///
+    /// [`rem_2pi`]: #method.rem_2pi
     #[allow(clippy::many_single_char_names)]
     pub fn sin(self) -> Quad {
         match self.pre_sin() {
-            Some(r) => r,
+            Some(r) => s::guard("Quad::sin", &[self.0], r),
             None => {
+                // Sine is odd; see sin_cos
+                if self.is_sign_negative() {
+                    return -(-self).sin();
+                }
+
                 // Strategy:
                 //
                 // We choose integers a and b so that
                 //
                 //      x = s + aπ/2 + bπ/1024
                 //
-                // where |s| <= π/2048. Using a precomputed table of sin (kπ/1024) and cos
-                // (kπ/1024), we can compute sin x from sin s and cos s. This greatly
+                // where |s| <= π/2048, give or take the few ulps described at c::TRIG_STEP.
+                // Using a precomputed table of sin (kπ/1024) and cos (kπ/1024), we can
+                // compute sin x from sin s and cos s. This greatly
                 // increases the convergence of the Taylor series for sine and cosine.
                 let (j, k, t) = reduce(self);
                 let abs_k = k.abs() as usize;
@@ -124,6 +158,11 @@ impl Quad {
     ///
     /// The domain of this function is (-∞, ∞), and the range is [-1, 1].
     ///
+    /// The argument is reduced exactly, as it is by [`rem_2pi`], so the result is just as
+    /// accurate for huge arguments and for arguments near the zeros of the function.
+    ///
+    /// Cosine is even, and `(-x).cos()` is exactly `x.cos()` for every *x*.
+    ///
     /// # Examples
     /// ```
     /// # use qd::qd;
@@ -133,11 +172,18 @@ impl Quad {
     /// let diff = (x - expected).abs();
     /// assert!(diff < qd!(1e-60));
     /// ```
+    ///
+    /// [`rem_2pi`]: #method.rem_2pi
     #[allow(clippy::many_single_char_names)]
     pub fn cos(self) -> Quad {
         match self.pre_cos() {
-            Some(r) => r,
+            Some(r) => s::guard("Quad::cos", &[self.0], r),
             None => {
+                // Cosine is even; see sin_cos
+                if self.is_sign_negative() {
+                    return (-self).cos();
+                }
+
                 let (j, k, t) = reduce(self);
                 let abs_k = k.abs() as usize;
 
@@ -177,6 +223,8 @@ impl Quad {
     ///
     /// The domain and range of this function are both (-∞, ∞).
     ///
+    /// Tangent is odd, and `(-x).tan()` is exactly `-x.tan()` for every *x*.
+    ///
     /// # Examples
     /// ```
     /// # use qd::qd;
@@ -191,6 +239,318 @@ impl Quad {
         s / c
     }
 
+    /// Computes the remainder of the `Quad` divided by 2π, the angle in [-π, π] that's the
+    /// same as the `Quad` in radians.
+    ///
+    /// The multiple of 2π that's subtracted is the one nearest to the `Quad`, and it's
+    /// subtracted exactly, using as many bits of π as it takes. The result therefore has
+    /// the full precision of a `Quad` however large the `Quad` is, and even when it's very
+    /// close to a multiple of 2π. This is how [`sin`], [`cos`], and [`tan`] reduce their
+    /// arguments.
+    ///
+    /// The remainder of an infinity or NaN is NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = qd!("1e30").rem_2pi();
+    /// let expected = qd!("-3.051353329691740210431461795374137282753433269691689421376616695140");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// assert!(Quad::PI.rem_2pi() == Quad::PI);
+    /// ```
+    ///
+    /// [`sin`]: #method.sin
+    /// [`cos`]: #method.cos
+    /// [`tan`]: #method.tan
+    pub fn rem_2pi(self) -> Quad {
+        if !self.is_finite() {
+            s::guard("Quad::rem_2pi", &[self.0], Quad::NAN)
+        } else if self.abs() <= Quad::PI {
+            self
+        } else {
+            let mut r = [0.0; 4];
+            r::half_turns(&self.to_array(), &mut r);
+            Quad(r[0], r[1], r[2], r[3]) * Quad::FRAC_PI_2
+        }
+    }
+
+    /// Computes the sine (sin) of π times the `Quad`, so the `Quad` is an angle in
+    /// half-turns rather than radians.
+    ///
+    /// The argument is reduced modulo 2 before it's multiplied by π, and that reduction is
+    /// exact, so unlike `(x * Quad::PI).sin()` this is accurate for arguments of any size.
+    /// The sine of every integer is exactly zero (with the sign of the argument), and the
+    /// sine of every odd multiple of 1/2 is exactly 1 or -1.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(1).sin_pi() == qd!(0));
+    /// assert!(qd!(0.5).sin_pi() == qd!(1));
+    ///
+    /// let x = qd!(0.25).sin_pi();
+    /// let diff = (x - Quad::FRAC_1_SQRT_2).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    pub fn sin_pi(self) -> Quad {
+        match self.pre_sin() {
+            Some(r) => s::guard("Quad::sin_pi", &[self.0], r),
+            None => {
+                let (k, t) = reduce_half_turns(self);
+                if t.is_zero() {
+                    return match k {
+                        0 | 2 if self.is_sign_negative() => Quad::NEG_ZERO,
+                        0 | 2 => Quad::ZERO,
+                        1 => Quad::ONE,
+                        _ => Quad::NEG_ONE,
+                    };
+                }
+                let (sin_t, cos_t) = (Quad::PI * t).sin_cos();
+                match k {
+                    0 => sin_t,
+                    1 => cos_t,
+                    2 => -sin_t,
+                    _ => -cos_t,
+                }
+            }
+        }
+    }
+
+    /// Computes the cosine (cos) of π times the `Quad`, so the `Quad` is an angle in
+    /// half-turns rather than radians.
+    ///
+    /// The argument is reduced modulo 2 before it's multiplied by π, and that reduction is
+    /// exact, so unlike `(x * Quad::PI).cos()` this is accurate for arguments of any size.
+    /// The cosine of every integer is exactly 1 or -1, and the cosine of every odd multiple
+    /// of 1/2 is exactly zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(1).cos_pi() == qd!(-1));
+    /// assert!(qd!(0.5).cos_pi() == qd!(0));
+    ///
+    /// // (x * π).cos() loses about 20 digits here
+    /// let x = Quad::new(1e20, 0.25, 0.0, 0.0).cos_pi();
+    /// let diff = (x - Quad::FRAC_1_SQRT_2).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    pub fn cos_pi(self) -> Quad {
+        match self.pre_cos() {
+            Some(r) => s::guard("Quad::cos_pi", &[self.0], r),
+            None => {
+                let (k, t) = reduce_half_turns(self);
+                if t.is_zero() {
+                    return match k {
+                        0 => Quad::ONE,
+                        2 => Quad::NEG_ONE,
+                        _ => Quad::ZERO,
+                    };
+                }
+                let (sin_t, cos_t) = (Quad::PI * t).sin_cos();
+                match k {
+                    0 => cos_t,
+                    1 => -sin_t,
+                    2 => -cos_t,
+                    _ => sin_t,
+                }
+            }
+        }
+    }
+
+    /// Computes the tangent (tan) of π times the `Quad`, so the `Quad` is an angle in
+    /// half-turns rather than radians.
+    ///
+    /// This is [`sin_pi`] divided by [`cos_pi`], and it shares their exact reduction. The
+    /// tangent of every integer is exactly zero, and the tangent of every odd multiple of
+    /// 1/2 is infinite: ∞ for 1/2, -∞ for 3/2, and so on alternately.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(2).tan_pi() == qd!(0));
+    /// assert!(qd!(0.5).tan_pi() == Quad::INFINITY);
+    ///
+    /// let x = qd!(0.25).tan_pi();
+    /// let diff = (x - qd!(1)).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`sin_pi`]: #method.sin_pi
+    /// [`cos_pi`]: #method.cos_pi
+    pub fn tan_pi(self) -> Quad {
+        match self.pre_sin() {
+            Some(r) => s::guard("Quad::tan_pi", &[self.0], r),
+            None => {
+                let (sin_x, cos_x) = (self.sin_pi(), self.cos_pi());
+                if cos_x.is_zero() {
+                    if sin_x.is_sign_positive() {
+                        Quad::INFINITY
+                    } else {
+                        Quad::NEG_INFINITY
+                    }
+                } else {
+                    sin_x / cos_x
+                }
+            }
+        }
+    }
+
+    /// Converts the `Quad` from degrees to radians.
+    ///
+    /// The `Quad` is multiplied by π/180 to the full precision of a `Quad`, so this is more
+    /// accurate than `x * Quad::PI / qd!(180)`, which rounds twice.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let diff = (qd!(90).to_radians() - Quad::FRAC_PI_2).abs();
+    /// assert!(diff < qd!(1e-63));
+    /// ```
+    #[inline]
+    pub fn to_radians(self) -> Quad {
+        self * c::RAD_PER_DEG
+    }
+
+    /// Converts the `Quad` from radians to degrees.
+    ///
+    /// The `Quad` is multiplied by 180/π to the full precision of a `Quad`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let diff = (Quad::FRAC_PI_3.to_degrees() - qd!(60)).abs();
+    /// assert!(diff < qd!(1e-61));
+    /// ```
+    #[inline]
+    pub fn to_degrees(self) -> Quad {
+        self * c::DEG_PER_RAD
+    }
+
+    /// Computes the sine (sin) of the `Quad`, taking it to be an angle in degrees.
+    ///
+    /// The argument is reduced modulo 360 and then to within 45 degrees of a multiple of
+    /// 90 before it's converted to radians, and that reduction is exact. So unlike
+    /// `x.to_radians().sin()`, this is just as accurate for huge arguments as for small
+    /// ones. The sine of every multiple of 180 is exactly zero (with the sign of the
+    /// argument), the sine of every odd multiple of 90 is exactly 1 or -1, and the sine of
+    /// ±30 is exactly ±1/2 (as is the sine of anything that reduces to one of those).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(180).sin_deg() == qd!(0));
+    /// assert!(qd!(-270).sin_deg() == qd!(1));
+    /// assert!(qd!(150).sin_deg() == qd!(0.5));
+    ///
+    /// // 10^30 + 45 degrees is 325 degrees past a multiple of 360
+    /// let x = (qd!("1e30") + qd!(45)).sin_deg();
+    /// assert!(x == qd!(325).sin_deg());
+    /// let diff = (x + qd!(35).to_radians().sin()).abs();
+    /// assert!(diff < qd!(1e-62));
+    /// ```
+    pub fn sin_deg(self) -> Quad {
+        match self.pre_sin() {
+            Some(r) => s::guard("Quad::sin_deg", &[self.0], r),
+            None => {
+                let (k, t) = reduce_degrees(self);
+                if t.is_zero() {
+                    return match k {
+                        0 | 2 if self.is_sign_negative() => Quad::NEG_ZERO,
+                        0 | 2 => Quad::ZERO,
+                        1 => Quad::ONE,
+                        _ => Quad::NEG_ONE,
+                    };
+                }
+                let (sin_t, cos_t) = sin_cos_degrees(t);
+                match k {
+                    0 => sin_t,
+                    1 => cos_t,
+                    2 => -sin_t,
+                    _ => -cos_t,
+                }
+            }
+        }
+    }
+
+    /// Computes the cosine (cos) of the `Quad`, taking it to be an angle in degrees.
+    ///
+    /// This shares the exact reduction of [`sin_deg`]. The cosine of every multiple of 360
+    /// is exactly 1, the cosine of every odd multiple of 180 is exactly -1, the cosine of
+    /// every odd multiple of 90 is exactly zero, and the cosine of ±60 is exactly 1/2.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(540).cos_deg() == qd!(-1));
+    /// assert!(qd!(90).cos_deg() == qd!(0));
+    /// assert!(qd!(-60).cos_deg() == qd!(0.5));
+    ///
+    /// let diff = (qd!(45).cos_deg() - Quad::FRAC_1_SQRT_2).abs();
+    /// assert!(diff < qd!(1e-63));
+    /// ```
+    ///
+    /// [`sin_deg`]: #method.sin_deg
+    pub fn cos_deg(self) -> Quad {
+        match self.pre_cos() {
+            Some(r) => s::guard("Quad::cos_deg", &[self.0], r),
+            None => {
+                let (k, t) = reduce_degrees(self);
+                if t.is_zero() {
+                    return match k {
+                        0 => Quad::ONE,
+                        2 => Quad::NEG_ONE,
+                        _ => Quad::ZERO,
+                    };
+                }
+                let (sin_t, cos_t) = sin_cos_degrees(t);
+                match k {
+                    0 => cos_t,
+                    1 => -sin_t,
+                    2 => -cos_t,
+                    _ => sin_t,
+                }
+            }
+        }
+    }
+
+    /// Computes the tangent (tan) of the `Quad`, taking it to be an angle in degrees.
+    ///
+    /// This is [`sin_deg`] divided by [`cos_deg`], and it shares their exact reduction.
+    /// The tangent of every multiple of 180 is exactly zero, the tangent of every odd
+    /// multiple of 45 is exactly 1 or -1, and the tangent of every odd multiple of 90 is
+    /// infinite: ∞ for 90, -∞ for 270, and so on alternately.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(360).tan_deg() == qd!(0));
+    /// assert!(qd!(135).tan_deg() == qd!(-1));
+    /// assert!(qd!(90).tan_deg() == Quad::INFINITY);
+    /// ```
+    ///
+    /// [`sin_deg`]: #method.sin_deg
+    /// [`cos_deg`]: #method.cos_deg
+    pub fn tan_deg(self) -> Quad {
+        match self.pre_sin() {
+            Some(r) => s::guard("Quad::tan_deg", &[self.0], r),
+            None => {
+                let (sin_x, cos_x) = (self.sin_deg(), self.cos_deg());
+                if cos_x.is_zero() {
+                    if sin_x.is_sign_positive() {
+                        Quad::INFINITY
+                    } else {
+                        Quad::NEG_INFINITY
+                    }
+                } else {
+                    sin_x / cos_x
+                }
+            }
+        }
+    }
+
     /// Computes the 2-argument inverse tangent (tan<sup>-1</sup>) of this `Quad` and
     /// another `Quad`.
     ///
@@ -209,6 +569,13 @@ impl Quad {
     ///
     /// This function extends the range of the result to [-π, π].
     ///
+    /// Only the ratio of the arguments matters, and the result is accurate for any finite
+    /// arguments, from those near [`MAX`] down to the smallest normal `f64`.
+    ///
+    /// Zeros and infinities are handled as they are by `f64::atan2`. The result has the
+    /// sign of the `Quad` that this is called on, so `qd!(-0.0).atan2(qd!(-1))` is -π
+    /// rather than π, and both arguments being zero gives ±0 or ±π rather than NaN.
+    ///
     /// Because this function deals with angles around the origin and Cartesian coordinates,
     /// it's very useful for converting between Cartesian and polar coordinates.
     ///
@@ -235,49 +602,56 @@ impl Quad {
     /// ```
     ///
     /// [`atan`]: #method.atan
+    /// [`MAX`]: #associatedconstant.MAX
     pub fn atan2(self, other: Quad) -> Quad {
         match self.pre_atan2(&other) {
-            Some(r) => r,
+            Some(r) => s::guard("Quad::atan2", &[self.0, other.0], r),
             None => {
                 // Strategy:
                 //
-                // Use Newton's iteration to solve one of the following equations
+                // Use Newton's iteration to solve
+                //
+                //      f(z) = y cos z - x sin z = 0
                 //
-                //      sin z = y / r
-                //      cos z = x / r
+                // which holds exactly when z is the angle of (x, y). The iteration is
                 //
-                // where r = √(x² + y²).
+                //      z' = z + (y cos z - x sin z) / (y sin z + x cos z)
                 //
-                // The iteration is given by z' = z + (y - sin z) / cos z
-                //      (for the first equation) z' = z - (x - cos z) / sin z
-                //      (for the second equation)
+                // The denominator is r cos(z - θ), where r = √(x² + y²) and θ is the true
+                // angle, so it's never far from r. Unlike solving sin z = y / r or
+                // cos z = x / r, this doesn't have to calculate r or divide by it, and so
+                // doesn't pick up the rounding errors of either. It also works the same way
+                // in every direction, so there's no choosing between equations.
                 //
-                // Here, x and y are normalized so that x² + y² = 1. If |x| > |y|, the first
-                // iteration is used since the denominator is larger. Otherwise the second
-                // is used.
-                let r = (self.sqr() + other.sqr()).sqrt();
-                let x = other / r;
-                let y = self / r;
+                // The products overflow if the arguments are near MAX, and they lose
+                // precision to the subnormal range if the arguments are much below 1e-150.
+                // The angle depends only on the ratio of the arguments, so in those cases
+                // both are first scaled by the same power of 2, one that brings the larger
+                // of them near 1.
+                let m = self.0.abs().max(other.0.abs());
+                let (y, x) = if !(1e-150..=1e150).contains(&m) {
+                    let k = -(m.log2().floor() as i32);
+                    (c::scale(self, k), c::scale(other, k))
+                } else {
+                    (self, other)
+                };
 
                 // Compute f64 approximation to atan
                 let mut z = Quad::from(self.0.atan2(other.0));
 
-                if x.0.abs() > y.0.abs() {
-                    // Use the first iteration above
-                    let (sin_z, cos_z) = z.sin_cos();
-                    z += (y - sin_z) / cos_z;
-                    let (sin_z, cos_z) = z.sin_cos();
-                    z += (y - sin_z) / cos_z;
-                    let (sin_z, cos_z) = z.sin_cos();
-                    z += (y - sin_z) / cos_z;
-                } else {
-                    // Use the second iteration above
-                    let (sin_z, cos_z) = z.sin_cos();
-                    z -= (x - cos_z) / sin_z;
-                    let (sin_z, cos_z) = z.sin_cos();
-                    z -= (x - cos_z) / sin_z;
+                // Each iteration about doubles the number of correct digits, so three of
+                // them are usually enough. But the last of those works right at the limit
+                // of the precision of sin_cos, so iterations continue until a correction
+                // is below the precision of z, up to two more times.
+                let eps = c::mul_pwr2(Quad::EPSILON, 2f64.powi(u::exponent(z.0) + 1));
+
+                for _ in 0..ATAN2_MAX_ITER {
                     let (sin_z, cos_z) = z.sin_cos();
-                    z -= (x - cos_z) / sin_z;
+                    let d = (y * cos_z - x * sin_z) / (y * sin_z + x * cos_z);
+                    z += d;
+                    if d.abs() < eps {
+                        break;
+                    }
                 }
                 z
             }
@@ -301,7 +675,7 @@ impl Quad {
     /// [`NAN`]: #associatedconstant.NAN
     pub fn asin(self) -> Quad {
         match self.pre_asin() {
-            Some(r) => r,
+            Some(r) => s::guard("Quad::asin", &[self.0], r),
             None => self.atan2((Quad::ONE - self.sqr()).sqrt()),
         }
     }
@@ -323,11 +697,67 @@ impl Quad {
     /// [`NAN`]: #associatedconstant.NAN
     pub fn acos(self) -> Quad {
         match self.pre_acos() {
-            Some(r) => r,
+            Some(r) => s::guard("Quad::acos", &[self.0], r),
             None => (Quad::ONE - self.sqr()).sqrt().atan2(self),
         }
     }
 
+    /// Computes the inverse sine (sin<sup>-1</sup>) of the `Quad`, treating arguments that
+    /// are barely outside of [-1, 1] as ±1.
+    ///
+    /// A number that should be ±1, like the cosine of an angle worked out from a dot
+    /// product, can come out a few ulps past it because of rounding, and [`asin`] returns
+    /// [`NAN`] for it. This function first moves any argument that is no more than 4 ×
+    /// [`EPSILON`] outside of [-1, 1] to the nearer of -1 and 1, so those arguments give
+    /// ±π/2 instead. Arguments farther out than that are still out of the domain and still
+    /// result in [`NAN`]. Inside of the domain, this is exactly the same as [`asin`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::ONE + Quad::EPSILON * qd!(2);
+    /// assert!(x.asin().is_nan());
+    /// assert!(x.asin_clamped() == Quad::FRAC_PI_2);
+    ///
+    /// assert!(qd!(1.001).asin_clamped().is_nan());
+    /// assert!(qd!(0.5).asin_clamped() == qd!(0.5).asin());
+    /// ```
+    ///
+    /// [`asin`]: #method.asin
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn asin_clamped(self) -> Quad {
+        self.clamp_unit().asin()
+    }
+
+    /// Computes the inverse cosine (cos<sup>-1</sup>) of the `Quad`, treating arguments
+    /// that are barely outside of [-1, 1] as ±1.
+    ///
+    /// This is to [`acos`] what [`asin_clamped`] is to [`asin`]. Any argument that is no
+    /// more than 4 × [`EPSILON`] outside of [-1, 1] is moved to the nearer of -1 and 1
+    /// first, so it gives 0 or π instead of [`NAN`]. Arguments farther out than that still
+    /// result in [`NAN`], and inside of the domain this is exactly the same as [`acos`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = -Quad::ONE - Quad::EPSILON * qd!(2);
+    /// assert!(x.acos().is_nan());
+    /// assert!(x.acos_clamped() == Quad::PI);
+    ///
+    /// assert!(qd!(-1.001).acos_clamped().is_nan());
+    /// assert!(qd!(0.5).acos_clamped() == qd!(0.5).acos());
+    /// ```
+    ///
+    /// [`acos`]: #method.acos
+    /// [`asin`]: #method.asin
+    /// [`asin_clamped`]: #method.asin_clamped
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn acos_clamped(self) -> Quad {
+        self.clamp_unit().acos()
+    }
+
     /// Computes the inverse tangent (tan<sup>-1</sup>) of the `Quad`. The domain of this
     /// function is [-∞, ∞] and the range is [-π/2, π/2].
     ///
@@ -356,7 +786,7 @@ impl Quad {
     #[inline]
     fn pre_sin_cos(&self) -> Option<(Quad, Quad)> {
         if self.is_zero() {
-            Some((Quad::ZERO, Quad::ONE))
+            Some((*self, Quad::ONE))
         } else if !self.is_finite() {
             Some((Quad::NAN, Quad::NAN))
         } else {
@@ -367,7 +797,7 @@ impl Quad {
     #[inline]
     fn pre_sin(&self) -> Option<Quad> {
         if self.is_zero() {
-            Some(Quad::ZERO)
+            Some(*self)
         } else if !self.is_finite() {
             Some(Quad::NAN)
         } else {
@@ -388,32 +818,35 @@ impl Quad {
 
     #[inline]
     fn pre_atan2(&self, other: &Quad) -> Option<Quad> {
-        if other.is_zero() {
-            if self.is_zero() {
-                Some(Quad::NAN)
-            } else if self.is_sign_positive() {
-                Some(Quad::FRAC_PI_2)
+        // The special cases are those of `f64::atan2`. The result always has the sign of
+        // `self`, which is how an angle of 0 or π still tells which side of the x-axis it
+        // came from.
+        if self.is_nan() || other.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_infinite() {
+            let r = if other.is_infinite() {
+                if other.is_sign_positive() {
+                    Quad::FRAC_PI_4
+                } else {
+                    Quad::FRAC_3_PI_4
+                }
             } else {
-                Some(-Quad::FRAC_PI_2)
-            }
-        } else if self.is_zero() {
-            if other.is_sign_positive() {
-                Some(Quad::ZERO)
+                Quad::FRAC_PI_2
+            };
+            Some(if self.is_sign_positive() { r } else { -r })
+        } else if self.is_zero() || other.is_infinite() {
+            let r = if other.is_sign_positive() {
+                Quad::ZERO
             } else {
-                Some(Quad::PI)
-            }
-        } else if self.is_infinite() {
-            if other.is_infinite() {
-                Some(Quad::NAN)
-            } else if self.is_sign_positive() {
+                Quad::PI
+            };
+            Some(if self.is_sign_positive() { r } else { -r })
+        } else if other.is_zero() {
+            if self.is_sign_positive() {
                 Some(Quad::FRAC_PI_2)
             } else {
                 Some(-Quad::FRAC_PI_2)
             }
-        } else if other.is_infinite() {
-            Some(Quad::ZERO)
-        } else if self.is_nan() || other.is_nan() {
-            Some(Quad::NAN)
         } else if *self == *other {
             if self.is_sign_positive() {
                 Some(Quad::FRAC_PI_4)
@@ -456,14 +889,71 @@ impl Quad {
             None
         }
     }
+
+    // Returns ±1 in place of an argument that's outside of [-1, 1] by no more than
+    // `CLAMP_TOLERANCE`, and the argument itself otherwise (NaN included).
+    #[inline]
+    fn clamp_unit(self) -> Quad {
+        let a = self.abs();
+        if a > Quad::ONE && a - Quad::ONE <= CLAMP_TOLERANCE {
+            Quad::ONE.copysign(self)
+        } else {
+            self
+        }
+    }
+}
+
+// Splits `x`, an angle in half-turns, into 2n + k/2 + t for some integer n, with k in
+// [0, 3] and |t| at most 1/4. Each component minus the even integer nearest to it is
+// exact, and so is the sum of those differences, so every step of this is exact and t
+// has all of the precision of the fractional part of `x`.
+fn reduce_half_turns(x: Quad) -> (i32, Quad) {
+    let frac = |a: f64| a - 2.0 * (a / 2.0).round();
+    // The first pass leaves a sum in [-4, 4], and the second brings that into [-1, 1]
+    let r = Quad::from_sum(&[frac(x.0), frac(x.1), frac(x.2), frac(x.3)]);
+    let r = Quad::from_sum(&[frac(r.0), frac(r.1), frac(r.2), frac(r.3)]);
+    let k = (2.0 * r.0).round();
+    let t = Quad::from_sum(&[r.0 - k / 2.0, r.1, r.2, r.3]);
+    ((k as i32).rem_euclid(4), t)
 }
 
-const FRAC_PI_1024: Quad = Quad(
-    3.067_961_575_771_282_3e-3,
-    1.195_944_139_792_337e-19,
-    -2.924_579_892_303_066e-36,
-    1.086_381_075_061_875_9e-52,
-);
+// Splits `x`, an angle in degrees, into 360n + 90k + t for some integer n, with k in
+// [0, 3] and |t| at most 45 (or a hair more, if the lower components push it over). The
+// remainder of an `f64` divided by 360 is always exact, and so is the difference between
+// a number no larger than 360 and the multiple of 90 nearest to it, so like
+// `reduce_half_turns`, this is exact.
+fn reduce_degrees(x: Quad) -> (i32, Quad) {
+    // The first pass leaves a sum in (-1440, 1440), and the second brings that into
+    // (-360, 360) give or take the lower components
+    let r = Quad::from_sum(&[x.0 % 360.0, x.1 % 360.0, x.2 % 360.0, x.3 % 360.0]);
+    let r = Quad::from_sum(&[r.0 % 360.0, r.1 % 360.0, r.2 % 360.0, r.3 % 360.0]);
+    let k = (r.0 / 90.0).round();
+    let t = Quad::from_sum(&[r.0 - 90.0 * k, r.1, r.2, r.3]);
+    ((k as i32).rem_euclid(4), t)
+}
+
+// Computes the sine and cosine of `t` degrees, where `t` is nonzero and no more than about
+// 45 in magnitude. The angles whose sines or cosines are exactly 1/2 are handled
+// separately so that those come out exact, and ±45 so that its sine and cosine are the
+// same.
+fn sin_cos_degrees(t: Quad) -> (Quad, Quad) {
+    let a = t.abs();
+    let (sin_a, cos_a) = if a == Quad(30.0, 0.0, 0.0, 0.0) {
+        (
+            Quad(0.5, 0.0, 0.0, 0.0),
+            c::mul_pwr2(Quad::from(3).sqrt(), 0.5),
+        )
+    } else if a == Quad(45.0, 0.0, 0.0, 0.0) {
+        (Quad::FRAC_1_SQRT_2, Quad::FRAC_1_SQRT_2)
+    } else {
+        (a * c::RAD_PER_DEG).sin_cos()
+    };
+    if t.is_sign_negative() {
+        (-sin_a, cos_a)
+    } else {
+        (sin_a, cos_a)
+    }
+}
 
 // Compute sin a using the Taylor series. This assumes that |a| <= π/2048.
 #[allow(clippy::many_single_char_names)]
@@ -475,14 +965,13 @@ fn sin_taylor(a: Quad) -> Quad {
         let x = -a.sqr();
         let mut s = a;
         let mut r = a;
-        let mut i = 0;
 
-        loop {
+        // 1/3!, 1/5!, 1/7!, ...
+        for f in c::INV_FACTS.iter().step_by(2) {
             r *= x;
-            let t = r * c::INV_FACTS[i];
+            let t = r * *f;
             s += t;
-            i += 2;
-            if i >= c::INV_FACTS.len() || t.abs() <= threshold {
+            if t.abs() <= threshold {
                 break;
             }
         }
@@ -500,14 +989,13 @@ fn cos_taylor(a: Quad) -> Quad {
         let x = -a.sqr();
         let mut r = x;
         let mut s = Quad::ONE + c::mul_pwr2(r, 0.5);
-        let mut i = 1;
 
-        loop {
+        // 1/4!, 1/6!, 1/8!, ...
+        for f in c::INV_FACTS.iter().skip(1).step_by(2) {
             r *= x;
-            let t = r * c::INV_FACTS[i];
+            let t = r * *f;
             s += t;
-            i += 2;
-            if i >= c::INV_FACTS.len() || t.abs() <= threshold {
+            if t.abs() <= threshold {
                 break;
             }
         }
@@ -515,45 +1003,66 @@ fn cos_taylor(a: Quad) -> Quad {
     }
 }
 
-// Computes both the sine and cosine of a using the Taylor series. This is a bit quicker
-// than calling the two functions above separately, since if you have one of them you can
-// calculate the other more efficiently.
+// Computes both the sine and cosine of a using the Taylor series. The two series are
+// summed side by side, sharing the powers of a between them. This is quicker than calling
+// the two functions above separately, and unlike deriving one from the other with
+// √(1 - x²), it doesn't compound the error of the square root into the second result, so
+// sin² + cos² stays as close to 1 as the two separate series would get it.
 fn sincos_taylor(a: Quad) -> (Quad, Quad) {
     if a.is_zero() {
         (Quad::ZERO, Quad::ONE)
     } else {
-        let sin_a = sin_taylor(a);
-        (sin_a, (Quad::ONE - sin_a.sqr()).sqrt())
+        let sin_threshold = c::mul_pwr2(a.abs() * Quad::EPSILON, 0.5);
+        let cos_threshold = c::mul_pwr2(Quad::EPSILON, 0.5);
+        let x = -a.sqr();
+        let mut r = x;
+        let mut sin_a = a + a * r * c::INV_FACTS[0];
+        let mut cos_a = Quad::ONE + c::mul_pwr2(r, 0.5);
+
+        // (1/4!, 1/5!), (1/6!, 1/7!), ...
+        for f in c::INV_FACTS[1..].chunks_exact(2) {
+            r *= x;
+            let tc = r * f[0];
+            let ts = a * r * f[1];
+            cos_a += tc;
+            sin_a += ts;
+            if tc.abs() <= cos_threshold && ts.abs() <= sin_threshold {
+                break;
+            }
+        }
+        (sin_a, cos_a)
     }
 }
 
 // Helper function to reduce the input to a value whose sin/cos can be calculated via Taylor
-// series. It firsts reduces modulo 2π, then π/2, then π/1024. Aside from returning the
-// reduced value (`t`), it also returns the group within the next higher modulo in which the
-// value fell (`j` and `k`, this is the quadrant for `j`).
+// series. It finds the multiple of π/2 nearest to the argument, and then the multiple of
+// π/1024 nearest to what's left. Aside from returning the reduced value (`t`), it also
+// returns the multiples (`j` and `k`, where `j` is the quadrant).
+//
+// `j` is in [-1, 2] and `k` is in [-256, 256]. |t| is π/2048 at most, give or take the few
+// ulps described at c::TRIG_STEP.
+//
+// Past π/4, the argument is reduced with the exact bits of 2/π (see `common::reduction`),
+// so `t` has the full precision of a `Quad` however large the argument is and however
+// close it is to a multiple of π/2.
 #[inline]
 #[allow(clippy::many_single_char_names)]
 fn reduce(a: Quad) -> (i32, i32, Quad) {
-    // reduce modulo 2π
-    let z = (a / Quad::TAU).round();
-    let r = a - z * Quad::TAU;
-
-    // reduce modulo π/2
-    let mut q = (r.0 / Quad::FRAC_PI_2.0 + 0.5).floor();
-    let mut t = r - Quad(q, 0.0, 0.0, 0.0) * Quad::FRAC_PI_2;
-    let j = q as i32;
-
-    // reduce modulo π/1024
-    q = (t.0 / FRAC_PI_1024.0 + 0.5).floor();
-    t -= Quad(q, 0.0, 0.0, 0.0) * FRAC_PI_1024;
-    let k = q as i32;
+    if a.0.abs() < Quad::FRAC_PI_4.0 {
+        let k = (a.0 / c::TRIG_STEP.0 + 0.5).floor() as i32;
+        return (0, k, a - c::TRIG_STEP.mul_i32(k));
+    }
 
-    (j, k, t)
+    let mut f = [0.0; 4];
+    let (j, k) = r::quarter_turns(&a.to_array(), c::TRIG_STEPS as u32 / 2, &mut f);
+    let t = Quad(f[0], f[1], f[2], f[3]) * Quad::FRAC_PI_2;
+    (if j == 3 { -1 } else { j }, k, t)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::random::Rng;
 
     // sin tests
     test_all_near!(
@@ -847,6 +1356,327 @@ mod tests {
             Quad::NAN.sin_cos().1;
     );
 
+    // sin² + cos² should be 1 to within 2 ulps, and sin_cos should agree with sin and cos to
+    // within an ulp, for arguments across a wide range of magnitudes.
+    test!(sin_cos_identity: {
+        let mut rng = Rng::new(0x1234_5678_9abc_def1);
+        for _ in 0..100_000 {
+            let m = 1.0 + rng.next_f64();
+            let e = rng.range(-30, 41);
+            let hi = m * 2f64.powi(e);
+            let lo = (rng.next_u64() >> 11) as f64 * 2f64.powi(e - 106);
+            let x = Quad(hi, 0.0, 0.0, 0.0) + Quad(lo, 0.0, 0.0, 0.0);
+            let x = if rng.next_u64() % 2 == 0 { x } else { -x };
+            let (s, c) = x.sin_cos();
+            let dev = (s.sqr() + c.sqr() - Quad::ONE).abs();
+            assert!(
+                dev <= c::mul_pwr2(Quad::EPSILON, 2.0),
+                "sin² + cos² - 1 = {:e} for x = {:?}", dev, x
+            );
+
+            let (sin_x, cos_x) = (x.sin(), x.cos());
+            assert!(
+                (s - sin_x).abs() <= Quad::EPSILON * sin_x.abs(),
+                "sin_cos(x).0 = {:?}, sin(x) = {:?} for x = {:?}", s, sin_x, x
+            );
+            assert!(
+                (c - cos_x).abs() <= Quad::EPSILON * cos_x.abs(),
+                "sin_cos(x).1 = {:?}, cos(x) = {:?} for x = {:?}", c, cos_x, x
+            );
+        }
+    });
+
+    // midpoint tests, arguments halfway between two multiples of the reduction step, where
+    // the reduced argument is as large as it gets
+    test_all_near!(
+        sin_mid_1:
+            qd!("0.001533980186284765612303697150264079079954864575237392967614859668146258"),
+            (Quad::PI * qd!(1) / qd!(2048)).sin();
+        sin_mid_3:
+            qd!("0.004601926120448570764901699296911967372884925161511020046049954472880089"),
+            (Quad::PI * qd!(3) / qd!(2048)).sin();
+        sin_mid_511:
+            qd!("0.7060212614493397902524503933815839897077829035586527714539138606149773"),
+            (Quad::PI * qd!(511) / qd!(2048)).sin();
+        sin_mid_513:
+            qd!("0.7081906370331953125878242541405249882449454875338085648459262974947150"),
+            (Quad::PI * qd!(513) / qd!(2048)).sin();
+        sin_mid_1025:
+            qd!("0.9999988234517019099290257101715260190482679228897650392238625294550997"),
+            (Quad::PI * qd!(1025) / qd!(2048)).sin();
+        sin_mid_1537:
+            qd!("0.7060212614493397902524503933815839897077829035586527714539138605971392"),
+            (Quad::PI * qd!(1537) / qd!(2048)).sin();
+        sin_mid_neg_1535:
+            qd!("-0.7081906370331953125878242541405249882449454875338085648459262974786075"),
+            (Quad::PI * qd!(-1535) / qd!(2048)).sin();
+        sin_mid_4609:
+            qd!("0.7081906370331953125878242541405249882449454875338085648459262975126838"),
+            (Quad::PI * qd!(4609) / qd!(2048)).sin();
+        sin_mid_6555:
+            qd!("-0.5895213186410639015633296889478561398105159047455654510521344058571565"),
+            (Quad::PI * qd!(6555) / qd!(2048)).sin();
+        cos_mid_1:
+            qd!("0.9999988234517019099290257101715260190482679228897650392238625294551118"),
+            (Quad::PI * qd!(1) / qd!(2048)).cos();
+        cos_mid_513:
+            qd!("0.7060212614493397902524503933815839897077829035586527714539138606085800"),
+            (Quad::PI * qd!(513) / qd!(2048)).cos();
+        cos_mid_1023:
+            qd!("0.001533980186284765612303697150264079079954864575237392967614859661505360"),
+            (Quad::PI * qd!(1023) / qd!(2048)).cos();
+        cos_mid_1025:
+            qd!("-0.001533980186284765612303697150264079079954864575237392967614859676011193"),
+            (Quad::PI * qd!(1025) / qd!(2048)).cos();
+        cos_mid_2047:
+            qd!("-0.9999988234517019099290257101715260190482679228897650392238625294551595"),
+            (Quad::PI * qd!(2047) / qd!(2048)).cos();
+        cos_mid_4095:
+            qd!("0.9999988234517019099290257101715260190482679228897650392238625294551217"),
+            (Quad::PI * qd!(4095) / qd!(2048)).cos();
+        cos_mid_6555:
+            qd!("-0.8077528179261903405949593671963971223303189792059227423325050797998031"),
+            (Quad::PI * qd!(6555) / qd!(2048)).cos();
+    );
+
+    // Reduction tests. These check the argument reduction against sines and cosines of
+    // kπ/1024 that don't come from the tables, but from Taylor series summed directly.
+    fn taylor(x: Quad) -> (Quad, Quad) {
+        let mut sums = [Quad::ZERO, Quad::ZERO];
+        let mut term = Quad::ONE;
+        for n in 0..50 {
+            sums[n % 2] += if n % 4 < 2 { term } else { -term };
+            term = term * x / Quad::from(n as i32 + 1);
+        }
+        (sums[1], sums[0])
+    }
+
+    fn steps() -> Vec<(Quad, Quad)> {
+        (0..=c::TRIG_STEPS as i32 / 4)
+            .map(|k| taylor(Quad::PI * Quad::from(k) / Quad::from(c::TRIG_STEPS as i32)))
+            .collect()
+    }
+
+    // A dense grid over several turns in both directions, plus the midpoints between
+    // multiples of the step and the f64s on either side of them, where the reduced argument
+    // is at its largest.
+    fn grid() -> Vec<Quad> {
+        let mut xs: Vec<Quad> = (-2500..=2500)
+            .map(|i| Quad(i as f64 / 128.0, 0.0, 0.0, 0.0) + Quad(1e-17 * i as f64, 0.0, 0.0, 0.0))
+            .collect();
+        for m in -1500..1500 {
+            let x = Quad::PI * Quad::from(2 * m + 1) / Quad::from(c::TRIG_STEPS as i32 * 2);
+            let bits = x.0.to_bits();
+            xs.extend(&[
+                x,
+                Quad(f64::from_bits(bits - 1), 0.0, 0.0, 0.0),
+                Quad(f64::from_bits(bits + 1), 0.0, 0.0, 0.0),
+            ]);
+        }
+        xs
+    }
+
+    test!(reduce_constants: {
+        assert!(c::SINES.len() == c::TRIG_STEPS / 4);
+        assert!(c::COSINES.len() == c::TRIG_STEPS / 4);
+        // Both are correctly rounded, and scaling by a power of 2 doesn't change that
+        assert!(c::TRIG_STEP == c::mul_pwr2(Quad::PI, 1.0 / c::TRIG_STEPS as f64));
+        assert!(Quad::FRAC_PI_2 == c::mul_pwr2(Quad::PI, 0.5));
+        assert!(Quad::TAU == c::mul_pwr2(Quad::PI, 2.0));
+    });
+
+    test!(reduce_tables: {
+        for (k, &(s, c)) in steps().iter().enumerate().skip(1) {
+            let sin_dev = (c::SINES[k - 1] - s).abs();
+            let cos_dev = (c::COSINES[k - 1] - c).abs();
+            let (sin_tol, cos_tol) = (c::mul_pwr2(Quad::EPSILON * s, 4.0), c::mul_pwr2(Quad::EPSILON * c, 4.0));
+            assert!(sin_dev <= sin_tol, "sin({}π/1024) is off by {:e}", k, sin_dev);
+            assert!(cos_dev <= cos_tol, "cos({}π/1024) is off by {:e}", k, cos_dev);
+        }
+    });
+
+    test!(reduce_bound: {
+        let max_k = c::TRIG_STEPS as i32 / 4;
+        let bound = c::TRIG_STEP.0 * (0.5 + c::TRIG_STEPS as f64 * f64::EPSILON);
+        for x in grid() {
+            let (j, k, t) = reduce(x);
+            assert!(j.abs() <= 2, "j = {} for x = {:?}", j, x);
+            assert!(k.abs() <= max_k, "k = {} for x = {:?}", k, x);
+            assert!(t.abs() <= Quad(bound, 0.0, 0.0, 0.0), "t = {:?} for x = {:?}", t, x);
+        }
+    });
+
+    // The sine and cosine of every point on the grid have to be what the reduced argument
+    // and the independent values of sin and cos of kπ/1024 say they are, to within a few
+    // ulps.
+    test!(reduce_recombination: {
+        let steps = steps();
+        for x in grid() {
+            let (j, k, t) = reduce(x);
+            let (sin_t, cos_t) = taylor(t);
+            let (v, u) = steps[k.unsigned_abs() as usize];
+            let v = if k < 0 { -v } else { v };
+            let (s, c) = (u * sin_t + v * cos_t, u * cos_t - v * sin_t);
+            let (s, c) = match j {
+                0 => (s, c),
+                1 => (c, -s),
+                -1 => (-c, s),
+                _ => (-s, -c),
+            };
+            let sin_dev = (x.sin() - s).abs();
+            let cos_dev = (x.cos() - c).abs();
+            assert!(sin_dev <= Quad::EPSILON, "sin(x) is off by {:e} for x = {:?}", sin_dev, x);
+            assert!(cos_dev <= Quad::EPSILON, "cos(x) is off by {:e} for x = {:?}", cos_dev, x);
+        }
+    });
+
+    // Negating the argument has to negate the sine and the tangent and leave the cosine
+    // alone, bit for bit. The reduction rounds to the nearest multiple of π/2 and then of
+    // the step, so the arguments most likely to break this are the near-ties of those
+    // roundings: the midpoints in the grid, and the odd multiples of π/4 and the f64s
+    // within a few ulps of them.
+    fn assert_symmetric(x: Quad) {
+        let (s, c) = x.sin_cos();
+        assert!(
+            (-x).sin_cos() == (-s, c),
+            "sin_cos isn't symmetric at {:?}",
+            x
+        );
+        assert!((-x).sin() == -x.sin(), "sin isn't odd at {:?}", x);
+        assert!((-x).cos() == x.cos(), "cos isn't even at {:?}", x);
+        assert!((-x).tan() == -x.tan(), "tan isn't odd at {:?}", x);
+    }
+
+    // Pseudo-random arguments with full-precision tails, spread over magnitudes from
+    // 2^-20 to 2^20.
+    fn check_symmetry_random(samples: usize) {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..samples {
+            let e = rng.range(-20, 21);
+            let mut x = Quad::ZERO;
+            for i in 0..4 {
+                let m = (rng.next_u64() >> 11) as f64 * 2f64.powi(e - 53 * (i as i32 + 1));
+                x += Quad::from(m);
+            }
+            assert_symmetric(x);
+        }
+    }
+
+    test!(symmetry_near_ties: {
+        let mut xs = grid();
+        for m in 0..64 {
+            let x = Quad::FRAC_PI_4 * Quad::from(2 * m + 1);
+            let bits = x.0.to_bits();
+            xs.push(x);
+            for d in 1..4 {
+                xs.push(Quad::from(f64::from_bits(bits - d)));
+                xs.push(Quad::from(f64::from_bits(bits + d)));
+            }
+        }
+        for x in xs {
+            assert_symmetric(x);
+        }
+    });
+
+    test!(symmetry_random: {
+        check_symmetry_random(5_000);
+    });
+
+    #[test]
+    #[ignore]
+    fn symmetry_random_long() {
+        check_symmetry_random(1_000_000);
+    }
+
+    // huge arguments and near multiples of π/2, where a reduction by a multiple of 2π that
+    // was only as precise as a Quad would lose most or all of the digits
+    test_all_near!(
+        sin_1e10:
+            qd!("-0.4875060250875106915277942943481060416764473169227868857452545378451586"),
+            qd!("1e10").sin();
+        cos_1e10:
+            qd!("0.8731196226768560011761913453076951961904126001676867360692192928759264"),
+            qd!("1e10").cos();
+        tan_1e10:
+            qd!("-0.5583496378112418465618934073186368185816480993306071649962329593435824"),
+            qd!("1e10").tan();
+        sin_1e20:
+            qd!("-0.6452512852657808442058117113125230074069041966868971183031170068878986"),
+            qd!("1e20").sin();
+        cos_1e20:
+            qd!("0.7639704044417283004001468027378811228344734417470044806221890669465897"),
+            qd!("1e20").cos();
+        tan_1e20:
+            qd!("-0.8446024630198842541840932340005535811653554727925843124542581382679836"),
+            qd!("1e20").tan();
+        sin_1e30:
+            qd!("-0.09011690191213805803038642895298733027439633299304344988546066657977398"),
+            qd!("1e30").sin();
+        cos_1e30:
+            qd!("-0.9959311944053957023942485879970486411302477349550482713468004179937721"),
+            qd!("1e30").cos();
+        tan_1e30:
+            qd!("0.09048506806330217256622313805004127372738954023205417991033965089185426"),
+            qd!("1e30").tan();
+        sin_f64_max:
+            qd!("0.004961954789184061790502671197074705750764699956791831722604733008698889"),
+            Quad::from(f64::MAX).sin();
+        sin_1e22_pi:
+            qd!("-8.676722636988833509911966654960328656252637552464003663484935924519102e-44"),
+            (qd!("1e22") * Quad::PI).sin();
+        cos_1e22_pi:
+            Quad::ONE,
+            (qd!("1e22") * Quad::PI).cos();
+        tan_1e22_pi:
+            qd!("-8.676722636988833509911966654960328656252637552464003663484935924519102e-44"),
+            (qd!("1e22") * Quad::PI).tan();
+        sin_1e40_pi_2:
+            qd!("-3.105999825658579060221213853305695696237350744605782951094881133745523e-26"),
+            (qd!("1e40") * Quad::FRAC_PI_2).sin();
+        cos_1e40_pi_2:
+            qd!("0.9999999999999999999999999999999999999999999999999995176382541504438241"),
+            (qd!("1e40") * Quad::FRAC_PI_2).cos();
+        tan_1e40_pi_2:
+            qd!("-3.105999825658579060221213853305695696237350744605784449310379646834827e-26"),
+            (qd!("1e40") * Quad::FRAC_PI_2).tan();
+    );
+
+    // rem_2pi tests
+    test_all_near!(
+        rem_2pi_1e30:
+            qd!("-3.051353329691740210431461795374137282753433269691689421376616695140295"),
+            qd!("1e30").rem_2pi();
+        rem_2pi_neg_1e308:
+            qd!("-2.671020314562465192571991828547750800244881825940704337574233997405627"),
+            Quad::from(-1e308).rem_2pi();
+        rem_2pi_1e22_pi:
+            qd!("-8.676722636988833509911966654960328656252637552464003663484935924519102e-44"),
+            (qd!("1e22") * Quad::PI).rem_2pi();
+        rem_2pi_7:
+            qd!("0.7168146928204135230747132334409942316056612012497883580501108153843672"),
+            qd!(7).rem_2pi();
+    );
+    test_all_exact!(
+        rem_2pi_one:
+            Quad::ONE,
+            Quad::ONE.rem_2pi();
+        rem_2pi_pi:
+            Quad::PI,
+            Quad::PI.rem_2pi();
+        rem_2pi_neg_pi:
+            -Quad::PI,
+            (-Quad::PI).rem_2pi();
+        rem_2pi_inf:
+            Quad::NAN,
+            Quad::INFINITY.rem_2pi();
+        rem_2pi_nan:
+            Quad::NAN,
+            Quad::NAN.rem_2pi();
+    );
+    test!(rem_2pi_neg_zero: {
+        assert!(Quad::NEG_ZERO.rem_2pi().is_sign_negative());
+    });
+
     // tan tests
     test_all_near!(
         tan_zero:
@@ -948,6 +1778,46 @@ mod tests {
             qd!("-0.48234790710102497548087851189637102255315375602186930768577892528851"),
             (-Quad::FRAC_PI_6).atan2(Quad::ONE);
     );
+    test_all_prec!(
+        atan2_near_pos_y_axis_far:
+            qd!("9.999999999999999999999999999996666666666666666666666666666668666666667e-16"),
+            qd!("1").atan2(qd!("1e15")),
+            60;
+        atan2_near_pos_x_axis:
+            qd!("3.333333333333333333333333333333333333333320987654320987654320987654321e-21"),
+            qd!("1e-20").atan2(qd!("3")),
+            60;
+        atan2_near_neg_x_axis:
+            qd!("-3.141592653589793238459310049946169550863836066041772487641611271320162"),
+            qd!("-1e-20").atan2(qd!("-3")),
+            60;
+        atan2_near_y_axis:
+            qd!("1.570796326794897619231321691639751442098584699354219577154138962820575"),
+            qd!("1e15").atan2(qd!("-1")),
+            60;
+    );
+    test_all_prec!(
+        atan2_ratio_1e10:
+            qd!("9.999999999999999999966666666666666666666866666666666666666665238095238e-11"),
+            qd!("1").atan2(qd!("1e10")),
+            62;
+        atan2_small_over_two:
+            qd!("4.999999999999999999999999999999583333333333333333333333333333395833333e-16"),
+            qd!("1e-15").atan2(qd!("2")),
+            62;
+        atan2_neg_ratio_1e10:
+            qd!("-9.999999999999999999966666666666666666666866666666666666666665238095238e-11"),
+            qd!("-1").atan2(qd!("1e10")),
+            62;
+        atan2_y_axis_1e10:
+            qd!("1.570796326894896619231321691639418108765251366354221577154138962820575"),
+            qd!("1e10").atan2(qd!("-1")),
+            62;
+        atan2_neg_small_over_neg_two:
+            qd!("-3.141592653589792738462643383279502884197169399416772487641611258974483"),
+            qd!("-1e-15").atan2(qd!("-2")),
+            62;
+    );
     test_all_near!(
         atan2_ones_pos_pos:
             Quad::FRAC_PI_4,
@@ -984,14 +1854,23 @@ mod tests {
     );
     test_all_exact!(
         atan2_zero_zero:
-            Quad::NAN,
+            Quad::ZERO,
             Quad::ZERO.atan2(Quad::ZERO);
+        atan2_zero_neg_zero:
+            Quad::PI,
+            Quad::ZERO.atan2(Quad::NEG_ZERO);
         atan2_inf_inf:
-            Quad::NAN,
+            Quad::FRAC_PI_4,
             Quad::INFINITY.atan2(Quad::INFINITY);
+        atan2_neg_inf_neg_inf:
+            -Quad::FRAC_3_PI_4,
+            Quad::NEG_INFINITY.atan2(Quad::NEG_INFINITY);
         atan2_one_inf:
             Quad::ZERO,
             Quad::ONE.atan2(Quad::INFINITY);
+        atan2_one_neg_inf:
+            Quad::PI,
+            Quad::ONE.atan2(Quad::NEG_INFINITY);
         atan2_nan_one:
             Quad::NAN,
             Quad::NAN.atan2(Quad::ONE);
@@ -1003,6 +1882,326 @@ mod tests {
             Quad::NAN.atan2(Quad::NAN);
     );
 
+    // Every combination of zeros and infinities of either sign, each of which should give
+    // the angle that `f64::atan2` does, with the same sign
+    test!(atan2_zeros_and_infinities: {
+        let specials = [0.0, -0.0, f64::INFINITY, f64::NEG_INFINITY];
+        let angles = [
+            Quad::ZERO,
+            Quad::FRAC_PI_4,
+            Quad::FRAC_PI_2,
+            Quad::FRAC_3_PI_4,
+            Quad::PI,
+        ];
+        for &y in &specials {
+            for &x in &specials {
+                let f = y.atan2(x);
+                let angle = *angles.iter().find(|a| a.0 == f.abs()).unwrap();
+                let expected = if f.is_sign_negative() { -angle } else { angle };
+                let actual = Quad(y, 0.0, 0.0, 0.0).atan2(Quad(x, 0.0, 0.0, 0.0));
+                assert!(
+                    actual == expected && actual.is_sign_negative() == f.is_sign_negative(),
+                    "atan2({:?}, {:?}) = {:?}, expected {:?}",
+                    y,
+                    x,
+                    actual,
+                    expected
+                );
+            }
+        }
+    });
+
+    // sin_pi, cos_pi, and tan_pi tests
+    test_all_near!(
+        sin_pi_quarter:
+            Quad::FRAC_1_SQRT_2,
+            qd!(0.25).sin_pi();
+        sin_pi_neg_three_quarters:
+            -Quad::FRAC_1_SQRT_2,
+            qd!(-0.75).sin_pi();
+        sin_pi_sixth:
+            qd!(0.5),
+            (qd!(1) / qd!(6)).sin_pi();
+        cos_pi_third:
+            qd!(0.5),
+            (qd!(1) / qd!(3)).cos_pi();
+        cos_pi_five_quarters:
+            -Quad::FRAC_1_SQRT_2,
+            qd!(1.25).cos_pi();
+        tan_pi_quarter:
+            qd!(1),
+            qd!(0.25).tan_pi();
+        tan_pi_neg_three_quarters:
+            qd!(1),
+            qd!(-0.75).tan_pi();
+        sin_pi_composed:
+            (qd!(0.3) * Quad::PI).sin(),
+            qd!(0.3).sin_pi();
+        cos_pi_composed:
+            (qd!(-0.7) * Quad::PI).cos(),
+            qd!(-0.7).cos_pi();
+        cos_pi_big:
+            Quad::FRAC_1_SQRT_2,
+            Quad(1e20, 0.25, 0.0, 0.0).cos_pi();
+        sin_pi_big:
+            -Quad::FRAC_1_SQRT_2,
+            Quad(1e20, -0.25, 0.0, 0.0).sin_pi();
+    );
+    test_all_exact!(
+        sin_pi_one:
+            Quad::ZERO,
+            qd!(1).sin_pi();
+        sin_pi_neg_one:
+            Quad::NEG_ZERO,
+            qd!(-1).sin_pi();
+        sin_pi_half:
+            Quad::ONE,
+            qd!(0.5).sin_pi();
+        sin_pi_neg_half:
+            Quad::NEG_ONE,
+            qd!(-0.5).sin_pi();
+        sin_pi_three_halves:
+            Quad::NEG_ONE,
+            qd!(1.5).sin_pi();
+        sin_pi_zero:
+            Quad::ZERO,
+            Quad::ZERO.sin_pi();
+        sin_pi_huge_half:
+            Quad::ONE,
+            Quad(2f64.powi(60), 0.5, 0.0, 0.0).sin_pi();
+        sin_pi_inf:
+            Quad::NAN,
+            Quad::INFINITY.sin_pi();
+        cos_pi_one:
+            Quad::NEG_ONE,
+            qd!(1).cos_pi();
+        cos_pi_two:
+            Quad::ONE,
+            qd!(2).cos_pi();
+        cos_pi_half:
+            Quad::ZERO,
+            qd!(0.5).cos_pi();
+        cos_pi_neg_half:
+            Quad::ZERO,
+            qd!(-0.5).cos_pi();
+        cos_pi_huge:
+            Quad::ONE,
+            Quad(1e300, 0.0, 0.0, 0.0).cos_pi();
+        cos_pi_zero:
+            Quad::ONE,
+            Quad::NEG_ZERO.cos_pi();
+        cos_pi_nan:
+            Quad::NAN,
+            Quad::NAN.cos_pi();
+        tan_pi_two:
+            Quad::ZERO,
+            qd!(2).tan_pi();
+        tan_pi_half:
+            Quad::INFINITY,
+            qd!(0.5).tan_pi();
+        tan_pi_three_halves:
+            Quad::NEG_INFINITY,
+            qd!(1.5).tan_pi();
+        tan_pi_neg_half:
+            Quad::NEG_INFINITY,
+            qd!(-0.5).tan_pi();
+        tan_pi_inf:
+            Quad::NAN,
+            Quad::NEG_INFINITY.tan_pi();
+    );
+    test_all_assert!(
+        sin_pi_one_sign:
+            qd!(1).sin_pi().is_sign_positive();
+        sin_pi_neg_two_sign:
+            qd!(-2).sin_pi().is_sign_negative();
+        cos_pi_half_sign:
+            qd!(-0.5).cos_pi().is_sign_positive();
+    );
+    // Adding an even integer changes nothing, since the reduction is exact. The arguments
+    // are single `f64`s so that adding the integers to them is exact too.
+    test!(sin_pi_periodic: {
+        for &a in &[0.1, -0.3, Quad::PI[0] / 4.0, -Quad::E[0] / 3.0] {
+            let x = Quad(a, 0.0, 0.0, 0.0);
+            for &e in &[2.0, -4.0, 2f64.powi(40), -(2f64.powi(120))] {
+                let y = Quad::from_sum(&[a, e]);
+                assert!(y.sin_pi() == x.sin_pi(), "{:?}.sin_pi()", y);
+                assert!(y.cos_pi() == x.cos_pi(), "{:?}.cos_pi()", y);
+            }
+            assert!((-x).sin_pi() == -x.sin_pi());
+            assert!((-x).cos_pi() == x.cos_pi());
+        }
+    });
+    // Multiplying by π first makes an error in the argument that's about as large as the
+    // argument times the precision, and that error goes straight into the result
+    test!(cos_pi_versus_composed: {
+        let x = Quad(1e20, 0.25, 0.0, 0.0);
+        let pi = (x.cos_pi() - Quad::FRAC_1_SQRT_2).abs();
+        let composed = ((x * Quad::PI).cos() - Quad::FRAC_1_SQRT_2).abs();
+        assert!(pi < Quad::EPSILON);
+        assert!(composed > Quad::EPSILON * qd!(1e15), "composed error {:?}", composed);
+    });
+
+    // sin_deg, cos_deg, tan_deg, to_radians, and to_degrees tests
+    test_all_near!(
+        sin_deg_45:
+            Quad::FRAC_1_SQRT_2,
+            qd!(45).sin_deg();
+        sin_deg_neg_135:
+            -Quad::FRAC_1_SQRT_2,
+            qd!(-135).sin_deg();
+        cos_deg_30:
+            qd!(3).sqrt() / qd!(2),
+            qd!(30).cos_deg();
+        sin_deg_240:
+            -qd!(3).sqrt() / qd!(2),
+            qd!(240).sin_deg();
+        tan_deg_60:
+            qd!(3).sqrt(),
+            qd!(60).tan_deg();
+        tan_deg_neg_30:
+            -qd!(3).sqrt() / qd!(3),
+            qd!(-30).tan_deg();
+        sin_deg_composed:
+            qd!(80).to_radians().sin(),
+            qd!(80).sin_deg();
+        cos_deg_composed:
+            qd!(-17.5).to_radians().cos(),
+            qd!(-17.5).cos_deg();
+        to_radians_180:
+            Quad::PI,
+            qd!(180).to_radians();
+        to_radians_1:
+            Quad::PI / qd!(180),
+            qd!(1).to_radians();
+        to_degrees_pi:
+            qd!(180),
+            Quad::PI.to_degrees();
+        to_degrees_1:
+            qd!(180) / Quad::PI,
+            qd!(1).to_degrees();
+    );
+    test_all_exact!(
+        sin_deg_30:
+            qd!(0.5),
+            qd!(30).sin_deg();
+        sin_deg_150:
+            qd!(0.5),
+            qd!(150).sin_deg();
+        sin_deg_neg_30:
+            qd!(-0.5),
+            qd!(-30).sin_deg();
+        sin_deg_210:
+            qd!(-0.5),
+            qd!(210).sin_deg();
+        sin_deg_90:
+            Quad::ONE,
+            qd!(90).sin_deg();
+        sin_deg_270:
+            Quad::NEG_ONE,
+            qd!(270).sin_deg();
+        sin_deg_neg_90:
+            Quad::NEG_ONE,
+            qd!(-90).sin_deg();
+        sin_deg_180:
+            Quad::ZERO,
+            qd!(180).sin_deg();
+        sin_deg_huge:
+            Quad::ZERO,
+            qd!(1_000_000_080).sin_deg();
+        sin_deg_huge_80:
+            qd!(80).sin_deg(),
+            Quad::from_sum(&[360.0 * 2f64.powi(70), 80.0]).sin_deg();
+        sin_deg_inf:
+            Quad::NAN,
+            Quad::INFINITY.sin_deg();
+        cos_deg_60:
+            qd!(0.5),
+            qd!(60).cos_deg();
+        cos_deg_300:
+            qd!(0.5),
+            qd!(300).cos_deg();
+        cos_deg_120:
+            qd!(-0.5),
+            qd!(120).cos_deg();
+        cos_deg_90:
+            Quad::ZERO,
+            qd!(90).cos_deg();
+        cos_deg_180:
+            Quad::NEG_ONE,
+            qd!(180).cos_deg();
+        cos_deg_720:
+            Quad::ONE,
+            qd!(-720).cos_deg();
+        cos_deg_45:
+            qd!(45).sin_deg(),
+            qd!(45).cos_deg();
+        cos_deg_huge:
+            Quad::ONE,
+            Quad(1e300, 0.0, 0.0, 0.0).cos_deg();
+        cos_deg_nan:
+            Quad::NAN,
+            Quad::NAN.cos_deg();
+        tan_deg_45:
+            Quad::ONE,
+            qd!(45).tan_deg();
+        tan_deg_135:
+            Quad::NEG_ONE,
+            qd!(135).tan_deg();
+        tan_deg_neg_315:
+            Quad::ONE,
+            qd!(-315).tan_deg();
+        tan_deg_180:
+            Quad::ZERO,
+            qd!(180).tan_deg();
+        tan_deg_90:
+            Quad::INFINITY,
+            qd!(90).tan_deg();
+        tan_deg_270:
+            Quad::NEG_INFINITY,
+            qd!(270).tan_deg();
+        tan_deg_neg_inf:
+            Quad::NAN,
+            Quad::NEG_INFINITY.tan_deg();
+        to_radians_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.to_radians();
+        to_degrees_max:
+            Quad::INFINITY,
+            Quad::MAX.to_degrees();
+    );
+    test_all_assert!(
+        sin_deg_360_sign:
+            qd!(360).sin_deg().is_sign_positive();
+        sin_deg_neg_180_sign:
+            qd!(-180).sin_deg().is_sign_negative();
+        cos_deg_90_sign:
+            qd!(-90).cos_deg().is_sign_positive();
+    );
+    // Adding a multiple of 360 changes nothing, since the reduction is exact. The
+    // arguments are single `f64`s so that adding the multiples to them is exact too.
+    test!(sin_deg_periodic: {
+        for &a in &[0.1, -37.3, Quad::PI[0] * 20.0, -Quad::E[0] * 50.0] {
+            let x = Quad(a, 0.0, 0.0, 0.0);
+            for &e in &[360.0, -720.0, 360.0 * 2f64.powi(40), -45.0 * 2f64.powi(120)] {
+                let y = Quad::from_sum(&[a, e]);
+                assert!(y.sin_deg() == x.sin_deg(), "{:?}.sin_deg()", y);
+                assert!(y.cos_deg() == x.cos_deg(), "{:?}.cos_deg()", y);
+            }
+            assert!((-x).sin_deg() == -x.sin_deg());
+            assert!((-x).cos_deg() == x.cos_deg());
+        }
+    });
+    // Converting to radians first makes an error in the argument that's about as large as
+    // the argument times the precision
+    test!(sin_deg_versus_composed: {
+        let x = Quad::from_sum(&[360.0 * 2f64.powi(70), 80.0]);
+        let expected = qd!(80).to_radians().sin();
+        let deg = (x.sin_deg() - expected).abs();
+        let composed = (x.to_radians().sin() - expected).abs();
+        assert!(deg < Quad::EPSILON);
+        assert!(composed > Quad::EPSILON * qd!(1e15), "composed error {:?}", composed);
+    });
+
     // asin tests
     test_all_near!(
         asin_one:
@@ -1093,6 +2292,97 @@ mod tests {
             Quad::NAN.acos();
     );
 
+    // asin_clamped and acos_clamped tests
+    test_all_exact!(
+        asin_clamped_two_eps_above_one:
+            Quad::FRAC_PI_2,
+            Quad(1.0, 2.0 * Quad::EPSILON.0, 0.0, 0.0).asin_clamped();
+        asin_clamped_two_eps_below_neg_one:
+            -Quad::FRAC_PI_2,
+            Quad(-1.0, -2.0 * Quad::EPSILON.0, 0.0, 0.0).asin_clamped();
+        asin_clamped_tolerance:
+            Quad::FRAC_PI_2,
+            Quad(1.0, 4.0 * Quad::EPSILON.0, 0.0, 0.0).asin_clamped();
+        asin_clamped_neg_tolerance:
+            -Quad::FRAC_PI_2,
+            Quad(-1.0, -4.0 * Quad::EPSILON.0, 0.0, 0.0).asin_clamped();
+        asin_clamped_past_tolerance:
+            Quad::NAN,
+            Quad(1.0, 5.0 * Quad::EPSILON.0, 0.0, 0.0).asin_clamped();
+        asin_clamped_neg_past_tolerance:
+            Quad::NAN,
+            Quad(-1.0, -5.0 * Quad::EPSILON.0, 0.0, 0.0).asin_clamped();
+        asin_clamped_1e_10_above_one:
+            Quad::NAN,
+            qd!(1.0000000001).asin_clamped();
+        asin_clamped_1e_10_below_neg_one:
+            Quad::NAN,
+            qd!(-1.0000000001).asin_clamped();
+        asin_clamped_far:
+            Quad::NAN,
+            qd!(1.001).asin_clamped();
+        asin_clamped_inf:
+            Quad::NAN,
+            Quad::INFINITY.asin_clamped();
+        asin_clamped_nan:
+            Quad::NAN,
+            Quad::NAN.asin_clamped();
+        asin_clamped_one:
+            Quad::FRAC_PI_2,
+            Quad::ONE.asin_clamped();
+        asin_clamped_half:
+            qd!(0.5).asin(),
+            qd!(0.5).asin_clamped();
+        asin_clamped_below_one:
+            Quad(1.0, -2.0 * Quad::EPSILON.0, 0.0, 0.0).asin(),
+            Quad(1.0, -2.0 * Quad::EPSILON.0, 0.0, 0.0).asin_clamped();
+        acos_clamped_two_eps_above_one:
+            Quad::ZERO,
+            Quad(1.0, 2.0 * Quad::EPSILON.0, 0.0, 0.0).acos_clamped();
+        acos_clamped_two_eps_below_neg_one:
+            Quad::PI,
+            Quad(-1.0, -2.0 * Quad::EPSILON.0, 0.0, 0.0).acos_clamped();
+        acos_clamped_tolerance:
+            Quad::ZERO,
+            Quad(1.0, 4.0 * Quad::EPSILON.0, 0.0, 0.0).acos_clamped();
+        acos_clamped_neg_tolerance:
+            Quad::PI,
+            Quad(-1.0, -4.0 * Quad::EPSILON.0, 0.0, 0.0).acos_clamped();
+        acos_clamped_past_tolerance:
+            Quad::NAN,
+            Quad(1.0, 5.0 * Quad::EPSILON.0, 0.0, 0.0).acos_clamped();
+        acos_clamped_neg_past_tolerance:
+            Quad::NAN,
+            Quad(-1.0, -5.0 * Quad::EPSILON.0, 0.0, 0.0).acos_clamped();
+        acos_clamped_1e_10_above_one:
+            Quad::NAN,
+            qd!(1.0000000001).acos_clamped();
+        acos_clamped_1e_10_below_neg_one:
+            Quad::NAN,
+            qd!(-1.0000000001).acos_clamped();
+        acos_clamped_far:
+            Quad::NAN,
+            qd!(-1.001).acos_clamped();
+        acos_clamped_neg_inf:
+            Quad::NAN,
+            Quad::NEG_INFINITY.acos_clamped();
+        acos_clamped_nan:
+            Quad::NAN,
+            Quad::NAN.acos_clamped();
+        acos_clamped_half:
+            qd!(0.5).acos(),
+            qd!(0.5).acos_clamped();
+        acos_clamped_below_one:
+            Quad(1.0, -2.0 * Quad::EPSILON.0, 0.0, 0.0).acos(),
+            Quad(1.0, -2.0 * Quad::EPSILON.0, 0.0, 0.0).acos_clamped();
+    );
+    test_all_assert!(
+        asin_unclamped_two_eps_above_one:
+            Quad(1.0, 2.0 * Quad::EPSILON.0, 0.0, 0.0).asin().is_nan();
+        acos_unclamped_two_eps_below_neg_one:
+            Quad(-1.0, -2.0 * Quad::EPSILON.0, 0.0, 0.0).acos().is_nan();
+    );
+
     // atan tests
     test_all_near!(
         atan_pi:
@@ -1143,4 +2433,15 @@ mod tests {
             Quad::NAN,
             Quad::NAN.atan();
     );
+
+    // series tests
+    test!(taylor_past_table: {
+        // Arguments this large never get a term under the threshold, so the series run to
+        // the end of the table and stop there
+        let x = qd!(3);
+        let (sin, cos) = sincos_taylor(x);
+        assert!(sin.is_finite() && cos.is_finite());
+        assert!(sin_taylor(x).is_finite());
+        assert!(cos_taylor(x).is_finite());
+    });
 }
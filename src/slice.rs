@@ -0,0 +1,1444 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! Bulk conversions between slices of `Double`s or `Quad`s and slices of their components.
+//!
+//! A slice of `Quad`s is an *array of structures* (AoS): each element's four components
+//! sit next to each other in memory. SIMD and GPU code usually wants the opposite, a
+//! *structure of arrays* (SoA), where all of the first components are in one contiguous
+//! slice, all of the second components are in another, and so on. The functions here move
+//! numbers between the two layouts, along with [`heads`], which pulls out just the first
+//! components (the `f64` approximations) for code that only needs those.
+//!
+//! The functions are generic over [`Components`], which is implemented by [`Double`] (two
+//! lanes) and [`Quad`] (four lanes). The number of lanes is checked at compile time, so
+//! passing four slices for a `Double` won't compile. The lengths of the slices are checked
+//! at run time, and every function panics if they don't match.
+//!
+//! The module also has the level-1 BLAS kernels that iterative refinement and similar
+//! algorithms are built on: [`axpy`] (*y* ← α*x* + *y*), [`axpby`] (*y* ← α*x* + β*y*),
+//! and [`scal`] (*x* ← α*x*). `axpy` never rounds a product before adding it when that
+//! would matter, so it stays accurate even when α*x* and *y* nearly cancel.
+//! [`axpy_f64_into_double`] and [`axpy_f64_into_quad`] do the same with an `f64` α and
+//! *x*, promoting each product exactly as it's needed rather than converting all of *x*
+//! first, and [`axpy_double_into_f64`] and [`axpy_quad_into_f64`] go the other way, for
+//! `f64` data updated with a more precise α.
+//!
+//! Along with those are the reductions [`dot`] and [`sum`]. `sum` is exact until its one
+//! final rounding, and so are [`dot_f64_into_double`] and [`dot_f64_into_quad`], which take
+//! the dot product of two slices of `f64`s without converting either of them. Those stay
+//! accurate however ill-conditioned the dot product is, and they're much faster than
+//! converting the `f64`s to `Quad`s and multiplying those.
+//!
+//! # Examples
+//! ```
+//! # use qd::{qd, Quad};
+//! use qd::slice;
+//!
+//! let quads = vec![Quad::PI, Quad::E, qd!(1.5)];
+//! let (mut a, mut b, mut c, mut d) = (vec![0.0; 3], vec![0.0; 3], vec![0.0; 3], vec![0.0; 3]);
+//! slice::to_soa(&quads, [&mut a, &mut b, &mut c, &mut d]);
+//! assert!(a == [Quad::PI[0], Quad::E[0], 1.5]);
+//! assert!(d == [Quad::PI[3], Quad::E[3], 0.0]);
+//!
+//! let mut back = vec![Quad::ZERO; 3];
+//! slice::from_soa([&a, &b, &c, &d], &mut back);
+//! assert!(back == quads);
+//! ```
+//!
+//! [`heads`]: fn.heads.html
+//! [`axpy`]: fn.axpy.html
+//! [`axpby`]: fn.axpby.html
+//! [`scal`]: fn.scal.html
+//! [`axpy_f64_into_double`]: fn.axpy_f64_into_double.html
+//! [`axpy_f64_into_quad`]: fn.axpy_f64_into_quad.html
+//! [`axpy_double_into_f64`]: fn.axpy_double_into_f64.html
+//! [`axpy_quad_into_f64`]: fn.axpy_quad_into_f64.html
+//! [`dot`]: fn.dot.html
+//! [`sum`]: fn.sum.html
+//! [`dot_f64_into_double`]: fn.dot_f64_into_double.html
+//! [`dot_f64_into_quad`]: fn.dot_f64_into_quad.html
+//! [`Components`]: trait.Components.html
+//! [`Double`]: ../struct.Double.html
+//! [`Quad`]: ../struct.Quad.html
+
+use crate::common::accumulator::Partials;
+use crate::common::primitive as p;
+use crate::double::Double;
+use crate::quad::Quad;
+
+// The number of elements handled at once when copying between layouts. Working on a
+// chunk at a time means that each pass over the elements for a single lane reads memory
+// that the previous lane's pass just brought into the cache, and each pass is a simple
+// strided copy that the compiler can unroll and vectorize. 64 `Quad`s are 2 KiB.
+const CHUNK: usize = 64;
+
+mod private {
+    use std::ops::{Add, Mul};
+
+    // Keeps `Components` from being implemented outside of this crate. The methods live
+    // here rather than on `Components` itself so that they aren't part of the public API.
+    // The implementations are in the `slice` submodules of `double` and `quad`, where the
+    // components are visible.
+    pub trait Sealed<const N: usize>:
+        Copy + PartialEq + Add<Output = Self> + Mul<Output = Self>
+    {
+        const ZERO: Self;
+        const ONE: Self;
+
+        fn components(self) -> [f64; N];
+        fn is_normalized(c: &[f64; N]) -> bool;
+        fn from_raw(c: [f64; N]) -> Self;
+        fn from_normalizing(c: [f64; N]) -> Self;
+        // a * b + c, accurate even when the product and c nearly cancel
+        fn fused_mul_add(a: Self, b: Self, c: Self) -> Self;
+    }
+}
+
+pub(crate) use self::private::Sealed;
+
+/// The types that the functions in this module work on, along with the number of `f64`
+/// components (lanes) that each has.
+///
+/// This trait is sealed; it's implemented as `Components<2>` for [`Double`] and as
+/// `Components<4>` for [`Quad`], and it can't be implemented for anything else.
+///
+/// [`Double`]: ../struct.Double.html
+/// [`Quad`]: ../struct.Quad.html
+pub trait Components<const N: usize>: private::Sealed<N> {}
+
+impl Components<2> for Double {}
+impl Components<4> for Quad {}
+
+/// Copies the components of each number in `values` into separate slices, one per
+/// component.
+///
+/// After this function returns, `out[k][i]` is component `k` of `values[i]`. The
+/// components are copied exactly as they are, including the signs of zeros and any NaNs.
+///
+/// # Panics
+/// Panics if any slice in `out` has a different length than `values`.
+///
+/// # Examples
+/// ```
+/// # use qd::{dd, Double};
+/// use qd::slice;
+///
+/// let doubles = [Double::PI, dd!(-2)];
+/// let (mut hi, mut lo) = ([0.0; 2], [0.0; 2]);
+/// slice::to_soa(&doubles, [&mut hi, &mut lo]);
+/// assert!(hi == [Double::PI[0], -2.0]);
+/// assert!(lo == [Double::PI[1], 0.0]);
+/// ```
+pub fn to_soa<T: Components<N>, const N: usize>(values: &[T], out: [&mut [f64]; N]) {
+    for lane in out.iter() {
+        assert_eq!(
+            lane.len(),
+            values.len(),
+            "component slice length does not match the number of values"
+        );
+    }
+    let mut out = out;
+    for (start, chunk) in (0..values.len()).step_by(CHUNK).zip(values.chunks(CHUNK)) {
+        for (k, lane) in out.iter_mut().enumerate() {
+            let lane = &mut lane[start..start + chunk.len()];
+            for (x, v) in lane.iter_mut().zip(chunk) {
+                *x = v.components()[k];
+            }
+        }
+    }
+}
+
+/// Builds numbers from separate slices of components, normalizing each one.
+///
+/// This is the inverse of [`to_soa`]: `out[i]` is built from `components[k][i]` for each
+/// component `k`. Components that are already normalized (as anything that came from
+/// `to_soa` is) are used exactly as they are. Components that aren't are renormalized, so
+/// that each element of `out` is the number closest to the sum of its components. A NaN in
+/// any component produces NaN, and an infinite sum produces an infinity of the same sign.
+///
+/// If the components are known to be normalized, [`from_soa_raw`] skips the check.
+///
+/// # Panics
+/// Panics if any slice in `components` has a different length than `out`.
+///
+/// # Examples
+/// ```
+/// # use qd::{dd, Double};
+/// use qd::slice;
+///
+/// let mut out = [Double::ZERO; 2];
+/// slice::from_soa([&[1.0, 1.0], &[0.0, 1.0]], &mut out);
+/// assert!(out == [dd!(1), dd!(2)]);
+/// assert!(out[1][0] == 2.0 && out[1][1] == 0.0);
+/// ```
+///
+/// [`to_soa`]: fn.to_soa.html
+/// [`from_soa_raw`]: fn.from_soa_raw.html
+pub fn from_soa<T: Components<N>, const N: usize>(components: [&[f64]; N], out: &mut [T]) {
+    gather(components, out, true);
+}
+
+/// Builds numbers from separate slices of components without normalizing them.
+///
+/// This works exactly like [`from_soa`], except that the components are put into each
+/// number as they are, which makes it as fast as [`to_soa`]. It's meant for components
+/// that are already normalized, such as those that came from `to_soa` or from code that
+/// does double-double or quad-double arithmetic itself.
+///
+/// Nothing unsafe happens if the components *aren't* normalized, but the numbers that
+/// result aren't valid `Double`s or `Quad`s. Arithmetic on them can lose precision, and
+/// comparisons and formatting can give wrong answers. Use `from_soa` if you're not sure.
+///
+/// # Panics
+/// Panics if any slice in `components` has a different length than `out`.
+///
+/// # Examples
+/// ```
+/// # use qd::{dd, Double};
+/// use qd::slice;
+///
+/// let mut out = [Double::ZERO; 2];
+/// slice::from_soa_raw([&[1.0, 1.0], &[0.0, 1.0]], &mut out);
+/// assert!(out[0] == dd!(1));
+/// // Not normalized, so this is not a valid representation of 2
+/// assert!(out[1][0] == 1.0 && out[1][1] == 1.0);
+/// ```
+///
+/// [`from_soa`]: fn.from_soa.html
+/// [`to_soa`]: fn.to_soa.html
+pub fn from_soa_raw<T: Components<N>, const N: usize>(components: [&[f64]; N], out: &mut [T]) {
+    gather(components, out, false);
+}
+
+/// Copies the first component of each number in `values` into `out`.
+///
+/// The first component of a `Double` or `Quad` is the `f64` closest to its value (this is
+/// what [`to_f64`] returns for a single number), so this is the quickest way to get a
+/// slice of numbers ready for code that only works with `f64`s, like plotting libraries.
+///
+/// # Panics
+/// Panics if `out` has a different length than `values`.
+///
+/// # Examples
+/// ```
+/// # use qd::{qd, Quad};
+/// use qd::slice;
+///
+/// let mut out = [0.0; 2];
+/// slice::heads(&[Quad::PI, qd!(0.25)], &mut out);
+/// assert!(out == [std::f64::consts::PI, 0.25]);
+/// ```
+///
+/// [`to_f64`]: ../struct.Quad.html#method.to_f64
+pub fn heads<T: Components<N>, const N: usize>(values: &[T], out: &mut [f64]) {
+    assert_eq!(
+        out.len(),
+        values.len(),
+        "output slice length does not match the number of values"
+    );
+    for (x, v) in out.iter_mut().zip(values) {
+        *x = v.components()[0];
+    }
+}
+
+/// Adds α*x* to *y*, element by element, as a fused multiply-add.
+///
+/// Calculating `alpha * x[i] + y[i]` with the operators rounds twice, once for the product
+/// and once for the sum. When α*x*<sub>*i*</sub> and *y*<sub>*i*</sub> nearly cancel, the
+/// error from rounding the product can be as large as the result itself. This function
+/// keeps all of the product's error terms in that case and rounds only once, so the
+/// relative error in each element of the result is no more than a few [`EPSILON`]s of the
+/// type however much cancellation there is.
+///
+/// If α is zero, *y* isn't changed at all, even if *x* has infinities or NaNs.
+///
+/// # Panics
+/// Panics if `y` has a different length than `x`.
+///
+/// # Examples
+/// ```
+/// # use qd::{dd, Double};
+/// use qd::slice;
+///
+/// let x = [dd!(1), dd!(2)];
+/// let mut y = [dd!(1), dd!(1)];
+/// slice::axpy(dd!(3), &x, &mut y);
+/// assert!(y == [dd!(4), dd!(7)]);
+///
+/// // The product isn't rounded before it's added, so its rounding error is what's left
+/// let mut y = [-(Double::PI * dd!(3))];
+/// slice::axpy(dd!(3), &[Double::PI], &mut y);
+/// assert!(y[0] != Double::ZERO);
+/// ```
+///
+/// [`EPSILON`]: ../struct.Double.html#associatedconstant.EPSILON
+pub fn axpy<T: Components<N>, const N: usize>(alpha: T, x: &[T], y: &mut [T]) {
+    check_lengths(x.len(), y.len());
+    if alpha == T::ZERO {
+        return;
+    }
+    if alpha == T::ONE {
+        for (v, &u) in y.iter_mut().zip(x) {
+            *v = u + *v;
+        }
+    } else {
+        for (v, &u) in y.iter_mut().zip(x) {
+            *v = T::fused_mul_add(alpha, u, *v);
+        }
+    }
+}
+
+/// Replaces *y* with α*x* + β*y*, element by element.
+///
+/// This rounds each element twice: once for β*y*<sub>*i*</sub> and once for the fused
+/// multiply-add of α*x*<sub>*i*</sub> to that. When β is one, it's the same as [`axpy`]
+/// and rounds only once.
+///
+/// If β is zero, the old contents of *y* are ignored (even if they're infinities or NaNs)
+/// and *y* becomes α*x*. If α is also one, that's an exact copy of *x*. If α is zero, *x*
+/// is ignored in the same way and this is the same as [`scal`] on *y*.
+///
+/// # Panics
+/// Panics if `y` has a different length than `x`.
+///
+/// # Examples
+/// ```
+/// # use qd::{qd, Quad};
+/// use qd::slice;
+///
+/// let x = [qd!(1), qd!(2)];
+/// let mut y = [qd!(10), qd!(20)];
+/// slice::axpby(qd!(2), &x, qd!(0.5), &mut y);
+/// assert!(y == [qd!(7), qd!(14)]);
+///
+/// slice::axpby(Quad::ONE, &[Quad::PI, Quad::E], Quad::ZERO, &mut y);
+/// assert!(y == [Quad::PI, Quad::E]);
+/// ```
+///
+/// [`axpy`]: fn.axpy.html
+/// [`scal`]: fn.scal.html
+pub fn axpby<T: Components<N>, const N: usize>(alpha: T, x: &[T], beta: T, y: &mut [T]) {
+    check_lengths(x.len(), y.len());
+    if beta == T::ONE {
+        axpy(alpha, x, y);
+    } else if alpha == T::ZERO {
+        scal(beta, y);
+    } else if beta == T::ZERO {
+        if alpha == T::ONE {
+            y.copy_from_slice(x);
+        } else {
+            for (v, &u) in y.iter_mut().zip(x) {
+                *v = alpha * u;
+            }
+        }
+    } else {
+        for (v, &u) in y.iter_mut().zip(x) {
+            *v = T::fused_mul_add(alpha, u, beta * *v);
+        }
+    }
+}
+
+/// Multiplies every element of *x* by α, in place.
+///
+/// If α is one, *x* isn't changed at all. Multiplying by zero is *not* a special case, so
+/// infinities and NaNs in *x* become NaNs, just as they do with the `*` operator.
+///
+/// # Examples
+/// ```
+/// # use qd::{dd, Double};
+/// use qd::slice;
+///
+/// let mut x = [dd!(1), dd!(-2.5)];
+/// slice::scal(dd!(4), &mut x);
+/// assert!(x == [dd!(4), dd!(-10)]);
+/// ```
+pub fn scal<T: Components<N>, const N: usize>(alpha: T, x: &mut [T]) {
+    if alpha == T::ONE {
+        return;
+    }
+    for v in x.iter_mut() {
+        *v = alpha * *v;
+    }
+}
+
+/// Calculates the dot product of *x* and *y*, the sum of the products
+/// *x*<sub>*i*</sub>*y*<sub>*i*</sub>.
+///
+/// Each product is added to the running sum with a fused multiply-add, so each element
+/// costs one rounding instead of two. That's as accurate as a dot product calculated in the
+/// precision of the type can be: the error is no more than a few [`EPSILON`]s per element,
+/// relative to the sum of the *absolute values* of the products. When the products nearly
+/// cancel, that can still be a large error relative to the result. The dot product of an
+/// empty *x* and *y* is zero.
+///
+/// For `f64` data, [`dot_f64_into_double`] and [`dot_f64_into_quad`] round only once, at
+/// the end, and don't have that problem.
+///
+/// # Panics
+/// Panics if `y` has a different length than `x`.
+///
+/// # Examples
+/// ```
+/// # use qd::{qd, Quad};
+/// use qd::slice;
+///
+/// let x = [qd!(1), qd!(2), qd!(3)];
+/// let y = [qd!(4), qd!(-5), qd!(6)];
+/// assert!(slice::dot(&x, &y) == qd!(12));
+/// ```
+///
+/// [`EPSILON`]: ../struct.Quad.html#associatedconstant.EPSILON
+/// [`dot_f64_into_double`]: fn.dot_f64_into_double.html
+/// [`dot_f64_into_quad`]: fn.dot_f64_into_quad.html
+pub fn dot<T: Components<N>, const N: usize>(x: &[T], y: &[T]) -> T {
+    check_lengths(x.len(), y.len());
+    x.iter()
+        .zip(y)
+        .fold(T::ZERO, |acc, (&u, &v)| T::fused_mul_add(u, v, acc))
+}
+
+/// Adds up the elements of *x*, rounding only once.
+///
+/// Every component of every element is added exactly to a running sum, as a
+/// [`DoubleAccumulator`] or a [`QuadAccumulator`] does, and the result is the number nearest
+/// to that sum. So it doesn't depend on the order of the elements or on how much they
+/// cancel. As with `f64` addition, the result is infinite if *x* has infinities of only one
+/// sign, and NaN if it has a NaN or infinities of both signs. The sum of an empty *x* is
+/// zero.
+///
+/// To sum a slice of `f64`s, use [`Double::from_sum`] or [`Quad::from_sum`].
+///
+/// # Examples
+/// ```
+/// # use qd::{qd, Quad};
+/// use qd::slice;
+///
+/// let big = qd!("1e40") / qd!(3);
+/// let x = [big, Quad::PI, -big];
+/// assert!(slice::sum(&x) == Quad::PI);
+/// // Adding the elements in order rounds after each one
+/// assert!(x.iter().fold(Quad::ZERO, |a, &b| a + b) != Quad::PI);
+/// ```
+///
+/// [`DoubleAccumulator`]: ../struct.DoubleAccumulator.html
+/// [`QuadAccumulator`]: ../struct.QuadAccumulator.html
+/// [`Double::from_sum`]: ../struct.Double.html#method.from_sum
+/// [`Quad::from_sum`]: ../struct.Quad.html#method.from_sum
+pub fn sum<T: Components<N>, const N: usize>(x: &[T]) -> T {
+    let mut partials = Partials::new();
+    for v in x {
+        for &c in v.components().iter() {
+            partials.add(c);
+        }
+    }
+    nearest(&partials)
+}
+
+/// Adds α*x* to *y*, element by element, where α and *x* are `f64`s and *y* holds
+/// `Double`s.
+///
+/// Each product α*x*<sub>*i*</sub> is calculated exactly, as a pair of `f64`s, and added to
+/// *y*<sub>*i*</sub> with one rounding. No `Double` version of *x* is ever made, which
+/// saves both the memory and the time it would take to convert it. This is the kernel for
+/// computing residuals in iterative refinement, where the matrix is in `f64` and the
+/// residual has to be more precise than that.
+///
+/// If α is zero, *y* isn't changed at all, even if *x* has infinities or NaNs.
+///
+/// # Panics
+/// Panics if `y` has a different length than `x`.
+///
+/// # Examples
+/// ```
+/// # use qd::{dd, Double};
+/// use qd::slice;
+///
+/// // (1 + ε)(1 - ε) - 1 is -ε², or -2^-104, which an f64 product would round away
+/// let e = f64::EPSILON;
+/// let mut y = [dd!(-1)];
+/// slice::axpy_f64_into_double(1.0 - e, &[1.0 + e], &mut y);
+/// assert!(y[0] == -dd!(2).powi(-104));
+/// ```
+pub fn axpy_f64_into_double(alpha: f64, x: &[f64], y: &mut [Double]) {
+    axpy_f64(alpha, x, y, |p, e| [p, e]);
+}
+
+/// Adds α*x* to *y*, element by element, where α and *x* are `f64`s and *y* holds `Quad`s.
+///
+/// This is the same as [`axpy_f64_into_double`], except for `Quad`s.
+///
+/// # Panics
+/// Panics if `y` has a different length than `x`.
+///
+/// # Examples
+/// ```
+/// # use qd::{qd, Quad};
+/// use qd::slice;
+///
+/// let e = f64::EPSILON;
+/// let mut y = [qd!(-1)];
+/// slice::axpy_f64_into_quad(1.0 - e, &[1.0 + e], &mut y);
+/// assert!(y[0] == -qd!(2).powi(-104));
+/// ```
+///
+/// [`axpy_f64_into_double`]: fn.axpy_f64_into_double.html
+pub fn axpy_f64_into_quad(alpha: f64, x: &[f64], y: &mut [Quad]) {
+    axpy_f64(alpha, x, y, |p, e| [p, e, 0.0, 0.0]);
+}
+
+// Does the work of the mixed-precision `axpy`s. `lanes` builds the components of a number
+// from the two halves of an error-free product. If the product overflows, its error term
+// is a NaN that has nothing to do with the result, so it's dropped.
+fn axpy_f64<T: Components<N>, F: Fn(f64, f64) -> [f64; N], const N: usize>(
+    alpha: f64,
+    x: &[f64],
+    y: &mut [T],
+    lanes: F,
+) {
+    check_lengths(x.len(), y.len());
+    if alpha == 0.0 {
+        return;
+    }
+    for (v, &u) in y.iter_mut().zip(x) {
+        let (p, e) = p::two_prod(alpha, u);
+        let e = if p.is_finite() { e } else { 0.0 };
+        *v = T::from_normalizing(lanes(p, e)) + *v;
+    }
+}
+
+/// Adds α*x* to *y*, element by element, where α is a `Double` and *x* and *y* hold
+/// `f64`s.
+///
+/// Each element becomes α*x*<sub>*i*</sub> + *y*<sub>*i*</sub>, calculated as a `Double`
+/// with a single rounding and then rounded to the nearest `f64`. This is the update of an
+/// iterative method (like conjugate gradients) that keeps its vectors in `f64` and its
+/// scalars in higher precision.
+///
+/// If α is zero, *y* isn't changed at all, even if *x* has infinities or NaNs.
+///
+/// # Panics
+/// Panics if `y` has a different length than `x`.
+///
+/// # Examples
+/// ```
+/// # use qd::{dd, Double};
+/// use qd::slice;
+///
+/// let mut y = [1.0, 2.0];
+/// slice::axpy_double_into_f64(dd!(1) / dd!(3), &[3.0, -6.0], &mut y);
+/// assert!(y[0] == 2.0);
+/// // What's left is the error in the `Double` nearest to 1/3, times -6
+/// assert!(y[1] != 0.0 && y[1].abs() < 1e-30);
+/// ```
+pub fn axpy_double_into_f64(alpha: Double, x: &[f64], y: &mut [f64]) {
+    axpy_into_f64(alpha, x, y);
+}
+
+/// Adds α*x* to *y*, element by element, where α is a `Quad` and *x* and *y* hold `f64`s.
+///
+/// This is the same as [`axpy_double_into_f64`], except for a `Quad` α.
+///
+/// # Panics
+/// Panics if `y` has a different length than `x`.
+///
+/// # Examples
+/// ```
+/// # use qd::{qd, Quad};
+/// use qd::slice;
+///
+/// let mut y = [0.0];
+/// slice::axpy_quad_into_f64(Quad::PI, &[1.0], &mut y);
+/// assert!(y == [std::f64::consts::PI]);
+/// ```
+///
+/// [`axpy_double_into_f64`]: fn.axpy_double_into_f64.html
+pub fn axpy_quad_into_f64(alpha: Quad, x: &[f64], y: &mut [f64]) {
+    axpy_into_f64(alpha, x, y);
+}
+
+/// Calculates the dot product of two slices of `f64`s as a `Double`, rounding only once.
+///
+/// Each product *x*<sub>*i*</sub>*y*<sub>*i*</sub> is calculated exactly, as a pair of
+/// `f64`s, and added exactly to a running sum, so the result is the `Double` nearest to the
+/// exact dot product. It's accurate no matter how ill-conditioned the dot product is, which
+/// an `f64` dot product (or even one calculated in `Double`s) isn't once the products
+/// cancel. No `Double` versions of *x* or *y* are ever made.
+///
+/// The products are calculated a block at a time, so that the loop that calculates them is
+/// a simple pass over both slices that the compiler can vectorize. A product that overflows
+/// makes the result infinite, and infinities and NaNs in *x* and *y* are handled as they
+/// would be by `f64` arithmetic. The dot product of an empty *x* and *y* is zero.
+///
+/// # Panics
+/// Panics if `y` has a different length than `x`.
+///
+/// # Examples
+/// ```
+/// use qd::slice;
+///
+/// // The large products cancel exactly, leaving only the small one
+/// let x = [1e30, 1.0, -1e30];
+/// let y = [1e30, 1e-10, 1e30];
+/// let d = slice::dot_f64_into_double(&x, &y);
+/// assert!(d[0] == 1e-10 && d[1] == 0.0);
+/// assert!(x.iter().zip(&y).map(|(a, b)| a * b).sum::<f64>() == 0.0);
+/// ```
+pub fn dot_f64_into_double(x: &[f64], y: &[f64]) -> Double {
+    nearest(&dot_f64(x, y))
+}
+
+/// Calculates the dot product of two slices of `f64`s as a `Quad`, rounding only once.
+///
+/// This is the same as [`dot_f64_into_double`], except that the result is a `Quad`.
+///
+/// # Panics
+/// Panics if `y` has a different length than `x`.
+///
+/// # Examples
+/// ```
+/// # use qd::{qd, Quad};
+/// use qd::slice;
+///
+/// // (1 + ε)² - 1 - 2ε is ε², which is far too small for an f64 dot product to see
+/// let e = f64::EPSILON;
+/// let x = [1.0 + e, -1.0, -2.0 * e];
+/// let y = [1.0 + e, 1.0, 1.0];
+/// assert!(slice::dot_f64_into_quad(&x, &y) == qd!(2).powi(-104));
+/// ```
+///
+/// [`dot_f64_into_double`]: fn.dot_f64_into_double.html
+pub fn dot_f64_into_quad(x: &[f64], y: &[f64]) -> Quad {
+    nearest(&dot_f64(x, y))
+}
+
+// Does the work of the `axpy`s that update `f64`s. Each element of *x* and *y* is put into
+// the first component of a number of the same type as α, which is exact.
+fn axpy_into_f64<T: Components<N>, const N: usize>(alpha: T, x: &[f64], y: &mut [f64]) {
+    check_lengths(x.len(), y.len());
+    if alpha == T::ZERO {
+        return;
+    }
+    let lift = |a: f64| {
+        let mut c = [0.0; N];
+        c[0] = a;
+        T::from_raw(c)
+    };
+    for (v, &u) in y.iter_mut().zip(x) {
+        *v = T::fused_mul_add(alpha, lift(u), lift(*v)).components()[0];
+    }
+}
+
+// Sums the exact products of the elements of `x` and `y`. The products for a chunk are
+// calculated into a buffer first and then added, which keeps the error-free products,
+// which are independent of each other, out of the loop that adds them. If a product
+// overflows, its error term is a NaN that has nothing to do with the result, so it's
+// dropped.
+fn dot_f64(x: &[f64], y: &[f64]) -> Partials {
+    check_lengths(x.len(), y.len());
+    let mut partials = Partials::new();
+    let mut buffer = [(0.0, 0.0); CHUNK];
+    for (a, b) in x.chunks(CHUNK).zip(y.chunks(CHUNK)) {
+        let products = &mut buffer[..a.len()];
+        for ((p, &u), &v) in products.iter_mut().zip(a).zip(b) {
+            *p = p::two_prod(u, v);
+        }
+        for &(h, l) in products.iter() {
+            partials.add(h);
+            if l != 0.0 && h.is_finite() {
+                partials.add(l);
+            }
+        }
+    }
+    partials
+}
+
+// Rounds an exact sum to the nearest number of the type.
+fn nearest<T: Components<N>, const N: usize>(partials: &Partials) -> T {
+    let mut c = [0.0; N];
+    c.copy_from_slice(&partials.components(N));
+    T::from_normalizing(c)
+}
+
+fn check_lengths(x: usize, y: usize) {
+    assert_eq!(y, x, "y slice length does not match the length of x");
+}
+
+// Builds each element of `out` from its components, normalizing them if `normalize` is
+// true. The components for a chunk are collected lane by lane into a small buffer first,
+// so that reading each lane is a sequential pass over contiguous memory. When normalizing,
+// the whole chunk is checked at once; if it's all normalized already (which is the common
+// case), it's copied just as it would be without normalizing.
+fn gather<T: Components<N>, const N: usize>(
+    components: [&[f64]; N],
+    out: &mut [T],
+    normalize: bool,
+) {
+    for lane in components.iter() {
+        assert_eq!(
+            lane.len(),
+            out.len(),
+            "component slice length does not match the number of values"
+        );
+    }
+    let mut buffer = [[0.0; N]; CHUNK];
+    for (start, chunk) in (0..out.len()).step_by(CHUNK).zip(out.chunks_mut(CHUNK)) {
+        for (k, lane) in components.iter().enumerate() {
+            let lane = &lane[start..start + chunk.len()];
+            for (b, &x) in buffer.iter_mut().zip(lane) {
+                b[k] = x;
+            }
+        }
+        let buffer = &buffer[..chunk.len()];
+        if normalize && !buffer.iter().fold(true, |ok, c| ok & T::is_normalized(c)) {
+            for (v, &b) in chunk.iter_mut().zip(buffer) {
+                *v = T::from_normalizing(b);
+            }
+        } else {
+            for (v, &b) in chunk.iter_mut().zip(buffer) {
+                *v = T::from_raw(b);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::bigint::Big;
+    use crate::common::random::Rng;
+
+    fn same_double(a: Double, b: Double) -> bool {
+        a[0].to_bits() == b[0].to_bits() && a[1].to_bits() == b[1].to_bits()
+    }
+
+    fn same_quad(a: Quad, b: Quad) -> bool {
+        a.components()
+            .iter()
+            .zip(b.components().iter())
+            .all(|(x, y)| x.to_bits() == y.to_bits())
+    }
+
+    // Enough values to cover several full chunks and a partial one
+    fn quads() -> Vec<Quad> {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        let mut values: Vec<Quad> = (0..3 * CHUNK + 17)
+            .map(|_| {
+                let n = rng.next_u64();
+                let x = Quad::from((n >> 11) as f64) / Quad::from(3.0);
+                x.powi((n % 40) as i32 - 20)
+            })
+            .collect();
+        values.extend_from_slice(&[
+            Quad::NEG_ZERO,
+            Quad::from((-1.0, -0.0, 0.0, -0.0)),
+            Quad::INFINITY,
+            Quad::MAX,
+            Quad::MIN_POSITIVE,
+        ]);
+        values
+    }
+
+    fn doubles() -> Vec<Double> {
+        quads()
+            .into_iter()
+            .map(|q| Double::from((q[0], q[1])))
+            .collect()
+    }
+
+    #[test]
+    fn quad_round_trip() {
+        let values = quads();
+        let n = values.len();
+        let (mut a, mut b, mut c, mut d) = (vec![0.0; n], vec![0.0; n], vec![0.0; n], vec![0.0; n]);
+        to_soa(&values, [&mut a, &mut b, &mut c, &mut d]);
+        for (i, v) in values.iter().enumerate() {
+            assert!(v[0] == a[i] && v[1] == b[i] && v[2] == c[i] && v[3] == d[i]);
+        }
+
+        let mut raw = vec![Quad::NAN; n];
+        let mut normalized = vec![Quad::NAN; n];
+        from_soa_raw([&a, &b, &c, &d], &mut raw);
+        from_soa([&a, &b, &c, &d], &mut normalized);
+        for i in 0..n {
+            assert!(
+                same_quad(values[i], raw[i]),
+                "{:?} came back as {:?}",
+                values[i],
+                raw[i]
+            );
+            assert!(
+                same_quad(values[i], normalized[i]),
+                "{:?} came back as {:?}",
+                values[i],
+                normalized[i]
+            );
+        }
+    }
+
+    #[test]
+    fn double_round_trip() {
+        let values = doubles();
+        let n = values.len();
+        let (mut a, mut b) = (vec![0.0; n], vec![0.0; n]);
+        to_soa(&values, [&mut a, &mut b]);
+
+        let mut raw = vec![Double::NAN; n];
+        let mut normalized = vec![Double::NAN; n];
+        from_soa_raw([&a, &b], &mut raw);
+        from_soa([&a, &b], &mut normalized);
+        for i in 0..n {
+            assert!(same_double(values[i], raw[i]));
+            assert!(same_double(values[i], normalized[i]));
+        }
+    }
+
+    #[test]
+    fn nan_round_trip() {
+        let (mut a, mut b) = ([0.0], [0.0]);
+        to_soa(&[Double::NAN], [&mut a, &mut b]);
+        let mut out = [Double::ZERO];
+        from_soa_raw([&a, &b], &mut out);
+        assert!(out[0].is_nan());
+        from_soa([&a, &b], &mut out);
+        assert!(out[0].is_nan());
+    }
+
+    #[test]
+    fn raw_denormalized() {
+        let mut out = [Quad::ZERO; 2];
+        from_soa_raw(
+            [&[1.0, 1e-20], &[1.0, 1.0], &[0.0, 0.0], &[0.0, 0.0]],
+            &mut out,
+        );
+        assert!(same_quad(out[0], Quad::from((1.0, 1.0, 0.0, 0.0))));
+        assert!(same_quad(out[1], Quad::from((1e-20, 1.0, 0.0, 0.0))));
+    }
+
+    #[test]
+    fn normalizing_denormalized() {
+        let mut out = [Quad::ZERO; 4];
+        from_soa(
+            [
+                &[1.0, 1e-20, 1.0, 1.0],
+                &[1.0, 1.0, f64::NAN, f64::MAX],
+                &[0.0, 0.0, 0.0, f64::MAX],
+                &[0.0, 0.0, 0.0, 0.0],
+            ],
+            &mut out,
+        );
+        assert!(same_quad(out[0], Quad::from((2.0, 0.0, 0.0, 0.0))));
+        assert!(same_quad(out[1], Quad::from((1.0, 1e-20, 0.0, 0.0))));
+        assert!(out[2].is_nan());
+        assert!(same_quad(out[3], Quad::INFINITY));
+
+        let mut out = [Double::ZERO; 2];
+        from_soa([&[1.0, 1.0], &[3e-16, -1.0]], &mut out);
+        assert!(out[0][0] + out[0][1] == out[0][0]);
+        assert!(out[0] == Double::from((1.0, 0.0)) + Double::from((3e-16, 0.0)));
+        assert!(same_double(out[1], Double::from((0.0, 0.0))));
+    }
+
+    #[test]
+    fn quad_heads() {
+        let values = quads();
+        let mut out = vec![0.0; values.len()];
+        heads(&values, &mut out);
+        for (v, x) in values.iter().zip(out.iter()) {
+            assert!(v[0].to_bits() == x.to_bits());
+        }
+    }
+
+    #[test]
+    fn double_heads() {
+        let values = doubles();
+        let mut out = vec![0.0; values.len()];
+        heads(&values, &mut out);
+        for (v, x) in values.iter().zip(out.iter()) {
+            assert!(v[0].to_bits() == x.to_bits());
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let values: [Quad; 0] = [];
+        to_soa(&values, [&mut [], &mut [], &mut [], &mut []]);
+        from_soa([&[], &[], &[], &[]], &mut [] as &mut [Quad]);
+        heads(&values, &mut []);
+    }
+
+    // The values from `quads` that are small enough that multiplying two of them can't
+    // overflow and large enough that it can't underflow
+    fn finite_quads() -> Vec<Quad> {
+        quads()
+            .into_iter()
+            .filter(|q| q[0].abs() < 1e100 && q[0].abs() > 1e-100)
+            .collect()
+    }
+
+    #[test]
+    fn double_axpy_accuracy() {
+        let x: Vec<Double> = finite_quads()
+            .iter()
+            .map(|q| Double::from((q[0], q[1])))
+            .collect();
+        let alpha = Double::from((3.0, 1e-17)) / Double::from(7.0);
+        for (i, &u) in x.iter().enumerate() {
+            // Every other y nearly cancels alpha * x
+            let v = if i % 2 == 0 {
+                -(alpha * u)
+            } else {
+                x[x.len() - 1 - i]
+            };
+            let mut y = [v];
+            axpy(alpha, &[u], &mut y);
+
+            let q = |d: Double| Quad::from((d[0], d[1], 0.0, 0.0));
+            let exact = q(alpha) * q(u) + q(v);
+            let err = (q(y[0]) - exact).abs();
+            assert!(
+                err <= exact.abs() * Quad::from(4.0 * Double::EPSILON[0]),
+                "{:?} * {:?} + {:?} gave {:?}",
+                alpha,
+                u,
+                v,
+                y[0]
+            );
+        }
+    }
+
+    #[test]
+    fn double_axpy_cancellation() {
+        // The naive calculation rounds the product and cancels it away entirely
+        let alpha = Double::PI;
+        let x = Double::E;
+        let v = -(alpha * x);
+        assert!(alpha * x + v == Double::ZERO);
+        let mut y = [v];
+        axpy(alpha, &[x], &mut y);
+        assert!(y[0] != Double::ZERO);
+
+        let q = |d: Double| Quad::from((d[0], d[1], 0.0, 0.0));
+        let exact = q(alpha) * q(x) + q(v);
+        let err = (q(y[0]) - exact).abs();
+        assert!(err <= exact.abs() * Quad::from(Double::EPSILON[0]));
+    }
+
+    #[test]
+    fn quad_axpy_accuracy() {
+        // Products of `f64`s are exact in a `Quad`, which gives an exact reference
+        let values = finite_quads();
+        for (i, &v) in values.iter().enumerate() {
+            let (a, u) = (values[i / 2][0], values[values.len() - 1 - i][0]);
+            let (h, l) = p::two_prod(a, u);
+            let v = if i % 2 == 0 {
+                -Quad::from((h, 0.0, 0.0, 0.0)) + v * Quad::from((h * 1e-40, 0.0, 0.0, 0.0))
+            } else {
+                v
+            };
+            let mut y = [v];
+            let q = |x: f64| Quad::from((x, 0.0, 0.0, 0.0));
+            axpy(q(a), &[q(u)], &mut y);
+
+            let exact = Quad::from((h, l, 0.0, 0.0)) + v;
+            let err = (y[0] - exact).abs();
+            assert!(
+                err <= exact.abs() * Quad::EPSILON * Quad::from(4.0),
+                "{} * {} + {:?}",
+                a,
+                u,
+                v
+            );
+        }
+    }
+
+    #[test]
+    fn axpy_alpha_zero() {
+        let x = [Double::NAN, Double::INFINITY, Double::ONE];
+        let mut y = [Double::NEG_ZERO, Double::PI, Double::NAN];
+        axpy(Double::ZERO, &x, &mut y);
+        assert!(same_double(y[0], Double::NEG_ZERO));
+        assert!(same_double(y[1], Double::PI));
+        assert!(y[2].is_nan());
+
+        let mut y = [Quad::NEG_ZERO];
+        axpy(Quad::ZERO, &[Quad::NAN], &mut y);
+        assert!(same_quad(y[0], Quad::NEG_ZERO));
+    }
+
+    #[test]
+    fn axpy_alpha_one() {
+        let x = doubles();
+        let mut y: Vec<Double> = x.iter().rev().copied().collect();
+        let expected: Vec<Double> = x.iter().zip(&y).map(|(&u, &v)| u + v).collect();
+        axpy(Double::ONE, &x, &mut y);
+        for (a, b) in y.iter().zip(&expected) {
+            assert!(same_double(*a, *b) || (a.is_nan() && b.is_nan()));
+        }
+    }
+
+    #[test]
+    fn axpy_signed_zeros() {
+        let mut y = [Double::NEG_ZERO, Double::NEG_ZERO, Double::ZERO];
+        axpy(
+            Double::PI,
+            &[Double::ZERO, Double::NEG_ZERO, Double::NEG_ZERO],
+            &mut y,
+        );
+        assert!(y[0].is_zero() && y[0].is_sign_positive());
+        assert!(y[1].is_zero() && y[1].is_sign_negative());
+        assert!(y[2].is_zero() && y[2].is_sign_positive());
+
+        let mut y = [Quad::NEG_ZERO];
+        axpy(-Quad::PI, &[Quad::ZERO], &mut y);
+        assert!(y[0].is_zero() && y[0].is_sign_negative());
+
+        // Exact cancellation of a nonzero product is a positive zero
+        let mut y = [Double::from(6.0)];
+        axpy(Double::from(-2.0), &[Double::from(3.0)], &mut y);
+        assert!(same_double(y[0], Double::ZERO));
+        let mut y = [Quad::from(-6.0)];
+        axpy(Quad::from(2.0), &[Quad::from(3.0)], &mut y);
+        assert!(same_quad(y[0], Quad::ZERO));
+    }
+
+    #[test]
+    fn axpy_nonfinite() {
+        let mut y = [Double::ONE, Double::INFINITY, Double::NAN];
+        axpy(
+            Double::from(2.0),
+            &[Double::INFINITY, Double::NEG_INFINITY, Double::ONE],
+            &mut y,
+        );
+        assert!(same_double(y[0], Double::INFINITY));
+        assert!(y[1].is_nan());
+        assert!(y[2].is_nan());
+    }
+
+    #[test]
+    fn axpby_cases() {
+        let x = [Double::PI, Double::E];
+
+        // beta = 0 and alpha = 1 is an exact copy, whatever y was
+        let mut y = [Double::NAN, Double::INFINITY];
+        axpby(Double::ONE, &x, Double::ZERO, &mut y);
+        assert!(same_double(y[0], x[0]) && same_double(y[1], x[1]));
+
+        let mut y = [Double::NAN, Double::INFINITY];
+        axpby(Double::from(2.0), &x, Double::ZERO, &mut y);
+        assert!(
+            y == [
+                Double::PI * Double::from(2.0),
+                Double::E * Double::from(2.0)
+            ]
+        );
+
+        let mut y = [Double::ONE, Double::from(2.0)];
+        axpby(
+            Double::ZERO,
+            &[Double::NAN, Double::NAN],
+            Double::from(3.0),
+            &mut y,
+        );
+        assert!(y == [Double::from(3.0), Double::from(6.0)]);
+
+        let mut y = [Double::ONE, Double::from(2.0)];
+        axpby(Double::ONE, &x, Double::ONE, &mut y);
+        assert!(y == [Double::PI + Double::ONE, Double::E + Double::from(2.0)]);
+
+        let mut y = [Quad::ONE, Quad::from(2.0)];
+        axpby(
+            Quad::from(2.0),
+            &[Quad::PI, Quad::E],
+            Quad::from(0.5),
+            &mut y,
+        );
+        assert!(y[0] == Quad::PI * Quad::from(2.0) + Quad::from(0.5));
+        assert!(y[1] == Quad::E * Quad::from(2.0) + Quad::ONE);
+    }
+
+    #[test]
+    fn scal_cases() {
+        let mut x = [Quad::NEG_ZERO, Quad::NAN, Quad::PI];
+        scal(Quad::ONE, &mut x);
+        assert!(same_quad(x[0], Quad::NEG_ZERO) && x[1].is_nan() && same_quad(x[2], Quad::PI));
+
+        scal(Quad::from(-2.0), &mut x);
+        assert!(
+            same_quad(x[0], Quad::ZERO) && x[1].is_nan() && x[2] == Quad::PI * Quad::from(-2.0)
+        );
+
+        let mut x = [Double::INFINITY, Double::ONE];
+        scal(Double::ZERO, &mut x);
+        assert!(x[0].is_nan() && same_double(x[1], Double::ZERO));
+    }
+
+    #[test]
+    fn axpy_f64_exact_products() {
+        let x: Vec<f64> = finite_quads().iter().map(|q| q[0] * 1e-10).collect();
+        let alpha = 1.0 / 3.0;
+        let start: Vec<Double> = x.iter().map(|&u| -Double::from((alpha * u, 0.0))).collect();
+        let mut y = start.clone();
+        axpy_f64_into_double(alpha, &x, &mut y);
+        for i in 0..x.len() {
+            let (h, l) = p::two_prod(alpha, x[i]);
+            assert!(y[i] == Double::from((h, l)) + start[i]);
+            assert!(y[i] == Double::from((l, 0.0)));
+        }
+
+        let start: Vec<Quad> = start
+            .iter()
+            .map(|d| Quad::from((d[0], d[1], 0.0, 0.0)))
+            .collect();
+        let mut y = start.clone();
+        axpy_f64_into_quad(alpha, &x, &mut y);
+        for i in 0..x.len() {
+            let (h, l) = p::two_prod(alpha, x[i]);
+            assert!(y[i] == Quad::from((h, l, 0.0, 0.0)) + start[i]);
+        }
+    }
+
+    #[test]
+    fn axpy_f64_alpha_zero() {
+        let mut y = [Double::NEG_ZERO];
+        axpy_f64_into_double(0.0, &[f64::NAN], &mut y);
+        assert!(same_double(y[0], Double::NEG_ZERO));
+        let mut y = [Quad::PI];
+        axpy_f64_into_quad(0.0, &[f64::INFINITY], &mut y);
+        assert!(same_quad(y[0], Quad::PI));
+    }
+
+    #[test]
+    fn axpy_f64_overflow() {
+        let mut y = [Double::ONE, Double::ONE];
+        axpy_f64_into_double(f64::MAX, &[2.0, -2.0], &mut y);
+        assert!(same_double(y[0], Double::INFINITY));
+        assert!(same_double(y[1], Double::NEG_INFINITY));
+    }
+
+    #[test]
+    fn axpy_into_f64_cases() {
+        let x: Vec<f64> = finite_quads().iter().map(|q| q[0]).collect();
+        let alpha = Quad::ONE / Quad::from(3.0);
+        let start: Vec<f64> = x.iter().map(|&u| -(u / 3.0)).collect();
+        let mut y = start.clone();
+        axpy_quad_into_f64(alpha, &x, &mut y);
+        for i in 0..x.len() {
+            let q = |a: f64| Quad::from((a, 0.0, 0.0, 0.0));
+            let exact = alpha * q(x[i]) + q(start[i]);
+            assert!(y[i] == exact[0], "{} * {:?} + {}", x[i], alpha, start[i]);
+        }
+
+        let mut y = start.clone();
+        axpy_double_into_f64(Double::ONE / Double::from(3.0), &x, &mut y);
+        for i in 0..x.len() {
+            // x / 3 loses about one bit in the rounding, so what's left is a small part of
+            // the f64 that it was rounded to
+            assert!(y[i].abs() <= start[i].abs() * f64::EPSILON);
+        }
+
+        let mut y = [-0.0, 1.0];
+        axpy_quad_into_f64(Quad::ZERO, &[f64::NAN, f64::INFINITY], &mut y);
+        assert!(y[0].to_bits() == (-0.0f64).to_bits() && y[1] == 1.0);
+        axpy_double_into_f64(Double::from(f64::MAX), &[2.0, -2.0], &mut y);
+        assert!(y == [f64::INFINITY, f64::NEG_INFINITY]);
+    }
+
+    #[test]
+    fn dot_cases() {
+        let x = [Double::from(1.0), Double::from(2.0), Double::from(3.0)];
+        let y = [Double::from(4.0), Double::from(-5.0), Double::from(6.0)];
+        assert!(dot(&x, &y) == Double::from(12.0));
+        assert!(dot(&[Double::PI], &[Double::E]) == Double::PI * Double::E);
+        // The first product's rounding error is what's left after the second cancels it
+        let r = dot(&[Double::PI, Double::E], &[Double::E, -Double::PI]);
+        assert!(r != Double::ZERO && r.abs() < Double::from(1e-30));
+
+        let x = finite_quads();
+        let expected = x.iter().fold(Quad::ZERO, |acc, &u| c_fma(u, u, acc));
+        assert!(same_quad(dot(&x, &x), expected));
+
+        assert!(dot(&[Quad::INFINITY], &[Quad::ONE]) == Quad::INFINITY);
+        assert!(dot(
+            &[Quad::INFINITY, Quad::ONE],
+            &[Quad::ONE, Quad::NEG_INFINITY]
+        )
+        .is_nan());
+    }
+
+    fn c_fma(a: Quad, b: Quad, c: Quad) -> Quad {
+        <Quad as Sealed<4>>::fused_mul_add(a, b, c)
+    }
+
+    #[test]
+    fn sum_cases() {
+        let big = Quad::from(1e40) / Quad::from(3.0);
+        let x = [big, Quad::PI, -big, Quad::E];
+        assert!(same_quad(sum(&x), Quad::PI + Quad::E));
+
+        let values = finite_quads();
+        let forward = sum(&values);
+        let mut reversed = values.clone();
+        reversed.reverse();
+        assert!(same_quad(forward, sum(&reversed)));
+        let mut acc = crate::QuadAccumulator::new();
+        values.iter().for_each(|&q| acc.push_quad(q));
+        assert!(same_quad(forward, acc.finish()));
+
+        let doubles: Vec<Double> = values.iter().map(|q| Double::from((q[0], q[1]))).collect();
+        let mut acc = crate::DoubleAccumulator::new();
+        doubles.iter().for_each(|&d| acc.push_double(d));
+        assert!(same_double(sum(&doubles), acc.finish()));
+
+        assert!(sum(&[Double::MAX, Double::MAX, -Double::MAX]) == Double::MAX);
+        assert!(sum(&[Quad::INFINITY, Quad::ONE]) == Quad::INFINITY);
+        assert!(sum(&[Quad::INFINITY, Quad::NEG_INFINITY]).is_nan());
+    }
+
+    // Splits a finite `f64` into a sign, an integer mantissa, and a power of 2
+    fn decompose(x: f64) -> (bool, u64, i32) {
+        let bits = x.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as i32;
+        let frac = bits & ((1 << 52) - 1);
+        let (m, e) = if biased == 0 {
+            (frac, -1074)
+        } else {
+            (frac | 1 << 52, biased - 1075)
+        };
+        (x < 0.0, m, e)
+    }
+
+    // The exact dot product of `x` and `y`, calculated with integers and converted to a
+    // `Quad` by adding up its bits 53 at a time
+    fn exact_dot(x: &[f64], y: &[f64]) -> Quad {
+        let terms: Vec<(bool, Big, i32)> = x
+            .iter()
+            .zip(y)
+            .map(|(&u, &v)| {
+                let (su, mu, eu) = decompose(u);
+                let (sv, mv, ev) = decompose(v);
+                let mut hi = Big::from_u64(mu);
+                hi.mul_small((mv >> 32) as u32);
+                let mut lo = Big::from_u64(mu);
+                lo.mul_small(mv as u32);
+                (su != sv, hi.shl(32).add(&lo), eu + ev)
+            })
+            .collect();
+        let low = terms.iter().map(|t| t.2).min().unwrap();
+        let (mut pos, mut neg) = (Big::default(), Big::default());
+        for (negative, m, e) in terms {
+            let scaled = m.shl((e - low) as u64);
+            if negative {
+                neg = neg.add(&scaled);
+            } else {
+                pos = pos.add(&scaled);
+            }
+        }
+        let (negative, mut rest) = if pos >= neg {
+            (false, pos.sub(&neg))
+        } else {
+            (true, neg.sub(&pos))
+        };
+        let mut parts = vec![];
+        while !rest.is_zero() {
+            let k = rest.bit_len().saturating_sub(53);
+            let top = rest.shr(k).low_u64() as f64;
+            parts.push(crate::common::utils::ldexp(top, k as i32 + low));
+            rest = rest.low_bits(k);
+        }
+        let r = Quad::from_sum(&parts);
+        if negative {
+            -r
+        } else {
+            r
+        }
+    }
+
+    // An ill-conditioned dot product, made as in Ogita, Rump, and Oishi's "Accurate Sum and
+    // Dot Product": the products in the first half have exponents spread out over `spread`
+    // bits, and each one in the second half is chosen to cancel most of the dot product so
+    // far. The condition number comes out at about 2^`spread`.
+    fn ill_conditioned(n: usize, spread: i32) -> (Vec<f64>, Vec<f64>) {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        let mut next = || rng.next_f64() * 2.0 - 1.0;
+        let half = n / 2;
+        let (mut x, mut y) = (vec![], vec![]);
+        for i in 0..half {
+            let e = if i == 0 {
+                spread / 2
+            } else if i == half - 1 {
+                0
+            } else {
+                ((next() + 1.0) * spread as f64 / 4.0).round() as i32
+            };
+            x.push(next() * 2f64.powi(e));
+            y.push(next() * 2f64.powi(e));
+        }
+        for i in half..n {
+            let e = spread / 2 - (spread / 2) * (i - half) as i32 / (n - half - 1) as i32;
+            let u = next() * 2f64.powi(e);
+            let s = exact_dot(&x, &y)[0];
+            x.push(u);
+            y.push((next() * 2f64.powi(e) - s) / u);
+        }
+        (x, y)
+    }
+
+    #[test]
+    fn dot_f64_ill_conditioned() {
+        let (x, y) = ill_conditioned(2 * CHUNK + 40, 100);
+        let exact = exact_dot(&x, &y);
+        let magnitude: f64 = x.iter().zip(&y).map(|(a, b)| (a * b).abs()).sum();
+        let condition = 2.0 * magnitude / exact[0].abs();
+        assert!(condition > 1e28, "condition number {:e}", condition);
+
+        // An f64 dot product has no correct digits at all
+        let naive: f64 = x.iter().zip(&y).map(|(a, b)| a * b).sum();
+        assert!((naive - exact[0]).abs() > exact[0].abs());
+
+        let q = dot_f64_into_quad(&x, &y);
+        assert!(
+            (q - exact).abs() <= exact.abs() * Quad::EPSILON,
+            "{:?} should be {:?}",
+            q,
+            exact
+        );
+        let d = dot_f64_into_double(&x, &y);
+        let (dq, eq) = (Quad::from((d[0], d[1], 0.0, 0.0)), exact);
+        assert!(
+            (dq - eq).abs() <= eq.abs() * Quad::from(Double::EPSILON[0]),
+            "{:?} should be {:?}",
+            d,
+            exact
+        );
+
+        // The result doesn't depend on the order
+        let (mut rx, mut ry) = (x.clone(), y.clone());
+        rx.reverse();
+        ry.reverse();
+        assert!(same_quad(dot_f64_into_quad(&rx, &ry), q));
+    }
+
+    #[test]
+    fn dot_f64_exact_products() {
+        // (1 + ε)² - 1 - 2ε is ε²
+        let e = f64::EPSILON;
+        let x = [1.0 + e, -1.0, -2.0 * e];
+        let y = [1.0 + e, 1.0, 1.0];
+        assert!(same_quad(
+            dot_f64_into_quad(&x, &y),
+            Quad::from((e * e, 0.0, 0.0, 0.0))
+        ));
+        assert!(same_double(
+            dot_f64_into_double(&x, &y),
+            Double::from((e * e, 0.0))
+        ));
+
+        let x: Vec<f64> = finite_quads().iter().map(|q| q[0]).collect();
+        let y: Vec<f64> = x.iter().rev().map(|&u| u * 1e-3).collect();
+        assert!(same_quad(dot_f64_into_quad(&x, &y), {
+            let mut acc = crate::QuadAccumulator::new();
+            for (&u, &v) in x.iter().zip(&y) {
+                let (h, l) = p::two_prod(u, v);
+                acc.push(h);
+                acc.push(l);
+            }
+            acc.finish()
+        }));
+    }
+
+    #[test]
+    fn dot_f64_nonfinite() {
+        assert!(dot_f64_into_quad(&[f64::MAX, 1.0], &[2.0, 1.0]) == Quad::INFINITY);
+        assert!(dot_f64_into_double(&[f64::MAX, f64::MAX], &[2.0, -2.0]).is_nan());
+        assert!(dot_f64_into_quad(&[f64::NAN, 1.0], &[1.0, 1.0]).is_nan());
+        assert!(dot_f64_into_double(&[f64::INFINITY], &[-1.0]) == Double::NEG_INFINITY);
+    }
+
+    #[test]
+    fn kernels_empty() {
+        axpy(Double::PI, &[], &mut []);
+        axpby(Quad::PI, &[], Quad::E, &mut []);
+        scal(Double::PI, &mut []);
+        axpy_f64_into_double(2.0, &[], &mut []);
+        axpy_f64_into_quad(2.0, &[], &mut []);
+        axpy_double_into_f64(Double::PI, &[], &mut []);
+        axpy_quad_into_f64(Quad::PI, &[], &mut []);
+        assert!(same_double(dot::<Double, 2>(&[], &[]), Double::ZERO));
+        assert!(same_quad(sum::<Quad, 4>(&[]), Quad::ZERO));
+        assert!(same_double(dot_f64_into_double(&[], &[]), Double::ZERO));
+        assert!(same_quad(dot_f64_into_quad(&[], &[]), Quad::ZERO));
+    }
+
+    #[test]
+    #[should_panic(expected = "y slice length")]
+    fn axpy_length_mismatch() {
+        axpy(Double::ONE, &[Double::ONE, Double::ONE], &mut [Double::ONE]);
+    }
+
+    #[test]
+    #[should_panic(expected = "y slice length")]
+    fn axpy_alpha_zero_length_mismatch() {
+        axpy(Quad::ZERO, &[Quad::ONE], &mut [Quad::ONE, Quad::ONE]);
+    }
+
+    #[test]
+    #[should_panic(expected = "y slice length")]
+    fn axpby_length_mismatch() {
+        axpby(Double::ONE, &[Double::ONE], Double::ZERO, &mut []);
+    }
+
+    #[test]
+    #[should_panic(expected = "y slice length")]
+    fn axpy_f64_length_mismatch() {
+        axpy_f64_into_quad(0.0, &[1.0], &mut []);
+    }
+
+    #[test]
+    #[should_panic(expected = "y slice length")]
+    fn axpy_into_f64_length_mismatch() {
+        axpy_quad_into_f64(Quad::ONE, &[1.0, 2.0], &mut [1.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "y slice length")]
+    fn dot_length_mismatch() {
+        dot(&[Quad::ONE], &[Quad::ONE, Quad::ONE]);
+    }
+
+    #[test]
+    #[should_panic(expected = "y slice length")]
+    fn dot_f64_length_mismatch() {
+        dot_f64_into_double(&[1.0, 2.0], &[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "component slice length")]
+    fn to_soa_length_mismatch() {
+        let (mut a, mut b) = ([0.0; 2], [0.0; 1]);
+        to_soa(&[Double::ONE, Double::ONE], [&mut a, &mut b]);
+    }
+
+    #[test]
+    #[should_panic(expected = "component slice length")]
+    fn from_soa_length_mismatch() {
+        let mut out = [Quad::ZERO; 2];
+        from_soa([&[1.0, 2.0], &[0.0, 0.0], &[0.0], &[0.0, 0.0]], &mut out);
+    }
+
+    #[test]
+    #[should_panic(expected = "component slice length")]
+    fn from_soa_raw_length_mismatch() {
+        let mut out = [Double::ZERO; 1];
+        from_soa_raw([&[1.0, 2.0], &[0.0, 0.0]], &mut out);
+    }
+
+    #[test]
+    #[should_panic(expected = "output slice length")]
+    fn heads_length_mismatch() {
+        let mut out = [0.0; 3];
+        heads(&[Quad::ONE, Quad::ONE], &mut out);
+    }
+}
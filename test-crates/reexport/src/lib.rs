@@ -0,0 +1,26 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// An intermediate crate that re-exports qd, both as a whole and piece by piece, the way a
+// larger library built on top of qd might. The crate that uses this one never names qd
+// directly.
+
+pub use qd;
+pub use qd::{dd, qd as quad};
+
+pub mod numbers {
+    pub use qd::prelude::*;
+}
+
+// A macro of this crate's own that expands to qd's macros
+#[macro_export]
+macro_rules! half {
+    ($x:expr) => {
+        (
+            $crate::qd::dd!($x) / $crate::qd::dd!(2),
+            $crate::qd::qd!($x) / $crate::qd::qd!(2),
+        )
+    };
+}
@@ -0,0 +1,8 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Everything in this crate is in its tests. qd is imported as `myqd`, so there is no crate
+// named `qd` in scope anywhere; any macro that refers to qd by name instead of through
+// `$crate` will fail to compile.
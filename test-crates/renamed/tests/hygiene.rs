@@ -0,0 +1,140 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// qd is a dependency of this crate under the name `myqd`, and it's also reachable through
+// the `qd_reexport` crate. None of these tests can name `qd` directly.
+
+mod renamed {
+    use myqd::{Double, Quad};
+
+    #[test]
+    fn dd_by_path() {
+        let x = myqd::dd!(1.5);
+        assert!(x == Double::from(1.5));
+        assert!(myqd::dd!("0.1") * myqd::dd!(10) == Double::ONE);
+    }
+
+    #[test]
+    fn qd_by_path() {
+        let x = myqd::qd!(1.5);
+        assert!(x == Quad::from(1.5));
+        assert!(myqd::qd!(0.25) * myqd::qd!(4) == Quad::ONE);
+    }
+
+    #[test]
+    fn macros_by_use() {
+        use myqd::{dd, qd};
+        assert!(dd!(3) + dd!(4) == Double::from(7));
+        assert!(qd!(3) + qd!(4) == Quad::from(7));
+    }
+
+    #[test]
+    fn macros_by_alias() {
+        use myqd::{dd as double, qd as quad};
+        assert!(double!(2).sqr() == Double::from(4));
+        assert!(quad!(2).sqr() == Quad::from(4));
+    }
+
+    // A literal is converted in a constant, which names more of qd than converting anything
+    // else does
+    const SEVENTH: Double = myqd::dd!("0.142857142857142857142857142857142857");
+    static HALVES: [Quad; 2] = [myqd::qd!(0.5), myqd::qd!(-0.5e0)];
+
+    #[test]
+    fn consts() {
+        assert!(
+            SEVENTH
+                == "0.142857142857142857142857142857142857"
+                    .parse::<Double>()
+                    .unwrap()
+        );
+        assert!(HALVES[0] == Quad::from(0.5) && HALVES[1] == Quad::from(-0.5));
+    }
+
+    #[test]
+    fn aliases() {
+        let x: myqd::DD = myqd::dd!(2);
+        let y: myqd::QD = myqd::qd!(2);
+        assert!(x == Double::from(2));
+        assert!(y == Quad::from(2));
+    }
+}
+
+mod prelude {
+    use myqd::prelude::*;
+
+    fn mean<T: Float>(values: &[T]) -> T {
+        let sum: T = values.iter().copied().sum();
+        sum / T::from_f64(values.len() as f64)
+    }
+
+    #[test]
+    fn glob() {
+        let d: DD = mean(&[dd!(1), dd!(2), dd!(3), dd!(4)]);
+        let q: QD = mean(&[qd!(1), qd!(2), qd!(3), qd!(4)]);
+        assert!(d == Double::from(2.5));
+        assert!(q == Quad::from(2.5));
+    }
+
+    // None of the prelude's names shadow anything from the standard prelude, so everything
+    // from std still means what it usually means
+    #[test]
+    fn no_std_collisions() {
+        let v: Vec<Option<f64>> = vec![Some(1.0), None];
+        let s: String = format!("{:?}", v);
+        assert_eq!(s, "[Some(1.0), None]");
+        let r: Result<i32, String> = "42".parse::<i32>().map_err(|e| e.to_string());
+        assert_eq!(r, Ok(42));
+        assert!(Quad::EPSILON.to_f64() < f64::EPSILON);
+    }
+}
+
+mod reexported {
+    use myqd::{Double, Quad};
+    use qd_reexport::numbers::{DD, QD};
+
+    #[test]
+    fn dd_through_reexport() {
+        let x: DD = qd_reexport::dd!(0.5);
+        assert!(x == Double::from(0.5));
+    }
+
+    #[test]
+    fn renamed_reexport() {
+        let x: QD = qd_reexport::quad!(0.5);
+        assert!(x == Quad::from(0.5));
+        static Y: QD = qd_reexport::quad!(-1.25);
+        assert!(Y == Quad::from(-1.25));
+    }
+
+    #[test]
+    fn through_reexported_crate() {
+        assert!(qd_reexport::qd::dd!(8) == Double::from(8));
+        assert!(qd_reexport::qd::qd!(8) == Quad::from(8));
+    }
+
+    #[test]
+    fn through_reexported_prelude() {
+        use qd_reexport::numbers::*;
+        assert!(dd!(1) + dd!(1) == Double::from(2));
+        assert!(qd!(1) + qd!(1) == Quad::from(2));
+    }
+
+    #[test]
+    fn nested_macro() {
+        let (d, q) = qd_reexport::half!(3);
+        assert!(d == Double::from(1.5));
+        assert!(q == Quad::from(1.5));
+    }
+
+    // The types that come through the re-export are the same types as the ones that come
+    // from the renamed dependency
+    #[test]
+    fn same_types() {
+        let x: Double = qd_reexport::dd!(1);
+        let y: qd_reexport::qd::Double = myqd::dd!(1);
+        assert!(x == y);
+    }
+}
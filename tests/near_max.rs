@@ -0,0 +1,396 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Operations on numbers within a factor of 4 of ±MAX.
+//
+// Every operation here has a result that can be represented, so each one has to come out
+// finite. Whether it's also accurate is checked against the same operation done on
+// arguments scaled down by a power of 2, far enough from MAX that nothing in the
+// calculation can overflow, with the result then scaled back up. Scaling by a power of 2
+// is exact, so the two should agree to within the precision of the type, and they usually
+// agree exactly.
+//
+// Results that can't be represented have to be infinities of the right sign, not NaN.
+
+macro_rules! near_max_tests {
+    ($T:ident, $m:ident, $exp_tol:expr) => {
+        mod $m {
+            use qd::$T;
+
+            // Arguments are scaled down by 2^SHIFT to calculate references. This is a
+            // multiple of 2, 3, and 4 so that roots can be scaled back up exactly.
+            const SHIFT: i32 = 996;
+
+            fn down(x: $T) -> $T {
+                x.ldexp(-SHIFT)
+            }
+
+            fn up(x: $T) -> $T {
+                x.ldexp(SHIFT)
+            }
+
+            fn n(x: f64) -> $T {
+                $T::from(x)
+            }
+
+            // Numbers within a factor of 4 of ±MAX, some of them with all components in
+            // use.
+            fn bigs() -> Vec<$T> {
+                let m = $T::MAX;
+                vec![
+                    m,
+                    -m,
+                    m * n(0.75),
+                    m / n(1.5),
+                    m / n(2.0),
+                    -m / n(3.0),
+                    m / $T::PI,
+                    m / n(4.0),
+                    -m * $T::FRAC_1_SQRT_2,
+                ]
+            }
+
+            fn assert_close(actual: $T, expected: $T, tol: $T, what: &str) {
+                assert!(actual.is_finite(), "{} = {:?} is not finite", what, actual);
+                let diff = (actual - expected).abs();
+                assert!(
+                    diff <= expected.abs() * tol,
+                    "{} = {:?}, expected {:?}",
+                    what,
+                    actual,
+                    expected
+                );
+            }
+
+            fn tol() -> $T {
+                $T::EPSILON * n(4.0)
+            }
+
+            #[test]
+            fn mul() {
+                let factors = [
+                    $T::FRAC_1_SQRT_2,
+                    n(0.5),
+                    n(0.99),
+                    n(-0.3),
+                    $T::FRAC_1_PI,
+                    n(1e-10),
+                ];
+                for &x in &bigs() {
+                    for &y in &factors {
+                        let expected = up(down(x) * y);
+                        assert_close(x * y, expected, tol(), &format!("{:?} * {:?}", x, y));
+                        assert_close(y * x, expected, tol(), &format!("{:?} * {:?}", y, x));
+                    }
+                }
+            }
+
+            #[test]
+            fn mul_to_near_max() {
+                for &x in &bigs() {
+                    let (a, b) = (down(x), n(2.0).powi(SHIFT));
+                    assert_close(a * b, x, tol(), &format!("{:?} * {:?}", a, b));
+                    // Rounding could take MAX itself past MAX here
+                    if x.abs() < $T::MAX {
+                        let (a, b) = (a.mul_i32(3), b / n(3.0));
+                        assert_close(a * b, x, tol(), &format!("{:?} * {:?}", a, b));
+                    }
+                }
+                let x = $T::MAX / n(4.0);
+                assert_close(x.mul_i32(3), up(down(x).mul_i32(3)), tol(), "mul_i32");
+            }
+
+            #[test]
+            fn mul_add() {
+                for &x in &bigs() {
+                    let y = n(0.75);
+                    let z = -x / n(8.0);
+                    let expected = up(down(x).mul_add(y, down(z)));
+                    assert_close(x.mul_add(y, z), expected, tol(), "mul_add");
+                }
+            }
+
+            #[test]
+            fn mul_overflow() {
+                let m = $T::MAX;
+                assert_eq!(m * n(2.0), $T::INFINITY);
+                assert_eq!(m * n(-2.0), $T::NEG_INFINITY);
+                assert_eq!(m / n(2.0) * n(3.0), $T::INFINITY);
+                assert_eq!(-m * m, $T::NEG_INFINITY);
+                assert_eq!(m.mul_i32(-3), $T::NEG_INFINITY);
+            }
+
+            #[test]
+            fn div() {
+                let divisors = [n(1.5), n(3.0), n(4.0), $T::PI, -$T::E, n(1e10), n(0.99)];
+                for &x in &bigs() {
+                    for &y in &divisors {
+                        if (x / y).abs() <= $T::MAX / n(1.01) {
+                            let expected = up(down(x) / y);
+                            assert_close(x / y, expected, tol(), &format!("{:?} / {:?}", x, y));
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn div_to_near_max() {
+                for &x in &bigs() {
+                    let (a, b) = (down(x), n(2.0).powi(-SHIFT));
+                    assert_close(a / b, x, tol(), &format!("{:?} / {:?}", a, b));
+                    if x.abs() < $T::MAX {
+                        let (a, b) = (x * n(1e-10), n(1e-10));
+                        assert_close(a / b, x, tol(), &format!("{:?} / {:?}", a, b));
+                    }
+                }
+            }
+
+            #[test]
+            fn div_overflow() {
+                let m = $T::MAX;
+                assert_eq!(m / n(0.5), $T::INFINITY);
+                assert_eq!(m / n(-0.25), $T::NEG_INFINITY);
+                assert_eq!(n(1e300) / n(1e-300), $T::INFINITY);
+            }
+
+            #[test]
+            fn add_sub() {
+                for &x in &bigs() {
+                    for &y in &[-x / n(2.0), x / n(3.0), -x * n(0.99), x / n(-7.0)] {
+                        let sum = up(down(x) + down(y));
+                        let diff = up(down(x) - down(y));
+                        if sum.abs() <= $T::MAX / n(1.01) {
+                            assert_close(x + y, sum, tol(), &format!("{:?} + {:?}", x, y));
+                        }
+                        if diff.abs() <= $T::MAX / n(1.01) {
+                            assert_close(x - y, diff, tol(), &format!("{:?} - {:?}", x, y));
+                        }
+                    }
+                }
+                let (a, b) = ($T::MAX / n(2.0), $T::MAX / n(3.0));
+                assert_close(a + b, up(down(a) + down(b)), tol(), "MAX/2 + MAX/3");
+            }
+
+            #[test]
+            fn add_sub_overflow() {
+                let m = $T::MAX;
+                assert_eq!(m + m, $T::INFINITY);
+                assert_eq!(-m - m, $T::NEG_INFINITY);
+                assert_eq!(m - (-m / n(2.0)), $T::INFINITY);
+            }
+
+            #[test]
+            fn sqr() {
+                for &x in &bigs() {
+                    let r = (x / n(1.01)).abs().sqrt();
+                    let expected = up(r.ldexp(-SHIFT / 2).sqr());
+                    assert_close(r.sqr(), expected, tol(), &format!("{:?}.sqr()", r));
+                    assert_close(r.powi(2), expected, tol(), &format!("{:?}.powi(2)", r));
+                    assert_close(r * r, expected, tol(), &format!("{:?} * {:?}", r, r));
+                }
+                let r = n(2.0).powi(512);
+                assert_eq!(r.sqr(), $T::INFINITY);
+                assert_eq!((-r).sqr(), $T::INFINITY);
+            }
+
+            #[test]
+            fn sqrt() {
+                for &x in &bigs() {
+                    let x = x.abs();
+                    let expected = down(x).sqrt().ldexp(SHIFT / 2);
+                    assert_close(x.sqrt(), expected, tol(), &format!("{:?}.sqrt()", x));
+                }
+            }
+
+            #[test]
+            fn hypot() {
+                for &x in &bigs() {
+                    let x = x / n(2.0);
+                    for &y in &[x, x / n(-3.0), x / n(1e10), $T::MAX / n(-4.0)] {
+                        let expected = up(down(x).hypot(down(y)));
+                        let what = format!("{:?}.hypot({:?})", x, y);
+                        assert_close(x.hypot(y), expected, tol(), &what);
+
+                        let z = y / n(2.0);
+                        let expected = up(down(x).hypot3(down(y), down(z)));
+                        let what = format!("{:?}.hypot3({:?}, {:?})", x, y, z);
+                        assert_close(x.hypot3(y, z), expected, tol(), &what);
+                    }
+                }
+                let m = $T::MAX;
+                assert_eq!(m.hypot(m), $T::INFINITY);
+                assert_eq!(m.hypot3(-m / n(2.0), m / n(2.0)), $T::INFINITY);
+            }
+
+            // Roots are a little less precise than the arithmetic
+            fn root_tol() -> $T {
+                $T::EPSILON * n(32.0)
+            }
+
+            #[test]
+            fn nroot() {
+                for &x in &bigs() {
+                    let expected = down(x).cbrt().ldexp(SHIFT / 3);
+                    assert_close(x.cbrt(), expected, root_tol(), &format!("{:?}.cbrt()", x));
+                    assert_close(
+                        x.nroot(3),
+                        expected,
+                        root_tol(),
+                        &format!("{:?}.nroot(3)", x),
+                    );
+
+                    let x = x.abs();
+                    let expected = down(x).nroot(4).ldexp(SHIFT / 4);
+                    assert_close(
+                        x.nroot(4),
+                        expected,
+                        root_tol(),
+                        &format!("{:?}.nroot(4)", x),
+                    );
+
+                    let expected = down(x).nroot(-2).ldexp(-SHIFT / 2);
+                    assert_close(
+                        x.nroot(-2),
+                        expected,
+                        root_tol(),
+                        &format!("{:?}.nroot(-2)", x),
+                    );
+                }
+            }
+
+            #[test]
+            fn atan() {
+                for &x in &bigs() {
+                    // 1/x is far too small to make a difference
+                    let expected = $T::FRAC_PI_2 * x.signum();
+                    assert_close(x.atan(), expected, tol(), &format!("{:?}.atan()", x));
+                }
+            }
+
+            #[test]
+            fn atan2() {
+                for &x in &bigs() {
+                    for &y in &[x / n(3.0), -x, x / n(-1e10), $T::MAX / n(2.0)] {
+                        let expected = down(x).atan2(down(y));
+                        let what = format!("{:?}.atan2({:?})", x, y);
+                        assert_close(x.atan2(y), expected, tol(), &what);
+                        let expected = down(y).atan2(down(x));
+                        let what = format!("{:?}.atan2({:?})", y, x);
+                        assert_close(y.atan2(x), expected, tol(), &what);
+                    }
+                    let expected = $T::FRAC_PI_2 * x.signum();
+                    assert_close(x.atan2(n(1.0)), expected, tol(), "atan2 of huge over one");
+                }
+            }
+
+            #[test]
+            fn tanh() {
+                for &x in &bigs() {
+                    assert_eq!(x.tanh(), x.signum(), "{:?}.tanh()", x);
+                }
+            }
+
+            // These can't be accurate, because the reduction by 2π can't be when the
+            // arguments are this large. They do have to stay in range, and they have to
+            // agree with each other.
+            #[test]
+            fn sin_cos() {
+                let mut xs = bigs();
+                xs.extend(&[n(1e100), n(-1e200), n(1e300), n(2.0).powi(1000)]);
+                for &x in &xs {
+                    let (s, c) = (x.sin(), x.cos());
+                    assert!(s.abs() <= n(1.0), "{:?}.sin() = {:?}", x, s);
+                    assert!(c.abs() <= n(1.0), "{:?}.cos() = {:?}", x, c);
+                    assert_eq!(x.sin_cos(), (s, c), "{:?}.sin_cos()", x);
+                    let one = s.sqr() + c.sqr();
+                    assert_close(one, n(1.0), n(1e-25), &format!("sin² + cos² at {:?}", x));
+                    assert!(x.tan().is_finite(), "{:?}.tan() = {:?}", x, x.tan());
+                }
+            }
+
+            #[test]
+            fn exp() {
+                for &x in &[n(700.0), n(709.0), n(709.5), n(709.78)] {
+                    let k = 64;
+                    let expected = (x - $T::LN_2 * n(k as f64)).exp().ldexp(k);
+                    assert_close(x.exp(), expected, n($exp_tol), &format!("exp({:?})", x));
+                }
+                assert_eq!(n(709.79).exp(), $T::INFINITY);
+                assert_eq!(n(710.0).exp(), $T::INFINITY);
+            }
+
+            #[test]
+            fn ln() {
+                let shift = $T::LN_2 * n(SHIFT as f64);
+                for &x in &bigs() {
+                    let x = x.abs();
+                    let expected = down(x).ln() + shift;
+                    assert_close(x.ln(), expected, tol(), &format!("{:?}.ln()", x));
+                    assert_close(x.ln_1p(), expected, tol(), &format!("{:?}.ln_1p()", x));
+
+                    let expected = down(x).log2() + n(SHIFT as f64);
+                    assert_close(x.log2(), expected, tol(), &format!("{:?}.log2()", x));
+                    let expected = (down(x).ln() + shift) / $T::LN_10;
+                    assert_close(x.log10(), expected, tol(), &format!("{:?}.log10()", x));
+
+                    // 1/x² is far too small to make a difference, so both of these are
+                    // ln(2x)
+                    let expected = down(x).ln() + $T::LN_2 * n((SHIFT + 1) as f64);
+                    assert_close(x.asinh(), expected, tol(), &format!("{:?}.asinh()", x));
+                    assert_close(x.acosh(), expected, tol(), &format!("{:?}.acosh()", x));
+                }
+            }
+
+            // powf goes through exp, and it's no more precise than exp is for results this
+            // large
+            #[test]
+            fn powf() {
+                for &x in &bigs() {
+                    let x = x.abs();
+                    let expected = x.sqrt();
+                    let what = format!("{:?}.powf(0.5)", x);
+                    assert_close(x.powf(n(0.5)), expected, n($exp_tol), &what);
+
+                    let r = (x / n(1.01)).sqrt();
+                    let what = format!("{:?}.powf(2)", r);
+                    assert_close(r.powf(n(2.0)), r.sqr(), n($exp_tol), &what);
+                }
+            }
+
+            #[test]
+            fn euclid() {
+                for &x in &bigs() {
+                    // Every number this large is an integer, so dividing it by 4 is exact
+                    let q = x.div_euclid(n(4.0));
+                    assert_eq!(q, x / n(4.0), "{:?}.div_euclid(4)", x);
+                    assert_eq!(x.rem_euclid(n(4.0)), n(0.0), "{:?}.rem_euclid(4)", x);
+
+                    let q = x.div_euclid(n(3.0));
+                    assert!(q.is_finite(), "{:?}.div_euclid(3) = {:?}", x, q);
+                    assert_close(q, up(down(x) / n(3.0)), tol(), "div_euclid");
+
+                    let r = x.rem_euclid($T::PI);
+                    assert!(r >= n(0.0) && r < $T::PI, "{:?}.rem_euclid(π) = {:?}", x, r);
+                }
+            }
+
+            #[test]
+            fn rounding() {
+                for &x in &bigs() {
+                    assert_eq!(x.floor(), x);
+                    assert_eq!(x.ceil(), x);
+                    assert_eq!(x.round(), x);
+                    assert_eq!(x.trunc(), x);
+                    assert_eq!(x.fract(), n(0.0));
+                    assert_eq!(x.abs().abs(), x.abs());
+                    assert_eq!(-(-x), x);
+                }
+            }
+        }
+    };
+}
+
+near_max_tests!(Double, double, 1e-29);
+near_max_tests!(Quad, quad, 1e-59);
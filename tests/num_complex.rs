@@ -0,0 +1,128 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Using `Double` and `Quad` as the parts of num-complex's `Complex`.
+//
+// num-complex needs only `Num` and `Clone` for complex arithmetic, and `Float` for
+// everything else (`norm`, `arg`, `exp`, `ln`, and so on), so implementing the num-traits
+// traits is all it takes. The FFT is a plain recursive radix-2 one, written against
+// `num_traits::Float` the way any code generic over that trait would be.
+
+#![cfg(feature = "num-traits")]
+
+use num_complex::Complex;
+use num_traits::{Float, FloatConst};
+use qd::{dd, qd, Double, Quad};
+
+fn fft<T: Float + FloatConst>(x: &[Complex<T>], inverse: bool) -> Vec<Complex<T>> {
+    let n = x.len();
+    if n == 1 {
+        return x.to_vec();
+    }
+    let even: Vec<_> = x.iter().step_by(2).cloned().collect();
+    let odd: Vec<_> = x.iter().skip(1).step_by(2).cloned().collect();
+    let (even, odd) = (fft(&even, inverse), fft(&odd, inverse));
+
+    let sign = if inverse { T::one() } else { -T::one() };
+    let tau = T::TAU() * sign / T::from(n).unwrap();
+    let mut out = vec![Complex::new(T::zero(), T::zero()); n];
+    for k in 0..n / 2 {
+        // The butterfly: both halves of the output come from the same product
+        let t = Complex::from_polar(T::one(), tau * T::from(k).unwrap()) * odd[k];
+        out[k] = even[k] + t;
+        out[k + n / 2] = even[k] - t;
+    }
+    out
+}
+
+fn max_error<T: Float>(a: &[Complex<T>], b: &[Complex<T>]) -> T {
+    a.iter()
+        .zip(b)
+        .fold(T::zero(), |e, (&x, &y)| e.max((x - y).norm()))
+}
+
+// A signal with a whole number of periods of two frequencies, whose transform is zero
+// except in the bins of those frequencies and their mirror images.
+fn signal<T: Float + FloatConst>(n: usize) -> (Vec<Complex<T>>, Vec<Complex<T>>) {
+    let n_t = T::from(n).unwrap();
+    let x = (0..n)
+        .map(|j| {
+            let t = T::TAU() * T::from(j).unwrap() / n_t;
+            let three = T::from(3).unwrap();
+            let five = T::from(5).unwrap();
+            let half = T::from(0.5).unwrap();
+            Complex::new((three * t).cos() + half * (five * t).sin(), T::zero())
+        })
+        .collect();
+
+    let zero = Complex::new(T::zero(), T::zero());
+    let mut expected = vec![zero; n];
+    let half_n = n_t / T::from(2).unwrap();
+    let quarter_n = n_t / T::from(4).unwrap();
+    expected[3] = Complex::new(half_n, T::zero());
+    expected[n - 3] = Complex::new(half_n, T::zero());
+    expected[5] = Complex::new(T::zero(), -quarter_n);
+    expected[n - 5] = Complex::new(T::zero(), quarter_n);
+    (x, expected)
+}
+
+#[test]
+fn double_norm() {
+    let z = Complex::new(dd!(1), dd!(1));
+    assert!((z.norm() - Double::SQRT_2).abs() < dd!(1e-31));
+    assert_eq!(Complex::new(dd!(3), dd!(4)).norm(), dd!(5));
+}
+
+#[test]
+fn quad_norm() {
+    let z = Complex::new(qd!(1), qd!(1));
+    assert!((z.norm() - Quad::SQRT_2).abs() < qd!(1e-63));
+    assert!((Complex::new(qd!(3), qd!(4)).norm() - qd!(5)).abs() < qd!(1e-62));
+}
+
+#[test]
+fn double_euler() {
+    let z = Complex::new(Double::ZERO, Double::PI).exp();
+    assert!((z.re + Double::ONE).abs() < dd!(1e-31));
+    assert!(z.im.abs() < dd!(1e-31));
+}
+
+#[test]
+fn quad_euler() {
+    let z = Complex::new(Quad::ZERO, Quad::PI).exp();
+    assert!((z.re + Quad::ONE).abs() < qd!(1e-62));
+    assert!(z.im.abs() < qd!(1e-62));
+}
+
+#[test]
+fn quad_exp_ln() {
+    let z = Complex::new(qd!(0.75), qd!(-2));
+    let w = z.exp().ln();
+    assert!((w - z).norm() < qd!(1e-62));
+
+    let polar = z.to_polar();
+    assert!((Complex::from_polar(polar.0, polar.1) - z).norm() < qd!(1e-62));
+    assert!((z * z.inv() - Complex::new(Quad::ONE, Quad::ZERO)).norm() < qd!(1e-62));
+}
+
+#[test]
+fn double_fft() {
+    let (x, expected) = signal::<Double>(64);
+    let y = fft(&x, false);
+    assert!(max_error(&y, &expected) < dd!(1e-29));
+
+    let back: Vec<_> = fft(&y, true).iter().map(|&z| z / dd!(64)).collect();
+    assert!(max_error(&back, &x) < dd!(1e-30));
+}
+
+#[test]
+fn quad_fft() {
+    let (x, expected) = signal::<Quad>(64);
+    let y = fft(&x, false);
+    assert!(max_error(&y, &expected) < qd!(1e-60));
+
+    let back: Vec<_> = fft(&y, true).iter().map(|&z| z / qd!(64)).collect();
+    assert!(max_error(&back, &x) < qd!(1e-61));
+}
@@ -0,0 +1,108 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Using `Double` and `Quad` with nalgebra through the traits of num-traits.
+//
+// The matrices are built and multiplied by nalgebra itself, which needs only `Zero`, `One`,
+// and the arithmetic operators. nalgebra's own `try_inverse` requires simba's
+// `ComplexField`, which num-traits can't provide, so the inverse comes from a Gauss-Jordan
+// elimination written against `num_traits::Float`, the way any code generic over that
+// trait would be.
+
+#![cfg(feature = "num-traits")]
+
+use nalgebra::Matrix3;
+use num_traits::Float;
+use qd::{Double, Quad};
+
+fn invert<T: Float + nalgebra::Scalar>(m: &Matrix3<T>) -> Option<Matrix3<T>> {
+    let mut a = m.clone();
+    let mut inv = Matrix3::from_fn(|i, j| if i == j { T::one() } else { T::zero() });
+
+    for col in 0..3 {
+        let pivot =
+            (col..3).max_by(|&i, &j| a[(i, col)].abs().partial_cmp(&a[(j, col)].abs()).unwrap())?;
+        if a[(pivot, col)].is_zero() {
+            return None;
+        }
+        a.swap_rows(col, pivot);
+        inv.swap_rows(col, pivot);
+
+        let p = a[(col, col)].recip();
+        for j in 0..3 {
+            a[(col, j)] = a[(col, j)] * p;
+            inv[(col, j)] = inv[(col, j)] * p;
+        }
+        for i in (0..3).filter(|&i| i != col) {
+            let f = a[(i, col)];
+            for j in 0..3 {
+                a[(i, j)] = a[(i, j)] - f * a[(col, j)];
+                inv[(i, j)] = inv[(i, j)] - f * inv[(col, j)];
+            }
+        }
+    }
+    Some(inv)
+}
+
+fn max_error<T: Float + nalgebra::Scalar>(m: &Matrix3<T>) -> T
+where
+    Matrix3<T>: std::ops::Mul<Output = Matrix3<T>>,
+{
+    let product = m.clone() * invert(m).unwrap();
+    let identity = Matrix3::<T>::identity();
+    product
+        .iter()
+        .zip(identity.iter())
+        .fold(T::zero(), |e, (&x, &y)| e.max((x - y).abs()))
+}
+
+fn hilbert<T: Float + nalgebra::Scalar>() -> Matrix3<T> {
+    Matrix3::from_fn(|i, j| T::from(i + j + 1).unwrap().recip())
+}
+
+#[test]
+fn double_inverse() {
+    let m = hilbert::<Double>();
+    assert!(max_error(&m) < Double::from(1e-30));
+
+    let m = Matrix3::new(
+        Double::from(2),
+        Double::PI,
+        Double::E,
+        Double::SQRT_2,
+        Double::from(-1),
+        Double::LN_2,
+        Double::from(7),
+        Double::from(0.5),
+        Double::from(3),
+    );
+    assert!(max_error(&m) < Double::from(1e-30));
+}
+
+#[test]
+fn quad_inverse() {
+    let m = hilbert::<Quad>();
+    assert!(max_error(&m) < Quad::from(1e-62));
+
+    let m = Matrix3::new(
+        Quad::from(2),
+        Quad::PI,
+        Quad::E,
+        Quad::SQRT_2,
+        Quad::from(-1),
+        Quad::LN_2,
+        Quad::from(7),
+        Quad::from(0.5),
+        Quad::from(3),
+    );
+    assert!(max_error(&m) < Quad::from(1e-62));
+}
+
+#[test]
+fn singular() {
+    // A column of zeros stays zero through every step of the elimination
+    let m = Matrix3::from_fn(|i, j| Quad::from((3 * i + j) as i32 * (j != 1) as i32));
+    assert!(invert(&m).is_none());
+}
@@ -0,0 +1,417 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Pinned results for every public math function.
+//
+// qd promises that the bits of a computed result don't change from one release to the
+// next unless the changelog says so. This file is how that promise is kept. The corpus in
+// `pinned_results.txt` records the exact component bits of every case listed in `cases`
+// below, and `pinned_results` fails if any of them come out differently. That includes
+// changes that make a result *more* accurate; those are welcome, but they have to be made
+// on purpose.
+//
+// When a change to the numerics is intentional:
+//
+// 1. Regenerate the corpus with `cargo test --test pinned_results -- --ignored`. This
+//    rewrites every entry and stamps the corpus with the current crate version and a
+//    checksum of the entries.
+// 2. List the change under "Numerical changes" in the section of `CHANGELOG.md` for that
+//    version, in the same commit.
+//
+// `corpus_marker` enforces both steps. The checksum means that entries can't change
+// without the corpus being regenerated (and therefore stamped with the version in which
+// they changed), and the stamped version has to have numerical changes listed in the
+// changelog.
+//
+// Some functions start from the platform's own `f64` approximation before refining it, and
+// a different starting point can occasionally change the last bit of a result. The corpus
+// is generated on x86_64 Linux, so that's where the results themselves are checked.
+
+use qd::{Double, Float, Quad};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+
+const CORPUS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/pinned_results.txt");
+const CHANGELOG: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/CHANGELOG.md");
+
+// Arguments for the cases, grouped by the domains of the functions that use them. They're
+// strings so that parsing is pinned along with everything else.
+const ANY: &[&str] = &["-2.5", "-0.1", "0.1", "0.75", "3", "100.5"];
+const POSITIVE: &[&str] = &["0.1", "0.75", "3", "100.5", "1e10"];
+const MODERATE: &[&str] = &["-20", "-2.5", "-0.1", "0.1", "0.75", "3", "20"];
+const UNIT: &[&str] = &["-0.9", "-0.1", "0.1", "0.75"];
+const ABOVE_ONE: &[&str] = &["1.5", "3", "100.5", "1e10"];
+const NEAR_ZERO: &[&str] = &["-0.5", "-1e-10", "1e-10", "0.75", "3"];
+const PAIRS: &[(&str, &str)] = &[("0.1", "3"), ("-2.5", "0.75"), ("100.5", "-0.1")];
+const LITERALS: &[&str] = &[
+    "0.1",
+    "-123456789.987654321",
+    "3.14159265358979323846264338327950288419716939937510582097494459230781640628620899",
+    "6.02214076e23",
+    "1e-300",
+];
+
+// What the cases need from each type beyond `Float`.
+trait Pinned: Float {
+    const NAME: &'static str;
+
+    fn bits(self) -> Vec<u64>;
+    fn add_bounded(self, other: Self) -> (Self, f64);
+    fn mul_bounded(self, other: Self) -> (Self, f64);
+    fn div_bounded(self, other: Self) -> (Self, f64);
+}
+
+impl Pinned for Double {
+    const NAME: &'static str = "Double";
+
+    fn bits(self) -> Vec<u64> {
+        self.to_bits().to_vec()
+    }
+
+    fn add_bounded(self, other: Double) -> (Double, f64) {
+        Double::add_bounded(self, other)
+    }
+
+    fn mul_bounded(self, other: Double) -> (Double, f64) {
+        Double::mul_bounded(self, other)
+    }
+
+    fn div_bounded(self, other: Double) -> (Double, f64) {
+        Double::div_bounded(self, other)
+    }
+}
+
+impl Pinned for Quad {
+    const NAME: &'static str = "Quad";
+
+    fn bits(self) -> Vec<u64> {
+        self.to_bits().to_vec()
+    }
+
+    fn add_bounded(self, other: Quad) -> (Quad, f64) {
+        Quad::add_bounded(self, other)
+    }
+
+    fn mul_bounded(self, other: Quad) -> (Quad, f64) {
+        Quad::mul_bounded(self, other)
+    }
+
+    fn div_bounded(self, other: Quad) -> (Quad, f64) {
+        Quad::div_bounded(self, other)
+    }
+}
+
+fn num<T: Pinned>(s: &str) -> T {
+    match s.parse() {
+        Ok(x) => x,
+        Err(_) => panic!("bad argument {:?}", s),
+    }
+}
+
+// A case's expression, like `Quad::powf(3, 0.75)`, along with the bits of its result.
+struct Cases(Vec<(String, Vec<u64>)>);
+
+impl Cases {
+    fn pin(&mut self, expr: String, bits: Vec<u64>) {
+        self.0.push((expr, bits));
+    }
+
+    fn unary<T: Pinned>(&mut self, name: &str, f: fn(T) -> T, args: &[&str]) {
+        for a in args {
+            let expr = format!("{}::{}({})", T::NAME, name, a);
+            self.pin(expr, f(num(a)).bits());
+        }
+    }
+
+    fn binary<T: Pinned>(&mut self, name: &str, f: fn(T, T) -> T, args: &[(&str, &str)]) {
+        for (a, b) in args {
+            let expr = format!("{}::{}({}, {})", T::NAME, name, a, b);
+            self.pin(expr, f(num(a), num(b)).bits());
+        }
+    }
+
+    fn integer<T: Pinned>(&mut self, name: &str, f: fn(T, i32) -> T, args: &[(&str, i32)]) {
+        for (a, n) in args {
+            let expr = format!("{}::{}({}, {})", T::NAME, name, a, n);
+            self.pin(expr, f(num(a), *n).bits());
+        }
+    }
+
+    fn pair<T: Pinned>(&mut self, name: &str, f: fn(T) -> (T, T), args: &[&str]) {
+        for a in args {
+            let expr = format!("{}::{}({})", T::NAME, name, a);
+            let (x, y) = f(num(a));
+            self.pin(expr, [x.bits(), y.bits()].concat());
+        }
+    }
+
+    fn bounded<T: Pinned>(&mut self, name: &str, f: fn(T, T) -> (T, f64)) {
+        for (a, b) in PAIRS {
+            let expr = format!("{}::{}({}, {})", T::NAME, name, a, b);
+            let (x, bound) = f(num(a), num(b));
+            self.pin(expr, [x.bits(), vec![bound.to_bits()]].concat());
+        }
+    }
+
+    fn all<T: Pinned>(&mut self) {
+        for s in LITERALS {
+            self.pin(format!("{}::from_str({})", T::NAME, s), num::<T>(s).bits());
+        }
+
+        self.binary::<T>("add", |a, b| a + b, PAIRS);
+        self.binary::<T>("sub", |a, b| a - b, PAIRS);
+        self.binary::<T>("mul", |a, b| a * b, PAIRS);
+        self.binary::<T>("div", |a, b| a / b, PAIRS);
+        self.binary::<T>("rem", |a, b| a % b, PAIRS);
+        self.bounded::<T>("add_bounded", T::add_bounded);
+        self.bounded::<T>("mul_bounded", T::mul_bounded);
+        self.bounded::<T>("div_bounded", T::div_bounded);
+
+        self.unary::<T>("abs", T::abs, ANY);
+        self.unary::<T>("floor", T::floor, ANY);
+        self.unary::<T>("ceil", T::ceil, ANY);
+        self.unary::<T>("round", T::round, ANY);
+        self.unary::<T>("trunc", T::trunc, ANY);
+        self.unary::<T>("fract", T::fract, ANY);
+        self.unary::<T>("signum", T::signum, ANY);
+
+        self.unary::<T>("sqr", T::sqr, ANY);
+        self.unary::<T>("sqrt", T::sqrt, POSITIVE);
+        self.unary::<T>("cbrt", T::cbrt, ANY);
+        self.unary::<T>("recip", T::recip, ANY);
+        self.integer::<T>("ldexp", T::ldexp, &[("0.1", 10), ("-2.5", -3)]);
+        self.integer::<T>(
+            "mul_i32",
+            T::mul_i32,
+            &[("0.1", 10), ("-2.5", -3), ("12345", 99)],
+        );
+        self.integer::<T>("nroot", T::nroot, &[("100.5", 5), ("-2.5", 3), ("0.75", 4)]);
+        self.integer::<T>("powi", T::powi, &[("3", 40), ("-2.5", 7), ("0.1", -3)]);
+        self.binary::<T>(
+            "powf",
+            T::powf,
+            &[
+                ("3", "0.75"),
+                ("0.75", "-2.5"),
+                ("100.5", "0.1"),
+                ("-2.5", "3"),
+            ],
+        );
+
+        self.unary::<T>("exp", T::exp, MODERATE);
+        self.unary::<T>("exp_m1", T::exp_m1, MODERATE);
+        self.unary::<T>("ln", T::ln, POSITIVE);
+        self.unary::<T>("ln_1p", T::ln_1p, NEAR_ZERO);
+        self.unary::<T>("log10", T::log10, POSITIVE);
+        self.unary::<T>("log2", T::log2, POSITIVE);
+        self.binary::<T>("log", T::log, &[("100.5", "3"), ("0.75", "0.1")]);
+        self.unary::<T>("gamma", T::gamma, ANY);
+        self.unary::<T>("ln_gamma", T::ln_gamma, ANY);
+        self.unary::<T>("erf", T::erf, MODERATE);
+        self.unary::<T>("erfc", T::erfc, MODERATE);
+
+        self.unary::<T>("sin", T::sin, ANY);
+        self.unary::<T>("cos", T::cos, ANY);
+        self.unary::<T>("tan", T::tan, ANY);
+        self.pair::<T>("sin_cos", T::sin_cos, ANY);
+        self.unary::<T>("asin", T::asin, UNIT);
+        self.unary::<T>("acos", T::acos, UNIT);
+        self.unary::<T>("atan", T::atan, ANY);
+        self.binary::<T>(
+            "atan2",
+            T::atan2,
+            &[
+                ("0.1", "3"),
+                ("-2.5", "0.75"),
+                ("100.5", "-0.1"),
+                ("-0.1", "-3"),
+            ],
+        );
+
+        self.unary::<T>("sinh", T::sinh, MODERATE);
+        self.unary::<T>("cosh", T::cosh, MODERATE);
+        self.unary::<T>("tanh", T::tanh, MODERATE);
+        self.pair::<T>("sinh_cosh", T::sinh_cosh, MODERATE);
+        self.unary::<T>("asinh", T::asinh, ANY);
+        self.unary::<T>("acosh", T::acosh, ABOVE_ONE);
+        self.unary::<T>("atanh", T::atanh, UNIT);
+
+        self.unary::<T>("softplus", T::softplus, MODERATE);
+        self.unary::<T>("logistic", T::logistic, MODERATE);
+        self.unary::<T>("log_logistic", T::log_logistic, MODERATE);
+        for (a, b, k) in &[("0.1", "0.75", "0.5"), ("-2.5", "3", "2")] {
+            let (x, y, k) = (num::<T>(a), num::<T>(b), num::<T>(k));
+            let args = format!("{}, {}, {}", a, b, k);
+            self.pin(
+                format!("{}::smooth_min({})", T::NAME, args),
+                x.smooth_min(y, k).bits(),
+            );
+            self.pin(
+                format!("{}::smooth_max({})", T::NAME, args),
+                x.smooth_max(y, k).bits(),
+            );
+        }
+    }
+}
+
+fn cases() -> Vec<(String, Vec<u64>)> {
+    let mut cases = Cases(Vec::new());
+    cases.all::<Double>();
+    cases.all::<Quad>();
+    cases.0
+}
+
+fn entry(expr: &str, bits: &[u64]) -> String {
+    let mut line = format!("{} =", expr);
+    for b in bits {
+        write!(line, " {:016x}", b).unwrap();
+    }
+    line
+}
+
+// FNV-1a, which is plenty to notice that the entries have changed.
+fn checksum(entries: &[&str]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    for b in entries.join("\n").bytes() {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+struct Corpus {
+    version: String,
+    checksum: u64,
+    entries: Vec<String>,
+}
+
+fn read_corpus() -> Corpus {
+    let text = fs::read_to_string(CORPUS).expect("the corpus should be readable");
+    let mut version = None;
+    let mut checksum = None;
+    let mut entries = Vec::new();
+
+    for line in text
+        .lines()
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+    {
+        if let Some(v) = line.strip_prefix("version ") {
+            version = Some(v.to_string());
+        } else if let Some(c) = line.strip_prefix("checksum ") {
+            checksum = Some(u64::from_str_radix(c, 16).expect("the checksum should be hex"));
+        } else {
+            entries.push(line.to_string());
+        }
+    }
+    Corpus {
+        version: version.expect("the corpus should have a version"),
+        checksum: checksum.expect("the corpus should have a checksum"),
+        entries,
+    }
+}
+
+#[test]
+#[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+fn pinned_results() {
+    let corpus = read_corpus();
+    let mut pinned = BTreeMap::new();
+    for line in &corpus.entries {
+        let mut parts = line.splitn(2, " = ");
+        let expr = parts.next().unwrap();
+        let bits = parts.next().expect("each entry should have bits");
+        pinned.insert(expr.to_string(), bits.to_string());
+    }
+
+    let mut failures = Vec::new();
+    let mut count = 0;
+    for (expr, bits) in cases() {
+        count += 1;
+        let actual = entry(&expr, &bits);
+        match pinned.get(&expr) {
+            Some(expected) if actual.ends_with(&format!("= {}", expected)) => {}
+            Some(expected) => failures.push(format!(
+                "{}\n  pinned: {}\n  actual: {}",
+                expr,
+                expected,
+                &actual[expr.len() + 3..]
+            )),
+            None => failures.push(format!("{} is not in the corpus", expr)),
+        }
+    }
+    if count != pinned.len() {
+        failures.push(format!(
+            "the corpus has {} entries, but there are {} cases",
+            pinned.len(),
+            count
+        ));
+    }
+
+    assert!(
+        failures.is_empty(),
+        "\n{} pinned results changed; if that's intentional, see tests/pinned_results.rs\n\n{}\n",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn corpus_marker() {
+    let corpus = read_corpus();
+    let entries: Vec<&str> = corpus.entries.iter().map(|e| e.as_str()).collect();
+    assert!(
+        checksum(&entries) == corpus.checksum,
+        "the corpus entries have changed without the corpus being regenerated"
+    );
+
+    // The version stamped on the corpus has to have its numerical changes listed in the
+    // changelog, in a "Numerical changes" list within the section for that version.
+    let changelog = fs::read_to_string(CHANGELOG).expect("the changelog should be readable");
+    let section = changelog
+        .split("\n## ")
+        .skip(1)
+        .find(|s| s.split_whitespace().next() == Some(corpus.version.as_str()))
+        .unwrap_or_else(|| panic!("the changelog has no section for {}", corpus.version));
+    let changes = section
+        .split("\n### ")
+        .find(|s| s.starts_with("Numerical changes"))
+        .unwrap_or_else(|| {
+            panic!(
+                "the changelog lists no numerical changes for {}",
+                corpus.version
+            )
+        });
+    assert!(
+        changes.lines().any(|l| l.starts_with("- ")),
+        "the numerical changes listed for {} are empty",
+        corpus.version
+    );
+}
+
+// Rewrites the corpus with the current results, stamped with the current crate version.
+// This is ignored so that it only runs when asked for.
+#[test]
+#[ignore]
+fn regenerate() {
+    let lines: Vec<String> = cases()
+        .iter()
+        .map(|(expr, bits)| entry(expr, bits))
+        .collect();
+    let entries: Vec<&str> = lines.iter().map(|l| l.as_str()).collect();
+
+    let mut text = String::new();
+    writeln!(
+        text,
+        "# Pinned results for qd. Don't edit this by hand; see tests/pinned_results.rs."
+    )
+    .unwrap();
+    writeln!(text, "version {}", env!("CARGO_PKG_VERSION")).unwrap();
+    writeln!(text, "checksum {:016x}", checksum(&entries)).unwrap();
+    writeln!(text).unwrap();
+    for line in lines {
+        writeln!(text, "{}", line).unwrap();
+    }
+    fs::write(CORPUS, text).expect("the corpus should be writable");
+}
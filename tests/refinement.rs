@@ -0,0 +1,180 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Mixed-precision iterative refinement, built from the kernels in `qd::slice`.
+//
+// The system is solved in `f64` with Gaussian elimination, which on an ill-conditioned
+// matrix only gets a few digits right. Each step of refinement then calculates the
+// residual b - Ax in `Double` precision with `axpy_f64_into_double`, solves for a
+// correction in `f64` using the same factorization, and adds the correction to the
+// `Double` solution. As long as the matrix isn't so ill-conditioned that the `f64` solve
+// gets nothing right, every step gains about as many digits as the first solve did, until
+// the error is down to about the condition number of the matrix times the precision of
+// the residual. For the matrix here, that's a condition number of around 10^10 times
+// `Double` precision, where an `f64` residual would stop at 10^10 times `f64` precision.
+
+use qd::{slice, Double};
+
+const N: usize = 8;
+
+// The entries of the matrix are multiples of 1 / `SCALE`
+const SCALE: f64 = (1 << 20) as f64;
+
+// An LU factorization with partial pivoting, stored in place, along with the row that
+// ended up in each position.
+struct Lu {
+    a: Vec<Vec<f64>>,
+    perm: Vec<usize>,
+}
+
+impl Lu {
+    fn new(mut a: Vec<Vec<f64>>) -> Lu {
+        let n = a.len();
+        let mut perm: Vec<usize> = (0..n).collect();
+        for k in 0..n {
+            let p = (k..n)
+                .max_by(|&i, &j| a[i][k].abs().partial_cmp(&a[j][k].abs()).unwrap())
+                .unwrap();
+            a.swap(k, p);
+            perm.swap(k, p);
+            for i in k + 1..n {
+                a[i][k] /= a[k][k];
+                for j in k + 1..n {
+                    a[i][j] -= a[i][k] * a[k][j];
+                }
+            }
+        }
+        Lu { a, perm }
+    }
+
+    fn solve(&self, b: &[f64]) -> Vec<f64> {
+        let n = b.len();
+        let mut x: Vec<f64> = self.perm.iter().map(|&i| b[i]).collect();
+        for i in 0..n {
+            for j in 0..i {
+                x[i] -= self.a[i][j] * x[j];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                x[i] -= self.a[i][j] * x[j];
+            }
+            x[i] /= self.a[i][i];
+        }
+        x
+    }
+}
+
+// A Hilbert matrix with its entries rounded to multiples of 1 / `SCALE`. It's just as
+// ill-conditioned as the real thing, but its entries are short enough that there's no
+// question of which matrix is being solved: it's this `f64` one, not an approximation.
+fn matrix() -> Vec<Vec<f64>> {
+    (0..N)
+        .map(|i| {
+            (0..N)
+                .map(|j| (1.0 / (i + j + 1) as f64 * SCALE).round() / SCALE)
+                .collect()
+        })
+        .collect()
+}
+
+// Columns of the matrix, which is how the residual is accumulated: b - Ax is b minus the
+// sum of each column times the matching element of x.
+fn columns(a: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    (0..N)
+        .map(|j| a.iter().map(|row| row[j]).collect())
+        .collect()
+}
+
+// The residual b - Ax, with no rounding except in adding up the `Double` sums. Each
+// element of x contributes its two components separately, so that each product is an
+// `f64` times an `f64`.
+fn residual(cols: &[Vec<f64>], b: &[Double], x: &[Double]) -> Vec<Double> {
+    let mut r = b.to_vec();
+    for (col, xj) in cols.iter().zip(x) {
+        slice::axpy_f64_into_double(-xj[0], col, &mut r);
+        slice::axpy_f64_into_double(-xj[1], col, &mut r);
+    }
+    r
+}
+
+fn max_error(x: &[Double], expected: &[Double]) -> f64 {
+    x.iter()
+        .zip(expected)
+        .map(|(&a, &b)| f64::from((a - b).abs()))
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn refinement_converges() {
+    let a = matrix();
+    let cols = columns(&a);
+
+    // The right-hand side is made from a known solution
+    let expected: Vec<Double> = (0..N)
+        .map(|i| Double::from((1.0 / (i as f64 + 3.0), 0.0)))
+        .collect();
+    let b = residual(&cols, &[Double::ZERO; N], &expected)
+        .iter()
+        .map(|&r| -r)
+        .collect::<Vec<_>>();
+
+    let lu = Lu::new(a);
+    let heads: Vec<f64> = b.iter().map(|&v| f64::from(v)).collect();
+    let mut x: Vec<Double> = lu
+        .solve(&heads)
+        .iter()
+        .map(|&v| Double::from((v, 0.0)))
+        .collect();
+    let first = max_error(&x, &expected);
+    assert!(
+        first > 1e-13,
+        "the f64 solve is too good to need refinement"
+    );
+
+    for _ in 0..10 {
+        let r = residual(&cols, &b, &x);
+        let rh: Vec<f64> = r.iter().map(|&v| f64::from(v)).collect();
+        let d = lu.solve(&rh);
+        slice::axpy_f64_into_double(1.0, &d, &mut x);
+    }
+    let last = max_error(&x, &expected);
+    assert!(last < 1e-25, "refinement only got to {:e}", last);
+}
+
+#[test]
+fn naive_residual_stalls() {
+    // The same refinement with a residual calculated in `f64` can't get past the accuracy
+    // of `f64`, which is why the residual needs the extra precision
+    let a = matrix();
+    let cols = columns(&a);
+    let expected: Vec<Double> = (0..N)
+        .map(|i| Double::from((1.0 / (i as f64 + 3.0), 0.0)))
+        .collect();
+    let b = residual(&cols, &[Double::ZERO; N], &expected)
+        .iter()
+        .map(|&r| -r)
+        .collect::<Vec<_>>();
+
+    let lu = Lu::new(a.clone());
+    let heads: Vec<f64> = b.iter().map(|&v| f64::from(v)).collect();
+    let mut x: Vec<Double> = lu
+        .solve(&heads)
+        .iter()
+        .map(|&v| Double::from((v, 0.0)))
+        .collect();
+
+    for _ in 0..10 {
+        let r: Vec<f64> = (0..N)
+            .map(|i| {
+                let ax: f64 = (0..N).map(|j| a[i][j] * f64::from(x[j])).sum();
+                heads[i] - ax
+            })
+            .collect();
+        let d = lu.solve(&r);
+        slice::axpy_f64_into_double(1.0, &d, &mut x);
+    }
+    assert!(max_error(&x, &expected) > 1e-16);
+}
@@ -0,0 +1,262 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// The signs of zeros, going into functions and coming out of them.
+//
+// Every function that has an `f64` counterpart is checked against it on both zeros, and
+// the result has to be the same down to the sign of a zero (or NaN when the `f64` result
+// is). Functions without a counterpart are checked against the value that IEEE 754 would
+// give them: odd functions keep the sign of a zero argument and even ones don't. The
+// same goes for the arithmetic operators, including the cases where both operands are
+// zero, and for rounding functions whose results are zero though their arguments aren't.
+
+macro_rules! unary {
+    ($($f:ident: $g:expr;)*) => {
+        $(
+            for &z in &ZEROS {
+                let g: fn(f64) -> f64 = $g;
+                check(n(z).$f(), g(z), &format!("{}({:?})", stringify!($f), z));
+            }
+        )*
+    };
+}
+
+macro_rules! binary {
+    ($T:ident; $($name:literal: $f:expr, $g:expr;)*) => {
+        let others = [0.0, -0.0, 1.0, -1.0, 2.5, -2.5, f64::INFINITY, f64::NEG_INFINITY];
+        $(
+            let f: fn($T, $T) -> $T = $f;
+            let g: fn(f64, f64) -> f64 = $g;
+            for &z in &ZEROS {
+                for &y in &others {
+                    let what = format!("{}({:?}, {:?})", $name, z, y);
+                    check(f(n(z), n(y)), g(z, y), &what);
+                    let what = format!("{}({:?}, {:?})", $name, y, z);
+                    check(f(n(y), n(z)), g(y, z), &what);
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! signed_zero_tests {
+    ($T:ident, $m:ident) => {
+        mod $m {
+            use qd::$T;
+
+            const ZEROS: [f64; 2] = [0.0, -0.0];
+
+            fn n(x: f64) -> $T {
+                $T::from(x)
+            }
+
+            // Checks that `actual` starts with `expected`, with the same sign if it's a
+            // zero. Whole numbers (which include zeros and infinities) must have nothing in
+            // the lower components; anything else, like π from `atan2`, is just rounded
+            // differently by `f64`.
+            fn check(actual: $T, expected: f64, what: &str) {
+                let same = if expected.is_nan() {
+                    actual.is_nan()
+                } else {
+                    actual[0].to_bits() == expected.to_bits()
+                        && (expected != expected.trunc() || actual == n(expected))
+                };
+                assert!(same, "{} = {:?}, expected {:?}", what, actual, expected);
+            }
+
+            #[test]
+            fn arithmetic() {
+                binary!($T;
+                    "add": |x, y| x + y, |x, y| x + y;
+                    "sub": |x, y| x - y, |x, y| x - y;
+                    "mul": |x, y| x * y, |x, y| x * y;
+                    "div": |x, y| x / y, |x, y| x / y;
+                    "rem": |x, y| x % y, |x, y| x % y;
+                    "mul_add": |x, y| x.mul_add(y, $T::NEG_ZERO), |x, y| x.mul_add(y, -0.0);
+                );
+                for &z in &ZEROS {
+                    check(-n(z), -z, &format!("-{:?}", z));
+                    for &k in &[0, 1, 3, -1, -3] {
+                        let what = format!("{:?}.mul_i32({})", z, k);
+                        check(n(z).mul_i32(k), z * k as f64, &what);
+                        let what = format!("2.5.mul_i32({})", k);
+                        check(n(z + 2.5).mul_i32(k), 2.5 * k as f64, &what);
+                    }
+                }
+                // Exact cancellation gives a positive zero
+                check($T::PI - $T::PI, 0.0, "π - π");
+                check(-$T::PI + $T::PI, 0.0, "-π + π");
+                check($T::PI * n(-0.0), -0.0, "π * -0");
+                check(n(-0.0) / $T::PI, -0.0, "-0 / π");
+                check($T::NEG_ONE / $T::INFINITY, -0.0, "-1 / ∞");
+            }
+
+            #[test]
+            fn comparison() {
+                binary!($T;
+                    "min": |x, y| x.min(y), |x, y| x.min(y);
+                    "max": |x, y| x.max(y), |x, y| x.max(y);
+                    "copysign": |x, y| x.copysign(y), |x, y| x.copysign(y);
+                    "hypot": |x, y| x.hypot(y), |x, y| x.hypot(y);
+                    "powf": |x, y| x.powf(y), |x, y| x.powf(y);
+                    "rem_euclid": |x, y| x.rem_euclid(y), |x, y| x.rem_euclid(y);
+                    "div_euclid": |x, y| x.div_euclid(y), |x, y| x.div_euclid(y);
+                );
+                assert!($T::NEG_ZERO == $T::ZERO);
+                assert!($T::NEG_ZERO.is_zero() && $T::ZERO.is_zero());
+                assert!($T::NEG_ZERO.is_sign_negative() && $T::ZERO.is_sign_positive());
+                unary!(
+                    abs: f64::abs;
+                    signum: f64::signum;
+                    recip: f64::recip;
+                    sqr: |x| x * x;
+                    next_up: f64::next_up;
+                    next_down: f64::next_down;
+                );
+                for &z in &ZEROS {
+                    check(n(z).ldexp(5), z, "ldexp");
+                    check(n(z).powi(3), z.powi(3), "powi(3)");
+                    check(n(z).powi(2), z.powi(2), "powi(2)");
+                    check(n(z).powi(-1), z.powi(-1), "powi(-1)");
+                }
+            }
+
+            #[test]
+            fn rounding() {
+                unary!(
+                    floor: f64::floor;
+                    ceil: f64::ceil;
+                    round: f64::round;
+                    round_ties_even: f64::round_ties_even;
+                    trunc: f64::trunc;
+                    fract: f64::fract;
+                );
+                for &z in &ZEROS {
+                    let (int, frac) = n(z).modf();
+                    check(int, z, &format!("modf({:?}).0", z));
+                    check(frac, z, &format!("modf({:?}).1", z));
+                }
+                // Arguments that round to zero from either side
+                for &x in &[0.3, -0.3, 0.5, -0.5, 0.7, -0.7] {
+                    check(n(x).floor(), x.floor(), &format!("floor({:?})", x));
+                    check(n(x).ceil(), x.ceil(), &format!("ceil({:?})", x));
+                    check(n(x).round(), x.round(), &format!("round({:?})", x));
+                    let what = format!("round_ties_even({:?})", x);
+                    check(n(x).round_ties_even(), x.round_ties_even(), &what);
+                    check(n(x).trunc(), x.trunc(), &format!("trunc({:?})", x));
+                }
+                // Numbers whose first components are integers and whose lower components
+                // take them to just inside (-1, 1)
+                let x = $T::ONE - n(1e-20);
+                check(x.floor(), 0.0, "floor(1 - 1e-20)");
+                check((-x).ceil(), -0.0, "ceil(-1 + 1e-20)");
+                check((-x).trunc(), -0.0, "trunc(-1 + 1e-20)");
+            }
+
+            #[test]
+            fn algebraic() {
+                unary!(
+                    sqrt: f64::sqrt;
+                    cbrt: f64::cbrt;
+                );
+                for &z in &ZEROS {
+                    check(n(z).nroot(3), z.cbrt(), "nroot(3)");
+                    // IEEE 754's `rootn` is +0 for either zero when the root is even
+                    check(n(z).nroot(2), 0.0, "nroot(2)");
+                }
+                assert!($T::NEG_ZERO.checked_sqrt() == Some($T::NEG_ZERO));
+            }
+
+            #[test]
+            fn transcendental() {
+                unary!(
+                    exp: f64::exp;
+                    exp2: f64::exp2;
+                    exp10: |_| 1.0;
+                    exp_m1: f64::exp_m1;
+                    ln: f64::ln;
+                    ln_1p: f64::ln_1p;
+                    log10: f64::log10;
+                    log2: f64::log2;
+                    sinh: f64::sinh;
+                    cosh: f64::cosh;
+                    tanh: f64::tanh;
+                    asinh: f64::asinh;
+                    acosh: f64::acosh;
+                    atanh: f64::atanh;
+                    logistic: |_| 0.5;
+                );
+                for &z in &ZEROS {
+                    let (s, c) = n(z).sinh_cosh();
+                    check(s, z, "sinh_cosh().0");
+                    check(c, 1.0, "sinh_cosh().1");
+                    check(n(z).log(n(10.0)), z.log(10.0), "log(10)");
+                }
+            }
+
+            #[test]
+            fn trigonometric() {
+                unary!(
+                    sin: f64::sin;
+                    cos: f64::cos;
+                    tan: f64::tan;
+                    asin: f64::asin;
+                    atan: f64::atan;
+                    to_degrees: f64::to_degrees;
+                    to_radians: f64::to_radians;
+                    rem_2pi: |x| x;
+                    sin_pi: |x| x;
+                    cos_pi: |_| 1.0;
+                    tan_pi: |x| x;
+                    sin_deg: |x| x;
+                    cos_deg: |_| 1.0;
+                    tan_deg: |x| x;
+                );
+                binary!($T;
+                    "atan2": |y, x| y.atan2(x), |y, x| y.atan2(x);
+                );
+                for &z in &ZEROS {
+                    let (s, c) = n(z).sin_cos();
+                    check(s, z, "sin_cos().0");
+                    check(c, 1.0, "sin_cos().1");
+                }
+                // Whole half-turns have sines of zero with the sign of the argument
+                check(n(-1.0).sin_pi(), -0.0, "sin_pi(-1)");
+                check(n(2.0).sin_pi(), 0.0, "sin_pi(2)");
+                check(n(-180.0).sin_deg(), -0.0, "sin_deg(-180)");
+            }
+
+            #[test]
+            fn special() {
+                unary!(
+                    erf: |x| x;
+                    erfc: |_| 1.0;
+                    gamma: |x| 1.0 / x;
+                    ln_gamma: |_| f64::INFINITY;
+                    j0: |_| 1.0;
+                    j1: |x| x;
+                    y0: |_| f64::NEG_INFINITY;
+                    y1: |_| f64::NEG_INFINITY;
+                );
+            }
+
+            #[test]
+            fn formatting() {
+                assert_eq!(format!("{}", $T::NEG_ZERO), "-0");
+                assert_eq!(format!("{}", $T::ZERO), "0");
+                assert_eq!(format!("{:e}", $T::NEG_ZERO), "-0e0");
+                assert_eq!(format!("{:.2}", $T::NEG_ZERO), "-0.00");
+                for s in &["-0", "-0.0", "-0e10", "-0.000"] {
+                    check(s.parse::<$T>().unwrap(), -0.0, s);
+                }
+                check("0".parse::<$T>().unwrap(), 0.0, "0");
+                check("-1e-400".parse::<$T>().unwrap(), -0.0, "-1e-400");
+            }
+        }
+    };
+}
+
+signed_zero_tests!(Double, double);
+signed_zero_tests!(Quad, quad);
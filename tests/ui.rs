@@ -0,0 +1,18 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Literals that `dd!` and `qd!` refuse to convert, and the compile errors they give, along
+// with some in `pass` that they have to take.
+//
+// The expected errors are in the `.stderr` files next to each test. They include the
+// compiler's own formatting, so they may need to be regenerated (by running this with the
+// environment variable `TRYBUILD=overwrite`) for a different version of the compiler.
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    t.pass("tests/ui/pass/*.rs");
+}
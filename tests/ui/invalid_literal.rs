@@ -0,0 +1,11 @@
+// Literals that don't convert to a number at all.
+
+use qd::{dd, qd};
+
+fn main() {
+    let _ = dd!(300u8);
+    let _ = dd!(1i128);
+    let _ = qd!(-1u32);
+    let _ = dd!(1e400);
+    let _ = qd!(b"1");
+}
@@ -0,0 +1,13 @@
+// A float literal with more significant digits than an `f64` holds would be rounded anywhere
+// but in the macro, so it's an error instead. Subnormals hold fewer digits still.
+
+use qd::{dd, qd, try_dd, Double, Quad};
+
+const PI: Double = dd!(3.14159265358979323846);
+
+fn main() {
+    let tenth = qd!(0.1000000000000000055511151231257827);
+    let e = try_dd!(2.718281828459045235360287);
+    let tiny = dd!(4.9e-324);
+    let _ = (PI, tenth, e, tiny, Quad::ZERO);
+}
@@ -0,0 +1,12 @@
+// The smallest subnormals have room for only a digit or two, but a literal with those
+// digits is exactly what printing one gives, so the macros take it.
+
+use qd::{dd, qd, Double, Quad};
+
+const MIN: Double = dd!(5e-324);
+
+fn main() {
+    assert!(MIN == Double::from(5e-324));
+    assert!(qd!(-1e-323) == Quad::from(-1e-323));
+    assert!(dd!(1e-45f32) == dd!("1e-45"));
+}